@@ -0,0 +1,58 @@
+//! Shared extraction of decorators and annotations on declarations.
+//!
+//! Python and TypeScript decorators and Java annotations sit in one of three
+//! places: as children of the declaration (a TypeScript class, a Python
+//! `decorated_definition`), inside its `modifiers` (Java), or as the
+//! declaration's preceding siblings (TypeScript methods in a class body, a
+//! function inside a Python `decorated_definition`, an exported class). One
+//! walk covers every analyzer.
+
+use tree_sitter::Node;
+
+use super::ParsedFile;
+
+/// Decorator and annotation node kinds.
+const ANNOTATION_KINDS: &[&str] = &["decorator", "annotation", "marker_annotation"];
+
+/// Children of a declaration that hold its annotations.
+const HOLDER_KINDS: &[&str] = &["modifiers"];
+
+/// Names of the decorators and annotations attached to a declaration node, in
+/// source order, without `@` or arguments: `@app.route("/")` is `app.route`,
+/// `@RequestMapping(value = "/api")` is `RequestMapping`.
+pub(crate) fn extract_annotations(parsed: &ParsedFile, node: Node) -> Vec<String> {
+    let mut nodes = Vec::new();
+
+    let mut sibling = node.prev_named_sibling();
+    while let Some(prev) = sibling.filter(|s| ANNOTATION_KINDS.contains(&s.kind())) {
+        nodes.push(prev);
+        sibling = prev.prev_named_sibling();
+    }
+    nodes.reverse();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if ANNOTATION_KINDS.contains(&child.kind()) {
+            nodes.push(child);
+        } else if HOLDER_KINDS.contains(&child.kind()) {
+            let mut holder = child.walk();
+            nodes.extend(
+                child
+                    .named_children(&mut holder)
+                    .filter(|c| ANNOTATION_KINDS.contains(&c.kind())),
+            );
+        }
+    }
+
+    nodes
+        .into_iter()
+        .map(|n| annotation_name(parsed.node_text(n)))
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+fn annotation_name(text: &str) -> String {
+    let text = text.trim_start_matches('@');
+    let end = text.find('(').unwrap_or(text.len());
+    text[..end].split_whitespace().collect()
+}
@@ -0,0 +1,67 @@
+//! Shared counting of `await` points in function bodies.
+//!
+//! An async function that never suspends is synchronous code with an async
+//! signature. Each analyzer lists the node kinds its grammar uses for
+//! awaiting in an `AwaitSyntax`; the walk is shared. Nested functions are
+//! not descended into, since their awaits belong to them.
+
+use tree_sitter::Node;
+
+/// Grammar-specific node kinds for awaiting.
+pub(crate) struct AwaitSyntax {
+    /// Await expression kinds (`await x`, `x.await`).
+    pub await_kinds: &'static [&'static str],
+    /// Statement kinds that await when they carry the given keyword child
+    /// (Python `async for`/`async with`, JavaScript `for await`).
+    pub awaiting_statements: &'static [(&'static str, &'static str)],
+    /// Future-producing block kinds that count as an await when returned
+    /// (Rust `async` blocks): the caller awaits them instead.
+    pub returned_future_kinds: &'static [&'static str],
+    /// Nested function kinds whose awaits are not counted.
+    pub function_kinds: &'static [&'static str],
+}
+
+fn has_keyword(node: Node, keyword: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|c| !c.is_named() && c.kind() == keyword);
+    found
+}
+
+/// Whether a node is the value a body hands back: the operand of a
+/// `return`, or the body's last expression.
+fn is_returned(node: Node, body: Node) -> bool {
+    let Some(parent) = node.parent() else {
+        return false;
+    };
+    if parent.kind() == "return_expression" || parent.kind() == "return_statement" {
+        return true;
+    }
+    let tail = if parent.kind() == "expression_statement" { parent } else { node };
+    tail.parent() == Some(body) && body.named_child(body.named_child_count().saturating_sub(1)) == Some(tail)
+}
+
+/// Count the await points of a function body.
+pub(crate) fn count_awaits(body: Node, syntax: &AwaitSyntax) -> usize {
+    let mut count = 0;
+    let mut stack = vec![body];
+
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        if node != body && syntax.function_kinds.contains(&kind) {
+            continue;
+        }
+        if syntax.await_kinds.contains(&kind)
+            || syntax
+                .awaiting_statements
+                .iter()
+                .any(|(statement, keyword)| kind == *statement && has_keyword(node, keyword))
+            || (syntax.returned_future_kinds.contains(&kind) && is_returned(node, body))
+        {
+            count += 1;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    count
+}
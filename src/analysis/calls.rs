@@ -0,0 +1,91 @@
+//! Shared extraction of call sites by callee name.
+//!
+//! Rules that ask "who calls this?" need every call in the project, keyed by
+//! the name being called. Grammars differ in the call node kind and the field
+//! holding the callee (`function`, `name`, `constructor`, `type`); each
+//! analyzer lists its pairs, and the callee is reduced to its last name
+//! segment: `client.fetch()`, `Client::fetch()`, and `fetch()` all call
+//! `fetch`.
+
+use tree_sitter::Node;
+
+use super::{CallSite, ParsedFile, Span};
+
+/// Fields that lead from a qualified or member callee to its last name.
+const NAME_FIELDS: &[&str] = &["name", "field", "attribute", "property", "function", "type"];
+
+/// The last name segment of a callee expression.
+pub(crate) fn callee_name<'a>(parsed: &'a ParsedFile, node: Node) -> Option<&'a str> {
+    let mut node = node;
+    loop {
+        // A plain name, not a scoped path like `x::c`
+        if node.kind().ends_with("identifier") && node.named_child_count() == 0 {
+            return Some(parsed.node_text(node));
+        }
+        node = NAME_FIELDS
+            .iter()
+            .find_map(|field| node.child_by_field_name(field))?;
+    }
+}
+
+/// Collect call sites from a parsed file, in source order.
+///
+/// `call_kinds` pairs each call node kind with the field holding its callee.
+/// Calls whose callee has no name (`(f || g)()`, `handlers[0]()`) are skipped.
+pub(crate) fn extract_call_sites(
+    parsed: &ParsedFile,
+    call_kinds: &[(&str, &str)],
+) -> Vec<CallSite> {
+    let mut calls = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        if let Some((_, field)) = call_kinds.iter().find(|(kind, _)| *kind == node.kind()) {
+            if let Some(callee) = node
+                .child_by_field_name(field)
+                .and_then(|c| callee_name(parsed, c))
+            {
+                calls.push(CallSite {
+                    callee: callee.to_string(),
+                    span: Span::from_node(node),
+                });
+            }
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    calls.sort_by_key(|c| c.span.start_byte);
+    calls
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers};
+    use std::path::Path;
+
+    fn callees(ext: &str, source: &str) -> Vec<String> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        facts.call_sites.into_iter().map(|c| c.callee).collect()
+    }
+
+    #[test]
+    fn test_extract_call_sites_per_language() {
+        let cases: [(&str, &str, &[&str]); 5] = [
+            ("rs", "fn a() { b(); x::c(1); s.d(); Cache::new(); parse::<u8>(t); }\n", &["b", "c", "d", "new", "parse"]),
+            ("java", "class A { void a() { b(); this.c(); new Cache(); } }\n", &["b", "c", "Cache"]),
+            ("py", "def a():\n    b()\n    x.c(d())\n", &["b", "c", "d"]),
+            ("js", "function a() { b(); x.y.c(); new Cache(); handlers[0](); }\n", &["b", "c", "Cache"]),
+            ("ts", "const a = (): void => { b(); x.c<number>(); };\n", &["b", "c"]),
+        ];
+
+        for (ext, source, expected) in cases {
+            assert_eq!(callees(ext, source), expected, "{} {:?}", ext, source);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Shared comment extraction.
+//!
+//! Every grammar exposes comments as nodes whose kind ends in "comment"
+//! (`comment`, `line_comment`, `block_comment`, `multiline_comment`), so the
+//! file header, generated-code markers, and comment line counts can be found
+//! without per-language configuration.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use tree_sitter::Node;
+
+use super::{Comment, LineCounts, ParsedFile, Span};
+
+/// Node kinds that may precede the header without ending it (`#!` lines).
+const PREAMBLE_KINDS: &[&str] = &["hashbang_line", "shebang_line", "shebang"];
+
+/// Markers code generators stamp on their output, matched per comment line
+/// with the comment syntax removed: Go's `Code generated ... DO NOT EDIT.`,
+/// `@generated`, and the protoc and Thrift banners.
+static GENERATED_MARKER: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"^Code generated .* DO NOT EDIT\.$|@generated\b|Generated by the protocol buffer compiler\.|Autogenerated by Thrift Compiler",
+    )
+    .unwrap()
+});
+
+/// Whether a node is a comment in any grammar.
+pub(crate) fn is_comment(node: Node) -> bool {
+    node.kind().ends_with("comment")
+}
+
+/// Last row containing comment text (some grammars include the trailing newline).
+fn last_row(node: Node) -> usize {
+    let end = node.end_position();
+    if end.column == 0 && end.row > node.start_position().row {
+        end.row - 1
+    } else {
+        end.row
+    }
+}
+
+/// Extract the first comment block of a file.
+///
+/// The block is the run of comment nodes at the top of the file, before any
+/// code, that are not separated by a blank line. Returns `None` if the file
+/// starts with code.
+pub(crate) fn extract_header_comment(parsed: &ParsedFile) -> Option<Comment> {
+    let root = parsed.tree.root_node();
+    let mut cursor = root.walk();
+    let mut nodes = root
+        .children(&mut cursor)
+        .skip_while(|n| PREAMBLE_KINDS.contains(&n.kind()));
+
+    let first = nodes.next().filter(|n| is_comment(*n))?;
+    let mut last = first;
+    let mut text = parsed.node_text(first).trim_end().to_string();
+
+    for node in nodes {
+        if !is_comment(node) || node.start_position().row > last_row(last) + 1 {
+            break;
+        }
+        text.push('\n');
+        text.push_str(parsed.node_text(node).trim_end());
+        last = node;
+    }
+
+    let mut span = Span::from_node(first);
+    let end = Span::from_node(last);
+    span.end_byte = end.end_byte;
+    span.end_line = end.end_line;
+    span.end_col = end.end_col;
+
+    Some(Comment { text, span })
+}
+
+/// Check the comments at the top of a file, before any code, for a
+/// generated-code marker.
+pub(crate) fn is_generated(parsed: &ParsedFile) -> bool {
+    let root = parsed.tree.root_node();
+    let mut cursor = root.walk();
+    let leading = root
+        .children(&mut cursor)
+        .skip_while(|n| PREAMBLE_KINDS.contains(&n.kind()))
+        .take_while(|n| is_comment(*n));
+
+    for node in leading {
+        for line in parsed.node_text(node).lines() {
+            let line = line
+                .trim()
+                .trim_start_matches(['/', '*', '#', '-', '!'])
+                .trim_end_matches(['/', '*'])
+                .trim();
+            if GENERATED_MARKER.is_match(line) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+/// Whether a node is a docstring: a string that is a statement of its own
+/// in a module or block (Python).
+fn is_docstring(node: Node) -> bool {
+    node.kind() == "string"
+        && node.parent().is_some_and(|stmt| {
+            stmt.kind() == "expression_statement"
+                && stmt.named_child_count() == 1
+                && stmt.parent().is_some_and(|p| matches!(p.kind(), "module" | "block"))
+        })
+}
+
+/// Count the code and comment lines of a file from its comment and
+/// docstring nodes.
+pub(crate) fn line_counts(parsed: &ParsedFile) -> LineCounts {
+    // Byte ranges of comments and docstrings, in source order
+    let mut ranges = Vec::new();
+    let mut cursor = parsed.tree.walk();
+    'walk: loop {
+        let node = cursor.node();
+        let is_text = is_comment(node) || is_docstring(node);
+        if is_text {
+            ranges.push(node.start_byte()..node.end_byte());
+        }
+        if !is_text && cursor.goto_first_child() {
+            continue;
+        }
+        while !cursor.goto_next_sibling() {
+            if !cursor.goto_parent() {
+                break 'walk;
+            }
+        }
+    }
+
+    let mut counts = LineCounts::default();
+    let mut ranges = ranges.into_iter().peekable();
+    let mut offset = 0;
+    for line in parsed.source_str().split_inclusive('\n') {
+        let start = offset;
+        offset += line.len();
+        let Some(indent) = line.find(|c: char| !c.is_whitespace()) else {
+            continue;
+        };
+        let first = start + indent;
+        while ranges.peek().is_some_and(|r| r.end <= first) {
+            ranges.next();
+        }
+        if ranges.peek().is_some_and(|r| r.start <= first) {
+            counts.comment += 1;
+        } else {
+            counts.code += 1;
+        }
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, FileFacts};
+    use std::path::Path;
+
+    fn facts(ext: &str, source: &str) -> FileFacts {
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("file.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        analyzer.extract_facts(&parsed).unwrap()
+    }
+
+    #[test]
+    fn test_header_comment_languages() {
+        let cases = [
+            ("go", "// Copyright 2024 Acme Corp\n// SPDX-License-Identifier: MIT\n\npackage main\n"),
+            ("rs", "// Copyright 2024 Acme Corp\n// SPDX-License-Identifier: MIT\n\nfn main() {}\n"),
+            ("py", "#!/usr/bin/env python3\n# Copyright 2024 Acme Corp\n# SPDX-License-Identifier: MIT\nimport os\n"),
+            ("ts", "/*\n * Copyright 2024 Acme Corp\n * SPDX-License-Identifier: MIT\n */\nexport const x = 1;\n"),
+            ("java", "/* Copyright 2024 Acme Corp */\n// SPDX-License-Identifier: MIT\npackage a;\n"),
+        ];
+        for (ext, source) in cases {
+            let header = facts(ext, source).header_comment.unwrap_or_else(|| panic!("{}", ext));
+            assert!(header.text.contains("Copyright 2024 Acme Corp"), "{}", ext);
+            assert!(header.text.contains("SPDX-License-Identifier"), "{}: {}", ext, header.text);
+        }
+    }
+
+    #[test]
+    fn test_header_comment_stops_at_blank_line_and_code() {
+        let f = facts("go", "// Copyright 2024 Acme Corp\n\n// Package main does things.\npackage main\n");
+        let header = f.header_comment.unwrap();
+        assert_eq!(header.text, "// Copyright 2024 Acme Corp");
+        assert_eq!(header.span.start_line, 1);
+        assert_eq!(header.span.end_line, 1);
+
+        let f = facts("go", "package main\n\n// Copyright 2024 Acme Corp\n");
+        assert!(f.header_comment.is_none());
+    }
+
+    #[test]
+    fn test_generated_markers() {
+        let generated = [
+            ("go", "// Copyright 2024 Acme Corp\n\n//go:build linux\n\n// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n"),
+            ("ts", "/**\n * @generated SignedSource<<abc123>>\n */\nexport const x = 1;\n"),
+            ("py", "# -*- coding: utf-8 -*-\n# Generated by the protocol buffer compiler.  DO NOT EDIT!\nimport os\n"),
+            ("java", "/**\n * Autogenerated by Thrift Compiler (0.19.0)\n *\n * DO NOT EDIT UNLESS YOU ARE SURE THAT YOU KNOW WHAT YOU ARE DOING\n */\npackage a;\n"),
+            ("rs", "// @generated by diesel CLI\nfn main() {}\n"),
+        ];
+        for (ext, source) in generated {
+            assert!(facts(ext, source).is_generated, "{}", ext);
+        }
+
+        let handwritten = [
+            // The marker must come before any code
+            ("go", "package api\n\n// Code generated by protoc-gen-go. DO NOT EDIT.\n"),
+            // and be the whole Go marker line
+            ("go", "// Code generated by hand, then edited.\npackage api\n"),
+            ("py", "# Parses the @generatedAt field\nimport os\n"),
+        ];
+        for (ext, source) in handwritten {
+            assert!(!facts(ext, source).is_generated, "{}: {}", ext, source);
+        }
+    }
+
+    #[test]
+    fn test_line_counts() {
+        use crate::analysis::LineCounts;
+
+        let cases = [
+            ("go", "// Package a does things.\npackage a\n\n/*\n Block\n*/\nfunc A() {} // trailing\n", (2, 4)),
+            ("rs", "//! Crate docs\n\n/// Adds.\nfn add(a: i32) -> i32 {\n    a + 1\n}\n", (3, 2)),
+            ("py", "\"\"\"Module\ndocs.\"\"\"\n\ndef f():\n    \"\"\"Doc.\"\"\"\n    x = \"\"\"not a docstring\"\"\"\n    # note\n    return x\n", (3, 4)),
+            ("ts", "/**\n * Docs\n */\nexport const x = 1;\n", (1, 3)),
+        ];
+        for (ext, source, (code, comment)) in cases {
+            assert_eq!(facts(ext, source).lines, LineCounts { code, comment }, "{}", ext);
+        }
+    }
+}
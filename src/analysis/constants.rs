@@ -0,0 +1,232 @@
+//! Shared extraction of constant groups and their values.
+//!
+//! A Go `const (...)` block, the variants of an enum, and a run of adjacent
+//! Rust `const` items each form a group. For every member the value
+//! expression is kept as written, so a rule can tell `A = 0; B = 0; C = 0`
+//! from a real mapping. Each analyzer lists the node kinds its grammar uses
+//! in a `ConstantSyntax`; the walk is shared.
+
+use tree_sitter::Node;
+
+use super::markers::has_marker;
+use super::{ConstantGroup, ConstantMember, ParsedFile, Span};
+
+/// Grammar-specific node kinds for constant groups.
+pub(crate) struct ConstantSyntax {
+    /// Declarations holding a group: enums, Go const declarations.
+    pub declaration_kinds: &'static [&'static str],
+    /// Bodies inside a declaration that hold its members (`enum_variant_list`).
+    pub body_kinds: &'static [&'static str],
+    /// Member nodes; one may declare several names (Swift `case b = 1, c = 2`).
+    pub member_kinds: &'static [&'static str],
+    /// Node kinds naming a member; one standing alone in a body is a member
+    /// without a value (TypeScript `enum E { A, B }`).
+    pub name_kinds: &'static [&'static str],
+    /// Standalone constant declarations grouped with adjacent ones (Rust `const_item`).
+    pub adjacent_kinds: &'static [&'static str],
+    /// Whether a member without a value repeats the previous member's
+    /// expression (Go const blocks) rather than getting an implicit one.
+    pub repeats_previous: bool,
+}
+
+/// Node kinds allowed between adjacent constants without ending the run.
+fn is_between_kind(kind: &str) -> bool {
+    kind.ends_with("comment") || kind == "attribute_item"
+}
+
+/// Collect groups of at least two constants from a parsed file, in source order.
+pub(crate) fn extract_constant_groups(parsed: &ParsedFile, syntax: &ConstantSyntax) -> Vec<ConstantGroup> {
+    let mut groups = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        if syntax.declaration_kinds.contains(&node.kind()) {
+            let members = declaration_members(parsed, node, syntax);
+            if members.len() >= 2 {
+                groups.push(ConstantGroup {
+                    name: node
+                        .child_by_field_name("name")
+                        .map(|n| parsed.node_text(n).to_string())
+                        .unwrap_or_default(),
+                    members,
+                    has_marker: marked(parsed, &[node]),
+                    span: Span::from_node(node),
+                });
+            }
+        }
+
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        if !syntax.adjacent_kinds.is_empty() {
+            groups.extend(adjacent_runs(parsed, &children, syntax));
+        }
+        stack.extend(children.into_iter().rev());
+    }
+
+    groups.sort_by_key(|g| g.span.start_byte);
+    groups
+}
+
+/// Members of a declaration, from its direct children and those of its body.
+fn declaration_members(parsed: &ParsedFile, decl: Node, syntax: &ConstantSyntax) -> Vec<ConstantMember> {
+    let mut members = Vec::new();
+    let mut cursor = decl.walk();
+    for child in decl.children(&mut cursor) {
+        if syntax.body_kinds.contains(&child.kind()) {
+            let mut body_cursor = child.walk();
+            for item in child.children(&mut body_cursor) {
+                if syntax.name_kinds.contains(&item.kind()) {
+                    members.push(ConstantMember {
+                        name: parsed.node_text(item).to_string(),
+                        value: None,
+                        line: item.start_position().row + 1,
+                    });
+                } else if syntax.member_kinds.contains(&item.kind()) {
+                    members.extend(member_values(parsed, item, syntax));
+                }
+            }
+        } else if syntax.member_kinds.contains(&child.kind()) {
+            members.extend(member_values(parsed, child, syntax));
+        }
+    }
+
+    if syntax.repeats_previous {
+        let mut previous: Option<String> = None;
+        for member in &mut members {
+            match &member.value {
+                Some(value) => previous = Some(value.clone()),
+                None => member.value = previous.clone(),
+            }
+        }
+    }
+    members
+}
+
+/// Names a member node declares, each with the expression after its `=`.
+fn member_values(parsed: &ParsedFile, member: Node, syntax: &ConstantSyntax) -> Vec<ConstantMember> {
+    let mut values: Vec<ConstantMember> = Vec::new();
+    let mut after_equals = false;
+    let mut cursor = member.walk();
+    for child in member.children(&mut cursor) {
+        if child.kind().ends_with("comment") {
+            continue;
+        }
+        if after_equals && child.is_named() {
+            if let Some(last) = values.last_mut() {
+                last.value = Some(parsed.node_text(child).trim().to_string());
+            }
+            after_equals = false;
+        } else if child.kind() == "=" {
+            after_equals = true;
+        } else if syntax.name_kinds.contains(&child.kind()) {
+            values.push(ConstantMember {
+                name: parsed.node_text(child).to_string(),
+                value: None,
+                line: child.start_position().row + 1,
+            });
+        }
+    }
+    values
+}
+
+/// Runs of two or more adjacent standalone constants among siblings.
+fn adjacent_runs(parsed: &ParsedFile, siblings: &[Node], syntax: &ConstantSyntax) -> Vec<ConstantGroup> {
+    let mut groups = Vec::new();
+    let mut run: Vec<Node> = Vec::new();
+    let mut flush = |run: &mut Vec<Node>| {
+        if run.len() >= 2 {
+            let members = run
+                .iter()
+                .flat_map(|item| member_values(parsed, *item, syntax).into_iter().take(1))
+                .collect();
+            let (first, last) = (run[0], run[run.len() - 1]);
+            let mut span = Span::from_node(first);
+            let end = Span::from_node(last);
+            span.end_byte = end.end_byte;
+            span.end_line = end.end_line;
+            span.end_col = end.end_col;
+            groups.push(ConstantGroup {
+                name: String::new(),
+                members,
+                has_marker: marked(parsed, run),
+                span,
+            });
+        }
+        run.clear();
+    };
+
+    for sibling in siblings {
+        if syntax.adjacent_kinds.contains(&sibling.kind()) {
+            run.push(*sibling);
+        } else if !is_between_kind(sibling.kind()) {
+            flush(&mut run);
+        }
+    }
+    flush(&mut run);
+    groups
+}
+
+/// Whether a marker comment is inside the nodes, between them, in the
+/// comment block right above the first, or after the last on its line.
+fn marked(parsed: &ParsedFile, nodes: &[Node]) -> bool {
+    let (Some(first), Some(last)) = (nodes.first(), nodes.last()) else {
+        return false;
+    };
+    let is_marker_comment = |n: Node| n.kind().ends_with("comment") && has_marker(parsed.node_text(n));
+
+    // Comments inside and between the nodes
+    let mut stack = Vec::new();
+    let mut sibling = Some(*first);
+    while let Some(node) = sibling {
+        stack.push(node);
+        if node.id() == last.id() {
+            break;
+        }
+        sibling = node.next_sibling();
+    }
+    while let Some(node) = stack.pop() {
+        if is_marker_comment(node) {
+            return true;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    // Wrappers starting on the same line (`typedef enum {...} E;`) own the comments around it
+    let mut outer_first = *first;
+    let mut outer_last = *last;
+    if nodes.len() == 1 {
+        while let Some(parent) = outer_first.parent() {
+            if parent.parent().is_none() || parent.start_position().row != outer_first.start_position().row {
+                break;
+            }
+            outer_first = parent;
+        }
+        outer_last = outer_first;
+    }
+
+    // The contiguous comment block above
+    let mut line = outer_first.start_position().row;
+    let mut above = outer_first.prev_sibling();
+    while let Some(node) = above {
+        if !node.kind().ends_with("comment") || node.end_position().row + 1 < line {
+            break;
+        }
+        if is_marker_comment(node) {
+            return true;
+        }
+        line = node.start_position().row;
+        above = node.prev_sibling();
+    }
+
+    // A trailing comment on the last line
+    let end_row = outer_last.end_position().row;
+    let mut after = outer_last.next_sibling();
+    while let Some(node) = after.filter(|n| n.start_position().row == end_row) {
+        if is_marker_comment(node) {
+            return true;
+        }
+        after = node.next_sibling();
+    }
+    false
+}
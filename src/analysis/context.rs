@@ -8,10 +8,16 @@
 
 use std::collections::HashMap;
 use std::fs;
+use std::ops::RangeInclusive;
 use std::path::{Path, PathBuf};
-use std::sync::RwLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
 
-use crate::analysis::{get_analyzer, Declaration, DeclarationKind, FileFacts};
+use crate::analysis::{
+    analysis_extension, get_analyzer, is_dockerfile, DockerfileAnalyzer, with_body_node_limit, with_markers, with_parse_timeout, Declaration, DeclarationKind, FileFacts,
+    MarkerVocabulary, StubAnalysis, StubDetector, DEFAULT_MAX_BODY_NODES,
+};
 
 /// Analysis context for a set of files.
 ///
@@ -24,6 +30,28 @@ pub struct AnalysisContext {
     facts_cache: RwLock<HashMap<PathBuf, FileFacts>>,
     /// Mapping from relative path to absolute path.
     path_map: RwLock<HashMap<String, PathBuf>>,
+    /// Function bodies with more syntax nodes have no control flow counted.
+    max_body_nodes: usize,
+    /// Marker words and ticket pattern for TODO-only bodies.
+    markers: Arc<MarkerVocabulary>,
+    /// Files parsed and analyzed, not counting cache hits.
+    files_parsed: AtomicUsize,
+    /// Line ranges blanked out before parsing, keyed by absolute path.
+    masks: RwLock<HashMap<PathBuf, Vec<RangeInclusive<usize>>>>,
+}
+
+/// Replace the text on the given 1-indexed line ranges with spaces. Line
+/// breaks are kept, so lines, columns, and byte offsets elsewhere in the
+/// source do not move.
+pub fn blank_lines(source: &mut [u8], ranges: &[RangeInclusive<usize>]) {
+    let mut line = 1;
+    for byte in source.iter_mut() {
+        if *byte == b'\n' {
+            line += 1;
+        } else if *byte != b'\r' && ranges.iter().any(|r| r.contains(&line)) {
+            *byte = b' ';
+        }
+    }
 }
 
 impl AnalysisContext {
@@ -33,14 +61,53 @@ impl AnalysisContext {
             base_dir: base_dir.as_ref().to_path_buf(),
             facts_cache: RwLock::new(HashMap::new()),
             path_map: RwLock::new(HashMap::new()),
+            max_body_nodes: DEFAULT_MAX_BODY_NODES,
+            markers: Arc::new(MarkerVocabulary::default()),
+            files_parsed: AtomicUsize::new(0),
+            masks: RwLock::new(HashMap::new()),
         }
     }
 
+    /// Set the node count above which a function body's control flow is
+    /// recorded as not computed (default: [`DEFAULT_MAX_BODY_NODES`]).
+    pub fn with_max_body_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_body_nodes = max_nodes;
+        self
+    }
+
+    /// Set the marker vocabulary analyzers use to find bodies holding only a
+    /// TODO-style comment (default: the built-in words).
+    pub fn with_marker_vocabulary(mut self, vocabulary: MarkerVocabulary) -> Self {
+        self.markers = Arc::new(vocabulary);
+        self
+    }
+
     /// Get the base directory.
     pub fn base_dir(&self) -> &Path {
         &self.base_dir
     }
 
+    /// Analyze only the code of a file outside the given line ranges: their
+    /// text is blanked before parsing, so declarations, line counts, and
+    /// everything derived from them leave those lines out. Facts already
+    /// cached for the file are dropped.
+    pub fn mask_lines<P: AsRef<Path>>(&self, path: P, ranges: Vec<RangeInclusive<usize>>) {
+        let path = path.as_ref();
+        let abs_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.base_dir.join(path)
+        };
+        self.facts_cache.write().unwrap().remove(&abs_path);
+        self.masks.write().unwrap().insert(abs_path, ranges);
+    }
+
+    /// How many files this context has parsed and analyzed; answers from
+    /// the cache are not counted.
+    pub fn files_parsed(&self) -> usize {
+        self.files_parsed.load(Ordering::Relaxed)
+    }
+
     /// Analyze a file and cache the results.
     ///
     /// Returns cached facts if already analyzed.
@@ -60,14 +127,11 @@ impl AnalysisContext {
             }
         }
 
-        // Determine language from extension
-        let ext = abs_path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("");
+        // Determine language from extension, or the file's language override
+        let ext = analysis_extension(&abs_path);
 
         let analyzer = get_analyzer(ext);
-        if analyzer.is_none() {
+        if analyzer.is_none() && !is_dockerfile(&abs_path) {
             // Return empty facts for unsupported files
             let rel_path = abs_path
                 .strip_prefix(&self.base_dir)
@@ -78,12 +142,21 @@ impl AnalysisContext {
             return Ok(FileFacts::empty(&rel_path, "unknown"));
         }
 
-        let analyzer = analyzer.unwrap();
-
-        // Read and parse file
-        let source = fs::read(&abs_path)?;
-        let parsed = analyzer.parse(&abs_path, &source)?;
-        let mut facts = analyzer.extract_facts(&parsed)?;
+        // Read and parse file; Dockerfiles are read line by line
+        let mut source = fs::read(&abs_path)?;
+        if let Some(ranges) = self.masks.read().unwrap().get(&abs_path) {
+            blank_lines(&mut source, ranges);
+        }
+        self.files_parsed.fetch_add(1, Ordering::Relaxed);
+        let mut facts = match analyzer {
+            Some(analyzer) => {
+                let parsed = analyzer.parse(&abs_path, &source)?;
+                with_markers(&self.markers, || {
+                    with_body_node_limit(self.max_body_nodes, || analyzer.extract_facts(&parsed))
+                })?
+            }
+            None => DockerfileAnalyzer::new().extract_facts("", &String::from_utf8_lossy(&source)),
+        };
 
         // Store relative path in facts
         let rel_path = abs_path
@@ -107,6 +180,56 @@ impl AnalysisContext {
         Ok(facts)
     }
 
+    /// Analyze a file within a wall-clock budget and cache the results.
+    ///
+    /// Returns `Ok(None)` if parsing and fact extraction took longer than
+    /// `budget`; nothing is cached for the file in that case. Parsing is
+    /// interrupted when the budget runs out; fact extraction is not.
+    pub fn analyze_file_within<P: AsRef<Path>>(
+        &self,
+        path: P,
+        budget: Duration,
+    ) -> anyhow::Result<Option<FileFacts>> {
+        let path = path.as_ref();
+        let start = Instant::now();
+        let result = with_parse_timeout(budget, || self.analyze_file(path));
+
+        if start.elapsed() < budget {
+            return result.map(Some);
+        }
+
+        let abs_path = if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            self.base_dir.join(path)
+        };
+        let mut cache = self.facts_cache.write().unwrap();
+        cache.remove(&abs_path);
+        Ok(None)
+    }
+
+    /// Analyze a file for stubs and per-function complexity.
+    ///
+    /// Returns the detector's findings and each function or method with a
+    /// body paired with its cyclomatic complexity, in source order. Fails
+    /// for files no registered analyzer handles.
+    pub fn analyze_stubs<P: AsRef<Path>>(
+        &self,
+        path: P,
+        detector: &StubDetector,
+    ) -> anyhow::Result<StubAnalysis> {
+        let path = path.as_ref();
+        if get_analyzer(analysis_extension(path)).is_none() {
+            anyhow::bail!(
+                "no analyzer for {} (call hollowcheck::init() to register analyzers)",
+                path.display()
+            );
+        }
+
+        let facts = self.analyze_file(path)?;
+        Ok((detector.detect(&facts), facts.callable_complexity()))
+    }
+
     /// Analyze multiple files.
     ///
     /// Processes files sequentially to maintain deterministic ordering.
@@ -0,0 +1,151 @@
+//! Shared extraction of callables marked deprecated.
+//!
+//! Each language marks deprecation differently: a Rust `#[deprecated]`
+//! attribute, a Java `@Deprecated` annotation or Javadoc `@deprecated` tag, a
+//! Python `@deprecated` decorator or `.. deprecated::` docstring directive,
+//! and a JSDoc `@deprecated` tag in JavaScript and TypeScript. The markers
+//! sit in or just before the declaration, so one walk covers every analyzer.
+
+use tree_sitter::Node;
+
+use super::annotations::extract_annotations;
+use super::{Deprecation, ParsedFile, Span};
+
+/// Nodes that may sit between a declaration and its doc comment.
+const PREFIX_KINDS: &[&str] = &["attribute_item", "decorator", "annotation", "marker_annotation"];
+
+/// Docstring directive marking a Python callable deprecated.
+const DOCSTRING_DIRECTIVE: &str = ".. deprecated::";
+
+/// The node whose siblings hold a declaration's comments: TypeScript
+/// `export function` and Python decorated functions are wrapped.
+fn anchor(node: Node) -> Node {
+    match node.parent() {
+        Some(p) if matches!(p.kind(), "export_statement" | "decorated_definition") => p,
+        _ => node,
+    }
+}
+
+/// Preceding siblings of a declaration up to the first non-prefix node, nearest first.
+fn prefixes(node: Node) -> impl Iterator<Item = Node> {
+    std::iter::successors(anchor(node).prev_named_sibling(), |n| n.prev_named_sibling())
+        .take_while(|n| PREFIX_KINDS.contains(&n.kind()) || n.kind().ends_with("comment"))
+}
+
+/// The marker deprecating a declaration node, as written, if any.
+fn deprecation_marker(parsed: &ParsedFile, node: Node) -> Option<String> {
+    // Annotations and decorators (`@Deprecated`, `@typing_extensions.deprecated`)
+    let annotations = extract_annotations(parsed, node);
+    if let Some(name) = annotations
+        .iter()
+        .find(|a| a.rsplit('.').next().is_some_and(|n| n.eq_ignore_ascii_case("deprecated")))
+    {
+        return Some(format!("@{}", name));
+    }
+
+    for prefix in prefixes(node) {
+        let text = parsed.node_text(prefix);
+        // Rust attributes
+        if prefix.kind() == "attribute_item" {
+            let name = text.trim_start_matches("#[").trim_start();
+            if name.starts_with("deprecated") {
+                return Some("#[deprecated]".to_string());
+            }
+        // JSDoc and Javadoc tags, only in `/** */` comments
+        } else if text.starts_with("/**") && text.contains("@deprecated") {
+            return Some("@deprecated".to_string());
+        }
+    }
+
+    // Python docstrings
+    let body = node.child_by_field_name("body")?;
+    let first = body.named_child(0).filter(|s| s.kind() == "expression_statement")?;
+    let docstring = first.named_child(0).filter(|s| s.kind() == "string")?;
+    parsed
+        .node_text(docstring)
+        .contains(DOCSTRING_DIRECTIVE)
+        .then(|| DOCSTRING_DIRECTIVE.to_string())
+}
+
+/// Collect the callables marked deprecated in a parsed file, in source order.
+///
+/// `declaration_kinds` lists the callable node kinds to check; each must have
+/// a `name` field.
+pub(crate) fn extract_deprecations(parsed: &ParsedFile, declaration_kinds: &[&str]) -> Vec<Deprecation> {
+    let mut deprecations = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        if declaration_kinds.contains(&node.kind()) {
+            let name = node.child_by_field_name("name");
+            if let (Some(name), Some(marker)) = (name, deprecation_marker(parsed, node)) {
+                deprecations.push(Deprecation {
+                    name: parsed.node_text(name).to_string(),
+                    marker,
+                    span: Span::from_node(node),
+                });
+            }
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    deprecations.sort_by_key(|d| d.span.start_byte);
+    deprecations
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers};
+    use std::path::Path;
+
+    fn deprecated(ext: &str, source: &str) -> Vec<(String, String)> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        facts.deprecations.into_iter().map(|d| (d.name, d.marker)).collect()
+    }
+
+    #[test]
+    fn test_extract_deprecations_per_language() {
+        type Case<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str)]);
+        let cases: [Case; 5] = [
+            (
+                "rs",
+                "/// Old entry point.\n#[deprecated(since = \"1.2\", note = \"use run\")]\npub fn start() {}\n\n#[inline]\npub fn run() {}\n\nimpl Cache {\n    #[deprecated]\n    fn purge(&self) {}\n}\n",
+                &[("start", "#[deprecated]"), ("purge", "#[deprecated]")],
+            ),
+            (
+                "java",
+                "class Api {\n    @Deprecated\n    public void start() {}\n\n    /** @deprecated use {@link #run()} */\n    public void stop() {}\n\n    // @deprecated is only a tag in Javadoc\n    public void run() {}\n}\n",
+                &[("start", "@Deprecated"), ("stop", "@deprecated")],
+            ),
+            (
+                "py",
+                "from typing_extensions import deprecated\n\n@deprecated(\"use run\")\ndef start():\n    pass\n\ndef stop():\n    \"\"\"Stop the server.\n\n    .. deprecated:: 1.2\n       Use :func:`shutdown`.\n    \"\"\"\n\ndef run():\n    \"\"\"Run the server.\"\"\"\n",
+                &[("start", "@deprecated"), ("stop", ".. deprecated::")],
+            ),
+            (
+                "js",
+                "/** @deprecated use run */\nfunction start() {}\n\n/** Runs. */\nfunction run() {}\n",
+                &[("start", "@deprecated")],
+            ),
+            (
+                "ts",
+                "/**\n * Start the server.\n * @deprecated use run\n */\nexport function start(): void {}\n\nclass Server {\n  /** @deprecated */\n  stop(): void {}\n}\n",
+                &[("start", "@deprecated"), ("stop", "@deprecated")],
+            ),
+        ];
+
+        for (ext, source, expected) in cases {
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|(n, m)| (n.to_string(), m.to_string()))
+                .collect();
+            assert_eq!(deprecated(ext, source), expected, "{}", ext);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Shared extraction of doc comments and export status of declarations.
+//!
+//! A doc comment is a comment of the language's doc form (`///`, `/**`, any
+//! comment in Go) ending on the line right above the declaration, or above
+//! the attributes and decorators attached to it, or a Python docstring. A
+//! declaration wrapped by another node, such as a TypeScript `export`
+//! statement, may carry the comment on the wrapper instead. Whether a
+//! declaration is exported follows the language's rule, named by
+//! [`ExportRule`].
+
+use tree_sitter::Node;
+
+use super::ParsedFile;
+
+/// How a language marks a declaration as part of its public API.
+pub(crate) enum ExportRule {
+    /// The name starts with an upper-case letter, and so does the receiver type's (Go).
+    Capitalized,
+    /// Neither the name nor an enclosing class name starts with `_`, and the
+    /// declaration is not nested in a function (Python).
+    NoUnderscore,
+    /// The declaration carries one of these visibility modifiers (`pub`, `public`).
+    Modifier(&'static [&'static str]),
+    /// The declaration, or the class around a method, sits in an `export`
+    /// statement, and a method is not `private` or `protected` (JavaScript, TypeScript).
+    ExportStatement,
+}
+
+/// Grammar-specific node kinds for doc comments and visibility.
+pub(crate) struct DocSyntax {
+    /// Comment node kinds; empty when only docstrings document declarations.
+    pub comment_kinds: &'static [&'static str],
+    /// Prefixes that make a comment a doc comment; any comment counts when empty.
+    pub doc_prefixes: &'static [&'static str],
+    /// Nodes around a declaration whose leading comment documents it
+    /// (`export_statement`, `lexical_declaration`).
+    pub wrapper_kinds: &'static [&'static str],
+    /// Nodes that may sit between a doc comment and the declaration
+    /// (`attribute_item`, `decorator`).
+    pub attached_kinds: &'static [&'static str],
+    /// Whether a string as the first statement of the body documents the
+    /// declaration (Python docstrings).
+    pub docstrings: bool,
+    /// How the language marks a declaration as exported.
+    pub export: ExportRule,
+}
+
+/// Children of a declaration that hold its visibility modifiers.
+const MODIFIER_KINDS: &[&str] = &["visibility_modifier", "modifiers", "accessibility_modifier"];
+
+/// Function node kinds that hide the declarations nested in them.
+const FUNCTION_KINDS: &[&str] = &["function_definition"];
+
+/// Modifier words of a declaration, as written (`pub`, `pub(crate)`, `public`, `static`).
+fn modifiers(parsed: &ParsedFile, node: Node) -> Vec<String> {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .filter(|c| MODIFIER_KINDS.contains(&c.kind()))
+        .flat_map(|c| {
+            parsed
+                .node_text(c)
+                .split_whitespace()
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Python's convention for names outside the public API.
+fn is_private_name(name: &str) -> bool {
+    name.starts_with('_')
+}
+
+/// Whether a declaration node is part of the file's public API.
+///
+/// `receiver` is the type a method belongs to, when the analyzer knows it.
+pub(crate) fn is_exported(
+    parsed: &ParsedFile,
+    node: Node,
+    name: &str,
+    receiver: Option<&str>,
+    syntax: &DocSyntax,
+) -> bool {
+    match &syntax.export {
+        ExportRule::Capitalized => {
+            let capitalized = |s: &str| s.chars().next().is_some_and(char::is_uppercase);
+            capitalized(name) && receiver.is_none_or(capitalized)
+        }
+        ExportRule::NoUnderscore => {
+            if is_private_name(name) {
+                return false;
+            }
+            let mut ancestor = node.parent();
+            while let Some(parent) = ancestor {
+                if FUNCTION_KINDS.contains(&parent.kind()) {
+                    return false;
+                }
+                let class_name = parent
+                    .child_by_field_name("name")
+                    .filter(|_| parent.kind() == "class_definition");
+                if class_name.is_some_and(|n| is_private_name(parsed.node_text(n))) {
+                    return false;
+                }
+                ancestor = parent.parent();
+            }
+            true
+        }
+        ExportRule::Modifier(public) => modifiers(parsed, node).iter().any(|m| public.contains(&m.as_str())),
+        ExportRule::ExportStatement => {
+            if modifiers(parsed, node).iter().any(|m| m == "private" || m == "protected") {
+                return false;
+            }
+            let mut ancestor = node.parent();
+            while let Some(parent) = ancestor {
+                match parent.kind() {
+                    "export_statement" => return true,
+                    // A function body: the declaration is local to it
+                    "statement_block" | "program" => return false,
+                    _ => ancestor = parent.parent(),
+                }
+            }
+            false
+        }
+    }
+}
+
+/// Whether a doc comment ends on the line above `node`, with only attached
+/// nodes (attributes, decorators) in between.
+fn comment_above(parsed: &ParsedFile, node: Node, syntax: &DocSyntax) -> bool {
+    let mut next_row = node.start_position().row;
+    let mut sibling = node.prev_sibling();
+    while let Some(prev) = sibling {
+        // A blank line separates the comment from the declaration
+        if prev.end_position().row + 1 < next_row {
+            return false;
+        }
+        if syntax.comment_kinds.contains(&prev.kind()) {
+            // A trailing comment belongs to the code before it on its line
+            let line_start = prev.start_byte() - prev.start_position().column;
+            if !parsed.source[line_start..prev.start_byte()].iter().all(u8::is_ascii_whitespace) {
+                return false;
+            }
+            let text = parsed.node_text(prev);
+            return syntax.doc_prefixes.is_empty() || syntax.doc_prefixes.iter().any(|p| text.starts_with(p));
+        }
+        if !syntax.attached_kinds.contains(&prev.kind()) {
+            return false;
+        }
+        next_row = prev.start_position().row;
+        sibling = prev.prev_sibling();
+    }
+    false
+}
+
+/// Whether the body of a declaration starts with a string statement.
+fn has_docstring(node: Node) -> bool {
+    let definition = node.child_by_field_name("definition").unwrap_or(node);
+    let Some(body) = definition.child_by_field_name("body") else {
+        return false;
+    };
+    let mut cursor = body.walk();
+    let first = body.named_children(&mut cursor).find(|c| c.kind() != "comment");
+    first
+        .filter(|s| s.kind() == "expression_statement")
+        .and_then(|s| s.named_child(0))
+        .is_some_and(|e| e.kind() == "string")
+}
+
+/// Whether a declaration node has a doc comment or docstring.
+pub(crate) fn has_doc_comment(parsed: &ParsedFile, node: Node, syntax: &DocSyntax) -> bool {
+    if syntax.docstrings && has_docstring(node) {
+        return true;
+    }
+    let mut target = node;
+    loop {
+        if !syntax.comment_kinds.is_empty() && comment_above(parsed, target, syntax) {
+            return true;
+        }
+        match target.parent() {
+            Some(parent) if syntax.wrapper_kinds.contains(&parent.kind()) => target = parent,
+            _ => return false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers};
+    use std::path::Path;
+
+    /// `(name, exported, documented)` of each declaration.
+    fn documented(ext: &str, source: &str) -> Vec<(String, bool, bool)> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        facts
+            .declarations
+            .into_iter()
+            .map(|d| (d.qualified_name(), d.is_exported, d.has_doc))
+            .collect()
+    }
+
+    fn expect(cases: &[(&str, bool, bool)]) -> Vec<(String, bool, bool)> {
+        cases.iter().map(|(n, e, d)| (n.to_string(), *e, *d)).collect()
+    }
+
+    #[test]
+    fn test_go_doc_comments() {
+        let source = "package main\n\n// Load reads the config.\nfunc Load() {}\n\nfunc Save() {}\n\n// helper is private.\nfunc helper() {}\n\n// Server serves.\ntype Server struct{}\n\n// Start starts.\nfunc (s *Server) Start() {}\n\nfunc (s *server) Stop() {}\n\n// Comment with a gap\n\nfunc Gap() {}\n";
+        assert_eq!(
+            documented("go", source),
+            expect(&[
+                ("Load", true, true),
+                ("Save", true, false),
+                ("helper", false, true),
+                ("Server", true, true),
+                ("Server.Start", true, true),
+                ("server.Stop", false, false),
+                ("Gap", true, false),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_rust_doc_comments() {
+        let source = "/// Loads.\n///\n/// Over several lines.\n#[inline]\npub fn load() {}\n\n// Plain comment.\npub fn save() {}\n\npub(crate) fn internal() {}\n\n/** Block doc. */\npub struct Config;\n\nimpl Config {\n    /// Creates.\n    pub fn new() -> Self { Config }\n    fn helper(&self) {} /// trailing\n    pub fn reset(&mut self) {}\n}\n";
+        assert_eq!(
+            documented("rs", source),
+            expect(&[
+                ("load", true, true),
+                ("save", true, false),
+                ("internal", false, false),
+                ("Config", true, true),
+                ("Config.new", true, true),
+                ("Config.helper", false, false),
+                ("Config.reset", true, false),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_python_docstrings() {
+        let source = "# Not a docstring\ndef load():\n    pass\n\n@cache\ndef save():\n    \"\"\"Save it.\"\"\"\n\ndef _helper():\n    \"\"\"Private.\"\"\"\n\nclass Store:\n    \"\"\"A store.\"\"\"\n    def get(self):\n        def inner():\n            pass\n        return inner\n\nclass _Hidden:\n    def run(self):\n        pass\n";
+        assert_eq!(
+            documented("py", source),
+            expect(&[
+                ("load", true, false),
+                // The decorated definition and the function inside it
+                ("save", true, true),
+                ("save", true, true),
+                ("_helper", false, true),
+                ("Store", true, true),
+                ("get", true, false),
+                ("inner", false, false),
+                ("_Hidden", false, false),
+                ("run", false, false),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_typescript_jsdoc() {
+        let source = "/** Loads. */\nexport function load() {}\n\nexport function save() {}\n\nfunction local() {}\n\n/** Formats. */\nexport const format = () => 1;\n\n// Not JSDoc\nexport class Store {\n  /** Gets. */\n  get() {}\n  private cache() {}\n}\n";
+        assert_eq!(
+            documented("ts", source),
+            expect(&[
+                ("load", true, true),
+                ("save", true, false),
+                ("local", false, false),
+                ("format", true, true),
+                ("Store", true, false),
+                ("get", true, true),
+                ("cache", false, false),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_java_javadoc() {
+        let source = "/** A store. */\npublic class Store {\n  /** Gets. */\n  @Override\n  public String get() { return \"\"; }\n  public void put() {}\n  void internal() {}\n}\n";
+        assert_eq!(
+            documented("java", source),
+            expect(&[
+                ("Store", true, true),
+                ("get", true, true),
+                ("put", true, false),
+                ("internal", false, false),
+            ])
+        );
+    }
+}
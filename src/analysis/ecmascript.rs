@@ -0,0 +1,80 @@
+//! Syntax shared by the JavaScript and TypeScript analyzers.
+//!
+//! TypeScript's grammar extends JavaScript's, so async functions, discarded
+//! calls, catch clauses, and `const` declarations look the same in both and
+//! are read by these helpers.
+
+use tree_sitter::Node;
+
+use super::literals::is_constant_name;
+use super::rethrows::Handler;
+use super::{DiscardedCall, ParsedFile, Span};
+
+/// Check whether a function node, or the function assigned by a declarator, is `async`.
+pub(crate) fn is_async_function(node: Node) -> bool {
+    let func = if node.kind() == "variable_declarator" {
+        node.child_by_field_name("value")
+    } else {
+        Some(node)
+    };
+    func.map(|f| f.children(&mut f.walk()).any(|c| c.kind() == "async"))
+        .unwrap_or(false)
+}
+
+/// Extract calls that stand alone as expression statements.
+///
+/// `await f()`, `return f()`, `void f()`, and `f().then(...)` are not bare
+/// calls of `f`, so they are naturally excluded.
+pub(crate) fn extract_discarded_calls(parsed: &ParsedFile) -> Vec<DiscardedCall> {
+    let mut calls = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        if node.kind() == "expression_statement" {
+            if let Some(expr) = node.named_child(0) {
+                if expr.kind() == "call_expression" {
+                    if let Some(func) = expr.child_by_field_name("function") {
+                        calls.push(DiscardedCall {
+                            callee: parsed.node_text(func).to_string(),
+                            span: Span::from_node(expr),
+                        });
+                    }
+                }
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    calls.sort_by_key(|c| c.span.start_byte);
+    calls
+}
+
+/// Returns the body of a `catch` clause and its parameter name.
+pub(crate) fn catch_handler<'a>(parsed: &'a ParsedFile, node: Node<'a>) -> Option<Handler<'a>> {
+    if node.kind() != "catch_clause" {
+        return None;
+    }
+    let error = node
+        .child_by_field_name("parameter")
+        .filter(|param| param.kind() == "identifier")
+        .map(|param| parsed.node_text(param).to_string());
+    Some(Handler { body: node.child_by_field_name("body")?, error })
+}
+
+/// Returns true if the node declares a constant.
+pub(crate) fn is_constant_node(parsed: &ParsedFile, node: Node) -> bool {
+    // Module-level `const` declarations, or any `const` with a SCREAMING_CASE name
+    if node.kind() != "lexical_declaration" || !parsed.node_text(node).starts_with("const") {
+        return false;
+    }
+    let top_level = node
+        .parent()
+        .map(|p| matches!(p.kind(), "program" | "export_statement"))
+        .unwrap_or(false);
+    top_level
+        || node
+            .named_children(&mut node.walk())
+            .filter_map(|d| d.child_by_field_name("name"))
+            .any(|name| is_constant_name(parsed.node_text(name)))
+}
@@ -92,8 +92,29 @@ pub struct Declaration {
     pub span: Span,
     /// For methods: the receiver type (e.g., "Config" for `func (c *Config) Validate()`).
     pub receiver: Option<String>,
+    /// Whether the function is declared `async` (JavaScript, TypeScript, Python, Rust).
+    pub is_async: bool,
+    /// Number of declared parameters, excluding receivers like `self` (callables only).
+    pub param_count: Option<usize>,
     /// Function body information (only for functions/methods).
     pub body: Option<FunctionBody>,
+    /// For interfaces and traits: names of the methods they declare. For
+    /// enums: variant names, which for Go are the constants of an `iota`
+    /// block typed with the declared type, and for TypeScript unions of
+    /// string literals are the literal values.
+    pub members: Vec<String>,
+    /// Decorators and annotations attached to the declaration, without `@`
+    /// or arguments (`app.route`, `RestController`), in source order.
+    /// Extracted for Python, Java, and TypeScript.
+    pub annotations: Vec<String>,
+    /// Whether the declaration is part of the file's public API: capitalized
+    /// in Go, `pub` in Rust, `public` in Java, exported in JavaScript and
+    /// TypeScript, not `_`-prefixed in Python (currently those languages).
+    pub is_exported: bool,
+    /// Whether a doc comment (`///`, `/** */`, a Go comment) sits right above
+    /// the declaration, or a Python docstring opens its body (currently Go,
+    /// Rust, Python, Java, JavaScript/TypeScript).
+    pub has_doc: bool,
 }
 
 impl Declaration {
@@ -114,6 +135,9 @@ pub struct FunctionBody {
     pub span: Span,
     /// Number of statements in the body.
     pub statement_count: usize,
+    /// Number of distinct statements once digit runs and whitespace are
+    /// normalized, so `items[0] = v` and `items[1] = v` count once.
+    pub distinct_statement_count: usize,
     /// Whether the body is empty (no statements).
     pub is_empty: bool,
     /// Whether the body only contains a panic/unimplemented call.
@@ -122,10 +146,82 @@ pub struct FunctionBody {
     pub is_nil_return_only: bool,
     /// Whether the body only contains a TODO comment.
     pub has_only_todo_comment: bool,
+    /// What the body's only statement does, when it has exactly one that
+    /// calls, raises, or returns a name.
+    pub sole_statement: Option<SoleStatement>,
+    /// Number of await points in the body, outside nested functions: `await`
+    /// expressions, `async for`/`async with`/`for await` loops, and a returned
+    /// Rust `async` block (JavaScript, TypeScript, Python, Rust; 0 elsewhere).
+    pub await_count: usize,
     /// Raw text of the body (for detailed analysis).
     pub text: String,
     /// Control flow information for complexity.
     pub control_flow: ControlFlowInfo,
+    /// Signature features that stub exemptions can require (Rust only).
+    pub signature: Vec<SignatureFeature>,
+}
+
+/// The only statement of a function body, by the name its AST targets.
+///
+/// Contract stub signatures match on the name, so one inside a string
+/// literal or beside other statements never counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SoleStatement {
+    /// Calls a function, method, or macro, or returns the call's result:
+    /// the callee as written (`deps.NotWired`, Rust macros without the `!`).
+    Call(String),
+    /// Raises or throws: the exception's type or name (`FeatureNotReady`).
+    Raise(String),
+    /// Returns a named value (`ERR_NOT_IMPLEMENTED`), explicitly or as a tail
+    /// expression. Zero values returned beside it (`return nil, ErrX`) are ignored.
+    Return(String),
+}
+
+impl SoleStatement {
+    /// The targeted name.
+    pub fn target(&self) -> &str {
+        match self {
+            SoleStatement::Call(name) | SoleStatement::Raise(name) | SoleStatement::Return(name) => name,
+        }
+    }
+
+    /// What the body does, for messages (`only raises FeatureNotReady`).
+    pub fn describe(&self) -> String {
+        match self {
+            SoleStatement::Call(name) => format!("only calls {}()", name),
+            SoleStatement::Raise(name) => format!("only raises {}", name),
+            SoleStatement::Return(name) => format!("only returns {}", name),
+        }
+    }
+}
+
+/// A feature of a function's signature that can mark an empty body as
+/// intentional.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureFeature {
+    /// Generic parameters with trait bounds (`fn check_send<T: Send>() {}`).
+    GenericBounds,
+    /// A parameter ignored with the `_` pattern (`fn initialize(_: Internal) {}`).
+    IgnoredParams,
+}
+
+impl SignatureFeature {
+    /// Name used in contracts (`signature: [generic_bounds]`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignatureFeature::GenericBounds => "generic_bounds",
+            SignatureFeature::IgnoredParams => "ignored_params",
+        }
+    }
+
+    /// Parse a contract name, the inverse of [`SignatureFeature::as_str`].
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "generic_bounds" => Some(SignatureFeature::GenericBounds),
+            "ignored_params" => Some(SignatureFeature::IgnoredParams),
+            _ => None,
+        }
+    }
 }
 
 /// Control flow information for cyclomatic complexity calculation.
@@ -149,24 +245,98 @@ pub struct ControlFlowInfo {
     pub ternary_count: usize,
     /// Number of catch/except clauses.
     pub catch_count: usize,
+    /// Whether counting was skipped because the body is too large; the
+    /// counters are all zero.
+    pub not_computed: bool,
 }
 
 impl ControlFlowInfo {
+    /// Highest cyclomatic complexity reported; larger counts are capped.
+    pub const MAX_COMPLEXITY: u32 = 10_000;
+
+    /// Control flow of a body too large to count.
+    pub fn not_computed() -> Self {
+        Self {
+            not_computed: true,
+            ..Self::default()
+        }
+    }
+
+    fn decision_points(&self) -> usize {
+        [
+            self.if_count,
+            self.loop_count,
+            self.case_count,
+            self.select_count,
+            self.and_count,
+            self.or_count,
+            self.ternary_count,
+            self.catch_count,
+        ]
+        .iter()
+        .fold(0usize, |sum, count| sum.saturating_add(*count))
+    }
+
     /// Calculate cyclomatic complexity.
     ///
     /// CC = 1 + decision_points
     /// Decision points: if, for, while, case, &&, ||, ?, catch
-    pub fn cyclomatic_complexity(&self) -> i32 {
-        let decision_points = self.if_count
-            + self.loop_count
-            + self.case_count
-            + self.select_count
-            + self.and_count
-            + self.or_count
-            + self.ternary_count
-            + self.catch_count;
+    ///
+    /// Capped at [`Self::MAX_COMPLEXITY`]; see [`Self::is_saturated`].
+    pub fn cyclomatic_complexity(&self) -> u32 {
+        let complexity = self.decision_points().saturating_add(1);
+        complexity.min(Self::MAX_COMPLEXITY as usize) as u32
+    }
+
+    /// Whether the complexity reached [`Self::MAX_COMPLEXITY`] and was capped.
+    pub fn is_saturated(&self) -> bool {
+        self.decision_points() >= Self::MAX_COMPLEXITY as usize
+    }
+
+    /// The complexity as reported: counted, capped, or not computed.
+    pub fn complexity(&self) -> Complexity {
+        if self.not_computed {
+            Complexity::NotComputed
+        } else if self.is_saturated() {
+            Complexity::Saturated
+        } else {
+            Complexity::Counted(self.cyclomatic_complexity())
+        }
+    }
+}
+
+/// A function's cyclomatic complexity as reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Complexity {
+    /// The counted complexity.
+    Counted(u32),
+    /// At least [`ControlFlowInfo::MAX_COMPLEXITY`]; the count was capped.
+    Saturated,
+    /// The body was too large to count.
+    NotComputed,
+}
+
+impl Complexity {
+    /// The complexity, capped at [`ControlFlowInfo::MAX_COMPLEXITY`];
+    /// `None` when it was not computed.
+    pub fn value(&self) -> Option<u32> {
+        match self {
+            Complexity::Counted(value) => Some(*value),
+            Complexity::Saturated => Some(ControlFlowInfo::MAX_COMPLEXITY),
+            Complexity::NotComputed => None,
+        }
+    }
+}
 
-        1 + decision_points as i32
+impl std::fmt::Display for Complexity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Complexity::Counted(value) => write!(f, "complexity {}", value),
+            Complexity::Saturated => {
+                write!(f, "complexity {} (saturated)", ControlFlowInfo::MAX_COMPLEXITY)
+            }
+            Complexity::NotComputed => write!(f, "complexity: not computed, body too large"),
+        }
     }
 }
 
@@ -177,10 +347,377 @@ pub struct Import {
     pub path: String,
     /// Optional alias (e.g., `import foo "bar"` -> alias is "foo").
     pub alias: Option<String>,
+    /// Whether the import is allowed to fail: Python imports in a `try` whose
+    /// `except` catches `ImportError`.
+    pub optional: bool,
     /// Source span.
     pub span: Span,
 }
 
+/// A string literal found in source code.
+#[derive(Debug, Clone)]
+pub struct StringLiteral {
+    /// The literal contents without quotes or prefixes.
+    pub value: String,
+    /// Source span of the whole literal.
+    pub span: Span,
+    /// Whether the literal initializes a constant/static declaration.
+    pub in_constant: bool,
+    /// Callee text of the nearest enclosing call (e.g., "log.Printf", "println!").
+    pub call_name: Option<String>,
+}
+
+/// A call whose result is discarded because it stands alone as a statement.
+#[derive(Debug, Clone)]
+pub struct DiscardedCall {
+    /// Callee text (e.g., "saveUser", "this.client.send").
+    pub callee: String,
+    /// Source span of the call expression.
+    pub span: Span,
+}
+
+/// Kind of statement that has no effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoopKind {
+    /// Go `_ = x` discarding a plain value.
+    BlankAssignment,
+    /// Python expression statement that is only a literal (`0`, `None`, a non-docstring string).
+    LiteralExpression,
+    /// Python `pass` in a block that has other statements.
+    RedundantPass,
+    /// JavaScript/TypeScript empty statement (`;`).
+    EmptyStatement,
+    /// Bare `return` as the last statement of a function with no results.
+    TrailingReturn,
+}
+
+impl NoopKind {
+    /// Get a human-readable description.
+    pub fn description(&self) -> &'static str {
+        match self {
+            NoopKind::BlankAssignment => "blank assignment of a plain value",
+            NoopKind::LiteralExpression => "literal used as a statement",
+            NoopKind::RedundantPass => "pass next to other statements",
+            NoopKind::EmptyStatement => "empty statement",
+            NoopKind::TrailingReturn => "bare return at the end of the function",
+        }
+    }
+}
+
+/// A statement that does nothing, such as `_ = x` or a trailing `return;`.
+#[derive(Debug, Clone)]
+pub struct NoopStatement {
+    /// What makes the statement a no-op.
+    pub kind: NoopKind,
+    /// Statement text, trimmed.
+    pub text: String,
+    /// Source span of the statement.
+    pub span: Span,
+}
+
+/// An error handler that logs the error and then passes it on unchanged.
+///
+/// A `catch`/`except` clause, or a Go `if err != nil` block, holding both a
+/// logging call and a rethrow of the handled error with nothing added:
+/// `raise`, `throw e`, `return nil, err`.
+#[derive(Debug, Clone)]
+pub struct LoggedRethrow {
+    /// Name the handler binds the error to (`e`, `err`); `None` for a Python
+    /// `except` clause without `as`.
+    pub error: Option<String>,
+    /// Callee of the logging call as written (`logger.exception`, `log.Printf`).
+    pub logger: String,
+    /// The rethrow statement, trimmed.
+    pub rethrow: String,
+    /// Source span of the handler.
+    pub span: Span,
+}
+
+/// A C or C++ function declaration or definition.
+#[derive(Debug, Clone)]
+pub struct FunctionSignature {
+    /// Unqualified function name (`~Cache` for destructors).
+    pub name: String,
+    /// Enclosing class, or the qualifier of an out-of-class definition (`Cache` for `Cache::size`).
+    pub scope: Option<String>,
+    /// Number of declared parameters; `(void)` declares none.
+    pub param_count: Option<usize>,
+    /// Whether this is a definition with a body (or `= default` / `= delete`).
+    pub is_definition: bool,
+    /// Storage-class specifiers (`extern`, `static`, `inline`); declarations
+    /// in an `extern "C"` block count as `extern`.
+    pub specifiers: Vec<String>,
+    /// Source span of the whole declaration or definition.
+    pub span: Span,
+}
+
+impl FunctionSignature {
+    /// Whether the signature carries the given storage-class specifier.
+    pub fn has_specifier(&self, specifier: &str) -> bool {
+        self.specifiers.iter().any(|s| s == specifier)
+    }
+
+    /// The name with its scope, such as `Cache::size`.
+    pub fn qualified_name(&self) -> String {
+        match &self.scope {
+            Some(scope) => format!("{}::{}", scope, self.name),
+            None => self.name.clone(),
+        }
+    }
+}
+
+/// A call expression, by the last name segment of its callee.
+#[derive(Debug, Clone)]
+pub struct CallSite {
+    /// Called name: `fetch` for `fetch()`, `client.fetch()`, and `Client::fetch()`.
+    pub callee: String,
+    /// Source span of the call.
+    pub span: Span,
+}
+
+/// A callable marked deprecated.
+#[derive(Debug, Clone)]
+pub struct Deprecation {
+    /// Callable name.
+    pub name: String,
+    /// The marker as written: `#[deprecated]`, `@Deprecated`, `@deprecated`, `.. deprecated::`.
+    pub marker: String,
+    /// Source span of the declaration.
+    pub span: Span,
+}
+
+/// A callable whose body only forwards its parameters, in order, to another call.
+#[derive(Debug, Clone)]
+pub struct ForwardingCall {
+    /// Name of the forwarding callable.
+    pub name: String,
+    /// Called name, reduced to its last segment like [`CallSite::callee`].
+    pub callee: String,
+    /// For methods: the enclosing class or impl block type.
+    pub receiver: Option<String>,
+    /// Whether the method sits in a Rust `impl Trait for Type` block.
+    pub in_trait_impl: bool,
+    /// Whether the callable is a property accessor (a JavaScript `get`, a Python `@property`).
+    pub is_accessor: bool,
+    /// Source span of the declaration.
+    pub span: Span,
+}
+
+/// A function that can panic through `unwrap`, `expect`, or `panic!`.
+#[derive(Debug, Clone)]
+pub struct UnwrapUsage {
+    /// Function name, with its impl type for methods (`Config::load`).
+    pub function: String,
+    /// Number of `.unwrap()` calls.
+    pub unwraps: usize,
+    /// Number of `.expect(...)` calls.
+    pub expects: usize,
+    /// Number of `panic!` invocations.
+    pub panics: usize,
+    /// Whether the function is test code: `#[test]`, or inside a `#[cfg(test)]` module.
+    pub in_test: bool,
+    /// Source span of the function.
+    pub span: Span,
+}
+
+impl UnwrapUsage {
+    /// Total panicking call sites.
+    pub fn total(&self) -> usize {
+        self.unwraps + self.expects + self.panics
+    }
+}
+
+/// A Swift function that can crash through `!` force-unwraps or `try!`.
+#[derive(Debug, Clone)]
+pub struct ForceUnwrapUsage {
+    /// Function name, with its enclosing type for methods (`Loader.load`).
+    pub function: String,
+    /// Number of postfix `!` force-unwraps.
+    pub force_unwraps: usize,
+    /// Number of `try!` expressions.
+    pub force_tries: usize,
+    /// Whether the function is test code: `@Test`, or a method of an `XCTestCase` subclass.
+    pub in_test: bool,
+    /// Source span of the function.
+    pub span: Span,
+}
+
+impl ForceUnwrapUsage {
+    /// Total crashing sites.
+    pub fn total(&self) -> usize {
+        self.force_unwraps + self.force_tries
+    }
+}
+
+/// A named parameter that its function body never references.
+#[derive(Debug, Clone)]
+pub struct UnusedParameter {
+    /// Name of the enclosing function, or `func literal` for Go closures.
+    pub function: String,
+    /// Parameter name.
+    pub name: String,
+    /// Declared type text, as written.
+    pub type_text: String,
+    /// Source span of the parameter declaration.
+    pub span: Span,
+}
+
+/// A named result that a bare `return` hands back without it ever being
+/// assigned first, so the caller always gets its zero value.
+#[derive(Debug, Clone)]
+pub struct UnassignedResult {
+    /// Name of the enclosing function, or `func literal` for Go closures.
+    pub function: String,
+    /// Result name.
+    pub name: String,
+    /// Declared type text, as written.
+    pub type_text: String,
+    /// Source span of the result declaration.
+    pub span: Span,
+    /// Line of the first bare `return`.
+    pub return_line: usize,
+}
+
+/// Constants declared together: a Go `const (...)` block, the variants of
+/// an enum, or a run of adjacent Rust `const` items.
+#[derive(Debug, Clone)]
+pub struct ConstantGroup {
+    /// Enum name, or empty for a const block or a run of constants.
+    pub name: String,
+    /// Members in source order.
+    pub members: Vec<ConstantMember>,
+    /// Whether a TODO-style marker comment sits inside the group, right
+    /// above it, or after it on its last line.
+    pub has_marker: bool,
+    /// Source span from the first declaration to the last.
+    pub span: Span,
+}
+
+/// A constant or enum variant and the value written for it.
+#[derive(Debug, Clone)]
+pub struct ConstantMember {
+    /// Constant or variant name.
+    pub name: String,
+    /// Value expression as written, or `None` when the language assigns
+    /// one implicitly. A Go spec without a value repeats the previous one's.
+    pub value: Option<String>,
+    /// Line of the name (1-indexed).
+    pub line: usize,
+}
+
+/// A parameter declared with a default value.
+#[derive(Debug, Clone)]
+pub struct ParameterDefault {
+    /// Name of the enclosing function.
+    pub function: String,
+    /// Parameter name.
+    pub name: String,
+    /// Default value, as written.
+    pub value: String,
+    /// Grammar node kind of the default value (`list`, `dictionary`, `call`).
+    pub value_kind: String,
+    /// Source span of the parameter.
+    pub span: Span,
+}
+
+/// A function cut off at the end of the file, with its body never closed,
+/// as generation that ran out of tokens leaves it.
+#[derive(Debug, Clone)]
+pub struct TruncatedFunction {
+    /// Function name, when the parser recovered it.
+    pub name: Option<String>,
+    /// From the start of the function to the end of the file.
+    pub span: Span,
+}
+
+/// A switch or match statement with its case labels.
+#[derive(Debug, Clone)]
+pub struct SwitchStatement {
+    /// Scrutinized expression text, without surrounding parentheses.
+    pub subject: String,
+    /// Case labels as written, one per matched value (`StatusActive`,
+    /// `Status.Active`, `"active"`, `Status::Active`).
+    pub cases: Vec<String>,
+    /// The `default:` or `_ =>` arm, if any.
+    pub default_arm: Option<DefaultArm>,
+    /// Source span of the statement.
+    pub span: Span,
+}
+
+/// The default arm of a switch or match.
+#[derive(Debug, Clone)]
+pub struct DefaultArm {
+    /// Whether the arm does nothing: empty, `break`, or a return of nil/None/nothing.
+    pub is_trivial: bool,
+    /// Source span of the arm.
+    pub span: Span,
+}
+
+/// A type declaring that it implements or extends an interface, trait, or base class.
+///
+/// Recorded from `implements`/`extends` clauses, Python base classes, and Rust
+/// `impl Trait for Type` blocks. An interface extending another is recorded
+/// with the interface as `type_name`. Go interfaces are satisfied implicitly,
+/// so Go only records interfaces embedded in other interfaces.
+#[derive(Debug, Clone)]
+pub struct Implementation {
+    /// The implementing type (e.g., "StripeProcessor").
+    pub type_name: String,
+    /// The implemented type, without package qualifiers or type arguments.
+    pub interface: String,
+    /// Source span of the declaration or impl block.
+    pub span: Span,
+}
+
+/// A callable's type parameters and the named types it returns.
+///
+/// Recorded for callables with a body that declare type parameters or a
+/// return type.
+#[derive(Debug, Clone)]
+pub struct CallableTypes {
+    /// Callable name.
+    pub name: String,
+    /// Declared type parameters, in order.
+    pub type_parameters: Vec<TypeParameter>,
+    /// Named types in the return type in source order (`Result`, `Box`,
+    /// `Processor` for `Result<Box<dyn Processor>, Error>`).
+    pub returns: Vec<String>,
+    /// Source span of the callable.
+    pub span: Span,
+}
+
+/// A type parameter and how often its callable refers to it.
+#[derive(Debug, Clone)]
+pub struct TypeParameter {
+    /// Parameter name (`T`).
+    pub name: String,
+    /// References in the signature and body, outside the parameter list and
+    /// `where` clauses.
+    pub references: usize,
+    /// Whether one of the references is in the return type.
+    pub in_return_type: bool,
+    /// Source span of the declaration.
+    pub span: Span,
+}
+
+/// A comment block in source code.
+#[derive(Debug, Clone)]
+pub struct Comment {
+    /// Raw comment text including comment markers, one line per source line.
+    pub text: String,
+    /// Source span covering the whole block.
+    pub span: Span,
+}
+
+/// Lines of code and of comments in a file. Blank lines are neither; a
+/// line is a comment line when it starts inside a comment or docstring,
+/// so code with a trailing comment counts as code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineCounts {
+    pub code: usize,
+    pub comment: usize,
+}
+
 /// All facts extracted from a single file.
 #[derive(Debug, Clone)]
 pub struct FileFacts {
@@ -194,6 +731,52 @@ pub struct FileFacts {
     pub declarations: Vec<Declaration>,
     /// All imports in the file.
     pub imports: Vec<Import>,
+    /// String literals outside of imports and standalone doc strings.
+    pub string_literals: Vec<StringLiteral>,
+    /// Calls used as bare expression statements (currently JavaScript/TypeScript).
+    pub discarded_calls: Vec<DiscardedCall>,
+    /// Statements with no effect (currently Go, Python, JavaScript/TypeScript).
+    pub noop_statements: Vec<NoopStatement>,
+    /// Error handlers that log the error and pass it on unchanged
+    /// (currently Go, Python, Java, JavaScript/TypeScript).
+    pub logged_rethrows: Vec<LoggedRethrow>,
+    /// Named parameters never referenced in their function body (currently Go).
+    pub unused_parameters: Vec<UnusedParameter>,
+    /// Named results only ever returned unassigned by a bare `return` (currently Go).
+    pub unassigned_results: Vec<UnassignedResult>,
+    /// Parameters with default values (currently Python).
+    pub parameter_defaults: Vec<ParameterDefault>,
+    /// Const blocks, enums, and runs of constants with their values
+    /// (currently Go, Rust, C, C++, TypeScript, Swift).
+    pub constant_groups: Vec<ConstantGroup>,
+    /// Function declarations and definitions (currently C and C++).
+    pub function_signatures: Vec<FunctionSignature>,
+    /// Every call, by callee name (currently Rust, Java, Python, JavaScript/TypeScript).
+    pub call_sites: Vec<CallSite>,
+    /// Callables marked deprecated (currently Rust, Java, Python, JavaScript/TypeScript).
+    pub deprecations: Vec<Deprecation>,
+    /// Callables that only forward their parameters to another call
+    /// (currently Rust, Java, Python, JavaScript/TypeScript).
+    pub forwarding_calls: Vec<ForwardingCall>,
+    /// Functions calling `unwrap`, `expect`, or `panic!` (currently Rust).
+    pub unwrap_usage: Vec<UnwrapUsage>,
+    /// Functions using `!` force-unwraps or `try!` (currently Swift).
+    pub force_unwraps: Vec<ForceUnwrapUsage>,
+    /// Switch and match statements (currently Go, TypeScript, Rust).
+    pub switches: Vec<SwitchStatement>,
+    /// Implements/extends relations declared in the file.
+    pub implementations: Vec<Implementation>,
+    /// Type parameters and return types of callables (currently Go, Rust, Java, TypeScript).
+    pub callable_types: Vec<CallableTypes>,
+    /// First comment block of the file, if it starts with one (license/copyright header).
+    pub header_comment: Option<Comment>,
+    /// Whether the comments before any code carry a generated-code marker
+    /// (`Code generated ... DO NOT EDIT.`, `@generated`, protoc or Thrift banners).
+    pub is_generated: bool,
+    /// Code and comment line counts.
+    pub lines: LineCounts,
+    /// Function the file ends inside of, when it stops mid-body.
+    pub truncated_function: Option<TruncatedFunction>,
     /// Whether the file had parse errors.
     pub has_parse_errors: bool,
     /// Parse error message (if any).
@@ -209,6 +792,27 @@ impl FileFacts {
             package: None,
             declarations: Vec::new(),
             imports: Vec::new(),
+            string_literals: Vec::new(),
+            discarded_calls: Vec::new(),
+            noop_statements: Vec::new(),
+            logged_rethrows: Vec::new(),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: Vec::new(),
+            function_signatures: Vec::new(),
+            call_sites: Vec::new(),
+            deprecations: Vec::new(),
+            forwarding_calls: Vec::new(),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: Vec::new(),
+            implementations: Vec::new(),
+            callable_types: Vec::new(),
+            header_comment: None,
+            is_generated: false,
+            lines: LineCounts::default(),
+            truncated_function: None,
             has_parse_errors: false,
             parse_error: None,
         }
@@ -229,12 +833,22 @@ impl FileFacts {
         self.declarations.iter().filter(|d| d.kind.is_callable())
     }
 
+    /// Get each function and method with a body, paired with its cyclomatic complexity.
+    pub fn callable_complexity(&self) -> Vec<(Declaration, Complexity)> {
+        self.callables()
+            .filter_map(|d| {
+                let body = d.body.as_ref()?;
+                Some((d.clone(), body.control_flow.complexity()))
+            })
+            .collect()
+    }
+
     /// Get total cyclomatic complexity of all functions.
-    pub fn total_complexity(&self) -> i32 {
+    pub fn total_complexity(&self) -> u64 {
         self.callables()
             .filter_map(|d| d.body.as_ref())
-            .map(|b| b.control_flow.cyclomatic_complexity())
-            .sum()
+            .map(|b| u64::from(b.control_flow.cyclomatic_complexity()))
+            .fold(0, u64::saturating_add)
     }
 }
 
@@ -252,6 +866,29 @@ mod tests {
         cf.and_count = 1;
         // 1 + 2 + 1 + 1 = 5
         assert_eq!(cf.cyclomatic_complexity(), 5);
+        assert!(!cf.is_saturated());
+    }
+
+    #[test]
+    fn test_cyclomatic_complexity_saturates() {
+        let cf = ControlFlowInfo {
+            case_count: usize::MAX,
+            if_count: 3,
+            ..Default::default()
+        };
+        assert_eq!(cf.cyclomatic_complexity(), ControlFlowInfo::MAX_COMPLEXITY);
+        assert!(cf.is_saturated());
+        assert_eq!(cf.complexity(), Complexity::Saturated);
+        assert_eq!(cf.complexity().to_string(), "complexity 10000 (saturated)");
+    }
+
+    #[test]
+    fn test_complexity_not_computed() {
+        let cf = ControlFlowInfo::not_computed();
+        assert_eq!(cf.complexity(), Complexity::NotComputed);
+        assert_eq!(cf.complexity().value(), None);
+        assert_eq!(cf.complexity().to_string(), "complexity: not computed, body too large");
+        assert_eq!(ControlFlowInfo::default().complexity(), Complexity::Counted(1));
     }
 
     #[test]
@@ -268,7 +905,13 @@ mod tests {
                 end_col: 11,
             },
             receiver: None,
+            is_async: false,
+            param_count: None,
             body: None,
+            members: Vec::new(),
+            annotations: Vec::new(),
+            is_exported: false,
+            has_doc: false,
         };
         assert_eq!(func.qualified_name(), "main");
 
@@ -284,7 +927,13 @@ mod tests {
                 end_col: 11,
             },
             receiver: Some("Config".to_string()),
+            is_async: false,
+            param_count: None,
             body: None,
+            members: Vec::new(),
+            annotations: Vec::new(),
+            is_exported: false,
+            has_doc: false,
         };
         assert_eq!(method.qualified_name(), "Config.Validate");
     }
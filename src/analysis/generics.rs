@@ -0,0 +1,209 @@
+//! Shared extraction of callables' type parameters and return types.
+//!
+//! `fn process<T: Display>(value: T)` is generic in name only: the type
+//! parameter appears once, so nothing ties two positions together and a
+//! concrete type or `impl Display` would do. The walk counts how often the
+//! signature and body refer to each type parameter, outside the parameter
+//! list and Rust `where` clauses, and records the named types of the return
+//! type for factory checks. Grammars that support generics share the field
+//! names used here: `type_parameters`, and `return_type`, `result` (Go), or
+//! `type` (Java) for the return type.
+
+use tree_sitter::Node;
+
+use super::{CallableTypes, ParsedFile, Span, TypeParameter};
+
+/// Fields holding a callable's return type.
+const RETURN_FIELDS: &[&str] = &["return_type", "result", "type"];
+
+/// Subtrees that declare or bound type parameters rather than use them.
+const DECLARING_KINDS: &[&str] = &["where_clause"];
+
+/// Names declared by a type parameter list; lifetimes and const parameters are skipped.
+fn declared_names(parsed: &ParsedFile, list: Node) -> Vec<(String, Span)> {
+    let mut names = Vec::new();
+    let mut cursor = list.walk();
+    for param in list.named_children(&mut cursor) {
+        if param.kind() == "type_identifier" {
+            names.push((parsed.node_text(param).to_string(), Span::from_node(param)));
+            continue;
+        }
+        if param.kind().contains("lifetime") || param.kind().contains("const") {
+            continue;
+        }
+        // Go declares several names per group: `[K, V comparable]`
+        let mut name_cursor = param.walk();
+        let mut declared: Vec<Node> = param.children_by_field_name("name", &mut name_cursor).collect();
+        if declared.is_empty() {
+            declared.extend(
+                param
+                    .child_by_field_name("left")
+                    .or_else(|| param.named_child(0)),
+            );
+        }
+        for name in declared {
+            if matches!(name.kind(), "type_identifier" | "identifier") {
+                names.push((parsed.node_text(name).to_string(), Span::from_node(name)));
+            }
+        }
+    }
+    names
+}
+
+/// Count references to `name` under `node`, skipping declaring subtrees.
+/// Returns the count and whether one lies inside `return_type`.
+fn count_references(parsed: &ParsedFile, node: Node, name: &str, skip: Option<Node>, return_type: Option<Node>) -> (usize, bool) {
+    let mut count = 0;
+    let mut in_return = false;
+    let mut stack = vec![node];
+    while let Some(n) = stack.pop() {
+        if Some(n) == skip || DECLARING_KINDS.contains(&n.kind()) {
+            continue;
+        }
+        if matches!(n.kind(), "type_identifier" | "identifier") && parsed.node_text(n) == name {
+            count += 1;
+            if return_type.is_some_and(|r| r.start_byte() <= n.start_byte() && n.end_byte() <= r.end_byte()) {
+                in_return = true;
+            }
+            continue;
+        }
+        let mut cursor = n.walk();
+        stack.extend(n.children(&mut cursor));
+    }
+    (count, in_return)
+}
+
+/// Named types of a return type in source order (`Result`, `Box`, `Processor`).
+fn returned_names(parsed: &ParsedFile, return_type: Node) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut stack = vec![return_type];
+    while let Some(n) = stack.pop() {
+        if n.kind() == "type_identifier" {
+            names.push(parsed.node_text(n).to_string());
+            continue;
+        }
+        let mut cursor = n.walk();
+        let children: Vec<Node> = n.children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+    names
+}
+
+/// Extract the type parameters and return types of callables with a body.
+pub(crate) fn extract_callable_types(parsed: &ParsedFile, callable_kinds: &[&str]) -> Vec<CallableTypes> {
+    let mut callables = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+
+        if !callable_kinds.contains(&node.kind()) || node.child_by_field_name("body").is_none() {
+            continue;
+        }
+        let Some(name) = node.child_by_field_name("name") else {
+            continue;
+        };
+        let return_type = RETURN_FIELDS.iter().find_map(|f| node.child_by_field_name(f));
+        let list = node.child_by_field_name("type_parameters");
+
+        let type_parameters: Vec<TypeParameter> = list
+            .map(|list| declared_names(parsed, list))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(name, span)| {
+                let (references, in_return_type) = count_references(parsed, node, &name, list, return_type);
+                TypeParameter {
+                    name,
+                    references,
+                    in_return_type,
+                    span,
+                }
+            })
+            .collect();
+        let returns = return_type.map(|r| returned_names(parsed, r)).unwrap_or_default();
+        if type_parameters.is_empty() && returns.is_empty() {
+            continue;
+        }
+
+        callables.push(CallableTypes {
+            name: parsed.node_text(name).to_string(),
+            type_parameters,
+            returns,
+            span: Span::from_node(node),
+        });
+    }
+    callables.sort_by_key(|c| c.span.start_byte);
+    callables
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers, CallableTypes};
+    use std::path::Path;
+
+    fn callable_types(ext: &str, source: &str) -> Vec<CallableTypes> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        analyzer.extract_facts(&parsed).unwrap().callable_types
+    }
+
+    /// `name: T=refs(+ret) ... -> returns` per callable.
+    fn summary(ext: &str, source: &str) -> Vec<String> {
+        callable_types(ext, source)
+            .into_iter()
+            .map(|c| {
+                let params: Vec<String> = c
+                    .type_parameters
+                    .iter()
+                    .map(|p| format!("{}={}{}", p.name, p.references, if p.in_return_type { "+ret" } else { "" }))
+                    .collect();
+                format!("{}: {} -> {}", c.name, params.join(" "), c.returns.join(","))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_callable_types_per_language() {
+        let cases: [(&str, &str, &[&str]); 4] = [
+            (
+                "rs",
+                "fn show<T: Display>(value: T) { println!(\"{}\", value); }\n\
+                 fn pair<'a, T, U>(a: &'a T, b: T) -> U where U: Default { U::default() }\n\
+                 fn new_store() -> Result<Box<dyn Store>, Error> { todo!() }\n",
+                &[
+                    "show: T=1 -> ",
+                    "pair: T=2 U=2+ret -> U",
+                    "new_store:  -> Result,Box,Store,Error",
+                ],
+            ),
+            (
+                "go",
+                "package p\n\n\
+                 func Map[K comparable, V any](m map[K]V) []K { return nil }\n\
+                 func NewStore() (Store, error) { return nil, nil }\n",
+                &["Map: K=2+ret V=1 -> K", "NewStore:  -> Store,error"],
+            ),
+            (
+                "ts",
+                "function first<T>(items: T[]): T { return items[0]; }\n\
+                 function log<T>(value: T): void { console.log(value); }\n\
+                 function createStore(): Store { return new MemoryStore(); }\n",
+                &["first: T=2+ret -> T", "log: T=1 -> ", "createStore:  -> Store"],
+            ),
+            (
+                "java",
+                "class A {\n\
+                   <T extends Comparable<T>> T max(T a, T b) { return a; }\n\
+                   static Store createStore() { return new MemoryStore(); }\n\
+                 }\n",
+                &["max: T=3+ret -> T", "createStore:  -> Store"],
+            ),
+        ];
+
+        for (ext, source, expected) in cases {
+            assert_eq!(summary(ext, source), expected, "{}", ext);
+        }
+    }
+}
@@ -0,0 +1,154 @@
+//! Shared extraction of interface members and implements/extends relations.
+//!
+//! Grammars spell interface bodies and inheritance clauses differently but
+//! the walk is the same, so each analyzer describes its syntax with an
+//! `InheritanceSyntax` and delegates to `declared_members` and
+//! `extract_implementations`.
+
+use tree_sitter::Node;
+
+use super::{Implementation, ParsedFile, Span};
+
+/// Grammar-specific node kinds used to find interface members and supertypes.
+pub(crate) struct InheritanceSyntax {
+    /// Node kinds holding an interface's or trait's members (e.g., "interface_body").
+    pub body_kinds: &'static [&'static str],
+    /// Member node kinds that declare a method (e.g., "method_signature").
+    pub member_kinds: &'static [&'static str],
+    /// Node kinds that declare a type with supertypes (classes, interfaces, impl blocks).
+    pub type_kinds: &'static [&'static str],
+    /// Field holding the declared type's name ("name"; Rust impl blocks use "type").
+    pub name_field: &'static str,
+    /// Fields whose node is itself a supertype (Rust `impl Trait for Type`).
+    pub supertype_fields: &'static [&'static str],
+    /// Child kinds listing supertypes; nested clauses are searched too.
+    pub clause_kinds: &'static [&'static str],
+    /// Clause children that are not supertypes (type arguments, keyword arguments).
+    pub skip_kinds: &'static [&'static str],
+}
+
+/// Strip package qualifiers and type arguments from a type reference
+/// (`ns.Repo<T>` -> `Repo`, `fmt::Display` -> `Display`).
+pub(crate) fn simple_type_name(text: &str) -> &str {
+    let base = text.split(['<', '[', '(']).next().unwrap_or(text);
+    base.rsplit(['.', ':']).next().unwrap_or(base).trim()
+}
+
+/// Names of the methods declared in an interface or trait body.
+pub(crate) fn declared_members(
+    parsed: &ParsedFile,
+    decl: Node,
+    syntax: &InheritanceSyntax,
+) -> Vec<String> {
+    let Some(body) = find_body(decl, syntax) else {
+        return Vec::new();
+    };
+
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor)
+        .filter_map(|member| {
+            // Python decorators wrap the method they apply to
+            if member.kind() == "decorated_definition" {
+                member.child_by_field_name("definition")
+            } else {
+                Some(member)
+            }
+        })
+        .filter(|member| syntax.member_kinds.contains(&member.kind()))
+        .filter_map(|member| member.child_by_field_name("name"))
+        .map(|name| parsed.node_text(name).to_string())
+        .collect()
+}
+
+/// Find the first node under `decl` that holds its members.
+fn find_body<'t>(decl: Node<'t>, syntax: &InheritanceSyntax) -> Option<Node<'t>> {
+    let mut cursor = decl.walk();
+    for child in decl.named_children(&mut cursor) {
+        if syntax.body_kinds.contains(&child.kind()) {
+            return Some(child);
+        }
+        if let Some(body) = find_body(child, syntax) {
+            return Some(body);
+        }
+    }
+    None
+}
+
+/// Collect every implements/extends relation declared in a parsed file.
+pub(crate) fn extract_implementations(
+    parsed: &ParsedFile,
+    syntax: &InheritanceSyntax,
+) -> Vec<Implementation> {
+    let mut implementations = Vec::new();
+    collect(parsed, parsed.tree.root_node(), syntax, &mut implementations);
+    implementations
+}
+
+fn collect(
+    parsed: &ParsedFile,
+    node: Node,
+    syntax: &InheritanceSyntax,
+    out: &mut Vec<Implementation>,
+) {
+    if syntax.type_kinds.contains(&node.kind()) {
+        let type_name = node
+            .child_by_field_name(syntax.name_field)
+            .map(|n| simple_type_name(parsed.node_text(n)).to_string())
+            // Anonymous classes still implement their interfaces
+            .unwrap_or_else(|| "<anonymous>".to_string());
+
+        let mut supertypes = Vec::new();
+        for field in syntax.supertype_fields {
+            supertypes.extend(node.child_by_field_name(field));
+        }
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if syntax.clause_kinds.contains(&child.kind()) {
+                clause_types(child, syntax, &mut supertypes);
+            }
+        }
+
+        for supertype in supertypes {
+            let interface = simple_type_name(parsed.node_text(supertype));
+            if !interface.is_empty() {
+                out.push(Implementation {
+                    type_name: type_name.clone(),
+                    interface: interface.to_string(),
+                    span: Span::from_node(node),
+                });
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect(parsed, child, syntax, out);
+    }
+}
+
+/// Collect the supertypes listed in a clause, descending into nested clauses.
+fn clause_types<'t>(clause: Node<'t>, syntax: &InheritanceSyntax, out: &mut Vec<Node<'t>>) {
+    let mut cursor = clause.walk();
+    for child in clause.named_children(&mut cursor) {
+        if syntax.clause_kinds.contains(&child.kind()) {
+            clause_types(child, syntax, out);
+        } else if !syntax.skip_kinds.contains(&child.kind()) && !child.kind().ends_with("comment")
+        {
+            out.push(child);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_type_name() {
+        assert_eq!(simple_type_name("Repo"), "Repo");
+        assert_eq!(simple_type_name("ns.Repo<T>"), "Repo");
+        assert_eq!(simple_type_name("fmt::Display"), "Display");
+        assert_eq!(simple_type_name("Generic[T]"), "Generic");
+        assert_eq!(simple_type_name("abc.ABC"), "ABC");
+    }
+}
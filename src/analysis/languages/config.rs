@@ -0,0 +1,501 @@
+//! YAML, JSON, and TOML configuration documents.
+//!
+//! Configuration files are parsed with their tree-sitter grammars rather
+//! than serde, so every key keeps the line it was written on. A document is
+//! flattened into one entry per key in source order, addressed by a dotted
+//! path from the root (`database.host`, `servers[0].port`). Sections and
+//! lists get an entry of their own, holding their size, so rules can tell an
+//! empty section from a missing one.
+
+use std::path::Path;
+
+use tree_sitter::{Node, Parser};
+
+/// Format of a configuration file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl ConfigFormat {
+    /// Format of a file, by extension.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "yaml" | "yml" => Some(ConfigFormat::Yaml),
+            "json" => Some(ConfigFormat::Json),
+            "toml" => Some(ConfigFormat::Toml),
+            _ => None,
+        }
+    }
+
+    fn language(&self) -> tree_sitter::Language {
+        match self {
+            ConfigFormat::Yaml => tree_sitter_yaml::LANGUAGE.into(),
+            ConfigFormat::Json => tree_sitter_json::LANGUAGE.into(),
+            ConfigFormat::Toml => tree_sitter_toml_ng::LANGUAGE.into(),
+        }
+    }
+}
+
+/// Value of a configuration key.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigValue {
+    /// String, with quotes and escapes removed.
+    String(String),
+    /// Integer or float.
+    Number(f64),
+    Bool(bool),
+    /// Explicit null, or a YAML key with no value.
+    Null,
+    /// Mapping, object, or table with this many keys.
+    Map(usize),
+    /// Sequence or array with this many items.
+    List(usize),
+    /// Anything else (dates, aliases), as written.
+    Other(String),
+}
+
+impl ConfigValue {
+    /// Whether the value is an empty string, null, or an empty section or list.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            ConfigValue::String(s) => s.trim().is_empty(),
+            ConfigValue::Null => true,
+            ConfigValue::Map(n) | ConfigValue::List(n) => *n == 0,
+            _ => false,
+        }
+    }
+}
+
+/// One key of a configuration document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfigEntry {
+    /// Dotted path from the document root (`database.host`, `servers[0].port`).
+    pub path: String,
+    pub value: ConfigValue,
+    /// Line the key is written on (1-indexed).
+    pub line: usize,
+}
+
+/// Parse a configuration document into its entries, in source order.
+///
+/// Every document of a multi-document YAML stream is included. Fails if the
+/// source does not parse.
+pub fn parse_config(format: ConfigFormat, source: &str) -> anyhow::Result<Vec<ConfigEntry>> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&format.language())
+        .map_err(|e| anyhow::anyhow!("loading {:?} grammar: {}", format, e))?;
+    let tree = parser
+        .parse(source, None)
+        .ok_or_else(|| anyhow::anyhow!("parsing {:?} document failed", format))?;
+    let root = tree.root_node();
+    if root.has_error() {
+        anyhow::bail!("invalid {:?} document", format);
+    }
+
+    let mut walker = Walker {
+        source,
+        entries: Vec::new(),
+    };
+    match format {
+        ConfigFormat::Yaml => {
+            let mut cursor = root.walk();
+            for document in root.named_children(&mut cursor) {
+                if let Some(node) = first_named_child(document) {
+                    walker.yaml_children(node, "");
+                }
+            }
+        }
+        ConfigFormat::Json => {
+            if let Some(node) = first_named_child(root) {
+                walker.json_children(node, "");
+            }
+        }
+        ConfigFormat::Toml => walker.toml_document(root),
+    }
+    Ok(walker.entries)
+}
+
+/// Join a parent path and a key.
+fn join(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+fn first_named_child(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let child = node.named_children(&mut cursor).find(|c| !c.kind().ends_with("comment"));
+    child
+}
+
+fn named_children(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    node.named_children(&mut cursor)
+        .filter(|c| !c.kind().ends_with("comment"))
+        .collect()
+}
+
+/// Remove quotes and escapes from a quoted string.
+fn unquote(text: &str) -> String {
+    for delimiter in ["\"\"\"", "'''"] {
+        if let Some(inner) = text.strip_prefix(delimiter).and_then(|t| t.strip_suffix(delimiter)) {
+            return inner.strip_prefix('\n').unwrap_or(inner).to_string();
+        }
+    }
+    if text.len() >= 2 && text.starts_with('"') && text.ends_with('"') {
+        return serde_json::from_str(text).unwrap_or_else(|_| text[1..text.len() - 1].to_string());
+    }
+    if text.len() >= 2 && text.starts_with('\'') && text.ends_with('\'') {
+        // YAML escapes a single quote by doubling it
+        return text[1..text.len() - 1].replace("''", "'");
+    }
+    text.to_string()
+}
+
+fn number(text: &str) -> Option<f64> {
+    text.replace('_', "").parse().ok()
+}
+
+struct Walker<'a> {
+    source: &'a str,
+    entries: Vec<ConfigEntry>,
+}
+
+impl Walker<'_> {
+    fn text(&self, node: Node) -> &str {
+        &self.source[node.byte_range()]
+    }
+
+    fn push(&mut self, path: String, value: ConfigValue, node: Node) {
+        self.entries.push(ConfigEntry {
+            path,
+            value,
+            line: node.start_position().row + 1,
+        });
+    }
+
+    // YAML
+
+    /// The content of a YAML node, past anchors and tags.
+    fn yaml_content<'t>(&self, node: Node<'t>) -> Node<'t> {
+        match node.kind() {
+            "block_node" | "flow_node" => named_children(node)
+                .into_iter()
+                .find(|c| !matches!(c.kind(), "anchor" | "tag"))
+                .map(|c| self.yaml_content(c))
+                .unwrap_or(node),
+            "plain_scalar" => first_named_child(node).unwrap_or(node),
+            _ => node,
+        }
+    }
+
+    fn yaml_value(&self, node: Option<Node>) -> ConfigValue {
+        let Some(node) = node.map(|n| self.yaml_content(n)) else {
+            return ConfigValue::Null;
+        };
+        let text = self.text(node);
+        match node.kind() {
+            "block_mapping" | "flow_mapping" => ConfigValue::Map(named_children(node).len()),
+            "block_sequence" | "flow_sequence" => ConfigValue::List(named_children(node).len()),
+            "integer_scalar" | "float_scalar" => number(text).map_or_else(|| ConfigValue::Other(text.to_string()), ConfigValue::Number),
+            "boolean_scalar" => ConfigValue::Bool(text.eq_ignore_ascii_case("true")),
+            "null_scalar" => ConfigValue::Null,
+            "string_scalar" => ConfigValue::String(text.to_string()),
+            "double_quote_scalar" | "single_quote_scalar" => ConfigValue::String(unquote(text)),
+            "block_scalar" => {
+                let body = text.split_once('\n').map_or("", |(_, body)| body);
+                ConfigValue::String(body.trim().to_string())
+            }
+            "block_node" | "flow_node" => ConfigValue::Null, // anchor or tag with no content
+            _ => ConfigValue::Other(text.to_string()),
+        }
+    }
+
+    /// Record the keys or items below a YAML mapping or sequence.
+    fn yaml_children(&mut self, node: Node, path: &str) {
+        let node = self.yaml_content(node);
+        match node.kind() {
+            "block_mapping" | "flow_mapping" => {
+                for pair in named_children(node) {
+                    let key = pair.child_by_field_name("key").map(|k| self.yaml_content(k));
+                    let Some(key) = key.map(|k| self.yaml_value(Some(k))) else {
+                        continue;
+                    };
+                    let key = match key {
+                        ConfigValue::String(s) | ConfigValue::Other(s) => s,
+                        ConfigValue::Number(n) => n.to_string(),
+                        _ => continue,
+                    };
+                    let value = pair.child_by_field_name("value");
+                    let child_path = join(path, &key);
+                    self.push(child_path.clone(), self.yaml_value(value), pair);
+                    if let Some(value) = value {
+                        self.yaml_children(value, &child_path);
+                    }
+                }
+            }
+            "block_sequence" | "flow_sequence" => {
+                for (i, item) in named_children(node).into_iter().enumerate() {
+                    let item_node = match item.kind() {
+                        "block_sequence_item" => first_named_child(item),
+                        _ => Some(item),
+                    };
+                    let child_path = format!("{}[{}]", path, i);
+                    self.push(child_path.clone(), self.yaml_value(item_node), item);
+                    if let Some(item_node) = item_node {
+                        self.yaml_children(item_node, &child_path);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // JSON
+
+    fn json_value(&self, node: Node) -> ConfigValue {
+        let text = self.text(node);
+        match node.kind() {
+            "object" => ConfigValue::Map(named_children(node).len()),
+            "array" => ConfigValue::List(named_children(node).len()),
+            "string" => ConfigValue::String(unquote(text)),
+            "number" => number(text).map_or_else(|| ConfigValue::Other(text.to_string()), ConfigValue::Number),
+            "true" => ConfigValue::Bool(true),
+            "false" => ConfigValue::Bool(false),
+            "null" => ConfigValue::Null,
+            _ => ConfigValue::Other(text.to_string()),
+        }
+    }
+
+    fn json_children(&mut self, node: Node, path: &str) {
+        match node.kind() {
+            "object" => {
+                for pair in named_children(node) {
+                    let (Some(key), Some(value)) = (
+                        pair.child_by_field_name("key"),
+                        pair.child_by_field_name("value"),
+                    ) else {
+                        continue;
+                    };
+                    let child_path = join(path, &unquote(self.text(key)));
+                    self.push(child_path.clone(), self.json_value(value), pair);
+                    self.json_children(value, &child_path);
+                }
+            }
+            "array" => {
+                for (i, item) in named_children(node).into_iter().enumerate() {
+                    let child_path = format!("{}[{}]", path, i);
+                    self.push(child_path.clone(), self.json_value(item), item);
+                    self.json_children(item, &child_path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // TOML
+
+    /// Path of a TOML key (`a`, `"b.c"`, `a.b`).
+    fn toml_key(&self, node: Node) -> String {
+        match node.kind() {
+            "dotted_key" => named_children(node)
+                .into_iter()
+                .map(|k| self.toml_key(k))
+                .collect::<Vec<_>>()
+                .join("."),
+            _ => unquote(self.text(node)),
+        }
+    }
+
+    fn toml_value(&self, node: Node) -> ConfigValue {
+        let text = self.text(node);
+        match node.kind() {
+            "inline_table" => ConfigValue::Map(named_children(node).len()),
+            "array" => ConfigValue::List(named_children(node).len()),
+            "string" => ConfigValue::String(unquote(text)),
+            "integer" | "float" => number(text).map_or_else(|| ConfigValue::Other(text.to_string()), ConfigValue::Number),
+            "boolean" => ConfigValue::Bool(text == "true"),
+            _ => ConfigValue::Other(text.to_string()),
+        }
+    }
+
+    fn toml_pair(&mut self, pair: Node, path: &str) {
+        let children = named_children(pair);
+        let [key, value] = children.as_slice() else {
+            return;
+        };
+        let child_path = join(path, &self.toml_key(*key));
+        self.push(child_path.clone(), self.toml_value(*value), pair);
+        self.toml_children(*value, &child_path);
+    }
+
+    fn toml_children(&mut self, node: Node, path: &str) {
+        match node.kind() {
+            "inline_table" => {
+                for pair in named_children(node) {
+                    self.toml_pair(pair, path);
+                }
+            }
+            "array" => {
+                for (i, item) in named_children(node).into_iter().enumerate() {
+                    let child_path = format!("{}[{}]", path, i);
+                    self.push(child_path.clone(), self.toml_value(item), item);
+                    self.toml_children(item, &child_path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn toml_document(&mut self, root: Node) {
+        let mut array_counts: Vec<(String, usize)> = Vec::new();
+        for node in named_children(root) {
+            match node.kind() {
+                "pair" => self.toml_pair(node, ""),
+                "table" | "table_array_element" => {
+                    let children = named_children(node);
+                    let Some((key, pairs)) = children.split_first() else {
+                        continue;
+                    };
+                    let mut path = self.toml_key(*key);
+                    if node.kind() == "table_array_element" {
+                        let index = match array_counts.iter_mut().find(|(p, _)| *p == path) {
+                            Some((_, count)) => {
+                                *count += 1;
+                                *count - 1
+                            }
+                            None => {
+                                array_counts.push((path.clone(), 1));
+                                0
+                            }
+                        };
+                        path = format!("{}[{}]", path, index);
+                    }
+                    self.push(path.clone(), ConfigValue::Map(pairs.len()), node);
+                    for pair in pairs {
+                        self.toml_pair(*pair, &path);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(format: ConfigFormat, source: &str) -> Vec<(String, ConfigValue, usize)> {
+        parse_config(format, source)
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.path, e.value, e.line))
+            .collect()
+    }
+
+    #[test]
+    fn test_yaml_entries() {
+        let source = r#"# service settings
+database:
+  host: TODO
+  port: 0
+  options: {}
+servers:
+  - name: "primary"
+    weight: 1.5
+api_key: ''
+notes:
+"#;
+        let found = entries(ConfigFormat::Yaml, source);
+        let expected = vec![
+            ("database".to_string(), ConfigValue::Map(3), 2),
+            ("database.host".to_string(), ConfigValue::String("TODO".to_string()), 3),
+            ("database.port".to_string(), ConfigValue::Number(0.0), 4),
+            ("database.options".to_string(), ConfigValue::Map(0), 5),
+            ("servers".to_string(), ConfigValue::List(1), 6),
+            ("servers[0]".to_string(), ConfigValue::Map(2), 7),
+            ("servers[0].name".to_string(), ConfigValue::String("primary".to_string()), 7),
+            ("servers[0].weight".to_string(), ConfigValue::Number(1.5), 8),
+            ("api_key".to_string(), ConfigValue::String(String::new()), 9),
+            ("notes".to_string(), ConfigValue::Null, 10),
+        ];
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn test_json_entries() {
+        let source = "{\n  \"server\": {\n    \"url\": \"https://example.com/api\",\n    \"retries\": [1, 2]\n  },\n  \"debug\": false\n}\n";
+        let found = entries(ConfigFormat::Json, source);
+        let paths: Vec<_> = found.iter().map(|(p, _, line)| (p.as_str(), *line)).collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("server", 2),
+                ("server.url", 3),
+                ("server.retries", 4),
+                ("server.retries[0]", 4),
+                ("server.retries[1]", 4),
+                ("debug", 6),
+            ]
+        );
+        assert_eq!(found[1].1, ConfigValue::String("https://example.com/api".to_string()));
+        assert_eq!(found[5].1, ConfigValue::Bool(false));
+    }
+
+    #[test]
+    fn test_toml_entries() {
+        let source = r#"title = "shop"
+
+[server]
+port = 8_080
+host = ''
+tls = { cert = "", key = "" }
+
+[[workers]]
+name = "mail"
+
+[[workers]]
+name = "billing"
+
+[cache."eu-west"]
+ttl = 30
+"#;
+        let found = entries(ConfigFormat::Toml, source);
+        let paths: Vec<_> = found.iter().map(|(p, _, line)| (p.as_str(), *line)).collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("title", 1),
+                ("server", 3),
+                ("server.port", 4),
+                ("server.host", 5),
+                ("server.tls", 6),
+                ("server.tls.cert", 6),
+                ("server.tls.key", 6),
+                ("workers[0]", 8),
+                ("workers[0].name", 9),
+                ("workers[1]", 11),
+                ("workers[1].name", 12),
+                ("cache.eu-west", 14),
+                ("cache.eu-west.ttl", 15),
+            ]
+        );
+        assert_eq!(found[2].1, ConfigValue::Number(8080.0));
+        assert!(found[3].1.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_document() {
+        assert!(parse_config(ConfigFormat::Json, "{\"a\": }").is_err());
+        assert_eq!(ConfigFormat::from_path(Path::new("conf/app.YML")), Some(ConfigFormat::Yaml));
+        assert_eq!(ConfigFormat::from_path(Path::new("Cargo.lock")), None);
+    }
+}
@@ -9,6 +9,16 @@ use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::params::count_parameters;
+use crate::analysis::signatures::extract_function_signatures;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::constants::{extract_constant_groups, ConstantSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
 
 const DECLARATION_QUERY: &str = r#"
 ; Function definitions
@@ -69,6 +79,55 @@ const IMPORT_QUERY: &str = r#"
 ) @include_local
 "#;
 
+/// String literal syntax for C++.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string_literal", "raw_string_literal"],
+    skip_kinds: &["preproc_include"],
+    call_kinds: &["call_expression"],
+    is_constant: is_constant_node,
+};
+
+/// Constant group syntax for C++.
+const CONSTANT_SYNTAX: ConstantSyntax = ConstantSyntax {
+    declaration_kinds: &["enum_specifier"],
+    body_kinds: &["enumerator_list"],
+    member_kinds: &["enumerator"],
+    name_kinds: &["identifier"],
+    adjacent_kinds: &[],
+    repeats_previous: false,
+};
+
+/// One-statement body syntax for C++, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_statement"],
+    // throw FeatureNotReady() throws a call expression
+    throw_kinds: &["throw_statement"],
+    throw_keyword: None,
+    value_lists: &[],
+    call_kinds: &["call_expression"],
+    construct_kinds: &["new_expression"],
+    name_kinds: &["identifier", "qualified_identifier", "field_expression"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for C++.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &["function_definition", "lambda_expression"],
+    keywords: &[],
+    parameter_kinds: &["parameter_list"],
+    body_openers: &["{"],
+};
+
+/// Returns true if the node declares a constant.
+fn is_constant_node(_parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    node.kind() == "declaration"
+        && node
+            .children(&mut node.walk())
+            .any(|c| c.kind() == "type_qualifier")
+}
+
 pub struct CppAnalyzer {
     language: Language,
 }
@@ -81,9 +140,7 @@ impl CppAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -148,7 +205,17 @@ impl CppAnalyzer {
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: false,
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: Vec::new(),
+                        annotations: Vec::new(),
+                        is_exported: false,
+                        has_doc: false,
                     });
                 }
             }
@@ -175,10 +242,12 @@ impl CppAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let control_flow = self.extract_control_flow(parsed, body_node)?;
@@ -186,12 +255,16 @@ impl CppAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only: false,
             is_nil_return_only: false,
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: 0,
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -203,8 +276,7 @@ impl CppAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -220,6 +292,9 @@ impl CppAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -282,6 +357,7 @@ impl CppAnalyzer {
                         path,
                         alias: None,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -334,6 +410,27 @@ impl LanguageAnalyzer for CppAnalyzer {
             package: None,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: Vec::new(),
+            logged_rethrows: Vec::new(),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: extract_constant_groups(parsed, &CONSTANT_SYNTAX),
+            function_signatures: extract_function_signatures(parsed),
+            call_sites: Vec::new(),
+            deprecations: Vec::new(),
+            forwarding_calls: Vec::new(),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: Vec::new(),
+            implementations: Vec::new(),
+            callable_types: Vec::new(),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -0,0 +1,346 @@
+//! Dockerfile analyzer.
+//!
+//! Dockerfiles are a flat list of instructions, so they are read line by
+//! line instead of through tree-sitter: comments and parser directives are
+//! dropped, backslash continuations are joined, and each instruction keeps
+//! the line it starts on. This analyzer does not implement
+//! `LanguageAnalyzer`; `AnalysisContext` dispatches files by name to it.
+
+use std::path::Path;
+
+use crate::analysis::{Declaration, DeclarationKind, FileFacts, Span, StringLiteral};
+
+/// A single Dockerfile instruction, with continuation lines joined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Instruction {
+    /// Upper-cased instruction keyword (e.g., "FROM", "CMD").
+    pub keyword: String,
+    /// Everything after the keyword, trimmed.
+    pub args: String,
+    /// Line the instruction starts on (1-indexed).
+    pub line: usize,
+    /// Byte range of the instruction in the source.
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
+impl Instruction {
+    /// Whether the arguments use the JSON exec form (`["echo", "hi"]`).
+    pub fn is_exec_form(&self) -> bool {
+        self.args.starts_with('[')
+    }
+
+    /// Command words: the exec-form array elements, or the shell-form words.
+    pub fn command_words(&self) -> Vec<String> {
+        if self.is_exec_form() {
+            if let Ok(words) = serde_json::from_str::<Vec<String>>(&self.args) {
+                return words;
+            }
+        }
+        self.args.split_whitespace().map(String::from).collect()
+    }
+
+    /// `KEY=value` pairs of an ENV, ARG, or LABEL instruction.
+    ///
+    /// Also handles the legacy `ENV KEY value` form and bare `ARG NAME`
+    /// (returned with an empty value). Quotes around values are removed.
+    pub fn key_values(&self) -> Vec<(String, String)> {
+        if !self.args.contains('=') {
+            let mut parts = self.args.splitn(2, char::is_whitespace);
+            let key = parts.next().unwrap_or("").to_string();
+            let value = unquote(parts.next().unwrap_or("").trim());
+            return if key.is_empty() { Vec::new() } else { vec![(key, value)] };
+        }
+
+        split_words(&self.args)
+            .into_iter()
+            .filter_map(|word| {
+                let (key, value) = word.split_once('=')?;
+                Some((key.to_string(), unquote(value)))
+            })
+            .collect()
+    }
+}
+
+/// Split on whitespace outside of double or single quotes.
+fn split_words(s: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    for c in s.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => {
+                quote = None;
+                current.push(c);
+            }
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                current.push(c);
+            }
+            (None, c) if c.is_whitespace() => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn unquote(s: &str) -> String {
+    let trimmed = s.trim();
+    for q in ['"', '\''] {
+        if trimmed.len() >= 2 && trimmed.starts_with(q) && trimmed.ends_with(q) {
+            return trimmed[1..trimmed.len() - 1].to_string();
+        }
+    }
+    trimmed.to_string()
+}
+
+/// Check if a file is a Dockerfile (`Dockerfile`, `Dockerfile.prod`,
+/// `api.Dockerfile`, `Containerfile`).
+pub fn is_dockerfile(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    name == "dockerfile"
+        || name == "containerfile"
+        || name.starts_with("dockerfile.")
+        || name.ends_with(".dockerfile")
+}
+
+/// Check if a file is a Docker Compose file (`docker-compose.yml`,
+/// `docker-compose.prod.yaml`, `compose.yaml`).
+pub fn is_compose_file(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("")
+        .to_lowercase();
+    let Some(stem) = name
+        .strip_suffix(".yml")
+        .or_else(|| name.strip_suffix(".yaml"))
+    else {
+        return false;
+    };
+    stem == "compose" || stem == "docker-compose" || stem.starts_with("docker-compose.")
+}
+
+/// Line-oriented Dockerfile analyzer.
+pub struct DockerfileAnalyzer;
+
+impl DockerfileAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn language_id(&self) -> &'static str {
+        "dockerfile"
+    }
+
+    /// Split a Dockerfile into instructions.
+    pub fn parse(&self, source: &str) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        let mut escape = '\\';
+        let mut in_directives = true;
+        let mut pending: Option<Instruction> = None;
+        let mut offset = 0;
+
+        for (idx, raw) in source.split_inclusive('\n').enumerate() {
+            let start = offset;
+            offset += raw.len();
+            let line = raw.trim_end_matches(['\n', '\r']);
+            let trimmed = line.trim();
+
+            // Parser directives (`# escape=``) are only honored before anything else
+            if in_directives {
+                if let Some(directive) = trimmed.strip_prefix('#') {
+                    if let Some((key, value)) = directive.split_once('=') {
+                        if key.trim().eq_ignore_ascii_case("escape") {
+                            escape = value.trim().chars().next().unwrap_or('\\');
+                        }
+                        continue;
+                    }
+                }
+                in_directives = false;
+            }
+
+            // Comments and blank lines do not end a continuation
+            if trimmed.starts_with('#') || trimmed.is_empty() {
+                continue;
+            }
+
+            let (text, continues) = match trimmed.strip_suffix(escape) {
+                Some(rest) => (rest.trim_end(), true),
+                None => (trimmed, false),
+            };
+
+            let mut current = match pending.take() {
+                Some(mut instruction) => {
+                    if !text.is_empty() {
+                        if !instruction.args.is_empty() {
+                            instruction.args.push(' ');
+                        }
+                        instruction.args.push_str(text);
+                    }
+                    instruction
+                }
+                None => {
+                    let mut parts = text.splitn(2, char::is_whitespace);
+                    Instruction {
+                        keyword: parts.next().unwrap_or("").to_uppercase(),
+                        args: parts.next().unwrap_or("").trim().to_string(),
+                        line: idx + 1,
+                        start_byte: start,
+                        end_byte: 0,
+                    }
+                }
+            };
+            current.end_byte = start + line.len();
+
+            if continues {
+                pending = Some(current);
+            } else {
+                instructions.push(current);
+            }
+        }
+        instructions.extend(pending);
+
+        instructions
+    }
+
+    /// Extract facts: build stages as types, ENV and ARG names as constants,
+    /// and their values as string literals.
+    pub fn extract_facts(&self, path: &str, source: &str) -> FileFacts {
+        let mut facts = FileFacts::empty(path, self.language_id());
+
+        for instruction in self.parse(source) {
+            let text = &source[instruction.start_byte..instruction.end_byte];
+            let span = Span {
+                start_byte: instruction.start_byte,
+                end_byte: instruction.end_byte,
+                start_line: instruction.line,
+                start_col: 1,
+                end_line: instruction.line + text.matches('\n').count(),
+                end_col: text.rsplit('\n').next().map_or(1, |l| l.len() + 1),
+            };
+            let declare = |name: &str, kind| Declaration {
+                name: name.to_string(),
+                kind,
+                span: span.clone(),
+                receiver: None,
+                is_async: false,
+                param_count: None,
+                body: None,
+                members: Vec::new(),
+                annotations: Vec::new(),
+                is_exported: false,
+                has_doc: false,
+            };
+
+            match instruction.keyword.as_str() {
+                "FROM" => {
+                    let words = instruction.command_words();
+                    if let Some(pos) = words.iter().position(|w| w.eq_ignore_ascii_case("as")) {
+                        if let Some(stage) = words.get(pos + 1) {
+                            facts.declarations.push(declare(stage, DeclarationKind::Type));
+                        }
+                    }
+                }
+                "ENV" | "ARG" => {
+                    for (key, value) in instruction.key_values() {
+                        facts.declarations.push(declare(&key, DeclarationKind::Const));
+                        if !value.is_empty() {
+                            facts.string_literals.push(StringLiteral {
+                                value,
+                                span: span.clone(),
+                                in_constant: true,
+                                call_name: None,
+                            });
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        facts
+    }
+}
+
+impl Default for DockerfileAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_instructions() {
+        let source = r#"# syntax=docker/dockerfile:1
+# build stage
+FROM golang:1.22 AS build
+RUN apt-get update && \
+    apt-get install -y git \
+    # comment inside a continuation
+    && rm -rf /var/lib/apt/lists/*
+ENV APP_ENV=production API_KEY="CHANGEME"
+ARG VERSION
+CMD ["echo", "TODO"]
+"#;
+        let analyzer = DockerfileAnalyzer::new();
+        let instructions = analyzer.parse(source);
+
+        let summary: Vec<_> = instructions
+            .iter()
+            .map(|i| (i.keyword.as_str(), i.line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("FROM", 3), ("RUN", 4), ("ENV", 8), ("ARG", 9), ("CMD", 10)]
+        );
+        assert_eq!(
+            instructions[1].args,
+            "apt-get update && apt-get install -y git && rm -rf /var/lib/apt/lists/*"
+        );
+        assert_eq!(
+            instructions[2].key_values(),
+            vec![
+                ("APP_ENV".to_string(), "production".to_string()),
+                ("API_KEY".to_string(), "CHANGEME".to_string())
+            ]
+        );
+        assert_eq!(instructions[4].command_words(), vec!["echo", "TODO"]);
+
+        let facts = analyzer.extract_facts("Dockerfile", source);
+        assert_eq!(facts.language, "dockerfile");
+        assert_eq!(facts.find_declaration("build").unwrap().kind, DeclarationKind::Type);
+        assert!(facts.find_declaration("VERSION").is_some());
+        let values: Vec<_> = facts.string_literals.iter().map(|l| l.value.as_str()).collect();
+        assert_eq!(values, vec!["production", "CHANGEME"]);
+    }
+
+    #[test]
+    fn test_file_names() {
+        assert!(is_dockerfile(Path::new("Dockerfile")));
+        assert!(is_dockerfile(Path::new("deploy/Dockerfile.prod")));
+        assert!(is_dockerfile(Path::new("api.Dockerfile")));
+        assert!(is_dockerfile(Path::new("Containerfile")));
+        assert!(!is_dockerfile(Path::new("dockerfile_test.go")));
+
+        assert!(is_compose_file(Path::new("docker-compose.yml")));
+        assert!(is_compose_file(Path::new("docker-compose.prod.yaml")));
+        assert!(is_compose_file(Path::new("compose.yaml")));
+        assert!(!is_compose_file(Path::new("composer.json")));
+        assert!(!is_compose_file(Path::new("config.yml")));
+    }
+}
@@ -3,11 +3,14 @@
 //! Extracts:
 //! - Function declarations (including methods with receivers)
 //! - Type declarations (struct, interface, type aliases)
-//! - Constant declarations
+//! - Interface method names and embedded interfaces
+//! - Constant declarations, and `iota` blocks as enum variants
+//! - Switch statements
 //! - Imports
 //! - Control flow for complexity
 //! - Function body details for stub detection
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use streaming_iterator::StreamingIterator;
@@ -17,6 +20,21 @@ use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::inheritance::{declared_members, extract_implementations, InheritanceSyntax};
+use crate::analysis::generics::extract_callable_types;
+use crate::analysis::params::{count_parameters, extract_unassigned_results, extract_unused_parameters};
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::constants::{extract_constant_groups, ConstantSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::noops::{extract_noop_statements, NoopSyntax};
+use crate::analysis::rethrows::{extract_logged_rethrows, Handler, RethrowSyntax};
+use crate::analysis::switches::{extract_switches, SwitchSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
+use crate::analysis::doc_comments::{has_doc_comment, is_exported, DocSyntax, ExportRule};
 
 /// Tree-sitter query for extracting Go declarations.
 const DECLARATION_QUERY: &str = r#"
@@ -110,6 +128,180 @@ const CONTROL_FLOW_QUERY: &str = r#"
 "#;
 
 
+/// String literal syntax for Go.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["interpreted_string_literal", "raw_string_literal"],
+    // Imports and struct tags are not shared values
+    skip_kinds: &["import_declaration", "field_declaration"],
+    call_kinds: &["call_expression"],
+    is_constant: is_constant_node,
+};
+
+/// Constant group syntax for Go.
+const CONSTANT_SYNTAX: ConstantSyntax = ConstantSyntax {
+    declaration_kinds: &["const_declaration"],
+    body_kinds: &[],
+    member_kinds: &["const_spec"],
+    name_kinds: &["identifier"],
+    adjacent_kinds: &[],
+    repeats_previous: true,
+};
+
+/// One-statement body syntax for Go, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_statement"],
+    // panic(...) is a call
+    throw_kinds: &[],
+    throw_keyword: None,
+    value_lists: &["expression_list"],
+    call_kinds: &["call_expression"],
+    construct_kinds: &[],
+    name_kinds: &["identifier", "selector_expression"],
+    zero_value_kinds: &[
+        "nil",
+        "int_literal",
+        "float_literal",
+        "interpreted_string_literal",
+        "raw_string_literal",
+        "true",
+        "false",
+    ],
+};
+
+/// Truncated function syntax for Go.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: FUNCTION_KINDS,
+    keywords: &["func"],
+    parameter_kinds: &["parameter_list"],
+    body_openers: &["{"],
+};
+
+/// Doc comment syntax for Go: any comment right above a declaration.
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    comment_kinds: &["comment"],
+    doc_prefixes: &[],
+    wrapper_kinds: &[],
+    attached_kinds: &[],
+    docstrings: false,
+    export: ExportRule::Capitalized,
+};
+
+/// Node kinds of Go functions with a body.
+const FUNCTION_KINDS: &[&str] = &["function_declaration", "method_declaration", "func_literal"];
+
+/// Error handler syntax for Go: an `if err != nil` block returning `err`.
+const RETHROW_SYNTAX: RethrowSyntax = RethrowSyntax {
+    handler: err_check,
+    rethrow_kind: "return_statement",
+    bare_rethrow: false,
+    call_kinds: &["call_expression"],
+    function_kinds: FUNCTION_KINDS,
+};
+
+/// Returns the consequence of an `if x != nil` statement and the checked name.
+fn err_check<'a>(parsed: &'a ParsedFile, node: tree_sitter::Node<'a>) -> Option<Handler<'a>> {
+    if node.kind() != "if_statement" {
+        return None;
+    }
+    let condition = node.child_by_field_name("condition")?;
+    if condition.kind() != "binary_expression" {
+        return None;
+    }
+    let operator = condition.child_by_field_name("operator")?;
+    let left = condition.child_by_field_name("left")?;
+    let right = condition.child_by_field_name("right")?;
+    if parsed.node_text(operator) != "!=" || left.kind() != "identifier" || right.kind() != "nil" {
+        return None;
+    }
+    Some(Handler {
+        body: node.child_by_field_name("consequence")?,
+        error: Some(parsed.node_text(left).to_string()),
+    })
+}
+
+/// No-op statement syntax for Go.
+const NOOP_SYNTAX: NoopSyntax = NoopSyntax {
+    function_kinds: FUNCTION_KINDS,
+    // A bare return with named results returns them
+    has_results: |node| node.child_by_field_name("result").is_some(),
+    blank_value_kinds: &[
+        "identifier",
+        "selector_expression",
+        "int_literal",
+        "float_literal",
+        "interpreted_string_literal",
+        "raw_string_literal",
+        "rune_literal",
+        "true",
+        "false",
+        "nil",
+    ],
+    literal_kinds: &[],
+    empty_statement: None,
+    pass_statement: None,
+};
+
+/// Switch statement syntax for Go.
+const SWITCH_SYNTAX: SwitchSyntax = SwitchSyntax {
+    switch_kinds: &["expression_switch_statement"],
+    case_kinds: &["expression_case"],
+    label_field: "value",
+    default_kinds: &["default_case"],
+};
+
+/// Interface syntax for Go.
+///
+/// Go types satisfy interfaces implicitly; the only declared relation is an
+/// interface embedding another.
+const INHERITANCE_SYNTAX: InheritanceSyntax = InheritanceSyntax {
+    body_kinds: &["interface_type"],
+    member_kinds: &["method_elem", "method_spec"],
+    type_kinds: &["type_spec"],
+    name_field: "name",
+    supertype_fields: &[],
+    clause_kinds: &["interface_type", "type_elem"],
+    skip_kinds: &["method_elem", "method_spec"],
+};
+
+/// Constants of `iota` blocks, by the type they are declared with.
+///
+/// A spec with a type starts a run of constants of that type; specs with only
+/// a name repeat the previous spec's type and expression.
+fn iota_variants(parsed: &ParsedFile) -> HashMap<String, Vec<String>> {
+    let mut variants: HashMap<String, Vec<String>> = HashMap::new();
+    let root = parsed.tree.root_node();
+    let mut cursor = root.walk();
+    for block in root.named_children(&mut cursor) {
+        if block.kind() != "const_declaration" || !parsed.node_text(block).contains("iota") {
+            continue;
+        }
+        let mut current: Option<String> = None;
+        let mut specs = block.walk();
+        for spec in block.named_children(&mut specs).filter(|n| n.kind() == "const_spec") {
+            if spec.child_by_field_name("value").is_some() {
+                current = spec
+                    .child_by_field_name("type")
+                    .map(|t| parsed.node_text(t).to_string());
+            }
+            let (Some(ty), Some(name)) = (&current, spec.child_by_field_name("name")) else {
+                continue;
+            };
+            let name = parsed.node_text(name);
+            if name != "_" {
+                variants.entry(ty.clone()).or_default().push(name.to_string());
+            }
+        }
+    }
+    variants
+}
+
+/// Returns true if the node declares a constant.
+fn is_constant_node(_parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    node.kind() == "const_declaration"
+}
+
 /// Go language analyzer.
 pub struct GoAnalyzer {
     language: Language,
@@ -125,9 +317,7 @@ impl GoAnalyzer {
 
     /// Create a new parser for this thread.
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     /// Extract the package name from a parsed file.
@@ -220,17 +410,43 @@ impl GoAnalyzer {
                         None
                     };
 
+                    let exported = is_exported(parsed, node, &name, receiver.as_deref(), &DOC_SYNTAX);
+                    let documented = has_doc_comment(parsed, node, &DOC_SYNTAX);
                     declarations.push(Declaration {
                         name,
                         kind,
                         span: Span::from_node(node),
                         receiver,
+                        is_async: false,
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: if kind == DeclarationKind::Interface {
+                            declared_members(parsed, node, &INHERITANCE_SYNTAX)
+                        } else {
+                            Vec::new()
+                        },
+                        annotations: Vec::new(),
+                        is_exported: exported,
+                        has_doc: documented,
                     });
                 }
             }
         }
 
+        // Go has no enum declaration; a type with an iota block is the equivalent
+        let variants = iota_variants(parsed);
+        for decl in &mut declarations {
+            if decl.kind == DeclarationKind::Type {
+                if let Some(names) = variants.get(&decl.name) {
+                    decl.members = names.clone();
+                }
+            }
+        }
+
         // Sort by position for deterministic output
         declarations.sort_by_key(|d| (d.span.start_byte, d.name.clone()));
 
@@ -258,12 +474,14 @@ impl GoAnalyzer {
         let span = Span::from_node(body_node);
 
         // Count statements
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| {
                 !matches!(n.kind(), "{" | "}" | "comment")
             })
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         // Analyze body contents
         let is_empty = statement_count == 0;
@@ -283,12 +501,16 @@ impl GoAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only,
             is_nil_return_only,
             has_only_todo_comment,
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: 0,
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -371,8 +593,7 @@ impl GoAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") || text.contains("UNIMPLEMENTED") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -391,6 +612,9 @@ impl GoAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -453,6 +677,7 @@ impl GoAnalyzer {
                         path,
                         alias,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -516,6 +741,27 @@ impl LanguageAnalyzer for GoAnalyzer {
             package,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: extract_noop_statements(parsed, &NOOP_SYNTAX),
+            logged_rethrows: extract_logged_rethrows(parsed, &RETHROW_SYNTAX),
+            unused_parameters: extract_unused_parameters(parsed, FUNCTION_KINDS),
+            unassigned_results: extract_unassigned_results(parsed, FUNCTION_KINDS),
+            parameter_defaults: Vec::new(),
+            constant_groups: extract_constant_groups(parsed, &CONSTANT_SYNTAX),
+            function_signatures: Vec::new(),
+            call_sites: Vec::new(),
+            deprecations: Vec::new(),
+            forwarding_calls: Vec::new(),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: extract_switches(parsed, &SWITCH_SYNTAX),
+            implementations: extract_implementations(parsed, &INHERITANCE_SYNTAX),
+            callable_types: extract_callable_types(parsed, &["function_declaration", "method_declaration"]),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors,
             parse_error,
         })
@@ -820,4 +1066,40 @@ func realFunc(x int) int {
         assert!(!body.is_nil_return_only);
         assert!(!body.has_only_todo_comment);
     }
+
+    #[test]
+    fn test_extract_string_literals() {
+        let source = r#"
+package main
+
+import "fmt"
+
+const Region = "us-east-1"
+
+type T struct {
+    Name string `json:"name"`
+}
+
+func main() {
+    fmt.Println("starting")
+    connect("us-east-1")
+}
+"#;
+        let (analyzer, parsed) = parse_go(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        // Import path and struct tag are skipped
+        assert_eq!(facts.string_literals.len(), 3);
+        let constant = &facts.string_literals[0];
+        assert_eq!(constant.value, "us-east-1");
+        assert!(constant.in_constant);
+
+        let logged = &facts.string_literals[1];
+        assert_eq!(logged.call_name.as_deref(), Some("fmt.Println"));
+
+        let arg = &facts.string_literals[2];
+        assert!(!arg.in_constant);
+        assert_eq!(arg.call_name.as_deref(), Some("connect"));
+        assert_eq!(arg.span.start_line, 14);
+    }
 }
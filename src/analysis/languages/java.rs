@@ -9,6 +9,22 @@ use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::annotations::extract_annotations;
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::inheritance::{declared_members, extract_implementations, InheritanceSyntax};
+use crate::analysis::generics::extract_callable_types;
+use crate::analysis::calls::extract_call_sites;
+use crate::analysis::deprecations::extract_deprecations;
+use crate::analysis::wrappers::extract_forwarding_calls;
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::rethrows::{extract_logged_rethrows, Handler, RethrowSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
+use crate::analysis::doc_comments::{has_doc_comment, is_exported, DocSyntax, ExportRule};
 
 const DECLARATION_QUERY: &str = r#"
 ; Method declarations
@@ -71,6 +87,105 @@ const PACKAGE_QUERY: &str = r#"
 )
 "#;
 
+/// Call node kinds for Java, with the field holding the callee.
+const CALL_KINDS: &[(&str, &str)] = &[("method_invocation", "name"), ("object_creation_expression", "type")];
+
+/// Callable node kinds for Java that can be marked deprecated.
+const CALLABLE_KINDS: &[&str] = &["method_declaration", "constructor_declaration"];
+
+/// Error handler syntax for Java.
+const RETHROW_SYNTAX: RethrowSyntax = RethrowSyntax {
+    handler: catch_handler,
+    rethrow_kind: "throw_statement",
+    bare_rethrow: false,
+    call_kinds: &["method_invocation"],
+    function_kinds: &["lambda_expression", "class_body"],
+};
+
+/// Returns the body of a `catch` clause and its parameter name.
+fn catch_handler<'a>(parsed: &'a ParsedFile, node: tree_sitter::Node<'a>) -> Option<Handler<'a>> {
+    if node.kind() != "catch_clause" {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let error = node
+        .named_children(&mut cursor)
+        .find(|n| n.kind() == "catch_formal_parameter")
+        .and_then(|param| param.child_by_field_name("name"))
+        .map(|name| parsed.node_text(name).to_string());
+    Some(Handler { body: node.child_by_field_name("body")?, error })
+}
+
+/// String literal syntax for Java.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string_literal"],
+    skip_kinds: &["import_declaration", "package_declaration"],
+    call_kinds: &["method_invocation", "object_creation_expression"],
+    is_constant: is_constant_node,
+};
+
+/// One-statement body syntax for Java, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_statement"],
+    throw_kinds: &["throw_statement"],
+    throw_keyword: None,
+    value_lists: &[],
+    call_kinds: &["method_invocation"],
+    construct_kinds: &["object_creation_expression"],
+    name_kinds: &["identifier", "field_access"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for Java.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &["method_declaration", "constructor_declaration", "lambda_expression"],
+    keywords: &[],
+    parameter_kinds: &["formal_parameters"],
+    body_openers: &["{"],
+};
+
+/// Doc comment syntax for Java: Javadoc blocks.
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    comment_kinds: &["line_comment", "block_comment"],
+    doc_prefixes: &["/**"],
+    wrapper_kinds: &[],
+    attached_kinds: &[],
+    docstrings: false,
+    export: ExportRule::Modifier(&["public"]),
+};
+
+/// Interface syntax for Java.
+const INHERITANCE_SYNTAX: InheritanceSyntax = InheritanceSyntax {
+    body_kinds: &["interface_body"],
+    member_kinds: &["method_declaration"],
+    type_kinds: &[
+        "class_declaration",
+        "interface_declaration",
+        "enum_declaration",
+        "record_declaration",
+    ],
+    name_field: "name",
+    supertype_fields: &[],
+    clause_kinds: &["superclass", "super_interfaces", "extends_interfaces", "type_list"],
+    skip_kinds: &[],
+};
+
+/// Returns true if the node declares a constant.
+fn is_constant_node(parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    if node.kind() != "field_declaration" {
+        return false;
+    }
+    node.children(&mut node.walk())
+        .find(|c| c.kind() == "modifiers")
+        .map(|m| {
+            let text = parsed.node_text(m);
+            text.contains("static") && text.contains("final")
+        })
+        .unwrap_or(false)
+}
+
 pub struct JavaAnalyzer {
     language: Language,
 }
@@ -83,9 +198,7 @@ impl JavaAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -141,12 +254,28 @@ impl JavaAnalyzer {
                         None
                     };
 
+                    let exported = is_exported(parsed, node, &name, None, &DOC_SYNTAX);
+                    let documented = has_doc_comment(parsed, node, &DOC_SYNTAX);
                     declarations.push(Declaration {
                         name,
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: false,
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: if kind == DeclarationKind::Interface {
+                            declared_members(parsed, node, &INHERITANCE_SYNTAX)
+                        } else {
+                            Vec::new()
+                        },
+                        annotations: extract_annotations(parsed, node),
+                        is_exported: exported,
+                        has_doc: documented,
                     });
                 }
             }
@@ -173,10 +302,12 @@ impl JavaAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "line_comment" | "block_comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let is_panic_only = self.is_throw_only(parsed, body_node);
@@ -185,12 +316,16 @@ impl JavaAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only,
             is_nil_return_only: self.is_null_return_only(parsed, body_node),
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: 0,
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -234,8 +369,7 @@ impl JavaAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "line_comment" | "block_comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -251,6 +385,9 @@ impl JavaAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -321,6 +458,7 @@ impl JavaAnalyzer {
                         path,
                         alias: None,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -374,6 +512,27 @@ impl LanguageAnalyzer for JavaAnalyzer {
             package,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: Vec::new(),
+            logged_rethrows: extract_logged_rethrows(parsed, &RETHROW_SYNTAX),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: Vec::new(),
+            function_signatures: Vec::new(),
+            call_sites: extract_call_sites(parsed, CALL_KINDS),
+            deprecations: extract_deprecations(parsed, CALLABLE_KINDS),
+            forwarding_calls: extract_forwarding_calls(parsed, CALLABLE_KINDS, CALL_KINDS),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: Vec::new(),
+            implementations: extract_implementations(parsed, &INHERITANCE_SYNTAX),
+            callable_types: extract_callable_types(parsed, CALLABLE_KINDS),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -435,6 +594,63 @@ enum MyEnum {
         assert!(facts.declarations.iter().any(|d| d.name == "MyEnum" && d.kind == DeclarationKind::Enum));
     }
 
+    #[test]
+    fn test_extract_implementations() {
+        let source = r#"
+interface Audited extends Repository<Item> {
+    void log(Event e);
+    default List<Event> history() { return List.of(); }
+}
+
+class SqlRepository extends Base implements Audited, com.acme.Closeable {}
+"#;
+        let (analyzer, parsed) = parse_java(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let audited = facts.find_declaration("Audited").unwrap();
+        assert_eq!(audited.members, vec!["log", "history"]);
+
+        let relations: Vec<_> = facts
+            .implementations
+            .iter()
+            .map(|i| (i.type_name.as_str(), i.interface.as_str()))
+            .collect();
+        assert_eq!(
+            relations,
+            vec![
+                ("Audited", "Repository"),
+                ("SqlRepository", "Base"),
+                ("SqlRepository", "Audited"),
+                ("SqlRepository", "Closeable"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_annotations() {
+        let source = r#"
+@RestController
+@RequestMapping("/api/users")
+public class UserController {
+    @GetMapping(value = "/{id}")
+    public User get(@PathVariable long id) { return repo.find(id); }
+
+    @org.junit.Test
+    void plain() {}
+
+    public void unannotated() {}
+}
+"#;
+        let (analyzer, parsed) = parse_java(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let annotations = |name: &str| facts.find_declaration(name).unwrap().annotations.clone();
+        assert_eq!(annotations("UserController"), vec!["RestController", "RequestMapping"]);
+        assert_eq!(annotations("get"), vec!["GetMapping"]);
+        assert_eq!(annotations("plain"), vec!["org.junit.Test"]);
+        assert!(annotations("unannotated").is_empty());
+    }
+
     #[test]
     fn test_stub_detection() {
         let source = r#"
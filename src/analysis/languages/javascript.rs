@@ -9,6 +9,22 @@ use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::calls::extract_call_sites;
+use crate::analysis::deprecations::extract_deprecations;
+use crate::analysis::wrappers::extract_forwarding_calls;
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::noops::{extract_noop_statements, NoopSyntax};
+use crate::analysis::awaits::{count_awaits, AwaitSyntax};
+use crate::analysis::ecmascript::{catch_handler, extract_discarded_calls, is_async_function, is_constant_node};
+use crate::analysis::rethrows::{extract_logged_rethrows, RethrowSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
+use crate::analysis::doc_comments::{has_doc_comment, is_exported, DocSyntax, ExportRule};
 
 const DECLARATION_QUERY: &str = r#"
 ; Function declarations
@@ -74,6 +90,110 @@ const IMPORT_QUERY: &str = r#"
 ) @reexport
 "#;
 
+/// Call node kinds for JavaScript, with the field holding the callee.
+const CALL_KINDS: &[(&str, &str)] = &[("call_expression", "function"), ("new_expression", "constructor")];
+
+/// Callable node kinds for JavaScript that can be marked deprecated.
+const CALLABLE_KINDS: &[&str] = &["function_declaration", "generator_function_declaration", "method_definition"];
+
+/// String literal syntax for JavaScript.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string", "template_string"],
+    skip_kinds: &["import_statement"],
+    call_kinds: &["call_expression", "new_expression"],
+    is_constant: is_constant_node,
+};
+
+/// One-statement body syntax for JavaScript, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_statement"],
+    throw_kinds: &["throw_statement"],
+    throw_keyword: None,
+    value_lists: &[],
+    call_kinds: &["call_expression"],
+    construct_kinds: &["new_expression"],
+    name_kinds: &["identifier", "member_expression"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for JavaScript.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "function",
+        "generator_function_declaration",
+        "generator_function",
+        "arrow_function",
+        "method_definition",
+    ],
+    keywords: &["function"],
+    parameter_kinds: &["formal_parameters"],
+    body_openers: &["{"],
+};
+
+/// Doc comment syntax for JavaScript: JSDoc blocks.
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    comment_kinds: &["comment"],
+    doc_prefixes: &["/**"],
+    wrapper_kinds: &["export_statement", "lexical_declaration", "variable_declaration"],
+    attached_kinds: &["decorator"],
+    docstrings: false,
+    export: ExportRule::ExportStatement,
+};
+
+/// Error handler syntax for JavaScript.
+const RETHROW_SYNTAX: RethrowSyntax = RethrowSyntax {
+    handler: catch_handler,
+    rethrow_kind: "throw_statement",
+    bare_rethrow: false,
+    call_kinds: &["call_expression"],
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "function",
+        "generator_function_declaration",
+        "generator_function",
+        "arrow_function",
+        "method_definition",
+    ],
+};
+
+/// Await syntax for JavaScript.
+const AWAIT_SYNTAX: AwaitSyntax = AwaitSyntax {
+    await_kinds: &["await_expression"],
+    awaiting_statements: &[("for_in_statement", "await")],
+    returned_future_kinds: &[],
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "arrow_function",
+        "method_definition",
+        "generator_function_declaration",
+        "generator_function",
+    ],
+};
+
+/// No-op statement syntax for JavaScript.
+const NOOP_SYNTAX: NoopSyntax = NoopSyntax {
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "function",
+        "generator_function_declaration",
+        "generator_function",
+        "arrow_function",
+        "method_definition",
+    ],
+    has_results: |_| false,
+    blank_value_kinds: &[],
+    literal_kinds: &[],
+    empty_statement: Some("empty_statement"),
+    pass_statement: None,
+};
+
 pub struct JavaScriptAnalyzer {
     language: Language,
 }
@@ -86,9 +206,7 @@ impl JavaScriptAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -140,12 +258,24 @@ impl JavaScriptAnalyzer {
                         None
                     };
 
+                    let exported = is_exported(parsed, node, &name, None, &DOC_SYNTAX);
+                    let documented = has_doc_comment(parsed, node, &DOC_SYNTAX);
                     declarations.push(Declaration {
                         name,
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: kind.is_callable() && is_async_function(node),
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: Vec::new(),
+                        annotations: Vec::new(),
+                        is_exported: exported,
+                        has_doc: documented,
                     });
                 }
             }
@@ -172,10 +302,12 @@ impl JavaScriptAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let control_flow = self.extract_control_flow(parsed, body_node)?;
@@ -183,12 +315,16 @@ impl JavaScriptAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only: self.is_throw_only(parsed, body_node),
             is_nil_return_only: self.is_null_return_only(parsed, body_node),
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: count_awaits(body_node, &AWAIT_SYNTAX),
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -232,8 +368,7 @@ impl JavaScriptAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -249,6 +384,9 @@ impl JavaScriptAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -306,6 +444,7 @@ impl JavaScriptAnalyzer {
                         path,
                         alias: None,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -358,6 +497,27 @@ impl LanguageAnalyzer for JavaScriptAnalyzer {
             package: None,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: extract_discarded_calls(parsed),
+            noop_statements: extract_noop_statements(parsed, &NOOP_SYNTAX),
+            logged_rethrows: extract_logged_rethrows(parsed, &RETHROW_SYNTAX),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: Vec::new(),
+            function_signatures: Vec::new(),
+            call_sites: extract_call_sites(parsed, CALL_KINDS),
+            deprecations: extract_deprecations(parsed, CALLABLE_KINDS),
+            forwarding_calls: extract_forwarding_calls(parsed, CALLABLE_KINDS, CALL_KINDS),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: Vec::new(),
+            implementations: Vec::new(),
+            callable_types: Vec::new(),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -1,23 +1,31 @@
 //! Language-specific analyzer implementations.
 
 mod c;
+mod config;
 mod cpp;
+mod dockerfile;
 mod go;
 mod java;
 mod javascript;
 mod python;
 mod rust_lang;
+mod schema;
 mod scala;
 mod swift;
 mod typescript;
 
 pub use c::CAnalyzer;
+pub use config::{parse_config, ConfigEntry, ConfigFormat, ConfigValue};
 pub use cpp::CppAnalyzer;
+pub use dockerfile::{is_compose_file, is_dockerfile, DockerfileAnalyzer, Instruction};
 pub use go::GoAnalyzer;
 pub use java::JavaAnalyzer;
 pub use javascript::JavaScriptAnalyzer;
 pub use python::PythonAnalyzer;
 pub use rust_lang::RustAnalyzer;
+pub use schema::{
+    is_schema_file, SchemaAnalyzer, SchemaComment, SchemaDefinition, SchemaField, SchemaFile, SchemaFormat,
+};
 pub use scala::ScalaAnalyzer;
 pub use swift::SwiftAnalyzer;
 pub use typescript::TypeScriptAnalyzer;
@@ -118,6 +126,49 @@ pub fn get_analyzer(ext: &str) -> Option<&'static dyn LanguageAnalyzer> {
     }
 }
 
+/// Map a file extension to a language ID.
+///
+/// Covers every language with an analyzer plus common languages without one,
+/// so language filters can recognize files that would not be analyzed anyway.
+pub fn language_for_extension(ext: &str) -> Option<&'static str> {
+    match ext {
+        "c" | "h" => Some("c"),
+        "cpp" | "cc" | "cxx" | "hpp" | "hh" => Some("cpp"),
+        "go" => Some("go"),
+        "java" => Some("java"),
+        "js" | "jsx" | "mjs" | "cjs" => Some("javascript"),
+        "py" | "pyi" => Some("python"),
+        "rs" => Some("rust"),
+        "scala" | "sc" => Some("scala"),
+        "swift" => Some("swift"),
+        "ts" | "tsx" | "mts" | "cts" => Some("typescript"),
+        "kt" | "kts" => Some("kotlin"),
+        "pl" | "pm" => Some("perl"),
+        "rb" => Some("ruby"),
+        _ => None,
+    }
+}
+
+/// Resolve a user-supplied language name or alias (e.g., "golang", "py", "ts") to its ID.
+pub fn normalize_language(name: &str) -> Option<&'static str> {
+    match name.trim().to_lowercase().as_str() {
+        "c" => Some("c"),
+        "cpp" | "c++" | "cxx" => Some("cpp"),
+        "go" | "golang" => Some("go"),
+        "java" => Some("java"),
+        "javascript" | "js" | "node" => Some("javascript"),
+        "python" | "py" | "python3" => Some("python"),
+        "rust" | "rs" => Some("rust"),
+        "scala" => Some("scala"),
+        "swift" => Some("swift"),
+        "typescript" | "ts" => Some("typescript"),
+        "kotlin" | "kt" => Some("kotlin"),
+        "perl" | "pl" => Some("perl"),
+        "ruby" | "rb" => Some("ruby"),
+        _ => None,
+    }
+}
+
 /// Get an analyzer by language ID.
 #[allow(dead_code)]
 pub fn get_analyzer_by_id(lang_id: &str) -> Option<&'static dyn LanguageAnalyzer> {
@@ -140,7 +191,6 @@ pub fn get_analyzer_by_id(lang_id: &str) -> Option<&'static dyn LanguageAnalyzer
 }
 
 /// Get all registered language IDs.
-#[allow(dead_code)]
 pub fn registered_languages() -> Vec<String> {
     vec![
         "c".to_string(),
@@ -7,8 +7,27 @@ use tree_sitter::{Language, Parser, Query, QueryCursor};
 
 use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
-    LanguageAnalyzer, ParsedFile, Span,
+    LanguageAnalyzer, ParameterDefault, ParsedFile, Span,
 };
+use crate::analysis::annotations::extract_annotations;
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::inheritance::{
+    declared_members, extract_implementations, simple_type_name, InheritanceSyntax,
+};
+use crate::analysis::calls::extract_call_sites;
+use crate::analysis::deprecations::extract_deprecations;
+use crate::analysis::wrappers::extract_forwarding_calls;
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::literals::{extract_string_literals, is_constant_name, LiteralSyntax};
+use crate::analysis::noops::{extract_noop_statements, NoopSyntax};
+use crate::analysis::awaits::{count_awaits, AwaitSyntax};
+use crate::analysis::rethrows::{extract_logged_rethrows, Handler, RethrowSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
+use crate::analysis::doc_comments::{has_doc_comment, is_exported, DocSyntax, ExportRule};
 
 const DECLARATION_QUERY: &str = r#"
 ; Function definitions
@@ -67,6 +86,174 @@ const IMPORT_QUERY: &str = r#"
 ) @import_relative
 "#;
 
+/// Tree-sitter query for parameters with default values.
+const PARAMETER_DEFAULT_QUERY: &str = r#"
+(function_definition
+  name: (identifier) @func_name
+  parameters: (parameters
+    [
+      (default_parameter name: (identifier) @param_name value: (_) @value)
+      (typed_default_parameter name: (identifier) @param_name value: (_) @value)
+    ] @param
+  )
+)
+"#;
+
+/// Call node kinds for Python, with the field holding the callee.
+const CALL_KINDS: &[(&str, &str)] = &[("call", "function")];
+
+/// Callable node kinds for Python that can be marked deprecated.
+const CALLABLE_KINDS: &[&str] = &["function_definition"];
+
+/// String literal syntax for Python.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string"],
+    skip_kinds: &["import_statement", "import_from_statement"],
+    call_kinds: &["call"],
+    is_constant: is_constant_node,
+};
+
+/// One-statement body syntax for Python, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_statement"],
+    throw_kinds: &["raise_statement"],
+    throw_keyword: None,
+    value_lists: &["expression_list"],
+    call_kinds: &["call"],
+    construct_kinds: &[],
+    name_kinds: &["identifier", "attribute"],
+    zero_value_kinds: &["none", "integer", "float", "string", "true", "false"],
+};
+
+/// Truncated function syntax for Python.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &["function_definition"],
+    keywords: &["def"],
+    parameter_kinds: &["parameters"],
+    body_openers: &[":"],
+};
+
+/// Doc comment syntax for Python: docstrings only.
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    comment_kinds: &[],
+    doc_prefixes: &[],
+    wrapper_kinds: &[],
+    attached_kinds: &[],
+    docstrings: true,
+    export: ExportRule::NoUnderscore,
+};
+
+/// Await syntax for Python.
+const AWAIT_SYNTAX: AwaitSyntax = AwaitSyntax {
+    await_kinds: &["await"],
+    awaiting_statements: &[("for_statement", "async"), ("with_statement", "async")],
+    returned_future_kinds: &[],
+    function_kinds: &["function_definition", "lambda"],
+};
+
+/// No-op statement syntax for Python.
+const NOOP_SYNTAX: NoopSyntax = NoopSyntax {
+    function_kinds: &["function_definition"],
+    has_results: |_| false,
+    blank_value_kinds: &[],
+    literal_kinds: &["integer", "float", "string", "concatenated_string", "true", "false", "none"],
+    empty_statement: None,
+    pass_statement: Some("pass_statement"),
+};
+
+/// Error handler syntax for Python.
+const RETHROW_SYNTAX: RethrowSyntax = RethrowSyntax {
+    handler: except_handler,
+    rethrow_kind: "raise_statement",
+    bare_rethrow: true,
+    call_kinds: &["call"],
+    function_kinds: &["function_definition", "lambda"],
+};
+
+/// Returns the body of an `except` clause and the name bound by `as`.
+fn except_handler<'a>(parsed: &'a ParsedFile, node: tree_sitter::Node<'a>) -> Option<Handler<'a>> {
+    if node.kind() != "except_clause" {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let body = node.named_children(&mut cursor).find(|n| n.kind() == "block")?;
+    let error = node
+        .child_by_field_name("value")
+        .filter(|value| value.kind() == "as_pattern")
+        .and_then(|pattern| pattern.child_by_field_name("alias"))
+        .map(|alias| parsed.node_text(alias).trim().to_string());
+    Some(Handler { body, error })
+}
+
+/// Base class syntax for Python.
+const INHERITANCE_SYNTAX: InheritanceSyntax = InheritanceSyntax {
+    body_kinds: &["block"],
+    member_kinds: &["function_definition"],
+    type_kinds: &["class_definition"],
+    name_field: "name",
+    supertype_fields: &[],
+    clause_kinds: &["argument_list"],
+    skip_kinds: &["keyword_argument"],
+};
+
+/// Returns true if a class is an abstract base (`class Repo(ABC)`,
+/// `class Repo(metaclass=ABCMeta)`).
+///
+/// Protocols are satisfied structurally and are left as plain types.
+fn is_abstract_base(parsed: &ParsedFile, class: tree_sitter::Node) -> bool {
+    let Some(bases) = class.child_by_field_name("superclasses") else {
+        return false;
+    };
+    let mut cursor = bases.walk();
+    let is_abstract = bases.named_children(&mut cursor).any(|base| match base.kind() {
+        "keyword_argument" => {
+            base.child_by_field_name("name").map(|n| parsed.node_text(n)) == Some("metaclass")
+                && base
+                    .child_by_field_name("value")
+                    .is_some_and(|v| simple_type_name(parsed.node_text(v)) == "ABCMeta")
+        }
+        _ => simple_type_name(parsed.node_text(base)) == "ABC",
+    });
+    is_abstract
+}
+
+/// Returns true if the node declares a constant.
+/// Whether an import sits in the body of a `try` whose handlers catch a
+/// failed import: `except ImportError`, `except ModuleNotFoundError`, or a
+/// bare `except`.
+fn is_optional_import(parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    let mut child = node;
+    while let Some(parent) = child.parent() {
+        if parent.kind() == "try_statement" && parent.child_by_field_name("body") == Some(child) {
+            let mut cursor = parent.walk();
+            let catches_import_error = parent
+                .named_children(&mut cursor)
+                .filter(|c| c.kind() == "except_clause")
+                .any(|clause| {
+                    let text = parsed.node_text(clause);
+                    let handled = text.split(':').next().unwrap_or("").trim();
+                    handled == "except" || handled.contains("ImportError") || handled.contains("ModuleNotFoundError")
+                });
+            if catches_import_error {
+                return true;
+            }
+        }
+        child = parent;
+    }
+    false
+}
+
+fn is_constant_node(parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    // Python has no const keyword; module-level SCREAMING_CASE names are the convention
+    node.kind() == "assignment"
+        && node
+            .child_by_field_name("left")
+            .map(|left| is_constant_name(parsed.node_text(left)))
+            .unwrap_or(false)
+}
+
 pub struct PythonAnalyzer {
     language: Language,
 }
@@ -79,9 +266,7 @@ impl PythonAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -129,21 +314,127 @@ impl PythonAnalyzer {
                         None
                     };
 
+                    // Abstract base classes are Python's interfaces
+                    let class = match node.kind() {
+                        "decorated_definition" => node.child_by_field_name("definition"),
+                        _ => Some(node),
+                    };
+                    let members = match class {
+                        Some(class)
+                            if kind == DeclarationKind::Type && is_abstract_base(parsed, class) =>
+                        {
+                            kind = DeclarationKind::Interface;
+                            declared_members(parsed, class, &INHERITANCE_SYNTAX)
+                        }
+                        _ => Vec::new(),
+                    };
+
+                    let exported = is_exported(parsed, node, &name, None, &DOC_SYNTAX);
+                    let documented = has_doc_comment(parsed, node, &DOC_SYNTAX);
                     declarations.push(Declaration {
                         name,
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: kind.is_callable()
+                            && node.children(&mut node.walk()).any(|c| c.kind() == "async"),
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members,
+                        annotations: extract_annotations(parsed, node),
+                        is_exported: exported,
+                        has_doc: documented,
                     });
                 }
             }
         }
 
+        declarations.extend(self.extract_module_variables(parsed));
         declarations.sort_by_key(|d| (d.span.start_byte, d.name.clone()));
         Ok(declarations)
     }
 
+    /// Extract module-level assignments (`TIMEOUT = 30`, `a, b = pair()`) as constants.
+    fn extract_module_variables(&self, parsed: &ParsedFile) -> Vec<Declaration> {
+        let root = parsed.tree.root_node();
+        let mut variables = Vec::new();
+
+        for stmt in root.named_children(&mut root.walk()) {
+            if stmt.kind() != "expression_statement" {
+                continue;
+            }
+            let Some(assignment) = stmt.named_child(0).filter(|n| n.kind() == "assignment") else {
+                continue;
+            };
+            let Some(left) = assignment.child_by_field_name("left") else {
+                continue;
+            };
+
+            let targets: Vec<tree_sitter::Node> = match left.kind() {
+                "identifier" => vec![left],
+                "pattern_list" | "tuple_pattern" => left
+                    .named_children(&mut left.walk())
+                    .filter(|n| n.kind() == "identifier")
+                    .collect(),
+                _ => continue,
+            };
+            for target in targets {
+                let name = parsed.node_text(target);
+                variables.push(Declaration {
+                    name: name.to_string(),
+                    kind: DeclarationKind::Const,
+                    span: Span::from_node(stmt),
+                    receiver: None,
+                    is_async: false,
+                    param_count: None,
+                    body: None,
+                    members: Vec::new(),
+                    annotations: Vec::new(),
+                    is_exported: !name.starts_with('_'),
+                    has_doc: false,
+                });
+            }
+        }
+
+        variables
+    }
+
+    /// Extract parameters declared with a default value (`items=[]`, `limit: int = 10`).
+    fn extract_parameter_defaults(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<ParameterDefault>> {
+        let query = Query::new(&self.language, PARAMETER_DEFAULT_QUERY)?;
+        let mut cursor = QueryCursor::new();
+        let mut matches = cursor.matches(&query, parsed.tree.root_node(), &parsed.source[..]);
+
+        let mut defaults = Vec::new();
+        while let Some(m) = matches.next() {
+            let node = |name: &str| {
+                m.captures
+                    .iter()
+                    .find(|c| query.capture_names()[c.index as usize] == name)
+                    .map(|c| c.node)
+            };
+            let (Some(function), Some(param), Some(param_name), Some(value)) =
+                (node("func_name"), node("param"), node("param_name"), node("value"))
+            else {
+                continue;
+            };
+            defaults.push(ParameterDefault {
+                function: parsed.node_text(function).to_string(),
+                name: parsed.node_text(param_name).to_string(),
+                value: parsed.node_text(value).to_string(),
+                value_kind: value.kind().to_string(),
+                span: Span::from_node(param),
+            });
+        }
+
+        defaults.sort_by_key(|d| d.span.start_byte);
+        Ok(defaults)
+    }
+
     fn extract_function_body(
         &self,
         parsed: &ParsedFile,
@@ -162,10 +453,12 @@ impl PythonAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let control_flow = self.extract_control_flow(parsed, body_node)?;
@@ -173,12 +466,16 @@ impl PythonAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only: self.is_raise_only(parsed, body_node),
             is_nil_return_only: self.is_none_return_only(parsed, body_node),
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: count_awaits(body_node, &AWAIT_SYNTAX),
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -254,8 +551,7 @@ impl PythonAnalyzer {
         for child in body_node.children(&mut body_node.walk()) {
             match child.kind() {
                 "comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -265,12 +561,9 @@ impl PythonAnalyzer {
                 "expression_statement" => {
                     // Check for docstring (string as first statement)
                     if let Some(first_child) = child.child(0) {
-                        if first_child.kind() == "string" {
-                            let text = parsed.node_text(first_child).to_uppercase();
-                            if text.contains("TODO") || text.contains("FIXME") {
-                                has_todo = true;
-                                continue;
-                            }
+                        if first_child.kind() == "string" && has_marker(parsed.node_text(first_child)) {
+                            has_todo = true;
+                            continue;
                         }
                     }
                     has_other = true;
@@ -287,6 +580,9 @@ impl PythonAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -348,6 +644,7 @@ impl PythonAnalyzer {
                         path,
                         alias: None,
                         span: Span::from_node(node),
+                        optional: is_optional_import(parsed, node),
                     });
                 }
             }
@@ -400,6 +697,27 @@ impl LanguageAnalyzer for PythonAnalyzer {
             package: None,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: extract_noop_statements(parsed, &NOOP_SYNTAX),
+            logged_rethrows: extract_logged_rethrows(parsed, &RETHROW_SYNTAX),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: self.extract_parameter_defaults(parsed)?,
+            constant_groups: Vec::new(),
+            function_signatures: Vec::new(),
+            call_sites: extract_call_sites(parsed, CALL_KINDS),
+            deprecations: extract_deprecations(parsed, CALLABLE_KINDS),
+            forwarding_calls: extract_forwarding_calls(parsed, CALLABLE_KINDS, CALL_KINDS),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: Vec::new(),
+            implementations: extract_implementations(parsed, &INHERITANCE_SYNTAX),
+            callable_types: Vec::new(),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -436,6 +754,75 @@ from . import local_module
         assert!(facts.imports.iter().any(|i| i.path == "typing"));
     }
 
+    #[test]
+    fn test_abstract_base_classes() {
+        let source = r#"
+class Repository(abc.ABC):
+    @abstractmethod
+    def find(self, id): ...
+
+    def save(self, item): ...
+
+class Legacy(metaclass=ABCMeta):
+    def run(self): ...
+
+class SqlRepository(Repository, Generic[T]):
+    def find(self, id):
+        return None
+"#;
+        let (analyzer, parsed) = parse_python(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let repo = facts.find_declaration("Repository").unwrap();
+        assert_eq!(repo.kind, DeclarationKind::Interface);
+        assert_eq!(repo.members, vec!["find", "save"]);
+        assert_eq!(facts.find_declaration("Legacy").unwrap().kind, DeclarationKind::Interface);
+        assert_eq!(facts.find_declaration("SqlRepository").unwrap().kind, DeclarationKind::Type);
+
+        let bases: Vec<_> = facts
+            .implementations
+            .iter()
+            .filter(|i| i.type_name == "SqlRepository")
+            .map(|i| i.interface.as_str())
+            .collect();
+        assert_eq!(bases, vec!["Repository", "Generic"]);
+    }
+
+    #[test]
+    fn test_extract_decorators() {
+        let source = r#"
+@app.route("/users/<int:id>", methods=["GET"])
+@login_required
+def show_user(id):
+    return render(id)
+
+@dataclass
+class User:
+    name: str
+
+    @property
+    def display(self):
+        return self.name.title()
+
+def helper():
+    pass
+"#;
+        let (analyzer, parsed) = parse_python(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        // The decorated definition and the function inside it carry the same list
+        let annotations = |name: &str| -> Vec<String> {
+            let found: Vec<_> = facts.declarations.iter().filter(|d| d.name == name).collect();
+            assert!(!found.is_empty(), "{} not found", name);
+            assert!(found.iter().all(|d| d.annotations == found[0].annotations));
+            found[0].annotations.clone()
+        };
+        assert_eq!(annotations("show_user"), vec!["app.route", "login_required"]);
+        assert_eq!(annotations("User"), vec!["dataclass"]);
+        assert_eq!(annotations("display"), vec!["property"]);
+        assert!(annotations("helper").is_empty());
+    }
+
     #[test]
     fn test_extract_functions() {
         let source = r#"
@@ -457,6 +844,30 @@ class MyClass:
         assert!(facts.declarations.iter().any(|d| d.name == "MyClass"));
     }
 
+    #[test]
+    fn test_module_variables() {
+        let source = r#"
+TIMEOUT = 30
+host, port = "localhost", 8080
+retries: int = 3
+config["debug"] = True
+
+def main():
+    local = 1
+"#;
+        let (analyzer, parsed) = parse_python(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let constants: Vec<_> = facts
+            .declarations_by_kind(DeclarationKind::Const)
+            .map(|d| (d.name.as_str(), d.span.start_line))
+            .collect();
+        assert_eq!(
+            constants,
+            vec![("TIMEOUT", 2), ("host", 3), ("port", 3), ("retries", 4)]
+        );
+    }
+
     #[test]
     fn test_stub_detection() {
         let source = r#"
@@ -506,4 +917,49 @@ def stub_none():
         let stub_none = facts.declarations.iter().find(|d| d.name == "stub_none").unwrap();
         assert!(stub_none.body.as_ref().unwrap().is_nil_return_only);
     }
+
+    #[test]
+    fn test_async_functions() {
+        let source = r#"
+async def fetch_user(user_id):
+    return await db.get(user_id)
+
+def sync_helper():
+    return 1
+"#;
+        let (analyzer, parsed) = parse_python(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        assert!(facts.find_declaration("fetch_user").unwrap().is_async);
+        assert!(!facts.find_declaration("sync_helper").unwrap().is_async);
+    }
+
+    #[test]
+    fn test_parameter_defaults() {
+        let source = r#"
+def append(item, items=[], *, seen: set = set(), limit: int = 10):
+    items.append(item)
+
+class Cache:
+    def get(self, key, fallback={}):
+        return fallback
+"#;
+        let (analyzer, parsed) = parse_python(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let defaults: Vec<_> = facts
+            .parameter_defaults
+            .iter()
+            .map(|d| (d.function.as_str(), d.name.as_str(), d.value.as_str(), d.value_kind.as_str(), d.span.start_line))
+            .collect();
+        assert_eq!(
+            defaults,
+            vec![
+                ("append", "items", "[]", "list", 2),
+                ("append", "seen", "set()", "call", 2),
+                ("append", "limit", "10", "integer", 2),
+                ("get", "fallback", "{}", "dictionary", 6),
+            ]
+        );
+    }
 }
@@ -3,9 +3,12 @@
 //! Extracts:
 //! - Function declarations
 //! - Impl methods
-//! - Struct/enum/trait definitions
+//! - Struct/enum/trait definitions, and enum variant names
+//! - Trait method names and `impl Trait for Type` blocks
 //! - Constant declarations
 //! - Use statements (imports)
+//! - Match expressions
+//! - `unwrap`/`expect`/`panic!` call sites per function
 //! - Control flow for complexity
 //! - Function body details for stub detection
 
@@ -16,8 +19,27 @@ use tree_sitter::{Language, Parser, Query, QueryCursor};
 
 use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
-    LanguageAnalyzer, ParsedFile, Span,
+    LanguageAnalyzer, ParsedFile, SignatureFeature, Span, UnwrapUsage,
 };
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::inheritance::{
+    declared_members, extract_implementations, simple_type_name, InheritanceSyntax,
+};
+use crate::analysis::generics::extract_callable_types;
+use crate::analysis::calls::extract_call_sites;
+use crate::analysis::deprecations::extract_deprecations;
+use crate::analysis::wrappers::extract_forwarding_calls;
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::constants::{extract_constant_groups, ConstantSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::switches::{extract_switches, SwitchSyntax};
+use crate::analysis::awaits::{count_awaits, AwaitSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
+use crate::analysis::doc_comments::{has_doc_comment, is_exported, DocSyntax, ExportRule};
 
 /// Tree-sitter query for extracting Rust declarations.
 const DECLARATION_QUERY: &str = r#"
@@ -98,6 +120,201 @@ const CONTROL_FLOW_QUERY: &str = r#"
 (try_expression) @try
 "#;
 
+/// Call node kinds for Rust, with the field holding the callee.
+const CALL_KINDS: &[(&str, &str)] = &[("call_expression", "function")];
+
+/// Callable node kinds for Rust that can be marked deprecated.
+const CALLABLE_KINDS: &[&str] = &["function_item"];
+
+/// String literal syntax for Rust.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string_literal", "raw_string_literal"],
+    // Attribute arguments like #[serde(rename = "...")] are metadata, not values
+    skip_kinds: &["attribute_item", "inner_attribute_item"],
+    call_kinds: &["call_expression", "macro_invocation"],
+    is_constant: is_constant_node,
+};
+
+/// Constant group syntax for Rust.
+const CONSTANT_SYNTAX: ConstantSyntax = ConstantSyntax {
+    declaration_kinds: &["enum_item"],
+    body_kinds: &["enum_variant_list"],
+    member_kinds: &["enum_variant"],
+    name_kinds: &["identifier"],
+    adjacent_kinds: &["const_item"],
+    repeats_previous: false,
+};
+
+/// One-statement body syntax for Rust, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_expression"],
+    // panic!(...) is a macro call
+    throw_kinds: &[],
+    throw_keyword: None,
+    value_lists: &[],
+    call_kinds: &["call_expression", "macro_invocation"],
+    construct_kinds: &[],
+    name_kinds: &["identifier", "scoped_identifier", "field_expression"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for Rust.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &["function_item", "closure_expression"],
+    keywords: &["fn"],
+    parameter_kinds: &["parameters"],
+    body_openers: &["{"],
+};
+
+/// Doc comment syntax for Rust.
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    comment_kinds: &["line_comment", "block_comment"],
+    doc_prefixes: &["///", "/**"],
+    wrapper_kinds: &[],
+    attached_kinds: &["attribute_item"],
+    docstrings: false,
+    export: ExportRule::Modifier(&["pub"]),
+};
+
+/// Trait syntax for Rust.
+const INHERITANCE_SYNTAX: InheritanceSyntax = InheritanceSyntax {
+    body_kinds: &["declaration_list"],
+    member_kinds: &["function_signature_item", "function_item"],
+    type_kinds: &["impl_item"],
+    name_field: "type",
+    supertype_fields: &["trait"],
+    clause_kinds: &[],
+    skip_kinds: &[],
+};
+
+/// Await syntax for Rust; a returned `async` block is awaited by the caller.
+const AWAIT_SYNTAX: AwaitSyntax = AwaitSyntax {
+    await_kinds: &["await_expression"],
+    awaiting_statements: &[],
+    returned_future_kinds: &["async_block"],
+    function_kinds: &["function_item", "closure_expression"],
+};
+
+/// Match expression syntax for Rust; the `_` arm is the default.
+const SWITCH_SYNTAX: SwitchSyntax = SwitchSyntax {
+    switch_kinds: &["match_expression"],
+    case_kinds: &["match_arm"],
+    label_field: "pattern",
+    default_kinds: &[],
+};
+
+/// Variant names of an enum item.
+fn enum_variants(parsed: &ParsedFile, node: tree_sitter::Node) -> Vec<String> {
+    let Some(body) = node.child_by_field_name("body") else {
+        return Vec::new();
+    };
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor)
+        .filter(|n| n.kind() == "enum_variant")
+        .filter_map(|n| n.child_by_field_name("name"))
+        .map(|n| parsed.node_text(n).to_string())
+        .collect()
+}
+
+/// Returns true if the node declares a constant.
+fn is_constant_node(_parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    matches!(node.kind(), "const_item" | "static_item")
+}
+
+/// Whether any outer attribute of an item matches `is_match`.
+fn has_attribute(parsed: &ParsedFile, node: tree_sitter::Node, is_match: impl Fn(&str) -> bool) -> bool {
+    std::iter::successors(node.prev_named_sibling(), |n| n.prev_named_sibling())
+        .take_while(|n| n.kind() == "attribute_item" || n.kind().ends_with("comment"))
+        .filter(|n| n.kind() == "attribute_item")
+        .any(|n| {
+            let text: String = parsed.node_text(n).split_whitespace().collect();
+            is_match(text.trim_start_matches("#[").trim_end_matches(']'))
+        })
+}
+
+/// Whether a function is test code: a `#[test]` function (`#[tokio::test]`
+/// too), or one inside a `#[cfg(test)]` module.
+fn is_test_code(parsed: &ParsedFile, function: tree_sitter::Node) -> bool {
+    if has_attribute(parsed, function, |a| a == "test" || a.ends_with("::test")) {
+        return true;
+    }
+    std::iter::successors(function.parent(), |n| n.parent())
+        .filter(|n| n.kind() == "mod_item")
+        .any(|m| has_attribute(parsed, m, |a| a == "cfg(test)"))
+}
+
+/// Count the `.unwrap()`, `.expect(...)`, and `panic!` sites of every
+/// function, in source order. Closures count towards their enclosing
+/// function; nested function items are counted on their own. Functions
+/// without any are omitted.
+fn extract_unwrap_usage(parsed: &ParsedFile) -> Vec<UnwrapUsage> {
+    let mut usage = Vec::new();
+    let mut functions = vec![parsed.tree.root_node()];
+
+    while let Some(node) = functions.pop() {
+        let mut stack = vec![node];
+        let (mut unwraps, mut expects, mut panics) = (0, 0, 0);
+        while let Some(n) = stack.pop() {
+            if n.kind() == "function_item" && n != node {
+                functions.push(n);
+                continue;
+            }
+            match n.kind() {
+                "call_expression" => {
+                    let method = n
+                        .child_by_field_name("function")
+                        .filter(|f| f.kind() == "field_expression")
+                        .and_then(|f| f.child_by_field_name("field"))
+                        .map(|f| parsed.node_text(f));
+                    match method {
+                        Some("unwrap") => unwraps += 1,
+                        Some("expect") => expects += 1,
+                        _ => {}
+                    }
+                }
+                "macro_invocation" => {
+                    let name = n.child_by_field_name("macro").map(|m| parsed.node_text(m));
+                    if matches!(name, Some("panic" | "std::panic" | "core::panic")) {
+                        panics += 1;
+                    }
+                }
+                _ => {}
+            }
+            let mut cursor = n.walk();
+            stack.extend(n.named_children(&mut cursor));
+        }
+
+        if node.kind() != "function_item" || unwraps + expects + panics == 0 {
+            continue;
+        }
+        let Some(name) = node.child_by_field_name("name") else {
+            continue;
+        };
+        let name = parsed.node_text(name);
+        let impl_type = std::iter::successors(node.parent(), |n| n.parent())
+            .take_while(|n| n.kind() != "function_item")
+            .find(|n| n.kind() == "impl_item")
+            .and_then(|n| n.child_by_field_name("type"))
+            .map(|t| simple_type_name(parsed.node_text(t)));
+        usage.push(UnwrapUsage {
+            function: match impl_type {
+                Some(ty) => format!("{}::{}", ty, name),
+                None => name.to_string(),
+            },
+            unwraps,
+            expects,
+            panics,
+            in_test: is_test_code(parsed, node),
+            span: Span::from_node(node),
+        });
+    }
+
+    usage.sort_by_key(|u| u.span.start_byte);
+    usage
+}
+
 /// Rust language analyzer.
 pub struct RustAnalyzer {
     language: Language,
@@ -113,9 +330,7 @@ impl RustAnalyzer {
 
     /// Create a new parser for this thread.
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     /// Extract declarations from a parsed file.
@@ -190,12 +405,34 @@ impl RustAnalyzer {
                         None
                     };
 
+                    let exported = is_exported(parsed, node, &name, None, &DOC_SYNTAX);
+                    let documented = has_doc_comment(parsed, node, &DOC_SYNTAX);
                     declarations.push(Declaration {
                         name,
                         kind,
                         span: Span::from_node(node),
                         receiver,
+                        is_async: kind.is_callable()
+                            && node
+                                .children(&mut node.walk())
+                                .filter(|c| c.kind() == "function_modifiers")
+                                .any(|m| m.children(&mut m.walk()).any(|c| c.kind() == "async")),
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: match kind {
+                            DeclarationKind::Trait => {
+                                declared_members(parsed, node, &INHERITANCE_SYNTAX)
+                            }
+                            DeclarationKind::Enum => enum_variants(parsed, node),
+                            _ => Vec::new(),
+                        },
+                        annotations: Vec::new(),
+                        is_exported: exported,
+                        has_doc: documented,
                     });
                 }
             }
@@ -227,26 +464,18 @@ impl RustAnalyzer {
         let span = Span::from_node(body_node);
 
         // Count statements
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "line_comment" | "block_comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         // Analyze body contents
-        let raw_is_empty = statement_count == 0;
+        let is_empty = statement_count == 0;
 
         // Check for panic/unimplemented/todo! macro
-        let raw_is_panic_only = self.is_panic_only_body(parsed, body_node);
-
-        // Check for legitimate Rust patterns that should NOT be flagged as stubs
-        let func_text = parsed.node_text(func_node);
-        let is_legitimate_pattern = self.is_legitimate_rust_pattern(
-            parsed, func_node, func_text, raw_is_empty, raw_is_panic_only
-        );
-
-        // Only flag as empty/panic if not a legitimate pattern
-        let is_empty = raw_is_empty && !is_legitimate_pattern;
-        let is_panic_only = raw_is_panic_only && !is_legitimate_pattern;
+        let is_panic_only = self.is_panic_only_body(parsed, body_node);
 
         // Check for None return
         let is_nil_return_only = self.is_none_return_only_body(parsed, body_node);
@@ -260,139 +489,48 @@ impl RustAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only,
             is_nil_return_only,
             has_only_todo_comment,
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: count_awaits(body_node, &AWAIT_SYNTAX),
             text: body_text,
             control_flow,
+            signature: self.signature_features(parsed, func_node),
         }))
     }
 
-    /// Check if this is a legitimate Rust pattern that should not be flagged as a stub.
+    /// Signature features that stub exemptions can match on.
     ///
-    /// Legitimate patterns include:
-    /// - Compile-time trait bound verification functions (check_send, is_unpin, etc.)
-    /// - Conditional compilation no-ops (metrics disabled, etc.)
-    /// - Platform compatibility stubs in stub.rs files
-    /// - Default trait implementations
-    fn is_legitimate_rust_pattern(
-        &self,
-        parsed: &ParsedFile,
-        func_node: tree_sitter::Node,
-        func_text: &str,
-        is_empty: bool,
-        is_panic_only: bool,
-    ) -> bool {
-        // Extract function name
-        let func_name = func_node
-            .children(&mut func_node.walk())
-            .find(|n| n.kind() == "identifier")
-            .map(|n| parsed.node_text(n))
-            .unwrap_or("");
-
-        // Check file path for stub/mock files
-        let path_lower = parsed.path.to_lowercase();
-        let is_stub_file = path_lower.ends_with("stub.rs")
-            || path_lower.ends_with("stubs.rs")
-            || path_lower.ends_with("mock.rs")
-            || path_lower.ends_with("mocks.rs")
-            || path_lower.ends_with("noop.rs")
-            || path_lower.contains("/stub/")
-            || path_lower.contains("/mock/");
-
-        // Platform stubs that panic are intentional
-        if is_panic_only && is_stub_file {
-            return true;
+    /// Empty functions with bounded generics are often compile-time trait
+    /// checks (`fn check_send<T: Send>() {}`), and empty callbacks that
+    /// ignore a parameter (`fn initialize(_: Internal) {}`) are no-ops by
+    /// design; the built-in `StubExemption` rules exempt both.
+    fn signature_features(&self, parsed: &ParsedFile, func_node: tree_sitter::Node) -> Vec<SignatureFeature> {
+        let mut features = Vec::new();
+
+        let bounded = func_node.child_by_field_name("type_parameters").is_some_and(|params| {
+            params
+                .named_children(&mut params.walk())
+                .any(|p| p.kind() == "constrained_type_parameter" || p.child_by_field_name("bounds").is_some())
+        }) || func_node.children(&mut func_node.walk()).any(|n| n.kind() == "where_clause");
+        if bounded {
+            features.push(SignatureFeature::GenericBounds);
         }
 
-        // Empty functions in stub/mock files are intentional no-ops
-        if is_empty && is_stub_file {
-            return true;
-        }
-
-        // Check for compile-time trait bound verification patterns
-        // These are empty functions that verify trait bounds at compile time
-        if is_empty {
-            // Common trait verification function names
-            let trait_check_names = [
-                "check_send", "check_sync", "check_unpin", "check_static",
-                "check_send_sync", "check_send_sync_val", "check_static_val",
-                "is_send", "is_sync", "is_unpin", "is_debug",
-                "_assert", "_assert_send", "_assert_sync", "_assert_unpin",
-            ];
-            if trait_check_names.iter().any(|&name| func_name == name || func_name.starts_with(name)) {
-                return true;
-            }
-
-            // Functions with generic type parameters and trait bounds are likely compile-time checks
-            // e.g., fn check_send<T: Send>() {}
-            if func_text.contains("<") && func_text.contains(":") {
-                // Has generic with bounds - likely a trait check
-                if func_name.starts_with("check_") || func_name.starts_with("is_") || func_name.starts_with("_") {
-                    return true;
-                }
-            }
-
-            // No-op counter/metric functions (often conditionally compiled)
-            let noop_prefixes = ["inc_", "dec_", "add_", "record_", "log_"];
-            if noop_prefixes.iter().any(|p| func_name.starts_with(p)) {
-                // Check if this looks like a metrics/counter file
-                if path_lower.contains("counter")
-                    || path_lower.contains("metric")
-                    || path_lower.contains("stats")
-                {
-                    return true;
-                }
-            }
-
-            // Default trait implementations (initialize, finalize, etc.)
-            let default_impl_names = [
-                "initialize", "finalize", "default", "new",
-                "consume", "flush", "clear", "reset",
-                "wake", "drop", "close",
-            ];
-            // Only skip if the function is very simple (no params or just &self)
-            let simple_signature = !func_text.contains(",") || func_text.contains("&self)") || func_text.contains("&mut self)");
-            if default_impl_names.iter().any(|&name| func_name == name) && simple_signature {
-                return true;
-            }
-
-            // Functions starting with underscore are often intentionally unused/placeholder
-            if func_name.starts_with("_") {
-                return true;
-            }
-
-            // retain_ready and similar callback functions
-            if func_name.contains("retain") || func_name.contains("callback") || func_name.contains("handler") {
-                return true;
-            }
-
-            // post_* and pre_* hook functions that may be no-ops
-            if func_name.starts_with("post_") || func_name.starts_with("pre_") || func_name.starts_with("on_") {
-                return true;
-            }
-
-            // unhandled_* functions are often intentional no-ops
-            if func_name.starts_with("unhandled_") {
-                return true;
-            }
-
-            // Functions with explicitly unused parameters (prefixed with _) are intentional no-ops
-            // e.g., fn initialize(_: Internal, _lower: usize) {}
-            // These are callbacks where the implementation doesn't need the params
-            if func_text.contains("_:") || func_text.contains("_,") || func_text.contains(", _)") {
-                return true;
-            }
-
-            // Methods on types that are intentionally empty (Empty, Noop, etc.)
-            let empty_type_names = ["Empty", "Noop", "NoOp", "Void", "Unit", "Null", "Dummy"];
-            if empty_type_names.iter().any(|t| path_lower.contains(&t.to_lowercase())) {
-                return true;
-            }
+        let ignored = func_node.child_by_field_name("parameters").is_some_and(|params| {
+            params
+                .named_children(&mut params.walk())
+                .filter_map(|p| p.child_by_field_name("pattern"))
+                .any(|pattern| parsed.node_text(pattern) == "_")
+        });
+        if ignored {
+            features.push(SignatureFeature::IgnoredParams);
         }
 
-        false
+        features
     }
 
     /// Check if a function body only contains a panic/unimplemented/todo! macro.
@@ -461,8 +599,7 @@ impl RustAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "line_comment" | "block_comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") || text.contains("UNIMPLEMENTED") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -481,6 +618,9 @@ impl RustAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -541,6 +681,7 @@ impl RustAnalyzer {
                         path,
                         alias,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -603,6 +744,27 @@ impl LanguageAnalyzer for RustAnalyzer {
             package: None, // Rust uses mod system, not packages
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: Vec::new(),
+            logged_rethrows: Vec::new(),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: extract_constant_groups(parsed, &CONSTANT_SYNTAX),
+            function_signatures: Vec::new(),
+            call_sites: extract_call_sites(parsed, CALL_KINDS),
+            deprecations: extract_deprecations(parsed, CALLABLE_KINDS),
+            forwarding_calls: extract_forwarding_calls(parsed, CALLABLE_KINDS, CALL_KINDS),
+            unwrap_usage: extract_unwrap_usage(parsed),
+            force_unwraps: Vec::new(),
+            switches: extract_switches(parsed, &SWITCH_SYNTAX),
+            implementations: extract_implementations(parsed, &INHERITANCE_SYNTAX),
+            callable_types: extract_callable_types(parsed, CALLABLE_KINDS),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors,
             parse_error,
         })
@@ -775,6 +937,38 @@ fn matcher(x: Option<i32>) -> i32 {
         assert!(body.control_flow.cyclomatic_complexity() >= 4);
     }
 
+    /// A generated opcode table with one arm per opcode.
+    fn opcode_table(arms: usize) -> String {
+        let mut source = String::from("fn decode(op: u16) -> &'static str {\n    match op {\n");
+        for i in 0..arms {
+            source.push_str(&format!("        {} => \"op{}\",\n", i, i));
+        }
+        source.push_str("        _ => \"unknown\",\n    }\n}\n");
+        source
+    }
+
+    #[test]
+    fn test_complexity_of_thousand_arm_match() {
+        let start = std::time::Instant::now();
+        let (analyzer, parsed) = parse_rust(&opcode_table(1000));
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+
+        let body = facts.find_declaration("decode").unwrap().body.as_ref().unwrap();
+        assert!(!body.control_flow.not_computed);
+        assert!(body.control_flow.cyclomatic_complexity() > 1000);
+    }
+
+    #[test]
+    fn test_complexity_not_computed_over_body_node_limit() {
+        let (analyzer, parsed) = parse_rust(&opcode_table(1000));
+        let facts = crate::analysis::with_body_node_limit(500, || analyzer.extract_facts(&parsed)).unwrap();
+
+        let body = facts.find_declaration("decode").unwrap().body.as_ref().unwrap();
+        assert!(body.control_flow.not_computed);
+        assert_eq!(body.control_flow.cyclomatic_complexity(), 1);
+    }
+
     #[test]
     fn test_stub_detection_empty() {
         let source = r#"
@@ -789,6 +983,24 @@ fn empty() {
         assert!(body.is_empty);
     }
 
+    #[test]
+    fn test_signature_features() {
+        let source = r#"
+fn check_send<T: Send>() {}
+fn check_where<T>() where T: Sync {}
+fn initialize(_: Internal, _lower: usize) {}
+fn plain<T>(value: T) {}
+"#;
+        let (analyzer, parsed) = parse_rust(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let signature = |name: &str| facts.find_declaration(name).unwrap().body.as_ref().unwrap().signature.clone();
+        assert_eq!(signature("check_send"), vec![SignatureFeature::GenericBounds]);
+        assert_eq!(signature("check_where"), vec![SignatureFeature::GenericBounds]);
+        assert_eq!(signature("initialize"), vec![SignatureFeature::IgnoredParams]);
+        assert!(signature("plain").is_empty());
+    }
+
     #[test]
     fn test_stub_detection_unimplemented() {
         let source = r#"
@@ -833,4 +1045,48 @@ fn with_todo() {
         let body = func.body.as_ref().unwrap();
         assert!(body.has_only_todo_comment);
     }
+
+    #[test]
+    fn test_extract_unwrap_usage() {
+        let source = r#"
+impl Config {
+    fn load(path: &str) -> Self {
+        let text = std::fs::read_to_string(path).expect("config readable");
+        let parse = |t: &str| toml::from_str(t).unwrap();
+        fn fallback() -> Self {
+            panic!("no config")
+        }
+        parse(&text)
+    }
+}
+
+fn checked(x: Option<u8>) -> u8 {
+    x.unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn loads() {
+        Config::load("a.toml").validate().unwrap();
+    }
+}
+"#;
+        let (analyzer, parsed) = parse_rust(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let summary: Vec<_> = facts
+            .unwrap_usage
+            .iter()
+            .map(|u| (u.function.as_str(), u.unwraps, u.expects, u.panics, u.in_test))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Config::load", 1, 1, 0, false),
+                ("fallback", 0, 0, 1, false),
+                ("loads", 1, 0, 0, true),
+            ]
+        );
+    }
 }
@@ -9,6 +9,14 @@ use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
 
 const DECLARATION_QUERY: &str = r#"
 ; Function definitions
@@ -56,6 +64,46 @@ const IMPORT_QUERY: &str = r#"
 (package_clause) @package
 "#;
 
+/// String literal syntax for Scala.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string", "interpolated_string"],
+    skip_kinds: &["import_declaration", "package_clause"],
+    call_kinds: &["call_expression"],
+    is_constant: is_constant_node,
+};
+
+/// One-statement body syntax for Scala, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &[],
+    statement_lists: &[],
+    return_kinds: &["return_expression"],
+    throw_kinds: &["throw_expression"],
+    throw_keyword: None,
+    value_lists: &[],
+    call_kinds: &["call_expression"],
+    construct_kinds: &["instance_expression"],
+    name_kinds: &["identifier", "field_expression"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for Scala.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &["function_definition"],
+    keywords: &["def"],
+    parameter_kinds: &["parameters"],
+    body_openers: &["{", "="],
+};
+
+/// Returns true if the node declares a constant.
+fn is_constant_node(_parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    // Member `val`s of objects and classes play the role of constants
+    node.kind() == "val_definition"
+        && node
+            .parent()
+            .map(|p| matches!(p.kind(), "template_body" | "compilation_unit"))
+            .unwrap_or(false)
+}
+
 pub struct ScalaAnalyzer {
     language: Language,
 }
@@ -68,9 +116,7 @@ impl ScalaAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -135,7 +181,17 @@ impl ScalaAnalyzer {
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: false,
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: Vec::new(),
+                        annotations: Vec::new(),
+                        is_exported: false,
+                        has_doc: false,
                     });
                 }
             }
@@ -163,10 +219,12 @@ impl ScalaAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let control_flow = self.extract_control_flow(parsed, body_node)?;
@@ -174,12 +232,16 @@ impl ScalaAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only: self.is_throw_only(parsed, body_node),
             is_nil_return_only: false,
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: 0,
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -205,8 +267,7 @@ impl ScalaAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -230,6 +291,9 @@ impl ScalaAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -295,6 +359,7 @@ impl ScalaAnalyzer {
                                 path,
                                 alias: None,
                                 span: Span::from_node(capture.node),
+                                optional: false,
                             });
                         }
                     }
@@ -350,6 +415,27 @@ impl LanguageAnalyzer for ScalaAnalyzer {
             package,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: Vec::new(),
+            logged_rethrows: Vec::new(),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: Vec::new(),
+            function_signatures: Vec::new(),
+            call_sites: Vec::new(),
+            deprecations: Vec::new(),
+            forwarding_calls: Vec::new(),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: Vec::new(),
+            implementations: Vec::new(),
+            callable_types: Vec::new(),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -0,0 +1,516 @@
+//! Protobuf and GraphQL schema analyzer.
+//!
+//! Schema files are a list of named definitions with fields, so they are
+//! read character by character instead of through tree-sitter: comments
+//! are set aside, braces open and close definitions, and each field keeps
+//! the line it starts on. Comments that parse as a field of the enclosing
+//! definition are kept as commented-out fields. Like the Dockerfile
+//! analyzer, this does not implement `LanguageAnalyzer`.
+
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Schema languages the analyzer reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaFormat {
+    /// Protocol Buffers (`.proto`)
+    Proto,
+    /// GraphQL SDL (`.graphql`, `.gql`)
+    GraphQL,
+}
+
+impl SchemaFormat {
+    /// Detect the schema language from a file extension.
+    pub fn for_path(path: &Path) -> Option<Self> {
+        let ext = path.extension().and_then(|e| e.to_str())?.to_lowercase();
+        match ext.as_str() {
+            "proto" => Some(Self::Proto),
+            "graphql" | "graphqls" | "gql" => Some(Self::GraphQL),
+            _ => None,
+        }
+    }
+}
+
+/// Check if a file is a Protobuf or GraphQL schema.
+pub fn is_schema_file(path: &Path) -> bool {
+    SchemaFormat::for_path(path).is_some()
+}
+
+/// A comment, with its markers (`//`, `#`, `*`) removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaComment {
+    pub text: String,
+    /// Line the comment is on (1-indexed).
+    pub line: usize,
+}
+
+/// A field, enum value, or RPC method of a definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaField {
+    pub name: String,
+    /// Line the field starts on (1-indexed).
+    pub line: usize,
+}
+
+/// A message, enum, service, type, input, or interface definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaDefinition {
+    /// Definition keyword (e.g., "message", "type", "enum").
+    pub keyword: String,
+    pub name: String,
+    /// Line the definition starts on (1-indexed).
+    pub line: usize,
+    /// Fields in declaration order; fields of a proto `oneof` count for the message.
+    pub fields: Vec<SchemaField>,
+    /// Number of definitions nested directly inside this one.
+    pub nested: usize,
+    /// Comments inside the definition that parse as one of its fields.
+    pub commented_fields: Vec<SchemaComment>,
+}
+
+/// Definitions and comments of a schema file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaFile {
+    pub definitions: Vec<SchemaDefinition>,
+    pub comments: Vec<SchemaComment>,
+}
+
+/// `message User`, `enum Status`, `service Users`, `oneof choice`.
+static PROTO_DEFINITION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(message|enum|service|oneof|extend)\s+([\w.]+)$").unwrap());
+
+/// `type Query`, `extend type User`, `input NewUser`, `interface Node implements Entity`.
+static GRAPHQL_DEFINITION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(?:extend\s+)?(type|input|interface|enum)\s+(\w+)").unwrap());
+
+/// `repeated string tags = 3`, `map<string, int32> counts = 4`.
+static PROTO_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(?:repeated|optional|required)\s+)?(?:map\s*<[^>]*>|[\w.]+)\s+(\w+)\s*=\s*\d+").unwrap()
+});
+
+/// `STATUS_ACTIVE = 1`.
+static PROTO_ENUM_VALUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\w+)\s*=\s*-?\d+").unwrap());
+
+/// `rpc GetUser(GetUserRequest) returns (User)`.
+static PROTO_RPC: Lazy<Regex> = Lazy::new(|| Regex::new(r"^rpc\s+(\w+)").unwrap());
+
+/// `user(id: ID!): User`, `name: String`.
+static GRAPHQL_FIELD: Lazy<Regex> = Lazy::new(|| Regex::new(r"^(\w+)\s*(?:\(.*\))?\s*:").unwrap());
+
+/// `ACTIVE`.
+static GRAPHQL_ENUM_VALUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^([A-Za-z_]\w*)$").unwrap());
+
+/// `@deprecated(reason: "use name")`, `@key`.
+static GRAPHQL_DIRECTIVE: Lazy<Regex> = Lazy::new(|| Regex::new(r"@\w+(?:\([^)]*\))?").unwrap());
+
+/// A whole comment that reads as a proto message field.
+static COMMENTED_PROTO_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?:(?:repeated|optional|required)\s+)?(?:map\s*<[^>]*>|[\w.]+)\s+\w+\s*=\s*\d+\s*(?:\[.*\])?\s*;?$")
+        .unwrap()
+});
+
+/// A whole comment that reads as a proto enum value.
+static COMMENTED_PROTO_ENUM_VALUE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\w+\s*=\s*-?\d+\s*(?:\[.*\])?\s*;?$").unwrap());
+
+/// A whole comment that reads as a GraphQL field with a named type.
+static COMMENTED_GRAPHQL_FIELD: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\w+\s*(?:\(.*\))?\s*:\s*\[*[A-Z]\w*!?\]*!?(?:\s*=\s*.+)?(?:\s*@.*)?,?$").unwrap()
+});
+
+/// A whole comment that reads as a GraphQL enum value.
+static COMMENTED_GRAPHQL_ENUM_VALUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[A-Z][A-Z0-9_]*$").unwrap());
+
+/// Line-oriented Protobuf and GraphQL analyzer.
+pub struct SchemaAnalyzer {
+    format: SchemaFormat,
+}
+
+/// Open braces: the definition each one belongs to, if any.
+type Frames = Vec<Option<usize>>;
+
+impl SchemaAnalyzer {
+    pub fn new(format: SchemaFormat) -> Self {
+        Self { format }
+    }
+
+    pub fn language_id(&self) -> &'static str {
+        match self.format {
+            SchemaFormat::Proto => "proto",
+            SchemaFormat::GraphQL => "graphql",
+        }
+    }
+
+    /// Read the definitions and comments of a schema.
+    pub fn parse(&self, source: &str) -> SchemaFile {
+        let mut file = SchemaFile::default();
+        let mut frames: Frames = Vec::new();
+        let mut segment = String::new();
+        let mut segment_line = 1;
+        let mut depth = 0i32;
+        let mut in_comment = false;
+
+        for (idx, raw) in source.lines().enumerate() {
+            let line = idx + 1;
+            let (code, comments) = self.split_line(raw, &mut in_comment);
+
+            for c in code.chars() {
+                match c {
+                    '(' | '[' => depth += 1,
+                    ')' | ']' => depth -= 1,
+                    '{' if depth <= 0 => {
+                        let header = std::mem::take(&mut segment);
+                        self.open(&mut file, &mut frames, header.trim(), segment_line);
+                        continue;
+                    }
+                    '}' if depth <= 0 => {
+                        self.flush(&mut file, &frames, &mut segment, segment_line);
+                        frames.pop();
+                        continue;
+                    }
+                    ';' if depth <= 0 && self.format == SchemaFormat::Proto => {
+                        self.flush(&mut file, &frames, &mut segment, segment_line);
+                        continue;
+                    }
+                    ',' if depth <= 0 && self.format == SchemaFormat::GraphQL => {
+                        self.flush(&mut file, &frames, &mut segment, segment_line);
+                        continue;
+                    }
+                    _ => {}
+                }
+                if segment.trim().is_empty() && !c.is_whitespace() {
+                    segment_line = line;
+                }
+                segment.push(c);
+            }
+            // A field ends with its line unless an argument list is still open
+            if depth <= 0 && self.format == SchemaFormat::GraphQL {
+                self.flush(&mut file, &frames, &mut segment, segment_line);
+            }
+            if !segment.ends_with(char::is_whitespace) && !segment.is_empty() {
+                segment.push(' ');
+            }
+
+            for text in comments {
+                let text = text.trim().trim_start_matches(['*', '/']).trim().to_string();
+                if text.is_empty() {
+                    continue;
+                }
+                let comment = SchemaComment { text, line };
+                if let Some(Some(def)) = frames.last() {
+                    let definition = &mut file.definitions[*def];
+                    if self.is_commented_field(&definition.keyword, &comment.text) {
+                        definition.commented_fields.push(comment.clone());
+                    }
+                }
+                file.comments.push(comment);
+            }
+        }
+
+        file
+    }
+
+    /// Split a line into its code and its comments. `in_comment` carries an
+    /// open block comment (proto) or description (GraphQL) across lines.
+    /// String contents are blanked so quoted braces and commas are ignored.
+    fn split_line(&self, line: &str, in_comment: &mut bool) -> (String, Vec<String>) {
+        let chars: Vec<char> = line.chars().collect();
+        let at = |i: usize, token: &str| token.chars().enumerate().all(|(k, c)| chars.get(i + k) == Some(&c));
+        let rest = |i: usize| chars[i.min(chars.len())..].iter().collect::<String>();
+        let close = match self.format {
+            SchemaFormat::Proto => "*/",
+            SchemaFormat::GraphQL => "\"\"\"",
+        };
+
+        let mut code = String::new();
+        let mut comments = Vec::new();
+        let mut comment = String::new();
+        let mut quote: Option<char> = None;
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            if *in_comment {
+                if at(i, close) {
+                    *in_comment = false;
+                    comments.push(std::mem::take(&mut comment));
+                    i += close.len();
+                } else {
+                    comment.push(c);
+                    i += 1;
+                }
+                continue;
+            }
+            if let Some(q) = quote {
+                if c == '\\' {
+                    i += 1;
+                } else if c == q {
+                    quote = None;
+                    code.push(c);
+                } else {
+                    code.push(' ');
+                }
+                i += 1;
+                continue;
+            }
+            match self.format {
+                SchemaFormat::Proto if at(i, "//") => {
+                    comments.push(rest(i + 2));
+                    break;
+                }
+                SchemaFormat::Proto if at(i, "/*") => {
+                    *in_comment = true;
+                    i += 2;
+                    continue;
+                }
+                SchemaFormat::GraphQL if c == '#' => {
+                    comments.push(rest(i + 1));
+                    break;
+                }
+                SchemaFormat::GraphQL if at(i, "\"\"\"") => {
+                    *in_comment = true;
+                    i += 3;
+                    continue;
+                }
+                // A string before anything else on the line is a description
+                SchemaFormat::GraphQL if c == '"' && code.trim().is_empty() => {
+                    let text = rest(i + 1);
+                    let end = text.find('"').unwrap_or(text.len());
+                    comments.push(text[..end].to_string());
+                    i += 1 + text[..end].chars().count() + 1;
+                    continue;
+                }
+                _ => {}
+            }
+            if c == '"' || (c == '\'' && self.format == SchemaFormat::Proto) {
+                quote = Some(c);
+            }
+            code.push(c);
+            i += 1;
+        }
+        if *in_comment && !comment.is_empty() {
+            comments.push(comment);
+        }
+
+        (code, comments)
+    }
+
+    /// Open a brace: a new definition, or an unnamed block such as an RPC's
+    /// options or a GraphQL `schema`.
+    fn open(&self, file: &mut SchemaFile, frames: &mut Frames, header: &str, line: usize) {
+        let parent = frames.last().copied().flatten();
+        let pattern = match self.format {
+            SchemaFormat::Proto => &PROTO_DEFINITION,
+            SchemaFormat::GraphQL => &GRAPHQL_DEFINITION,
+        };
+        let Some(caps) = pattern.captures(header) else {
+            // `rpc Get(Req) returns (Resp) {` is still a method of the service
+            let mut header = header.to_string();
+            self.flush(file, frames, &mut header, line);
+            frames.push(None);
+            return;
+        };
+
+        let keyword = caps[1].to_string();
+        if keyword == "oneof" && parent.is_some() {
+            frames.push(parent);
+            return;
+        }
+        if let Some(parent) = parent {
+            file.definitions[parent].nested += 1;
+        }
+        file.definitions.push(SchemaDefinition {
+            keyword,
+            name: caps[2].to_string(),
+            line,
+            fields: Vec::new(),
+            nested: 0,
+            commented_fields: Vec::new(),
+        });
+        frames.push(Some(file.definitions.len() - 1));
+    }
+
+    /// End the current segment, recording it if it is a field of the open definition.
+    fn flush(&self, file: &mut SchemaFile, frames: &Frames, segment: &mut String, line: usize) {
+        let text = std::mem::take(segment);
+        let text = text.trim();
+        if text.is_empty() {
+            return;
+        }
+        let Some(Some(def)) = frames.last() else {
+            return;
+        };
+        let definition = &mut file.definitions[*def];
+        for name in self.field_names(&definition.keyword, text) {
+            definition.fields.push(SchemaField { name, line });
+        }
+    }
+
+    /// Names of the fields a segment declares in a definition of the given
+    /// kind. GraphQL enum values need no separator, so a segment can hold several.
+    fn field_names(&self, keyword: &str, text: &str) -> Vec<String> {
+        let pattern = match (self.format, keyword) {
+            (SchemaFormat::Proto, "enum") => &PROTO_ENUM_VALUE,
+            (SchemaFormat::Proto, "service") => &PROTO_RPC,
+            (SchemaFormat::Proto, _) => &PROTO_FIELD,
+            (SchemaFormat::GraphQL, "enum") => {
+                return GRAPHQL_DIRECTIVE
+                    .replace_all(text, "")
+                    .split_whitespace()
+                    .filter(|word| GRAPHQL_ENUM_VALUE.is_match(word))
+                    .map(String::from)
+                    .collect();
+            }
+            (SchemaFormat::GraphQL, _) => &GRAPHQL_FIELD,
+        };
+        pattern.captures(text).map(|caps| caps[1].to_string()).into_iter().collect()
+    }
+
+    /// Whether a comment reads as a field of a definition of the given kind.
+    fn is_commented_field(&self, keyword: &str, text: &str) -> bool {
+        let pattern = match (self.format, keyword) {
+            (SchemaFormat::Proto, "enum") => &COMMENTED_PROTO_ENUM_VALUE,
+            (SchemaFormat::Proto, "service") => &PROTO_RPC,
+            (SchemaFormat::Proto, _) => &COMMENTED_PROTO_FIELD,
+            (SchemaFormat::GraphQL, "enum") => &COMMENTED_GRAPHQL_ENUM_VALUE,
+            (SchemaFormat::GraphQL, _) => &COMMENTED_GRAPHQL_FIELD,
+        };
+        pattern.is_match(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(file: &SchemaFile) -> Vec<(String, String, Vec<String>)> {
+        file.definitions
+            .iter()
+            .map(|d| {
+                let fields = d.fields.iter().map(|f| f.name.clone()).collect();
+                (d.keyword.clone(), d.name.clone(), fields)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_proto() {
+        let source = r#"syntax = "proto3";
+option go_package = "example.com/users;users";
+
+// A user account.
+message User {
+  string id = 1;
+  repeated string tags = 2 [deprecated = true];
+  map<string, int32> counts = 3;
+  oneof contact {
+    string email = 4;
+    string phone = 5;
+  }
+  // string nickname = 6;
+  message Address { string city = 1; }
+  /* TODO: add timestamps */
+}
+
+message Empty {}
+
+enum Status {
+  STATUS_UNKNOWN = 0;
+  // STATUS_ACTIVE = 1;
+}
+
+service Users {
+  rpc GetUser(GetUserRequest)
+      returns (User);
+  rpc ListUsers(ListUsersRequest) returns (stream User) {
+    option (google.api.http) = { get: "/v1/users" };
+  }
+}
+"#;
+        let file = SchemaAnalyzer::new(SchemaFormat::Proto).parse(source);
+        let s = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            summary(&file),
+            vec![
+                ("message".into(), "User".into(), s(&["id", "tags", "counts", "email", "phone"])),
+                ("message".into(), "Address".into(), s(&["city"])),
+                ("message".into(), "Empty".into(), vec![]),
+                ("enum".into(), "Status".into(), s(&["STATUS_UNKNOWN"])),
+                ("service".into(), "Users".into(), s(&["GetUser", "ListUsers"])),
+            ]
+        );
+
+        let user = &file.definitions[0];
+        assert_eq!((user.line, user.nested), (5, 1));
+        assert_eq!(user.fields[4].line, 11);
+        let commented: Vec<_> = user.commented_fields.iter().map(|c| (c.text.as_str(), c.line)).collect();
+        assert_eq!(commented, vec![("string nickname = 6;", 13)]);
+        assert_eq!(file.definitions[3].commented_fields[0].text, "STATUS_ACTIVE = 1;");
+        assert_eq!(file.definitions[4].fields[0].line, 26);
+
+        let comments: Vec<_> = file.comments.iter().map(|c| c.text.as_str()).collect();
+        assert!(comments.contains(&"TODO: add timestamps"));
+        assert!(comments.contains(&"A user account."));
+    }
+
+    #[test]
+    fn test_parse_graphql() {
+        let source = r#"schema { query: Query }
+
+"""
+The root query.
+"""
+type Query {
+  _empty: Boolean
+}
+
+extend type Query {
+  "Look up a user"
+  user(
+    id: ID!
+  ): User @deprecated(reason: "use node, not user")
+  # users(first: Int): [User!]!
+}
+
+type User implements Node @key(fields: "id") {
+  id: ID!, name: String
+  # TODO: add email
+}
+
+enum Role { ADMIN USER }
+
+input NewUser {}
+"#;
+        let file = SchemaAnalyzer::new(SchemaFormat::GraphQL).parse(source);
+        let s = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            summary(&file),
+            vec![
+                ("type".into(), "Query".into(), s(&["_empty"])),
+                ("type".into(), "Query".into(), s(&["user"])),
+                ("type".into(), "User".into(), s(&["id", "name"])),
+                ("enum".into(), "Role".into(), s(&["ADMIN", "USER"])),
+                ("input".into(), "NewUser".into(), vec![]),
+            ]
+        );
+        assert_eq!(file.definitions[1].fields[0].line, 12);
+        let commented: Vec<_> = file.definitions[1].commented_fields.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(commented, vec!["users(first: Int): [User!]!"]);
+        assert!(file.definitions[2].commented_fields.is_empty());
+
+        let comments: Vec<_> = file.comments.iter().map(|c| c.text.as_str()).collect();
+        assert_eq!(
+            comments,
+            vec!["The root query.", "Look up a user", "users(first: Int): [User!]!", "TODO: add email"]
+        );
+    }
+
+    #[test]
+    fn test_file_names() {
+        assert_eq!(SchemaFormat::for_path(Path::new("api/users.proto")), Some(SchemaFormat::Proto));
+        assert_eq!(SchemaFormat::for_path(Path::new("schema.graphql")), Some(SchemaFormat::GraphQL));
+        assert_eq!(SchemaFormat::for_path(Path::new("schema.GQL")), Some(SchemaFormat::GraphQL));
+        assert!(!is_schema_file(Path::new("proto.go")));
+    }
+}
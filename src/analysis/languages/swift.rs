@@ -6,9 +6,18 @@ use streaming_iterator::StreamingIterator;
 use tree_sitter::{Language, Parser, Query, QueryCursor};
 
 use crate::analysis::{
-    ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
+    ControlFlowInfo, Declaration, DeclarationKind, FileFacts, ForceUnwrapUsage, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::constants::{extract_constant_groups, ConstantSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
 
 const DECLARATION_QUERY: &str = r#"
 ; Function declarations
@@ -45,6 +54,154 @@ const IMPORT_QUERY: &str = r#"
 ) @import
 "#;
 
+/// String literal syntax for Swift.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["line_string_literal", "multi_line_string_literal"],
+    skip_kinds: &["import_declaration"],
+    call_kinds: &["call_expression"],
+    is_constant: is_constant_node,
+};
+
+/// Constant group syntax for Swift.
+const CONSTANT_SYNTAX: ConstantSyntax = ConstantSyntax {
+    declaration_kinds: &["class_declaration"],
+    body_kinds: &["enum_class_body"],
+    member_kinds: &["enum_entry"],
+    name_kinds: &["simple_identifier"],
+    adjacent_kinds: &[],
+    repeats_previous: false,
+};
+
+/// One-statement body syntax for Swift, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &[],
+    statement_lists: &["statements"],
+    // `return x` and `throw x` are both control transfer statements
+    return_kinds: &["control_transfer_statement"],
+    throw_kinds: &["control_transfer_statement"],
+    throw_keyword: Some("throw_keyword"),
+    value_lists: &[],
+    call_kinds: &["call_expression"],
+    construct_kinds: &[],
+    name_kinds: &["simple_identifier", "navigation_expression"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for Swift.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: FUNCTION_KINDS,
+    keywords: &["func", "init"],
+    parameter_kinds: &[],
+    body_openers: &["{"],
+};
+
+/// Function node kinds whose force-unwraps are counted on their own.
+const FUNCTION_KINDS: &[&str] = &["function_declaration", "init_declaration"];
+
+/// Whether a function is test code: marked `@Test`, or a method of a class
+/// that inherits from `XCTestCase`.
+fn is_test_code(parsed: &ParsedFile, function: tree_sitter::Node) -> bool {
+    let mut cursor = function.walk();
+    let marked = function
+        .children(&mut cursor)
+        .filter(|n| n.kind() == "modifiers")
+        .any(|modifiers| {
+            let mut cursor = modifiers.walk();
+            let found = modifiers
+                .named_children(&mut cursor)
+                .any(|n| n.kind() == "attribute" && parsed.node_text(n).starts_with("@Test"));
+            found
+        });
+    marked
+        || std::iter::successors(function.parent(), |n| n.parent())
+            .filter(|n| n.kind() == "class_declaration")
+            .any(|class| {
+                let mut cursor = class.walk();
+                let inherits = class
+                    .named_children(&mut cursor)
+                    .filter(|n| n.kind() == "inheritance_specifier")
+                    .any(|n| parsed.node_text(n) == "XCTestCase");
+                inherits
+            })
+}
+
+/// Name of a function, with its enclosing type or extension for methods.
+fn qualified_name(parsed: &ParsedFile, function: tree_sitter::Node) -> Option<String> {
+    let name = match function.kind() {
+        "init_declaration" => "init",
+        _ => parsed.node_text(function.child_by_field_name("name")?),
+    };
+    let owner = std::iter::successors(function.parent(), |n| n.parent())
+        .take_while(|n| !FUNCTION_KINDS.contains(&n.kind()))
+        .find(|n| n.kind() == "class_declaration")
+        .and_then(|n| n.child_by_field_name("name"))
+        .map(|n| parsed.node_text(n));
+    Some(match owner {
+        Some(owner) => format!("{}.{}", owner.split('<').next().unwrap_or(owner).trim(), name),
+        None => name.to_string(),
+    })
+}
+
+/// Count the postfix `!` force-unwraps and `try!` expressions of every
+/// function and initializer, in source order. Closures count towards their
+/// enclosing function; nested functions are counted on their own. Functions
+/// without any are omitted.
+fn extract_force_unwraps(parsed: &ParsedFile) -> Vec<ForceUnwrapUsage> {
+    let mut usage = Vec::new();
+    let mut functions = vec![parsed.tree.root_node()];
+
+    while let Some(node) = functions.pop() {
+        let mut stack = vec![node];
+        let (mut force_unwraps, mut force_tries) = (0, 0);
+        while let Some(n) = stack.pop() {
+            if FUNCTION_KINDS.contains(&n.kind()) && n != node {
+                functions.push(n);
+                continue;
+            }
+            match n.kind() {
+                "postfix_expression" if n.child_by_field_name("operation").is_some_and(|op| op.kind() == "bang") => {
+                    force_unwraps += 1;
+                }
+                "try_operator" if parsed.node_text(n) == "try!" => force_tries += 1,
+                _ => {}
+            }
+            let mut cursor = n.walk();
+            stack.extend(n.named_children(&mut cursor));
+        }
+
+        if !FUNCTION_KINDS.contains(&node.kind()) || force_unwraps + force_tries == 0 {
+            continue;
+        }
+        let Some(function) = qualified_name(parsed, node) else {
+            continue;
+        };
+        usage.push(ForceUnwrapUsage {
+            function,
+            force_unwraps,
+            force_tries,
+            in_test: is_test_code(parsed, node),
+            span: Span::from_node(node),
+        });
+    }
+
+    usage.sort_by_key(|u| u.span.start_byte);
+    usage
+}
+
+/// Returns true if the node declares a constant.
+fn is_constant_node(parsed: &ParsedFile, node: tree_sitter::Node) -> bool {
+    // Top-level or `static let` properties
+    if node.kind() != "property_declaration" {
+        return false;
+    }
+    let text = parsed.node_text(node);
+    let top_level = node
+        .parent()
+        .map(|p| p.kind() == "source_file")
+        .unwrap_or(false);
+    (top_level || text.contains("static ")) && text.contains("let ")
+}
+
 pub struct SwiftAnalyzer {
     language: Language,
 }
@@ -57,9 +214,7 @@ impl SwiftAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -112,7 +267,17 @@ impl SwiftAnalyzer {
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: false,
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: Vec::new(),
+                        annotations: Vec::new(),
+                        is_exported: false,
+                        has_doc: false,
                     });
                 }
             }
@@ -139,10 +304,12 @@ impl SwiftAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "comment" | "multiline_comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let control_flow = self.extract_control_flow(parsed, body_node)?;
@@ -150,12 +317,16 @@ impl SwiftAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only: self.is_fatal_error_only(parsed, body_node),
             is_nil_return_only: self.is_nil_return_only(parsed, body_node),
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: 0,
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -195,8 +366,7 @@ impl SwiftAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "comment" | "multiline_comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -212,6 +382,9 @@ impl SwiftAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -265,6 +438,7 @@ impl SwiftAnalyzer {
                         path,
                         alias: None,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -317,6 +491,27 @@ impl LanguageAnalyzer for SwiftAnalyzer {
             package: None,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: Vec::new(),
+            noop_statements: Vec::new(),
+            logged_rethrows: Vec::new(),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: extract_constant_groups(parsed, &CONSTANT_SYNTAX),
+            function_signatures: Vec::new(),
+            call_sites: Vec::new(),
+            deprecations: Vec::new(),
+            forwarding_calls: Vec::new(),
+            unwrap_usage: Vec::new(),
+            force_unwraps: extract_force_unwraps(parsed),
+            switches: Vec::new(),
+            implementations: Vec::new(),
+            callable_types: Vec::new(),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -386,4 +581,56 @@ func stubNil() -> String? {
         let stub_nil = facts.declarations.iter().find(|d| d.name == "stubNil").unwrap();
         assert!(stub_nil.body.as_ref().unwrap().is_nil_return_only);
     }
+
+    #[test]
+    fn test_extract_force_unwraps() {
+        let source = r#"
+import XCTest
+
+final class Loader {
+    init(path: String) {
+        self.url = URL(string: path)!
+    }
+
+    func load() -> Data {
+        let data = try! Data(contentsOf: url)
+        let retry = { self.cache!.fetch() }
+        let safe = try? Data(contentsOf: url)
+        if count != 0 { return safe ?? data }
+        return data
+    }
+}
+
+extension Loader {
+    func size() -> Int { cache?.size ?? 0 }
+}
+
+final class LoaderTests: XCTestCase {
+    func testLoad() {
+        XCTAssertNotNil(try! Loader(path: "a").load())
+    }
+}
+
+@Test func decodes() {
+    let value = try! decode(fixture!)
+}
+"#;
+        let (analyzer, parsed) = parse_swift(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let summary: Vec<_> = facts
+            .force_unwraps
+            .iter()
+            .map(|u| (u.function.as_str(), u.force_unwraps, u.force_tries, u.in_test))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Loader.init", 1, 0, false),
+                ("Loader.load", 1, 1, false),
+                ("LoaderTests.testLoad", 0, 1, true),
+                ("decodes", 1, 1, true),
+            ]
+        );
+    }
 }
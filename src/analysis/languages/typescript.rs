@@ -9,6 +9,27 @@ use crate::analysis::{
     ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import,
     LanguageAnalyzer, ParsedFile, Span,
 };
+use crate::analysis::annotations::extract_annotations;
+use crate::analysis::comments::{extract_header_comment, is_generated, line_counts};
+use crate::analysis::inheritance::{declared_members, extract_implementations, InheritanceSyntax};
+use crate::analysis::generics::extract_callable_types;
+use crate::analysis::calls::extract_call_sites;
+use crate::analysis::deprecations::extract_deprecations;
+use crate::analysis::wrappers::extract_forwarding_calls;
+use crate::analysis::params::count_parameters;
+use crate::analysis::traits::{exceeds_body_node_limit, new_parser};
+use crate::analysis::stubs::distinct_statement_count;
+use crate::analysis::sole_statements::{sole_statement, SoleStatementSyntax};
+use crate::analysis::constants::{extract_constant_groups, ConstantSyntax};
+use crate::analysis::literals::{extract_string_literals, LiteralSyntax};
+use crate::analysis::noops::{extract_noop_statements, NoopSyntax};
+use crate::analysis::awaits::{count_awaits, AwaitSyntax};
+use crate::analysis::ecmascript::{catch_handler, extract_discarded_calls, is_async_function, is_constant_node};
+use crate::analysis::rethrows::{extract_logged_rethrows, RethrowSyntax};
+use crate::analysis::switches::{extract_switches, SwitchSyntax};
+use crate::analysis::markers::has_marker;
+use crate::analysis::truncation::{find_truncated_function, TruncationSyntax};
+use crate::analysis::doc_comments::{has_doc_comment, is_exported, DocSyntax, ExportRule};
 
 const DECLARATION_QUERY: &str = r#"
 ; Function declarations
@@ -99,6 +120,191 @@ const IMPORT_QUERY: &str = r#"
 ) @reexport
 "#;
 
+/// Call node kinds for TypeScript, with the field holding the callee.
+const CALL_KINDS: &[(&str, &str)] = &[("call_expression", "function"), ("new_expression", "constructor")];
+
+/// Callable node kinds for TypeScript that can be marked deprecated.
+const CALLABLE_KINDS: &[&str] = &["function_declaration", "generator_function_declaration", "method_definition"];
+
+/// String literal syntax for TypeScript.
+const LITERAL_SYNTAX: LiteralSyntax = LiteralSyntax {
+    string_kinds: &["string", "template_string"],
+    // String literal types (`type Mode = "a" | "b"`) are types, not values
+    skip_kinds: &["import_statement", "literal_type"],
+    call_kinds: &["call_expression", "new_expression"],
+    is_constant: is_constant_node,
+};
+
+/// Constant group syntax for TypeScript.
+const CONSTANT_SYNTAX: ConstantSyntax = ConstantSyntax {
+    declaration_kinds: &["enum_declaration"],
+    body_kinds: &["enum_body"],
+    member_kinds: &["enum_assignment"],
+    name_kinds: &["property_identifier"],
+    adjacent_kinds: &[],
+    repeats_previous: false,
+};
+
+/// One-statement body syntax for TypeScript, for contract stub signatures.
+const SOLE_STATEMENT_SYNTAX: SoleStatementSyntax = SoleStatementSyntax {
+    expression_statements: &["expression_statement"],
+    statement_lists: &[],
+    return_kinds: &["return_statement"],
+    throw_kinds: &["throw_statement"],
+    throw_keyword: None,
+    value_lists: &[],
+    call_kinds: &["call_expression"],
+    construct_kinds: &["new_expression"],
+    name_kinds: &["identifier", "member_expression"],
+    zero_value_kinds: &[],
+};
+
+/// Truncated function syntax for TypeScript.
+const TRUNCATION_SYNTAX: TruncationSyntax = TruncationSyntax {
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "function",
+        "generator_function_declaration",
+        "generator_function",
+        "arrow_function",
+        "method_definition",
+    ],
+    keywords: &["function"],
+    parameter_kinds: &["formal_parameters"],
+    body_openers: &["{"],
+};
+
+/// Doc comment syntax for TypeScript: JSDoc blocks.
+const DOC_SYNTAX: DocSyntax = DocSyntax {
+    comment_kinds: &["comment"],
+    doc_prefixes: &["/**"],
+    wrapper_kinds: &["export_statement", "lexical_declaration", "variable_declaration"],
+    attached_kinds: &["decorator"],
+    docstrings: false,
+    export: ExportRule::ExportStatement,
+};
+
+/// Interface syntax for TypeScript.
+const INHERITANCE_SYNTAX: InheritanceSyntax = InheritanceSyntax {
+    body_kinds: &["interface_body", "object_type"],
+    member_kinds: &["method_signature"],
+    type_kinds: &[
+        "class_declaration",
+        "abstract_class_declaration",
+        "class",
+        "interface_declaration",
+    ],
+    name_field: "name",
+    supertype_fields: &[],
+    clause_kinds: &[
+        "class_heritage",
+        "extends_clause",
+        "implements_clause",
+        "extends_type_clause",
+    ],
+    skip_kinds: &["type_arguments"],
+};
+
+/// Error handler syntax for TypeScript.
+const RETHROW_SYNTAX: RethrowSyntax = RethrowSyntax {
+    handler: catch_handler,
+    rethrow_kind: "throw_statement",
+    bare_rethrow: false,
+    call_kinds: &["call_expression"],
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "function",
+        "generator_function_declaration",
+        "generator_function",
+        "arrow_function",
+        "method_definition",
+    ],
+};
+
+/// Await syntax for TypeScript.
+const AWAIT_SYNTAX: AwaitSyntax = AwaitSyntax {
+    await_kinds: &["await_expression"],
+    awaiting_statements: &[("for_in_statement", "await")],
+    returned_future_kinds: &[],
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "arrow_function",
+        "method_definition",
+        "generator_function_declaration",
+        "generator_function",
+    ],
+};
+
+/// No-op statement syntax for TypeScript.
+const NOOP_SYNTAX: NoopSyntax = NoopSyntax {
+    function_kinds: &[
+        "function_declaration",
+        "function_expression",
+        "function",
+        "generator_function_declaration",
+        "generator_function",
+        "arrow_function",
+        "method_definition",
+    ],
+    has_results: |_| false,
+    blank_value_kinds: &[],
+    literal_kinds: &[],
+    empty_statement: Some("empty_statement"),
+    pass_statement: None,
+};
+
+/// Switch statement syntax for TypeScript.
+const SWITCH_SYNTAX: SwitchSyntax = SwitchSyntax {
+    switch_kinds: &["switch_statement"],
+    case_kinds: &["switch_case"],
+    label_field: "value",
+    default_kinds: &["switch_default"],
+};
+
+/// Variants of an enum, or the values of a union of string literal types
+/// (`type Mode = "read" | "write"`). Empty for any other type alias.
+fn enum_members(parsed: &ParsedFile, node: tree_sitter::Node) -> Vec<String> {
+    let unquote = |text: &str| text.trim_matches(['"', '\'', '`']).to_string();
+    let mut members = Vec::new();
+
+    if node.kind() == "enum_declaration" {
+        let Some(body) = node.child_by_field_name("body") else {
+            return members;
+        };
+        let mut cursor = body.walk();
+        for item in body.named_children(&mut cursor) {
+            let name = match item.kind() {
+                "enum_assignment" => item.child_by_field_name("name"),
+                "property_identifier" | "string" => Some(item),
+                _ => None,
+            };
+            if let Some(name) = name {
+                members.push(unquote(parsed.node_text(name)));
+            }
+        }
+        return members;
+    }
+
+    let mut stack: Vec<_> = node.child_by_field_name("value").into_iter().collect();
+    while let Some(current) = stack.pop() {
+        match current.kind() {
+            "union_type" => {
+                let mut cursor = current.walk();
+                stack.extend(current.named_children(&mut cursor));
+            }
+            "literal_type" if current.named_child(0).is_some_and(|c| c.kind() == "string") => {
+                members.push(unquote(parsed.node_text(current)));
+            }
+            _ => return Vec::new(),
+        }
+    }
+    members.reverse();
+    members
+}
+
 pub struct TypeScriptAnalyzer {
     language: Language,
 }
@@ -111,9 +317,7 @@ impl TypeScriptAnalyzer {
     }
 
     fn create_parser(&self) -> anyhow::Result<Parser> {
-        let mut parser = Parser::new();
-        parser.set_language(&self.language)?;
-        Ok(parser)
+        new_parser(&self.language)
     }
 
     fn extract_declarations(&self, parsed: &ParsedFile) -> anyhow::Result<Vec<Declaration>> {
@@ -178,12 +382,32 @@ impl TypeScriptAnalyzer {
                         None
                     };
 
+                    let exported = is_exported(parsed, node, &name, None, &DOC_SYNTAX);
+                    let documented = has_doc_comment(parsed, node, &DOC_SYNTAX);
                     declarations.push(Declaration {
                         name,
                         kind,
                         span: Span::from_node(node),
                         receiver: None,
+                        is_async: kind.is_callable() && is_async_function(node),
+                        param_count: if kind.is_callable() {
+                            count_parameters(parsed, node)
+                        } else {
+                            None
+                        },
                         body,
+                        members: match kind {
+                            DeclarationKind::Interface => {
+                                declared_members(parsed, node, &INHERITANCE_SYNTAX)
+                            }
+                            DeclarationKind::Enum | DeclarationKind::Type => {
+                                enum_members(parsed, node)
+                            }
+                            _ => Vec::new(),
+                        },
+                        annotations: extract_annotations(parsed, node),
+                        is_exported: exported,
+                        has_doc: documented,
                     });
                 }
             }
@@ -210,10 +434,12 @@ impl TypeScriptAnalyzer {
         let body_text = parsed.node_text(body_node).to_string();
         let span = Span::from_node(body_node);
 
-        let statement_count = body_node
+        let statements: Vec<_> = body_node
             .children(&mut body_node.walk())
             .filter(|n| !matches!(n.kind(), "{" | "}" | "comment"))
-            .count();
+            .collect();
+        let statement_count = statements.len();
+        let distinct_statement_count = distinct_statement_count(parsed, &statements);
 
         let is_empty = statement_count == 0;
         let control_flow = self.extract_control_flow(parsed, body_node)?;
@@ -221,12 +447,16 @@ impl TypeScriptAnalyzer {
         Ok(Some(FunctionBody {
             span,
             statement_count,
+            distinct_statement_count,
             is_empty,
             is_panic_only: self.is_throw_only(parsed, body_node),
             is_nil_return_only: self.is_null_return_only(parsed, body_node),
             has_only_todo_comment: self.has_only_todo_comment(parsed, body_node),
+            sole_statement: sole_statement(parsed, &statements, &SOLE_STATEMENT_SYNTAX),
+            await_count: count_awaits(body_node, &AWAIT_SYNTAX),
             text: body_text,
             control_flow,
+            signature: Vec::new(),
         }))
     }
 
@@ -272,8 +502,7 @@ impl TypeScriptAnalyzer {
             match child.kind() {
                 "{" | "}" => continue,
                 "comment" => {
-                    let text = parsed.node_text(child).to_uppercase();
-                    if text.contains("TODO") || text.contains("FIXME") {
+                    if has_marker(parsed.node_text(child)) {
                         has_todo = true;
                     }
                 }
@@ -289,6 +518,9 @@ impl TypeScriptAnalyzer {
         parsed: &ParsedFile,
         body_node: tree_sitter::Node,
     ) -> anyhow::Result<ControlFlowInfo> {
+        if exceeds_body_node_limit(body_node) {
+            return Ok(ControlFlowInfo::not_computed());
+        }
         let query = Query::new(&self.language, CONTROL_FLOW_QUERY)?;
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(&query, body_node, &parsed.source[..]);
@@ -347,6 +579,7 @@ impl TypeScriptAnalyzer {
                         path,
                         alias: None,
                         span: Span::from_node(node),
+                        optional: false,
                     });
                 }
             }
@@ -399,6 +632,27 @@ impl LanguageAnalyzer for TypeScriptAnalyzer {
             package: None,
             declarations,
             imports,
+            string_literals: extract_string_literals(parsed, &LITERAL_SYNTAX),
+            discarded_calls: extract_discarded_calls(parsed),
+            noop_statements: extract_noop_statements(parsed, &NOOP_SYNTAX),
+            logged_rethrows: extract_logged_rethrows(parsed, &RETHROW_SYNTAX),
+            unused_parameters: Vec::new(),
+            unassigned_results: Vec::new(),
+            parameter_defaults: Vec::new(),
+            constant_groups: extract_constant_groups(parsed, &CONSTANT_SYNTAX),
+            function_signatures: Vec::new(),
+            call_sites: extract_call_sites(parsed, CALL_KINDS),
+            deprecations: extract_deprecations(parsed, CALLABLE_KINDS),
+            forwarding_calls: extract_forwarding_calls(parsed, CALLABLE_KINDS, CALL_KINDS),
+            unwrap_usage: Vec::new(),
+            force_unwraps: Vec::new(),
+            switches: extract_switches(parsed, &SWITCH_SYNTAX),
+            implementations: extract_implementations(parsed, &INHERITANCE_SYNTAX),
+            callable_types: extract_callable_types(parsed, CALLABLE_KINDS),
+            header_comment: extract_header_comment(parsed),
+            is_generated: is_generated(parsed),
+            lines: line_counts(parsed),
+            truncated_function: find_truncated_function(parsed, &TRUNCATION_SYNTAX),
             has_parse_errors: parsed.tree.root_node().has_error(),
             parse_error: None,
         })
@@ -466,6 +720,33 @@ enum Status {
         assert!(facts.declarations.iter().any(|d| d.name == "Status"));
     }
 
+    #[test]
+    fn test_extract_decorators() {
+        let source = r#"
+@Controller('users')
+export class UsersController {
+    @Get(':id')
+    @UseGuards(AuthGuard)
+    findOne(id: string) {
+        return this.users.find(id);
+    }
+
+    helper() {}
+}
+
+@Injectable()
+class UsersService {}
+"#;
+        let (analyzer, parsed) = parse_ts(source);
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+
+        let annotations = |name: &str| facts.find_declaration(name).unwrap().annotations.clone();
+        assert_eq!(annotations("UsersController"), vec!["Controller"]);
+        assert_eq!(annotations("findOne"), vec!["Get", "UseGuards"]);
+        assert!(annotations("helper").is_empty());
+        assert_eq!(annotations("UsersService"), vec!["Injectable"]);
+    }
+
     #[test]
     fn test_stub_detection() {
         let source = r#"
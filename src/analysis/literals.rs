@@ -0,0 +1,141 @@
+//! Shared string literal extraction.
+//!
+//! Grammars disagree on node names but the walk is the same everywhere, so
+//! each analyzer describes its syntax with a `LiteralSyntax` and delegates
+//! the traversal to `extract_string_literals`.
+
+use tree_sitter::Node;
+
+use super::{ParsedFile, Span, StringLiteral};
+
+/// Grammar-specific node kinds used to find and classify string literals.
+pub(crate) struct LiteralSyntax {
+    /// Node kinds that are string literals (e.g., "interpreted_string_literal").
+    pub string_kinds: &'static [&'static str],
+    /// Ancestor kinds whose literals are ignored entirely (imports, includes, tags).
+    pub skip_kinds: &'static [&'static str],
+    /// Call expression kinds used to record the enclosing callee.
+    pub call_kinds: &'static [&'static str],
+    /// Returns true if the node declares a constant (const, static, static final).
+    pub is_constant: fn(&ParsedFile, Node) -> bool,
+}
+
+/// Returns true for names written in SCREAMING_SNAKE_CASE.
+pub(crate) fn is_constant_name(name: &str) -> bool {
+    name.chars().any(|c| c.is_ascii_uppercase())
+        && name
+            .chars()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_')
+}
+
+/// Collect string literals from a parsed file.
+pub(crate) fn extract_string_literals(
+    parsed: &ParsedFile,
+    syntax: &LiteralSyntax,
+) -> Vec<StringLiteral> {
+    let mut literals = Vec::new();
+    collect(parsed, parsed.tree.root_node(), syntax, &mut literals);
+    literals
+}
+
+fn collect(
+    parsed: &ParsedFile,
+    node: Node,
+    syntax: &LiteralSyntax,
+    out: &mut Vec<StringLiteral>,
+) {
+    if syntax.skip_kinds.contains(&node.kind()) {
+        return;
+    }
+
+    if syntax.string_kinds.contains(&node.kind()) {
+        if !is_standalone_expression(node) {
+            out.push(StringLiteral {
+                value: literal_content(parsed.node_text(node)).to_string(),
+                span: Span::from_node(node),
+                in_constant: has_constant_ancestor(parsed, node, syntax),
+                call_name: enclosing_call_name(parsed, node, syntax),
+            });
+        }
+        // Nested literals inside template/interpolated strings are not separate values
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect(parsed, child, syntax, out);
+    }
+}
+
+/// A literal that is an entire statement on its own: docstrings, "use strict".
+fn is_standalone_expression(node: Node) -> bool {
+    node.parent()
+        .map(|p| p.kind() == "expression_statement" && p.named_child_count() == 1)
+        .unwrap_or(false)
+}
+
+fn has_constant_ancestor(parsed: &ParsedFile, node: Node, syntax: &LiteralSyntax) -> bool {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if (syntax.is_constant)(parsed, n) {
+            return true;
+        }
+        current = n.parent();
+    }
+    false
+}
+
+fn enclosing_call_name(parsed: &ParsedFile, node: Node, syntax: &LiteralSyntax) -> Option<String> {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if syntax.call_kinds.contains(&n.kind()) {
+            let text = parsed.node_text(n);
+            let end = text.find(['(', '{', '[']).unwrap_or(text.len());
+            let callee = text[..end].trim();
+            return if callee.is_empty() {
+                None
+            } else {
+                Some(callee.to_string())
+            };
+        }
+        current = n.parent();
+    }
+    None
+}
+
+/// Strip string prefixes (r, b, f, R, @, #) and surrounding quotes.
+fn literal_content(raw: &str) -> &str {
+    let unprefixed = raw.trim_start_matches(|c: char| c.is_ascii_alphabetic() || c == '@' || c == '#');
+    let unsuffixed = unprefixed.trim_end_matches('#');
+    for quote in ["\"\"\"", "'''", "\"", "'", "`"] {
+        if unsuffixed.len() >= 2 * quote.len()
+            && unsuffixed.starts_with(quote)
+            && unsuffixed.ends_with(quote)
+        {
+            return &unsuffixed[quote.len()..unsuffixed.len() - quote.len()];
+        }
+    }
+    unsuffixed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_literal_content() {
+        assert_eq!(literal_content("\"us-east-1\""), "us-east-1");
+        assert_eq!(literal_content("r#\"raw\"#"), "raw");
+        assert_eq!(literal_content("f\"a{b}\""), "a{b}");
+        assert_eq!(literal_content("'''doc'''"), "doc");
+        assert_eq!(literal_content("`tmpl`"), "tmpl");
+    }
+
+    #[test]
+    fn test_is_constant_name() {
+        assert!(is_constant_name("API_BASE"));
+        assert!(is_constant_name("V2"));
+        assert!(!is_constant_name("apiBase"));
+        assert!(!is_constant_name("_"));
+    }
+}
@@ -0,0 +1,284 @@
+//! Work markers in comments: TODO, FIXME, and the like.
+//!
+//! One vocabulary serves every check that reads markers: stub detection
+//! (bodies holding only a marker comment), `hollow_todos`, and forbidden
+//! patterns that name a marker word. A marker word matches when it is
+//! written in upper case as a whole word (`TODO`, `see FIXME below`), or in
+//! any case when it opens a comment and is followed by `:` or `(`
+//! (`# todo: retry`, `// Fixme(ana): ...`). Lower-case words in prose and
+//! identifiers such as `TODO_PATTERN` or `Status.UNIMPLEMENTED` are not
+//! markers.
+//!
+//! A marker's text is what follows the word, its `(owner)` attribution, and
+//! a `:` or `-` separator. With a ticket pattern, the first match in the
+//! attribution or the text is the marker's ticket: `TODO(PROJ-12): retry`
+//! is tracked in `PROJ-12`.
+
+use std::cell::RefCell;
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+/// Built-in marker words.
+pub const DEFAULT_MARKERS: &[&str] = &["TODO", "FIXME", "XXX", "HACK", "UNIMPLEMENTED"];
+
+/// Tokens that open a comment, or a docstring standing in for one.
+const COMMENT_OPENERS: &[&str] = &["//", "/*", "*", "#", "--", ";", "<!--", "\"\"\"", "'''"];
+
+lazy_static! {
+    static ref DEFAULT_VOCABULARY: MarkerVocabulary = MarkerVocabulary::default();
+}
+
+thread_local! {
+    /// Vocabulary used by analyzers on this thread; `None` is the built-in one.
+    static MARKERS: RefCell<Option<Arc<MarkerVocabulary>>> = const { RefCell::new(None) };
+}
+
+/// A marker found in a comment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Marker {
+    /// Marker word, in upper case (`TODO`, `FIXME`, or a contract addition)
+    pub kind: String,
+    /// Text after the marker, its attribution, and a `:` or `-` separator
+    pub text: String,
+    /// Issue-tracker reference matching the contract's ticket pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ticket: Option<String>,
+    /// Byte offset of the marker word in the searched text
+    #[serde(skip)]
+    pub column: usize,
+}
+
+impl Marker {
+    /// Whether the marker references a ticket, making it tracked work.
+    pub fn is_tracked(&self) -> bool {
+        self.ticket.is_some()
+    }
+
+    /// The text with the ticket reference taken out, for judging whether
+    /// the marker says what is left to do.
+    pub fn description(&self) -> String {
+        let Some(ticket) = &self.ticket else {
+            return self.text.clone();
+        };
+        let text = self.text.replacen(ticket.as_str(), "", 1).replace("()", "").replace("[]", "");
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        text.trim_matches(|c: char| c.is_whitespace() || ",;:-.".contains(c)).to_string()
+    }
+}
+
+/// The marker words and ticket pattern in effect.
+#[derive(Debug, Clone)]
+pub struct MarkerVocabulary {
+    words: Vec<String>,
+    ticket: Option<Regex>,
+}
+
+impl Default for MarkerVocabulary {
+    fn default() -> Self {
+        Self {
+            words: DEFAULT_MARKERS.iter().map(|w| w.to_string()).collect(),
+            ticket: None,
+        }
+    }
+}
+
+impl MarkerVocabulary {
+    /// The built-in words plus `additional` ones, with an optional ticket regex.
+    pub fn new(additional: &[String], ticket_pattern: Option<&str>) -> anyhow::Result<Self> {
+        let mut vocabulary = Self::default();
+        for word in additional {
+            if word.is_empty() || !word.chars().all(|c| c.is_alphanumeric() || c == '_') {
+                anyhow::bail!("invalid marker {:?}, must be a single word", word);
+            }
+            let word = word.to_uppercase();
+            if !vocabulary.words.contains(&word) {
+                vocabulary.words.push(word);
+            }
+        }
+        vocabulary.ticket = ticket_pattern
+            .map(|p| Regex::new(p).map_err(|e| anyhow::anyhow!("invalid marker ticket_pattern {:?}: {}", p, e)))
+            .transpose()?;
+        Ok(vocabulary)
+    }
+
+    /// The marker words, in upper case.
+    pub fn words(&self) -> &[String] {
+        &self.words
+    }
+
+    /// Whether `word` is a marker word, in any case.
+    pub fn is_marker_word(&self, word: &str) -> bool {
+        self.words.iter().any(|w| w.eq_ignore_ascii_case(word))
+    }
+
+    /// Whether a search pattern (a forbidden pattern regex) names a marker word.
+    pub fn names_marker(&self, pattern: &str) -> bool {
+        let upper = pattern.to_uppercase();
+        self.words.iter().any(|w| upper.contains(w.as_str()))
+    }
+
+    /// Every marker in a comment or source line, in order.
+    pub fn find(&self, text: &str) -> Vec<Marker> {
+        words(text)
+            .filter_map(|(start, end)| self.marker_at(text, start, end))
+            .collect()
+    }
+
+    /// The marker starting at byte `start` of `text`, if one does.
+    pub fn marker_starting_at(&self, text: &str, start: usize) -> Option<Marker> {
+        words(text)
+            .find(|(s, _)| *s == start)
+            .and_then(|(s, e)| self.marker_at(text, s, e))
+    }
+
+    /// Whether a comment contains a marker.
+    pub fn contains_marker(&self, text: &str) -> bool {
+        words(text).any(|(start, end)| self.is_marker(text, start, end))
+    }
+
+    fn is_marker(&self, text: &str, start: usize, end: usize) -> bool {
+        let word = &text[start..end];
+        if !self.is_marker_word(word) {
+            return false;
+        }
+        let before = &text[..start];
+        // A member reference such as `Status.UNIMPLEMENTED` or `Code::TODO`
+        if before.ends_with('.') || before.ends_with(':') {
+            return false;
+        }
+        if word.chars().all(|c| !c.is_lowercase()) {
+            return true;
+        }
+        let opens_comment = COMMENT_OPENERS.iter().any(|o| before.trim_end().ends_with(o));
+        opens_comment && (text[end..].starts_with(':') || text[end..].starts_with('('))
+    }
+
+    fn marker_at(&self, text: &str, start: usize, end: usize) -> Option<Marker> {
+        if !self.is_marker(text, start, end) {
+            return None;
+        }
+
+        let mut rest = &text[end..];
+        let mut attribution = "";
+        if let Some(inner) = rest.strip_prefix('(') {
+            if let Some(close) = inner.find(')') {
+                attribution = &inner[..close];
+                rest = &inner[close + 1..];
+            }
+        }
+        let rest = rest.trim_start();
+        let rest = rest.strip_prefix(':').or_else(|| rest.strip_prefix('-')).unwrap_or(rest);
+        let body = rest
+            .trim()
+            .trim_end_matches("*/")
+            .trim_end_matches("-->")
+            .trim_end_matches("\"\"\"")
+            .trim_end_matches("'''")
+            .trim();
+
+        let ticket = self.ticket.as_ref().and_then(|re| {
+            re.find(attribution)
+                .or_else(|| re.find(body))
+                .map(|m| m.as_str().to_string())
+        });
+        Some(Marker {
+            kind: text[start..end].to_uppercase(),
+            text: body.to_string(),
+            ticket,
+            column: start,
+        })
+    }
+}
+
+/// Byte ranges of the words (runs of letters, digits, and `_`) in `text`.
+fn words(text: &str) -> impl Iterator<Item = (usize, usize)> + '_ {
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut chars = text.char_indices().peekable();
+    std::iter::from_fn(move || {
+        while chars.next_if(|(_, c)| !is_word(*c)).is_some() {}
+        let (start, _) = chars.next()?;
+        let mut end = text.len();
+        while let Some((i, c)) = chars.peek().copied() {
+            if !is_word(c) {
+                end = i;
+                break;
+            }
+            chars.next();
+        }
+        Some((start, end))
+    })
+}
+
+/// Run `f` with analyzers on this thread reading markers with `vocabulary`.
+pub fn with_markers<T>(vocabulary: &Arc<MarkerVocabulary>, f: impl FnOnce() -> T) -> T {
+    let previous = MARKERS.with(|m| m.replace(Some(vocabulary.clone())));
+    let value = f();
+    MARKERS.with(|m| *m.borrow_mut() = previous);
+    value
+}
+
+/// Whether a comment contains a marker of this thread's vocabulary.
+pub(crate) fn has_marker(comment: &str) -> bool {
+    MARKERS.with(|m| match m.borrow().as_ref() {
+        Some(vocabulary) => vocabulary.contains_marker(comment),
+        None => DEFAULT_VOCABULARY.contains_marker(comment),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(vocabulary: &MarkerVocabulary, text: &str) -> Vec<String> {
+        vocabulary.find(text).into_iter().map(|m| m.kind).collect()
+    }
+
+    #[test]
+    fn test_case_handling() {
+        let vocabulary = MarkerVocabulary::default();
+        assert_eq!(kinds(&vocabulary, "// TODO: retry on 503"), vec!["TODO"]);
+        assert_eq!(kinds(&vocabulary, "// ToDo: retry on 503"), vec!["TODO"]);
+        assert_eq!(kinds(&vocabulary, "# fixme(ana) flaky"), vec!["FIXME"]);
+        assert_eq!(kinds(&vocabulary, "/* see the HACK below; also XXX */"), vec!["HACK", "XXX"]);
+        // Prose, identifiers, and member references are not markers
+        assert!(kinds(&vocabulary, "// add a todo item to the list").is_empty());
+        assert!(kinds(&vocabulary, "let todo: Vec<Item> = vec![];").is_empty());
+        assert!(kinds(&vocabulary, "static ref TODO_PATTERN: Regex").is_empty());
+        assert!(kinds(&vocabulary, "return Status.UNIMPLEMENTED;").is_empty());
+        assert!(kinds(&vocabulary, "    unimplemented!()").is_empty());
+    }
+
+    #[test]
+    fn test_text_attribution_and_ticket() {
+        let vocabulary = MarkerVocabulary::new(&["later".to_string()], Some(r"[A-Z]+-\d+")).unwrap();
+
+        let marker = &vocabulary.find("// TODO(PROJ-12): implement this")[0];
+        assert_eq!(marker.text, "implement this");
+        assert_eq!(marker.ticket.as_deref(), Some("PROJ-12"));
+        assert_eq!(marker.description(), "implement this");
+
+        let marker = &vocabulary.find("/* FIXME - handle 429 (OPS-7) */")[0];
+        assert_eq!(marker.text, "handle 429 (OPS-7)");
+        assert_eq!(marker.description(), "handle 429");
+
+        let marker = &vocabulary.find("// LATER: cache the lookup")[0];
+        assert_eq!((marker.kind.as_str(), marker.is_tracked()), ("LATER", false));
+        assert_eq!(marker.column, 3);
+
+        assert!(MarkerVocabulary::new(&["TO DO".to_string()], None).is_err());
+        assert!(MarkerVocabulary::new(&[], Some("(")).is_err());
+    }
+
+    #[test]
+    fn test_thread_vocabulary() {
+        assert!(has_marker("// UNIMPLEMENTED"));
+        assert!(!has_marker("// PENDING: wire up"));
+
+        let vocabulary = Arc::new(MarkerVocabulary::new(&["pending".to_string()], None).unwrap());
+        assert!(with_markers(&vocabulary, || has_marker("// PENDING: wire up")));
+        assert!(!has_marker("// PENDING: wire up"));
+    }
+}
@@ -5,7 +5,28 @@
 //! - Declarations (functions, methods, types, constants)
 //! - Imports/dependencies
 //! - Control flow information for complexity calculation
-//! - Function body analysis for stub detection
+//! - Function body analysis for stub detection, including what a
+//!   one-statement body calls, raises, or returns (`sole_statements`)
+//! - String literals for duplication checks
+//! - Interface members and implements/extends relations
+//! - Type parameter use and return types of callables
+//! - Enum variants and switch case labels
+//! - Constant groups and the values written for them (`constants`)
+//! - Error handlers that log and rethrow
+//! - Await points in function bodies
+//! - TODO-style markers in comments, with a shared vocabulary (`markers`)
+//! - Decorators and annotations on declarations
+//! - Doc comments and export status of declarations
+//! - Leading comment blocks for header checks
+//! - Code and comment line counts
+//! - Functions cut off at the end of the file
+//!
+//! Files are routed to an analyzer by extension, or by a language override
+//! from the contract or an inline `hollowcheck:language` comment (see
+//! `overrides`).
+//!
+//! YAML, JSON, and TOML configuration files are flattened into keyed
+//! entries with line numbers by `parse_config`.
 //!
 //! # Architecture
 //!
@@ -23,6 +44,14 @@
 //!                         └──────────────┘     └───────────────┘
 //! ```
 //!
+//! # Library Use
+//!
+//! After `hollowcheck::init()`, `StubDetector::analyze_file` parses one file
+//! and returns its stub findings and the cyclomatic complexity of each
+//! function (a `Complexity`, which may be saturated or not computed),
+//! without a contract or `Runner`. `AnalysisContext::analyze_stubs`
+//! does the same against the context's fact cache.
+//!
 //! # Adding a New Language
 //!
 //! 1. Create a new module in `src/analysis/languages/` (e.g., `python.rs`)
@@ -32,20 +61,54 @@
 //!
 //! See `languages/go.rs` for a reference implementation.
 
+mod annotations;
+mod awaits;
+mod calls;
+mod comments;
+mod constants;
 mod context;
+mod deprecations;
+mod doc_comments;
+mod ecmascript;
 mod facts;
+mod generics;
+mod inheritance;
 mod languages;
+mod literals;
+mod markers;
+mod noops;
+mod overrides;
+mod params;
+mod rethrows;
+mod signatures;
+mod sole_statements;
 mod stubs;
+mod switches;
 mod traits;
+mod truncation;
+mod wrappers;
 
-pub use context::AnalysisContext;
+pub use context::{blank_lines, AnalysisContext};
 pub use facts::{
-    ControlFlowInfo, Declaration, DeclarationKind, FileFacts, FunctionBody, Import, Span,
+    CallSite, CallableTypes, Comment, Complexity, ConstantGroup, ConstantMember, ControlFlowInfo, Declaration, DeclarationKind, DefaultArm, Deprecation, DiscardedCall, FileFacts,
+    ForceUnwrapUsage, ForwardingCall, FunctionBody, FunctionSignature, Implementation, Import, LineCounts, LoggedRethrow, NoopKind, NoopStatement, ParameterDefault, SignatureFeature, SoleStatement, Span, StringLiteral,
+    SwitchStatement, TruncatedFunction, TypeParameter, UnassignedResult, UnusedParameter, UnwrapUsage,
 };
 pub use languages::{
-    get_analyzer, register_analyzers, CAnalyzer, CppAnalyzer, GoAnalyzer, JavaAnalyzer,
-    JavaScriptAnalyzer, PythonAnalyzer, RustAnalyzer, ScalaAnalyzer, SwiftAnalyzer,
+    get_analyzer, parse_config, ConfigEntry, ConfigFormat, ConfigValue, is_compose_file, is_dockerfile, language_for_extension, normalize_language,
+    register_analyzers, registered_languages, CAnalyzer, CppAnalyzer, DockerfileAnalyzer, GoAnalyzer, Instruction, JavaAnalyzer, JavaScriptAnalyzer, PythonAnalyzer, RustAnalyzer,
+    is_schema_file, SchemaAnalyzer, SchemaComment, SchemaDefinition, SchemaField, SchemaFile, SchemaFormat, ScalaAnalyzer, SwiftAnalyzer,
     TypeScriptAnalyzer,
 };
-pub use stubs::{HollowBodyKind, StubDetector, StubDetectorConfig, StubFinding};
-pub use traits::{LanguageAnalyzer, ParsedFile};
+pub use markers::{with_markers, Marker, MarkerVocabulary, DEFAULT_MARKERS};
+pub use overrides::{
+    analysis_extension, analyzer_language, inline_language, language_override, read_inline_language, set_language_override,
+    LANGUAGE_DIRECTIVE,
+};
+pub use stubs::{
+    builtin_stub_exemption_ids, default_stub_exemptions, HollowBodyKind, StubAnalysis, StubDetector, StubDetectorConfig,
+    StubExemption, StubFinding, StubSignature, DEFAULT_MIN_DISTINCT_RATIO, DEFAULT_REPETITIVE_MIN_STATEMENTS,
+};
+pub use traits::{
+    with_body_node_limit, with_parse_timeout, LanguageAnalyzer, ParsedFile, DEFAULT_MAX_BODY_NODES,
+};
@@ -0,0 +1,119 @@
+//! Shared extraction of statements with no effect.
+//!
+//! Padding statements (`_ = x`, a stray `pass`, `;`, a final `return;`) add
+//! to statement counts without doing anything. Each analyzer lists the node
+//! kinds its grammar uses in a `NoopSyntax`; the walk is shared.
+
+use tree_sitter::Node;
+
+use super::{NoopKind, NoopStatement, ParsedFile, Span};
+
+/// Grammar-specific node kinds for no-op statements.
+pub(crate) struct NoopSyntax {
+    /// Function node kinds; their `body` is checked for a trailing bare return.
+    pub function_kinds: &'static [&'static str],
+    /// Returns true if the function declares results, making a bare return meaningful.
+    pub has_results: fn(Node) -> bool,
+    /// Value kinds that do nothing when assigned to `_` (Go).
+    pub blank_value_kinds: &'static [&'static str],
+    /// Literal kinds that do nothing as an expression statement inside a function (Python).
+    pub literal_kinds: &'static [&'static str],
+    /// Empty statement kind (`;`), if the grammar has one.
+    pub empty_statement: Option<&'static str>,
+    /// Pass statement kind, if the grammar has one.
+    pub pass_statement: Option<&'static str>,
+}
+
+/// Loop kinds whose empty body or header clause is an intended `;`.
+const LOOP_KINDS: &[&str] = &["for_statement", "for_in_statement", "while_statement", "do_statement"];
+
+fn statements(block: Node) -> Vec<Node> {
+    let mut cursor = block.walk();
+    block
+        .named_children(&mut cursor)
+        .filter(|n| !n.kind().ends_with("comment"))
+        .collect()
+}
+
+/// Collect no-op statements from a parsed file, in source order.
+pub(crate) fn extract_noop_statements(parsed: &ParsedFile, syntax: &NoopSyntax) -> Vec<NoopStatement> {
+    let mut noops = Vec::new();
+    let mut stack = vec![(parsed.tree.root_node(), false)];
+
+    while let Some((node, in_function)) = stack.pop() {
+        if let Some(kind) = noop_kind(parsed, node, in_function, syntax) {
+            noops.push(NoopStatement {
+                kind,
+                text: parsed.node_text(node).trim().to_string(),
+                span: Span::from_node(node),
+            });
+        }
+
+        let is_function = syntax.function_kinds.contains(&node.kind());
+        if is_function && !(syntax.has_results)(node) {
+            if let Some(body) = node.child_by_field_name("body") {
+                let body_statements = statements(body);
+                if let [_, .., last] = body_statements.as_slice() {
+                    if last.kind() == "return_statement" && last.named_child_count() == 0 {
+                        noops.push(NoopStatement {
+                            kind: NoopKind::TrailingReturn,
+                            text: parsed.node_text(*last).trim().to_string(),
+                            span: Span::from_node(*last),
+                        });
+                    }
+                }
+            }
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(
+            node.children(&mut cursor)
+                .map(|child| (child, in_function || is_function)),
+        );
+    }
+
+    noops.sort_by_key(|n| n.span.start_byte);
+    noops
+}
+
+fn noop_kind(
+    parsed: &ParsedFile,
+    node: Node,
+    in_function: bool,
+    syntax: &NoopSyntax,
+) -> Option<NoopKind> {
+    let parent = node.parent()?;
+
+    if syntax.empty_statement == Some(node.kind()) {
+        return (!LOOP_KINDS.contains(&parent.kind())).then_some(NoopKind::EmptyStatement);
+    }
+
+    if syntax.pass_statement == Some(node.kind()) {
+        return (statements(parent).len() > 1).then_some(NoopKind::RedundantPass);
+    }
+
+    match node.kind() {
+        "expression_statement" if in_function && node.named_child_count() == 1 => {
+            let value = node.named_child(0)?;
+            if !syntax.literal_kinds.contains(&value.kind()) {
+                return None;
+            }
+            // A leading string is a docstring
+            let is_docstring = value.kind().contains("string")
+                && statements(parent).first().is_some_and(|first| first.id() == node.id());
+            (!is_docstring).then_some(NoopKind::LiteralExpression)
+        }
+        "assignment_statement" if !syntax.blank_value_kinds.is_empty() => {
+            let left = node.child_by_field_name("left")?;
+            let right = node.child_by_field_name("right")?;
+            let operator = node.child_by_field_name("operator")?;
+            let is_blank = parsed.node_text(left) == "_" && operator.kind() == "=";
+            let is_plain = right.named_child_count() == 1
+                && right
+                    .named_child(0)
+                    .is_some_and(|v| syntax.blank_value_kinds.contains(&v.kind()));
+            (is_blank && is_plain).then_some(NoopKind::BlankAssignment)
+        }
+        _ => None,
+    }
+}
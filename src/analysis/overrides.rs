@@ -0,0 +1,140 @@
+//! Per-file language overrides.
+//!
+//! Analyzers are looked up by file extension, so a Starlark file saved as
+//! `.star`, a Go template saved as `.go.tmpl`, or an extensionless script is
+//! never analyzed. A file can be routed to an analyzer explicitly, by a
+//! contract `language_overrides` glob or by a comment on its first or second
+//! line:
+//!
+//! ```text
+//! # hollowcheck:language python
+//! ```
+//!
+//! Overrides are resolved once per scan and recorded here by path; every
+//! lookup that picks an analyzer or a comment syntax goes through
+//! [`analysis_extension`], which answers with the extension of the
+//! overriding language. An explicit override wins over anything inferred
+//! from the file itself.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+
+use super::languages::get_analyzer_by_id;
+use super::normalize_language;
+
+/// Marker of an inline language override comment.
+pub const LANGUAGE_DIRECTIVE: &str = "hollowcheck:language";
+
+/// Bytes read from the start of a file when looking for the directive.
+const DIRECTIVE_WINDOW: usize = 512;
+
+/// Language of each file routed by an override, by path as scanned.
+static OVERRIDES: Lazy<RwLock<HashMap<PathBuf, &'static str>>> = Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// Resolve a language name to the ID of an analyzer-backed language.
+///
+/// Returns `None` for unknown names and for languages without an analyzer
+/// (`ruby`, `perl`), which an override could not route anywhere.
+pub fn analyzer_language(name: &str) -> Option<&'static str> {
+    normalize_language(name).filter(|id| get_analyzer_by_id(id).is_some())
+}
+
+/// The extension the analyzer for a language ID is registered under.
+fn language_extension(language: &str) -> Option<&'static str> {
+    match language {
+        "c" => Some("c"),
+        "cpp" => Some("cpp"),
+        "go" => Some("go"),
+        "java" => Some("java"),
+        "javascript" => Some("js"),
+        "python" => Some("py"),
+        "rust" => Some("rs"),
+        "scala" => Some("scala"),
+        "swift" => Some("swift"),
+        "typescript" => Some("ts"),
+        _ => None,
+    }
+}
+
+/// Read the language named by a `hollowcheck:language` comment on the first
+/// or second line of `source`.
+///
+/// The comment syntax around the directive does not matter (`#`, `//`,
+/// `/* ... */`), so one directive works for any language.
+pub fn inline_language(source: &[u8]) -> Option<&'static str> {
+    let head = &source[..source.len().min(DIRECTIVE_WINDOW)];
+    let head = String::from_utf8_lossy(head);
+    head.lines().take(2).find_map(|line| {
+        let (_, rest) = line.split_once(LANGUAGE_DIRECTIVE)?;
+        let name = rest.split_whitespace().next()?;
+        let name = name.split("*/").next()?.split("-->").next()?;
+        analyzer_language(name)
+    })
+}
+
+/// Read the inline language override of a file, if it has one.
+pub fn read_inline_language(path: &Path) -> Option<&'static str> {
+    let mut head = Vec::with_capacity(DIRECTIVE_WINDOW);
+    File::open(path)
+        .ok()?
+        .take(DIRECTIVE_WINDOW as u64)
+        .read_to_end(&mut head)
+        .ok()?;
+    inline_language(&head)
+}
+
+/// Route a file to the analyzer for `language` for the rest of the process.
+pub fn set_language_override(path: &Path, language: &'static str) {
+    OVERRIDES.write().unwrap().insert(path.to_path_buf(), language);
+}
+
+/// The language a file was routed to by an override, if any.
+pub fn language_override(path: &Path) -> Option<&'static str> {
+    OVERRIDES.read().unwrap().get(path).copied()
+}
+
+/// The extension analyzers and comment syntaxes are looked up by: that of
+/// the file's override language, or the file's own.
+pub fn analysis_extension(path: &Path) -> &str {
+    if let Some(ext) = language_override(path).and_then(language_extension) {
+        return ext;
+    }
+    path.extension().and_then(|e| e.to_str()).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inline_language() {
+        assert_eq!(inline_language(b"# hollowcheck:language python\nload(\"x\")\n"), Some("python"));
+        assert_eq!(inline_language(b"#!/bin/sh\n// hollowcheck:language golang\n"), Some("go"));
+        assert_eq!(inline_language(b"{{/* hollowcheck:language go*/}}\n"), Some("go"));
+        assert_eq!(inline_language(b"/* hollowcheck:language ts */\n"), Some("typescript"));
+        // Only the first two lines count, and the language needs an analyzer
+        assert_eq!(inline_language(b"\n\n# hollowcheck:language python\n"), None);
+        assert_eq!(inline_language(b"# hollowcheck:language ruby\n"), None);
+        assert_eq!(inline_language(b"# hollowcheck:language\n"), None);
+    }
+
+    #[test]
+    fn test_analysis_extension_follows_override() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let template = temp.path().join("main.go.tmpl");
+        let script = temp.path().join("deploy");
+        assert_eq!(analysis_extension(&template), "tmpl");
+        assert_eq!(analysis_extension(&script), "");
+
+        set_language_override(&template, "go");
+        set_language_override(&script, "python");
+        assert_eq!(analysis_extension(&template), "go");
+        assert_eq!(analysis_extension(&script), "py");
+        assert_eq!(language_override(&script), Some("python"));
+    }
+}
@@ -0,0 +1,422 @@
+//! Shared parameter counting and usage for function declarations.
+//!
+//! Most grammars put the parameter list in a `parameters` field on the
+//! function node (C and C++ nest it inside the declarator, JavaScript arrow
+//! functions inside the variable's value, Swift lists `parameter` children
+//! directly), so one walk covers every analyzer.
+//!
+//! Usage is currently extracted for grammars whose parameter entries carry
+//! `name` and `type` fields (Go): a parameter is unused when no identifier in
+//! the function body spells its name. Go's named results are checked the
+//! same way: one that no statement assigns before the last bare `return` is
+//! always returned as its zero value.
+
+use tree_sitter::Node;
+
+use super::comments::is_comment;
+use super::{ParsedFile, Span, UnassignedResult, UnusedParameter};
+
+/// Parameter list children that are punctuation or receivers, not parameters.
+const NON_PARAMETER_KINDS: &[&str] = &[
+    "self_parameter",       // Rust `&self`
+    "receiver_parameter",   // Java `Foo this`
+    "keyword_separator",    // Python `*`
+    "positional_separator", // Python `/`
+];
+
+/// Fields that lead from a declaration node towards its parameter list.
+const NESTED_FIELDS: &[&str] = &["declarator", "value"];
+
+/// Find the parameter lists of a function node (Scala allows several).
+///
+/// A single-parameter arrow function (`x => x`) has a lone `parameter`
+/// field instead of a list; it is returned as its own one-entry "list".
+fn parameter_lists(node: Node) -> Vec<Node> {
+    let mut cursor = node.walk();
+    let lists: Vec<Node> = node
+        .children_by_field_name("parameters", &mut cursor)
+        .collect();
+    if !lists.is_empty() {
+        return lists;
+    }
+    if let Some(param) = node.child_by_field_name("parameter") {
+        return vec![param];
+    }
+
+    NESTED_FIELDS
+        .iter()
+        .filter_map(|field| node.child_by_field_name(field))
+        .map(parameter_lists)
+        .find(|lists| !lists.is_empty())
+        .unwrap_or_default()
+}
+
+/// Count the parameters declared by one entry of a parameter list.
+fn count_entry(parsed: &ParsedFile, entry: Node) -> usize {
+    match entry.kind() {
+        // Go: `a, b int` declares two parameters
+        "parameter_declaration" if entry.child_by_field_name("name").is_some() => {
+            let mut cursor = entry.walk();
+            entry.children_by_field_name("name", &mut cursor).count()
+        }
+        // C: `f(void)` declares none
+        "parameter_declaration"
+            if entry.child_by_field_name("declarator").is_none()
+                && parsed.node_text(entry).trim() == "void" =>
+        {
+            0
+        }
+        // Python: `self`/`cls` are receivers
+        "identifier" if matches!(parsed.node_text(entry), "self" | "cls") => 0,
+        kind if NON_PARAMETER_KINDS.contains(&kind) => 0,
+        _ => 1,
+    }
+}
+
+/// Count the parameters of a callable declaration node.
+///
+/// Receivers (`self`, `&self`, Java receiver parameters) are not counted; Go
+/// method receivers live outside the parameter list. Returns `None` when no
+/// parameter list can be found.
+pub(crate) fn count_parameters(parsed: &ParsedFile, node: Node) -> Option<usize> {
+    let lists = parameter_lists(node);
+    if lists.is_empty() {
+        // Swift lists parameters directly on the function node
+        let mut cursor = node.walk();
+        let direct = node
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() == "parameter")
+            .count();
+        return (node.kind() == "function_declaration").then_some(direct);
+    }
+
+    let count = lists
+        .iter()
+        .map(|list| {
+            if list.kind() == "identifier" {
+                return 1;
+            }
+            let mut cursor = list.walk();
+            list.named_children(&mut cursor)
+                .filter(|c| !is_comment(*c))
+                .map(|c| count_entry(parsed, c))
+                .sum::<usize>()
+        })
+        .sum();
+    Some(count)
+}
+
+/// The names of a callable's parameters, in order, excluding receivers.
+///
+/// Returns `None` when a parameter has no single plain name (destructuring,
+/// rest and splat parameters, Go's `a, b int`) or no parameter list is found.
+pub(crate) fn parameter_names(parsed: &ParsedFile, node: Node) -> Option<Vec<String>> {
+    let lists = parameter_lists(node);
+    if lists.is_empty() {
+        return None;
+    }
+
+    let mut names = Vec::new();
+    for list in lists {
+        if list.kind() == "identifier" {
+            names.push(parsed.node_text(list).to_string());
+            continue;
+        }
+        let mut cursor = list.walk();
+        for entry in list.named_children(&mut cursor).filter(|c| !is_comment(*c)) {
+            match count_entry(parsed, entry) {
+                0 => continue,
+                1 => {}
+                _ => return None,
+            }
+            let name = if entry.kind() == "identifier" {
+                entry
+            } else if entry.kind() == "typed_parameter" {
+                // Python `x: int` has no name field
+                entry.named_child(0)?
+            } else {
+                entry
+                    .child_by_field_name("pattern")
+                    .or_else(|| entry.child_by_field_name("name"))?
+            };
+            if name.kind() != "identifier" {
+                return None;
+            }
+            names.push(parsed.node_text(name).to_string());
+        }
+    }
+    Some(names)
+}
+
+/// Name used for functions without a `name` field (Go closures).
+const ANONYMOUS_FUNCTION: &str = "func literal";
+
+/// Whether any identifier under `node` is spelled `name`.
+fn references(parsed: &ParsedFile, node: Node, name: &str) -> bool {
+    let mut stack = vec![node];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "identifier" && parsed.node_text(node) == name {
+            return true;
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    false
+}
+
+/// Collect named parameters that their function body never references, in
+/// source order.
+///
+/// `function_kinds` lists the function node kinds to check; functions without
+/// a body (declarations only) are skipped, and so are blank (`_`) names. A
+/// closure that captures the parameter counts as a use.
+pub(crate) fn extract_unused_parameters(
+    parsed: &ParsedFile,
+    function_kinds: &[&str],
+) -> Vec<UnusedParameter> {
+    let mut unused = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+
+        if !function_kinds.contains(&node.kind()) {
+            continue;
+        }
+        let Some(body) = node.child_by_field_name("body") else {
+            continue;
+        };
+        let function = node
+            .child_by_field_name("name")
+            .map(|n| parsed.node_text(n).to_string())
+            .unwrap_or_else(|| ANONYMOUS_FUNCTION.to_string());
+
+        for list in parameter_lists(node) {
+            let mut cursor = list.walk();
+            for entry in list.named_children(&mut cursor) {
+                let Some(type_node) = entry.child_by_field_name("type") else {
+                    continue;
+                };
+                let mut names = entry.walk();
+                for name in entry.children_by_field_name("name", &mut names) {
+                    let name = parsed.node_text(name);
+                    if name == "_" || references(parsed, body, name) {
+                        continue;
+                    }
+                    unused.push(UnusedParameter {
+                        function: function.clone(),
+                        name: name.to_string(),
+                        type_text: parsed.node_text(type_node).to_string(),
+                        span: Span::from_node(entry),
+                    });
+                }
+            }
+        }
+    }
+
+    unused.sort_by_key(|p| p.span.start_byte);
+    unused
+}
+
+/// Whether a Go `return` statement is bare: it returns the named results.
+fn is_bare_return(node: Node) -> bool {
+    let mut cursor = node.walk();
+    node.kind() == "return_statement" && !node.named_children(&mut cursor).any(|c| !is_comment(c))
+}
+
+/// Start bytes of the bare returns of a function body, outside closures.
+fn bare_returns(body: Node) -> Vec<usize> {
+    let mut returns = Vec::new();
+    let mut stack = vec![body];
+    while let Some(node) = stack.pop() {
+        if node.kind() == "func_literal" {
+            continue;
+        }
+        if is_bare_return(node) {
+            returns.push(node.start_byte());
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+    returns
+}
+
+/// Start byte of the first statement under `body` that may write `name`:
+/// the left side of an assignment, `:=`, or range clause, `name++` and
+/// `name--`, and `&name` (the callee may write through the pointer).
+///
+/// A write inside a closure counts as the first, since a deferred closure
+/// runs after every `return`.
+fn first_write(parsed: &ParsedFile, body: Node, name: &str) -> Option<usize> {
+    let mut first: Option<usize> = None;
+    let mut stack = vec![(body, false)];
+    while let Some((node, in_closure)) = stack.pop() {
+        let target = match node.kind() {
+            "assignment_statement" | "short_var_declaration" | "range_clause" => node.child_by_field_name("left"),
+            "inc_statement" | "dec_statement" => node.named_child(0),
+            "unary_expression"
+                if node
+                    .child_by_field_name("operator")
+                    .is_some_and(|op| parsed.node_text(op) == "&") =>
+            {
+                node.child_by_field_name("operand")
+            }
+            _ => None,
+        };
+        if target.is_some_and(|target| references(parsed, target, name)) {
+            let at = if in_closure { 0 } else { node.start_byte() };
+            first = Some(first.map_or(at, |f| f.min(at)));
+        }
+        let in_closure = in_closure || node.kind() == "func_literal";
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor).map(|c| (c, in_closure)));
+    }
+    first
+}
+
+/// Collect named results that bare `return`s hand back without any
+/// assignment, in source order.
+///
+/// `function_kinds` lists the function node kinds to check. A result counts
+/// as assigned when a write to it comes before the last bare return; one
+/// written only after it, or never, is always returned as its zero value.
+/// Functions without a bare return, and blank (`_`) results, are skipped.
+pub(crate) fn extract_unassigned_results(parsed: &ParsedFile, function_kinds: &[&str]) -> Vec<UnassignedResult> {
+    let mut unassigned = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+
+        if !function_kinds.contains(&node.kind()) {
+            continue;
+        }
+        let (Some(body), Some(results)) = (node.child_by_field_name("body"), node.child_by_field_name("result"))
+        else {
+            continue;
+        };
+        if results.kind() != "parameter_list" {
+            continue;
+        }
+        let returns = bare_returns(body);
+        let (Some(&first_return), Some(&last_return)) = (returns.iter().min(), returns.iter().max()) else {
+            continue;
+        };
+        let function = node
+            .child_by_field_name("name")
+            .map(|n| parsed.node_text(n).to_string())
+            .unwrap_or_else(|| ANONYMOUS_FUNCTION.to_string());
+        let return_line = parsed.source[..first_return].iter().filter(|&&b| b == b'\n').count() + 1;
+
+        let mut cursor = results.walk();
+        for entry in results.named_children(&mut cursor) {
+            let Some(type_node) = entry.child_by_field_name("type") else {
+                continue;
+            };
+            let mut names = entry.walk();
+            for name in entry.children_by_field_name("name", &mut names) {
+                let name = parsed.node_text(name);
+                if name == "_" || first_write(parsed, body, name).is_some_and(|at| at < last_return) {
+                    continue;
+                }
+                unassigned.push(UnassignedResult {
+                    function: function.clone(),
+                    name: name.to_string(),
+                    type_text: parsed.node_text(type_node).to_string(),
+                    span: Span::from_node(entry),
+                    return_line,
+                });
+            }
+        }
+    }
+
+    unassigned.sort_by_key(|r| r.span.start_byte);
+    unassigned
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers};
+    use std::path::Path;
+
+    fn param_count(ext: &str, source: &str, name: &str) -> Option<usize> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        facts.find_declaration(name).unwrap().param_count
+    }
+
+    #[test]
+    fn test_count_parameters_per_language() {
+        let cases = [
+            ("go", "package p\nfunc f(a, b int, c string, rest ...int) {}\n", "f", 4),
+            ("go", "package p\ntype T struct{}\nfunc (t *T) m(x int) {}\n", "m", 1),
+            ("py", "class A:\n    def m(self, a, b=1, *args, c, **kw):\n        pass\n", "m", 5),
+            ("py", "def g(a, /, b, *, c):\n    pass\n", "g", 3),
+            ("rs", "struct S;\nimpl S {\n    fn m(&self, a: u8, b: u8) {}\n}\n", "m", 2),
+            ("js", "function f(a, {b, c}, ...rest) {}\n", "f", 3),
+            ("js", "const g = x => x;\n", "g", 1),
+            ("ts", "function f(a: number, b?: string, c = 1): void {}\n", "f", 3),
+            ("java", "class A { void m(int a, String b) {} }\n", "m", 2),
+            ("c", "int f(void) { return 0; }\nint g(int a, char *b) { return a; }\n", "f", 0),
+            ("c", "int g(int a, char *b) { return a; }\n", "g", 2),
+            ("cpp", "int f(int a, int b, int c) { return a; }\n", "f", 3),
+            ("swift", "func f(a: Int, b c: Int) -> Int { return a }\n", "f", 2),
+            ("scala", "object O { def f(a: Int)(b: Int, c: Int): Int = a }\n", "f", 3),
+        ];
+
+        for (ext, source, name, expected) in cases {
+            assert_eq!(
+                param_count(ext, source, name),
+                Some(expected),
+                "{} {:?}",
+                ext,
+                source
+            );
+        }
+    }
+
+    #[test]
+    fn test_extract_unused_parameters_go() {
+        register_analyzers();
+        let source = r#"package svc
+
+func (s *Server) Fetch(ctx context.Context, id string, _ int) error {
+	go func(n int) {
+		s.log(id)
+	}(1)
+	return nil
+}
+
+func Watch(ctx context.Context, ch chan int) {
+	go func() {
+		<-ctx.Done()
+	}()
+	ch <- 1
+}
+
+type Store interface {
+	Get(ctx context.Context) error
+}
+"#;
+        let analyzer = get_analyzer("go").unwrap();
+        let parsed = analyzer.parse(Path::new("svc.go"), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        let unused: Vec<_> = facts
+            .unused_parameters
+            .iter()
+            .map(|p| (p.function.as_str(), p.name.as_str(), p.type_text.as_str(), p.span.start_line))
+            .collect();
+        assert_eq!(
+            unused,
+            vec![
+                ("Fetch", "ctx", "context.Context", 3),
+                ("func literal", "n", "int", 4),
+            ]
+        );
+    }
+}
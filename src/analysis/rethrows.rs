@@ -0,0 +1,258 @@
+//! Shared extraction of error handlers that log and rethrow.
+//!
+//! Logging an error and passing it on unchanged at every layer prints the
+//! same failure once per layer. Within one handler the pattern is visible: a
+//! logging call next to `raise`, `throw e`, or Go's `return err`. Each
+//! analyzer says how its grammar spells a handler and a rethrow in a
+//! `RethrowSyntax`; the walk is shared.
+
+use tree_sitter::Node;
+
+use super::{LoggedRethrow, ParsedFile, Span};
+
+/// An error handler: its body and the name it binds the error to.
+pub(crate) struct Handler<'a> {
+    pub body: Node<'a>,
+    pub error: Option<String>,
+}
+
+/// Grammar-specific shapes of error handlers.
+pub(crate) struct RethrowSyntax {
+    /// Returns the handler a node opens, if it is one.
+    pub handler: for<'a> fn(&'a ParsedFile, Node<'a>) -> Option<Handler<'a>>,
+    /// Statement kind that passes an error on (`raise_statement`,
+    /// `throw_statement`, or `return_statement` for Go).
+    pub rethrow_kind: &'static str,
+    /// Whether a rethrow with no operand re-raises the handled error (Python `raise`).
+    pub bare_rethrow: bool,
+    /// Call node kinds; the callee is the text before the `arguments` field.
+    pub call_kinds: &'static [&'static str],
+    /// Function node kinds; handlers inside them are separate.
+    pub function_kinds: &'static [&'static str],
+}
+
+/// Method names of logging calls (`logger.error`, `log.Printf`, `console.warn`).
+const LOG_METHODS: &[&str] = &[
+    "trace", "debug", "info", "warn", "warning", "error", "exception", "critical", "severe", "log",
+    "print", "printf", "println", "debugf", "infof", "warnf", "warningf", "errorf",
+];
+
+/// Whether a callee such as `self.logger.exception` is a logging call: a
+/// logging method on a receiver named like a logger, or on `console`.
+fn is_logging_callee(callee: &str) -> bool {
+    let Some((receiver, method)) = callee.rsplit_once('.') else {
+        return false;
+    };
+    LOG_METHODS.contains(&method.to_ascii_lowercase().as_str())
+        && receiver.split('.').any(|part| {
+            let part = part.to_ascii_lowercase();
+            part == "console" || part.contains("log")
+        })
+}
+
+/// The callee of a call node as written, without whitespace.
+fn callee_text(parsed: &ParsedFile, call: Node) -> Option<String> {
+    let arguments = call.child_by_field_name("arguments")?;
+    let text = parsed.source.get(call.start_byte()..arguments.start_byte())?;
+    let text = std::str::from_utf8(text).ok()?;
+    Some(text.split_whitespace().collect())
+}
+
+/// Whether a rethrow statement passes `error` on with nothing added: no
+/// operand (where that re-raises), or the error name as the last operand and
+/// no `cause`.
+fn passes_on(parsed: &ParsedFile, statement: Node, error: Option<&str>, syntax: &RethrowSyntax) -> bool {
+    if statement.child_by_field_name("cause").is_some() {
+        return false;
+    }
+    let mut cursor = statement.walk();
+    let mut operands: Vec<Node> = statement
+        .named_children(&mut cursor)
+        .filter(|n| !n.kind().ends_with("comment"))
+        .collect();
+    if let [list] = operands.as_slice() {
+        if list.kind() == "expression_list" {
+            let list = *list;
+            let mut cursor = list.walk();
+            operands = list.named_children(&mut cursor).collect();
+        }
+    }
+
+    match (operands.last(), error) {
+        (None, _) => syntax.bare_rethrow,
+        (Some(last), Some(error)) => last.kind() == "identifier" && parsed.node_text(*last) == error,
+        (Some(_), None) => false,
+    }
+}
+
+/// The first logging call and the first rethrow of the error in a handler
+/// body, outside nested functions.
+fn scan_handler<'a>(
+    parsed: &'a ParsedFile,
+    handler: &Handler<'a>,
+    syntax: &RethrowSyntax,
+) -> (Option<String>, Option<Node<'a>>) {
+    let mut logger = None;
+    let mut rethrow = None;
+    let mut stack = vec![handler.body];
+
+    while let Some(node) = stack.pop() {
+        if syntax.function_kinds.contains(&node.kind()) {
+            continue;
+        }
+        if logger.is_none() && syntax.call_kinds.contains(&node.kind()) {
+            logger = callee_text(parsed, node).filter(|c| is_logging_callee(c));
+        }
+        if rethrow.is_none()
+            && node.kind() == syntax.rethrow_kind
+            && passes_on(parsed, node, handler.error.as_deref(), syntax)
+        {
+            rethrow = Some(node);
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    (logger, rethrow)
+}
+
+/// Collect handlers that log the error and pass it on unchanged, in source order.
+pub(crate) fn extract_logged_rethrows(parsed: &ParsedFile, syntax: &RethrowSyntax) -> Vec<LoggedRethrow> {
+    let mut found = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        if let Some(handler) = (syntax.handler)(parsed, node) {
+            if let (Some(logger), Some(rethrow)) = scan_handler(parsed, &handler, syntax) {
+                found.push(LoggedRethrow {
+                    error: handler.error,
+                    logger,
+                    rethrow: parsed.node_text(rethrow).trim().trim_end_matches(';').to_string(),
+                    span: Span::from_node(node),
+                });
+            }
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    found.sort_by_key(|r| r.span.start_byte);
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_logging_callee;
+    use crate::analysis::{get_analyzer, register_analyzers};
+    use std::path::Path;
+
+    fn rethrows(ext: &str, source: &str) -> Vec<(usize, Option<String>, String, String)> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        facts
+            .logged_rethrows
+            .into_iter()
+            .map(|r| (r.span.start_line, r.error, r.logger, r.rethrow))
+            .collect()
+    }
+
+    #[test]
+    fn test_logging_callees() {
+        for callee in ["logger.error", "self.logger.exception", "logging.warning", "log.Printf", "LOG.warn", "console.error", "slog.Error"] {
+            assert!(is_logging_callee(callee), "{}", callee);
+        }
+        for callee in ["print", "error", "db.error", "logger.setLevel", "e.printStackTrace"] {
+            assert!(!is_logging_callee(callee), "{}", callee);
+        }
+    }
+
+    #[test]
+    fn test_python_except_clauses() {
+        let source = r#"def load(path):
+    try:
+        return read(path)
+    except OSError as e:
+        logger.exception("read failed")
+        raise
+    except ValueError as e:
+        logging.error("bad data: %s", e)
+        raise e
+    except KeyError as e:
+        logger.error("missing key")
+        raise ConfigError("missing key") from e
+    except TypeError:
+        logger.error("wrong type")
+        raise
+"#;
+        let found = rethrows("py", source);
+        assert_eq!(
+            found,
+            vec![
+                (4, Some("e".to_string()), "logger.exception".to_string(), "raise".to_string()),
+                (7, Some("e".to_string()), "logging.error".to_string(), "raise e".to_string()),
+                (13, None, "logger.error".to_string(), "raise".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_java_catch_clauses() {
+        let source = r#"class Repo {
+    User find(String id) throws IOException {
+        try {
+            return client.get(id);
+        } catch (IOException e) {
+            LOG.error("lookup failed", e);
+            throw e;
+        } catch (RuntimeException e) {
+            log.warn("lookup failed", e);
+            throw new RepoException("lookup " + id, e);
+        }
+    }
+}
+"#;
+        let found = rethrows("java", source);
+        assert_eq!(found, vec![(5, Some("e".to_string()), "LOG.error".to_string(), "throw e".to_string())]);
+    }
+
+    #[test]
+    fn test_js_and_ts_catch_clauses() {
+        let js = "async function save(user) {\n  try {\n    await db.put(user);\n  } catch (err) {\n    console.error(err);\n    throw err;\n  }\n}\n";
+        assert_eq!(
+            rethrows("js", js),
+            vec![(4, Some("err".to_string()), "console.error".to_string(), "throw err".to_string())]
+        );
+
+        let ts = "function parse(raw: string): Config {\n  try {\n    return JSON.parse(raw);\n  } catch (err: unknown) {\n    this.logger.warn('bad config');\n    const retry = () => { throw err; };\n    throw new ConfigError(String(err));\n  }\n}\n";
+        assert!(rethrows("ts", ts).is_empty());
+    }
+
+    #[test]
+    fn test_go_err_checks() {
+        let source = r#"package store
+
+func (s *Store) Load(id string) (*User, error) {
+	user, err := s.db.Get(id)
+	if err != nil {
+		log.Printf("load %s: %v", id, err)
+		return nil, err
+	}
+	if err := s.cache.Put(user); err != nil {
+		s.logger.Error("cache put failed", "err", err)
+		return nil, fmt.Errorf("cache user %s: %w", id, err)
+	}
+	if err != nil {
+		return nil, err
+	}
+	return user, nil
+}
+"#;
+        let found = rethrows("go", source);
+        assert_eq!(found, vec![(5, Some("err".to_string()), "log.Printf".to_string(), "return nil, err".to_string())]);
+    }
+}
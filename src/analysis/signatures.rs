@@ -0,0 +1,227 @@
+//! Shared extraction of C and C++ function declarations and definitions.
+//!
+//! A C header declares functions (`int parse(const char *s);`) that a
+//! translation unit defines. The declaration query of each analyzer only
+//! sees definitions, so this walk records both, with the storage-class
+//! specifiers that tell an `extern` or `static inline` function apart.
+//! Declarations are read at file, namespace, `extern "C"`, preprocessor
+//! conditional, template, and class scope; function bodies are not entered.
+
+use tree_sitter::Node;
+
+use super::params::count_parameters;
+use super::{FunctionSignature, ParsedFile, Span};
+
+/// Declarator kinds wrapping a function declarator (`*f(...)`, `&f(...)`).
+const WRAPPER_DECLARATORS: &[&str] = &["pointer_declarator", "reference_declarator"];
+
+/// Declarator kinds that name a function; anything else (a parenthesized
+/// `(*cb)`) declares a function pointer.
+const NAME_KINDS: &[&str] = &[
+    "identifier",
+    "field_identifier",
+    "qualified_identifier",
+    "destructor_name",
+    "operator_name",
+];
+
+/// Find the function declarator under a declarator, if it declares a function.
+fn function_declarator(node: Node) -> Option<Node> {
+    let mut node = node;
+    while WRAPPER_DECLARATORS.contains(&node.kind()) {
+        node = match node.child_by_field_name("declarator") {
+            Some(inner) => inner,
+            // Reference declarators have no field for their inner declarator
+            None => node.named_child(node.named_child_count().checked_sub(1)?)?,
+        };
+    }
+    let name = node.child_by_field_name("declarator")?;
+    (node.kind() == "function_declarator" && NAME_KINDS.contains(&name.kind())).then_some(node)
+}
+
+/// Split a declarator name into the unqualified name and its qualifier
+/// (`Foo::bar` -> `bar`, `Foo`).
+fn split_name(parsed: &ParsedFile, function: Node) -> Option<(String, Option<String>)> {
+    let name = function.child_by_field_name("declarator")?;
+    let text = parsed.node_text(name);
+    Some(match text.rsplit_once("::") {
+        Some((scope, name)) => (name.to_string(), Some(scope.to_string())),
+        None => (text.to_string(), None),
+    })
+}
+
+/// Name of the class or struct whose body contains `node`, if any.
+fn enclosing_class(parsed: &ParsedFile, node: Node) -> Option<String> {
+    let list = node.parent().filter(|p| p.kind() == "field_declaration_list")?;
+    let class = list.parent()?;
+    class
+        .child_by_field_name("name")
+        .map(|n| parsed.node_text(n).to_string())
+}
+
+/// Storage-class specifiers of a declaration (`extern`, `static`, `inline`),
+/// plus `extern` for declarations inside an `extern "C"` block.
+fn specifiers(parsed: &ParsedFile, node: Node) -> Vec<String> {
+    let mut cursor = node.walk();
+    let mut specifiers: Vec<String> = node
+        .children(&mut cursor)
+        .filter(|c| c.kind() == "storage_class_specifier")
+        .map(|c| parsed.node_text(c).to_string())
+        .collect();
+    let in_linkage_block = std::iter::successors(node.parent(), |n| n.parent())
+        .any(|n| n.kind() == "linkage_specification");
+    if in_linkage_block && !specifiers.iter().any(|s| s == "extern") {
+        specifiers.push("extern".to_string());
+    }
+    specifiers
+}
+
+fn signature(
+    parsed: &ParsedFile,
+    node: Node,
+    function: Node,
+    is_definition: bool,
+) -> Option<FunctionSignature> {
+    let (name, qualifier) = split_name(parsed, function)?;
+    Some(FunctionSignature {
+        name,
+        scope: qualifier.or_else(|| enclosing_class(parsed, node)),
+        param_count: count_parameters(parsed, function),
+        is_definition,
+        specifiers: specifiers(parsed, node),
+        span: Span::from_node(node),
+    })
+}
+
+/// Collect function declarations and definitions from a C or C++ file, in
+/// source order.
+///
+/// Pure virtual methods (`= 0`) and `friend` declarations are skipped: they
+/// need no definition of their own. `= default` and `= delete` count as
+/// definitions.
+pub(crate) fn extract_function_signatures(parsed: &ParsedFile) -> Vec<FunctionSignature> {
+    let mut signatures = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        match node.kind() {
+            "function_definition" => {
+                let function = node
+                    .child_by_field_name("declarator")
+                    .and_then(function_declarator);
+                if let Some(sig) = function.and_then(|f| signature(parsed, node, f, true)) {
+                    signatures.push(sig);
+                }
+                // Declarations inside the body are local
+                continue;
+            }
+            "declaration" | "field_declaration" => {
+                let is_friend = node.parent().is_some_and(|p| p.kind() == "friend_declaration");
+                let is_pure = node.child_by_field_name("default_value").is_some();
+                if !is_friend && !is_pure {
+                    let mut cursor = node.walk();
+                    for declarator in node.children_by_field_name("declarator", &mut cursor) {
+                        if let Some(sig) = function_declarator(declarator)
+                            .and_then(|f| signature(parsed, node, f, false))
+                        {
+                            signatures.push(sig);
+                        }
+                    }
+                }
+            }
+            "compound_statement" => continue,
+            _ => {}
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+    }
+
+    signatures.sort_by_key(|s| s.span.start_byte);
+    signatures
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers, FunctionSignature};
+    use std::path::Path;
+
+    fn signatures(ext: &str, source: &str) -> Vec<FunctionSignature> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        analyzer.extract_facts(&parsed).unwrap().function_signatures
+    }
+
+    fn summary(sigs: &[FunctionSignature]) -> Vec<(String, Option<usize>, bool, String)> {
+        sigs.iter()
+            .map(|s| (s.qualified_name(), s.param_count, s.is_definition, s.specifiers.join(" ")))
+            .collect()
+    }
+
+    #[test]
+    fn test_c_declarations_and_definitions() {
+        let source = r#"extern int open_db(const char *path);
+static inline int clamp(int v) { return v < 0 ? 0 : v; }
+char *dup_string(const char *s, ...);
+void (*on_event)(int code);
+int width(void), height;
+#ifdef _WIN32
+void win_only(void);
+#endif
+typedef void handler(int);
+
+int count_rows(struct db *db) {
+    int local(int);
+    return 0;
+}
+"#;
+        assert_eq!(
+            summary(&signatures("c", source)),
+            vec![
+                ("open_db".to_string(), Some(1), false, "extern".to_string()),
+                ("clamp".to_string(), Some(1), true, "static inline".to_string()),
+                ("dup_string".to_string(), Some(2), false, String::new()),
+                ("width".to_string(), Some(0), false, String::new()),
+                ("win_only".to_string(), Some(0), false, String::new()),
+                ("count_rows".to_string(), Some(1), true, String::new()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_cpp_declarations_and_definitions() {
+        let source = r#"namespace store {
+void flush(int fd);
+}
+extern "C" { void c_entry(void); }
+class Cache {
+public:
+    Cache();
+    ~Cache();
+    virtual void evict() = 0;
+    int size() const;
+    bool empty() const { return size() == 0; }
+    Cache(const Cache&) = delete;
+    friend void swap(Cache&, Cache&);
+};
+int Cache::size() const { return 0; }
+int &slot(int &index);
+"#;
+        assert_eq!(
+            summary(&signatures("cpp", source)),
+            vec![
+                ("flush".to_string(), Some(1), false, String::new()),
+                ("c_entry".to_string(), Some(0), false, "extern".to_string()),
+                ("Cache::Cache".to_string(), Some(0), false, String::new()),
+                ("Cache::~Cache".to_string(), Some(0), false, String::new()),
+                ("Cache::size".to_string(), Some(0), false, String::new()),
+                ("Cache::empty".to_string(), Some(0), true, String::new()),
+                ("Cache::Cache".to_string(), Some(1), true, String::new()),
+                ("Cache::size".to_string(), Some(0), true, String::new()),
+                ("slot".to_string(), Some(1), false, String::new()),
+            ]
+        );
+    }
+}
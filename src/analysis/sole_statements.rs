@@ -0,0 +1,176 @@
+//! Shared extraction of what a one-statement function body does.
+//!
+//! Projects have their own "not implemented" idioms: raising
+//! `FeatureNotReady()`, calling `deps.NotWired()`, returning an
+//! `ERR_NOT_IMPLEMENTED` sentinel. Contracts list them under
+//! `custom_stub_signatures`, and a body whose only statement targets one is
+//! a stub. The target comes from the statement's AST (the callee, the raised
+//! type, the returned name), never from its text, so a name inside a string
+//! literal does not match. Each analyzer lists the node kinds its grammar
+//! uses in a `SoleStatementSyntax`; the classification is shared.
+
+use tree_sitter::Node;
+
+use super::{ParsedFile, SoleStatement};
+
+/// Grammar-specific node kinds for classifying a body's only statement.
+pub(crate) struct SoleStatementSyntax {
+    /// Statements that wrap one expression (`expression_statement`).
+    pub expression_statements: &'static [&'static str],
+    /// Statement lists a body may nest its statements in (Swift `statements`).
+    pub statement_lists: &'static [&'static str],
+    /// Return statements and expressions.
+    pub return_kinds: &'static [&'static str],
+    /// Raise and throw statements and expressions.
+    pub throw_kinds: &'static [&'static str],
+    /// Child kind that makes a return-kind statement a throw (Swift's
+    /// `control_transfer_statement` is both).
+    pub throw_keyword: Option<&'static str>,
+    /// Lists of returned values (Go `expression_list`).
+    pub value_lists: &'static [&'static str],
+    /// Call expressions, including macro invocations.
+    pub call_kinds: &'static [&'static str],
+    /// Constructor expressions (`new X()`), whose target is the constructed type.
+    pub construct_kinds: &'static [&'static str],
+    /// Identifiers and qualified names a sentinel can be written as.
+    pub name_kinds: &'static [&'static str],
+    /// Zero values ignored beside the sentinel of a multi-value return (`nil`, `0`).
+    pub zero_value_kinds: &'static [&'static str],
+}
+
+/// Classify a body's statements: `None` unless there is exactly one, and it
+/// calls, raises, or returns a name.
+///
+/// `statements` are the body's children without braces and comments, as
+/// the analyzer counts them.
+pub(crate) fn sole_statement(
+    parsed: &ParsedFile,
+    statements: &[Node],
+    syntax: &SoleStatementSyntax,
+) -> Option<SoleStatement> {
+    let [mut stmt] = statements else {
+        return None;
+    };
+    while syntax.statement_lists.contains(&stmt.kind()) {
+        let mut cursor = stmt.walk();
+        let inner: Vec<_> = stmt
+            .named_children(&mut cursor)
+            .filter(|n| !n.kind().ends_with("comment"))
+            .collect();
+        let [only] = inner.as_slice() else {
+            return None;
+        };
+        stmt = *only;
+    }
+
+    // A name on its own is a tail expression (Rust, Scala); one wrapped in an
+    // expression statement is discarded, not returned.
+    if syntax.name_kinds.contains(&stmt.kind()) {
+        return Some(SoleStatement::Return(target_text(parsed, stmt)));
+    }
+    if syntax.expression_statements.contains(&stmt.kind()) {
+        stmt = first_named(stmt)?;
+    }
+
+    let kind = stmt.kind();
+    let is_throw = syntax.throw_kinds.contains(&kind)
+        && syntax
+            .throw_keyword
+            .is_none_or(|keyword| has_child_kind(stmt, keyword));
+    if is_throw {
+        let thrown = first_named(stmt)?;
+        return target(parsed, thrown, syntax).map(SoleStatement::Raise);
+    }
+    if syntax.return_kinds.contains(&kind) {
+        let value = returned_value(stmt, syntax)?;
+        if syntax.call_kinds.contains(&value.kind()) {
+            return callee(parsed, value).map(SoleStatement::Call);
+        }
+        if syntax.name_kinds.contains(&value.kind()) {
+            return Some(SoleStatement::Return(target_text(parsed, value)));
+        }
+        return None;
+    }
+    if syntax.call_kinds.contains(&kind) {
+        return callee(parsed, stmt).map(SoleStatement::Call);
+    }
+    None
+}
+
+/// First named child that is not a comment or keyword token.
+fn first_named(node: Node) -> Option<Node> {
+    let mut cursor = node.walk();
+    let first = node
+        .named_children(&mut cursor)
+        .find(|n| !n.kind().ends_with("comment") && !n.kind().ends_with("_keyword"));
+    first
+}
+
+fn has_child_kind(node: Node, kind: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|c| c.kind() == kind);
+    found
+}
+
+/// The one value of a return that is not a zero value.
+fn returned_value<'a>(stmt: Node<'a>, syntax: &SoleStatementSyntax) -> Option<Node<'a>> {
+    let mut values = Vec::new();
+    let mut stack = vec![stmt];
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        for child in node.named_children(&mut cursor) {
+            if syntax.value_lists.contains(&child.kind()) {
+                stack.push(child);
+            } else if !child.kind().ends_with("comment") && !child.kind().ends_with("_keyword") {
+                values.push(child);
+            }
+        }
+    }
+    let mut named = values
+        .into_iter()
+        .filter(|v| !syntax.zero_value_kinds.contains(&v.kind()));
+    let value = named.next()?;
+    named.next().is_none().then_some(value)
+}
+
+/// Target of a raised value: the called or constructed type, or the name.
+fn target(parsed: &ParsedFile, node: Node, syntax: &SoleStatementSyntax) -> Option<String> {
+    let kind = node.kind();
+    if syntax.call_kinds.contains(&kind) {
+        return callee(parsed, node);
+    }
+    if syntax.construct_kinds.contains(&kind) {
+        let constructed = node
+            .child_by_field_name("constructor")
+            .or_else(|| node.child_by_field_name("type"))
+            .or_else(|| first_named(node))?;
+        return Some(target_text(parsed, constructed));
+    }
+    syntax
+        .name_kinds
+        .contains(&kind)
+        .then(|| target_text(parsed, node))
+}
+
+/// The callee of a call: its `function` or `macro` field, or the text
+/// before its arguments (Java's `object.name(...)`, Swift's call suffix).
+fn callee(parsed: &ParsedFile, call: Node) -> Option<String> {
+    if let Some(function) = call
+        .child_by_field_name("function")
+        .or_else(|| call.child_by_field_name("macro"))
+    {
+        return Some(target_text(parsed, function));
+    }
+    let mut cursor = call.walk();
+    let arguments = call
+        .child_by_field_name("arguments")
+        .or_else(|| call.named_children(&mut cursor).find(|c| c.kind() == "call_suffix"))?;
+    let text = std::str::from_utf8(&parsed.source[call.start_byte()..arguments.start_byte()]).ok()?;
+    let text: String = text.split_whitespace().collect();
+    (!text.is_empty()).then_some(text)
+}
+
+/// A name's text with whitespace removed (`deps . NotWired` is `deps.NotWired`).
+fn target_text(parsed: &ParsedFile, node: Node) -> String {
+    parsed.node_text(node).split_whitespace().collect()
+}
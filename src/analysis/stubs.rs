@@ -5,8 +5,24 @@
 //! - Bodies containing only panic/unimplemented/todo! calls
 //! - Bodies returning only nil/None/null
 //! - Bodies containing only TODO comments
+//! - Long bodies that repeat a few statements over and over, such as the
+//!   same assignment pasted with an incremented index
+//! - Bodies whose only statement calls, raises, or returns a name a
+//!   contract lists as its project's stub idiom ([`StubSignature`])
+//!
+//! Intentional stubs (compile-time trait checks, no-op hooks, platform
+//! stubs) are exempted by [`StubExemption`] allow rules rather than by
+//! per-language logic. Analyzers only record the facts the rules match on,
+//! such as [`SignatureFeature`]s. The built-in rules carry ids, which
+//! contracts use under `stub_exemptions` to override or remove them.
+
+use std::path::Path;
 
-use crate::analysis::{FileFacts, FunctionBody, Span};
+use regex::Regex;
+
+use crate::analysis::{
+    AnalysisContext, Complexity, Declaration, FileFacts, FunctionBody, ParsedFile, SignatureFeature, SoleStatement, Span,
+};
 
 /// Kind of hollow/stub body detected.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +35,10 @@ pub enum HollowBodyKind {
     NilReturnOnly,
     /// Body only contains TODO/FIXME comment.
     TodoCommentOnly,
+    /// Body of many statements with few distinct ones.
+    Repetitive,
+    /// Body whose only statement matches a contract's custom stub signature.
+    CustomSignature,
 }
 
 impl HollowBodyKind {
@@ -29,6 +49,33 @@ impl HollowBodyKind {
             HollowBodyKind::PanicOnly => "only contains panic/unimplemented/todo! call",
             HollowBodyKind::NilReturnOnly => "only returns nil/None",
             HollowBodyKind::TodoCommentOnly => "only contains TODO comment",
+            HollowBodyKind::Repetitive => "repeats the same few statements",
+            HollowBodyKind::CustomSignature => "matches a custom stub signature",
+        }
+    }
+
+    /// Name used in contracts (`kinds: [empty, panic]`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HollowBodyKind::Empty => "empty",
+            HollowBodyKind::PanicOnly => "panic",
+            HollowBodyKind::NilReturnOnly => "nil_return",
+            HollowBodyKind::TodoCommentOnly => "todo_comment",
+            HollowBodyKind::Repetitive => "repetitive_body",
+            HollowBodyKind::CustomSignature => "custom_signature",
+        }
+    }
+
+    /// Parse a contract name, the inverse of [`HollowBodyKind::as_str`].
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "empty" => Some(HollowBodyKind::Empty),
+            "panic" => Some(HollowBodyKind::PanicOnly),
+            "nil_return" => Some(HollowBodyKind::NilReturnOnly),
+            "todo_comment" => Some(HollowBodyKind::TodoCommentOnly),
+            "repetitive_body" => Some(HollowBodyKind::Repetitive),
+            "custom_signature" => Some(HollowBodyKind::CustomSignature),
+            _ => None,
         }
     }
 
@@ -39,6 +86,8 @@ impl HollowBodyKind {
             HollowBodyKind::PanicOnly => 1,
             HollowBodyKind::TodoCommentOnly => 2,
             HollowBodyKind::NilReturnOnly => 3,
+            HollowBodyKind::Repetitive => 4,
+            HollowBodyKind::CustomSignature => 5,
         }
     }
 }
@@ -64,6 +113,210 @@ pub struct StubFinding {
     pub kind: HollowBodyKind,
     /// The function body text (for context).
     pub body_text: String,
+    /// For [`HollowBodyKind::CustomSignature`], the index of the matched
+    /// entry in [`StubDetectorConfig::custom_signatures`] and the statement
+    /// it matched.
+    pub custom_signature: Option<(usize, SoleStatement)>,
+}
+
+/// A project's own stub idiom: a name that, when a function body's only
+/// statement calls, raises, or returns it, makes the body a stub.
+///
+/// A name matches a target equal to it or ending in `.name` or `::name`, so
+/// `NotWired` matches `deps.NotWired()` while `deps.NotWired` matches only
+/// that receiver.
+#[derive(Debug, Clone)]
+pub struct StubSignature {
+    /// Analyzer language (`python`, `go`) the names apply to.
+    pub language: String,
+    /// Called functions, raised types, and returned identifiers.
+    pub names: Vec<String>,
+}
+
+impl StubSignature {
+    /// Returns true if `statement` targets one of the names.
+    pub fn matches(&self, statement: &SoleStatement) -> bool {
+        let target = statement.target();
+        self.names.iter().any(|name| {
+            target == name
+                || target
+                    .strip_suffix(name.as_str())
+                    .is_some_and(|prefix| prefix.ends_with('.') || prefix.ends_with("::"))
+        })
+    }
+}
+
+/// An allow rule for stubs that are intentional.
+///
+/// A finding is exempt when its function name matches `name_pattern`, its
+/// file path matches `path_pattern`, its file is in one of `languages`, its
+/// signature has every feature in `signature`, and its kind is listed in
+/// `kinds`. Patterns are unanchored regexes; a missing pattern matches
+/// everything, and empty `languages` or `kinds` match every language or kind.
+#[derive(Debug, Clone)]
+pub struct StubExemption {
+    /// Identifier contracts use to override or remove the rule.
+    pub id: String,
+    /// Function or method name, without the receiver.
+    pub name_pattern: Option<Regex>,
+    /// File path as analyzed, with `/` separators.
+    pub path_pattern: Option<Regex>,
+    /// Analyzer languages (`rust`, `go`) the rule applies to.
+    pub languages: Vec<String>,
+    /// Signature features the function must have.
+    pub signature: Vec<SignatureFeature>,
+    /// Stub kinds exempted.
+    pub kinds: Vec<HollowBodyKind>,
+}
+
+impl StubExemption {
+    /// Returns true if this rule exempts a `kind` stub of `decl` in the file of `facts`.
+    pub fn matches(&self, decl: &Declaration, facts: &FileFacts, kind: HollowBodyKind) -> bool {
+        let signature = decl.body.as_ref().map(|b| b.signature.as_slice()).unwrap_or_default();
+        (self.kinds.is_empty() || self.kinds.contains(&kind))
+            && (self.languages.is_empty() || self.languages.contains(&facts.language))
+            && self.signature.iter().all(|f| signature.contains(f))
+            && self.name_pattern.as_ref().is_none_or(|re| re.is_match(&decl.name))
+            && self.path_pattern.as_ref().is_none_or(|re| re.is_match(&facts.path.replace('\\', "/")))
+    }
+}
+
+/// A built-in stub exemption, compiled by [`default_stub_exemptions`].
+struct BuiltinExemption {
+    id: &'static str,
+    name_pattern: Option<&'static str>,
+    path_pattern: Option<&'static str>,
+    languages: &'static [&'static str],
+    signature: &'static [SignatureFeature],
+    kinds: &'static [HollowBodyKind],
+}
+
+impl BuiltinExemption {
+    const fn rust(id: &'static str, kinds: &'static [HollowBodyKind]) -> Self {
+        Self { id, name_pattern: None, path_pattern: None, languages: &["rust"], signature: &[], kinds }
+    }
+
+    const fn name(self, pattern: &'static str) -> Self {
+        Self { name_pattern: Some(pattern), ..self }
+    }
+
+    const fn path(self, pattern: &'static str) -> Self {
+        Self { path_pattern: Some(pattern), ..self }
+    }
+
+    const fn signature(self, signature: &'static [SignatureFeature]) -> Self {
+        Self { signature, ..self }
+    }
+
+    fn compile(&self) -> StubExemption {
+        let pattern = |p: Option<&str>| p.map(|p| Regex::new(p).expect("built-in stub exemption"));
+        StubExemption {
+            id: self.id.to_string(),
+            name_pattern: pattern(self.name_pattern),
+            path_pattern: pattern(self.path_pattern),
+            languages: self.languages.iter().map(|l| l.to_string()).collect(),
+            signature: self.signature.to_vec(),
+            kinds: self.kinds.to_vec(),
+        }
+    }
+}
+
+const EMPTY: &[HollowBodyKind] = &[HollowBodyKind::Empty];
+
+/// The built-in stub exemptions, in the order they are documented.
+///
+/// These cover Rust idioms that look hollow but are not (platform and mock
+/// stubs, compile-time trait bound checks, no-op metric counters and hooks,
+/// methods on intentionally empty types) and the no-op methods Kubernetes
+/// interfaces require.
+const BUILTIN_EXEMPTIONS: &[BuiltinExemption] = &[
+    // Platform stubs and mocks in stub.rs/mock.rs files or stub/ and mock/ directories
+    BuiltinExemption::rust("rust-stub-files", &[HollowBodyKind::Empty, HollowBodyKind::PanicOnly])
+        .path(r"(?i)((stubs?|mocks?|noop)\.rs|/(stub|mock)/.*\.rs)$"),
+    // Compile-time trait bound checks: fn check_send<T: Send>() {}
+    BuiltinExemption::rust("rust-trait-bound-checks", EMPTY)
+        .name(r"^(check_(send|sync|unpin|static)|is_(send|sync|unpin|debug)|_assert)"),
+    BuiltinExemption::rust("rust-generic-bound-checks", EMPTY)
+        .name(r"^(check_|is_|_)")
+        .signature(&[SignatureFeature::GenericBounds]),
+    // Callbacks that ignore a parameter: fn initialize(_: Internal) {}
+    BuiltinExemption::rust("rust-ignored-params", EMPTY).signature(&[SignatureFeature::IgnoredParams]),
+    // Counters and metrics compiled to no-ops when the feature is off
+    BuiltinExemption::rust("rust-noop-metrics", EMPTY)
+        .name(r"^(inc|dec|add|record|log)_")
+        .path(r"(?i)(counter|metric|stats).*\.rs$"),
+    // Trait methods whose default behavior is to do nothing
+    BuiltinExemption::rust("rust-default-trait-methods", EMPTY)
+        .name(r"^(initialize|finalize|default|new|consume|flush|clear|reset|wake|drop|close)$"),
+    // Intentionally unused functions, callbacks, and hooks
+    BuiltinExemption::rust("rust-underscore-functions", EMPTY).name(r"^_"),
+    BuiltinExemption::rust("rust-callbacks", EMPTY).name(r"retain|callback|handler"),
+    BuiltinExemption::rust("rust-lifecycle-hooks", EMPTY).name(r"^(post|pre|unhandled)_"),
+    BuiltinExemption::rust("rust-on-hooks", EMPTY).name(r"^on_"),
+    // Methods on Empty, Noop, Dummy, ... types
+    BuiltinExemption::rust("rust-empty-types", EMPTY).path(r"(?i)(empty|noop|void|unit|null|dummy).*\.rs$"),
+    // Optional hooks of Kubernetes API machinery interfaces, in any language
+    BuiltinExemption {
+        id: "kubernetes-noop-methods",
+        name_pattern: Some(
+            r"(?i)(canonicalize|destroy|preparefor|enablemetrics|setallocated|setavailable|incrementallocations)",
+        ),
+        path_pattern: None,
+        languages: &[],
+        signature: &[],
+        kinds: EMPTY,
+    },
+];
+
+/// Built-in stub exemptions, used unless a contract removes or replaces them.
+pub fn default_stub_exemptions() -> Vec<StubExemption> {
+    BUILTIN_EXEMPTIONS.iter().map(BuiltinExemption::compile).collect()
+}
+
+/// Ids of the built-in stub exemptions.
+pub fn builtin_stub_exemption_ids() -> impl Iterator<Item = &'static str> {
+    BUILTIN_EXEMPTIONS.iter().map(|e| e.id)
+}
+
+/// Default ratio of distinct to total statements below which a body is
+/// repetitive.
+pub const DEFAULT_MIN_DISTINCT_RATIO: f64 = 0.2;
+
+/// Default fewest statements a body needs before its repetition is checked.
+pub const DEFAULT_REPETITIVE_MIN_STATEMENTS: usize = 10;
+
+/// Number of distinct statements among `statements`, comparing their text
+/// with digit runs replaced by `#` and whitespace collapsed.
+///
+/// `items[0] = v;` and `items[1] = v;` are the same statement, so a body
+/// pasted with an incremented index counts once however long it is.
+pub(crate) fn distinct_statement_count(parsed: &ParsedFile, statements: &[tree_sitter::Node]) -> usize {
+    statements
+        .iter()
+        .map(|stmt| {
+            let mut normalized = String::new();
+            let mut in_digits = false;
+            for word in parsed.node_text(*stmt).split_whitespace() {
+                if !normalized.is_empty() {
+                    normalized.push(' ');
+                }
+                for c in word.chars() {
+                    if c.is_ascii_digit() {
+                        if !in_digits {
+                            normalized.push('#');
+                        }
+                        in_digits = true;
+                    } else {
+                        normalized.push(c);
+                        in_digits = false;
+                    }
+                }
+                in_digits = false;
+            }
+            normalized
+        })
+        .collect::<std::collections::HashSet<_>>()
+        .len()
 }
 
 /// Configuration for stub detection.
@@ -80,10 +333,20 @@ pub struct StubDetectorConfig {
     /// Minimum complexity threshold - functions below this are flagged.
     /// Set to 0 to disable complexity-based detection.
     pub min_complexity: i32,
+    /// Bodies of at least `repetitive_min_statements` statements whose
+    /// ratio of distinct to total statements is below this are flagged as
+    /// repetitive. Set to 0 to disable repetition detection.
+    pub min_distinct_ratio: f64,
+    /// Fewest statements a body needs before its repetition is checked.
+    pub repetitive_min_statements: usize,
     /// Skip functions with these exact names.
     pub skip_functions: Vec<String>,
     /// Skip methods on these receiver types.
     pub skip_receivers: Vec<String>,
+    /// Allow rules for intentional stubs.
+    pub exemptions: Vec<StubExemption>,
+    /// Project stub idioms; checked before the built-in kinds.
+    pub custom_signatures: Vec<StubSignature>,
 }
 
 impl Default for StubDetectorConfig {
@@ -94,15 +357,23 @@ impl Default for StubDetectorConfig {
             detect_nil_return: false, // Disabled by default - many legitimate returns nil
             detect_todo_comment: true,
             min_complexity: 0,
+            min_distinct_ratio: DEFAULT_MIN_DISTINCT_RATIO,
+            repetitive_min_statements: DEFAULT_REPETITIVE_MIN_STATEMENTS,
             skip_functions: vec![
                 "main".to_string(),
                 "init".to_string(),
             ],
             skip_receivers: vec![],
+            exemptions: default_stub_exemptions(),
+            custom_signatures: vec![],
         }
     }
 }
 
+/// A file's stub findings and each function with a body paired with its
+/// cyclomatic complexity, which says when it was capped or not computed.
+pub type StubAnalysis = (Vec<StubFinding>, Vec<(Declaration, Complexity)>);
+
 /// Stub detector that analyzes function bodies for hollow implementations.
 pub struct StubDetector {
     config: StubDetectorConfig,
@@ -142,7 +413,15 @@ impl StubDetector {
             }
 
             if let Some(ref body) = decl.body {
-                if let Some(kind) = self.classify_body(body) {
+                let custom_signature = self.match_custom_signature(body, &facts.language);
+                let kind = match custom_signature {
+                    Some(_) => Some(HollowBodyKind::CustomSignature),
+                    None => self.classify_body(body),
+                };
+                if let Some(kind) = kind {
+                    if self.config.exemptions.iter().any(|e| e.matches(decl, facts, kind)) {
+                        continue;
+                    }
                     findings.push(StubFinding {
                         name: decl.name.clone(),
                         qualified_name: decl.qualified_name(),
@@ -150,6 +429,7 @@ impl StubDetector {
                         span: decl.span.clone(),
                         kind,
                         body_text: body.text.clone(),
+                        custom_signature,
                     });
                 }
             }
@@ -161,6 +441,15 @@ impl StubDetector {
         findings
     }
 
+    /// Parse a single file and return its stub findings and per-function complexity.
+    ///
+    /// Needs only the analyzers registered (`hollowcheck::init()`), not a
+    /// contract or runner. Findings carry the path as given. To analyze many
+    /// files with caching, use [`AnalysisContext::analyze_stubs`].
+    pub fn analyze_file<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<StubAnalysis> {
+        AnalysisContext::new("").analyze_stubs(path, self)
+    }
+
     /// Detect stubs across multiple files.
     pub fn detect_all(&self, facts: &[FileFacts]) -> Vec<StubFinding> {
         let mut all_findings = Vec::new();
@@ -178,6 +467,17 @@ impl StubDetector {
         all_findings
     }
 
+    /// The first custom signature for `language` that the body's only
+    /// statement matches, with its index.
+    fn match_custom_signature(&self, body: &FunctionBody, language: &str) -> Option<(usize, SoleStatement)> {
+        let statement = body.sole_statement.as_ref()?;
+        self.config
+            .custom_signatures
+            .iter()
+            .position(|sig| sig.language == language && sig.matches(statement))
+            .map(|index| (index, statement.clone()))
+    }
+
     /// Classify a function body as a stub type, if applicable.
     fn classify_body(&self, body: &FunctionBody) -> Option<HollowBodyKind> {
         // Check TODO comment first - a body with only TODO comment has is_empty=true
@@ -199,8 +499,21 @@ impl StubDetector {
             return Some(HollowBodyKind::NilReturnOnly);
         }
 
+        if self.is_repetitive(body) {
+            return Some(HollowBodyKind::Repetitive);
+        }
+
         None
     }
+
+    /// Whether a body is long enough to check and has too few distinct
+    /// statements for its length.
+    fn is_repetitive(&self, body: &FunctionBody) -> bool {
+        let total = body.statement_count;
+        self.config.min_distinct_ratio > 0.0
+            && total >= self.config.repetitive_min_statements.max(1)
+            && (body.distinct_statement_count as f64) < self.config.min_distinct_ratio * total as f64
+    }
 }
 
 impl Default for StubDetector {
@@ -221,6 +534,27 @@ mod tests {
             package: Some("main".to_string()),
             declarations: decls,
             imports: vec![],
+            string_literals: vec![],
+            discarded_calls: vec![],
+            noop_statements: vec![],
+            logged_rethrows: vec![],
+            unused_parameters: vec![],
+            unassigned_results: vec![],
+            parameter_defaults: vec![],
+            constant_groups: vec![],
+            function_signatures: vec![],
+            call_sites: vec![],
+            deprecations: vec![],
+            forwarding_calls: vec![],
+            unwrap_usage: vec![],
+            force_unwraps: vec![],
+            switches: Vec::new(),
+            implementations: vec![],
+            callable_types: vec![],
+            header_comment: None,
+            is_generated: false,
+            lines: Default::default(),
+            truncated_function: None,
             has_parse_errors: false,
             parse_error: None,
         }
@@ -239,7 +573,13 @@ mod tests {
                 end_col: 1,
             },
             receiver: None,
+            is_async: false,
+            param_count: None,
             body: Some(body),
+            members: Vec::new(),
+            annotations: Vec::new(),
+            is_exported: false,
+            has_doc: false,
         }
     }
 
@@ -259,12 +599,16 @@ mod tests {
                 end_col: 1,
             },
             statement_count: if is_empty { 0 } else { 1 },
+            distinct_statement_count: if is_empty { 0 } else { 1 },
             is_empty,
             is_panic_only,
             is_nil_return_only,
             has_only_todo_comment,
+            await_count: 0,
             text: "{}".to_string(),
             control_flow: ControlFlowInfo::default(),
+            signature: Vec::new(),
+            sole_statement: None,
         }
     }
 
@@ -348,6 +692,77 @@ mod tests {
         assert_eq!(findings[0].kind, HollowBodyKind::NilReturnOnly);
     }
 
+    #[test]
+    fn test_default_exemptions_cover_rust_idioms() {
+        let detector = StubDetector::new();
+        let detect = |path: &str, name: &str, body: FunctionBody| {
+            let mut facts = make_facts(vec![make_decl(name, body)]);
+            facts.path = path.to_string();
+            if path.ends_with(".rs") {
+                facts.language = "rust".to_string();
+            }
+            detector.detect(&facts).len()
+        };
+        let empty = || make_body(true, false, false, false);
+        let panic = || make_body(false, true, false, false);
+        let with_signature = |signature: &[SignatureFeature]| FunctionBody {
+            signature: signature.to_vec(),
+            ..empty()
+        };
+
+        assert_eq!(detect("src/lib.rs", "on_shutdown", empty()), 0);
+        assert_eq!(detect("src/lib.rs", "check_send_sync", empty()), 0);
+        assert_eq!(detect("src/metrics.rs", "inc_requests", empty()), 0);
+        assert_eq!(detect("src/platform/stub.rs", "spawn", panic()), 0);
+        // Only empty bodies are exempt by name, and only in Rust files
+        assert_eq!(detect("src/lib.rs", "on_shutdown", panic()), 1);
+        assert_eq!(detect("src/hooks.go", "on_shutdown", empty()), 1);
+        assert_eq!(detect("src/lib.rs", "inc_requests", empty()), 1);
+
+        // Signature idioms the Rust analyzer records
+        assert_eq!(detect("src/lib.rs", "check_bounds", with_signature(&[SignatureFeature::GenericBounds])), 0);
+        assert_eq!(detect("src/lib.rs", "check_bounds", empty()), 1);
+        assert_eq!(detect("src/lib.rs", "load", with_signature(&[SignatureFeature::IgnoredParams])), 0);
+        assert_eq!(detect("src/hooks.go", "load", with_signature(&[SignatureFeature::IgnoredParams])), 1);
+
+        // Kubernetes no-op hooks apply in every language
+        assert_eq!(detect("pkg/registry/rest.go", "PrepareForCreate", empty()), 0);
+    }
+
+    #[test]
+    fn test_builtin_exemption_ids_are_unique() {
+        let ids: Vec<_> = builtin_stub_exemption_ids().collect();
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(ids.len(), unique.len());
+        assert!(ids.contains(&"rust-on-hooks"));
+    }
+
+    #[test]
+    fn test_custom_exemptions_replace_defaults() {
+        let config = StubDetectorConfig {
+            exemptions: vec![StubExemption {
+                id: "go-event-hooks".to_string(),
+                name_pattern: Some(Regex::new("^On[A-Z]").unwrap()),
+                path_pattern: Some(Regex::new(r"/handlers/.*\.go$").unwrap()),
+                languages: vec![],
+                signature: vec![],
+                kinds: vec![HollowBodyKind::Empty],
+            }],
+            ..Default::default()
+        };
+        let detector = StubDetector::with_config(config);
+        let detect = |path: &str, name: &str| {
+            let mut facts = make_facts(vec![make_decl(name, make_body(true, false, false, false))]);
+            facts.path = path.to_string();
+            detector.detect(&facts).len()
+        };
+
+        assert_eq!(detect("/repo/handlers/events.go", "OnCreate"), 0);
+        assert_eq!(detect("/repo/handlers/events.go", "Create"), 1);
+        assert_eq!(detect("/repo/models/events.go", "OnCreate"), 1);
+        assert_eq!(detect("src/lib.rs", "on_shutdown"), 1);
+    }
+
     #[test]
     fn test_no_findings_for_real_implementation() {
         let detector = StubDetector::new();
@@ -359,4 +774,114 @@ mod tests {
         let findings = detector.detect(&facts);
         assert_eq!(findings.len(), 0);
     }
+
+    #[test]
+    fn test_analyze_file() {
+        crate::analysis::register_analyzers();
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("payments.go");
+        std::fs::write(
+            &file,
+            r#"package payments
+
+func Charge(amount int) error {
+	panic("not implemented")
+}
+
+func Total(items []int) int {
+	total := 0
+	for _, item := range items {
+		if item > 0 {
+			total += item
+		}
+	}
+	return total
+}
+"#,
+        )
+        .unwrap();
+
+        let (findings, complexity) = StubDetector::new().analyze_file(&file).unwrap();
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].name, "Charge");
+        assert_eq!(findings[0].kind, HollowBodyKind::PanicOnly);
+        assert_eq!(findings[0].file, file.to_string_lossy());
+
+        let complexity: Vec<_> = complexity.iter().map(|(d, c)| (d.name.as_str(), *c)).collect();
+        assert_eq!(complexity, vec![("Charge", Complexity::Counted(1)), ("Total", Complexity::Counted(3))]);
+
+        let err = StubDetector::new().analyze_file(temp.path().join("notes.txt")).unwrap_err();
+        assert!(err.to_string().starts_with("no analyzer for"));
+    }
+
+    #[test]
+    fn test_analyze_stubs_reports_complexity_not_computed() {
+        crate::analysis::register_analyzers();
+
+        let mut source = String::from("fn decode(op: u16) -> u16 {\n    match op {\n");
+        for i in 0..200 {
+            source.push_str(&format!("        {} => {},\n", i, i * 2));
+        }
+        source.push_str("        _ => 0,\n    }\n}\n\nfn encode(op: u16) -> u16 {\n    op + 1\n}\n");
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("opcodes.rs");
+        std::fs::write(&file, source).unwrap();
+
+        let ctx = AnalysisContext::new(temp.path()).with_max_body_nodes(100);
+        let (_, complexity) = ctx.analyze_stubs(&file, &StubDetector::new()).unwrap();
+        let complexity: Vec<_> = complexity.iter().map(|(d, c)| (d.name.as_str(), c.to_string())).collect();
+        assert_eq!(
+            complexity,
+            vec![
+                ("decode", "complexity: not computed, body too large".to_string()),
+                ("encode", "complexity 1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_repetitive_body() {
+        crate::analysis::register_analyzers();
+
+        let mut source = String::from("def fill(table):\n");
+        for i in 0..12 {
+            source.push_str(&format!("    table[{}] = compute({})\n", i, i * 10));
+        }
+        source.push_str("\n\ndef build(rows):\n");
+        for i in 0..12 {
+            source.push_str(&format!("    row{} = rows.get('r{}')\n    check(row{})\n", i, i, i));
+        }
+        source.push_str("\n\ndef short(t):\n");
+        for i in 0..4 {
+            source.push_str(&format!("    t[{}] = 0\n", i));
+        }
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("tables.py");
+        std::fs::write(&file, &source).unwrap();
+
+        let (findings, _) = StubDetector::new().analyze_file(&file).unwrap();
+        let found: Vec<_> = findings.iter().map(|f| (f.name.as_str(), f.kind)).collect();
+        assert_eq!(
+            found,
+            vec![("fill", HollowBodyKind::Repetitive), ("build", HollowBodyKind::Repetitive)]
+        );
+
+        // Raising the bar on length, or disabling the ratio, lets them through
+        let lenient = |min_distinct_ratio: f64, repetitive_min_statements: usize| {
+            StubDetector::with_config(StubDetectorConfig {
+                min_distinct_ratio,
+                repetitive_min_statements,
+                ..StubDetectorConfig::default()
+            })
+            .analyze_file(&file)
+            .unwrap()
+            .0
+            .len()
+        };
+        assert_eq!(lenient(0.0, 10), 0);
+        assert_eq!(lenient(0.2, 20), 1);
+    }
 }
@@ -0,0 +1,149 @@
+//! Shared extraction of switch and match statements.
+//!
+//! Each analyzer lists the node kinds of its grammar in a `SwitchSyntax`;
+//! the walk collecting case labels and judging the default arm is shared.
+//! Labels keep their qualifier (`Status.Active`, `Status::Active`) so rules
+//! can tell which enum a switch is over.
+
+use tree_sitter::Node;
+
+use super::{DefaultArm, ParsedFile, Span, SwitchStatement};
+
+/// Grammar-specific node kinds for switch statements.
+pub(crate) struct SwitchSyntax {
+    /// Switch or match node kinds; the scrutinized value is their `value` field.
+    pub switch_kinds: &'static [&'static str],
+    /// Case or arm node kinds.
+    pub case_kinds: &'static [&'static str],
+    /// Field of a case node holding its label(s).
+    pub label_field: &'static str,
+    /// Default arm node kinds (`default:`); an arm whose only label is `_` is also one.
+    pub default_kinds: &'static [&'static str],
+}
+
+/// Label wrapper kinds whose children are the individual labels.
+const LABEL_LIST_KINDS: &[&str] = &["expression_list", "or_pattern", "match_pattern"];
+
+/// Block kinds whose statements are the arm body.
+const BLOCK_KINDS: &[&str] = &["block", "statement_block"];
+
+/// Default arm bodies that do nothing, after trailing `;` are removed.
+const TRIVIAL_BODIES: &[&str] = &[
+    "",
+    "break",
+    "continue",
+    "return",
+    "return nil",
+    "return nil, nil",
+    "return null",
+    "return undefined",
+    "return None",
+    "return false",
+    "return \"\"",
+    "{}",
+    "()",
+    "None",
+    "Ok(())",
+];
+
+/// Collect switch statements from a parsed file, in source order.
+pub(crate) fn extract_switches(parsed: &ParsedFile, syntax: &SwitchSyntax) -> Vec<SwitchStatement> {
+    let mut switches = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        if syntax.switch_kinds.contains(&node.kind()) {
+            if let Some(switch) = switch_statement(parsed, node, syntax) {
+                switches.push(switch);
+            }
+        }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    switches.sort_by_key(|s| s.span.start_byte);
+    switches
+}
+
+fn switch_statement(parsed: &ParsedFile, node: Node, syntax: &SwitchSyntax) -> Option<SwitchStatement> {
+    let value = node.child_by_field_name("value")?;
+    let subject = parsed
+        .node_text(value)
+        .trim_start_matches('(')
+        .trim_end_matches(')')
+        .trim()
+        .to_string();
+
+    let mut cases = Vec::new();
+    let mut default_arm = None;
+    let mut stack = vec![node];
+    while let Some(current) = stack.pop() {
+        let mut cursor = current.walk();
+        for child in current.named_children(&mut cursor) {
+            if syntax.default_kinds.contains(&child.kind()) {
+                default_arm = Some(default(parsed, child, None));
+            } else if syntax.case_kinds.contains(&child.kind()) {
+                let label = child.child_by_field_name(syntax.label_field);
+                let labels = label.map(|l| labels(parsed, l)).unwrap_or_default();
+                if labels == ["_"] {
+                    default_arm = Some(default(parsed, child, label));
+                } else {
+                    cases.extend(labels);
+                }
+            } else if !syntax.switch_kinds.contains(&child.kind()) && child.id() != value.id() {
+                // Case lists are wrapped in a body node in some grammars
+                stack.push(child);
+            }
+        }
+    }
+
+    Some(SwitchStatement {
+        subject,
+        cases,
+        default_arm,
+        span: Span::from_node(node),
+    })
+}
+
+/// Labels of one case, with call arguments and struct fields removed
+/// (`Shape::Circle(r)` becomes `Shape::Circle`).
+fn labels(parsed: &ParsedFile, node: Node) -> Vec<String> {
+    if LABEL_LIST_KINDS.contains(&node.kind()) && node.named_child_count() > 0 {
+        let mut cursor = node.walk();
+        return node
+            .named_children(&mut cursor)
+            .flat_map(|child| labels(parsed, child))
+            .collect();
+    }
+    let text = parsed.node_text(node);
+    let end = text.find(['(', '{']).unwrap_or(text.len());
+    vec![text[..end].trim().to_string()]
+}
+
+fn default(parsed: &ParsedFile, node: Node, label: Option<Node>) -> DefaultArm {
+    let mut cursor = node.walk();
+    let mut body: Vec<Node> = node
+        .named_children(&mut cursor)
+        .filter(|c| Some(c.id()) != label.map(|l| l.id()) && !c.kind().ends_with("comment"))
+        .collect();
+    if let [block] = body.as_slice() {
+        if BLOCK_KINDS.contains(&block.kind()) {
+            let block = *block;
+            let mut cursor = block.walk();
+            body = block
+                .named_children(&mut cursor)
+                .filter(|c| !c.kind().ends_with("comment"))
+                .collect();
+        }
+    }
+
+    let text = match body.as_slice() {
+        [] => String::new(),
+        [statement] => parsed.node_text(*statement).trim().trim_end_matches(';').trim().to_string(),
+        _ => return DefaultArm { is_trivial: false, span: Span::from_node(node) },
+    };
+    DefaultArm {
+        is_trivial: TRIVIAL_BODIES.contains(&text.as_str()),
+        span: Span::from_node(node),
+    }
+}
@@ -1,9 +1,63 @@
 //! Core traits for language analysis.
 
+use std::cell::Cell;
 use std::path::Path;
+use std::time::Duration;
+
+use tree_sitter::{Language, Parser};
 
 use super::FileFacts;
 
+/// Default node count above which a function body's control flow is not counted.
+pub const DEFAULT_MAX_BODY_NODES: usize = 50_000;
+
+thread_local! {
+    /// Timeout applied to parsers created on this thread.
+    static PARSE_TIMEOUT: Cell<Option<Duration>> = const { Cell::new(None) };
+    /// Body size limit applied to control-flow extraction on this thread.
+    static BODY_NODE_LIMIT: Cell<usize> = const { Cell::new(DEFAULT_MAX_BODY_NODES) };
+}
+
+/// Run `f` with tree-sitter parses started on this thread limited to `timeout`.
+///
+/// A parse that runs out of time fails like an unparseable file; callers
+/// tell the two apart by the elapsed time.
+pub fn with_parse_timeout<T>(timeout: Duration, f: impl FnOnce() -> T) -> T {
+    let previous = PARSE_TIMEOUT.with(|t| t.replace(Some(timeout)));
+    let value = f();
+    PARSE_TIMEOUT.with(|t| t.set(previous));
+    value
+}
+
+/// Run `f` with control-flow extraction on this thread skipping function
+/// bodies of more than `max_nodes` syntax nodes.
+///
+/// A generated 6,000-arm `match` costs seconds in the query cursor and
+/// says nothing about whether the function is hollow; its control flow is
+/// recorded as not computed instead.
+pub fn with_body_node_limit<T>(max_nodes: usize, f: impl FnOnce() -> T) -> T {
+    let previous = BODY_NODE_LIMIT.with(|l| l.replace(max_nodes));
+    let value = f();
+    BODY_NODE_LIMIT.with(|l| l.set(previous));
+    value
+}
+
+/// Whether a function body is over the thread's body size limit.
+pub(crate) fn exceeds_body_node_limit(body: tree_sitter::Node) -> bool {
+    body.descendant_count() > BODY_NODE_LIMIT.with(|l| l.get())
+}
+
+/// Create a parser for `language`, honoring the thread's parse timeout.
+pub(crate) fn new_parser(language: &Language) -> anyhow::Result<Parser> {
+    let mut parser = Parser::new();
+    parser.set_language(language)?;
+    if let Some(timeout) = PARSE_TIMEOUT.with(|t| t.get()) {
+        // Zero disables the timeout, so round up
+        parser.set_timeout_micros((timeout.as_micros() as u64).max(1));
+    }
+    Ok(parser)
+}
+
 /// Holds a parsed tree-sitter tree and associated metadata.
 ///
 /// This is kept separate from FileFacts to allow reusing the tree
@@ -0,0 +1,212 @@
+//! Shared detection of functions cut off at the end of the file.
+//!
+//! Generated code that ran out of tokens stops in the middle of a function.
+//! The parser then closes the body with MISSING `}` nodes, wraps the tail
+//! of the function in an ERROR node, or gives up on the rest of the file in
+//! a top-level ERROR node that still holds the function's keyword and
+//! parameter list. In each case the damage reaches the end of the file,
+//! which an ordinary syntax error rarely does. Each analyzer lists the node
+//! kinds its grammar uses in a `TruncationSyntax`; the search is shared.
+
+use tree_sitter::Node;
+
+use super::{ParsedFile, Span, TruncatedFunction};
+
+/// Grammar-specific node kinds for function headers and bodies.
+pub(crate) struct TruncationSyntax {
+    /// Function node kinds.
+    pub function_kinds: &'static [&'static str],
+    /// Keyword tokens that start a function (`func`, `def`, `fn`).
+    pub keywords: &'static [&'static str],
+    /// Parameter list kinds; in an ERROR node they mark a function header
+    /// the parser could not attach to a function node.
+    pub parameter_kinds: &'static [&'static str],
+    /// Tokens that open a function body (`{`, Python `:`).
+    pub body_openers: &'static [&'static str],
+}
+
+fn is_identifier(node: Node) -> bool {
+    node.kind().ends_with("identifier")
+}
+
+/// Name of a function node, or of the nearest named function around it.
+///
+/// C and C++ functions name themselves in a chain of declarators.
+fn function_name(parsed: &ParsedFile, function: Node, syntax: &TruncationSyntax) -> Option<String> {
+    let mut current = Some(function);
+    while let Some(node) = current {
+        if syntax.function_kinds.contains(&node.kind()) {
+            let mut name = node.child_by_field_name("name");
+            let mut declarator = node.child_by_field_name("declarator");
+            while let (None, Some(d)) = (name, declarator) {
+                name = Some(d).filter(|d| is_identifier(*d));
+                declarator = d.child_by_field_name("declarator");
+            }
+            if let Some(name) = name {
+                return Some(parsed.node_text(name).to_string());
+            }
+        }
+        current = node.parent();
+    }
+    None
+}
+
+/// The unfinished function header inside an ERROR node: the last function
+/// keyword or parameter list outside a complete function, followed by a
+/// body opener. Returns the header's first node and the function name.
+fn header_in_error<'t>(parsed: &ParsedFile, error: Node<'t>, syntax: &TruncationSyntax) -> Option<(Node<'t>, Option<String>)> {
+    let mut header: Option<Node> = None;
+    let mut opened = false;
+    let mut stack = vec![error];
+
+    // Depth-first in source order, so later nodes overwrite earlier ones
+    while let Some(node) = stack.pop() {
+        let kind = node.kind();
+        if node != error && syntax.function_kinds.contains(&kind) && !node.has_error() {
+            continue;
+        }
+        if (!node.is_named() && syntax.keywords.contains(&kind)) || syntax.parameter_kinds.contains(&kind) {
+            header = Some(node);
+            opened = false;
+            continue;
+        }
+        if !node.is_named() && syntax.body_openers.contains(&kind) && header.is_some() {
+            opened = true;
+        }
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        stack.extend(children.into_iter().rev());
+    }
+
+    let header = header.filter(|_| opened)?;
+    if syntax.parameter_kinds.contains(&header.kind()) {
+        let name = header.prev_named_sibling().filter(|n| is_identifier(*n));
+        Some((name.unwrap_or(header), name.map(|n| parsed.node_text(n).to_string())))
+    } else {
+        let name = header.next_named_sibling().filter(|n| is_identifier(*n));
+        Some((header, name.map(|n| parsed.node_text(n).to_string())))
+    }
+}
+
+/// Find a function cut off at the end of the file, if there is one.
+///
+/// Looks for an ERROR or MISSING node, or an empty function body, that
+/// reaches the last non-whitespace byte, and for the function it sits in.
+/// Syntax errors elsewhere in the file, and damage at the end outside any
+/// function, are not truncation.
+pub(crate) fn find_truncated_function(parsed: &ParsedFile, syntax: &TruncationSyntax) -> Option<TruncatedFunction> {
+    let end = parsed.source.iter().rposition(|b| !b.is_ascii_whitespace())? + 1;
+    let root = parsed.tree.root_node();
+    // The span ends at the last non-whitespace byte
+    let line_start = parsed.source[..end].iter().rposition(|&b| b == b'\n').map_or(0, |i| i + 1);
+    let end_line = parsed.source[..end].iter().filter(|&&b| b == b'\n').count() + 1;
+    let truncated = |start: Node, name: Option<String>| {
+        let mut span = Span::from_node(start);
+        span.end_byte = end;
+        span.end_line = end_line;
+        span.end_col = end - line_start + 1;
+        TruncatedFunction { name, span }
+    };
+
+    let mut stack = vec![root];
+    while let Some(node) = stack.pop() {
+        if node.end_byte() < end {
+            continue;
+        }
+        let kind = node.kind();
+
+        // A function whose body has nothing in it at the end of the file (`def f():`)
+        if syntax.function_kinds.contains(&kind) {
+            if let Some(body) = node.child_by_field_name("body") {
+                if body.start_byte() == body.end_byte() {
+                    return Some(truncated(node, function_name(parsed, node, syntax)));
+                }
+            }
+        }
+
+        if node.is_error() || node.is_missing() {
+            // Damage inside a function body
+            let mut ancestor = node.parent();
+            while let Some(candidate) = ancestor {
+                if syntax.function_kinds.contains(&candidate.kind()) {
+                    return Some(truncated(candidate, function_name(parsed, candidate, syntax)));
+                }
+                ancestor = candidate.parent();
+            }
+            if node.is_error() {
+                // A header the parser left inside the ERROR node
+                if let Some((start, name)) = header_in_error(parsed, node, syntax) {
+                    return Some(truncated(start, name));
+                }
+                // A bodiless function right before an ERROR that opens its body
+                let opens_body = node.child(0).is_some_and(|c| syntax.body_openers.contains(&c.kind()));
+                if let Some(previous) = node.prev_named_sibling() {
+                    if opens_body
+                        && syntax.function_kinds.contains(&previous.kind())
+                        && previous.child_by_field_name("body").is_none()
+                    {
+                        return Some(truncated(previous, function_name(parsed, previous, syntax)));
+                    }
+                }
+            }
+            continue;
+        }
+
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers};
+    use std::path::Path;
+
+    fn truncated(ext: &str, source: &str) -> Option<(Option<String>, usize)> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        let facts = analyzer.extract_facts(&parsed).unwrap();
+        facts.truncated_function.map(|t| (t.name, t.span.start_line))
+    }
+
+    #[test]
+    fn test_functions_cut_off_at_end_of_file() {
+        let cases = [
+            ("go", "package main\n\nfunc Done() int {\n\treturn 1\n}\n\nfunc Process(items []int) int {\n\ttotal := 0\n\tfor _, i := range items {\n\t\ttotal += i\n", "Process", 7),
+            ("py", "def process(items):\n    total = 0\n    for i in items:\n        total += (i *\n", "process", 1),
+            ("py", "class Orders:\n    def total(self):\n", "total", 2),
+            ("js", "function done() { return 1; }\n\nfunction process(items) {\n  let total = 0;\n  for (const i of items) {\n    total += i;\n", "process", 3),
+            ("ts", "class A {\n  run(): number {\n    const x = compute(\n", "run", 2),
+            ("rs", "fn done() -> i32 { 1 }\n\nfn process(items: &[i32]) -> i32 {\n    let mut total = 0;\n    for i in items {\n        total += i;\n", "process", 3),
+            ("java", "class A {\n  int run() {\n    int x = 1;\n    return x +\n", "run", 2),
+            ("c", "int run(void) {\n  int x = 1;\n  if (x) {\n", "run", 1),
+            ("swift", "func run() -> Int {\n  let x = 1\n  if x > 0 {\n", "run", 1),
+            ("scala", "object A {\n  def run(): Int = {\n    val x = 1\n", "run", 2),
+        ];
+        for (ext, source, name, line) in cases {
+            assert_eq!(truncated(ext, source), Some((Some(name.to_string()), line)), "{}: {:?}", ext, source);
+        }
+    }
+
+    #[test]
+    fn test_other_syntax_errors_are_not_truncation() {
+        let cases = [
+            // Complete files
+            ("go", "package main\n\nfunc Done() int {\n\treturn 1\n}\n"),
+            ("py", "def done():\n    return 1\n"),
+            // A syntax error before the end of the file
+            ("go", "package main\n\nfunc Bad() int {\n\treturn 1 +\n}\n\nfunc Ok() {}\n"),
+            ("js", "function bad() { return 1 +; }\n\nfunction ok() { return 2; }\n"),
+            // Damage at the end outside any function
+            ("js", "function ok() { return 2; }\n}\n"),
+            ("py", "def ok():\n    return 2\n\nitems = [1, 2,\n"),
+        ];
+        for (ext, source) in cases {
+            assert_eq!(truncated(ext, source), None, "{}: {:?}", ext, source);
+        }
+    }
+}
@@ -0,0 +1,259 @@
+//! Shared extraction of callables that only forward their parameters.
+//!
+//! `async function getUser(id) { return await users.getUser(id); }` adds a
+//! name and nothing else. A callable forwards when its body is one statement
+//! whose value, after `return`, `await`, and Rust's `?`, is a single call
+//! passing the callable's own parameters through unchanged and in order. The
+//! walk only records the shape; whether the callee is project code, and
+//! whether the method exists to satisfy an interface, is decided by the rule.
+
+use tree_sitter::Node;
+
+use super::annotations::extract_annotations;
+use super::calls::callee_name;
+use super::comments::is_comment;
+use super::inheritance::simple_type_name;
+use super::params::parameter_names;
+use super::{ForwardingCall, ParsedFile, Span};
+
+/// Wrappers around the forwarded expression that add no behavior.
+const TRANSPARENT_KINDS: &[&str] = &[
+    "return_statement",
+    "return_expression",
+    "expression_statement",
+    "await_expression",
+    "await",
+    "try_expression",
+    "parenthesized_expression",
+];
+
+/// Class-like declarations whose body makes a callable a method.
+const CLASS_KINDS: &[&str] = &[
+    "class_declaration",
+    "abstract_class_declaration",
+    "class_definition",
+    "class",
+    "enum_declaration",
+    "record_declaration",
+];
+
+/// Decorators that turn a Python method into a property accessor.
+const ACCESSOR_DECORATORS: &[&str] = &["property", "cached_property", "getter"];
+
+/// The only statement of a body, skipping a leading Python docstring.
+fn single_statement(body: Node) -> Option<Node> {
+    let mut cursor = body.walk();
+    let mut statements: Vec<Node> = body
+        .named_children(&mut cursor)
+        .filter(|c| !is_comment(*c))
+        .collect();
+    if statements.len() == 2
+        && statements[0].kind() == "expression_statement"
+        && statements[0].named_child(0).is_some_and(|s| s.kind() == "string")
+    {
+        statements.remove(0);
+    }
+    (statements.len() == 1).then(|| statements[0])
+}
+
+/// The call a body forwards to, with its callee name, if the body is a
+/// single pass-through call.
+fn forwarded_callee<'a>(
+    parsed: &'a ParsedFile,
+    body: Node,
+    params: &[String],
+    call_kinds: &[(&str, &str)],
+) -> Option<&'a str> {
+    let mut node = single_statement(body)?;
+    while TRANSPARENT_KINDS.contains(&node.kind()) {
+        node = node.named_child(0)?;
+    }
+
+    let (_, field) = call_kinds.iter().find(|(kind, _)| *kind == node.kind())?;
+    let callee = callee_name(parsed, node.child_by_field_name(field)?)?;
+    let arguments = node.child_by_field_name("arguments")?;
+
+    let mut cursor = arguments.walk();
+    let args: Vec<Node> = arguments
+        .named_children(&mut cursor)
+        .filter(|c| !is_comment(*c))
+        .collect();
+    let passes_through = args.len() == params.len()
+        && args
+            .iter()
+            .zip(params)
+            .all(|(arg, param)| arg.kind() == "identifier" && parsed.node_text(*arg) == param);
+    passes_through.then_some(callee)
+}
+
+/// The enclosing type of a method, and whether it is a Rust trait impl.
+fn receiver(parsed: &ParsedFile, node: Node, declaration_kinds: &[&str]) -> (Option<String>, bool) {
+    for ancestor in std::iter::successors(node.parent(), |n| n.parent()) {
+        if ancestor.kind() == "impl_item" {
+            let name = ancestor
+                .child_by_field_name("type")
+                .map(|t| simple_type_name(parsed.node_text(t)).to_string());
+            return (name, ancestor.child_by_field_name("trait").is_some());
+        }
+        if CLASS_KINDS.contains(&ancestor.kind()) {
+            let name = ancestor
+                .child_by_field_name("name")
+                .map(|n| parsed.node_text(n).to_string());
+            return (name, false);
+        }
+        // A function nested in another callable is not a method
+        if declaration_kinds.contains(&ancestor.kind()) {
+            break;
+        }
+    }
+    (None, false)
+}
+
+/// Whether a callable is a property accessor: a JavaScript or TypeScript
+/// `get` method, or a Python method decorated with `@property`.
+fn is_accessor(parsed: &ParsedFile, node: Node) -> bool {
+    let mut cursor = node.walk();
+    let is_getter = node.children(&mut cursor).any(|c| c.kind() == "get");
+    is_getter
+        || extract_annotations(parsed, node).iter().any(|a| {
+            let name = a.rsplit('.').next().unwrap_or(a);
+            ACCESSOR_DECORATORS.contains(&name)
+        })
+}
+
+/// Collect callables whose body only forwards their parameters to another
+/// call, in source order.
+///
+/// `declaration_kinds` lists the callable node kinds to check, each with
+/// `name` and `body` fields; `call_kinds` pairs call node kinds with their
+/// callee field, as for call sites.
+pub(crate) fn extract_forwarding_calls(
+    parsed: &ParsedFile,
+    declaration_kinds: &[&str],
+    call_kinds: &[(&str, &str)],
+) -> Vec<ForwardingCall> {
+    let mut forwarding = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+
+        if !declaration_kinds.contains(&node.kind()) {
+            continue;
+        }
+        let (Some(name), Some(body)) = (node.child_by_field_name("name"), node.child_by_field_name("body")) else {
+            continue;
+        };
+        let Some(params) = parameter_names(parsed, node) else {
+            continue;
+        };
+        let Some(callee) = forwarded_callee(parsed, body, &params, call_kinds) else {
+            continue;
+        };
+
+        let (receiver, in_trait_impl) = receiver(parsed, node, declaration_kinds);
+        forwarding.push(ForwardingCall {
+            name: parsed.node_text(name).to_string(),
+            callee: callee.to_string(),
+            receiver,
+            in_trait_impl,
+            is_accessor: is_accessor(parsed, node),
+            span: Span::from_node(node),
+        });
+    }
+
+    forwarding.sort_by_key(|f| f.span.start_byte);
+    forwarding
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::{get_analyzer, register_analyzers, ForwardingCall};
+    use std::path::Path;
+
+    fn forwarding(ext: &str, source: &str) -> Vec<ForwardingCall> {
+        register_analyzers();
+        let analyzer = get_analyzer(ext).unwrap();
+        let path = format!("test.{}", ext);
+        let parsed = analyzer.parse(Path::new(&path), source.as_bytes()).unwrap();
+        analyzer.extract_facts(&parsed).unwrap().forwarding_calls
+    }
+
+    fn summary(ext: &str, source: &str) -> Vec<(String, String)> {
+        forwarding(ext, source)
+            .into_iter()
+            .map(|f| (f.name, f.callee))
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_forwarding_calls_per_language() {
+        type Case<'a> = (&'a str, &'a str, &'a [(&'a str, &'a str)]);
+        let cases: [Case; 5] = [
+            (
+                "js",
+                "async function getUserById(id) { return await userService.getUserById(id); }\n\
+                 function save(user, opts) { return store.save(opts, user); }\n\
+                 function load(id) { log(id); return store.load(id); }\n\
+                 function find(id) { return store.find(id, true); }\n",
+                &[("getUserById", "getUserById")],
+            ),
+            (
+                "ts",
+                "export function fetchOrder(id: string, opts?: Options): Promise<Order> {\n  return api.fetchOrder(id, opts);\n}\nfunction all(...ids: string[]) { return load(...ids); }\n",
+                &[("fetchOrder", "fetchOrder")],
+            ),
+            (
+                "py",
+                "class Users:\n    def get(self, user_id):\n        \"\"\"Get a user.\"\"\"\n        return self.repo.get(user_id)\n\n    def put(self, user):\n        return self.repo.put(user=user)\n\nasync def load(key):\n    return await cache.load(key)\n",
+                &[("get", "get"), ("load", "load")],
+            ),
+            (
+                "java",
+                "class Orders {\n    Order find(long id) { return repository.findById(id); }\n    void save(Order o) { repository.save(o); }\n    int count() { return repository.count() + 1; }\n}\n",
+                &[("find", "findById"), ("save", "save")],
+            ),
+            (
+                "rs",
+                "fn parse(input: &str) -> Result<Ast> {\n    Ok(parser::parse(input)?)\n}\n\nfn load(path: &Path) -> Result<Config> {\n    config::load(path)\n}\n\nasync fn fetch(url: Url) -> Result<Body> {\n    http::fetch(url).await\n}\n",
+                &[("load", "load"), ("fetch", "fetch")],
+            ),
+        ];
+
+        for (ext, source, expected) in cases {
+            let expected: Vec<_> = expected
+                .iter()
+                .map(|(n, c)| (n.to_string(), c.to_string()))
+                .collect();
+            assert_eq!(summary(ext, source), expected, "{}", ext);
+        }
+    }
+
+    #[test]
+    fn test_receivers_and_accessors() {
+        let rust = forwarding(
+            "rs",
+            "impl Display for Name {\n    fn fmt(&self, f: &mut Formatter) -> fmt::Result {\n        write_name(f)\n    }\n}\n\nimpl Store {\n    fn get(&self, key: Key) -> Option<Value> {\n        self.inner.get(key)\n    }\n}\n",
+        );
+        let summary: Vec<_> = rust
+            .iter()
+            .map(|f| (f.name.as_str(), f.receiver.as_deref(), f.in_trait_impl))
+            .collect();
+        assert_eq!(summary, vec![("fmt", Some("Name"), true), ("get", Some("Store"), false)]);
+
+        let js = forwarding(
+            "js",
+            "class User {\n  get name() { return this.profile.name(); }\n  email() { return this.profile.email(); }\n}\n",
+        );
+        let summary: Vec<_> = js.iter().map(|f| (f.name.as_str(), f.is_accessor)).collect();
+        assert_eq!(summary, vec![("name", true), ("email", false)]);
+
+        let py = forwarding(
+            "py",
+            "class User:\n    @property\n    def name(self):\n        return self.profile.name()\n",
+        );
+        assert!(py[0].is_accessor);
+        assert_eq!(py[0].receiver.as_deref(), Some("User"));
+    }
+}
@@ -0,0 +1,10 @@
+//! `cargo hollowcheck` entry point.
+//!
+//! Cargo runs this binary for `cargo hollowcheck`; it is the same CLI as
+//! `hollowcheck`, which recognizes the subcommand name Cargo passes first.
+
+use hollowcheck::cli;
+
+fn main() {
+    std::process::exit(cli::run(cli::parse_args()));
+}
@@ -0,0 +1,217 @@
+//! Crash- and race-safe storage for the user-level disk caches.
+//!
+//! Several hollowcheck processes (parallel CI jobs, editor integrations) share
+//! one cache directory. A plain `fs::write` lets a reader observe a half
+//! written file, and since cache loaders accept anything that parses, a
+//! truncated file would be trusted until its TTL expired.
+//!
+//! Files written here are therefore:
+//! 1. Written to a uniquely named temp file in the same directory, then
+//!    atomically renamed over the destination, so readers only ever see a
+//!    complete old file or a complete new one.
+//! 2. Terminated by a footer recording the entry count and a checksum of the
+//!    body. `read_verified` rejects files whose footer is missing or does not
+//!    match, and callers treat that as a cache miss.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Prefix of the footer line that closes every cache file.
+const FOOTER_PREFIX: &str = "# hollowcheck-cache";
+
+/// Counter that keeps temp file names unique between threads of one process.
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// 64-bit FNV-1a. Stable across Rust versions, unlike `DefaultHasher`, so
+/// files written by one hollowcheck build verify under another; violation
+/// fingerprints use it for the same reason.
+pub(crate) fn checksum(data: &[u8]) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in data {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// Number of entry lines in a body (blank lines and `#` comments excluded).
+fn count_entries(body: &str) -> usize {
+    body.lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+        .count()
+}
+
+fn footer(body: &str) -> String {
+    format!(
+        "{} entries={} checksum={:016x}\n",
+        FOOTER_PREFIX,
+        count_entries(body),
+        checksum(body.as_bytes())
+    )
+}
+
+/// Atomically replace `path` with `body` followed by a verification footer.
+///
+/// The parent directory is created if needed. When several writers race,
+/// the last rename wins and every reader sees one complete version.
+pub(crate) fn write_atomic(path: &Path, body: &str) -> io::Result<()> {
+    let dir = path
+        .parent()
+        .filter(|p| !p.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    fs::create_dir_all(dir)?;
+
+    let file_name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| "cache".to_string());
+    let temp_path = dir.join(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let body = normalized(body);
+    let write_result = (|| {
+        let mut file = fs::File::create(&temp_path)?;
+        file.write_all(body.as_bytes())?;
+        file.write_all(footer(&body).as_bytes())?;
+        file.sync_all()
+    })();
+
+    let result = write_result.and_then(|()| match fs::rename(&temp_path, path) {
+        Ok(()) => Ok(()),
+        // On platforms where rename cannot replace an existing file, a
+        // concurrent writer already put a complete copy in place.
+        Err(_) if path.exists() => Ok(()),
+        Err(e) => Err(e),
+    });
+
+    if result.is_err() || temp_path.exists() {
+        let _ = fs::remove_file(&temp_path);
+    }
+    result
+}
+
+/// Body as it is stored on disk: newline-terminated unless empty.
+fn normalized(body: &str) -> std::borrow::Cow<'_, str> {
+    if body.is_empty() || body.ends_with('\n') {
+        body.into()
+    } else {
+        format!("{}\n", body).into()
+    }
+}
+
+/// Read a file written by `write_atomic`, returning its body.
+///
+/// Returns `None` if the file is missing, lacks a footer, or its entry
+/// count or checksum does not match the body.
+pub(crate) fn read_verified(path: &Path) -> Option<String> {
+    let content = fs::read_to_string(path).ok()?;
+    let trimmed = content.strip_suffix('\n')?;
+    let footer_start = trimmed.rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let (body, footer_line) = content.split_at(footer_start);
+
+    if footer_line != footer(body) {
+        return None;
+    }
+    Some(body.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("nested/dir/modules.txt");
+
+        write_atomic(&path, "# version: 1\nfmt\nos\n").unwrap();
+        assert_eq!(
+            read_verified(&path).as_deref(),
+            Some("# version: 1\nfmt\nos\n")
+        );
+
+        // Missing trailing newline is normalized
+        write_atomic(&path, "exists:1").unwrap();
+        assert_eq!(read_verified(&path).as_deref(), Some("exists:1\n"));
+    }
+
+    #[test]
+    fn test_rejects_truncated_and_unverified_files() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("go_stdlib.txt");
+
+        write_atomic(&path, "fmt\nnet/http\nos\n").unwrap();
+        let full = fs::read_to_string(&path).unwrap();
+
+        // Truncated mid-body: footer missing
+        fs::write(&path, &full[..8]).unwrap();
+        assert!(read_verified(&path).is_none());
+
+        // Entry removed but footer kept: checksum mismatch
+        fs::write(&path, full.replace("net/http\n", "")).unwrap();
+        assert!(read_verified(&path).is_none());
+
+        // Legacy file without footer
+        fs::write(&path, "fmt\nos\n").unwrap();
+        assert!(read_verified(&path).is_none());
+
+        assert!(read_verified(&temp.path().join("missing.txt")).is_none());
+    }
+
+    #[test]
+    fn test_concurrent_writers_and_readers() {
+        let temp = TempDir::new().unwrap();
+        let path = Arc::new(temp.path().join("shared.txt"));
+
+        let bodies: Arc<Vec<String>> = Arc::new(
+            (0..6)
+                .map(|w| {
+                    (0..500)
+                        .map(|i| format!("writer{}-module{}\n", w, i))
+                        .collect()
+                })
+                .collect(),
+        );
+
+        let mut handles = Vec::new();
+        for writer in 0..6 {
+            let path = Arc::clone(&path);
+            let bodies = Arc::clone(&bodies);
+            handles.push(std::thread::spawn(move || {
+                for _ in 0..50 {
+                    write_atomic(&path, &bodies[writer]).unwrap();
+                }
+            }));
+        }
+        for _ in 0..6 {
+            let path = Arc::clone(&path);
+            let bodies = Arc::clone(&bodies);
+            handles.push(std::thread::spawn(move || {
+                for _ in 0..200 {
+                    // Files are never removed, so once one exists every read must verify
+                    let existed = path.exists();
+                    match read_verified(&path) {
+                        Some(body) => assert!(bodies.contains(&body), "corrupted read"),
+                        None => assert!(!existed, "complete file failed verification"),
+                    }
+                }
+            }));
+        }
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(bodies.contains(&read_verified(&path).unwrap()));
+        // No temp files left behind
+        let leftovers = fs::read_dir(temp.path()).unwrap().count();
+        assert_eq!(leftovers, 1);
+    }
+}
@@ -0,0 +1,176 @@
+//! Running as a Cargo subcommand: `cargo hollowcheck`.
+//!
+//! Cargo runs `cargo hollowcheck <args>` as `cargo-hollowcheck hollowcheck
+//! <args>`, so an invocation is recognized by its first argument. Under
+//! Cargo the subcommand defaults to `lint` and the path to the workspace
+//! root, the way `cargo audit` and `cargo deny` behave from any member
+//! directory.
+//!
+//! `--package` resolution asks `cargo metadata` for the workspace members and
+//! is only available with the `cargo` feature, so builds for other ecosystems
+//! do not carry the dependency.
+
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+use crate::workspace::{find_workspace, WorkspaceKind};
+
+/// The first argument Cargo passes to the `cargo-hollowcheck` binary.
+pub const SUBCOMMAND: &str = "hollowcheck";
+
+/// Returns true if `args` (including the program name) come from `cargo hollowcheck`.
+pub fn is_subcommand_invocation(args: &[OsString]) -> bool {
+    args.get(1).is_some_and(|arg| arg == SUBCOMMAND)
+}
+
+/// Rewrite a `cargo hollowcheck` command line to the standalone form.
+///
+/// Drops the subcommand name Cargo passes along and inserts `lint` when no
+/// hollowcheck subcommand follows, so `cargo hollowcheck --format json` is
+/// `hollowcheck lint --format json`. `--help` and `--version` stay global.
+pub fn standalone_args(mut args: Vec<OsString>, subcommands: &[String]) -> Vec<OsString> {
+    if is_subcommand_invocation(&args) {
+        args.remove(1);
+    }
+    let names_subcommand = args.get(1).is_some_and(|arg| {
+        let arg = arg.to_string_lossy();
+        subcommands.iter().any(|s| *s == arg)
+            || ["-h", "--help", "-V", "--version"].contains(&arg.as_ref())
+    });
+    if !names_subcommand {
+        args.insert(1.min(args.len()), OsString::from("lint"));
+    }
+    args
+}
+
+/// The Cargo workspace root for a run started in `dir`.
+///
+/// That is the enclosing `[workspace]`, or else the nearest directory with a
+/// `Cargo.toml`. Returns `None` outside a Cargo project.
+pub fn workspace_root(dir: &Path) -> Option<PathBuf> {
+    if let Some(ws) = find_workspace(dir).filter(|ws| ws.kind == WorkspaceKind::Cargo) {
+        return Some(ws.root);
+    }
+    dir.ancestors()
+        .find(|d| d.join("Cargo.toml").is_file())
+        .map(Path::to_path_buf)
+}
+
+/// A Cargo package findings are attributed to in `--message-format json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Package {
+    pub name: String,
+    /// Package ID as `cargo metadata` reports it
+    pub id: String,
+    pub manifest_path: PathBuf,
+}
+
+impl Package {
+    /// Directory containing the package manifest.
+    pub fn dir(&self) -> &Path {
+        self.manifest_path.parent().unwrap_or(Path::new("."))
+    }
+
+    /// The package a scan of `dir` covers when no `--package` was given.
+    ///
+    /// Named after the directory, since reading the manifest's name needs
+    /// `cargo metadata`.
+    pub fn for_dir(dir: &Path) -> Self {
+        let name = dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self {
+            id: format!("path+file://{}#{}", dir.display(), name),
+            name,
+            manifest_path: dir.join("Cargo.toml"),
+        }
+    }
+}
+
+/// Find the workspace member named `name`, asking `cargo metadata` from `dir`.
+#[cfg(feature = "cargo")]
+pub fn find_package(dir: &Path, name: &str) -> anyhow::Result<Package> {
+    let metadata = cargo_metadata::MetadataCommand::new()
+        .current_dir(dir)
+        .no_deps()
+        .exec()
+        .map_err(|e| anyhow::anyhow!("cargo metadata failed: {}", e))?;
+    metadata
+        .workspace_packages()
+        .into_iter()
+        .find(|p| p.name == name)
+        .map(|p| Package {
+            name: p.name.clone(),
+            id: p.id.repr.clone(),
+            manifest_path: p.manifest_path.clone().into_std_path_buf(),
+        })
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "package {:?} is not a member of the workspace at {}",
+                name,
+                metadata.workspace_root
+            )
+        })
+}
+
+/// Find the workspace member named `name`; needs the `cargo` feature.
+#[cfg(not(feature = "cargo"))]
+pub fn find_package(_dir: &Path, name: &str) -> anyhow::Result<Package> {
+    anyhow::bail!(
+        "cannot resolve --package {:?}: hollowcheck was built without the `cargo` feature",
+        name
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<OsString> {
+        list.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn test_standalone_args() {
+        let subcommands = vec!["lint".to_string(), "check".to_string(), "init".to_string()];
+        assert!(is_subcommand_invocation(&args(&["cargo-hollowcheck", "hollowcheck"])));
+        assert!(!is_subcommand_invocation(&args(&["hollowcheck", "lint"])));
+
+        let cases: &[(&[&str], &[&str])] = &[
+            (&["cargo-hollowcheck", "hollowcheck"], &["cargo-hollowcheck", "lint"]),
+            (
+                &["cargo-hollowcheck", "hollowcheck", "-p", "worker"],
+                &["cargo-hollowcheck", "lint", "-p", "worker"],
+            ),
+            (&["cargo-hollowcheck", "hollowcheck", "check", "."], &["cargo-hollowcheck", "check", "."]),
+            (&["cargo-hollowcheck", "hollowcheck", "init"], &["cargo-hollowcheck", "init"]),
+            (&["cargo-hollowcheck", "hollowcheck", "--version"], &["cargo-hollowcheck", "--version"]),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(standalone_args(args(input), &subcommands), args(expected), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_workspace_root() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("crates/worker/src")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n").unwrap();
+        std::fs::write(root.join("crates/worker/Cargo.toml"), "[package]\nname = \"worker\"\n").unwrap();
+
+        assert_eq!(workspace_root(&root.join("crates/worker/src")), Some(root.to_path_buf()));
+
+        std::fs::write(root.join("Cargo.toml"), "[package]\nname = \"app\"\n").unwrap();
+        std::fs::create_dir(root.join(".git")).unwrap();
+        assert_eq!(
+            workspace_root(&root.join("crates/worker/src")),
+            Some(root.join("crates/worker"))
+        );
+
+        let package = Package::for_dir(&root.join("crates/worker"));
+        assert_eq!(package.name, "worker");
+        assert_eq!(package.dir(), root.join("crates/worker"));
+    }
+}
@@ -0,0 +1,286 @@
+//! CI entrypoint: platform detection and output plans for `hollowcheck ci`.
+//!
+//! The platform is read from the environment variables each CI system sets
+//! (the same ones the stdlib cache uses to detect CI). Each platform gets
+//! outputs that its UI picks up without a wrapper script:
+//!
+//! - GitHub Actions: workflow command annotations on stdout, a Markdown job
+//!   summary in `$GITHUB_STEP_SUMMARY`, and a SARIF file for code scanning.
+//! - GitLab CI: a code quality report artifact.
+//! - Jenkins and others: a JSON report at a well-known path.
+//!
+//! On pull and merge requests, the target branch becomes the `--changed-since`
+//! ref so only violations in changed files are reported.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// CI system the run is executing on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Platform {
+    GitHub,
+    GitLab,
+    Jenkins,
+    Generic,
+}
+
+impl Platform {
+    /// Detect the platform from environment variables.
+    pub fn detect(env: impl Fn(&str) -> Option<String>) -> Self {
+        if env("GITHUB_ACTIONS").is_some() {
+            Platform::GitHub
+        } else if env("GITLAB_CI").is_some() {
+            Platform::GitLab
+        } else if env("JENKINS_URL").is_some() {
+            Platform::Jenkins
+        } else {
+            Platform::Generic
+        }
+    }
+
+    /// Parse a platform name as given to `--platform`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "github" | "github-actions" => Some(Platform::GitHub),
+            "gitlab" | "gitlab-ci" => Some(Platform::GitLab),
+            "jenkins" => Some(Platform::Jenkins),
+            "generic" => Some(Platform::Generic),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Platform::GitHub => "github",
+            Platform::GitLab => "gitlab",
+            Platform::Jenkins => "jenkins",
+            Platform::Generic => "generic",
+        }
+    }
+
+    /// Base ref of the pull or merge request being built, if the platform
+    /// exposes one.
+    ///
+    /// Branch names are returned as `origin/<branch>`, since CI checkouts
+    /// rarely have a local branch for the target.
+    pub fn base_ref(&self, env: impl Fn(&str) -> Option<String>) -> Option<String> {
+        let env = |name: &str| env(name).filter(|v| !v.is_empty());
+        match self {
+            Platform::GitHub => env("GITHUB_BASE_REF").map(|b| format!("origin/{}", b)),
+            Platform::GitLab => env("CI_MERGE_REQUEST_DIFF_BASE_SHA").or_else(|| {
+                env("CI_MERGE_REQUEST_TARGET_BRANCH_NAME").map(|b| format!("origin/{}", b))
+            }),
+            Platform::Jenkins => env("CHANGE_TARGET").map(|b| format!("origin/{}", b)),
+            Platform::Generic => None,
+        }
+    }
+}
+
+impl fmt::Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a `hollowcheck ci` run will do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CiPlan {
+    pub platform: Platform,
+    /// Format written to stdout: `github` annotations, `pretty`, or `json`.
+    pub console_format: String,
+    /// Format of the report artifact: `sarif`, `gitlab`, or `json`.
+    pub artifact_format: String,
+    /// Where the report artifact is written.
+    pub artifact_path: PathBuf,
+    /// Markdown job summary file, appended to.
+    pub summary_path: Option<PathBuf>,
+    /// Only report violations in files changed since this ref.
+    pub changed_since: Option<String>,
+}
+
+impl CiPlan {
+    /// Default plan for the platform in the environment.
+    pub fn detect(env: impl Fn(&str) -> Option<String>) -> Self {
+        Self::for_platform(Platform::detect(&env), env)
+    }
+
+    /// Default plan for a given platform.
+    pub fn for_platform(platform: Platform, env: impl Fn(&str) -> Option<String>) -> Self {
+        let changed_since = platform.base_ref(&env);
+        match platform {
+            Platform::GitHub => Self {
+                platform,
+                console_format: "github".to_string(),
+                artifact_format: "sarif".to_string(),
+                artifact_path: PathBuf::from("hollowcheck.sarif"),
+                summary_path: env("GITHUB_STEP_SUMMARY")
+                    .filter(|p| !p.is_empty())
+                    .map(PathBuf::from),
+                changed_since,
+            },
+            Platform::GitLab => Self {
+                platform,
+                console_format: "pretty".to_string(),
+                artifact_format: "gitlab".to_string(),
+                artifact_path: PathBuf::from("gl-code-quality-report.json"),
+                summary_path: None,
+                changed_since,
+            },
+            Platform::Jenkins | Platform::Generic => Self {
+                platform,
+                console_format: "pretty".to_string(),
+                artifact_format: "json".to_string(),
+                artifact_path: PathBuf::from("hollowcheck-report.json"),
+                summary_path: None,
+                changed_since,
+            },
+        }
+    }
+}
+
+impl fmt::Display for CiPlan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "platform:      {}", self.platform)?;
+        writeln!(f, "console:       {}", self.console_format)?;
+        writeln!(
+            f,
+            "artifact:      {} ({})",
+            self.artifact_path.display(),
+            self.artifact_format
+        )?;
+        match &self.summary_path {
+            Some(path) => writeln!(f, "summary:       {}", path.display())?,
+            None => writeln!(f, "summary:       none")?,
+        }
+        match &self.changed_since {
+            Some(base) => write!(f, "changed since: {}", base),
+            None => write!(f, "changed since: full scan"),
+        }
+    }
+}
+
+pub(crate) fn git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| anyhow::anyhow!("cannot run git: {}", e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Resolve a ref to a commit, or None if the repository does not have it
+/// (for example the target branch in a shallow clone).
+pub fn resolve_ref(dir: &Path, git_ref: &str) -> Option<String> {
+    git(dir, &["rev-parse", "--verify", "--quiet", &format!("{}^{{commit}}", git_ref)]).ok()
+}
+
+/// Files changed between the merge base of `git_ref` and HEAD, including
+/// uncommitted and untracked files. Paths are absolute; deleted files are
+/// left out.
+pub fn changed_files(dir: &Path, git_ref: &str) -> anyhow::Result<HashSet<PathBuf>> {
+    let toplevel = PathBuf::from(git(dir, &["rev-parse", "--show-toplevel"])?);
+    let merge_base = git(dir, &["merge-base", git_ref, "HEAD"])?;
+    let changed = git(
+        dir,
+        &["diff", "--name-only", "--no-renames", "--diff-filter=d", &merge_base],
+    )?;
+    let untracked = git(dir, &["ls-files", "--others", "--exclude-standard", "--full-name"])?;
+    Ok(changed
+        .lines()
+        .chain(untracked.lines())
+        .filter(|l| !l.is_empty())
+        .map(|name| {
+            let path = toplevel.join(name);
+            path.canonicalize().unwrap_or(path)
+        })
+        .collect())
+}
+
+/// Tracked files whose working copy has modifications not yet staged.
+///
+/// Paths are absolute. Outside a git repository there is nothing to
+/// protect, so the set is empty.
+pub fn unstaged_files(dir: &Path) -> anyhow::Result<HashSet<PathBuf>> {
+    let Ok(toplevel) = git(dir, &["rev-parse", "--show-toplevel"]) else {
+        return Ok(HashSet::new());
+    };
+    let toplevel = PathBuf::from(toplevel);
+    let modified = git(dir, &["diff", "--name-only", "--no-renames"])?;
+    Ok(modified
+        .lines()
+        .filter(|l| !l.is_empty())
+        .map(|name| {
+            let path = toplevel.join(name);
+            path.canonicalize().unwrap_or(path)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn test_github_pull_request_plan() {
+        let plan = CiPlan::detect(env(&[
+            ("CI", "true"),
+            ("GITHUB_ACTIONS", "true"),
+            ("GITHUB_BASE_REF", "main"),
+            ("GITHUB_STEP_SUMMARY", "/tmp/summary.md"),
+        ]));
+        assert_eq!(plan.platform, Platform::GitHub);
+        assert_eq!(plan.console_format, "github");
+        assert_eq!(plan.artifact_format, "sarif");
+        assert_eq!(plan.summary_path, Some(PathBuf::from("/tmp/summary.md")));
+        assert_eq!(plan.changed_since.as_deref(), Some("origin/main"));
+
+        // Push builds set GITHUB_BASE_REF to an empty string
+        let plan = CiPlan::detect(env(&[("GITHUB_ACTIONS", "true"), ("GITHUB_BASE_REF", "")]));
+        assert_eq!(plan.changed_since, None);
+    }
+
+    #[test]
+    fn test_gitlab_and_generic_plans() {
+        let plan = CiPlan::detect(env(&[
+            ("GITLAB_CI", "true"),
+            ("CI_MERGE_REQUEST_TARGET_BRANCH_NAME", "develop"),
+        ]));
+        assert_eq!(plan.platform, Platform::GitLab);
+        assert_eq!(plan.artifact_format, "gitlab");
+        assert_eq!(plan.artifact_path, PathBuf::from("gl-code-quality-report.json"));
+        assert_eq!(plan.changed_since.as_deref(), Some("origin/develop"));
+
+        // The diff base SHA is preferred over the branch name
+        let plan = CiPlan::detect(env(&[
+            ("GITLAB_CI", "true"),
+            ("CI_MERGE_REQUEST_DIFF_BASE_SHA", "abc123"),
+            ("CI_MERGE_REQUEST_TARGET_BRANCH_NAME", "develop"),
+        ]));
+        assert_eq!(plan.changed_since.as_deref(), Some("abc123"));
+
+        let plan = CiPlan::detect(env(&[("CI", "true")]));
+        assert_eq!(plan.platform, Platform::Generic);
+        assert_eq!(plan.artifact_format, "json");
+        assert_eq!(plan.changed_since, None);
+        assert!(plan.to_string().contains("changed since: full scan"));
+    }
+}
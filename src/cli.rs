@@ -1,17 +1,39 @@
 //! Command-line interface for hollowcheck.
 
-use clap::{Parser, Subcommand};
+use clap::{CommandFactory, FromArgMatches, Parser, Subcommand};
 use colored::*;
 use indicatif::{ProgressBar, ProgressStyle};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
-use walkdir::WalkDir;
 
-use crate::contract::{self, Contract};
-use crate::detect::Runner;
+use crate::ci::{self, CiPlan, Platform};
+use crate::contract::{self, Contract, ValueSource};
+use crate::policy::{self, RemoteContract};
+use crate::prefetch;
+use crate::diff;
+use crate::discovery::FileDiscovery;
+use crate::doctor;
+use crate::events::{Event, EventStream, Phase};
+use crate::theme::{Theme, THEME_ENV};
+use crate::user_config::UserConfig;
+use crate::fleet::{self, FleetRepo, RepoScan};
+use crate::detect::{
+    extract_imports, insert_suppression_comments, waiver_entries,
+    DependencyValidator, DetectionResult, ManifestType, Runner, Severity, StubDetectionConfig, TraceVerdict, Violation,
+    ViolationRule, WaiverStatus, DEFAULT_EXPIRY_WINDOW,
+};
+use crate::migrate;
 use crate::parser;
+use crate::patch::PatchSet;
+use crate::registry::RegistryType;
 use crate::report;
 use crate::score;
+use crate::spec::{self, TaskSpec};
+use crate::workspace::find_workspace;
 
 /// Exit codes.
 pub const EXIT_SUCCESS: i32 = 0;
@@ -39,29 +61,140 @@ pub struct Cli {
 pub enum Commands {
     /// Check code quality against a contract
     #[command(visible_alias = "check")]
-    Lint(LintArgs),
+    Lint(Box<LintArgs>),
     /// Create a new hollowcheck contract from a template
     Init(InitArgs),
+    /// Trace how the dependency check decides on one import in a file
+    WhyImport(WhyImportArgs),
+    /// Compare two JSON reports: violations added, removed, or moved
+    Diff(DiffArgs),
+    /// Lint in CI with the outputs the detected CI platform expects
+    Ci(CiArgs),
+    /// Check this build and environment: analyzers, runtimes, caches, and registries
+    Doctor(DoctorArgs),
+    /// List the contract's waivers: active, expiring, and expired
+    Waivers(WaiversArgs),
+    /// Convert a contract or report from the Go version of hollowcheck
+    Migrate(MigrateArgs),
+    /// Scan every repository in a manifest and report on the whole fleet
+    Fleet(FleetArgs),
+    /// Fill the registry and stdlib caches for a tree's imports, without linting
+    Prefetch(PrefetchArgs),
+    /// Check a delivery against a task specification: files, symbols, subcommands, and routes
+    VerifySpec(VerifySpecArgs),
+}
+
+/// Parse the process arguments, including `cargo hollowcheck` invocations.
+pub fn parse_args() -> Cli {
+    let args: Vec<OsString> = std::env::args_os().collect();
+    if crate::cargo::is_subcommand_invocation(&args) {
+        parse_cargo_args(args)
+    } else {
+        Cli::parse_from(args)
+    }
+}
+
+/// Check a `--language` value, so an unknown name is reported against the flag.
+fn parse_language(name: &str) -> Result<String, String> {
+    match crate::analysis::normalize_language(name) {
+        Some(_) => Ok(name.to_string()),
+        None => Err(format!("unknown language {:?}", name)),
+    }
+}
+
+/// Parse a `cargo hollowcheck` command line.
+///
+/// `lint` is the default subcommand and the Cargo workspace root enclosing
+/// the working directory the default path.
+fn parse_cargo_args(args: Vec<OsString>) -> Cli {
+    let mut subcommands = vec!["help".to_string()];
+    for command in Cli::command().get_subcommands() {
+        subcommands.push(command.get_name().to_string());
+        subcommands.extend(command.get_all_aliases().map(String::from));
+    }
+    let args = crate::cargo::standalone_args(args, &subcommands);
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let root = crate::cargo::workspace_root(&cwd).unwrap_or(cwd);
+    let command = Cli::command()
+        .bin_name("cargo hollowcheck")
+        .mut_subcommand("lint", |lint| {
+            lint.mut_arg("path", |path| path.required(false).default_value(root.into_os_string()))
+        });
+    let mut cli = Cli::from_arg_matches(&command.get_matches_from(args)).unwrap_or_else(|e| e.exit());
+    if let Commands::Lint(ref mut lint) = cli.command {
+        lint.cargo = true;
+    }
+    cli
+}
+
+/// Run a parsed command line, returning the process exit code.
+pub fn run(cli: Cli) -> i32 {
+    let result = match cli.command {
+        Commands::Lint(args) => run_lint(&args),
+        Commands::Init(args) => run_init(&args),
+        Commands::WhyImport(args) => run_why_import(&args),
+        Commands::Diff(args) => run_diff(&args),
+        Commands::Ci(args) => run_ci(&args),
+        Commands::Doctor(args) => run_doctor(&args),
+        Commands::Waivers(args) => run_waivers(&args),
+        Commands::Migrate(args) => run_migrate(&args),
+        Commands::Fleet(args) => run_fleet(&args),
+        Commands::Prefetch(args) => run_prefetch(&args),
+        Commands::VerifySpec(args) => run_verify_spec(&args),
+    };
+    result.unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        EXIT_ERROR
+    })
 }
 
 /// Arguments for the lint command.
-#[derive(Parser)]
+#[derive(Parser, Clone)]
 pub struct LintArgs {
     /// Path to check (file or directory)
     pub path: PathBuf,
 
-    /// Path to contract YAML file (default: auto-discover)
+    /// Path or HTTPS URL of the contract YAML file (default: auto-discover)
     #[arg(short, long)]
     pub contract: Option<PathBuf>,
 
-    /// Output format: pretty, json, or sarif
-    #[arg(short, long, default_value = "pretty")]
+    /// SHA-256 pin of a contract given as a URL
+    #[arg(long, value_name = "HASH")]
+    pub contract_sha256: Option<String>,
+
+    /// Output format: auto, pretty, json, sarif, github, gitlab, or editor (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
     pub format: String,
 
+    /// Keep the Go version's JSON schema (project-level violations get an empty file, not null)
+    #[arg(long)]
+    pub go_compat: bool,
+
+    /// Pretty output theme: default, colorblind, or mono (default: $HOLLOWCHECK_THEME, then the user config)
+    #[arg(long, value_name = "THEME")]
+    pub theme: Option<String>,
+
     /// Maximum acceptable hollowness score (exit non-zero if exceeded)
     #[arg(short, long)]
     pub threshold: Option<i32>,
 
+    /// Contract profile to merge over the base settings (default: $HOLLOWCHECK_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Fail if any contract waiver expires within DAYS days (default: 14) or has expired
+    #[arg(long, value_name = "DAYS", num_args = 0..=1, default_missing_value = "14")]
+    pub strict_waivers: Option<i64>,
+
+    /// Record at most N violations of each rule in the report (the rest are still scored)
+    #[arg(long, value_name = "N")]
+    pub max_violations_per_rule: Option<usize>,
+
+    /// Record at most N violations in the report (the rest are still scored)
+    #[arg(long, value_name = "N")]
+    pub max_violations_total: Option<usize>,
+
     /// Analysis mode: code (default) or prose
     #[arg(short, long)]
     pub mode: Option<String>,
@@ -70,10 +203,30 @@ pub struct LintArgs {
     #[arg(long)]
     pub show_suppressed: bool,
 
+    /// Show each violation's stable fingerprint (for the suppression file)
+    #[arg(long)]
+    pub show_fingerprints: bool,
+
+    /// List enabled rules that found nothing in the breakdown, with 0 points
+    #[arg(long)]
+    pub show_clean_rules: bool,
+
     /// Skip dependency verification (registry lookups)
     #[arg(long)]
     pub skip_registry_check: bool,
 
+    /// Run only this rule (repeatable, e.g. --only mock_data --only stub_function)
+    #[arg(long = "only", value_name = "RULE", conflicts_with = "skip_rules")]
+    pub only_rules: Vec<ViolationRule>,
+
+    /// Do not run this rule (repeatable, e.g. --skip hallucinated_dependency to stay offline)
+    #[arg(long = "skip", value_name = "RULE")]
+    pub skip_rules: Vec<ViolationRule>,
+
+    /// Attach a dependency pipeline trace to each hallucinated_dependency violation (JSON output)
+    #[arg(long)]
+    pub explain_dependencies: bool,
+
     /// Use strict thresholds for AI-generated code (more aggressive detection)
     #[arg(long)]
     pub strict: bool,
@@ -89,6 +242,95 @@ pub struct LintArgs {
     /// Include files matching these patterns even if they would normally be excluded
     #[arg(long = "include", value_name = "PATTERN")]
     pub include_patterns: Vec<String>,
+
+    /// Scan dependency and build directories (node_modules, vendor, target, .venv, ...)
+    #[arg(long)]
+    pub no_default_excludes: bool,
+
+    /// Only analyze these languages (comma-separated or repeated, e.g. go,python)
+    #[arg(long = "language", value_name = "LANG", value_delimiter = ',', value_parser = parse_language)]
+    pub languages: Vec<String>,
+
+    /// List the files that would be scanned and the analyzer for each, then exit
+    #[arg(long)]
+    pub list_files: bool,
+
+    /// Print the effective contract after extends, profile, and flags are applied, then exit
+    #[arg(long)]
+    pub config_dump: bool,
+
+    /// Only report violations in files changed since this git ref (merge base to working tree)
+    #[arg(long, value_name = "REF")]
+    pub changed_since: Option<String>,
+
+    /// Compare against a JSON report from an earlier `lint --format json` run, recording new violations (path or http(s) URL)
+    #[arg(long, value_name = "REPORT")]
+    pub baseline: Option<PathBuf>,
+
+    /// With --baseline, report and gate on new violations only; pre-existing ones are summarized in one line
+    #[arg(long, requires = "baseline")]
+    pub only_new: bool,
+
+    /// Print a single status line (`hollowcheck: 3E 5W 1I (D, 42%)`) instead of a report
+    #[arg(long)]
+    pub severity_summary: bool,
+
+    /// With --severity-summary, print nothing when there are no violations
+    #[arg(short, long)]
+    pub quiet: bool,
+
+    /// Print only `rule_id: count` lines and the total instead of a report
+    #[arg(long, conflicts_with = "severity_summary")]
+    pub count_only: bool,
+
+    /// Scan only this Cargo workspace member's directory (needs the `cargo` feature)
+    #[arg(short, long, value_name = "NAME")]
+    pub package: Option<String>,
+
+    /// Report like cargo: `human` diagnostics with code frames on stderr, or `json` message lines on stdout
+    #[arg(long, value_name = "FMT")]
+    pub message_format: Option<String>,
+
+    /// Insert an ignore-next-line comment above every reported violation, after showing the diff
+    #[arg(long, conflicts_with_all = ["severity_summary", "count_only", "message_format"])]
+    pub fix_suppressions: bool,
+
+    /// With --fix-suppressions, apply the changes without asking
+    #[arg(short, long, requires = "fix_suppressions")]
+    pub yes: bool,
+
+    /// With --fix-suppressions, print the diff and write nothing
+    #[arg(long, requires = "fix_suppressions")]
+    pub diff_only: bool,
+
+    /// With --fix-suppressions, write files that have unstaged changes
+    #[arg(long, requires = "fix_suppressions")]
+    pub force: bool,
+
+    /// Re-run the scan whenever a file under the path changes, until interrupted
+    #[arg(long, conflicts_with_all = ["fix_suppressions", "list_files", "config_dump"])]
+    pub watch: bool,
+
+    /// With --watch, serve the latest JSON report on ADDR (`GET /report`, `GET /events`); needs the `serve` feature
+    #[arg(long, value_name = "ADDR", requires = "watch")]
+    pub serve: Option<String>,
+
+    /// Write progress events in this format (jsonl) to stderr, one per line; stdout keeps only the report
+    #[arg(long, value_name = "FORMAT")]
+    pub events: Option<String>,
+
+    /// With --events, write the events to this file descriptor instead of stderr
+    #[arg(long, value_name = "N", requires = "events")]
+    pub events_fd: Option<i32>,
+
+    /// Set when invoked as `cargo hollowcheck`
+    #[arg(skip)]
+    pub cargo: bool,
+
+    /// Set for `fleet` scans: discover the contract from the scan path only,
+    /// never from the current directory
+    #[arg(skip)]
+    pub contract_from_scan_path: bool,
 }
 
 /// Arguments for the init command.
@@ -107,6 +349,256 @@ pub struct InitArgs {
     pub list: bool,
 }
 
+/// Arguments for the why-import command.
+#[derive(Parser)]
+pub struct WhyImportArgs {
+    /// Source file containing the import
+    pub file: PathBuf,
+
+    /// Import as extracted by hollowcheck (e.g. `requests`, `github.com/pkg/errors`)
+    pub import: String,
+
+    /// Project directory the lint run would scan (default: current directory)
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+
+    /// Path to contract YAML file (default: auto-discover)
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// Stop before the registry lookup
+    #[arg(long)]
+    pub skip_registry_check: bool,
+
+    /// Print the trace as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// Arguments for the doctor command.
+#[derive(Parser)]
+pub struct DoctorArgs {
+    /// Path to contract YAML file, for the registries to probe (default: auto-discover)
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// Output format: auto, pretty, or json (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
+    pub format: String,
+
+    /// Do not probe package registries
+    #[arg(long)]
+    pub offline: bool,
+}
+
+/// Arguments for the waivers command.
+#[derive(Parser)]
+pub struct WaiversArgs {
+    /// Path to contract YAML file (default: auto-discover)
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// Output format: auto, pretty, or json (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
+    pub format: String,
+
+    /// Days before expiry at which a waiver is listed as expiring
+    #[arg(long, value_name = "DAYS", default_value_t = DEFAULT_EXPIRY_WINDOW)]
+    pub within: i64,
+}
+
+/// Arguments for the migrate command.
+#[derive(Parser)]
+pub struct MigrateArgs {
+    #[command(subcommand)]
+    pub command: MigrateCommand,
+}
+
+#[derive(Subcommand)]
+pub enum MigrateCommand {
+    /// Convert a Go-version contract YAML to the current schema
+    Contract(MigrateFileArgs),
+    /// Upgrade a Go-version JSON report so `diff` can compare it
+    Report(MigrateFileArgs),
+}
+
+/// Arguments for the migrate subcommands.
+#[derive(Parser)]
+pub struct MigrateFileArgs {
+    /// File written for the Go version
+    pub path: PathBuf,
+
+    /// Write the result to this file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for the diff command.
+#[derive(Parser)]
+pub struct DiffArgs {
+    /// JSON report of the base revision
+    #[arg(long)]
+    pub base: PathBuf,
+
+    /// JSON report of the head revision
+    #[arg(long)]
+    pub head: PathBuf,
+
+    /// Output format: auto, pretty, or json (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
+    pub format: String,
+}
+
+/// Arguments for the fleet command.
+#[derive(Parser)]
+pub struct FleetArgs {
+    /// YAML manifest listing the checked-out repositories to scan
+    #[arg(short, long)]
+    pub manifest: PathBuf,
+
+    /// Output format: auto, pretty, or json (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
+    pub format: String,
+
+    /// Repositories scanned at once (default: the manifest's `jobs`, or the number of CPUs)
+    #[arg(short, long, value_name = "N")]
+    pub jobs: Option<usize>,
+
+    /// Write each repository's full JSON report to DIR/<name>.json
+    #[arg(long, value_name = "DIR")]
+    pub reports_dir: Option<PathBuf>,
+
+    /// Contract profile to merge over each repository's contract (default: $HOLLOWCHECK_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Skip dependency verification (registry lookups)
+    #[arg(long)]
+    pub skip_registry_check: bool,
+}
+
+/// Arguments for the prefetch command.
+#[derive(Parser)]
+pub struct PrefetchArgs {
+    /// Path whose imports are prefetched (file or directory)
+    pub path: PathBuf,
+
+    /// Path to contract YAML file, for exclusions and registry settings (default: auto-discover)
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// Contract profile to merge over the base contract (default: $HOLLOWCHECK_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Output format: auto, pretty, or json (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
+    pub format: String,
+}
+
+/// Arguments for the verify-spec command.
+#[derive(Parser)]
+pub struct VerifySpecArgs {
+    /// Path to the task specification JSON file
+    pub spec: PathBuf,
+
+    /// Path of the delivery to check (default: current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Path to contract YAML file, for exclusions and stub settings (default: auto-discover)
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// Contract profile to merge over the base contract (default: $HOLLOWCHECK_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Output format: auto, pretty, or json (auto: pretty on a terminal, JSON otherwise)
+    #[arg(short, long, default_value = "auto")]
+    pub format: String,
+
+    /// Completion percentage required to pass (default: the spec's min_completion, or 100)
+    #[arg(long, value_name = "PERCENT")]
+    pub min_completion: Option<f64>,
+}
+
+/// Arguments for the ci command.
+#[derive(Parser)]
+pub struct CiArgs {
+    /// Path to check (default: current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Path or HTTPS URL of the contract YAML file (default: auto-discover)
+    #[arg(short, long)]
+    pub contract: Option<PathBuf>,
+
+    /// SHA-256 pin of a contract given as a URL
+    #[arg(long, value_name = "HASH")]
+    pub contract_sha256: Option<String>,
+
+    /// CI platform: github, gitlab, jenkins, or generic (default: detect from the environment)
+    #[arg(long)]
+    pub platform: Option<String>,
+
+    /// Format written to stdout: github, pretty, or json
+    #[arg(long)]
+    pub console: Option<String>,
+
+    /// Report artifact format: sarif, gitlab, or json
+    #[arg(short, long)]
+    pub format: Option<String>,
+
+    /// Report artifact path
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Markdown job summary file to append to (GitHub: $GITHUB_STEP_SUMMARY)
+    #[arg(long)]
+    pub summary: Option<PathBuf>,
+
+    /// Do not write a job summary
+    #[arg(long)]
+    pub no_summary: bool,
+
+    /// Only report violations in files changed since this git ref (default: the PR base)
+    #[arg(long, value_name = "REF")]
+    pub changed_since: Option<String>,
+
+    /// Report violations in all files, even on a pull request
+    #[arg(long, conflicts_with = "changed_since")]
+    pub full_scan: bool,
+
+    /// Maximum acceptable hollowness score (exit non-zero if exceeded)
+    #[arg(short, long)]
+    pub threshold: Option<i32>,
+
+    /// Contract profile to merge over the base settings (default: $HOLLOWCHECK_PROFILE)
+    #[arg(long, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Fail if any contract waiver expires within DAYS days (default: 14) or has expired
+    #[arg(long, value_name = "DAYS", num_args = 0..=1, default_missing_value = "14")]
+    pub strict_waivers: Option<i64>,
+
+    /// Record at most N violations of each rule in the report (the rest are still scored)
+    #[arg(long, value_name = "N")]
+    pub max_violations_per_rule: Option<usize>,
+
+    /// Record at most N violations in the report (the rest are still scored)
+    #[arg(long, value_name = "N")]
+    pub max_violations_total: Option<usize>,
+
+    /// Skip dependency verification (registry lookups)
+    #[arg(long)]
+    pub skip_registry_check: bool,
+
+    /// Print the resolved plan and exit without scanning
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
 /// Available contract templates.
 struct Template {
     name: &'static str,
@@ -143,154 +635,170 @@ static TEMPLATES: &[Template] = &[
     },
 ];
 
-/// Discover a contract file in the current directory.
+/// Find a contract file directly inside `dir`.
+fn contract_in(dir: &Path) -> Option<PathBuf> {
+    DEFAULT_CONTRACT_NAMES
+        .iter()
+        .map(|name| dir.join(name))
+        .find(|path| path.exists())
+}
+
+/// Discover a contract file for a scan.
+///
+/// The current directory is checked first. Otherwise the scan directory and
+/// each of its parents up to the enclosing workspace root are checked, so the
+/// contract closest to the scanned sub-project wins.
 /// Returns None if no contract file is found.
-fn discover_contract() -> Option<PathBuf> {
+fn discover_contract(scan_path: &Path) -> Option<PathBuf> {
     for name in DEFAULT_CONTRACT_NAMES {
         let path = PathBuf::from(name);
         if path.exists() {
             return Some(path);
         }
     }
-    None
-}
-
-/// Default directory patterns to exclude from scanning.
-const DEFAULT_EXCLUDED_DIRS: &[&str] = &[
-    // Build artifacts
-    "target",
-    "node_modules",
-    "vendor",
-    "dist",
-    "build",
-    "_build",
-    // Test directories
-    "test",
-    "tests",
-    "__tests__",
-    "__test__",
-    "testdata",
-    "test_data",
-    "test-data",
-    // Example directories
-    "example",
-    "examples",
-    // Benchmark directories
-    "bench",
-    "benches",
-    "benchmark",
-    "benchmarks",
-    // Documentation
-    "doc",
-    "docs",
-    "documentation",
-];
+    discover_contract_near(scan_path)
+}
 
-/// Check if a filename indicates a test file.
-fn is_test_file(filename: &str) -> bool {
-    // Common test file patterns across languages
-    filename.ends_with("_test.go")
-        || filename.ends_with("_test.rs")
-        || filename.ends_with("_test.py")
-        || filename.ends_with("_test.js")
-        || filename.ends_with("_test.ts")
-        || filename.ends_with("_test.tsx")
-        || filename.ends_with("_test.jsx")
-        || filename.ends_with(".test.js")
-        || filename.ends_with(".test.ts")
-        || filename.ends_with(".test.tsx")
-        || filename.ends_with(".test.jsx")
-        || filename.ends_with(".spec.js")
-        || filename.ends_with(".spec.ts")
-        || filename.ends_with(".spec.tsx")
-        || filename.ends_with(".spec.jsx")
-        || filename.starts_with("test_")
-        || filename == "conftest.py"
-}
-
-/// Collect files to scan with additional exclude/include patterns.
-fn collect_files_with_patterns(
-    root: &Path,
-    contract: &Contract,
-    extra_excludes: &[String],
-    include_patterns: &[String],
-) -> anyhow::Result<Vec<PathBuf>> {
-    let supported_extensions = [
-        "go", "rs", "py", "js", "ts", "jsx", "tsx", "java", "kt", "c", "cpp", "h", "hpp",
-    ];
-
-    let include_test_files = contract.should_include_test_files();
-    let mut files = Vec::new();
-
-    // Build extra exclude matchers
-    let extra_matchers: Vec<_> = extra_excludes
-        .iter()
-        .filter_map(|p| globset::Glob::new(p).ok().map(|g| g.compile_matcher()))
-        .collect();
+/// Discover a contract file in the scan directory or its parents up to the
+/// enclosing workspace root, ignoring the current directory.
+fn discover_contract_near(scan_path: &Path) -> Option<PathBuf> {
+    let scan_dir = if scan_path.is_file() { scan_path.parent()? } else { scan_path };
+    let workspace_root = find_workspace(scan_dir)?.root;
+    scan_dir
+        .ancestors()
+        .take_while(|dir| dir.starts_with(&workspace_root))
+        .find_map(contract_in)
+}
 
-    // Build include matchers
-    let include_matchers: Vec<_> = include_patterns
-        .iter()
-        .filter_map(|p| globset::Glob::new(p).ok().map(|g| g.compile_matcher()))
-        .collect();
+/// Discover the files to scan below `root`.
+///
+/// Returns the files to scan and the number of files skipped by the
+/// contract's language filter.
+fn collect_files(discovery: FileDiscovery) -> anyhow::Result<(Vec<PathBuf>, usize)> {
+    let (files, language_excluded): (Vec<_>, Vec<_>) =
+        discovery.discover()?.into_iter().partition(|f| f.class.is_analyzed());
+    Ok((files.into_iter().map(|f| f.path).collect(), language_excluded.len()))
+}
 
-    for entry in WalkDir::new(root)
-        .follow_links(true)
-        .into_iter()
-        .filter_entry(|e| {
-            let name = e.file_name().to_string_lossy();
-            // Skip hidden directories
-            if e.file_type().is_dir() && name.starts_with('.') {
-                return false;
-            }
-            // Skip default excluded directories
-            if e.file_type().is_dir() && DEFAULT_EXCLUDED_DIRS.contains(&name.as_ref()) {
-                return false;
-            }
-            true
+/// Print the effective contract for `--config-dump`.
+///
+/// Flags that take precedence over contract settings at scan time are applied
+/// to the printed copy. YAML (the default) lists where each setting a layer
+/// over the base contract came from in a leading comment, so the output is
+/// still a loadable contract; `--format json` puts them under `sources`.
+fn print_config_dump(
+    args: &LintArgs,
+    contract_path: &str,
+    contract: &Contract,
+    mut sources: Vec<ValueSource>,
+) -> anyhow::Result<()> {
+    let mut effective = contract.clone();
+    let mut flag = |key: &str, name: &str| {
+        sources.push(ValueSource {
+            key: key.to_string(),
+            source: name.to_string(),
         })
-    {
-        let entry = entry?;
-        if entry.file_type().is_file() {
-            let path = entry.path();
-            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-
-            if supported_extensions.contains(&ext) {
-                let path_str = path.to_string_lossy();
-                let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-
-                // Check if file matches include patterns (always include these)
-                let force_include = include_matchers.iter().any(|m| m.is_match(&*path_str));
-
-                if !force_include {
-                    // Skip test files unless explicitly included
-                    if !include_test_files && is_test_file(filename) {
-                        continue;
-                    }
-
-                    // Skip files matching excluded_paths patterns from contract
-                    if contract.is_path_excluded(path) {
-                        continue;
-                    }
+    };
+    if let Some(threshold) = args.threshold {
+        effective.threshold = Some(threshold);
+        flag("threshold", "--threshold");
+    }
+    if let Some(mode) = &args.mode {
+        effective.mode = Some(mode.clone());
+        flag("mode", "--mode");
+    }
+    if let Some(limit) = args.max_violations_per_rule {
+        effective.max_violations_per_rule = Some(limit);
+        flag("max_violations_per_rule", "--max-violations-per-rule");
+    }
+    if let Some(limit) = args.max_violations_total {
+        effective.max_violations_total = Some(limit);
+        flag("max_violations_total", "--max-violations-total");
+    }
 
-                    // Skip files matching extra exclude patterns from CLI
-                    if extra_matchers.iter().any(|m| m.is_match(&*path_str)) {
-                        continue;
-                    }
-                }
+    // A setting changed by several layers shows the last one
+    let sources: BTreeMap<String, String> = sources.into_iter().map(|s| (s.key, s.source)).collect();
+
+    if args.format == "json" {
+        let dump = serde_json::json!({
+            "contract_path": contract_path,
+            "contract": effective,
+            "sources": sources,
+        });
+        println!("{}", serde_json::to_string_pretty(&dump)?);
+        return Ok(());
+    }
 
-                files.push(path.to_path_buf());
-            }
+    println!("# Effective contract: {}", contract_path);
+    if !sources.is_empty() {
+        println!("# Settings from layers over the base contract:");
+        for (key, source) in &sources {
+            println!("#   {}: {}", key, source);
         }
     }
+    print!("{}", serde_yaml::to_string(&effective)?);
+    Ok(())
+}
+
+/// Print each collected file with the analyzer that would handle it.
+fn print_file_list(root: &Path, files: &[PathBuf]) {
+    let mut entries: Vec<(String, &str)> = files
+        .iter()
+        .map(|file| {
+            let display = file
+                .strip_prefix(root)
+                .ok()
+                .filter(|p| !p.as_os_str().is_empty())
+                .unwrap_or(file)
+                .to_string_lossy()
+                .to_string();
+            let ext = crate::analysis::analysis_extension(file);
+            let analyzer = crate::analysis::get_analyzer(ext)
+                .map(|a| a.language_id())
+                .unwrap_or("skipped: no analyzer");
+            (display, analyzer)
+        })
+        .collect();
+    entries.sort();
 
-    Ok(files)
+    let width = entries.iter().map(|(path, _)| path.len()).max().unwrap_or(0);
+    for (path, analyzer) in &entries {
+        println!("{:width$}  {}", path, analyzer, width = width);
+    }
 }
 
-/// Run the lint command.
-pub fn run_lint(args: &LintArgs) -> anyhow::Result<i32> {
+/// A completed scan, ready to be written in any format.
+struct Scan {
+    abs_path: PathBuf,
+    contract_path: String,
+    result: DetectionResult,
+    hollowness: score::HollownessScore,
+}
+
+/// Discover files, run detection, and score the result.
+///
+/// Returns `Err(code)` when the command should exit without writing a
+/// report: after printing an input error, for `--list-files`, and when
+/// there is nothing to scan.
+fn scan(args: &LintArgs, is_interactive: bool) -> anyhow::Result<Result<Scan, i32>> {
     let start_time = Instant::now();
-    let is_interactive = args.format == "pretty";
+
+    // Declared first, so it outlives every sink and writes all events before the report
+    let event_stream = match &args.events {
+        Some(format) => match EventStream::open(format, args.events_fd) {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return Ok(Err(EXIT_ERROR));
+            }
+        },
+        None => EventStream::disabled(),
+    };
+    let events = event_stream.sink();
+
+    // Events sharing stderr get it to themselves: progress is dropped and warnings become events
+    let events_on_stderr = args.events.is_some() && args.events_fd.is_none_or(|fd| fd == 2);
+    let is_interactive = is_interactive && !events_on_stderr;
 
     // Show progress only in interactive mode
     let progress_msg = |msg: &str| {
@@ -299,20 +807,11 @@ pub fn run_lint(args: &LintArgs) -> anyhow::Result<i32> {
         }
     };
 
-    // Validate format
-    if args.format != "pretty" && args.format != "json" && args.format != "sarif" {
-        eprintln!(
-            "Error: invalid format {:?}, must be 'pretty', 'json', or 'sarif'",
-            args.format
-        );
-        return Ok(EXIT_ERROR);
-    }
-
     // Validate mode
     let mode = args.mode.as_deref().unwrap_or("code");
     if mode != "code" && mode != "prose" {
         eprintln!("Error: invalid mode {:?}, must be 'code' or 'prose'", mode);
-        return Ok(EXIT_ERROR);
+        return Ok(Err(EXIT_ERROR));
     }
 
     // Phase 1: Initialization
@@ -326,108 +825,232 @@ pub fn run_lint(args: &LintArgs) -> anyhow::Result<i32> {
     // Validate strict/relaxed flags are not both set
     if args.strict && args.relaxed {
         eprintln!("Error: cannot use both --strict and --relaxed flags");
-        return Ok(EXIT_ERROR);
+        return Ok(Err(EXIT_ERROR));
     }
 
+    // Resolve path
+    let abs_path = match args.path.canonicalize() {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("Error: cannot access path {:?}: {}", args.path, e);
+            return Ok(Err(EXIT_ERROR));
+        }
+    };
+
+    // An explicit --profile wins over the environment
+    let profile = args
+        .profile
+        .clone()
+        .or_else(|| std::env::var(contract::PROFILE_ENV).ok())
+        .filter(|p| !p.is_empty());
+
     // Discover contract if not specified, or use default if none found
-    let (contract_path, mut contract) = match &args.contract {
+    let (contract_path, (mut contract, mut sources)) = match &args.contract {
+        Some(p) if policy::is_remote(&p.to_string_lossy()) => {
+            let url = p.to_string_lossy().to_string();
+            let Some(sha256) = args.contract_sha256.clone() else {
+                eprintln!("Error: contract URL {} needs an integrity pin: pass --contract-sha256", url);
+                return Ok(Err(EXIT_ERROR));
+            };
+            let remote = RemoteContract {
+                url: url.clone(),
+                sha256,
+                timeout_ms: None,
+            };
+            match Contract::resolve_remote(&remote, profile.as_deref()) {
+                Ok(c) => (url, c),
+                Err(e) => {
+                    eprintln!("Error parsing contract: {}", e);
+                    return Ok(Err(EXIT_ERROR));
+                }
+            }
+        }
         Some(p) => {
             // Explicit contract specified - must exist
-            match Contract::parse_file(p) {
+            match Contract::resolve_file(p, profile.as_deref()) {
                 Ok(c) => (p.to_string_lossy().to_string(), c),
                 Err(e) => {
                     eprintln!("Error parsing contract: {}", e);
-                    return Ok(EXIT_ERROR);
+                    return Ok(Err(EXIT_ERROR));
                 }
             }
         }
         None => {
             // No explicit contract - try to discover, or use default
-            match discover_contract() {
+            let discovered = if args.contract_from_scan_path {
+                discover_contract_near(&abs_path)
+            } else {
+                discover_contract(&abs_path)
+            };
+            match discovered {
                 Some(p) => {
-                    match Contract::parse_file(&p) {
+                    match Contract::resolve_file(&p, profile.as_deref()) {
                         Ok(c) => (p.to_string_lossy().to_string(), c),
                         Err(e) => {
                             eprintln!("Error parsing contract: {}", e);
-                            return Ok(EXIT_ERROR);
+                            return Ok(Err(EXIT_ERROR));
                         }
                     }
                 }
+                None if profile.is_some() => {
+                    eprintln!(
+                        "Error: profile {:?} selected but no contract file found",
+                        profile.unwrap_or_default()
+                    );
+                    return Ok(Err(EXIT_ERROR));
+                }
                 None => {
                     // No contract found - use default
                     if is_interactive {
                         eprintln!("{} No contract file found, using default settings", "ℹ".blue());
                     }
-                    ("<default>".to_string(), Contract::default_contract())
+                    ("<default>".to_string(), (Contract::default_contract(), Vec::new()))
                 }
             }
         }
     };
 
-    // Apply strict/relaxed thresholds if specified
+    if is_interactive {
+        if let Some(ref remote) = contract.extends {
+            eprintln!(
+                "{} Contract extends {} (sha256 {})",
+                "ℹ".blue(),
+                remote.url,
+                remote.sha256.to_ascii_lowercase()
+            );
+        }
+        if let Some(ref profile) = profile {
+            eprintln!("{} Using contract profile {:?}", "ℹ".blue(), profile);
+        }
+    }
+
+    // Apply strict/relaxed thresholds if specified
     if args.strict || args.relaxed {
         use crate::detect::GodObjectConfig;
-        let thresholds = if args.strict {
-            GodObjectConfig::strict()
+        let (thresholds, flag) = if args.strict {
+            (GodObjectConfig::strict(), "--strict")
         } else {
-            GodObjectConfig::relaxed()
+            (GodObjectConfig::relaxed(), "--relaxed")
         };
 
         // Update contract's god_objects config with the selected thresholds
         let god_cfg = contract.god_objects.get_or_insert(Default::default());
+        let mut set = Vec::new();
         if god_cfg.max_file_lines.is_none() {
             god_cfg.max_file_lines = Some(thresholds.max_file_lines);
+            set.push("god_objects.max_file_lines".to_string());
         }
         if god_cfg.max_function_lines.is_none() {
             god_cfg.max_function_lines = Some(thresholds.max_function_lines);
+            set.push("god_objects.max_function_lines".to_string());
         }
         if god_cfg.max_function_complexity.is_none() {
             god_cfg.max_function_complexity = Some(thresholds.max_function_complexity);
+            set.push("god_objects.max_function_complexity".to_string());
         }
         if god_cfg.max_functions_per_file.is_none() {
             god_cfg.max_functions_per_file = Some(thresholds.max_functions_per_file);
+            set.push("god_objects.max_functions_per_file".to_string());
         }
         if god_cfg.max_class_methods.is_none() {
             god_cfg.max_class_methods = Some(thresholds.max_class_methods);
+            set.push("god_objects.max_class_methods".to_string());
         }
+        sources.extend(ValueSource::from_layer(set, flag));
+    }
+
+    // --language overrides the contract's language allowlist
+    if !args.languages.is_empty() {
+        let filter = contract.languages.get_or_insert(Default::default());
+        filter.include = args.languages.clone();
+        sources.extend(ValueSource::from_layer(vec!["languages.include".to_string()], "--language"));
     }
 
     // Validate contract
     if let Err(e) = contract::validate(&contract) {
         eprintln!("Error: invalid contract: {}", e);
-        return Ok(EXIT_ERROR);
+        return Ok(Err(EXIT_ERROR));
     }
-
-    // Resolve path
-    let abs_path = match args.path.canonicalize() {
-        Ok(p) => p,
-        Err(e) => {
-            eprintln!("Error: cannot access path {:?}: {}", args.path, e);
-            return Ok(EXIT_ERROR);
+    let contract_warnings = contract::validation_warnings(&contract);
+    if !events_on_stderr {
+        for warning in &contract_warnings {
+            eprintln!("Warning: {}", warning);
         }
-    };
+    }
+
+    if args.config_dump {
+        print_config_dump(args, &contract_path, &contract, sources)?;
+        return Ok(Err(EXIT_SUCCESS));
+    }
 
     // Check path exists
     let metadata = match std::fs::metadata(&abs_path) {
         Ok(m) => m,
         Err(e) => {
             eprintln!("Error: {}", e);
-            return Ok(EXIT_ERROR);
+            return Ok(Err(EXIT_ERROR));
         }
     };
 
+    let rule_names = |rules: &[ViolationRule]| rules.iter().map(|r| r.as_str().to_string()).collect();
+    events.emit(Event::RunStarted {
+        path: abs_path.to_string_lossy().to_string(),
+        contract: contract_path.clone(),
+        profile: profile.clone(),
+        mode: mode.to_string(),
+        skip_registry_check: args.skip_registry_check,
+        only_rules: rule_names(&args.only_rules),
+        skip_rules: rule_names(&args.skip_rules),
+        languages: args.languages.clone(),
+        changed_since: args.changed_since.clone(),
+        baseline: args.baseline.as_ref().map(|b| b.to_string_lossy().to_string()),
+    });
+    for message in contract_warnings {
+        events.emit(Event::Warning { message });
+    }
+    let warn = |message: String| {
+        if !events_on_stderr {
+            eprintln!("Warning: {}", message);
+        }
+        events.emit(Event::Warning { message });
+    };
+
     // Phase 2: File collection
     progress_msg("Scanning files...");
+    events.emit(Event::PhaseStarted { phase: Phase::Discovery });
     let collect_start = Instant::now();
-    let files = if metadata.is_dir() {
-        collect_files_with_patterns(&abs_path, &contract, &args.exclude_patterns, &args.include_patterns)?
+    let (files, language_excluded) = if metadata.is_dir() {
+        collect_files(
+            FileDiscovery::new(&abs_path, &contract)
+                .default_excludes(!args.no_default_excludes)
+                .exclude_patterns(&args.exclude_patterns)
+                .include_patterns(&args.include_patterns),
+        )?
     } else {
-        vec![abs_path.clone()]
+        (vec![abs_path.clone()], 0)
     };
 
+    events.emit(Event::PhaseCompleted {
+        phase: Phase::Discovery,
+        count: files.len(),
+        duration_ms: collect_start.elapsed().as_millis() as u64,
+    });
+
+    // Dry run: show the scan scope without running detection
+    if args.list_files {
+        print_file_list(&abs_path, &files);
+        if !events_on_stderr {
+            eprintln!("{} files would be scanned", files.len());
+            if language_excluded > 0 {
+                eprintln!("{} files excluded by language filter", language_excluded);
+            }
+        }
+        return Ok(Err(EXIT_SUCCESS));
+    }
+
     if files.is_empty() {
-        eprintln!("Warning: no files to scan");
-        return Ok(EXIT_SUCCESS);
+        warn("no files to scan".to_string());
+        return Ok(Err(EXIT_SUCCESS));
     }
 
     if is_interactive {
@@ -435,64 +1058,369 @@ pub fn run_lint(args: &LintArgs) -> anyhow::Result<i32> {
     }
 
     // Phase 3: Analysis with progress bar for large file counts
+    events.emit(Event::PhaseStarted { phase: Phase::Analysis });
     let analysis_start = Instant::now();
-    let result = if is_interactive && files.len() > 10 {
+    let progress = (is_interactive && files.len() > 10).then(|| {
         // Show progress bar for larger codebases
         let pb = ProgressBar::new(files.len() as u64);
         pb.set_style(ProgressStyle::default_bar()
-            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} files ({eta})")
+            .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} files ({eta}) {msg}")
             .unwrap()
             .progress_chars("#>-"));
-
-        // Clone pb for the closure
-        let pb_clone = pb.clone();
-
-        // Run detection with progress callback
-        let runner = Runner::new(&abs_path)
-            .skip_registry_check(args.skip_registry_check)
-            .with_progress(move |current, _total| {
-                pb_clone.set_position(current as u64);
+        pb
+    });
+
+    let mut runner = Runner::new(&abs_path)
+        .skip_registry_check(args.skip_registry_check)
+        .explain_dependencies(args.explain_dependencies)
+        .default_excludes(!args.no_default_excludes)
+        .only_rules(&args.only_rules)
+        .skip_rules(&args.skip_rules);
+    if let Some(pb) = progress.clone() {
+        runner = runner.with_progress(move |current, _total| {
+            pb.set_position(current as u64);
+        });
+    }
+    if progress.is_some() || events.is_enabled() {
+        let pb = progress.clone();
+        let events = events.clone();
+        runner = runner.with_dependency_progress(move |checked, total| {
+            if let Some(pb) = &pb {
+                pb.set_message(format!("checking packages {}/{}", checked, total));
+            }
+            events.emit(Event::RegistryBatchCompleted { checked, total });
+        });
+    }
+    if events.is_enabled() {
+        let events = events.clone();
+        let root = abs_path.clone();
+        runner = runner.with_file_callback(move |file, text_rule_violations, analyzed, total| {
+            let file = file.strip_prefix(&root).unwrap_or(file);
+            events.emit(Event::FileAnalyzed {
+                file: file.to_string_lossy().to_string(),
+                text_rule_violations,
+                analyzed,
+                total,
             });
-        let result = runner.run(&files, &contract)?;
+        });
+    }
+    let mut result = runner.run(&files, &contract)?;
 
+    if let Some(pb) = progress {
         pb.finish_and_clear();
         eprintln!("  {} Analysis complete ({:.1}s)", "✓".green(), analysis_start.elapsed().as_secs_f32());
-        result
-    } else {
-        // No progress bar for small file counts
-        let runner = Runner::new(&abs_path).skip_registry_check(args.skip_registry_check);
-        runner.run(&files, &contract)?
-    };
+    }
+    events.emit(Event::PhaseCompleted {
+        phase: Phase::Analysis,
+        count: files.len(),
+        duration_ms: analysis_start.elapsed().as_millis() as u64,
+    });
+
+    // Runner only sees files that passed discovery; include the ones discovery filtered out
+    result.language_excluded += language_excluded;
+    for warning in &result.contract_warnings {
+        warn(warning.clone());
+    }
 
     if is_interactive && start_time.elapsed().as_secs_f32() > 1.0 {
         eprintln!("  {} Total time: {:.1}s", "✓".green(), start_time.elapsed().as_secs_f32());
         eprintln!();
     }
 
-    // Calculate score
-    let hollowness = if let Some(threshold) = args.threshold {
-        score::calculate_with_threshold(&result, threshold)
+    events.emit(Event::PhaseStarted { phase: Phase::Scoring });
+    let scoring_start = Instant::now();
+
+    // Only report violations in files changed since the given ref
+    if let Some(ref base) = args.changed_since {
+        let changed = crate::ci::changed_files(&abs_path, base)?;
+        result.violations.retain(|v| match v.file.as_deref() {
+            Some(file) => {
+                let path = abs_path.join(file);
+                changed.contains(&path.canonicalize().unwrap_or(path))
+            }
+            None => true,
+        });
+    }
+
+    // Violations the baseline report does not have
+    if let Some(ref baseline) = args.baseline {
+        let location = baseline.to_string_lossy();
+        let new = match diff::load_baseline(&location) {
+            Ok(report) => diff::new_violations(&report, &result),
+            // An unreachable artifact store should not fail the run: every violation is new
+            Err(e) if policy::is_remote(&location) => {
+                warn(format!("{}; comparing against an empty baseline", e));
+                result.violations.clone()
+            }
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return Ok(Err(EXIT_ERROR));
+            }
+        };
+        result.set_baseline(location, new);
+    }
+
+    // Calculate score; with --only-new, pass or fail on new violations alone
+    let points = score::PointWeights::from_contract(&contract);
+    let mut hollowness = if args.only_new {
+        score::calculate_for_new_violations(&result, args.threshold.unwrap_or(0), &points)
+            .with_max_debt(contract.max_debt)
+            .with_sub_scores(&result.new_violations, contract.sub_scores.as_ref())
+    } else if let Some(threshold) = args.threshold {
+        score::calculate_with_threshold(&result, threshold, &points)
+            .with_max_debt(contract.max_debt)
+            .with_sub_scores(&result.violations, contract.sub_scores.as_ref())
     } else {
         score::calculate(&result, &contract)
     };
 
+    if args.show_clean_rules {
+        hollowness.include_clean_rules(&result.checked_rules);
+    }
+
+    // Waivers about to lapse fail the run, so they are renewed or paid off in time
+    if let Some(within) = args.strict_waivers {
+        let today = crate::detect::today();
+        for entry in waiver_entries(&contract.waivers, &today, within) {
+            if entry.status == WaiverStatus::Active {
+                continue;
+            }
+            let w = &entry.waiver;
+            eprintln!(
+                "Error: waiver for {} on {} ({}) {} {}",
+                w.rule,
+                crate::detect::waiver_target(w),
+                w.owner,
+                if entry.status == WaiverStatus::Expired { "expired" } else { "expires" },
+                w.expires
+            );
+            hollowness.passed = false;
+        }
+    }
+
+    // Bound the report only after scoring, so the score sees every violation.
+    // The severity summary and rule counts count them all, so they are never bounded.
+    if !args.severity_summary && !args.count_only {
+        let per_rule = args.max_violations_per_rule.or(contract.max_violations_per_rule);
+        let total = args.max_violations_total.or(contract.max_violations_total);
+        if per_rule == Some(0) || total == Some(0) {
+            eprintln!("Error: violation limits must be at least 1");
+            return Ok(Err(EXIT_ERROR));
+        }
+        result.truncate(per_rule, total);
+    }
+
+    events.emit(Event::PhaseCompleted {
+        phase: Phase::Scoring,
+        count: result.violations.len(),
+        duration_ms: scoring_start.elapsed().as_millis() as u64,
+    });
+    let by_severity = [
+        (Severity::Critical, "critical"),
+        (Severity::Error, "error"),
+        (Severity::Warning, "warning"),
+        (Severity::Info, "info"),
+    ]
+    .into_iter()
+    .map(|(severity, name)| (name, result.violations.iter().filter(|v| v.severity == severity).count()))
+    .collect();
+    events.emit(Event::RunCompleted {
+        score: hollowness.score,
+        grade: hollowness.grade.clone(),
+        threshold: hollowness.threshold,
+        passed: hollowness.passed,
+        files_scanned: files.len(),
+        violations: result.violations.len(),
+        by_severity,
+        duration_ms: start_time.elapsed().as_millis() as u64,
+    });
+
+    Ok(Ok(Scan {
+        abs_path,
+        contract_path,
+        result,
+        hollowness,
+    }))
+}
+
+/// The pretty output theme: `--theme`, then `HOLLOWCHECK_THEME`, then the
+/// user config, then `mono` if `NO_COLOR` is set. The user config is only
+/// read when neither of the first two picks a theme; a config that cannot be
+/// read, or names an unknown theme, is skipped with a warning.
+fn resolve_theme(flag: Option<&str>) -> anyhow::Result<Theme> {
+    let env = std::env::var(THEME_ENV).ok();
+    let user_theme = if flag.is_none() && env.as_deref().is_none_or(str::is_empty) {
+        match UserConfig::load() {
+            Ok(config) => config.theme.filter(|theme| match theme.parse::<Theme>() {
+                Ok(_) => true,
+                Err(e) => {
+                    eprintln!("Warning: ignoring theme in the user config: {}", e);
+                    false
+                }
+            }),
+            Err(e) => {
+                eprintln!("Warning: ignoring the user config: {:#}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    Theme::resolve(flag, env.as_deref(), user_theme.as_deref(), no_color)
+}
+
+/// Run the lint command.
+pub fn run_lint(args: &LintArgs) -> anyhow::Result<i32> {
+    if args.watch {
+        return run_watch(args);
+    }
+
+    // `auto` resolves to pretty output on a terminal and compact JSON for pipes and files
+    let stdout_is_tty = std::io::stdout().is_terminal();
+    let stderr_is_tty = std::io::stderr().is_terminal();
+    let format = resolve_format(&args.format, stdout_is_tty);
+
+    // Under cargo, auto output on a terminal is cargo's own diagnostic style
+    let message_format = args.message_format.as_deref().or(
+        (args.cargo && args.format == "auto" && stderr_is_tty).then_some("human"),
+    );
+    if message_format.is_some_and(|f| f != "human" && f != "json") {
+        eprintln!(
+            "Error: invalid message format {:?}, must be 'human' or 'json'",
+            message_format.unwrap_or_default()
+        );
+        return Ok(EXIT_ERROR);
+    }
+    let is_interactive = !args.severity_summary
+        && !args.count_only
+        && match message_format {
+            Some(f) => f == "human" && stderr_is_tty,
+            None => format == "pretty",
+        };
+
+    // Validate format
+    if !["pretty", "json", "sarif", "github", "gitlab", "editor"].contains(&format) {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', 'json', 'sarif', 'github', 'gitlab', or 'editor'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+    let theme = if format == "pretty" {
+        match resolve_theme(args.theme.as_deref()) {
+            Ok(theme) => theme,
+            Err(e) => {
+                eprintln!("Error: {:#}", e);
+                return Ok(EXIT_ERROR);
+            }
+        }
+    } else {
+        Theme::default()
+    };
+
+    // --package narrows the scan to one workspace member's directory
+    let package = match &args.package {
+        Some(name) => match crate::cargo::find_package(&args.path, name) {
+            Ok(package) => Some(package),
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                return Ok(EXIT_ERROR);
+            }
+        },
+        None => None,
+    };
+    let scoped;
+    let args = match &package {
+        Some(package) => {
+            scoped = LintArgs {
+                path: package.dir().to_path_buf(),
+                ..args.clone()
+            };
+            &scoped
+        }
+        None => args,
+    };
+
+    let Scan {
+        abs_path,
+        contract_path,
+        result,
+        hollowness,
+    } = match scan(args, is_interactive)? {
+        Ok(scan) => scan,
+        Err(code) => return Ok(code),
+    };
+
+    if args.fix_suppressions {
+        return fix_suppressions(&abs_path, &result, args);
+    }
+
     // Output results
     let path_str = args.path.to_string_lossy().to_string();
 
-    match args.format.as_str() {
+    if args.severity_summary {
+        if !(args.quiet && result.violations.is_empty()) {
+            println!("{}", report::render_severity_summary(&result, &hollowness));
+        }
+        return Ok(if hollowness.passed { EXIT_SUCCESS } else { EXIT_FAILED });
+    }
+
+    if args.count_only {
+        println!("{}", report::render_rule_counts(&result));
+        return Ok(if hollowness.passed { EXIT_SUCCESS } else { EXIT_FAILED });
+    }
+
+    match message_format {
+        Some("human") => {
+            eprint!("{}", report::render_cargo_diagnostics(&abs_path, &result, &hollowness, stderr_is_tty));
+            return Ok(if hollowness.passed { EXIT_SUCCESS } else { EXIT_FAILED });
+        }
+        Some(_) => {
+            let dir = if abs_path.is_dir() { abs_path.as_path() } else { abs_path.parent().unwrap_or(&abs_path) };
+            let package = package.unwrap_or_else(|| crate::cargo::Package::for_dir(dir));
+            println!("{}", report::render_cargo_messages(&abs_path, &package, &result, &hollowness)?);
+            return Ok(if hollowness.passed { EXIT_SUCCESS } else { EXIT_FAILED });
+        }
+        None => {}
+    }
+
+    match format {
         "json" => {
-            report::write_json(&path_str, &contract_path, &result, &hollowness)?;
+            // Explicit --format json stays indented; auto-selected JSON is compact
+            let options = report::JsonOptions {
+                compact: args.format == "auto",
+                go_compat: args.go_compat,
+            };
+            report::write_json(&path_str, &contract_path, &result, &hollowness, options)?;
         }
         "sarif" => {
             report::write_sarif(&abs_path, &result)?;
         }
+        "github" => {
+            print!("{}", report::render_github_annotations(&abs_path, &result));
+        }
+        "gitlab" => {
+            println!("{}", report::render_gitlab(&abs_path, &result)?);
+        }
+        "editor" => {
+            // Quickfix paths resolve against the editor's working directory
+            let cwd = std::env::current_dir()?;
+            print!("{}", report::render_editor(&cwd, &result));
+        }
         _ => {
             report::write_pretty(
                 &path_str,
                 &contract_path,
                 &result,
                 &hollowness,
-                args.show_suppressed,
+                report::PrettyOptions {
+                    show_suppressed: args.show_suppressed,
+                    show_fingerprints: args.show_fingerprints,
+                    only_new: args.only_new,
+                    theme,
+                    color: None,
+                },
             );
         }
     }
@@ -505,6 +1433,763 @@ pub fn run_lint(args: &LintArgs) -> anyhow::Result<i32> {
     }
 }
 
+/// Lint again on every change under the path. With `--serve`, each report
+/// is published over HTTP and only a status line is printed.
+fn run_watch(args: &LintArgs) -> anyhow::Result<i32> {
+    let once = LintArgs {
+        watch: false,
+        serve: None,
+        ..args.clone()
+    };
+    let hub = crate::serve::ReportHub::default();
+    if let Some(addr) = &args.serve {
+        let bound = crate::serve::serve(addr, hub.clone())?;
+        eprintln!("Serving the latest report at http://{}/report, with events at /events", bound);
+    }
+
+    let path_str = args.path.to_string_lossy().to_string();
+    let lint = || -> anyhow::Result<()> {
+        if args.serve.is_none() {
+            run_lint(&once)?;
+            return Ok(());
+        }
+        // Input errors are printed by the scan; the next change may fix them
+        if let Ok(Scan {
+            contract_path,
+            result,
+            hollowness,
+            ..
+        }) = scan(&once, false)?
+        {
+            let options = report::JsonOptions {
+                compact: true,
+                go_compat: args.go_compat,
+            };
+            hub.publish(report::render_json(&path_str, &contract_path, &result, &hollowness, options)?);
+            eprintln!("{}", report::render_severity_summary(&result, &hollowness));
+        }
+        Ok(())
+    };
+    crate::watch::watch(&args.path, || {
+        if let Err(e) = lint() {
+            eprintln!("Error: {}", e);
+        }
+        Ok(())
+    })?;
+    Ok(EXIT_SUCCESS)
+}
+
+/// Reason written into comments inserted by `--fix-suppressions`.
+const FIX_SUPPRESSIONS_REASON: &str = "existing violation, suppressed with --fix-suppressions";
+
+/// Suppress every active violation with an inline comment: show the diff,
+/// then write it once confirmed.
+fn fix_suppressions(abs_path: &Path, result: &DetectionResult, args: &LintArgs) -> anyhow::Result<i32> {
+    let base = if abs_path.is_dir() { abs_path } else { abs_path.parent().unwrap_or(abs_path) };
+
+    let mut by_file: BTreeMap<&str, Vec<&Violation>> = BTreeMap::new();
+    for v in &result.violations {
+        if let Some(file) = v.file.as_deref() {
+            by_file.entry(file).or_default().push(v);
+        }
+    }
+
+    let mut patch = PatchSet::new();
+    let mut suppressed = 0;
+    for (file, violations) in &by_file {
+        let path = base.join(file);
+        let Ok(original) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let Some(modified) = insert_suppression_comments(file, &original, violations, FIX_SUPPRESSIONS_REASON) else {
+            continue;
+        };
+        suppressed += violations.iter().filter(|v| v.line > 0).count();
+        patch.push(path, original, modified);
+    }
+
+    if patch.is_empty() {
+        println!("No violations to suppress.");
+        return Ok(EXIT_SUCCESS);
+    }
+
+    let color = std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none();
+    if args.diff_only {
+        print!("{}", patch.render(base, color));
+        return Ok(EXIT_SUCCESS);
+    }
+
+    let dirty = patch.dirty_files()?;
+    if !dirty.is_empty() && !args.force {
+        eprintln!("Error: refusing to edit files with unstaged changes (use --force to write anyway):");
+        for path in dirty {
+            eprintln!("  {}", path.strip_prefix(base).unwrap_or(path).display());
+        }
+        return Ok(EXIT_ERROR);
+    }
+
+    print!("{}", patch.render(base, color));
+
+    let files = patch.edits().len();
+    if !args.yes {
+        if !std::io::stdin().is_terminal() {
+            eprintln!("Error: cannot ask for confirmation without a terminal; pass --yes to apply or --diff-only to preview");
+            return Ok(EXIT_ERROR);
+        }
+        eprint!("Apply changes to {} file{}? [y/N] ", files, if files == 1 { "" } else { "s" });
+        let mut answer = String::new();
+        std::io::stdin().read_line(&mut answer)?;
+        if !matches!(answer.trim(), "y" | "Y" | "yes") {
+            println!("No changes written.");
+            return Ok(EXIT_SUCCESS);
+        }
+    }
+
+    patch.apply()?;
+    println!(
+        "Suppressed {} violation{} in {} file{}.",
+        suppressed,
+        if suppressed == 1 { "" } else { "s" },
+        files,
+        if files == 1 { "" } else { "s" }
+    );
+    Ok(EXIT_SUCCESS)
+}
+
+/// Run the why-import command.
+///
+/// Returns `EXIT_FAILED` when the pipeline would flag the import.
+pub fn run_why_import(args: &WhyImportArgs) -> anyhow::Result<i32> {
+    let file = args
+        .file
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("cannot access file {:?}: {}", args.file, e))?;
+    let root = args.root.clone().unwrap_or_else(|| PathBuf::from("."));
+    let root = root
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("cannot access root {:?}: {}", root, e))?;
+
+    let ext = file.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let registry = RegistryType::from_extension(ext)
+        .ok_or_else(|| anyhow::anyhow!("no package registry for .{} files", ext))?;
+
+    let contract = match args.contract.clone().or_else(|| discover_contract(&root)) {
+        Some(path) => Contract::parse_file(&path)?,
+        None => Contract::default_contract(),
+    };
+    let config = match contract.dependency_verification {
+        Some(config) => {
+            if !config.is_enabled() {
+                eprintln!("Note: dependency_verification is disabled in the contract; tracing anyway");
+            }
+            config
+        }
+        None => Contract::default_contract()
+            .dependency_verification
+            .unwrap_or_default(),
+    };
+
+    let validator = DependencyValidator::new(ManifestType::Auto, &root, &config)?;
+    let mut trace = validator.trace_import(&args.import, &file, registry);
+    trace.line = extract_imports(&file)?
+        .into_iter()
+        .find(|i| i.name == args.import)
+        .map(|i| i.line);
+
+    if trace.verdict == TraceVerdict::Unchecked && !args.skip_registry_check {
+        let runtime = tokio::runtime::Runtime::new()?;
+        let lookup = runtime.block_on(validator.lookup_registry(registry, &args.import));
+        trace.set_lookup(lookup);
+    }
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&trace)?);
+    } else {
+        println!("{}", trace);
+    }
+
+    Ok(if trace.verdict.is_violation() { EXIT_FAILED } else { EXIT_SUCCESS })
+}
+
+/// Run the diff command.
+///
+/// Returns `EXIT_FAILED` when the head report has violations the base does not.
+pub fn run_diff(args: &DiffArgs) -> anyhow::Result<i32> {
+    let format = resolve_format(&args.format, std::io::stdout().is_terminal());
+    if format != "pretty" && format != "json" {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', or 'json'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+
+    let base = diff::load_report(&args.base)?;
+    let head = diff::load_report(&args.head)?;
+    let report_diff = diff::diff_reports(base, head);
+
+    if format == "json" {
+        println!("{}", diff::render_diff_json(&report_diff)?);
+    } else {
+        print!("{}", diff::render_diff_pretty(&report_diff));
+    }
+
+    Ok(if report_diff.has_added() { EXIT_FAILED } else { EXIT_SUCCESS })
+}
+
+/// Run the doctor command.
+///
+/// Returns `EXIT_FAILED` when an analyzer fails its self-test.
+pub fn run_doctor(args: &DoctorArgs) -> anyhow::Result<i32> {
+    let format = resolve_format(&args.format, std::io::stdout().is_terminal());
+    if format != "pretty" && format != "json" {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', or 'json'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+
+    let contract = match args.contract.clone().or_else(|| discover_contract(Path::new("."))) {
+        Some(path) => Contract::parse_file(&path)?,
+        None => Contract::default_contract(),
+    };
+    let config = contract.dependency_verification.unwrap_or_default();
+    let report = doctor::run_doctor(config, args.offline)?;
+
+    if format == "json" {
+        println!("{}", doctor::render_doctor_json(&report)?);
+    } else {
+        print!("{}", doctor::render_doctor_pretty(&report));
+    }
+
+    Ok(if report.passed() { EXIT_SUCCESS } else { EXIT_FAILED })
+}
+
+/// Run the waivers command.
+pub fn run_waivers(args: &WaiversArgs) -> anyhow::Result<i32> {
+    let format = resolve_format(&args.format, std::io::stdout().is_terminal());
+    if format != "pretty" && format != "json" {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', or 'json'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+
+    let Some(path) = args.contract.clone().or_else(|| discover_contract(Path::new("."))) else {
+        eprintln!("Error: no contract file found");
+        return Ok(EXIT_ERROR);
+    };
+    let contract = Contract::parse_file(&path)?;
+    if let Err(e) = contract::validate(&contract) {
+        eprintln!("Error: invalid contract: {}", e);
+        return Ok(EXIT_ERROR);
+    }
+
+    let today = crate::detect::today();
+    let entries = waiver_entries(&contract.waivers, &today, args.within);
+    if format == "json" {
+        println!("{}", report::render_waivers_json(&entries)?);
+    } else {
+        print!("{}", report::render_waivers_pretty(&entries, &today));
+    }
+    Ok(EXIT_SUCCESS)
+}
+
+/// Run the migrate command.
+///
+/// The converted file goes to stdout or `--output`; what was dropped or
+/// changed is reported on stderr.
+pub fn run_migrate(args: &MigrateArgs) -> anyhow::Result<i32> {
+    let (file, converted, label, notes) = match &args.command {
+        MigrateCommand::Contract(file) => {
+            let source = std::fs::read_to_string(&file.path)?;
+            let origin = file.path.display().to_string();
+            let migration = migrate::migrate_contract(&source, &origin)?;
+            (file, migration.yaml, "Warning", migration.warnings)
+        }
+        MigrateCommand::Report(file) => {
+            let source = std::fs::read_to_string(&file.path)?;
+            let migration = migrate::migrate_report(&source)?;
+            let json = serde_json::to_string_pretty(&migration.report)?;
+            (file, format!("{}\n", json), "Upgraded", migration.changes)
+        }
+    };
+
+    for note in &notes {
+        eprintln!("{}: {}", label, note);
+    }
+    match &file.output {
+        Some(output) => {
+            std::fs::write(output, converted)?;
+            eprintln!("Wrote {}", output.display());
+        }
+        None => print!("{}", converted),
+    }
+    Ok(EXIT_SUCCESS)
+}
+
+/// Run the ci command.
+///
+/// Resolves the platform's default plan, applies explicit flags over it, and
+/// runs a lint whose report goes to stdout, an artifact file, and the job
+/// summary at once.
+pub fn run_ci(args: &CiArgs) -> anyhow::Result<i32> {
+    let env = |name: &str| std::env::var(name).ok();
+    let mut plan = match args.platform.as_deref() {
+        Some(name) => match Platform::parse(name) {
+            Some(platform) => CiPlan::for_platform(platform, env),
+            None => {
+                eprintln!(
+                    "Error: invalid platform {:?}, must be 'github', 'gitlab', 'jenkins', or 'generic'",
+                    name
+                );
+                return Ok(EXIT_ERROR);
+            }
+        },
+        None => CiPlan::detect(env),
+    };
+
+    // Explicit flags win over platform defaults
+    if let Some(ref console) = args.console {
+        plan.console_format = console.clone();
+    }
+    if let Some(ref format) = args.format {
+        plan.artifact_format = format.clone();
+    }
+    if let Some(ref output) = args.output {
+        plan.artifact_path = output.clone();
+    }
+    if args.summary.is_some() {
+        plan.summary_path = args.summary.clone();
+    }
+    if args.no_summary {
+        plan.summary_path = None;
+    }
+    if args.changed_since.is_some() {
+        plan.changed_since = args.changed_since.clone();
+    }
+    if args.full_scan {
+        plan.changed_since = None;
+    }
+
+    if !["github", "pretty", "json"].contains(&plan.console_format.as_str()) {
+        eprintln!(
+            "Error: invalid console format {:?}, must be 'github', 'pretty', or 'json'",
+            plan.console_format
+        );
+        return Ok(EXIT_ERROR);
+    }
+    if !["sarif", "gitlab", "json"].contains(&plan.artifact_format.as_str()) {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'sarif', 'gitlab', or 'json'",
+            plan.artifact_format
+        );
+        return Ok(EXIT_ERROR);
+    }
+
+    // Shallow clones often lack the target branch; fall back to a full scan
+    if let Some(base) = plan.changed_since.clone() {
+        if ci::resolve_ref(&args.path, &base).is_none() {
+            eprintln!(
+                "Warning: base ref {:?} not found (fetch it or deepen the clone); reporting all files",
+                base
+            );
+            plan.changed_since = None;
+        }
+    }
+
+    if args.dry_run {
+        println!("{}", plan);
+        return Ok(EXIT_SUCCESS);
+    }
+
+    let lint_args = LintArgs {
+        path: args.path.clone(),
+        contract: args.contract.clone(),
+        contract_sha256: args.contract_sha256.clone(),
+        format: plan.console_format.clone(),
+        go_compat: false,
+        theme: None,
+        threshold: args.threshold,
+        profile: args.profile.clone(),
+        strict_waivers: args.strict_waivers,
+        max_violations_per_rule: args.max_violations_per_rule,
+        max_violations_total: args.max_violations_total,
+        mode: None,
+        show_suppressed: false,
+        show_fingerprints: false,
+        show_clean_rules: false,
+        skip_registry_check: args.skip_registry_check,
+        only_rules: Vec::new(),
+        skip_rules: Vec::new(),
+        explain_dependencies: false,
+        strict: false,
+        relaxed: false,
+        exclude_patterns: Vec::new(),
+        include_patterns: Vec::new(),
+        no_default_excludes: false,
+        languages: Vec::new(),
+        list_files: false,
+        config_dump: false,
+        changed_since: plan.changed_since.clone(),
+        baseline: None,
+        only_new: false,
+        severity_summary: false,
+        quiet: false,
+        count_only: false,
+        package: None,
+        message_format: None,
+        fix_suppressions: false,
+        yes: false,
+        diff_only: false,
+        force: false,
+        watch: false,
+        serve: None,
+        events: None,
+        events_fd: None,
+        cargo: false,
+        contract_from_scan_path: false,
+    };
+    let Scan {
+        abs_path,
+        contract_path,
+        result,
+        hollowness,
+    } = match scan(&lint_args, false)? {
+        Ok(scan) => scan,
+        Err(code) => return Ok(code),
+    };
+    let path_str = args.path.to_string_lossy().to_string();
+
+    match plan.console_format.as_str() {
+        "github" => print!("{}", report::render_github_annotations(&abs_path, &result)),
+        "json" => report::write_json(
+            &path_str,
+            &contract_path,
+            &result,
+            &hollowness,
+            report::JsonOptions::default(),
+        )?,
+        _ => report::write_pretty(
+            &path_str,
+            &contract_path,
+            &result,
+            &hollowness,
+            report::PrettyOptions::default(),
+        ),
+    }
+
+    let artifact = match plan.artifact_format.as_str() {
+        "sarif" => report::render_sarif(&abs_path, &result)?,
+        "gitlab" => report::render_gitlab(&abs_path, &result)?,
+        _ => report::render_json(
+            &path_str,
+            &contract_path,
+            &result,
+            &hollowness,
+            report::JsonOptions::default(),
+        )?,
+    };
+    std::fs::write(&plan.artifact_path, artifact)
+        .map_err(|e| anyhow::anyhow!("cannot write {:?}: {}", plan.artifact_path, e))?;
+
+    if let Some(ref summary_path) = plan.summary_path {
+        use std::io::Write;
+        let mut summary = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(summary_path)
+            .map_err(|e| anyhow::anyhow!("cannot open summary {:?}: {}", summary_path, e))?;
+        summary.write_all(report::render_step_summary(&abs_path, &result, &hollowness).as_bytes())?;
+    }
+
+    Ok(if hollowness.passed { EXIT_SUCCESS } else { EXIT_FAILED })
+}
+
+/// Scan one fleet repository the way `lint` would, writing its full JSON
+/// report into `reports_dir` when one is given.
+fn scan_fleet_repo(repo: &FleetRepo, args: &FleetArgs) -> anyhow::Result<RepoScan> {
+    // Checked up front so the fleet report carries the reason, not just an exit code
+    repo.path
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("cannot access path {:?}: {}", repo.path, e))?;
+    if let Some(ref contract) = repo.contract {
+        let profile = args.profile.clone().or_else(|| std::env::var(contract::PROFILE_ENV).ok());
+        let (resolved, _) = Contract::resolve_file(contract, profile.as_deref().filter(|p| !p.is_empty()))
+            .map_err(|e| anyhow::anyhow!("invalid contract {:?}: {}", contract, e))?;
+        contract::validate(&resolved).map_err(|e| anyhow::anyhow!("invalid contract {:?}: {}", contract, e))?;
+    }
+
+    let lint_args = LintArgs {
+        path: repo.path.clone(),
+        contract: repo.contract.clone(),
+        contract_sha256: None,
+        format: "json".to_string(),
+        go_compat: false,
+        theme: None,
+        threshold: None,
+        profile: args.profile.clone(),
+        strict_waivers: None,
+        max_violations_per_rule: None,
+        max_violations_total: None,
+        mode: None,
+        show_suppressed: false,
+        show_fingerprints: false,
+        show_clean_rules: false,
+        skip_registry_check: args.skip_registry_check,
+        only_rules: Vec::new(),
+        skip_rules: Vec::new(),
+        explain_dependencies: false,
+        strict: false,
+        relaxed: false,
+        exclude_patterns: Vec::new(),
+        include_patterns: Vec::new(),
+        no_default_excludes: false,
+        languages: Vec::new(),
+        list_files: false,
+        config_dump: false,
+        changed_since: None,
+        baseline: None,
+        only_new: false,
+        severity_summary: false,
+        quiet: false,
+        count_only: false,
+        package: None,
+        message_format: None,
+        fix_suppressions: false,
+        yes: false,
+        diff_only: false,
+        force: false,
+        watch: false,
+        serve: None,
+        events: None,
+        events_fd: None,
+        cargo: false,
+        contract_from_scan_path: true,
+    };
+    let Scan {
+        contract_path,
+        result,
+        hollowness,
+        ..
+    } = match scan(&lint_args, false)? {
+        Ok(scan) => scan,
+        Err(EXIT_SUCCESS) => anyhow::bail!("no files to scan"),
+        Err(code) => anyhow::bail!("scan exited with code {}; see the errors above", code),
+    };
+
+    let mut summary = RepoScan::new(&result, hollowness.clone());
+    if let Some(ref dir) = args.reports_dir {
+        let path = dir.join(fleet::report_file_name(&repo.name()));
+        let json = report::render_json(
+            &repo.path.to_string_lossy(),
+            &contract_path,
+            &result,
+            &hollowness,
+            report::JsonOptions::default(),
+        )?;
+        std::fs::write(&path, json).map_err(|e| anyhow::anyhow!("cannot write {:?}: {}", path, e))?;
+        summary.report = Some(path);
+    }
+    Ok(summary)
+}
+
+/// Run the fleet command.
+///
+/// Returns `EXIT_FAILED` when any repository fails its threshold or could
+/// not be scanned.
+pub fn run_fleet(args: &FleetArgs) -> anyhow::Result<i32> {
+    let format = resolve_format(&args.format, std::io::stdout().is_terminal());
+    if format != "pretty" && format != "json" {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', or 'json'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+    if args.jobs == Some(0) {
+        eprintln!("Error: --jobs must be at least 1");
+        return Ok(EXIT_ERROR);
+    }
+
+    let manifest = match fleet::load_manifest(&args.manifest) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return Ok(EXIT_ERROR);
+        }
+    };
+    if let Some(ref dir) = args.reports_dir {
+        std::fs::create_dir_all(dir).map_err(|e| anyhow::anyhow!("cannot create {:?}: {}", dir, e))?;
+    }
+
+    parser::init();
+    let jobs = args.jobs.or(manifest.jobs).unwrap_or_else(rayon::current_num_threads);
+    let show_progress = format == "pretty" && std::io::stderr().is_terminal();
+    let outcomes = fleet::scan_fleet(&manifest.repos, jobs, |repo| {
+        let scanned = scan_fleet_repo(repo, args);
+        if show_progress {
+            let mark = if scanned.is_ok() { "✓".green() } else { "!".yellow() };
+            eprintln!("  {} {}", mark, repo.name().dimmed());
+        }
+        scanned
+    })?;
+
+    // A repository that could not be scanned keeps no report from an earlier run
+    if let Some(ref dir) = args.reports_dir {
+        for outcome in outcomes.iter().filter(|o| o.result.is_err()) {
+            let stale = dir.join(fleet::report_file_name(&outcome.repo.name()));
+            if let Err(e) = std::fs::remove_file(&stale) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    eprintln!("Warning: cannot remove stale report {:?}: {}", stale, e);
+                }
+            }
+        }
+    }
+
+    let fleet_report = fleet::aggregate(outcomes);
+    if format == "json" {
+        println!("{}", fleet::render_fleet_json(&fleet_report)?);
+    } else {
+        print!("{}", fleet::render_fleet_pretty(&fleet_report));
+    }
+
+    Ok(if fleet_report.passed() { EXIT_SUCCESS } else { EXIT_FAILED })
+}
+
+/// Run the prefetch command.
+///
+/// Collects files the way `lint` does and warms the caches for their
+/// imports. Failed lookups are reported but do not fail the command: the
+/// lint run looks those packages up again.
+pub fn run_prefetch(args: &PrefetchArgs) -> anyhow::Result<i32> {
+    let format = resolve_format(&args.format, std::io::stdout().is_terminal());
+    if format != "pretty" && format != "json" {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', or 'json'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+
+    let abs_path = args
+        .path
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("cannot access path {:?}: {}", args.path, e))?;
+    let profile = args
+        .profile
+        .clone()
+        .or_else(|| std::env::var(contract::PROFILE_ENV).ok())
+        .filter(|p| !p.is_empty());
+    let contract = match args.contract.clone().or_else(|| discover_contract(&abs_path)) {
+        Some(path) => Contract::resolve_file(&path, profile.as_deref())?.0,
+        None => Contract::default_contract(),
+    };
+    contract::validate(&contract).map_err(|e| anyhow::anyhow!("invalid contract: {}", e))?;
+
+    let files = if abs_path.is_dir() {
+        collect_files(FileDiscovery::new(&abs_path, &contract))?.0
+    } else {
+        vec![abs_path.clone()]
+    };
+
+    let config = contract.dependency_verification.clone().unwrap_or_default();
+    let report = prefetch::run_prefetch(&abs_path, &files, &config)?;
+
+    if format == "json" {
+        println!("{}", prefetch::render_prefetch_json(&report)?);
+    } else {
+        print!("{}", prefetch::render_prefetch_pretty(&report));
+    }
+    if !report.complete() {
+        eprintln!("Warning: some packages are not cached; lint runs will look them up");
+    }
+
+    Ok(EXIT_SUCCESS)
+}
+
+/// Run the verify-spec command.
+///
+/// Collects files the way `lint` does and resolves each expected artifact
+/// of the spec against them. Fails when the completion is below the minimum.
+pub fn run_verify_spec(args: &VerifySpecArgs) -> anyhow::Result<i32> {
+    let format = resolve_format(&args.format, std::io::stdout().is_terminal());
+    if format != "pretty" && format != "json" {
+        eprintln!(
+            "Error: invalid format {:?}, must be 'auto', 'pretty', or 'json'",
+            args.format
+        );
+        return Ok(EXIT_ERROR);
+    }
+    if let Some(min) = args.min_completion {
+        if !(0.0..=100.0).contains(&min) {
+            eprintln!("Error: --min-completion {} must be between 0 and 100", min);
+            return Ok(EXIT_ERROR);
+        }
+    }
+
+    let task_spec = TaskSpec::parse_file(&args.spec)?;
+    let abs_path = args
+        .path
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("cannot access path {:?}: {}", args.path, e))?;
+    let profile = args
+        .profile
+        .clone()
+        .or_else(|| std::env::var(contract::PROFILE_ENV).ok())
+        .filter(|p| !p.is_empty());
+    let contract = match args.contract.clone().or_else(|| discover_contract(&abs_path)) {
+        Some(path) => Contract::resolve_file(&path, profile.as_deref())?.0,
+        None => Contract::default_contract(),
+    };
+    contract::validate(&contract).map_err(|e| anyhow::anyhow!("invalid contract: {}", e))?;
+
+    let (base_dir, files) = if abs_path.is_dir() {
+        let files = collect_files(FileDiscovery::new(&abs_path, &contract))?.0;
+        (abs_path.clone(), files)
+    } else {
+        let parent = abs_path.parent().map(Path::to_path_buf).unwrap_or_else(|| abs_path.clone());
+        (parent, vec![abs_path.clone()])
+    };
+
+    crate::init();
+    let mut stub_config = StubDetectionConfig::default_enabled();
+    stub_config.markers = Some(Arc::new(contract.markers.clone().unwrap_or_default().vocabulary()?));
+    if let Some(exemptions) = &contract.stub_exemptions {
+        stub_config.exemptions = exemptions.resolve()?;
+    }
+    let repetitive_config = contract.repetitive_bodies.clone().unwrap_or_default();
+    stub_config.min_distinct_ratio = if repetitive_config.is_enabled() { repetitive_config.min_distinct_ratio() } else { 0.0 };
+    stub_config.repetitive_min_statements = repetitive_config.min_statements();
+    stub_config.custom_signatures = contract
+        .custom_stub_signatures
+        .iter()
+        .map(|s| s.resolve())
+        .collect::<anyhow::Result<_>>()?;
+    let report = spec::verify_spec(&base_dir, &files, &task_spec, &stub_config, args.min_completion)?;
+
+    if format == "json" {
+        println!("{}", spec::render_spec_json(&report)?);
+    } else {
+        print!("{}", spec::render_spec_pretty(&report));
+    }
+
+    Ok(if report.passed() { EXIT_SUCCESS } else { EXIT_FAILED })
+}
+
+/// Resolve `--format auto` against whether stdout is a terminal.
+///
+/// Explicit formats are returned unchanged.
+fn resolve_format(format: &str, stdout_is_tty: bool) -> &str {
+    match format {
+        "auto" if stdout_is_tty => "pretty",
+        "auto" => "json",
+        other => other,
+    }
+}
+
 /// Run the init command.
 pub fn run_init(args: &InitArgs) -> anyhow::Result<i32> {
     // List mode
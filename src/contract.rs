@@ -3,9 +3,15 @@
 //! A contract defines the quality requirements for a codebase.
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
+use crate::policy::{self, RemoteContract};
+
+/// Environment variable naming the profile to use when `--profile` is not given.
+pub const PROFILE_ENV: &str = "HOLLOWCHECK_PROFILE";
+
 /// Top-level contract definition.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct Contract {
@@ -15,6 +21,9 @@ pub struct Contract {
     pub name: String,
     #[serde(default)]
     pub description: Option<String>,
+    /// Remote contract this one builds on, pinned by its sha256
+    #[serde(default)]
+    pub extends: Option<RemoteContract>,
     /// "code" (default) or "prose"
     #[serde(default)]
     pub mode: Option<String>,
@@ -24,16 +33,47 @@ pub struct Contract {
     /// Glob patterns for paths to exclude from analysis (e.g., "**/docs/**", "**/examples/**")
     #[serde(default)]
     pub excluded_paths: Vec<String>,
+    /// Languages to analyze (allowlist) or skip (denylist)
+    #[serde(default)]
+    pub languages: Option<LanguageFilter>,
+    /// Analyze files matching a glob as the given language (`"*.go.tmpl": go`).
+    /// An inline `hollowcheck:language` comment in the file wins
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub language_overrides: BTreeMap<String, String>,
+    /// Dependency and build directories skipped per ecosystem. Default: all ecosystems
+    #[serde(default)]
+    pub default_excludes: Option<DefaultExcludesConfig>,
     #[serde(default)]
     pub required_files: Vec<RequiredFile>,
     #[serde(default)]
     pub required_symbols: Vec<RequiredSymbol>,
     #[serde(default)]
     pub forbidden_patterns: Vec<ForbiddenPattern>,
+    /// Glob patterns for files that must not be part of the deliverable
+    #[serde(default)]
+    pub forbidden_files: Vec<ForbiddenFile>,
+    /// Whether `.env` files are forbidden by the built-in pattern. Default: true
+    #[serde(default)]
+    pub forbid_env_files: Option<bool>,
+    /// Size limits for files in the deliverable
+    #[serde(default)]
+    pub max_file_size: Option<MaxFileSizeConfig>,
     #[serde(default)]
     pub mock_signatures: Option<MockSignaturesConfig>,
     #[serde(default)]
     pub complexity: Vec<ComplexityRequirement>,
+    /// Minimum share of implemented (non-stub) callables per glob
+    #[serde(default)]
+    pub implementation_coverage: Vec<ImplementationCoverage>,
+    /// Allow rules for intentional stubs, merged with or replacing the built-in rules
+    #[serde(default)]
+    pub stub_exemptions: Option<StubExemptionsConfig>,
+    /// Long function bodies that repeat a few statements, reported as stubs. Default: enabled
+    #[serde(default)]
+    pub repetitive_bodies: Option<RepetitiveBodiesConfig>,
+    /// Project stub idioms: names that make a one-statement body a stub when it calls, raises, or returns them
+    #[serde(default)]
+    pub custom_stub_signatures: Vec<CustomStubSignatureConfig>,
     #[serde(default)]
     pub required_tests: Vec<RequiredTest>,
     #[serde(default)]
@@ -44,17 +84,243 @@ pub struct Contract {
     pub dependency_verification: Option<DependencyVerificationConfig>,
     #[serde(default)]
     pub god_objects: Option<GodObjectContractConfig>,
+    /// Functions with too many parameters. Default: enabled, max 7
+    #[serde(default)]
+    pub long_parameter_lists: Option<LongParameterListsConfig>,
     /// Whether to detect hollow TODOs (TODOs without meaningful context). Default: true
     #[serde(default)]
     pub hollow_todos: Option<HollowTodosConfig>,
+    /// Marker words and ticket references shared by stub detection, hollow TODOs, and
+    /// forbidden patterns. Default: TODO, FIXME, XXX, HACK, UNIMPLEMENTED; no ticket pattern
+    #[serde(default)]
+    pub markers: Option<MarkersConfig>,
+    /// Whether to detect floating promises in JS/TS. Default: true
+    #[serde(default)]
+    pub floating_promises: Option<FloatingPromisesConfig>,
+    /// Opt-in detection of string literals repeated across the project
+    #[serde(default)]
+    pub repeated_literals: Option<RepeatedLiteralsConfig>,
+    /// Opt-in check that every file starts with the expected license header
+    #[serde(default)]
+    pub detect_header: Option<HeaderConfig>,
+    /// Opt-in check that documented API exists in the code
+    #[serde(default)]
+    pub doc_drift: Option<DocDriftConfig>,
+    /// HTTP handlers that respond 200 with an empty body. Default: enabled
+    #[serde(default)]
+    pub empty_handlers: Option<EmptyHandlersConfig>,
+    /// Wall-clock budget for parsing very large files. Default: enabled
+    #[serde(default)]
+    pub analysis_budget: Option<AnalysisBudgetConfig>,
+    /// Opt-in check that every interface and trait has an implementing type
+    #[serde(default)]
+    pub unimplemented_interfaces: Option<UnimplementedInterfacesConfig>,
+    /// Opt-in check for interfaces, factories, and type parameters with a single type behind them
+    #[serde(default)]
+    pub premature_abstraction: Option<PrematureAbstractionConfig>,
+    /// Weak hashes and ciphers, ECB mode, and hardcoded IVs and salts. Default: enabled
+    #[serde(default)]
+    pub weak_crypto: Option<WeakCryptoConfig>,
+    /// Python functions, classes, and variables named after a builtin. Default: enabled
+    #[serde(default)]
+    pub builtin_shadowing: Option<BuiltinShadowingConfig>,
+    /// Hollow and placeholder Dockerfiles and compose files. Default: enabled
+    #[serde(default)]
+    pub containers: Option<ContainerChecksConfig>,
+    /// Placeholder images and TODO steps in CI pipeline files. Default: enabled
+    #[serde(default)]
+    pub ci_configs: Option<CiConfigChecksConfig>,
+    /// Empty definitions and placeholder fields in Protobuf and GraphQL schemas. Default: enabled
+    #[serde(default)]
+    pub schemas: Option<SchemaChecksConfig>,
+    /// Statements with no effect that pad function bodies. Default: enabled
+    #[serde(default)]
+    pub noop_statements: Option<NoopStatementsConfig>,
+    /// Go functions that accept a `context.Context` and never use it. Default: enabled
+    #[serde(default)]
+    pub unused_context: Option<UnusedContextConfig>,
+    /// Go named results that a bare `return` hands back without ever being assigned. Default: enabled
+    #[serde(default)]
+    pub unassigned_named_returns: Option<UnassignedNamedReturnsConfig>,
+    /// Constant groups with identical, or sequential and TODO-marked, values. Default: enabled
+    #[serde(default)]
+    pub placeholder_constants: Option<PlaceholderConstantsConfig>,
+    /// C and C++ header functions with no definition in any scanned translation unit. Default: enabled
+    #[serde(default)]
+    pub missing_definitions: Option<MissingDefinitionsConfig>,
+    /// Deprecated callables still called from many sites. Default: enabled
+    #[serde(default)]
+    pub deprecated_usage: Option<DeprecatedUsageConfig>,
+    /// Rust functions with many `unwrap`/`expect`/`panic!` sites. Default: enabled
+    #[serde(default)]
+    pub unwrap_overuse: Option<UnwrapOveruseConfig>,
+    /// Swift functions with many `!` force-unwraps and `try!` expressions. Default: enabled
+    #[serde(default)]
+    pub force_unwraps: Option<ForceUnwrapsConfig>,
+    /// Relative and project-local imports of modules that do not exist. Default: enabled
+    #[serde(default)]
+    pub missing_local_modules: Option<MissingLocalModulesConfig>,
+    /// Error handlers that log an error and rethrow it unchanged. Default: enabled
+    #[serde(default)]
+    pub redundant_error_logging: Option<RedundantErrorLoggingConfig>,
+    /// Functions that panic where the rest of their file returns errors, or the reverse. Default: enabled
+    #[serde(default)]
+    pub inconsistent_error_handling: Option<InconsistentErrorHandlingConfig>,
+    /// Async functions whose body never awaits anything. Default: enabled
+    #[serde(default)]
+    pub async_without_await: Option<AsyncWithoutAwaitConfig>,
+    /// Python parameters that default to a mutable list, dict, or set. Default: enabled
+    #[serde(default)]
+    pub mutable_defaults: Option<MutableDefaultsConfig>,
+    /// Files that end inside a function, as cut-off generated output leaves them. Default: enabled
+    #[serde(default)]
+    pub truncated_code: Option<TruncatedCodeConfig>,
+    /// Files stamped as generated (`Code generated ... DO NOT EDIT.`, `@generated`): skipped,
+    /// or checked only by the listed rules. Default: checked like any other file
+    #[serde(default)]
+    pub generated_files: Option<GeneratedFilesConfig>,
+    /// Opt-in check for switches over an enum that leave most variants to a trivial default
+    #[serde(default)]
+    pub unhandled_enum_cases: Option<UnhandledEnumCasesConfig>,
+    /// Opt-in check for callables that only forward their parameters to another project function
+    #[serde(default)]
+    pub trivial_wrappers: Option<TrivialWrappersConfig>,
+    /// Opt-in check for files with no comments across complex functions, or mostly comments
+    #[serde(default)]
+    pub comment_ratio: Option<CommentRatioConfig>,
+    /// Opt-in check that exported declarations carry doc comments, with an optional coverage gate
+    #[serde(default)]
+    pub doc_coverage: Option<DocCoverageConfig>,
+    /// Opt-in check for inline suppressions that suppress nothing or are shadowed by a file-level one
+    #[serde(default)]
+    pub unused_suppressions: Option<UnusedSuppressionsConfig>,
+    /// Language, runtime, and framework versions the delivery must target. Without it,
+    /// the versions found are only reported
+    #[serde(default)]
+    pub environment: Option<EnvironmentConfig>,
+    /// Expectations for YAML, JSON, and TOML configuration files, by glob
+    #[serde(default)]
+    pub config_files: Vec<ConfigFileSpec>,
+    /// Violations accepted on purpose, each until an expiry date
+    #[serde(default)]
+    pub waivers: Vec<Waiver>,
+    /// Rule severities by path: each entry turns rules off or sets their
+    /// severity for the files matching its globs
+    #[serde(default)]
+    pub scopes: Vec<RuleScope>,
+    /// Maximum acceptable hollowness score. Default: 25
+    #[serde(default)]
+    pub threshold: Option<i32>,
+    /// Maximum acceptable debt score: points kept out of the score by
+    /// suppressions, downgrades, waivers, and the baseline. Default: no limit
+    #[serde(default)]
+    pub max_debt: Option<i32>,
+    /// Structural and content sub-scores: rule categories and a threshold for each
+    #[serde(default)]
+    pub sub_scores: Option<SubScoresConfig>,
+    /// Points per violation by rule name, overriding the built-in weights
+    /// (e.g., `mock_data: 5`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub points: BTreeMap<String, i32>,
+    /// Opt-in heuristic estimate of the work left behind each hollow function
+    #[serde(default)]
+    pub estimation: Option<EstimationConfig>,
+    /// Violations of one rule recorded in the report; the rest are only counted
+    #[serde(default)]
+    pub max_violations_per_rule: Option<usize>,
+    /// Violations recorded in the report across all rules; the rest are only counted
+    #[serde(default)]
+    pub max_violations_total: Option<usize>,
+    /// Named sets of overrides merged over the settings above when selected
+    #[serde(default)]
+    pub profiles: BTreeMap<String, serde_yaml::Value>,
+}
+
+/// Refuse a contract written for the Go version, pointing at `hollowcheck migrate`.
+///
+/// Its settings would otherwise be ignored or fail with a schema error that
+/// does not say why.
+fn reject_go_contract(path: &Path, value: &serde_yaml::Value) -> anyhow::Result<()> {
+    let fields = crate::migrate::go_contract_fields(value);
+    if fields.is_empty() {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "{} looks like a contract for the Go version of hollowcheck (it uses {}); \
+         convert it with `hollowcheck migrate contract {}`",
+        path.display(),
+        fields.iter().map(|f| format!("`{}`", f)).collect::<Vec<_>>().join(", "),
+        path.display()
+    )
 }
 
 impl Contract {
     /// Parse a contract from a YAML file.
     pub fn parse_file<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
+        Self::parse_file_with_profile(path, None)
+    }
+
+    /// Parse a contract from a YAML file with a profile merged over it.
+    ///
+    /// With no profile this is `parse_file`. See [`merge_profile`] for how
+    /// the profile's settings combine with the base.
+    pub fn parse_file_with_profile<P: AsRef<Path>>(
+        path: P,
+        profile: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        Self::resolve_file(path, profile).map(|(contract, _)| contract)
+    }
+
+    /// Fetch a remote contract and parse it with a profile merged over it.
+    ///
+    /// The content must match the pin; see [`policy::fetch_contract`].
+    pub fn parse_remote_with_profile(
+        remote: &RemoteContract,
+        profile: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        Self::resolve_remote(remote, profile).map(|(contract, _)| contract)
+    }
+
+    /// Parse a contract file like [`Contract::parse_file_with_profile`],
+    /// also returning the settings each layer over the base set.
+    ///
+    /// Settings the file sets over the contract it `extends` are attributed
+    /// to the file; settings from the profile to `profile "<name>"`.
+    pub fn resolve_file<P: AsRef<Path>>(
+        path: P,
+        profile: Option<&str>,
+    ) -> anyhow::Result<(Self, Vec<ValueSource>)> {
         let content = fs::read_to_string(path.as_ref())?;
-        let contract: Contract = serde_yaml::from_str(&content)?;
-        Ok(contract)
+        let value: serde_yaml::Value = serde_yaml::from_str(&content)?;
+        reject_go_contract(path.as_ref(), &value)?;
+        Self::resolve_layers(value, &path.as_ref().display().to_string(), profile)
+    }
+
+    /// Fetch and parse a remote contract like
+    /// [`Contract::parse_remote_with_profile`], also returning the settings
+    /// each layer over the base set.
+    pub fn resolve_remote(
+        remote: &RemoteContract,
+        profile: Option<&str>,
+    ) -> anyhow::Result<(Self, Vec<ValueSource>)> {
+        let content = policy::fetch_contract(remote)?;
+        let value: serde_yaml::Value = serde_yaml::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("invalid contract {}: {}", remote.url, e))?;
+        Self::resolve_layers(value, &remote.url, profile)
+    }
+
+    /// Merge `extends` and the profile into a contract document and parse it.
+    fn resolve_layers(
+        mut value: serde_yaml::Value,
+        source: &str,
+        profile: Option<&str>,
+    ) -> anyhow::Result<(Self, Vec<ValueSource>)> {
+        let mut sources = ValueSource::from_layer(resolve_extends(&mut value)?, source);
+        if let Some(profile) = profile {
+            let keys = merge_profile(&mut value, profile)?;
+            sources.extend(ValueSource::from_layer(keys, &format!("profile {:?}", profile)));
+        }
+        Ok((serde_yaml::from_value(value)?, sources))
     }
 
     /// Create a default minimal contract for use when no contract file is found.
@@ -67,20 +333,78 @@ impl Contract {
             version: "1.0".to_string(),
             name: "default".to_string(),
             description: Some("Auto-generated default contract".to_string()),
+            extends: None,
             mode: Some("code".to_string()),
             include_test_files: Some(false),
             excluded_paths: vec![],
+            languages: None,
+            language_overrides: BTreeMap::new(),
+            default_excludes: Some(DefaultExcludesConfig::default()),
             required_files: vec![],
             required_symbols: vec![],
             forbidden_patterns: default_forbidden_patterns(),
+            forbidden_files: vec![],
+            forbid_env_files: Some(true),
+            max_file_size: None,
             mock_signatures: Some(default_mock_signatures()),
             complexity: vec![],
+            implementation_coverage: vec![],
+            stub_exemptions: None,
+            repetitive_bodies: None,
+            custom_stub_signatures: vec![],
             required_tests: vec![],
             coverage_threshold: None,
             prose: None,
             dependency_verification: Some(default_dependency_verification()),
             god_objects: Some(default_god_objects()),
+            long_parameter_lists: Some(LongParameterListsConfig::default()),
             hollow_todos: Some(HollowTodosConfig { enabled: true }),
+            markers: None,
+            floating_promises: Some(FloatingPromisesConfig { enabled: true }),
+            repeated_literals: None,
+            detect_header: None,
+            doc_drift: None,
+            empty_handlers: Some(EmptyHandlersConfig::default()),
+            analysis_budget: Some(AnalysisBudgetConfig::default()),
+            unimplemented_interfaces: None,
+            premature_abstraction: None,
+            weak_crypto: Some(WeakCryptoConfig::default()),
+            builtin_shadowing: Some(BuiltinShadowingConfig::default()),
+            containers: Some(ContainerChecksConfig::default()),
+            ci_configs: Some(CiConfigChecksConfig::default()),
+            schemas: Some(SchemaChecksConfig::default()),
+            noop_statements: Some(NoopStatementsConfig::default()),
+            unused_context: Some(UnusedContextConfig::default()),
+            unassigned_named_returns: Some(UnassignedNamedReturnsConfig::default()),
+            placeholder_constants: Some(PlaceholderConstantsConfig::default()),
+            missing_definitions: Some(MissingDefinitionsConfig::default()),
+            deprecated_usage: Some(DeprecatedUsageConfig::default()),
+            unwrap_overuse: Some(UnwrapOveruseConfig::default()),
+            force_unwraps: Some(ForceUnwrapsConfig::default()),
+            missing_local_modules: Some(MissingLocalModulesConfig::default()),
+            redundant_error_logging: Some(RedundantErrorLoggingConfig::default()),
+            inconsistent_error_handling: Some(InconsistentErrorHandlingConfig::default()),
+            async_without_await: Some(AsyncWithoutAwaitConfig::default()),
+            mutable_defaults: Some(MutableDefaultsConfig::default()),
+            truncated_code: Some(TruncatedCodeConfig::default()),
+            generated_files: None,
+            unhandled_enum_cases: None,
+            trivial_wrappers: None,
+            comment_ratio: None,
+            doc_coverage: None,
+            unused_suppressions: None,
+            environment: None,
+            config_files: vec![],
+            waivers: vec![],
+            scopes: vec![],
+            threshold: None,
+            max_debt: None,
+            sub_scores: None,
+            points: BTreeMap::new(),
+            estimation: None,
+            max_violations_per_rule: None,
+            max_violations_total: None,
+            profiles: BTreeMap::new(),
         }
     }
 
@@ -96,6 +420,7 @@ impl Contract {
 
     /// Check if a path should be excluded based on excluded_paths patterns.
     /// Uses globset for matching, which supports `**` for recursive directory matching.
+    /// Matching is case-sensitive on every platform, against the paths as walked.
     pub fn is_path_excluded(&self, path: &Path) -> bool {
         if self.excluded_paths.is_empty() {
             return false;
@@ -114,6 +439,63 @@ impl Contract {
         false
     }
 
+    /// Returns whether floating promise detection is enabled (defaults to true).
+    pub fn detect_floating_promises(&self) -> bool {
+        self.floating_promises
+            .as_ref()
+            .map(|c| c.enabled)
+            .unwrap_or(true)
+    }
+
+    /// Returns whether truncated code detection is enabled (defaults to true).
+    pub fn detect_truncated_code(&self) -> bool {
+        self.truncated_code
+            .as_ref()
+            .map(|c| c.enabled)
+            .unwrap_or(true)
+    }
+
+    /// Returns whether a language ID passes the contract's language filter.
+    pub fn is_language_enabled(&self, language: &str) -> bool {
+        self.languages
+            .as_ref()
+            .map(|f| f.allows(language))
+            .unwrap_or(true)
+    }
+
+    /// Check if a path is excluded because its extension maps to a filtered-out language.
+    /// Files whose language is unknown are never excluded by the filter.
+    pub fn is_path_language_excluded(&self, path: &Path) -> bool {
+        let ext = crate::analysis::analysis_extension(path);
+        match crate::analysis::language_for_extension(ext) {
+            Some(language) => !self.is_language_enabled(language),
+            None => false,
+        }
+    }
+
+    /// The language a `language_overrides` glob assigns to a path relative to
+    /// the project root.
+    ///
+    /// Globs without a `/` match the file name. When several globs match, the
+    /// longest wins.
+    pub fn language_override(&self, relative: &Path) -> Option<&'static str> {
+        let path_str = relative.to_string_lossy();
+        let name = relative.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+        self.language_overrides
+            .iter()
+            .filter(|(pattern, _)| {
+                let target = if pattern.contains('/') { &path_str } else { &name };
+                globset::Glob::new(pattern).is_ok_and(|g| g.compile_matcher().is_match(&**target))
+            })
+            .max_by_key(|(pattern, _)| pattern.len())
+            .and_then(|(_, language)| crate::analysis::analyzer_language(language))
+    }
+
+    /// Returns whether `.env` files are forbidden (defaults to true).
+    pub fn forbid_env_files(&self) -> bool {
+        self.forbid_env_files.unwrap_or(true)
+    }
+
     /// Returns whether hollow TODO detection is enabled (defaults to true).
     pub fn detect_hollow_todos(&self) -> bool {
         self.hollow_todos
@@ -123,6 +505,34 @@ impl Contract {
     }
 }
 
+/// Allowlist/denylist of languages to analyze.
+///
+/// Names are language IDs or aliases ("go", "golang", "py", "ts").
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct LanguageFilter {
+    /// Only analyze these languages (all languages when empty)
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// Never analyze these languages (takes precedence over `include`)
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+impl LanguageFilter {
+    /// Returns whether a language ID passes the filter.
+    pub fn allows(&self, language: &str) -> bool {
+        let matches = |names: &[String]| {
+            names
+                .iter()
+                .any(|n| crate::analysis::normalize_language(n) == Some(language))
+        };
+        if matches(&self.exclude) {
+            return false;
+        }
+        self.include.is_empty() || matches(&self.include)
+    }
+}
+
 /// A file that must exist.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RequiredFile {
@@ -168,6 +578,53 @@ pub struct ForbiddenPattern {
     pub description: Option<String>,
 }
 
+/// A glob pattern for files that must not be delivered (e.g., "**/*.orig").
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForbiddenFile {
+    /// Glob pattern relative to the project root
+    pub pattern: String,
+    /// Explanation shown in the violation message
+    #[serde(default)]
+    pub message: Option<String>,
+    /// "critical", "error", "warning", or "info" (default: "error")
+    #[serde(default)]
+    pub severity: Option<String>,
+}
+
+/// Maximum file sizes for the deliverable.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct MaxFileSizeConfig {
+    /// Limit in bytes applied to every file not matched by `paths`
+    #[serde(default)]
+    pub max_bytes: Option<u64>,
+    /// Per-glob limits; the first matching entry wins
+    #[serde(default)]
+    pub paths: Vec<FileSizeLimit>,
+}
+
+impl MaxFileSizeConfig {
+    /// Returns the size limit for a path relative to the project root, if any.
+    pub fn limit_for(&self, rel_path: &str) -> Option<u64> {
+        self.paths
+            .iter()
+            .find(|l| {
+                globset::Glob::new(&l.path)
+                    .map(|g| g.compile_matcher().is_match(rel_path))
+                    .unwrap_or(false)
+            })
+            .map(|l| l.max_bytes)
+            .or(self.max_bytes)
+    }
+}
+
+/// A size limit for files matching a glob.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FileSizeLimit {
+    /// Glob pattern relative to the project root (e.g., "assets/**")
+    pub path: String,
+    pub max_bytes: u64,
+}
+
 /// A regex pattern identifying mock/placeholder data.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct MockSignature {
@@ -217,6 +674,163 @@ pub struct ComplexityRequirement {
     pub min_complexity: i32,
 }
 
+/// Minimum ratio of implemented callables for files matching a glob.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ImplementationCoverage {
+    /// Glob pattern relative to the project root (e.g., "src/api/**")
+    pub path: String,
+    /// Required ratio of non-stub functions/methods, from 0.0 to 1.0
+    pub min_ratio: f64,
+}
+
+/// Stub exemption rules: the built-in rules, minus removed ids, plus the
+/// contract's own.
+///
+/// Also accepts a plain list of rules, which replaces the built-in rules.
+#[derive(Debug, Clone, Serialize)]
+pub struct StubExemptionsConfig {
+    /// Start from the built-in rules (default: true)
+    pub builtins: bool,
+    /// Ids of built-in rules to drop (e.g., "rust-on-hooks")
+    pub remove: Vec<String>,
+    /// Rules to add; a rule with a built-in rule's id replaces it
+    pub rules: Vec<StubExemptionConfig>,
+}
+
+/// The forms `stub_exemptions` is written in.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StubExemptionsSpec {
+    Replace(Vec<StubExemptionConfig>),
+    Merge {
+        #[serde(default = "default_true")]
+        builtins: bool,
+        #[serde(default)]
+        remove: Vec<String>,
+        #[serde(default)]
+        rules: Vec<StubExemptionConfig>,
+    },
+}
+
+impl<'de> Deserialize<'de> for StubExemptionsConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match StubExemptionsSpec::deserialize(deserializer)? {
+            StubExemptionsSpec::Replace(rules) => Self { builtins: false, remove: Vec::new(), rules },
+            StubExemptionsSpec::Merge { builtins, remove, rules } => Self { builtins, remove, rules },
+        })
+    }
+}
+
+impl StubExemptionsConfig {
+    /// The merged rules the stub detector applies.
+    pub fn resolve(&self) -> anyhow::Result<Vec<crate::analysis::StubExemption>> {
+        let builtin_ids: Vec<&str> = crate::analysis::builtin_stub_exemption_ids().collect();
+        for id in &self.remove {
+            if !builtin_ids.contains(&id.as_str()) {
+                anyhow::bail!("unknown built-in stub exemption id {:?} in remove", id);
+            }
+        }
+
+        let mut exemptions: Vec<_> = if self.builtins {
+            crate::analysis::default_stub_exemptions()
+                .into_iter()
+                .filter(|e| !self.remove.contains(&e.id))
+                .collect()
+        } else {
+            Vec::new()
+        };
+        for (i, rule) in self.rules.iter().enumerate() {
+            let exemption = rule
+                .compile(i)
+                .map_err(|e| anyhow::anyhow!("invalid stub_exemptions[{}]: {}", i, e))?;
+            if self.rules[..i].iter().any(|r| r.id.as_ref() == Some(&exemption.id)) {
+                anyhow::bail!("duplicate stub exemption id {:?}", exemption.id);
+            }
+            match exemptions.iter_mut().find(|e| e.id == exemption.id) {
+                Some(existing) => *existing = exemption,
+                None => exemptions.push(exemption),
+            }
+        }
+        Ok(exemptions)
+    }
+}
+
+/// An allow rule for stubs that are intentional, such as no-op hooks.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StubExemptionConfig {
+    /// Identifier; a built-in rule's id replaces that rule (default: "contract-<index>")
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Regex searched in the function or method name (e.g., "^On[A-Z]")
+    #[serde(default)]
+    pub name_pattern: Option<String>,
+    /// Regex searched in the file path (e.g., "/handlers/.*\\.go$")
+    #[serde(default)]
+    pub path_pattern: Option<String>,
+    /// Languages the rule applies to (default: all)
+    #[serde(default)]
+    pub languages: Vec<String>,
+    /// Signature features required: generic_bounds, ignored_params (Rust)
+    #[serde(default)]
+    pub signature: Vec<String>,
+    /// Stub kinds exempted: empty, panic, nil_return, todo_comment, repetitive_body, custom_signature (default: all)
+    #[serde(default)]
+    pub kinds: Vec<String>,
+}
+
+impl StubExemptionConfig {
+    /// Compile the patterns, languages, and kinds into a detector rule;
+    /// `index` names a rule without an id.
+    pub fn compile(&self, index: usize) -> anyhow::Result<crate::analysis::StubExemption> {
+        if self.name_pattern.is_none() && self.path_pattern.is_none() && self.signature.is_empty() {
+            anyhow::bail!("needs a name_pattern, a path_pattern, or a signature");
+        }
+        let pattern = |p: &Option<String>| {
+            p.as_deref()
+                .map(|p| regex::Regex::new(p).map_err(|e| anyhow::anyhow!("invalid pattern {:?}: {}", p, e)))
+                .transpose()
+        };
+        let languages = self
+            .languages
+            .iter()
+            .map(|l| {
+                crate::analysis::normalize_language(l)
+                    .map(str::to_string)
+                    .ok_or_else(|| anyhow::anyhow!("unknown language {:?}", l))
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let signature = self
+            .signature
+            .iter()
+            .map(|s| {
+                crate::analysis::SignatureFeature::parse(s).ok_or_else(|| {
+                    anyhow::anyhow!("invalid signature {:?}, must be 'generic_bounds' or 'ignored_params'", s)
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        let kinds = self
+            .kinds
+            .iter()
+            .map(|k| {
+                crate::analysis::HollowBodyKind::parse(k).ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "invalid kind {:?}, must be 'empty', 'panic', 'nil_return', 'todo_comment', 'repetitive_body', or 'custom_signature'",
+                        k
+                    )
+                })
+            })
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        Ok(crate::analysis::StubExemption {
+            id: self.id.clone().unwrap_or_else(|| format!("contract-{}", index)),
+            name_pattern: pattern(&self.name_pattern)?,
+            path_pattern: pattern(&self.path_pattern)?,
+            languages,
+            signature,
+            kinds,
+        })
+    }
+}
+
 /// A test function that must exist.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct RequiredTest {
@@ -292,12 +906,23 @@ pub struct DependencyVerificationConfig {
     /// Package names or glob patterns to skip verification for (e.g., internal packages)
     #[serde(default)]
     pub allowlist: Vec<String>,
+    /// Package an import name comes from, where the name matching cannot tell
+    /// (e.g., `cv2: opencv-python`); consulted before the manifest heuristics
+    #[serde(default)]
+    pub import_overrides: BTreeMap<String, String>,
     /// How long to cache registry responses in hours (default: 24)
     #[serde(default = "default_cache_ttl")]
     pub cache_ttl_hours: u32,
     /// If true, treat registry timeouts as errors; if false, warn but pass (default: false)
     #[serde(default)]
     pub fail_on_timeout: bool,
+    /// Packages looked up per batch; memory stays bounded by one batch (default: 500)
+    #[serde(default)]
+    pub chunk_size: Option<usize>,
+    /// Unique packages above which registry lookups are skipped with a single
+    /// warning, since the scan root is likely misconfigured (default: 10000)
+    #[serde(default)]
+    pub max_packages: Option<usize>,
 }
 
 fn default_true() -> bool {
@@ -313,6 +938,16 @@ impl DependencyVerificationConfig {
     pub fn is_enabled(&self) -> bool {
         self.enabled
     }
+
+    /// Returns the number of packages looked up per batch (defaults to 500).
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size.unwrap_or(500).max(1)
+    }
+
+    /// Returns the unique package count above which lookups are skipped (defaults to 10000).
+    pub fn max_packages(&self) -> usize {
+        self.max_packages.unwrap_or(10_000)
+    }
 }
 
 /// Configuration for individual registries.
@@ -393,6 +1028,62 @@ impl GodObjectContractConfig {
     }
 }
 
+/// Configuration for long parameter list detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LongParameterListsConfig {
+    /// Whether long parameter list detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Maximum parameters per function, excluding receivers (default: 7)
+    #[serde(default)]
+    pub max_params: Option<usize>,
+    /// Function name globs that are never flagged (default: constructors and builders)
+    #[serde(default)]
+    pub allowlist: Option<Vec<String>>,
+}
+
+impl Default for LongParameterListsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_params: None,
+            allowlist: None,
+        }
+    }
+}
+
+/// Constructor and builder names exempt from the parameter limit by default.
+const DEFAULT_PARAM_ALLOWLIST: &[&str] = &[
+    "new",
+    "new_*",
+    "New*",
+    "__init__",
+    "constructor",
+    "init",
+    "build",
+    "*Builder.*",
+];
+
+impl LongParameterListsConfig {
+    /// Returns whether long parameter list detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the parameter limit (defaults to 7).
+    pub fn max_params(&self) -> usize {
+        self.max_params.unwrap_or(7)
+    }
+
+    /// Returns the exempt name globs (defaults to common constructor/builder names).
+    pub fn allowlist(&self) -> Vec<&str> {
+        match &self.allowlist {
+            Some(patterns) => patterns.iter().map(String::as_str).collect(),
+            None => DEFAULT_PARAM_ALLOWLIST.to_vec(),
+        }
+    }
+}
+
 /// Configuration for hollow TODO detection.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct HollowTodosConfig {
@@ -401,89 +1092,2314 @@ pub struct HollowTodosConfig {
     pub enabled: bool,
 }
 
-/// Default forbidden patterns for the default contract.
-fn default_forbidden_patterns() -> Vec<ForbiddenPattern> {
-    vec![
-        ForbiddenPattern {
-            pattern: r#"(?i)\bpanic!\s*\(\s*\)"#.to_string(),
-            description: Some("Empty panic! call".to_string()),
-        },
-        ForbiddenPattern {
-            pattern: r#"(?i)console\.log\("#.to_string(),
-            description: Some("Debug console.log left in code".to_string()),
-        },
-        ForbiddenPattern {
-            pattern: r#"(?i)print\s*\(\s*['"]debug"#.to_string(),
-            description: Some("Debug print statement".to_string()),
-        },
-    ]
+/// Marker vocabulary and the handling of markers that reference a ticket.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct MarkersConfig {
+    /// Marker words added to the built-in TODO, FIXME, XXX, HACK, and UNIMPLEMENTED
+    #[serde(default)]
+    pub words: Vec<String>,
+    /// Regex for issue-tracker references in marker text, such as `JIRA-\d+` (default: none)
+    #[serde(default)]
+    pub ticket_pattern: Option<String>,
+    /// Severity for hollow TODO and forbidden pattern violations on a marker that
+    /// references a ticket (default: "info")
+    #[serde(default)]
+    pub tracked_severity: Option<String>,
 }
 
-/// Default mock signatures config for the default contract.
-fn default_mock_signatures() -> MockSignaturesConfig {
-    MockSignaturesConfig {
-        patterns: vec![
-            MockSignature {
-                pattern: r#"(?i)(test|fake|mock|dummy|placeholder|sample|example)[-_]?(data|value|user|email|name|id|response|result)"#.to_string(),
-                description: Some("Mock/placeholder data pattern".to_string()),
-            },
-            MockSignature {
-                pattern: r#"(foo|bar|baz|qux|lorem|ipsum)"#.to_string(),
-                description: Some("Common placeholder text".to_string()),
-            },
-            MockSignature {
-                pattern: r#"(?i)(CHANGEME|REPLACE[-_]?ME|YOUR[-_]?.*[-_]?HERE|XXX[-_]?XXX)"#.to_string(),
-                description: Some("Placeholder marker".to_string()),
-            },
-        ],
-        skip_test_files: Some(true),
-        test_file_severity: None,
+impl MarkersConfig {
+    /// Returns the marker vocabulary: the built-in words, the added ones, and the ticket pattern.
+    pub fn vocabulary(&self) -> anyhow::Result<crate::analysis::MarkerVocabulary> {
+        crate::analysis::MarkerVocabulary::new(&self.words, self.ticket_pattern.as_deref())
     }
-}
 
-/// Default dependency verification config for the default contract.
-fn default_dependency_verification() -> DependencyVerificationConfig {
-    DependencyVerificationConfig {
-        enabled: true,
-        registries: RegistriesConfig::default(),
-        allowlist: vec![],
-        cache_ttl_hours: 24,
-        fail_on_timeout: false,
+    /// Returns the severity name for tracked markers.
+    pub fn tracked_severity(&self) -> &str {
+        self.tracked_severity.as_deref().unwrap_or("info")
     }
 }
 
-/// Default god objects config for the default contract.
-fn default_god_objects() -> GodObjectContractConfig {
-    GodObjectContractConfig {
-        enabled: true,
-        max_file_lines: Some(500),
-        max_function_lines: Some(50),
-        max_function_complexity: Some(15),
-        max_functions_per_file: Some(20),
-        max_class_methods: Some(15),
-    }
+/// Configuration for floating promise detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct FloatingPromisesConfig {
+    /// Whether floating promise detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
 }
 
-/// Validate a contract for correctness.
-pub fn validate(contract: &Contract) -> anyhow::Result<()> {
-    // Validate mode
-    if let Some(mode) = &contract.mode {
-        if mode != "code" && mode != "prose" {
+/// Configuration for repeated string literal detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct RepeatedLiteralsConfig {
+    /// Whether repeated literal detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Minimum literal length in characters to consider (default: 6)
+    #[serde(default)]
+    pub min_length: Option<usize>,
+    /// Number of occurrences at which a literal is flagged (default: 5)
+    #[serde(default)]
+    pub min_occurrences: Option<usize>,
+    /// Number of distinct files a literal must appear in to be flagged (default: 1)
+    #[serde(default)]
+    pub min_files: Option<usize>,
+    /// Literal values never flagged, in addition to the built-in common strings
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+/// Configuration for license/copyright header detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct HeaderConfig {
+    /// Whether header detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Regex the first comment block must match (comment markers are stripped first)
+    #[serde(default)]
+    pub pattern: Option<String>,
+    /// Literal text the first comment block must contain (whitespace-insensitive)
+    #[serde(default)]
+    pub text: Option<String>,
+}
+
+/// Configuration for documentation drift detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct DocDriftConfig {
+    /// Whether doc drift detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Globs of markdown files to check, relative to the project root (default: README.md)
+    #[serde(default)]
+    pub docs: Vec<String>,
+    /// Regex matching documented CLI invocations; capture group 1 is the subcommand
+    #[serde(default)]
+    pub command_pattern: Option<String>,
+    /// Names that are never reported
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+impl DocDriftConfig {
+    /// Returns whether doc drift detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the documentation globs (defaults to `README.md`).
+    pub fn doc_globs(&self) -> Vec<&str> {
+        if self.docs.is_empty() {
+            vec!["README.md"]
+        } else {
+            self.docs.iter().map(String::as_str).collect()
+        }
+    }
+}
+
+/// Configuration for empty HTTP handler detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct EmptyHandlersConfig {
+    /// Whether empty handler detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Extra framework patterns; an entry named like a built-in replaces it
+    #[serde(default)]
+    pub frameworks: Vec<HandlerFramework>,
+    /// Handler name globs that are never flagged, case-insensitive (default: health probes)
+    #[serde(default)]
+    pub allowlist: Option<Vec<String>>,
+}
+
+impl Default for EmptyHandlersConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            frameworks: vec![],
+            allowlist: None,
+        }
+    }
+}
+
+/// Patterns recognizing a hollow success response in one web framework.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HandlerFramework {
+    /// Framework name, shown in messages (e.g. "express")
+    pub name: String,
+    /// File extensions the patterns apply to, without the dot
+    pub extensions: Vec<String>,
+    /// Regex matching a success response with an empty or placeholder body
+    pub success: String,
+    /// Regex matching a body write; if it matches elsewhere in the handler,
+    /// the success response is not flagged
+    #[serde(default)]
+    pub body: Option<String>,
+}
+
+/// Handler names exempt by default: liveness and readiness probes answer 200 with no body.
+const DEFAULT_HANDLER_ALLOWLIST: &[&str] = &[
+    "*health*",
+    "*ping*",
+    "*ready*",
+    "*readiness*",
+    "*liveness*",
+];
+
+impl EmptyHandlersConfig {
+    /// Returns whether empty handler detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the built-in framework patterns merged with the contract's.
+    pub fn frameworks(&self) -> Vec<HandlerFramework> {
+        let mut frameworks: Vec<HandlerFramework> = builtin_handler_frameworks()
+            .into_iter()
+            .filter(|b| !self.frameworks.iter().any(|f| f.name == b.name))
+            .collect();
+        frameworks.extend(self.frameworks.iter().cloned());
+        frameworks
+    }
+
+    /// Returns the exempt handler name globs (defaults to health check names).
+    pub fn allowlist(&self) -> Vec<&str> {
+        match &self.allowlist {
+            Some(patterns) => patterns.iter().map(String::as_str).collect(),
+            None => DEFAULT_HANDLER_ALLOWLIST.to_vec(),
+        }
+    }
+}
+
+/// Configuration for the per-file analysis budget.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AnalysisBudgetConfig {
+    /// Whether large files are analyzed under a time budget (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Line count from which a file is analyzed under the budget (default: 10000)
+    #[serde(default)]
+    pub large_file_lines: Option<usize>,
+    /// Wall-clock milliseconds allowed for parsing a large file (default: 10000)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+    /// Syntax nodes above which a function body's complexity is not computed (default: 50000)
+    #[serde(default)]
+    pub max_body_nodes: Option<usize>,
+    /// Milliseconds one forbidden pattern or mock signature may spend matching a file
+    /// before it is disabled for the rest of the run (default: 2000)
+    #[serde(default)]
+    pub pattern_timeout_ms: Option<u64>,
+}
+
+impl Default for AnalysisBudgetConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            large_file_lines: None,
+            timeout_ms: None,
+            max_body_nodes: None,
+            pattern_timeout_ms: None,
+        }
+    }
+}
+
+impl AnalysisBudgetConfig {
+    /// Returns whether the analysis budget is enforced.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the line count from which the budget applies (defaults to 10000).
+    pub fn large_file_lines(&self) -> usize {
+        self.large_file_lines.unwrap_or(10_000)
+    }
+
+    /// Returns the per-file budget (defaults to 10 seconds).
+    pub fn timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.timeout_ms.unwrap_or(10_000))
+    }
+
+    /// Returns the body size above which complexity is not computed
+    /// (defaults to 50000 syntax nodes).
+    pub fn max_body_nodes(&self) -> usize {
+        self.max_body_nodes.unwrap_or(crate::analysis::DEFAULT_MAX_BODY_NODES)
+    }
+
+    /// Returns the per-file matching budget of each contract pattern
+    /// (defaults to 2 seconds), or `None` when the budget is not enforced.
+    pub fn pattern_timeout(&self) -> Option<std::time::Duration> {
+        self.enabled
+            .then(|| std::time::Duration::from_millis(self.pattern_timeout_ms.unwrap_or(2_000)))
+    }
+}
+
+/// Configuration for unimplemented interface detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct UnimplementedInterfacesConfig {
+    /// Whether unimplemented interface detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Interfaces declaring fewer methods are not checked (default: 2)
+    #[serde(default)]
+    pub min_methods: Option<usize>,
+    /// Interface name globs that are intentionally abstract and never flagged
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+}
+
+impl UnimplementedInterfacesConfig {
+    /// Returns whether unimplemented interface detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the method count below which interfaces are skipped (defaults to 2).
+    pub fn min_methods(&self) -> usize {
+        self.min_methods.unwrap_or(2)
+    }
+}
+
+/// Configuration for premature abstraction detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct PrematureAbstractionConfig {
+    /// Whether premature abstraction detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Flag interfaces and traits with exactly one implementing type (default: true)
+    #[serde(default)]
+    pub single_implementation: Option<bool>,
+    /// Flag generic functions whose type parameter is used in a single position (default: true)
+    #[serde(default)]
+    pub unused_type_parameters: Option<bool>,
+    /// Flag `New*`/`Create*` factories returning a single-implementation interface (default: true)
+    #[serde(default)]
+    pub single_type_factories: Option<bool>,
+}
+
+impl PrematureAbstractionConfig {
+    /// Returns whether premature abstraction detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns whether single-implementation interfaces are flagged.
+    pub fn checks_single_implementation(&self) -> bool {
+        self.single_implementation.unwrap_or(true)
+    }
+
+    /// Returns whether single-position type parameters are flagged.
+    pub fn checks_unused_type_parameters(&self) -> bool {
+        self.unused_type_parameters.unwrap_or(true)
+    }
+
+    /// Returns whether factories of single-implementation interfaces are flagged.
+    pub fn checks_single_type_factories(&self) -> bool {
+        self.single_type_factories.unwrap_or(true)
+    }
+}
+
+/// Configuration for weak cryptography detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WeakCryptoConfig {
+    /// Whether weak cryptography detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Severity for weak cryptography findings (default: "warning")
+    #[serde(default)]
+    pub severity: Option<String>,
+    /// Flag MD5 and SHA-1 digests (default: true)
+    #[serde(default)]
+    pub hashes: Option<bool>,
+    /// Flag DES, 3DES, RC4, and Blowfish ciphers (default: true)
+    #[serde(default)]
+    pub ciphers: Option<bool>,
+    /// Flag ECB mode (default: true)
+    #[serde(default)]
+    pub ecb_mode: Option<bool>,
+    /// Flag IVs and nonces passed as literals (default: true)
+    #[serde(default)]
+    pub fixed_ivs: Option<bool>,
+    /// Flag key derivation salts passed as literals (default: true)
+    #[serde(default)]
+    pub fixed_salts: Option<bool>,
+}
+
+impl Default for WeakCryptoConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            severity: None,
+            hashes: None,
+            ciphers: None,
+            ecb_mode: None,
+            fixed_ivs: None,
+            fixed_salts: None,
+        }
+    }
+}
+
+impl WeakCryptoConfig {
+    /// Returns whether weak cryptography detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the severity name for findings.
+    pub fn severity(&self) -> &str {
+        self.severity.as_deref().unwrap_or("warning")
+    }
+
+    /// Returns whether the named kind of weakness is flagged.
+    pub fn checks(&self, kind: &str) -> bool {
+        let toggle = match kind {
+            "hashes" => self.hashes,
+            "ciphers" => self.ciphers,
+            "ecb_mode" => self.ecb_mode,
+            "fixed_ivs" => self.fixed_ivs,
+            "fixed_salts" => self.fixed_salts,
+            _ => return false,
+        };
+        toggle.unwrap_or(true)
+    }
+}
+
+/// Configuration for the built-in dependency and build directory excludes.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DefaultExcludesConfig {
+    /// Whether vendored directories are skipped (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Ecosystems whose directories are skipped (default: all of `VENDORED_DIRS`)
+    #[serde(default)]
+    pub ecosystems: Option<Vec<String>>,
+    /// Directory names scanned even though an ecosystem lists them (e.g. "vendor")
+    #[serde(default)]
+    pub keep: Vec<String>,
+}
+
+impl Default for DefaultExcludesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ecosystems: None,
+            keep: vec![],
+        }
+    }
+}
+
+/// Dependency, virtualenv, cache, and build output directories by ecosystem.
+///
+/// Their contents are third-party or generated: scanning them is slow and
+/// floods the report with findings the project does not own.
+pub const VENDORED_DIRS: &[(&str, &[&str])] = &[
+    (
+        "javascript",
+        &[
+            "node_modules",
+            "bower_components",
+            "jspm_packages",
+            ".yarn",
+            ".pnpm-store",
+            ".next",
+            ".nuxt",
+            ".svelte-kit",
+        ],
+    ),
+    (
+        "python",
+        &[
+            ".venv",
+            "venv",
+            "__pycache__",
+            "__pypackages__",
+            "site-packages",
+            ".eggs",
+            ".tox",
+            ".nox",
+            ".mypy_cache",
+            ".pytest_cache",
+        ],
+    ),
+    ("go", &["vendor"]),
+    ("rust", &["target"]),
+    ("java", &["target", ".gradle", ".m2"]),
+    ("php", &["vendor"]),
+    ("ruby", &["vendor", ".bundle"]),
+    ("swift", &[".build", "Pods", "Carthage", "DerivedData"]),
+    ("build", &["dist", "build", "_build"]),
+];
+
+impl DefaultExcludesConfig {
+    /// Returns whether vendored directories are skipped.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the directory names to skip, without the kept ones.
+    pub fn dirs(&self) -> Vec<&'static str> {
+        let mut dirs: Vec<&'static str> = VENDORED_DIRS
+            .iter()
+            .filter(|(ecosystem, _)| {
+                self.ecosystems
+                    .as_ref()
+                    .is_none_or(|selected| selected.iter().any(|e| e == ecosystem))
+            })
+            .flat_map(|(_, dirs)| dirs.iter().copied())
+            .filter(|dir| !self.keep.iter().any(|k| k == dir))
+            .collect();
+        dirs.sort_unstable();
+        dirs.dedup();
+        dirs
+    }
+}
+
+/// Configuration for Python builtin shadowing detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BuiltinShadowingConfig {
+    /// Whether builtin shadowing detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Names treated as builtins; replaces the default list when set
+    #[serde(default)]
+    pub builtins: Option<Vec<String>>,
+    /// Builtin names the project shadows on purpose
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+}
+
+impl Default for BuiltinShadowingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            builtins: None,
+            allowlist: vec![],
+        }
+    }
+}
+
+/// Python builtin functions and types that are commonly shadowed by accident.
+pub const DEFAULT_PYTHON_BUILTINS: &[&str] = &[
+    "abs", "aiter", "all", "anext", "any", "ascii", "bin", "bool", "breakpoint", "bytearray",
+    "bytes", "callable", "chr", "classmethod", "compile", "complex", "delattr", "dict", "dir",
+    "divmod", "enumerate", "eval", "exec", "filter", "float", "format", "frozenset", "getattr",
+    "globals", "hasattr", "hash", "help", "hex", "id", "input", "int", "isinstance",
+    "issubclass", "iter", "len", "list", "locals", "map", "max", "memoryview", "min", "next",
+    "object", "oct", "open", "ord", "pow", "print", "property", "range", "repr", "reversed",
+    "round", "set", "setattr", "slice", "sorted", "staticmethod", "str", "sum", "super",
+    "tuple", "type", "vars", "zip",
+];
+
+impl BuiltinShadowingConfig {
+    /// Returns whether builtin shadowing detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the builtin names to check (defaults to `DEFAULT_PYTHON_BUILTINS`).
+    pub fn builtins(&self) -> Vec<&str> {
+        match &self.builtins {
+            Some(names) => names.iter().map(String::as_str).collect(),
+            None => DEFAULT_PYTHON_BUILTINS.to_vec(),
+        }
+    }
+}
+
+/// Configuration for Dockerfile and docker-compose checks.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ContainerChecksConfig {
+    /// Whether container checks are enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Severity for base images without a tag or tagged `latest` (default: "warning")
+    #[serde(default)]
+    pub latest_tag_severity: Option<String>,
+    /// Severity for placeholder image names (default: "error")
+    #[serde(default)]
+    pub placeholder_severity: Option<String>,
+    /// Severity for RUN steps that only echo a TODO marker (default: "warning")
+    #[serde(default)]
+    pub todo_severity: Option<String>,
+}
+
+impl Default for ContainerChecksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            latest_tag_severity: None,
+            placeholder_severity: None,
+            todo_severity: None,
+        }
+    }
+}
+
+impl ContainerChecksConfig {
+    /// Returns whether container checks are enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the severity name for unpinned base images.
+    pub fn latest_tag_severity(&self) -> &str {
+        self.latest_tag_severity.as_deref().unwrap_or("warning")
+    }
+
+    /// Returns the severity name for placeholder images.
+    pub fn placeholder_severity(&self) -> &str {
+        self.placeholder_severity.as_deref().unwrap_or("error")
+    }
+
+    /// Returns the severity name for TODO build steps.
+    pub fn todo_severity(&self) -> &str {
+        self.todo_severity.as_deref().unwrap_or("warning")
+    }
+}
+
+/// Configuration for CI pipeline file checks.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CiConfigChecksConfig {
+    /// Whether CI pipeline checks are enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Severity for job and service images without a tag or tagged `latest` (default: "warning")
+    #[serde(default)]
+    pub latest_tag_severity: Option<String>,
+    /// Severity for placeholder images and values (default: "error")
+    #[serde(default)]
+    pub placeholder_severity: Option<String>,
+    /// Severity for TODO markers and steps that only echo one (default: "warning")
+    #[serde(default)]
+    pub todo_severity: Option<String>,
+}
+
+impl Default for CiConfigChecksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            latest_tag_severity: None,
+            placeholder_severity: None,
+            todo_severity: None,
+        }
+    }
+}
+
+impl CiConfigChecksConfig {
+    /// Returns whether CI pipeline checks are enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the severity name for unpinned images.
+    pub fn latest_tag_severity(&self) -> &str {
+        self.latest_tag_severity.as_deref().unwrap_or("warning")
+    }
+
+    /// Returns the severity name for placeholder images and values.
+    pub fn placeholder_severity(&self) -> &str {
+        self.placeholder_severity.as_deref().unwrap_or("error")
+    }
+
+    /// Returns the severity name for TODO markers.
+    pub fn todo_severity(&self) -> &str {
+        self.todo_severity.as_deref().unwrap_or("warning")
+    }
+}
+
+/// Empty definitions allowed by default: well-known empty messages and
+/// request/response wrappers kept for API evolution.
+pub const DEFAULT_SCHEMA_ALLOW_EMPTY: &[&str] = &["^Empty$", "(Request|Response|Ack)$"];
+
+/// Configuration for Protobuf and GraphQL schema checks.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SchemaChecksConfig {
+    /// Whether schema checks are enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Field names to report as placeholders, in addition to the built-in ones
+    #[serde(default)]
+    pub placeholder_fields: Vec<String>,
+    /// Regexes for definition names allowed to be empty (default: `DEFAULT_SCHEMA_ALLOW_EMPTY`)
+    #[serde(default)]
+    pub allow_empty: Option<Vec<String>>,
+    /// Severity for TODO markers in schema comments (default: "warning")
+    #[serde(default)]
+    pub todo_severity: Option<String>,
+}
+
+impl Default for SchemaChecksConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            placeholder_fields: Vec::new(),
+            allow_empty: None,
+            todo_severity: None,
+        }
+    }
+}
+
+impl SchemaChecksConfig {
+    /// Returns whether schema checks are enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the patterns for definitions allowed to be empty.
+    pub fn allow_empty(&self) -> Vec<&str> {
+        match &self.allow_empty {
+            Some(patterns) => patterns.iter().map(String::as_str).collect(),
+            None => DEFAULT_SCHEMA_ALLOW_EMPTY.to_vec(),
+        }
+    }
+
+    /// Returns the severity name for TODO markers.
+    pub fn todo_severity(&self) -> &str {
+        self.todo_severity.as_deref().unwrap_or("warning")
+    }
+}
+
+/// Configuration for no-op statement detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NoopStatementsConfig {
+    /// Whether no-op statement detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for NoopStatementsConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl NoopStatementsConfig {
+    /// Returns whether no-op statement detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for unused Go `context.Context` detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnusedContextConfig {
+    /// Whether unused context detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for UnusedContextConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl UnusedContextConfig {
+    /// Returns whether unused context detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for unassigned Go named result detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnassignedNamedReturnsConfig {
+    /// Whether unassigned named result detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for UnassignedNamedReturnsConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl UnassignedNamedReturnsConfig {
+    /// Returns whether unassigned named result detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for placeholder constant group detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PlaceholderConstantsConfig {
+    /// Whether placeholder constant detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Severity for placeholder constant findings (default: "info")
+    #[serde(default)]
+    pub severity: Option<String>,
+    /// Fewest members a group needs to be checked (default: 3)
+    #[serde(default)]
+    pub min_members: Option<usize>,
+}
+
+impl Default for PlaceholderConstantsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            severity: None,
+            min_members: None,
+        }
+    }
+}
+
+impl PlaceholderConstantsConfig {
+    /// Returns whether placeholder constant detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the severity name for findings.
+    pub fn severity(&self) -> &str {
+        self.severity.as_deref().unwrap_or("info")
+    }
+
+    /// Returns the fewest members a group needs to be checked.
+    pub fn min_members(&self) -> usize {
+        self.min_members.unwrap_or(3)
+    }
+}
+
+/// Configuration for C and C++ missing definition detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MissingDefinitionsConfig {
+    /// Whether missing definition detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Name globs of functions provided by external libraries; `extern`
+    /// declarations matching them need no definition in the scan
+    #[serde(default)]
+    pub external: Vec<String>,
+    /// Name globs of functions never flagged, such as platform-conditional implementations
+    #[serde(default)]
+    pub ignore: Vec<String>,
+}
+
+impl Default for MissingDefinitionsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            external: vec![],
+            ignore: vec![],
+        }
+    }
+}
+
+impl MissingDefinitionsConfig {
+    /// Returns whether missing definition detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for deprecated usage detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DeprecatedUsageConfig {
+    /// Whether deprecated usage detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Call sites at which a deprecated callable is reported (default: 5)
+    #[serde(default)]
+    pub min_call_sites: Option<usize>,
+}
+
+impl Default for DeprecatedUsageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_call_sites: None,
+        }
+    }
+}
+
+impl DeprecatedUsageConfig {
+    /// Returns whether deprecated usage detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the call site count at which a deprecated callable is reported.
+    pub fn min_call_sites(&self) -> usize {
+        self.min_call_sites.unwrap_or(5)
+    }
+}
+
+/// Configuration for Rust unwrap overuse detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnwrapOveruseConfig {
+    /// Whether unwrap overuse detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// `unwrap`/`expect`/`panic!` sites allowed per function (default: 3)
+    #[serde(default)]
+    pub max_per_function: Option<usize>,
+    /// Glob patterns for files where any `unwrap`/`expect`/`panic!` is reported
+    #[serde(default)]
+    pub production_paths: Vec<String>,
+}
+
+impl Default for UnwrapOveruseConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_per_function: None,
+            production_paths: vec![],
+        }
+    }
+}
+
+impl UnwrapOveruseConfig {
+    /// Returns whether unwrap overuse detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the panicking call sites allowed per function.
+    pub fn max_per_function(&self) -> usize {
+        self.max_per_function.unwrap_or(3)
+    }
+}
+
+/// Configuration for Swift force-unwrap detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ForceUnwrapsConfig {
+    /// Whether force-unwrap detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// `!` force-unwraps and `try!` expressions allowed per function (default: 3)
+    #[serde(default)]
+    pub max_per_function: Option<usize>,
+    /// Glob patterns for test targets, which are exempt (default: `Tests`
+    /// directories and `*Tests.swift`/`*Test.swift` files; `[]` checks everything)
+    #[serde(default)]
+    pub test_paths: Option<Vec<String>>,
+}
+
+impl Default for ForceUnwrapsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_per_function: None,
+            test_paths: None,
+        }
+    }
+}
+
+impl ForceUnwrapsConfig {
+    /// Returns whether force-unwrap detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the crashing sites allowed per function.
+    pub fn max_per_function(&self) -> usize {
+        self.max_per_function.unwrap_or(3)
+    }
+
+    /// Returns the glob patterns of exempt test targets.
+    pub fn test_paths(&self) -> Vec<String> {
+        match &self.test_paths {
+            Some(paths) => paths.clone(),
+            None => ["**/Tests/**", "**/*Tests/**", "**/*Tests.swift", "**/*Test.swift"]
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Configuration for missing local module detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MissingLocalModulesConfig {
+    /// Whether missing local module detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for MissingLocalModulesConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl MissingLocalModulesConfig {
+    /// Returns whether missing local module detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for redundant error logging detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RedundantErrorLoggingConfig {
+    /// Whether redundant error logging detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for RedundantErrorLoggingConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl RedundantErrorLoggingConfig {
+    /// Returns whether redundant error logging detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for inconsistent error handling detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InconsistentErrorHandlingConfig {
+    /// Whether inconsistent error handling detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for InconsistentErrorHandlingConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl InconsistentErrorHandlingConfig {
+    /// Returns whether inconsistent error handling detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Configuration for async-without-await detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AsyncWithoutAwaitConfig {
+    /// Whether async-without-await detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Function name globs that are async only to fit a caller or protocol
+    /// (default: async context manager and iterator hooks, async test setup)
+    #[serde(default)]
+    pub passthrough: Option<Vec<String>>,
+    /// Check Python `async def` (default: true)
+    #[serde(default)]
+    pub python: Option<bool>,
+    /// Check TypeScript async functions and methods (default: true)
+    #[serde(default)]
+    pub typescript: Option<bool>,
+    /// Check JavaScript async functions and methods (default: true)
+    #[serde(default)]
+    pub javascript: Option<bool>,
+    /// Check Rust `async fn` (default: true)
+    #[serde(default)]
+    pub rust: Option<bool>,
+}
+
+impl Default for AsyncWithoutAwaitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            passthrough: None,
+            python: None,
+            typescript: None,
+            javascript: None,
+            rust: None,
+        }
+    }
+}
+
+/// Names that are async to satisfy a protocol, not to do I/O of their own.
+const DEFAULT_ASYNC_PASSTHROUGH: &[&str] = &[
+    "__aenter__",
+    "__aexit__",
+    "__aiter__",
+    "__anext__",
+    "asyncSetUp",
+    "asyncTearDown",
+];
+
+impl AsyncWithoutAwaitConfig {
+    /// Returns whether async-without-await detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the exempt function name globs.
+    pub fn passthrough(&self) -> Vec<String> {
+        match &self.passthrough {
+            Some(patterns) => patterns.clone(),
+            None => DEFAULT_ASYNC_PASSTHROUGH.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    /// Returns whether async functions in the given language are checked.
+    pub fn checks_language(&self, language: &str) -> bool {
+        let toggle = match language {
+            "python" => self.python,
+            "typescript" => self.typescript,
+            "javascript" => self.javascript,
+            "rust" => self.rust,
+            _ => return false,
+        };
+        toggle.unwrap_or(true)
+    }
+}
+
+/// Configuration for Python mutable default detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MutableDefaultsConfig {
+    /// Whether mutable default detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Callables whose result is mutable, matched by their last dotted
+    /// segment; replaces the default list when set
+    #[serde(default)]
+    pub constructors: Option<Vec<String>>,
+}
+
+impl Default for MutableDefaultsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            constructors: None,
+        }
+    }
+}
+
+/// Python callables that build a fresh mutable container.
+pub const DEFAULT_MUTABLE_CONSTRUCTORS: &[&str] = &[
+    "list", "dict", "set", "bytearray", "defaultdict", "OrderedDict", "Counter", "deque",
+];
+
+impl MutableDefaultsConfig {
+    /// Returns whether mutable default detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the mutable constructor names (defaults to `DEFAULT_MUTABLE_CONSTRUCTORS`).
+    pub fn constructors(&self) -> Vec<&str> {
+        match &self.constructors {
+            Some(names) => names.iter().map(String::as_str).collect(),
+            None => DEFAULT_MUTABLE_CONSTRUCTORS.to_vec(),
+        }
+    }
+}
+
+/// Configuration for truncated code detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TruncatedCodeConfig {
+    /// Whether truncated code detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for TruncatedCodeConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Handling of files that carry a generated-code marker.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct GeneratedFilesConfig {
+    /// Whether generated files are treated specially (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Rules still checked in generated files; when empty they are skipped entirely
+    #[serde(default)]
+    pub rules: Vec<String>,
+    /// Whether generated files edited by hand are checked outside their
+    /// generated blocks (default: true)
+    #[serde(default = "default_true")]
+    pub modified: bool,
+    /// Also count a generated file as edited by hand when its last commit
+    /// touched no generator input (default: false)
+    #[serde(default)]
+    pub git: bool,
+}
+
+impl Default for GeneratedFilesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            rules: vec![],
+            modified: true,
+            git: false,
+        }
+    }
+}
+
+impl GeneratedFilesConfig {
+    /// Returns whether generated files are treated specially.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Rules still checked in generated files; names that do not parse are
+    /// left out (validation rejects them).
+    pub fn rules(&self) -> Vec<crate::detect::ViolationRule> {
+        self.rules.iter().filter_map(|r| r.parse().ok()).collect()
+    }
+}
+
+/// Configuration for unhandled enum case detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct UnhandledEnumCasesConfig {
+    /// Whether unhandled enum case detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Fraction of variants a switch must handle before its default is trusted (default: 0.75)
+    #[serde(default)]
+    pub min_coverage: Option<f64>,
+    /// Check Go switches over `iota` constants (default: true)
+    #[serde(default)]
+    pub go: Option<bool>,
+    /// Check TypeScript switches over enums and string literal unions (default: true)
+    #[serde(default)]
+    pub typescript: Option<bool>,
+    /// Check Rust matches with a `_` arm (default: true)
+    #[serde(default)]
+    pub rust: Option<bool>,
+}
+
+impl UnhandledEnumCasesConfig {
+    /// Returns whether unhandled enum case detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the minimum handled fraction of variants (defaults to 0.75).
+    pub fn min_coverage(&self) -> f64 {
+        self.min_coverage.unwrap_or(0.75)
+    }
+
+    /// Returns whether switches in the given language are checked.
+    pub fn checks_language(&self, language: &str) -> bool {
+        let toggle = match language {
+            "go" => self.go,
+            "typescript" => self.typescript,
+            "rust" => self.rust,
+            _ => return false,
+        };
+        toggle.unwrap_or(true)
+    }
+}
+
+/// Configuration for trivial wrapper detection.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct TrivialWrappersConfig {
+    /// Whether trivial wrapper detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Glob patterns for files whose wrappers are a deliberate public API facade
+    #[serde(default)]
+    pub api_facade_paths: Vec<String>,
+    /// Wrappers of one callee above which they are reported together (default: 3)
+    #[serde(default)]
+    pub consolidate_after: Option<usize>,
+}
+
+impl TrivialWrappersConfig {
+    /// Returns whether trivial wrapper detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the wrapper count per callee above which one violation is reported.
+    pub fn consolidate_after(&self) -> usize {
+        self.consolidate_after.unwrap_or(3)
+    }
+}
+
+/// Configuration for repetitive function body detection.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RepetitiveBodiesConfig {
+    /// Whether repetitive body detection is enabled (default: true)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Ratio of distinct to total statements below which a body is repetitive (default: 0.2)
+    #[serde(default)]
+    pub min_distinct_ratio: Option<f64>,
+    /// Statements a body needs before its repetition is checked (default: 10)
+    #[serde(default)]
+    pub min_statements: Option<usize>,
+}
+
+impl Default for RepetitiveBodiesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_distinct_ratio: None,
+            min_statements: None,
+        }
+    }
+}
+
+impl RepetitiveBodiesConfig {
+    /// Returns whether repetitive body detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the distinct-statement ratio below which a body is flagged.
+    pub fn min_distinct_ratio(&self) -> f64 {
+        self.min_distinct_ratio.unwrap_or(crate::analysis::DEFAULT_MIN_DISTINCT_RATIO)
+    }
+
+    /// Returns the statements a body needs before its repetition is checked.
+    pub fn min_statements(&self) -> usize {
+        self.min_statements.unwrap_or(crate::analysis::DEFAULT_REPETITIVE_MIN_STATEMENTS)
+    }
+}
+
+/// Words too generic to be a custom stub signature: keywords and the
+/// built-in zero values, which legitimate one-line bodies use everywhere.
+const GENERIC_STUB_SIGNATURE_NAMES: &[&str] = &[
+    "return", "raise", "throw", "new", "nil", "null", "None", "undefined", "true", "false", "True", "False", "self",
+    "this", "super",
+];
+
+/// A project's own stub idiom for one language, such as raising
+/// `FeatureNotReady` or returning `ERR_NOT_IMPLEMENTED`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CustomStubSignatureConfig {
+    /// Language the names apply to (e.g., "python", "go")
+    pub language: String,
+    /// Called functions, raised types, or returned identifiers (e.g., "FeatureNotReady", "deps.NotWired")
+    pub names: Vec<String>,
+    /// Severity of matching bodies: "critical", "error", "warning", or "info" (default: "warning")
+    #[serde(default)]
+    pub severity: Option<String>,
+}
+
+impl CustomStubSignatureConfig {
+    /// Check the language, names, and severity and build the detector's signature.
+    pub fn resolve(&self) -> anyhow::Result<crate::detect::CustomStubSignature> {
+        let language = crate::analysis::normalize_language(&self.language)
+            .filter(|l| crate::analysis::registered_languages().iter().any(|r| r == l))
+            .ok_or_else(|| anyhow::anyhow!("unknown language {:?}", self.language))?;
+        if self.names.is_empty() {
+            anyhow::bail!("needs at least one name");
+        }
+        let identifier_path = regex::Regex::new(r"^[A-Za-z_$][\w$]*((\.|::)[A-Za-z_$][\w$]*)*$").expect("valid regex");
+        for name in &self.names {
+            if name.chars().count() < 2 || GENERIC_STUB_SIGNATURE_NAMES.contains(&name.as_str()) {
+                anyhow::bail!("name {:?} is too generic to mark a stub", name);
+            }
+            if !identifier_path.is_match(name) {
+                anyhow::bail!("name {:?} must be an identifier or a dotted path (e.g., \"deps.NotWired\")", name);
+            }
+        }
+        let severity = self
+            .severity
+            .as_deref()
+            .unwrap_or("warning")
+            .parse::<crate::detect::Severity>()
+            .map_err(|e| anyhow::anyhow!("invalid severity: {}", e))?;
+        Ok(crate::detect::CustomStubSignature {
+            signature: crate::analysis::StubSignature {
+                language: language.to_string(),
+                names: self.names.clone(),
+            },
+            severity,
+        })
+    }
+}
+
+/// Configuration for comment density checks.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct CommentRatioConfig {
+    /// Whether comment ratio detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Comment-to-code line ratio at or below which a file with complex functions is under-documented (default: 0.0)
+    #[serde(default)]
+    pub min_ratio: Option<f64>,
+    /// Comment-to-code line ratio above which a file is mostly comments (default: 1.5)
+    #[serde(default)]
+    pub max_ratio: Option<f64>,
+    /// Complex functions a file needs before it can be under-documented (default: 3)
+    #[serde(default)]
+    pub min_complex_functions: Option<usize>,
+    /// Cyclomatic complexity at which a function counts as complex (default: 5)
+    #[serde(default)]
+    pub complex_function_complexity: Option<u32>,
+    /// Code and comment lines a file needs before it can be mostly comments (default: 20)
+    #[serde(default)]
+    pub min_lines: Option<usize>,
+}
+
+impl CommentRatioConfig {
+    /// Returns whether comment ratio detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the ratio at or below which a file with complex functions is flagged.
+    pub fn min_ratio(&self) -> f64 {
+        self.min_ratio.unwrap_or(0.0)
+    }
+
+    /// Returns the ratio above which a file is flagged as mostly comments.
+    pub fn max_ratio(&self) -> f64 {
+        self.max_ratio.unwrap_or(1.5)
+    }
+
+    /// Returns the complex functions a file needs before it can be under-documented.
+    pub fn min_complex_functions(&self) -> usize {
+        self.min_complex_functions.unwrap_or(3)
+    }
+
+    /// Returns the cyclomatic complexity at which a function counts as complex.
+    pub fn complex_function_complexity(&self) -> u32 {
+        self.complex_function_complexity.unwrap_or(5)
+    }
+
+    /// Returns the lines a file needs before it can be mostly comments.
+    pub fn min_lines(&self) -> usize {
+        self.min_lines.unwrap_or(20)
+    }
+}
+
+/// Configuration for documentation coverage of exported declarations.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct DocCoverageConfig {
+    /// Whether doc coverage detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Share of exported declarations that must be documented, from 0.0 to 1.0;
+    /// below it a project-level error is reported (default: no gate)
+    #[serde(default)]
+    pub min_doc_coverage: Option<f64>,
+    /// Undocumented declarations reported per file; the rest are counted in one violation (default: 5)
+    #[serde(default)]
+    pub max_per_file: Option<usize>,
+    /// Count exported constants and module-level variables (default: false)
+    #[serde(default)]
+    pub include_constants: Option<bool>,
+    /// Count declarations in test files (default: false)
+    #[serde(default)]
+    pub include_tests: Option<bool>,
+}
+
+impl DocCoverageConfig {
+    /// Returns whether doc coverage detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the undocumented declarations reported per file.
+    pub fn max_per_file(&self) -> usize {
+        self.max_per_file.unwrap_or(5)
+    }
+
+    /// Returns whether exported constants are counted.
+    pub fn include_constants(&self) -> bool {
+        self.include_constants.unwrap_or(false)
+    }
+
+    /// Returns whether test files are counted.
+    pub fn include_tests(&self) -> bool {
+        self.include_tests.unwrap_or(false)
+    }
+}
+
+/// Configuration for reporting stale inline suppressions.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct UnusedSuppressionsConfig {
+    /// Whether unused suppression detection is enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Report line suppressions already covered by a file-level suppression (default: true)
+    #[serde(default)]
+    pub redundant: Option<bool>,
+    /// Where findings go: "violations" (Info violations) or "suppressed", listed
+    /// with the suppressions they describe (default: "violations")
+    #[serde(default)]
+    pub report: Option<String>,
+}
+
+impl UnusedSuppressionsConfig {
+    /// Returns whether unused suppression detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns whether redundant line suppressions are reported.
+    pub fn redundant(&self) -> bool {
+        self.redundant.unwrap_or(true)
+    }
+
+    /// Returns whether findings go to the suppressed list instead of the violations.
+    pub fn report_as_suppressed(&self) -> bool {
+        self.report.as_deref() == Some("suppressed")
+    }
+}
+
+/// Expected language, runtime, and framework versions.
+///
+/// Each value is a version or constraint (`"3.11"`, `">=1.22"`, `"^4.2"`); a
+/// bare partial version such as `"3.11"` allows any `3.11.x`.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct EnvironmentConfig {
+    /// Whether environment checks are enabled (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Python version, checked against pyproject.toml `requires-python`
+    #[serde(default)]
+    pub python: Option<String>,
+    /// Go version, checked against the go.mod `go` directive and `toolchain` line
+    #[serde(default)]
+    pub go: Option<String>,
+    /// Node.js version, checked against package.json `engines.node`
+    #[serde(default)]
+    pub node: Option<String>,
+    /// Rust version, checked against Cargo.toml `rust-version`
+    #[serde(default)]
+    pub rust: Option<String>,
+    /// Framework versions by package name: PyPI or npm name, or Go module path
+    #[serde(default)]
+    pub frameworks: BTreeMap<String, String>,
+}
+
+impl EnvironmentConfig {
+    /// Returns whether environment checks are enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the expected versions by name: the runtimes, then the frameworks.
+    pub fn expectations(&self) -> Vec<(&str, &str)> {
+        [
+            ("python", &self.python),
+            ("go", &self.go),
+            ("node", &self.node),
+            ("rust", &self.rust),
+        ]
+        .into_iter()
+        .filter_map(|(name, version)| Some((name, version.as_deref()?)))
+        .chain(self.frameworks.iter().map(|(name, version)| (name.as_str(), version.as_str())))
+        .collect()
+    }
+}
+
+/// Expectations for configuration files matching a glob.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConfigFileSpec {
+    /// Glob pattern relative to the project root (e.g., "config/*.yaml")
+    pub pattern: String,
+    /// Dotted key paths that must be present and non-empty (e.g., "database.host")
+    #[serde(default)]
+    pub required_keys: Vec<String>,
+    /// Numeric bounds for keys
+    #[serde(default)]
+    pub ranges: Vec<ConfigRange>,
+    /// Check values for placeholders and mock signatures (default: true)
+    #[serde(default)]
+    pub placeholders: Option<bool>,
+}
+
+impl ConfigFileSpec {
+    /// Returns whether values are checked for placeholders.
+    pub fn checks_placeholders(&self) -> bool {
+        self.placeholders.unwrap_or(true)
+    }
+}
+
+/// Inclusive numeric bounds for a configuration key.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConfigRange {
+    /// Dotted key path; `[*]` matches any list index (e.g., "servers[*].port")
+    pub key: String,
+    #[serde(default)]
+    pub min: Option<f64>,
+    #[serde(default)]
+    pub max: Option<f64>,
+}
+
+/// Violations of one rule accepted on purpose until a date.
+///
+/// `path` waives every violation of the rule in matching files; `fingerprint`
+/// waives one violation. All fields but the target are required.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Waiver {
+    /// Rule the waiver covers (e.g., "stub_function")
+    pub rule: String,
+    /// Glob pattern relative to the project root
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// Fingerprint of a single violation, as shown by `--show-fingerprints`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    /// Person or team who accepted the debt
+    #[serde(default)]
+    pub owner: String,
+    /// Why the hollow code is acceptable for now
+    #[serde(default)]
+    pub justification: String,
+    /// Last day the waiver applies, as `YYYY-MM-DD`
+    #[serde(default)]
+    pub expires: String,
+}
+
+/// Rule severities for the files under some paths.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RuleScope {
+    /// Glob patterns relative to the project root (e.g., "examples/**")
+    pub paths: Vec<String>,
+    /// Level of each rule in these paths, by rule name
+    pub rules: BTreeMap<String, ScopeLevel>,
+}
+
+/// What a scope does with a rule's violations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScopeLevel {
+    /// Drop the violations
+    Off,
+    /// Report them as warnings
+    Warn,
+    /// Report them as errors
+    Error,
+}
+
+/// Settings for the structural and content sub-scores.
+///
+/// Every rule counts toward one of the two; `categories` moves rules between
+/// them. A sub-score over its threshold fails the run even when the total
+/// score passes.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SubScoresConfig {
+    /// Maximum acceptable structural sub-score, 0-100. Default: no limit
+    #[serde(default)]
+    pub structural_threshold: Option<i32>,
+    /// Maximum acceptable content sub-score, 0-100. Default: no limit
+    #[serde(default)]
+    pub content_threshold: Option<i32>,
+    /// Category overrides by rule name (e.g., `hollow_config: structural`)
+    #[serde(default)]
+    pub categories: BTreeMap<String, crate::detect::ScoreCategory>,
+}
+
+impl SubScoresConfig {
+    /// Returns the sub-score a rule counts toward, with overrides applied.
+    pub fn category(&self, rule: crate::detect::ViolationRule) -> crate::detect::ScoreCategory {
+        self.categories
+            .iter()
+            .find(|(name, _)| name.parse::<crate::detect::ViolationRule>() == Ok(rule))
+            .map(|(_, category)| *category)
+            .unwrap_or_else(|| rule.score_category())
+    }
+}
+
+/// Configuration for the heuristic effort estimate of hollow functions.
+///
+/// Each signal adds its points per unit; the buckets turn the total into
+/// trivial, small, medium, or large, and weight each bucket in the project
+/// roll-up. Unset values take the defaults in [`crate::detect::EffortModel`].
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+pub struct EstimationConfig {
+    /// Whether estimates are made (default: true when present)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Points per unit of each signal
+    #[serde(default)]
+    pub signals: EstimationSignals,
+    /// Point thresholds and roll-up weights of the buckets
+    #[serde(default)]
+    pub buckets: EstimationBuckets,
+}
+
+impl EstimationConfig {
+    /// Returns whether estimates are made.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+/// Points per unit of each effort signal.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct EstimationSignals {
+    /// Per declared parameter, up to five (default: 1)
+    #[serde(default)]
+    pub parameter: Option<u32>,
+    /// Per return type unit: one for a plain type, two for a generic,
+    /// tuple, or union type (default: 2)
+    #[serde(default)]
+    pub return_type: Option<u32>,
+    /// Per ten words of doc comment, docstring, or TODO text, up to five (default: 1)
+    #[serde(default)]
+    pub description: Option<u32>,
+    /// Per call site elsewhere in the project, up to five (default: 1)
+    #[serde(default)]
+    pub reference: Option<u32>,
+    /// When the function is part of its module's exported API (default: 2)
+    #[serde(default)]
+    pub exported: Option<u32>,
+}
+
+/// The effort buckets.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct EstimationBuckets {
+    #[serde(default)]
+    pub trivial: Option<EstimationBucket>,
+    #[serde(default)]
+    pub small: Option<EstimationBucket>,
+    #[serde(default)]
+    pub medium: Option<EstimationBucket>,
+    #[serde(default)]
+    pub large: Option<EstimationBucket>,
+}
+
+/// Threshold and roll-up weight of one effort bucket.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct EstimationBucket {
+    /// Fewest points a finding needs to land in the bucket; trivial takes
+    /// everything below small and has none
+    #[serde(default)]
+    pub min_points: Option<u32>,
+    /// What one finding in the bucket adds to the weighted total
+    #[serde(default)]
+    pub weight: Option<u32>,
+}
+
+/// Built-in hollow response patterns for common web frameworks.
+fn builtin_handler_frameworks() -> Vec<HandlerFramework> {
+    let framework = |name: &str, extensions: &[&str], success: &str, body: Option<&str>| {
+        HandlerFramework {
+            name: name.to_string(),
+            extensions: extensions.iter().map(|e| e.to_string()).collect(),
+            success: success.to_string(),
+            body: body.map(String::from),
+        }
+    };
+
+    vec![
+        framework(
+            "net/http",
+            &["go"],
+            r"\.WriteHeader\(\s*(?:200|http\.StatusOK)\s*\)",
+            Some(r"\.Write\(|\.Encode\(|fmt\.Fprint|io\.Copy\(|io\.WriteString\(|http\.Serve(?:Content|File)\(|\.Execute(?:Template)?\("),
+        ),
+        framework(
+            "gin",
+            &["go"],
+            r"\bc\.(?:Status\(\s*(?:200|http\.StatusOK)\s*\)|JSON\(\s*(?:200|http\.StatusOK)\s*,\s*gin\.H\{\s*\}\s*\))",
+            Some(r"\bc\.(?:JSON|IndentedJSON|PureJSON|String|Data|HTML|XML|YAML|File|Render|Stream)\("),
+        ),
+        framework(
+            "express",
+            &["js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts"],
+            r#"\bres\.sendStatus\(\s*200\s*\)|\bres(?:\.status\(\s*200\s*\))?\.(?:json|send)\(\s*(?:\{\s*\}|\[\s*\]|""|''|``)?\s*\)"#,
+            None,
+        ),
+        framework(
+            "flask",
+            &["py"],
+            r#"\breturn\s+(?:""|''|\{\s*\}|\[\s*\]|jsonify\(\s*(?:\{\s*\})?\s*\))\s*,\s*200\b"#,
+            None,
+        ),
+    ]
+}
+
+impl HeaderConfig {
+    /// Returns whether header detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+impl RepeatedLiteralsConfig {
+    /// Returns whether repeated literal detection is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Returns the minimum literal length (defaults to 6).
+    pub fn min_length(&self) -> usize {
+        self.min_length.unwrap_or(6)
+    }
+
+    /// Returns the occurrence threshold (defaults to 5).
+    pub fn min_occurrences(&self) -> usize {
+        self.min_occurrences.unwrap_or(5).max(2)
+    }
+
+    /// Returns the distinct file threshold (defaults to 1).
+    pub fn min_files(&self) -> usize {
+        self.min_files.unwrap_or(1).max(1)
+    }
+}
+
+/// Default forbidden patterns for the default contract.
+fn default_forbidden_patterns() -> Vec<ForbiddenPattern> {
+    vec![
+        ForbiddenPattern {
+            pattern: r#"(?i)\bpanic!\s*\(\s*\)"#.to_string(),
+            description: Some("Empty panic! call".to_string()),
+        },
+        ForbiddenPattern {
+            pattern: r#"(?i)console\.log\("#.to_string(),
+            description: Some("Debug console.log left in code".to_string()),
+        },
+        ForbiddenPattern {
+            pattern: r#"(?i)print\s*\(\s*['"]debug"#.to_string(),
+            description: Some("Debug print statement".to_string()),
+        },
+    ]
+}
+
+/// Default mock signatures config for the default contract.
+fn default_mock_signatures() -> MockSignaturesConfig {
+    MockSignaturesConfig {
+        patterns: vec![
+            MockSignature {
+                pattern: r#"(?i)(test|fake|mock|dummy|placeholder|sample|example)[-_]?(data|value|user|email|name|id|response|result)"#.to_string(),
+                description: Some("Mock/placeholder data pattern".to_string()),
+            },
+            MockSignature {
+                pattern: r#"(foo|bar|baz|qux|lorem|ipsum)"#.to_string(),
+                description: Some("Common placeholder text".to_string()),
+            },
+            MockSignature {
+                pattern: r#"(?i)(CHANGEME|REPLACE[-_]?ME|YOUR[-_]?.*[-_]?HERE|XXX[-_]?XXX)"#.to_string(),
+                description: Some("Placeholder marker".to_string()),
+            },
+        ],
+        skip_test_files: Some(true),
+        test_file_severity: None,
+    }
+}
+
+/// Default dependency verification config for the default contract.
+fn default_dependency_verification() -> DependencyVerificationConfig {
+    DependencyVerificationConfig {
+        enabled: true,
+        registries: RegistriesConfig::default(),
+        allowlist: vec![],
+        import_overrides: BTreeMap::new(),
+        cache_ttl_hours: 24,
+        fail_on_timeout: false,
+        chunk_size: None,
+        max_packages: None,
+    }
+}
+
+/// Default god objects config for the default contract.
+fn default_god_objects() -> GodObjectContractConfig {
+    GodObjectContractConfig {
+        enabled: true,
+        max_file_lines: Some(500),
+        max_function_lines: Some(50),
+        max_function_complexity: Some(15),
+        max_functions_per_file: Some(20),
+        max_class_methods: Some(15),
+    }
+}
+
+/// A contract setting whose effective value comes from a layer over the
+/// base contract: a contract file over the one it `extends`, a profile, or
+/// a command-line flag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ValueSource {
+    /// Dotted path of the setting (`god_objects.max_file_lines`).
+    pub key: String,
+    /// The layer that set it: a contract path or URL, `profile "ci"`, or a flag such as `--strict`.
+    pub source: String,
+}
+
+impl ValueSource {
+    /// Attribute setting paths returned by a merge to one layer.
+    pub fn from_layer(keys: Vec<String>, source: &str) -> Vec<Self> {
+        keys.into_iter()
+            .map(|key| Self {
+                key,
+                source: source.to_string(),
+            })
+            .collect()
+    }
+}
+
+/// Merge the named profile from a contract document's `profiles` over the
+/// rest of the document.
+///
+/// Mappings merge key by key, recursively, so a profile can change one field
+/// of a section and keep the others. Anything else in the profile, including
+/// lists, replaces the base value outright, and `~` resets a section to its
+/// default. Command-line flags such as `--threshold` still apply on top.
+///
+/// Returns the dotted paths of the settings the profile changed.
+pub fn merge_profile(document: &mut serde_yaml::Value, name: &str) -> anyhow::Result<Vec<String>> {
+    let profiles = document.get("profiles").and_then(|p| p.as_mapping());
+    let Some(overrides) = profiles.and_then(|p| p.get(name)).cloned() else {
+        let names: Vec<&str> = profiles
+            .map(|p| p.keys().filter_map(|k| k.as_str()).collect())
+            .unwrap_or_default();
+        if names.is_empty() {
+            anyhow::bail!("unknown profile {:?}: the contract defines no profiles", name);
+        }
+        anyhow::bail!("unknown profile {:?}, must be one of: {}", name, names.join(", "));
+    };
+    check_profile(name, &overrides)?;
+    // An empty profile changes nothing
+    if overrides.is_null() {
+        return Ok(Vec::new());
+    }
+    let mut changed = Vec::new();
+    changed_keys(document, &overrides, "", &mut changed);
+    merge_yaml(document, overrides);
+    Ok(changed)
+}
+
+fn check_profile(name: &str, overrides: &serde_yaml::Value) -> anyhow::Result<()> {
+    match overrides {
+        serde_yaml::Value::Null => Ok(()),
+        serde_yaml::Value::Mapping(m) if m.contains_key("profiles") => {
+            anyhow::bail!("profile {:?} cannot define profiles", name)
+        }
+        serde_yaml::Value::Mapping(_) => Ok(()),
+        _ => anyhow::bail!("profile {:?} must be a mapping of contract settings", name),
+    }
+}
+
+/// Merge a contract document over the remote contract it `extends`.
+///
+/// Settings combine as profiles do (see [`merge_profile`]): the local
+/// document's mappings merge into the remote's key by key, and its lists
+/// and scalars replace the remote's. A remote contract cannot itself
+/// extend another.
+///
+/// Returns the dotted paths of the settings the local document changed;
+/// empty when it extends nothing.
+pub fn resolve_extends(document: &mut serde_yaml::Value) -> anyhow::Result<Vec<String>> {
+    let Some(extends) = document.get("extends") else {
+        return Ok(Vec::new());
+    };
+    if let Some(url) = extends.as_str() {
+        anyhow::bail!(
+            "extends {:?} needs an integrity pin: extends: {{url: {:?}, sha256: <hash>}}",
+            url,
+            url
+        );
+    }
+    let remote: RemoteContract = serde_yaml::from_value(extends.clone())
+        .map_err(|e| anyhow::anyhow!("invalid extends: {}", e))?;
+
+    let content = policy::fetch_contract(&remote)?;
+    let mut base: serde_yaml::Value = serde_yaml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("invalid contract {}: {}", remote.url, e))?;
+    if base.get("extends").is_some() {
+        anyhow::bail!("remote contract {} cannot extend another contract", remote.url);
+    }
+    let mut changed = Vec::new();
+    changed_keys(&base, document, "", &mut changed);
+    merge_yaml(&mut base, std::mem::take(document));
+    *document = base;
+    Ok(changed)
+}
+
+/// Collect the dotted paths of the settings that merging `overrides` over
+/// `base` would change, following [`merge_yaml`]: mappings recurse, also
+/// into a section the base does not set, and any other value that differs
+/// from the base is one setting.
+fn changed_keys(base: &serde_yaml::Value, overrides: &serde_yaml::Value, prefix: &str, changed: &mut Vec<String>) {
+    let empty = serde_yaml::Mapping::new();
+    let base_mapping = match base {
+        serde_yaml::Value::Mapping(base) => Some(base),
+        serde_yaml::Value::Null => Some(&empty),
+        _ => None,
+    };
+    match (base_mapping, overrides) {
+        (Some(base), serde_yaml::Value::Mapping(overrides)) => {
+            for (key, value) in overrides {
+                let name = key.as_str().map(str::to_string).unwrap_or_else(|| {
+                    serde_yaml::to_string(key).unwrap_or_default().trim().to_string()
+                });
+                let path = if prefix.is_empty() { name } else { format!("{}.{}", prefix, name) };
+                let existing = base.get(key).unwrap_or(&serde_yaml::Value::Null);
+                changed_keys(existing, value, &path, changed);
+            }
+        }
+        _ => {
+            if base != overrides {
+                changed.push(prefix.to_string());
+            }
+        }
+    }
+}
+
+fn merge_yaml(base: &mut serde_yaml::Value, overrides: serde_yaml::Value) {
+    match (base, overrides) {
+        (serde_yaml::Value::Mapping(base), serde_yaml::Value::Mapping(overrides)) => {
+            for (key, value) in overrides {
+                match base.get_mut(&key) {
+                    Some(existing) => merge_yaml(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overrides) => *base = overrides,
+    }
+}
+
+/// Validate a contract for correctness.
+pub fn validate(contract: &Contract) -> anyhow::Result<()> {
+    // Validate mode
+    if let Some(mode) = &contract.mode {
+        if mode != "code" && mode != "prose" {
             anyhow::bail!("invalid mode {:?}, must be 'code' or 'prose'", mode);
         }
     }
 
-    // Validate forbidden patterns compile
-    for p in &contract.forbidden_patterns {
-        regex::Regex::new(&p.pattern)
-            .map_err(|e| anyhow::anyhow!("invalid forbidden pattern {:?}: {}", p.pattern, e))?;
+    // Validate report limits; a limit of zero would leave an empty report
+    for (name, limit) in [
+        ("max_violations_per_rule", contract.max_violations_per_rule),
+        ("max_violations_total", contract.max_violations_total),
+    ] {
+        if limit == Some(0) {
+            anyhow::bail!("invalid {} 0, must be at least 1", name);
+        }
+    }
+
+    // Validate forbidden patterns compile within the size limit
+    for p in &contract.forbidden_patterns {
+        crate::detect::compile_user_pattern(&p.pattern)
+            .map_err(|e| anyhow::anyhow!("invalid forbidden pattern {:?}: {}", p.pattern, e))?;
+    }
+
+    // Validate mock signature patterns compile within the size limit
+    if let Some(mock_cfg) = &contract.mock_signatures {
+        for s in &mock_cfg.patterns {
+            crate::detect::compile_user_pattern(&s.pattern)
+                .map_err(|e| anyhow::anyhow!("invalid mock signature {:?}: {}", s.pattern, e))?;
+        }
+    }
+
+    // Validate implementation coverage globs and ratios
+    for req in &contract.implementation_coverage {
+        globset::Glob::new(&req.path).map_err(|e| {
+            anyhow::anyhow!("invalid implementation_coverage path {:?}: {}", req.path, e)
+        })?;
+        if !(0.0..=1.0).contains(&req.min_ratio) {
+            anyhow::bail!(
+                "invalid implementation_coverage min_ratio {} for {:?}, must be between 0.0 and 1.0",
+                req.min_ratio,
+                req.path
+            );
+        }
+    }
+
+    // Validate forbidden file globs and severities
+    for f in &contract.forbidden_files {
+        globset::Glob::new(&f.pattern)
+            .map_err(|e| anyhow::anyhow!("invalid forbidden_files pattern {:?}: {}", f.pattern, e))?;
+        if let Some(severity) = &f.severity {
+            severity
+                .parse::<crate::detect::Severity>()
+                .map_err(|e| anyhow::anyhow!("invalid forbidden_files severity for {:?}: {}", f.pattern, e))?;
+        }
+    }
+
+    // Validate max_file_size globs
+    if let Some(size_cfg) = &contract.max_file_size {
+        for limit in &size_cfg.paths {
+            globset::Glob::new(&limit.path).map_err(|e| {
+                anyhow::anyhow!("invalid max_file_size path {:?}: {}", limit.path, e)
+            })?;
+        }
     }
 
-    // Validate mock signature patterns compile
-    if let Some(mock_cfg) = &contract.mock_signatures {
-        for s in &mock_cfg.patterns {
-            regex::Regex::new(&s.pattern)
-                .map_err(|e| anyhow::anyhow!("invalid mock signature {:?}: {}", s.pattern, e))?;
+    // Validate expected header regex
+    if let Some(pattern) = contract.detect_header.as_ref().and_then(|h| h.pattern.as_ref()) {
+        regex::Regex::new(pattern)
+            .map_err(|e| anyhow::anyhow!("invalid detect_header pattern {:?}: {}", pattern, e))?;
+    }
+
+    // Validate parameter list allowlist globs
+    if let Some(params_cfg) = &contract.long_parameter_lists {
+        for pattern in params_cfg.allowlist() {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid long_parameter_lists allowlist {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate the deprecated usage threshold
+    if contract.deprecated_usage.as_ref().is_some_and(|c| c.min_call_sites() == 0) {
+        anyhow::bail!("deprecated_usage.min_call_sites must be at least 1");
+    }
+
+    // Validate unwrap overuse production globs
+    if let Some(unwrap_cfg) = &contract.unwrap_overuse {
+        for pattern in &unwrap_cfg.production_paths {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid unwrap_overuse production_paths {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate async passthrough globs
+    if let Some(async_cfg) = &contract.async_without_await {
+        for pattern in &async_cfg.passthrough() {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid async_without_await passthrough {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate force-unwrap test globs
+    if let Some(force_cfg) = &contract.force_unwraps {
+        for pattern in &force_cfg.test_paths() {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid force_unwraps test_paths {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate trivial wrapper facade globs
+    if let Some(wrappers_cfg) = &contract.trivial_wrappers {
+        for pattern in &wrappers_cfg.api_facade_paths {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid trivial_wrappers api_facade_paths {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate comment ratio bounds
+    if let Some(ratio_cfg) = &contract.comment_ratio {
+        if ratio_cfg.min_ratio() < 0.0 || ratio_cfg.max_ratio() <= ratio_cfg.min_ratio() {
+            anyhow::bail!(
+                "invalid comment_ratio bounds: min_ratio {} and max_ratio {}, must satisfy 0.0 <= min_ratio < max_ratio",
+                ratio_cfg.min_ratio(),
+                ratio_cfg.max_ratio()
+            );
+        }
+        if ratio_cfg.min_complex_functions() == 0 {
+            anyhow::bail!("comment_ratio.min_complex_functions must be at least 1");
+        }
+    }
+
+    // Validate where unused suppressions are reported
+    if let Some(report) = contract.unused_suppressions.as_ref().and_then(|c| c.report.as_deref()) {
+        if !matches!(report, "violations" | "suppressed") {
+            anyhow::bail!("invalid unused_suppressions report {:?}, must be 'violations' or 'suppressed'", report);
+        }
+    }
+
+    // Validate the documentation coverage gate
+    if let Some(doc_cfg) = &contract.doc_coverage {
+        if let Some(min) = doc_cfg.min_doc_coverage {
+            if !(0.0..=1.0).contains(&min) {
+                anyhow::bail!("invalid doc_coverage min_doc_coverage {}, must be between 0.0 and 1.0", min);
+            }
+        }
+    }
+
+    // Validate the effort buckets: trivial takes everything below small
+    if let Some(estimation_cfg) = &contract.estimation {
+        if estimation_cfg.buckets.trivial.as_ref().is_some_and(|b| b.min_points.is_some()) {
+            anyhow::bail!("estimation bucket trivial takes no min_points; it holds every finding below small");
+        }
+        let model = crate::detect::EffortModel::from_config(estimation_cfg);
+        let [small, medium, large] = model.thresholds();
+        if small == 0 || small >= medium || medium >= large {
+            anyhow::bail!(
+                "invalid estimation bucket thresholds: small {}, medium {}, large {}, must satisfy 0 < small < medium < large",
+                small,
+                medium,
+                large
+            );
+        }
+    }
+
+    // Validate expected environment versions
+    if let Some(environment_cfg) = &contract.environment {
+        for (name, version) in environment_cfg.expectations() {
+            if crate::detect::VersionReq::parse(version).is_none() {
+                anyhow::bail!("invalid environment version {:?} for {}", version, name);
+            }
+        }
+    }
+
+    // Validate missing definition name globs
+    if let Some(definitions_cfg) = &contract.missing_definitions {
+        for (field, patterns) in [
+            ("external", &definitions_cfg.external),
+            ("ignore", &definitions_cfg.ignore),
+        ] {
+            for pattern in patterns {
+                globset::Glob::new(pattern).map_err(|e| {
+                    anyhow::anyhow!("invalid missing_definitions {} {:?}: {}", field, pattern, e)
+                })?;
+            }
+        }
+    }
+
+    // Validate intentionally abstract interface globs
+    if let Some(interface_cfg) = &contract.unimplemented_interfaces {
+        for pattern in &interface_cfg.allowlist {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid unimplemented_interfaces allowlist {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate doc drift globs and command pattern
+    if let Some(doc_cfg) = &contract.doc_drift {
+        for pattern in &doc_cfg.docs {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid doc_drift docs pattern {:?}: {}", pattern, e)
+            })?;
+        }
+        if let Some(pattern) = &doc_cfg.command_pattern {
+            let re = regex::Regex::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid doc_drift command_pattern {:?}: {}", pattern, e)
+            })?;
+            if re.captures_len() < 2 {
+                anyhow::bail!(
+                    "doc_drift command_pattern {:?} needs a capture group for the subcommand",
+                    pattern
+                );
+            }
+        }
+    }
+
+    // Validate empty handler framework regexes and allowlist globs
+    if let Some(handler_cfg) = &contract.empty_handlers {
+        for f in &handler_cfg.frameworks {
+            for pattern in std::iter::once(&f.success).chain(&f.body) {
+                regex::Regex::new(pattern).map_err(|e| {
+                    anyhow::anyhow!("invalid empty_handlers pattern for {:?}: {}", f.name, e)
+                })?;
+            }
+        }
+        for pattern in handler_cfg.allowlist() {
+            globset::Glob::new(pattern).map_err(|e| {
+                anyhow::anyhow!("invalid empty_handlers allowlist {:?}: {}", pattern, e)
+            })?;
+        }
+    }
+
+    // Validate default exclude ecosystems
+    if let Some(excludes_cfg) = &contract.default_excludes {
+        for name in excludes_cfg.ecosystems.iter().flatten() {
+            if !VENDORED_DIRS.iter().any(|(ecosystem, _)| ecosystem == name) {
+                anyhow::bail!("unknown ecosystem {:?} in default_excludes", name);
+            }
+        }
+    }
+
+    // Validate container and CI pipeline check severities
+    let infra_severities = [
+        contract.containers.as_ref().map(|c| {
+            ("containers", [c.latest_tag_severity(), c.placeholder_severity(), c.todo_severity()])
+        }),
+        contract.ci_configs.as_ref().map(|c| {
+            ("ci_configs", [c.latest_tag_severity(), c.placeholder_severity(), c.todo_severity()])
+        }),
+    ];
+    for (section, severities) in infra_severities.into_iter().flatten() {
+        let fields = ["latest_tag_severity", "placeholder_severity", "todo_severity"];
+        for (field, severity) in fields.into_iter().zip(severities) {
+            severity
+                .parse::<crate::detect::Severity>()
+                .map_err(|e| anyhow::anyhow!("invalid {} {}: {}", section, field, e))?;
+        }
+    }
+
+    // Validate schema check patterns and severity
+    if let Some(schemas) = &contract.schemas {
+        for pattern in schemas.allow_empty() {
+            regex::Regex::new(pattern)
+                .map_err(|e| anyhow::anyhow!("invalid schemas allow_empty pattern {:?}: {}", pattern, e))?;
+        }
+        schemas
+            .todo_severity()
+            .parse::<crate::detect::Severity>()
+            .map_err(|e| anyhow::anyhow!("invalid schemas todo_severity: {}", e))?;
+    }
+
+    // Validate marker words, ticket pattern, and tracked severity
+    if let Some(markers_cfg) = &contract.markers {
+        markers_cfg.vocabulary()?;
+        markers_cfg
+            .tracked_severity()
+            .parse::<crate::detect::Severity>()
+            .map_err(|e| anyhow::anyhow!("invalid markers tracked_severity: {}", e))?;
+    }
+
+    // Validate weak cryptography severity
+    if let Some(crypto_cfg) = &contract.weak_crypto {
+        crypto_cfg
+            .severity()
+            .parse::<crate::detect::Severity>()
+            .map_err(|e| anyhow::anyhow!("invalid weak_crypto severity: {}", e))?;
+    }
+
+    // Validate placeholder constant settings
+    if let Some(constants_cfg) = &contract.placeholder_constants {
+        constants_cfg
+            .severity()
+            .parse::<crate::detect::Severity>()
+            .map_err(|e| anyhow::anyhow!("invalid placeholder_constants severity: {}", e))?;
+        if constants_cfg.min_members() < 2 {
+            anyhow::bail!(
+                "invalid placeholder_constants min_members {}, must be at least 2",
+                constants_cfg.min_members()
+            );
+        }
+    }
+
+    // Validate unhandled enum case coverage
+    if let Some(enum_cfg) = &contract.unhandled_enum_cases {
+        if !(0.0..=1.0).contains(&enum_cfg.min_coverage()) {
+            anyhow::bail!(
+                "invalid unhandled_enum_cases min_coverage {}, must be between 0.0 and 1.0",
+                enum_cfg.min_coverage()
+            );
+        }
+    }
+
+    // Validate config file globs and ranges
+    for spec in &contract.config_files {
+        globset::Glob::new(&spec.pattern)
+            .map_err(|e| anyhow::anyhow!("invalid config_files pattern {:?}: {}", spec.pattern, e))?;
+        for range in &spec.ranges {
+            if let (Some(min), Some(max)) = (range.min, range.max) {
+                if min > max {
+                    anyhow::bail!(
+                        "invalid config_files range for {:?}: min {} is greater than max {}",
+                        range.key,
+                        min,
+                        max
+                    );
+                }
+            }
+        }
+    }
+
+    // Validate language names
+    if let Some(filter) = &contract.languages {
+        for name in filter.include.iter().chain(&filter.exclude) {
+            if crate::analysis::normalize_language(name).is_none() {
+                anyhow::bail!("unknown language {:?} in languages filter", name);
+            }
+        }
+    }
+
+    // Validate language overrides route to an analyzer
+    for (pattern, language) in &contract.language_overrides {
+        globset::Glob::new(pattern)
+            .map_err(|e| anyhow::anyhow!("invalid language_overrides pattern {:?}: {}", pattern, e))?;
+        if crate::analysis::analyzer_language(language).is_none() {
+            anyhow::bail!(
+                "language_overrides {:?}: no analyzer for language {:?}",
+                pattern,
+                language
+            );
         }
     }
 
@@ -493,9 +3409,180 @@ pub fn validate(contract: &Contract) -> anyhow::Result<()> {
             .map_err(|e| anyhow::anyhow!("invalid excluded_paths pattern {:?}: {}", pattern, e))?;
     }
 
+    // Validate stub exemption rules and removed ids
+    if let Some(exemptions) = &contract.stub_exemptions {
+        exemptions.resolve()?;
+    }
+
+    // Validate the repetitive body ratio
+    if let Some(repetitive) = &contract.repetitive_bodies {
+        if !(0.0..=1.0).contains(&repetitive.min_distinct_ratio()) {
+            anyhow::bail!(
+                "invalid repetitive_bodies min_distinct_ratio {}, must be between 0.0 and 1.0",
+                repetitive.min_distinct_ratio()
+            );
+        }
+    }
+
+    // Validate custom stub signatures
+    for (i, signature) in contract.custom_stub_signatures.iter().enumerate() {
+        signature
+            .resolve()
+            .map_err(|e| anyhow::anyhow!("invalid custom_stub_signatures[{}]: {}", i, e))?;
+    }
+
+    // Validate the rules kept for generated files
+    if let Some(generated) = &contract.generated_files {
+        for rule in &generated.rules {
+            rule.parse::<crate::detect::ViolationRule>()
+                .map_err(|e| anyhow::anyhow!("invalid generated_files rule: {}", e))?;
+        }
+    }
+
+    // Validate waivers: every field is required, and the target is a glob or a fingerprint
+    for (i, waiver) in contract.waivers.iter().enumerate() {
+        let at = format!("waivers[{}]", i);
+        waiver
+            .rule
+            .parse::<crate::detect::ViolationRule>()
+            .map_err(|e| anyhow::anyhow!("invalid {} rule: {}", at, e))?;
+        match (&waiver.path, &waiver.fingerprint) {
+            (Some(_), Some(_)) | (None, None) => {
+                anyhow::bail!("invalid {}: set exactly one of path or fingerprint", at)
+            }
+            (Some(path), None) => {
+                globset::Glob::new(path)
+                    .map_err(|e| anyhow::anyhow!("invalid {} path {:?}: {}", at, path, e))?;
+            }
+            (None, Some(_)) => {}
+        }
+        if waiver.owner.trim().is_empty() {
+            anyhow::bail!("invalid {}: owner is required", at);
+        }
+        if waiver.justification.trim().is_empty() {
+            anyhow::bail!("invalid {}: justification is required", at);
+        }
+        if crate::detect::days_since_epoch(&waiver.expires).is_none() {
+            anyhow::bail!(
+                "invalid {} expires {:?}, must be a YYYY-MM-DD date",
+                at,
+                waiver.expires
+            );
+        }
+    }
+
+    // Validate scope globs and rule names
+    for (i, scope) in contract.scopes.iter().enumerate() {
+        if scope.paths.is_empty() {
+            anyhow::bail!("invalid scopes[{}]: paths is empty", i);
+        }
+        for path in &scope.paths {
+            globset::Glob::new(path)
+                .map_err(|e| anyhow::anyhow!("invalid scopes[{}] path {:?}: {}", i, path, e))?;
+        }
+        for rule in scope.rules.keys() {
+            rule.parse::<crate::detect::ViolationRule>()
+                .map_err(|e| anyhow::anyhow!("invalid scopes[{}] rule: {}", i, e))?;
+        }
+    }
+
+    // Validate threshold range
+    if let Some(threshold) = contract.threshold {
+        if !(0..=100).contains(&threshold) {
+            anyhow::bail!("invalid threshold {}, must be between 0 and 100", threshold);
+        }
+    }
+    if let Some(max_debt) = contract.max_debt {
+        if !(0..=100).contains(&max_debt) {
+            anyhow::bail!("invalid max_debt {}, must be between 0 and 100", max_debt);
+        }
+    }
+    if let Some(sub_scores) = &contract.sub_scores {
+        for (name, threshold) in [
+            ("structural_threshold", sub_scores.structural_threshold),
+            ("content_threshold", sub_scores.content_threshold),
+        ] {
+            if let Some(threshold) = threshold.filter(|t| !(0..=100).contains(t)) {
+                anyhow::bail!("invalid sub_scores {} {}, must be between 0 and 100", name, threshold);
+            }
+        }
+        for rule in sub_scores.categories.keys() {
+            rule.parse::<crate::detect::ViolationRule>()
+                .map_err(|e| anyhow::anyhow!("invalid sub_scores category: {}", e))?;
+        }
+    }
+    for (rule, points) in &contract.points {
+        rule.parse::<crate::detect::ViolationRule>()
+            .map_err(|e| anyhow::anyhow!("invalid points: {}", e))?;
+        if !(0..=100).contains(points) {
+            anyhow::bail!("invalid points for {} {}, must be between 0 and 100", rule, points);
+        }
+    }
+
+    // Validate the shape of every profile, not only the selected one
+    for (name, overrides) in &contract.profiles {
+        check_profile(name, overrides)?;
+    }
+
     Ok(())
 }
 
+/// Non-fatal contract problems worth reporting before a run.
+///
+/// Flags structural requirements (required files, symbols, tests,
+/// complexity targets) that point at files in a language excluded by the
+/// `languages` filter. Symbol, test, and complexity requirements on those
+/// files are skipped by the runner rather than failing.
+///
+/// Also flags forbidden patterns and mock signatures likely to match slowly
+/// on large files, such as nested quantifiers; the runner disables them if
+/// they exceed their matching budget.
+pub fn validation_warnings(contract: &Contract) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let mut check = |section: &str, file: &str| {
+        if contract.is_path_language_excluded(Path::new(file)) {
+            warnings.push(format!(
+                "{} entry {:?} targets a language excluded by the languages filter",
+                section, file
+            ));
+        }
+    };
+
+    for f in &contract.required_files {
+        check("required_files", &f.path);
+    }
+    for s in &contract.required_symbols {
+        check("required_symbols", &s.file);
+    }
+    for t in &contract.required_tests {
+        if let Some(file) = &t.file {
+            check("required_tests", file);
+        }
+    }
+    for c in &contract.complexity {
+        if let Some(file) = &c.file {
+            check("complexity", file);
+        }
+    }
+
+    let mock_patterns = contract.mock_signatures.iter().flat_map(|cfg| &cfg.patterns);
+    let patterns = contract
+        .forbidden_patterns
+        .iter()
+        .map(|p| ("forbidden_patterns", &p.pattern))
+        .chain(mock_patterns.map(|s| ("mock_signatures", &s.pattern)));
+    for (section, pattern) in patterns {
+        if let Some(reason) = crate::detect::suspicious_pattern(pattern) {
+            warnings.push(format!(
+                "{} pattern {:?} {} and may match slowly on large files",
+                section, pattern, reason
+            ));
+        }
+    }
+
+    warnings
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -518,6 +3605,454 @@ forbidden_patterns:
         assert_eq!(contract.forbidden_patterns.len(), 1);
     }
 
+    #[test]
+    fn test_validate_implementation_coverage_ratio() {
+        let yaml = r#"
+implementation_coverage:
+  - path: "src/api/**"
+    min_ratio: 1.5
+"#;
+        let contract: Contract = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(contract.implementation_coverage.len(), 1);
+        let err = validate(&contract).unwrap_err();
+        assert!(err.to_string().contains("min_ratio"));
+    }
+
+    #[test]
+    fn test_validate_stub_exemptions() {
+        let parse = |entry: &str| -> Contract { serde_yaml::from_str(&format!("stub_exemptions:\n  - {}\n", entry)).unwrap() };
+
+        let contract = parse("name_pattern: \"^On[A-Z]\"\n    kinds: [empty, todo_comment]");
+        assert!(validate(&contract).is_ok());
+        let exemptions = contract.stub_exemptions.unwrap();
+        assert!(!exemptions.builtins);
+        let exemption = exemptions.rules[0].compile(0).unwrap();
+        assert_eq!(exemption.id, "contract-0");
+        assert_eq!(
+            exemption.kinds,
+            vec![crate::analysis::HollowBodyKind::Empty, crate::analysis::HollowBodyKind::TodoCommentOnly]
+        );
+
+        for (entry, error) in [
+            ("kinds: [empty]", "needs a name_pattern, a path_pattern, or a signature"),
+            ("name_pattern: \"(\"", "invalid pattern"),
+            ("path_pattern: \"mocks/\"\n    kinds: [stub]", "invalid kind \"stub\""),
+        ] {
+            let err = validate(&parse(entry)).unwrap_err().to_string();
+            assert!(err.starts_with("invalid stub_exemptions[0]: "), "{}", err);
+            assert!(err.contains(error), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_validate_custom_stub_signatures() {
+        let parse = |entry: &str| -> Contract {
+            serde_yaml::from_str(&format!("custom_stub_signatures:\n  - {}\n", entry)).unwrap()
+        };
+
+        let contract = parse("language: golang\n    names: [ErrNotImplemented, deps.NotWired]\n    severity: error");
+        assert!(validate(&contract).is_ok());
+        let resolved = contract.custom_stub_signatures[0].resolve().unwrap();
+        assert_eq!(resolved.signature.language, "go");
+        assert_eq!(resolved.severity, crate::detect::Severity::Error);
+        let resolved = parse("language: python\n    names: [FeatureNotReady]").custom_stub_signatures[0].resolve().unwrap();
+        assert_eq!(resolved.severity, crate::detect::Severity::Warning);
+
+        for (entry, error) in [
+            ("language: cobol\n    names: [NotReady]", "unknown language \"cobol\""),
+            ("language: kotlin\n    names: [NotReady]", "unknown language \"kotlin\""),
+            ("language: go\n    names: []", "needs at least one name"),
+            ("language: go\n    names: [e]", "name \"e\" is too generic"),
+            ("language: python\n    names: [None]", "name \"None\" is too generic"),
+            ("language: rust\n    names: [return]", "name \"return\" is too generic"),
+            ("language: go\n    names: [\"return ErrX\"]", "must be an identifier or a dotted path"),
+            ("language: go\n    names: [ErrX]\n    severity: fatal", "invalid severity"),
+        ] {
+            let err = validate(&parse(entry)).unwrap_err().to_string();
+            assert!(err.starts_with("invalid custom_stub_signatures[0]: "), "{}", err);
+            assert!(err.contains(error), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_stub_exemptions_merge_with_builtins() {
+        let resolve = |yaml: &str| {
+            let contract: Contract = serde_yaml::from_str(&format!("stub_exemptions:\n{}", yaml)).unwrap();
+            contract.stub_exemptions.unwrap().resolve()
+        };
+        let builtins = crate::analysis::default_stub_exemptions();
+
+        let exemptions = resolve(
+            "  remove: [rust-on-hooks]\n  rules:\n    - id: rust-callbacks\n      name_pattern: \"^visit_\"\n    \
+             - signature: [ignored_params]\n      languages: [go]\n",
+        )
+        .unwrap();
+        let ids: Vec<_> = exemptions.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids.len(), builtins.len());
+        assert!(!ids.contains(&"rust-on-hooks"));
+        assert_eq!(ids.last(), Some(&"contract-1"));
+        // A rule with a built-in id replaces it in place
+        let callbacks = exemptions.iter().find(|e| e.id == "rust-callbacks").unwrap();
+        assert_eq!(callbacks.name_pattern.as_ref().unwrap().as_str(), "^visit_");
+        assert!(callbacks.languages.is_empty());
+
+        assert!(resolve("  builtins: false\n").unwrap().is_empty());
+
+        for (yaml, error) in [
+            ("  remove: [rust-hooks]\n", "unknown built-in stub exemption id \"rust-hooks\""),
+            ("  rules:\n    - signature: [bounded]\n", "invalid signature \"bounded\""),
+            ("  rules:\n    - name_pattern: x\n      languages: [cobol]\n", "unknown language \"cobol\""),
+            (
+                "  rules:\n    - {id: a, name_pattern: x}\n    - {id: a, name_pattern: y}\n",
+                "duplicate stub exemption id \"a\"",
+            ),
+        ] {
+            let err = resolve(yaml).unwrap_err().to_string();
+            assert!(err.contains(error), "{}", err);
+        }
+    }
+
+    #[test]
+    fn test_parse_forbidden_files_and_size_limits() {
+        let yaml = r#"
+forbidden_files:
+  - pattern: "**/*.orig"
+    message: "patch leftover"
+    severity: warning
+forbid_env_files: false
+max_file_size:
+  max_bytes: 1000
+  paths:
+    - path: "assets/**"
+      max_bytes: 5000
+"#;
+        let contract: Contract = serde_yaml::from_str(yaml).unwrap();
+        assert!(validate(&contract).is_ok());
+        assert_eq!(contract.forbidden_files.len(), 1);
+        assert!(!contract.forbid_env_files());
+
+        let size_cfg = contract.max_file_size.unwrap();
+        assert_eq!(size_cfg.limit_for("assets/logo.png"), Some(5000));
+        assert_eq!(size_cfg.limit_for("src/main.go"), Some(1000));
+
+        let bad: Contract = serde_yaml::from_str(
+            "forbidden_files:\n  - pattern: \"*.log\"\n    severity: fatal\n",
+        )
+        .unwrap();
+        assert!(validate(&bad).unwrap_err().to_string().contains("severity"));
+    }
+
+    #[test]
+    fn test_language_filter() {
+        let yaml = r#"
+languages:
+  include: [golang, py]
+required_symbols:
+  - name: "render"
+    kind: function
+    file: "web/app.ts"
+"#;
+        let contract: Contract = serde_yaml::from_str(yaml).unwrap();
+        assert!(validate(&contract).is_ok());
+        assert!(contract.is_language_enabled("go"));
+        assert!(contract.is_language_enabled("python"));
+        assert!(!contract.is_language_enabled("typescript"));
+        assert!(contract.is_path_language_excluded(Path::new("web/app.ts")));
+        assert!(!contract.is_path_language_excluded(Path::new("README.md")));
+
+        let warnings = validation_warnings(&contract);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("web/app.ts"));
+
+        let filter = LanguageFilter {
+            include: vec![],
+            exclude: vec!["perl".to_string()],
+        };
+        assert!(!filter.allows("perl"));
+        assert!(filter.allows("go"));
+
+        let bad: Contract = serde_yaml::from_str("languages:\n  include: [cobol]\n").unwrap();
+        assert!(validate(&bad).unwrap_err().to_string().contains("cobol"));
+    }
+
+    #[test]
+    fn test_profiles_merge_over_base() {
+        let yaml = r#"
+threshold: 50
+excluded_paths: ["examples/**", "scripts/**"]
+god_objects:
+  enabled: true
+  max_file_lines: 800
+  max_function_lines: 80
+profiles:
+  ci:
+    threshold: 10
+    excluded_paths: ["examples/**"]
+    god_objects:
+      max_file_lines: 400
+    dependency_verification: ~
+  dev: ~
+"#;
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join(".hollowcheck.yaml");
+        fs::write(&path, yaml).unwrap();
+
+        let base = Contract::parse_file_with_profile(&path, None).unwrap();
+        assert_eq!(base.threshold, Some(50));
+        assert_eq!(base.profiles.len(), 2);
+        assert!(validate(&base).is_ok());
+
+        let ci = Contract::parse_file_with_profile(&path, Some("ci")).unwrap();
+        assert_eq!(ci.threshold, Some(10));
+        assert_eq!(ci.excluded_paths, vec!["examples/**"]);
+        let god = ci.god_objects.unwrap();
+        assert_eq!(god.max_file_lines, Some(400));
+        assert_eq!(god.max_function_lines, Some(80));
+        assert!(god.enabled);
+
+        let dev = Contract::parse_file_with_profile(&path, Some("dev")).unwrap();
+        assert_eq!(dev.threshold, Some(50));
+
+        let err = Contract::parse_file_with_profile(&path, Some("release")).unwrap_err();
+        assert!(err.to_string().contains("must be one of: ci, dev"), "{}", err);
+
+        let (_, sources) = Contract::resolve_file(&path, Some("ci")).unwrap();
+        let keys: Vec<_> = sources.iter().map(|s| s.key.as_str()).collect();
+        assert_eq!(keys, vec!["threshold", "excluded_paths", "god_objects.max_file_lines"]);
+        assert!(sources.iter().all(|s| s.source == "profile \"ci\""));
+        assert!(Contract::resolve_file(&path, Some("dev")).unwrap().1.is_empty());
+    }
+
+    #[test]
+    fn test_profiles_validation() {
+        let mut doc: serde_yaml::Value =
+            serde_yaml::from_str("profiles:\n  ci:\n    profiles: {}\n").unwrap();
+        assert!(merge_profile(&mut doc, "ci").unwrap_err().to_string().contains("cannot define"));
+
+        let contract: Contract = serde_yaml::from_str("profiles:\n  ci: [strict]\n").unwrap();
+        assert!(validate(&contract).unwrap_err().to_string().contains("must be a mapping"));
+
+        let contract: Contract = serde_yaml::from_str("threshold: 120\n").unwrap();
+        assert!(validate(&contract).unwrap_err().to_string().contains("threshold"));
+    }
+
+    #[test]
+    fn test_extends_requires_pin() {
+        let mut doc: serde_yaml::Value =
+            serde_yaml::from_str("extends: https://policies.example/hollowcheck/base-v3.yaml\n").unwrap();
+        let err = resolve_extends(&mut doc).unwrap_err().to_string();
+        assert!(err.contains("needs an integrity pin"), "{}", err);
+
+        let mut doc: serde_yaml::Value =
+            serde_yaml::from_str("extends:\n  url: https://policies.example/base.yaml\n").unwrap();
+        assert!(resolve_extends(&mut doc).unwrap_err().to_string().contains("invalid extends"));
+
+        // Contracts without extends are untouched
+        let mut doc: serde_yaml::Value = serde_yaml::from_str("threshold: 20\n").unwrap();
+        resolve_extends(&mut doc).unwrap();
+        assert_eq!(doc.get("threshold").and_then(|t| t.as_i64()), Some(20));
+    }
+
+    #[test]
+    fn test_validate_environment_versions() {
+        let mut contract = Contract {
+            environment: Some(EnvironmentConfig {
+                enabled: true,
+                python: Some(">=3.11,<3.13".to_string()),
+                frameworks: BTreeMap::from([("django".to_string(), "^4.2".to_string())]),
+                ..EnvironmentConfig::default()
+            }),
+            ..Contract::default()
+        };
+        assert!(validate(&contract).is_ok());
+
+        contract.environment.as_mut().unwrap().go = Some("latest".to_string());
+        let err = validate(&contract).unwrap_err();
+        assert_eq!(err.to_string(), "invalid environment version \"latest\" for go");
+    }
+
+    #[test]
+    fn test_validate_points() {
+        let points = |yaml: &str| -> anyhow::Result<Contract> {
+            let contract: Contract = serde_yaml::from_str(&format!("points:\n{}", yaml)).unwrap();
+            validate(&contract).map(|_| contract)
+        };
+
+        assert_eq!(points("  mock_data: 5\n  god-file: 0\n").unwrap().points.len(), 2);
+        let err = points("  stubs: 5\n").unwrap_err();
+        assert!(err.to_string().contains("invalid points: unknown rule: stubs"), "{}", err);
+        let err = points("  mock_data: -1\n").unwrap_err();
+        assert_eq!(err.to_string(), "invalid points for mock_data -1, must be between 0 and 100");
+    }
+
+    #[test]
+    fn test_validate_estimation() {
+        let estimation = |yaml: &str| -> anyhow::Result<Contract> {
+            let contract: Contract = serde_yaml::from_str(&format!("estimation:\n{}", yaml))?;
+            validate(&contract).map(|_| contract)
+        };
+
+        let contract = estimation("  buckets:\n    small: {min_points: 2, weight: 3}\n").unwrap();
+        assert!(contract.estimation.unwrap().is_enabled());
+        let err = estimation("  buckets:\n    medium: {min_points: 15}\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid estimation bucket thresholds: small 3, medium 15, large 12, must satisfy 0 < small < medium < large"
+        );
+        let err = estimation("  buckets:\n    trivial: {min_points: 1}\n").unwrap_err();
+        assert!(err.to_string().contains("trivial takes no min_points"), "{}", err);
+        let err = estimation("  signals:\n    params: 2\n").unwrap_err();
+        assert!(err.to_string().contains("unknown field `params`"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_unused_suppressions() {
+        let unused = |yaml: &str| -> anyhow::Result<Contract> {
+            let contract: Contract = serde_yaml::from_str(&format!("unused_suppressions:\n{}", yaml)).unwrap();
+            validate(&contract).map(|_| contract)
+        };
+
+        let config = unused("  redundant: false\n").unwrap().unused_suppressions.unwrap();
+        assert!(config.is_enabled());
+        assert!(!config.redundant());
+        assert!(!config.report_as_suppressed());
+        assert!(unused("  report: suppressed\n").unwrap().unused_suppressions.unwrap().report_as_suppressed());
+        let err = unused("  report: warnings\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid unused_suppressions report \"warnings\", must be 'violations' or 'suppressed'"
+        );
+    }
+
+    #[test]
+    fn test_excluded_paths_match_case() {
+        let contract = Contract {
+            excluded_paths: vec!["Vendor/**".to_string(), "**/*.PB.go".to_string()],
+            ..Default::default()
+        };
+        assert!(contract.is_path_excluded(Path::new("Vendor/lib/a.go")));
+        assert!(!contract.is_path_excluded(Path::new("vendor/lib/a.go")));
+        assert!(contract.is_path_excluded(Path::new("api/users.PB.go")));
+        assert!(!contract.is_path_excluded(Path::new("api/users.pb.go")));
+    }
+
+    #[test]
+    fn test_validate_schemas() {
+        let schemas = |yaml: &str| -> anyhow::Result<Contract> {
+            let contract: Contract = serde_yaml::from_str(&format!("schemas:\n{}", yaml)).unwrap();
+            validate(&contract).map(|_| contract)
+        };
+
+        let config = schemas("  placeholder_fields: [reserved]\n").unwrap().schemas.unwrap();
+        assert!(config.is_enabled());
+        assert_eq!(config.allow_empty(), DEFAULT_SCHEMA_ALLOW_EMPTY.to_vec());
+        assert_eq!(config.todo_severity(), "warning");
+        let config = schemas("  allow_empty: ['^Marker$']\n").unwrap().schemas.unwrap();
+        assert_eq!(config.allow_empty(), vec!["^Marker$"]);
+
+        let err = schemas("  allow_empty: ['(']\n").unwrap_err();
+        assert!(err.to_string().starts_with("invalid schemas allow_empty pattern \"(\""));
+        let err = schemas("  todo_severity: loud\n").unwrap_err();
+        assert!(err.to_string().starts_with("invalid schemas todo_severity"));
+    }
+
+    #[test]
+    fn test_validate_placeholder_constants() {
+        let constants = |yaml: &str| -> anyhow::Result<Contract> {
+            let contract: Contract = serde_yaml::from_str(&format!("placeholder_constants:\n{}", yaml)).unwrap();
+            validate(&contract).map(|_| contract)
+        };
+
+        let config = constants("  enabled: true\n").unwrap().placeholder_constants.unwrap();
+        assert_eq!(config.severity(), "info");
+        assert_eq!(config.min_members(), 3);
+
+        let err = constants("  min_members: 1\n").unwrap_err();
+        assert_eq!(err.to_string(), "invalid placeholder_constants min_members 1, must be at least 2");
+        let err = constants("  severity: loud\n").unwrap_err();
+        assert!(err.to_string().starts_with("invalid placeholder_constants severity"));
+    }
+
+    #[test]
+    fn test_validate_sub_scores() {
+        let sub_scores = |yaml: &str| -> anyhow::Result<Contract> {
+            let contract: Contract = serde_yaml::from_str(&format!("sub_scores:\n{}", yaml)).unwrap();
+            validate(&contract).map(|_| contract)
+        };
+
+        let contract = sub_scores("  content_threshold: 60\n  categories:\n    config-files: structural\n").unwrap();
+        let config = contract.sub_scores.unwrap();
+        use crate::detect::{ScoreCategory, ViolationRule};
+        assert_eq!(config.category(ViolationRule::HollowConfig), ScoreCategory::Structural);
+        assert_eq!(config.category(ViolationRule::MockData), ScoreCategory::Content);
+        assert_eq!(config.category(ViolationRule::StubFunction), ScoreCategory::Structural);
+
+        let err = sub_scores("  structural_threshold: 101\n").unwrap_err();
+        assert_eq!(err.to_string(), "invalid sub_scores structural_threshold 101, must be between 0 and 100");
+        let err = sub_scores("  categories:\n    stubs: content\n").unwrap_err();
+        assert!(err.to_string().contains("unknown rule: stubs"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_waivers() {
+        let waiver = |fields: &str| -> anyhow::Result<()> {
+            let contract: Contract = serde_yaml::from_str(&format!("waivers:\n  - {}\n", fields)).unwrap();
+            validate(&contract)
+        };
+        let complete = "owner: \"@data-team\"\n    justification: phase 2\n    expires: 2026-12-31";
+
+        assert!(waiver(&format!("rule: stub_function\n    path: \"src/export/**\"\n    {}", complete)).is_ok());
+        assert!(waiver(&format!("rule: stub_function\n    fingerprint: \"0a1b2c3d4e5f6a7b\"\n    {}", complete)).is_ok());
+
+        let err = |fields: String| waiver(&fields).unwrap_err().to_string();
+        assert!(err(format!("rule: stubs\n    path: src\n    {}", complete)).contains("rule"));
+        assert!(err(format!("rule: stub_function\n    {}", complete)).contains("exactly one of path or fingerprint"));
+        assert!(err("rule: stub_function\n    path: src\n    justification: x\n    expires: 2026-12-31".to_string())
+            .contains("owner is required"));
+        assert!(err("rule: stub_function\n    path: src\n    owner: me\n    justification: x\n    expires: soon".to_string())
+            .contains("YYYY-MM-DD"));
+    }
+
+    #[test]
+    fn test_validate_scopes() {
+        let scopes = |yaml: &str| -> anyhow::Result<()> {
+            let contract: Contract = serde_yaml::from_str(&format!("scopes:\n{}", yaml))?;
+            validate(&contract)
+        };
+
+        assert!(scopes("  - paths: [\"examples/**\"]\n    rules: {low_complexity: off, mock_data: warn}\n").is_ok());
+        let err = |yaml: &str| scopes(yaml).unwrap_err().to_string();
+        assert!(err("  - paths: [\"src/**\"]\n    rules: {stubs: error}\n").contains("invalid scopes[0] rule"));
+        assert!(err("  - paths: [\"src/[\"]\n    rules: {mock_data: off}\n").contains("invalid scopes[0] path"));
+        assert!(err("  - paths: []\n    rules: {mock_data: off}\n").contains("paths is empty"));
+        assert!(err("  - paths: [\"src/**\"]\n    rules: {mock_data: loud}\n").contains("unknown variant"));
+    }
+
+    #[test]
+    fn test_validate_pattern_safeguards() {
+        let yaml = r#"
+forbidden_patterns:
+  - pattern: "TODO"
+  - pattern: "(a+)+b"
+mock_signatures:
+  patterns:
+    - pattern: "lorem{1,5000}"
+"#;
+        let contract: Contract = serde_yaml::from_str(yaml).unwrap();
+        assert!(validate(&contract).is_ok());
+        assert_eq!(
+            validation_warnings(&contract),
+            vec![
+                "forbidden_patterns pattern \"(a+)+b\" nests a quantifier inside a repeated group and may match slowly on large files",
+                "mock_signatures pattern \"lorem{1,5000}\" repeats up to 5000 times and may match slowly on large files",
+            ]
+        );
+
+        let huge: Contract = serde_yaml::from_str("forbidden_patterns:\n  - pattern: '\\w{1000}'\n").unwrap();
+        let err = validate(&huge).unwrap_err().to_string();
+        assert!(err.contains("invalid forbidden pattern") && err.contains("size limit"), "{}", err);
+    }
+
     #[test]
     fn test_mock_signatures_defaults() {
         let cfg = MockSignaturesConfig::default();
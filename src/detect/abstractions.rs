@@ -0,0 +1,326 @@
+//! Detection of abstractions with a single concrete type behind them.
+//!
+//! Generated code often arrives "enterprise-shaped": a `PaymentGateway`
+//! interface with one `StripePaymentGateway` behind it, a `NewGateway`
+//! factory that can only construct that one type, and generic functions
+//! whose type parameter appears once, so nothing ties two positions
+//! together. Each layer is indirection a reader pays for without a second
+//! implementation to justify it. Implementations come from the index shared
+//! with `unimplemented_interface`, so they are matched the same way: by
+//! simple name within one language, structurally for Go.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::analysis::{AnalysisContext, FileFacts};
+use crate::contract::PrematureAbstractionConfig;
+
+use super::interfaces::{facts_by_language, IndexedInterface, InterfaceIndex};
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Factory function names: `NewStore`, `CreateStore`, `new`, `create_store`.
+static FACTORY_NAME: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^(New|Create|new|create)([A-Z_]|$)").unwrap());
+
+/// A factory: file, line, and name.
+type FactorySite<'a> = (&'a str, usize, &'a str);
+
+/// Quote and join names for a message: `'A', 'B'`.
+fn quoted(names: &[&str]) -> String {
+    names
+        .iter()
+        .map(|n| format!("'{}'", n))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Type parameters used in a single position, one violation per callable.
+fn check_type_parameters(path: &str, facts: &FileFacts) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for callable in &facts.callable_types {
+        let unused: Vec<&str> = callable
+            .type_parameters
+            .iter()
+            .filter(|p| p.references <= 1 && !p.in_return_type)
+            .map(|p| p.name.as_str())
+            .collect();
+        if unused.is_empty() {
+            continue;
+        }
+        let (noun, verb) = if unused.len() == 1 {
+            ("type parameter", "is")
+        } else {
+            ("type parameters", "are each")
+        };
+        violations.push(Violation {
+            rule: ViolationRule::PrematureAbstraction,
+            message: format!(
+                "'{}' is generic in name only: {} {} {} used in a single position; a concrete type would do",
+                callable.name,
+                noun,
+                unused.join(", "),
+                verb
+            ),
+            file: Some(path.to_string()),
+            line: callable.span.start_line,
+            severity: Severity::Info,
+        });
+    }
+    violations
+}
+
+/// Check the abstractions of one language.
+fn check_language(
+    files: &[(String, FileFacts)],
+    config: &PrematureAbstractionConfig,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    let index = InterfaceIndex::build(files);
+    let single: Vec<&IndexedInterface> = index
+        .interfaces
+        .iter()
+        .filter(|iface| !iface.methods.is_empty() && iface.implementers.len() == 1)
+        .collect();
+
+    if config.checks_single_implementation() {
+        for iface in &single {
+            let decl = iface.decl;
+            let implementer = iface.implementers.first().copied().unwrap_or_default();
+            violations.push(Violation {
+                rule: ViolationRule::PrematureAbstraction,
+                message: format!(
+                    "{} '{}' has a single implementation, '{}'; use the concrete type until a second one exists",
+                    decl.kind, decl.name, implementer
+                ),
+                file: Some(iface.path.to_string()),
+                line: decl.span.start_line,
+                severity: Severity::Info,
+            });
+        }
+    }
+
+    if config.checks_unused_type_parameters() {
+        for (path, facts) in files {
+            violations.extend(check_type_parameters(path, facts));
+        }
+    }
+
+    if config.checks_single_type_factories() && !single.is_empty() {
+        let by_name: HashMap<&str, &IndexedInterface> =
+            single.iter().map(|iface| (iface.decl.name.as_str(), *iface)).collect();
+
+        // Factories per interface, in order of the first factory
+        let mut factories: Vec<(&IndexedInterface, Vec<FactorySite>)> = Vec::new();
+        for (path, facts) in files {
+            for callable in &facts.callable_types {
+                if !FACTORY_NAME.is_match(&callable.name) {
+                    continue;
+                }
+                let Some(iface) = callable.returns.iter().find_map(|r| by_name.get(r.as_str())) else {
+                    continue;
+                };
+                let site = (path.as_str(), callable.span.start_line, callable.name.as_str());
+                match factories.iter_mut().find(|(i, _)| std::ptr::eq(*i, *iface)) {
+                    Some((_, sites)) => sites.push(site),
+                    None => factories.push((iface, vec![site])),
+                }
+            }
+        }
+
+        for (iface, sites) in factories {
+            let names: Vec<&str> = sites.iter().map(|(_, _, name)| *name).collect();
+            let (path, line, _) = sites[0];
+            let implementer = iface.implementers.first().copied().unwrap_or_default();
+            let (noun, verb) = if names.len() == 1 {
+                ("factory", "returns")
+            } else {
+                ("factories", "return")
+            };
+            violations.push(Violation {
+                rule: ViolationRule::PrematureAbstraction,
+                message: format!(
+                    "{} {} {} {} '{}', which only '{}' implements; return the concrete type",
+                    noun,
+                    quoted(&names),
+                    verb,
+                    iface.decl.kind,
+                    iface.decl.name,
+                    implementer
+                ),
+                file: Some(path.to_string()),
+                line,
+                severity: Severity::Info,
+            });
+        }
+    }
+
+    violations
+}
+
+/// Find interfaces with exactly one implementing type, factories that only
+/// construct that type, and generic functions whose type parameters are
+/// used in a single position.
+///
+/// Every finding is reported once per abstraction: at the interface, at the
+/// first factory of an interface, and at the generic function.
+pub fn detect_premature_abstraction<P: AsRef<Path>>(
+    analysis_ctx: &AnalysisContext,
+    files: &[P],
+    config: Option<&PrematureAbstractionConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    let config = match config {
+        Some(c) if c.is_enabled() => c,
+        _ => return Ok(result),
+    };
+
+    let by_language = facts_by_language(analysis_ctx, files, &mut result);
+    for files in by_language.values() {
+        for v in check_language(files, config) {
+            result.add_violation(v);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn detect(sources: &[(&str, &str)], config: &PrematureAbstractionConfig) -> Vec<Violation> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let mut files = Vec::new();
+        for (name, source) in sources {
+            let file = temp.path().join(name);
+            std::fs::write(&file, source).unwrap();
+            files.push(file);
+        }
+
+        let ctx = AnalysisContext::new(temp.path());
+        detect_premature_abstraction(&ctx, &files, Some(config))
+            .unwrap()
+            .violations
+    }
+
+    fn enabled() -> PrematureAbstractionConfig {
+        PrematureAbstractionConfig {
+            enabled: true,
+            ..PrematureAbstractionConfig::default()
+        }
+    }
+
+    const GO_GATEWAY: &str = r#"package payments
+
+type Gateway interface {
+	Charge(amount int) error
+	Refund(id string) error
+}
+
+type StripeGateway struct{}
+
+func (g *StripeGateway) Charge(amount int) error { return nil }
+func (g *StripeGateway) Refund(id string) error { return nil }
+
+func NewGateway() Gateway { return &StripeGateway{} }
+
+func NewDefaultGateway() (Gateway, error) { return &StripeGateway{}, nil }
+
+type Notifier interface {
+	Notify(msg string) error
+}
+
+type EmailNotifier struct{}
+type SmsNotifier struct{}
+
+func (n EmailNotifier) Notify(msg string) error { return nil }
+func (n SmsNotifier) Notify(msg string) error { return nil }
+
+func NewNotifier() Notifier { return EmailNotifier{} }
+
+func Keys[K comparable, V any](m map[K]V) []K { return nil }
+
+func Print[T any](value T) { println(value) }
+"#;
+
+    #[test]
+    fn test_go_single_implementation_and_factories() {
+        let violations = detect(&[("payments.go", GO_GATEWAY)], &enabled());
+        let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "interface 'Gateway' has a single implementation, 'StripeGateway'; use the concrete type until a second one exists",
+                "'Keys' is generic in name only: type parameter V is used in a single position; a concrete type would do",
+                "'Print' is generic in name only: type parameter T is used in a single position; a concrete type would do",
+                "factories 'NewGateway', 'NewDefaultGateway' return interface 'Gateway', which only 'StripeGateway' implements; return the concrete type",
+            ]
+        );
+        assert!(violations.iter().all(|v| v.severity == Severity::Info));
+        assert_eq!(violations[0].line, 3);
+        // Consolidated at the first factory
+        assert_eq!(violations[3].line, 13);
+    }
+
+    #[test]
+    fn test_nominal_implementations_and_toggles() {
+        let sources = [
+            (
+                "store.rs",
+                r#"
+pub trait Store {
+    fn get(&self, key: &str) -> Option<String>;
+}
+
+pub struct MemoryStore;
+
+impl Store for MemoryStore {
+    fn get(&self, key: &str) -> Option<String> { None }
+}
+
+pub fn create_store() -> Box<dyn Store> { Box::new(MemoryStore) }
+
+pub fn largest<T: PartialOrd>(a: T, b: T) -> T { if a > b { a } else { b } }
+
+pub fn show<T: std::fmt::Display, U: Into<String>>(value: T, label: U) { println!("{}", value); }
+"#,
+            ),
+            (
+                "repo.ts",
+                r#"
+interface Repository { find(id: string): Item; }
+class SqlRepository implements Repository { find(id: string) { return db.get(id); } }
+class CachedRepository implements Repository { find(id: string) { return cache.get(id); } }
+"#,
+            ),
+        ];
+
+        let violations = detect(&sources, &enabled());
+        assert_eq!(violations.len(), 3);
+        assert!(violations[0].message.starts_with("trait 'Store' has a single implementation, 'MemoryStore'"));
+        assert!(violations[1]
+            .message
+            .starts_with("'show' is generic in name only: type parameters T, U are each used"));
+        assert!(violations[2]
+            .message
+            .starts_with("factory 'create_store' returns trait 'Store', which only 'MemoryStore' implements"));
+
+        let config = PrematureAbstractionConfig {
+            enabled: true,
+            single_implementation: Some(false),
+            unused_type_parameters: Some(false),
+            single_type_factories: None,
+        };
+        let violations = detect(&sources, &config);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.starts_with("factory 'create_store'"));
+    }
+}
@@ -0,0 +1,319 @@
+//! Detection of async functions that never await anything.
+//!
+//! `async def fetch_orders(...)` with no `await` in its body is synchronous
+//! code wearing an async signature, usually because the I/O it stands for was
+//! never written. Await points per body come from `FunctionBody::await_count`
+//! (Python, JavaScript, TypeScript, Rust).
+//!
+//! Not flagged:
+//! - bodies that stub detection already reports (empty, panic-only,
+//!   nil-return-only, TODO-only), so one hollow function is reported once
+//! - abstract and overload stubs (`@abstractmethod`, `@overload`) and methods
+//!   declared by an interface, ABC, or trait
+//! - methods that must be async for interface compatibility: those
+//!   implementing a project interface member of the same name, and every
+//!   method of a Rust trait impl, whose signature the trait fixes
+//! - functions whose name matches the `passthrough` list
+//! - Rust bodies that return an `async` block, which the caller awaits
+
+use std::path::Path;
+
+use crate::analysis::{Declaration, DeclarationKind, FileFacts, FunctionBody};
+use crate::contract::AsyncWithoutAwaitConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Decorators marking a declaration-only Python method.
+const STUB_DECORATORS: &[&str] = &["abstractmethod", "overload"];
+
+/// Whether stub detection reports the body, making this rule redundant.
+fn is_stub_body(body: &FunctionBody) -> bool {
+    body.is_empty || body.is_panic_only || body.is_nil_return_only || body.has_only_todo_comment
+}
+
+fn is_stub_declaration(decl: &Declaration) -> bool {
+    decl.annotations.iter().any(|a| {
+        let name = a.rsplit('.').next().unwrap_or(a);
+        STUB_DECORATORS.contains(&name)
+    })
+}
+
+fn contains(outer: &Declaration, inner: &Declaration) -> bool {
+    outer.span.start_byte <= inner.span.start_byte && inner.span.end_byte <= outer.span.end_byte
+}
+
+/// Whether a project interface or trait named `interface` declares `member`.
+fn interface_declares(facts: &FactsBundle, interface: &str, member: &str) -> bool {
+    facts.symbols_named(interface).iter().any(|loc| {
+        matches!(loc.kind, DeclarationKind::Interface | DeclarationKind::Trait)
+            && matches!(facts.file(&loc.file), Some(Ok(f)) if f.declarations.iter().any(|d| {
+                d.name == interface && d.members.iter().any(|m| m == member)
+            }))
+    })
+}
+
+/// Whether the callable only exists to match a signature fixed elsewhere.
+fn is_interface_member(facts: &FactsBundle, file_facts: &FileFacts, decl: &Declaration) -> bool {
+    let declared_by_interface = file_facts
+        .declarations
+        .iter()
+        .filter(|d| matches!(d.kind, DeclarationKind::Interface | DeclarationKind::Trait))
+        .any(|d| contains(d, decl));
+    if declared_by_interface {
+        return true;
+    }
+
+    file_facts
+        .implementations
+        .iter()
+        .filter(|i| i.span.start_byte <= decl.span.start_byte && decl.span.end_byte <= i.span.end_byte)
+        .any(|i| file_facts.language == "rust" || interface_declares(facts, &i.interface, &decl.name))
+}
+
+/// Flag async callables whose body never awaits.
+pub fn detect_async_without_await<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &AsyncWithoutAwaitConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let passthrough = build_globs("async_without_await passthrough", &config.passthrough())?;
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if !config.checks_language(&file_facts.language) {
+            continue;
+        }
+        result.scanned += 1;
+
+        for decl in file_facts.callables().filter(|d| d.is_async) {
+            let Some(body) = &decl.body else {
+                continue;
+            };
+            if body.await_count > 0
+                || is_stub_body(body)
+                || is_stub_declaration(decl)
+                || passthrough.is_match(&decl.name)
+                || passthrough.is_match(decl.qualified_name())
+                || is_interface_member(facts, file_facts, decl)
+            {
+                continue;
+            }
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::AsyncWithoutAwait,
+                    format!(
+                        "{} is async but never awaits; make it synchronous or write the awaited call it stands for",
+                        decl.qualified_name()
+                    ),
+                )
+                .at(path.to_string_lossy(), decl.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &AsyncWithoutAwaitConfig) -> Vec<(String, usize, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::AsyncWithoutAwait]);
+        detect_async_without_await(&facts, &paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                let file = v.file.as_deref().unwrap();
+                let name = Path::new(file).file_name().unwrap().to_string_lossy().to_string();
+                let function = v.message.split(' ').next().unwrap().to_string();
+                (name, v.line, function)
+            })
+            .collect()
+    }
+
+    const PYTHON: &str = r#"from abc import ABC, abstractmethod
+from typing import overload
+
+class OrderSource(ABC):
+    @abstractmethod
+    async def fetch_orders(self, customer_id):
+        return []
+
+class ApiOrderSource(OrderSource):
+    async def fetch_orders(self, customer_id):
+        orders = [o for o in CACHE if o.customer == customer_id]
+        return sorted(orders)
+
+    async def refresh(self):
+        rows = await self.client.get("/orders")
+        async with self.lock:
+            self.rows = rows
+
+    async def __aenter__(self):
+        return self
+
+async def load_report(path):
+    data = {"path": path}
+    return data
+
+async def stream_rows(cursor):
+    async for row in cursor:
+        print(row)
+
+async def not_done_yet():
+    pass
+
+@overload
+async def lookup(key: int) -> int: ...
+
+async def schedule(job):
+    def run():
+        return job()
+    job.queued = True
+    return run
+"#;
+
+    #[test]
+    fn test_python() {
+        let found = detect(&[("orders.py", PYTHON)], &AsyncWithoutAwaitConfig::default());
+        assert_eq!(
+            found,
+            vec![
+                ("orders.py".to_string(), 22, "load_report".to_string()),
+                ("orders.py".to_string(), 36, "schedule".to_string()),
+            ]
+        );
+    }
+
+    const TYPESCRIPT: &str = r#"interface Repository {
+  save(order: Order): Promise<void>;
+}
+
+abstract class Base {
+  abstract load(): Promise<Order[]>;
+}
+
+export class MemoryRepository implements Repository {
+  async save(order: Order): Promise<void> {
+    this.orders.push(order);
+  }
+
+  async count(): Promise<number> {
+    return this.orders.length;
+  }
+}
+
+export async function fetchOrders(customerId: string): Promise<Order[]> {
+  const orders = ORDERS.filter((o) => o.customer === customerId);
+  return orders;
+}
+
+export async function syncOrders(source: Source): Promise<void> {
+  for await (const order of source.stream()) {
+    upsert(order);
+  }
+  const later = async () => {
+    await flush();
+  };
+}
+"#;
+
+    #[test]
+    fn test_typescript() {
+        let found = detect(&[("orders.ts", TYPESCRIPT)], &AsyncWithoutAwaitConfig::default());
+        let functions: Vec<_> = found.iter().map(|(_, line, f)| (*line, f.as_str())).collect();
+        assert_eq!(functions, vec![(14, "count"), (19, "fetchOrders")]);
+    }
+
+    const JAVASCRIPT: &str = r#"async function getVersion() {
+  return "1.0.0";
+}
+
+async function loadConfig(path) {
+  const text = await readFile(path, "utf8");
+  return JSON.parse(text);
+}
+
+async function save(record) {
+  throw new Error("not implemented");
+}
+"#;
+
+    #[test]
+    fn test_javascript_and_passthrough() {
+        let found = detect(&[("config.js", JAVASCRIPT)], &AsyncWithoutAwaitConfig::default());
+        assert_eq!(found, vec![("config.js".to_string(), 1, "getVersion".to_string())]);
+
+        let config = AsyncWithoutAwaitConfig {
+            passthrough: Some(vec!["get*".to_string()]),
+            ..Default::default()
+        };
+        assert!(detect(&[("config.js", JAVASCRIPT)], &config).is_empty());
+    }
+
+    const RUST: &str = r#"use std::future::Future;
+
+pub trait Store {
+    async fn get(&self, key: &str) -> Option<String>;
+}
+
+impl Store for MemoryStore {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.map.get(key).cloned()
+    }
+}
+
+impl MemoryStore {
+    pub async fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub async fn fetch(&self, url: &str) -> String {
+        let body = self.client.get(url).send().await;
+        body.text()
+    }
+
+    pub async fn deferred(&self) -> impl Future<Output = usize> {
+        let map = self.map.clone();
+        async move { map.len() }
+    }
+}
+"#;
+
+    #[test]
+    fn test_rust_and_language_toggles() {
+        let found = detect(&[("store.rs", RUST)], &AsyncWithoutAwaitConfig::default());
+        assert_eq!(found, vec![("store.rs".to_string(), 14, "MemoryStore.len".to_string())]);
+
+        let config = AsyncWithoutAwaitConfig {
+            rust: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(detect(&[("store.rs", RUST), ("config.js", JAVASCRIPT)], &config).len(), 1);
+    }
+}
@@ -0,0 +1,162 @@
+//! Per-file analysis budget for very large files.
+//!
+//! One 30k-line generated file can hold a worker for the whole run while it
+//! is parsed and every rule walks it, and a pathological input can make
+//! tree-sitter's memory use balloon. Files with at least `large_file_lines`
+//! lines are parsed once, before any rule runs, under a wall-clock budget.
+//! Files that finish in time are cached for the AST-backed rules; files that
+//! run out of time are reported as `analysis_timeout` and skipped by every
+//! rule.
+
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext};
+use crate::contract::AnalysisBudgetConfig;
+
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Count the lines of a file if it has at least `min_lines`.
+fn large_file_lines(path: &Path, min_lines: usize) -> Option<usize> {
+    // A file with fewer bytes than the threshold cannot have that many lines
+    let size = std::fs::metadata(path).ok()?.len();
+    if size < min_lines as u64 {
+        return None;
+    }
+    let source = std::fs::read(path).ok()?;
+    let newlines = source.iter().filter(|&&b| b == b'\n').count();
+    let lines = newlines + usize::from(!source.is_empty() && !source.ends_with(b"\n"));
+    (lines >= min_lines).then_some(lines)
+}
+
+/// Parse large files under the budget and split off the ones that exceed it.
+///
+/// Returns the files every rule should still check, and a result with an
+/// `analysis_timeout` violation for each file that was dropped. Files
+/// without an analyzer are kept: the text-based rules scan them linearly.
+pub fn enforce_analysis_budget(
+    analysis_ctx: &AnalysisContext,
+    files: &[PathBuf],
+    config: &AnalysisBudgetConfig,
+) -> (Vec<PathBuf>, DetectionResult) {
+    let mut result = DetectionResult::new();
+    if !config.is_enabled() {
+        return (files.to_vec(), result);
+    }
+
+    let min_lines = config.large_file_lines();
+    let budget = config.timeout();
+    let debug = std::env::var("HOLLOWCHECK_DEBUG").is_ok();
+
+    let timed_out: Vec<Option<usize>> = files
+        .par_iter()
+        .map(|file| {
+            let ext = analysis_extension(file);
+            get_analyzer(ext)?;
+            let lines = large_file_lines(file, min_lines)?;
+
+            let start = Instant::now();
+            let outcome = analysis_ctx.analyze_file_within(file, budget);
+            if debug {
+                eprintln!(
+                    "[debug] Large file {} ({} lines) analyzed in {:.1}s{}",
+                    file.display(),
+                    lines,
+                    start.elapsed().as_secs_f32(),
+                    if matches!(outcome, Ok(None)) { ", over budget" } else { "" }
+                );
+            }
+            matches!(outcome, Ok(None)).then_some(lines)
+        })
+        .collect();
+
+    let mut kept = Vec::with_capacity(files.len());
+    for (file, timed_out) in files.iter().zip(timed_out) {
+        match timed_out {
+            Some(lines) => result.add_violation(Violation {
+                rule: ViolationRule::AnalysisTimeout,
+                message: format!(
+                    "file has {} lines and could not be analyzed within {}ms; skipped by all rules",
+                    lines,
+                    budget.as_millis()
+                ),
+                file: Some(file.to_string_lossy().to_string()),
+                line: 1,
+                severity: Severity::Warning,
+            }),
+            None => kept.push(file.clone()),
+        }
+    }
+
+    (kept, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_files(temp: &TempDir) -> Vec<PathBuf> {
+        let large = temp.path().join("generated.go");
+        let mut source = String::from("package generated\n\n");
+        for i in 0..1200 {
+            source.push_str(&format!("func F{}(a int) int {{ if a > {} {{ return a }}; return {} }}\n", i, i, i));
+        }
+        std::fs::write(&large, source).unwrap();
+
+        let small = temp.path().join("main.go");
+        std::fs::write(&small, "package main\n\nfunc main() {}\n").unwrap();
+
+        vec![large, small]
+    }
+
+    #[test]
+    fn test_large_file_over_budget_is_skipped() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let files = write_files(&temp);
+        let ctx = AnalysisContext::new(temp.path());
+        let config = AnalysisBudgetConfig {
+            enabled: true,
+            large_file_lines: Some(1000),
+            timeout_ms: Some(0),
+            max_body_nodes: None,
+            pattern_timeout_ms: None,
+        };
+
+        let (kept, result) = enforce_analysis_budget(&ctx, &files, &config);
+        assert_eq!(kept, vec![files[1].clone()]);
+        assert_eq!(result.violations.len(), 1);
+        let v = &result.violations[0];
+        assert_eq!(v.rule, ViolationRule::AnalysisTimeout);
+        assert!(v.message.starts_with("file has 1202 lines"));
+        // Nothing is cached for the skipped file
+        assert!(ctx.facts_for_file(&files[0]).is_none());
+    }
+
+    #[test]
+    fn test_large_file_within_budget_is_cached() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let files = write_files(&temp);
+        let ctx = AnalysisContext::new(temp.path());
+        let config = AnalysisBudgetConfig {
+            enabled: true,
+            large_file_lines: Some(1000),
+            timeout_ms: Some(60_000),
+            max_body_nodes: None,
+            pattern_timeout_ms: None,
+        };
+
+        let (kept, result) = enforce_analysis_budget(&ctx, &files, &config);
+        assert_eq!(kept, files);
+        assert!(result.violations.is_empty());
+        assert!(ctx.facts_for_file(&files[0]).is_some());
+        // Small files are left to the rules
+        assert!(ctx.facts_for_file(&files[1]).is_none());
+    }
+}
@@ -0,0 +1,162 @@
+//! Detection of Python names that shadow builtins.
+//!
+//! A module that defines `def list(...)`, `class type`, or `id = 0` hides
+//! the builtin for everything below it: the code runs until some later
+//! line calls `list(x)` expecting the builtin and gets the local name.
+//! Generated code falls into this often, naming helpers after what they
+//! return. Methods and attributes are not checked, since `obj.id` never
+//! hides `id`.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::analysis::{Declaration, DeclarationKind};
+use crate::contract::BuiltinShadowingConfig;
+
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
+
+/// Label for a shadowing declaration, or None if it cannot shadow a builtin.
+fn shadowing_kind(decl: &Declaration, classes: &[&Declaration]) -> Option<&'static str> {
+    match decl.kind {
+        DeclarationKind::Function => {
+            // Methods live in the class namespace
+            let is_method = classes.iter().any(|c| {
+                c.span.start_byte < decl.span.start_byte && decl.span.end_byte <= c.span.end_byte
+            });
+            (!is_method).then_some("function")
+        }
+        DeclarationKind::Type | DeclarationKind::Interface => Some("class"),
+        DeclarationKind::Const => Some("variable"),
+        _ => None,
+    }
+}
+
+/// Flag Python functions, classes, and module-level variables named after a builtin.
+///
+/// Names in the allowlist are exempt; the builtin list itself can be
+/// replaced in the contract.
+pub fn detect_builtin_shadowing<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &BuiltinShadowingConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let builtins: HashSet<&str> = config
+        .builtins()
+        .into_iter()
+        .filter(|b| !config.allowlist.iter().any(|a| a == b))
+        .collect();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if file_facts.language != "python" {
+            continue;
+        }
+        result.scanned += 1;
+
+        let classes: Vec<&Declaration> = file_facts
+            .declarations
+            .iter()
+            .filter(|d| matches!(d.kind, DeclarationKind::Type | DeclarationKind::Interface))
+            .collect();
+
+        for decl in &file_facts.declarations {
+            if !builtins.contains(decl.name.as_str()) {
+                continue;
+            }
+            let Some(kind) = shadowing_kind(decl, &classes) else {
+                continue;
+            };
+
+            result.add_violation(Violation {
+                rule: ViolationRule::BuiltinShadowing,
+                message: format!(
+                    "{} '{}' shadows the Python builtin {}(); later calls in this module get the local name",
+                    kind, decl.name, decl.name
+                ),
+                file: Some(path.to_string_lossy().to_string()),
+                line: decl.span.start_line,
+                severity: Severity::Warning,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str, config: &BuiltinShadowingConfig) -> Vec<Violation> {
+        test_support::violations(name, source, ViolationRule::BuiltinShadowing, |facts, files| {
+            detect_builtin_shadowing(facts, files, config)
+        })
+    }
+
+    const SOURCE: &str = r#"
+id = 0
+input, output = read_args()
+
+def list(items):
+    return [i for i in items]
+
+class type:
+    pass
+
+class User:
+    def id(self):
+        return self._id
+
+    def format(self, spec):
+        return str(self)
+
+def load(path):
+    def open(p):
+        return p
+    return open(path)
+"#;
+
+    #[test]
+    fn test_detect_builtin_shadowing() {
+        let violations = detect("helpers.py", SOURCE, &BuiltinShadowingConfig::default());
+
+        let found: Vec<_> = violations.iter().map(|v| (v.line, v.message.as_str())).collect();
+        assert_eq!(found.len(), 5, "{:?}", found);
+        assert!(violations.iter().all(|v| v.rule == ViolationRule::BuiltinShadowing));
+        assert!(found[0].1.starts_with("variable 'id' shadows the Python builtin id()"));
+        assert_eq!((found[1].0, found[2].0), (3, 5));
+        assert!(found[1].1.starts_with("variable 'input'"));
+        assert!(found[2].1.starts_with("function 'list'"));
+        assert!(found[3].1.starts_with("class 'type'"));
+        // Nested functions shadow within their scope; methods do not
+        assert_eq!(found[4].0, 19);
+        assert!(found[4].1.starts_with("function 'open'"));
+
+        // Other languages are not checked
+        let go = "package h\n\nfunc len() int { return 0 }\n";
+        assert!(detect("helpers.go", go, &BuiltinShadowingConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_custom_builtins_and_allowlist() {
+        let config = BuiltinShadowingConfig {
+            builtins: Some(vec!["list".to_string(), "load".to_string(), "type".to_string()]),
+            allowlist: vec!["type".to_string()],
+            ..BuiltinShadowingConfig::default()
+        };
+        let violations = detect("helpers.py", SOURCE, &config);
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'list'"));
+        assert!(violations[1].message.contains("'load'"));
+    }
+}
@@ -0,0 +1,265 @@
+// hollowcheck:ignore-file mock_data - Test fixtures contain placeholder values
+//! Detection of placeholder content in CI pipeline files.
+//!
+//! Generated pipelines have the same gaps as generated Dockerfiles: a job
+//! container `your-registry/app:latest`, a step that runs `echo "TODO"`, a
+//! `# TODO: add deploy job` left in place. GitHub Actions workflows
+//! (`.github/workflows/*.yml`) and GitLab CI (`.gitlab-ci.yml`) live where
+//! file discovery never looks, so they are read from the project root. The
+//! check is line-based, so a pipeline that no longer parses is still checked.
+//! Images and TODO markers are judged as in the container checks.
+
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::contract::{CiConfigChecksConfig, Contract};
+
+use super::containers::{
+    is_noop_command, is_unpinned, split_comment, InfraSeverities, PLACEHOLDER_IMAGE, TODO_MARKER,
+};
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// A `key: value` line, optionally a list item.
+static KEY_VALUE: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*(?:-\s+)?([\w.-]+)\s*:\s*(.*)$").unwrap());
+
+/// Values that were never filled in (`CHANGEME`, `<runner>`, `your-registry-here`).
+static PLACEHOLDER_VALUE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(changeme|change[-_]?me|replace[-_]?me|placeholder|your[-_][\w.-]+|<[^>]+>)$").unwrap()
+});
+
+/// Keys whose value is a container image.
+const IMAGE_KEYS: &[&str] = &["image", "container"];
+
+/// Keys whose value is a shell command.
+const COMMAND_KEYS: &[&str] = &["run", "script", "before_script", "after_script"];
+
+/// Pipeline files under a project root, in a stable order.
+fn pipeline_files(base: &Path) -> Vec<std::path::PathBuf> {
+    let mut files = Vec::new();
+    let gitlab = base.join(".gitlab-ci.yml");
+    if gitlab.is_file() {
+        files.push(gitlab);
+    }
+    if let Ok(entries) = std::fs::read_dir(base.join(".github").join("workflows")) {
+        let mut workflows: Vec<_> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file() && p.extension().is_some_and(|e| e == "yml" || e == "yaml"))
+            .collect();
+        workflows.sort();
+        files.extend(workflows);
+    }
+    files
+}
+
+/// A value without its surrounding quotes, if it is quoted as a whole.
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    ['"', '\'']
+        .iter()
+        .find_map(|q| value.strip_prefix(*q)?.strip_suffix(*q))
+        .unwrap_or(value)
+}
+
+/// Shell words of a command, without quotes.
+fn command_words(command: &str) -> Vec<String> {
+    command
+        .split_whitespace()
+        .map(|w| unquote(w).to_string())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+fn hollow(file: &str, line: usize, severity: Severity, message: String) -> Violation {
+    Violation::new(ViolationRule::HollowCiConfig, message)
+        .at(file, line)
+        .with_severity(severity)
+}
+
+/// Check one pipeline file.
+fn check_pipeline(file: &str, source: &str, severities: InfraSeverities) -> Vec<Violation> {
+    let mut violations = Vec::new();
+
+    for (idx, raw) in source.lines().enumerate() {
+        let line = idx + 1;
+        let (code, comment) = split_comment(raw);
+
+        // A list item or block scalar line with no key is a command
+        let (key, value) = match KEY_VALUE.captures(code) {
+            Some(caps) => (Some(caps[1].to_string()), unquote(&caps[2]).to_string()),
+            None => (None, code.trim().trim_start_matches("- ").to_string()),
+        };
+        let is_command = key.as_deref().is_none_or(|k| COMMAND_KEYS.contains(&k));
+
+        if TODO_MARKER.is_match(code) && is_command && is_noop_command(&command_words(&value)) {
+            violations.push(hollow(
+                file,
+                line,
+                severities.todo,
+                format!("step only runs '{}'; the job was never written", value),
+            ));
+            continue;
+        }
+        if TODO_MARKER.is_match(raw) {
+            let text = comment.filter(|c| TODO_MARKER.is_match(c)).unwrap_or(code);
+            violations.push(hollow(
+                file,
+                line,
+                severities.todo,
+                format!("TODO marker left in the pipeline: '{}'", text.trim()),
+            ));
+            continue;
+        }
+
+        let Some(key) = key else {
+            continue;
+        };
+        let image = match key.as_str() {
+            k if IMAGE_KEYS.contains(&k) => Some(value.as_str()),
+            "uses" => value.strip_prefix("docker://"),
+            _ => None,
+        };
+        match image {
+            Some(image) if image.is_empty() || image.contains('$') => {}
+            Some(image) if PLACEHOLDER_IMAGE.is_match(image) || PLACEHOLDER_VALUE.is_match(image) => {
+                violations.push(hollow(
+                    file,
+                    line,
+                    severities.placeholder,
+                    format!("{} '{}' is a placeholder", key, image),
+                ));
+            }
+            Some(image) if is_unpinned(image) => {
+                violations.push(hollow(
+                    file,
+                    line,
+                    severities.latest,
+                    format!("{} '{}' resolves to latest; pin a version tag or digest", key, image),
+                ));
+            }
+            Some(_) => {}
+            None if PLACEHOLDER_VALUE.is_match(&value) => {
+                violations.push(hollow(
+                    file,
+                    line,
+                    severities.placeholder,
+                    format!("'{}' has placeholder value '{}'", key, value),
+                ));
+            }
+            None => {}
+        }
+    }
+
+    violations
+}
+
+/// Check the project's GitHub Actions workflows and GitLab CI file for
+/// placeholder images and values, unpinned images, and TODO markers.
+pub fn detect_hollow_ci_configs<P: AsRef<Path>>(
+    base_dir: P,
+    contract: &Contract,
+    config: &CiConfigChecksConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let base = base_dir.as_ref();
+    let severities = InfraSeverities::parse(
+        "ci_configs",
+        config.latest_tag_severity(),
+        config.placeholder_severity(),
+        config.todo_severity(),
+    )?;
+
+    for path in pipeline_files(base) {
+        if contract.is_path_excluded(&path) {
+            continue;
+        }
+        let Ok(source) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        result.scanned += 1;
+        let rel_path = path.strip_prefix(base).unwrap_or(&path).to_string_lossy().to_string();
+        for violation in check_pipeline(&rel_path, &source, severities) {
+            result.add_violation(violation);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &CiConfigChecksConfig) -> Vec<Violation> {
+        let temp = TempDir::new().unwrap();
+        for (name, content) in files {
+            let path = temp.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+        }
+        detect_hollow_ci_configs(temp.path(), &Contract::default_contract(), config)
+            .unwrap()
+            .violations
+    }
+
+    const WORKFLOW: &str = r#"name: ci
+on: [push]
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    container: node:latest
+    services:
+      db:
+        image: postgres:16
+    steps:
+      - uses: actions/checkout@v4
+      - run: npm ci
+      - run: echo "TODO"
+      - uses: docker://your-registry-here/linter
+  deploy:
+    # TODO: add the deploy job
+    runs-on: <runner>
+    steps:
+      - name: Check for TODOs
+        run: ./scripts/check-todos.sh
+"#;
+
+    #[test]
+    fn test_github_workflow() {
+        let violations = detect(&[(".github/workflows/ci.yml", WORKFLOW)], &CiConfigChecksConfig::default());
+        let found: Vec<_> = violations.iter().map(|v| (v.line, v.severity)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (6, Severity::Warning),
+                (13, Severity::Warning),
+                (14, Severity::Error),
+                (16, Severity::Warning),
+                (17, Severity::Error),
+            ],
+            "{:?}",
+            violations
+        );
+        assert_eq!(violations[0].file.as_deref(), Some(".github/workflows/ci.yml"));
+        assert_eq!(violations[1].message, "step only runs 'echo \"TODO\"'; the job was never written");
+        assert_eq!(violations[2].message, "uses 'your-registry-here/linter' is a placeholder");
+        assert_eq!(violations[3].message, "TODO marker left in the pipeline: 'TODO: add the deploy job'");
+        assert_eq!(violations[4].message, "'runs-on' has placeholder value '<runner>'");
+    }
+
+    #[test]
+    fn test_gitlab_ci_and_severities() {
+        let gitlab = "image: python:3.12\n\ntest:\n  image: my-app\n  script:\n    - echo TODO\n    - pytest\n";
+        let config = CiConfigChecksConfig {
+            placeholder_severity: Some("warning".to_string()),
+            todo_severity: Some("info".to_string()),
+            ..CiConfigChecksConfig::default()
+        };
+        let violations = detect(&[(".gitlab-ci.yml", gitlab)], &config);
+        let found: Vec<_> = violations.iter().map(|v| (v.line, v.severity)).collect();
+        assert_eq!(found, vec![(4, Severity::Warning), (6, Severity::Info)], "{:?}", violations);
+    }
+}
@@ -0,0 +1,191 @@
+//! Detection of source files with too few or too many comments.
+//!
+//! The code analog of prose density: a file whose complex functions carry
+//! no comments leaves the reader to reverse-engineer every branch, and a
+//! file that is mostly comments over a little code is often generated
+//! padding that restates what each line does. Line counts come from the
+//! analyzers (`FileFacts::lines`), so comment syntax inside strings is not
+//! miscounted and Python docstrings count as comments. The license header
+//! documents nothing about the code and is left out.
+
+use std::path::Path;
+
+use crate::analysis::FileFacts;
+use crate::contract::CommentRatioConfig;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Comment lines of a file, without its header comment.
+fn documentation_lines(facts: &FileFacts) -> usize {
+    let header = facts
+        .header_comment
+        .as_ref()
+        .map_or(0, |h| h.span.end_line + 1 - h.span.start_line);
+    facts.lines.comment.saturating_sub(header)
+}
+
+/// Check one file against both bounds.
+fn check_file(path: &Path, facts: &FileFacts, config: &CommentRatioConfig) -> Option<Violation> {
+    let code = facts.lines.code;
+    if code == 0 {
+        return None;
+    }
+    let comment = documentation_lines(facts);
+    let ratio = comment as f64 / code as f64;
+    let file = path.to_string_lossy();
+
+    if ratio > config.max_ratio() && code + comment >= config.min_lines() {
+        return Some(
+            Violation::new(
+                ViolationRule::CommentRatio,
+                format!(
+                    "file is mostly comments: {} comment lines for {} lines of code ({:.1} per line, maximum {}); comments that restate the code are padding",
+                    comment,
+                    code,
+                    ratio,
+                    config.max_ratio()
+                ),
+            )
+            .at(file, 1),
+        );
+    }
+
+    if ratio > config.min_ratio() {
+        return None;
+    }
+    let complex: Vec<_> = facts
+        .declarations
+        .iter()
+        .filter(|d| {
+            d.body.as_ref().is_some_and(|b| {
+                b.control_flow.cyclomatic_complexity() >= config.complex_function_complexity()
+            })
+        })
+        .collect();
+    if complex.len() < config.min_complex_functions() {
+        return None;
+    }
+
+    let names: Vec<String> = complex.iter().map(|d| d.qualified_name()).collect();
+    let comments = if comment == 0 {
+        "no comments".to_string()
+    } else {
+        format!("{} comment lines for {} lines of code", comment, code)
+    };
+    Some(
+        Violation::new(
+            ViolationRule::CommentRatio,
+            format!(
+                "file is under-documented: {} across {} complex functions ({}); explain why they branch the way they do",
+                comments,
+                complex.len(),
+                names.join(", ")
+            ),
+        )
+        .at(file, complex[0].span.start_line),
+    )
+}
+
+/// Flag files whose comment-to-code line ratio is at or below `min_ratio`
+/// while they hold enough complex functions, or above `max_ratio`.
+pub fn detect_comment_ratio<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &CommentRatioConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+        if let Some(v) = check_file(path, file_facts, config) {
+            result.add_violation(v);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str, config: &CommentRatioConfig) -> Vec<Violation> {
+        test_support::violations(name, source, ViolationRule::CommentRatio, |facts, files| {
+            detect_comment_ratio(facts, files, config)
+        })
+    }
+
+    fn enabled() -> CommentRatioConfig {
+        CommentRatioConfig {
+            enabled: true,
+            ..CommentRatioConfig::default()
+        }
+    }
+
+    /// A Go function with cyclomatic complexity 5.
+    fn branchy(name: &str, comment: &str) -> String {
+        format!(
+            "{}func {}(xs []int) int {{\n\tn := 0\n\tfor _, x := range xs {{\n\t\tif x > 0 && x < 10 {{\n\t\t\tn++\n\t\t}} else if x > 100 {{\n\t\t\tn--\n\t\t}}\n\t}}\n\treturn n\n}}\n\n",
+            comment, name
+        )
+    }
+
+    #[test]
+    fn test_under_documented_complex_functions() {
+        let header = "// Copyright 2024 Acme Corp\n// SPDX-License-Identifier: MIT\n\npackage scoring\n\n";
+        let bare = format!("{}{}{}{}", header, branchy("A", ""), branchy("B", ""), branchy("C", ""));
+
+        let violations = detect("scoring.go", &bare, &enabled());
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "file is under-documented: no comments across 3 complex functions (A, B, C); explain why they branch the way they do"
+        );
+        assert_eq!(violations[0].line, 6);
+
+        // One comment is enough at the default bound, and two complex functions are too few
+        let commented = format!("{}{}{}{}", header, branchy("A", "// A counts the small values.\n"), branchy("B", ""), branchy("C", ""));
+        assert!(detect("scoring.go", &commented, &enabled()).is_empty());
+        let two = format!("{}{}{}", header, branchy("A", ""), branchy("B", ""));
+        assert!(detect("scoring.go", &two, &enabled()).is_empty());
+
+        let strict = CommentRatioConfig {
+            min_ratio: Some(0.1),
+            min_complex_functions: Some(2),
+            ..enabled()
+        };
+        let violations = detect("scoring.go", &commented, &strict);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("1 comment lines for 34 lines of code across 3"), "{}", violations[0].message);
+    }
+
+    #[test]
+    fn test_mostly_comments() {
+        let mut padded = String::from("def total(items):\n");
+        for i in 0..9 {
+            padded.push_str(&format!(
+                "    # Step {}: iterate over the items and add each one to the running total.\n    # This ensures that every item is counted exactly once.\n",
+                i
+            ));
+        }
+        padded.push_str("    \"\"\"Return the total.\"\"\"\n    return sum(items)\n");
+
+        let violations = detect("totals.py", &padded, &enabled());
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "file is mostly comments: 19 comment lines for 2 lines of code (9.5 per line, maximum 1.5); comments that restate the code are padding"
+        );
+
+        // Short files are left alone
+        let short = "def total(items):\n    \"\"\"Return the total of the items.\"\"\"\n    return sum(items)\n";
+        assert!(detect("totals.py", short, &enabled()).is_empty());
+    }
+}
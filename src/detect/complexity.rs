@@ -1,25 +1,37 @@
 //! Detection of functions with low cyclomatic complexity.
 //!
-//! This module uses AST-backed analysis via the AnalysisContext to calculate
-//! cyclomatic complexity from control-flow facts extracted by tree-sitter.
+//! This module calculates cyclomatic complexity from the control-flow facts
+//! tree-sitter extracts, read from the scan's `FactsBundle`.
 //!
 //! Cyclomatic complexity is calculated as:
 //! - Start at 1
 //! - Add 1 for each: if, for, while, case, &&, ||, ?, catch
+//!
+//! Bodies too large to count (a generated opcode table) have no complexity
+//! computed and satisfy any minimum: whatever they are, they are not stubs.
+//! They are reported as info findings so the skipped check is visible.
+//! Complexities over the cap are reported as saturated.
+//!
+//! There is no text-matching fallback. Decision points are syntax nodes, so
+//! an `if` or `AND` in a comment or string literal never counts, and a file
+//! without an analyzer fails its requirements instead of being estimated.
+//! Violations name the syntax tree the number was measured on.
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
-use crate::analysis::{get_analyzer, AnalysisContext, DeclarationKind, FileFacts};
+use crate::analysis::{Complexity, DeclarationKind, FileFacts};
 use crate::contract::ComplexityRequirement;
 
-use super::{DetectionResult, Severity, Violation, ViolationRule};
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
 
 /// Complexity information for a function.
 #[derive(Debug, Clone)]
 struct FuncComplexity {
     name: String,
-    complexity: i32,
+    /// Language whose syntax tree the complexity was measured on.
+    language: String,
+    complexity: Complexity,
     #[allow(dead_code)]
     file: String,
     line: usize,
@@ -30,7 +42,7 @@ struct FuncComplexity {
 /// Uses AST-backed analysis for supported languages. Files with unsupported
 /// extensions will cause an explicit failure for any complexity checks in them.
 pub fn detect_low_complexity<P: AsRef<Path>>(
-    analysis_ctx: &AnalysisContext,
+    facts: &FactsBundle,
     files: &[P],
     requirements: &[ComplexityRequirement],
 ) -> anyhow::Result<DetectionResult> {
@@ -40,7 +52,7 @@ pub fn detect_low_complexity<P: AsRef<Path>>(
         return Ok(result);
     }
 
-    let base = analysis_ctx.base_dir();
+    let base = facts.base_dir();
 
     // Collect the set of files we need to analyze (only those with explicit requirements)
     let required_files: HashSet<&str> = requirements
@@ -55,7 +67,7 @@ pub fn detect_low_complexity<P: AsRef<Path>>(
     let mut unsupported_files: HashSet<String> = HashSet::new();
 
     // Build a map of function complexities by file
-    let mut funcs_by_file: HashMap<String, Vec<FuncComplexity>> = HashMap::new();
+    let mut funcs_by_file: BTreeMap<String, Vec<FuncComplexity>> = BTreeMap::new();
 
     // Sort files for deterministic processing
     let mut sorted_files: Vec<_> = files.iter().collect();
@@ -74,20 +86,17 @@ pub fn detect_low_complexity<P: AsRef<Path>>(
             continue;
         }
 
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-
-        // Check if we have an analyzer for this extension
-        if get_analyzer(ext).is_none() {
+        // Files without facts have no analyzer for their extension
+        let Some(file_facts) = facts.file(path) else {
             if required_files.contains(rel_path.as_str()) {
                 unsupported_files.insert(rel_path.clone());
             }
             continue;
-        }
+        };
 
-        // Use AST-backed analysis
-        match analysis_ctx.analyze_file(path) {
-            Ok(facts) => {
-                let funcs = extract_complexities_from_facts(&facts);
+        match file_facts {
+            Ok(file_facts) => {
+                let funcs = extract_complexities_from_facts(file_facts);
                 funcs_by_file.insert(rel_path, funcs);
                 result.scanned += 1;
             }
@@ -96,7 +105,7 @@ pub fn detect_low_complexity<P: AsRef<Path>>(
                 result.add_violation(Violation {
                     rule: ViolationRule::LowComplexity,
                     message: format!("failed to parse file for complexity analysis: {}", e),
-                    file: rel_path,
+                    file: Some(rel_path),
                     line: 0,
                     severity: Severity::Error,
                 });
@@ -117,7 +126,7 @@ pub fn detect_low_complexity<P: AsRef<Path>>(
                         "cannot verify complexity for {:?}: no analyzer for file extension",
                         req.symbol
                     ),
-                    file: file.clone(),
+                    file: Some(file.clone()),
                     line: 0,
                     severity: Severity::Error,
                 });
@@ -125,48 +134,56 @@ pub fn detect_low_complexity<P: AsRef<Path>>(
             }
         }
 
-        let (found, actual_complexity, line) = if let Some(ref file) = req.file {
+        let found = if let Some(ref file) = req.file {
             // Look in specific file
             funcs_by_file
                 .get(file)
                 .and_then(|funcs| funcs.iter().find(|f| f.name == req.symbol))
-                .map(|f| (true, f.complexity, f.line))
-                .unwrap_or((false, 0, 0))
+                .map(|f| (file.clone(), f))
         } else {
-            // Look in any file
-            funcs_by_file
-                .values()
-                .flatten()
-                .find(|f| f.name == req.symbol)
-                .map(|f| (true, f.complexity, f.line))
-                .unwrap_or((false, 0, 0))
+            // Look in any file, reporting the first (by path) that defines it
+            funcs_by_file.iter().find_map(|(file, funcs)| {
+                funcs
+                    .iter()
+                    .find(|f| f.name == req.symbol)
+                    .map(|f| (file.clone(), f))
+            })
         };
 
-        if !found {
-            let file = req.file.clone().unwrap_or_else(|| "(any file)".to_string());
+        let Some((file, func)) = found else {
+            // Without a target file the symbol is missing from the whole project
             violations.push(Violation {
                 rule: ViolationRule::LowComplexity,
                 message: format!("symbol {:?} not found for complexity check", req.symbol),
-                file,
+                file: req.file.clone(),
                 line: 0,
                 severity: Severity::Error,
             });
             continue;
-        }
+        };
 
-        if actual_complexity < req.min_complexity {
-            let file = req
-                .file
-                .clone()
-                .unwrap_or_else(|| "(found in codebase)".to_string());
+        let Some(actual_complexity) = func.complexity.value() else {
+            violations.push(Violation {
+                rule: ViolationRule::LowComplexity,
+                message: format!(
+                    "symbol {:?} {}; treated as meeting the minimum of {}",
+                    req.symbol, func.complexity, req.min_complexity
+                ),
+                file: Some(file),
+                line: func.line,
+                severity: Severity::Info,
+            });
+            continue;
+        };
+        if i64::from(actual_complexity) < i64::from(req.min_complexity) {
             violations.push(Violation {
                 rule: ViolationRule::LowComplexity,
                 message: format!(
-                    "symbol {:?} has complexity {}, minimum required is {}",
-                    req.symbol, actual_complexity, req.min_complexity
+                    "symbol {:?} has {} (measured on the {} syntax tree), minimum required is {}",
+                    req.symbol, func.complexity, func.language, req.min_complexity
                 ),
-                file,
-                line,
+                file: Some(file),
+                line: func.line,
                 severity: Severity::Error,
             });
         }
@@ -193,7 +210,8 @@ fn extract_complexities_from_facts(facts: &FileFacts) -> Vec<FuncComplexity> {
         .filter_map(|decl| {
             decl.body.as_ref().map(|body| FuncComplexity {
                 name: decl.name.clone(),
-                complexity: body.control_flow.cyclomatic_complexity(),
+                language: facts.language.clone(),
+                complexity: body.control_flow.complexity(),
                 file: facts.path.clone(),
                 line: decl.span.start_line,
             })
@@ -204,6 +222,7 @@ fn extract_complexities_from_facts(facts: &FileFacts) -> Vec<FuncComplexity> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::analysis::AnalysisContext;
     use tempfile::TempDir;
 
     #[test]
@@ -238,7 +257,7 @@ func process(x int) int {
 
         assert_eq!(funcs.len(), 1);
         // 1 (base) + 2 (if) + 1 (for) + 1 (&&) = 5
-        assert_eq!(funcs[0].complexity, 5);
+        assert_eq!(funcs[0].complexity, Complexity::Counted(5));
     }
 
     #[test]
@@ -284,7 +303,8 @@ func complex(x int) int {
             },
         ];
 
-        let result = detect_low_complexity(&analysis_ctx, &[&file_path], &requirements).unwrap();
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::LowComplexity]);
+        let result = detect_low_complexity(&facts, &[&file_path], &requirements).unwrap();
         // simple has complexity 1, required 3 -> violation
         // complex has complexity 4, required 3 -> ok
         assert_eq!(result.violations.len(), 1);
@@ -306,7 +326,8 @@ func complex(x int) int {
             min_complexity: 5,
         }];
 
-        let result = detect_low_complexity(&analysis_ctx, &[&file_path], &requirements).unwrap();
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::LowComplexity]);
+        let result = detect_low_complexity(&facts, &[&file_path], &requirements).unwrap();
         assert_eq!(result.violations.len(), 1);
         assert!(result.violations[0]
             .message
@@ -336,8 +357,170 @@ func main() {}
             min_complexity: 5,
         }];
 
-        let result = detect_low_complexity(&analysis_ctx, &[&file_path], &requirements).unwrap();
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::LowComplexity]);
+        let result = detect_low_complexity(&facts, &[&file_path], &requirements).unwrap();
         assert_eq!(result.violations.len(), 1);
         assert!(result.violations[0].message.contains("not found"));
     }
+
+    #[test]
+    fn test_body_too_large_satisfies_minimum() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("opcodes.rs");
+        let mut source = String::from("fn decode(op: u16) -> u16 {\n    match op {\n");
+        for i in 0..200 {
+            source.push_str(&format!("        {} => {},\n", i, i * 2));
+        }
+        source.push_str("        _ => 0,\n    }\n}\n\nfn encode(op: u16) -> u16 {\n    op\n}\n");
+        std::fs::write(&file_path, source).unwrap();
+
+        let analysis_ctx = AnalysisContext::new(temp.path()).with_max_body_nodes(100);
+        let requirement = |symbol: &str| ComplexityRequirement {
+            symbol: symbol.to_string(),
+            file: Some("opcodes.rs".to_string()),
+            min_complexity: 5000,
+        };
+        let requirements = vec![requirement("decode"), requirement("encode")];
+
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::LowComplexity]);
+        let result = detect_low_complexity(&facts, &[&file_path], &requirements).unwrap();
+        let found: Vec<_> = result.violations.iter().map(|v| (v.severity, v.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    Severity::Info,
+                    "symbol \"decode\" complexity: not computed, body too large; treated as meeting the minimum of 5000"
+                ),
+                (
+                    Severity::Error,
+                    "symbol \"encode\" has complexity 1 (measured on the rust syntax tree), minimum required is 5000"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comments_and_strings_do_not_count() {
+        crate::analysis::register_analyzers();
+
+        // Stubs whose only branching lives in comments and string literals
+        let cases = [
+            (
+                "handler.go",
+                "go",
+                r#"package main
+
+// Handle checks: if req != nil && req.ok { for each item ... } else || case
+func Handle(req *Request) string {
+	/* if a && b { switch x { case 1: } } */
+	log("SELECT * FROM t WHERE a = 1 AND b = 2 OR c = 3 && d || e")
+	return "if ok { for { select { case <-done: } } }"
+}
+"#,
+            ),
+            (
+                "handler.py",
+                "python",
+                r#"def handle(req):
+    """If the request is valid and not expired, for each item: try/except."""
+    # if req and req.ok: for x in items: while True: pass
+    log("SELECT * FROM t WHERE a = 1 AND b = 2 OR c = 3")
+    return "if x else y"
+"#,
+            ),
+            (
+                "handler.js",
+                "javascript",
+                r#"// if (a && b) { for (;;) {} } else if (c || d) {}
+function handle(req) {
+  /* switch (x) { case 1: case 2: } try {} catch (e) {} */
+  log(`if (${req} && ok) { while (true) {} }`);
+  return "a ? b : c || d && e";
+}
+"#,
+            ),
+            (
+                "handler.rs",
+                "rust",
+                r#"/// if a && b { for x in y { match z { _ => {} } } }
+fn handle(req: &str) -> &str {
+    // while let Some(x) = it.next() { if x || y {} }
+    log("SELECT * FROM t WHERE a = 1 AND b = 2 OR c = 3 && d || e");
+    "if ok { loop {} }"
+}
+"#,
+            ),
+            (
+                "Handler.java",
+                "java",
+                r#"class Handler {
+    /** if (a && b) { for (;;) {} } catch (Exception e) */
+    String handle(String req) {
+        // switch (x) { case 1: case 2: } while (c || d) {}
+        return "a ? b : c || d && e";
+    }
+}
+"#,
+            ),
+        ];
+
+        for (name, language, source) in cases {
+            let temp = TempDir::new().unwrap();
+            let file_path = temp.path().join(name);
+            std::fs::write(&file_path, source).unwrap();
+
+            let symbol = if language == "go" { "Handle" } else { "handle" };
+            let requirements = vec![ComplexityRequirement {
+                symbol: symbol.to_string(),
+                file: Some(name.to_string()),
+                min_complexity: 2,
+            }];
+
+            let analysis_ctx = AnalysisContext::new(temp.path());
+            let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::LowComplexity]);
+            let result = detect_low_complexity(&facts, &[&file_path], &requirements).unwrap();
+            assert_eq!(result.violations.len(), 1, "{}", name);
+            assert_eq!(
+                result.violations[0].message,
+                format!(
+                    "symbol {:?} has complexity 1 (measured on the {} syntax tree), minimum required is 2",
+                    symbol, language
+                ),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_requirement_without_file() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("main.go");
+        std::fs::write(&file_path, "package main\n\nfunc main() {}\n").unwrap();
+
+        let analysis_ctx = AnalysisContext::new(temp.path());
+        let requirement = |symbol: &str| ComplexityRequirement {
+            symbol: symbol.to_string(),
+            file: None,
+            min_complexity: 5,
+        };
+        let requirements = vec![requirement("main"), requirement("missing")];
+
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::LowComplexity]);
+        let result = detect_low_complexity(&facts, &[&file_path], &requirements).unwrap();
+        assert_eq!(result.violations.len(), 2);
+        // Missing everywhere is a project-level finding
+        let missing = &result.violations[0];
+        assert!(missing.is_project_level());
+        assert!(missing.message.contains("\"missing\" not found"));
+        // Found symbols report the file that defines them
+        let low = &result.violations[1];
+        assert_eq!(low.file.as_deref(), Some("main.go"));
+        assert_eq!(low.line, 3);
+    }
 }
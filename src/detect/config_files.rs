@@ -0,0 +1,348 @@
+// hollowcheck:ignore-file mock_data - Test fixtures contain placeholder values
+//! Detection of hollow YAML, JSON, and TOML configuration files.
+//!
+//! Delivered configuration looks complete while configuring nothing:
+//! `host: TODO`, `apiKey: ""`, `port: 0`, or a required section left as an
+//! empty map. Files are matched against the contract's `config_files` globs
+//! and read with `parse_config`, which keeps the line of every key. Like
+//! `forbidden_files`, the project is walked independently of source
+//! discovery, since configuration files are never passed to the language
+//! analyzers. Values matching a mock signature are reported as `mock_data`,
+//! the same as compose environment values.
+
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::analysis::{parse_config, ConfigEntry, ConfigFormat, ConfigValue};
+use crate::contract::{ConfigFileSpec, ConfigRange, Contract};
+
+use super::files::DEPENDENCY_DIRS;
+use super::mocks::{compile_mock_signatures, CompiledMockSignature};
+use super::{DetectionResult, Violation, ViolationRule};
+
+/// Values that were never filled in.
+static PLACEHOLDER_VALUE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)^(todo|fixme|tbd|tbc|xxx+|changeme|change[-_ ]?me|replace[-_ ]?me|fill[-_ ]?me[-_ ]?in|placeholder|<[^>]+>|\.\.\.)$",
+    )
+    .unwrap()
+});
+
+/// Keys that hold a credential, by their last path segment.
+static CREDENTIAL_KEY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)(password|passwd|secret|token|api[-_]?key|private[-_]?key|credentials?)$").unwrap()
+});
+
+/// Keys that hold a network port, by their last path segment.
+static PORT_KEY: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)(^|[-_])port$").unwrap());
+
+/// Last segment of a dotted path, without a list index.
+fn last_segment(path: &str) -> &str {
+    let segment = path.rsplit('.').next().unwrap_or(path);
+    segment.split('[').next().unwrap_or(segment)
+}
+
+/// Whether a key path matches a pattern whose `[*]` stands for any list index.
+fn key_matches(pattern: &str, path: &str) -> bool {
+    if !pattern.contains("[*]") {
+        return pattern == path;
+    }
+    static INDEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\[\d+\]").unwrap());
+    INDEX.replace_all(path, "[*]") == pattern
+}
+
+fn hollow(file: &str, line: usize, message: String) -> Violation {
+    Violation::new(ViolationRule::HollowConfig, message).at(file, line)
+}
+
+/// Check the values of a document for placeholders.
+fn check_placeholders(
+    file: &str,
+    entries: &[ConfigEntry],
+    signatures: &[CompiledMockSignature],
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for entry in entries {
+        let key = last_segment(&entry.path);
+        match &entry.value {
+            ConfigValue::String(value) if PLACEHOLDER_VALUE.is_match(value.trim()) => {
+                violations.push(hollow(
+                    file,
+                    entry.line,
+                    format!("config key '{}' has placeholder value '{}'", entry.path, value.trim()),
+                ));
+            }
+            ConfigValue::String(value) if value.trim().is_empty() && CREDENTIAL_KEY.is_match(key) => {
+                violations.push(hollow(
+                    file,
+                    entry.line,
+                    format!("config key '{}' is an empty credential", entry.path),
+                ));
+            }
+            ConfigValue::String(value) => {
+                if let Some(signature) = signatures.iter().find(|s| s.regex.is_match(value)) {
+                    violations.push(
+                        Violation::new(ViolationRule::MockData, signature.message()).at(file, entry.line),
+                    );
+                }
+            }
+            ConfigValue::Number(n) if *n == 0.0 && PORT_KEY.is_match(key) => {
+                violations.push(hollow(file, entry.line, format!("config key '{}' is port 0", entry.path)));
+            }
+            _ => {}
+        }
+    }
+    violations
+}
+
+/// Check that required keys are present and non-empty.
+fn check_required(file: &str, entries: &[ConfigEntry], keys: &[&String]) -> Vec<Violation> {
+    keys.iter()
+        .filter_map(|key| match entries.iter().find(|e| e.path == **key) {
+            None => Some(hollow(file, 0, format!("required config key '{}' is missing", key))),
+            Some(entry) if entry.value.is_empty() => Some(hollow(
+                file,
+                entry.line,
+                format!("required config key '{}' is empty", key),
+            )),
+            Some(_) => None,
+        })
+        .collect()
+}
+
+/// Check numeric keys against their bounds.
+fn check_ranges(file: &str, entries: &[ConfigEntry], ranges: &[&ConfigRange]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for range in ranges {
+        for entry in entries.iter().filter(|e| key_matches(&range.key, &e.path)) {
+            let ConfigValue::Number(n) = entry.value else {
+                violations.push(hollow(
+                    file,
+                    entry.line,
+                    format!("config key '{}' must be a number", entry.path),
+                ));
+                continue;
+            };
+            let below = range.min.is_some_and(|min| n < min);
+            let above = range.max.is_some_and(|max| n > max);
+            if below || above {
+                let bound = |b: Option<f64>| b.map_or(String::new(), |b| b.to_string());
+                violations.push(hollow(
+                    file,
+                    entry.line,
+                    format!(
+                        "config key '{}' is {}, outside {}..{}",
+                        entry.path,
+                        n,
+                        bound(range.min),
+                        bound(range.max)
+                    ),
+                ));
+            }
+        }
+    }
+    violations
+}
+
+/// Check configuration files matching the contract's `config_files` globs.
+///
+/// Files that fail to parse are counted as scanned and skipped. A file
+/// matched by several entries gets the required keys and ranges of all of
+/// them.
+pub fn detect_hollow_config<P: AsRef<Path>>(
+    base_dir: P,
+    contract: &Contract,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let base = base_dir.as_ref();
+    if contract.config_files.is_empty() {
+        return Ok(result);
+    }
+
+    let specs: Vec<(&ConfigFileSpec, globset::GlobMatcher)> = contract
+        .config_files
+        .iter()
+        .map(|spec| {
+            let glob = globset::Glob::new(&spec.pattern).map_err(|e| {
+                anyhow::anyhow!("invalid config_files pattern {:?}: {}", spec.pattern, e)
+            })?;
+            Ok((spec, glob.compile_matcher()))
+        })
+        .collect::<anyhow::Result<_>>()?;
+    let signatures = match &contract.mock_signatures {
+        Some(cfg) => compile_mock_signatures(cfg)?,
+        None => Vec::new(),
+    };
+
+    let walker = WalkDir::new(base).into_iter().filter_entry(|e| {
+        let name = e.file_name().to_string_lossy();
+        !(e.depth() > 0
+            && e.file_type().is_dir()
+            && (name.starts_with('.') || DEPENDENCY_DIRS.contains(&name.as_ref())))
+    });
+    let mut entries: Vec<_> = walker
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .collect();
+    entries.sort_by(|a, b| a.path().cmp(b.path()));
+
+    for entry in entries {
+        let path = entry.path();
+        let Some(format) = ConfigFormat::from_path(path) else {
+            continue;
+        };
+        if contract.is_path_excluded(path) {
+            continue;
+        }
+        let rel_path = path
+            .strip_prefix(base)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        let matched: Vec<&ConfigFileSpec> = specs
+            .iter()
+            .filter(|(_, matcher)| matcher.is_match(&rel_path))
+            .map(|(spec, _)| *spec)
+            .collect();
+        if matched.is_empty() {
+            continue;
+        }
+
+        let Ok(source) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        result.scanned += 1;
+        let Ok(doc) = parse_config(format, &source) else {
+            continue;
+        };
+
+        let required: Vec<&String> = matched.iter().flat_map(|s| &s.required_keys).collect();
+        let ranges: Vec<&ConfigRange> = matched.iter().flat_map(|s| &s.ranges).collect();
+        let mut violations = check_required(&rel_path, &doc, &required);
+        violations.extend(check_ranges(&rel_path, &doc, &ranges));
+        if matched.iter().any(|s| s.checks_placeholders()) {
+            violations.extend(check_placeholders(&rel_path, &doc, &signatures));
+        }
+
+        violations.sort_by_key(|v| v.line);
+        for violation in violations {
+            result.add_violation(violation);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], contract_yaml: &str) -> Vec<(String, String, usize, String)> {
+        let temp = TempDir::new().unwrap();
+        for (name, content) in files {
+            let path = temp.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+        }
+        let mut contract: Contract = serde_yaml::from_str(contract_yaml).unwrap();
+        contract.mock_signatures = Contract::default_contract().mock_signatures;
+        detect_hollow_config(temp.path(), &contract)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                (
+                    v.rule.as_str().to_string(),
+                    v.file.unwrap_or_default(),
+                    v.line,
+                    v.message,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_placeholder_values() {
+        let yaml = r#"database:
+  host: TODO
+  port: 0
+  password: ""
+  user: app
+notify:
+  webhook: https://hooks.internal/deploy
+  email: CHANGEME
+"#;
+        let found = detect(
+            &[("config/app.yaml", yaml), ("other/app.yaml", yaml)],
+            "config_files:\n  - pattern: \"config/*.yaml\"\n",
+        );
+        let summary: Vec<_> = found
+            .iter()
+            .map(|(rule, file, line, _)| (rule.as_str(), file.as_str(), *line))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("hollow_config", "config/app.yaml", 2),
+                ("hollow_config", "config/app.yaml", 3),
+                ("hollow_config", "config/app.yaml", 4),
+                ("hollow_config", "config/app.yaml", 8),
+            ],
+            "{:?}",
+            found
+        );
+        assert!(found[0].3.contains("'database.host' has placeholder value 'TODO'"));
+        assert!(found[2].3.contains("empty credential"));
+    }
+
+    #[test]
+    fn test_required_keys_and_ranges() {
+        let json = r#"{
+  "server": {
+    "port": 70000,
+    "timeout": "soon"
+  },
+  "features": {},
+  "upstreams": [{"port": 8080}, {"port": -1}]
+}
+"#;
+        let contract = r#"
+config_files:
+  - pattern: "**/*.json"
+    required_keys: [server.port, features, database.url]
+    placeholders: false
+    ranges:
+      - key: server.port
+        min: 1
+        max: 65535
+      - key: server.timeout
+        min: 0
+      - key: "upstreams[*].port"
+        min: 1
+"#;
+        let found = detect(&[("settings.json", json)], contract);
+        let messages: Vec<_> = found.iter().map(|(_, _, line, msg)| (*line, msg.as_str())).collect();
+        assert_eq!(
+            messages,
+            vec![
+                (0, "required config key 'database.url' is missing"),
+                (3, "config key 'server.port' is 70000, outside 1..65535"),
+                (4, "config key 'server.timeout' must be a number"),
+                (6, "required config key 'features' is empty"),
+                (7, "config key 'upstreams[1].port' is -1, outside 1.."),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_toml_mock_signature() {
+        let toml = "[smtp]\nhost = \"mail.internal\"\nfrom = \"fake_user@shop.io\"\nport = 0\n";
+        let found = detect(&[("settings.toml", toml)], "config_files:\n  - pattern: \"*.toml\"\n");
+        let rules: Vec<_> = found.iter().map(|(rule, _, line, _)| (rule.as_str(), *line)).collect();
+        assert_eq!(rules, vec![("mock_data", 3), ("hollow_config", 4)]);
+    }
+}
@@ -0,0 +1,697 @@
+// hollowcheck:ignore-file mock_data - Test fixtures contain placeholder values
+//! Detection of hollow Dockerfiles and docker-compose files.
+//!
+//! Generated infrastructure tends to look finished while running nothing:
+//! an image whose CMD is `echo "TODO"`, a HEALTHCHECK that always passes, a
+//! port exposed on an image with no server, or `API_KEY=CHANGEME` baked into
+//! the environment. Dockerfiles are read with the line-oriented
+//! `DockerfileAnalyzer`; compose files are read as YAML. Placeholder values
+//! are matched with the contract's mock signatures. The severities of
+//! unpinned images, placeholder images, and TODO steps come from the
+//! contract, and are shared with the CI pipeline checks.
+
+use std::fs;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde_yaml::Value;
+
+use crate::analysis::{is_compose_file, is_dockerfile, DockerfileAnalyzer, Instruction};
+use crate::contract::{ContainerChecksConfig, MockSignaturesConfig};
+
+use super::mocks::{compile_mock_signatures, CompiledMockSignature};
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Base images that only run a shell or REPL unless the Dockerfile sets a command.
+const RUNTIME_BASE_IMAGES: &[&str] = &[
+    "alpine", "debian", "ubuntu", "busybox", "centos", "fedora", "rockylinux", "amazonlinux",
+    "python", "node", "golang", "rust", "openjdk", "eclipse-temurin", "amazoncorretto", "ruby",
+    "php", "perl", "elixir", "erlang",
+];
+
+/// Image names that were never filled in (`<your-image>`, `my-app`, `your-registry/api`).
+pub(super) static PLACEHOLDER_IMAGE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^(.*<[^>]*>.*|(your|my|example)[-_]?(app|image|service|project|org|registry)\b.*)$")
+        .unwrap()
+});
+
+/// Markers of work left for later in a command or comment.
+pub(super) static TODO_MARKER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(TODO|FIXME|XXX|TBD)\b").unwrap());
+
+/// Severities the contract sets for infrastructure findings.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct InfraSeverities {
+    /// Images without a tag or tagged `latest`
+    pub latest: Severity,
+    /// Images and values that were never filled in
+    pub placeholder: Severity,
+    /// Steps that only print a TODO marker
+    pub todo: Severity,
+}
+
+impl InfraSeverities {
+    /// Parse the contract's severity names; `section` names the contract
+    /// section in errors.
+    pub fn parse(section: &str, latest: &str, placeholder: &str, todo: &str) -> anyhow::Result<Self> {
+        let parse = |field: &str, name: &str| {
+            name.parse::<Severity>()
+                .map_err(|e| anyhow::anyhow!("invalid {} {}: {}", section, field, e))
+        };
+        Ok(Self {
+            latest: parse("latest_tag_severity", latest)?,
+            placeholder: parse("placeholder_severity", placeholder)?,
+            todo: parse("todo_severity", todo)?,
+        })
+    }
+}
+
+/// Split a line into its code and its `#` comment.
+pub(super) fn split_comment(line: &str) -> (&str, Option<&str>) {
+    if let Some(comment) = line.trim_start().strip_prefix('#') {
+        return ("", Some(comment));
+    }
+    match line.find(" #") {
+        Some(idx) => (&line[..idx], Some(&line[idx + 2..])),
+        None => (line, None),
+    }
+}
+
+/// TODO markers in comments, as violations at the given severity.
+fn todo_comments(file: &str, source: &str, kind: &str, severity: Severity) -> Vec<Violation> {
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| {
+            let comment = split_comment(line).1?.trim();
+            TODO_MARKER.is_match(comment).then(|| {
+                hollow(file, idx + 1, format!("TODO marker left in the {}: '{}'", kind, comment))
+                    .with_severity(severity)
+            })
+        })
+        .collect()
+}
+
+/// Check whether a command does nothing: `echo`, `sleep`, `true`, `exit 0`,
+/// or `tail -f /dev/null`, alone or chained, optionally wrapped in `sh -c`.
+pub(super) fn is_noop_command(words: &[String]) -> bool {
+    let words: Vec<String> = match words {
+        [shell, flag, rest @ ..]
+            if matches!(shell.as_str(), "sh" | "bash" | "/bin/sh" | "/bin/bash") && flag == "-c" =>
+        {
+            rest.join(" ").split_whitespace().map(String::from).collect()
+        }
+        _ => words.to_vec(),
+    };
+    if words.is_empty() {
+        return false;
+    }
+
+    words
+        .split(|w| matches!(w.as_str(), "&&" | ";" | "||"))
+        .all(|segment| {
+            let program = segment
+                .first()
+                .map(|w| w.trim_end_matches(';').rsplit('/').next().unwrap_or(w))
+                .unwrap_or("");
+            let args: Vec<&str> = segment.iter().skip(1).map(String::as_str).collect();
+            match program {
+                "echo" | "printf" | "sleep" | "true" | ":" => true,
+                "exit" => matches!(args.as_slice(), [] | ["0"]),
+                "tail" => args == ["-f", "/dev/null"],
+                _ => false,
+            }
+        })
+}
+
+/// A build stage: the `FROM` line and what the stage sets.
+struct Stage {
+    image: String,
+    alias: Option<String>,
+    has_command: bool,
+    expose_line: Option<usize>,
+}
+
+/// Image and optional `AS` alias of a FROM instruction, skipping flags.
+fn parse_from(instruction: &Instruction) -> (String, Option<String>) {
+    let words: Vec<String> = instruction
+        .command_words()
+        .into_iter()
+        .filter(|w| !w.starts_with("--"))
+        .collect();
+    let image = words.first().cloned().unwrap_or_default();
+    let alias = words
+        .iter()
+        .position(|w| w.eq_ignore_ascii_case("as"))
+        .and_then(|pos| words.get(pos + 1))
+        .map(|a| a.to_lowercase());
+    (image, alias)
+}
+
+/// Image name without registry, tag, or digest (`docker.io/library/node:20` -> `node`).
+fn image_basename(image: &str) -> &str {
+    let name = image.split('@').next().unwrap_or(image);
+    let last = name.rsplit('/').next().unwrap_or(name);
+    last.split(':').next().unwrap_or(last)
+}
+
+/// Whether an image reference floats: no tag, or the `latest` tag.
+pub(super) fn is_unpinned(image: &str) -> bool {
+    if image.contains('@') {
+        return false;
+    }
+    let last = image.rsplit('/').next().unwrap_or(image);
+    match last.split_once(':') {
+        Some((_, tag)) => tag == "latest",
+        None => true,
+    }
+}
+
+/// The command words of a HEALTHCHECK, or None for `HEALTHCHECK NONE`.
+fn healthcheck_command(instruction: &Instruction) -> Option<Vec<String>> {
+    let (_, command) = instruction.args.split_once("CMD")?;
+    let command = Instruction {
+        args: command.trim().to_string(),
+        ..instruction.clone()
+    };
+    Some(command.command_words())
+}
+
+/// Find the first line at or after `from` (1-indexed) whose trimmed text
+/// starts with `needle`; falls back to `from`.
+fn find_line(source: &str, from: usize, needle: &str) -> usize {
+    source
+        .lines()
+        .enumerate()
+        .skip(from.saturating_sub(1))
+        .find(|(_, line)| line.trim_start().trim_start_matches("- ").starts_with(needle))
+        .map_or(from, |(idx, _)| idx + 1)
+}
+
+fn hollow(file: &str, line: usize, message: String) -> Violation {
+    Violation::new(ViolationRule::HollowContainer, message).at(file, line)
+}
+
+fn mock_data(file: &str, line: usize, message: String) -> Violation {
+    Violation::new(ViolationRule::MockData, message).at(file, line)
+}
+
+/// Check a Dockerfile.
+fn check_dockerfile(
+    path: &Path,
+    source: &str,
+    severities: InfraSeverities,
+    signatures: &[CompiledMockSignature],
+) -> Vec<Violation> {
+    let file = path.to_string_lossy().to_string();
+    let analyzer = DockerfileAnalyzer::new();
+    let mut violations = Vec::new();
+    let mut stages: Vec<Stage> = Vec::new();
+
+    for instruction in analyzer.parse(source) {
+        let line = instruction.line;
+        match instruction.keyword.as_str() {
+            "FROM" => {
+                let (image, alias) = parse_from(&instruction);
+                let is_stage = stages
+                    .iter()
+                    .any(|s| s.alias.as_deref() == Some(image.to_lowercase().as_str()));
+                if PLACEHOLDER_IMAGE.is_match(&image) {
+                    violations.push(
+                        hollow(&file, line, format!("base image '{}' is a placeholder", image))
+                            .with_severity(severities.placeholder),
+                    );
+                } else if image != "scratch" && !is_stage && !image.contains('$') && is_unpinned(&image) {
+                    violations.push(
+                        hollow(
+                            &file,
+                            line,
+                            format!(
+                                "base image '{}' resolves to latest; pin a version tag or digest",
+                                image
+                            ),
+                        )
+                        .with_severity(severities.latest),
+                    );
+                }
+                stages.push(Stage {
+                    image,
+                    alias,
+                    has_command: false,
+                    expose_line: None,
+                });
+            }
+            "CMD" | "ENTRYPOINT" => {
+                if let Some(stage) = stages.last_mut() {
+                    stage.has_command = true;
+                }
+                let words = instruction.command_words();
+                if is_noop_command(&words) {
+                    violations.push(hollow(
+                        &file,
+                        line,
+                        format!(
+                            "{} only runs '{}'; the container starts without doing any work",
+                            instruction.keyword,
+                            words.join(" ")
+                        ),
+                    ));
+                }
+            }
+            "HEALTHCHECK" => {
+                if let Some(words) = healthcheck_command(&instruction) {
+                    if is_noop_command(&words) {
+                        violations.push(hollow(
+                            &file,
+                            line,
+                            format!(
+                                "HEALTHCHECK runs '{}', which always passes",
+                                words.join(" ")
+                            ),
+                        ));
+                    }
+                }
+            }
+            "RUN" => {
+                let words = instruction.command_words();
+                if TODO_MARKER.is_match(&instruction.args) && is_noop_command(&words) {
+                    violations.push(
+                        hollow(
+                            &file,
+                            line,
+                            format!("RUN only runs '{}'; the build step was never written", instruction.args.trim()),
+                        )
+                        .with_severity(severities.todo),
+                    );
+                }
+            }
+            "EXPOSE" => {
+                if let Some(stage) = stages.last_mut() {
+                    stage.expose_line.get_or_insert(line);
+                }
+            }
+            "COPY" | "ADD" => {
+                let words = instruction.command_words();
+                if words.iter().any(|w| w.starts_with("--from")) {
+                    continue;
+                }
+                let sources: Vec<&String> = words.iter().filter(|w| !w.starts_with("--")).collect();
+                let copies_context = sources.len() > 1
+                    && sources[..sources.len() - 1]
+                        .iter()
+                        .any(|s| matches!(s.as_str(), "." | "./"));
+                if copies_context && !has_dockerignore(path) {
+                    violations.push(
+                        hollow(
+                            &file,
+                            line,
+                            format!(
+                                "{} copies the whole build context but there is no .dockerignore; .git, .env files, and local build output end up in the image",
+                                instruction.keyword
+                            ),
+                        )
+                        .with_severity(Severity::Warning),
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // EXPOSE on a final image that falls back to its base's shell or REPL
+    if let Some(last) = stages.last() {
+        let mut chain = vec![last];
+        while let Some(parent) = stages.iter().find(|s| {
+            s.alias.is_some() && s.alias.as_deref() == Some(chain[chain.len() - 1].image.to_lowercase().as_str())
+        }) {
+            if chain.iter().any(|s| std::ptr::eq(*s, parent)) {
+                break;
+            }
+            chain.push(parent);
+        }
+        let has_command = chain.iter().any(|s| s.has_command);
+        let base = image_basename(&chain[chain.len() - 1].image);
+        if let Some(expose_line) = last.expose_line {
+            if !has_command && RUNTIME_BASE_IMAGES.contains(&base) {
+                violations.push(hollow(
+                    &file,
+                    expose_line,
+                    format!(
+                        "EXPOSE without CMD or ENTRYPOINT; the container runs the {} default command and nothing listens on the port",
+                        base
+                    ),
+                ));
+            }
+        }
+    }
+
+    violations.extend(todo_comments(&file, source, "Dockerfile", severities.todo));
+
+    // ENV and ARG values
+    let facts = analyzer.extract_facts(&file, source);
+    for literal in &facts.string_literals {
+        for signature in signatures {
+            if signature.regex.is_match(&literal.value) {
+                violations.push(mock_data(&file, literal.span.start_line, signature.message()));
+            }
+        }
+    }
+
+    violations
+}
+
+/// Whether the build context of a Dockerfile has a `.dockerignore`, either
+/// shared or Dockerfile-specific (`Dockerfile.prod.dockerignore`).
+fn has_dockerignore(dockerfile: &Path) -> bool {
+    let dir = dockerfile.parent().unwrap_or(Path::new("."));
+    let specific = dockerfile
+        .file_name()
+        .map(|n| format!("{}.dockerignore", n.to_string_lossy()));
+    dir.join(".dockerignore").exists() || specific.is_some_and(|n| dir.join(n).exists())
+}
+
+/// Plain text of a scalar YAML value.
+fn scalar(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Check a docker-compose file.
+fn check_compose(
+    path: &Path,
+    source: &str,
+    severities: InfraSeverities,
+    signatures: &[CompiledMockSignature],
+) -> Vec<Violation> {
+    let file = path.to_string_lossy().to_string();
+    let mut violations = todo_comments(&file, source, "compose file", severities.todo);
+
+    let Ok(doc) = serde_yaml::from_str::<Value>(source) else {
+        return violations;
+    };
+    let Some(services) = doc.get("services").and_then(Value::as_mapping) else {
+        return violations;
+    };
+    let services_line = find_line(source, 1, "services:");
+
+    for (name, service) in services {
+        let Some(name) = name.as_str() else {
+            continue;
+        };
+        let line = find_line(source, services_line, &format!("{}:", name));
+
+        let is_empty = match service {
+            Value::Null => true,
+            Value::Mapping(m) => {
+                !m.contains_key("image") && !m.contains_key("build") && !m.contains_key("extends")
+            }
+            _ => false,
+        };
+        if is_empty {
+            violations.push(hollow(
+                &file,
+                line,
+                format!("service '{}' has no image or build; it defines nothing to run", name),
+            ));
+            continue;
+        }
+
+        // Placeholder images; a service with a build section names its own image
+        if let (Some(image), None) = (
+            service.get("image").and_then(Value::as_str),
+            service.get("build"),
+        ) {
+            let image_line = find_line(source, line, "image:");
+            if PLACEHOLDER_IMAGE.is_match(image) {
+                violations.push(
+                    hollow(
+                        &file,
+                        image_line,
+                        format!("service '{}' uses placeholder image '{}'", name, image),
+                    )
+                    .with_severity(severities.placeholder),
+                );
+            } else if let Some(signature) = signatures.iter().find(|s| s.regex.is_match(image)) {
+                violations.push(mock_data(&file, image_line, signature.message()));
+            } else if !image.contains('$') && is_unpinned(image) {
+                violations.push(
+                    hollow(
+                        &file,
+                        image_line,
+                        format!(
+                            "service '{}' image '{}' resolves to latest; pin a version tag or digest",
+                            name, image
+                        ),
+                    )
+                    .with_severity(severities.latest),
+                );
+            }
+        }
+
+        // Environment values, as a mapping or a list of KEY=VALUE
+        let env: Vec<(String, String)> = match service.get("environment") {
+            Some(Value::Mapping(m)) => m
+                .iter()
+                .filter_map(|(k, v)| Some((scalar(k)?, scalar(v)?)))
+                .collect(),
+            Some(Value::Sequence(items)) => items
+                .iter()
+                .filter_map(|item| {
+                    let (k, v) = item.as_str()?.split_once('=')?;
+                    Some((k.to_string(), v.to_string()))
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        for (key, value) in env {
+            for signature in signatures {
+                if signature.regex.is_match(&value) {
+                    violations.push(mock_data(&file, find_line(source, line, &key), signature.message()));
+                }
+            }
+        }
+
+        // Healthchecks that always pass
+        let test = service.get("healthcheck").and_then(|h| h.get("test"));
+        let words: Vec<String> = match test {
+            Some(Value::String(s)) => s.split_whitespace().map(String::from).collect(),
+            Some(Value::Sequence(items)) => {
+                let items: Vec<String> = items.iter().filter_map(scalar).collect();
+                match items.first().map(String::as_str) {
+                    Some("CMD") => items[1..].to_vec(),
+                    Some("CMD-SHELL") => items[1..].join(" ").split_whitespace().map(String::from).collect(),
+                    _ => Vec::new(),
+                }
+            }
+            _ => Vec::new(),
+        };
+        if is_noop_command(&words) {
+            violations.push(hollow(
+                &file,
+                find_line(source, line, "healthcheck:"),
+                format!("service '{}' healthcheck runs '{}', which always passes", name, words.join(" ")),
+            ));
+        }
+    }
+
+    violations
+}
+
+/// Check Dockerfiles and docker-compose files for hollow or placeholder
+/// infrastructure. Other files are ignored.
+pub fn detect_hollow_containers<P: AsRef<Path>>(
+    files: &[P],
+    config: &ContainerChecksConfig,
+    mocks: Option<&MockSignaturesConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let severities = InfraSeverities::parse(
+        "containers",
+        config.latest_tag_severity(),
+        config.placeholder_severity(),
+        config.todo_severity(),
+    )?;
+    let signatures = match mocks {
+        Some(cfg) => compile_mock_signatures(cfg)?,
+        None => Vec::new(),
+    };
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let dockerfile = is_dockerfile(path);
+        if !dockerfile && !is_compose_file(path) {
+            continue;
+        }
+        let Ok(source) = fs::read_to_string(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        let violations = if dockerfile {
+            check_dockerfile(path, &source, severities, &signatures)
+        } else {
+            check_compose(path, &source, severities, &signatures)
+        };
+        for violation in violations {
+            result.add_violation(violation);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &ContainerChecksConfig) -> Vec<Violation> {
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+        let mocks = crate::contract::Contract::default_contract().mock_signatures;
+        detect_hollow_containers(&paths, config, mocks.as_ref())
+            .unwrap()
+            .violations
+    }
+
+    #[test]
+    fn test_hollow_dockerfile() {
+        let dockerfile = r#"FROM node:latest AS build
+WORKDIR /app
+COPY . .
+ENV API_KEY=CHANGEME NODE_ENV=production
+
+FROM build
+EXPOSE 8080
+HEALTHCHECK --interval=30s CMD exit 0
+"#;
+        let violations = detect(&[("Dockerfile", dockerfile)], &ContainerChecksConfig::default());
+        let found: Vec<_> = violations
+            .iter()
+            .map(|v| (v.line, v.rule, v.severity))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, ViolationRule::HollowContainer, Severity::Warning),
+                (3, ViolationRule::HollowContainer, Severity::Warning),
+                (8, ViolationRule::HollowContainer, Severity::Error),
+                (7, ViolationRule::HollowContainer, Severity::Error),
+                (4, ViolationRule::MockData, Severity::Warning),
+            ],
+            "{:?}",
+            violations
+        );
+        assert!(violations[3].message.contains("node default command"));
+
+        // Echo-only commands, and the latest severity from the contract
+        let config = ContainerChecksConfig {
+            latest_tag_severity: Some("error".to_string()),
+            ..ContainerChecksConfig::default()
+        };
+        let violations = detect(
+            &[
+                ("Dockerfile", "FROM alpine\nCMD [\"sh\", \"-c\", \"echo TODO && sleep infinity\"]\n"),
+                (".dockerignore", ".git\n"),
+            ],
+            &config,
+        );
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert!(violations[1].message.starts_with("CMD only runs"));
+    }
+
+    #[test]
+    fn test_minimal_dockerfiles_pass() {
+        let scratch = "FROM golang:1.22 AS build\nCOPY . .\nRUN go build -o /server\n\nFROM scratch\nCOPY --from=build /server /server\nEXPOSE 8080\nENTRYPOINT [\"/server\"]\n";
+        let distroless = "FROM gcr.io/distroless/static:nonroot\nCOPY server /server\nEXPOSE 8080\nCMD [\"/server\"]\n";
+        let violations = detect(
+            &[
+                ("Dockerfile", scratch),
+                ("Dockerfile.distroless", distroless),
+                (".dockerignore", ".git\n"),
+            ],
+            &ContainerChecksConfig::default(),
+        );
+        assert!(violations.is_empty(), "{:?}", violations);
+    }
+
+    #[test]
+    fn test_compose_checks() {
+        let compose = r#"services:
+  api:
+    image: your-registry/api:1.0
+    environment:
+      - DATABASE_URL=postgres://db/app
+      - SECRET_KEY=CHANGEME
+  worker: {}
+  web:
+    build: .
+    image: myapp:latest
+    healthcheck:
+      test: ["CMD-SHELL", "exit 0"]
+  db:
+    image: postgres:16
+    environment:
+      POSTGRES_PASSWORD: REPLACE_ME
+"#;
+        let violations = detect(&[("docker-compose.yml", compose)], &ContainerChecksConfig::default());
+        let found: Vec<_> = violations.iter().map(|v| (v.line, v.rule)).collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, ViolationRule::HollowContainer),
+                (6, ViolationRule::MockData),
+                (7, ViolationRule::HollowContainer),
+                (11, ViolationRule::HollowContainer),
+                (16, ViolationRule::MockData),
+            ],
+            "{:?}",
+            violations
+        );
+        assert!(violations[2].message.contains("'worker' has no image or build"));
+    }
+
+    #[test]
+    fn test_placeholder_images_and_todo_steps() {
+        let dockerfile = "FROM your-registry-here/base:1.0\nRUN echo \"TODO\"\nRUN echo building && make\n# TODO: add a non-root user\nCMD [\"/app\"]\n";
+        let compose = "services:\n  cache:\n    image: redis\n";
+        let config = ContainerChecksConfig {
+            placeholder_severity: Some("critical".to_string()),
+            ..ContainerChecksConfig::default()
+        };
+        let violations = detect(&[("Dockerfile", dockerfile), ("compose.yaml", compose)], &config);
+        let found: Vec<_> = violations
+            .iter()
+            .map(|v| (v.line, v.severity, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (1, Severity::Critical, "base image 'your-registry-here/base:1.0' is a placeholder"),
+                (2, Severity::Warning, "RUN only runs 'echo \"TODO\"'; the build step was never written"),
+                (4, Severity::Warning, "TODO marker left in the Dockerfile: 'TODO: add a non-root user'"),
+                (
+                    3,
+                    Severity::Warning,
+                    "service 'cache' image 'redis' resolves to latest; pin a version tag or digest"
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,152 @@
+//! Detection of Go `context.Context` parameters that are never used.
+//!
+//! Idiomatic Go threads a `ctx context.Context` through each call chain. A
+//! function that accepts one but never passes it on, or checks `ctx.Done()`
+//! or `ctx.Err()`, ignores cancellation and deadlines; in generated service
+//! code that usually means the body was never really written. Parameters
+//! come from `FileFacts::unused_parameters`, read from the `FactsBundle`.
+//! Naming the parameter `_` marks it as intentionally unused.
+
+use std::path::Path;
+
+use crate::analysis::FileFacts;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Import path of the standard context package.
+const CONTEXT_PACKAGE: &str = "context";
+
+/// Names the file uses for the context package: `context`, or its alias.
+fn context_qualifiers(facts: &FileFacts) -> Vec<&str> {
+    facts
+        .imports
+        .iter()
+        .filter(|import| import.path == CONTEXT_PACKAGE)
+        .map(|import| import.alias.as_deref().unwrap_or(CONTEXT_PACKAGE))
+        .filter(|name| *name != "_")
+        .collect()
+}
+
+/// Flag Go functions whose `context.Context` parameter is never referenced.
+pub fn detect_unused_context<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if file_facts.language != "go" {
+            continue;
+        }
+        result.scanned += 1;
+
+        let qualifiers = context_qualifiers(file_facts);
+        for param in &file_facts.unused_parameters {
+            let is_context = param
+                .type_text
+                .split_once('.')
+                .is_some_and(|(pkg, name)| name == "Context" && qualifiers.contains(&pkg));
+            if !is_context {
+                continue;
+            }
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::UnusedContext,
+                    format!(
+                        "{} accepts {} {} but never uses it; cancellation and deadlines are ignored",
+                        param.function, param.name, param.type_text
+                    ),
+                )
+                .at(path.to_string_lossy(), param.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str) -> Vec<(usize, String)> {
+        test_support::detect(name, source, ViolationRule::UnusedContext, detect_unused_context)
+    }
+
+    #[test]
+    fn test_go_unused_context() {
+        let source = r#"package svc
+
+import "context"
+
+func (s *Service) Sync(ctx context.Context, id string) error {
+	return s.store.Save(id)
+}
+
+func (s *Service) Load(ctx context.Context, id string) error {
+	return s.store.Load(ctx, id)
+}
+
+func Wait(ctx context.Context) {
+	<-ctx.Done()
+}
+
+func Handle(_ context.Context, n int) int {
+	return n
+}
+
+func Other(ctx other.Context) {}
+"#;
+        let found = detect("svc.go", source);
+        assert_eq!(
+            found,
+            vec![(
+                5,
+                "Sync accepts ctx context.Context but never uses it; cancellation and deadlines are ignored"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_aliased_context_import() {
+        let source = r#"package svc
+
+import stdctx "context"
+
+func Poll(c stdctx.Context, n int) int {
+	return n
+}
+"#;
+        let found = detect("poll.go", source);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 5);
+        assert!(found[0].1.starts_with("Poll accepts c stdctx.Context"));
+    }
+
+    #[test]
+    fn test_closure_capture_counts_as_use() {
+        let source = r#"package svc
+
+import "context"
+
+func Start(ctx context.Context) {
+	go func() {
+		select {
+		case <-ctx.Done():
+		}
+	}()
+}
+"#;
+        assert!(detect("start.go", source).is_empty());
+    }
+}
@@ -0,0 +1,653 @@
+//! Detection of known-weak cryptography.
+//!
+//! MD5 and SHA-1 digests, DES and RC4 ciphers, ECB mode, and IVs or salts
+//! written into the source all compile, pass tests, and look like security
+//! code in review. Each language lists the calls that select a weak
+//! primitive, and a pattern only applies to files that import its module,
+//! so a project's own `md5()` helper or `Cipher` class is not flagged. Only
+//! files with such an import are parsed.
+
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use once_cell::sync::Lazy;
+use rayon::prelude::*;
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::analysis::{analysis_extension, get_analyzer, FileFacts, ParsedFile};
+use crate::contract::WeakCryptoConfig;
+
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
+
+/// Call node kinds across languages; each holds its arguments in `arguments`.
+const CALL_KINDS: &[&str] = &[
+    "call",                       // Python
+    "call_expression",            // Go, JS/TS
+    "new_expression",             // JS/TS
+    "method_invocation",          // Java
+    "object_creation_expression", // Java
+];
+
+/// A kind of weak cryptography, named as in the contract's toggles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Weakness {
+    Hash,
+    Cipher,
+    Ecb,
+    FixedIv,
+    FixedSalt,
+}
+
+impl Weakness {
+    /// Contract toggle for this kind.
+    fn toggle(self) -> &'static str {
+        match self {
+            Weakness::Hash => "hashes",
+            Weakness::Cipher => "ciphers",
+            Weakness::Ecb => "ecb_mode",
+            Weakness::FixedIv => "fixed_ivs",
+            Weakness::FixedSalt => "fixed_salts",
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Weakness::Hash => "weak hash",
+            Weakness::Cipher => "weak cipher",
+            Weakness::Ecb => "ECB mode",
+            Weakness::FixedIv => "hardcoded IV",
+            Weakness::FixedSalt => "hardcoded salt",
+        }
+    }
+
+    fn advice(self) -> &'static str {
+        match self {
+            Weakness::Hash => "MD5 and SHA-1 collisions are practical; use SHA-256, or a password KDF for secrets",
+            Weakness::Cipher => "DES, 3DES, RC4, and Blowfish are broken or deprecated; use AES-GCM or ChaCha20-Poly1305",
+            Weakness::Ecb => "identical plaintext blocks encrypt identically; use an authenticated mode such as GCM",
+            Weakness::FixedIv => "a reused IV leaks plaintext; generate a random one per message",
+            Weakness::FixedSalt => "a fixed salt allows precomputed attacks; generate one per secret",
+        }
+    }
+}
+
+/// How a call's arguments select the weakness.
+#[derive(Debug, Clone, Copy)]
+enum Arguments {
+    /// Every call is weak.
+    Any,
+    /// Some argument matches the regex (an algorithm name or mode constant).
+    Matching(&'static str),
+    /// The positional argument at the index, or the keyword argument with
+    /// the name, is a literal.
+    LiteralAt(usize, &'static str),
+}
+
+/// A call that selects a weak primitive.
+struct WeakPattern {
+    languages: &'static [&'static str],
+    /// Modules one of which must be imported; submodules count.
+    modules: &'static [&'static str],
+    /// Regex for the callee as written, matched after an optional qualifier.
+    callee: &'static str,
+    arguments: Arguments,
+    weakness: Weakness,
+}
+
+const PYTHON: &[&str] = &["python"];
+const GO: &[&str] = &["go"];
+const JAVA: &[&str] = &["java"];
+const JS: &[&str] = &["javascript", "typescript"];
+
+const PYCRYPTODOME: &[&str] = &["Crypto", "Cryptodome"];
+const NODE_CRYPTO: &[&str] = &["crypto", "node:crypto"];
+
+/// Weak calls per language. The first match of a call is reported.
+const PATTERNS: &[WeakPattern] = &[
+    // Python: hashlib, pycryptodome, cryptography
+    WeakPattern {
+        languages: PYTHON,
+        modules: &["hashlib"],
+        callee: r"(md5|sha1)",
+        arguments: Arguments::Any,
+        weakness: Weakness::Hash,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: &["hashlib"],
+        callee: r"hashlib\.new",
+        arguments: Arguments::Matching(r#"^[bru]*["'](?i:md4|md5|sha1)["']$"#),
+        weakness: Weakness::Hash,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: &["hashlib"],
+        callee: r"pbkdf2_hmac",
+        arguments: Arguments::LiteralAt(2, "salt"),
+        weakness: Weakness::FixedSalt,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: PYCRYPTODOME,
+        callee: r"(DES|DES3|ARC2|ARC4|Blowfish|CAST)\.new",
+        arguments: Arguments::Any,
+        weakness: Weakness::Cipher,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: PYCRYPTODOME,
+        callee: r"\w+\.new",
+        arguments: Arguments::Matching(r"\bMODE_ECB$"),
+        weakness: Weakness::Ecb,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: PYCRYPTODOME,
+        callee: r"\w+\.new",
+        arguments: Arguments::LiteralAt(2, "iv"),
+        weakness: Weakness::FixedIv,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: &["cryptography"],
+        callee: r"(TripleDES|ARC4|Blowfish|CAST5|IDEA|SEED)",
+        arguments: Arguments::Any,
+        weakness: Weakness::Cipher,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: &["cryptography"],
+        callee: r"ECB",
+        arguments: Arguments::Any,
+        weakness: Weakness::Ecb,
+    },
+    WeakPattern {
+        languages: PYTHON,
+        modules: &["cryptography"],
+        callee: r"(CBC|CFB|CFB8|OFB|GCM)",
+        arguments: Arguments::LiteralAt(0, "initialization_vector"),
+        weakness: Weakness::FixedIv,
+    },
+    // Go: standard library and x/crypto
+    WeakPattern {
+        languages: GO,
+        modules: &["crypto/md5"],
+        callee: r"md5\.(New|Sum)",
+        arguments: Arguments::Any,
+        weakness: Weakness::Hash,
+    },
+    WeakPattern {
+        languages: GO,
+        modules: &["crypto/sha1"],
+        callee: r"sha1\.(New|Sum)",
+        arguments: Arguments::Any,
+        weakness: Weakness::Hash,
+    },
+    WeakPattern {
+        languages: GO,
+        modules: &["crypto/des"],
+        callee: r"des\.(NewCipher|NewTripleDESCipher)",
+        arguments: Arguments::Any,
+        weakness: Weakness::Cipher,
+    },
+    WeakPattern {
+        languages: GO,
+        modules: &["crypto/rc4"],
+        callee: r"rc4\.NewCipher",
+        arguments: Arguments::Any,
+        weakness: Weakness::Cipher,
+    },
+    WeakPattern {
+        languages: GO,
+        modules: &["crypto/cipher"],
+        callee: r"cipher\.(NewCBCEncrypter|NewCBCDecrypter|NewCFBEncrypter|NewCFBDecrypter|NewCTR|NewOFB)",
+        arguments: Arguments::LiteralAt(1, ""),
+        weakness: Weakness::FixedIv,
+    },
+    WeakPattern {
+        languages: GO,
+        modules: &["golang.org/x/crypto/pbkdf2", "golang.org/x/crypto/scrypt", "golang.org/x/crypto/argon2"],
+        callee: r"(pbkdf2\.Key|scrypt\.Key|argon2\.Key|argon2\.IDKey)",
+        arguments: Arguments::LiteralAt(1, ""),
+        weakness: Weakness::FixedSalt,
+    },
+    // Java: JCA
+    WeakPattern {
+        languages: JAVA,
+        modules: &["java.security"],
+        callee: r"MessageDigest\.getInstance",
+        arguments: Arguments::Matching(r#"^"(?i:md2|md5|sha-?1)"$"#),
+        weakness: Weakness::Hash,
+    },
+    WeakPattern {
+        languages: JAVA,
+        modules: &["javax.crypto"],
+        callee: r"Cipher\.getInstance",
+        arguments: Arguments::Matching(r#"^"(?i:des|desede|tripledes|rc2|rc4|arcfour|blowfish)(/[^"]*)?"$"#),
+        weakness: Weakness::Cipher,
+    },
+    WeakPattern {
+        languages: JAVA,
+        modules: &["javax.crypto"],
+        // A bare "AES" selects the provider default, which is ECB
+        callee: r"Cipher\.getInstance",
+        arguments: Arguments::Matching(r#"^"(?i:\w+/ECB/[^"]*|aes)"$"#),
+        weakness: Weakness::Ecb,
+    },
+    WeakPattern {
+        languages: JAVA,
+        modules: &["javax.crypto"],
+        callee: r"IvParameterSpec",
+        arguments: Arguments::LiteralAt(0, ""),
+        weakness: Weakness::FixedIv,
+    },
+    WeakPattern {
+        languages: JAVA,
+        modules: &["javax.crypto"],
+        callee: r"GCMParameterSpec",
+        arguments: Arguments::LiteralAt(1, ""),
+        weakness: Weakness::FixedIv,
+    },
+    WeakPattern {
+        languages: JAVA,
+        modules: &["javax.crypto"],
+        callee: r"PBEKeySpec",
+        arguments: Arguments::LiteralAt(1, ""),
+        weakness: Weakness::FixedSalt,
+    },
+    // JavaScript and TypeScript: node:crypto
+    WeakPattern {
+        languages: JS,
+        modules: NODE_CRYPTO,
+        callee: r"(createHash|createHmac)",
+        arguments: Arguments::Matching(r#"^["'`](?i:md4|md5|sha1)["'`]$"#),
+        weakness: Weakness::Hash,
+    },
+    WeakPattern {
+        languages: JS,
+        modules: NODE_CRYPTO,
+        callee: r"(createCipheriv|createDecipheriv|createCipher|createDecipher)",
+        arguments: Arguments::Matching(r#"^["'`](?i:(des|des3|rc2|rc4|bf|blowfish|cast5)(-[a-z0-9]+)*)["'`]$"#),
+        weakness: Weakness::Cipher,
+    },
+    WeakPattern {
+        languages: JS,
+        modules: NODE_CRYPTO,
+        callee: r"(createCipheriv|createDecipheriv)",
+        arguments: Arguments::Matching(r#"^["'`](?i:[a-z0-9-]+-ecb)["'`]$"#),
+        weakness: Weakness::Ecb,
+    },
+    WeakPattern {
+        languages: JS,
+        modules: NODE_CRYPTO,
+        callee: r"(createCipheriv|createDecipheriv)",
+        arguments: Arguments::LiteralAt(2, ""),
+        weakness: Weakness::FixedIv,
+    },
+    WeakPattern {
+        languages: JS,
+        modules: NODE_CRYPTO,
+        callee: r"(pbkdf2|pbkdf2Sync|scrypt|scryptSync)",
+        arguments: Arguments::LiteralAt(1, ""),
+        weakness: Weakness::FixedSalt,
+    },
+];
+
+/// Arguments that are fixed bytes: string and bytes literals, zero-filled
+/// buffers, and byte array literals.
+static FIXED_BYTES: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(concat!(
+        r#"^(?:[bBrRuU]{0,2}["'`]"#,             // string and bytes literals
+        r#"|bytes\(\s*\d+\s*\)|bytearray\(\s*\d+\s*\)"#, // Python zero-filled
+        r#"|Buffer\.(?:alloc|from)\(\s*[\d"'`\[]"#, // Node buffers
+        r#"|new\s+Uint8Array\(\s*[\d\[]"#,
+        r#"|new\s+byte\s*\[\s*\d*\s*\]"#,          // Java arrays
+        r#"|"[^"]*"\s*\.getBytes\("#,
+        r#"|\[\]byte\s*(?:\(\s*["`]|\{)"#,         // Go conversions and literals
+        r#"|make\(\s*\[\]byte\s*,\s*\d+\s*\))"#,
+    ))
+    .unwrap()
+});
+
+/// A pattern with its regexes compiled.
+struct CompiledPattern {
+    pattern: &'static WeakPattern,
+    callee: Regex,
+    argument: Option<Regex>,
+}
+
+static COMPILED: Lazy<Vec<CompiledPattern>> = Lazy::new(|| {
+    PATTERNS
+        .iter()
+        .map(|pattern| CompiledPattern {
+            pattern,
+            callee: Regex::new(&format!(r"^(?:[\w.]+\.)?{}$", pattern.callee)).unwrap(),
+            argument: match pattern.arguments {
+                Arguments::Matching(re) => Some(Regex::new(re).unwrap()),
+                _ => None,
+            },
+        })
+        .collect()
+});
+
+/// Whether the file imports `module` or one of its submodules.
+fn imports_module(facts: &FileFacts, module: &str) -> bool {
+    facts.imports.iter().any(|import| {
+        import.path == module
+            || import
+                .path
+                .strip_prefix(module)
+                .is_some_and(|rest| rest.starts_with('.') || rest.starts_with('/'))
+    })
+}
+
+/// An argument as written, with its keyword for Python keyword arguments.
+struct Argument<'a> {
+    keyword: Option<&'a str>,
+    text: &'a str,
+}
+
+/// The callee text of a call (`hashlib.md5`, `Cipher.getInstance`,
+/// `IvParameterSpec`) and its arguments.
+fn call_parts<'a>(parsed: &'a ParsedFile, call: Node) -> Option<(String, Vec<Argument<'a>>)> {
+    let args = call.child_by_field_name("arguments")?;
+    let source = parsed.source_str();
+    let callee: String = source[call.start_byte()..args.start_byte()]
+        .trim_start_matches("new ")
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    let mut cursor = args.walk();
+    let arguments = args
+        .named_children(&mut cursor)
+        .filter(|arg| !arg.kind().contains("comment"))
+        .map(|arg| match (arg.kind(), arg.child_by_field_name("value")) {
+            ("keyword_argument", Some(value)) => Argument {
+                keyword: arg.child_by_field_name("name").map(|n| parsed.node_text(n)),
+                text: parsed.node_text(value),
+            },
+            _ => Argument {
+                keyword: None,
+                text: parsed.node_text(arg),
+            },
+        })
+        .collect();
+    Some((callee, arguments))
+}
+
+/// Shorten an argument for a message.
+fn excerpt(text: &str) -> String {
+    let line = text.lines().next().unwrap_or("");
+    if line.len() > 40 || line.len() < text.len() {
+        let end = (0..=40.min(line.len())).rev().find(|&i| line.is_char_boundary(i)).unwrap_or(0);
+        format!("{}...", &line[..end])
+    } else {
+        line.to_string()
+    }
+}
+
+/// The evidence for a call matching `compiled`, or None if it does not.
+fn match_call(compiled: &CompiledPattern, callee: &str, arguments: &[Argument]) -> Option<String> {
+    if !compiled.callee.is_match(callee) {
+        return None;
+    }
+    match compiled.pattern.arguments {
+        Arguments::Any => Some(format!("{}()", callee)),
+        Arguments::Matching(_) => {
+            let re = compiled.argument.as_ref()?;
+            arguments
+                .iter()
+                .find(|a| re.is_match(a.text))
+                .map(|a| format!("{}({})", callee, excerpt(a.text)))
+        }
+        Arguments::LiteralAt(index, keyword) => {
+            let arg = arguments
+                .iter()
+                .find(|a| !keyword.is_empty() && a.keyword == Some(keyword))
+                .or_else(|| arguments.iter().filter(|a| a.keyword.is_none()).nth(index))?;
+            FIXED_BYTES
+                .is_match(arg.text)
+                .then(|| format!("{} passed to {}()", excerpt(arg.text), callee))
+        }
+    }
+}
+
+/// Find weak cryptography calls in a single parsed file.
+fn check_file(
+    parsed: &ParsedFile,
+    path: &str,
+    patterns: &[&CompiledPattern],
+    severity: Severity,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let mut stack = vec![parsed.tree.root_node()];
+    while let Some(node) = stack.pop() {
+        let mut cursor = node.walk();
+        stack.extend(node.named_children(&mut cursor));
+
+        if !CALL_KINDS.contains(&node.kind()) {
+            continue;
+        }
+        let Some((callee, arguments)) = call_parts(parsed, node) else {
+            continue;
+        };
+        // Python digests declared as not used for security
+        if arguments
+            .iter()
+            .any(|a| a.keyword == Some("usedforsecurity") && a.text == "False")
+        {
+            continue;
+        }
+
+        let found = patterns
+            .iter()
+            .find_map(|p| match_call(p, &callee, &arguments).map(|evidence| (p.pattern.weakness, evidence)));
+        if let Some((weakness, evidence)) = found {
+            violations.push(Violation {
+                rule: ViolationRule::WeakCrypto,
+                message: format!("{} ({}): {}", weakness.label(), evidence, weakness.advice()),
+                file: Some(path.to_string()),
+                line: node.start_position().row + 1,
+                severity,
+            });
+        }
+    }
+    violations.sort_by_key(|v| v.line);
+    violations
+}
+
+/// Detect weak hashes and ciphers, ECB mode, and hardcoded IVs and salts.
+///
+/// Files are processed in parallel. A pattern applies only when the file
+/// imports its module; files importing none are not parsed.
+pub fn detect_weak_crypto<P: AsRef<Path> + Sync>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &WeakCryptoConfig,
+) -> anyhow::Result<DetectionResult> {
+    let severity = config
+        .severity()
+        .parse::<Severity>()
+        .map_err(|e| anyhow::anyhow!("invalid weak_crypto severity: {}", e))?;
+    let enabled: Vec<&CompiledPattern> = COMPILED
+        .iter()
+        .filter(|p| config.checks(p.pattern.weakness.toggle()))
+        .collect();
+    let scanned = AtomicUsize::new(0);
+
+    let file_results: Vec<Vec<Violation>> = files
+        .par_iter()
+        .filter_map(|file| {
+            let path = file.as_ref();
+            let file_facts = facts.file(path)?.ok()?;
+            scanned.fetch_add(1, Ordering::Relaxed);
+
+            let applicable: Vec<&CompiledPattern> = enabled
+                .iter()
+                .copied()
+                .filter(|p| p.pattern.languages.contains(&file_facts.language.as_str()))
+                .filter(|p| p.pattern.modules.iter().any(|m| imports_module(file_facts, m)))
+                .collect();
+            if applicable.is_empty() {
+                return None;
+            }
+
+            let ext = analysis_extension(path);
+            let analyzer = get_analyzer(ext)?;
+            let source = std::fs::read(path).ok()?;
+            let parsed = analyzer.parse(path, &source).ok()?;
+            Some(check_file(&parsed, &path.to_string_lossy(), &applicable, severity))
+        })
+        .collect();
+
+    let mut result = DetectionResult::new();
+    result.scanned = scanned.load(Ordering::Relaxed);
+    for violations in file_results {
+        for v in violations {
+            result.add_violation(v);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str, config: &WeakCryptoConfig) -> Vec<Violation> {
+        test_support::violations(name, source, ViolationRule::WeakCrypto, |facts, files| {
+            detect_weak_crypto(facts, files, config)
+        })
+    }
+
+    fn summary(violations: &[Violation]) -> Vec<String> {
+        violations
+            .iter()
+            .map(|v| format!("{}: {}", v.line, v.message.split(':').next().unwrap_or("")))
+            .collect()
+    }
+
+    #[test]
+    fn test_python_hashlib_and_ciphers() {
+        let violations = detect(
+            "auth.py",
+            r#"import hashlib
+from Crypto.Cipher import AES, DES
+
+def hash_password(password):
+    return hashlib.md5(password.encode()).hexdigest()
+
+def etag(body):
+    return hashlib.md5(body, usedforsecurity=False).hexdigest()
+
+def derive(password):
+    return hashlib.pbkdf2_hmac("sha256", password, b"static-salt", 100000)
+
+def encrypt(key, data):
+    legacy = DES.new(key, DES.MODE_CBC)
+    cipher = AES.new(key, AES.MODE_ECB)
+    cbc = AES.new(key, AES.MODE_CBC, iv=b"\x00" * 16)
+    return hashlib.sha256(data).digest()
+"#,
+            &WeakCryptoConfig::default(),
+        );
+
+        assert_eq!(
+            summary(&violations),
+            [
+                "5: weak hash (hashlib.md5())",
+                "11: hardcoded salt (b\"static-salt\" passed to hashlib.pbkdf2_hmac())",
+                "14: weak cipher (DES.new())",
+                "15: ECB mode (AES.new(AES.MODE_ECB))",
+                "16: hardcoded IV (b\"\\x00\" * 16 passed to AES.new())",
+            ]
+        );
+        assert!(violations.iter().all(|v| v.rule == ViolationRule::WeakCrypto));
+        assert!(violations.iter().all(|v| v.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_go_java_and_node() {
+        let go = detect(
+            "hash.go",
+            r#"package auth
+
+import (
+	"crypto/cipher"
+	"crypto/md5"
+	"crypto/sha256"
+)
+
+func Fingerprint(b []byte) [16]byte { return md5.Sum(b) }
+
+func Digest(b []byte) [32]byte { return sha256.Sum256(b) }
+
+func Encrypter(block cipher.Block) cipher.BlockMode {
+	return cipher.NewCBCEncrypter(block, make([]byte, 16))
+}
+"#,
+            &WeakCryptoConfig::default(),
+        );
+        assert_eq!(
+            summary(&go),
+            ["9: weak hash (md5.Sum())", "14: hardcoded IV (make([]byte, 16) passed to cipher.NewCBCEncrypter())"]
+        );
+
+        let java = detect(
+            "Crypto.java",
+            r#"import java.security.MessageDigest;
+import javax.crypto.Cipher;
+import javax.crypto.spec.IvParameterSpec;
+
+class Crypto {
+    byte[] hash(byte[] data) throws Exception {
+        return MessageDigest.getInstance("MD5").digest(data);
+    }
+    Cipher cipher() throws Exception {
+        Cipher c = Cipher.getInstance("AES/ECB/PKCS5Padding");
+        Cipher d = Cipher.getInstance("DESede/CBC/PKCS5Padding");
+        IvParameterSpec iv = new IvParameterSpec(new byte[16]);
+        return Cipher.getInstance("AES/GCM/NoPadding");
+    }
+}
+"#,
+            &WeakCryptoConfig::default(),
+        );
+        assert_eq!(
+            summary(&java),
+            [
+                "7: weak hash (MessageDigest.getInstance(\"MD5\"))",
+                "10: ECB mode (Cipher.getInstance(\"AES/ECB/PKCS5Padding\"))",
+                "11: weak cipher (Cipher.getInstance(\"DESede/CBC/PKCS5Padding\"))",
+                "12: hardcoded IV (new byte[16] passed to IvParameterSpec())",
+            ]
+        );
+
+        // Without the import, a project's own createHash is not node:crypto
+        let source = "const token = createHash('md5').update(secret).digest('hex');\n";
+        assert!(detect("token.js", source, &WeakCryptoConfig::default()).is_empty());
+        let node = detect(
+            "token.js",
+            &format!("const {{ createHash }} = require('crypto');\n{}", source),
+            &WeakCryptoConfig::default(),
+        );
+        assert_eq!(summary(&node), ["2: weak hash (createHash('md5'))"]);
+    }
+
+    #[test]
+    fn test_toggles_and_severity() {
+        let source = "import hashlib\nh = hashlib.sha1(b'x')\nk = hashlib.pbkdf2_hmac('sha256', b'pw', b'salt', 1000)\n";
+        let config = WeakCryptoConfig {
+            hashes: Some(false),
+            severity: Some("error".to_string()),
+            ..WeakCryptoConfig::default()
+        };
+        let violations = detect("keys.py", source, &config);
+        assert_eq!(summary(&violations), ["3: hardcoded salt (b'salt' passed to hashlib.pbkdf2_hmac())"]);
+        assert_eq!(violations[0].severity, Severity::Error);
+    }
+}
@@ -0,0 +1,258 @@
+//! Detection of C and C++ header functions that are never defined.
+//!
+//! A common shape of hollow C code is a rich header, declaring every function
+//! the spec asked for with full documentation, in front of a source file that
+//! defines three of them. Declarations come from `FileFacts::function_signatures`
+//! of each header; definitions come from the project-wide definition index, so
+//! a function defined in any scanned file (including `static inline` ones in
+//! the header itself) counts.
+//!
+//! Declarations match definitions by name and parameter count. C has no
+//! overloading and `.h` headers may declare `f()` for `f(int)`, so in `.h`
+//! files the name alone is enough.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::analysis::{analysis_extension, FunctionSignature};
+use crate::contract::MissingDefinitionsConfig;
+
+use super::globs::build_globs;
+use super::{DefinitionLocation, DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Header file extensions whose declarations are checked.
+const HEADER_EXTENSIONS: &[&str] = &["h", "hh", "hpp"];
+
+/// Source file extensions of translation units.
+const TRANSLATION_UNIT_EXTENSIONS: &[&str] = &["c", "cc", "cpp", "cxx"];
+
+fn extension(path: &Path) -> &str {
+    analysis_extension(path)
+}
+
+/// Whether any definition satisfies a declaration.
+fn is_defined(sig: &FunctionSignature, definitions: &[DefinitionLocation], name_only: bool) -> bool {
+    definitions.iter().any(|d| {
+        name_only
+            || d.param_count.is_none()
+            || sig.param_count.is_none()
+            || d.param_count == sig.param_count
+    })
+}
+
+/// Flag header-declared C and C++ functions with no definition anywhere in the scan.
+///
+/// Nothing is flagged when the scan holds no translation units, since a
+/// header on its own cannot be checked. `extern` declarations whose name
+/// matches `external`, and any name matching `ignore`, are exempt.
+pub fn detect_missing_definitions<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &MissingDefinitionsConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let external = build_globs("missing_definitions external", &config.external)?;
+    let ignore = build_globs("missing_definitions ignore", &config.ignore)?;
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    let translation_units = sorted_files
+        .iter()
+        .filter(|path| TRANSLATION_UNIT_EXTENSIONS.contains(&extension(path)))
+        .filter(|path| matches!(facts.file(path), Some(Ok(_))))
+        .count();
+    if translation_units == 0 {
+        return Ok(result);
+    }
+
+    for path in sorted_files {
+        let ext = extension(path);
+        if !HEADER_EXTENSIONS.contains(&ext) {
+            continue;
+        }
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        let display = path.strip_prefix(facts.base_dir()).unwrap_or(path).display();
+        let mut reported = HashSet::new();
+        for sig in file_facts.function_signatures.iter().filter(|s| !s.is_definition) {
+            let qualified = sig.qualified_name();
+            if ignore.is_match(&sig.name) || ignore.is_match(&qualified) {
+                continue;
+            }
+            if sig.has_specifier("extern") && (external.is_match(&sig.name) || external.is_match(&qualified)) {
+                continue;
+            }
+            let definitions = facts.definitions_named(&sig.name);
+            if is_defined(sig, definitions, ext == "h") {
+                continue;
+            }
+            // Declared again under another #ifdef branch
+            if !reported.insert((qualified.clone(), sig.param_count)) {
+                continue;
+            }
+
+            let mut message = format!(
+                "function {} declared at {}:{}: no definition found in {} scanned translation unit{}",
+                qualified,
+                display,
+                sig.span.start_line,
+                translation_units,
+                if translation_units == 1 { "" } else { "s" }
+            );
+            if let (Some(count), Some(other)) = (sig.param_count, definitions.first()) {
+                message.push_str(&format!(
+                    " (declared with {} parameters; a definition at {}:{} takes {})",
+                    count,
+                    other.file.strip_prefix(facts.base_dir()).unwrap_or(&other.file).display(),
+                    other.line,
+                    other.param_count.map_or("?".to_string(), |c| c.to_string())
+                ));
+            }
+            result.add_violation(
+                Violation::new(ViolationRule::MissingDefinition, message)
+                    .at(path.to_string_lossy(), sig.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &MissingDefinitionsConfig) -> Vec<(String, usize, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::MissingDefinition]);
+        detect_missing_definitions(&facts, &paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                let file = Path::new(v.file.as_deref().unwrap()).file_name().unwrap().to_string_lossy().to_string();
+                (file, v.line, v.message)
+            })
+            .collect()
+    }
+
+    const DB_HEADER: &str = r#"#ifndef DB_H
+#define DB_H
+
+/* Open a database file. */
+int db_open(const char *path);
+/* Close the database. */
+void db_close(void);
+/* Run a query and return the row count. */
+int db_query(const char *sql, char **rows);
+/* Export rows as CSV. */
+int db_export_csv(const char *path);
+
+static inline int db_ok(int rc) { return rc == 0; }
+extern int sqlite3_open(const char *path, void **db);
+#ifdef _WIN32
+void db_lock_win(void);
+#endif
+
+#endif
+"#;
+
+    const DB_SOURCE: &str = r#"#include "db.h"
+
+int db_open(const char *path) {
+    return path != 0;
+}
+
+void db_close() {
+}
+
+int db_query(const char *sql) {
+    return 0;
+}
+"#;
+
+    #[test]
+    fn test_c_header_functions_without_definition() {
+        let found = detect(
+            &[("include/db.h", DB_HEADER), ("src/db.c", DB_SOURCE)],
+            &MissingDefinitionsConfig::default(),
+        );
+        let summary: Vec<_> = found.iter().map(|(f, l, _)| (f.as_str(), *l)).collect();
+        // db_query is matched by name in a C header despite its parameter count
+        assert_eq!(summary, vec![("db.h", 11), ("db.h", 14), ("db.h", 16)]);
+        assert_eq!(
+            found[0].2,
+            "function db_export_csv declared at include/db.h:11: no definition found in 1 scanned translation unit"
+        );
+    }
+
+    #[test]
+    fn test_external_and_ignored_names() {
+        let config = MissingDefinitionsConfig {
+            external: vec!["sqlite3_*".to_string()],
+            ignore: vec!["*_win".to_string()],
+            ..Default::default()
+        };
+        let found = detect(&[("include/db.h", DB_HEADER), ("src/db.c", DB_SOURCE)], &config);
+        let lines: Vec<_> = found.iter().map(|(_, line, _)| *line).collect();
+        assert_eq!(lines, vec![11]);
+    }
+
+    #[test]
+    fn test_header_only_scan_is_not_checked() {
+        let found = detect(&[("include/db.h", DB_HEADER)], &MissingDefinitionsConfig::default());
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_cpp_methods_match_by_parameter_count() {
+        let header = r#"class Cache {
+public:
+    Cache();
+    int get(int key);
+    int get(int key, int fallback);
+    void clear();
+    virtual void evict() = 0;
+};
+"#;
+        let source = r#"#include "cache.hpp"
+
+Cache::Cache() {}
+
+int Cache::get(int key) { return key; }
+
+void Cache::clear() {}
+"#;
+        let found = detect(
+            &[("cache.hpp", header), ("cache.cpp", source)],
+            &MissingDefinitionsConfig::default(),
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].1, 5);
+        assert_eq!(
+            found[0].2,
+            "function Cache::get declared at cache.hpp:5: no definition found in 1 scanned translation unit \
+             (declared with 2 parameters; a definition at cache.cpp:5 takes 1)"
+        );
+    }
+}
@@ -10,19 +10,52 @@
 //! 2. **PyPI Fallback**: For packages not covered by manifest, check if they
 //!    exist on PyPI. Only flags packages that truly don't exist anywhere.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+use crate::analysis::analysis_extension;
 use crate::contract::DependencyVerificationConfig;
-use crate::registry::{PackageStatus, RegistryClient, RegistryType};
+use crate::registry::{PackageStatus, RegistryClient, RegistryError, RegistryType};
+use crate::workspace::find_workspace;
 
-use super::imports::{extract_imports, ImportedDependency};
+use super::dependency_trace::{
+    AllowlistTrace, DependencyTrace, ManifestTrace, RegistryLookup, TraceVerdict,
+};
+use super::imports::extract_imports;
 use super::manifest::{
     detect_manifest_type, GoManifest, HomeAssistantManifest, ManifestProvider, ManifestType,
     NoManifest, PythonManifest,
 };
 use super::{DetectionResult, Severity, Violation, ViolationRule};
 
+/// Python imports whose PyPI package has an unrelated name, which no
+/// manifest heuristic matches. `import_overrides` in the contract wins.
+const PYPI_IMPORT_OVERRIDES: &[(&str, &str)] = &[
+    ("PIL", "pillow"),
+    ("cv2", "opencv-python"),
+    ("bs4", "beautifulsoup4"),
+    ("yaml", "PyYAML"),
+    ("sklearn", "scikit-learn"),
+    ("skimage", "scikit-image"),
+    ("dateutil", "python-dateutil"),
+    ("dotenv", "python-dotenv"),
+    ("jwt", "PyJWT"),
+    ("Crypto", "pycryptodome"),
+    ("OpenSSL", "pyOpenSSL"),
+    ("serial", "pyserial"),
+    ("usb", "pyusb"),
+    ("zmq", "pyzmq"),
+    ("fitz", "PyMuPDF"),
+    ("docx", "python-docx"),
+    ("pptx", "python-pptx"),
+    ("magic", "python-magic"),
+    ("MySQLdb", "mysqlclient"),
+    ("attr", "attrs"),
+    ("gi", "PyGObject"),
+    ("win32api", "pywin32"),
+    ("wx", "wxPython"),
+];
+
 /// Dependency validator using the trait-based manifest system.
 ///
 /// This struct combines manifest-based validation with PyPI fallback
@@ -32,11 +65,16 @@ pub struct DependencyValidator {
     manifest_type: ManifestType,
     /// The manifest provider (implements ManifestProvider trait)
     manifest: Box<dyn ManifestProvider>,
+    /// Directory the manifest was loaded from
+    manifest_root: PathBuf,
+    /// Whether the manifest came from the workspace root instead of the project
+    workspace_fallback: bool,
     /// Registry client for PyPI checking
     registry_client: RegistryClient,
     /// Local packages to auto-allowlist
-    #[allow(dead_code)]
     local_packages: Vec<String>,
+    /// Import names mapped to their package by the contract
+    import_overrides: HashMap<String, String>,
 }
 
 impl DependencyValidator {
@@ -46,9 +84,17 @@ impl DependencyValidator {
         project_root: &Path,
         config: &DependencyVerificationConfig,
     ) -> anyhow::Result<Self> {
-        let detected_type = match manifest_type {
-            ManifestType::Auto => detect_manifest_type(project_root),
-            other => other,
+        // A sub-project of a workspace may have no manifest of its own; fall
+        // back to the one at the workspace root
+        let workspace = find_workspace(project_root);
+        let (detected_type, manifest_root) = match manifest_type {
+            ManifestType::Auto => match (detect_manifest_type(project_root), &workspace) {
+                (ManifestType::None, Some(ws)) if ws.root != project_root => {
+                    (detect_manifest_type(&ws.root), ws.root.as_path())
+                }
+                (detected, _) => (detected, project_root),
+            },
+            other => (other, project_root),
         };
 
         let manifest: Box<dyn ManifestProvider> = match detected_type {
@@ -56,19 +102,19 @@ impl DependencyValidator {
                 if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
                     eprintln!("[debug] Detected Home Assistant project, loading component manifests...");
                 }
-                Box::new(HomeAssistantManifest::from_root(project_root)?)
+                Box::new(HomeAssistantManifest::from_root(manifest_root)?)
             }
             ManifestType::PythonStandard => {
                 if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
                     eprintln!("[debug] Detected Python project, loading manifests...");
                 }
-                Box::new(PythonManifest::from_root(project_root)?)
+                Box::new(PythonManifest::from_root(manifest_root)?)
             }
             ManifestType::Go => {
                 if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
                     eprintln!("[debug] Detected Go project, loading go.mod...");
                 }
-                Box::new(GoManifest::from_root(project_root)?)
+                Box::new(GoManifest::from_root(manifest_root)?)
             }
             ManifestType::None | ManifestType::Auto => {
                 if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
@@ -86,16 +132,38 @@ impl DependencyValidator {
             );
         }
 
-        // Detect local packages and extend allowlist
-        let local_packages = detect_local_packages(project_root);
+        // Detect local packages, including sibling workspace members, and extend allowlist
+        let mut local_packages = detect_local_packages(project_root);
+        if let Some(ws) = &workspace {
+            if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
+                eprintln!(
+                    "[debug] Workspace root ({}): {}",
+                    ws.kind.as_str(),
+                    ws.root.display()
+                );
+            }
+            local_packages.extend(detect_local_packages(&ws.root));
+            for member in ws.member_dirs() {
+                local_packages.extend(detect_local_packages(&member));
+            }
+            local_packages.sort();
+            local_packages.dedup();
+        }
         let mut extended_config = config.clone();
         extended_config.allowlist.extend(local_packages.clone());
 
         Ok(Self {
             manifest_type: detected_type,
             manifest,
+            workspace_fallback: manifest_root != project_root,
+            manifest_root: manifest_root.to_path_buf(),
             registry_client: RegistryClient::new(extended_config),
             local_packages,
+            import_overrides: config
+                .import_overrides
+                .iter()
+                .map(|(import, package)| (import.to_lowercase(), package.clone()))
+                .collect(),
         })
     }
 
@@ -104,9 +172,39 @@ impl DependencyValidator {
         &self.manifest_type
     }
 
+    /// The package an import comes from, if an override names one.
+    ///
+    /// The contract's `import_overrides` apply to every registry; the
+    /// built-in table only to PyPI imports. Names compare ignoring case.
+    pub fn package_for(&self, import_name: &str, registry: RegistryType) -> Option<&str> {
+        if let Some(package) = self.import_overrides.get(&import_name.to_lowercase()) {
+            return Some(package);
+        }
+        if registry != RegistryType::PyPI {
+            return None;
+        }
+        PYPI_IMPORT_OVERRIDES
+            .iter()
+            .find(|(import, _)| import.eq_ignore_ascii_case(import_name))
+            .map(|(_, package)| *package)
+    }
+
+    /// The package an import in `file_path` comes from, if an override names one.
+    fn override_in(&self, import_name: &str, file_path: &Path) -> Option<&str> {
+        let ext = analysis_extension(file_path);
+        let registry = RegistryType::from_extension(ext)?;
+        self.package_for(import_name, registry)
+    }
+
     /// Check if an import is valid according to the manifest.
+    ///
+    /// An overridden import is valid only if its package is declared; the
+    /// name heuristics are not tried on the import itself.
     pub fn is_valid_import(&self, import_name: &str, file_path: &Path) -> bool {
-        self.manifest.is_valid_import(import_name, file_path)
+        match self.override_in(import_name, file_path) {
+            Some(package) => self.manifest.is_valid_import(package, file_path),
+            None => self.manifest.is_valid_import(import_name, file_path),
+        }
     }
 
     /// Get the scope (component/module) a file belongs to.
@@ -132,8 +230,8 @@ impl DependencyValidator {
             return None;
         }
 
-        // Check manifest
-        if self.manifest.is_valid_import(import_name, file_path) {
+        // Check manifest, under the overridden package name if there is one
+        if self.is_valid_import(import_name, file_path) {
             return None;
         }
 
@@ -145,6 +243,69 @@ impl DependencyValidator {
     pub fn registry_client(&self) -> &RegistryClient {
         &self.registry_client
     }
+
+    /// Trace the allowlist and manifest steps of the pipeline for one import.
+    ///
+    /// The registry step is left to [`DependencyValidator::lookup_registry`];
+    /// until it runs, imports that need it have the `Unchecked` verdict.
+    pub fn trace_import(
+        &self,
+        import_name: &str,
+        file_path: &Path,
+        registry: RegistryType,
+    ) -> DependencyTrace {
+        let matched = self.registry_client.allowlist_match(import_name);
+        let package = self.package_for(import_name, registry);
+        let explanation = match matched {
+            Some(_) => None,
+            None => Some(self.manifest.explain_import(package.unwrap_or(import_name), file_path)),
+        };
+
+        let verdict = match &explanation {
+            None => TraceVerdict::Allowlisted,
+            Some(e) if e.valid => TraceVerdict::Declared,
+            Some(_) if registry == RegistryType::Go => TraceVerdict::NotInGoMod,
+            Some(_) => TraceVerdict::Unchecked,
+        };
+
+        DependencyTrace {
+            import: import_name.to_string(),
+            package: package.map(String::from),
+            file: file_path.to_string_lossy().to_string(),
+            line: None,
+            registry: registry.as_str().to_string(),
+            allowlist: AllowlistTrace {
+                patterns: self.registry_client.allowlist_len(),
+                local_packages: self.local_packages.clone(),
+                matched: matched.map(String::from),
+            },
+            manifest: ManifestTrace {
+                provider: self.manifest_type.as_str().to_string(),
+                root: self.manifest_root.to_string_lossy().to_string(),
+                workspace_fallback: self.workspace_fallback,
+                explanation,
+            },
+            lookup: None,
+            verdict,
+        }
+    }
+
+    /// Look an import's package up in its registry, noting whether the cache answered.
+    pub async fn lookup_registry(&self, registry: RegistryType, import_name: &str) -> RegistryLookup {
+        let package = self.package_for(import_name, registry).unwrap_or(import_name);
+        let (cached, status) = lookup(&self.registry_client, registry, package).await;
+        RegistryLookup::new(registry.as_str(), cached, &status)
+    }
+}
+
+/// Check a package, noting whether the status was already cached.
+async fn lookup(
+    client: &RegistryClient,
+    registry: RegistryType,
+    package: &str,
+) -> (bool, Result<PackageStatus, RegistryError>) {
+    let cached = client.cached_status(registry, package).is_some();
+    (cached, client.check_package(registry, package).await)
 }
 
 /// Detect local package names from project manifest files.
@@ -157,6 +318,10 @@ fn detect_local_packages(base_dir: &Path) -> Vec<String> {
     if cargo_toml.exists() {
         if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
             if let Some(name) = parse_cargo_package_name(&content) {
+                // `shared-models` is imported as `use shared_models::...`
+                if name.contains('-') {
+                    local_packages.push(name.replace('-', "_"));
+                }
                 local_packages.push(name);
             }
         }
@@ -177,6 +342,8 @@ fn detect_local_packages(base_dir: &Path) -> Vec<String> {
     if go_mod.exists() {
         if let Ok(content) = std::fs::read_to_string(&go_mod) {
             if let Some(name) = parse_go_module_name(&content) {
+                // Imports name packages inside the module (`example.com/shared/models`)
+                local_packages.push(format!("{}/*", name));
                 local_packages.push(name);
             }
         }
@@ -232,10 +399,23 @@ fn parse_pyproject_name(content: &str) -> Option<String> {
 /// Uses a two-phase approach:
 /// 1. **Manifest validation**: Validate imports against declared deps
 /// 2. **PyPI fallback**: For packages not covered by manifest, check if they exist on PyPI
+///
+/// With `explain`, a [`DependencyTrace`] of each violation's import is added
+/// to `dependency_traces`.
+///
+/// The first pass over the files keeps one entry per unique import, not its
+/// locations. Registry lookups run in batches of `chunk_size` packages, and
+/// only the imports that fail are located, in a second pass over the files,
+/// so memory follows the number of unique packages and violations rather
+/// than every import in the project. `progress` receives (checked, total)
+/// packages after each batch. Above `max_packages` unique packages the
+/// lookups are skipped and a contract warning is recorded instead.
 pub fn detect_hallucinated_dependencies(
     base_dir: &Path,
     files: &[PathBuf],
     config: Option<&DependencyVerificationConfig>,
+    explain: bool,
+    progress: Option<&(dyn Fn(usize, usize) + Send + Sync)>,
 ) -> anyhow::Result<DetectionResult> {
     let mut result = DetectionResult::new();
 
@@ -248,119 +428,164 @@ pub fn detect_hallucinated_dependencies(
     // Create the validator
     let validator = DependencyValidator::new(ManifestType::Auto, base_dir, config)?;
 
-    // Extract all imports from all files
-    let mut all_imports: Vec<ImportedDependency> = Vec::new();
-    for file in files {
+    // Unique (registry, import) pairs, each with the first file importing it
+    let mut unique_imports: HashMap<(RegistryType, String), usize> = HashMap::new();
+    for (index, file) in files.iter().enumerate() {
         if let Ok(imports) = extract_imports(file) {
-            all_imports.extend(imports);
+            for import in imports {
+                unique_imports.entry((import.registry, import.name)).or_insert(index);
+            }
         }
         result.scanned += 1;
     }
 
-    if all_imports.is_empty() {
+    if unique_imports.is_empty() {
         return Ok(result);
     }
 
-    // Deduplicate imports by (registry, name)
-    let mut unique_imports: HashMap<(RegistryType, String), Vec<ImportedDependency>> =
-        HashMap::new();
-    for import in all_imports {
-        unique_imports
-            .entry((import.registry, import.name.clone()))
-            .or_default()
-            .push(import);
+    // Filter imports: remove those covered by manifest or allowlist.
+    // Go imports not in go.mod fail without a registry lookup, since
+    // go.mod is authoritative
+    let mut go_missing: HashSet<String> = HashSet::new();
+    let mut imports_to_check: Vec<(RegistryType, String, String)> = Vec::new();
+    for ((registry, import), index) in unique_imports {
+        if validator.registry_client().is_allowlisted(&import)
+            || validator.is_valid_import(&import, &files[index])
+        {
+            continue;
+        }
+        if registry == RegistryType::Go {
+            go_missing.insert(import);
+            continue;
+        }
+        // Overridden imports are looked up under their package name
+        let package = validator.package_for(&import, registry).unwrap_or(&import).to_string();
+        imports_to_check.push((registry, package, import));
     }
 
-    // Filter imports: remove those covered by manifest or allowlist
-    // Also collect Go violations directly (no PyPI check needed for Go)
-    let mut go_violations: Vec<Violation> = Vec::new();
+    // Message, severity, and lookup of each import the registries failed
+    let mut failures: HashMap<(RegistryType, String), (String, Severity, RegistryLookup)> =
+        HashMap::new();
+    let packages_to_check = imports_to_check.len();
 
-    let imports_to_check: HashMap<(RegistryType, String), Vec<ImportedDependency>> = unique_imports
-        .into_iter()
-        .filter(|((registry, pkg), locations)| {
-            // Skip if allowlisted (works for all languages)
-            if validator.registry_client().is_allowlisted(pkg) {
-                return false;
-            }
+    if std::env::var("HOLLOWCHECK_DEBUG").is_ok() && packages_to_check > 0 {
+        eprintln!(
+            "[debug] {} packages to check against PyPI (after manifest filtering)",
+            packages_to_check
+        );
+    }
 
-            // Check manifest validation (works for Python AND Go)
-            if let Some(loc) = locations.first() {
-                if validator.is_valid_import(pkg, Path::new(&loc.file)) {
-                    return false;
-                }
+    // Tens of thousands of unique packages almost always means the scan
+    // root includes vendored or generated code
+    let max_packages = config.max_packages();
+    if packages_to_check > max_packages {
+        // Not a finding about any dependency, so it is a contract warning
+        result.contract_warnings.push(format!(
+            "registry check skipped: {} unique packages need a lookup, more than the limit of {}; check the scan root or raise dependency_verification.max_packages",
+            packages_to_check, max_packages
+        ));
+    } else if packages_to_check > 0 {
+        // Phase 2: Check remaining packages against PyPI, one batch at a time
+        imports_to_check.sort_by(|a, b| (a.0.as_str(), &a.1, &a.2).cmp(&(b.0.as_str(), &b.1, &b.2)));
+        let chunk_size = config.chunk_size();
+        let runtime = tokio::runtime::Runtime::new()?;
+        let mut pending = imports_to_check.into_iter();
+        let mut checked_count = 0;
+        loop {
+            let chunk: Vec<_> = pending.by_ref().take(chunk_size).collect();
+            if chunk.is_empty() {
+                break;
             }
+            checked_count += chunk.len();
 
-            // For Go, if not in manifest and not in allowlist, it's a violation
-            // Go doesn't need registry checking - go.mod is authoritative
-            if *registry == RegistryType::Go {
-                for loc in locations {
-                    go_violations.push(Violation {
-                        rule: ViolationRule::HallucinatedDependency,
-                        message: format!(
-                            "Go import \"{}\" not found in go.mod",
-                            pkg
-                        ),
-                        file: loc.file.clone(),
-                        line: loc.line,
-                        severity: Severity::Critical,
-                    });
+            let checked =
+                runtime.block_on(async { check_packages(validator.registry_client(), chunk).await });
+            for (registry, import, lookup, failure) in checked {
+                if let Some((message, severity)) = failure {
+                    failures.insert((registry, import), (message, severity, lookup));
                 }
-                return false; // Don't include in PyPI check
             }
 
-            // For Python (and other registries), need registry verification
-            true
-        })
-        .collect();
+            if let Some(cb) = progress {
+                cb(checked_count, packages_to_check);
+            }
+            if std::env::var("HOLLOWCHECK_DEBUG").is_ok() && packages_to_check > chunk_size {
+                eprintln!("[debug] Checked {}/{} packages", checked_count, packages_to_check);
+            }
+        }
 
-    // Add Go violations to result
-    for v in go_violations {
-        result.add_violation(v);
+        // Log cache stats for debugging
+        if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
+            let (hits, misses) = validator.registry_client().cache_stats();
+            eprintln!("[debug] Registry cache: {} hits, {} misses", hits, misses);
+        }
     }
 
-    let packages_to_check = imports_to_check.len();
-
-    // Skip if nothing to check
-    if packages_to_check == 0 {
+    if go_missing.is_empty() && failures.is_empty() {
         return Ok(result);
     }
 
-    if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
-        eprintln!(
-            "[debug] {} packages to check against PyPI (after manifest filtering)",
-            packages_to_check
-        );
-    }
-
-    // Phase 2: Check remaining packages against PyPI
-    let runtime = tokio::runtime::Runtime::new()?;
-    let violations =
-        runtime.block_on(async { check_packages(validator.registry_client(), imports_to_check).await });
-
-    // Log cache stats for debugging
-    if std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
-        let (hits, misses) = validator.registry_client().cache_stats();
-        eprintln!("[debug] Registry cache: {} hits, {} misses", hits, misses);
-    }
-
-    for v in violations {
-        result.add_violation(v);
+    // Report every location of a failed import, dropping repeats of a
+    // package on the same line
+    let mut traces: Vec<DependencyTrace> = Vec::new();
+    for file in files {
+        let Ok(imports) = extract_imports(file) else {
+            continue;
+        };
+        let mut seen = HashSet::new();
+        for import in imports {
+            if !seen.insert((import.registry, import.name.clone(), import.line)) {
+                continue;
+            }
+            let (message, severity, lookup) = if import.registry == RegistryType::Go {
+                if !go_missing.contains(&import.name) {
+                    continue;
+                }
+                (format!("Go import \"{}\" not found in go.mod", import.name), Severity::Critical, None)
+            } else {
+                match failures.get(&(import.registry, import.name.clone())) {
+                    Some((message, severity, lookup)) => (message.clone(), *severity, Some(lookup)),
+                    None => continue,
+                }
+            };
+            if explain {
+                let mut trace = validator.trace_import(&import.name, file, import.registry);
+                trace.line = Some(import.line);
+                if let Some(lookup) = lookup {
+                    trace.set_lookup(lookup.clone());
+                }
+                traces.push(trace);
+            }
+            result.add_violation(Violation {
+                rule: ViolationRule::HallucinatedDependency,
+                message,
+                file: Some(import.file),
+                line: import.line,
+                severity,
+            });
+        }
     }
+    result.dependency_traces = traces;
 
     Ok(result)
 }
 
-/// Check packages against registries asynchronously with concurrent requests.
+/// Check a batch of packages against registries with concurrent requests.
+///
+/// Takes (registry, package, import) triples and returns each import with
+/// its package's lookup and, when the package is missing or could not be
+/// verified, the message and severity to report the import with. Messages
+/// name the import too where an override renamed it.
 async fn check_packages(
     client: &RegistryClient,
-    imports: HashMap<(RegistryType, String), Vec<ImportedDependency>>,
-) -> Vec<Violation> {
+    imports: Vec<(RegistryType, String, String)>,
+) -> Vec<(RegistryType, String, RegistryLookup, Option<(String, Severity)>)> {
     use futures::stream::{self, StreamExt};
 
     // Filter out allowlisted packages first
     let packages_to_check: Vec<_> = imports
         .into_iter()
-        .filter(|((_, package), _)| !client.is_allowlisted(package))
+        .filter(|(_, package, _)| !client.is_allowlisted(package))
         .collect();
 
     if packages_to_check.is_empty() {
@@ -369,73 +594,46 @@ async fn check_packages(
 
     // Check packages concurrently with up to 50 parallel requests
     let results: Vec<_> = stream::iter(packages_to_check)
-        .map(|((registry, package), locations)| async move {
-            let status = client.check_package(registry, &package).await;
-            (registry, package, locations, status)
+        .map(|(registry, package, import)| async move {
+            let (cached, status) = lookup(client, registry, &package).await;
+            (registry, package, import, cached, status)
         })
         .buffer_unordered(50)
         .collect()
         .await;
 
-    // Process results into violations
-    let mut violations = Vec::new();
+    // Process results into failures
+    let mut checked = Vec::new();
     let fail_on_timeout = client.fail_on_timeout();
 
-    for (registry, package, locations, status) in results {
-        match status {
-            Ok(PackageStatus::NotFound) => {
-                for loc in locations {
-                    violations.push(Violation {
-                        rule: ViolationRule::HallucinatedDependency,
-                        message: format!(
-                            "package \"{}\" not found in {}",
-                            package,
-                            registry.as_str()
-                        ),
-                        file: loc.file,
-                        line: loc.line,
-                        severity: Severity::Critical,
-                    });
-                }
-            }
-            Ok(PackageStatus::Exists) => {
-                // Package exists, no violation
-            }
-            Ok(PackageStatus::Unknown(reason)) => {
-                if fail_on_timeout {
-                    for loc in locations {
-                        violations.push(Violation {
-                            rule: ViolationRule::HallucinatedDependency,
-                            message: format!(
-                                "could not verify \"{}\" in {}: {}",
-                                package,
-                                registry.as_str(),
-                                reason
-                            ),
-                            file: loc.file,
-                            line: loc.line,
-                            severity: Severity::Warning,
-                        });
-                    }
-                }
-            }
-            Err(e) => {
-                if fail_on_timeout {
-                    for loc in &locations {
-                        violations.push(Violation {
-                            rule: ViolationRule::HallucinatedDependency,
-                            message: format!("registry error checking \"{}\": {}", package, e),
-                            file: loc.file.clone(),
-                            line: loc.line,
-                            severity: Severity::Warning,
-                        });
-                    }
-                }
-            }
-        }
+    for (registry, package, import, cached, status) in results {
+        let lookup = RegistryLookup::new(registry.as_str(), cached, &status);
+        let shown = if import == package {
+            format!("\"{}\"", package)
+        } else {
+            format!("\"{}\" (imported as \"{}\")", package, import)
+        };
+        let failure = match status {
+            Ok(PackageStatus::NotFound) => Some((
+                format!("package {} not found in {}", shown, registry.as_str()),
+                Severity::Critical,
+            )),
+            // Package exists, no violation
+            Ok(PackageStatus::Exists) => None,
+            Ok(PackageStatus::Unknown(reason)) if fail_on_timeout => Some((
+                format!("could not verify {} in {}: {}", shown, registry.as_str(), reason),
+                Severity::Warning,
+            )),
+            Err(e) if fail_on_timeout => Some((
+                format!("registry error checking {}: {}", shown, e),
+                Severity::Warning,
+            )),
+            Ok(PackageStatus::Unknown(_)) | Err(_) => None,
+        };
+        checked.push((registry, import, lookup, failure));
     }
 
-    violations
+    checked
 }
 
 #[cfg(test)]
@@ -464,10 +662,65 @@ mod tests {
         );
 
         let result =
-            detect_hallucinated_dependencies(temp.path(), &[file], Some(&config)).unwrap();
+            detect_hallucinated_dependencies(temp.path(), &[file], Some(&config), false, None)
+                .unwrap();
         assert!(result.violations.is_empty());
     }
 
+    #[test]
+    fn test_registry_lookups_in_batches() {
+        let mut config = DependencyVerificationConfig {
+            enabled: true,
+            fail_on_timeout: true,
+            chunk_size: Some(2),
+            ..Default::default()
+        };
+        // A disabled registry answers without the network
+        config.registries.pypi.enabled = false;
+
+        let temp = TempDir::new().unwrap();
+        let file = create_test_file(
+            &temp,
+            "app.py",
+            "import alpha_pkg_12345, alpha_pkg_12345\nimport beta_pkg_12345\nimport gamma_pkg_12345\n",
+        );
+
+        let other = create_test_file(&temp, "other.py", "\nimport alpha_pkg_12345\n");
+
+        let calls = std::sync::Mutex::new(Vec::new());
+        let progress = |checked, total| calls.lock().unwrap().push((checked, total));
+        let result = detect_hallucinated_dependencies(
+            temp.path(),
+            &[file.clone(), other],
+            Some(&config),
+            true,
+            Some(&progress),
+        )
+        .unwrap();
+
+        // The repeated import on line 1 is reported once, and a package is
+        // looked up once but reported at each file importing it
+        let mut locations: Vec<_> = result.violations.iter().map(|v| (v.location().to_string(), v.line)).collect();
+        locations.sort();
+        let path = |name: &str| temp.path().join(name).to_string_lossy().to_string();
+        assert_eq!(
+            locations,
+            vec![(path("app.py"), 1), (path("app.py"), 2), (path("app.py"), 3), (path("other.py"), 2)]
+        );
+        assert!(result.violations.iter().all(|v| v.severity == Severity::Warning));
+        assert_eq!(result.dependency_traces.len(), 4);
+        assert_eq!(*calls.lock().unwrap(), vec![(2, 3), (3, 3)]);
+
+        // Over the ceiling, one contract warning replaces the lookups
+        config.max_packages = Some(2);
+        let result =
+            detect_hallucinated_dependencies(temp.path(), &[file], Some(&config), false, None)
+                .unwrap();
+        assert!(result.violations.is_empty());
+        assert_eq!(result.contract_warnings.len(), 1);
+        assert!(result.contract_warnings[0].contains("3 unique packages"));
+    }
+
     #[test]
     fn test_parse_cargo_package_name() {
         let content = r#"
@@ -574,6 +827,44 @@ version = "0.1.0"
         assert_eq!(validator.manifest_type(), &ManifestType::PythonStandard);
     }
 
+    #[test]
+    fn test_trace_import() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("requirements.txt"), "pyswitchbot\n").unwrap();
+        let file = temp.path().join("app.py");
+
+        let config = DependencyVerificationConfig {
+            enabled: true,
+            allowlist: vec!["acme_*".to_string()],
+            ..Default::default()
+        };
+        let validator =
+            DependencyValidator::new(ManifestType::Auto, temp.path(), &config).unwrap();
+
+        let trace = validator.trace_import("switchbot", &file, RegistryType::PyPI);
+        assert_eq!(trace.verdict, TraceVerdict::Declared);
+        assert_eq!(trace.manifest.provider, "python");
+        assert!(!trace.manifest.workspace_fallback);
+        let explanation = trace.manifest.explanation.as_ref().unwrap();
+        assert_eq!(
+            explanation.matched().unwrap().rule,
+            Some(crate::detect::MatchRule::PyPrefix)
+        );
+
+        // The allowlist decides before the manifest is consulted
+        let trace = validator.trace_import("acme_billing", &file, RegistryType::PyPI);
+        assert_eq!(trace.verdict, TraceVerdict::Allowlisted);
+        assert_eq!(trace.allowlist.matched.as_deref(), Some("acme_*"));
+        assert!(trace.manifest.explanation.is_none());
+
+        let mut trace = validator.trace_import("imaginary", &file, RegistryType::PyPI);
+        assert_eq!(trace.verdict, TraceVerdict::Unchecked);
+        trace.set_lookup(RegistryLookup::new("pypi", true, &Ok(PackageStatus::NotFound)));
+        assert_eq!(trace.verdict, TraceVerdict::NotFound);
+        assert!(trace.verdict.is_violation());
+        assert!(trace.to_string().contains("5. Registry: pypi (cache hit): not found"));
+    }
+
     #[test]
     fn test_dependency_validator_auto_detect_ha() {
         let temp = TempDir::new().unwrap();
@@ -614,4 +905,133 @@ version = "0.1.0"
         // Not declared
         assert!(!validator.is_valid_import("nonexistent", &file));
     }
+
+    #[test]
+    fn test_import_overrides() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("requirements.txt"),
+            "opencv-python>=4.8\nacme-platform-client\n",
+        )
+        .unwrap();
+
+        let config = DependencyVerificationConfig {
+            enabled: true,
+            import_overrides: [("acme_sdk".to_string(), "acme-platform-client".to_string())].into(),
+            ..Default::default()
+        };
+        let validator =
+            DependencyValidator::new(ManifestType::Auto, temp.path(), &config).unwrap();
+        let file = temp.path().join("app.py");
+
+        // Built-in table and contract overrides both name the package to look for
+        assert!(validator.is_valid_import("cv2", &file));
+        assert!(validator.is_valid_import("acme_sdk", &file));
+        // The package of an overridden import must itself be declared
+        assert!(!validator.is_valid_import("PIL", &file));
+
+        // The built-in table is for Python imports only
+        assert_eq!(validator.package_for("yaml", RegistryType::PyPI), Some("PyYAML"));
+        assert_eq!(validator.package_for("yaml", RegistryType::Npm), None);
+        assert_eq!(
+            validator.package_for("acme_sdk", RegistryType::Npm),
+            Some("acme-platform-client")
+        );
+
+        let trace = validator.trace_import("cv2", &file, RegistryType::PyPI);
+        assert_eq!(trace.package.as_deref(), Some("opencv-python"));
+        assert_eq!(trace.verdict, TraceVerdict::Declared);
+        assert!(trace.to_string().contains("comes from package \"opencv-python\""));
+    }
+
+    #[test]
+    fn test_cargo_workspace_siblings_allowlisted_from_leaf() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"crates/*\"]\n",
+        )
+        .unwrap();
+        for name in ["worker", "shared-models"] {
+            let dir = root.join("crates").join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("Cargo.toml"),
+                format!("[package]\nname = \"{}\"\n", name),
+            )
+            .unwrap();
+        }
+
+        let config = DependencyVerificationConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let validator =
+            DependencyValidator::new(ManifestType::Auto, &root.join("crates/worker"), &config)
+                .unwrap();
+
+        // `use shared_models::...` from the worker crate
+        assert!(validator.registry_client().is_allowlisted("shared_models"));
+        assert!(validator.registry_client().is_allowlisted("worker"));
+        assert!(!validator.registry_client().is_allowlisted("serde"));
+    }
+
+    #[test]
+    fn test_pnpm_workspace_siblings_allowlisted_from_leaf() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::write(root.join("pnpm-workspace.yaml"), "packages:\n  - \"packages/*\"\n").unwrap();
+        for (dir, name) in [("web", "@acme/web"), ("ui", "@acme/ui")] {
+            let dir = root.join("packages").join(dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("package.json"), format!("{{\"name\": \"{}\"}}", name))
+                .unwrap();
+        }
+
+        let config = DependencyVerificationConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let validator =
+            DependencyValidator::new(ManifestType::Auto, &root.join("packages/web"), &config)
+                .unwrap();
+
+        assert!(validator.registry_client().is_allowlisted("@acme/ui"));
+        assert!(!validator.registry_client().is_allowlisted("left-pad"));
+    }
+
+    #[test]
+    fn test_check_packages_reuses_pooled_connections() {
+        use crate::registry::test_support::serve_counting_connections;
+        use crate::registry::MAX_CONNECTIONS_PER_HOST;
+        use std::sync::atomic::Ordering;
+
+        // A chunked body has no declared length, like many registry responses
+        let (url, accepted) =
+            serve_counting_connections("HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n2\r\n{}\r\n0\r\n\r\n");
+        let client = RegistryClient::with_base_url(&url);
+        let imports: Vec<_> = (0..400)
+            .map(|i| {
+                let name = format!("package-{}", i);
+                (RegistryType::Npm, name.clone(), name)
+            })
+            .collect();
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let checked = runtime.block_on(check_packages(&client, imports));
+
+        assert_eq!(checked.len(), 400);
+        assert!(checked.iter().all(|(_, _, _, failure)| failure.is_none()));
+        assert_eq!(client.cache_stats(), (0, 400));
+        // A finished connection returns to the pool asynchronously, so a
+        // request that gets a slot just before can still dial a new one; the
+        // count stays near the slot count instead of growing with requests
+        let connections = accepted.load(Ordering::SeqCst);
+        assert!(
+            connections <= 2 * MAX_CONNECTIONS_PER_HOST,
+            "{} connections opened for 400 lookups",
+            connections
+        );
+    }
 }
@@ -0,0 +1,245 @@
+//! Step-by-step trace of the hallucinated dependency pipeline for one import.
+//!
+//! When a manifest rejects an import it obviously declares, or accepts one it
+//! should not, the pipeline's answer alone does not say which step decided.
+//! A trace records each step in order: the manifest provider that was
+//! loaded, the allowlist, every declared package compared and the rule that
+//! accepted or rejected it, and the registry lookup with its cache state.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::registry::{PackageStatus, RegistryError};
+
+use super::manifest::ImportExplanation;
+
+/// Outcome of the pipeline for a traced import.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum TraceVerdict {
+    /// Matched an allowlist pattern or local package
+    Allowlisted,
+    /// Accepted by the manifest
+    Declared,
+    /// Go import missing from go.mod (Go imports are never looked up)
+    NotInGoMod,
+    /// Needs a registry lookup that was not performed
+    Unchecked,
+    /// Found in the registry
+    Exists,
+    /// Not found in the registry
+    NotFound,
+    /// The registry could not answer
+    Unverified,
+}
+
+impl TraceVerdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraceVerdict::Allowlisted => "allowlisted",
+            TraceVerdict::Declared => "declared",
+            TraceVerdict::NotInGoMod => "not-in-go-mod",
+            TraceVerdict::Unchecked => "unchecked",
+            TraceVerdict::Exists => "exists",
+            TraceVerdict::NotFound => "not-found",
+            TraceVerdict::Unverified => "unverified",
+        }
+    }
+
+    /// Whether the pipeline reports the import as hallucinated.
+    ///
+    /// Unverified imports are only reported with `fail_on_timeout`.
+    pub fn is_violation(&self) -> bool {
+        matches!(self, TraceVerdict::NotInGoMod | TraceVerdict::NotFound)
+    }
+}
+
+/// The manifest provider consulted for an import.
+#[derive(Debug, Clone, Serialize)]
+pub struct ManifestTrace {
+    /// Detected manifest type (`python`, `go`, `home-assistant`, `none`)
+    pub provider: String,
+    /// Directory the manifest was loaded from
+    pub root: String,
+    /// Whether `root` is the workspace root because the project has no manifest
+    pub workspace_fallback: bool,
+    /// The provider's comparisons; None if the allowlist decided first
+    pub explanation: Option<ImportExplanation>,
+}
+
+/// The allowlist consulted for an import.
+#[derive(Debug, Clone, Serialize)]
+pub struct AllowlistTrace {
+    /// Number of patterns, including local packages
+    pub patterns: usize,
+    /// Local and workspace package names added to the allowlist
+    pub local_packages: Vec<String>,
+    /// The pattern that matched the import
+    pub matched: Option<String>,
+}
+
+/// A registry lookup for an import.
+#[derive(Debug, Clone, Serialize)]
+pub struct RegistryLookup {
+    /// Registry queried (`pypi`, `npm`, `crates`)
+    pub registry: String,
+    /// Whether the status came from the registry cache rather than the network
+    pub cached: bool,
+    /// `exists`, `not found`, or why the registry could not answer
+    pub status: String,
+}
+
+impl RegistryLookup {
+    pub fn new(registry: &str, cached: bool, status: &Result<PackageStatus, RegistryError>) -> Self {
+        let status = match status {
+            Ok(PackageStatus::Exists) => "exists".to_string(),
+            Ok(PackageStatus::NotFound) => "not found".to_string(),
+            Ok(PackageStatus::Unknown(reason)) => format!("unknown ({})", reason),
+            Err(e) => format!("error ({})", e),
+        };
+        Self {
+            registry: registry.to_string(),
+            cached,
+            status,
+        }
+    }
+
+    fn verdict(&self) -> TraceVerdict {
+        match self.status.as_str() {
+            "exists" => TraceVerdict::Exists,
+            "not found" => TraceVerdict::NotFound,
+            _ => TraceVerdict::Unverified,
+        }
+    }
+}
+
+/// The dependency pipeline's steps for one import in one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyTrace {
+    /// Import name as extracted from the file
+    pub import: String,
+    /// Package the import comes from, when an import override names it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub package: Option<String>,
+    /// File containing the import
+    pub file: String,
+    /// Line of the import; None if the file's imports do not include it
+    pub line: Option<usize>,
+    /// Registry the import belongs to
+    pub registry: String,
+    pub allowlist: AllowlistTrace,
+    pub manifest: ManifestTrace,
+    /// Registry lookup; None if an earlier step decided
+    pub lookup: Option<RegistryLookup>,
+    pub verdict: TraceVerdict,
+}
+
+impl DependencyTrace {
+    /// Record the registry lookup and take its verdict.
+    pub fn set_lookup(&mut self, lookup: RegistryLookup) {
+        self.verdict = lookup.verdict();
+        self.lookup = Some(lookup);
+    }
+
+    /// Whether the trace belongs to a violation at `file:line` with `message`.
+    pub fn matches(&self, file: Option<&str>, line: usize, message: &str) -> bool {
+        file == Some(self.file.as_str())
+            && self.line == Some(line)
+            && message.contains(&format!("\"{}\"", self.import))
+    }
+}
+
+impl fmt::Display for DependencyTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Import \"{}\" in {} ({})", self.import, self.file, self.registry)?;
+        writeln!(f)?;
+
+        match self.line {
+            Some(line) => writeln!(f, "1. Extraction: imported on line {}", line)?,
+            None => writeln!(
+                f,
+                "1. Extraction: not among the file's imports (standard library, relative, and framework imports are skipped)"
+            )?,
+        }
+        if let Some(package) = &self.package {
+            writeln!(f, "   comes from package \"{}\" (import override)", package)?;
+        }
+
+        let manifest = &self.manifest;
+        writeln!(
+            f,
+            "2. Manifest: {} provider at {}{}",
+            manifest.provider,
+            manifest.root,
+            if manifest.workspace_fallback { " (workspace root fallback)" } else { "" }
+        )?;
+        if let Some(explanation) = &manifest.explanation {
+            writeln!(f, "   scope: {}", explanation.scope.as_deref().unwrap_or("none"))?;
+        }
+
+        let allowlist = &self.allowlist;
+        write!(f, "3. Allowlist: {} patterns", allowlist.patterns)?;
+        if !allowlist.local_packages.is_empty() {
+            write!(f, ", local packages: {}", allowlist.local_packages.join(", "))?;
+        }
+        writeln!(f)?;
+        match &allowlist.matched {
+            Some(pattern) => writeln!(f, "   matched by {:?}", pattern)?,
+            None => writeln!(f, "   no pattern matches")?,
+        }
+
+        match &manifest.explanation {
+            Some(explanation) => {
+                writeln!(f, "4. Manifest match: {} entries compared", explanation.attempts.len())?;
+                let width = explanation
+                    .attempts
+                    .iter()
+                    .map(|a| a.source.len() + a.declared.len())
+                    .max()
+                    .unwrap_or(0);
+                for attempt in &explanation.attempts {
+                    let pad = width - attempt.source.len() - attempt.declared.len();
+                    let rule = attempt.rule.map_or("rejected", |r| r.as_str());
+                    writeln!(
+                        f,
+                        "   - {}: {}{}  {}",
+                        attempt.source,
+                        attempt.declared,
+                        " ".repeat(pad),
+                        rule
+                    )?;
+                }
+            }
+            None => writeln!(f, "4. Manifest match: not reached")?,
+        }
+
+        match &self.lookup {
+            Some(lookup) => writeln!(
+                f,
+                "5. Registry: {} ({}): {}",
+                lookup.registry,
+                if lookup.cached { "cache hit" } else { "cache miss" },
+                lookup.status
+            )?,
+            None if self.verdict == TraceVerdict::Unchecked => {
+                writeln!(f, "5. Registry: skipped")?
+            }
+            None => writeln!(f, "5. Registry: not reached")?,
+        }
+
+        writeln!(f)?;
+        let verdict = match self.verdict {
+            TraceVerdict::Allowlisted => "accepted, allowlisted".to_string(),
+            TraceVerdict::Declared => "accepted, declared in the manifest".to_string(),
+            TraceVerdict::NotInGoMod => "flagged, not found in go.mod".to_string(),
+            TraceVerdict::Unchecked => "undecided, needs a registry lookup".to_string(),
+            TraceVerdict::Exists => format!("accepted, exists in {}", self.registry),
+            TraceVerdict::NotFound => format!("flagged, not found in {}", self.registry),
+            TraceVerdict::Unverified => {
+                "unverified, flagged only with fail_on_timeout".to_string()
+            }
+        };
+        write!(f, "Verdict: {}", verdict)
+    }
+}
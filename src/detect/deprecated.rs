@@ -0,0 +1,177 @@
+//! Detection of deprecated callables that are still widely called.
+//!
+//! Marking a function deprecated starts a migration; it does not finish one.
+//! Deprecation markers come from `FileFacts::deprecations` and calls from the
+//! project-wide call index, so the report says how many call sites are still
+//! left for each deprecated function. There is no type resolution: calls
+//! match by name, and a recursive call from inside the deprecated function
+//! itself is not counted.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::contract::DeprecatedUsageConfig;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Flag deprecated callables called from at least `min_call_sites` places.
+pub fn detect_deprecated_usage<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &DeprecatedUsageConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let threshold = config.min_call_sites();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        for deprecation in &file_facts.deprecations {
+            let span = &deprecation.span;
+            let calls: Vec<_> = facts
+                .calls_to(&deprecation.name)
+                .iter()
+                .filter(|call| {
+                    call.file != path || call.line < span.start_line || call.line > span.end_line
+                })
+                .collect();
+            if calls.len() < threshold {
+                continue;
+            }
+
+            let calling_files: BTreeSet<_> = calls.iter().map(|call| &call.file).collect();
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::DeprecatedUsage,
+                    format!(
+                        "deprecated function {} ({}) is still called from {} site{} in {} file{}",
+                        deprecation.name,
+                        deprecation.marker,
+                        calls.len(),
+                        if calls.len() == 1 { "" } else { "s" },
+                        calling_files.len(),
+                        if calling_files.len() == 1 { "" } else { "s" }
+                    ),
+                )
+                .at(path.to_string_lossy(), span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &DeprecatedUsageConfig) -> Vec<(usize, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::DeprecatedUsage]);
+        detect_deprecated_usage(&facts, &paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| (v.line, v.message))
+            .collect()
+    }
+
+    const LIB: &str = r#"/// Parses a config file.
+#[deprecated(since = "0.4", note = "use Config::load")]
+pub fn parse_config(path: &str) -> Config {
+    if path.is_empty() {
+        return parse_config("default.toml");
+    }
+    Config::load(path)
+}
+
+#[deprecated]
+pub fn legacy_flush() {}
+"#;
+
+    const MAIN: &str = r#"fn main() {
+    let a = lib::parse_config("a.toml");
+    let b = parse_config("b.toml");
+    legacy_flush();
+}
+"#;
+
+    const WORKER: &str = r#"fn run() {
+    let c = crate::lib::parse_config("c.toml");
+}
+"#;
+
+    #[test]
+    fn test_rust_deprecated_calls_across_files() {
+        let config = DeprecatedUsageConfig {
+            min_call_sites: Some(2),
+            ..Default::default()
+        };
+        let found = detect(&[("lib.rs", LIB), ("main.rs", MAIN), ("worker.rs", WORKER)], &config);
+        assert_eq!(
+            found,
+            vec![(
+                3,
+                "deprecated function parse_config (#[deprecated]) is still called from 3 sites in 2 files"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_default_threshold() {
+        let found = detect(
+            &[("lib.rs", LIB), ("main.rs", MAIN), ("worker.rs", WORKER)],
+            &DeprecatedUsageConfig::default(),
+        );
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_python_docstring_deprecation() {
+        let source = r#"def fetch(url):
+    """Fetch a page.
+
+    .. deprecated:: 2.0
+       Use :func:`get`.
+    """
+    return get(url)
+
+def crawl(urls):
+    return [fetch(u) for u in urls]
+"#;
+        let config = DeprecatedUsageConfig {
+            min_call_sites: Some(1),
+            ..Default::default()
+        };
+        let found = detect(&[("pages.py", source)], &config);
+        assert_eq!(
+            found,
+            vec![(
+                1,
+                "deprecated function fetch (.. deprecated::) is still called from 1 site in 1 file".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,288 @@
+//! Documentation coverage of exported declarations.
+//!
+//! Delivery standards often require doc comments on the public API, and
+//! generated code is inconsistent about it: it documents private helpers
+//! and leaves exported functions bare. The analyzers record which
+//! declarations are exported and whether a doc comment or docstring sits
+//! on them (`Declaration::is_exported`, `Declaration::has_doc`). This rule
+//! reports undocumented exported declarations at Info severity, a few per
+//! file, and measures coverage per file and for the project. The figures go
+//! to the report's stats, and `min_doc_coverage` turns the project figure
+//! into a gate. Constants and test files are left out unless the contract
+//! asks for them.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::{Declaration, DeclarationKind};
+use crate::contract::DocCoverageConfig;
+
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
+
+/// Documented and total exported declarations of one file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileDocCoverage {
+    pub file: String,
+    pub documented: usize,
+    pub total: usize,
+}
+
+/// Documented and total exported declarations across the project.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DocCoverage {
+    pub documented: usize,
+    pub total: usize,
+    /// Documented share of the total, rounded to three decimals; 1.0 when
+    /// there is nothing to document
+    pub coverage: f64,
+    /// Files with at least one exported declaration
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<FileDocCoverage>,
+}
+
+impl DocCoverage {
+    fn ratio(documented: usize, total: usize) -> f64 {
+        if total == 0 {
+            return 1.0;
+        }
+        (documented as f64 / total as f64 * 1000.0).round() / 1000.0
+    }
+
+    fn add_file(&mut self, file: String, documented: usize, total: usize) {
+        self.documented += documented;
+        self.total += total;
+        self.coverage = Self::ratio(self.documented, self.total);
+        self.files.push(FileDocCoverage { file, documented, total });
+    }
+
+    /// Add another set of figures to this one.
+    pub fn merge(&mut self, other: DocCoverage) {
+        self.documented += other.documented;
+        self.total += other.total;
+        self.coverage = Self::ratio(self.documented, self.total);
+        self.files.extend(other.files);
+    }
+}
+
+/// The declarations a file's coverage is measured over.
+///
+/// Python reports a decorated function twice, as the decorated definition
+/// and the function inside it; the inner one is left out.
+fn counted<'a>(declarations: &'a [Declaration], config: &DocCoverageConfig) -> Vec<&'a Declaration> {
+    let exported: Vec<&Declaration> = declarations
+        .iter()
+        .filter(|d| d.is_exported)
+        .filter(|d| config.include_constants() || d.kind != DeclarationKind::Const)
+        .collect();
+    exported
+        .iter()
+        .filter(|d| {
+            !exported.iter().any(|outer| {
+                outer.name == d.name
+                    && outer.span.start_byte < d.span.start_byte
+                    && d.span.end_byte <= outer.span.end_byte
+            })
+        })
+        .copied()
+        .collect()
+}
+
+/// Report exported declarations without doc comments and measure coverage.
+pub fn detect_doc_coverage<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &DocCoverageConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let mut coverage = DocCoverage::default();
+
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if !config.include_tests() && facts.is_test_file(path) {
+            continue;
+        }
+        result.scanned += 1;
+
+        let declarations = counted(&file_facts.declarations, config);
+        if declarations.is_empty() {
+            continue;
+        }
+        let file = path.to_string_lossy();
+        let documentation = if file_facts.language == "python" { "docstring" } else { "doc comment" };
+        let undocumented: Vec<&&Declaration> = declarations.iter().filter(|d| !d.has_doc).collect();
+        let documented = declarations.len() - undocumented.len();
+
+        for decl in undocumented.iter().take(config.max_per_file()) {
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::DocCoverage,
+                    format!(
+                        "exported {} '{}' has no {}",
+                        decl.kind,
+                        decl.qualified_name(),
+                        documentation
+                    ),
+                )
+                .at(file.clone(), decl.span.start_line),
+            );
+        }
+        if let Some(first) = undocumented.get(config.max_per_file()) {
+            let more = undocumented.len() - config.max_per_file();
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::DocCoverage,
+                    format!(
+                        "{} more exported declaration{} in this file without a {} ({} of {} documented)",
+                        more,
+                        if more == 1 { "" } else { "s" },
+                        documentation,
+                        documented,
+                        declarations.len()
+                    ),
+                )
+                .at(file.clone(), first.span.start_line),
+            );
+        }
+        coverage.add_file(file.into_owned(), documented, declarations.len());
+    }
+
+    if let Some(min) = config.min_doc_coverage {
+        if coverage.total > 0 && coverage.coverage < min {
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::DocCoverage,
+                    format!(
+                        "documentation coverage of exported declarations is {:.1}% ({} of {}), below the contract's minimum of {:.1}%",
+                        coverage.coverage * 100.0,
+                        coverage.documented,
+                        coverage.total,
+                        min * 100.0
+                    ),
+                )
+                .with_severity(Severity::Error),
+            );
+        }
+    }
+
+    result.stats.doc_coverage = Some(coverage);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &DocCoverageConfig) -> DetectionResult {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, source)| {
+                let path = temp.path().join(name);
+                std::fs::write(&path, source).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::DocCoverage]);
+        detect_doc_coverage(&facts, &paths, config).unwrap()
+    }
+
+    const GO_SOURCE: &str = r#"package store
+
+// MaxItems caps a store.
+const MaxItems = 10
+
+const Timeout = 5
+
+// Store holds items.
+type Store struct{}
+
+// Get returns an item.
+func (s *Store) Get() {}
+
+func (s *Store) Put() {}
+
+func (s *Store) Delete() {}
+
+func New() *Store { return &Store{} }
+
+// helper is documented but private.
+func helper() {}
+"#;
+
+    #[test]
+    fn test_undocumented_exports_and_coverage() {
+        let result = detect(
+            &[("store.go", GO_SOURCE), ("store_test.go", "package store\n\nfunc TestGet() {}\n")],
+            &DocCoverageConfig::default(),
+        );
+
+        let found: Vec<_> = result.violations.iter().map(|v| (v.line, v.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (14, "exported method 'Store.Put' has no doc comment"),
+                (16, "exported method 'Store.Delete' has no doc comment"),
+                (18, "exported function 'New' has no doc comment"),
+            ]
+        );
+        assert!(result.violations.iter().all(|v| v.severity == Severity::Info));
+
+        // Constants and the test file are left out
+        let coverage = result.stats.doc_coverage.unwrap();
+        assert_eq!((coverage.documented, coverage.total, coverage.coverage), (2, 5, 0.4));
+        assert_eq!(coverage.files.len(), 1);
+        assert!(coverage.files[0].file.ends_with("store.go"));
+    }
+
+    #[test]
+    fn test_cap_and_coverage_gate() {
+        let config = DocCoverageConfig {
+            enabled: true,
+            min_doc_coverage: Some(0.8),
+            max_per_file: Some(1),
+            include_constants: Some(true),
+            include_tests: None,
+        };
+        let result = detect(&[("store.go", GO_SOURCE)], &config);
+
+        let found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.file.is_some(), v.severity, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (true, Severity::Info, "exported const 'Timeout' has no doc comment"),
+                (true, Severity::Info, "3 more exported declarations in this file without a doc comment (3 of 7 documented)"),
+                (
+                    false,
+                    Severity::Error,
+                    "documentation coverage of exported declarations is 42.9% (3 of 7), below the contract's minimum of 80.0%"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python_decorated_functions_count_once() {
+        let source = "import functools\n\n@functools.cache\ndef load():\n    \"\"\"Load it.\"\"\"\n\ndef save():\n    pass\n";
+        let result = detect(&[("store.py", source)], &DocCoverageConfig::default());
+
+        let coverage = result.stats.doc_coverage.unwrap();
+        assert_eq!((coverage.documented, coverage.total), (1, 2));
+        assert_eq!(result.violations[0].message, "exported function 'save' has no docstring");
+    }
+}
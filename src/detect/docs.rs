@@ -0,0 +1,696 @@
+//! Detection of documentation that describes API the code never defines.
+//!
+//! Generated deliveries often ship a polished README documenting functions,
+//! types, and CLI subcommands that were never built. This rule reads the
+//! markdown files configured under `doc_drift`, pulls API-looking references
+//! out of fenced code blocks and inline code spans, and checks each one
+//! against the declarations found in the project's source files.
+//!
+//! The rule is a reviewer aid rather than a gate: violations are reported at
+//! Info severity, and noise (short words, stdlib names, example output) is
+//! filtered heuristically and through the contract's `ignore` list.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use walkdir::WalkDir;
+
+use crate::contract::DocDriftConfig;
+
+use super::files::DEPENDENCY_DIRS;
+use super::globs::build_globs;
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// References shorter than this are too ambiguous to check.
+const MIN_REFERENCE_LEN: usize = 4;
+
+/// Fence info strings whose contents are data or output, never API usage.
+const SKIPPED_FENCES: &[&str] = &[
+    "text",
+    "txt",
+    "plain",
+    "plaintext",
+    "output",
+    "log",
+    "json",
+    "jsonc",
+    "yaml",
+    "yml",
+    "toml",
+    "ini",
+    "csv",
+    "xml",
+    "html",
+    "diff",
+    "markdown",
+    "md",
+    "mermaid",
+];
+
+/// Fence info strings for shell sessions; only the command pattern applies inside them.
+const SHELL_FENCES: &[&str] = &[
+    "sh",
+    "bash",
+    "zsh",
+    "fish",
+    "shell",
+    "console",
+    "shell-session",
+    "terminal",
+    "powershell",
+    "ps1",
+    "cmd",
+    "bat",
+];
+
+/// Keywords that look like calls (`while (`, `match (`) but are not references.
+const CALL_KEYWORDS: &[&str] = &[
+    "while", "match", "switch", "return", "catch", "elif", "except", "typeof", "sizeof", "await",
+    "yield", "assert", "lambda", "select", "unless", "until",
+];
+
+/// Keywords that introduce a definition in an example (`def handler(`).
+const DEFINITION_KEYWORDS: &[&str] = &["def", "fn", "func", "function", "class", "struct"];
+
+/// Standard library and runtime names that never need a project declaration.
+const BUILTIN_NAMES: &[&str] = &[
+    // Python
+    "print",
+    "input",
+    "open",
+    "range",
+    "dict",
+    "list",
+    "tuple",
+    "float",
+    "isinstance",
+    "super",
+    "enumerate",
+    "sorted",
+    "filter",
+    "Exception",
+    "ValueError",
+    "TypeError",
+    "KeyError",
+    "RuntimeError",
+    "NotImplementedError",
+    // JavaScript / TypeScript
+    "require",
+    "fetch",
+    "console",
+    "setTimeout",
+    "setInterval",
+    "parseInt",
+    "parseFloat",
+    "Promise",
+    "Object",
+    "Array",
+    "Error",
+    "JSON",
+    "Date",
+    "Math",
+    "Number",
+    "Boolean",
+    "Symbol",
+    "RegExp",
+    "Buffer",
+    "describe",
+    "expect",
+    // Go
+    "make",
+    "append",
+    "panic",
+    "string",
+    "error",
+    // Rust
+    "println",
+    "eprintln",
+    "format",
+    "String",
+    "HashMap",
+    "HashSet",
+    "BTreeMap",
+    "BTreeSet",
+    "Vec",
+    "Option",
+    "Result",
+    "Some",
+    "None",
+    "Box",
+    "Arc",
+    "Mutex",
+    "RwLock",
+    "Default",
+    "assert_eq",
+    "unwrap",
+    "expect",
+    // Product and technology names that look like CamelCase identifiers
+    "JavaScript",
+    "TypeScript",
+    "GitHub",
+    "GitLab",
+    "PostgreSQL",
+    "MySQL",
+    "MongoDB",
+    "NodeJS",
+    "PyPI",
+    "OpenAI",
+    "OpenAPI",
+    "GraphQL",
+    "WebSocket",
+    "OAuth",
+    "YouTube",
+    "LinkedIn",
+    "DevOps",
+    "PowerShell",
+    "VSCode",
+    "CocoaPods",
+];
+
+/// Qualifiers of calls into the standard library or a runtime global.
+const STDLIB_QUALIFIERS: &[&str] = &[
+    "std",
+    "core",
+    "alloc",
+    "fmt",
+    "os",
+    "io",
+    "sys",
+    "json",
+    "re",
+    "time",
+    "path",
+    "math",
+    "log",
+    "http",
+    "net",
+    "strings",
+    "strconv",
+    "errors",
+    "context",
+    "sync",
+    "bytes",
+    "console",
+    "Math",
+    "JSON",
+    "Object",
+    "Array",
+    "Promise",
+    "Number",
+    "Date",
+    "process",
+    "subprocess",
+    "logging",
+    "datetime",
+    "collections",
+    "itertools",
+    "functools",
+    "asyncio",
+    "typing",
+    "pathlib",
+    "shutil",
+    "random",
+    "window",
+    "document",
+];
+
+lazy_static! {
+    /// Inline code spans: `` `Client.connect()` ``.
+    static ref INLINE_CODE: Regex = Regex::new(r"`([^`]+)`").unwrap();
+    /// Call shapes with an optional qualifier: `client.fetch(`, `Config::load(`, `run(`.
+    static ref CALL: Regex =
+        Regex::new(r"\b((?:[A-Za-z_]\w*(?:\.|::))*)([A-Za-z_]\w*)\s*\(").unwrap();
+    /// CamelCase identifiers with at least two humps: `HttpClient`, `RetryPolicy`.
+    static ref CAMEL_CASE: Regex = Regex::new(r"\b[A-Z][a-z0-9]+(?:[A-Z][a-z0-9]*)+\b").unwrap();
+    /// String literal contents, blanked before extracting references.
+    static ref STRING_LITERAL: Regex = Regex::new(r#""[^"]*"|'[^']*'"#).unwrap();
+}
+
+/// How a documented reference is checked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ReferenceKind {
+    /// A function, method, or type name.
+    Api,
+    /// A CLI subcommand captured by the contract's command pattern.
+    Command,
+}
+
+/// An API reference found in a documentation file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct DocReference {
+    name: String,
+    /// First segment of the call qualifier (`requests` in `requests.get(`).
+    root: Option<String>,
+    line: usize,
+    kind: ReferenceKind,
+}
+
+/// How the lines of a fenced block are treated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FenceMode {
+    /// Source code: API references and commands are extracted.
+    Code,
+    /// Shell sessions and unlabeled blocks: only commands are extracted.
+    Commands,
+    /// Data and example output: nothing is extracted.
+    Skip,
+}
+
+/// Names the documentation may legitimately refer to.
+#[derive(Debug, Default)]
+struct DeclarationIndex {
+    /// Declaration and receiver names across the project.
+    names: HashSet<String>,
+    /// Import aliases and last path segments; calls through them are external API.
+    imports: HashSet<String>,
+    /// Normalized declaration names and short string literals, for subcommands.
+    commands: HashSet<String>,
+}
+
+impl DeclarationIndex {
+    fn resolves(&self, reference: &DocReference) -> bool {
+        match reference.kind {
+            ReferenceKind::Api => {
+                self.names.contains(&reference.name)
+                    || reference
+                        .root
+                        .as_ref()
+                        .is_some_and(|r| self.imports.contains(r))
+            }
+            ReferenceKind::Command => self.commands.contains(&normalize_command(&reference.name)),
+        }
+    }
+}
+
+/// Lowercase and drop separators so `list-files` matches `ListFiles` and `list_files`.
+fn normalize_command(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Decide how a fenced block is treated from its info string and the prose before it.
+fn fence_mode(info: &str, preceding_prose: &str) -> FenceMode {
+    let lang = info
+        .split(|c: char| c.is_whitespace() || c == ',' || c == '{')
+        .next()
+        .unwrap_or("")
+        .to_lowercase();
+    let prose = preceding_prose.to_lowercase();
+
+    if SKIPPED_FENCES.contains(&lang.as_str()) || prose.contains("output") {
+        FenceMode::Skip
+    } else if lang.is_empty() || SHELL_FENCES.contains(&lang.as_str()) {
+        FenceMode::Commands
+    } else {
+        FenceMode::Code
+    }
+}
+
+/// Strip string literals and trailing comments from a line of example code.
+fn strip_code_noise(line: &str) -> String {
+    let without_strings = STRING_LITERAL.replace_all(line, "\"\"");
+    let end = [" //", " #", "\t//", "\t#"]
+        .iter()
+        .filter_map(|marker| without_strings.find(marker))
+        .min()
+        .unwrap_or(without_strings.len());
+    let code = &without_strings[..end];
+    if code.trim_start().starts_with("//") || code.trim_start().starts_with('#') {
+        String::new()
+    } else {
+        code.to_string()
+    }
+}
+
+/// Returns true if a name is too short or too generic to be worth checking.
+fn is_noise(name: &str) -> bool {
+    name.len() < MIN_REFERENCE_LEN || BUILTIN_NAMES.contains(&name)
+}
+
+/// Extract function and type references from a snippet of code.
+fn extract_api_references(code: &str, line: usize, refs: &mut Vec<DocReference>) {
+    let mut push = |name: &str, root: Option<&str>| {
+        if !is_noise(name) {
+            refs.push(DocReference {
+                name: name.to_string(),
+                root: root.map(String::from),
+                line,
+                kind: ReferenceKind::Api,
+            });
+        }
+    };
+
+    for caps in CALL.captures_iter(code) {
+        let qualifier = caps.get(1).map_or("", |m| m.as_str());
+        let name = &caps[2];
+        let start = caps.get(1).map_or(0, |m| m.start());
+
+        let preceding_word = code[..start].split_whitespace().next_back().unwrap_or("");
+        if CALL_KEYWORDS.contains(&name) || DEFINITION_KEYWORDS.contains(&preceding_word) {
+            continue;
+        }
+
+        let segments: Vec<&str> = qualifier
+            .split(['.', ':'])
+            .filter(|s| !s.is_empty())
+            .collect();
+        if segments
+            .first()
+            .is_some_and(|root| STDLIB_QUALIFIERS.contains(root))
+        {
+            continue;
+        }
+        // `Config::load(` also documents the `Config` type
+        let root = segments.first().copied();
+        if let Some(owner) = segments
+            .last()
+            .filter(|s| s.starts_with(char::is_uppercase))
+        {
+            push(owner, root.filter(|r| r != owner));
+        }
+        push(name, root);
+    }
+
+    for m in CAMEL_CASE.find_iter(code) {
+        push(m.as_str(), None);
+    }
+}
+
+/// Extract command references using the contract's command pattern.
+fn extract_command_references(
+    text: &str,
+    line: usize,
+    command_pattern: Option<&Regex>,
+    refs: &mut Vec<DocReference>,
+) {
+    let Some(re) = command_pattern else {
+        return;
+    };
+    for caps in re.captures_iter(text) {
+        if let Some(m) = caps.get(1) {
+            refs.push(DocReference {
+                name: m.as_str().to_string(),
+                root: None,
+                line,
+                kind: ReferenceKind::Command,
+            });
+        }
+    }
+}
+
+/// Extract references from fenced code blocks and inline code spans of a markdown file.
+fn extract_references(content: &str, command_pattern: Option<&Regex>) -> Vec<DocReference> {
+    let mut refs = Vec::new();
+    let mut fence: Option<(String, FenceMode)> = None;
+    let mut last_prose = "";
+
+    for (idx, raw_line) in content.lines().enumerate() {
+        let line_num = idx + 1;
+        let trimmed = raw_line.trim_start();
+
+        if let Some((marker, mode)) = &fence {
+            let closes = trimmed.starts_with(marker.as_str())
+                && trimmed.trim_start_matches(['`', '~']).trim().is_empty();
+            if closes {
+                fence = None;
+                last_prose = "";
+                continue;
+            }
+            match mode {
+                FenceMode::Code => {
+                    let code = strip_code_noise(raw_line);
+                    extract_command_references(&code, line_num, command_pattern, &mut refs);
+                    extract_api_references(&code, line_num, &mut refs);
+                }
+                FenceMode::Commands => {
+                    extract_command_references(raw_line, line_num, command_pattern, &mut refs);
+                }
+                FenceMode::Skip => {}
+            }
+            continue;
+        }
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            let marker_len = trimmed
+                .chars()
+                .take_while(|c| *c == '`' || *c == '~')
+                .count();
+            let (marker, info) = trimmed.split_at(marker_len);
+            fence = Some((marker.to_string(), fence_mode(info.trim(), last_prose)));
+            continue;
+        }
+
+        for caps in INLINE_CODE.captures_iter(raw_line) {
+            let span = &caps[1];
+            let before = refs.len();
+            extract_command_references(span, line_num, command_pattern, &mut refs);
+            if refs.len() == before {
+                extract_api_references(span, line_num, &mut refs);
+            }
+        }
+
+        if !trimmed.is_empty() {
+            last_prose = trimmed;
+        }
+    }
+
+    refs
+}
+
+/// Build the set of names documentation can resolve against from the project's sources.
+fn build_index<P: AsRef<Path>>(facts: &FactsBundle, files: &[P]) -> (DeclarationIndex, usize) {
+    let mut index = DeclarationIndex::default();
+    let mut scanned = 0;
+
+    for file in files {
+        let Some(Ok(file_facts)) = facts.file(file.as_ref()) else {
+            continue;
+        };
+        scanned += 1;
+
+        for decl in &file_facts.declarations {
+            index.commands.insert(normalize_command(&decl.name));
+            index.names.insert(decl.name.clone());
+            if let Some(receiver) = &decl.receiver {
+                index.names.insert(receiver.clone());
+            }
+        }
+        for import in &file_facts.imports {
+            let root = match &import.alias {
+                Some(alias) => alias.as_str(),
+                None => import.path.rsplit(['/', '.', ':']).next().unwrap_or(""),
+            };
+            index.imports.insert(root.to_string());
+        }
+        for literal in &file_facts.string_literals {
+            if literal.value.len() <= 40 && !literal.value.contains(char::is_whitespace) {
+                index.commands.insert(normalize_command(&literal.value));
+            }
+        }
+    }
+
+    (index, scanned)
+}
+
+/// Check documentation files for references to API the project never declares.
+///
+/// Docs are found by walking the scan's base directory for files matching the configured
+/// globs (default `README.md`). Each unresolved name is reported once per
+/// doc file, at its first occurrence. Nothing is reported when no source
+/// file could be analyzed, since every reference would be unresolved.
+pub fn detect_doc_drift<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: Option<&DocDriftConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    let config = match config {
+        Some(c) if c.is_enabled() => c,
+        _ => return Ok(result),
+    };
+
+    let doc_globs = build_globs("doc_drift docs pattern", &config.doc_globs())?;
+    let command_pattern = config
+        .command_pattern
+        .as_ref()
+        .map(|p| Regex::new(p))
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("invalid doc_drift command_pattern: {}", e))?;
+
+    let base_dir = facts.base_dir();
+    let (index, scanned) = build_index(facts, files);
+    if scanned == 0 {
+        return Ok(result);
+    }
+
+    let walker = WalkDir::new(base_dir).into_iter().filter_entry(|e| {
+        let name = e.file_name().to_string_lossy();
+        !(e.depth() > 0
+            && e.file_type().is_dir()
+            && (name.starts_with('.') || DEPENDENCY_DIRS.contains(&name.as_ref())))
+    });
+    let mut docs: Vec<_> = walker
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let rel = e
+                .path()
+                .strip_prefix(base_dir)
+                .ok()?
+                .to_string_lossy()
+                .to_string();
+            doc_globs.is_match(&rel).then(|| (rel, e.into_path()))
+        })
+        .collect();
+    docs.sort();
+
+    for (rel_path, path) in docs {
+        let content = match std::fs::read_to_string(&path) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+        result.scanned += 1;
+
+        let mut reported = HashSet::new();
+        for reference in extract_references(&content, command_pattern.as_ref()) {
+            if config.ignore.contains(&reference.name)
+                || index.resolves(&reference)
+                || !reported.insert((reference.kind, reference.name.clone()))
+            {
+                continue;
+            }
+
+            let message = match reference.kind {
+                ReferenceKind::Api => format!(
+                    "documentation references {:?}, but no declaration with that name exists in the code",
+                    reference.name
+                ),
+                ReferenceKind::Command => format!(
+                    "documentation shows subcommand {:?}, but nothing in the code defines it",
+                    reference.name
+                ),
+            };
+            result.add_violation(Violation {
+                rule: ViolationRule::DocDrift,
+                message,
+                file: Some(rel_path.clone()),
+                line: reference.line,
+                severity: ViolationRule::DocDrift.default_severity(),
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn config(command_pattern: Option<&str>, ignore: &[&str]) -> DocDriftConfig {
+        DocDriftConfig {
+            enabled: true,
+            docs: vec![],
+            command_pattern: command_pattern.map(String::from),
+            ignore: ignore.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_extract_references_skips_output_and_definitions() {
+        let doc = r#"# Usage
+
+Call `Client.connect()` or `retryPolicy()`; set `x` to 1.
+
+```python
+def handler(event):
+    client = RateLimiter(limit=5)
+    client.throttle(event)  # also see HiddenName
+    print(json.dumps(event))
+```
+
+Example output:
+
+```python
+MissingOutputName()
+```
+"#;
+        let refs = extract_references(doc, None);
+        let names: Vec<_> = refs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Client",
+                "connect",
+                "retryPolicy",
+                "RateLimiter",
+                "RateLimiter",
+                "throttle"
+            ]
+        );
+        assert_eq!(refs[0].line, 3);
+        assert_eq!(refs[3].line, 7);
+    }
+
+    #[test]
+    fn test_detect_undocumented_api_and_commands() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("cli.py");
+        std::fs::write(
+            &source,
+            "import requests\n\nclass Client:\n    def connect(self):\n        return requests.get(\"https://x\")\n\ndef list_files():\n    pass\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("README.md"),
+            "# Tool\n\n```bash\nmytool list-files\nmytool export --all\n```\n\nUse `Client.connect()` then `Client.disconnect()`.\n\n```python\nrequests.post(url)\nSyncEngine.start()\n```\n",
+        )
+        .unwrap();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let cfg = config(Some(r"mytool\s+([a-z][\w-]*)"), &["start"]);
+        let facts = FactsBundle::for_rules(&ctx, &[&source], &[ViolationRule::DocDrift]);
+        let result = detect_doc_drift(&facts, &[&source], Some(&cfg)).unwrap();
+
+        let found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.line, v.message.split('"').nth(1).unwrap().to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (5, "export".to_string()),
+                (8, "disconnect".to_string()),
+                (12, "SyncEngine".to_string()),
+            ]
+        );
+        assert!(result
+            .violations
+            .iter()
+            .all(|v| v.rule == ViolationRule::DocDrift
+                && v.severity == super::super::Severity::Info
+                && v.location() == "README.md"));
+    }
+
+    #[test]
+    fn test_disabled_without_config() {
+        let temp = TempDir::new().unwrap();
+        let ctx = AnalysisContext::new(temp.path());
+        let files: Vec<std::path::PathBuf> = vec![];
+        let facts = FactsBundle::for_rules(&ctx, &files, &[ViolationRule::DocDrift]);
+        assert!(detect_doc_drift(&facts, &files, None)
+            .unwrap()
+            .violations
+            .is_empty());
+    }
+}
@@ -0,0 +1,660 @@
+//! Heuristic estimate of the work left behind hollow functions.
+//!
+//! "How much is left" has no exact answer, but a rough, repeatable one beats
+//! a violation count. Each `stub_function` and `low_complexity` finding gets
+//! points from static signals of the function it points at: its declared
+//! parameters, how much its return type promises, how much its doc comment,
+//! docstring, or TODO text describes, how many call sites elsewhere depend on
+//! it, and whether it is part of its module's exported API. The points place
+//! it in a trivial, small, medium, or large bucket, and the report rolls the
+//! buckets up into counts and a weighted total.
+//!
+//! The estimate is deterministic: the same source and contract always give
+//! the same buckets. It is not a schedule. There is no type resolution, call
+//! sites match by name and are only indexed where the analyzer records them,
+//! and findings whose function cannot be located get no estimate.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::Declaration;
+use crate::contract::{EstimationBucket, EstimationConfig};
+
+use super::{FactKind, FactsBundle, Violation, ViolationRule};
+
+/// Fact kinds the estimate reads.
+pub const ESTIMATION_FACTS: &[FactKind] = &[FactKind::Declarations, FactKind::Bodies, FactKind::CallIndex];
+
+/// Signal units counted at most, so one signal cannot decide the bucket alone.
+const MAX_UNITS: u32 = 5;
+
+/// Words of description per unit of the description signal.
+const WORDS_PER_UNIT: usize = 10;
+
+/// Rough size of the work left in a hollow function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EffortBucket {
+    Trivial,
+    Small,
+    Medium,
+    Large,
+}
+
+impl EffortBucket {
+    /// All buckets, smallest first.
+    pub const ALL: [EffortBucket; 4] = [
+        EffortBucket::Trivial,
+        EffortBucket::Small,
+        EffortBucket::Medium,
+        EffortBucket::Large,
+    ];
+
+    /// Name used in contracts and reports.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EffortBucket::Trivial => "trivial",
+            EffortBucket::Small => "small",
+            EffortBucket::Medium => "medium",
+            EffortBucket::Large => "large",
+        }
+    }
+}
+
+impl std::fmt::Display for EffortBucket {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Points each signal contributed to an estimate.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffortBasis {
+    pub parameters: u32,
+    pub return_type: u32,
+    pub description: u32,
+    pub references: u32,
+    pub exported: u32,
+}
+
+impl EffortBasis {
+    fn total(&self) -> u32 {
+        self.parameters + self.return_type + self.description + self.references + self.exported
+    }
+}
+
+/// Estimated effort for one finding.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Effort {
+    pub bucket: EffortBucket,
+    pub points: u32,
+    /// Points by signal, which add up to `points`
+    pub basis: EffortBasis,
+}
+
+/// Findings per bucket across the project, and their weighted total.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EffortEstimate {
+    /// Always true: the figures come from static signals, not measurement
+    pub heuristic: bool,
+    pub trivial: usize,
+    pub small: usize,
+    pub medium: usize,
+    pub large: usize,
+    /// Sum of the bucket weights of every estimated finding
+    pub weighted_total: u32,
+}
+
+impl EffortEstimate {
+    fn add(&mut self, effort: &Effort, model: &EffortModel) {
+        *self.count_mut(effort.bucket) += 1;
+        self.weighted_total += model.weight(effort.bucket);
+    }
+
+    fn count_mut(&mut self, bucket: EffortBucket) -> &mut usize {
+        match bucket {
+            EffortBucket::Trivial => &mut self.trivial,
+            EffortBucket::Small => &mut self.small,
+            EffortBucket::Medium => &mut self.medium,
+            EffortBucket::Large => &mut self.large,
+        }
+    }
+
+    /// Findings estimated in a bucket.
+    pub fn count(&self, bucket: EffortBucket) -> usize {
+        match bucket {
+            EffortBucket::Trivial => self.trivial,
+            EffortBucket::Small => self.small,
+            EffortBucket::Medium => self.medium,
+            EffortBucket::Large => self.large,
+        }
+    }
+
+    /// Findings estimated in all buckets.
+    pub fn total(&self) -> usize {
+        self.trivial + self.small + self.medium + self.large
+    }
+
+    /// Add another run's estimate to this one.
+    pub fn merge(&mut self, other: EffortEstimate) {
+        self.heuristic |= other.heuristic;
+        self.trivial += other.trivial;
+        self.small += other.small;
+        self.medium += other.medium;
+        self.large += other.large;
+        self.weighted_total += other.weighted_total;
+    }
+}
+
+/// How much a function's return type promises.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReturnKind {
+    /// Nothing, `void`, `()`, `None`, or a type the signature does not state
+    None,
+    /// One plain type
+    Plain,
+    /// A generic, tuple, union, or multiple result type
+    Compound,
+}
+
+impl ReturnKind {
+    fn units(self) -> u32 {
+        match self {
+            ReturnKind::None => 0,
+            ReturnKind::Plain => 1,
+            ReturnKind::Compound => 2,
+        }
+    }
+}
+
+/// Static signals of one hollow function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Signals {
+    params: usize,
+    return_kind: ReturnKind,
+    description_words: usize,
+    references: usize,
+    exported: bool,
+}
+
+/// Signal weights and bucket settings, with the contract's values over the defaults.
+///
+/// Defaults: 1 point per parameter, 2 per return type unit, 1 per ten words
+/// of description, 1 per call site, and 2 for an exported function. Small
+/// starts at 3 points, medium at 7, and large at 12; the roll-up weights are
+/// 1, 2, 5, and 13.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EffortModel {
+    parameter: u32,
+    return_type: u32,
+    description: u32,
+    reference: u32,
+    exported: u32,
+    /// Fewest points for small, medium, and large
+    thresholds: [u32; 3],
+    /// Roll-up weights of trivial, small, medium, and large
+    weights: [u32; 4],
+}
+
+impl Default for EffortModel {
+    fn default() -> Self {
+        Self::from_config(&EstimationConfig::default())
+    }
+}
+
+impl EffortModel {
+    /// Resolve a contract's `estimation` section.
+    pub fn from_config(config: &EstimationConfig) -> Self {
+        let signals = &config.signals;
+        let buckets = &config.buckets;
+        let min_points = |bucket: &Option<EstimationBucket>, default| {
+            bucket.as_ref().and_then(|b| b.min_points).unwrap_or(default)
+        };
+        let weight = |bucket: &Option<EstimationBucket>, default| {
+            bucket.as_ref().and_then(|b| b.weight).unwrap_or(default)
+        };
+        Self {
+            parameter: signals.parameter.unwrap_or(1),
+            return_type: signals.return_type.unwrap_or(2),
+            description: signals.description.unwrap_or(1),
+            reference: signals.reference.unwrap_or(1),
+            exported: signals.exported.unwrap_or(2),
+            thresholds: [
+                min_points(&buckets.small, 3),
+                min_points(&buckets.medium, 7),
+                min_points(&buckets.large, 12),
+            ],
+            weights: [
+                weight(&buckets.trivial, 1),
+                weight(&buckets.small, 2),
+                weight(&buckets.medium, 5),
+                weight(&buckets.large, 13),
+            ],
+        }
+    }
+
+    /// Fewest points for small, medium, and large.
+    pub fn thresholds(&self) -> [u32; 3] {
+        self.thresholds
+    }
+
+    /// Roll-up weight of a bucket.
+    pub fn weight(&self, bucket: EffortBucket) -> u32 {
+        self.weights[bucket as usize]
+    }
+
+    fn bucket(&self, points: u32) -> EffortBucket {
+        let [small, medium, large] = self.thresholds;
+        if points >= large {
+            EffortBucket::Large
+        } else if points >= medium {
+            EffortBucket::Medium
+        } else if points >= small {
+            EffortBucket::Small
+        } else {
+            EffortBucket::Trivial
+        }
+    }
+
+    fn estimate(&self, signals: &Signals) -> Effort {
+        let units = |n: usize| (n as u32).min(MAX_UNITS);
+        let basis = EffortBasis {
+            parameters: units(signals.params) * self.parameter,
+            return_type: signals.return_kind.units() * self.return_type,
+            description: units(signals.description_words / WORDS_PER_UNIT) * self.description,
+            references: units(signals.references) * self.reference,
+            exported: if signals.exported { self.exported } else { 0 },
+        };
+        let points = basis.total();
+        Effort {
+            bucket: self.bucket(points),
+            points,
+            basis,
+        }
+    }
+}
+
+/// Whether a rule's findings point at a hollow function to estimate.
+fn is_estimated(rule: ViolationRule) -> bool {
+    matches!(rule, ViolationRule::StubFunction | ViolationRule::LowComplexity)
+}
+
+/// Estimate the effort behind each hollow-function finding in `violations`.
+///
+/// Returns the estimates keyed by `Violation::key()` and the project roll-up.
+pub fn estimate_effort(
+    facts: &FactsBundle,
+    violations: &[Violation],
+    config: &EstimationConfig,
+) -> (BTreeMap<String, Effort>, EffortEstimate) {
+    let model = EffortModel::from_config(config);
+    let mut efforts = BTreeMap::new();
+    let mut estimate = EffortEstimate {
+        heuristic: true,
+        ..Default::default()
+    };
+    let mut sources: BTreeMap<PathBuf, Option<String>> = BTreeMap::new();
+
+    for violation in violations.iter().filter(|v| is_estimated(v.rule) && v.line > 0) {
+        let Some(file) = violation.file.as_deref() else {
+            continue;
+        };
+        // Complexity findings carry paths relative to the scan root
+        let path = [Path::new(file).to_path_buf(), facts.base_dir().join(file)]
+            .into_iter()
+            .find(|p| facts.file(p).is_some());
+        let Some(path) = path else {
+            continue;
+        };
+        let Some(Ok(file_facts)) = facts.file(&path) else {
+            continue;
+        };
+        let Some(decl) = function_at(&file_facts.declarations, violation.line) else {
+            continue;
+        };
+        let Some(source) = sources
+            .entry(path.clone())
+            .or_insert_with(|| std::fs::read_to_string(&path).ok())
+            .as_deref()
+        else {
+            continue;
+        };
+
+        let signals = Signals {
+            params: decl.param_count.unwrap_or(0),
+            return_kind: return_kind(&file_facts.language, signature(source, decl), &decl.name),
+            description_words: description_words(source, decl),
+            references: facts
+                .calls_to(&decl.name)
+                .iter()
+                .filter(|call| {
+                    call.file != path || call.line < decl.span.start_line || call.line > decl.span.end_line
+                })
+                .count(),
+            exported: decl.is_exported,
+        };
+        let effort = model.estimate(&signals);
+        estimate.add(&effort, &model);
+        efforts.insert(violation.key(), effort);
+    }
+
+    (efforts, estimate)
+}
+
+/// The callable declared on `line`, the innermost one if several start there.
+fn function_at(declarations: &[Declaration], line: usize) -> Option<&Declaration> {
+    declarations
+        .iter()
+        .filter(|d| d.kind.is_callable() && d.span.start_line == line)
+        .min_by_key(|d| d.span.end_byte - d.span.start_byte)
+}
+
+/// Source of a declaration up to its body.
+pub(super) fn signature<'a>(source: &'a str, decl: &Declaration) -> &'a str {
+    let end = decl.body.as_ref().map_or(decl.span.end_byte, |b| b.span.start_byte);
+    source.get(decl.span.start_byte..end.max(decl.span.start_byte)).unwrap_or("")
+}
+
+/// Classify the return type a signature declares.
+///
+/// The type is read after `->` (Rust, Python, Swift), after the parameter
+/// list (Go), after a `:` following it (TypeScript, Scala), or before the
+/// name (Java, C, C++). JavaScript declares none.
+fn return_kind(language: &str, signature: &str, name: &str) -> ReturnKind {
+    let Some((before, after)) = split_at_params(signature, name) else {
+        return ReturnKind::None;
+    };
+    let declared = match language {
+        "rust" | "python" | "swift" => after.split_once("->").map(|(_, t)| {
+            let t = t.split(" where ").next().unwrap_or(t);
+            t.trim().trim_end_matches([':', '{']).trim()
+        }),
+        "go" => Some(after.trim().trim_end_matches('{').trim()),
+        "typescript" | "scala" => after
+            .trim_start()
+            .strip_prefix(':')
+            .map(|t| t.split("=>").next().unwrap_or(t).trim().trim_end_matches(['{', '=']).trim()),
+        "java" | "c" | "cpp" => Some(type_before_name(before)),
+        _ => None,
+    };
+    match declared {
+        None | Some("" | "void" | "()" | "None" | "Unit" | "Void" | "Promise<void>") => ReturnKind::None,
+        Some(t) if t.contains(['<', '[', '(', ',', '|', '{']) => ReturnKind::Compound,
+        Some(_) => ReturnKind::Plain,
+    }
+}
+
+/// Split a signature around the parameter list that follows `name`: the text
+/// before the name, and the text after the closing parenthesis.
+pub(super) fn split_at_params<'a>(signature: &'a str, name: &str) -> Option<(&'a str, &'a str)> {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    let mut search = 0;
+    while let Some(found) = signature[search..].find(name) {
+        let start = search + found;
+        let end = start + name.len();
+        search = end;
+        if signature[..start].ends_with(is_ident) || signature[end..].starts_with(is_ident) {
+            continue;
+        }
+        // Generic parameters may sit between the name and the parameter list
+        let mut rest = signature[end..].trim_start();
+        if rest.starts_with(['<', '[']) {
+            rest = &rest[balanced_len(rest)?..];
+            rest = rest.trim_start();
+        }
+        if !rest.starts_with('(') {
+            continue;
+        }
+        let params_len = balanced_len(rest)?;
+        return Some((&signature[..start], &rest[params_len..]));
+    }
+    None
+}
+
+/// Length of the bracketed group `text` opens, through its closing bracket.
+fn balanced_len(text: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in text.char_indices() {
+        match c {
+            '(' | '[' | '<' | '{' => depth += 1,
+            ')' | ']' | '>' | '}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The return type written before a Java, C, or C++ function name, without
+/// modifiers, annotations, type parameters, or a `Class::` qualifier.
+fn type_before_name(before: &str) -> &str {
+    const MODIFIERS: &[&str] = &[
+        "public", "private", "protected", "static", "final", "abstract", "synchronized", "native",
+        "default", "inline", "virtual", "extern", "constexpr", "explicit", "friend",
+    ];
+    let mut text = before.trim();
+    // A qualifier glued to the name belongs to it, not to the type
+    while text.ends_with("::") {
+        text = text[..text.len() - 2].trim_end_matches(|c: char| c.is_alphanumeric() || c == '_');
+    }
+    loop {
+        text = text.trim_start();
+        if text.starts_with('@') {
+            let len = text.find(char::is_whitespace).unwrap_or(text.len());
+            text = &text[len..];
+        } else if text.starts_with('<') {
+            match balanced_len(text) {
+                Some(len) => text = &text[len..],
+                None => break,
+            }
+        } else if let Some(word) = MODIFIERS
+            .iter()
+            .find(|m| text.strip_prefix(*m).is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace)))
+        {
+            text = &text[word.len()..];
+        } else {
+            break;
+        }
+    }
+    text.trim()
+}
+
+/// Words of description the function carries: the comment block right
+/// above it and the comments and docstrings in its body, TODO text included.
+fn description_words(source: &str, decl: &Declaration) -> usize {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut words = 0;
+
+    // Doc comments above, past attributes and decorators
+    let mut line = decl.span.start_line.saturating_sub(1);
+    while line > 0 {
+        let text = lines.get(line - 1).map_or("", |l| l.trim());
+        line -= 1;
+        if text.starts_with("#[") || text.starts_with('@') {
+            continue;
+        }
+        match comment_text(text) {
+            Some(comment) => words += count_words(comment),
+            None => break,
+        }
+    }
+
+    let Some(body) = &decl.body else {
+        return words;
+    };
+    let mut in_docstring = false;
+    for text in body.text.lines().map(str::trim) {
+        let quotes = text.matches("\"\"\"").count() + text.matches("'''").count();
+        if in_docstring || quotes > 0 {
+            words += count_words(&text.replace("\"\"\"", " ").replace("'''", " "));
+            in_docstring ^= quotes % 2 == 1;
+        } else if let Some(comment) = comment_text(text) {
+            words += count_words(comment);
+        }
+    }
+    words
+}
+
+/// The text of a comment line, without its comment markers.
+fn comment_text(line: &str) -> Option<&str> {
+    ["///", "//!", "//", "/**", "/*", "*/", "*", "#", "--"]
+        .iter()
+        .find_map(|prefix| line.strip_prefix(prefix))
+        .map(|text| text.trim_end_matches("*/"))
+}
+
+fn count_words(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_effort_buckets() {
+        let model = EffortModel::default();
+        let signals = |params, return_kind, description_words, references, exported| Signals {
+            params,
+            return_kind,
+            description_words,
+            references,
+            exported,
+        };
+        // (signals, points, bucket)
+        let cases = [
+            (signals(0, ReturnKind::None, 0, 0, false), 0, EffortBucket::Trivial),
+            (signals(1, ReturnKind::None, 9, 1, false), 2, EffortBucket::Trivial),
+            (signals(1, ReturnKind::Plain, 0, 0, false), 3, EffortBucket::Small),
+            (signals(2, ReturnKind::None, 12, 0, true), 5, EffortBucket::Small),
+            (signals(1, ReturnKind::Compound, 10, 0, true), 8, EffortBucket::Medium),
+            (signals(2, ReturnKind::Compound, 25, 3, true), 13, EffortBucket::Large),
+            // Each capped signal stops at five units
+            (signals(9, ReturnKind::None, 0, 0, false), 5, EffortBucket::Small),
+            (signals(0, ReturnKind::None, 200, 40, false), 10, EffortBucket::Medium),
+            (signals(9, ReturnKind::Compound, 200, 40, true), 21, EffortBucket::Large),
+        ];
+        for (signals, points, bucket) in cases {
+            let effort = model.estimate(&signals);
+            assert_eq!((effort.points, effort.bucket), (points, bucket), "{:?}", signals);
+            assert_eq!(effort.basis.total(), effort.points);
+        }
+
+        // Contract weights and thresholds replace the defaults
+        let config: EstimationConfig = serde_yaml::from_str(
+            "signals:\n  exported: 10\nbuckets:\n  large:\n    min_points: 10\n    weight: 20\n",
+        )
+        .unwrap();
+        let model = EffortModel::from_config(&config);
+        let effort = model.estimate(&signals(0, ReturnKind::None, 0, 0, true));
+        assert_eq!((effort.points, effort.bucket), (10, EffortBucket::Large));
+        assert_eq!(model.thresholds(), [3, 7, 10]);
+        assert_eq!(model.weight(EffortBucket::Large), 20);
+        assert_eq!(model.weight(EffortBucket::Medium), 5);
+    }
+
+    #[test]
+    fn test_return_kind() {
+        // (language, signature, name, kind)
+        let cases = [
+            ("go", "func Load(path string) ", "Load", ReturnKind::None),
+            ("go", "func (s *Store) Get(id int) *Item ", "Get", ReturnKind::Plain),
+            ("go", "func Parse(data []byte) (Config, error) ", "Parse", ReturnKind::Compound),
+            ("go", "func Map[T any](items []T) []T ", "Map", ReturnKind::Compound),
+            ("rust", "pub fn run(&self) ", "run", ReturnKind::None),
+            ("rust", "fn size(&self) -> usize ", "size", ReturnKind::Plain),
+            ("rust", "fn load<P: AsRef<Path>>(p: P) -> Result<Config> ", "load", ReturnKind::Compound),
+            ("rust", "fn unit() -> () ", "unit", ReturnKind::None),
+            ("python", "def handle(self, event) -> None:", "handle", ReturnKind::None),
+            ("python", "def total(items) -> int:", "total", ReturnKind::Plain),
+            ("python", "def pairs(items) -> list[tuple[str, int]]:", "pairs", ReturnKind::Compound),
+            ("python", "def untyped(x):", "untyped", ReturnKind::None),
+            ("typescript", "export function render(): string ", "render", ReturnKind::Plain),
+            ("typescript", "async function fetchAll(): Promise<User[]> ", "fetchAll", ReturnKind::Compound),
+            ("typescript", "function reset(): void ", "reset", ReturnKind::None),
+            ("javascript", "function compute(a, b) ", "compute", ReturnKind::None),
+            ("java", "@Override public void close() ", "close", ReturnKind::None),
+            ("java", "public static int count(String s) ", "count", ReturnKind::Plain),
+            ("java", "public <T> List<T> filter(List<T> items) ", "filter", ReturnKind::Compound),
+            ("java", "public Service(Config config) ", "Service", ReturnKind::None),
+            ("cpp", "int Widget::size() const ", "size", ReturnKind::Plain),
+            ("c", "static void cleanup(struct ctx *c) ", "cleanup", ReturnKind::None),
+            ("swift", "func load() -> [String] ", "load", ReturnKind::Compound),
+            ("scala", "def name(id: Int): String =", "name", ReturnKind::Plain),
+        ];
+        for (language, signature, name, kind) in cases {
+            assert_eq!(return_kind(language, signature, name), kind, "{}: {}", language, signature);
+        }
+    }
+
+    #[test]
+    fn test_estimate_effort() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let store = temp.path().join("store.py");
+        let app = temp.path().join("app.py");
+        std::fs::write(
+            &store,
+            r#"def load_config(path: str, strict: bool) -> dict[str, str]:
+    """Read the YAML file at path, validate every section against the
+    schema, and return the merged settings with defaults applied."""
+    pass
+
+
+def _helper():
+    pass
+"#,
+        )
+        .unwrap();
+        std::fs::write(&app, "from store import load_config\n\nload_config('a', True)\nload_config('b', False)\n").unwrap();
+        let files = vec![app, store.clone()];
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::build(&ctx, &files, ESTIMATION_FACTS);
+        let file = store.to_string_lossy();
+        let violations = vec![
+            Violation::new(ViolationRule::StubFunction, "stub function \"load_config\"").at(file.clone(), 1),
+            Violation::new(ViolationRule::StubFunction, "stub function \"_helper\"").at(file.clone(), 7),
+            Violation::new(ViolationRule::MockData, "mock data").at(file.clone(), 1),
+        ];
+
+        let (efforts, estimate) = estimate_effort(&facts, &violations, &EstimationConfig::default());
+        assert_eq!(efforts.len(), 2);
+        let load = &efforts[&violations[0].key()];
+        assert_eq!(
+            load.basis,
+            EffortBasis {
+                parameters: 2,
+                return_type: 4,
+                description: 2,
+                references: 2,
+                exported: 2,
+            }
+        );
+        assert_eq!((load.points, load.bucket), (12, EffortBucket::Large));
+        let helper = &efforts[&violations[1].key()];
+        assert_eq!((helper.points, helper.bucket), (0, EffortBucket::Trivial));
+        assert_eq!(
+            estimate,
+            EffortEstimate {
+                heuristic: true,
+                trivial: 1,
+                small: 0,
+                medium: 0,
+                large: 1,
+                weighted_total: 14,
+            }
+        );
+    }
+}
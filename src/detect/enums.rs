@@ -0,0 +1,323 @@
+//! Detection of switches that leave most enum variants to a trivial default.
+//!
+//! A recurring hollow shape: a switch over a known enum implements two cases
+//! and sends the rest to `default: return nil` or `_ => {}`, silently
+//! dropping behavior. Variants come from `Declaration::members` (Go `iota`
+//! blocks, TypeScript enums and string literal unions, Rust enums) and case
+//! labels from `FileFacts::switches`, both read from the `FactsBundle`.
+//!
+//! Which enum a switch is over is inferred from its labels: every label must
+//! name a variant of the enum, and a qualified label (`Status.Active`,
+//! `Shape::Circle`) must be qualified with the enum's name. Switches whose
+//! labels fit more than one enum are skipped.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use crate::analysis::{DeclarationKind, SwitchStatement};
+use crate::contract::UnhandledEnumCasesConfig;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// An enum declared in the scanned tree.
+struct EnumDecl<'a> {
+    name: &'a str,
+    variants: &'a [String],
+    /// TypeScript union of string literal types, matched by quoted labels.
+    is_literal_union: bool,
+}
+
+/// A case label split into its qualifier and variant name.
+struct Label<'a> {
+    qualifier: Option<&'a str>,
+    variant: &'a str,
+    is_quoted: bool,
+}
+
+impl<'a> Label<'a> {
+    fn parse(text: &'a str) -> Self {
+        let quotes: &[char] = &['"', '\'', '`'];
+        if text.starts_with(quotes) {
+            return Self {
+                qualifier: None,
+                variant: text.trim_matches(quotes),
+                is_quoted: true,
+            };
+        }
+        let split = match text.rfind("::") {
+            Some(i) => Some((i, 2)),
+            None => text.rfind('.').map(|i| (i, 1)),
+        };
+        let (qualifier, variant) = match split {
+            Some((i, sep)) => (Some(&text[..i]), &text[i + sep..]),
+            None => (None, text),
+        };
+        // Only the last path segment names the enum (`models::Status::Active`)
+        let qualifier = qualifier.map(|q| q.rsplit(['.', ':']).next().unwrap_or(q));
+        Self {
+            qualifier,
+            variant,
+            is_quoted: false,
+        }
+    }
+
+    fn fits(&self, decl: &EnumDecl, language: &str) -> bool {
+        if self.is_quoted != decl.is_literal_union || !decl.variants.iter().any(|v| v == self.variant) {
+            return false;
+        }
+        match self.qualifier {
+            None => true,
+            Some(q) => q == decl.name || q == "Self" || language == "go", // Go qualifies with the package
+        }
+    }
+}
+
+/// Flag switches over a project enum that handle fewer than `min_coverage`
+/// of its variants and send the rest to an empty or trivial default.
+pub fn detect_unhandled_enum_cases<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &UnhandledEnumCasesConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    // Enums of each language, across the whole tree
+    let mut enums: BTreeMap<&str, Vec<EnumDecl>> = BTreeMap::new();
+    for path in &sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        for decl in &file_facts.declarations {
+            if decl.members.is_empty()
+                || !matches!(decl.kind, DeclarationKind::Enum | DeclarationKind::Type)
+            {
+                continue;
+            }
+            enums.entry(&file_facts.language).or_default().push(EnumDecl {
+                name: &decl.name,
+                variants: &decl.members,
+                is_literal_union: file_facts.language == "typescript"
+                    && decl.kind == DeclarationKind::Type,
+            });
+        }
+    }
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        let language = file_facts.language.as_str();
+        if !config.checks_language(language) {
+            continue;
+        }
+        result.scanned += 1;
+
+        let declared = enums.get(language).map_or(&[][..], Vec::as_slice);
+        for switch in &file_facts.switches {
+            if !switch.default_arm.as_ref().is_some_and(|d| d.is_trivial) {
+                continue;
+            }
+            let Some(decl) = switched_enum(switch, declared, language) else {
+                continue;
+            };
+
+            let handled: BTreeSet<&str> = switch.cases.iter().map(|c| Label::parse(c).variant).collect();
+            let coverage = handled.len() as f64 / decl.variants.len() as f64;
+            if coverage >= config.min_coverage() {
+                continue;
+            }
+
+            let missing: Vec<&str> = decl
+                .variants
+                .iter()
+                .map(String::as_str)
+                .filter(|v| !handled.contains(v))
+                .collect();
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::UnhandledEnumCases,
+                    format!(
+                        "switch on `{}` handles {} of {} {} variants; {} fall through to an empty default",
+                        switch.subject,
+                        handled.len(),
+                        decl.variants.len(),
+                        decl.name,
+                        missing.join(", ")
+                    ),
+                )
+                .at(path.to_string_lossy(), switch.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+/// The one enum every label of the switch belongs to.
+fn switched_enum<'a>(
+    switch: &SwitchStatement,
+    declared: &'a [EnumDecl<'a>],
+    language: &str,
+) -> Option<&'a EnumDecl<'a>> {
+    if switch.cases.is_empty() {
+        return None;
+    }
+    let labels: Vec<Label> = switch.cases.iter().map(|c| Label::parse(c)).collect();
+    let mut candidates = declared
+        .iter()
+        .filter(|decl| labels.iter().all(|l| l.fits(decl, language)));
+    let first = candidates.next()?;
+    // The same enum declared twice (generated copies, build variants) is not ambiguous
+    candidates
+        .all(|other| other.name == first.name && other.variants == first.variants)
+        .then_some(first)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str, config: &UnhandledEnumCasesConfig) -> Vec<(usize, String)> {
+        test_support::detect(name, source, ViolationRule::UnhandledEnumCases, |facts, files| {
+            detect_unhandled_enum_cases(facts, files, config)
+        })
+    }
+
+    #[test]
+    fn test_go_iota_switch() {
+        let source = r#"package orders
+
+type Status int
+
+const (
+	Pending Status = iota
+	Paid
+	Shipped
+	Delivered
+	Cancelled
+)
+
+func Label(s Status) string {
+	switch s {
+	case Pending:
+		return "pending"
+	case Paid:
+		return "paid"
+	default:
+		return ""
+	}
+}
+
+func Refund(s Status) error {
+	switch s {
+	case Pending, Paid:
+		return nil
+	default:
+		return fmt.Errorf("cannot refund order in state %d", s)
+	}
+}
+
+func Kind(n int) string {
+	switch n {
+	case 1:
+		return "one"
+	default:
+		return ""
+	}
+}
+"#;
+        let found = detect("orders.go", source, &UnhandledEnumCasesConfig::default());
+        assert_eq!(
+            found,
+            vec![(
+                14,
+                "switch on `s` handles 2 of 5 Status variants; Shipped, Delivered, Cancelled fall through to an empty default"
+                    .to_string()
+            )]
+        );
+
+        let config = UnhandledEnumCasesConfig {
+            min_coverage: Some(0.4),
+            ..Default::default()
+        };
+        assert!(detect("orders.go", source, &config).is_empty());
+    }
+
+    #[test]
+    fn test_typescript_enum_and_union() {
+        let source = r#"enum Color { Red, Green, Blue, Alpha }
+type Mode = "read" | "write" | "append";
+
+function paint(c: Color) {
+  switch (c) {
+    case Color.Red:
+      return "red";
+    default:
+      break;
+  }
+}
+
+function open(m: Mode) {
+  switch (m) {
+    case "read":
+      return openRead();
+    default:
+      return;
+  }
+}
+
+function all(c: Color) {
+  switch (c) {
+    case Color.Red: return 1;
+    case Color.Green: return 2;
+    case Color.Blue: return 3;
+    default: return 0;
+  }
+}
+"#;
+        let found = detect("paint.ts", source, &UnhandledEnumCasesConfig::default());
+        let lines: Vec<_> = found.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![5, 14], "{:?}", found);
+        assert!(found[1].1.contains("write, append"));
+
+        let config = UnhandledEnumCasesConfig {
+            typescript: Some(false),
+            ..Default::default()
+        };
+        assert!(detect("paint.ts", source, &config).is_empty());
+    }
+
+    #[test]
+    fn test_rust_wildcard_arm() {
+        let source = r#"enum Shape {
+    Circle(f64),
+    Square { side: f64 },
+    Triangle,
+    Point,
+}
+
+fn area(s: &Shape) -> f64 {
+    match s {
+        Shape::Circle(r) => 3.14 * r * r,
+        _ => {}
+    }
+}
+
+fn describe(s: &Shape) -> &str {
+    match s {
+        Shape::Circle(_) => "round",
+        _ => panic!("unsupported shape"),
+    }
+}
+"#;
+        let found = detect("shape.rs", source, &UnhandledEnumCasesConfig::default());
+        assert_eq!(found.len(), 1, "{:?}", found);
+        assert_eq!(found[0].0, 9);
+        assert!(found[0].1.contains("Square, Triangle, Point"));
+    }
+}
@@ -0,0 +1,376 @@
+//! Detection of language, runtime, and framework versions that differ from
+//! the contract.
+//!
+//! A spec says "Python 3.11, Django 4.2, Go 1.22"; a delivery written for
+//! something else says so in its manifests: `go 1.19` in go.mod,
+//! `requires-python = ">=3.7"`, `django==3.2.18`. The versions are read from
+//! the project root: the go.mod `go` directive and `toolchain` line,
+//! pyproject.toml `requires-python` (or Poetry's `python` dependency),
+//! package.json `engines.node`, Cargo.toml `rust-version`, and the
+//! constraints of the Python, Go, and npm dependencies. Each is compared
+//! against the contract's `environment` section through [`VersionReq::targets`];
+//! a runtime the contract names but the manifest leaves undeclared is
+//! reported too. Without the section, the versions found are only listed
+//! in the report.
+
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::contract::EnvironmentConfig;
+
+use super::manifest::{DeclaredVersion, GoManifest, PythonManifest};
+use super::{DetectionResult, Violation, ViolationRule};
+
+mod version;
+
+pub use version::{Version, VersionReq};
+
+/// Frameworks reported without a contract section, by ecosystem.
+const PYTHON_FRAMEWORKS: &[&str] = &["django", "flask", "fastapi", "sqlalchemy", "pydantic"];
+const GO_FRAMEWORKS: &[&str] = &[
+    "github.com/gin-gonic/gin",
+    "github.com/labstack/echo/v4",
+    "github.com/gofiber/fiber/v2",
+    "github.com/go-chi/chi/v5",
+];
+const NODE_FRAMEWORKS: &[&str] = &["react", "next", "express", "vue", "@angular/core", "@nestjs/core"];
+
+/// npm dependency sections, in the order they are searched.
+const NPM_DEPENDENCY_KEYS: &[&str] = &["dependencies", "peerDependencies", "devDependencies"];
+
+/// `rust-version = "1.75"` in `[package]` or `[workspace.package]`.
+static RUST_VERSION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?m)^\s*rust-version\s*=\s*"([^"]+)""#).unwrap());
+
+/// A language, runtime, or framework version the project declares.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnvironmentEntry {
+    /// `python`, `go`, `node`, `rust`, or a framework's package name
+    pub name: String,
+    /// Version or constraint as written (`1.22`, `>=3.11,<3.13`, `^4.2`)
+    pub declared: String,
+    /// What declares it (`go directive`, `requires-python`, `dependency`)
+    pub source: String,
+    /// Manifest file, relative to the project root
+    pub file: String,
+    pub line: usize,
+    /// Version the contract's environment section expects
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+}
+
+/// A declared version with the constraint it stands for, if it parses.
+struct Declared {
+    entry: EnvironmentEntry,
+    req: Option<VersionReq>,
+}
+
+impl Declared {
+    fn new(name: &str, source: &str, declared: &DeclaredVersion, req: Option<VersionReq>) -> Self {
+        Declared {
+            entry: EnvironmentEntry {
+                name: name.to_string(),
+                declared: declared.constraint.clone(),
+                source: source.to_string(),
+                file: declared.file.clone(),
+                line: declared.line,
+                expected: None,
+            },
+            req,
+        }
+    }
+}
+
+/// The manifest that declares a runtime's version, and where in it.
+fn runtime_manifest(name: &str) -> Option<(&'static str, &'static str)> {
+    match name {
+        "python" => Some(("pyproject.toml", "requires-python")),
+        "go" => Some(("go.mod", "go directive")),
+        "node" => Some(("package.json", "engines.node")),
+        "rust" => Some(("Cargo.toml", "rust-version")),
+        _ => None,
+    }
+}
+
+/// 1-based line of the first `needle` at or after byte `from`, or 1.
+fn line_of(content: &str, needle: &str, from: usize) -> usize {
+    content
+        .get(from..)
+        .and_then(|rest| rest.find(needle))
+        .map_or(1, |i| content[..from + i].matches('\n').count() + 1)
+}
+
+fn go_versions(base: &Path, frameworks: &[&str]) -> Vec<Declared> {
+    let Ok(manifest) = GoManifest::from_root(base) else {
+        return Vec::new();
+    };
+    let mut found = Vec::new();
+    // The go directive is the minimum version; the toolchain line names one release
+    if let Some(go) = manifest.go_directive() {
+        let req = Version::parse(&go.constraint).map(VersionReq::at_least);
+        found.push(Declared::new("go", "go directive", go, req));
+    }
+    if let Some(toolchain) = manifest.toolchain() {
+        let version = toolchain.constraint.strip_prefix("go").unwrap_or(&toolchain.constraint);
+        let req = Version::parse(version).map(VersionReq::exact);
+        found.push(Declared::new("go", "toolchain", toolchain, req));
+    }
+    // Minimal version selection: a required version is the lowest one used
+    for module in frameworks {
+        if let Some(declared) = manifest.declared_version(module) {
+            let req = Version::parse(&declared.constraint).map(VersionReq::at_least);
+            found.push(Declared::new(module, "dependency", &declared, req));
+        }
+    }
+    found
+}
+
+fn python_versions(base: &Path, frameworks: &[&str]) -> Vec<Declared> {
+    let Ok(manifest) = PythonManifest::from_root(base) else {
+        return Vec::new();
+    };
+    let mut found = Vec::new();
+    if let Some(python) = manifest.requires_python() {
+        let req = VersionReq::parse(&python.constraint);
+        found.push(Declared::new("python", "requires-python", python, req));
+    }
+    for package in frameworks {
+        if let Some(declared) = manifest.declared_version(package) {
+            let req = VersionReq::parse(&declared.constraint);
+            found.push(Declared::new(package, "dependency", declared, req));
+        }
+    }
+    found
+}
+
+fn node_versions(base: &Path, frameworks: &[&str]) -> Vec<Declared> {
+    let Ok(content) = std::fs::read_to_string(base.join("package.json")) else {
+        return Vec::new();
+    };
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return Vec::new();
+    };
+    let declared_at = |section: &str, key: &str| -> Option<DeclaredVersion> {
+        let value = json.get(section)?.get(key)?.as_str()?;
+        let start = content.find(&format!("\"{}\"", section)).unwrap_or(0);
+        let line = line_of(&content, &format!("\"{}\"", key), start);
+        Some(DeclaredVersion::new(value, "package.json", line))
+    };
+
+    let mut found = Vec::new();
+    if let Some(node) = declared_at("engines", "node") {
+        let req = VersionReq::parse(&node.constraint);
+        found.push(Declared::new("node", "engines.node", &node, req));
+    }
+    for package in frameworks {
+        if let Some(declared) = NPM_DEPENDENCY_KEYS.iter().find_map(|section| declared_at(section, package)) {
+            let req = VersionReq::parse(&declared.constraint);
+            found.push(Declared::new(package, "dependency", &declared, req));
+        }
+    }
+    found
+}
+
+fn rust_versions(base: &Path) -> Vec<Declared> {
+    let Ok(content) = std::fs::read_to_string(base.join("Cargo.toml")) else {
+        return Vec::new();
+    };
+    let Some(m) = RUST_VERSION.captures(&content).and_then(|c| c.get(1)) else {
+        return Vec::new();
+    };
+    // rust-version is the minimum supported Rust version
+    let declared = DeclaredVersion::new(m.as_str(), "Cargo.toml", line_of(&content, "rust-version", 0));
+    let req = Version::parse(m.as_str()).map(VersionReq::at_least);
+    vec![Declared::new("rust", "rust-version", &declared, req)]
+}
+
+/// Versions declared under `base`, with the known frameworks and those in `extra`.
+fn declared_versions(base: &Path, extra: &[&str]) -> Vec<Declared> {
+    let with_extra = |known: &[&'static str]| -> Vec<&str> {
+        let mut names: Vec<&str> = known.to_vec();
+        for name in extra {
+            if !names.contains(name) {
+                names.push(name);
+            }
+        }
+        names
+    };
+    let mut found = go_versions(base, &with_extra(GO_FRAMEWORKS));
+    found.extend(python_versions(base, &with_extra(PYTHON_FRAMEWORKS)));
+    found.extend(node_versions(base, &with_extra(NODE_FRAMEWORKS)));
+    found.extend(rust_versions(base));
+    found
+}
+
+/// Report the language, runtime, and framework versions declared under
+/// `base`, and with a config, flag those that do not target the versions
+/// it expects.
+pub fn detect_environment_mismatch(
+    base: &Path,
+    config: Option<&EnvironmentConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let expectations = config.map(|c| c.expectations()).unwrap_or_default();
+    let extra: Vec<&str> = expectations
+        .iter()
+        .map(|(name, _)| *name)
+        .filter(|name| runtime_manifest(name).is_none())
+        .collect();
+    let mut declared = declared_versions(base, &extra);
+
+    for (name, expected) in &expectations {
+        let Some(expected_req) = VersionReq::parse(expected) else {
+            continue;
+        };
+        let mut any = false;
+        for d in declared.iter_mut().filter(|d| d.entry.name == *name) {
+            any = true;
+            d.entry.expected = Some(expected.to_string());
+            if d.req.as_ref().is_none_or(|req| req.targets(&expected_req)) {
+                continue;
+            }
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::EnvironmentMismatch,
+                    format!(
+                        "{} version mismatch: {} declares {} ({}), contract expects {}",
+                        name, d.entry.file, d.entry.declared, d.entry.source, expected
+                    ),
+                )
+                .at(d.entry.file.as_str(), d.entry.line),
+            );
+        }
+
+        // A runtime left undeclared in a manifest that has a place for it
+        if let Some((file, key)) = runtime_manifest(name) {
+            if !any && base.join(file).is_file() {
+                result.add_violation(
+                    Violation::new(
+                        ViolationRule::EnvironmentMismatch,
+                        format!(
+                            "{} version not declared: {} has no {}, contract expects {}",
+                            name, file, key, expected
+                        ),
+                    )
+                    .at(file, 1),
+                );
+            }
+        }
+    }
+
+    result.environment = declared.into_iter().map(|d| d.entry).collect();
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    fn project(files: &[(&str, &str)]) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        for (name, content) in files {
+            std::fs::write(temp.path().join(name), content).unwrap();
+        }
+        temp
+    }
+
+    fn delivery() -> TempDir {
+        project(&[
+            ("go.mod", "module example.com/api\n\ngo 1.19\n\nrequire github.com/gin-gonic/gin v1.9.1\n"),
+            (
+                "pyproject.toml",
+                "[project]\nname = \"api\"\nrequires-python = \">=3.7\"\ndependencies = [\n    \"django>=4.2,<5.0\",\n]\n",
+            ),
+            ("package.json", "{\n  \"name\": \"web\",\n  \"dependencies\": {\n    \"react\": \"^18.2.0\"\n  }\n}\n"),
+            ("Cargo.toml", "[package]\nname = \"core\"\nrust-version = \"1.75\"\n"),
+        ])
+    }
+
+    #[test]
+    fn test_inference_only() {
+        let temp = delivery();
+        let result = detect_environment_mismatch(temp.path(), None).unwrap();
+
+        assert!(result.violations.is_empty());
+        let found: Vec<_> = result
+            .environment
+            .iter()
+            .map(|e| (e.name.as_str(), e.declared.as_str(), e.file.as_str(), e.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("go", "1.19", "go.mod", 3),
+                ("github.com/gin-gonic/gin", "v1.9.1", "go.mod", 5),
+                ("python", ">=3.7", "pyproject.toml", 3),
+                ("django", ">=4.2,<5.0", "pyproject.toml", 5),
+                ("react", "^18.2.0", "package.json", 4),
+                ("rust", "1.75", "Cargo.toml", 3),
+            ]
+        );
+        assert!(result.environment.iter().all(|e| e.expected.is_none()));
+    }
+
+    #[test]
+    fn test_mismatches() {
+        let temp = delivery();
+        let config = EnvironmentConfig {
+            enabled: true,
+            python: Some("3.11".to_string()),
+            go: Some("1.22".to_string()),
+            node: Some("20".to_string()),
+            rust: Some(">=1.70".to_string()),
+            frameworks: BTreeMap::from([
+                ("django".to_string(), "4.2".to_string()),
+                ("react".to_string(), "19".to_string()),
+            ]),
+        };
+        let result = detect_environment_mismatch(temp.path(), Some(&config)).unwrap();
+
+        let messages: Vec<_> = result.violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "python version mismatch: pyproject.toml declares >=3.7 (requires-python), contract expects 3.11",
+                "go version mismatch: go.mod declares 1.19 (go directive), contract expects 1.22",
+                "node version not declared: package.json has no engines.node, contract expects 20",
+                "react version mismatch: package.json declares ^18.2.0 (dependency), contract expects 19",
+            ]
+        );
+        assert_eq!(result.violations[1].file.as_deref(), Some("go.mod"));
+        assert_eq!(result.violations[1].line, 3);
+
+        let django = result.environment.iter().find(|e| e.name == "django").unwrap();
+        assert_eq!(django.expected.as_deref(), Some("4.2"));
+    }
+
+    #[test]
+    fn test_toolchain_and_poetry() {
+        let temp = project(&[
+            ("go.mod", "module example.com/api\n\ngo 1.22\ntoolchain go1.21.5\n"),
+            ("pyproject.toml", "[tool.poetry.dependencies]\npython = \"^3.11\"\nflask = {version = \"^2.3\"}\n"),
+        ]);
+        let config = EnvironmentConfig {
+            enabled: true,
+            python: Some("3.11".to_string()),
+            go: Some("1.22".to_string()),
+            frameworks: BTreeMap::from([("flask".to_string(), ">=3.0".to_string())]),
+            ..EnvironmentConfig::default()
+        };
+        let result = detect_environment_mismatch(temp.path(), Some(&config)).unwrap();
+
+        let messages: Vec<_> = result.violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "go version mismatch: go.mod declares go1.21.5 (toolchain), contract expects 1.22",
+                "flask version mismatch: pyproject.toml declares ^2.3 (dependency), contract expects >=3.0",
+            ]
+        );
+    }
+}
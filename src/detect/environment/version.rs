@@ -0,0 +1,415 @@
+//! Version constraints as written in manifests.
+//!
+//! One grammar covers the constraint styles the environment check meets:
+//! npm and Poetry (`^4.2`, `~1.22.0`, `>=18 <21 || 22`, `3.x`), PEP 440
+//! (`>=3.11,<3.13`, `~=3.11`, `==4.2.*`), and bare versions. A constraint
+//! is a union of half-open ranges. Pre-release and build suffixes are
+//! dropped (`1.22rc1` is `1.22`), and `!=` exclusions do not narrow a range.
+
+use std::cmp::Ordering;
+use std::fmt;
+
+/// A dotted numeric version. Missing components compare as zero, so
+/// `3.11` and `3.11.0` are equal.
+#[derive(Debug, Clone)]
+pub struct Version(Vec<u64>);
+
+impl Version {
+    /// Parse a version, ignoring a leading `v` and any pre-release or build
+    /// suffix. Returns None if it does not start with a number.
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let s = s.strip_prefix('v').unwrap_or(s);
+        let mut parts = Vec::new();
+        for component in s.split('.') {
+            let digits: String = component.chars().take_while(|c| c.is_ascii_digit()).collect();
+            if digits.is_empty() {
+                break;
+            }
+            parts.push(digits.parse().ok()?);
+            if digits.len() < component.len() {
+                break;
+            }
+        }
+        (!parts.is_empty()).then_some(Version(parts))
+    }
+
+    fn component(&self, i: usize) -> u64 {
+        self.0.get(i).copied().unwrap_or(0)
+    }
+
+    /// The first version past this one at component `i`: `bump(1)` of
+    /// `1.22.3` is `1.23`.
+    fn bump(&self, i: usize) -> Self {
+        let mut parts: Vec<u64> = (0..=i).map(|j| self.component(j)).collect();
+        parts[i] += 1;
+        Version(parts)
+    }
+}
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Version {}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let len = self.0.len().max(other.0.len());
+        (0..len)
+            .map(|i| self.component(i).cmp(&other.component(i)))
+            .find(|o| o.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self.0.iter().map(|p| p.to_string()).collect();
+        write!(f, "{}", parts.join("."))
+    }
+}
+
+/// One end of a range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bound {
+    version: Version,
+    inclusive: bool,
+}
+
+/// A range of versions; a missing bound is unbounded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Range {
+    lower: Option<Bound>,
+    upper: Option<Bound>,
+}
+
+impl Range {
+    fn at_least(version: Version) -> Self {
+        Range {
+            lower: Some(Bound { version, inclusive: true }),
+            upper: None,
+        }
+    }
+
+    fn between(lower: Version, upper: Version) -> Self {
+        Range {
+            lower: Some(Bound { version: lower, inclusive: true }),
+            upper: Some(Bound { version: upper, inclusive: false }),
+        }
+    }
+
+    fn exact(version: Version) -> Self {
+        Range {
+            lower: Some(Bound { version: version.clone(), inclusive: true }),
+            upper: Some(Bound { version, inclusive: true }),
+        }
+    }
+
+    fn contains(&self, version: &Version) -> bool {
+        let above = self.lower.as_ref().is_none_or(|b| match version.cmp(&b.version) {
+            Ordering::Greater => true,
+            Ordering::Equal => b.inclusive,
+            Ordering::Less => false,
+        });
+        let below = self.upper.as_ref().is_none_or(|b| match version.cmp(&b.version) {
+            Ordering::Less => true,
+            Ordering::Equal => b.inclusive,
+            Ordering::Greater => false,
+        });
+        above && below
+    }
+
+    /// Versions in both ranges.
+    fn intersect(&self, other: &Range) -> Range {
+        let lower = match (&self.lower, &other.lower) {
+            (Some(a), Some(b)) => Some(match a.version.cmp(&b.version) {
+                Ordering::Greater => a.clone(),
+                Ordering::Less => b.clone(),
+                Ordering::Equal => Bound { version: a.version.clone(), inclusive: a.inclusive && b.inclusive },
+            }),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        let upper = match (&self.upper, &other.upper) {
+            (Some(a), Some(b)) => Some(match a.version.cmp(&b.version) {
+                Ordering::Less => a.clone(),
+                Ordering::Greater => b.clone(),
+                Ordering::Equal => Bound { version: a.version.clone(), inclusive: a.inclusive && b.inclusive },
+            }),
+            (a, b) => a.clone().or_else(|| b.clone()),
+        };
+        Range { lower, upper }
+    }
+
+    fn is_empty(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => match lower.version.cmp(&upper.version) {
+                Ordering::Greater => true,
+                Ordering::Equal => !(lower.inclusive && upper.inclusive),
+                Ordering::Less => false,
+            },
+            _ => false,
+        }
+    }
+}
+
+/// A version constraint: the union of its `||` alternatives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionReq {
+    ranges: Vec<Range>,
+}
+
+/// Operators, longest first so `>=` is not read as `>`.
+const OPERATORS: &[&str] = &["===", "~=", "==", "!=", ">=", "<=", ">", "<", "=", "^", "~"];
+
+impl VersionReq {
+    /// Parse a constraint. Returns None if any part of it is not a version
+    /// or operator this grammar knows.
+    pub fn parse(s: &str) -> Option<Self> {
+        let ranges = s
+            .split("||")
+            .map(parse_alternative)
+            .collect::<Option<Vec<_>>>()?;
+        Some(VersionReq { ranges })
+    }
+
+    /// Versions from `version` up.
+    pub fn at_least(version: Version) -> Self {
+        VersionReq { ranges: vec![Range::at_least(version)] }
+    }
+
+    /// Exactly `version`.
+    pub fn exact(version: Version) -> Self {
+        VersionReq { ranges: vec![Range::exact(version)] }
+    }
+
+    /// Whether `version` satisfies the constraint.
+    pub fn matches(&self, version: &Version) -> bool {
+        self.ranges.iter().any(|r| r.contains(version))
+    }
+
+    /// The lowest version the constraint admits, or None if it has no
+    /// lower bound.
+    pub fn minimum(&self) -> Option<&Version> {
+        self.ranges
+            .iter()
+            .filter(|r| !r.is_empty())
+            .map(|r| r.lower.as_ref().map(|b| &b.version))
+            .try_fold(None::<&Version>, |min, lower| {
+                let lower = lower?;
+                Some(Some(min.map_or(lower, |m| m.min(lower))))
+            })
+            .flatten()
+    }
+
+    /// Whether some version satisfies both constraints.
+    pub fn intersects(&self, other: &VersionReq) -> bool {
+        self.ranges
+            .iter()
+            .any(|a| other.ranges.iter().any(|b| !a.intersect(b).is_empty()))
+    }
+
+    /// Whether a project declaring this constraint targets the versions
+    /// `expected` allows: its lowest admitted version is one of them, or,
+    /// without a lower bound, the two overlap. `>=3.7` does not target
+    /// `3.11` even though 3.11 satisfies it; code written for 3.7 runs
+    /// there but is not written for it.
+    pub fn targets(&self, expected: &VersionReq) -> bool {
+        match self.minimum() {
+            Some(minimum) => expected.matches(minimum),
+            None => self.intersects(expected),
+        }
+    }
+}
+
+/// Parse one `||` alternative: comparators separated by commas or spaces,
+/// or a hyphen range `1.2 - 2.3`.
+fn parse_alternative(s: &str) -> Option<Range> {
+    // Join operators to their versions (`>= 3.11` → `>=3.11`)
+    let mut tokens: Vec<String> = Vec::new();
+    for word in s.split([',', ' ', '\t']).filter(|w| !w.is_empty()) {
+        match tokens.last_mut() {
+            Some(last) if OPERATORS.contains(&last.as_str()) => last.push_str(word),
+            _ => tokens.push(word.to_string()),
+        }
+    }
+
+    if let [from, dash, to] = tokens.as_slice() {
+        if dash == "-" {
+            let (from, to) = (Version::parse(from)?, Version::parse(to)?);
+            return Some(Range {
+                lower: Some(Bound { version: from, inclusive: true }),
+                upper: Some(Bound { version: to, inclusive: true }),
+            });
+        }
+    }
+
+    tokens
+        .iter()
+        .try_fold(Range::default(), |range, token| Some(range.intersect(&parse_comparator(token)?)))
+}
+
+/// Parse a single comparator such as `^4.2`, `~=3.11`, `<3.13`, or `3.11.*`.
+fn parse_comparator(token: &str) -> Option<Range> {
+    let (op, rest) = OPERATORS
+        .iter()
+        .find_map(|op| token.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("", token));
+
+    // Wildcards: `*`, `3.x`, `3.11.*`
+    let wildcard = rest.split('.').any(|c| matches!(c, "*" | "x" | "X"));
+    let components = rest
+        .split('.')
+        .take_while(|c| !matches!(*c, "*" | "x" | "X"))
+        .count();
+    if components == 0 {
+        return wildcard.then(Range::default);
+    }
+    let version = Version::parse(rest)?;
+    let n = components.min(version.0.len());
+
+    let range = match op {
+        "!=" => Range::default(),
+        ">=" => Range::at_least(version),
+        ">" => Range {
+            lower: Some(Bound { version, inclusive: false }),
+            upper: None,
+        },
+        "<=" => Range {
+            lower: None,
+            upper: Some(Bound { version, inclusive: true }),
+        },
+        "<" => Range {
+            lower: None,
+            upper: Some(Bound { version, inclusive: false }),
+        },
+        // ^4.2 → <5, ^0.2.3 → <0.3, ^0.0.3 → <0.0.4
+        "^" => {
+            let i = (0..n).find(|&i| version.component(i) != 0).unwrap_or(n - 1);
+            let upper = version.bump(i);
+            Range::between(version, upper)
+        }
+        // ~1.22.0 → <1.23, ~1 → <2
+        "~" => {
+            let upper = version.bump(if n >= 2 { 1 } else { 0 });
+            Range::between(version, upper)
+        }
+        // ~=3.11 → <4, ~=1.22.0 → <1.23
+        "~=" if n >= 2 => {
+            let upper = version.bump(n - 2);
+            Range::between(version, upper)
+        }
+        "~=" => Range::at_least(version),
+        // A bare partial version is a range (`3.11` is `3.11.*`), as in npm
+        "" if n < 3 => {
+            let upper = version.bump(n - 1);
+            Range::between(version, upper)
+        }
+        _ if wildcard => {
+            let upper = version.bump(n - 1);
+            Range::between(version, upper)
+        }
+        _ => Range::exact(version),
+    };
+    Some(range)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    fn req(s: &str) -> VersionReq {
+        VersionReq::parse(s).unwrap_or_else(|| panic!("unparsed: {}", s))
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(v("3.11"), v("3.11.0"));
+        assert_eq!(v("v1.9.1"), v("1.9.1"));
+        assert_eq!(v("1.22rc1"), v("1.22"));
+        assert_eq!(v("4.2.0-beta.1"), v("4.2"));
+        assert!(v("1.10") > v("1.9"));
+        assert_eq!(Version::parse("latest"), None);
+    }
+
+    #[test]
+    fn test_caret() {
+        let django = req("^4.2");
+        assert!(django.matches(&v("4.2")));
+        assert!(django.matches(&v("4.9.1")));
+        assert!(!django.matches(&v("5.0")));
+        assert!(!django.matches(&v("4.1.9")));
+
+        assert!(req("^0.2.3").matches(&v("0.2.9")));
+        assert!(!req("^0.2.3").matches(&v("0.3.0")));
+        assert!(!req("^0.0.3").matches(&v("0.0.4")));
+    }
+
+    #[test]
+    fn test_pep440_range() {
+        let python = req(">=3.11,<3.13");
+        assert!(python.matches(&v("3.11")));
+        assert!(python.matches(&v("3.12.4")));
+        assert!(!python.matches(&v("3.13")));
+        assert!(!python.matches(&v("3.10.14")));
+        assert_eq!(python.minimum(), Some(&v("3.11")));
+
+        assert!(req("~=3.11").matches(&v("3.14")));
+        assert!(!req("~=3.11").matches(&v("4.0")));
+        assert!(req("==4.2.*").matches(&v("4.2.11")));
+        assert!(!req("==4.2.*").matches(&v("4.3")));
+        assert!(req(">=4.2, !=4.2.1").matches(&v("4.2.1")));
+    }
+
+    #[test]
+    fn test_tilde() {
+        let go = req("~1.22.0");
+        assert!(go.matches(&v("1.22.5")));
+        assert!(!go.matches(&v("1.23.0")));
+        assert!(!go.matches(&v("1.21.9")));
+        assert!(req("~1").matches(&v("1.99")));
+        assert!(!req("~=1.22.0").matches(&v("1.23")));
+    }
+
+    #[test]
+    fn test_npm_ranges() {
+        let node = req(">= 18 < 21 || 22");
+        assert!(node.matches(&v("20.11.0")));
+        assert!(node.matches(&v("22.1")));
+        assert!(!node.matches(&v("21.0")));
+        assert_eq!(node.minimum(), Some(&v("18")));
+
+        assert!(req("3.x").matches(&v("3.9")));
+        assert!(req("*").matches(&v("0.1")));
+        assert_eq!(req("*").minimum(), None);
+        assert!(req("1.2 - 2.3").matches(&v("2.3")));
+        assert!(req("18").matches(&v("18.19.1")));
+        assert!(!req("1.22.3").matches(&v("1.22.4")));
+        assert_eq!(VersionReq::parse(">=latest"), None);
+    }
+
+    #[test]
+    fn test_targets() {
+        let spec = req("3.11");
+        assert!(req(">=3.11,<3.13").targets(&spec));
+        assert!(req("^3.11").targets(&spec));
+        assert!(!req(">=3.7").targets(&spec));
+        assert!(!req("<3.10").targets(&spec));
+        assert!(req("<3.12").targets(&spec));
+        assert!(VersionReq::at_least(v("1.22.1")).targets(&req("1.22")));
+        assert!(!VersionReq::at_least(v("1.19")).targets(&req("1.22")));
+        assert!(!VersionReq::exact(v("3.2.18")).targets(&req(">=4.2")));
+    }
+}
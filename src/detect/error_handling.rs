@@ -0,0 +1,335 @@
+//! Detection of files that mix error-returning and panicking functions.
+//!
+//! A module written in one pass settles on one way to report failure: Rust
+//! functions return `Result`, Go functions return an `error`, Swift functions
+//! are declared `throws`, TypeScript functions return a `Result` type. When a
+//! file is stitched together from separate generations, or half migrated from
+//! one strategy to the other, some of its functions panic on failure instead
+//! (`panic!`, `panic(...)`, `log.Fatal`, `fatalError`, `throw`), and callers
+//! cannot handle its failures the same way. The functions of each file that
+//! use the minority strategy are flagged. Declarations and bodies come from
+//! the `FactsBundle`; signatures are read from the source.
+
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::analysis::Declaration;
+
+use super::effort::{signature, split_at_params};
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+static RUST_RESULT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bResult\b").unwrap());
+static GO_ERROR: Lazy<Regex> = Lazy::new(|| Regex::new(r"\berror\b").unwrap());
+static SWIFT_THROWS: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:re)?throws\b").unwrap());
+static TS_RESULT: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b(?:Result|Either)<").unwrap());
+
+static RUST_PANIC: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bpanic!").unwrap());
+static GO_PANIC: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bpanic\(|\blog\.(?:Fatal|Fatalf|Fatalln|Panic|Panicf|Panicln)\(").unwrap());
+static SWIFT_PANIC: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bfatalError\(").unwrap());
+static TS_PANIC: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bthrow\s").unwrap());
+
+/// How a function reports failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Strategy {
+    /// Through its return type or `throws` declaration
+    Returns,
+    /// By panicking, with the call that does it
+    Panics(String),
+}
+
+/// What a language's error-returning functions return, for messages.
+fn error_channel(language: &str) -> &'static str {
+    match language {
+        "rust" => "`Result`",
+        "go" => "an `error` result",
+        "swift" => "`throws`",
+        _ => "a `Result` type",
+    }
+}
+
+/// How functions that do not return errors fail, singular and plural.
+fn panic_verb(language: &str) -> (&'static str, &'static str) {
+    match language {
+        "typescript" => ("throws", "throw"),
+        _ => ("panics", "panic"),
+    }
+}
+
+/// Whether the signature text after the parameter list declares an error channel.
+fn returns_errors(language: &str, after_params: &str) -> bool {
+    match language {
+        "rust" => after_params.split_once("->").is_some_and(|(_, t)| RUST_RESULT.is_match(t)),
+        "go" => GO_ERROR.is_match(after_params),
+        "swift" => SWIFT_THROWS.is_match(after_params.split("->").next().unwrap_or(after_params)),
+        "typescript" => after_params.trim_start().starts_with(':') && TS_RESULT.is_match(after_params),
+        _ => false,
+    }
+}
+
+/// The panicking call in a body, as written (`panic!`, `log.Fatalf`).
+fn panic_call(language: &str, body: &str) -> Option<String> {
+    let pattern = match language {
+        "rust" => &RUST_PANIC,
+        "go" => &GO_PANIC,
+        "swift" => &SWIFT_PANIC,
+        "typescript" => &TS_PANIC,
+        _ => return None,
+    };
+    Some(pattern.find(body)?.as_str().trim_end_matches('(').trim().to_string())
+}
+
+/// Classify how a function reports failure; `None` if it does neither.
+///
+/// Entry points and Go `Must*` helpers panic by convention, and stubs are
+/// reported by `stub_function`, so none of them count.
+fn classify(language: &str, source: &str, decl: &Declaration) -> Option<Strategy> {
+    let body = decl.body.as_ref()?;
+    if body.is_panic_only || matches!(decl.name.as_str(), "main" | "init") {
+        return None;
+    }
+    if language == "go" && decl.name.starts_with("Must") {
+        return None;
+    }
+    let (_, after_params) = split_at_params(signature(source, decl), &decl.name)?;
+    if returns_errors(language, after_params) {
+        return Some(Strategy::Returns);
+    }
+    panic_call(language, &body.text).map(Strategy::Panics)
+}
+
+/// "1 other function" or "3 other functions".
+fn others(count: usize) -> String {
+    if count == 1 {
+        "1 other function".to_string()
+    } else {
+        format!("{} other functions", count)
+    }
+}
+
+/// Flag functions whose error strategy is the minority in their file, for
+/// Rust, Go, Swift, and TypeScript.
+pub fn detect_inconsistent_error_handling<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        let language = file_facts.language.as_str();
+        if !matches!(language, "rust" | "go" | "swift" | "typescript") {
+            continue;
+        }
+        let Ok(source) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        let classified: Vec<(&Declaration, Strategy)> = file_facts
+            .declarations
+            .iter()
+            .filter(|d| d.kind.is_callable())
+            .filter_map(|d| classify(language, &source, d).map(|s| (d, s)))
+            .collect();
+        let returning = classified.iter().filter(|(_, s)| *s == Strategy::Returns).count();
+        let panicking = classified.len() - returning;
+        if returning == 0 || panicking == 0 || returning == panicking {
+            continue;
+        }
+
+        let channel = error_channel(language);
+        let (panics, panic) = panic_verb(language);
+        for (decl, strategy) in &classified {
+            let message = match strategy {
+                Strategy::Panics(call) if panicking < returning => format!(
+                    "{} {} on failure with `{}` while {} in this file report errors through {}; use one error strategy per file",
+                    decl.name,
+                    panics,
+                    call,
+                    others(returning),
+                    channel
+                ),
+                Strategy::Returns if returning < panicking => format!(
+                    "{} reports errors through {} while {} in this file {} on failure; use one error strategy per file",
+                    decl.name,
+                    channel,
+                    others(panicking),
+                    panic
+                ),
+                _ => continue,
+            };
+            result.add_violation(
+                Violation::new(ViolationRule::InconsistentErrorHandling, message)
+                    .at(path.to_string_lossy(), decl.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str) -> Vec<(usize, String)> {
+        test_support::detect(name, source, ViolationRule::InconsistentErrorHandling, detect_inconsistent_error_handling)
+    }
+
+    #[test]
+    fn test_rust_panic_among_results() {
+        let source = r#"use std::fs;
+
+pub fn load(path: &str) -> Result<String, std::io::Error> {
+    fs::read_to_string(path)
+}
+
+pub fn parse(text: &str) -> anyhow::Result<Config> {
+    let config = toml::from_str(text)?;
+    Ok(config)
+}
+
+pub fn validate(config: &Config) -> Result<(), String> {
+    if config.name.is_empty() {
+        return Err("name is required".to_string());
+    }
+    Ok(())
+}
+
+pub fn save(config: &Config, path: &str) {
+    if fs::write(path, config.to_string()).is_err() {
+        panic!("cannot write {}", path);
+    }
+}
+
+pub fn name(config: &Config) -> &str {
+    &config.name
+}
+"#;
+        let found = detect("config.rs", source);
+        assert_eq!(
+            found,
+            vec![(
+                19,
+                "save panics on failure with `panic!` while 3 other functions in this file report errors through `Result`; use one error strategy per file".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_go_error_among_panics() {
+        let source = r#"package store
+
+func MustOpen(path string) *DB {
+	db, err := Open(path)
+	if err != nil {
+		panic(err)
+	}
+	return db
+}
+
+func Get(db *DB, key string) string {
+	v, ok := db.data[key]
+	if !ok {
+		log.Fatalf("missing key %s", key)
+	}
+	return v
+}
+
+func Put(db *DB, key, value string) {
+	if db.readOnly {
+		panic("read-only store")
+	}
+	db.data[key] = value
+}
+
+func Open(path string) (*DB, error) {
+	if path == "" {
+		return nil, errors.New("empty path")
+	}
+	return &DB{path: path}, nil
+}
+"#;
+        let found = detect("store.go", source);
+        assert_eq!(
+            found,
+            vec![(
+                26,
+                "Open reports errors through an `error` result while 2 other functions in this file panic on failure; use one error strategy per file".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_consistent_or_tied_files() {
+        let consistent = r#"package store
+
+func Get(db *DB, key string) (string, error) {
+	v, ok := db.data[key]
+	if !ok {
+		return "", fmt.Errorf("missing key %s", key)
+	}
+	return v, nil
+}
+
+func Put(db *DB, key, value string) error {
+	if db.readOnly {
+		return errors.New("read-only store")
+	}
+	db.data[key] = value
+	return nil
+}
+"#;
+        assert!(detect("store.go", consistent).is_empty());
+
+        let tied = r#"func load(path: String) throws -> Data {
+    return try Data(contentsOf: URL(fileURLWithPath: path))
+}
+
+func decode(data: Data) -> Config {
+    guard let config = try? JSONDecoder().decode(Config.self, from: data) else {
+        fatalError("invalid config")
+    }
+    return config
+}
+"#;
+        assert!(detect("Config.swift", tied).is_empty());
+    }
+
+    #[test]
+    fn test_typescript_throw_among_results() {
+        let source = r#"export function parseUser(raw: string): Result<User, ParseError> {
+  const data = JSON.parse(raw);
+  return data.id ? ok(data) : err(new ParseError("missing id"));
+}
+
+export function parseOrder(raw: string): Result<Order, ParseError> {
+  const data = JSON.parse(raw);
+  return data.total >= 0 ? ok(data) : err(new ParseError("negative total"));
+}
+
+export function parseItem(raw: string): Item {
+  const data = JSON.parse(raw);
+  if (!data.sku) {
+    throw new Error("missing sku");
+  }
+  return data;
+}
+"#;
+        let found = detect("parse.ts", source);
+        assert_eq!(found.len(), 1, "{:?}", found);
+        assert_eq!(found[0].0, 11);
+        assert!(found[0].1.starts_with("parseItem throws on failure with `throw`"), "{}", found[0].1);
+    }
+}
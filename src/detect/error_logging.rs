@@ -0,0 +1,127 @@
+//! Detection of error handlers that log an error and pass it on unchanged.
+//!
+//! When every layer catches an error, logs it, and rethrows it as-is, one
+//! failure is logged once per layer with no new context, which buries the
+//! line that matters. A handler should either handle the error, add context
+//! (`raise ... from e`, `fmt.Errorf("...: %w", err)`), or leave logging to
+//! the layer that does. Handlers come from `FileFacts::logged_rethrows`,
+//! read from the `FactsBundle`.
+
+use std::path::Path;
+
+use crate::analysis::{Declaration, LoggedRethrow};
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// The innermost function or method whose span contains the handler.
+fn enclosing<'a>(declarations: &'a [Declaration], handler: &LoggedRethrow) -> Option<&'a Declaration> {
+    declarations
+        .iter()
+        .filter(|d| d.kind.is_callable())
+        .filter(|d| d.span.start_byte <= handler.span.start_byte && handler.span.end_byte <= d.span.end_byte)
+        .min_by_key(|d| d.span.end_byte - d.span.start_byte)
+}
+
+/// Flag handlers that log and rethrow in Go, Python, Java, and JavaScript/TypeScript.
+pub fn detect_redundant_error_logging<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        for handler in &file_facts.logged_rethrows {
+            let location = match enclosing(&file_facts.declarations, handler) {
+                Some(func) => format!("{} error handler", func.name),
+                None => "error handler".to_string(),
+            };
+            let error = match &handler.error {
+                Some(name) => format!("`{}`", name),
+                None => "the exception".to_string(),
+            };
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::RedundantErrorLogging,
+                    format!(
+                        "{} logs {} with `{}` and passes it on unchanged with `{}`; handle it, add context, or leave logging to the caller",
+                        location, error, handler.logger, handler.rethrow
+                    ),
+                )
+                .at(path.to_string_lossy(), handler.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str) -> Vec<(usize, String)> {
+        test_support::detect(name, source, ViolationRule::RedundantErrorLogging, detect_redundant_error_logging)
+    }
+
+    #[test]
+    fn test_names_enclosing_function() {
+        let source = r#"import logging
+
+logger = logging.getLogger(__name__)
+
+class Store:
+    def load(self, key):
+        try:
+            return self.backend.get(key)
+        except KeyError:
+            logger.exception("missing %s", key)
+            raise
+
+def save(store, key, value):
+    try:
+        store.put(key, value)
+    except OSError as err:
+        logger.error("write failed: %s", err)
+        raise StoreError(key) from err
+"#;
+        let found = detect("store.py", source);
+        assert_eq!(
+            found,
+            vec![(
+                9,
+                "load error handler logs the exception with `logger.exception` and passes it on unchanged with `raise`; handle it, add context, or leave logging to the caller".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_go_log_and_return() {
+        let source = r#"package store
+
+func Open(path string) (*DB, error) {
+	db, err := sql.Open("sqlite", path)
+	if err != nil {
+		log.Printf("open %s: %v", path, err)
+		return nil, err
+	}
+	return db, nil
+}
+"#;
+        let found = detect("store.go", source);
+        assert_eq!(found.len(), 1, "{:?}", found);
+        assert_eq!(found[0].0, 5);
+        assert!(found[0].1.starts_with("Open error handler logs `err` with `log.Printf`"), "{}", found[0].1);
+        assert!(found[0].1.contains("`return nil, err`"), "{}", found[0].1);
+    }
+}
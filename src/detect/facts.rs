@@ -0,0 +1,461 @@
+//! Facts shared by rules, computed once per scan.
+//!
+//! Rules declare the fact kinds they read through
+//! [`ViolationRule::required_facts`]. Before any rule runs, the runner
+//! builds a [`FactsBundle`] holding every kind the enabled rules need:
+//! per-file AST facts come from a single parse of each file, and project-wide
+//! indexes are built once. Rules then read the bundle instead of deriving the
+//! same facts again, so two rules can never disagree about what a file declares.
+//!
+//! Rules that have not moved to the bundle yet read facts through the
+//! `AnalysisContext`, which shares the same parse cache.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext, DeclarationKind, FileFacts};
+
+use super::ViolationRule;
+
+/// A kind of fact a rule can require.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FactKind {
+    /// Functions, methods, types, and constants of each file, whether they
+    /// are exported and documented, which callables are marked deprecated,
+    /// parameter default values, and the values of constant groups.
+    Declarations,
+    /// Function bodies: statement counts, control flow, no-op and switch
+    /// statements, unused parameters and unassigned named results, calls
+    /// that only forward parameters,
+    /// and functions cut off at the end of the file.
+    Bodies,
+    /// Import statements of each file.
+    Imports,
+    /// Call expressions: calls used as statements, and every call site by callee name.
+    Calls,
+    /// String literals outside imports and docstrings.
+    StringLiterals,
+    /// Project-wide index of declarations by name.
+    SymbolIndex,
+    /// Project-wide index of C and C++ function definitions by name.
+    DefinitionIndex,
+    /// Project-wide index of call sites by callee name.
+    CallIndex,
+    /// Which files are test files, by name or by a test directory on their path.
+    TestClassification,
+    /// Code and comment line counts of each file.
+    Comments,
+}
+
+impl FactKind {
+    /// Whether this kind is extracted from each file's AST.
+    pub fn is_per_file(&self) -> bool {
+        !matches!(
+            self,
+            FactKind::SymbolIndex
+                | FactKind::DefinitionIndex
+                | FactKind::CallIndex
+                | FactKind::TestClassification
+        )
+    }
+}
+
+impl ViolationRule {
+    /// Fact kinds the rule reads from the [`FactsBundle`].
+    ///
+    /// Empty for rules that do not use the bundle.
+    pub fn required_facts(&self) -> &'static [FactKind] {
+        match self {
+            ViolationRule::MissingSymbol => &[FactKind::Declarations],
+            ViolationRule::LowComplexity => &[FactKind::Declarations, FactKind::Bodies],
+            ViolationRule::MissingTest => &[FactKind::Declarations, FactKind::TestClassification],
+            ViolationRule::DocDrift => &[
+                FactKind::Declarations,
+                FactKind::Imports,
+                FactKind::StringLiterals,
+            ],
+            ViolationRule::BuiltinShadowing => &[FactKind::Declarations],
+            ViolationRule::NoopStatement => &[FactKind::Bodies],
+            ViolationRule::UnusedContext => &[FactKind::Bodies, FactKind::Imports],
+            ViolationRule::UnassignedNamedReturn => &[FactKind::Bodies],
+            ViolationRule::PlaceholderConstants => &[FactKind::Declarations],
+            ViolationRule::MissingDefinition => &[FactKind::Declarations, FactKind::DefinitionIndex],
+            ViolationRule::DeprecatedUsage => &[FactKind::Declarations, FactKind::CallIndex],
+            ViolationRule::UnwrapOveruse => &[FactKind::Bodies, FactKind::TestClassification],
+            ViolationRule::RepeatedLiteral => &[FactKind::StringLiterals, FactKind::TestClassification],
+            ViolationRule::ForceUnwrap => &[FactKind::Bodies],
+            ViolationRule::MissingLocalModule => &[FactKind::Imports],
+            ViolationRule::RedundantErrorLogging => &[FactKind::Declarations, FactKind::Bodies],
+            ViolationRule::InconsistentErrorHandling => &[FactKind::Declarations, FactKind::Bodies],
+            ViolationRule::MutableDefault => &[FactKind::Declarations],
+            ViolationRule::TruncatedCode => &[FactKind::Bodies],
+            ViolationRule::AsyncWithoutAwait => &[
+                FactKind::Declarations,
+                FactKind::Bodies,
+                FactKind::SymbolIndex,
+            ],
+            ViolationRule::TrivialWrapper => &[
+                FactKind::Declarations,
+                FactKind::Bodies,
+                FactKind::SymbolIndex,
+            ],
+            ViolationRule::UnhandledEnumCases => &[FactKind::Declarations, FactKind::Bodies],
+            ViolationRule::WeakCrypto => &[FactKind::Imports],
+            ViolationRule::CommentRatio => &[FactKind::Declarations, FactKind::Bodies, FactKind::Comments],
+            ViolationRule::DocCoverage => &[FactKind::Declarations, FactKind::TestClassification],
+            _ => &[],
+        }
+    }
+}
+
+/// Where a declaration lives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SymbolLocation {
+    /// File containing the declaration, as passed to the runner.
+    pub file: PathBuf,
+    /// Declaration kind.
+    pub kind: DeclarationKind,
+    /// Line of the declaration (1-indexed).
+    pub line: usize,
+}
+
+/// Where a C or C++ function is defined.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DefinitionLocation {
+    /// File containing the definition, as passed to the runner.
+    pub file: PathBuf,
+    /// Line of the definition (1-indexed).
+    pub line: usize,
+    /// Number of declared parameters.
+    pub param_count: Option<usize>,
+}
+
+/// Where a call is made.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallLocation {
+    /// File containing the call, as passed to the runner.
+    pub file: PathBuf,
+    /// Line of the call (1-indexed).
+    pub line: usize,
+}
+
+/// Check if a file name follows a test file convention (`*_test.go`,
+/// `*.spec.ts`, `test_*.py`, `conftest.py`, ...).
+pub fn is_test_file_name(filename: &str) -> bool {
+    filename.ends_with("_test.go")
+        || filename.ends_with("_test.rs")
+        || filename.ends_with("_test.py")
+        || filename.ends_with("_test.js")
+        || filename.ends_with("_test.ts")
+        || filename.ends_with("_test.tsx")
+        || filename.ends_with("_test.jsx")
+        || filename.ends_with(".test.js")
+        || filename.ends_with(".test.ts")
+        || filename.ends_with(".test.tsx")
+        || filename.ends_with(".test.jsx")
+        || filename.ends_with(".spec.js")
+        || filename.ends_with(".spec.ts")
+        || filename.ends_with(".spec.tsx")
+        || filename.ends_with(".spec.jsx")
+        || filename.starts_with("test_")
+        || filename == "conftest.py"
+}
+
+/// Directories holding test code, wherever they sit in the tree.
+const TEST_DIRS: &[&str] = &["test", "tests", "__tests__", "testdata"];
+
+/// Check if a file, relative to the scan root, is test code: its name
+/// follows a test convention or a directory on its path holds tests.
+fn is_test_path(relative: &Path) -> bool {
+    let test_name = relative
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(is_test_file_name);
+    test_name
+        || relative.parent().is_some_and(|dir| {
+            dir.components().any(|c| {
+                matches!(c, Component::Normal(d) if d.to_str().is_some_and(|d| TEST_DIRS.contains(&d.to_ascii_lowercase().as_str())))
+            })
+        })
+}
+
+/// Read-only facts for one scan.
+pub struct FactsBundle {
+    base_dir: PathBuf,
+    /// AST facts per file, or the error that prevented extraction.
+    files: HashMap<PathBuf, Result<FileFacts, String>>,
+    symbol_index: Option<BTreeMap<String, Vec<SymbolLocation>>>,
+    definition_index: Option<BTreeMap<String, Vec<DefinitionLocation>>>,
+    call_index: Option<BTreeMap<String, Vec<CallLocation>>>,
+    test_files: Option<HashSet<PathBuf>>,
+    /// How many times each kind was computed.
+    computed: BTreeMap<FactKind, usize>,
+}
+
+impl FactsBundle {
+    /// Compute the facts every rule in `rules` requires.
+    pub fn for_rules<P: AsRef<Path> + Sync>(
+        analysis_ctx: &AnalysisContext,
+        files: &[P],
+        rules: &[ViolationRule],
+    ) -> Self {
+        let kinds: Vec<FactKind> = rules
+            .iter()
+            .flat_map(|r| r.required_facts().iter().copied())
+            .collect();
+        Self::build(analysis_ctx, files, &kinds)
+    }
+
+    /// Compute the given fact kinds; each kind is computed once however
+    /// often it appears in `kinds`.
+    pub fn build<P: AsRef<Path> + Sync>(
+        analysis_ctx: &AnalysisContext,
+        files: &[P],
+        kinds: &[FactKind],
+    ) -> Self {
+        let kinds: BTreeSet<FactKind> = kinds.iter().copied().collect();
+        let mut bundle = Self {
+            base_dir: analysis_ctx.base_dir().to_path_buf(),
+            files: HashMap::new(),
+            symbol_index: None,
+            definition_index: None,
+            call_index: None,
+            test_files: None,
+            computed: BTreeMap::new(),
+        };
+
+        // One parse per file covers every per-file kind; the indexes are built from it
+        let per_file: Vec<FactKind> = kinds.iter().copied().filter(FactKind::is_per_file).collect();
+        let needs_index = kinds.iter().any(|k| {
+            matches!(k, FactKind::SymbolIndex | FactKind::DefinitionIndex | FactKind::CallIndex)
+        });
+        if !per_file.is_empty() || needs_index {
+            let parsed_before = analysis_ctx.files_parsed();
+            bundle.files = files
+                .par_iter()
+                .map(|f| f.as_ref())
+                .filter(|path| {
+                    let ext = analysis_extension(path);
+                    get_analyzer(ext).is_some()
+                })
+                .map(|path| {
+                    let facts = analysis_ctx.analyze_file(path).map_err(|e| e.to_string());
+                    (path.to_path_buf(), facts)
+                })
+                .collect();
+            // Files the context already had cached were not parsed again
+            let parsed = analysis_ctx.files_parsed() - parsed_before;
+            for kind in per_file {
+                *bundle.computed.entry(kind).or_default() += parsed;
+            }
+        }
+
+        if kinds.contains(&FactKind::SymbolIndex) {
+            let mut index: BTreeMap<String, Vec<SymbolLocation>> = BTreeMap::new();
+            for (path, facts) in &bundle.files {
+                let Ok(facts) = facts else {
+                    continue;
+                };
+                for decl in &facts.declarations {
+                    index.entry(decl.name.clone()).or_default().push(SymbolLocation {
+                        file: path.clone(),
+                        kind: decl.kind,
+                        line: decl.span.start_line,
+                    });
+                }
+            }
+            for locations in index.values_mut() {
+                locations.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+            }
+            bundle.symbol_index = Some(index);
+            *bundle.computed.entry(FactKind::SymbolIndex).or_default() += 1;
+        }
+
+        if kinds.contains(&FactKind::DefinitionIndex) {
+            let mut index: BTreeMap<String, Vec<DefinitionLocation>> = BTreeMap::new();
+            for (path, facts) in &bundle.files {
+                let Ok(facts) = facts else {
+                    continue;
+                };
+                for sig in facts.function_signatures.iter().filter(|s| s.is_definition) {
+                    index.entry(sig.name.clone()).or_default().push(DefinitionLocation {
+                        file: path.clone(),
+                        line: sig.span.start_line,
+                        param_count: sig.param_count,
+                    });
+                }
+            }
+            for locations in index.values_mut() {
+                locations.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+            }
+            bundle.definition_index = Some(index);
+            *bundle.computed.entry(FactKind::DefinitionIndex).or_default() += 1;
+        }
+
+        if kinds.contains(&FactKind::CallIndex) {
+            let mut index: BTreeMap<String, Vec<CallLocation>> = BTreeMap::new();
+            for (path, facts) in &bundle.files {
+                let Ok(facts) = facts else {
+                    continue;
+                };
+                for call in &facts.call_sites {
+                    index.entry(call.callee.clone()).or_default().push(CallLocation {
+                        file: path.clone(),
+                        line: call.span.start_line,
+                    });
+                }
+            }
+            for locations in index.values_mut() {
+                locations.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+            }
+            bundle.call_index = Some(index);
+            *bundle.computed.entry(FactKind::CallIndex).or_default() += 1;
+        }
+
+        if kinds.contains(&FactKind::TestClassification) {
+            let test_files = files
+                .iter()
+                .map(|f| f.as_ref())
+                .filter(|path| is_test_path(path.strip_prefix(&bundle.base_dir).unwrap_or(path)))
+                .map(Path::to_path_buf)
+                .collect();
+            bundle.test_files = Some(test_files);
+            *bundle.computed.entry(FactKind::TestClassification).or_default() += 1;
+        }
+
+        bundle
+    }
+
+    /// Base directory of the scan.
+    pub fn base_dir(&self) -> &Path {
+        &self.base_dir
+    }
+
+    /// AST facts for a file, or the parse error.
+    ///
+    /// Returns None for files no analyzer handles, and for every file when
+    /// no per-file kind was requested.
+    pub fn file(&self, path: &Path) -> Option<Result<&FileFacts, &str>> {
+        self.files
+            .get(path)
+            .map(|facts| facts.as_ref().map_err(String::as_str))
+    }
+
+    /// Declarations with the given name across the project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`FactKind::SymbolIndex`] was not requested.
+    pub fn symbols_named(&self, name: &str) -> &[SymbolLocation] {
+        self.symbol_index
+            .as_ref()
+            .expect("SymbolIndex facts were not requested")
+            .get(name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// C and C++ function definitions with the given unqualified name across the project.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`FactKind::DefinitionIndex`] was not requested.
+    pub fn definitions_named(&self, name: &str) -> &[DefinitionLocation] {
+        self.definition_index
+            .as_ref()
+            .expect("DefinitionIndex facts were not requested")
+            .get(name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Calls to the given name across the project, by file and line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`FactKind::CallIndex`] was not requested.
+    pub fn calls_to(&self, name: &str) -> &[CallLocation] {
+        self.call_index
+            .as_ref()
+            .expect("CallIndex facts were not requested")
+            .get(name)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether a file is a test file.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`FactKind::TestClassification`] was not requested.
+    pub fn is_test_file(&self, path: &Path) -> bool {
+        self.test_files
+            .as_ref()
+            .expect("TestClassification facts were not requested")
+            .contains(path)
+    }
+
+    /// How many times a fact kind was computed: the files parsed for it for
+    /// per-file kinds, once for project-wide kinds, zero if not requested.
+    /// Per-file kinds share one parse, and files the analysis context had
+    /// already cached are not parsed again.
+    pub fn computations(&self, kind: FactKind) -> usize {
+        self.computed.get(&kind).copied().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_each_kind_computed_once() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let files: Vec<PathBuf> = [
+            ("main.go", "package main\n\nfunc main() {}\n"),
+            ("main_test.go", "package main\n\nfunc TestMain(t *testing.T) {}\n"),
+            ("util.py", "def main():\n    return 1\n"),
+            ("README.md", "# Project\n"),
+            ("tests/helpers.py", "def fixture():\n    return 1\n"),
+        ]
+        .iter()
+        .map(|(name, content)| {
+            let path = temp.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+            path
+        })
+        .collect();
+
+        // Three rules that all read declarations
+        let ctx = AnalysisContext::new(temp.path());
+        let rules = [
+            ViolationRule::MissingSymbol,
+            ViolationRule::BuiltinShadowing,
+            ViolationRule::MissingTest,
+        ];
+        let facts = FactsBundle::for_rules(&ctx, &files, &rules);
+
+        // Each of the four analyzable files was parsed once, not once per rule
+        assert_eq!(ctx.files_parsed(), 4);
+        assert_eq!(facts.computations(FactKind::Declarations), 4);
+        assert_eq!(facts.computations(FactKind::TestClassification), 1);
+        assert_eq!(facts.computations(FactKind::Bodies), 0);
+        assert_eq!(facts.computations(FactKind::SymbolIndex), 0);
+
+        assert!(facts.file(&files[0]).unwrap().unwrap().find_declaration("main").is_some());
+        assert!(facts.file(&files[3]).is_none());
+        assert!(facts.is_test_file(&files[1]));
+        assert!(!facts.is_test_file(&files[0]));
+        assert!(facts.is_test_file(&files[4]));
+
+        // The symbol index spans languages, and reuses the files already parsed
+        let facts = FactsBundle::build(&ctx, &files, &[FactKind::SymbolIndex, FactKind::SymbolIndex]);
+        assert_eq!(facts.computations(FactKind::SymbolIndex), 1);
+        assert_eq!(ctx.files_parsed(), 4);
+        let mains: Vec<_> = facts.symbols_named("main").iter().map(|s| &s.file).collect();
+        assert_eq!(mains, vec![&files[0], &files[2]]);
+    }
+}
@@ -1,11 +1,87 @@
-//! Detection of missing required files.
+//! Detection of missing required files, forbidden deliverable files, and
+//! paths that collide on case-insensitive filesystems.
+//!
+//! Paths are compared case-sensitively whatever the filesystem: a required
+//! file is looked up entry by entry against the directory listings, so a
+//! contract naming `README.md` fails on macOS as it does on Linux when only
+//! `readme.md` exists, and reports the mismatch.
 
-use crate::contract::RequiredFile;
-use std::path::Path;
+use crate::contract::{Contract, RequiredFile};
+use std::collections::BTreeMap;
+use std::path::{Component, Path};
+use walkdir::WalkDir;
 
 use super::{DetectionResult, Severity, Violation, ViolationRule};
 
-/// Check that all required files exist.
+/// Third-party dependency directories that are never walked for forbidden files.
+pub(super) const DEPENDENCY_DIRS: &[&str] = &["node_modules", "vendor"];
+
+/// Suffixes that mark a `.env` file as a template safe to commit.
+const ENV_TEMPLATE_SUFFIXES: &[&str] = &[".example", ".sample", ".template", ".dist"];
+
+/// How a relative path resolves against the directory listings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaseLookup {
+    /// Every component names a directory entry exactly.
+    Exact,
+    /// Only a path differing in case exists; holds that path as listed.
+    CaseMismatch(String),
+    /// No entry matches, in any case.
+    Missing,
+}
+
+/// Resolve `relative` under `base` component by component, comparing names
+/// case-sensitively, so the answer does not depend on the filesystem.
+pub fn lookup_exact_case(base: &Path, relative: &str) -> CaseLookup {
+    let mut current = base.to_path_buf();
+    let mut listed = Vec::new();
+    let mut mismatch = false;
+
+    for component in Path::new(relative).components() {
+        let name = match component {
+            Component::Normal(name) => name.to_string_lossy().to_string(),
+            Component::CurDir => continue,
+            // Paths leaving the base or absolute paths are taken as given
+            _ => {
+                return if base.join(relative).exists() { CaseLookup::Exact } else { CaseLookup::Missing };
+            }
+        };
+        let Ok(entries) = std::fs::read_dir(&current) else {
+            return CaseLookup::Missing;
+        };
+        let mut names: Vec<String> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+
+        let found = match names.iter().find(|n| **n == name) {
+            Some(exact) => exact.clone(),
+            None => match names.iter().find(|n| n.to_lowercase() == name.to_lowercase()) {
+                Some(other) => {
+                    mismatch = true;
+                    other.clone()
+                }
+                None => return CaseLookup::Missing,
+            },
+        };
+        current.push(&found);
+        listed.push(found);
+    }
+
+    if mismatch {
+        CaseLookup::CaseMismatch(listed.join("/"))
+    } else {
+        CaseLookup::Exact
+    }
+}
+
+/// Message for a contract path that only exists with different case.
+pub fn case_mismatch_message(found: &str, required: &str) -> String {
+    format!("found '{}' but contract requires '{}' — case mismatch", found, required)
+}
+
+/// Check that all required files exist, with the exact case the contract uses.
 pub fn detect_missing_files<P: AsRef<Path>>(
     base_dir: P,
     files: &[RequiredFile],
@@ -18,6 +94,30 @@ pub fn detect_missing_files<P: AsRef<Path>>(
             continue;
         }
 
+        match lookup_exact_case(base, &f.path) {
+            CaseLookup::Exact => {}
+            CaseLookup::CaseMismatch(found) => {
+                result.add_violation(Violation {
+                    rule: ViolationRule::MissingFile,
+                    message: case_mismatch_message(&found, &f.path),
+                    file: Some(f.path.clone()),
+                    line: 0,
+                    severity: Severity::Critical,
+                });
+                continue;
+            }
+            CaseLookup::Missing => {
+                result.add_violation(Violation {
+                    rule: ViolationRule::MissingFile,
+                    message: format!("required file {:?} does not exist", f.path),
+                    file: Some(f.path.clone()),
+                    line: 0,
+                    severity: Severity::Critical,
+                });
+                continue;
+            }
+        }
+
         let full_path = base.join(&f.path);
         match std::fs::metadata(&full_path) {
             Ok(metadata) => {
@@ -25,7 +125,7 @@ pub fn detect_missing_files<P: AsRef<Path>>(
                     result.add_violation(Violation {
                         rule: ViolationRule::MissingFile,
                         message: format!("required file {:?} is a directory, not a file", f.path),
-                        file: f.path.clone(),
+                        file: Some(f.path.clone()),
                         line: 0,
                         severity: Severity::Critical,
                     });
@@ -35,7 +135,7 @@ pub fn detect_missing_files<P: AsRef<Path>>(
                 result.add_violation(Violation {
                     rule: ViolationRule::MissingFile,
                     message: format!("required file {:?} does not exist", f.path),
-                    file: f.path.clone(),
+                    file: Some(f.path.clone()),
                     line: 0,
                     severity: Severity::Critical,
                 });
@@ -49,6 +149,197 @@ pub fn detect_missing_files<P: AsRef<Path>>(
     Ok(result)
 }
 
+/// Check the deliverable for sibling paths whose names differ only by case
+/// (`README.md` and `Readme.md`). Git keeps both, but a checkout on a
+/// case-insensitive filesystem keeps only one of them.
+pub fn detect_case_collisions<P: AsRef<Path>>(
+    base_dir: P,
+    contract: &Contract,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let base = base_dir.as_ref();
+
+    let walker = WalkDir::new(base).into_iter().filter_entry(|e| {
+        let name = e.file_name().to_string_lossy();
+        !(e.depth() > 0
+            && e.file_type().is_dir()
+            && (name.starts_with('.') || DEPENDENCY_DIRS.contains(&name.as_ref())))
+    });
+
+    // Relative paths grouped by parent directory and lowercased name
+    let mut groups: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+    for entry in walker.filter_map(|e| e.ok()).filter(|e| e.depth() > 0) {
+        let path = entry.path();
+        if contract.is_path_excluded(path) {
+            continue;
+        }
+        let Ok(rel_path) = path.strip_prefix(base) else {
+            continue;
+        };
+        let parent = rel_path.parent().map(|p| p.to_string_lossy().to_string()).unwrap_or_default();
+        let name = entry.file_name().to_string_lossy().to_lowercase();
+        groups
+            .entry((parent, name))
+            .or_default()
+            .push(rel_path.to_string_lossy().to_string());
+    }
+
+    for mut paths in groups.into_values().filter(|paths| paths.len() > 1) {
+        paths.sort();
+        let listed: Vec<String> = paths.iter().map(|p| format!("{:?}", p)).collect();
+        result.add_violation(Violation {
+            rule: ViolationRule::CaseCollision,
+            message: format!(
+                "paths differ only by case: {}; only one survives a checkout on a case-insensitive filesystem",
+                listed.join(", ")
+            ),
+            file: Some(paths[0].clone()),
+            line: 0,
+            severity: ViolationRule::CaseCollision.default_severity(),
+        });
+    }
+
+    Ok(result)
+}
+
+/// Returns true for `.env` and `.env.*` files, excluding templates like `.env.example`.
+fn is_env_file(file_name: &str) -> bool {
+    (file_name == ".env" || file_name.starts_with(".env."))
+        && !ENV_TEMPLATE_SUFFIXES.iter().any(|s| file_name.ends_with(s))
+}
+
+/// Format a byte count for messages (e.g., "40.0 MB").
+fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    const MB: f64 = KB * 1024.0;
+    let b = bytes as f64;
+    if b >= MB {
+        format!("{:.1} MB", b / MB)
+    } else if b >= KB {
+        format!("{:.1} KB", b / KB)
+    } else {
+        format!("{} bytes", bytes)
+    }
+}
+
+/// Check the deliverable for files that must not be there.
+///
+/// Walks the project independently of source discovery, since junk files
+/// (`.orig`, `.pyc`, archives) have no supported extension and would never
+/// reach the per-file detectors. Only paths and metadata are inspected.
+/// Files matching `excluded_paths` are skipped; every rejected file is counted
+/// in `DetectionResult::rejected`.
+pub fn detect_forbidden_files<P: AsRef<Path>>(
+    base_dir: P,
+    contract: &Contract,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let base = base_dir.as_ref();
+
+    let forbid_env = contract.forbid_env_files();
+    let size_cfg = contract.max_file_size.as_ref();
+    if contract.forbidden_files.is_empty() && !forbid_env && size_cfg.is_none() {
+        return Ok(result);
+    }
+
+    let patterns: Vec<_> = contract
+        .forbidden_files
+        .iter()
+        .map(|f| {
+            let glob = globset::Glob::new(&f.pattern).map_err(|e| {
+                anyhow::anyhow!("invalid forbidden_files pattern {:?}: {}", f.pattern, e)
+            })?;
+            let severity = match &f.severity {
+                Some(s) => s.parse::<Severity>().map_err(|e| anyhow::anyhow!(e))?,
+                None => ViolationRule::ForbiddenFile.default_severity(),
+            };
+            Ok((f, glob.compile_matcher(), severity))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    let walker = WalkDir::new(base).into_iter().filter_entry(|e| {
+        let name = e.file_name().to_string_lossy();
+        !(e.depth() > 0
+            && e.file_type().is_dir()
+            && (name.starts_with('.') || DEPENDENCY_DIRS.contains(&name.as_ref())))
+    });
+
+    let mut entries: Vec<_> = walker
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .collect();
+    entries.sort_by(|a, b| a.path().cmp(b.path()));
+
+    for entry in entries {
+        let path = entry.path();
+        if contract.is_path_excluded(path) {
+            continue;
+        }
+
+        let rel_path = path
+            .strip_prefix(base)
+            .ok()
+            .filter(|p| !p.as_os_str().is_empty())
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+        let file_name = entry.file_name().to_string_lossy();
+        let mut rejected = false;
+
+        if let Some((f, _, severity)) = patterns.iter().find(|(_, m, _)| m.is_match(&rel_path)) {
+            let reason = f
+                .message
+                .clone()
+                .unwrap_or_else(|| format!("matches forbidden pattern {:?}", f.pattern));
+            result.add_violation(Violation {
+                rule: ViolationRule::ForbiddenFile,
+                message: format!("forbidden file {:?}: {}", rel_path, reason),
+                file: Some(rel_path.clone()),
+                line: 0,
+                severity: *severity,
+            });
+            rejected = true;
+        } else if forbid_env && is_env_file(&file_name) {
+            result.add_violation(Violation {
+                rule: ViolationRule::ForbiddenFile,
+                message: format!(
+                    "forbidden file {:?}: environment files may hold secrets and must not be delivered",
+                    rel_path
+                ),
+                file: Some(rel_path.clone()),
+                line: 0,
+                severity: ViolationRule::ForbiddenFile.default_severity(),
+            });
+            rejected = true;
+        }
+
+        if let Some(limit) = size_cfg.and_then(|c| c.limit_for(&rel_path)) {
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            if size > limit {
+                result.add_violation(Violation {
+                    rule: ViolationRule::ForbiddenFile,
+                    message: format!(
+                        "file {:?} is {}, exceeding the {} limit",
+                        rel_path,
+                        format_size(size),
+                        format_size(limit)
+                    ),
+                    file: Some(rel_path.clone()),
+                    line: 0,
+                    severity: ViolationRule::ForbiddenFile.default_severity(),
+                });
+                rejected = true;
+            }
+        }
+
+        if rejected {
+            result.rejected += 1;
+        }
+    }
+
+    Ok(result)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,4 +385,146 @@ mod tests {
         assert_eq!(result.violations.len(), 1);
         assert!(result.violations[0].message.contains("is a directory"));
     }
+
+    #[test]
+    fn test_detect_case_mismatch() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("docs")).unwrap();
+        std::fs::write(temp.path().join("readme.md"), "# app").unwrap();
+        std::fs::write(temp.path().join("docs/Guide.md"), "guide").unwrap();
+        std::fs::write(temp.path().join("main.go"), "package main").unwrap();
+
+        let required = |path: &str| RequiredFile {
+            path: path.to_string(),
+            required: true,
+        };
+        let files = vec![required("README.md"), required("Docs/guide.md"), required("./main.go"), required("Main.go")];
+
+        let result = detect_missing_files(temp.path(), &files).unwrap();
+        let messages: Vec<_> = result.violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "found 'readme.md' but contract requires 'README.md' — case mismatch",
+                "found 'docs/Guide.md' but contract requires 'Docs/guide.md' — case mismatch",
+                "found 'main.go' but contract requires 'Main.go' — case mismatch",
+            ]
+        );
+
+        assert_eq!(lookup_exact_case(temp.path(), "docs/Guide.md"), CaseLookup::Exact);
+        assert_eq!(lookup_exact_case(temp.path(), "docs/missing.md"), CaseLookup::Missing);
+        assert_eq!(lookup_exact_case(temp.path(), "readme.md/x"), CaseLookup::Missing);
+    }
+
+    #[test]
+    fn test_detect_case_collisions() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("src/Utils")).unwrap();
+        std::fs::create_dir_all(temp.path().join("src/utils")).unwrap();
+        std::fs::create_dir_all(temp.path().join("generated")).unwrap();
+        for name in ["README.md", "Readme.md", "src/main.go", "src/Main_test.go", "generated/A.go", "generated/a.go"] {
+            std::fs::write(temp.path().join(name), "x").unwrap();
+        }
+
+        let contract = Contract {
+            excluded_paths: vec!["**/generated/**".to_string()],
+            ..Default::default()
+        };
+        let result = detect_case_collisions(temp.path(), &contract).unwrap();
+        let found: Vec<_> = result.violations.iter().map(|v| (v.location(), v.message.as_str())).collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "README.md",
+                    "paths differ only by case: \"README.md\", \"Readme.md\"; only one survives a checkout on a case-insensitive filesystem"
+                ),
+                (
+                    "src/Utils",
+                    "paths differ only by case: \"src/Utils\", \"src/utils\"; only one survives a checkout on a case-insensitive filesystem"
+                ),
+            ]
+        );
+        assert!(result.violations.iter().all(|v| v.rule == ViolationRule::CaseCollision));
+        assert!(result.violations.iter().all(|v| v.severity == Severity::Error));
+    }
+
+    #[test]
+    fn test_detect_forbidden_files() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("src/__pycache__")).unwrap();
+        std::fs::write(temp.path().join("src/main.py"), "print('ok')").unwrap();
+        std::fs::write(temp.path().join("src/main.py.orig"), "old").unwrap();
+        std::fs::write(temp.path().join("src/__pycache__/main.cpython-312.pyc"), "x").unwrap();
+        std::fs::write(temp.path().join(".env"), "API_KEY=changeme").unwrap();
+        std::fs::write(temp.path().join(".env.example"), "API_KEY=").unwrap();
+
+        let contract: Contract = serde_yaml::from_str(
+            r#"
+forbidden_files:
+  - pattern: "**/*.orig"
+    message: "patch leftover"
+  - pattern: "**/*.pyc"
+    severity: warning
+"#,
+        )
+        .unwrap();
+
+        let result = detect_forbidden_files(temp.path(), &contract).unwrap();
+        assert_eq!(result.violations.len(), 3);
+        assert_eq!(result.rejected, 3);
+        assert!(result.violations.iter().all(|v| v.rule == ViolationRule::ForbiddenFile));
+        assert!(result.violations.iter().all(|v| v.line == 0));
+
+        let orig = result.violations.iter().find(|v| v.location() == "src/main.py.orig").unwrap();
+        assert!(orig.message.contains("patch leftover"));
+        assert_eq!(orig.severity, Severity::Error);
+
+        let pyc = result.violations.iter().find(|v| v.location().ends_with(".pyc")).unwrap();
+        assert_eq!(pyc.severity, Severity::Warning);
+
+        assert!(result.violations.iter().any(|v| v.location() == ".env"));
+        assert!(!result.violations.iter().any(|v| v.location() == ".env.example"));
+    }
+
+    #[test]
+    fn test_detect_env_file_can_be_disabled() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join(".env"), "API_KEY=changeme").unwrap();
+
+        let contract = Contract {
+            forbid_env_files: Some(false),
+            ..Default::default()
+        };
+        let result = detect_forbidden_files(temp.path(), &contract).unwrap();
+        assert!(result.violations.is_empty());
+        assert_eq!(result.rejected, 0);
+    }
+
+    #[test]
+    fn test_detect_oversized_files() {
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("assets")).unwrap();
+        std::fs::write(temp.path().join("node_modules.tar"), vec![0u8; 4096]).unwrap();
+        std::fs::write(temp.path().join("assets/logo.png"), vec![0u8; 4096]).unwrap();
+        std::fs::write(temp.path().join("main.go"), "package main").unwrap();
+
+        let contract: Contract = serde_yaml::from_str(
+            r#"
+forbid_env_files: false
+max_file_size:
+  max_bytes: 1024
+  paths:
+    - path: "assets/**"
+      max_bytes: 8192
+"#,
+        )
+        .unwrap();
+
+        let result = detect_forbidden_files(temp.path(), &contract).unwrap();
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].location(), "node_modules.tar");
+        assert!(result.violations[0].message.contains("4.0 KB"));
+        assert_eq!(result.rejected, 1);
+    }
 }
@@ -0,0 +1,375 @@
+//! Stable violation fingerprints for tracking violations across runs.
+//!
+//! A fingerprint is 16 hex digits of an FNV-1a 64 hash, with a NUL after
+//! each part, over (version 1):
+//!
+//! 1. the version tag `hollowcheck/v1`
+//! 2. the rule id
+//! 3. the file path relative to the scan root, with `/` separators (empty for
+//!    project-level violations)
+//! 4. the qualified name of the innermost declaration enclosing the line
+//!    (`Config.Validate`), or empty where none is known
+//! 5. the message template: the message with digit runs replaced by `#` and
+//!    whitespace collapsed, so counts and line numbers in messages do not
+//!    take part
+//! 6. the content window: the violation's line and the nearest non-blank
+//!    line above and below it, each trimmed with whitespace collapsed and
+//!    joined with `\n`. The window stops at the enclosing declaration's
+//!    bounds and is empty for violations without a line.
+//!
+//! Nothing in the hash is a line number, so lines shifting after an edit
+//! elsewhere in the file, or declarations being reordered, leave the
+//! fingerprint unchanged. Renaming the file or the enclosing declaration,
+//! or editing the flagged line, changes it. Violations with the same inputs
+//! in one file are numbered in line order; from the second on, the
+//! fingerprint is hashed again with the version tag and `#2`, `#3`, ...
+//!
+//! Any change to the parts or their normalization bumps
+//! [`FINGERPRINT_VERSION`], and the version tag with it.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::analysis::AnalysisContext;
+
+use super::Violation;
+
+/// Version of the fingerprint scheme.
+pub const FINGERPRINT_VERSION: u32 = 1;
+
+/// 16 hex digits of an FNV-1a 64 hash over `parts`, each followed by a NUL.
+pub fn hash_parts(parts: &[&str]) -> String {
+    let mut data = Vec::with_capacity(parts.iter().map(|p| p.len() + 1).sum());
+    for part in parts {
+        data.extend_from_slice(part.as_bytes());
+        data.push(0);
+    }
+    format!("{:016x}", crate::cache_file::checksum(&data))
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Message with digit runs replaced by `#` and whitespace collapsed.
+fn message_template(message: &str) -> String {
+    let mut template = String::with_capacity(message.len());
+    let mut in_digits = false;
+    for c in message.chars() {
+        if c.is_ascii_digit() {
+            if !in_digits {
+                template.push('#');
+            }
+            in_digits = true;
+        } else {
+            template.push(c);
+            in_digits = false;
+        }
+    }
+    collapse_whitespace(&template)
+}
+
+/// The flagged line and its nearest non-blank neighbors within `bounds`
+/// (1-indexed, inclusive).
+fn content_window(lines: &[&str], line: usize, bounds: (usize, usize)) -> String {
+    if line == 0 || line > lines.len() {
+        return String::new();
+    }
+    let (first, last) = (bounds.0.max(1), bounds.1.min(lines.len()));
+    let non_blank = |n: &usize| !lines[*n - 1].trim().is_empty();
+
+    let above = (first..line).rev().find(non_blank);
+    let below = (line + 1..=last).find(non_blank);
+    [above, Some(line), below]
+        .into_iter()
+        .flatten()
+        .map(|n| collapse_whitespace(lines[n - 1]))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Path relative to the scan root, with `/` separators.
+fn relative_path(file: &str, base_dir: &Path) -> String {
+    let path = Path::new(file);
+    let relative = path.strip_prefix(base_dir).unwrap_or(path);
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    relative.trim_start_matches("./").to_string()
+}
+
+/// A file a violation points into, read once per run.
+struct SourceFile {
+    content: String,
+    /// (start, end, qualified name) of each declaration
+    scopes: Vec<(usize, usize, String)>,
+}
+
+impl SourceFile {
+    /// Innermost declaration enclosing `line`.
+    fn scope(&self, line: usize) -> Option<&(usize, usize, String)> {
+        self.scopes
+            .iter()
+            .filter(|(start, end, _)| (*start..=*end).contains(&line))
+            .min_by_key(|(start, end, _)| end - start)
+    }
+}
+
+/// Fingerprint of a violation from its normalized parts.
+fn fingerprint_of(rule: &str, path: &str, scope: &str, message: &str, window: &str) -> String {
+    let tag = format!("hollowcheck/v{}", FINGERPRINT_VERSION);
+    hash_parts(&[&tag, rule, path, scope, &message_template(message), window])
+}
+
+/// Fingerprint of a violation without reading its file: no enclosing
+/// declaration and an empty content window.
+///
+/// Used for results that did not come from the runner.
+pub fn fallback_fingerprint(violation: &Violation) -> String {
+    fallback_fingerprint_parts(
+        violation.rule.as_str(),
+        violation.file.as_deref(),
+        &violation.message,
+        Path::new(""),
+    )
+}
+
+/// Fingerprint from a violation's rule, file, and message alone, with the
+/// file made relative to `base_dir`: no enclosing declaration and an empty
+/// content window.
+///
+/// Used for reports written without fingerprints.
+pub fn fallback_fingerprint_parts(rule: &str, file: Option<&str>, message: &str, base_dir: &Path) -> String {
+    fingerprint_of(rule, &relative_path(file.unwrap_or(""), base_dir), "", message, "")
+}
+
+/// Fingerprint every violation, keyed by `Violation::key()`.
+///
+/// Declarations come from the analysis context, and only for `analyzable`
+/// files; others (skipped by the analysis budget, or in languages without
+/// an analyzer) have no enclosing declaration.
+pub fn fingerprint_violations(
+    base_dir: &Path,
+    ctx: &AnalysisContext,
+    analyzable: &[PathBuf],
+    violations: &[&Violation],
+) -> BTreeMap<String, String> {
+    let analyzable: HashSet<&Path> = analyzable.iter().map(PathBuf::as_path).collect();
+    let mut sources: HashMap<&str, Option<SourceFile>> = HashMap::new();
+
+    // Number identical inputs in line order
+    let mut ordered: Vec<&Violation> = violations.to_vec();
+    ordered.sort_by_key(|v| (v.file.clone(), v.line));
+
+    let mut seen: HashMap<String, usize> = HashMap::new();
+    let mut fingerprints = BTreeMap::new();
+    for violation in ordered {
+        let (path, scope, window) = match violation.file.as_deref() {
+            None => (String::new(), String::new(), String::new()),
+            Some(file) => {
+                let source = sources
+                    .entry(file)
+                    .or_insert_with(|| load_source(base_dir, ctx, &analyzable, file));
+                let (scope, window) = match source {
+                    Some(source) => {
+                        let lines: Vec<&str> = source.content.lines().collect();
+                        match source.scope(violation.line) {
+                            Some((start, end, name)) => (
+                                name.clone(),
+                                content_window(&lines, violation.line, (*start, *end)),
+                            ),
+                            None => (
+                                String::new(),
+                                content_window(&lines, violation.line, (1, lines.len())),
+                            ),
+                        }
+                    }
+                    None => (String::new(), String::new()),
+                };
+                (relative_path(file, base_dir), scope, window)
+            }
+        };
+
+        let mut fingerprint = fingerprint_of(
+            violation.rule.as_str(),
+            &path,
+            &scope,
+            &violation.message,
+            &window,
+        );
+        let occurrence = seen.entry(fingerprint.clone()).or_default();
+        *occurrence += 1;
+        if *occurrence > 1 {
+            let tag = format!("hollowcheck/v{}", FINGERPRINT_VERSION);
+            fingerprint = hash_parts(&[&tag, &fingerprint, &format!("#{}", occurrence)]);
+        }
+        fingerprints.insert(violation.key(), fingerprint);
+    }
+    fingerprints
+}
+
+fn load_source(
+    base_dir: &Path,
+    ctx: &AnalysisContext,
+    analyzable: &HashSet<&Path>,
+    file: &str,
+) -> Option<SourceFile> {
+    let path = Path::new(file);
+    let path = if path.is_absolute() || path.exists() {
+        path.to_path_buf()
+    } else {
+        base_dir.join(path)
+    };
+    let content = std::fs::read_to_string(&path).ok()?;
+    let scopes = if analyzable.contains(Path::new(file)) {
+        ctx.analyze_file(&path)
+            .map(|facts| {
+                facts
+                    .declarations
+                    .iter()
+                    .map(|d| (d.span.start_line, d.span.end_line, d.qualified_name()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    Some(SourceFile { content, scopes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::ViolationRule;
+    use tempfile::TempDir;
+
+    /// Fingerprints of stub-like violations on each `panic("todo")` line of a
+    /// Go file, keyed by the function they are in.
+    fn fingerprints(name: &str, source: &str) -> BTreeMap<String, String> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join(name);
+        std::fs::write(&file, source).unwrap();
+
+        let mut violations = Vec::new();
+        let mut function = String::new();
+        for (i, line) in source.lines().enumerate() {
+            if let Some(rest) = line.strip_prefix("func ") {
+                function = rest.split('(').next().unwrap().to_string();
+            }
+            if line.contains("panic(") {
+                violations.push(
+                    Violation::new(ViolationRule::ForbiddenPattern, "forbidden pattern found: panic(")
+                        .at(file.to_string_lossy(), i + 1),
+                );
+            }
+            if line.contains("example.com") {
+                violations.push(
+                    Violation::new(ViolationRule::MockData, format!("mock data in {} at line {}", function, i + 1))
+                        .at(file.to_string_lossy(), i + 1),
+                );
+            }
+        }
+
+        let ctx = AnalysisContext::new(temp.path());
+        let refs: Vec<&Violation> = violations.iter().collect();
+        let by_key = fingerprint_violations(temp.path(), &ctx, std::slice::from_ref(&file), &refs);
+        violations
+            .iter()
+            .map(|v| {
+                let scope = source.lines().take(v.line).filter(|l| l.starts_with("func ")).last().unwrap_or("");
+                (format!("{} {}", v.rule, scope), by_key[&v.key()].clone())
+            })
+            .collect()
+    }
+
+    const FUNCTIONS: [&str; 4] = [
+        "func Charge(amount int) error {\n\tpanic(\"todo\")\n}\n",
+        "func Refund(id string) error {\n\tif id == \"\" {\n\t\treturn nil\n\t}\n\tpanic(\"todo\")\n}\n",
+        "func Endpoint() string {\n\treturn \"https://example.com/api\"\n}\n",
+        "func Ping() bool {\n\treturn true\n}\n",
+    ];
+
+    fn program(order: &[usize], padding: usize) -> String {
+        let mut source = String::from("package payments\n\n");
+        for (n, i) in order.iter().enumerate() {
+            source.push_str(&"\n".repeat(if n == 0 { padding } else { 1 }));
+            source.push_str(FUNCTIONS[*i]);
+        }
+        source
+    }
+
+    fn permutations(items: &[usize]) -> Vec<Vec<usize>> {
+        if items.len() <= 1 {
+            return vec![items.to_vec()];
+        }
+        let mut all = Vec::new();
+        for (i, item) in items.iter().enumerate() {
+            let mut rest = items.to_vec();
+            rest.remove(i);
+            for mut tail in permutations(&rest) {
+                tail.insert(0, *item);
+                all.push(tail);
+            }
+        }
+        all
+    }
+
+    #[test]
+    fn test_stable_under_reordering_and_line_shifts() {
+        let expected = fingerprints("pay.go", &program(&[0, 1, 2, 3], 0));
+        assert_eq!(expected.len(), 3, "{:?}", expected);
+
+        for order in permutations(&[0, 1, 2, 3]) {
+            for padding in [0, 1, 7] {
+                let found = fingerprints("pay.go", &program(&order, padding));
+                assert_eq!(found, expected, "order {:?}, padding {}", order, padding);
+            }
+        }
+    }
+
+    #[test]
+    fn test_stable_under_unrelated_edits() {
+        let expected = fingerprints("pay.go", &program(&[0, 1, 2, 3], 0));
+
+        let edited = program(&[0, 1, 2, 3], 0)
+            .replace("return true", "return len(os.Args) > 1")
+            .replace("package payments\n", "package payments\n\nimport \"os\"\n\n// Payments API.\n");
+        assert_eq!(fingerprints("pay.go", &edited), expected);
+    }
+
+    #[test]
+    fn test_changes_on_rename() {
+        let original = fingerprints("pay.go", &program(&[0, 1, 2, 3], 0));
+
+        let renamed = program(&[0, 1, 2, 3], 0).replace("func Charge(", "func Capture(");
+        let found = fingerprints("pay.go", &renamed);
+        assert_ne!(found["forbidden_pattern func Capture(amount int) error {"], original["forbidden_pattern func Charge(amount int) error {"]);
+        assert_eq!(found["mock_data func Endpoint() string {"], original["mock_data func Endpoint() string {"]);
+
+        let moved = fingerprints("billing.go", &program(&[0, 1, 2, 3], 0));
+        assert!(moved.values().all(|fp| !original.values().any(|o| o == fp)));
+
+        let flagged_line_edited = program(&[0, 1, 2, 3], 0).replace("example.com/api", "example.com/v2");
+        let found = fingerprints("pay.go", &flagged_line_edited);
+        assert_ne!(found["mock_data func Endpoint() string {"], original["mock_data func Endpoint() string {"]);
+    }
+
+    #[test]
+    fn test_hash_parts_is_fixed() {
+        // Stored fingerprints depend on this value never changing
+        assert_eq!(hash_parts(&["hollowcheck/v1", "stub_function"]), "243bfd55d142fb95");
+    }
+
+    #[test]
+    fn test_message_template_and_occurrences() {
+        assert_eq!(message_template("complexity 1,  expected 12"), "complexity #, expected #");
+
+        let a = Violation::new(ViolationRule::ForbiddenPattern, "TODO").at("a.go", 3);
+        let b = Violation::new(ViolationRule::ForbiddenPattern, "TODO").at("a.go", 9);
+        let ctx = AnalysisContext::new("/nonexistent");
+        let found = fingerprint_violations(Path::new("/nonexistent"), &ctx, &[], &[&b, &a]);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[&a.key()], fallback_fingerprint(&a));
+        assert_ne!(found[&a.key()], found[&b.key()]);
+    }
+}
@@ -0,0 +1,171 @@
+//! Detection of Swift functions that lean on `!` force-unwraps and `try!`.
+//!
+//! A postfix `!` crashes when the optional is nil and `try!` crashes when the
+//! call throws; generated Swift uses both to get past the type checker.
+//! Crashing sites per function come from `FileFacts::force_unwraps`; a
+//! function is flagged when it has more than `max_per_function` of them.
+//! Test code (`@Test` functions, `XCTestCase` subclasses, and files matching
+//! `test_paths`) is exempt.
+
+use std::path::Path;
+
+use crate::analysis::ForceUnwrapUsage;
+use crate::contract::ForceUnwrapsConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Describe the crashing sites of a function: "2 force-unwraps, 1 try!".
+fn breakdown(usage: &ForceUnwrapUsage) -> String {
+    let unwraps = match usage.force_unwraps {
+        0 => None,
+        1 => Some("1 force-unwrap".to_string()),
+        n => Some(format!("{} force-unwraps", n)),
+    };
+    let tries = (usage.force_tries > 0).then(|| format!("{} try!", usage.force_tries));
+    unwraps.into_iter().chain(tries).collect::<Vec<_>>().join(", ")
+}
+
+/// Flag Swift functions with too many `!` force-unwraps and `try!` expressions.
+pub fn detect_force_unwraps<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &ForceUnwrapsConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let test_paths = build_globs("force_unwraps test_paths", &config.test_paths())?;
+    let max = config.max_per_function();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if file_facts.language != "swift" {
+            continue;
+        }
+        let relative = path.strip_prefix(facts.base_dir()).unwrap_or(path);
+        if test_paths.is_match(relative) {
+            continue;
+        }
+        result.scanned += 1;
+
+        for usage in &file_facts.force_unwraps {
+            let total = usage.total();
+            if usage.in_test || total <= max {
+                continue;
+            }
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::ForceUnwrap,
+                    format!(
+                        "{} can crash at {} site{} ({}), more than the limit of {}",
+                        usage.function,
+                        total,
+                        if total == 1 { "" } else { "s" },
+                        breakdown(usage),
+                        max
+                    ),
+                )
+                .at(path.to_string_lossy(), usage.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &ForceUnwrapsConfig) -> Vec<(String, usize, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::ForceUnwrap]);
+        detect_force_unwraps(&facts, &paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                let file = v.file.as_deref().unwrap();
+                let relative = Path::new(file).strip_prefix(temp.path()).unwrap().display().to_string();
+                (relative, v.line, v.message)
+            })
+            .collect()
+    }
+
+    const PROFILE: &str = r#"import Foundation
+
+struct ProfileLoader {
+    func load(from url: URL) -> Profile {
+        let data = try! Data(contentsOf: url)
+        let json = try! JSONSerialization.jsonObject(with: data) as! [String: Any]
+        let name = json["name"] as? String
+        return Profile(name: name!, avatar: URL(string: json["avatar"] as! String)!)
+    }
+
+    func cached(_ key: String) -> Profile? {
+        return cache[key]!
+    }
+}
+
+final class ProfileLoaderTests: XCTestCase {
+    func testLoad() {
+        let a = try! loader.load(from: fixture!)
+        let b = try! loader.load(from: fixture!)
+        XCTAssertEqual(a!, b!)
+    }
+}
+"#;
+
+    #[test]
+    fn test_functions_over_the_limit() {
+        let found = detect(
+            &[("Sources/Profile/ProfileLoader.swift", PROFILE), ("Tests/ProfileTests/Fixtures.swift", PROFILE)],
+            &ForceUnwrapsConfig::default(),
+        );
+        assert_eq!(
+            found,
+            vec![(
+                "Sources/Profile/ProfileLoader.swift".to_string(),
+                4,
+                "ProfileLoader.load can crash at 4 sites (2 force-unwraps, 2 try!), more than the limit of 3"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_configurable_limit_and_test_paths() {
+        let config = ForceUnwrapsConfig {
+            max_per_function: Some(0),
+            test_paths: Some(vec![]),
+            ..Default::default()
+        };
+        let found = detect(&[("Tests/ProfileTests/Fixtures.swift", PROFILE)], &config);
+        let lines: Vec<_> = found.iter().map(|(_, line, _)| *line).collect();
+        assert_eq!(lines, vec![4, 11]);
+        assert_eq!(
+            found[1].2,
+            "ProfileLoader.cached can crash at 1 site (1 force-unwrap), more than the limit of 0"
+        );
+    }
+}
@@ -0,0 +1,404 @@
+//! Special handling of generated files.
+//!
+//! Files written by protoc, Thrift, sqlc, or an ORM generator are not hand
+//! edited, so findings in them only bury the ones the user can act on. The
+//! analyzers mark a file as generated when its leading comments carry a
+//! generator's stamp (`FileFacts::is_generated`). With a `generated_files`
+//! contract section, such files are skipped by every rule, or checked only
+//! by the rules it lists.
+//!
+//! A generated file someone has since edited by hand is different: the edits
+//! are code the user owns. Such a file is kept in the scan when it shows
+//! hand-written declarations outside the blocks its generator marks
+//! (`BEGIN GENERATED` ... `END GENERATED`, or everything up to a footer such
+//! as protoc's `@@protoc_insertion_point(module_scope)`), carries a comment
+//! like `manually edited`, or, when asked for, when git shows its last commit
+//! touched no generator input. The generated blocks of such a file are
+//! blanked out before analysis, so whole-file measures (line and function
+//! counts, comment ratio, documentation and implementation tallies) cover
+//! only the hand-written code. Findings inside the blocks from rules that
+//! read the file as text are then treated as in any generated file; the
+//! others are reported as usual, with a note.
+
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext, FileFacts};
+use crate::contract::GeneratedFilesConfig;
+
+use super::{Violation, ViolationRule};
+
+/// Start of a block a generator owns.
+static BLOCK_BEGIN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:begin|start)[ _-](?:auto-?)?generated\b|<auto-generated>").unwrap());
+
+/// End of a block a generator owns; without a matching start, the block
+/// starts at the top of the file (a footer).
+static BLOCK_END: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bend[ _-](?:of[ _-])?(?:auto-?)?generated\b|</auto-generated>|@@protoc_insertion_point\(module_scope\)",
+    )
+    .unwrap()
+});
+
+/// A note that the file was changed by hand.
+static MANUAL_EDIT: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\bmanual(?:ly)?[ -]?(?:edit|edited|edits|change|changed|changes|addition|additions|added)\b|\bhand[ -]?(?:edited|written|added)\b|\b(?:added|edited|changed) by hand\b",
+    )
+    .unwrap()
+});
+
+/// Generator warnings that mention manual changes only to forbid them
+/// ("Manual changes to this file will be overwritten").
+static GENERATOR_WARNING: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?i)\b(?:not|never|may|will be|overwritten|lost)\b").unwrap());
+
+/// Note appended to findings in the hand-edited parts of a generated file.
+const EDITED_NOTE: &str = " (hand-edited code in a generated file)";
+
+/// Generated files found in a scan, in absolute form.
+#[derive(Debug, Default)]
+pub struct GeneratedFiles {
+    /// Files that are generated throughout.
+    pub pristine: HashSet<PathBuf>,
+    /// Generated files edited by hand, with the line ranges their generator
+    /// still owns. Empty when the file marks no blocks, so all of it is
+    /// checked.
+    pub modified: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+impl GeneratedFiles {
+    /// The generated blocks of a hand-edited file; empty for any other file.
+    pub fn blocks(&self, base_dir: &Path, path: &Path) -> &[RangeInclusive<usize>] {
+        self.modified.get(&absolute(base_dir, path)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Have `analysis_ctx` analyze hand-edited files outside their generated blocks.
+    pub fn mask_blocks(&self, analysis_ctx: &AnalysisContext) {
+        for (path, blocks) in &self.modified {
+            if !blocks.is_empty() {
+                analysis_ctx.mask_lines(path, blocks.clone());
+            }
+        }
+    }
+}
+
+/// Whether a finding measures a whole file rather than pointing at a line.
+/// These are taken outside the generated blocks, so they always concern the
+/// hand-written code, whatever line they are reported at.
+fn is_whole_file(v: &Violation) -> bool {
+    matches!(v.rule, ViolationRule::GodFile | ViolationRule::CommentRatio)
+}
+
+/// Absolute form of a path, as the analysis cache keys it.
+fn absolute(base_dir: &Path, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        base_dir.join(path)
+    }
+}
+
+/// Text of a comment line, without its comment marker.
+fn comment_text(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    ["//", "/*", "<!--", "#", "*", "--", ";"]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+}
+
+/// The line ranges a generator owns in a generated file that was edited by
+/// hand, or `None` when nothing shows a hand edit.
+fn edited_regions(source: &str, facts: &FileFacts) -> Option<Vec<RangeInclusive<usize>>> {
+    let mut blocks = Vec::new();
+    let mut open = None;
+    let mut manual_edit = false;
+    let mut last_line = 0;
+    for (line, text) in (1..).zip(source.lines()) {
+        last_line = line;
+        let Some(comment) = comment_text(text) else {
+            continue;
+        };
+        if BLOCK_BEGIN.is_match(comment) {
+            open.get_or_insert(line);
+        } else if BLOCK_END.is_match(comment) {
+            blocks.push(open.take().unwrap_or(1)..=line);
+        } else if MANUAL_EDIT.is_match(comment) && !GENERATOR_WARNING.is_match(comment) {
+            manual_edit = true;
+        }
+    }
+    if let Some(start) = open {
+        blocks.push(start..=last_line);
+    }
+
+    let hand_written = !blocks.is_empty()
+        && facts
+            .declarations
+            .iter()
+            .any(|d| !blocks.iter().any(|block| block.contains(&d.span.start_line)));
+    (manual_edit || hand_written).then_some(blocks)
+}
+
+/// Whether a file is an input to a code generator: a schema, IDL, or API
+/// description.
+fn is_generator_input(path: &str) -> bool {
+    let path = Path::new(path);
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("").to_lowercase();
+    matches!(ext, "proto" | "thrift" | "graphql" | "graphqls" | "gql" | "sql" | "avsc" | "fbs" | "prisma")
+        || (matches!(ext, "yaml" | "yml" | "json") && (name.contains("openapi") || name.contains("swagger")))
+}
+
+/// Whether git history shows a file edited by hand: it has been committed
+/// more than once, and its last commit touched no generator input.
+fn edited_in_git(base_dir: &Path, path: &Path) -> bool {
+    let path = path.to_string_lossy();
+    let Ok(log) = crate::ci::git(base_dir, &["log", "-2", "--format=%H", "--", &path]) else {
+        return false;
+    };
+    let commits: Vec<_> = log.lines().collect();
+    if commits.len() < 2 {
+        return false;
+    }
+    crate::ci::git(base_dir, &["show", "--format=", "--name-only", commits[0]])
+        .is_ok_and(|changed| !changed.lines().any(is_generator_input))
+}
+
+/// Files whose facts mark them as generated, split into those generated
+/// throughout and those edited by hand since.
+///
+/// Files without an analyzer are never considered generated.
+pub fn find_generated_files(
+    base_dir: &Path,
+    analysis_ctx: &AnalysisContext,
+    files: &[PathBuf],
+    config: &GeneratedFilesConfig,
+) -> GeneratedFiles {
+    let mut generated = GeneratedFiles::default();
+    for file in files {
+        if get_analyzer(analysis_extension(file)).is_none() {
+            continue;
+        }
+        let Ok(facts) = analysis_ctx.analyze_file(file) else {
+            continue;
+        };
+        if !facts.is_generated {
+            continue;
+        }
+        let path = absolute(base_dir, file);
+        let edits = if config.modified {
+            let source = std::fs::read_to_string(&path).unwrap_or_default();
+            edited_regions(&source, &facts).or_else(|| (config.git && edited_in_git(base_dir, &path)).then(Vec::new))
+        } else {
+            None
+        };
+        match edits {
+            Some(blocks) => {
+                generated.modified.insert(path, blocks);
+            }
+            None => {
+                generated.pristine.insert(path);
+            }
+        }
+    }
+    generated
+}
+
+/// Drop the files in `generated` from a file list.
+pub fn drop_generated_files(base_dir: &Path, files: Vec<PathBuf>, generated: &HashSet<PathBuf>) -> Vec<PathBuf> {
+    files
+        .into_iter()
+        .filter(|f| !generated.contains(&absolute(base_dir, f)))
+        .collect()
+}
+
+/// Keep only violations in generated files of the rules still checked there.
+pub fn filter_generated_violations(
+    base_dir: &Path,
+    violations: Vec<Violation>,
+    generated: &HashSet<PathBuf>,
+    rules: &[ViolationRule],
+) -> Vec<Violation> {
+    violations
+        .into_iter()
+        .filter(|v| {
+            rules.contains(&v.rule)
+                || !v
+                    .file
+                    .as_deref()
+                    .is_some_and(|file| generated.contains(&absolute(base_dir, Path::new(file))))
+        })
+        .collect()
+}
+
+/// Keep findings in hand-edited generated files only where they apply.
+///
+/// Findings inside a generated block, or tied to no line of a file that
+/// marks blocks, are kept only for the rules still checked in generated
+/// files. The others, whole-file measures included, keep their severity and
+/// get a note in the message.
+pub fn scope_edited_violations(
+    base_dir: &Path,
+    violations: Vec<Violation>,
+    modified: &HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+    rules: &[ViolationRule],
+) -> Vec<Violation> {
+    violations
+        .into_iter()
+        .filter_map(|mut v| {
+            let Some(blocks) = v
+                .file
+                .as_deref()
+                .and_then(|file| modified.get(&absolute(base_dir, Path::new(file))))
+            else {
+                return Some(v);
+            };
+            let in_block = !blocks.is_empty()
+                && !is_whole_file(&v)
+                && (v.line == 0 || blocks.iter().any(|b| b.contains(&v.line)));
+            if in_block {
+                return rules.contains(&v.rule).then_some(v);
+            }
+            v.message.push_str(EDITED_NOTE);
+            Some(v)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_generated_files_and_their_violations() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = temp.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path
+        };
+        let files = vec![
+            write("api.pb.go", "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n"),
+            write("main.go", "package main\n\nfunc main() {}\n"),
+            write("schema.graphql", "# @generated\ntype Query { id: ID }\n"),
+        ];
+
+        let ctx = AnalysisContext::new(temp.path());
+        let generated = find_generated_files(temp.path(), &ctx, &files, &GeneratedFilesConfig::default()).pristine;
+        assert_eq!(generated, HashSet::from([files[0].clone()]));
+        assert_eq!(
+            drop_generated_files(temp.path(), files.clone(), &generated),
+            vec![files[1].clone(), files[2].clone()]
+        );
+
+        let violations = vec![
+            Violation::new(ViolationRule::StubFunction, "stub").at("api.pb.go", 3),
+            Violation::new(ViolationRule::HallucinatedDependency, "missing").at(files[0].to_string_lossy(), 3),
+            Violation::new(ViolationRule::StubFunction, "stub").at("main.go", 3),
+            Violation::new(ViolationRule::MissingFile, "missing"),
+        ];
+        let kept = filter_generated_violations(
+            temp.path(),
+            violations,
+            &generated,
+            &[ViolationRule::HallucinatedDependency],
+        );
+        let rules: Vec<_> = kept.iter().map(|v| (v.rule, v.file.as_deref().map(|f| f.ends_with("main.go")))).collect();
+        assert_eq!(
+            rules,
+            vec![
+                (ViolationRule::HallucinatedDependency, Some(false)),
+                (ViolationRule::StubFunction, Some(true)),
+                (ViolationRule::MissingFile, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_edited_generated_files() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let write = |name: &str, content: &str| {
+            let path = temp.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            path
+        };
+        let header = "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n\n";
+        let files = vec![
+            // Blocks only: generated throughout
+            write(
+                "blocks.pb.go",
+                &format!("{header}// BEGIN GENERATED CODE\nfunc Encode() {{}}\n// END GENERATED CODE\n"),
+            ),
+            // A declaration after the generated block
+            write(
+                "added.pb.go",
+                &format!("{header}// BEGIN GENERATED CODE\nfunc Encode() {{}}\n// END GENERATED CODE\n\nfunc Decode() {{}}\n"),
+            ),
+            // A declaration after the protoc footer
+            write(
+                "footer.pb.go",
+                &format!("{header}func Encode() {{}}\n\n// @@protoc_insertion_point(module_scope)\n\nfunc Decode() {{}}\n"),
+            ),
+            // A note, and a generator warning that is not one
+            write("noted.pb.go", &format!("{header}// Manually edited to add retries\nfunc Encode() {{}}\n")),
+            write(
+                "warned.pb.go",
+                &format!("{header}// Manual changes to this file will be overwritten.\nfunc Encode() {{}}\n"),
+            ),
+        ];
+
+        let ctx = AnalysisContext::new(temp.path());
+        let generated = find_generated_files(temp.path(), &ctx, &files, &GeneratedFilesConfig::default());
+        assert_eq!(generated.pristine, HashSet::from([files[0].clone(), files[4].clone()]));
+        assert_eq!(generated.modified[&files[1]], vec![5..=7]);
+        assert_eq!(generated.modified[&files[2]], vec![1..=7]);
+        assert!(generated.modified[&files[3]].is_empty());
+
+        let config = GeneratedFilesConfig {
+            modified: false,
+            ..Default::default()
+        };
+        let generated = find_generated_files(temp.path(), &ctx, &files, &config);
+        assert_eq!(generated.pristine.len(), files.len());
+        assert!(generated.modified.is_empty());
+
+        let modified = HashMap::from([(files[1].clone(), vec![5..=7]), (files[3].clone(), vec![])]);
+        let violations = vec![
+            Violation::new(ViolationRule::StubFunction, "stub").at("added.pb.go", 6),
+            Violation::new(ViolationRule::StubFunction, "stub").at("added.pb.go", 9),
+            Violation::new(ViolationRule::MockData, "mock").at("added.pb.go", 0),
+            // Whole-file measures are taken outside the blocks, wherever they are reported
+            Violation::new(ViolationRule::GodFile, "large").at("added.pb.go", 6),
+            Violation::new(ViolationRule::StubFunction, "stub").at("noted.pb.go", 6),
+        ];
+        let kept = scope_edited_violations(temp.path(), violations, &modified, &[]);
+        let kept: Vec<_> = kept.iter().map(|v| (v.file.as_deref().unwrap(), v.line, v.message.as_str())).collect();
+        assert_eq!(
+            kept,
+            vec![
+                ("added.pb.go", 9, "stub (hand-edited code in a generated file)"),
+                ("added.pb.go", 6, "large (hand-edited code in a generated file)"),
+                ("noted.pb.go", 6, "stub (hand-edited code in a generated file)"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generator_inputs() {
+        assert!(is_generator_input("api/service.proto"));
+        assert!(is_generator_input("spec/openapi.yaml"));
+        assert!(is_generator_input("db/queries.sql"));
+        assert!(!is_generator_input("config/settings.yaml"));
+        assert!(!is_generator_input("api/service.pb.go"));
+    }
+}
@@ -0,0 +1,31 @@
+//! Compiling contract glob patterns.
+
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+/// Compile the glob patterns of a contract field into one set.
+///
+/// `field` names the setting in errors, e.g. `"trivial_wrappers api_facade_paths"`.
+pub(crate) fn build_globs<S: AsRef<str>>(field: &str, patterns: &[S]) -> anyhow::Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let pattern = pattern.as_ref();
+        let glob = Glob::new(pattern).map_err(|e| anyhow::anyhow!("invalid {} {:?}: {}", field, pattern, e))?;
+        builder.add(glob);
+    }
+    Ok(builder.build()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_globs_names_field_in_errors() {
+        let globs = build_globs("unwrap_overuse production_paths", &["src/**", "lib/*.rs"]).unwrap();
+        assert!(globs.is_match("src/main.rs"));
+        assert!(!globs.is_match("tests/main.rs"));
+
+        let err = build_globs("doc_drift docs", &["docs/[.md"]).unwrap_err();
+        assert!(err.to_string().starts_with("invalid doc_drift docs \"docs/[.md\""), "{}", err);
+    }
+}
@@ -7,8 +7,10 @@
 //! - God classes: Too many methods
 
 use std::collections::HashMap;
+use std::ops::RangeInclusive;
 use std::path::Path;
 
+use crate::analysis::{analysis_extension, blank_lines};
 use crate::parser;
 
 use super::{DetectionResult, Severity, Violation, ViolationRule};
@@ -74,7 +76,7 @@ pub fn detect_god_objects<P: AsRef<Path>>(
 
     for file in files {
         let file_path = file.as_ref();
-        let file_violations = check_file(file_path, config)?;
+        let file_violations = check_file(file_path, config, &[])?;
         result.violations.extend(file_violations);
         result.scanned += 1;
     }
@@ -82,16 +84,38 @@ pub fn detect_god_objects<P: AsRef<Path>>(
     Ok(result)
 }
 
-/// Check a single file for god object issues.
-fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<Violation>> {
+/// Detect god objects in a file outside the given line ranges, such as the
+/// blocks a code generator owns in a generated file edited by hand since.
+/// The ranges are blanked before parsing and their lines are not counted.
+pub fn detect_god_objects_outside<P: AsRef<Path>>(
+    file: P,
+    excluded: &[RangeInclusive<usize>],
+    config: &GodObjectConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    result.violations = check_file(file.as_ref(), config, excluded)?;
+    result.scanned += 1;
+    Ok(result)
+}
+
+/// Check a single file for god object issues, leaving out the excluded lines.
+fn check_file(
+    file_path: &Path,
+    config: &GodObjectConfig,
+    excluded: &[RangeInclusive<usize>],
+) -> anyhow::Result<Vec<Violation>> {
     let mut violations = Vec::new();
     let file_str = file_path.to_string_lossy().to_string();
 
     // Read file content ONCE and reuse
-    let content = std::fs::read(file_path)?;
+    let mut content = std::fs::read(file_path)?;
+    blank_lines(&mut content, excluded);
     let content_str = String::from_utf8_lossy(&content);
     let lines: Vec<&str> = content_str.lines().collect();
-    let line_count = lines.len();
+    let excluded_lines = (1..=lines.len())
+        .filter(|line| excluded.iter().any(|r| r.contains(line)))
+        .count();
+    let line_count = lines.len() - excluded_lines;
 
     // Check file line count first (cheap check)
     let exceeds_file_lines = line_count > config.max_file_lines;
@@ -102,7 +126,7 @@ fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<
                 "file has {} lines, exceeds maximum of {}",
                 line_count, config.max_file_lines
             ),
-            file: file_str.clone(),
+            file: Some(file_str.clone()),
             line: 1,
             severity: Severity::Warning,
         });
@@ -121,14 +145,11 @@ fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<
     }
 
     // Try to get parser for this file
-    let extension = file_path
-        .extension()
-        .and_then(|e| e.to_str())
-        .map(|e| format!(".{}", e));
-
-    let Some(ext) = extension else {
+    let ext = analysis_extension(file_path);
+    if ext.is_empty() {
         return Ok(violations);
-    };
+    }
+    let ext = format!(".{}", ext);
 
     let Some(file_parser) = parser::for_extension(&ext) else {
         return Ok(violations);
@@ -149,7 +170,7 @@ fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<
                 "file has {} functions, exceeds maximum of {}",
                 function_count, config.max_functions_per_file
             ),
-            file: file_str.clone(),
+            file: Some(file_str.clone()),
             line: 1,
             severity: Severity::Warning,
         });
@@ -187,7 +208,7 @@ fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<
                     methods.len(),
                     config.max_class_methods
                 ),
-                file: file_str.clone(),
+                file: Some(file_str.clone()),
                 line: first_method_line,
                 severity: Severity::Warning,
             });
@@ -213,7 +234,7 @@ fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<
                         "function '{}' has ~{} lines, exceeds maximum of {}",
                         swc.symbol.name, func_lines, config.max_function_lines
                     ),
-                    file: file_str.clone(),
+                    file: Some(file_str.clone()),
                     line: swc.symbol.line,
                     severity: Severity::Warning,
                 });
@@ -231,7 +252,7 @@ fn check_file(file_path: &Path, config: &GodObjectConfig) -> anyhow::Result<Vec<
                                 "function '{}' has complexity {}, exceeds maximum of {}",
                                 swc.symbol.name, complexity, config.max_function_complexity
                             ),
-                            file: file_str.clone(),
+                            file: Some(file_str.clone()),
                             line: swc.symbol.line,
                             severity: Severity::Warning,
                         });
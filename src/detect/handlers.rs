@@ -0,0 +1,300 @@
+//! Detection of hollow HTTP handlers.
+//!
+//! A handler that answers `200 OK` with an empty or placeholder body looks
+//! like a working endpoint to a route listing, a smoke test, and a reviewer
+//! skimming the diff, but it does nothing. Each framework contributes a regex
+//! for the hollow success response and, optionally, one for writing a real
+//! body; matches are scoped to the enclosing function through the parse tree.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use globset::GlobSet;
+use rayon::prelude::*;
+use regex::Regex;
+use tree_sitter::Node;
+
+use crate::analysis::{analysis_extension, get_analyzer, ParsedFile};
+use crate::contract::EmptyHandlersConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Node kinds that delimit a handler body.
+const FUNCTION_KINDS: &[&str] = &[
+    "function_declaration",           // Go, JS/TS
+    "method_declaration",             // Go
+    "func_literal",                   // Go
+    "function_definition",            // Python
+    "function_expression",            // JS/TS
+    "function",                       // JS (older grammars)
+    "arrow_function",                 // JS/TS
+    "method_definition",              // JS/TS
+    "generator_function_declaration", // JS/TS
+];
+
+/// A framework's patterns with regexes compiled.
+struct CompiledFramework {
+    name: String,
+    extensions: Vec<String>,
+    success: Regex,
+    body: Option<Regex>,
+}
+
+fn compile_frameworks(config: &EmptyHandlersConfig) -> anyhow::Result<Vec<CompiledFramework>> {
+    config
+        .frameworks()
+        .into_iter()
+        .map(|f| {
+            let compile = |pattern: &str| {
+                Regex::new(pattern).map_err(|e| {
+                    anyhow::anyhow!("invalid empty_handlers pattern for {:?}: {}", f.name, e)
+                })
+            };
+            Ok(CompiledFramework {
+                success: compile(&f.success)?,
+                body: f.body.as_deref().map(compile).transpose()?,
+                name: f.name,
+                extensions: f.extensions,
+            })
+        })
+        .collect()
+}
+
+/// Find the innermost function containing a byte range.
+fn enclosing_function(root: Node, start: usize, end: usize) -> Option<Node> {
+    let mut node = root.descendant_for_byte_range(start, end)?;
+    loop {
+        if FUNCTION_KINDS.contains(&node.kind()) {
+            return Some(node);
+        }
+        node = node.parent()?;
+    }
+}
+
+/// Name of a handler function, including arrow functions bound to a variable.
+fn function_name<'a>(parsed: &'a ParsedFile, func: Node) -> Option<&'a str> {
+    let name = func.child_by_field_name("name").or_else(|| {
+        let parent = func.parent()?;
+        if parent.kind() == "variable_declarator" {
+            parent.child_by_field_name("name")
+        } else {
+            None
+        }
+    })?;
+    Some(parsed.node_text(name))
+}
+
+/// Find hollow success responses in a single parsed file.
+fn check_file(
+    parsed: &ParsedFile,
+    path: &Path,
+    frameworks: &[&CompiledFramework],
+    allowlist: &GlobSet,
+) -> Vec<Violation> {
+    let source = parsed.source_str();
+    let root = parsed.tree.root_node();
+    // One violation per handler, even if it matches several frameworks
+    let mut reported: HashSet<usize> = HashSet::new();
+    let mut violations = Vec::new();
+
+    for framework in frameworks {
+        for m in framework.success.find_iter(source) {
+            // Top-level matches are not inside a handler
+            let Some(func) = enclosing_function(root, m.start(), m.end()) else {
+                continue;
+            };
+            if reported.contains(&func.start_byte()) {
+                continue;
+            }
+
+            let name = function_name(parsed, func);
+            if name.is_some_and(|n| allowlist.is_match(n.to_lowercase())) {
+                continue;
+            }
+            if let Some(body) = &framework.body {
+                // The success response itself may look like a body write (`c.JSON(200, gin.H{})`)
+                let rest = framework.success.replace_all(parsed.node_text(func), "");
+                if body.is_match(&rest) {
+                    continue;
+                }
+            }
+
+            reported.insert(func.start_byte());
+            let handler = match name {
+                Some(name) => format!("handler '{}'", name),
+                None => "anonymous handler".to_string(),
+            };
+            violations.push(Violation {
+                rule: ViolationRule::EmptyHandler,
+                message: format!(
+                    "{} responds 200 with an empty body ({}: {:?}); the endpoint does nothing",
+                    handler,
+                    framework.name,
+                    m.as_str()
+                ),
+                file: Some(path.to_string_lossy().to_string()),
+                line: source[..m.start()].matches('\n').count() + 1,
+                severity: Severity::Warning,
+            });
+        }
+    }
+
+    violations.sort_by_key(|v| v.line);
+    violations
+}
+
+/// Detect HTTP handlers that respond 200 with an empty or placeholder body.
+///
+/// Files are processed in parallel; only extensions named by a framework
+/// are read. Handlers whose name matches the allowlist are exempt.
+pub fn detect_empty_handlers<P: AsRef<Path> + Sync>(
+    files: &[P],
+    config: &EmptyHandlersConfig,
+) -> anyhow::Result<DetectionResult> {
+    let frameworks = compile_frameworks(config)?;
+    // Handler names are matched case-insensitively
+    let patterns: Vec<String> = config.allowlist().iter().map(|p| p.to_lowercase()).collect();
+    let allowlist = build_globs("empty_handlers allowlist", &patterns)?;
+    let scanned = AtomicUsize::new(0);
+
+    let file_results: Vec<Vec<Violation>> = files
+        .par_iter()
+        .filter_map(|file| {
+            let path = file.as_ref();
+            let ext = analysis_extension(path);
+            let applicable: Vec<&CompiledFramework> = frameworks
+                .iter()
+                .filter(|f| f.extensions.iter().any(|e| e == ext))
+                .collect();
+            if applicable.is_empty() {
+                return None;
+            }
+
+            let analyzer = get_analyzer(ext)?;
+            let source = std::fs::read(path).ok()?;
+            let parsed = analyzer.parse(path, &source).ok()?;
+
+            scanned.fetch_add(1, Ordering::Relaxed);
+            Some(check_file(&parsed, path, &applicable, &allowlist))
+        })
+        .collect();
+
+    let mut result = DetectionResult::new();
+    result.scanned = scanned.load(Ordering::Relaxed);
+    for violations in file_results {
+        for v in violations {
+            result.add_violation(v);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::HandlerFramework;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str, config: &EmptyHandlersConfig) -> Vec<Violation> {
+        test_support::violations(name, source, ViolationRule::EmptyHandler, |_, files| {
+            detect_empty_handlers(files, config)
+        })
+    }
+
+    #[test]
+    fn test_go_net_http_and_gin() {
+        let violations = detect(
+            "api.go",
+            r#"package api
+
+func CreateOrder(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusOK)
+}
+
+func ListOrders(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusOK)
+	json.NewEncoder(w).Encode(orders)
+}
+
+func Healthz(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(200)
+}
+
+func GetUser(c *gin.Context) {
+	c.JSON(http.StatusOK, gin.H{})
+}
+"#,
+            &EmptyHandlersConfig::default(),
+        );
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.rule == ViolationRule::EmptyHandler));
+        assert_eq!(violations[0].line, 4);
+        assert!(violations[0].message.contains("'CreateOrder'"));
+        assert!(violations[0].message.contains("net/http"));
+        assert_eq!(violations[1].line, 17);
+        assert!(violations[1].message.contains("gin"));
+    }
+
+    #[test]
+    fn test_express_handlers() {
+        let violations = detect(
+            "routes.ts",
+            r#"
+app.post('/orders', (req, res) => {
+    res.json({});
+});
+
+app.get('/orders', async (req, res) => {
+    const orders = await db.orders.findAll();
+    res.status(200).json(orders);
+});
+
+const deleteOrder = (req, res) => res.sendStatus(200);
+
+res.send();
+"#,
+            &EmptyHandlersConfig::default(),
+        );
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("anonymous handler"));
+        assert_eq!(violations[1].line, 11);
+        assert!(violations[1].message.contains("'deleteOrder'"));
+    }
+
+    #[test]
+    fn test_flask_and_custom_framework() {
+        let source = r#"
+@app.route("/orders", methods=["POST"])
+def create_order():
+    return "", 200
+
+@app.route("/users")
+def list_users():
+    return Response(status=200)
+"#;
+        let violations = detect("app.py", source, &EmptyHandlersConfig::default());
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 4);
+
+        // A contract entry named like a built-in replaces it
+        let config = EmptyHandlersConfig {
+            frameworks: vec![HandlerFramework {
+                name: "flask".to_string(),
+                extensions: vec!["py".to_string()],
+                success: r"\bResponse\(\s*status\s*=\s*200\s*\)".to_string(),
+                body: None,
+            }],
+            ..EmptyHandlersConfig::default()
+        };
+        let violations = detect("app.py", source, &config);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 8);
+        assert!(violations[0].message.contains("'list_users'"));
+    }
+}
@@ -0,0 +1,232 @@
+//! Detection of missing, mismatched, and placeholder license headers.
+//!
+//! Generated files often carry a copyright line from some other project, or a
+//! template header that was never filled in (`Copyright <YEAR> <COMPANY>`).
+//! When the contract opts in with `detect_header`, each file's first comment
+//! block is compared against the expected header and scanned for unfilled
+//! placeholders.
+
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext};
+use crate::contract::HeaderConfig;
+
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+lazy_static! {
+    /// Template placeholders left in copyright notices: <YEAR>, [COMPANY], {owner}, YYYY, "Your Name".
+    static ref PLACEHOLDER: Regex = Regex::new(
+        r"(?i)[<\[{]\s*(?:year|yyyy|company|owner|author|name|fullname|organization|organisation|holder|name of copyright owner|copyright holder)\s*[>\]}]|\byyyy\b|\byour (?:name|company|organization|organisation)\b"
+    )
+    .unwrap();
+}
+
+/// Remove comment markers so patterns can be written against the header text.
+fn strip_comment_markers(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let line = line.trim();
+            let line = line.strip_suffix("*/").unwrap_or(line).trim_end();
+            let line = ["/**", "/*", "///", "//!", "//", "*", "#", "--"]
+                .iter()
+                .find_map(|marker| line.strip_prefix(marker))
+                .unwrap_or(line);
+            line.trim()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Collapse runs of whitespace so line wrapping does not affect text matching.
+fn normalize_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Find the first copyright line that still contains a template placeholder.
+fn find_placeholder(header: &str) -> Option<&str> {
+    header
+        .lines()
+        .find(|line| line.to_lowercase().contains("copyright") && PLACEHOLDER.is_match(line))
+}
+
+/// Check each file's leading comment block against the contract's header rules.
+///
+/// Files without an analyzer are skipped. A file with no leading comment is
+/// flagged only when an expected `pattern` or `text` is configured.
+pub fn detect_bad_headers<P: AsRef<Path>>(
+    analysis_ctx: &AnalysisContext,
+    files: &[P],
+    config: Option<&HeaderConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    let config = match config {
+        Some(c) if c.is_enabled() => c,
+        _ => return Ok(result),
+    };
+
+    let pattern = config
+        .pattern
+        .as_ref()
+        .map(|p| Regex::new(p))
+        .transpose()
+        .map_err(|e| anyhow::anyhow!("invalid detect_header pattern: {}", e))?;
+    let expected_text = config.text.as_deref().map(normalize_whitespace);
+    let expects_header = pattern.is_some() || expected_text.is_some();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let ext = analysis_extension(path);
+        if get_analyzer(ext).is_none() {
+            continue;
+        }
+
+        let facts = match analysis_ctx.analyze_file(path) {
+            Ok(facts) => facts,
+            Err(_) => continue,
+        };
+        result.scanned += 1;
+
+        let file_str = path.to_string_lossy().to_string();
+        let header = match &facts.header_comment {
+            Some(header) => header,
+            None => {
+                if expects_header {
+                    result.add_violation(Violation {
+                        rule: ViolationRule::BadHeader,
+                        message: "file has no header comment; expected the project license header"
+                            .to_string(),
+                        file: Some(file_str),
+                        line: 1,
+                        severity: Severity::Error,
+                    });
+                }
+                continue;
+            }
+        };
+
+        let stripped = strip_comment_markers(&header.text);
+
+        if let Some(line) = find_placeholder(&stripped) {
+            result.add_violation(Violation {
+                rule: ViolationRule::BadHeader,
+                message: format!("header contains an unfilled placeholder: {:?}", line),
+                file: Some(file_str),
+                line: header.span.start_line,
+                severity: Severity::Error,
+            });
+            continue;
+        }
+
+        let pattern_ok = pattern.as_ref().is_none_or(|re| re.is_match(&stripped));
+        let text_ok = expected_text
+            .as_ref()
+            .is_none_or(|t| normalize_whitespace(&stripped).contains(t.as_str()));
+        if !pattern_ok || !text_ok {
+            let first_line = stripped.lines().find(|l| !l.is_empty()).unwrap_or("");
+            result.add_violation(Violation {
+                rule: ViolationRule::BadHeader,
+                message: format!(
+                    "header does not match the expected license header (starts with {:?})",
+                    first_line
+                ),
+                file: Some(file_str),
+                line: header.span.start_line,
+                severity: Severity::Error,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn config(pattern: Option<&str>, text: Option<&str>) -> HeaderConfig {
+        HeaderConfig {
+            enabled: true,
+            pattern: pattern.map(String::from),
+            text: text.map(String::from),
+        }
+    }
+
+    #[test]
+    fn test_detect_mismatched_and_missing_headers() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let good = temp.path().join("good.go");
+        let wrong = temp.path().join("wrong.go");
+        let missing = temp.path().join("missing.go");
+        std::fs::write(&good, "// Copyright 2024 Acme Corp\n// Licensed under MIT\n\npackage main\n").unwrap();
+        std::fs::write(&wrong, "// Copyright 2019 Globex Inc\n\npackage main\n").unwrap();
+        std::fs::write(&missing, "package main\n").unwrap();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let cfg = config(Some(r"^Copyright \d{4} Acme Corp"), None);
+        let result = detect_bad_headers(&ctx, &[&good, &wrong, &missing], Some(&cfg)).unwrap();
+
+        assert_eq!(result.violations.len(), 2);
+        assert!(result.violations.iter().all(|v| v.rule == ViolationRule::BadHeader));
+        assert!(result.violations[0].location().ends_with("missing.go"));
+        assert!(result.violations[1].location().ends_with("wrong.go"));
+        assert!(result.violations[1].message.contains("Globex"));
+    }
+
+    #[test]
+    fn test_detect_placeholder_header() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("lib.py");
+        std::fs::write(
+            &file,
+            "# Copyright (c) <YEAR> <COMPANY>\n# All rights reserved.\n\nimport os\n",
+        )
+        .unwrap();
+
+        let ctx = AnalysisContext::new(temp.path());
+        // Placeholders are flagged even without an expected header
+        let result = detect_bad_headers(&ctx, &[&file], Some(&config(None, None))).unwrap();
+
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].line, 1);
+        assert!(result.violations[0].message.contains("<YEAR>"));
+    }
+
+    #[test]
+    fn test_expected_text_ignores_wrapping() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("main.ts");
+        std::fs::write(
+            &file,
+            "/*\n * Licensed under the Apache License,\n * Version 2.0\n */\nexport const x = 1;\n",
+        )
+        .unwrap();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let cfg = config(None, Some("Licensed under the Apache License, Version 2.0"));
+        let result = detect_bad_headers(&ctx, &[&file], Some(&cfg)).unwrap();
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_without_config() {
+        let temp = TempDir::new().unwrap();
+        let ctx = AnalysisContext::new(temp.path());
+        let files: Vec<std::path::PathBuf> = vec![];
+        assert!(detect_bad_headers(&ctx, &files, None).unwrap().violations.is_empty());
+    }
+}
@@ -0,0 +1,238 @@
+//! Detection of modules with too few implemented functions.
+//!
+//! Rather than enumerating every required symbol, a contract can require that
+//! a minimum share of the callables under a glob are real implementations.
+//! Each callable with a body is classified using the `FunctionBody` stub flags
+//! extracted by the AST analyzers.
+
+use std::path::Path;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext, FunctionBody};
+use crate::contract::ImplementationCoverage;
+
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Implemented vs. total callable counts for a single requirement.
+#[derive(Debug, Clone, Copy, Default)]
+struct CallableTally {
+    implemented: usize,
+    total: usize,
+}
+
+impl CallableTally {
+    fn ratio(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.implemented as f64 / self.total as f64
+        }
+    }
+}
+
+/// Returns true if the body looks like a placeholder rather than an implementation.
+fn is_stub_body(body: &FunctionBody) -> bool {
+    body.is_empty || body.is_panic_only || body.has_only_todo_comment
+}
+
+/// Check that each configured glob meets its minimum implementation ratio.
+///
+/// Callables without a body (interface methods, abstract declarations) are not
+/// counted. Globs that match no callables are skipped rather than flagged.
+pub fn detect_insufficient_implementation<P: AsRef<Path>>(
+    analysis_ctx: &AnalysisContext,
+    files: &[P],
+    requirements: &[ImplementationCoverage],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    if requirements.is_empty() {
+        return Ok(result);
+    }
+
+    let base = analysis_ctx.base_dir();
+
+    let matchers = requirements
+        .iter()
+        .map(|req| {
+            globset::Glob::new(&req.path)
+                .map(|g| g.compile_matcher())
+                .map_err(|e| {
+                    anyhow::anyhow!("invalid implementation_coverage path {:?}: {}", req.path, e)
+                })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut tallies = vec![CallableTally::default(); requirements.len()];
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let rel_path = path
+            .strip_prefix(base)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .to_string();
+
+        let matching: Vec<usize> = matchers
+            .iter()
+            .enumerate()
+            .filter(|(_, m)| m.is_match(&rel_path))
+            .map(|(i, _)| i)
+            .collect();
+        if matching.is_empty() {
+            continue;
+        }
+
+        let ext = analysis_extension(path);
+        if get_analyzer(ext).is_none() {
+            continue;
+        }
+
+        let facts = match analysis_ctx.analyze_file(path) {
+            Ok(facts) => facts,
+            Err(_) => continue,
+        };
+        result.scanned += 1;
+
+        let mut file_tally = CallableTally::default();
+        for decl in &facts.declarations {
+            if !decl.kind.is_callable() {
+                continue;
+            }
+            if let Some(ref body) = decl.body {
+                file_tally.total += 1;
+                if !is_stub_body(body) {
+                    file_tally.implemented += 1;
+                }
+            }
+        }
+
+        for i in matching {
+            tallies[i].total += file_tally.total;
+            tallies[i].implemented += file_tally.implemented;
+        }
+    }
+
+    for (req, tally) in requirements.iter().zip(&tallies) {
+        if tally.total == 0 {
+            continue;
+        }
+
+        let ratio = tally.ratio();
+        if ratio < req.min_ratio {
+            // The glob spans many files, so the violation is project-level
+            result.add_violation(Violation {
+                rule: ViolationRule::InsufficientImplementation,
+                message: format!(
+                    "{}: only {}/{} callables implemented ({:.1}%), below required {:.1}%",
+                    req.path,
+                    tally.implemented,
+                    tally.total,
+                    ratio * 100.0,
+                    req.min_ratio * 100.0
+                ),
+                file: None,
+                line: 0,
+                severity: Severity::Error,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_module(dir: &Path) -> std::path::PathBuf {
+        let file_path = dir.join("api.go");
+        std::fs::write(
+            &file_path,
+            r#"
+package api
+
+func List() []string {
+    return []string{"a", "b"}
+}
+
+func Create(name string) error {
+    panic("not implemented")
+}
+
+func Delete(name string) error {
+}
+
+func Update(name string) error {
+}
+"#,
+        )
+        .unwrap();
+        file_path
+    }
+
+    #[test]
+    fn test_ratio_below_minimum() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file_path = write_module(temp.path());
+
+        let analysis_ctx = AnalysisContext::new(temp.path());
+        let reqs = vec![ImplementationCoverage {
+            path: "*.go".to_string(),
+            min_ratio: 0.8,
+        }];
+
+        let result =
+            detect_insufficient_implementation(&analysis_ctx, &[&file_path], &reqs).unwrap();
+        assert_eq!(result.violations.len(), 1);
+        let v = &result.violations[0];
+        assert_eq!(v.rule, ViolationRule::InsufficientImplementation);
+        assert_eq!(v.file, None);
+        assert!(v.is_project_level());
+        assert!(v.message.starts_with("*.go: only 1/4"), "message: {}", v.message);
+        assert!(v.message.contains("25.0%"), "message: {}", v.message);
+    }
+
+    #[test]
+    fn test_ratio_met() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file_path = write_module(temp.path());
+
+        let analysis_ctx = AnalysisContext::new(temp.path());
+        let reqs = vec![ImplementationCoverage {
+            path: "**/*.go".to_string(),
+            min_ratio: 0.25,
+        }];
+
+        let result =
+            detect_insufficient_implementation(&analysis_ctx, &[&file_path], &reqs).unwrap();
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_unmatched_glob_is_skipped() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file_path = write_module(temp.path());
+
+        let analysis_ctx = AnalysisContext::new(temp.path());
+        let reqs = vec![ImplementationCoverage {
+            path: "internal/**".to_string(),
+            min_ratio: 1.0,
+        }];
+
+        let result =
+            detect_insufficient_implementation(&analysis_ctx, &[&file_path], &reqs).unwrap();
+        assert!(result.violations.is_empty());
+        assert_eq!(result.scanned, 0);
+    }
+}
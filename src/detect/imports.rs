@@ -4,6 +4,7 @@
 //! Supports Python, JavaScript/TypeScript, Go, and Rust.
 
 use super::stdlib::{is_stdlib, StdlibLanguage};
+use crate::analysis::analysis_extension;
 use crate::registry::RegistryType;
 use phf;
 use regex::Regex;
@@ -43,7 +44,7 @@ pub struct ImportedDependency {
 
 /// Extract all imports from a source file.
 pub fn extract_imports(file_path: &Path) -> anyhow::Result<Vec<ImportedDependency>> {
-    let ext = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    let ext = analysis_extension(file_path);
 
     let content = fs::read_to_string(file_path)?;
     let file_str = file_path.to_string_lossy().to_string();
@@ -453,7 +454,7 @@ fn is_likely_internal_module(name: &str) -> bool {
     }
 
     // O(1) lookup in perfect hash set
-    INTERNAL_MODULE_NAMES.contains(&name.to_lowercase().as_str())
+    INTERNAL_MODULE_NAMES.contains(name.to_lowercase().as_str())
 }
 
 #[cfg(test)]
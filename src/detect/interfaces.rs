@@ -0,0 +1,409 @@
+//! Detection of interfaces and traits that no type implements.
+//!
+//! A delivery can declare the `PaymentProcessor` interface the spec asked
+//! for, along with its DTOs, and never write a type that implements it:
+//! every required symbol exists, but there is no behavior behind the
+//! abstraction. Implementations are found through implements/extends
+//! clauses, Python base classes, and Rust `impl Trait for Type` blocks; Go
+//! interfaces are satisfied by any type whose methods cover them.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::Path;
+
+use globset::GlobSet;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext, Declaration, DeclarationKind, FileFacts};
+use crate::contract::UnimplementedInterfacesConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Concrete types implementing each supertype, directly or through
+/// intermediate interfaces and base classes.
+fn nominal_implementers<'a>(
+    files: &'a [(String, FileFacts)],
+    interfaces: &HashSet<&str>,
+) -> HashMap<&'a str, BTreeSet<&'a str>> {
+    let mut supertypes: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (_, facts) in files {
+        for imp in &facts.implementations {
+            supertypes
+                .entry(imp.type_name.as_str())
+                .or_default()
+                .push(imp.interface.as_str());
+        }
+    }
+
+    let mut implementers: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for &concrete in supertypes.keys().filter(|name| !interfaces.contains(*name)) {
+        let mut visited = HashSet::new();
+        let mut pending = vec![concrete];
+        while let Some(name) = pending.pop() {
+            for &parent in supertypes.get(name).into_iter().flatten() {
+                if visited.insert(parent) {
+                    implementers.entry(parent).or_default().insert(concrete);
+                    pending.push(parent);
+                }
+            }
+        }
+    }
+    implementers
+}
+
+/// Methods a Go interface requires, including those of embedded interfaces
+/// declared in the project.
+fn go_method_set<'a>(
+    decl: &'a Declaration,
+    by_name: &HashMap<&str, &'a Declaration>,
+    embedded: &HashMap<&str, Vec<&'a str>>,
+) -> Vec<&'a str> {
+    let mut methods: Vec<&str> = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pending = vec![decl];
+    while let Some(iface) = pending.pop() {
+        if !visited.insert(iface.name.as_str()) {
+            continue;
+        }
+        for member in &iface.members {
+            if !methods.contains(&member.as_str()) {
+                methods.push(member);
+            }
+        }
+        for name in embedded.get(iface.name.as_str()).into_iter().flatten() {
+            pending.extend(by_name.get(name));
+        }
+    }
+    methods
+}
+
+/// An interface, trait, or abstract base class and the types implementing it.
+pub(crate) struct IndexedInterface<'a> {
+    /// File declaring the interface.
+    pub path: &'a str,
+    pub decl: &'a Declaration,
+    /// Methods the interface requires; for Go, including embedded interfaces.
+    pub methods: Vec<&'a str>,
+    /// Concrete types implementing the interface, by simple name.
+    pub implementers: BTreeSet<&'a str>,
+}
+
+/// The interfaces of one language and their implementing types.
+///
+/// Shared by the rules that ask how many types stand behind an abstraction:
+/// none (`unimplemented_interface`) or exactly one (`premature_abstraction`).
+pub(crate) struct InterfaceIndex<'a> {
+    /// Interfaces in file order.
+    pub interfaces: Vec<IndexedInterface<'a>>,
+}
+
+impl<'a> InterfaceIndex<'a> {
+    /// Index the interfaces declared in `files`, all of one language.
+    pub fn build(files: &'a [(String, FileFacts)]) -> Self {
+        let declared: Vec<(&str, &Declaration)> = files
+            .iter()
+            .flat_map(|(path, facts)| {
+                facts
+                    .declarations
+                    .iter()
+                    .filter(|d| matches!(d.kind, DeclarationKind::Interface | DeclarationKind::Trait))
+                    .map(move |d| (path.as_str(), d))
+            })
+            .collect();
+        if declared.is_empty() {
+            return Self { interfaces: Vec::new() };
+        }
+
+        let names: HashSet<&str> = declared.iter().map(|(_, d)| d.name.as_str()).collect();
+        let nominal = nominal_implementers(files, &names);
+
+        // Go: interfaces embedded in other interfaces, and each type's method set
+        let structural = files.iter().any(|(_, facts)| facts.language == "go");
+        let by_name: HashMap<&str, &Declaration> =
+            declared.iter().map(|(_, d)| (d.name.as_str(), *d)).collect();
+        let mut embedded: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut method_sets: HashMap<&str, HashSet<&str>> = HashMap::new();
+        if structural {
+            for (_, facts) in files {
+                for imp in &facts.implementations {
+                    embedded
+                        .entry(imp.type_name.as_str())
+                        .or_default()
+                        .push(imp.interface.as_str());
+                }
+                for decl in facts.callables() {
+                    if let Some(receiver) = &decl.receiver {
+                        method_sets
+                            .entry(receiver.as_str())
+                            .or_default()
+                            .insert(decl.name.as_str());
+                    }
+                }
+            }
+        }
+
+        let interfaces = declared
+            .into_iter()
+            .map(|(path, decl)| {
+                let (methods, implementers) = if structural {
+                    let methods = go_method_set(decl, &by_name, &embedded);
+                    let implementers = method_sets
+                        .iter()
+                        .filter(|(_, set)| methods.iter().all(|m| set.contains(m)))
+                        .map(|(&receiver, _)| receiver)
+                        .collect();
+                    (methods, implementers)
+                } else {
+                    (
+                        decl.members.iter().map(String::as_str).collect(),
+                        nominal.get(decl.name.as_str()).cloned().unwrap_or_default(),
+                    )
+                };
+                IndexedInterface {
+                    path,
+                    decl,
+                    methods,
+                    implementers,
+                }
+            })
+            .collect();
+        Self { interfaces }
+    }
+}
+
+/// Check the interfaces of one language against that language's types.
+fn check_language(
+    files: &[(String, FileFacts)],
+    min_methods: usize,
+    allowlist: &GlobSet,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    for iface in InterfaceIndex::build(files).interfaces {
+        let decl = iface.decl;
+        let methods = &iface.methods;
+        if methods.is_empty() || methods.len() < min_methods || allowlist.is_match(&decl.name) {
+            continue;
+        }
+        if !iface.implementers.is_empty() {
+            continue;
+        }
+
+        violations.push(Violation {
+            rule: ViolationRule::UnimplementedInterface,
+            message: format!(
+                "{} '{}' has no implementing type in the project ({} methods: {})",
+                decl.kind,
+                decl.name,
+                methods.len(),
+                methods.join(", ")
+            ),
+            file: Some(iface.path.to_string()),
+            line: decl.span.start_line,
+            severity: Severity::Warning,
+        });
+    }
+    violations
+}
+
+/// Analyze `files` with an analyzer and group their facts by language,
+/// counting each analyzed file in `result`.
+pub(crate) fn facts_by_language<P: AsRef<Path>>(
+    analysis_ctx: &AnalysisContext,
+    files: &[P],
+    result: &mut DetectionResult,
+) -> BTreeMap<String, Vec<(String, FileFacts)>> {
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    let mut by_language: BTreeMap<String, Vec<(String, FileFacts)>> = BTreeMap::new();
+    for file in sorted_files {
+        let path = file.as_ref();
+        let ext = analysis_extension(path);
+        if get_analyzer(ext).is_none() {
+            continue;
+        }
+
+        let facts = match analysis_ctx.analyze_file(path) {
+            Ok(facts) => facts,
+            Err(_) => continue,
+        };
+        result.scanned += 1;
+
+        by_language
+            .entry(facts.language.clone())
+            .or_default()
+            .push((path.to_string_lossy().to_string(), facts));
+    }
+    by_language
+}
+
+/// Find interfaces, traits, and abstract base classes that no type implements.
+///
+/// Implementations are only matched within the same language, by simple
+/// name. Interfaces with fewer than `min_methods` methods (callbacks,
+/// markers) and names matching the allowlist are not checked.
+pub fn detect_unimplemented_interfaces<P: AsRef<Path>>(
+    analysis_ctx: &AnalysisContext,
+    files: &[P],
+    config: Option<&UnimplementedInterfacesConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    let config = match config {
+        Some(c) if c.is_enabled() => c,
+        _ => return Ok(result),
+    };
+    let min_methods = config.min_methods();
+    let allowlist = build_globs("unimplemented_interfaces allowlist", &config.allowlist)?;
+
+    let by_language = facts_by_language(analysis_ctx, files, &mut result);
+
+    for files in by_language.values() {
+        for v in check_language(files, min_methods, &allowlist) {
+            result.add_violation(v);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn detect(sources: &[(&str, &str)], config: &UnimplementedInterfacesConfig) -> Vec<Violation> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let mut files = Vec::new();
+        for (name, source) in sources {
+            let file = temp.path().join(name);
+            std::fs::write(&file, source).unwrap();
+            files.push(file);
+        }
+
+        let ctx = AnalysisContext::new(temp.path());
+        detect_unimplemented_interfaces(&ctx, &files, Some(config))
+            .unwrap()
+            .violations
+    }
+
+    fn enabled() -> UnimplementedInterfacesConfig {
+        UnimplementedInterfacesConfig {
+            enabled: true,
+            ..UnimplementedInterfacesConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_go_structural_implementations() {
+        let violations = detect(
+            &[
+                (
+                    "payments.go",
+                    r#"package payments
+
+type PaymentProcessor interface {
+	Charge(amount int) error
+	Refund(id string) error
+	Void(id string) error
+}
+
+type Reader interface {
+	Read(p []byte) (int, error)
+	Close() error
+}
+
+type ReadSeeker interface {
+	Reader
+	Seek(offset int64) error
+}
+
+type Callback interface {
+	Done()
+}
+"#,
+                ),
+                (
+                    "file.go",
+                    r#"package payments
+
+type File struct{}
+
+func (f *File) Read(p []byte) (int, error) { return 0, nil }
+func (f *File) Close() error { return nil }
+"#,
+                ),
+            ],
+            &enabled(),
+        );
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.rule == ViolationRule::UnimplementedInterface));
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0]
+            .message
+            .contains("interface 'PaymentProcessor' has no implementing type in the project (3 methods: Charge, Refund, Void)"));
+        // Embedded methods count towards the method set
+        assert!(violations[1].message.contains("'ReadSeeker'"));
+        assert!(violations[1].message.contains("Seek, Read, Close"));
+    }
+
+    #[test]
+    fn test_nominal_implementations_and_allowlist() {
+        let sources = [
+            (
+                "store.rs",
+                r#"
+pub trait Store {
+    fn get(&self, key: &str) -> Option<String>;
+    fn put(&mut self, key: &str, value: String);
+}
+
+pub trait Cache {
+    fn get(&self, key: &str) -> Option<String>;
+    fn evict(&mut self, key: &str);
+}
+
+pub struct MemoryStore;
+
+impl Store for MemoryStore {
+    fn get(&self, key: &str) -> Option<String> { None }
+    fn put(&mut self, key: &str, value: String) {}
+}
+"#,
+            ),
+            (
+                "repo.ts",
+                r#"
+interface Repository { find(id: string): Item; save(item: Item): void; }
+interface AuditedRepository extends Repository { history(id: string): Event[]; log(e: Event): void; }
+class SqlRepository implements AuditedRepository {
+    find(id: string) { return db.get(id); }
+    save(item: Item) { db.put(item); }
+    history(id: string) { return []; }
+    log(e: Event) {}
+}
+interface Notifier { send(msg: string): void; close(): void; }
+"#,
+            ),
+        ];
+
+        let violations = detect(&sources, &enabled());
+        assert_eq!(violations.len(), 2);
+        // Grouped by language: Rust first, then TypeScript
+        assert!(violations[0].message.contains("trait 'Cache'"));
+        assert!(violations[0].message.contains("(2 methods: get, evict)"));
+        assert!(violations[1].message.contains("interface 'Notifier'"));
+
+        let config = UnimplementedInterfacesConfig {
+            enabled: true,
+            min_methods: None,
+            allowlist: vec!["*Notifier".to_string()],
+        };
+        let violations = detect(&sources, &config);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'Cache'"));
+    }
+}
@@ -0,0 +1,109 @@
+//! Per-file isolation of analysis crashes.
+//!
+//! Pathological input can make tree-sitter or a fact extractor panic, and a
+//! panic on a rayon worker takes the whole run down with it. Every file with
+//! an analyzer is analyzed once, in parallel, before any rule runs, with each
+//! file behind its own `catch_unwind`. Facts of files that succeed are cached
+//! for the rules; files that panic are reported as `analysis_error` and
+//! skipped by every rule, so the rest of the scan still produces a report.
+
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext};
+
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// The message a panic was raised with.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
+/// Run `analyze` on each file, turning panics into `analysis_error` violations.
+fn isolate_with<F>(files: &[PathBuf], analyze: F) -> (Vec<PathBuf>, DetectionResult)
+where
+    F: Fn(&Path) + Sync,
+{
+    let panics: Vec<Option<String>> = files
+        .par_iter()
+        .map(|file| {
+            let ext = analysis_extension(file);
+            get_analyzer(ext)?;
+            panic::catch_unwind(AssertUnwindSafe(|| analyze(file)))
+                .err()
+                .map(|payload| panic_message(payload.as_ref()).to_string())
+        })
+        .collect();
+
+    let mut result = DetectionResult::new();
+    let mut kept = Vec::with_capacity(files.len());
+    for (file, panic) in files.iter().zip(panics) {
+        match panic {
+            Some(message) => {
+                result.errored += 1;
+                result.add_violation(Violation {
+                    rule: ViolationRule::AnalysisError,
+                    message: format!("analysis crashed ({}); skipped by all rules", message),
+                    file: Some(file.to_string_lossy().to_string()),
+                    line: 1,
+                    severity: Severity::Warning,
+                });
+            }
+            None => kept.push(file.clone()),
+        }
+    }
+
+    (kept, result)
+}
+
+/// Analyze every file once and split off the ones whose analysis panics.
+///
+/// Returns the files every rule should still check, and a result with an
+/// `analysis_error` violation and an `errored` count for each file that was
+/// dropped. Files without an analyzer are kept: the text-based rules scan
+/// them linearly. Ordinary analysis errors are left to the rules, as before.
+pub fn isolate_analysis_failures(
+    analysis_ctx: &AnalysisContext,
+    files: &[PathBuf],
+) -> (Vec<PathBuf>, DetectionResult) {
+    isolate_with(files, |file| {
+        let _ = analysis_ctx.analyze_file(file);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panicking_file_is_reported_and_skipped() {
+        crate::analysis::register_analyzers();
+
+        let files: Vec<PathBuf> = ["src/a.go", "src/bad.go", "README.md", "src/c.go"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        let (kept, result) = isolate_with(&files, |file| {
+            if file.ends_with("bad.go") {
+                panic!("byte index 12 is out of range");
+            }
+        });
+
+        assert_eq!(kept, vec![files[0].clone(), files[2].clone(), files[3].clone()]);
+        assert_eq!(result.errored, 1);
+        assert_eq!(result.violations.len(), 1);
+        let v = &result.violations[0];
+        assert_eq!(v.rule, ViolationRule::AnalysisError);
+        assert_eq!(v.file.as_deref(), Some("src/bad.go"));
+        assert_eq!(
+            v.message,
+            "analysis crashed (byte index 12 is out of range); skipped by all rules"
+        );
+    }
+}
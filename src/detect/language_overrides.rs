@@ -0,0 +1,70 @@
+//! Routing of files to analyzers by language override.
+//!
+//! Before any rule runs, each file's override is resolved and recorded with
+//! `analysis::set_language_override`, so every later analyzer lookup sees
+//! it. An inline `hollowcheck:language` comment wins over the contract's
+//! `language_overrides` globs. Overridden files are often not quite the
+//! language they are routed to (template syntax in a `.go.tmpl`), so reports
+//! mark violations found in them.
+
+use std::path::{Path, PathBuf};
+
+use crate::analysis::{read_inline_language, set_language_override};
+use crate::contract::Contract;
+
+/// The language a file should be analyzed as, if an override names one.
+pub fn resolve_language_override(base_dir: &Path, path: &Path, contract: &Contract) -> Option<&'static str> {
+    read_inline_language(path).or_else(|| contract.language_override(path.strip_prefix(base_dir).unwrap_or(path)))
+}
+
+/// Resolve and record the language override of each file.
+///
+/// Returns the number of files routed by an override.
+pub fn apply_language_overrides(base_dir: &Path, files: &[PathBuf], contract: &Contract) -> usize {
+    let mut routed = 0;
+    for file in files {
+        if let Some(language) = resolve_language_override(base_dir, file, contract) {
+            set_language_override(file, language);
+            routed += 1;
+        }
+    }
+    routed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::{analysis_extension, language_override};
+
+    #[test]
+    fn test_contract_globs_and_inline_precedence() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir(root.join("tools")).unwrap();
+        let star = root.join("tools/rules.star");
+        let template = root.join("main.go.tmpl");
+        let inline = root.join("tools/gen.star");
+        let script = root.join("deploy");
+        let other = root.join("notes.star");
+        std::fs::write(&star, "def rule(ctx):\n    return ctx\n").unwrap();
+        std::fs::write(&template, "package main\n\nfunc {{.Name}}() {}\n").unwrap();
+        std::fs::write(&inline, "// hollowcheck:language javascript\nfunction gen() {}\n").unwrap();
+        std::fs::write(&script, "#!/usr/bin/env python3\n# hollowcheck:language python\nprint('hi')\n").unwrap();
+        std::fs::write(&other, "plain text\n").unwrap();
+
+        let contract: Contract = serde_yaml::from_str(
+            "language_overrides:\n  \"tools/*.star\": python\n  \"*.go.tmpl\": golang\n",
+        )
+        .unwrap();
+        let files = vec![star.clone(), template.clone(), inline.clone(), script.clone(), other.clone()];
+        assert_eq!(apply_language_overrides(root, &files, &contract), 4);
+
+        assert_eq!(language_override(&star), Some("python"));
+        assert_eq!(analysis_extension(&template), "go");
+        // The inline comment wins over the matching glob
+        assert_eq!(language_override(&inline), Some("javascript"));
+        assert_eq!(language_override(&script), Some("python"));
+        // `tools/*.star` is relative to the root, so a top-level .star file is left alone
+        assert_eq!(language_override(&other), None);
+    }
+}
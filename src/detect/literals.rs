@@ -0,0 +1,374 @@
+//! Detection of string literals duplicated across the project.
+//!
+//! The same magic string ("us-east-1", an API base path, a queue name) pasted
+//! at many call sites is a drift risk: changing it means finding every copy.
+//! This rule collects string literals from the AST of every analyzed file and
+//! reports values that repeat often enough to deserve a named constant.
+//! Literals and test classification come from the `FactsBundle`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use crate::analysis::StringLiteral;
+use crate::contract::RepeatedLiteralsConfig;
+
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
+
+/// Maximum number of extra locations listed in a single violation message.
+const MAX_LISTED_LOCATIONS: usize = 10;
+
+/// Strings every codebase repeats: protocol names, encodings, and the
+/// results of JavaScript `typeof`. A constant for them adds nothing.
+const COMMON_LITERALS: &[&str] = &[
+    "application/json",
+    "application/x-www-form-urlencoded",
+    "multipart/form-data",
+    "text/plain",
+    "text/html",
+    "Content-Type",
+    "content-type",
+    "Authorization",
+    "User-Agent",
+    "Accept-Encoding",
+    "DELETE",
+    "OPTIONS",
+    "utf-8",
+    "UTF-8",
+    "string",
+    "number",
+    "boolean",
+    "object",
+    "function",
+    "undefined",
+    "bigint",
+    "symbol",
+];
+
+/// A single occurrence of a literal.
+#[derive(Debug, Clone)]
+struct Occurrence {
+    file: String,
+    line: usize,
+}
+
+/// Check if a callee looks like a logging or printing call.
+fn is_log_call(callee: &str) -> bool {
+    let lower = callee.to_lowercase();
+    let last = lower
+        .rsplit(['.', ':'])
+        .next()
+        .unwrap_or(&lower)
+        .trim_end_matches('!');
+
+    lower.contains("log")
+        || last.starts_with("print")
+        || last.starts_with("eprint")
+        || matches!(
+            last,
+            "debug" | "info" | "warn" | "warning" | "error" | "trace" | "fatal" | "critical"
+        )
+}
+
+/// Check if a callee loads a module (`require("fs")`, `import("./x")`).
+fn is_module_load(callee: &str) -> bool {
+    matches!(callee, "require" | "import")
+}
+
+/// Returns true if the literal should be counted.
+fn is_candidate(lit: &StringLiteral, config: &RepeatedLiteralsConfig) -> bool {
+    if lit.in_constant
+        || COMMON_LITERALS.contains(&lit.value.as_str())
+        || config.ignore.contains(&lit.value)
+    {
+        return false;
+    }
+    let min_length = config.min_length();
+    if lit.value.chars().count() < min_length || lit.value.trim().is_empty() {
+        return false;
+    }
+    match lit.call_name.as_deref() {
+        Some(callee) => !is_log_call(callee) && !is_module_load(callee),
+        None => true,
+    }
+}
+
+/// Find string literals repeated across the project.
+///
+/// Literals shorter than the configured minimum, common protocol strings,
+/// literals in test files, arguments to logging calls, and literals that
+/// initialize a constant are ignored. A value is flagged when it occurs
+/// `min_occurrences` times across at least `min_files` files; each produces
+/// one violation at its first occurrence listing the remaining locations.
+pub fn detect_repeated_literals<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: Option<&RepeatedLiteralsConfig>,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    let config = match config {
+        Some(c) if c.is_enabled() => c,
+        _ => return Ok(result),
+    };
+    let min_occurrences = config.min_occurrences();
+    let min_files = config.min_files();
+
+    let base = facts.base_dir();
+
+    // BTreeMap keeps the output order stable across runs
+    let mut occurrences: BTreeMap<String, Vec<Occurrence>> = BTreeMap::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        if facts.is_test_file(path) {
+            continue;
+        }
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        for lit in file_facts
+            .string_literals
+            .iter()
+            .filter(|lit| is_candidate(lit, config))
+        {
+            occurrences
+                .entry(lit.value.clone())
+                .or_default()
+                .push(Occurrence {
+                    file: path.to_string_lossy().to_string(),
+                    line: lit.span.start_line,
+                });
+        }
+    }
+
+    for (value, locations) in occurrences {
+        if locations.len() < min_occurrences {
+            continue;
+        }
+        let files: HashSet<&str> = locations.iter().map(|o| o.file.as_str()).collect();
+        if files.len() < min_files {
+            continue;
+        }
+
+        let first = &locations[0];
+        let others: Vec<String> = locations[1..]
+            .iter()
+            .take(MAX_LISTED_LOCATIONS)
+            .map(|o| {
+                let rel = Path::new(&o.file)
+                    .strip_prefix(base)
+                    .unwrap_or(Path::new(&o.file))
+                    .to_string_lossy()
+                    .to_string();
+                format!("{}:{}", rel, o.line)
+            })
+            .collect();
+        let remaining = locations.len() - 1 - others.len();
+        let more = if remaining > 0 {
+            format!(", and {} more", remaining)
+        } else {
+            String::new()
+        };
+
+        result.add_violation(Violation {
+            rule: ViolationRule::RepeatedLiteral,
+            message: format!(
+                "string literal {:?} repeated {} times; extract it to a named constant (also at {}{})",
+                value,
+                locations.len(),
+                others.join(", "),
+                more
+            ),
+            file: Some(first.file.clone()),
+            line: first.line,
+            severity: Severity::Warning,
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(
+        dir: &Path,
+        files: &[&std::path::PathBuf],
+        config: Option<&RepeatedLiteralsConfig>,
+    ) -> DetectionResult {
+        let ctx = AnalysisContext::new(dir);
+        let facts = FactsBundle::for_rules(&ctx, files, &[ViolationRule::RepeatedLiteral]);
+        detect_repeated_literals(&facts, files, config).unwrap()
+    }
+
+    fn enabled(min_occurrences: usize) -> RepeatedLiteralsConfig {
+        RepeatedLiteralsConfig {
+            enabled: true,
+            min_length: None,
+            min_occurrences: Some(min_occurrences),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_repeated_literal_across_files() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let a = temp.path().join("a.go");
+        let b = temp.path().join("b.go");
+        std::fs::write(
+            &a,
+            r#"
+package main
+
+func a() {
+    connect("us-east-1")
+    connect("us-east-1")
+}
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            &b,
+            r#"
+package main
+
+func b() {
+    connect("us-east-1")
+}
+"#,
+        )
+        .unwrap();
+
+        let result = detect(temp.path(), &[&a, &b], Some(&enabled(3)));
+
+        assert_eq!(result.violations.len(), 1);
+        let v = &result.violations[0];
+        assert_eq!(v.rule, ViolationRule::RepeatedLiteral);
+        assert_eq!(v.line, 5);
+        assert!(v.message.contains("us-east-1"));
+        assert!(v.message.contains("repeated 3 times"));
+        assert!(v.message.contains("b.go:5"), "message: {}", v.message);
+    }
+
+    #[test]
+    fn test_min_files_and_common_strings() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("client.ts");
+        std::fs::write(
+            &file,
+            r#"
+function send(body: unknown) {
+    if (typeof body === "object") {
+        post("/v1/orders", body, { "Content-Type": "application/json" });
+        post("/v1/orders", body, { "Content-Type": "application/json" });
+    } else if (typeof body === "object") {
+        post("/v1/orders", body, { "Content-Type": "application/json" });
+    }
+}
+"#,
+        )
+        .unwrap();
+
+        let result = detect(temp.path(), &[&file], Some(&enabled(3)));
+        let values: Vec<_> = result.violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(values.len(), 1, "{:?}", values);
+        assert!(values[0].contains("/v1/orders"));
+
+        // Repeats within one file are left alone when two files are required
+        let config = RepeatedLiteralsConfig {
+            min_files: Some(2),
+            ..enabled(3)
+        };
+        let result = detect(temp.path(), &[&file], Some(&config));
+        assert!(result.violations.is_empty());
+
+        let config = RepeatedLiteralsConfig {
+            ignore: vec!["/v1/orders".to_string()],
+            ..enabled(3)
+        };
+        let result = detect(temp.path(), &[&file], Some(&config));
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_ignores_constants_and_logs() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("main.py");
+        std::fs::write(
+            &file,
+            r#"
+QUEUE_NAME = "orders-queue"
+
+def handler():
+    logger.info("processing order")
+    logger.info("processing order")
+    logger.info("processing order")
+    publish(QUEUE_NAME)
+"#,
+        )
+        .unwrap();
+
+        let result = detect(temp.path(), &[&file], Some(&enabled(2)));
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_skips_test_files() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("main_test.go");
+        std::fs::write(
+            &file,
+            r#"
+package main
+
+func TestA() {
+    check("fixture-value")
+    check("fixture-value")
+}
+"#,
+        )
+        .unwrap();
+
+        // Files under a test directory are test code too
+        let fixture = temp.path().join("tests").join("fixtures.py");
+        std::fs::create_dir_all(fixture.parent().unwrap()).unwrap();
+        std::fs::write(&fixture, "def a():\n    check(\"fixture-value\")\n    check(\"fixture-value\")\n").unwrap();
+
+        let result = detect(temp.path(), &[&file, &fixture], Some(&enabled(2)));
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_disabled_without_config() {
+        let temp = TempDir::new().unwrap();
+        let result = detect(temp.path(), &[], None);
+        assert!(result.violations.is_empty());
+    }
+
+    #[test]
+    fn test_is_log_call() {
+        assert!(is_log_call("log.Printf"));
+        assert!(is_log_call("println!"));
+        assert!(is_log_call("tracing::warn!"));
+        assert!(is_log_call("console.log"));
+        assert!(!is_log_call("http.Get"));
+        assert!(!is_log_call("errors.New"));
+    }
+}
@@ -0,0 +1,593 @@
+//! Detection of imports of project modules that do not exist.
+//!
+//! Registry checks only cover third-party packages, but generated code just
+//! as often imports a local module that was never written:
+//! `from .utils.validator import validate` with no `utils/validator.py`, or
+//! `import { parseConfig } from './config/parser'` with nothing at that
+//! path. Imports come from `FileFacts::imports`; each ecosystem's resolver
+//! decides whether an import is project-local and, if so, which paths it
+//! may resolve to:
+//!
+//! - **Python**: relative imports against the importing package, and
+//!   absolute imports whose top-level package is in the project (at the
+//!   scan root or under `src/`). Modules, packages with `__init__.py`, and
+//!   namespace packages all resolve.
+//! - **JavaScript/TypeScript**: `./` and `../` specifiers, and specifiers
+//!   matched by the nearest `tsconfig.json` or `jsconfig.json` `paths` or
+//!   found under its `baseUrl`, with extension and `index` file resolution.
+//! - **Go**: import paths under the module path of the nearest `go.mod`,
+//!   which must name a directory with at least one `.go` file.
+//!
+//! Everything else is left to dependency verification. Python imports that
+//! are allowed to fail (inside `try`/`except ImportError`) are reported as
+//! Info.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+use std::rc::Rc;
+
+use crate::analysis::Import;
+use crate::detect::manifest::GoManifest;
+
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
+
+/// Extensions a JavaScript or TypeScript specifier without one may resolve to, in order.
+const JS_EXTENSIONS: &[&str] = &["ts", "tsx", "d.ts", "mts", "cts", "js", "jsx", "mjs", "cjs", "json"];
+
+/// Compiled JavaScript extensions a TypeScript source may be imported under.
+const JS_OUTPUT_EXTENSIONS: &[(&str, &[&str])] = &[
+    ("js", &["ts", "tsx"]),
+    ("jsx", &["tsx"]),
+    ("mjs", &["mts"]),
+    ("cjs", &["cts"]),
+];
+
+/// Config files that carry `paths` and `baseUrl` for module resolution.
+const TS_CONFIG_FILES: &[&str] = &["tsconfig.json", "jsconfig.json"];
+
+/// Where an import points.
+enum Resolution {
+    /// The import is not project-local; dependency verification owns it
+    External,
+    /// A project path the import resolves to exists
+    Found,
+    /// None of these candidate paths exist
+    Missing(Vec<PathBuf>),
+}
+
+/// `paths` and `baseUrl` from a tsconfig or jsconfig.
+#[derive(Debug, Default)]
+struct TsPaths {
+    base_url: Option<PathBuf>,
+    /// Patterns with at most one `*`, and their target patterns, relative to `base_url`
+    paths: Vec<(String, Vec<String>)>,
+    /// Directory the config file is in, for `paths` without a `baseUrl`
+    config_dir: PathBuf,
+}
+
+/// Remove `//` and `/* */` comments and trailing commas, which tsconfig allows.
+fn strip_jsonc(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    let mut in_string = false;
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    // A comma followed only by whitespace before a closing bracket
+    let mut cleaned = String::with_capacity(out.len());
+    let bytes: Vec<char> = out.chars().collect();
+    let mut in_string = false;
+    for (i, &c) in bytes.iter().enumerate() {
+        if c == '"' && (i == 0 || bytes[i - 1] != '\\') {
+            in_string = !in_string;
+        }
+        if c == ',' && !in_string {
+            let next = bytes[i + 1..].iter().find(|c| !c.is_whitespace());
+            if matches!(next, Some('}') | Some(']')) {
+                continue;
+            }
+        }
+        cleaned.push(c);
+    }
+    cleaned
+}
+
+/// Read `compilerOptions.baseUrl` and `compilerOptions.paths` from a config file.
+fn read_ts_paths(config: &Path) -> Option<TsPaths> {
+    let source = std::fs::read_to_string(config).ok()?;
+    let value: serde_json::Value = serde_json::from_str(&strip_jsonc(&source)).ok()?;
+    let options = value.get("compilerOptions")?;
+    let config_dir = config.parent()?.to_path_buf();
+    let base_url = options
+        .get("baseUrl")
+        .and_then(|b| b.as_str())
+        .map(|b| config_dir.join(b));
+    let paths = options
+        .get("paths")
+        .and_then(|p| p.as_object())
+        .map(|paths| {
+            paths
+                .iter()
+                .map(|(pattern, targets)| {
+                    let targets = targets
+                        .as_array()
+                        .map(|t| t.iter().filter_map(|t| t.as_str().map(String::from)).collect())
+                        .unwrap_or_default();
+                    (pattern.clone(), targets)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    Some(TsPaths {
+        base_url,
+        paths,
+        config_dir,
+    })
+}
+
+/// Match a `paths` pattern, returning the text `*` stands for.
+fn match_ts_pattern<'a>(pattern: &str, specifier: &'a str) -> Option<&'a str> {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => specifier.strip_prefix(prefix)?.strip_suffix(suffix),
+        None => (pattern == specifier).then_some(""),
+    }
+}
+
+/// Lexically normalize `.` and `..` components.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Per-scan resolver, caching the config files found for each directory.
+struct Resolver<'a> {
+    base_dir: &'a Path,
+    go_modules: RefCell<HashMap<PathBuf, Option<(PathBuf, String)>>>,
+    ts_configs: RefCell<HashMap<PathBuf, Option<Rc<TsPaths>>>>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(base_dir: &'a Path) -> Self {
+        Self {
+            base_dir,
+            go_modules: RefCell::new(HashMap::new()),
+            ts_configs: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn resolve(&self, language: &str, file: &Path, import: &str) -> Resolution {
+        match language {
+            "python" => self.resolve_python(file, import),
+            "javascript" | "typescript" => self.resolve_js(file, import),
+            "go" => self.resolve_go(file, import),
+            _ => Resolution::External,
+        }
+    }
+
+    // ---- Python ---------------------------------------------------------
+
+    /// Paths a dotted module name may be, under a directory.
+    fn python_candidates(dir: &Path, parts: &[&str]) -> Vec<PathBuf> {
+        let target = parts.iter().fold(dir.to_path_buf(), |path, part| path.join(part));
+        vec![
+            target.with_extension("py"),
+            target.with_extension("pyi"),
+            target.join("__init__.py"),
+            // Namespace packages are plain directories
+            target,
+        ]
+    }
+
+    /// Whether a directory holds Python code: a package or namespace package.
+    fn is_python_package(dir: &Path) -> bool {
+        std::fs::read_dir(dir).is_ok_and(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|e| e.path().extension().is_some_and(|ext| ext == "py" || ext == "pyi"))
+        })
+    }
+
+    fn resolve_python(&self, file: &Path, import: &str) -> Resolution {
+        let Some(dir) = file.parent() else {
+            return Resolution::External;
+        };
+
+        let module = import.trim_start_matches('.');
+        let dots = import.len() - module.len();
+        let parts: Vec<&str> = module.split('.').filter(|p| !p.is_empty()).collect();
+
+        if dots > 0 {
+            // One dot is the importing file's package; each further dot goes up
+            let package = (1..dots).try_fold(dir.to_path_buf(), |d, _| d.parent().map(Path::to_path_buf));
+            let Some(package) = package else {
+                return Resolution::External;
+            };
+            if parts.is_empty() {
+                return Resolution::Found;
+            }
+            return Self::first_existing(Self::python_candidates(&package, &parts));
+        }
+
+        // Absolute imports are local when their top-level package is in the project
+        let Some(top) = parts.first() else {
+            return Resolution::External;
+        };
+        let roots = [self.base_dir.to_path_buf(), self.base_dir.join("src"), dir.to_path_buf()];
+        let root = roots.iter().find(|root| {
+            root.join(top).with_extension("py").is_file() || Self::is_python_package(&root.join(top))
+        });
+        match root {
+            Some(root) if parts.len() > 1 => Self::first_existing(Self::python_candidates(root, &parts)),
+            Some(_) => Resolution::Found,
+            None => Resolution::External,
+        }
+    }
+
+    // ---- JavaScript / TypeScript -----------------------------------------
+
+    /// Paths a specifier may be, relative to where it points.
+    fn js_candidates(target: &Path) -> Vec<PathBuf> {
+        let name = target.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        let extension = name.rsplit_once('.').map(|(_, ext)| ext);
+        if let Some((_, sources)) = JS_OUTPUT_EXTENSIONS.iter().find(|(out, _)| Some(*out) == extension) {
+            // `./util.js` in TypeScript names `./util.ts`
+            let mut candidates = vec![target.to_path_buf()];
+            candidates.extend(sources.iter().map(|src| target.with_extension(src)));
+            return candidates;
+        }
+        if extension.is_some_and(|ext| JS_EXTENSIONS.contains(&ext)) {
+            return vec![target.to_path_buf()];
+        }
+
+        let with_ext = |base: &Path| {
+            JS_EXTENSIONS
+                .iter()
+                .map(|e| PathBuf::from(format!("{}.{}", base.display(), e)))
+                .collect::<Vec<_>>()
+        };
+        // Any other extension is an asset (`./styles.css`) or part of the name (`./user.service`)
+        let mut candidates = if extension.is_some() {
+            vec![target.to_path_buf()]
+        } else {
+            Vec::new()
+        };
+        candidates.extend(with_ext(target));
+        candidates.extend(with_ext(&target.join("index")));
+        candidates.push(target.join("package.json"));
+        candidates
+    }
+
+    /// The nearest tsconfig or jsconfig at or above a directory.
+    fn ts_paths(&self, dir: &Path) -> Option<Rc<TsPaths>> {
+        if let Some(cached) = self.ts_configs.borrow().get(dir) {
+            return cached.clone();
+        }
+        let found = TS_CONFIG_FILES
+            .iter()
+            .map(|name| dir.join(name))
+            .find(|config| config.is_file())
+            .and_then(|config| read_ts_paths(&config))
+            .map(Rc::new)
+            .or_else(|| dir.parent().and_then(|parent| self.ts_paths(parent)));
+        self.ts_configs.borrow_mut().insert(dir.to_path_buf(), found.clone());
+        found
+    }
+
+    fn resolve_js(&self, file: &Path, import: &str) -> Resolution {
+        let Some(dir) = file.parent() else {
+            return Resolution::External;
+        };
+        // Bundler query strings and fragments (`./worker?worker`) are not part of the path
+        let specifier = import.split(['?', '#']).next().unwrap_or(import);
+
+        if specifier.starts_with("./") || specifier.starts_with("../") || specifier == "." || specifier == ".." {
+            return Self::first_existing(Self::js_candidates(&normalize(&dir.join(specifier))));
+        }
+        if specifier.starts_with('/') || specifier.contains(':') {
+            return Resolution::External;
+        }
+
+        let Some(config) = self.ts_paths(dir) else {
+            return Resolution::External;
+        };
+
+        // `paths` entries, most specific (longest prefix) first
+        let mut patterns: Vec<&(String, Vec<String>)> = config.paths.iter().collect();
+        patterns.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.split('*').next().unwrap_or("").len()));
+        for (pattern, targets) in patterns {
+            let Some(star) = match_ts_pattern(pattern, specifier) else {
+                continue;
+            };
+            let root = config.base_url.as_deref().unwrap_or(&config.config_dir);
+            let candidates: Vec<PathBuf> = targets
+                .iter()
+                .flat_map(|target| Self::js_candidates(&normalize(&root.join(target.replacen('*', star, 1)))))
+                .collect();
+            return Self::first_existing(candidates);
+        }
+
+        // Under `baseUrl`, a specifier is local when its first segment is in the project
+        if let Some(base_url) = &config.base_url {
+            let first = specifier.split('/').next().unwrap_or(specifier);
+            let local = base_url.join(first).is_dir()
+                || JS_EXTENSIONS
+                    .iter()
+                    .any(|e| PathBuf::from(format!("{}.{}", base_url.join(first).display(), e)).is_file());
+            if local {
+                return Self::first_existing(Self::js_candidates(&normalize(&base_url.join(specifier))));
+            }
+        }
+        Resolution::External
+    }
+
+    // ---- Go ---------------------------------------------------------------
+
+    /// Root directory and module path of the nearest `go.mod` at or above a directory.
+    fn go_module(&self, dir: &Path) -> Option<(PathBuf, String)> {
+        if let Some(cached) = self.go_modules.borrow().get(dir) {
+            return cached.clone();
+        }
+        let go_mod = dir.join("go.mod");
+        let found = if go_mod.is_file() {
+            GoManifest::from_go_mod(&go_mod)
+                .ok()
+                .map(|manifest| (dir.to_path_buf(), manifest.root_module().to_string()))
+        } else {
+            dir.parent().and_then(|parent| self.go_module(parent))
+        };
+        self.go_modules.borrow_mut().insert(dir.to_path_buf(), found.clone());
+        found
+    }
+
+    fn resolve_go(&self, file: &Path, import: &str) -> Resolution {
+        let Some((root, module)) = file.parent().and_then(|dir| self.go_module(dir)) else {
+            return Resolution::External;
+        };
+        let rest = match import.strip_prefix(module.as_str()) {
+            Some("") => "",
+            Some(rest) if rest.starts_with('/') => &rest[1..],
+            _ => return Resolution::External,
+        };
+
+        let package = root.join(rest);
+        let has_go_files = std::fs::read_dir(&package).is_ok_and(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .any(|e| e.path().extension().is_some_and(|ext| ext == "go"))
+        });
+        if has_go_files {
+            Resolution::Found
+        } else {
+            Resolution::Missing(vec![package])
+        }
+    }
+
+    fn first_existing(candidates: Vec<PathBuf>) -> Resolution {
+        if candidates.iter().any(|c| c.exists()) {
+            Resolution::Found
+        } else {
+            Resolution::Missing(candidates)
+        }
+    }
+
+    /// A candidate path for messages, relative to the scan root.
+    fn display(&self, path: &Path) -> String {
+        path.strip_prefix(self.base_dir).unwrap_or(path).display().to_string()
+    }
+}
+
+fn message(resolver: &Resolver, language: &str, import: &Import, tried: &[PathBuf]) -> String {
+    let tried = if language == "go" {
+        format!("{}/ has no .go files", resolver.display(&tried[0]))
+    } else {
+        let paths: Vec<String> = tried.iter().map(|p| resolver.display(p)).collect();
+        format!("tried {}", paths.join(", "))
+    };
+    let optional = if import.optional { " (optional import)" } else { "" };
+    format!("import {:?} points at a project module that does not exist{}; {}", import.path, optional, tried)
+}
+
+/// Flag project-local imports whose target module does not exist.
+pub fn detect_missing_local_modules<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let resolver = Resolver::new(facts.base_dir());
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        let language = file_facts.language.as_str();
+        if !matches!(language, "python" | "javascript" | "typescript" | "go") {
+            continue;
+        }
+        result.scanned += 1;
+
+        // Facts may list imports by path; report them in line order
+        let mut imports: Vec<&Import> = file_facts.imports.iter().collect();
+        imports.sort_by_key(|import| import.span.start_line);
+
+        for import in imports {
+            let Resolution::Missing(tried) = resolver.resolve(language, path, &import.path) else {
+                continue;
+            };
+            let violation = Violation::new(
+                ViolationRule::MissingLocalModule,
+                message(&resolver, language, import, &tried),
+            )
+            .at(path.to_string_lossy(), import.span.start_line);
+            result.add_violation(if import.optional {
+                violation.with_severity(Severity::Info)
+            } else {
+                violation
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)]) -> Vec<(String, usize, Severity, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::MissingLocalModule]);
+        detect_missing_local_modules(&facts, &paths)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                let file = Path::new(v.file.as_deref().unwrap()).strip_prefix(temp.path()).unwrap();
+                (file.display().to_string(), v.line, v.severity, v.message)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_python_relative_and_project_imports() {
+        let app = "from .utils.validator import validate\n\
+                   from .utils import helpers\n\
+                   from .models import User\n\
+                   from . import config\n\
+                   import requests\n\
+                   from shop.payments.gateway import charge\n\
+                   try:\n    from .speedups import fast_path\nexcept ImportError:\n    fast_path = None\n";
+        let found = detect(&[
+            ("shop/__init__.py", ""),
+            ("shop/app.py", app),
+            ("shop/models/__init__.py", "class User: pass\n"),
+            ("shop/utils/helpers.py", "def helper(): pass\n"),
+            ("shop/payments/__init__.py", ""),
+        ]);
+        let summary: Vec<_> = found.iter().map(|(f, l, s, _)| (f.as_str(), *l, *s)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("shop/app.py", 1, Severity::Error),
+                ("shop/app.py", 6, Severity::Error),
+                ("shop/app.py", 8, Severity::Info),
+            ],
+            "{:?}",
+            found
+        );
+        assert_eq!(
+            found[0].3,
+            "import \".utils.validator\" points at a project module that does not exist; tried \
+             shop/utils/validator.py, shop/utils/validator.pyi, shop/utils/validator/__init__.py, shop/utils/validator"
+        );
+        assert!(found[2].3.contains("(optional import)"));
+    }
+
+    #[test]
+    fn test_js_relative_and_tsconfig_paths() {
+        let tsconfig = r#"{
+  // Path aliases
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": { "@/*": ["src/*"], },
+  },
+}"#;
+        let main = "import { parseConfig } from './config/parser';\n\
+                    import { format } from './util.js';\n\
+                    import Button from '@/components/Button';\n\
+                    import Modal from '@/components/Modal';\n\
+                    import { helper } from 'lib/helper';\n\
+                    import React from 'react';\n\
+                    import './styles.css';\n\
+                    import routes from './routes';\n";
+        let found = detect(&[
+            ("tsconfig.json", tsconfig),
+            ("src/main.ts", main),
+            ("src/util.ts", "export const format = 1;\n"),
+            ("src/components/Button.tsx", "export default 1;\n"),
+            ("src/routes/index.ts", "export default [];\n"),
+            ("src/styles.css", "body {}\n"),
+            ("lib/other.ts", "export const x = 1;\n"),
+        ]);
+        let summary: Vec<_> = found.iter().map(|(f, l, _, _)| (f.as_str(), *l)).collect();
+        assert_eq!(summary, vec![("src/main.ts", 1), ("src/main.ts", 4), ("src/main.ts", 5)], "{:?}", found);
+        assert!(found[0].3.contains("tried src/config/parser.ts, src/config/parser.tsx"));
+        assert!(found[1].3.contains("src/components/Modal/index.ts"));
+    }
+
+    #[test]
+    fn test_go_packages_under_module_root() {
+        let main = "package main\n\nimport (\n\t\"fmt\"\n\t\"example.com/shop/internal/orders\"\n\t\"example.com/shop/internal/billing\"\n\t\"github.com/google/uuid\"\n)\n";
+        let found = detect(&[
+            ("go.mod", "module example.com/shop\n\ngo 1.22\n"),
+            ("cmd/main.go", main),
+            ("internal/orders/orders.go", "package orders\n"),
+            ("internal/billing/README.md", "todo\n"),
+        ]);
+        assert_eq!(found.len(), 1, "{:?}", found);
+        assert_eq!((found[0].0.as_str(), found[0].1), ("cmd/main.go", 6));
+        assert!(found[0].3.ends_with("; internal/billing/ has no .go files"));
+    }
+}
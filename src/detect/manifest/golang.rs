@@ -8,7 +8,7 @@ use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
-use super::{ManifestProvider, ManifestStats};
+use super::{DeclaredVersion, ImportExplanation, ManifestProvider, ManifestStats, MatchRule};
 
 /// Go module manifest provider.
 ///
@@ -23,6 +23,12 @@ pub struct GoManifest {
     external_modules: HashMap<String, String>,
     /// Replace directives: module path → local path or replacement module
     replace_directives: HashMap<String, String>,
+    /// 1-based go.mod line of each required module
+    require_lines: HashMap<String, usize>,
+    /// The `go` directive: the minimum Go version of the module
+    go_directive: Option<DeclaredVersion>,
+    /// The `toolchain` line, as written (`go1.22.3`)
+    toolchain: Option<DeclaredVersion>,
 }
 
 impl GoManifest {
@@ -46,11 +52,14 @@ impl GoManifest {
         let mut root_module = String::new();
         let mut external_modules = HashMap::new();
         let mut replace_directives = HashMap::new();
+        let mut require_lines = HashMap::new();
+        let mut go_directive = None;
+        let mut toolchain = None;
 
         let mut in_require_block = false;
         let mut in_replace_block = false;
 
-        for line in content.lines() {
+        for (i, line) in content.lines().enumerate() {
             let line = line.trim();
 
             // Skip empty lines and comments
@@ -68,6 +77,20 @@ impl GoManifest {
                 continue;
             }
 
+            // Parse go and toolchain directives
+            let directive = |name: &str| {
+                let value = line.strip_prefix(name)?.split("//").next()?.trim();
+                (!value.is_empty()).then(|| DeclaredVersion::new(value, "go.mod", i + 1))
+            };
+            if let Some(version) = directive("go ") {
+                go_directive = Some(version);
+                continue;
+            }
+            if let Some(version) = directive("toolchain ") {
+                toolchain = Some(version);
+                continue;
+            }
+
             // Track block starts/ends
             if line == "require (" {
                 in_require_block = true;
@@ -86,6 +109,7 @@ impl GoManifest {
             // Parse single-line require
             if line.starts_with("require ") && !line.contains('(') {
                 if let Some((module, version)) = parse_require_line(line.strip_prefix("require ").unwrap_or("")) {
+                    require_lines.insert(module.clone(), i + 1);
                     external_modules.insert(module, version);
                 }
                 continue;
@@ -102,6 +126,7 @@ impl GoManifest {
             // Parse require block entries
             if in_require_block {
                 if let Some((module, version)) = parse_require_line(line) {
+                    require_lines.insert(module.clone(), i + 1);
                     external_modules.insert(module, version);
                 }
                 continue;
@@ -124,6 +149,9 @@ impl GoManifest {
             root_module,
             external_modules,
             replace_directives,
+            require_lines,
+            go_directive,
+            toolchain,
         })
     }
 
@@ -150,6 +178,23 @@ impl GoManifest {
     pub fn replace_directives(&self) -> &HashMap<String, String> {
         &self.replace_directives
     }
+
+    /// Get the `go` directive.
+    pub fn go_directive(&self) -> Option<&DeclaredVersion> {
+        self.go_directive.as_ref()
+    }
+
+    /// Get the `toolchain` line.
+    pub fn toolchain(&self) -> Option<&DeclaredVersion> {
+        self.toolchain.as_ref()
+    }
+
+    /// The version required for a module, if it is required with one.
+    pub fn declared_version(&self, module: &str) -> Option<DeclaredVersion> {
+        let version = self.external_modules.get(module).filter(|v| !v.is_empty())?;
+        let line = self.require_lines.get(module).copied().unwrap_or(0);
+        Some(DeclaredVersion::new(version.clone(), "go.mod", line))
+    }
 }
 
 /// Parse a require line: "google.golang.org/grpc v1.78.0"
@@ -180,8 +225,8 @@ fn parse_replace_line(line: &str) -> Option<(String, String)> {
     // Split on "=>"
     let parts: Vec<&str> = line.split("=>").collect();
     if parts.len() == 2 {
-        let from = parts[0].trim().split_whitespace().next().unwrap_or("").to_string();
-        let to = parts[1].trim().split_whitespace().next().unwrap_or("").to_string();
+        let from = parts[0].split_whitespace().next().unwrap_or("").to_string();
+        let to = parts[1].split_whitespace().next().unwrap_or("").to_string();
         if !from.is_empty() && !to.is_empty() {
             return Some((from, to));
         }
@@ -190,6 +235,18 @@ fn parse_replace_line(line: &str) -> Option<(String, String)> {
     None
 }
 
+/// Returns true if `import_name` is `module` or a package inside it.
+fn in_module(import_name: &str, module: &str) -> bool {
+    import_name == module || import_name.starts_with(&format!("{}/", module))
+}
+
+/// Sorted keys of a module map, for a stable explanation order.
+fn sorted_modules(modules: &HashMap<String, String>) -> Vec<&str> {
+    let mut keys: Vec<&str> = modules.keys().map(String::as_str).collect();
+    keys.sort();
+    keys
+}
+
 impl ManifestProvider for GoManifest {
     fn is_valid_import(&self, import_name: &str, _file_path: &Path) -> bool {
         // Case 1: Go stdlib (no dots in first component)
@@ -199,31 +256,45 @@ impl ManifestProvider for GoManifest {
 
         // Case 2: Internal to root module
         // "k8s.io/kubernetes/pkg/..." is internal
-        if import_name == self.root_module
-            || import_name.starts_with(&format!("{}/", self.root_module))
-        {
+        if in_module(import_name, &self.root_module) {
             return true;
         }
 
         // Case 3: Replace directives (local modules)
         // "k8s.io/component-base/config" is valid if "k8s.io/component-base" is replaced
-        for replaced_module in self.replace_directives.keys() {
-            if import_name == replaced_module
-                || import_name.starts_with(&format!("{}/", replaced_module))
-            {
-                return true;
-            }
+        if self.replace_directives.keys().any(|m| in_module(import_name, m)) {
+            return true;
         }
 
         // Case 4: External module subpackages
         // "google.golang.org/grpc/credentials" is valid if "google.golang.org/grpc" is required
-        for module in self.external_modules.keys() {
-            if import_name == module || import_name.starts_with(&format!("{}/", module)) {
-                return true;
-            }
+        self.external_modules.keys().any(|m| in_module(import_name, m))
+    }
+
+    fn explain_import(&self, import_name: &str, file_path: &Path) -> ImportExplanation {
+        let mut explanation = ImportExplanation::new(self.get_scope(file_path));
+        let rule = |rule: MatchRule| move |m: &str| in_module(import_name, m).then_some(rule);
+
+        if Self::is_stdlib(import_name) {
+            explanation.accept("stdlib", import_name, MatchRule::Stdlib);
+            return explanation;
         }
 
-        false
+        let root = [self.root_module.as_str()];
+        if !explanation.compare("go.mod module", root, rule(MatchRule::RootModule))
+            && !explanation.compare(
+                "go.mod replace",
+                sorted_modules(&self.replace_directives),
+                rule(MatchRule::Replace),
+            )
+        {
+            explanation.compare(
+                "go.mod require",
+                sorted_modules(&self.external_modules),
+                rule(MatchRule::Require),
+            );
+        }
+        explanation
     }
 
     fn get_declared_imports(&self, _file_path: &Path) -> Vec<String> {
@@ -297,6 +368,23 @@ replace (
         assert!(manifest.replace_directives.contains_key("k8s.io/component-base"));
     }
 
+    #[test]
+    fn test_declared_versions() {
+        let manifest = GoManifest::parse_go_mod(sample_go_mod()).unwrap();
+
+        assert_eq!(manifest.go_directive(), Some(&DeclaredVersion::new("1.22.0", "go.mod", 4)));
+        assert_eq!(manifest.toolchain(), None);
+        assert_eq!(
+            manifest.declared_version("google.golang.org/grpc"),
+            Some(DeclaredVersion::new("v1.78.0", "go.mod", 9))
+        );
+        assert_eq!(manifest.declared_version("github.com/gin-gonic/gin"), None);
+
+        let manifest =
+            GoManifest::parse_go_mod("module example.com/app\n\ngo 1.21\ntoolchain go1.22.3 // local\n").unwrap();
+        assert_eq!(manifest.toolchain(), Some(&DeclaredVersion::new("go1.22.3", "go.mod", 4)));
+    }
+
     #[test]
     fn test_stdlib_imports() {
         let manifest = GoManifest::parse_go_mod(sample_go_mod()).unwrap();
@@ -367,6 +455,29 @@ replace (
         assert!(!manifest.is_valid_import("example.com/fake/module", &file));
     }
 
+    #[test]
+    fn test_explain_import() {
+        let manifest = GoManifest::parse_go_mod(sample_go_mod()).unwrap();
+        let file = PathBuf::from("/some/file.go");
+
+        let explanation = manifest.explain_import("net/http", &file);
+        assert_eq!(explanation.matched().unwrap().rule, Some(MatchRule::Stdlib));
+
+        let explanation = manifest.explain_import("k8s.io/api/core/v1", &file);
+        let matched = explanation.matched().unwrap();
+        assert_eq!(matched.source, "go.mod replace");
+        assert_eq!(matched.declared, "k8s.io/api");
+        assert_eq!(matched.rule, Some(MatchRule::Replace));
+
+        let explanation = manifest.explain_import("google.golang.org/grpc/metadata", &file);
+        assert_eq!(explanation.matched().unwrap().rule, Some(MatchRule::Require));
+
+        // Every declared module is compared before rejecting
+        let explanation = manifest.explain_import("example.com/fake/module", &file);
+        assert!(!explanation.valid);
+        assert_eq!(explanation.attempts.len(), 1 + 3 + 7);
+    }
+
     #[test]
     fn test_from_file() {
         let temp = TempDir::new().unwrap();
@@ -12,8 +12,8 @@ use phf::phf_map;
 use serde::Deserialize;
 use walkdir::WalkDir;
 
-use super::utils::{extract_package_name, import_matches_package};
-use super::{ManifestProvider, ManifestStats};
+use super::utils::{extract_package_name, package_match_rule};
+use super::{ImportExplanation, ManifestProvider, ManifestStats, MatchRule};
 
 /// Known submodules that don't match their parent package names.
 /// Maps import name → PyPI package name prefix.
@@ -24,14 +24,19 @@ static KNOWN_SUBMODULES: phf::Map<&'static str, &'static str> = phf_map! {
 
 /// Check if an import is a known submodule of a declared package.
 fn is_known_submodule(import_name: &str, requirements: &[String]) -> bool {
-    let import_lower = import_name.to_lowercase();
-    if let Some(parent_pkg) = KNOWN_SUBMODULES.get(&import_lower) {
-        return requirements.iter().any(|req| {
-            let pkg = extract_package_name(req).to_lowercase();
-            pkg.starts_with(parent_pkg)
-        });
-    }
-    false
+    KNOWN_SUBMODULES.contains_key(import_name.to_lowercase().as_str())
+        && requirements
+            .iter()
+            .any(|req| known_submodule_rule(import_name, &extract_package_name(req)).is_some())
+}
+
+/// Matches if the import is a known submodule of the package.
+fn known_submodule_rule(import_name: &str, pkg_name: &str) -> Option<MatchRule> {
+    let parent_pkg = KNOWN_SUBMODULES.get(import_name.to_lowercase().as_str())?;
+    pkg_name
+        .to_lowercase()
+        .starts_with(parent_pkg)
+        .then_some(MatchRule::KnownSubmodule)
 }
 
 /// Check if an import is a Home Assistant internal package (not on PyPI).
@@ -180,110 +185,123 @@ impl HomeAssistantManifest {
     /// - `py-synologydsm-api` → import `synology_dsm`
     /// - `paho-mqtt` → import `paho`
     fn fuzzy_match_requirements(&self, import_name: &str, requirements: &[String]) -> bool {
-        let import_lower = import_name.to_lowercase();
-        let import_normalized = import_lower.replace('-', "_");
-        let import_no_sep = import_normalized.replace('_', "");
-
-        for req in requirements {
-            let pkg_name = extract_package_name(req);
-            let pkg_lower = pkg_name.to_lowercase();
-            let pkg_normalized = pkg_lower.replace('-', "_");
-            let pkg_no_sep = pkg_normalized.replace('_', "");
-
-            // Use the common matching logic
-            if import_matches_package(import_name, &pkg_name) {
-                return true;
-            }
+        requirements
+            .iter()
+            .any(|req| fuzzy_match_rule(import_name, &extract_package_name(req)).is_some())
+    }
 
-            // Match without any separators (handles underscore placement differences)
-            // py-synologydsm-api → synology_dsm (synologydsm == synologydsm)
-            if pkg_no_sep.contains(&import_no_sep) || import_no_sep.contains(&pkg_no_sep) {
-                return true;
-            }
+    /// Check if import matches any loggers (authoritative check).
+    fn matches_loggers(&self, import_name: &str, loggers: &[String]) -> bool {
+        loggers
+            .iter()
+            .any(|logger| logger_match_rule(import_name, logger).is_some())
+    }
+}
 
-            // Additional HA-specific matching patterns
-            let pkg_parts: Vec<&str> = pkg_lower.split(|c| c == '-' || c == '_').collect();
-
-            // Try first word + any other word matching
-            // tuya-device-sharing-sdk → tuya_sharing
-            if pkg_parts.len() >= 2 {
-                // Try first + each subsequent word
-                for i in 1..pkg_parts.len() {
-                    let abbreviated = format!("{}_{}", pkg_parts[0], pkg_parts[i]);
-                    if abbreviated == import_normalized {
-                        return true;
-                    }
-                    // Without separator
-                    let abbreviated_no_sep = format!("{}{}", pkg_parts[0], pkg_parts[i]);
-                    if abbreviated_no_sep == import_no_sep {
-                        return true;
-                    }
-                }
-            }
+/// The rule under which an import fuzzily matches a requirement's package name.
+fn fuzzy_match_rule(import_name: &str, pkg_name: &str) -> Option<MatchRule> {
+    let import_lower = import_name.to_lowercase();
+    let import_normalized = import_lower.replace('-', "_");
+    let import_no_sep = import_normalized.replace('_', "");
 
-            // Handle api/sdk suffix removal
-            // py-synologydsm-api → synology_dsm
-            let pkg_no_suffix = pkg_normalized
-                .strip_suffix("_api")
-                .or_else(|| pkg_normalized.strip_suffix("_sdk"))
-                .or_else(|| pkg_normalized.strip_suffix("_client"))
-                .unwrap_or(&pkg_normalized);
+    let pkg_lower = pkg_name.to_lowercase();
+    let pkg_normalized = pkg_lower.replace('-', "_");
+    let pkg_no_sep = pkg_normalized.replace('_', "");
 
-            if pkg_no_suffix == import_normalized {
-                return true;
-            }
+    // Use the common matching logic
+    if let Some(rule) = package_match_rule(import_name, pkg_name) {
+        return Some(rule);
+    }
 
-            // Also strip py prefix after removing suffix
-            let pkg_stripped = pkg_no_suffix.strip_prefix("py_").unwrap_or(pkg_no_suffix);
-            if pkg_stripped == import_normalized {
-                return true;
-            }
+    // Match without any separators (handles underscore placement differences)
+    // py-synologydsm-api → synology_dsm (synologydsm == synologydsm)
+    if pkg_no_sep.contains(&import_no_sep) || import_no_sep.contains(&pkg_no_sep) {
+        return Some(MatchRule::Substring);
+    }
 
-            // Check if import without separators matches package without separators (after stripping)
-            let pkg_stripped_no_sep = pkg_stripped.replace('_', "");
-            if pkg_stripped_no_sep == import_no_sep {
-                return true;
+    // Additional HA-specific matching patterns
+    let pkg_parts: Vec<&str> = pkg_lower.split(['-', '_']).collect();
+
+    // Try first word + any other word matching
+    // tuya-device-sharing-sdk → tuya_sharing
+    if pkg_parts.len() >= 2 {
+        // Try first + each subsequent word
+        for part in &pkg_parts[1..] {
+            let abbreviated = format!("{}_{}", pkg_parts[0], part);
+            if abbreviated == import_normalized {
+                return Some(MatchRule::Abbreviation);
             }
-
-            // Handle imports that are just the first part
-            // paho-mqtt → paho
-            if let Some(first_part) = pkg_lower.split(|c| c == '-' || c == '_').next() {
-                if first_part == import_normalized || first_part == import_lower {
-                    return true;
-                }
+            // Without separator
+            let abbreviated_no_sep = format!("{}{}", pkg_parts[0], part);
+            if abbreviated_no_sep == import_no_sep {
+                return Some(MatchRule::Abbreviation);
             }
         }
-
-        false
     }
 
-    /// Check if import matches any loggers (authoritative check).
-    fn matches_loggers(&self, import_name: &str, loggers: &[String]) -> bool {
-        let import_lower = import_name.to_lowercase();
+    // Handle api/sdk suffix removal
+    // py-synologydsm-api → synology_dsm
+    let pkg_no_suffix = pkg_normalized
+        .strip_suffix("_api")
+        .or_else(|| pkg_normalized.strip_suffix("_sdk"))
+        .or_else(|| pkg_normalized.strip_suffix("_client"))
+        .unwrap_or(&pkg_normalized);
 
-        for logger in loggers {
-            let logger_lower = logger.to_lowercase();
+    if pkg_no_suffix == import_normalized {
+        return Some(MatchRule::SuffixStripped);
+    }
 
-            // Exact match
-            if logger_lower == import_lower {
-                return true;
-            }
+    // Also strip py prefix after removing suffix
+    let pkg_stripped = pkg_no_suffix.strip_prefix("py_").unwrap_or(pkg_no_suffix);
+    if pkg_stripped == import_normalized {
+        return Some(MatchRule::SuffixStripped);
+    }
 
-            // Logger is a sub-path of import (namespace package)
-            // e.g., logger "jaraco.abode" validates import "jaraco"
-            if logger_lower.starts_with(&format!("{}.", import_lower)) {
-                return true;
-            }
+    // Check if import without separators matches package without separators (after stripping)
+    let pkg_stripped_no_sep = pkg_stripped.replace('_', "");
+    if pkg_stripped_no_sep == import_no_sep {
+        return Some(MatchRule::SuffixStripped);
+    }
 
-            // Import is a sub-path of logger
-            // e.g., import "aioesphomeapi" with logger "aioesphomeapi"
-            if import_lower.starts_with(&format!("{}.", logger_lower)) {
-                return true;
-            }
+    // Handle imports that are just the first part
+    // paho-mqtt → paho
+    if let Some(first_part) = pkg_lower.split(['-', '_']).next() {
+        if first_part == import_normalized || first_part == import_lower {
+            return Some(MatchRule::FirstPart);
         }
+    }
+
+    None
+}
+
+/// The rule under which an import matches a declared logger name.
+fn logger_match_rule(import_name: &str, logger: &str) -> Option<MatchRule> {
+    let import_lower = import_name.to_lowercase();
+    let logger_lower = logger.to_lowercase();
+
+    // Exact match
+    if logger_lower == import_lower {
+        return Some(MatchRule::Logger);
+    }
+
+    // Logger is a sub-path of import (namespace package)
+    // e.g., logger "jaraco.abode" validates import "jaraco"
+    if logger_lower.starts_with(&format!("{}.", import_lower)) {
+        return Some(MatchRule::Logger);
+    }
 
-        false
+    // Import is a sub-path of logger
+    // e.g., import "aioesphomeapi" with logger "aioesphomeapi"
+    if import_lower.starts_with(&format!("{}.", logger_lower)) {
+        return Some(MatchRule::Logger);
     }
+
+    None
+}
+
+/// Package names of a list of requirements.
+fn requirement_names(requirements: &[String]) -> Vec<String> {
+    requirements.iter().map(|r| extract_package_name(r)).collect()
 }
 
 impl ManifestProvider for HomeAssistantManifest {
@@ -370,6 +388,49 @@ impl ManifestProvider for HomeAssistantManifest {
             })
     }
 
+    fn explain_import(&self, import_name: &str, file_path: &Path) -> ImportExplanation {
+        let mut explanation = ImportExplanation::new(self.get_scope(file_path));
+        let fuzzy = |pkg: &str| fuzzy_match_rule(import_name, pkg);
+        let submodule = |pkg: &str| known_submodule_rule(import_name, pkg);
+        let is_submodule = KNOWN_SUBMODULES.contains_key(import_name.to_lowercase().as_str());
+
+        if self.is_template_file(file_path) {
+            explanation.accept("template file", &file_path.to_string_lossy(), MatchRule::TemplateFile);
+            return explanation;
+        }
+        if is_homeassistant_internal(import_name) {
+            explanation.accept("internal", import_name, MatchRule::Internal);
+            return explanation;
+        }
+
+        let global = requirement_names(&self.global_requirements);
+        let global = global.iter().map(String::as_str);
+        if explanation.compare("global requirements", global.clone(), fuzzy)
+            || (is_submodule && explanation.compare("global requirements", global, submodule))
+        {
+            return explanation;
+        }
+
+        let Some(component) = self
+            .find_component(file_path)
+            .and_then(|dir| self.component_manifests.get(dir))
+        else {
+            return explanation;
+        };
+
+        let source = format!("{} manifest", component.domain);
+        let loggers = component.loggers.iter().map(String::as_str);
+        if explanation.compare(&source, loggers, |l| logger_match_rule(import_name, l)) {
+            return explanation;
+        }
+        let requirements = requirement_names(&component.requirements);
+        let requirements = requirements.iter().map(String::as_str);
+        if !(is_submodule && explanation.compare(&source, requirements.clone(), submodule)) {
+            explanation.compare(&source, requirements, fuzzy);
+        }
+        explanation
+    }
+
     fn stats(&self) -> ManifestStats {
         let package_count: usize = self.component_manifests
             .values()
@@ -611,4 +672,53 @@ mod tests {
         assert!(manifest.is_valid_import("homeassistant_frontend", &file_path));
         assert!(manifest.is_valid_import("insteon_frontend", &file_path));
     }
+
+    #[test]
+    fn test_explain_import_matches_is_valid_import() {
+        let temp = TempDir::new().unwrap();
+        let root = create_ha_structure(&temp);
+
+        create_component(
+            &root,
+            "tuya",
+            r#"{
+                "domain": "tuya",
+                "requirements": ["tuya-device-sharing-sdk==0.2.1"],
+                "loggers": ["tuya_iot"]
+            }"#,
+        );
+
+        let manifest = HomeAssistantManifest::from_root(&root).unwrap();
+        let file_path = root.join("homeassistant/components/tuya/__init__.py");
+
+        for import in ["tuya_sharing", "tuya_iot", "hass_frontend", "requests"] {
+            let explanation = manifest.explain_import(import, &file_path);
+            assert_eq!(
+                explanation.valid,
+                manifest.is_valid_import(import, &file_path),
+                "{}",
+                import
+            );
+        }
+
+        let explanation = manifest.explain_import("tuya_sharing", &file_path);
+        assert_eq!(explanation.scope.as_deref(), Some("tuya"));
+        let rules: Vec<_> = explanation
+            .attempts
+            .iter()
+            .map(|a| (a.declared.as_str(), a.rule))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![
+                ("tuya_iot", None),
+                ("tuya-device-sharing-sdk", Some(MatchRule::Abbreviation)),
+            ]
+        );
+        assert_eq!(explanation.matched().unwrap().source, "tuya manifest");
+
+        let explanation = manifest.explain_import("requests", &file_path);
+        assert!(explanation.matched().is_none());
+        assert_eq!(explanation.attempts.len(), 2);
+    }
 }
@@ -13,9 +13,15 @@
 //!     ├── PythonManifest         (pyproject.toml, requirements.txt, setup.cfg)
 //!     └── NoManifest             (pure PyPI phantom detection)
 //! ```
+//!
+//! Providers also implement `explain_import`, which records every declared
+//! package compared against an import and the [`MatchRule`] that accepted
+//! it, for `hollowcheck why-import`.
 
 use std::path::Path;
 
+use serde::Serialize;
+
 mod golang;
 mod homeassistant;
 mod none;
@@ -42,6 +48,18 @@ pub enum ManifestType {
     None,
 }
 
+impl ManifestType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ManifestType::HomeAssistant => "home-assistant",
+            ManifestType::PythonStandard => "python",
+            ManifestType::Go => "go",
+            ManifestType::Auto => "auto",
+            ManifestType::None => "none",
+        }
+    }
+}
+
 /// Trait for manifest-based import validation.
 ///
 /// Implementations of this trait provide project-specific logic for
@@ -70,6 +88,188 @@ pub trait ManifestProvider: Send + Sync {
     fn stats(&self) -> ManifestStats {
         ManifestStats::default()
     }
+
+    /// Explain how `is_valid_import` reaches its answer.
+    ///
+    /// Records the declared entries compared against the import, in the order
+    /// `is_valid_import` consults them, up to the first one that accepts it.
+    /// The default records no comparisons.
+    fn explain_import(&self, import_name: &str, file_path: &Path) -> ImportExplanation {
+        ImportExplanation {
+            valid: self.is_valid_import(import_name, file_path),
+            ..ImportExplanation::new(self.get_scope(file_path))
+        }
+    }
+}
+
+/// The rule that matched an import to a declared entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum MatchRule {
+    /// Same name, ignoring case (`requests` → `requests`)
+    Exact,
+    /// Same name with hyphens and underscores unified (`flask-restful` → `flask_restful`)
+    Normalized,
+    /// `py` prefix dropped (`pyswitchbot` → `switchbot`)
+    PyPrefix,
+    /// `python-` prefix dropped (`python-miio` → `miio`)
+    PythonPrefix,
+    /// Namespace package (`jaraco.abode` → `jaraco`)
+    Namespace,
+    /// `-py` suffix dropped (`somelib-py` → `somelib`)
+    PySuffix,
+    /// `.py` suffix dropped (`Mastodon.py` → `mastodon`)
+    DotPySuffix,
+    /// `-client` suffix dropped
+    ClientSuffix,
+    /// `-api` suffix dropped
+    ApiSuffix,
+    /// Same name without separators (`evohome-async` → `evohomeasync`)
+    NoSeparator,
+    /// One name contains the other without separators
+    Substring,
+    /// First word plus a later word (`tuya-device-sharing-sdk` → `tuya_sharing`)
+    Abbreviation,
+    /// `api`/`sdk`/`client` suffix and `py` prefix stripped (`py-synologydsm-api` → `synologydsm`)
+    SuffixStripped,
+    /// First word of the package (`paho-mqtt` → `paho`)
+    FirstPart,
+    /// Declared logger name or one of its namespaces
+    Logger,
+    /// Known submodule of a differently named package (`didl_lite` → `async-upnp-client`)
+    KnownSubmodule,
+    /// Project-internal package that is never published
+    Internal,
+    /// File in a template directory, which is not validated
+    TemplateFile,
+    /// Standard library package
+    Stdlib,
+    /// Package inside the project's own module
+    RootModule,
+    /// Module redirected by a `replace` directive
+    Replace,
+    /// Package inside a required module
+    Require,
+}
+
+impl MatchRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchRule::Exact => "exact",
+            MatchRule::Normalized => "normalized",
+            MatchRule::PyPrefix => "py-prefix",
+            MatchRule::PythonPrefix => "python-prefix",
+            MatchRule::Namespace => "namespace",
+            MatchRule::PySuffix => "py-suffix",
+            MatchRule::DotPySuffix => "dot-py-suffix",
+            MatchRule::ClientSuffix => "client-suffix",
+            MatchRule::ApiSuffix => "api-suffix",
+            MatchRule::NoSeparator => "no-separator",
+            MatchRule::Substring => "substring",
+            MatchRule::Abbreviation => "abbreviation",
+            MatchRule::SuffixStripped => "suffix-stripped",
+            MatchRule::FirstPart => "first-part",
+            MatchRule::Logger => "logger",
+            MatchRule::KnownSubmodule => "known-submodule",
+            MatchRule::Internal => "internal",
+            MatchRule::TemplateFile => "template-file",
+            MatchRule::Stdlib => "stdlib",
+            MatchRule::RootModule => "root-module",
+            MatchRule::Replace => "replace",
+            MatchRule::Require => "require",
+        }
+    }
+}
+
+/// One declared entry compared against an import.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MatchAttempt {
+    /// Where the entry is declared (e.g. `requirements`, `go.mod require`)
+    pub source: String,
+    /// The declared package, module, or logger
+    pub declared: String,
+    /// The rule that accepted the import, or None if none did
+    pub rule: Option<MatchRule>,
+}
+
+/// Structured reasons behind a manifest's verdict on one import.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ImportExplanation {
+    /// Scope of the importing file, as reported by `get_scope`
+    pub scope: Option<String>,
+    /// Declared entries compared, in order; only the last one can have matched
+    pub attempts: Vec<MatchAttempt>,
+    /// Whether the manifest accepts the import
+    pub valid: bool,
+}
+
+impl ImportExplanation {
+    /// Start an explanation for a file in `scope`.
+    pub fn new(scope: Option<String>) -> Self {
+        Self {
+            scope,
+            ..Self::default()
+        }
+    }
+
+    /// Record an acceptance that does not depend on a declared package.
+    pub fn accept(&mut self, source: &str, declared: &str, rule: MatchRule) {
+        self.attempts.push(MatchAttempt {
+            source: source.to_string(),
+            declared: declared.to_string(),
+            rule: Some(rule),
+        });
+        self.valid = true;
+    }
+
+    /// Compare the import against declared entries, stopping at the first match.
+    ///
+    /// Returns true if `matcher` accepted one of them.
+    pub fn compare<'a, I, F>(&mut self, source: &str, declared: I, matcher: F) -> bool
+    where
+        I: IntoIterator<Item = &'a str>,
+        F: Fn(&str) -> Option<MatchRule>,
+    {
+        for entry in declared {
+            let rule = matcher(entry);
+            self.attempts.push(MatchAttempt {
+                source: source.to_string(),
+                declared: entry.to_string(),
+                rule,
+            });
+            if rule.is_some() {
+                self.valid = true;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// The attempt that accepted the import, if any.
+    pub fn matched(&self) -> Option<&MatchAttempt> {
+        self.attempts.last().filter(|a| a.rule.is_some())
+    }
+}
+
+/// A version constraint declared in a manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeclaredVersion {
+    /// Constraint as written (`>=4.2,<5`, `^4.2`, `v1.9.1`)
+    pub constraint: String,
+    /// Manifest file, relative to the project root
+    pub file: String,
+    /// 1-based line of the declaration
+    pub line: usize,
+}
+
+impl DeclaredVersion {
+    pub fn new(constraint: impl Into<String>, file: impl Into<String>, line: usize) -> Self {
+        Self {
+            constraint: constraint.into(),
+            file: file.into(),
+            line,
+        }
+    }
 }
 
 /// Statistics about a loaded manifest.
@@ -108,6 +308,8 @@ pub fn detect_manifest_type(dir: &Path) -> ManifestType {
 
 /// Common utility functions for manifest parsing.
 pub mod utils {
+    use super::MatchRule;
+
     /// Extract package name from a requirement string.
     ///
     /// Handles various requirement formats:
@@ -116,17 +318,55 @@ pub mod utils {
     /// - `package[extra]>=1.0` → `package`
     /// - `tuya-device-sharing-sdk==0.2.1` → `tuya-device-sharing-sdk`
     pub fn extract_package_name(req: &str) -> String {
-        req.split(|c| c == '=' || c == '>' || c == '<' || c == '~' || c == '[' || c == ';' || c == ' ')
+        req.split(['=', '>', '<', '~', '[', ';', ' '])
             .next()
             .unwrap_or("")
             .trim()
             .to_string()
     }
 
+    /// Extract the version constraint from a requirement string.
+    ///
+    /// Handles PEP 508 requirements and Poetry dependency lines:
+    /// - `django>=4.2,<5.0` → `>=4.2,<5.0`
+    /// - `requests[socks] (>=2.0) ; python_version < "3.8"` → `>=2.0`
+    /// - `django = "^4.2"` → `^4.2`
+    /// - `django = {version = "^4.2", extras = ["argon2"]}` → `^4.2`
+    ///
+    /// Returns None when the requirement has no constraint.
+    pub fn extract_version_spec(req: &str) -> Option<String> {
+        let unquote = |s: &str| s.trim_matches(|c: char| matches!(c, '"' | '\'' | ',' | '}') || c.is_whitespace()).to_string();
+
+        let name = extract_package_name(req);
+        let rest = req.trim_start().strip_prefix(name.as_str())?.trim_start();
+        let rest = match rest.strip_prefix('[') {
+            Some(extras) => extras.split_once(']').map_or("", |(_, r)| r).trim_start(),
+            None => rest,
+        };
+
+        let spec = match rest.strip_prefix('=') {
+            // Poetry: `name = "spec"` or `name = {version = "spec", ...}`
+            Some(value) if !value.starts_with('=') => {
+                let value = value.trim();
+                match value.strip_prefix('{') {
+                    Some(table) => table.split(',').find_map(|kv| {
+                        let (key, value) = kv.split_once('=')?;
+                        (key.trim() == "version").then(|| unquote(value))
+                    })?,
+                    None => unquote(value),
+                }
+            }
+            _ => {
+                let spec = rest.split(';').next().unwrap_or("").trim();
+                spec.trim_start_matches('(').trim_end_matches(')').trim().to_string()
+            }
+        };
+        (!spec.is_empty()).then_some(spec)
+    }
+
     /// Normalize a package name for comparison.
     ///
     /// Converts to lowercase and normalizes separators (hyphens/underscores).
-    #[allow(dead_code)]
     pub fn normalize_package_name(name: &str) -> String {
         name.to_lowercase().replace('-', "_")
     }
@@ -140,76 +380,83 @@ pub mod utils {
     /// - .py suffix: `Mastodon.py` → import `mastodon`
     /// - Hyphens vs underscores: `flask-restful` → import `flask_restful`
     pub fn import_matches_package(import_name: &str, package_name: &str) -> bool {
+        package_match_rule(import_name, package_name).is_some()
+    }
+
+    /// The rule under which an import name matches a package name, if any.
+    ///
+    /// Rules are tried in the order listed on [`import_matches_package`].
+    pub fn package_match_rule(import_name: &str, package_name: &str) -> Option<MatchRule> {
         let pkg = package_name.to_lowercase();
         let imp = import_name.to_lowercase();
 
         // Direct match
         if pkg == imp {
-            return true;
+            return Some(MatchRule::Exact);
         }
 
         // Normalize: replace hyphens/underscores
         let pkg_normalized = pkg.replace('-', "_");
         let imp_normalized = imp.replace('-', "_");
         if pkg_normalized == imp_normalized {
-            return true;
+            return Some(MatchRule::Normalized);
         }
 
         // py prefix: pyswitchbot → switchbot
         if let Some(stripped) = pkg.strip_prefix("py") {
             if stripped == imp || stripped.replace('-', "_") == imp_normalized {
-                return true;
+                return Some(MatchRule::PyPrefix);
             }
         }
 
         // python- prefix: python-miio → miio
         if let Some(stripped) = pkg.strip_prefix("python-") {
             if stripped == imp || stripped.replace('-', "_") == imp_normalized {
-                return true;
+                return Some(MatchRule::PythonPrefix);
             }
         }
 
         // Namespace packages: jaraco.abode → jaraco
         if pkg.starts_with(&format!("{}.", imp)) || pkg.starts_with(&format!("{}-", imp)) {
-            return true;
+            return Some(MatchRule::Namespace);
         }
 
         // -py suffix: somelib-py → somelib
         if let Some(stripped) = pkg.strip_suffix("-py") {
             if stripped == imp {
-                return true;
+                return Some(MatchRule::PySuffix);
             }
         }
 
         // .py suffix: Mastodon.py → mastodon
         if let Some(stripped) = pkg.strip_suffix(".py") {
             if stripped.to_lowercase() == imp {
-                return true;
+                return Some(MatchRule::DotPySuffix);
             }
         }
 
         // -client suffix: some-client → some (for API client packages)
         if let Some(stripped) = pkg.strip_suffix("-client") {
             if stripped == imp {
-                return true;
+                return Some(MatchRule::ClientSuffix);
             }
         }
 
         // -api suffix
         if let Some(stripped) = pkg.strip_suffix("-api") {
             if stripped == imp {
-                return true;
+                return Some(MatchRule::ApiSuffix);
             }
         }
 
         // Async variations: evohome-async → evohomeasync
-        let pkg_no_sep = pkg.replace('-', "").replace('_', "");
-        let imp_no_sep = imp.replace('-', "").replace('_', "");
+        let pkg_no_sep = pkg.replace(['-', '_'], "");
+        let imp_no_sep = imp.replace(['-', '_'], "");
         if pkg_no_sep == imp_no_sep {
-            return true;
+            return Some(MatchRule::NoSeparator);
         }
 
-        false
+        None
     }
 
     #[cfg(test)]
@@ -229,6 +476,23 @@ pub mod utils {
             );
         }
 
+        #[test]
+        fn test_extract_version_spec() {
+            assert_eq!(extract_version_spec("django>=4.2,<5.0").as_deref(), Some(">=4.2,<5.0"));
+            assert_eq!(extract_version_spec("pyswitchbot==0.40.0").as_deref(), Some("==0.40.0"));
+            assert_eq!(
+                extract_version_spec("requests[socks] (>=2.0) ; python_version < \"3.8\"").as_deref(),
+                Some(">=2.0")
+            );
+            assert_eq!(extract_version_spec("django = \"^4.2\"").as_deref(), Some("^4.2"));
+            assert_eq!(
+                extract_version_spec("django = {version = \"^4.2\", extras = [\"argon2\"]}").as_deref(),
+                Some("^4.2")
+            );
+            assert_eq!(extract_version_spec("simple"), None);
+            assert_eq!(extract_version_spec("flask[async]"), None);
+        }
+
         #[test]
         fn test_import_matches_package() {
             // Direct match
@@ -259,6 +523,19 @@ pub mod utils {
             // Non-matches
             assert!(!import_matches_package("notrelated", "something-else"));
         }
+
+        #[test]
+        fn test_package_match_rule() {
+            assert_eq!(package_match_rule("Requests", "requests"), Some(MatchRule::Exact));
+            assert_eq!(package_match_rule("switchbot", "pyswitchbot"), Some(MatchRule::PyPrefix));
+            assert_eq!(package_match_rule("miio", "python-miio"), Some(MatchRule::PythonPrefix));
+            assert_eq!(package_match_rule("jaraco", "jaraco.abode"), Some(MatchRule::Namespace));
+            assert_eq!(
+                package_match_rule("evohomeasync", "evohome-async"),
+                Some(MatchRule::NoSeparator)
+            );
+            assert_eq!(package_match_rule("notrelated", "something-else"), None);
+        }
     }
 }
 
@@ -6,12 +6,15 @@
 //! - setup.cfg
 //! - setup.py (basic parsing)
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use super::utils::{extract_package_name, import_matches_package};
-use super::{ManifestProvider, ManifestStats};
+use super::utils::{
+    extract_package_name, extract_version_spec, import_matches_package, normalize_package_name,
+    package_match_rule,
+};
+use super::{DeclaredVersion, ImportExplanation, ManifestProvider, ManifestStats};
 
 /// Standard Python manifest provider.
 ///
@@ -22,6 +25,10 @@ pub struct PythonManifest {
     root: PathBuf,
     /// All declared packages (normalized names)
     packages: HashSet<String>,
+    /// Version constraints of declared packages, by normalized name; the first declaration wins
+    versions: HashMap<String, DeclaredVersion>,
+    /// `requires-python` from pyproject.toml
+    requires_python: Option<DeclaredVersion>,
 }
 
 impl PythonManifest {
@@ -30,6 +37,8 @@ impl PythonManifest {
         let mut manifest = Self {
             root: root.to_path_buf(),
             packages: HashSet::new(),
+            versions: HashMap::new(),
+            requires_python: None,
         };
 
         // Parse all requirement sources
@@ -40,6 +49,32 @@ impl PythonManifest {
         Ok(manifest)
     }
 
+    /// Record a requirement's package and its version constraint, if any.
+    fn declare(&mut self, requirement: &str, path: &Path, line: usize) {
+        let pkg_name = extract_package_name(requirement);
+        if pkg_name.is_empty() || pkg_name.starts_with('#') || pkg_name.starts_with('[') {
+            return;
+        }
+        if let Some(spec) = extract_version_spec(requirement) {
+            let file = path.strip_prefix(&self.root).unwrap_or(path).to_string_lossy().to_string();
+            self.versions
+                .entry(normalize_package_name(&pkg_name))
+                .or_insert_with(|| DeclaredVersion::new(spec, file, line));
+        }
+        self.packages.insert(pkg_name.to_lowercase());
+    }
+
+    /// The version constraint declared for a package, if any.
+    pub fn declared_version(&self, package: &str) -> Option<&DeclaredVersion> {
+        self.versions.get(&normalize_package_name(package))
+    }
+
+    /// The Python versions the project supports: `requires-python`, or
+    /// the `python` entry of Poetry's dependencies.
+    pub fn requires_python(&self) -> Option<&DeclaredVersion> {
+        self.requires_python.as_ref().or_else(|| self.versions.get("python"))
+    }
+
     /// Parse requirements*.txt files at root.
     fn parse_requirements_txt(&mut self) -> anyhow::Result<()> {
         if let Ok(entries) = fs::read_dir(&self.root) {
@@ -58,7 +93,7 @@ impl PythonManifest {
     /// Parse a single requirements.txt file.
     fn parse_requirements_file(&mut self, path: &Path) -> anyhow::Result<()> {
         if let Ok(content) = fs::read_to_string(path) {
-            for line in content.lines() {
+            for (i, line) in content.lines().enumerate() {
                 let line = line.trim();
 
                 // Skip comments and empty lines
@@ -71,10 +106,7 @@ impl PythonManifest {
                     continue;
                 }
 
-                let pkg_name = extract_package_name(line);
-                if !pkg_name.is_empty() {
-                    self.packages.insert(pkg_name.to_lowercase());
-                }
+                self.declare(line, path, i + 1);
             }
         }
         Ok(())
@@ -93,9 +125,18 @@ impl PythonManifest {
         let mut in_deps = false;
         let mut brace_depth = 0;
 
-        for line in content.lines() {
+        for (i, line) in content.lines().enumerate() {
             let trimmed = line.trim();
 
+            // requires-python = ">=3.11" in [project]
+            if let Some(value) = trimmed.strip_prefix("requires-python") {
+                if let Some(spec) = value.trim_start().strip_prefix('=') {
+                    let spec = spec.trim().trim_matches(|c| c == '"' || c == '\'');
+                    self.requires_python = Some(DeclaredVersion::new(spec, "pyproject.toml", i + 1));
+                }
+                continue;
+            }
+
             // Track section headers
             if trimmed.starts_with("[project.dependencies]")
                 || trimmed.starts_with("[tool.poetry.dependencies]")
@@ -132,12 +173,8 @@ impl PythonManifest {
                 // First, strip quotes and commas
                 let cleaned = trimmed.trim_matches(|c| c == '"' || c == ',' || c == '\'');
 
-                // Use extract_package_name which handles all version specifiers
-                let pkg_name = extract_package_name(cleaned);
-
-                if !pkg_name.is_empty() && !pkg_name.starts_with('#') && !pkg_name.starts_with('[') {
-                    self.packages.insert(pkg_name.to_lowercase());
-                }
+                // Record the package with whatever version specifier follows it
+                self.declare(cleaned, &path, i + 1);
             }
         }
 
@@ -154,7 +191,7 @@ impl PythonManifest {
         let content = fs::read_to_string(&path)?;
         let mut in_install_requires = false;
 
-        for line in content.lines() {
+        for (i, line) in content.lines().enumerate() {
             let trimmed = line.trim();
 
             if trimmed.starts_with('[') {
@@ -167,10 +204,7 @@ impl PythonManifest {
                 if let Some(eq_pos) = trimmed.find('=') {
                     let value = trimmed[eq_pos + 1..].trim();
                     if !value.is_empty() {
-                        let pkg = extract_package_name(value);
-                        if !pkg.is_empty() {
-                            self.packages.insert(pkg.to_lowercase());
-                        }
+                        self.declare(value, &path, i + 1);
                     }
                 }
                 continue;
@@ -183,10 +217,7 @@ impl PythonManifest {
                     continue;
                 }
 
-                let pkg = extract_package_name(trimmed);
-                if !pkg.is_empty() {
-                    self.packages.insert(pkg.to_lowercase());
-                }
+                self.declare(trimmed, &path, i + 1);
             }
         }
 
@@ -231,11 +262,25 @@ impl ManifestProvider for PythonManifest {
             package_count: self.packages.len(),
         }
     }
+
+    fn explain_import(&self, import_name: &str, file_path: &Path) -> ImportExplanation {
+        let import_lower = import_name.to_lowercase();
+        let mut explanation = ImportExplanation::new(self.get_scope(file_path));
+
+        // Direct match first, as in `is_valid_import`, then the rest in a stable order
+        let mut packages: Vec<&str> = self.packages.iter().map(String::as_str).collect();
+        packages.sort_by_key(|p| (*p != import_lower, *p));
+        explanation.compare("declared packages", packages, |pkg| {
+            package_match_rule(&import_lower, pkg)
+        });
+        explanation
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::detect::manifest::MatchRule;
     use tempfile::TempDir;
 
     #[test]
@@ -288,6 +333,42 @@ dependencies = [
         assert!(manifest.packages.contains("flask"));
     }
 
+    #[test]
+    fn test_declared_versions() {
+        let temp = TempDir::new().unwrap();
+
+        fs::write(
+            temp.path().join("pyproject.toml"),
+            r#"
+[project]
+name = "myproject"
+requires-python = ">=3.11,<3.13"
+dependencies = [
+    "Django>=4.2,<5.0",
+    "flask",
+]
+"#,
+        )
+        .unwrap();
+        fs::write(temp.path().join("requirements.txt"), "# pinned\ncelery==5.3.6\n").unwrap();
+
+        let manifest = PythonManifest::from_root(temp.path()).unwrap();
+
+        assert_eq!(
+            manifest.requires_python(),
+            Some(&DeclaredVersion::new(">=3.11,<3.13", "pyproject.toml", 4))
+        );
+        assert_eq!(
+            manifest.declared_version("celery"),
+            Some(&DeclaredVersion::new("==5.3.6", "requirements.txt", 2))
+        );
+        assert_eq!(
+            manifest.declared_version("django"),
+            Some(&DeclaredVersion::new(">=4.2,<5.0", "pyproject.toml", 6))
+        );
+        assert_eq!(manifest.declared_version("flask"), None);
+    }
+
     #[test]
     fn test_is_valid_import_py_prefix() {
         let temp = TempDir::new().unwrap();
@@ -322,6 +403,38 @@ dependencies = [
         assert!(manifest.is_valid_import("jaraco", &file));
     }
 
+    #[test]
+    fn test_explain_import() {
+        let temp = TempDir::new().unwrap();
+
+        fs::write(
+            temp.path().join("requirements.txt"),
+            "aiohttp\npyswitchbot==0.40.0\nswitchbot-api\n",
+        )
+        .unwrap();
+
+        let manifest = PythonManifest::from_root(temp.path()).unwrap();
+        let file = temp.path().join("test.py");
+
+        // Rejected entries are listed before the one that matched
+        let explanation = manifest.explain_import("switchbot", &file);
+        assert!(explanation.valid);
+        let rules: Vec<_> = explanation
+            .attempts
+            .iter()
+            .map(|a| (a.declared.as_str(), a.rule))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![("aiohttp", None), ("pyswitchbot", Some(MatchRule::PyPrefix))]
+        );
+
+        let explanation = manifest.explain_import("requests", &file);
+        assert!(!explanation.valid);
+        assert_eq!(explanation.attempts.len(), 3);
+        assert!(explanation.matched().is_none());
+    }
+
     #[test]
     fn test_stats() {
         let temp = TempDir::new().unwrap();
@@ -7,14 +7,43 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+use super::user_patterns::{compile_user_pattern, PatternGuard};
 use super::{DetectionResult, Severity, Violation, ViolationRule};
 
 /// Pre-compiled mock signature with metadata.
-struct CompiledMockSignature {
-    regex: Regex,
+pub(super) struct CompiledMockSignature {
+    pub(super) regex: Regex,
     description: Option<String>,
 }
 
+impl CompiledMockSignature {
+    /// Violation message for a match of this signature.
+    pub(super) fn message(&self) -> String {
+        if let Some(desc) = &self.description {
+            format!("mock data signature {:?} found: {}", self.regex.as_str(), desc)
+        } else {
+            format!("mock data signature {:?} found", self.regex.as_str())
+        }
+    }
+}
+
+/// Compile the contract's mock signatures.
+pub(super) fn compile_mock_signatures(
+    cfg: &MockSignaturesConfig,
+) -> anyhow::Result<Vec<CompiledMockSignature>> {
+    cfg.patterns
+        .iter()
+        .map(|s| {
+            let regex = compile_user_pattern(&s.pattern)
+                .map_err(|e| anyhow::anyhow!("compiling mock signature {:?}: {}", s.pattern, e))?;
+            Ok(CompiledMockSignature {
+                regex,
+                description: s.description.clone(),
+            })
+        })
+        .collect()
+}
+
 /// Check if a file path is a test file (ends with _test.go).
 fn is_test_file(file_path: &Path) -> bool {
     file_path
@@ -44,9 +73,12 @@ fn is_known_utility_file(file_path: &Path) -> bool {
 }
 
 /// Scan files for mock data signatures defined in the contract.
+///
+/// Signatures that exceed their matching budget are disabled through `guard`.
 pub fn detect_mock_data<P: AsRef<Path>>(
     files: &[P],
     cfg: Option<&MockSignaturesConfig>,
+    guard: &PatternGuard,
 ) -> anyhow::Result<DetectionResult> {
     let mut result = DetectionResult::new();
 
@@ -56,18 +88,7 @@ pub fn detect_mock_data<P: AsRef<Path>>(
     };
 
     // Pre-compile all patterns
-    let compiled: Vec<CompiledMockSignature> = cfg
-        .patterns
-        .iter()
-        .map(|s| {
-            let regex = Regex::new(&s.pattern)
-                .map_err(|e| anyhow::anyhow!("compiling mock signature {:?}: {}", s.pattern, e))?;
-            Ok(CompiledMockSignature {
-                regex,
-                description: s.description.clone(),
-            })
-        })
-        .collect::<anyhow::Result<Vec<_>>>()?;
+    let compiled = compile_mock_signatures(cfg)?;
 
     // Determine test file handling
     let skip_test_files = cfg.should_skip_test_files();
@@ -102,7 +123,7 @@ pub fn detect_mock_data<P: AsRef<Path>>(
             Severity::Warning
         };
 
-        let violations = scan_file_for_mocks(path, &compiled, severity)?;
+        let violations = scan_file_for_mocks(path, &compiled, severity, guard)?;
         result.violations.extend(violations);
         result.scanned += 1;
     }
@@ -272,11 +293,16 @@ fn scan_file_for_mocks(
     file_path: &Path,
     signatures: &[CompiledMockSignature],
     severity: Severity,
+    guard: &PatternGuard,
 ) -> anyhow::Result<Vec<Violation>> {
     let file = File::open(file_path)?;
     let reader = BufReader::new(file);
     let mut violations = Vec::new();
     let file_str = file_path.to_string_lossy().to_string();
+    let mut meters: Vec<_> = signatures
+        .iter()
+        .map(|s| guard.meter("mock_signatures", &s.regex, &file_str))
+        .collect();
 
     // Read all lines for context awareness
     let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
@@ -289,18 +315,12 @@ fn scan_file_for_mocks(
             continue;
         }
 
-        for s in signatures {
-            if s.regex.is_match(line) {
-                let msg = if let Some(desc) = &s.description {
-                    format!("mock data signature {:?} found: {}", s.regex.as_str(), desc)
-                } else {
-                    format!("mock data signature {:?} found", s.regex.as_str())
-                };
-
+        for (s, meter) in signatures.iter().zip(meters.iter_mut()) {
+            if meter.is_match(line) == Some(true) {
                 violations.push(Violation {
                     rule: ViolationRule::MockData,
-                    message: msg,
-                    file: file_str.clone(),
+                    message: s.message(),
+                    file: Some(file_str.clone()),
                     line: line_number,
                     severity,
                 });
@@ -345,7 +365,7 @@ var userId = "user-12345"
             test_file_severity: None,
         };
 
-        let result = detect_mock_data(&[&file_path], Some(&cfg)).unwrap();
+        let result = detect_mock_data(&[&file_path], Some(&cfg), &PatternGuard::default()).unwrap();
         assert_eq!(result.violations.len(), 2);
         assert!(result
             .violations
@@ -374,7 +394,7 @@ var testUrl = "https://example.com/api"
             test_file_severity: None,
         };
 
-        let result = detect_mock_data(&[&test_file], Some(&cfg)).unwrap();
+        let result = detect_mock_data(&[&test_file], Some(&cfg), &PatternGuard::default()).unwrap();
         assert_eq!(result.violations.len(), 0);
         assert_eq!(result.scanned, 1);
     }
@@ -400,7 +420,7 @@ var testUrl = "https://example.com/api"
             test_file_severity: Some("info".to_string()),
         };
 
-        let result = detect_mock_data(&[&test_file], Some(&cfg)).unwrap();
+        let result = detect_mock_data(&[&test_file], Some(&cfg), &PatternGuard::default()).unwrap();
         assert_eq!(result.violations.len(), 1);
         assert_eq!(result.violations[0].severity, Severity::Info);
     }
@@ -437,7 +457,7 @@ MIN_ORACLE_NUMBER = -999999999999999999999999999999999999999
             test_file_severity: None,
         };
 
-        let result = detect_mock_data(&[&file_path], Some(&cfg)).unwrap();
+        let result = detect_mock_data(&[&file_path], Some(&cfg), &PatternGuard::default()).unwrap();
         // Should NOT flag these as mock data - they're legitimate numeric constants
         assert_eq!(result.violations.len(), 0, "Should not flag numeric range constants");
     }
@@ -466,7 +486,7 @@ FIELD_LIMITS = {
             test_file_severity: None,
         };
 
-        let result = detect_mock_data(&[&file_path], Some(&cfg)).unwrap();
+        let result = detect_mock_data(&[&file_path], Some(&cfg), &PatternGuard::default()).unwrap();
         assert_eq!(result.violations.len(), 0, "Should not flag config dictionary values");
     }
 
@@ -502,7 +522,7 @@ func GetUser() User {
             test_file_severity: None,
         };
 
-        let result = detect_mock_data(&[&file_path], Some(&cfg)).unwrap();
+        let result = detect_mock_data(&[&file_path], Some(&cfg), &PatternGuard::default()).unwrap();
         // Should still flag actual mock data in non-config contexts
         assert_eq!(result.violations.len(), 2, "Should flag actual mock data");
     }
@@ -6,45 +6,214 @@
 //!   - `stubs`: Hollow function detection (empty, panic-only, TODO-only)
 //!   - `symbols`: Required symbol verification
 //!   - `complexity`: Cyclomatic complexity checking
+//!   - `implementation`: Ratio of implemented callables per glob
+//!   - `literals`: String literals repeated across the project
+//!   - `promises`: Floating (unawaited) promises in JS/TS
+//!   - `headers`: License header mismatches and placeholders
+//!   - `docs`: Documented functions, types, and subcommands missing from the code
+//!   - `params`: Functions with too many parameters
+//!   - `handlers`: HTTP handlers that respond 200 with an empty body
+//!   - `budget`: Large files that cannot be parsed within the per-file time budget
+//!   - `isolation`: Files whose analysis crashes, contained and skipped
+//!   - `interfaces`: Interfaces and traits with no implementing type
+//!   - `abstractions`: Interfaces, factories, and type parameters with a single type behind them
+//!   - `crypto`: Weak hashes and ciphers, ECB mode, and hardcoded IVs and salts
+//!   - `comment_ratio`: Files with no comments across complex functions, or mostly comments
+//!   - `doc_coverage`: Exported declarations without doc comments, and coverage per file and project
+//!   - `builtins`: Python names that shadow builtins
+//!   - `noops`: Statements with no effect that pad function bodies
+//!   - `enums`: Switches that leave most enum variants to a trivial default
+//!   - `contexts`: Go `context.Context` parameters that are never used
+//!   - `named_returns`: Go named results returned by a bare `return` without being assigned
+//!   - `placeholder_constants`: Constant groups with identical or placeholder values
+//!   - `definitions`: C and C++ header functions with no definition in the scan
+//!   - `deprecated`: Deprecated callables still called from many sites
+//!   - `unwraps`: Rust functions with many `unwrap`/`expect`/`panic!` sites
+//!   - `force_unwraps`: Swift functions with many `!` force-unwraps and `try!` expressions
+//!   - `local_modules`: Relative and project-local imports of modules that do not exist
+//!   - `wrappers`: Callables that only forward their parameters to another function
+//!   - `error_logging`: Error handlers that log an error and rethrow it unchanged
+//!   - `error_handling`: Files that mix error-returning and panicking functions
+//!   - `async_await`: Async functions whose body never awaits anything
+//!   - `mutable_defaults`: Python parameters defaulting to a mutable list, dict, or set
+//!   - `truncated`: Files that end inside a function, as cut-off generated output does
+//!
+//! - **Path/metadata rules**:
+//!   - `files`: Required files, forbidden files, file size limits, and case collisions
+//!   - `config_files`: YAML, JSON, and TOML configuration left with placeholders
+//!   - `ci_configs`: CI pipelines with placeholder images or TODO steps
+//!   - `environment`: Language, runtime, and framework versions declared in manifests
+//!   - `vendored`: Dependency and build directories skipped before any rule runs
+//!   - `generated`: Files stamped by a code generator, skipped or checked by fewer rules
+//!   - `language_overrides`: Files routed to an analyzer by contract glob or inline comment
 //!
 //! - **Text-based rules**:
 //!   - `patterns`: Forbidden pattern matching
 //!   - `todos`: Hollow TODO comment detection
 //!   - `mocks`: Mock data detection
+//!   - `containers`: Hollow Dockerfiles and placeholder docker-compose services
+//!   - `schemas`: Empty definitions and placeholder fields in Protobuf and GraphQL schemas
+//!   - `user_patterns`: Size limit and matching budget for contract regexes
+//!
+//! After the rules run, contract scopes (see `scopes`) drop violations or
+//! set their severity by file path. The runner then gives every violation a
+//! stable fingerprint (see `fingerprint`), which the suppression file
+//! matches. Inline suppressions that matched nothing can be reported as
+//! `unused_suppression` (see `suppress`). Contract waivers (see `waivers`)
+//! then set aside accepted violations until their expiry date. Last, the
+//! hollow functions left get an effort estimate (see `effort`) when the
+//! contract asks for one.
+//!
+//! AST-backed rules declare the facts they read in
+//! `ViolationRule::required_facts`; the runner computes them once per scan
+//! into a `FactsBundle` (see `facts`) before any rule runs.
 
+mod abstractions;
+mod async_await;
+mod budget;
+mod builtins;
+mod ci_configs;
+mod comment_ratio;
 mod complexity;
+mod config_files;
+mod containers;
+mod crypto;
+mod contexts;
+mod definitions;
+mod deprecated;
 mod dependencies;
+mod dependency_trace;
+mod doc_coverage;
+mod docs;
+mod effort;
+mod enums;
+mod environment;
+mod error_handling;
+mod error_logging;
+mod facts;
 mod files;
+mod fingerprint;
+mod generated;
+mod globs;
+mod force_unwraps;
 mod god_objects;
+mod handlers;
+mod headers;
+mod implementation;
 mod imports;
+mod interfaces;
+mod isolation;
+mod language_overrides;
+mod literals;
+mod local_modules;
 pub mod manifest;
+mod mutable_defaults;
 mod mocks;
+mod named_returns;
+mod noops;
+mod params;
+mod placeholder_constants;
 mod patterns;
+mod promises;
 mod runner;
+mod schemas;
+mod scopes;
 mod stdlib;
 mod stubs;
 mod suppress;
 mod symbols;
+#[cfg(test)]
+mod test_support;
 mod todos;
+mod truncated;
 mod types;
+mod unwraps;
+mod user_patterns;
+mod vendored;
+mod waivers;
+mod wrappers;
 
+pub use abstractions::detect_premature_abstraction;
+pub use async_await::detect_async_without_await;
+pub use budget::enforce_analysis_budget;
+pub use builtins::detect_builtin_shadowing;
+pub use ci_configs::detect_hollow_ci_configs;
+pub use comment_ratio::detect_comment_ratio;
 pub use complexity::detect_low_complexity;
+pub use config_files::detect_hollow_config;
+pub use containers::detect_hollow_containers;
+pub use contexts::detect_unused_context;
+pub use crypto::detect_weak_crypto;
+pub use definitions::detect_missing_definitions;
+pub use deprecated::detect_deprecated_usage;
 pub use dependencies::{detect_hallucinated_dependencies, DependencyValidator};
+pub use dependency_trace::{
+    AllowlistTrace, DependencyTrace, ManifestTrace, RegistryLookup, TraceVerdict,
+};
+pub use doc_coverage::{detect_doc_coverage, DocCoverage, FileDocCoverage};
+pub use docs::detect_doc_drift;
+pub use effort::{estimate_effort, Effort, EffortBasis, EffortBucket, EffortEstimate, EffortModel, ESTIMATION_FACTS};
+pub use enums::detect_unhandled_enum_cases;
+pub use environment::{detect_environment_mismatch, EnvironmentEntry, Version, VersionReq};
+pub use error_handling::detect_inconsistent_error_handling;
+pub use error_logging::detect_redundant_error_logging;
+pub use facts::{
+    is_test_file_name, CallLocation, DefinitionLocation, FactKind, FactsBundle, SymbolLocation,
+};
 pub use manifest::{
-    detect_manifest_type, GoManifest, HomeAssistantManifest, ManifestProvider, ManifestStats,
-    ManifestType, NoManifest, PythonManifest,
+    detect_manifest_type, DeclaredVersion, GoManifest, HomeAssistantManifest, ImportExplanation, ManifestProvider,
+    ManifestStats, ManifestType, MatchAttempt, MatchRule, NoManifest, PythonManifest,
 };
-pub use files::detect_missing_files;
-pub use god_objects::{detect_god_objects, GodObjectConfig};
+pub use files::{
+    case_mismatch_message, detect_case_collisions, detect_forbidden_files, detect_missing_files, lookup_exact_case, CaseLookup,
+};
+pub use mutable_defaults::detect_mutable_defaults;
+pub use force_unwraps::detect_force_unwraps;
+pub use fingerprint::{
+    fallback_fingerprint, fallback_fingerprint_parts, fingerprint_violations, hash_parts, FINGERPRINT_VERSION,
+};
+pub use generated::{
+    drop_generated_files, filter_generated_violations, find_generated_files, scope_edited_violations, GeneratedFiles,
+};
+pub use god_objects::{detect_god_objects, detect_god_objects_outside, GodObjectConfig};
+pub use handlers::detect_empty_handlers;
+pub use headers::detect_bad_headers;
+pub use implementation::detect_insufficient_implementation;
+pub use isolation::isolate_analysis_failures;
+pub(crate) use isolation::panic_message;
+pub use language_overrides::{apply_language_overrides, resolve_language_override};
 pub use imports::{extract_imports, ImportedDependency};
+pub use interfaces::detect_unimplemented_interfaces;
+pub use literals::detect_repeated_literals;
+pub use local_modules::detect_missing_local_modules;
 pub use mocks::detect_mock_data;
+pub use named_returns::detect_unassigned_named_returns;
+pub use noops::detect_noop_statements;
+pub use params::detect_long_parameter_lists;
+pub use placeholder_constants::detect_placeholder_constants;
 pub use patterns::detect_forbidden_patterns;
+pub use promises::detect_floating_promises;
 pub use runner::Runner;
-pub use stubs::{detect_stub_functions, StubDetectionConfig};
+pub use schemas::detect_hollow_schemas;
+pub use scopes::apply_scopes;
+pub use stubs::{detect_stub_functions, CustomStubSignature, StubDetectionConfig};
 pub use suppress::{
-    collect_suppressions, filter_suppressed, parse_suppressions, SuppressedViolation, Suppression,
-    SuppressionType,
+    apply_downgrades, collect_directives, collect_suppressions, detect_stale_suppressions, filter_fingerprint_suppressed,
+    filter_suppressed, insert_suppression_comments, load_suppression_file, parse_directives, parse_suppressions, Directives,
+    Downgrade, DowngradedViolation, FingerprintSuppression, SuppressedViolation, Suppression,
+    SuppressionType, SUPPRESSION_FILE,
 };
-pub use symbols::{detect_missing_symbols, detect_missing_tests};
+pub(crate) use stdlib::{cache_dir as stdlib_cache_dir, runtime_version, warm_stdlib, StdlibLanguage};
+pub(crate) use suppress::{days_since_epoch, today};
+pub use symbols::{declaration_kind_to_symbol_kind, detect_missing_symbols, detect_missing_tests};
+pub use user_patterns::{compile_user_pattern, suspicious_pattern, PatternGuard, PatternMeter, PATTERN_SIZE_LIMIT};
 pub use todos::detect_hollow_todos;
-pub use types::{DetectionResult, Severity, Violation, ViolationRule};
+pub use truncated::detect_truncated_code;
+pub use types::{DetectionResult, ScanStats, ScoreCategory, Severity, Violation, ViolationRule, RULE_ALIASES};
+pub use unwraps::detect_unwrap_overuse;
+pub use vendored::{drop_vendored_files, is_vendored};
+pub use waivers::{
+    apply_waivers, waiver_days_left, waiver_entries, waiver_status, waiver_target, WaivedViolation,
+    WaiverEntry, WaiverStatus, DEFAULT_EXPIRY_WINDOW,
+};
+pub use wrappers::detect_trivial_wrappers;
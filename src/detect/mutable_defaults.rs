@@ -0,0 +1,141 @@
+//! Detection of Python parameters with mutable default values.
+//!
+//! `def add(item, items=[])` evaluates `[]` once, when the function is
+//! defined, so every call that appends to `items` changes the default the
+//! next call sees. Generated Python reproduces the pattern constantly. List,
+//! dict, and set literals and comprehensions are flagged, as are calls to
+//! mutable constructors (`set()`, `dict()`, `collections.defaultdict(list)`).
+//! Immutable defaults such as `()`, `frozenset()`, and `None` are not.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::analysis::ParameterDefault;
+use crate::contract::MutableDefaultsConfig;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Grammar node kinds of mutable literal defaults.
+const MUTABLE_LITERAL_KINDS: &[&str] = &[
+    "list",
+    "dictionary",
+    "set",
+    "list_comprehension",
+    "dictionary_comprehension",
+    "set_comprehension",
+];
+
+/// Whether a default value is mutable: a container literal, or a call to one
+/// of the mutable constructors, matched by its last dotted segment.
+fn is_mutable(default: &ParameterDefault, constructors: &HashSet<&str>) -> bool {
+    if MUTABLE_LITERAL_KINDS.contains(&default.value_kind.as_str()) {
+        return true;
+    }
+    if default.value_kind != "call" {
+        return false;
+    }
+    let callee = default.value.split('(').next().unwrap_or("").trim();
+    let name = callee.rsplit('.').next().unwrap_or(callee);
+    constructors.contains(name)
+}
+
+/// Flag Python parameters whose default value is mutable.
+pub fn detect_mutable_defaults<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &MutableDefaultsConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let constructors: HashSet<&str> = config.constructors().into_iter().collect();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if file_facts.language != "python" {
+            continue;
+        }
+        result.scanned += 1;
+
+        for default in &file_facts.parameter_defaults {
+            if !is_mutable(default, &constructors) {
+                continue;
+            }
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::MutableDefault,
+                    format!(
+                        "parameter '{}' of {} defaults to {}, which is created once and shared by every call; default to None and build it in the body",
+                        default.name, default.function, default.value
+                    ),
+                )
+                .at(path.to_string_lossy(), default.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(source: &str, config: &MutableDefaultsConfig) -> Vec<(usize, String)> {
+        test_support::violations("orders.py", source, ViolationRule::MutableDefault, |facts, files| {
+            detect_mutable_defaults(facts, files, config)
+        })
+        .into_iter()
+        .map(|v| (v.line, v.message.split('\'').nth(1).unwrap().to_string()))
+        .collect()
+    }
+
+    const SOURCE: &str = r#"import collections
+
+def add_item(item, items=[]):
+    items.append(item)
+    return items
+
+def merge(
+    base,
+    overrides: dict = {},
+    seen=set(),
+    index=collections.defaultdict(list),
+    squares=[n * n for n in range(3)],
+):
+    return {**base, **overrides}
+
+def lookup(key, default=None, tags=(), frozen=frozenset(), retries=3, name="x"):
+    return default
+
+class Repository:
+    def find(self, filters={}, *, limit: int = 10):
+        return []
+"#;
+
+    #[test]
+    fn test_mutable_defaults() {
+        let found = detect(SOURCE, &MutableDefaultsConfig::default());
+        let expected = [(3, "items"), (9, "overrides"), (10, "seen"), (11, "index"), (12, "squares"), (20, "filters")];
+        assert_eq!(
+            found,
+            expected.iter().map(|(l, n)| (*l, n.to_string())).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_constructors_replace_defaults() {
+        let config = MutableDefaultsConfig {
+            constructors: Some(vec!["frozenset".to_string()]),
+            ..Default::default()
+        };
+        let found: Vec<_> = detect(SOURCE, &config).into_iter().map(|(_, name)| name).collect();
+        // Literals are always mutable; only the constructor calls change
+        assert_eq!(found, vec!["items", "overrides", "squares", "frozen", "filters"]);
+    }
+}
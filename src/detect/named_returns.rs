@@ -0,0 +1,141 @@
+//! Detection of Go named results that are returned without being assigned.
+//!
+//! A Go function with named results can end in a bare `return`, which hands
+//! back whatever the results hold. A result no statement ever writes is
+//! always returned as its zero value: `(n int, err error)` whose body never
+//! sets `err` reports success on every path. Generated code produces this
+//! when the happy path is written and the failure handling is not. Results
+//! come from `FileFacts::unassigned_results`, read from the `FactsBundle`.
+
+use std::path::Path;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Flag Go named results that a bare `return` hands back unassigned.
+pub fn detect_unassigned_named_returns<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if file_facts.language != "go" {
+            continue;
+        }
+        result.scanned += 1;
+
+        for named in &file_facts.unassigned_results {
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::UnassignedNamedReturn,
+                    format!(
+                        "{} never assigns named result {} {}; the bare return on line {} always returns its zero value",
+                        named.function, named.name, named.type_text, named.return_line
+                    ),
+                )
+                .at(path.to_string_lossy(), named.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str) -> Vec<(usize, String)> {
+        test_support::detect(name, source, ViolationRule::UnassignedNamedReturn, detect_unassigned_named_returns)
+    }
+
+    #[test]
+    fn test_go_unassigned_named_return() {
+        let source = r#"package store
+
+func (s *Store) Count() (n int, err error) {
+	n = len(s.items)
+	return
+}
+
+func Parse(text string) (value int, ok bool) {
+	for _, r := range text {
+		value = value*10 + int(r-'0')
+	}
+	ok = true
+	return
+}
+
+func Explicit() (n int, err error) {
+	return 0, nil
+}
+
+func Blank() (_ int, err error) {
+	err = nil
+	return
+}
+"#;
+        let found = detect("store.go", source);
+        assert_eq!(
+            found,
+            vec![(
+                3,
+                "Count never assigns named result err error; the bare return on line 5 always returns its zero value"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_assignment_after_last_bare_return() {
+        let source = r#"package store
+
+func Load(path string) (data []byte, err error) {
+	if path == "" {
+		return
+	}
+	data, err = read(path)
+	return data, err
+}
+"#;
+        let found = detect("load.go", source);
+        let names: Vec<_> = found.iter().map(|(line, msg)| (*line, msg.split(';').next().unwrap())).collect();
+        assert_eq!(
+            names,
+            vec![
+                (3, "Load never assigns named result data []byte"),
+                (3, "Load never assigns named result err error"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deferred_closure_and_pointer_count_as_writes() {
+        let source = r#"package store
+
+func Close(f *File) (err error) {
+	defer func() {
+		if cerr := f.Close(); cerr != nil {
+			err = cerr
+		}
+	}()
+	return
+}
+
+func Decode(b []byte) (v Value, err error) {
+	err = json.Unmarshal(b, &v)
+	return
+}
+"#;
+        assert!(detect("close.go", source).is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+//! Detection of no-op statements that pad function bodies.
+//!
+//! Generated code sometimes fills a body with statements that do nothing
+//! (`_ = x`, a literal on its own line, `pass` next to real code, a stray
+//! `;`, a final `return;`) so it looks substantive. They also count toward
+//! the statement-count heuristics of other rules. Statements come from the
+//! body AST via `FileFacts::noop_statements`, read from the `FactsBundle`.
+
+use std::path::Path;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Flag standalone statements with no effect in Go, Python, and JavaScript/TypeScript.
+pub fn detect_noop_statements<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        for noop in &file_facts.noop_statements {
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::NoopStatement,
+                    format!("no-op statement `{}`: {}", noop.text, noop.kind.description()),
+                )
+                .at(path.to_string_lossy(), noop.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn detect(name: &str, source: &str) -> Vec<(usize, String)> {
+        test_support::detect(name, source, ViolationRule::NoopStatement, detect_noop_statements)
+    }
+
+    #[test]
+    fn test_go_noops() {
+        let source = r#"package svc
+
+func Process(items []string) {
+	count := len(items)
+	_ = count
+	_ = cfg.Timeout
+	_ = compute()
+	for _, item := range items {
+		handle(item)
+	}
+	return
+}
+
+func Total(items []int) (sum int) {
+	for _, i := range items {
+		sum += i
+	}
+	return
+}
+"#;
+        let found = detect("svc.go", source);
+        assert_eq!(
+            found,
+            vec![
+                (5, "no-op statement `_ = count`: blank assignment of a plain value".to_string()),
+                (6, "no-op statement `_ = cfg.Timeout`: blank assignment of a plain value".to_string()),
+                (11, "no-op statement `return`: bare return at the end of the function".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_python_noops() {
+        let source = r#""""Module docstring."""
+
+class Config:
+    """Settings."""
+    pass
+
+def load(path):
+    """Load a file."""
+    data = read(path)
+    0
+    None
+    "still loading"
+    pass
+    return
+
+def stub():
+    pass
+"#;
+        let found = detect("loader.py", source);
+        let lines: Vec<_> = found.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![5, 10, 11, 12, 13, 14], "{:?}", found);
+        assert!(found[1].1.contains("literal used as a statement"));
+        assert!(found[4].1.contains("pass next to other statements"));
+    }
+
+    #[test]
+    fn test_js_noops() {
+        let source = r#"function save(user) {
+  db.put(user);;
+  return;
+}
+
+function wait() {
+  for (;;) {
+    if (ready()) break;
+  }
+  while (poll());
+}
+
+const done = () => {
+  return;
+};
+"#;
+        let found = detect("save.js", source);
+        let lines: Vec<_> = found.iter().map(|(line, _)| *line).collect();
+        assert_eq!(lines, vec![2, 3], "{:?}", found);
+        assert!(found[0].1.contains("empty statement"));
+    }
+}
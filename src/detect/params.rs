@@ -0,0 +1,131 @@
+//! Detection of functions with suspiciously long parameter lists.
+//!
+//! A signature with eight positional arguments (often several of the same
+//! type) is easy to call with arguments swapped and usually means a missing
+//! options struct. Parameter counts come from the AST; receivers such as
+//! `self` are not counted.
+
+use std::path::Path;
+
+use crate::analysis::{analysis_extension, get_analyzer, AnalysisContext};
+use crate::contract::LongParameterListsConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Flag callables whose parameter count exceeds `max_params`.
+///
+/// Functions whose name or qualified name (`Builder.with_options`) matches
+/// the allowlist are exempt.
+pub fn detect_long_parameter_lists<P: AsRef<Path>>(
+    analysis_ctx: &AnalysisContext,
+    files: &[P],
+    config: &LongParameterListsConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let max_params = config.max_params();
+    let allowlist = build_globs("long_parameter_lists allowlist", &config.allowlist())?;
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let ext = analysis_extension(path);
+        if get_analyzer(ext).is_none() {
+            continue;
+        }
+
+        let facts = match analysis_ctx.analyze_file(path) {
+            Ok(facts) => facts,
+            Err(_) => continue,
+        };
+        result.scanned += 1;
+
+        for decl in facts.callables() {
+            let count = match decl.param_count {
+                Some(count) if count > max_params => count,
+                _ => continue,
+            };
+            if allowlist.is_match(&decl.name) || allowlist.is_match(decl.qualified_name()) {
+                continue;
+            }
+
+            result.add_violation(Violation {
+                rule: ViolationRule::TooManyParams,
+                message: format!(
+                    "function '{}' has {} parameters, exceeds maximum of {}; consider grouping them into a struct or options object",
+                    decl.qualified_name(),
+                    count,
+                    max_params
+                ),
+                file: Some(path.to_string_lossy().to_string()),
+                line: decl.span.start_line,
+                severity: Severity::Warning,
+            });
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_long_parameter_lists() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("service.go");
+        std::fs::write(
+            &file,
+            r#"package service
+
+func Create(a, b, c, d, e, f, g, h string) error { return nil }
+
+func NewService(a, b, c, d, e, f, g, h string) *Service { return nil }
+
+func (s *Service) Update(id int, name string) error { return nil }
+"#,
+        )
+        .unwrap();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let config = LongParameterListsConfig::default();
+        let result = detect_long_parameter_lists(&ctx, &[&file], &config).unwrap();
+
+        assert_eq!(result.violations.len(), 1);
+        let v = &result.violations[0];
+        assert_eq!(v.rule, ViolationRule::TooManyParams);
+        assert_eq!(v.line, 3);
+        assert!(v.message.contains("'Create' has 8 parameters"));
+    }
+
+    #[test]
+    fn test_custom_limit_and_allowlist() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("app.py");
+        std::fs::write(
+            &file,
+            "class Client:\n    def __init__(self, a, b, c):\n        pass\n\n    def send(self, a, b, c):\n        pass\n\ndef render_row(a, b, c):\n    pass\n",
+        )
+        .unwrap();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let config = LongParameterListsConfig {
+            enabled: true,
+            max_params: Some(2),
+            allowlist: Some(vec!["__init__".to_string(), "render_*".to_string()]),
+        };
+        let result = detect_long_parameter_lists(&ctx, &[&file], &config).unwrap();
+
+        assert_eq!(result.violations.len(), 1);
+        assert!(result.violations[0].message.contains("'send' has 3 parameters"));
+    }
+}
@@ -1,15 +1,21 @@
 //! Detection of forbidden patterns in code.
-
+//!
+//! A pattern naming a marker word (`TODO`, or any word of the contract's
+//! marker vocabulary) skips matches that only talk about markers, such as
+//! identifiers and pattern definitions. A match on a marker that references
+//! a ticket is reported at the tracked severity.
+//!
+//! Patterns are compiled and matched under the safeguards of `user_patterns`.
+
+use crate::analysis::MarkerVocabulary;
 use crate::contract::ForbiddenPattern;
 use regex::Regex;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
-use super::{DetectionResult, Violation, ViolationRule};
-
-/// Patterns that indicate TODO/FIXME markers - these need special context handling
-const TODO_LIKE_PATTERNS: &[&str] = &["TODO", "FIXME", "XXX", "HACK"];
+use super::user_patterns::{compile_user_pattern, PatternGuard};
+use super::{DetectionResult, Severity, Violation, ViolationRule};
 
 /// Pre-compiled pattern with metadata.
 struct CompiledPattern {
@@ -20,9 +26,14 @@ struct CompiledPattern {
 }
 
 /// Scan files for forbidden patterns defined in the contract.
+///
+/// Patterns that exceed their matching budget are disabled through `guard`.
 pub fn detect_forbidden_patterns<P: AsRef<Path>>(
     files: &[P],
     patterns: &[ForbiddenPattern],
+    markers: &MarkerVocabulary,
+    tracked_severity: Severity,
+    guard: &PatternGuard,
 ) -> anyhow::Result<DetectionResult> {
     let mut result = DetectionResult::new();
 
@@ -34,12 +45,10 @@ pub fn detect_forbidden_patterns<P: AsRef<Path>>(
     let compiled: Vec<CompiledPattern> = patterns
         .iter()
         .map(|p| {
-            let regex = Regex::new(&p.pattern)
+            let regex = compile_user_pattern(&p.pattern)
                 .map_err(|e| anyhow::anyhow!("compiling pattern {:?}: {}", p.pattern, e))?;
             // Check if this is a TODO-like pattern that needs special handling
-            let is_todo_like = TODO_LIKE_PATTERNS
-                .iter()
-                .any(|t| p.pattern.to_uppercase().contains(t));
+            let is_todo_like = markers.names_marker(&p.pattern);
             Ok(CompiledPattern {
                 regex,
                 description: p.description.clone(),
@@ -50,8 +59,7 @@ pub fn detect_forbidden_patterns<P: AsRef<Path>>(
 
     // Scan each file
     for file in files {
-        let violations = scan_file_for_patterns(file.as_ref(), &compiled)?;
-        result.violations.extend(violations);
+        scan_file_for_patterns(file.as_ref(), &compiled, markers, tracked_severity, guard, &mut result)?;
         result.scanned += 1;
     }
 
@@ -62,49 +70,65 @@ pub fn detect_forbidden_patterns<P: AsRef<Path>>(
 fn scan_file_for_patterns(
     file_path: &Path,
     patterns: &[CompiledPattern],
-) -> anyhow::Result<Vec<Violation>> {
+    markers: &MarkerVocabulary,
+    tracked_severity: Severity,
+    guard: &PatternGuard,
+    result: &mut DetectionResult,
+) -> anyhow::Result<()> {
     let file = File::open(file_path)?;
     let reader = BufReader::new(file);
-    let mut violations = Vec::new();
     let file_str = file_path.to_string_lossy().to_string();
+    let mut meters: Vec<_> = patterns
+        .iter()
+        .map(|p| guard.meter("forbidden_patterns", &p.regex, &file_str))
+        .collect();
 
     for (line_num, line_result) in reader.lines().enumerate() {
         let line = line_result?;
         let line_number = line_num + 1;
 
-        for p in patterns {
-            // Find all matches with their positions
-            for mat in p.regex.find_iter(&line) {
+        for (p, meter) in patterns.iter().zip(meters.iter_mut()) {
+            // Find all matches with their positions; none once over budget
+            let Some(matches) = meter.find(&line) else {
+                continue;
+            };
+            for (start, end) in matches {
                 // Skip if match is inside a string literal
-                if is_inside_string_literal(&line, mat.start()) {
+                if is_inside_string_literal(&line, start) {
                     continue;
                 }
 
                 // For TODO-like patterns, apply additional context filtering
-                if p.is_todo_like {
-                    if should_skip_todo_pattern(&line, file_path, mat.start(), mat.end()) {
-                        continue;
-                    }
+                if p.is_todo_like && should_skip_todo_pattern(&line, file_path, start, end) {
+                    continue;
                 }
 
-                let msg = if let Some(desc) = &p.description {
+                let mut msg = if let Some(desc) = &p.description {
                     format!("forbidden pattern {:?} found: {}", p.regex.as_str(), desc)
                 } else {
                     format!("forbidden pattern {:?} found", p.regex.as_str())
                 };
 
-                violations.push(Violation {
-                    rule: ViolationRule::ForbiddenPattern,
-                    message: msg,
-                    file: file_str.clone(),
-                    line: line_number,
-                    severity: ViolationRule::ForbiddenPattern.default_severity(),
-                });
+                // A match on a marker keeps the marker for the report
+                let marker = markers.marker_starting_at(&line, start);
+                let mut severity = ViolationRule::ForbiddenPattern.default_severity();
+                if let Some(ticket) = marker.as_ref().and_then(|m| m.ticket.as_ref()) {
+                    msg.push_str(&format!(", tracked in {}", ticket));
+                    severity = tracked_severity;
+                }
+
+                let violation = Violation::new(ViolationRule::ForbiddenPattern, msg)
+                    .at(file_str.clone(), line_number)
+                    .with_severity(severity);
+                match marker {
+                    Some(marker) => result.add_marker_violation(violation, marker),
+                    None => result.add_violation(violation),
+                }
             }
         }
     }
 
-    Ok(violations)
+    Ok(())
 }
 
 /// Determine if a TODO-like pattern match should be skipped based on context.
@@ -141,10 +165,10 @@ fn should_skip_todo_pattern(line: &str, file_path: &Path, start: usize, end: usi
     }
 
     // Skip if line is a regex pattern definition (common in detection code)
-    if upper.contains("REGEX") || upper.contains("PATTERN") {
-        if trimmed.contains("Regex::new") || trimmed.contains("r\"") || trimmed.contains("r#\"") {
-            return true;
-        }
+    if (upper.contains("REGEX") || upper.contains("PATTERN"))
+        && (trimmed.contains("Regex::new") || trimmed.contains("r\"") || trimmed.contains("r#\""))
+    {
+        return true;
     }
 
     // Skip lazy_static pattern definitions
@@ -313,7 +337,7 @@ func main() {{
             description: Some("Remove TODO comments".to_string()),
         }];
 
-        let result = detect_forbidden_patterns(&[&file_path], &patterns).unwrap();
+        let result = detect_forbidden_patterns(&[&file_path], &patterns, &MarkerVocabulary::default(), Severity::Info, &PatternGuard::default()).unwrap();
         assert_eq!(result.violations.len(), 1);
         assert_eq!(result.violations[0].rule, ViolationRule::ForbiddenPattern);
         assert_eq!(result.violations[0].line, 3);
@@ -341,7 +365,7 @@ var msg = "{}: this is in a string"
             description: None,
         }];
 
-        let result = detect_forbidden_patterns(&[&file_path], &patterns).unwrap();
+        let result = detect_forbidden_patterns(&[&file_path], &patterns, &MarkerVocabulary::default(), Severity::Info, &PatternGuard::default()).unwrap();
         // Should only find the one in the comment, not the one in the string
         assert_eq!(result.violations.len(), 1);
         assert_eq!(result.violations[0].line, 3);
@@ -361,4 +385,30 @@ var msg = "{}: this is in a string"
         // Escaped quote
         assert!(is_inside_string_literal(r#""hello \" world""#, 10));
     }
+
+    #[test]
+    fn test_marker_matches_carry_the_marker() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("queue.go");
+        // hollowcheck:ignore-file forbidden_pattern - Test fixture
+        let fixme = "FIXME";
+        std::fs::write(
+            &file_path,
+            format!("// {0}(OPS-31): drain on shutdown\n// {0}: drain on shutdown\nvar {0}_COUNT = 2\n", fixme),
+        )
+        .unwrap();
+
+        let patterns = vec![ForbiddenPattern {
+            pattern: fixme.to_string(),
+            description: None,
+        }];
+        let markers = MarkerVocabulary::new(&[], Some(r"OPS-\d+")).unwrap();
+        let result = detect_forbidden_patterns(&[&file_path], &patterns, &markers, Severity::Info, &PatternGuard::default()).unwrap();
+
+        let found: Vec<_> = result.violations.iter().map(|v| (v.line, v.severity)).collect();
+        assert_eq!(found, vec![(1, Severity::Info), (2, Severity::Warning)]);
+        assert!(result.violations[0].message.ends_with(", tracked in OPS-31"));
+        let marker = &result.markers[&result.violations[1].key()];
+        assert_eq!((marker.kind.as_str(), marker.text.as_str(), marker.ticket.as_ref()), ("FIXME", "drain on shutdown", None));
+    }
 }
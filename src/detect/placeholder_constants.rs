@@ -0,0 +1,302 @@
+//! Detection of constant groups whose values were never decided.
+//!
+//! `A = 0; B = 0; C = 0` compiles, and so does an enum numbered `1, 2, 3`
+//! under a `// TODO: real codes` comment, but neither models anything yet.
+//! Generated data-model code leaves these behind when the names were known
+//! and the values were not. A group is flagged when every member has the
+//! same literal value, or, next to a TODO-style marker, when the values
+//! count up from 0 or 1 or are all placeholders (`""`, `"TODO"`, `"TBD"`).
+//! Groups come from `FileFacts::constant_groups`, read from the
+//! `FactsBundle`; members with implicit or non-literal values (Go `iota`,
+//! `1 << 2`, another constant) leave their group unchecked.
+
+use std::path::Path;
+
+use crate::analysis::ConstantGroup;
+use crate::contract::PlaceholderConstantsConfig;
+
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
+
+/// String values that stand in for a real one.
+const PLACEHOLDER_STRINGS: &[&str] = &["", "todo", "tbd", "fixme", "xxx", "placeholder", "changeme", "change_me"];
+
+/// A member value written as a literal.
+#[derive(Debug, Clone, PartialEq)]
+enum Literal {
+    Integer(i128),
+    /// String contents, without quotes.
+    String(String),
+    /// Any other literal (floats), as written.
+    Other(String),
+}
+
+/// Parse a value expression as a literal; `None` for anything else.
+fn literal(text: &str) -> Option<Literal> {
+    let text = text.trim();
+    if let Some(quote) = text.chars().next().filter(|c| matches!(c, '"' | '`' | '\'')) {
+        let inner = text.strip_prefix(quote)?.strip_suffix(quote)?;
+        return Some(Literal::String(inner.to_string()));
+    }
+    if let Some(n) = integer(text) {
+        return Some(Literal::Integer(n));
+    }
+    let float = text.trim_end_matches(['f', 'F']).replace('_', "");
+    float.parse::<f64>().ok().map(|_| Literal::Other(text.to_string()))
+}
+
+/// Parse an integer literal, with an optional sign, base prefix, digit
+/// separators, and type suffix (`0x1F`, `1_000u32`, `10UL`, `-1`).
+fn integer(text: &str) -> Option<i128> {
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest.trim_start()),
+        None => (false, text),
+    };
+    let lower = digits.to_ascii_lowercase().replace(['_', '\''], "");
+    let (radix, body) = match lower.get(..2) {
+        Some("0x") => (16, &lower[2..]),
+        Some("0o") => (8, &lower[2..]),
+        Some("0b") => (2, &lower[2..]),
+        _ => (10, lower.as_str()),
+    };
+    let end = body.find(|c: char| !c.is_digit(radix)).unwrap_or(body.len());
+    let (number, suffix) = body.split_at(end);
+    let valid_suffix = suffix.is_empty()
+        || suffix.trim_start_matches(['u', 'l']).is_empty()
+        || (suffix.starts_with(['u', 'i']) && suffix[1..].parse::<u32>().is_ok())
+        || suffix == "usize"
+        || suffix == "isize";
+    if number.is_empty() || !valid_suffix {
+        return None;
+    }
+    let value = i128::from_str_radix(number, radix).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// Why a group's values look like placeholders.
+fn placeholder_reason(group: &ConstantGroup, values: &[Literal]) -> Option<String> {
+    let first = &values[0];
+    if values.iter().all(|v| v == first) {
+        let shown = group.members[0].value.as_deref().unwrap_or_default();
+        return Some(format!("gives all {} members the same value {}", values.len(), shown));
+    }
+    if !group.has_marker {
+        return None;
+    }
+
+    let integers: Vec<i128> = values
+        .iter()
+        .filter_map(|v| match v {
+            Literal::Integer(n) => Some(*n),
+            _ => None,
+        })
+        .collect();
+    if integers.len() == values.len()
+        && matches!(integers[0], 0 | 1)
+        && integers.windows(2).all(|w| w[1] == w[0] + 1)
+    {
+        return Some(format!(
+            "numbers its {} members {} to {} next to a TODO marker",
+            values.len(),
+            integers[0],
+            integers[integers.len() - 1]
+        ));
+    }
+
+    let all_placeholders = values
+        .iter()
+        .all(|v| matches!(v, Literal::String(s) if PLACEHOLDER_STRINGS.contains(&s.trim().to_lowercase().as_str())));
+    all_placeholders.then(|| "holds only placeholder strings next to a TODO marker".to_string())
+}
+
+/// Flag constant groups with identical values, or sequential or placeholder
+/// values next to a TODO marker.
+pub fn detect_placeholder_constants<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &PlaceholderConstantsConfig,
+) -> anyhow::Result<DetectionResult> {
+    let severity = config
+        .severity()
+        .parse::<Severity>()
+        .map_err(|e| anyhow::anyhow!("invalid placeholder_constants severity: {}", e))?;
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        for group in &file_facts.constant_groups {
+            if group.members.len() < config.min_members() {
+                continue;
+            }
+            let Some(values) = group
+                .members
+                .iter()
+                .map(|m| m.value.as_deref().and_then(literal))
+                .collect::<Option<Vec<Literal>>>()
+            else {
+                continue;
+            };
+            let Some(reason) = placeholder_reason(group, &values) else {
+                continue;
+            };
+
+            let mut names: Vec<&str> = group.members.iter().take(3).map(|m| m.name.as_str()).collect();
+            if group.members.len() > 3 {
+                names.push("...");
+            }
+            let label = if group.name.is_empty() {
+                "constant group".to_string()
+            } else {
+                format!("constant group {:?}", group.name)
+            };
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::PlaceholderConstants,
+                    format!("{} ({}) {}", label, names.join(", "), reason),
+                )
+                .at(path.to_string_lossy(), group.span.start_line)
+                .with_severity(severity),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::detect::test_support;
+
+    fn violations(name: &str, source: &str, config: &PlaceholderConstantsConfig) -> Vec<Violation> {
+        test_support::violations(name, source, ViolationRule::PlaceholderConstants, |facts, files| {
+            detect_placeholder_constants(facts, files, config)
+        })
+    }
+
+    fn detect(name: &str, source: &str) -> Vec<(usize, String)> {
+        violations(name, source, &PlaceholderConstantsConfig::default())
+            .into_iter()
+            .map(|v| (v.line, v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_go_const_blocks() {
+        let source = r#"package billing
+
+const (
+	PlanFree = 0
+	PlanPro
+	PlanEnterprise
+)
+
+// TODO: use the codes from the billing provider
+const (
+	StatusActive   = 1
+	StatusPastDue  = 2
+	StatusCanceled = 3
+)
+
+const (
+	KindA Kind = iota
+	KindB
+	KindC
+)
+
+const (
+	RegionUS = 1
+	RegionEU = 2
+	RegionAP = 3
+)
+
+const (
+	Timeout = 30
+	Retries = 3
+	Port    = 8080
+)
+"#;
+        let found = detect("billing.go", source);
+        assert_eq!(
+            found,
+            vec![
+                (3, "constant group (PlanFree, PlanPro, PlanEnterprise) gives all 3 members the same value 0".to_string()),
+                (
+                    10,
+                    "constant group (StatusActive, StatusPastDue, StatusCanceled) numbers its 3 members 1 to 3 next to a TODO marker"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_enums_across_languages() {
+        let cases = [
+            ("codes.rs", "enum Code {\n    Ok = 0,\n    NotFound = 0,\n    Internal = 0,\n}\n", 1),
+            ("limits.rs", "const MAX_USERS: u32 = 10;\nconst MAX_TEAMS: u32 = 10;\n// note\nconst MAX_ORGS: u32 = 10;\n", 1),
+            ("codes.c", "typedef enum {\n    CODE_OK = 0, // TODO\n    CODE_WARN = 1,\n    CODE_FAIL = 2,\n} code_t;\n", 1),
+            ("codes.ts", "export enum Tier {\n  Free = \"TODO\",\n  Pro = \"TBD\",\n  Team = \"\",\n}\n// TODO: tiers\n", 0),
+            ("codes.ts", "// FIXME: placeholder names\nexport enum Tier {\n  Free = \"TODO\",\n  Pro = \"TBD\",\n  Team = \"\",\n}\n", 2),
+            ("Codes.swift", "enum Code: Int {\n    case ok = 7, warn = 7\n    case fail = 7\n}\n", 1),
+        ];
+        for (name, source, line) in cases {
+            let found = detect(name, source);
+            if line == 0 {
+                assert!(found.is_empty(), "{}: {:?}", name, found);
+            } else {
+                assert_eq!(found.len(), 1, "{}: {:?}", name, found);
+                assert_eq!(found[0].0, line, "{}: {:?}", name, found);
+            }
+        }
+
+        // Implicit values and real mappings are left alone
+        let quiet = [
+            ("state.rs", "// TODO: more states\nenum State {\n    Idle,\n    Running,\n    Done,\n}\n"),
+            ("codes.c", "enum code { CODE_OK = 0, CODE_WARN, CODE_FAIL };\n"),
+            ("http.ts", "enum Status {\n  Ok = 200,\n  NotFound = 404,\n  Error = 500,\n}\n"),
+            ("flags.rs", "const A: bool = false;\nconst B: bool = false;\nconst C: bool = false;\n"),
+        ];
+        for (name, source) in quiet {
+            assert!(detect(name, source).is_empty(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_min_members_and_severity() {
+        let source = "enum Pair {\n    Left = 0,\n    Right = 0,\n}\n";
+        assert!(detect("pair.rs", source).is_empty());
+
+        let config = PlaceholderConstantsConfig {
+            min_members: Some(2),
+            severity: Some("warning".to_string()),
+            ..Default::default()
+        };
+        let found = violations("pair.rs", source, &config);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].severity, Severity::Warning);
+        assert_eq!(
+            found[0].message,
+            "constant group \"Pair\" (Left, Right) gives all 2 members the same value 0"
+        );
+    }
+
+    #[test]
+    fn test_integer_literals() {
+        assert_eq!(integer("0x1F"), Some(31));
+        assert_eq!(integer("1_000u32"), Some(1000));
+        assert_eq!(integer("10UL"), Some(10));
+        assert_eq!(integer("-1"), Some(-1));
+        assert_eq!(integer("1 << 2"), None);
+        assert_eq!(integer("iota"), None);
+        assert_eq!(literal("false"), None);
+    }
+}
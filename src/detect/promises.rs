@@ -0,0 +1,185 @@
+//! Detection of floating promises in JavaScript/TypeScript.
+//!
+//! A promise that is created but never awaited, returned, or chained runs
+//! detached: its rejection goes unhandled and callers race its completion.
+//! This rule flags bare call statements whose callee is a local `async`
+//! function, or whose name suggests it returns a promise.
+
+use std::collections::HashSet;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use rayon::prelude::*;
+
+use crate::analysis::{analysis_extension, get_analyzer, FileFacts};
+
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// File extensions this rule applies to.
+const PROMISE_EXTENSIONS: &[&str] = &["ts", "tsx", "mts", "js", "jsx", "mjs"];
+
+/// Returns true if the callee name suggests it returns a promise.
+fn is_promise_returning_name(name: &str) -> bool {
+    name.ends_with("Async") || name == "fetch"
+}
+
+/// Get the final identifier of a callee (`this.client.save` -> `save`).
+fn callee_name(callee: &str) -> &str {
+    callee
+        .rsplit('.')
+        .next()
+        .unwrap_or(callee)
+        .trim_start_matches('#')
+}
+
+/// Find floating promises in a single file's facts.
+fn check_facts(facts: &FileFacts, file: &Path) -> Vec<Violation> {
+    let async_names: HashSet<&str> = facts
+        .callables()
+        .filter(|d| d.is_async)
+        .map(|d| d.name.as_str())
+        .collect();
+
+    facts
+        .discarded_calls
+        .iter()
+        .filter_map(|call| {
+            // Calls on the result of another call (`a().b()`) are chains, not local functions
+            if call.callee.contains('(') {
+                return None;
+            }
+            let name = callee_name(&call.callee);
+            let reason = if async_names.contains(name) {
+                "async function"
+            } else if is_promise_returning_name(name) {
+                "promise-returning call"
+            } else {
+                return None;
+            };
+
+            Some(Violation {
+                rule: ViolationRule::FloatingPromise,
+                message: format!(
+                    "{} {:?} is called without await, return, or .then/.catch; its result is never observed",
+                    reason, call.callee
+                ),
+                file: Some(file.to_string_lossy().to_string()),
+                line: call.span.start_line,
+                severity: Severity::Warning,
+            })
+        })
+        .collect()
+}
+
+/// Detect floating promises in JavaScript/TypeScript files.
+///
+/// Files are processed in parallel; other languages are ignored.
+pub fn detect_floating_promises<P: AsRef<Path> + Sync>(
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let scanned = AtomicUsize::new(0);
+
+    let file_results: Vec<Vec<Violation>> = files
+        .par_iter()
+        .filter_map(|file| {
+            let path = file.as_ref();
+            let ext = analysis_extension(path);
+            if !PROMISE_EXTENSIONS.contains(&ext) {
+                return None;
+            }
+
+            let analyzer = get_analyzer(ext)?;
+            let source = std::fs::read(path).ok()?;
+            let parsed = analyzer.parse(path, &source).ok()?;
+            let facts = analyzer.extract_facts(&parsed).ok()?;
+
+            scanned.fetch_add(1, Ordering::Relaxed);
+            Some(check_facts(&facts, path))
+        })
+        .collect();
+
+    let mut result = DetectionResult::new();
+    result.scanned = scanned.load(Ordering::Relaxed);
+    for violations in file_results {
+        for v in violations {
+            result.add_violation(v);
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_detect_floating_async_call() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("service.ts");
+        std::fs::write(
+            &file,
+            r#"
+async function saveUser(id: string): Promise<void> {
+    await db.insert(id);
+}
+
+export async function handler(id: string) {
+    saveUser(id);
+    await saveUser(id);
+    void saveUser(id);
+    saveUser(id).catch(console.error);
+    return saveUser(id);
+}
+"#,
+        )
+        .unwrap();
+
+        let result = detect_floating_promises(&[&file]).unwrap();
+        assert_eq!(result.violations.len(), 1);
+        let v = &result.violations[0];
+        assert_eq!(v.rule, ViolationRule::FloatingPromise);
+        assert_eq!(v.line, 7);
+        assert!(v.message.contains("saveUser"));
+    }
+
+    #[test]
+    fn test_detect_naming_heuristic_js() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("client.js");
+        std::fs::write(
+            &file,
+            r#"
+function refresh() {
+    this.loadAsync();
+    fetch("/api/ping");
+    render();
+}
+"#,
+        )
+        .unwrap();
+
+        let result = detect_floating_promises(&[&file]).unwrap();
+        assert_eq!(result.violations.len(), 2);
+        assert!(result.violations.iter().any(|v| v.message.contains("this.loadAsync")));
+        assert!(result.violations.iter().any(|v| v.message.contains("fetch")));
+    }
+
+    #[test]
+    fn test_ignores_other_languages() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("main.py");
+        std::fs::write(&file, "async def f():\n    pass\n\ndef g():\n    f()\n").unwrap();
+
+        let result = detect_floating_promises(&[&file]).unwrap();
+        assert!(result.violations.is_empty());
+        assert_eq!(result.scanned, 0);
+    }
+}
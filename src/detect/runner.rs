@@ -8,23 +8,42 @@ use rayon::prelude::*;
 
 use crate::analysis::AnalysisContext;
 use crate::contract::Contract;
+use crate::discovery::{FileClass, FileDiscovery};
+use crate::workspace::find_workspace;
 
 use super::{
-    collect_suppressions, detect_forbidden_patterns, detect_god_objects,
-    detect_hallucinated_dependencies, detect_hollow_todos, detect_low_complexity,
-    detect_missing_files, detect_missing_symbols, detect_missing_tests, detect_mock_data,
-    detect_stub_functions, filter_suppressed, DetectionResult, GodObjectConfig,
-    StubDetectionConfig,
+    apply_downgrades, apply_scopes, collect_directives, detect_bad_headers, detect_builtin_shadowing, detect_doc_drift, detect_empty_handlers,
+    apply_waivers, detect_async_without_await, detect_case_collisions, detect_deprecated_usage, detect_floating_promises, detect_hollow_ci_configs, detect_hollow_config, detect_hollow_containers, detect_hollow_schemas, detect_forbidden_files, detect_forbidden_patterns,
+    detect_force_unwraps, detect_god_objects_outside, detect_hallucinated_dependencies, detect_hollow_todos,
+    detect_insufficient_implementation, detect_long_parameter_lists, detect_low_complexity,
+    detect_missing_definitions, detect_missing_files, detect_missing_local_modules, detect_missing_symbols, detect_missing_tests, detect_mock_data, detect_mutable_defaults,
+    detect_truncated_code,
+    detect_noop_statements,
+    detect_inconsistent_error_handling, detect_redundant_error_logging, detect_repeated_literals, detect_stub_functions, detect_trivial_wrappers, detect_unhandled_enum_cases,
+    detect_comment_ratio, detect_doc_coverage, detect_stale_suppressions, detect_environment_mismatch, detect_placeholder_constants, detect_premature_abstraction, detect_unimplemented_interfaces, detect_unassigned_named_returns, detect_unused_context, detect_unwrap_overuse, detect_weak_crypto,
+    drop_generated_files, enforce_analysis_budget, estimate_effort, filter_fingerprint_suppressed,
+    filter_generated_violations, filter_suppressed, find_generated_files, fingerprint_violations, scope_edited_violations, isolate_analysis_failures, load_suppression_file, DetectionResult, FactKind, FactsBundle,
+    GodObjectConfig, PatternGuard, Severity, StubDetectionConfig, ViolationRule, ESTIMATION_FACTS, SUPPRESSION_FILE,
 };
+use super::suppress::today;
 
 /// Progress callback type for reporting file processing progress.
 pub type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
 
+/// Callback type for reporting each file as the per-file rules finish it.
+pub type FileCallback = Arc<dyn Fn(&Path, usize, usize, usize) + Send + Sync>;
+
 /// Executes all detection checks against a set of files.
 pub struct Runner {
     base_dir: PathBuf,
     skip_registry_check: bool,
+    explain_dependencies: bool,
+    default_excludes: bool,
+    only_rules: Vec<ViolationRule>,
+    skip_rules: Vec<ViolationRule>,
     progress_callback: Option<ProgressCallback>,
+    dependency_progress: Option<ProgressCallback>,
+    file_callback: Option<FileCallback>,
 }
 
 impl Runner {
@@ -33,7 +52,13 @@ impl Runner {
         Self {
             base_dir: base_dir.as_ref().to_path_buf(),
             skip_registry_check: false,
+            explain_dependencies: false,
+            default_excludes: true,
+            only_rules: Vec::new(),
+            skip_rules: Vec::new(),
             progress_callback: None,
+            dependency_progress: None,
+            file_callback: None,
         }
     }
 
@@ -43,6 +68,36 @@ impl Runner {
         self
     }
 
+    /// Run only these rules, where the contract enables them. Default: every rule
+    pub fn only_rules(mut self, rules: &[ViolationRule]) -> Self {
+        self.only_rules = rules.to_vec();
+        self
+    }
+
+    /// Do not run these rules, even where the contract enables them.
+    pub fn skip_rules(mut self, rules: &[ViolationRule]) -> Self {
+        self.skip_rules = rules.to_vec();
+        self
+    }
+
+    /// Whether `rule` is selected by `only_rules` and `skip_rules`.
+    fn runs(&self, rule: ViolationRule) -> bool {
+        (self.only_rules.is_empty() || self.only_rules.contains(&rule)) && !self.skip_rules.contains(&rule)
+    }
+
+    /// Set whether to trace the dependency pipeline for each hallucinated dependency.
+    pub fn explain_dependencies(mut self, explain: bool) -> Self {
+        self.explain_dependencies = explain;
+        self
+    }
+
+    /// Set whether files in dependency and build directories (the contract's
+    /// `default_excludes`) are dropped before any rule runs. Default: true
+    pub fn default_excludes(mut self, enabled: bool) -> Self {
+        self.default_excludes = enabled;
+        self
+    }
+
     /// Set a progress callback that will be called as files are processed.
     /// The callback receives (current_count, total_count).
     pub fn with_progress<F>(mut self, callback: F) -> Self
@@ -53,23 +108,256 @@ impl Runner {
         self
     }
 
+    /// Set a callback called after each batch of registry lookups.
+    /// The callback receives (checked_packages, total_packages).
+    pub fn with_dependency_progress<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(usize, usize) + Send + Sync + 'static,
+    {
+        self.dependency_progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Set a callback called as the per-file rules finish each file.
+    /// The callback receives (file, text_rule_violations, current_count, total_count);
+    /// the violations are those of the per-file text rules only, not the file's total.
+    pub fn with_file_callback<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&Path, usize, usize, usize) + Send + Sync + 'static,
+    {
+        self.file_callback = Some(Arc::new(callback));
+        self
+    }
+
     /// Run all detection checks defined in the contract.
     pub fn run(&self, files: &[PathBuf], contract: &Contract) -> anyhow::Result<DetectionResult> {
         let mut result = DetectionResult::new();
+        result.scan_root = Some(self.base_dir.to_string_lossy().to_string());
+        result.workspace_root =
+            find_workspace(&self.base_dir).map(|ws| ws.root.to_string_lossy().to_string());
+
+        // Route files to analyzers by language override and classify them:
+        // vendored and language-filtered files are dropped, Dockerfiles and
+        // compose files only go through the container checks, and schemas
+        // only through the schema checks
+        let classified = FileDiscovery::new(&self.base_dir, contract)
+            .default_excludes(self.default_excludes)
+            .classify(files);
+        let of_class = |class: FileClass| -> Vec<PathBuf> {
+            classified.iter().filter(|f| f.class == class).map(|f| f.path.clone()).collect()
+        };
+        let dropped = of_class(FileClass::Vendored).len();
+        if dropped > 0 && std::env::var("HOLLOWCHECK_DEBUG").is_ok() {
+            eprintln!("[debug] Skipped {} files in dependency and build directories", dropped);
+        }
+        result.language_excluded = of_class(FileClass::LanguageExcluded).len();
+        let container_files = of_class(FileClass::Container);
+        let schema_files = of_class(FileClass::Schema);
+        let files = of_class(FileClass::Source);
+
+        // Create analysis context for AST-backed detection
+        let budget_config = contract.analysis_budget.clone().unwrap_or_default();
+        let markers_config = contract.markers.clone().unwrap_or_default();
+        let markers = markers_config.vocabulary()?;
+        let tracked_severity: Severity = markers_config
+            .tracked_severity()
+            .parse()
+            .map_err(|e| anyhow::anyhow!("invalid markers tracked_severity: {}", e))?;
+        let analysis_ctx = AnalysisContext::new(&self.base_dir)
+            .with_max_body_nodes(budget_config.max_body_nodes())
+            .with_marker_vocabulary(markers.clone());
+
+        // Parse very large files up front under a time budget; files over it
+        // are reported and skipped by every rule
+        let (files, budget_result) = enforce_analysis_budget(&analysis_ctx, &files, &budget_config);
+        result.merge(budget_result);
+
+        // Analyze every file once with crashes contained to the file; files
+        // that crash are reported and skipped by every rule
+        let (files, isolation_result) = isolate_analysis_failures(&analysis_ctx, &files);
+        result.merge(isolation_result);
+
+        // Generated files are skipped, or only checked by the rules the contract keeps for them
+        let generated_config = contract.generated_files.clone().filter(|c| c.is_enabled());
+        // Generated files edited by hand since are checked outside their generated blocks
+        let generated = match &generated_config {
+            Some(config) => find_generated_files(&self.base_dir, &analysis_ctx, &files, config),
+            None => Default::default(),
+        };
+        generated.mask_blocks(&analysis_ctx);
+        result.generated = generated.pristine.len();
+        result.generated_modified = generated.modified.len();
+        let generated_rules = generated_config.map(|c| c.rules()).unwrap_or_default();
+        let files = if generated_rules.is_empty() {
+            drop_generated_files(&self.base_dir, files, &generated.pristine)
+        } else {
+            files
+        };
+        let files = files.as_slice();
+
+        // Requirements on files in excluded languages are skipped; the CLI
+        // reports them as contract warnings
+        let targets_excluded = |file: &str| contract.is_path_language_excluded(Path::new(file));
+        let required_symbols: Vec<_> = contract
+            .required_symbols
+            .iter()
+            .filter(|s| self.runs(ViolationRule::MissingSymbol) && !targets_excluded(&s.file))
+            .cloned()
+            .collect();
+        let complexity: Vec<_> = contract
+            .complexity
+            .iter()
+            .filter(|c| self.runs(ViolationRule::LowComplexity) && !c.file.as_deref().is_some_and(targets_excluded))
+            .cloned()
+            .collect();
+        let required_tests: Vec<_> = contract
+            .required_tests
+            .iter()
+            .filter(|t| self.runs(ViolationRule::MissingTest) && !t.file.as_deref().is_some_and(targets_excluded))
+            .cloned()
+            .collect();
+        let builtins_config = contract.builtin_shadowing.clone().unwrap_or_default();
+        let crypto_config = contract.weak_crypto.clone().unwrap_or_default();
+        let noop_config = contract.noop_statements.clone().unwrap_or_default();
+        let context_config = contract.unused_context.clone().unwrap_or_default();
+        let named_returns_config = contract.unassigned_named_returns.clone().unwrap_or_default();
+        let constants_config = contract.placeholder_constants.clone().unwrap_or_default();
+        let definitions_config = contract.missing_definitions.clone().unwrap_or_default();
+        let deprecated_config = contract.deprecated_usage.clone().unwrap_or_default();
+        let unwrap_config = contract.unwrap_overuse.clone().unwrap_or_default();
+        let force_unwrap_config = contract.force_unwraps.clone().unwrap_or_default();
+        let local_modules_config = contract.missing_local_modules.clone().unwrap_or_default();
+        let error_logging_config = contract.redundant_error_logging.clone().unwrap_or_default();
+        let error_handling_config = contract.inconsistent_error_handling.clone().unwrap_or_default();
+        let async_config = contract.async_without_await.clone().unwrap_or_default();
+        let mutable_defaults_config = contract.mutable_defaults.clone().unwrap_or_default();
+        let enum_config = contract
+            .unhandled_enum_cases
+            .clone()
+            .filter(|c| c.is_enabled() && self.runs(ViolationRule::UnhandledEnumCases));
+        let wrappers_config = contract
+            .trivial_wrappers
+            .clone()
+            .filter(|c| c.is_enabled() && self.runs(ViolationRule::TrivialWrapper));
+        let ratio_config = contract
+            .comment_ratio
+            .clone()
+            .filter(|c| c.is_enabled() && self.runs(ViolationRule::CommentRatio));
+        let doc_coverage_config = contract
+            .doc_coverage
+            .clone()
+            .filter(|c| c.is_enabled() && self.runs(ViolationRule::DocCoverage));
+        let unused_suppressions_config = contract
+            .unused_suppressions
+            .clone()
+            .filter(|c| c.is_enabled() && self.runs(ViolationRule::UnusedSuppression));
+        let literals_config = contract
+            .repeated_literals
+            .as_ref()
+            .filter(|_| self.runs(ViolationRule::RepeatedLiteral));
+        let interfaces_config = contract
+            .unimplemented_interfaces
+            .as_ref()
+            .filter(|_| self.runs(ViolationRule::UnimplementedInterface));
+        let abstraction_config = contract
+            .premature_abstraction
+            .as_ref()
+            .filter(|_| self.runs(ViolationRule::PrematureAbstraction));
+        let header_config = contract.detect_header.as_ref().filter(|_| self.runs(ViolationRule::BadHeader));
+        let doc_config = contract.doc_drift.as_ref().filter(|_| self.runs(ViolationRule::DocDrift));
+
+        // Compute the facts the enabled rules read, once for all of them
+        let fact_rules: Vec<ViolationRule> = [
+            (!required_symbols.is_empty(), ViolationRule::MissingSymbol),
+            (!complexity.is_empty(), ViolationRule::LowComplexity),
+            (!required_tests.is_empty(), ViolationRule::MissingTest),
+            (doc_config.is_some_and(|c| c.is_enabled()), ViolationRule::DocDrift),
+            (builtins_config.is_enabled(), ViolationRule::BuiltinShadowing),
+            (crypto_config.is_enabled(), ViolationRule::WeakCrypto),
+            (noop_config.is_enabled(), ViolationRule::NoopStatement),
+            (context_config.is_enabled(), ViolationRule::UnusedContext),
+            (named_returns_config.is_enabled(), ViolationRule::UnassignedNamedReturn),
+            (constants_config.is_enabled(), ViolationRule::PlaceholderConstants),
+            (definitions_config.is_enabled(), ViolationRule::MissingDefinition),
+            (deprecated_config.is_enabled(), ViolationRule::DeprecatedUsage),
+            (unwrap_config.is_enabled(), ViolationRule::UnwrapOveruse),
+            (force_unwrap_config.is_enabled(), ViolationRule::ForceUnwrap),
+            (local_modules_config.is_enabled(), ViolationRule::MissingLocalModule),
+            (error_logging_config.is_enabled(), ViolationRule::RedundantErrorLogging),
+            (error_handling_config.is_enabled(), ViolationRule::InconsistentErrorHandling),
+            (async_config.is_enabled(), ViolationRule::AsyncWithoutAwait),
+            (mutable_defaults_config.is_enabled(), ViolationRule::MutableDefault),
+            (contract.detect_truncated_code(), ViolationRule::TruncatedCode),
+            (enum_config.is_some(), ViolationRule::UnhandledEnumCases),
+            (wrappers_config.is_some(), ViolationRule::TrivialWrapper),
+            (ratio_config.is_some(), ViolationRule::CommentRatio),
+            (doc_coverage_config.is_some(), ViolationRule::DocCoverage),
+            (literals_config.is_some_and(|c| c.is_enabled()), ViolationRule::RepeatedLiteral),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, rule)| (enabled && self.runs(rule)).then_some(rule))
+        .collect();
+        let estimation_config = contract.estimation.as_ref().filter(|c| c.is_enabled());
+        let mut fact_kinds: Vec<FactKind> = fact_rules
+            .iter()
+            .flat_map(|r| r.required_facts().iter().copied())
+            .collect();
+        if estimation_config.is_some() {
+            fact_kinds.extend_from_slice(ESTIMATION_FACTS);
+        }
+        let facts = FactsBundle::build(&analysis_ctx, files, &fact_kinds);
+
         let total_files = files.len();
         let processed = Arc::new(AtomicUsize::new(0));
 
-        // Collect suppressions from all files (parallelized)
-        let suppression_map = collect_suppressions(files)?;
-        let all_suppressions: Vec<_> = suppression_map.values().flatten().cloned().collect();
+        // Collect suppression and downgrade directives from all files
+        let directives = collect_directives(&[files, container_files.as_slice(), schema_files.as_slice()].concat());
 
         // Check required files (not file-parallel, quick)
-        let file_result = detect_missing_files(&self.base_dir, &contract.required_files)?;
-        result.merge(file_result);
+        if self.runs(ViolationRule::MissingFile) {
+            let file_result = detect_missing_files(&self.base_dir, &contract.required_files)?;
+            result.merge(file_result);
+        }
+
+        // Check for forbidden and oversized files (path and metadata only)
+        if self.runs(ViolationRule::ForbiddenFile) {
+            let forbidden_file_result = detect_forbidden_files(&self.base_dir, contract)?;
+            result.merge(forbidden_file_result);
+        }
+
+        // Check for sibling paths that differ only by case (paths only)
+        if self.runs(ViolationRule::CaseCollision) {
+            let collision_result = detect_case_collisions(&self.base_dir, contract)?;
+            result.merge(collision_result);
+        }
+
+        // Check configuration files against the contract's expectations (walks the project);
+        // values matching a mock signature are reported as mock data
+        if self.runs(ViolationRule::HollowConfig) || self.runs(ViolationRule::MockData) {
+            let config_result = detect_hollow_config(&self.base_dir, contract)?;
+            result.merge(config_result);
+        }
+
+        // Check CI pipeline files for placeholders and TODO steps (read from the project root)
+        let ci_config = contract.ci_configs.clone().unwrap_or_default();
+        if ci_config.is_enabled() && self.runs(ViolationRule::HollowCiConfig) {
+            let ci_result = detect_hollow_ci_configs(&self.base_dir, contract, &ci_config)?;
+            result.merge(ci_result);
+        }
+
+        // Read the declared language and framework versions from the project
+        // root; they are checked only against an environment section
+        let environment_config = contract
+            .environment
+            .clone()
+            .filter(|c| c.is_enabled() && self.runs(ViolationRule::EnvironmentMismatch));
+        let environment_result = detect_environment_mismatch(&self.base_dir, environment_config.as_ref())?;
+        result.merge(environment_result);
 
         // Build god object config if enabled
+        let god_rules = [ViolationRule::GodFile, ViolationRule::GodFunction, ViolationRule::GodClass];
         let god_config = contract.god_objects.as_ref().and_then(|god_cfg| {
-            if god_cfg.is_enabled() {
+            if god_cfg.is_enabled() && god_rules.iter().any(|r| self.runs(*r)) {
                 let defaults = GodObjectConfig::default();
                 Some(GodObjectConfig {
                     max_file_lines: god_cfg.max_file_lines.unwrap_or(defaults.max_file_lines),
@@ -92,11 +380,16 @@ impl Runner {
         });
 
         // Run per-file detectors in parallel
-        let detect_todos = contract.detect_hollow_todos();
+        let detect_todos = contract.detect_hollow_todos() && self.runs(ViolationRule::HollowTodo);
         let patterns = &contract.forbidden_patterns;
+        let detect_patterns = !patterns.is_empty() && self.runs(ViolationRule::ForbiddenPattern);
         let mock_config = contract.mock_signatures.as_ref();
+        let detect_mocks = self.runs(ViolationRule::MockData);
         let progress_cb = self.progress_callback.clone();
+        let file_cb = self.file_callback.clone();
         let processed_clone = processed.clone();
+        // Shared so a pattern that blows its budget on one file is skipped on the rest
+        let pattern_guard = PatternGuard::new(budget_config.pattern_timeout());
 
         let file_results: Vec<DetectionResult> = files
             .par_iter()
@@ -104,27 +397,32 @@ impl Runner {
                 let mut file_result = DetectionResult::new();
 
                 // Forbidden patterns
-                if !patterns.is_empty() {
-                    if let Ok(r) = detect_forbidden_patterns(&[file.clone()], patterns) {
+                if detect_patterns {
+                    if let Ok(r) =
+                        detect_forbidden_patterns(std::slice::from_ref(file), patterns, &markers, tracked_severity, &pattern_guard)
+                    {
                         file_result.merge(r);
                     }
                 }
 
                 // Mock data
-                if let Ok(r) = detect_mock_data(&[file.clone()], mock_config) {
-                    file_result.merge(r);
+                if detect_mocks {
+                    if let Ok(r) = detect_mock_data(std::slice::from_ref(file), mock_config, &pattern_guard) {
+                        file_result.merge(r);
+                    }
                 }
 
                 // Hollow TODOs
                 if detect_todos {
-                    if let Ok(r) = detect_hollow_todos(&[file.clone()]) {
+                    if let Ok(r) = detect_hollow_todos(std::slice::from_ref(file), &markers, tracked_severity) {
                         file_result.merge(r);
                     }
                 }
 
                 // God objects
                 if let Some(ref config) = god_config {
-                    if let Ok(r) = detect_god_objects(&[file.clone()], config) {
+                    let blocks = generated.blocks(&self.base_dir, file);
+                    if let Ok(r) = detect_god_objects_outside(file, blocks, config) {
                         file_result.merge(r);
                     }
                 }
@@ -134,6 +432,9 @@ impl Runner {
                 if let Some(ref cb) = progress_cb {
                     cb(current, total_files);
                 }
+                if let Some(ref cb) = file_cb {
+                    cb(file, file_result.violations.len(), current, total_files);
+                }
 
                 file_result
             })
@@ -143,49 +444,425 @@ impl Runner {
         for r in file_results {
             result.merge(r);
         }
+        result.contract_warnings.extend(pattern_guard.warnings());
 
         // Non-parallelizable checks (require cross-file context)
-        // Create analysis context for AST-backed detection
-        let analysis_ctx = AnalysisContext::new(&self.base_dir);
 
-        // Check required symbols (uses AST-backed analysis)
-        let symbol_result =
-            detect_missing_symbols(&analysis_ctx, files, &contract.required_symbols)?;
+        // Check required symbols (uses shared facts)
+        let symbol_result = detect_missing_symbols(&facts, files, &required_symbols)?;
         result.merge(symbol_result);
 
-        // Check complexity requirements (uses AST-backed analysis)
-        let complexity_result = detect_low_complexity(&analysis_ctx, files, &contract.complexity)?;
+        // Check complexity requirements (uses shared facts)
+        let complexity_result = detect_low_complexity(&facts, files, &complexity)?;
         result.merge(complexity_result);
 
+        // Check implementation coverage ratios (uses AST-backed analysis)
+        if self.runs(ViolationRule::InsufficientImplementation) {
+            let coverage_result = detect_insufficient_implementation(
+                &analysis_ctx,
+                files,
+                &contract.implementation_coverage,
+            )?;
+            result.merge(coverage_result);
+        }
+
+        // Check for repeated string literals (opt-in, uses AST-backed analysis)
+        let literal_result = detect_repeated_literals(&facts, files, literals_config)?;
+        result.merge(literal_result);
+
+        // Check that interfaces and traits have an implementing type (opt-in)
+        let interface_result = detect_unimplemented_interfaces(&analysis_ctx, files, interfaces_config)?;
+        result.merge(interface_result);
+
+        // Check for abstractions with a single concrete type behind them (opt-in)
+        let abstraction_result = detect_premature_abstraction(&analysis_ctx, files, abstraction_config)?;
+        result.merge(abstraction_result);
+
+        // Check for weak hashes, ciphers, modes, IVs, and salts (uses shared facts)
+        if crypto_config.is_enabled() && self.runs(ViolationRule::WeakCrypto) {
+            let crypto_result = detect_weak_crypto(&facts, files, &crypto_config)?;
+            result.merge(crypto_result);
+        }
+
+        // Check for Python names that shadow builtins (uses shared facts)
+        if builtins_config.is_enabled() && self.runs(ViolationRule::BuiltinShadowing) {
+            let builtins_result = detect_builtin_shadowing(&facts, files, &builtins_config)?;
+            result.merge(builtins_result);
+        }
+
+        // Check for statements with no effect (uses shared facts)
+        if noop_config.is_enabled() && self.runs(ViolationRule::NoopStatement) {
+            let noop_result = detect_noop_statements(&facts, files)?;
+            result.merge(noop_result);
+        }
+
+        // Check Go functions that accept a context.Context and never use it (uses shared facts)
+        if context_config.is_enabled() && self.runs(ViolationRule::UnusedContext) {
+            let context_result = detect_unused_context(&facts, files)?;
+            result.merge(context_result);
+        }
+
+        // Check Go named results that a bare return hands back unassigned (uses shared facts)
+        if named_returns_config.is_enabled() && self.runs(ViolationRule::UnassignedNamedReturn) {
+            let named_returns_result = detect_unassigned_named_returns(&facts, files)?;
+            result.merge(named_returns_result);
+        }
+
+        // Check constant groups for identical or placeholder values (uses shared facts)
+        if constants_config.is_enabled() && self.runs(ViolationRule::PlaceholderConstants) {
+            let constants_result = detect_placeholder_constants(&facts, files, &constants_config)?;
+            result.merge(constants_result);
+        }
+
+        // Check C and C++ headers for functions no translation unit defines (uses shared facts)
+        if definitions_config.is_enabled() && self.runs(ViolationRule::MissingDefinition) {
+            let definitions_result = detect_missing_definitions(&facts, files, &definitions_config)?;
+            result.merge(definitions_result);
+        }
+
+        // Check deprecated callables that are still widely called (uses shared facts)
+        if deprecated_config.is_enabled() && self.runs(ViolationRule::DeprecatedUsage) {
+            let deprecated_result = detect_deprecated_usage(&facts, files, &deprecated_config)?;
+            result.merge(deprecated_result);
+        }
+
+        // Check Rust functions that unwrap instead of handling errors (uses shared facts)
+        if unwrap_config.is_enabled() && self.runs(ViolationRule::UnwrapOveruse) {
+            let unwrap_result = detect_unwrap_overuse(&facts, files, &unwrap_config)?;
+            result.merge(unwrap_result);
+        }
+
+        // Check Swift functions that force-unwrap instead of handling nil or errors (uses shared facts)
+        if force_unwrap_config.is_enabled() && self.runs(ViolationRule::ForceUnwrap) {
+            let force_unwrap_result = detect_force_unwraps(&facts, files, &force_unwrap_config)?;
+            result.merge(force_unwrap_result);
+        }
+
+        // Check relative and project-local imports against the file tree (uses shared facts)
+        if local_modules_config.is_enabled() && self.runs(ViolationRule::MissingLocalModule) {
+            let local_modules_result = detect_missing_local_modules(&facts, files)?;
+            result.merge(local_modules_result);
+        }
+
+        // Check error handlers that log and rethrow the same error (uses shared facts)
+        if error_logging_config.is_enabled() && self.runs(ViolationRule::RedundantErrorLogging) {
+            let error_logging_result = detect_redundant_error_logging(&facts, files)?;
+            result.merge(error_logging_result);
+        }
+
+        // Check files that mix error-returning and panicking functions (uses shared facts)
+        if error_handling_config.is_enabled() && self.runs(ViolationRule::InconsistentErrorHandling) {
+            let error_handling_result = detect_inconsistent_error_handling(&facts, files)?;
+            result.merge(error_handling_result);
+        }
+
+        // Check async functions that never await anything (uses shared facts)
+        if async_config.is_enabled() && self.runs(ViolationRule::AsyncWithoutAwait) {
+            let async_result = detect_async_without_await(&facts, files, &async_config)?;
+            result.merge(async_result);
+        }
+
+        // Check Python parameters that default to a mutable value (uses shared facts)
+        if mutable_defaults_config.is_enabled() && self.runs(ViolationRule::MutableDefault) {
+            let mutable_defaults_result = detect_mutable_defaults(&facts, files, &mutable_defaults_config)?;
+            result.merge(mutable_defaults_result);
+        }
+
+        // Check files that end inside a function (uses shared facts)
+        if contract.detect_truncated_code() && self.runs(ViolationRule::TruncatedCode) {
+            let truncated_result = detect_truncated_code(&facts, files)?;
+            result.merge(truncated_result);
+        }
+
+        // Check callables that only forward their parameters to project functions (opt-in, uses shared facts)
+        if let Some(wrappers_config) = &wrappers_config {
+            let wrappers_result = detect_trivial_wrappers(&facts, files, wrappers_config)?;
+            result.merge(wrappers_result);
+        }
+
+        // Check files for too few comments across complex functions, or too many (opt-in, uses shared facts)
+        if let Some(ratio_config) = &ratio_config {
+            let ratio_result = detect_comment_ratio(&facts, files, ratio_config)?;
+            result.merge(ratio_result);
+        }
+
+        // Check exported declarations for doc comments and measure coverage (opt-in, uses shared facts)
+        if let Some(doc_coverage_config) = &doc_coverage_config {
+            let doc_coverage_result = detect_doc_coverage(&facts, files, doc_coverage_config)?;
+            result.merge(doc_coverage_result);
+        }
+
+        // Check switches over enums for variants left to a trivial default (opt-in, uses shared facts)
+        if let Some(enum_config) = &enum_config {
+            let enum_result = detect_unhandled_enum_cases(&facts, files, enum_config)?;
+            result.merge(enum_result);
+        }
+
+        // Check Dockerfiles and compose files for hollow or placeholder infrastructure
+        let container_config = contract.containers.clone().unwrap_or_default();
+        if container_config.is_enabled() && self.runs(ViolationRule::HollowContainer) {
+            let container_result = detect_hollow_containers(
+                &container_files,
+                &container_config,
+                contract.mock_signatures.as_ref(),
+            )?;
+            result.merge(container_result);
+        }
+
+        // Check Protobuf and GraphQL schemas for empty definitions and placeholder fields
+        let schema_config = contract.schemas.clone().unwrap_or_default();
+        if schema_config.is_enabled() && self.runs(ViolationRule::HollowSchema) {
+            let schema_result = detect_hollow_schemas(&schema_files, &schema_config)?;
+            result.merge(schema_result);
+        }
+
+        // Check license headers (opt-in, uses AST leading comments)
+        let header_result = detect_bad_headers(&analysis_ctx, files, header_config)?;
+        result.merge(header_result);
+
+        // Check parameter list lengths (uses AST-backed analysis)
+        let params_config = contract.long_parameter_lists.clone().unwrap_or_default();
+        if params_config.is_enabled() && self.runs(ViolationRule::TooManyParams) {
+            let params_result =
+                detect_long_parameter_lists(&analysis_ctx, files, &params_config)?;
+            result.merge(params_result);
+        }
+
+        // Check documentation against the project's declarations (opt-in)
+        let doc_result = detect_doc_drift(&facts, files, doc_config)?;
+        result.merge(doc_result);
+
         // Check for stub functions using AST analysis
         // This uses the new tree-sitter based analyzer for precise detection
-        let stub_config = StubDetectionConfig::default_enabled();
-        let stub_result = detect_stub_functions(files, Some(&stub_config))?;
-        result.merge(stub_result);
+        let mut stub_config = StubDetectionConfig::default_enabled();
+        stub_config.markers = Some(Arc::new(markers.clone()));
+        if let Some(exemptions) = &contract.stub_exemptions {
+            stub_config.exemptions = exemptions.resolve()?;
+        }
+        let repetitive_config = contract.repetitive_bodies.clone().unwrap_or_default();
+        stub_config.min_distinct_ratio = if repetitive_config.is_enabled() { repetitive_config.min_distinct_ratio() } else { 0.0 };
+        stub_config.repetitive_min_statements = repetitive_config.min_statements();
+        stub_config.custom_signatures = contract
+            .custom_stub_signatures
+            .iter()
+            .map(|s| s.resolve())
+            .collect::<anyhow::Result<_>>()?;
+        if self.runs(ViolationRule::StubFunction) {
+            let stub_result = detect_stub_functions(files, Some(&stub_config))?;
+            result.merge(stub_result);
+        }
+
+        // Check for floating promises in JS/TS files
+        if contract.detect_floating_promises() && self.runs(ViolationRule::FloatingPromise) {
+            let promise_result = detect_floating_promises(files)?;
+            result.merge(promise_result);
+        }
+
+        // Check for HTTP handlers that respond 200 with an empty body
+        let handlers_config = contract.empty_handlers.clone().unwrap_or_default();
+        if handlers_config.is_enabled() && self.runs(ViolationRule::EmptyHandler) {
+            let handler_result = detect_empty_handlers(files, &handlers_config)?;
+            result.merge(handler_result);
+        }
 
-        // Check required tests
-        let test_result = detect_missing_tests(&self.base_dir, files, &contract.required_tests)?;
+        // Check required tests (uses shared facts)
+        let test_result = detect_missing_tests(&facts, files, &required_tests)?;
         result.merge(test_result);
 
         // Check for hallucinated dependencies (unless skipped)
-        if !self.skip_registry_check {
+        if !self.skip_registry_check && self.runs(ViolationRule::HallucinatedDependency) {
             let dep_result = detect_hallucinated_dependencies(
                 &self.base_dir,
                 files,
                 contract.dependency_verification.as_ref(),
+                self.explain_dependencies,
+                self.dependency_progress.as_deref(),
             )?;
             result.merge(dep_result);
         }
 
+        // Record the rules this run checked, so reports can list the clean ones
+        let checked = [
+            (!contract.forbidden_patterns.is_empty(), ViolationRule::ForbiddenPattern),
+            (mock_config.is_some_and(|c| !c.patterns.is_empty()), ViolationRule::MockData),
+            (!contract.required_files.is_empty(), ViolationRule::MissingFile),
+            (!required_symbols.is_empty(), ViolationRule::MissingSymbol),
+            (!complexity.is_empty(), ViolationRule::LowComplexity),
+            (!required_tests.is_empty(), ViolationRule::MissingTest),
+            (
+                !self.skip_registry_check
+                    && contract.dependency_verification.as_ref().is_some_and(|c| c.is_enabled()),
+                ViolationRule::HallucinatedDependency,
+            ),
+            (detect_todos, ViolationRule::HollowTodo),
+            (true, ViolationRule::StubFunction),
+            (!contract.implementation_coverage.is_empty(), ViolationRule::InsufficientImplementation),
+            (
+                contract.repeated_literals.as_ref().is_some_and(|c| c.is_enabled()),
+                ViolationRule::RepeatedLiteral,
+            ),
+            (contract.detect_floating_promises(), ViolationRule::FloatingPromise),
+            (
+                !contract.forbidden_files.is_empty()
+                    || contract.forbid_env_files()
+                    || contract.max_file_size.is_some(),
+                ViolationRule::ForbiddenFile,
+            ),
+            (true, ViolationRule::CaseCollision),
+            (
+                contract.detect_header.as_ref().is_some_and(|c| c.is_enabled()),
+                ViolationRule::BadHeader,
+            ),
+            (
+                contract.doc_drift.as_ref().is_some_and(|c| c.is_enabled()),
+                ViolationRule::DocDrift,
+            ),
+            (params_config.is_enabled(), ViolationRule::TooManyParams),
+            (handlers_config.is_enabled(), ViolationRule::EmptyHandler),
+            (budget_config.is_enabled(), ViolationRule::AnalysisTimeout),
+            (true, ViolationRule::AnalysisError),
+            (
+                contract.unimplemented_interfaces.as_ref().is_some_and(|c| c.is_enabled()),
+                ViolationRule::UnimplementedInterface,
+            ),
+            (
+                contract.premature_abstraction.as_ref().is_some_and(|c| c.is_enabled()),
+                ViolationRule::PrematureAbstraction,
+            ),
+            (builtins_config.is_enabled(), ViolationRule::BuiltinShadowing),
+            (crypto_config.is_enabled(), ViolationRule::WeakCrypto),
+            (container_config.is_enabled(), ViolationRule::HollowContainer),
+            (ci_config.is_enabled(), ViolationRule::HollowCiConfig),
+            (schema_config.is_enabled(), ViolationRule::HollowSchema),
+            (environment_config.is_some(), ViolationRule::EnvironmentMismatch),
+            (noop_config.is_enabled(), ViolationRule::NoopStatement),
+            (enum_config.is_some(), ViolationRule::UnhandledEnumCases),
+            (!contract.config_files.is_empty(), ViolationRule::HollowConfig),
+            (!contract.waivers.is_empty(), ViolationRule::ExpiredWaiver),
+            (unused_suppressions_config.is_some(), ViolationRule::UnusedSuppression),
+            (context_config.is_enabled(), ViolationRule::UnusedContext),
+            (named_returns_config.is_enabled(), ViolationRule::UnassignedNamedReturn),
+            (constants_config.is_enabled(), ViolationRule::PlaceholderConstants),
+            (definitions_config.is_enabled(), ViolationRule::MissingDefinition),
+            (deprecated_config.is_enabled(), ViolationRule::DeprecatedUsage),
+            (unwrap_config.is_enabled(), ViolationRule::UnwrapOveruse),
+            (force_unwrap_config.is_enabled(), ViolationRule::ForceUnwrap),
+            (local_modules_config.is_enabled(), ViolationRule::MissingLocalModule),
+            (error_logging_config.is_enabled(), ViolationRule::RedundantErrorLogging),
+            (error_handling_config.is_enabled(), ViolationRule::InconsistentErrorHandling),
+            (async_config.is_enabled(), ViolationRule::AsyncWithoutAwait),
+            (mutable_defaults_config.is_enabled(), ViolationRule::MutableDefault),
+            (contract.detect_truncated_code(), ViolationRule::TruncatedCode),
+            (wrappers_config.is_some(), ViolationRule::TrivialWrapper),
+            (ratio_config.is_some(), ViolationRule::CommentRatio),
+            (doc_coverage_config.is_some(), ViolationRule::DocCoverage),
+            (god_config.is_some(), ViolationRule::GodFile),
+            (god_config.is_some(), ViolationRule::GodFunction),
+            (god_config.is_some(), ViolationRule::GodClass),
+        ];
+        result.checked_rules = checked
+            .into_iter()
+            .filter_map(|(enabled, rule)| (enabled && self.runs(rule)).then_some(rule))
+            .collect();
+
+        // Findings in generated files only count for the rules kept for them
+        if !generated.pristine.is_empty() && !generated_rules.is_empty() {
+            result.violations = filter_generated_violations(
+                &self.base_dir,
+                std::mem::take(&mut result.violations),
+                &generated.pristine,
+                &generated_rules,
+            );
+        }
+        if !generated.modified.is_empty() {
+            result.violations = scope_edited_violations(
+                &self.base_dir,
+                std::mem::take(&mut result.violations),
+                &generated.modified,
+                &generated_rules,
+            );
+        }
+
+        // Contract scopes turn rules off or set their severity by path
+        result.violations = apply_scopes(std::mem::take(&mut result.violations), &contract.scopes, &self.base_dir)?;
+
         // Deduplicate violations before applying suppressions
         result.deduplicate();
 
+        // Fingerprint everything, suppressed or not, for cross-run tracking
+        let violations: Vec<_> = result.violations.iter().collect();
+        result.fingerprints = fingerprint_violations(&self.base_dir, &analysis_ctx, files, &violations);
+
+        // Suppressions that match nothing, judged against every finding before any is suppressed
+        let stale_suppressions = unused_suppressions_config.as_ref().map(|config| {
+            let stale = detect_stale_suppressions(
+                &result.violations,
+                &directives.suppressions,
+                |rule| result.checked_rules.contains(&rule),
+                config.redundant(),
+            );
+            (config, stale)
+        });
+
         // Apply suppressions - filter violations and track suppressed ones
-        if !all_suppressions.is_empty() {
-            let (active, suppressed) = filter_suppressed(result.violations, &all_suppressions);
+        if !directives.suppressions.is_empty() {
+            let (active, suppressed) = filter_suppressed(result.violations, &directives.suppressions);
             result.violations = active;
             result.suppressed = suppressed;
         }
+        if let Some((config, stale)) = stale_suppressions {
+            if config.report_as_suppressed() {
+                result.suppressed.extend(stale);
+            } else {
+                result.violations.extend(stale.into_iter().map(|s| s.violation));
+            }
+        }
+
+        // Then the suppression file, by fingerprint
+        let fingerprint_suppressions = load_suppression_file(&self.base_dir)?;
+        if !fingerprint_suppressions.is_empty() {
+            let file = self.base_dir.join(SUPPRESSION_FILE).to_string_lossy().to_string();
+            let (active, suppressed) = filter_fingerprint_suppressed(
+                std::mem::take(&mut result.violations),
+                &fingerprint_suppressions,
+                &file,
+                &today(),
+                |v| result.fingerprint(v),
+            );
+            result.violations = active;
+            result.suppressed.extend(suppressed);
+        }
+
+        // Then contract waivers, which also report the ones that expired
+        if !contract.waivers.is_empty() {
+            let (active, waived) = apply_waivers(
+                std::mem::take(&mut result.violations),
+                &contract.waivers,
+                &self.base_dir,
+                &today(),
+                |v| result.fingerprint(v),
+            )?;
+            result.violations = active;
+            result.waived = waived;
+        }
+
+        // Rules left out with only_rules or skip_rules report nothing, whichever
+        // check found them (budget, isolation, and waiver findings included)
+        if !self.only_rules.is_empty() || !self.skip_rules.is_empty() {
+            result.violations.retain(|v| self.runs(v.rule));
+            result.suppressed.retain(|s| self.runs(s.violation.rule));
+            result.waived.retain(|w| self.runs(w.violation.rule));
+        }
+
+        // Apply downgrades to what is left, before scoring sees the severities
+        result.downgraded = apply_downgrades(&mut result.violations, &directives.downgrades);
+
+        // Estimate the work left in the hollow functions still reported
+        if let Some(config) = estimation_config {
+            let (efforts, estimate) = estimate_effort(&facts, &result.violations, config);
+            result.efforts = efforts;
+            result.stats.effort = Some(estimate);
+        }
 
         Ok(result)
     }
@@ -194,7 +871,7 @@ impl Runner {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::contract::{ForbiddenPattern, HollowTodosConfig, RequiredFile};
+    use crate::contract::{ForbiddenPattern, HollowTodosConfig, RequiredFile, UnusedSuppressionsConfig};
     use tempfile::TempDir;
 
     #[test]
@@ -238,6 +915,131 @@ func main() {{}}
         assert!(result.violations[0].message.contains(todo_marker));
     }
 
+    #[test]
+    fn test_runner_rule_selection() {
+        let temp = TempDir::new().unwrap();
+        let main_go = temp.path().join("main.go");
+        std::fs::write(
+            &main_go,
+            "package main\n\n// FIXME: wire up\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+        )
+        .unwrap();
+        let contract = Contract {
+            forbidden_patterns: vec![ForbiddenPattern {
+                pattern: "FIXME".to_string(),
+                description: None,
+            }],
+            ..Default::default()
+        };
+        let rules = |result: &DetectionResult| {
+            let mut rules: Vec<_> = result.violations.iter().map(|v| v.rule).collect();
+            rules.dedup();
+            rules
+        };
+
+        let result = Runner::new(temp.path())
+            .only_rules(&[ViolationRule::ForbiddenPattern])
+            .run(std::slice::from_ref(&main_go), &contract)
+            .unwrap();
+        assert_eq!(rules(&result), vec![ViolationRule::ForbiddenPattern]);
+        assert_eq!(result.checked_rules, vec![ViolationRule::ForbiddenPattern]);
+
+        let result = Runner::new(temp.path())
+            .skip_rules(&[ViolationRule::ForbiddenPattern])
+            .run(std::slice::from_ref(&main_go), &contract)
+            .unwrap();
+        assert!(rules(&result).contains(&ViolationRule::StubFunction));
+        assert!(!rules(&result).contains(&ViolationRule::ForbiddenPattern));
+        assert!(!result.checked_rules.contains(&ViolationRule::ForbiddenPattern));
+    }
+
+    #[test]
+    fn test_runner_applies_scopes() {
+        let temp = TempDir::new().unwrap();
+        let stub = "package app\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n";
+        let files: Vec<_> = ["src/app.go", "examples/demo.go", "internal/core.go"]
+            .iter()
+            .map(|name| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, stub).unwrap();
+                path
+            })
+            .collect();
+        let contract: Contract = serde_yaml::from_str(
+            "scopes:\n  - paths: [\"examples/**\"]\n    rules: {stub_function: off}\n  - paths: [\"src/**\"]\n    rules: {stub_function: warn}\n",
+        )
+        .unwrap();
+
+        let result = Runner::new(temp.path())
+            .only_rules(&[ViolationRule::StubFunction])
+            .run(&files, &contract)
+            .unwrap();
+        let mut found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.file.as_deref().unwrap().strip_prefix(&*temp.path().to_string_lossy()).unwrap().to_string(), v.severity))
+            .collect();
+        found.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            found,
+            vec![
+                ("/internal/core.go".to_string(), ViolationRule::StubFunction.default_severity()),
+                ("/src/app.go".to_string(), Severity::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_runner_disables_slow_patterns() {
+        let temp = TempDir::new().unwrap();
+        // One 1 MiB line of accented words, on which the first pattern takes
+        // seconds, and a small file; both log to the console
+        let words = ["café", "naïve", "zoë", "var", "x1", "función", "λ", "données"];
+        let mut bundle = String::from("console.log(init);");
+        for i in 0.. {
+            bundle.push_str(words[i % words.len()]);
+            bundle.push(if i % 7 == 6 { '=' } else { ' ' });
+            if bundle.len() >= 1 << 20 {
+                break;
+            }
+        }
+        let files = vec![temp.path().join("bundle.txt"), temp.path().join("app.js")];
+        std::fs::write(&files[0], &bundle).unwrap();
+        std::fs::write(&files[1], "function start() {\n  console.log(start);\n}\n").unwrap();
+        let contract: Contract = serde_yaml::from_str(
+            "forbidden_patterns:\n  - pattern: '\\b(\\w+\\b\\W+){10}zzz'\n  - pattern: 'console\\.log'\nanalysis_budget:\n  pattern_timeout_ms: 100\n",
+        )
+        .unwrap();
+
+        let started = std::time::Instant::now();
+        let result = Runner::new(temp.path())
+            .only_rules(&[ViolationRule::ForbiddenPattern])
+            .run(&files, &contract)
+            .unwrap();
+        assert!(started.elapsed() < std::time::Duration::from_secs(5), "{:?}", started.elapsed());
+
+        let mut found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.file.as_deref().unwrap().rsplit('/').next().unwrap().to_string(), v.line, v.message.clone()))
+            .collect();
+        found.sort();
+        assert_eq!(
+            found,
+            vec![
+                ("app.js".to_string(), 2, "forbidden pattern \"console\\\\.log\" found".to_string()),
+                ("bundle.txt".to_string(), 1, "forbidden pattern \"console\\\\.log\" found".to_string()),
+            ]
+        );
+        assert_eq!(result.contract_warnings.len(), 1);
+        assert!(
+            result.contract_warnings[0].starts_with(r#"forbidden_patterns pattern "\\b(\\w+\\b\\W+){10}zzz" took over 100 ms on "#),
+            "{}",
+            result.contract_warnings[0]
+        );
+    }
+
     #[test]
     fn test_runner_with_suppression() {
         let temp = TempDir::new().unwrap();
@@ -275,4 +1077,346 @@ func main() {{}}
         assert_eq!(result.violations.len(), 0);
         assert_eq!(result.suppressed.len(), 1);
     }
+
+    #[test]
+    fn test_runner_unused_suppressions() {
+        let temp = TempDir::new().unwrap();
+        let main_go = temp.path().join("main.go");
+        std::fs::write(
+            &main_go,
+            r#"
+package main
+
+// hollowcheck:ignore-next-line forbidden_pattern - Used
+// TODO: implement this
+func main() {} // hollowcheck:ignore forbidden_pattern - Left behind
+"#,
+        )
+        .unwrap();
+
+        let mut contract = Contract {
+            forbidden_patterns: vec![ForbiddenPattern {
+                pattern: "TODO".to_string(),
+                description: None,
+            }],
+            hollow_todos: Some(HollowTodosConfig { enabled: false }),
+            unused_suppressions: Some(UnusedSuppressionsConfig {
+                enabled: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let runner = Runner::new(temp.path());
+        let result = runner.run(std::slice::from_ref(&main_go), &contract).unwrap();
+        assert!(result.checked_rules.contains(&ViolationRule::UnusedSuppression));
+        assert_eq!(result.suppressed.len(), 1);
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(result.violations[0].rule, ViolationRule::UnusedSuppression);
+        assert_eq!(result.violations[0].line, 6);
+        assert_eq!(result.violations[0].severity, Severity::Info);
+        assert_eq!(result.violations[0].message, "suppression of forbidden_pattern matched no violation");
+
+        // Reported alongside the suppressions instead
+        contract.unused_suppressions.as_mut().unwrap().report = Some("suppressed".to_string());
+        let result = runner.run(&[main_go], &contract).unwrap();
+        assert!(result.violations.is_empty());
+        assert_eq!(result.suppressed.len(), 2);
+        assert_eq!(result.suppressed[1].violation.rule, ViolationRule::UnusedSuppression);
+        assert_eq!(result.suppressed[1].suppression.line, 6);
+    }
+
+    #[test]
+    fn test_runner_language_filter() {
+        let temp = TempDir::new().unwrap();
+        let main_go = temp.path().join("main.go");
+        let app_ts = temp.path().join("app.ts");
+        std::fs::write(&main_go, "package main\n\nfunc main() {}\n").unwrap();
+        std::fs::write(&app_ts, "// FIXME: wire this up\nexport const x = 1;\n").unwrap();
+
+        let contract: Contract = serde_yaml::from_str(
+            r#"
+languages:
+  include: [go]
+forbidden_patterns:
+  - pattern: "FIXME"
+required_symbols:
+  - name: "render"
+    kind: function
+    file: "app.ts"
+hollow_todos:
+  enabled: false
+"#,
+        )
+        .unwrap();
+
+        let runner = Runner::new(temp.path());
+        let result = runner.run(&[main_go, app_ts], &contract).unwrap();
+
+        // The TS file is neither scanned by text rules nor required to define symbols
+        assert!(result.violations.is_empty(), "{:?}", result.violations);
+        assert_eq!(result.language_excluded, 1);
+    }
+
+    #[test]
+    fn test_runner_skips_vendored_dirs() {
+        let temp = TempDir::new().unwrap();
+        let main_go = temp.path().join("main.go");
+        let vendored = temp.path().join("node_modules/left-pad/index.js");
+        std::fs::create_dir_all(vendored.parent().unwrap()).unwrap();
+        std::fs::write(&main_go, "package main\n\nfunc main() {}\n").unwrap();
+        std::fs::write(&vendored, "// FIXME: third-party\nmodule.exports = 1;\n").unwrap();
+
+        let contract = Contract {
+            forbidden_patterns: vec![ForbiddenPattern {
+                pattern: "FIXME".to_string(),
+                description: None,
+            }],
+            hollow_todos: Some(HollowTodosConfig { enabled: false }),
+            ..Default::default()
+        };
+        let files = vec![main_go, vendored];
+
+        let result = Runner::new(temp.path()).run(&files, &contract).unwrap();
+        assert!(result.violations.is_empty(), "{:?}", result.violations);
+
+        let result = Runner::new(temp.path())
+            .default_excludes(false)
+            .run(&files, &contract)
+            .unwrap();
+        assert_eq!(result.violations.len(), 1);
+        assert!(result.violations[0].location().contains("node_modules"));
+    }
+
+    #[test]
+    fn test_runner_with_downgrades() {
+        let temp = TempDir::new().unwrap();
+        let dockerfile = temp.path().join("Dockerfile");
+        let body = "FROM node:latest\nWORKDIR /app\nRUN npm ci\nCMD [\"node\", \"server.js\"]\n";
+
+        // The contract raises unpinned base images to errors
+        let contract: Contract = serde_yaml::from_str(
+            r#"
+containers:
+  latest_tag_severity: error
+hollow_todos:
+  enabled: false
+"#,
+        )
+        .unwrap();
+        let runner = Runner::new(temp.path());
+
+        std::fs::write(&dockerfile, body).unwrap();
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract).unwrap();
+        assert_eq!(result.violations.len(), 1, "{:?}", result.violations);
+        assert_eq!(result.violations[0].severity, Severity::Error);
+        assert!(result.downgraded.is_empty());
+        assert!(!crate::score::calculate_with_threshold(&result, 0, &Default::default()).passed);
+
+        // The file's downgrade wins over the contract for that file
+        std::fs::write(
+            &dockerfile,
+            format!("# hollowcheck:downgrade hollow_container=info - pinned by the release job\n{}", body),
+        )
+        .unwrap();
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract).unwrap();
+        assert_eq!(result.violations.len(), 1, "{:?}", result.violations);
+        assert_eq!(result.violations[0].severity, Severity::Info);
+        assert_eq!(result.downgraded.len(), 1);
+        assert_eq!(result.downgraded[0].original_severity, Severity::Error);
+        assert_eq!(result.downgraded[0].downgrade.suppression.reason, "pinned by the release job");
+        assert!(result.suppressed.is_empty());
+
+        // Still listed, but no longer counted against the threshold
+        let score = crate::score::calculate_with_threshold(&result, 0, &Default::default());
+        assert_eq!(score.score, 0);
+        assert!(score.passed);
+    }
+
+    #[test]
+    fn test_runner_generated_files() {
+        let temp = TempDir::new().unwrap();
+        let generated = temp.path().join("api.pb.go");
+        std::fs::write(
+            &generated,
+            "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n\n// TODO: generator bug\nfunc (x *Req) Reset() {}\n",
+        )
+        .unwrap();
+        let main_go = temp.path().join("main.go");
+        std::fs::write(&main_go, "package main\n\n// TODO: wire this\nfunc main() {}\n").unwrap();
+        let files = vec![generated.clone(), main_go];
+
+        let run = |yaml: &str| {
+            let mut contract: Contract = serde_yaml::from_str(yaml).unwrap();
+            contract.forbidden_patterns = vec![ForbiddenPattern {
+                pattern: "TODO".to_string(),
+                description: Some("work marker".to_string()),
+            }];
+            Runner::new(temp.path()).skip_registry_check(true).run(&files, &contract).unwrap()
+        };
+        let in_generated = |result: &DetectionResult| -> Vec<ViolationRule> {
+            result
+                .violations
+                .iter()
+                .filter(|v| v.file.as_deref().is_some_and(|f| f.ends_with("api.pb.go")))
+                .map(|v| v.rule)
+                .collect()
+        };
+
+        // Without the section, generated files are checked like any other
+        let result = run("{}");
+        assert!(in_generated(&result).contains(&ViolationRule::StubFunction));
+        assert_eq!(result.generated, 0);
+
+        let result = run("generated_files: {}\n");
+        assert!(in_generated(&result).is_empty());
+        assert!(result.violations.iter().any(|v| v.rule == ViolationRule::ForbiddenPattern));
+        assert_eq!(result.generated, 1);
+
+        let result = run("generated_files:\n  rules: [forbidden_patterns]\n");
+        assert_eq!(in_generated(&result), vec![ViolationRule::ForbiddenPattern]);
+        assert_eq!(result.generated, 1);
+    }
+
+    #[test]
+    fn test_runner_edited_generated_file() {
+        let temp = TempDir::new().unwrap();
+        let edited = temp.path().join("api.pb.go");
+        std::fs::write(
+            &edited,
+            "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n\n\
+             // BEGIN GENERATED CODE\nfunc (x *Req) Reset() {}\n// END GENERATED CODE\n\n\
+             func (x *Req) Validate() {}\n",
+        )
+        .unwrap();
+        let files = vec![edited];
+
+        let contract: Contract = serde_yaml::from_str("generated_files: {}\n").unwrap();
+        let result = Runner::new(temp.path()).skip_registry_check(true).run(&files, &contract).unwrap();
+        let stubs: Vec<_> = result
+            .violations
+            .iter()
+            .filter(|v| v.rule == ViolationRule::StubFunction)
+            .collect();
+        assert_eq!(stubs.len(), 1, "{:?}", result.violations);
+        assert_eq!(stubs[0].line, 9);
+        assert_eq!(stubs[0].severity, ViolationRule::StubFunction.default_severity());
+        assert!(stubs[0].message.ends_with("(hand-edited code in a generated file)"));
+        assert_eq!((result.generated, result.generated_modified), (0, 1));
+    }
+
+    #[test]
+    fn test_runner_whole_file_rules_skip_generated_blocks() {
+        let temp = TempDir::new().unwrap();
+        let edited = temp.path().join("api.pb.go");
+        let accessors: String = ["A", "B", "C", "D", "E"]
+            .iter()
+            .map(|f| format!("func (x *Req) Get{}() int {{\n\tif x == nil {{\n\t\treturn 0\n\t}}\n\treturn x.{}\n}}\n", f, f.to_lowercase()))
+            .collect();
+        std::fs::write(
+            &edited,
+            format!(
+                "// Code generated by protoc-gen-go. DO NOT EDIT.\n\npackage api\n\n\
+                 // BEGIN GENERATED CODE\n{}// END GENERATED CODE\n\n\
+                 // Validate checks the request fields.\nfunc (x *Req) Validate() error {{\n\tif x.a < 0 {{\n\t\treturn errNegative\n\t}}\n\treturn nil\n}}\n",
+                accessors
+            ),
+        )
+        .unwrap();
+        let files = vec![edited];
+
+        // Five undocumented accessors in the generated block would break both limits
+        let contract: Contract = serde_yaml::from_str(
+            "generated_files: {}\ngod_objects:\n  max_functions_per_file: 3\n  max_file_lines: 30\n\
+             doc_coverage:\n  min_doc_coverage: 0.5\n",
+        )
+        .unwrap();
+        let result = Runner::new(temp.path()).skip_registry_check(true).run(&files, &contract).unwrap();
+        let whole_file: Vec<_> = result
+            .violations
+            .iter()
+            .filter(|v| matches!(v.rule, ViolationRule::GodFile | ViolationRule::DocCoverage))
+            .collect();
+        assert!(whole_file.is_empty(), "{:?}", whole_file);
+        assert_eq!(result.generated_modified, 1);
+
+        // The hand-written part alone still counts
+        let contract: Contract =
+            serde_yaml::from_str("generated_files: {}\ngod_objects:\n  max_file_lines: 5\n").unwrap();
+        let result = Runner::new(temp.path()).skip_registry_check(true).run(&files, &contract).unwrap();
+        let god_files: Vec<_> = result.violations.iter().filter(|v| v.rule == ViolationRule::GodFile).collect();
+        assert_eq!(god_files.len(), 1, "{:?}", result.violations);
+        assert!(god_files[0].message.starts_with("file has 12 lines"), "{}", god_files[0].message);
+        assert!(god_files[0].message.ends_with("(hand-edited code in a generated file)"));
+    }
+
+    #[test]
+    fn test_runner_with_suppression_file() {
+        let temp = TempDir::new().unwrap();
+        let dockerfile = temp.path().join("Dockerfile");
+        let body = "FROM node:latest\nWORKDIR /app\nRUN npm ci\nCMD [\"node\", \"server.js\"]\n";
+        let contract: Contract = serde_yaml::from_str("hollow_todos:\n  enabled: false\n").unwrap();
+        let runner = Runner::new(temp.path());
+
+        std::fs::write(&dockerfile, body).unwrap();
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract).unwrap();
+        assert_eq!(result.violations.len(), 1, "{:?}", result.violations);
+        let fingerprint = result.fingerprint(&result.violations[0]);
+        assert_eq!(result.fingerprints.len(), 1);
+
+        // Suppressed by fingerprint, and still after the lines shift
+        let suppressions = temp.path().join(SUPPRESSION_FILE);
+        std::fs::write(
+            &suppressions,
+            format!("suppressions:\n  - fingerprint: \"{}\"\n    reason: base image pinned in CI\n", fingerprint),
+        )
+        .unwrap();
+        std::fs::write(&dockerfile, format!("\n\n{}", body)).unwrap();
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract).unwrap();
+        assert!(result.violations.is_empty(), "{:?}", result.violations);
+        assert_eq!(result.suppressed.len(), 1);
+        assert_eq!(result.suppressed[0].violation.line, 3);
+        assert_eq!(result.suppressed[0].suppression.suppression_type, crate::detect::SuppressionType::Fingerprint);
+        assert_eq!(result.suppressed[0].suppression.reason, "base image pinned in CI");
+
+        // An expired entry no longer applies
+        std::fs::write(
+            &suppressions,
+            format!("suppressions:\n  - fingerprint: \"{}\"\n    expires: 2000-01-01\n", fingerprint),
+        )
+        .unwrap();
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract).unwrap();
+        assert_eq!(result.violations.len(), 1);
+        assert!(result.suppressed.is_empty());
+    }
+
+    #[test]
+    fn test_runner_with_waivers() {
+        let temp = TempDir::new().unwrap();
+        let dockerfile = temp.path().join("Dockerfile");
+        std::fs::write(&dockerfile, "FROM node:latest\nRUN npm ci\nCMD [\"node\", \"server.js\"]\n").unwrap();
+        let runner = Runner::new(temp.path());
+        let contract_with = |expires: &str| -> Contract {
+            serde_yaml::from_str(&format!(
+                "hollow_todos:\n  enabled: false\nwaivers:\n  - rule: hollow_container\n    path: Dockerfile\n    owner: platform\n    justification: pinned by the release job\n    expires: {}\n",
+                expires
+            ))
+            .unwrap()
+        };
+
+        // Waived until the expiry date, and not scored
+        let contract = contract_with("2999-12-31");
+        assert!(crate::contract::validate(&contract).is_ok());
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract).unwrap();
+        assert!(result.violations.is_empty(), "{:?}", result.violations);
+        assert_eq!(result.waived.len(), 1);
+        assert_eq!(result.waived[0].waiver.owner, "platform");
+        assert_eq!(crate::score::calculate(&result, &contract).score, 0);
+
+        // Back in full once expired, with a notice about the waiver
+        let result = runner.run(std::slice::from_ref(&dockerfile), &contract_with("2000-01-01")).unwrap();
+        assert!(result.waived.is_empty());
+        let rules: Vec<_> = result.violations.iter().map(|v| v.rule).collect();
+        assert_eq!(rules, vec![ViolationRule::HollowContainer, ViolationRule::ExpiredWaiver]);
+    }
 }
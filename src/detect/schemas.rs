@@ -0,0 +1,255 @@
+//! Detection of hollow Protobuf and GraphQL schemas.
+//!
+//! A generated API surface often compiles while describing nothing: a
+//! `message User {}`, a `type Query { _empty: Boolean }` kept only so the
+//! schema validates, fields commented out until "later", and TODO markers
+//! where the real shape should be. Schemas are read with the line-oriented
+//! `SchemaAnalyzer`. Empty definitions whose names the contract allows
+//! (such as `Empty` or `*Request`) are not reported.
+
+use std::fs;
+use std::path::Path;
+
+use regex::Regex;
+
+use crate::analysis::{SchemaAnalyzer, SchemaDefinition, SchemaFormat};
+use crate::contract::SchemaChecksConfig;
+
+use super::containers::TODO_MARKER;
+use super::{DetectionResult, Severity, Violation, ViolationRule};
+
+/// Field names that only hold a place (compared lowercased, without leading underscores).
+const PLACEHOLDER_FIELDS: &[&str] = &[
+    "", "empty", "dummy", "placeholder", "todo", "tbd", "fixme", "foo", "bar", "baz", "noop", "stub", "unused",
+];
+
+/// Detect empty definitions, placeholder and commented-out fields, and TODO
+/// markers in `.proto` and `.graphql` files.
+pub fn detect_hollow_schemas<P: AsRef<Path>>(
+    files: &[P],
+    config: &SchemaChecksConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let todo_severity = config
+        .todo_severity()
+        .parse::<Severity>()
+        .map_err(|e| anyhow::anyhow!("invalid schemas todo_severity: {}", e))?;
+    let allow_empty = config
+        .allow_empty()
+        .iter()
+        .map(|p| Regex::new(p).map_err(|e| anyhow::anyhow!("invalid schemas allow_empty pattern {:?}: {}", p, e)))
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let placeholders: Vec<String> = PLACEHOLDER_FIELDS
+        .iter()
+        .map(|name| name.to_string())
+        .chain(config.placeholder_fields.iter().map(|name| normalize_field(name)))
+        .collect();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().collect();
+    sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
+
+    for file in sorted_files {
+        let path = file.as_ref();
+        let Some(format) = SchemaFormat::for_path(path) else {
+            continue;
+        };
+        let Ok(source) = fs::read_to_string(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        let file_str = path.to_string_lossy();
+        let schema = SchemaAnalyzer::new(format).parse(&source);
+        for definition in &schema.definitions {
+            for violation in check_definition(&file_str, definition, &allow_empty, &placeholders) {
+                result.add_violation(violation);
+            }
+        }
+        for comment in &schema.comments {
+            if TODO_MARKER.is_match(&comment.text) {
+                let message = format!("TODO marker left in the schema: '{}'", comment.text);
+                result.add_violation(hollow(&file_str, comment.line, message).with_severity(todo_severity));
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+fn hollow(file: &str, line: usize, message: String) -> Violation {
+    Violation::new(ViolationRule::HollowSchema, message).at(file, line)
+}
+
+/// Lowercase a field name and drop leading underscores (`_Empty` -> `empty`).
+fn normalize_field(name: &str) -> String {
+    name.trim_start_matches('_').to_lowercase()
+}
+
+/// What a definition of the given kind holds, for violation messages.
+fn members(keyword: &str) -> &'static str {
+    match keyword {
+        "enum" => "values",
+        "service" => "methods",
+        _ => "fields",
+    }
+}
+
+/// Check one definition for missing, placeholder, and commented-out fields.
+fn check_definition(
+    file: &str,
+    definition: &SchemaDefinition,
+    allow_empty: &[Regex],
+    placeholders: &[String],
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    let kind = &definition.keyword;
+    let name = &definition.name;
+
+    if definition.fields.is_empty()
+        && definition.nested == 0
+        && !allow_empty.iter().any(|re| re.is_match(name))
+    {
+        violations.push(hollow(
+            file,
+            definition.line,
+            format!("{} '{}' defines no {}", kind, name, members(kind)),
+        ));
+    }
+
+    for field in &definition.fields {
+        if placeholders.contains(&normalize_field(&field.name)) {
+            violations.push(hollow(
+                file,
+                field.line,
+                format!("placeholder field '{}' in {} '{}'", field.name, kind, name),
+            ));
+        }
+    }
+
+    // Commented-out fields that carry a TODO marker are reported as TODOs
+    for comment in &definition.commented_fields {
+        if !TODO_MARKER.is_match(&comment.text) {
+            violations.push(
+                hollow(
+                    file,
+                    comment.line,
+                    format!("commented-out field in {} '{}': '{}'", kind, name, comment.text),
+                )
+                .with_severity(Severity::Warning),
+            );
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &SchemaChecksConfig) -> Vec<(String, usize, Severity)> {
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+        detect_hollow_schemas(&paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| (v.message, v.line, v.severity))
+            .collect()
+    }
+
+    #[test]
+    fn test_hollow_proto() {
+        let proto = r#"syntax = "proto3";
+
+message User {}
+
+message Order {
+  string id = 1;
+  // string customer_id = 2;
+  // int64 total = 3; TODO
+}
+
+message Empty {}
+message GetUserRequest {}
+
+service Users {
+  // TODO: add ListUsers
+}
+"#;
+        let found = detect(&[("api.proto", proto)], &SchemaChecksConfig::default());
+        assert_eq!(
+            found,
+            vec![
+                ("message 'User' defines no fields".to_string(), 3, Severity::Error),
+                (
+                    "commented-out field in message 'Order': 'string customer_id = 2;'".to_string(),
+                    7,
+                    Severity::Warning
+                ),
+                ("service 'Users' defines no methods".to_string(), 14, Severity::Error),
+                ("TODO marker left in the schema: 'int64 total = 3; TODO'".to_string(), 8, Severity::Warning),
+                ("TODO marker left in the schema: 'TODO: add ListUsers'".to_string(), 15, Severity::Warning),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hollow_graphql() {
+        let graphql = r#"type Query {
+  _empty: Boolean
+}
+
+type Mutation {
+  createUser(input: NewUser!): User
+  # deleteUser(id: ID!): Boolean
+}
+
+type User {
+  id: ID!
+  placeholder: String
+}
+
+input NewUser {
+}
+"#;
+        let config = SchemaChecksConfig {
+            placeholder_fields: vec!["_Reserved".to_string()],
+            todo_severity: Some("error".to_string()),
+            ..Default::default()
+        };
+        let found = detect(&[("schema.graphql", graphql), ("notes.txt", "type Foo {}")], &config);
+        let messages: Vec<_> = found.iter().map(|(m, line, _)| (m.as_str(), *line)).collect();
+        assert_eq!(
+            messages,
+            vec![
+                ("placeholder field '_empty' in type 'Query'", 2),
+                ("commented-out field in type 'Mutation': 'deleteUser(id: ID!): Boolean'", 7),
+                ("placeholder field 'placeholder' in type 'User'", 12),
+                ("input 'NewUser' defines no fields", 15),
+            ]
+        );
+
+        let reserved = detect(&[("ext.gql", "extend type User {\n  reserved: Int\n}\n")], &config);
+        assert_eq!(reserved.len(), 1);
+        assert_eq!(reserved[0].0, "placeholder field 'reserved' in type 'User'");
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        let config = SchemaChecksConfig {
+            allow_empty: Some(vec!["(".to_string()]),
+            ..Default::default()
+        };
+        assert!(detect_hollow_schemas::<&Path>(&[], &config).is_err());
+    }
+}
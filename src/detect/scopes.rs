@@ -0,0 +1,180 @@
+//! Contract scopes: rule severities by path.
+//!
+//! A scope names path globs and, for some rules, a level that applies to
+//! violations in the matching files:
+//!
+//! ```yaml
+//! scopes:
+//!   - paths: ["examples/**"]
+//!     rules: {low_complexity: off}
+//!   - paths: ["fixtures/**", "tests/fixtures/**"]
+//!     rules: {mock_data: off, stub_function: warn}
+//! ```
+//!
+//! `off` drops the violations, `warn` and `error` set their severity. When
+//! several scopes set the same rule for a file, the one with the longest
+//! matching glob wins, as the most specific; on a tie the later entry wins.
+//! Scopes only act on what enabled rules find: they never turn a rule on.
+//! Globs match case-sensitively on every platform, so `Examples/**` does
+//! not scope `examples/demo.py` on macOS either.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use globset::{Glob, GlobMatcher};
+
+use crate::contract::{RuleScope, ScopeLevel};
+
+use super::{Severity, Violation, ViolationRule};
+
+struct CompiledScope {
+    globs: Vec<(GlobMatcher, usize)>,
+    rules: HashMap<ViolationRule, ScopeLevel>,
+}
+
+impl CompiledScope {
+    fn compile(scope: &RuleScope) -> anyhow::Result<Self> {
+        let globs = scope
+            .paths
+            .iter()
+            .map(|path| {
+                let glob = Glob::new(path).map_err(|e| anyhow::anyhow!("invalid scope path {:?}: {}", path, e))?;
+                Ok((glob.compile_matcher(), path.len()))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        let rules = scope
+            .rules
+            .iter()
+            .map(|(rule, level)| {
+                let rule = rule.parse().map_err(|e| anyhow::anyhow!("invalid scope rule: {}", e))?;
+                Ok((rule, *level))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self { globs, rules })
+    }
+
+    /// Length of the longest glob matching `relative`, if any does.
+    fn specificity(&self, relative: &str) -> Option<usize> {
+        self.globs
+            .iter()
+            .filter(|(glob, _)| glob.is_match(relative))
+            .map(|(_, len)| *len)
+            .max()
+    }
+}
+
+/// Apply the contract's scopes to violations, dropping those scoped `off`
+/// and setting the severity of those scoped `warn` or `error`.
+///
+/// File paths are matched relative to `base_dir`; violations without a file
+/// are left alone.
+pub fn apply_scopes(violations: Vec<Violation>, scopes: &[RuleScope], base_dir: &Path) -> anyhow::Result<Vec<Violation>> {
+    if scopes.is_empty() {
+        return Ok(violations);
+    }
+    let compiled = scopes.iter().map(CompiledScope::compile).collect::<anyhow::Result<Vec<_>>>()?;
+
+    let mut kept = Vec::with_capacity(violations.len());
+    for mut violation in violations {
+        let Some(file) = violation.file.as_deref() else {
+            kept.push(violation);
+            continue;
+        };
+        let path = Path::new(file);
+        let relative = path.strip_prefix(base_dir).unwrap_or(path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let relative = relative.trim_start_matches("./");
+
+        let level = compiled
+            .iter()
+            .enumerate()
+            .filter_map(|(i, scope)| {
+                let level = scope.rules.get(&violation.rule)?;
+                Some(((scope.specificity(relative)?, i), *level))
+            })
+            .max_by_key(|(rank, _)| *rank)
+            .map(|(_, level)| level);
+
+        match level {
+            Some(ScopeLevel::Off) => continue,
+            Some(ScopeLevel::Warn) => violation.severity = Severity::Warning,
+            Some(ScopeLevel::Error) => violation.severity = Severity::Error,
+            None => {}
+        }
+        kept.push(violation);
+    }
+    Ok(kept)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scopes(yaml: &str) -> Vec<RuleScope> {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    fn violation(rule: ViolationRule, file: &str) -> Violation {
+        Violation::new(rule, "found").at(format!("/repo/{}", file), 1)
+    }
+
+    #[test]
+    fn test_scopes_drop_and_set_severity() {
+        let scopes = scopes(
+            "- paths: [\"examples/**\"]\n  rules: {low_complexity: off, stub_function: warn}\n- paths: [\"fixtures/**\"]\n  rules: {mock_data: off}\n",
+        );
+        let violations = vec![
+            violation(ViolationRule::LowComplexity, "examples/demo.py"),
+            violation(ViolationRule::LowComplexity, "src/app.py"),
+            violation(ViolationRule::StubFunction, "examples/demo.py"),
+            violation(ViolationRule::MockData, "fixtures/users.json"),
+            violation(ViolationRule::MockData, "src/users.py"),
+            Violation::new(ViolationRule::MissingFile, "README.md is missing"),
+        ];
+
+        let kept = apply_scopes(violations, &scopes, Path::new("/repo")).unwrap();
+        let summary: Vec<_> = kept
+            .iter()
+            .map(|v| (v.rule.as_str(), v.file.as_deref().unwrap_or(""), v.severity))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("low_complexity", "/repo/src/app.py", ViolationRule::LowComplexity.default_severity()),
+                ("stub_function", "/repo/examples/demo.py", Severity::Warning),
+                ("mock_data", "/repo/src/users.py", ViolationRule::MockData.default_severity()),
+                ("missing_file", "", ViolationRule::MissingFile.default_severity()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_most_specific_scope_wins() {
+        let scopes = scopes(
+            "- paths: [\"src/legacy/**\"]\n  rules: {stub_function: off}\n- paths: [\"src/**\"]\n  rules: {stub_function: error}\n- paths: [\"src/**\"]\n  rules: {stub_function: warn}\n",
+        );
+        let violations = vec![
+            violation(ViolationRule::StubFunction, "src/legacy/old.go"),
+            violation(ViolationRule::StubFunction, "src/new.go"),
+        ];
+
+        let kept = apply_scopes(violations, &scopes, Path::new("/repo")).unwrap();
+        // src/legacy/** is longer than src/**; the later of the two src/** entries wins
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].file.as_deref(), Some("/repo/src/new.go"));
+        assert_eq!(kept[0].severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_scope_paths_match_case() {
+        let scopes = scopes("- paths: [\"Examples/**\"]\n  rules: {low_complexity: off}\n");
+        let violations = vec![
+            violation(ViolationRule::LowComplexity, "examples/demo.py"),
+            violation(ViolationRule::LowComplexity, "Examples/demo.py"),
+        ];
+
+        let kept = apply_scopes(violations, &scopes, Path::new("/repo")).unwrap();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].file.as_deref(), Some("/repo/examples/demo.py"));
+    }
+}
@@ -11,11 +11,13 @@
 
 use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::sync::RwLock;
 use std::time::{Duration, SystemTime};
 
+use crate::cache_file;
+
 mod fallback;
 
 /// Cache entry with metadata.
@@ -85,7 +87,28 @@ fn get_or_refresh_stdlib(lang: StdlibLanguage) -> HashSet<String> {
         return cached;
     }
 
-    // Query runtime
+    refresh_stdlib(lang).0
+}
+
+/// Load a language's stdlib list into the memory and disk caches ahead of a
+/// scan, returning the module count and where the list came from: `cached`
+/// for a fresh disk cache, otherwise the runtime version or `embedded`.
+///
+/// Unlike lookups during a scan, this queries the runtime under CI too, so
+/// a CI job that warms the cache once gives later jobs the installed
+/// runtime's list instead of the embedded one.
+pub(crate) fn warm_stdlib(lang: StdlibLanguage) -> (usize, String) {
+    if let Some(cached) = load_disk_cache(lang) {
+        let count = cached.len();
+        update_memory_cache(lang, cached, "cached".to_string());
+        return (count, "cached".to_string());
+    }
+    let (modules, version) = refresh_stdlib(lang);
+    (modules.len(), version)
+}
+
+/// Query the runtime for a language's stdlib list and store it in both caches.
+fn refresh_stdlib(lang: StdlibLanguage) -> (HashSet<String>, String) {
     let (mut modules, version) = query_runtime(lang).unwrap_or_else(|| {
         (
             fallback::get_embedded_fallback(lang),
@@ -102,9 +125,9 @@ fn get_or_refresh_stdlib(lang: StdlibLanguage) -> HashSet<String> {
     save_disk_cache(lang, &modules, &version);
 
     // Update memory cache
-    update_memory_cache(lang, modules.clone(), version);
+    update_memory_cache(lang, modules.clone(), version.clone());
 
-    modules
+    (modules, version)
 }
 
 /// Check if we're in a CI environment.
@@ -118,7 +141,7 @@ fn is_ci() -> bool {
 }
 
 /// Get cache directory.
-fn cache_dir() -> PathBuf {
+pub(crate) fn cache_dir() -> PathBuf {
     directories::ProjectDirs::from("", "", "hollowcheck")
         .map(|d| d.cache_dir().to_path_buf())
         .unwrap_or_else(|| PathBuf::from(".").join(".hollowcheck_cache"))
@@ -137,15 +160,27 @@ fn cache_file(lang: StdlibLanguage) -> PathBuf {
 
 /// Load from disk cache if fresh.
 fn load_disk_cache(lang: StdlibLanguage) -> Option<HashSet<String>> {
-    let path = cache_file(lang);
-    let metadata = fs::metadata(&path).ok()?;
+    load_cache_file(&cache_file(lang))
+}
+
+/// Save to disk cache.
+fn save_disk_cache(lang: StdlibLanguage, modules: &HashSet<String>, version: &str) {
+    let _ = save_cache_file(&cache_file(lang), modules, version);
+}
+
+/// Read a cache file, rejecting stale, truncated, or unverifiable files.
+///
+/// Other hollowcheck processes may be rewriting the same file, so anything
+/// that fails the footer check is treated as a miss rather than trusted.
+fn load_cache_file(path: &Path) -> Option<HashSet<String>> {
+    let metadata = fs::metadata(path).ok()?;
 
     // Check if stale
     if metadata.modified().ok()?.elapsed().ok()? > CACHE_TTL {
         return None;
     }
 
-    let content = fs::read_to_string(&path).ok()?;
+    let content = cache_file::read_verified(path)?;
     let modules: HashSet<String> = content
         .lines()
         .filter(|l| !l.is_empty() && !l.starts_with('#'))
@@ -159,15 +194,12 @@ fn load_disk_cache(lang: StdlibLanguage) -> Option<HashSet<String>> {
     Some(modules)
 }
 
-/// Save to disk cache.
-fn save_disk_cache(lang: StdlibLanguage, modules: &HashSet<String>, version: &str) {
-    let path = cache_file(lang);
-
-    // Ensure directory exists
-    if let Some(parent) = path.parent() {
-        let _ = fs::create_dir_all(parent);
-    }
-
+/// Atomically write a cache file with a verification footer.
+fn save_cache_file(
+    path: &Path,
+    modules: &HashSet<String>,
+    version: &str,
+) -> std::io::Result<()> {
     let mut content = format!("# version: {}\n", version);
     let mut sorted: Vec<_> = modules.iter().collect();
     sorted.sort();
@@ -176,7 +208,7 @@ fn save_disk_cache(lang: StdlibLanguage, modules: &HashSet<String>, version: &st
         content.push('\n');
     }
 
-    let _ = fs::write(path, content);
+    cache_file::write_atomic(path, &content)
 }
 
 /// Update in-memory cache.
@@ -250,6 +282,27 @@ else:
     Some((modules, version))
 }
 
+/// Version of the runtime queried for a language's stdlib, or None when it
+/// is not installed. Rust needs no runtime and always has None.
+pub(crate) fn runtime_version(lang: StdlibLanguage) -> Option<String> {
+    let (command, args): (String, &[&str]) = match lang {
+        StdlibLanguage::Python => (find_python()?, &["--version"]),
+        StdlibLanguage::JavaScript => ("node".to_string(), &["--version"]),
+        StdlibLanguage::Go => ("go".to_string(), &["version"]),
+        StdlibLanguage::Rust => return None,
+    };
+    let output = Command::new(&command).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Python 2 and some 3.x builds print the version on stderr
+    let version = [&output.stdout, &output.stderr]
+        .iter()
+        .map(|out| String::from_utf8_lossy(out).trim().to_string())
+        .find(|s| !s.is_empty())?;
+    Some(version)
+}
+
 /// Find Python executable.
 fn find_python() -> Option<String> {
     for cmd in ["python3", "python"] {
@@ -401,4 +454,55 @@ mod tests {
         // 1000 cached lookups should be < 50ms (being generous for CI)
         assert!(elapsed.as_millis() < 50, "Cache too slow: {:?}", elapsed);
     }
+
+    #[test]
+    fn test_disk_cache_rejects_truncated_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("go_stdlib.txt");
+        let modules: HashSet<String> = ["fmt", "net/http", "os"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        save_cache_file(&path, &modules, "go1.22").unwrap();
+        assert_eq!(load_cache_file(&path), Some(modules));
+
+        // Simulate a reader that raced a non-atomic writer
+        let content = fs::read_to_string(&path).unwrap();
+        fs::write(&path, &content[..content.len() / 2]).unwrap();
+        assert_eq!(load_cache_file(&path), None);
+    }
+
+    #[test]
+    fn test_disk_cache_concurrent_save_load() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("python_stdlib.txt");
+
+        let sets: Vec<HashSet<String>> = (0..4)
+            .map(|w| (0..300).map(|i| format!("mod_{}_{}", w, i)).collect())
+            .collect();
+
+        std::thread::scope(|scope| {
+            for set in &sets {
+                let path = &path;
+                scope.spawn(move || {
+                    for _ in 0..25 {
+                        save_cache_file(path, set, "3.12").unwrap();
+                    }
+                });
+            }
+            for _ in 0..4 {
+                let (path, sets) = (&path, &sets);
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        if let Some(loaded) = load_cache_file(path) {
+                            assert!(sets.contains(&loaded), "loaded a corrupted module set");
+                        }
+                    }
+                });
+            }
+        });
+
+        assert!(sets.contains(&load_cache_file(&path).unwrap()));
+    }
 }
@@ -5,11 +5,14 @@
 
 use std::path::Path;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 use rayon::prelude::*;
 
 use crate::analysis::{
-    get_analyzer, HollowBodyKind, StubDetector, StubDetectorConfig, StubFinding,
+    default_stub_exemptions, analysis_extension, get_analyzer, with_markers, HollowBodyKind, MarkerVocabulary, StubDetector,
+    StubDetectorConfig, StubExemption, StubFinding, StubSignature, DEFAULT_MIN_DISTINCT_RATIO,
+    DEFAULT_REPETITIVE_MIN_STATEMENTS,
 };
 
 use super::{DetectionResult, Severity, Violation, ViolationRule};
@@ -70,48 +73,13 @@ fn should_skip_stub_detection(file_path: &Path, function_name: &str) -> bool {
     false
 }
 
-/// Check if a function is a legitimate no-op for interface compliance.
-///
-/// Some frameworks (especially Kubernetes) have interfaces where empty
-/// implementations are intentional and correct - they represent optional
-/// hooks or default behaviors that don't need to do anything.
-fn is_legitimate_noop(function_name: &str, file_path: &Path, body_kind: &HollowBodyKind) -> bool {
-    let func_lower = function_name.to_lowercase();
-    let path_str = file_path.to_string_lossy().to_lowercase();
-
-    // Only apply to empty bodies, not panic/todo
-    if !matches!(body_kind, HollowBodyKind::Empty) {
-        return false;
-    }
-
-    // Kubernetes API machinery patterns (interface compliance)
-    let kubernetes_noop_methods = [
-        "canonicalize",           // Strategy pattern - optional normalization
-        "destroy",                // Resource cleanup - may be empty
-        "preparefor",             // Preparation hooks - may be empty
-        "enablemetrics",          // Metrics - may be disabled
-        "setallocated",           // Metrics recording - may be no-op
-        "setavailable",
-        "incrementallocations",
-        "incrementallocationerrors",
-    ];
-
-    for pattern in &kubernetes_noop_methods {
-        if func_lower.contains(pattern) {
-            return true;
-        }
-    }
-
-    // Registry/storage patterns in Kubernetes
-    if path_str.contains("/registry/") && (
-        func_lower.ends_with("rest.destroy") ||
-        func_lower.contains("strategy.") ||
-        func_lower.contains("preparefor")
-    ) {
-        return true;
-    }
-
-    false
+/// A contract's custom stub signature and the severity of its findings.
+#[derive(Debug, Clone)]
+pub struct CustomStubSignature {
+    /// Language and names matched.
+    pub signature: StubSignature,
+    /// Severity of bodies that match.
+    pub severity: Severity,
 }
 
 /// Configuration for stub detection in contracts.
@@ -125,19 +93,36 @@ pub struct StubDetectionConfig {
     pub detect_nil_return: bool,
     /// Enable detection of TODO-comment-only bodies.
     pub detect_todo_comment: bool,
+    /// Distinct-to-total statement ratio below which a body is repetitive (0 disables).
+    pub min_distinct_ratio: f64,
+    /// Statements a body needs before its repetition is checked.
+    pub repetitive_min_statements: usize,
     /// Function names to skip.
     pub skip_functions: Vec<String>,
+    /// Allow rules for intentional stubs.
+    pub exemptions: Vec<StubExemption>,
+    /// Marker vocabulary for TODO-comment-only bodies (default: the built-in words).
+    pub markers: Option<Arc<MarkerVocabulary>>,
+    /// Project stub idioms from the contract's `custom_stub_signatures`.
+    pub custom_signatures: Vec<CustomStubSignature>,
 }
 
 impl StubDetectionConfig {
     /// Create a default configuration (all detections enabled except nil-return).
+    ///
+    /// Repetitive bodies are checked with the default thresholds.
     pub fn default_enabled() -> Self {
         Self {
             detect_empty: true,
             detect_panic: true,
             detect_nil_return: false,
             detect_todo_comment: true,
+            min_distinct_ratio: DEFAULT_MIN_DISTINCT_RATIO,
+            repetitive_min_statements: DEFAULT_REPETITIVE_MIN_STATEMENTS,
             skip_functions: vec!["main".to_string(), "init".to_string()],
+            exemptions: default_stub_exemptions(),
+            markers: None,
+            custom_signatures: vec![],
         }
     }
 }
@@ -161,8 +146,12 @@ pub fn detect_stub_functions<P: AsRef<Path> + Sync>(
             detect_nil_return: cfg.detect_nil_return,
             detect_todo_comment: cfg.detect_todo_comment,
             min_complexity: 0,
+            min_distinct_ratio: cfg.min_distinct_ratio,
+            repetitive_min_statements: cfg.repetitive_min_statements,
             skip_functions: cfg.skip_functions.clone(),
             skip_receivers: vec![],
+            exemptions: cfg.exemptions.clone(),
+            custom_signatures: cfg.custom_signatures.iter().map(|c| c.signature.clone()).collect(),
         }
     } else {
         StubDetectorConfig::default()
@@ -178,7 +167,7 @@ pub fn detect_stub_functions<P: AsRef<Path> + Sync>(
             let path = file.as_ref();
 
             // Get file extension
-            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+            let ext = analysis_extension(path);
 
             // Get analyzer for this extension
             let analyzer = get_analyzer(ext)?;
@@ -186,14 +175,18 @@ pub fn detect_stub_functions<P: AsRef<Path> + Sync>(
             // Read and parse file
             let source = std::fs::read(path).ok()?;
             let parsed = analyzer.parse(path, &source).ok()?;
-            let facts = analyzer.extract_facts(&parsed).ok()?;
+            let facts = match config.and_then(|c| c.markers.as_ref()) {
+                Some(markers) => with_markers(markers, || analyzer.extract_facts(&parsed)),
+                None => analyzer.extract_facts(&parsed),
+            }
+            .ok()?;
 
             scanned.fetch_add(1, Ordering::Relaxed);
 
             // Detect stubs
             let findings = detector.detect(&facts);
 
-            // Convert findings to violations, filtering out test code and legitimate no-ops
+            // Convert findings to violations, filtering out test code
             let violations: Vec<Violation> = findings
                 .into_iter()
                 .filter(|finding| {
@@ -203,7 +196,7 @@ pub fn detect_stub_functions<P: AsRef<Path> + Sync>(
                         .split("::")
                         .last()
                         .or_else(|| finding.qualified_name.split('.')
-                            .last())
+                            .next_back())
                         .unwrap_or(&finding.qualified_name);
 
                     // Skip test code
@@ -211,14 +204,18 @@ pub fn detect_stub_functions<P: AsRef<Path> + Sync>(
                         return false;
                     }
 
-                    // Skip legitimate interface compliance no-ops
-                    if is_legitimate_noop(func_name, path, &finding.kind) {
+                    // A body cut off at the end of the file is reported as truncated code
+                    if facts
+                        .truncated_function
+                        .as_ref()
+                        .is_some_and(|t| t.span.start_byte == finding.span.start_byte)
+                    {
                         return false;
                     }
 
                     true
                 })
-                .map(|finding| stub_finding_to_violation(finding, path))
+                .map(|finding| stub_finding_to_violation(finding, path, config))
                 .collect();
 
             Some(violations)
@@ -238,12 +235,31 @@ pub fn detect_stub_functions<P: AsRef<Path> + Sync>(
 }
 
 /// Convert a StubFinding to a Violation.
-fn stub_finding_to_violation(finding: StubFinding, file_path: &Path) -> Violation {
+fn stub_finding_to_violation(finding: StubFinding, file_path: &Path, config: Option<&StubDetectionConfig>) -> Violation {
+    if let Some((index, statement)) = &finding.custom_signature {
+        let severity = config
+            .and_then(|c| c.custom_signatures.get(*index))
+            .map_or(Severity::Warning, |c| c.severity);
+        return Violation {
+            rule: ViolationRule::StubFunction,
+            message: format!(
+                "stub function {:?}: {} (custom stub signature)",
+                finding.qualified_name,
+                statement.describe()
+            ),
+            file: Some(file_path.to_string_lossy().to_string()),
+            line: finding.span.start_line,
+            severity,
+        };
+    }
+
     let severity = match finding.kind {
         HollowBodyKind::Empty => Severity::Error,
         HollowBodyKind::PanicOnly => Severity::Error,
         HollowBodyKind::TodoCommentOnly => Severity::Warning,
         HollowBodyKind::NilReturnOnly => Severity::Warning,
+        HollowBodyKind::Repetitive => Severity::Warning,
+        HollowBodyKind::CustomSignature => Severity::Warning,
     };
 
     let message = format!(
@@ -255,7 +271,7 @@ fn stub_finding_to_violation(finding: StubFinding, file_path: &Path) -> Violatio
     Violation {
         rule: ViolationRule::StubFunction,
         message,
-        file: file_path.to_string_lossy().to_string(),
+        file: Some(file_path.to_string_lossy().to_string()),
         line: finding.span.start_line,
         severity,
     }
@@ -490,4 +506,299 @@ func Canonicalize() {
         assert_eq!(result.violations.len(), 1);
         assert!(result.violations[0].message.contains("panic"));
     }
+
+    /// Messages of the custom-signature findings in `source`.
+    fn detect_custom(file_name: &str, source: &str, language: &str, names: &[&str]) -> Vec<String> {
+        init_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join(file_name);
+        fs::write(&file_path, source).unwrap();
+
+        let mut config = StubDetectionConfig::default_enabled();
+        config.custom_signatures = vec![CustomStubSignature {
+            signature: StubSignature {
+                language: language.to_string(),
+                names: names.iter().map(|n| n.to_string()).collect(),
+            },
+            severity: Severity::Error,
+        }];
+        let result = detect_stub_functions(&[&file_path], Some(&config)).unwrap();
+        result
+            .violations
+            .into_iter()
+            .filter(|v| v.message.ends_with("(custom stub signature)"))
+            .inspect(|v| assert_eq!(v.severity, Severity::Error))
+            .map(|v| v.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_custom_signature_per_language() {
+        // Each fixture has one-statement stubs, the same name used beside
+        // other statements, and the name inside a string literal
+        // File name, language, signature names, source, expected messages
+        type Fixture = (&'static str, &'static str, &'static [&'static str], &'static str, &'static [&'static str]);
+        let cases: &[Fixture] = &[
+            (
+                "export.py",
+                "python",
+                &["FeatureNotReady", "ERR_NOT_IMPLEMENTED"],
+                r#"
+def export_csv(rows):
+    raise FeatureNotReady("csv export")
+
+def export_json(rows):
+    return None, errors.ERR_NOT_IMPLEMENTED
+
+def parse(text):
+    if not text:
+        raise FeatureNotReady("empty input")
+    return text.split(",")
+
+def describe():
+    return "raise FeatureNotReady"
+"#,
+                &[
+                    r#"stub function "export_csv": only raises FeatureNotReady (custom stub signature)"#,
+                    r#"stub function "export_json": only returns errors.ERR_NOT_IMPLEMENTED (custom stub signature)"#,
+                ],
+            ),
+            (
+                "store.go",
+                "go",
+                &["ErrNotImplemented"],
+                r#"package store
+
+func (s *Store) Export(w io.Writer) error {
+	return ErrNotImplemented
+}
+
+func (s *Store) Sync() (int, error) {
+	return 0, ErrNotImplemented
+}
+
+func (s *Store) Load(key string) (string, error) {
+	if key == "" {
+		return "", ErrNotImplemented
+	}
+	return s.items[key], nil
+}
+
+func Describe() string {
+	return "ErrNotImplemented"
+}
+"#,
+                &[
+                    r#"stub function "Store.Export": only returns ErrNotImplemented (custom stub signature)"#,
+                    r#"stub function "Store.Sync": only returns ErrNotImplemented (custom stub signature)"#,
+                ],
+            ),
+            (
+                "export.js",
+                "javascript",
+                &["notWired"],
+                r#"
+function exportCsv(rows) {
+  return deps.notWired();
+}
+
+function load(key) {
+  const value = cache.get(key);
+  if (!value) deps.notWired();
+  return value;
+}
+
+function label() {
+  return "deps.notWired()";
+}
+"#,
+                &[r#"stub function "exportCsv": only calls deps.notWired() (custom stub signature)"#],
+            ),
+            (
+                "export.ts",
+                "typescript",
+                &["NotImplementedError"],
+                r#"
+function toCsv(rows: string[]): string {
+  throw new NotImplementedError("csv");
+}
+
+function toJson(rows: string[]): string {
+  if (rows.length === 0) {
+    throw new NotImplementedError("empty");
+  }
+  return JSON.stringify(rows);
+}
+
+function label(): string {
+  return "throw new NotImplementedError()";
+}
+"#,
+                &[r#"stub function "toCsv": only raises NotImplementedError (custom stub signature)"#],
+            ),
+            (
+                "export.rs",
+                "rust",
+                &["not_wired"],
+                r#"
+fn export(rows: &[Row]) -> Result<()> {
+    crate::deps::not_wired()
+}
+
+fn import(data: &[u8]) -> Result<()> {
+    if data.is_empty() {
+        return not_wired();
+    }
+    apply(data)
+}
+
+fn label() -> &'static str {
+    "not_wired()"
+}
+"#,
+                &[r#"stub function "export": only calls crate::deps::not_wired() (custom stub signature)"#],
+            ),
+            (
+                "Exporter.java",
+                "java",
+                &["NOT_READY"],
+                r#"
+public class Exporter {
+    public Status export(List<Row> rows) {
+        return Status.NOT_READY;
+    }
+
+    public Status sync(List<Row> rows) {
+        if (rows.isEmpty()) {
+            return Status.NOT_READY;
+        }
+        return store.write(rows);
+    }
+
+    public String label() {
+        return "Status.NOT_READY";
+    }
+}
+"#,
+                &[r#"stub function "export": only returns Status.NOT_READY (custom stub signature)"#],
+            ),
+            (
+                "export.c",
+                "c",
+                &["E_NOT_READY"],
+                r#"
+int export_rows(struct row *rows, int n) {
+    return E_NOT_READY;
+}
+
+int sync_rows(struct row *rows, int n) {
+    if (n == 0) {
+        return E_NOT_READY;
+    }
+    return write_rows(rows, n);
+}
+
+const char *label(void) {
+    return "E_NOT_READY";
+}
+"#,
+                &[r#"stub function "export_rows": only returns E_NOT_READY (custom stub signature)"#],
+            ),
+            (
+                "export.cpp",
+                "cpp",
+                &["FeatureNotReady"],
+                r#"
+int Exporter::exportRows(const Rows& rows) {
+    throw FeatureNotReady("rows");
+}
+
+int Exporter::syncRows(const Rows& rows) {
+    if (rows.empty()) {
+        throw FeatureNotReady("empty");
+    }
+    return store_.write(rows);
+}
+
+std::string Exporter::label() {
+    return "FeatureNotReady";
+}
+"#,
+                &[r#"stub function "exportRows": only raises FeatureNotReady (custom stub signature)"#],
+            ),
+            (
+                "Exporter.scala",
+                "scala",
+                &["FeatureNotReady"],
+                r#"
+object Exporter {
+  def exportRows(rows: Seq[Row]): Int = {
+    throw new FeatureNotReady("rows")
+  }
+
+  def syncRows(rows: Seq[Row]): Int = {
+    if (rows.isEmpty) throw new FeatureNotReady("empty")
+    store.write(rows)
+  }
+
+  def label(): String = {
+    "FeatureNotReady"
+  }
+}
+"#,
+                &[r#"stub function "exportRows": only raises FeatureNotReady (custom stub signature)"#],
+            ),
+            (
+                "Exporter.swift",
+                "swift",
+                &["FeatureNotReady"],
+                r#"
+func exportRows(_ rows: [Row]) throws -> Int {
+    throw FeatureNotReady()
+}
+
+func syncRows(_ rows: [Row]) throws -> Int {
+    if rows.isEmpty {
+        throw FeatureNotReady()
+    }
+    return try store.write(rows)
+}
+
+func label() -> String {
+    return "FeatureNotReady"
+}
+"#,
+                &[r#"stub function "exportRows": only raises FeatureNotReady (custom stub signature)"#],
+            ),
+        ];
+
+        for (file_name, language, names, source, expected) in cases {
+            assert_eq!(&detect_custom(file_name, source, language, names), expected, "{}", language);
+        }
+    }
+
+    #[test]
+    fn test_custom_signature_language_and_receiver() {
+        let source = r#"
+def export_csv(rows):
+    raise FeatureNotReady("csv export")
+
+def connect(url):
+    legacy.not_wired()
+
+def sync(rows):
+    deps.not_wired()
+"#;
+        // A signature for another language never matches
+        assert!(detect_custom("export.py", source, "go", &["FeatureNotReady"]).is_empty());
+        // A dotted name matches only that receiver; a bare one matches any
+        assert_eq!(
+            detect_custom("export.py", source, "python", &["deps.not_wired"]),
+            vec![r#"stub function "sync": only calls deps.not_wired() (custom stub signature)"#]
+        );
+        assert_eq!(detect_custom("export.py", source, "python", &["not_wired"]).len(), 2);
+        // A suffix of a longer name does not match
+        assert!(detect_custom("export.py", source, "python", &["wired", "NotReady"]).is_empty());
+    }
 }
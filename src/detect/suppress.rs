@@ -4,13 +4,35 @@
 //! - `// hollowcheck:ignore <rule> - <reason>`
 //! - `// hollowcheck:ignore-next-line <rule> - <reason>`
 //! - `// hollowcheck:ignore-file <rule> - <reason>`
+//!
+//! and severity downgrades, which keep the violation in the report at a
+//! lower severity instead of removing it:
+//! - `// hollowcheck:downgrade <rule>=<severity> - <reason>` (file-level, at the top of the file)
+//! - `// hollowcheck:downgrade-next-line <rule>=<severity> - <reason>`
+//!
+//! Violations can also be suppressed by fingerprint from a
+//! `.hollowcheck-suppressions.yaml` file at the scan root, for code that
+//! cannot carry a comment or should not be edited:
+//!
+//! ```yaml
+//! suppressions:
+//!   - fingerprint: "3f9a0c2e7b1d4a58"
+//!     reason: Legacy endpoint, removed in 2.0
+//!     expires: 2026-12-31
+//! ```
+//!
+//! Fingerprints are quoted so that one of only digits stays a string. An
+//! entry past its `expires` date no longer applies.
 
 use regex::Regex;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
-use super::{Violation, ViolationRule};
+use crate::analysis::analysis_extension;
+
+use super::files::{lookup_exact_case, CaseLookup};
+use super::{Severity, Violation, ViolationRule};
 
 /// How a suppression applies.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -22,6 +44,8 @@ pub enum SuppressionType {
     NextLine,
     /// Applies to the entire file
     File,
+    /// Applies to the violation with a fingerprint, from the suppression file
+    Fingerprint,
 }
 
 /// An inline suppression directive.
@@ -39,6 +63,30 @@ pub struct Suppression {
     pub suppression_type: SuppressionType,
 }
 
+impl Suppression {
+    /// Create a suppression of `rule` (or "*") with an empty reason.
+    pub fn new(
+        rule: impl Into<String>,
+        file: impl Into<String>,
+        line: usize,
+        suppression_type: SuppressionType,
+    ) -> Self {
+        Self {
+            rule: rule.into(),
+            reason: String::new(),
+            file: file.into(),
+            line,
+            suppression_type,
+        }
+    }
+
+    /// Set the human-readable reason.
+    pub fn with_reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = reason.into();
+        self
+    }
+}
+
 /// A violation that was suppressed.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SuppressedViolation {
@@ -46,17 +94,54 @@ pub struct SuppressedViolation {
     pub suppression: Suppression,
 }
 
+impl SuppressedViolation {
+    pub fn new(violation: Violation, suppression: Suppression) -> Self {
+        Self {
+            violation,
+            suppression,
+        }
+    }
+}
+
+/// An inline directive lowering the severity of matching violations.
+///
+/// Rule, scope, and reason work as for a suppression; matching violations
+/// stay in the report with `severity` instead of being removed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Downgrade {
+    pub suppression: Suppression,
+    /// Severity matching violations are lowered to
+    pub severity: Severity,
+}
+
+/// A violation whose severity was lowered by a downgrade directive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DowngradedViolation {
+    /// The violation as reported, with the lowered severity
+    pub violation: Violation,
+    /// Severity the rule assigned before the downgrade
+    pub original_severity: Severity,
+    pub downgrade: Downgrade,
+}
+
+/// Directives parsed from one or more files.
+#[derive(Debug, Clone, Default)]
+pub struct Directives {
+    pub suppressions: Vec<Suppression>,
+    pub downgrades: Vec<Downgrade>,
+}
+
 lazy_static::lazy_static! {
     /// Patterns for matching suppression comments.
     static ref SUPPRESSION_PATTERNS: Vec<Regex> = vec![
         // Go/JS/TS style: // hollowcheck:...
-        Regex::new(r"//\s*hollowcheck:(ignore(?:-file|-next-line)?)\s+(\S+)\s*(?:-\s*(.*))?").unwrap(),
+        Regex::new(r"//\s*hollowcheck:(ignore(?:-file|-next-line)?|downgrade(?:-next-line)?)\s+(\S+)\s*(?:-\s*(.*))?").unwrap(),
         // Python/Shell style: # hollowcheck:...
-        Regex::new(r"#\s*hollowcheck:(ignore(?:-file|-next-line)?)\s+(\S+)\s*(?:-\s*(.*))?").unwrap(),
+        Regex::new(r"#\s*hollowcheck:(ignore(?:-file|-next-line)?|downgrade(?:-next-line)?)\s+(\S+)\s*(?:-\s*(.*))?").unwrap(),
         // Block comment style: /* hollowcheck:... */
-        Regex::new(r"/\*\s*hollowcheck:(ignore(?:-file|-next-line)?)\s+(\S+)\s*(?:-\s*(.*?))?\s*\*/").unwrap(),
+        Regex::new(r"/\*\s*hollowcheck:(ignore(?:-file|-next-line)?|downgrade(?:-next-line)?)\s+(\S+)\s*(?:-\s*(.*?))?\s*\*/").unwrap(),
         // HTML comment style: <!-- hollowcheck:... -->
-        Regex::new(r"<!--\s*hollowcheck:(ignore(?:-file|-next-line)?)\s+(\S+)\s*(?:-\s*(.*?))?\s*-->").unwrap(),
+        Regex::new(r"<!--\s*hollowcheck:(ignore(?:-file|-next-line)?|downgrade(?:-next-line)?)\s+(\S+)\s*(?:-\s*(.*?))?\s*-->").unwrap(),
     ];
 
     /// Comment prefixes by file extension.
@@ -89,7 +174,14 @@ lazy_static::lazy_static! {
 
 /// Parse suppression directives from file content.
 pub fn parse_suppressions(file_path: &str, content: &str) -> Vec<Suppression> {
-    let mut suppressions = Vec::new();
+    parse_directives(file_path, content).suppressions
+}
+
+/// Parse suppression and downgrade directives from file content.
+///
+/// A downgrade whose target is not a known severity is ignored.
+pub fn parse_directives(file_path: &str, content: &str) -> Directives {
+    let mut directives = Directives::default();
     let mut in_package_block = true;
 
     for (line_num, line) in content.lines().enumerate() {
@@ -112,14 +204,14 @@ pub fn parse_suppressions(file_path: &str, content: &str) -> Vec<Suppression> {
                     .unwrap_or_default();
 
                 let suppression_type = match directive {
-                    "ignore-file" => {
+                    "ignore-file" | "downgrade" => {
                         // File-level suppressions must be at the top of the file
                         if !in_package_block && line_number > 10 {
                             continue;
                         }
                         SuppressionType::File
                     }
-                    "ignore-next-line" => SuppressionType::NextLine,
+                    "ignore-next-line" | "downgrade-next-line" => SuppressionType::NextLine,
                     "ignore" => {
                         // Check if there's content before the suppression directive.
                         // If the suppression is alone on the line, treat as next-line.
@@ -146,23 +238,39 @@ pub fn parse_suppressions(file_path: &str, content: &str) -> Vec<Suppression> {
                     _ => continue,
                 };
 
-                suppressions.push(Suppression {
-                    rule: rule.to_string(),
-                    reason,
-                    file: file_path.to_string(),
-                    line: if suppression_type == SuppressionType::File {
-                        0
-                    } else {
-                        line_number
-                    },
-                    suppression_type,
-                });
-                break; // Only one suppression per line
+                let line = if suppression_type == SuppressionType::File {
+                    0
+                } else {
+                    line_number
+                };
+                if directive.starts_with("downgrade") {
+                    // `<rule>=<severity>`
+                    let Some((rule, severity)) = rule.split_once('=') else {
+                        break;
+                    };
+                    let Ok(severity) = severity.parse::<Severity>() else {
+                        break;
+                    };
+                    directives.downgrades.push(Downgrade {
+                        suppression: Suppression::new(rule, file_path, line, suppression_type)
+                            .with_reason(reason),
+                        severity,
+                    });
+                } else {
+                    directives.suppressions.push(Suppression {
+                        rule: rule.to_string(),
+                        reason,
+                        file: file_path.to_string(),
+                        line,
+                        suppression_type,
+                    });
+                }
+                break; // Only one directive per line
             }
         }
     }
 
-    suppressions
+    directives
 }
 
 /// Check if a line is a comment or empty for the given file type.
@@ -171,10 +279,7 @@ fn is_comment_or_empty(line: &str, file_path: &str) -> bool {
         return true;
     }
 
-    let ext = Path::new(file_path)
-        .extension()
-        .and_then(|e| e.to_str())
-        .unwrap_or("");
+    let ext = analysis_extension(Path::new(file_path));
 
     let default_prefixes: &[&str] = &["//", "#", "/*", "<!--"];
     let prefixes = COMMENT_PREFIXES
@@ -193,14 +298,14 @@ fn is_comment_or_empty(line: &str, file_path: &str) -> bool {
 
 /// Check if a violation matches a suppression.
 pub fn matches_suppression(violation: &Violation, suppression: &Suppression) -> bool {
-    // Must be same file
-    if violation.file != suppression.file {
+    // Must be same file; project-level violations have no file to annotate
+    if violation.file.as_deref() != Some(suppression.file.as_str()) {
         return false;
     }
 
     // Must match rule (or suppression is for all rules with "*")
     if suppression.rule != "*" {
-        if let Some(rule) = ViolationRule::parse(&suppression.rule) {
+        if let Ok(rule) = suppression.rule.parse::<ViolationRule>() {
             if violation.rule != rule {
                 return false;
             }
@@ -213,6 +318,8 @@ pub fn matches_suppression(violation: &Violation, suppression: &Suppression) ->
         SuppressionType::File => true,
         SuppressionType::Line => violation.line == suppression.line,
         SuppressionType::NextLine => violation.line == suppression.line + 1,
+        // Matched by fingerprint in filter_fingerprint_suppressed
+        SuppressionType::Fingerprint => false,
     }
 }
 
@@ -228,10 +335,7 @@ pub fn filter_suppressed(
         let mut was_suppressed = false;
         for suppression in suppressions {
             if matches_suppression(&violation, suppression) {
-                suppressed.push(SuppressedViolation {
-                    violation: violation.clone(),
-                    suppression: suppression.clone(),
-                });
+                suppressed.push(SuppressedViolation::new(violation.clone(), suppression.clone()));
                 was_suppressed = true;
                 break;
             }
@@ -244,6 +348,288 @@ pub fn filter_suppressed(
     (active, suppressed)
 }
 
+/// Rule a suppression names: `None` for `*`, `Err` for an unknown name.
+fn suppressed_rule(suppression: &Suppression) -> Result<Option<ViolationRule>, ()> {
+    if suppression.rule == "*" {
+        return Ok(None);
+    }
+    suppression.rule.parse::<ViolationRule>().map(Some).map_err(|_| ())
+}
+
+/// Report inline suppressions that suppress nothing.
+///
+/// A suppression is unused when no violation in `violations`, the findings
+/// before suppressions apply, matches it. With `redundant`, a line
+/// suppression is also reported when an `ignore-file` directive in the same
+/// file already covers its rule. Suppressions of rules for which `checked`
+/// is false are skipped: a rule that did not run says nothing about the
+/// code. Each finding comes paired with the suppression it describes.
+pub fn detect_stale_suppressions(
+    violations: &[Violation],
+    suppressions: &[Suppression],
+    checked: impl Fn(ViolationRule) -> bool,
+    redundant: bool,
+) -> Vec<SuppressedViolation> {
+    let mut stale = Vec::new();
+
+    for suppression in suppressions {
+        let rule = match suppressed_rule(suppression) {
+            Ok(rule) => rule,
+            Err(()) => {
+                let message = format!("suppression names unknown rule '{}'", suppression.rule);
+                stale.push((suppression, message));
+                continue;
+            }
+        };
+        if rule.is_some_and(|r| !checked(r)) {
+            continue;
+        }
+        let described = match rule {
+            Some(rule) => rule.as_str().to_string(),
+            None => "all rules".to_string(),
+        };
+
+        let is_file_level = suppression.suppression_type == SuppressionType::File;
+        let shadowed_by_file = !is_file_level
+            && suppressions.iter().any(|f| {
+                f.suppression_type == SuppressionType::File
+                    && f.file == suppression.file
+                    && suppressed_rule(f).is_ok_and(|r| r.is_none() || r == rule)
+            });
+        if redundant && shadowed_by_file {
+            let message = format!(
+                "suppression of {} is redundant: an ignore-file directive already suppresses it in this file",
+                described
+            );
+            stale.push((suppression, message));
+            continue;
+        }
+
+        if !violations.iter().any(|v| matches_suppression(v, suppression)) {
+            let message = if is_file_level {
+                format!("file-level suppression of {} matched no violation in this file", described)
+            } else {
+                format!("suppression of {} matched no violation", described)
+            };
+            stale.push((suppression, message));
+        }
+    }
+
+    stale
+        .into_iter()
+        .map(|(suppression, message)| {
+            SuppressedViolation::new(
+                Violation::new(ViolationRule::UnusedSuppression, message).at(suppression.file.clone(), suppression.line),
+                suppression.clone(),
+            )
+        })
+        .collect()
+}
+
+/// Name of the fingerprint suppression file, at the scan root.
+pub const SUPPRESSION_FILE: &str = ".hollowcheck-suppressions.yaml";
+
+/// An entry of the suppression file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FingerprintSuppression {
+    pub fingerprint: String,
+    #[serde(default)]
+    pub reason: String,
+    /// Last day the entry applies, as `YYYY-MM-DD`
+    #[serde(default)]
+    pub expires: Option<String>,
+}
+
+impl FingerprintSuppression {
+    /// Whether the entry no longer applies on `today` (`YYYY-MM-DD`).
+    pub fn is_expired(&self, today: &str) -> bool {
+        self.expires.as_deref().is_some_and(|expires| expires < today)
+    }
+}
+
+#[derive(Deserialize)]
+struct SuppressionFile {
+    #[serde(default)]
+    suppressions: Vec<FingerprintSuppression>,
+}
+
+/// Whether `date` has the form `YYYY-MM-DD`.
+pub(crate) fn is_iso_date(date: &str) -> bool {
+    let bytes = date.as_bytes();
+    bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+}
+
+/// Load the suppression file from `base_dir`; no file means no entries.
+pub fn load_suppression_file(base_dir: &Path) -> anyhow::Result<Vec<FingerprintSuppression>> {
+    let path = base_dir.join(SUPPRESSION_FILE);
+    // Only the exact name counts, even where the filesystem ignores case
+    if lookup_exact_case(base_dir, SUPPRESSION_FILE) != CaseLookup::Exact {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| anyhow::anyhow!("cannot read {:?}: {}", path, e))?;
+    if content.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+    let file: SuppressionFile = serde_yaml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("invalid suppression file {:?}: {}", path, e))?;
+    for entry in &file.suppressions {
+        if let Some(expires) = entry.expires.as_deref().filter(|e| !is_iso_date(e)) {
+            anyhow::bail!(
+                "invalid suppression file {:?}: expires {:?} of {} is not a YYYY-MM-DD date",
+                path,
+                expires,
+                entry.fingerprint
+            );
+        }
+    }
+    Ok(file.suppressions)
+}
+
+/// Today's UTC date as `YYYY-MM-DD`.
+pub(crate) fn today() -> String {
+    let secs = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    civil_date(secs / 86_400)
+}
+
+/// Day count since 1970-01-01 of a `YYYY-MM-DD` date.
+pub(crate) fn days_since_epoch(date: &str) -> Option<i64> {
+    if !is_iso_date(date) {
+        return None;
+    }
+    let year: i64 = date[0..4].parse().ok()?;
+    let month: i64 = date[5..7].parse().ok()?;
+    let day: i64 = date[8..10].parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    // Howard Hinnant's days_from_civil
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146_097 + doe - 719_468)
+}
+
+/// Proleptic Gregorian date of a day count since 1970-01-01.
+fn civil_date(days: u64) -> String {
+    // Howard Hinnant's days_from_civil, inverted
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// Separate violations into active and suppressed by the fingerprint
+/// suppression file. `fingerprint` gives each violation's fingerprint;
+/// entries expired on `today` are ignored.
+pub fn filter_fingerprint_suppressed(
+    violations: Vec<Violation>,
+    entries: &[FingerprintSuppression],
+    file: &str,
+    today: &str,
+    fingerprint: impl Fn(&Violation) -> String,
+) -> (Vec<Violation>, Vec<SuppressedViolation>) {
+    let live: Vec<&FingerprintSuppression> = entries.iter().filter(|e| !e.is_expired(today)).collect();
+    if live.is_empty() {
+        return (violations, Vec::new());
+    }
+
+    let mut active = Vec::new();
+    let mut suppressed = Vec::new();
+    for violation in violations {
+        let fp = fingerprint(&violation);
+        match live.iter().find(|e| e.fingerprint == fp) {
+            Some(entry) => {
+                let suppression =
+                    Suppression::new(violation.rule.as_str(), file, 0, SuppressionType::Fingerprint)
+                        .with_reason(entry.reason.clone());
+                suppressed.push(SuppressedViolation::new(violation, suppression));
+            }
+            None => active.push(violation),
+        }
+    }
+    (active, suppressed)
+}
+
+/// Rank of a severity, higher is more severe.
+fn severity_rank(severity: Severity) -> u8 {
+    match severity {
+        Severity::Info => 0,
+        Severity::Warning => 1,
+        Severity::Error => 2,
+        Severity::Critical => 3,
+    }
+}
+
+/// Lower the severity of violations matched by a downgrade directive.
+///
+/// The first matching directive applies. A directive never raises a
+/// severity: a violation already at or below its target is left alone.
+pub fn apply_downgrades(
+    violations: &mut [Violation],
+    downgrades: &[Downgrade],
+) -> Vec<DowngradedViolation> {
+    let mut downgraded = Vec::new();
+    if downgrades.is_empty() {
+        return downgraded;
+    }
+
+    for violation in violations.iter_mut() {
+        let Some(downgrade) = downgrades
+            .iter()
+            .find(|d| matches_suppression(violation, &d.suppression))
+        else {
+            continue;
+        };
+        if severity_rank(downgrade.severity) >= severity_rank(violation.severity) {
+            continue;
+        }
+        let original_severity = violation.severity;
+        violation.severity = downgrade.severity;
+        downgraded.push(DowngradedViolation {
+            violation: violation.clone(),
+            original_severity,
+            downgrade: downgrade.clone(),
+        });
+    }
+
+    downgraded
+}
+
+/// Collect suppression and downgrade directives from all files.
+pub fn collect_directives<P: AsRef<Path>>(files: &[P]) -> Directives {
+    let mut result = Directives::default();
+
+    for file in files {
+        let path = file.as_ref();
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue; // Skip files we can't read
+        };
+
+        let directives = parse_directives(&path.to_string_lossy(), &content);
+        result.suppressions.extend(directives.suppressions);
+        result.downgrades.extend(directives.downgrades);
+    }
+
+    result
+}
+
 /// Collect suppressions from all files.
 pub fn collect_suppressions<P: AsRef<Path>>(
     files: &[P],
@@ -267,6 +653,51 @@ pub fn collect_suppressions<P: AsRef<Path>>(
     Ok(result)
 }
 
+/// Insert an `ignore-next-line` comment above each line of `content` with
+/// a violation, in the file's line comment style, line endings, and the
+/// indentation of the line it suppresses. A line with violations of
+/// several rules gets one comment for all rules (`*`).
+///
+/// Returns `None` for a file type without a known comment style.
+pub fn insert_suppression_comments(
+    file_path: &str,
+    content: &str,
+    violations: &[&Violation],
+    reason: &str,
+) -> Option<String> {
+    let ext = analysis_extension(Path::new(file_path));
+    let open = *COMMENT_PREFIXES.get(ext)?.first()?;
+    let close = if open == "<!--" { " -->" } else { "" };
+    let ending = crate::patch::line_ending(content);
+
+    let mut rules: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for v in violations.iter().filter(|v| v.line > 0) {
+        let entry = rules.entry(v.line).or_default();
+        if !entry.contains(&v.rule.as_str()) {
+            entry.push(v.rule.as_str());
+        }
+    }
+
+    let mut out = String::with_capacity(content.len());
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        if let Some(line_rules) = rules.get(&(index + 1)) {
+            let indent_len = line.len() - line.trim_start_matches([' ', '\t']).len();
+            let rule = if line_rules.len() == 1 { line_rules[0] } else { "*" };
+            out.push_str(&format!(
+                "{}{} hollowcheck:ignore-next-line {} - {}{}{}",
+                &line[..indent_len],
+                open,
+                rule,
+                reason,
+                close,
+                ending
+            ));
+        }
+        out.push_str(line);
+    }
+    Some(out)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,7 +741,7 @@ var testUrl = "example.com"
         let violation = Violation {
             rule: ViolationRule::ForbiddenPattern,
             message: "TODO found".to_string(),
-            file: "main.go".to_string(),
+            file: Some("main.go".to_string()),
             line: 5,
             severity: Severity::Error,
         };
@@ -355,4 +786,200 @@ var testUrl = "example.com"
         };
         assert!(matches_suppression(&violation, &wildcard));
     }
+
+    #[test]
+    fn test_parse_downgrades() {
+        let content = r#"// hollowcheck:downgrade stub_function=info - mid-rewrite, tracked in JIRA-123
+// hollowcheck:downgrade god_file=loud - not a severity
+package billing
+
+// hollowcheck:ignore-file mock_data - fixtures
+
+func Charge() {
+    // hollowcheck:downgrade-next-line forbidden_pattern=warning
+    // FIXME: retry
+}
+
+// hollowcheck:downgrade low_complexity=info - too late for a file-level directive
+"#;
+        let directives = parse_directives("billing.go", content);
+        assert_eq!(directives.suppressions.len(), 1);
+        assert_eq!(directives.downgrades.len(), 2);
+
+        let file = &directives.downgrades[0];
+        assert_eq!(file.suppression.rule, "stub_function");
+        assert_eq!(file.suppression.suppression_type, SuppressionType::File);
+        assert_eq!(file.suppression.reason, "mid-rewrite, tracked in JIRA-123");
+        assert_eq!(file.severity, Severity::Info);
+
+        let next = &directives.downgrades[1];
+        assert_eq!(next.suppression.suppression_type, SuppressionType::NextLine);
+        assert_eq!(next.suppression.line, 8);
+        assert_eq!(next.severity, Severity::Warning);
+
+        // Downgrades are not suppressions
+        assert_eq!(parse_suppressions("billing.go", content).len(), 1);
+    }
+
+    #[test]
+    fn test_apply_downgrades_never_raises() {
+        let downgrade = |rule: &str, severity| Downgrade {
+            suppression: Suppression::new(rule, "main.go", 0, SuppressionType::File),
+            severity,
+        };
+        let mut violations = vec![
+            Violation::new(ViolationRule::StubFunction, "empty").at("main.go", 3),
+            Violation::new(ViolationRule::MockData, "example.com")
+                .at("main.go", 9)
+                .with_severity(Severity::Info),
+            Violation::new(ViolationRule::StubFunction, "empty").at("other.go", 3),
+        ];
+        let downgrades = [
+            downgrade("stub_function", Severity::Warning),
+            downgrade("mock_data", Severity::Error),
+        ];
+
+        let downgraded = apply_downgrades(&mut violations, &downgrades);
+        assert_eq!(downgraded.len(), 1);
+        assert_eq!(downgraded[0].original_severity, ViolationRule::StubFunction.default_severity());
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(violations[1].severity, Severity::Info);
+        assert_eq!(violations[2].severity, ViolationRule::StubFunction.default_severity());
+    }
+
+    #[test]
+    fn test_suppression_file_and_expiry() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(11_016), "2000-02-29");
+        assert_eq!(civil_date(20_743), "2026-10-17");
+        assert_eq!(days_since_epoch("2000-02-29"), Some(11_016));
+        assert_eq!(days_since_epoch("2026-10-17"), Some(20_743));
+        assert_eq!(days_since_epoch("2026-13-01"), None);
+
+        let temp = tempfile::TempDir::new().unwrap();
+        assert!(load_suppression_file(temp.path()).unwrap().is_empty());
+        std::fs::write(temp.path().join(".Hollowcheck-Suppressions.yaml"), "suppressions: [{fingerprint: aaaa}]\n")
+            .unwrap();
+        assert!(load_suppression_file(temp.path()).unwrap().is_empty());
+
+        std::fs::write(
+            temp.path().join(SUPPRESSION_FILE),
+            "suppressions:\n  - fingerprint: aaaa\n    reason: legacy\n    expires: 2026-06-30\n  - fingerprint: bbbb\n  - fingerprint: \"0012345678901234\"\n",
+        )
+        .unwrap();
+        let entries = load_suppression_file(temp.path()).unwrap();
+        assert_eq!(entries.len(), 3);
+
+        let violations = vec![
+            Violation::new(ViolationRule::StubFunction, "aaaa").at("a.go", 1),
+            Violation::new(ViolationRule::StubFunction, "bbbb").at("b.go", 1),
+            Violation::new(ViolationRule::StubFunction, "cccc").at("c.go", 1),
+        ];
+        let by_message = |v: &Violation| v.message.clone();
+
+        let (active, suppressed) =
+            filter_fingerprint_suppressed(violations.clone(), &entries, SUPPRESSION_FILE, "2026-06-30", by_message);
+        assert_eq!(active.len(), 1);
+        assert_eq!(suppressed.len(), 2);
+        assert_eq!(suppressed[0].suppression.suppression_type, SuppressionType::Fingerprint);
+        assert_eq!(suppressed[0].suppression.reason, "legacy");
+
+        let (active, suppressed) =
+            filter_fingerprint_suppressed(violations, &entries, SUPPRESSION_FILE, "2026-07-01", by_message);
+        assert_eq!(active.len(), 2);
+        assert_eq!(suppressed[0].violation.message, "bbbb");
+
+        std::fs::write(
+            temp.path().join(SUPPRESSION_FILE),
+            "suppressions:\n  - fingerprint: aaaa\n    expires: next week\n",
+        )
+        .unwrap();
+        assert!(load_suppression_file(temp.path()).is_err());
+    }
+
+    #[test]
+    fn test_insert_suppression_comments() {
+        let content = "package main\r\n\r\nfunc main() {\r\n\t// TODO: later\r\n\tpanic(\"x\")\r\n}";
+        let violations = [
+            Violation::new(ViolationRule::ForbiddenPattern, "todo").at("main.go", 4),
+            Violation::new(ViolationRule::ForbiddenPattern, "panic").at("main.go", 5),
+            Violation::new(ViolationRule::StubFunction, "stub").at("main.go", 5),
+            Violation::new(ViolationRule::MissingFile, "project").at("main.go", 0),
+        ];
+        let refs: Vec<&Violation> = violations.iter().collect();
+
+        let edited = insert_suppression_comments("main.go", content, &refs, "existing").unwrap();
+        assert_eq!(
+            edited,
+            "package main\r\n\r\nfunc main() {\r\n\
+             \t// hollowcheck:ignore-next-line forbidden_pattern - existing\r\n\t// TODO: later\r\n\
+             \t// hollowcheck:ignore-next-line * - existing\r\n\tpanic(\"x\")\r\n}"
+        );
+
+        // The inserted comments suppress the violations on their next line
+        let suppressions = parse_suppressions("main.go", &edited);
+        assert_eq!(suppressions.len(), 2);
+        assert_eq!(suppressions[0].suppression_type, SuppressionType::NextLine);
+        assert_eq!(suppressions[0].line, 4);
+        assert_eq!(suppressions[1].rule, "*");
+
+        let html = insert_suppression_comments("a.html", "<p>x</p>\n", &refs[..1], "r");
+        assert_eq!(html, Some("<p>x</p>\n".to_string()));
+        let html = insert_suppression_comments(
+            "a.html",
+            "<p>x</p>\n",
+            &[&Violation::new(ViolationRule::ForbiddenPattern, "x").at("a.html", 1)],
+            "r",
+        );
+        assert_eq!(
+            html.unwrap(),
+            "<!-- hollowcheck:ignore-next-line forbidden_pattern - r -->\n<p>x</p>\n"
+        );
+        assert!(insert_suppression_comments("data.bin", "x\n", &refs, "r").is_none());
+    }
+
+    #[test]
+    fn test_detect_stale_suppressions() {
+        let content = r#"package main
+
+// hollowcheck:ignore-file mock_data - Fixtures
+func main() {
+    // TODO: later // hollowcheck:ignore forbidden_pattern - Tracked
+    x := 1 // hollowcheck:ignore forbidden_pattern - Stale
+    y := 2 // hollowcheck:ignore mock_data - Covered by the file directive
+    z := 3 // hollowcheck:ignore no_such_rule - Typo
+    w := 4 // hollowcheck:ignore weak_crypto - Not checked
+}
+"#;
+        let suppressions = parse_suppressions("main.go", content);
+        assert_eq!(suppressions.len(), 6);
+        let violations = vec![Violation::new(ViolationRule::ForbiddenPattern, "TODO found").at("main.go", 5)];
+        let checked = |rule: ViolationRule| rule != ViolationRule::WeakCrypto;
+
+        let stale = detect_stale_suppressions(&violations, &suppressions, checked, true);
+        let found: Vec<_> = stale
+            .iter()
+            .map(|s| {
+                assert_eq!(s.violation.rule, ViolationRule::UnusedSuppression);
+                (s.violation.line, s.violation.message.as_str())
+            })
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (0, "file-level suppression of mock_data matched no violation in this file"),
+                (6, "suppression of forbidden_pattern matched no violation"),
+                (
+                    7,
+                    "suppression of mock_data is redundant: an ignore-file directive already suppresses it in this file"
+                ),
+                (8, "suppression names unknown rule 'no_such_rule'"),
+            ]
+        );
+
+        // Without redundancy checks the shadowed line suppression is merely unused
+        let stale = detect_stale_suppressions(&violations, &suppressions, checked, false);
+        assert_eq!(stale.len(), 4);
+        assert_eq!(stale[2].violation.message, "suppression of mock_data matched no violation");
+    }
 }
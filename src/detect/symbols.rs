@@ -1,16 +1,17 @@
 //! Detection of missing required symbols and tests.
 //!
-//! This module uses AST-backed analysis via the AnalysisContext to extract
-//! symbols from source files. For supported languages, it uses tree-sitter
-//! for accurate parsing. Unsupported extensions result in explicit failures.
+//! This module reads declarations from the scan's `FactsBundle`. For
+//! supported languages they come from tree-sitter; unsupported extensions
+//! result in explicit failures.
 
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
-use crate::analysis::{get_analyzer, AnalysisContext, DeclarationKind, FileFacts};
+use crate::analysis::{DeclarationKind, FileFacts};
 use crate::contract::{RequiredSymbol, RequiredTest, SymbolKind};
 
-use super::{DetectionResult, Severity, Violation, ViolationRule};
+use super::files::case_mismatch_message;
+use super::{DetectionResult, FactsBundle, Severity, Violation, ViolationRule};
 
 /// Information about a found symbol.
 #[derive(Debug, Clone)]
@@ -23,7 +24,7 @@ pub struct SymbolInfo {
 }
 
 /// Convert from analysis DeclarationKind to contract SymbolKind.
-fn declaration_kind_to_symbol_kind(kind: DeclarationKind) -> SymbolKind {
+pub fn declaration_kind_to_symbol_kind(kind: DeclarationKind) -> SymbolKind {
     match kind {
         DeclarationKind::Function => SymbolKind::Function,
         DeclarationKind::Method => SymbolKind::Method,
@@ -38,7 +39,7 @@ fn declaration_kind_to_symbol_kind(kind: DeclarationKind) -> SymbolKind {
 /// Uses AST-backed analysis for supported languages. Files with unsupported
 /// extensions will cause an explicit failure for any symbols required in them.
 pub fn detect_missing_symbols<P: AsRef<Path>>(
-    analysis_ctx: &AnalysisContext,
+    facts: &FactsBundle,
     files: &[P],
     symbols: &[RequiredSymbol],
 ) -> anyhow::Result<DetectionResult> {
@@ -48,7 +49,7 @@ pub fn detect_missing_symbols<P: AsRef<Path>>(
         return Ok(result);
     }
 
-    let base = analysis_ctx.base_dir();
+    let base = facts.base_dir();
 
     // Collect the set of files we actually need to analyze
     let required_files: HashSet<&str> = symbols.iter().map(|s| s.file.as_str()).collect();
@@ -59,6 +60,9 @@ pub fn detect_missing_symbols<P: AsRef<Path>>(
     // Build a map of found symbols by file (only for required files)
     let mut found_symbols: HashMap<String, Vec<SymbolInfo>> = HashMap::new();
 
+    // Every scanned path, as walked, to explain required files that differ only by case
+    let mut scanned_paths: Vec<String> = Vec::new();
+
     // Sort files for deterministic processing
     let mut sorted_files: Vec<_> = files.iter().collect();
     sorted_files.sort_by(|a, b| a.as_ref().cmp(b.as_ref()));
@@ -70,24 +74,22 @@ pub fn detect_missing_symbols<P: AsRef<Path>>(
             .unwrap_or(path)
             .to_string_lossy()
             .to_string();
+        scanned_paths.push(rel_path.clone());
 
-        // Skip files that aren't needed
+        // Skip files that aren't needed; paths compare case-sensitively
         if !required_files.contains(rel_path.as_str()) {
             continue;
         }
 
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-
-        // Check if we have an analyzer for this extension
-        if get_analyzer(ext).is_none() {
+        // Files without facts have no analyzer for their extension
+        let Some(file_facts) = facts.file(path) else {
             unsupported_files.insert(rel_path.clone());
             continue;
-        }
+        };
 
-        // Use AST-backed analysis
-        match analysis_ctx.analyze_file(path) {
-            Ok(facts) => {
-                let syms = extract_symbols_from_facts(&facts);
+        match file_facts {
+            Ok(file_facts) => {
+                let syms = extract_symbols_from_facts(file_facts);
                 found_symbols.insert(rel_path, syms);
                 result.scanned += 1;
             }
@@ -96,7 +98,7 @@ pub fn detect_missing_symbols<P: AsRef<Path>>(
                 result.add_violation(Violation {
                     rule: ViolationRule::MissingSymbol,
                     message: format!("failed to parse file for symbol extraction: {}", e),
-                    file: rel_path,
+                    file: Some(rel_path),
                     line: 0,
                     severity: Severity::Error,
                 });
@@ -116,13 +118,33 @@ pub fn detect_missing_symbols<P: AsRef<Path>>(
                     "cannot verify {} {:?}: no analyzer for file extension",
                     req.kind, req.name
                 ),
-                file: req.file.clone(),
+                file: Some(req.file.clone()),
                 line: 0,
                 severity: Severity::Critical,
             });
             continue;
         }
 
+        // A file that only exists with different case is reported as such
+        if !scanned_paths.contains(&req.file) {
+            let lower = req.file.to_lowercase();
+            if let Some(found) = scanned_paths.iter().find(|p| p.to_lowercase() == lower) {
+                violations.push(Violation {
+                    rule: ViolationRule::MissingSymbol,
+                    message: format!(
+                        "{}: cannot verify {} {:?}",
+                        case_mismatch_message(found, &req.file),
+                        req.kind,
+                        req.name
+                    ),
+                    file: Some(req.file.clone()),
+                    line: 0,
+                    severity: Severity::Critical,
+                });
+                continue;
+            }
+        }
+
         let found = found_symbols
             .get(&req.file)
             .map(|syms| {
@@ -135,7 +157,7 @@ pub fn detect_missing_symbols<P: AsRef<Path>>(
             violations.push(Violation {
                 rule: ViolationRule::MissingSymbol,
                 message: format!("required {} {:?} not found", req.kind, req.name),
-                file: req.file.clone(),
+                file: Some(req.file.clone()),
                 line: 0,
                 severity: Severity::Critical,
             });
@@ -169,9 +191,9 @@ fn extract_symbols_from_facts(facts: &FileFacts) -> Vec<SymbolInfo> {
 }
 
 /// Check that all required test functions exist.
-pub fn detect_missing_tests<P1: AsRef<Path>, P2: AsRef<Path>>(
-    base_dir: P1,
-    files: &[P2],
+pub fn detect_missing_tests<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
     tests: &[RequiredTest],
 ) -> anyhow::Result<DetectionResult> {
     let mut result = DetectionResult::new();
@@ -180,10 +202,7 @@ pub fn detect_missing_tests<P1: AsRef<Path>, P2: AsRef<Path>>(
         return Ok(result);
     }
 
-    let base = base_dir.as_ref();
-
-    // Create an analysis context for this check
-    let analysis_ctx = AnalysisContext::new(base);
+    let base = facts.base_dir();
 
     // Build a map of found test functions by file
     let mut found_tests: HashMap<String, Vec<String>> = HashMap::new();
@@ -195,10 +214,9 @@ pub fn detect_missing_tests<P1: AsRef<Path>, P2: AsRef<Path>>(
     // Only parse test files
     for file in sorted_files {
         let path = file.as_ref();
-        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
 
-        // Only parse Go test files for now
-        if !file_name.ends_with("_test.go") {
+        // Only Go test files for now
+        if !facts.is_test_file(path) || path.extension().is_none_or(|e| e != "go") {
             continue;
         }
 
@@ -209,8 +227,8 @@ pub fn detect_missing_tests<P1: AsRef<Path>, P2: AsRef<Path>>(
             .to_string();
 
         // Use AST-backed analysis
-        if let Ok(facts) = analysis_ctx.analyze_file(path) {
-            let test_names: Vec<String> = facts
+        if let Some(Ok(file_facts)) = facts.file(path) {
+            let test_names: Vec<String> = file_facts
                 .declarations
                 .iter()
                 .filter(|d| {
@@ -241,14 +259,11 @@ pub fn detect_missing_tests<P1: AsRef<Path>, P2: AsRef<Path>>(
         };
 
         if !found {
-            let file = req
-                .file
-                .clone()
-                .unwrap_or_else(|| "(any test file)".to_string());
+            // Tests not pinned to a file are missing from the whole project
             violations.push(Violation {
                 rule: ViolationRule::MissingTest,
                 message: format!("required test {:?} not found", req.name),
-                file,
+                file: req.file.clone(),
                 line: 0,
                 severity: Severity::Warning,
             });
@@ -270,6 +285,7 @@ pub fn detect_missing_tests<P1: AsRef<Path>, P2: AsRef<Path>>(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::analysis::AnalysisContext;
     use tempfile::TempDir;
 
     #[test]
@@ -343,11 +359,45 @@ func main() {}
             },
         ];
 
-        let result = detect_missing_symbols(&analysis_ctx, &[&file_path], &symbols).unwrap();
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::MissingSymbol]);
+        let result = detect_missing_symbols(&facts, &[&file_path], &symbols).unwrap();
         assert_eq!(result.violations.len(), 1);
         assert!(result.violations[0].message.contains("Handler"));
     }
 
+    #[test]
+    fn test_required_file_case_mismatch() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        std::fs::create_dir(temp.path().join("src")).unwrap();
+        let file_path = temp.path().join("src/main.go");
+        std::fs::write(&file_path, "package main\n\nfunc main() {}\n").unwrap();
+
+        let analysis_ctx = AnalysisContext::new(temp.path());
+        let symbols = vec![
+            RequiredSymbol {
+                name: "main".to_string(),
+                kind: SymbolKind::Function,
+                file: "SRC/Main.go".to_string(),
+            },
+            RequiredSymbol {
+                name: "main".to_string(),
+                kind: SymbolKind::Function,
+                file: "src/main.go".to_string(),
+            },
+        ];
+
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::MissingSymbol]);
+        let result = detect_missing_symbols(&facts, &[&file_path], &symbols).unwrap();
+        assert_eq!(result.violations.len(), 1);
+        assert_eq!(
+            result.violations[0].message,
+            "found 'src/main.go' but contract requires 'SRC/Main.go' — case mismatch: cannot verify function \"main\""
+        );
+        assert_eq!(result.violations[0].file.as_deref(), Some("SRC/Main.go"));
+    }
+
     #[test]
     fn test_unsupported_extension_fails() {
         crate::analysis::register_analyzers();
@@ -363,7 +413,8 @@ func main() {}
             file: "main.xyz".to_string(),
         }];
 
-        let result = detect_missing_symbols(&analysis_ctx, &[&file_path], &symbols).unwrap();
+        let facts = FactsBundle::for_rules(&analysis_ctx, &[&file_path], &[ViolationRule::MissingSymbol]);
+        let result = detect_missing_symbols(&facts, &[&file_path], &symbols).unwrap();
         assert_eq!(result.violations.len(), 1);
         assert!(result.violations[0]
             .message
@@ -0,0 +1,38 @@
+//! Fixtures shared by detector tests.
+
+use std::path::PathBuf;
+
+use tempfile::TempDir;
+
+use crate::analysis::AnalysisContext;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Write `source` to a file called `name` in a fresh project, compute the
+/// facts `rule` reads, and run `detect` over the file.
+pub(crate) fn violations<F>(name: &str, source: &str, rule: ViolationRule, detect: F) -> Vec<Violation>
+where
+    F: FnOnce(&FactsBundle, &[PathBuf]) -> anyhow::Result<DetectionResult>,
+{
+    crate::analysis::register_analyzers();
+
+    let temp = TempDir::new().unwrap();
+    let file = temp.path().join(name);
+    std::fs::write(&file, source).unwrap();
+
+    let ctx = AnalysisContext::new(temp.path());
+    let files = [file];
+    let facts = FactsBundle::for_rules(&ctx, &files, &[rule]);
+    detect(&facts, &files).unwrap().violations
+}
+
+/// The line and message of each violation [`violations`] finds.
+pub(crate) fn detect<F>(name: &str, source: &str, rule: ViolationRule, detect: F) -> Vec<(usize, String)>
+where
+    F: FnOnce(&FactsBundle, &[PathBuf]) -> anyhow::Result<DetectionResult>,
+{
+    violations(name, source, rule, detect)
+        .into_iter()
+        .map(|v| (v.line, v.message))
+        .collect()
+}
@@ -13,6 +13,12 @@
 //! - `// TODO`
 //! - `// FIXME: fix this`
 //! - `// TODO: finish later`
+//!
+//! Markers are found with the contract's marker vocabulary (see
+//! `analysis::markers`). An owner attribution is not context:
+//! `// TODO(jsmith): implement this` is hollow. A hollow marker that
+//! references a ticket is tracked work and is reported at the tracked
+//! severity instead of as a warning.
 
 use lazy_static::lazy_static;
 use regex::Regex;
@@ -20,14 +26,11 @@ use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::Path;
 
+use crate::analysis::MarkerVocabulary;
+
 use super::{DetectionResult, Severity, Violation, ViolationRule};
 
 lazy_static! {
-    /// Pattern to match TODO/FIXME markers
-    static ref TODO_PATTERN: Regex = Regex::new(
-        r"(?i)\b(TODO|FIXME|XXX|HACK)\b\s*:?\s*(.*)"
-    ).unwrap();
-
     /// Patterns indicating hollow/generic TODO content.
     /// These patterns match generic placeholder text without technical specifics.
     static ref HOLLOW_PATTERNS: Vec<Regex> = vec![
@@ -92,13 +95,17 @@ lazy_static! {
 
 /// Detect hollow TODOs in the given files.
 ///
-/// Returns violations for TODOs that lack meaningful context.
-pub fn detect_hollow_todos<P: AsRef<Path>>(files: &[P]) -> anyhow::Result<DetectionResult> {
+/// Returns violations for TODOs that lack meaningful context, at
+/// `tracked_severity` when the marker references a ticket.
+pub fn detect_hollow_todos<P: AsRef<Path>>(
+    files: &[P],
+    markers: &MarkerVocabulary,
+    tracked_severity: Severity,
+) -> anyhow::Result<DetectionResult> {
     let mut result = DetectionResult::new();
 
     for file in files {
-        let violations = scan_file_for_hollow_todos(file.as_ref())?;
-        result.violations.extend(violations);
+        scan_file_for_hollow_todos(file.as_ref(), markers, tracked_severity, &mut result)?;
         result.scanned += 1;
     }
 
@@ -127,10 +134,14 @@ fn is_hollow_todo(content: &str) -> bool {
 }
 
 /// Scan a single file for hollow TODOs.
-fn scan_file_for_hollow_todos(file_path: &Path) -> anyhow::Result<Vec<Violation>> {
+fn scan_file_for_hollow_todos(
+    file_path: &Path,
+    markers: &MarkerVocabulary,
+    tracked_severity: Severity,
+    result: &mut DetectionResult,
+) -> anyhow::Result<()> {
     let file = File::open(file_path)?;
     let reader = BufReader::new(file);
-    let mut violations = Vec::new();
     let file_str = file_path.to_string_lossy().to_string();
 
     for (line_num, line_result) in reader.lines().enumerate() {
@@ -138,76 +149,56 @@ fn scan_file_for_hollow_todos(file_path: &Path) -> anyhow::Result<Vec<Violation>
         let line_number = line_num + 1;
 
         // Skip if line is inside a string literal (simplified check)
-        if is_likely_string_content(&line) {
+        if is_likely_string_content(&line, markers) {
             continue;
         }
 
         // Skip if TODO is in test context (test fixtures, YAML descriptions, etc.)
-        if is_todo_in_test_context(&line, file_path) {
+        if is_todo_in_test_context(&line, file_path, markers) {
+            continue;
+        }
+
+        // Check the first marker on the line
+        let Some(marker) = markers.find(&line).into_iter().next() else {
+            continue;
+        };
+        let content = marker.description();
+        if !is_hollow_todo(&content) {
             continue;
         }
 
-        // Check for TODO markers
-        if let Some(caps) = TODO_PATTERN.captures(&line) {
-            let marker = caps.get(1).map(|m| m.as_str()).unwrap_or("TODO");
-            let content = caps.get(2).map(|m| m.as_str()).unwrap_or("");
-
-            // Check if this is a hollow TODO
-            if is_hollow_todo(content) {
-                let msg = if content.trim().is_empty() {
-                    format!("{} marker without context", marker.to_uppercase())
-                } else {
-                    format!(
-                        "{} with hollow context: {:?}",
-                        marker.to_uppercase(),
-                        content.trim()
-                    )
-                };
-
-                violations.push(Violation {
-                    rule: ViolationRule::HollowTodo,
-                    message: msg,
-                    file: file_str.clone(),
-                    line: line_number,
-                    severity: Severity::Warning,
-                });
-            }
+        let mut msg = if content.is_empty() {
+            format!("{} marker without context", marker.kind)
+        } else {
+            format!("{} with hollow context: {:?}", marker.kind, content)
+        };
+        let mut severity = Severity::Warning;
+        if let Some(ticket) = &marker.ticket {
+            msg.push_str(&format!(", tracked in {}", ticket));
+            severity = tracked_severity;
         }
+
+        let violation = Violation::new(ViolationRule::HollowTodo, msg)
+            .at(file_str.clone(), line_number)
+            .with_severity(severity);
+        result.add_marker_violation(violation, marker);
     }
 
-    Ok(violations)
+    Ok(())
 }
 
 /// Simple check if a line is likely a string literal (not a comment).
 ///
 /// This is a heuristic to avoid flagging TODOs that are in user-facing strings.
-fn is_likely_string_content(line: &str) -> bool {
+fn is_likely_string_content(line: &str, markers: &MarkerVocabulary) -> bool {
     let trimmed = line.trim();
+    let upper = trimmed.to_uppercase();
 
     // If line starts with a quote and contains TODO, it's likely a string
-    if (trimmed.starts_with('"') || trimmed.starts_with('\''))
-        && trimmed.to_uppercase().contains("TODO")
-    {
-        return true;
-    }
-
-    // Check for common patterns of string assignments with TODO content
-    // e.g., let msg = "TODO: implement this"
-    let string_assignment =
-        regex::Regex::new(r#"(?i)=\s*["'].*(?:TODO|FIXME|XXX|HACK).*["']"#).unwrap();
-    if string_assignment.is_match(trimmed) {
-        return true;
-    }
-
-    // Check for function calls with TODO in string args
-    // e.g., println!("TODO: implement this")
-    let fn_call_string =
-        regex::Regex::new(r#"(?i)\(\s*["'].*(?:TODO|FIXME|XXX|HACK).*["']\s*\)"#).unwrap();
-    if fn_call_string.is_match(trimmed) {
-        return true;
-    }
-
-    false
+    // (string assignments and call arguments are caught by the quote count
+    // in is_todo_in_test_context)
+    (trimmed.starts_with('"') || trimmed.starts_with('\''))
+        && markers.words().iter().any(|w| upper.contains(w.as_str()))
 }
 
 /// Check if a TODO is in a test context (test fixtures, YAML descriptions, etc.)
@@ -216,16 +207,12 @@ fn is_likely_string_content(line: &str) -> bool {
 /// - Test fixture data
 /// - YAML description fields
 /// - Comments explaining test cases
-fn is_todo_in_test_context(line: &str, file_path: &Path) -> bool {
+fn is_todo_in_test_context(line: &str, file_path: &Path, markers: &MarkerVocabulary) -> bool {
     let trimmed = line.trim();
     let upper = trimmed.to_uppercase();
 
     // Check if TODO is inside a string literal
-    if is_inside_string_literal(trimmed, "TODO")
-        || is_inside_string_literal(trimmed, "FIXME")
-        || is_inside_string_literal(trimmed, "XXX")
-        || is_inside_string_literal(trimmed, "HACK")
-    {
+    if markers.words().iter().any(|w| is_inside_string_literal(trimmed, w)) {
         return true;
     }
 
@@ -358,7 +345,7 @@ fn main() {
         )
         .unwrap();
 
-        let result = detect_hollow_todos(&[&file_path]).unwrap();
+        let result = detect_hollow_todos(&[&file_path], &MarkerVocabulary::default(), Severity::Info).unwrap();
 
         // Should flag "implement this function" and empty FIXME
         // Should NOT flag the io_uring TODO or the #456 reference
@@ -382,7 +369,7 @@ fn main() {
         )
         .unwrap();
 
-        let result = detect_hollow_todos(&[&file_path]).unwrap();
+        let result = detect_hollow_todos(&[&file_path], &MarkerVocabulary::default(), Severity::Info).unwrap();
 
         // Should not flag TODOs in strings
         assert_eq!(result.violations.len(), 0);
@@ -429,9 +416,45 @@ forbidden_patterns:
         )
         .unwrap();
 
-        let result = detect_hollow_todos(&[&file_path]).unwrap();
+        let result = detect_hollow_todos(&[&file_path], &MarkerVocabulary::default(), Severity::Info).unwrap();
 
         // Should not flag TODOs in YAML description/pattern fields
         assert_eq!(result.violations.len(), 0);
     }
+
+    #[test]
+    fn test_tracked_markers_are_downgraded() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("billing.py");
+        std::fs::write(
+            &file_path,
+            r#"def charge(order):
+    # TODO(PAY-118): implement this
+    # Fixme: handle
+    # PENDING
+    # todo items are listed in the sidebar
+    return order.total
+"#,
+        )
+        .unwrap();
+
+        let markers = MarkerVocabulary::new(&["pending".to_string()], Some(r"PAY-\d+")).unwrap();
+        let result = detect_hollow_todos(&[&file_path], &markers, Severity::Info).unwrap();
+
+        let found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.line, v.severity, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (2, Severity::Info, "TODO with hollow context: \"implement this\", tracked in PAY-118"),
+                (3, Severity::Warning, "FIXME with hollow context: \"handle\""),
+                (4, Severity::Warning, "PENDING marker without context"),
+            ]
+        );
+        let marker = &result.markers[&result.violations[0].key()];
+        assert_eq!((marker.kind.as_str(), marker.ticket.as_deref()), ("TODO", Some("PAY-118")));
+    }
 }
@@ -0,0 +1,97 @@
+//! Detection of functions cut off at the end of the file.
+//!
+//! Generated code that ran out of tokens ends in the middle of a function:
+//! the body is never closed, or its last statement stops halfway. The
+//! analyzers record such a function in `FileFacts::truncated_function` when
+//! the parse damage reaches the end of the file inside it, which tells
+//! "generation was cut off" apart from an ordinary syntax error elsewhere in
+//! the file. The file is best regenerated rather than patched.
+
+use std::path::Path;
+
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Flag files that end inside a function.
+pub fn detect_truncated_code<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        let Some(truncated) = &file_facts.truncated_function else {
+            continue;
+        };
+        let function = match &truncated.name {
+            Some(name) => format!("function '{}'", name),
+            None => "a function".to_string(),
+        };
+        result.add_violation(
+            Violation::new(
+                ViolationRule::TruncatedCode,
+                format!(
+                    "{} is cut off at the end of the file (lines {}-{}), likely generated output that ran out of tokens; regenerate it",
+                    function, truncated.span.start_line, truncated.span.end_line
+                ),
+            )
+            .at(path.to_string_lossy(), truncated.span.start_line),
+        );
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_truncated_code() {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let sources = [
+            ("complete.go", "package orders\n\nfunc Total(items []int) int {\n\treturn len(items)\n}\n"),
+            ("broken.go", "package orders\n\nfunc Bad() int {\n\treturn 1 +\n}\n\nfunc Ok() {}\n"),
+            ("orders.go", "package orders\n\nfunc Total(items []int) int {\n\tsum := 0\n\tfor _, i := range items {\n\t\tsum += i\n"),
+            ("orders.py", "def total(items):\n    return sum(items)\n\ndef refund(order):\n    if order.paid:\n        order.balance -= (order.total *\n"),
+        ];
+        let files: Vec<_> = sources
+            .iter()
+            .map(|(name, source)| {
+                let path = temp.path().join(name);
+                std::fs::write(&path, source).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &files, &[ViolationRule::TruncatedCode]);
+        let result = detect_truncated_code(&facts, &files).unwrap();
+
+        assert_eq!(result.scanned, 4);
+        let found: Vec<_> = result
+            .violations
+            .iter()
+            .map(|v| (v.file.as_deref().unwrap().rsplit('/').next().unwrap(), v.line, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("orders.go", 3, "function 'Total' is cut off at the end of the file (lines 3-6), likely generated output that ran out of tokens; regenerate it"),
+                ("orders.py", 4, "function 'refund' is cut off at the end of the file (lines 4-6), likely generated output that ran out of tokens; regenerate it"),
+            ]
+        );
+    }
+}
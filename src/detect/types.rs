@@ -1,4 +1,18 @@
 //! Core types for detection results.
+//!
+//! # Stability
+//!
+//! [`DetectionResult`], [`Violation`], [`Severity`], and [`ViolationRule`]
+//! are the inputs to scoring and every report writer, and tools outside this
+//! crate build them to replay stored findings. Their public fields, the
+//! constructors on this page, and the rule names returned by
+//! [`ViolationRule::as_str`] are stable: fields and rules may be added in a
+//! minor release, but none are renamed or removed. Match on
+//! [`ViolationRule`] with a wildcard arm, since new rules arrive regularly.
+//! [`DetectionResult::dependency_traces`] is diagnostic output and its shape
+//! may change.
+
+use std::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
@@ -8,7 +22,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
-    /// Missing implementation, hallucinated deps - absolute blockers
+    /// Missing implementation, hallucinated deps - absolute blockers (weighted above Error)
     Critical,
     /// Forbidden patterns, low complexity - serious issues
     Error,
@@ -51,6 +65,25 @@ impl std::str::FromStr for Severity {
     }
 }
 
+/// The hollowness sub-score a rule counts toward.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ScoreCategory {
+    /// The code isn't written: stubs, missing symbols, low complexity
+    Structural,
+    /// The code is written but fake: mock data, TODOs, placeholder configs
+    Content,
+}
+
+impl ScoreCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreCategory::Structural => "structural",
+            ScoreCategory::Content => "content",
+        }
+    }
+}
+
 /// Rule names for different violation types.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ViolationRule {
@@ -74,6 +107,127 @@ pub enum ViolationRule {
     /// Stub function - AST-detected hollow function body
     #[serde(rename = "stub_function")]
     StubFunction,
+    /// Too few implemented callables under a contract glob
+    #[serde(rename = "insufficient_implementation")]
+    InsufficientImplementation,
+    /// String literal repeated across the project instead of a constant
+    #[serde(rename = "repeated_literal")]
+    RepeatedLiteral,
+    /// JS/TS promise created but never awaited, returned, or chained
+    #[serde(rename = "floating_promise")]
+    FloatingPromise,
+    /// File that must not be delivered (junk artifact or over the size limit)
+    #[serde(rename = "forbidden_file")]
+    ForbiddenFile,
+    /// Sibling paths whose names differ only by case
+    #[serde(rename = "case_collision")]
+    CaseCollision,
+    /// First comment block missing, not matching the expected header, or placeholder-filled
+    #[serde(rename = "bad_header")]
+    BadHeader,
+    /// Documentation references a function, type, or subcommand the code never declares
+    #[serde(rename = "doc_drift")]
+    DocDrift,
+    /// Function declares more parameters than the contract allows
+    #[serde(rename = "too_many_params")]
+    TooManyParams,
+    /// HTTP handler that responds 200 with an empty or placeholder body
+    #[serde(rename = "empty_handler")]
+    EmptyHandler,
+    /// File too large or slow to analyze within the per-file budget; not checked
+    #[serde(rename = "analysis_timeout")]
+    AnalysisTimeout,
+    /// File whose analysis panicked; skipped by every rule
+    #[serde(rename = "analysis_error")]
+    AnalysisError,
+    /// Interface or trait that no type in the project implements
+    #[serde(rename = "unimplemented_interface")]
+    UnimplementedInterface,
+    /// Interface, factory, or type parameter with a single concrete type behind it
+    #[serde(rename = "premature_abstraction")]
+    PrematureAbstraction,
+    /// Weak hash or cipher, ECB mode, or a hardcoded IV or salt
+    #[serde(rename = "weak_crypto")]
+    WeakCrypto,
+    /// Source file with no comments across complex functions, or mostly comments
+    #[serde(rename = "comment_ratio")]
+    CommentRatio,
+    /// Exported declaration without a doc comment, or project doc coverage below the contract's minimum
+    #[serde(rename = "doc_coverage")]
+    DocCoverage,
+    /// Python function, class, or module variable named after a builtin
+    #[serde(rename = "builtin_shadowing")]
+    BuiltinShadowing,
+    /// Dockerfile or compose service that builds or runs nothing real
+    #[serde(rename = "hollow_container")]
+    HollowContainer,
+    /// CI pipeline with placeholder images, unpinned images, or TODO steps
+    #[serde(rename = "hollow_ci_config")]
+    HollowCiConfig,
+    /// Protobuf or GraphQL definition that is empty, a placeholder, or commented out
+    #[serde(rename = "hollow_schema")]
+    HollowSchema,
+    /// Language, runtime, or framework version that differs from the contract's environment
+    #[serde(rename = "environment_mismatch")]
+    EnvironmentMismatch,
+    /// Statement with no effect that pads a function body
+    #[serde(rename = "noop_statement")]
+    NoopStatement,
+    /// Switch over an enum that leaves most variants to a trivial default
+    #[serde(rename = "unhandled_enum_cases")]
+    UnhandledEnumCases,
+    /// Configuration file with placeholder values, missing keys, or out-of-range numbers
+    #[serde(rename = "hollow_config")]
+    HollowConfig,
+    /// Contract waiver past its expiry date; the violations it covered are reported again
+    #[serde(rename = "expired_waiver")]
+    ExpiredWaiver,
+    /// Inline suppression that matched no violation, or a line suppression
+    /// already covered by a file-level one
+    #[serde(rename = "unused_suppression")]
+    UnusedSuppression,
+    /// Go function that accepts a `context.Context` and never uses it
+    #[serde(rename = "unused_context")]
+    UnusedContext,
+    /// Go named result that a bare `return` hands back without it ever being assigned
+    #[serde(rename = "unassigned_named_return")]
+    UnassignedNamedReturn,
+    /// Constant group whose values are all identical, or sequential or placeholder values next to a TODO marker
+    #[serde(rename = "placeholder_constants")]
+    PlaceholderConstants,
+    /// C or C++ header function with no definition in any scanned translation unit
+    #[serde(rename = "missing_definition")]
+    MissingDefinition,
+    /// Callable marked deprecated that is still called from many sites
+    #[serde(rename = "deprecated_usage")]
+    DeprecatedUsage,
+    /// Callable whose body only forwards its parameters to another project function
+    #[serde(rename = "trivial_wrapper")]
+    TrivialWrapper,
+    /// Rust function with more `unwrap`/`expect`/`panic!` sites than allowed
+    #[serde(rename = "unwrap_overuse")]
+    UnwrapOveruse,
+    /// Swift function with more `!` force-unwraps and `try!` than allowed
+    #[serde(rename = "force_unwrap")]
+    ForceUnwrap,
+    /// Relative or project-local import whose target module does not exist
+    #[serde(rename = "missing_local_module")]
+    MissingLocalModule,
+    /// Error handler that logs an error and rethrows it unchanged
+    #[serde(rename = "redundant_error_logging")]
+    RedundantErrorLogging,
+    /// Function that panics where the rest of its file returns errors, or the reverse
+    #[serde(rename = "inconsistent_error_handling")]
+    InconsistentErrorHandling,
+    /// Async function whose body never awaits anything
+    #[serde(rename = "async_without_await")]
+    AsyncWithoutAwait,
+    /// Python parameter whose default is a mutable list, dict, or set
+    #[serde(rename = "mutable_default")]
+    MutableDefault,
+    /// Function cut off at the end of the file, as generation that ran out of tokens leaves it
+    #[serde(rename = "truncated_code")]
+    TruncatedCode,
     // God object rules
     #[serde(rename = "god_file")]
     GodFile,
@@ -108,6 +262,46 @@ impl ViolationRule {
             ViolationRule::HallucinatedDependency => "hallucinated_dependency",
             ViolationRule::HollowTodo => "hollow_todo",
             ViolationRule::StubFunction => "stub_function",
+            ViolationRule::InsufficientImplementation => "insufficient_implementation",
+            ViolationRule::RepeatedLiteral => "repeated_literal",
+            ViolationRule::FloatingPromise => "floating_promise",
+            ViolationRule::ForbiddenFile => "forbidden_file",
+            ViolationRule::CaseCollision => "case_collision",
+            ViolationRule::BadHeader => "bad_header",
+            ViolationRule::DocDrift => "doc_drift",
+            ViolationRule::TooManyParams => "too_many_params",
+            ViolationRule::EmptyHandler => "empty_handler",
+            ViolationRule::AnalysisTimeout => "analysis_timeout",
+            ViolationRule::AnalysisError => "analysis_error",
+            ViolationRule::UnimplementedInterface => "unimplemented_interface",
+            ViolationRule::PrematureAbstraction => "premature_abstraction",
+            ViolationRule::WeakCrypto => "weak_crypto",
+            ViolationRule::CommentRatio => "comment_ratio",
+            ViolationRule::DocCoverage => "doc_coverage",
+            ViolationRule::BuiltinShadowing => "builtin_shadowing",
+            ViolationRule::HollowContainer => "hollow_container",
+            ViolationRule::HollowCiConfig => "hollow_ci_config",
+            ViolationRule::HollowSchema => "hollow_schema",
+            ViolationRule::EnvironmentMismatch => "environment_mismatch",
+            ViolationRule::NoopStatement => "noop_statement",
+            ViolationRule::UnhandledEnumCases => "unhandled_enum_cases",
+            ViolationRule::HollowConfig => "hollow_config",
+            ViolationRule::ExpiredWaiver => "expired_waiver",
+            ViolationRule::UnusedSuppression => "unused_suppression",
+            ViolationRule::UnusedContext => "unused_context",
+            ViolationRule::UnassignedNamedReturn => "unassigned_named_return",
+            ViolationRule::PlaceholderConstants => "placeholder_constants",
+            ViolationRule::MissingDefinition => "missing_definition",
+            ViolationRule::DeprecatedUsage => "deprecated_usage",
+            ViolationRule::TrivialWrapper => "trivial_wrapper",
+            ViolationRule::UnwrapOveruse => "unwrap_overuse",
+            ViolationRule::ForceUnwrap => "force_unwrap",
+            ViolationRule::MissingLocalModule => "missing_local_module",
+            ViolationRule::RedundantErrorLogging => "redundant_error_logging",
+            ViolationRule::InconsistentErrorHandling => "inconsistent_error_handling",
+            ViolationRule::AsyncWithoutAwait => "async_without_await",
+            ViolationRule::MutableDefault => "mutable_default",
+            ViolationRule::TruncatedCode => "truncated_code",
             ViolationRule::GodFile => "god_file",
             ViolationRule::GodFunction => "god_function",
             ViolationRule::GodClass => "god_class",
@@ -120,6 +314,72 @@ impl ViolationRule {
         }
     }
 
+    /// Every rule, in declaration order.
+    pub const ALL: &'static [ViolationRule] = &[
+        ViolationRule::ForbiddenPattern,
+        ViolationRule::MockData,
+        ViolationRule::MissingFile,
+        ViolationRule::MissingSymbol,
+        ViolationRule::LowComplexity,
+        ViolationRule::MissingTest,
+        ViolationRule::HallucinatedDependency,
+        ViolationRule::HollowTodo,
+        ViolationRule::StubFunction,
+        ViolationRule::InsufficientImplementation,
+        ViolationRule::RepeatedLiteral,
+        ViolationRule::FloatingPromise,
+        ViolationRule::ForbiddenFile,
+        ViolationRule::CaseCollision,
+        ViolationRule::BadHeader,
+        ViolationRule::DocDrift,
+        ViolationRule::TooManyParams,
+        ViolationRule::EmptyHandler,
+        ViolationRule::AnalysisTimeout,
+        ViolationRule::AnalysisError,
+        ViolationRule::UnimplementedInterface,
+        ViolationRule::PrematureAbstraction,
+        ViolationRule::WeakCrypto,
+        ViolationRule::CommentRatio,
+        ViolationRule::DocCoverage,
+        ViolationRule::BuiltinShadowing,
+        ViolationRule::HollowContainer,
+        ViolationRule::HollowCiConfig,
+        ViolationRule::HollowSchema,
+        ViolationRule::EnvironmentMismatch,
+        ViolationRule::NoopStatement,
+        ViolationRule::UnhandledEnumCases,
+        ViolationRule::HollowConfig,
+        ViolationRule::ExpiredWaiver,
+        ViolationRule::UnusedSuppression,
+        ViolationRule::UnusedContext,
+        ViolationRule::UnassignedNamedReturn,
+        ViolationRule::PlaceholderConstants,
+        ViolationRule::MissingDefinition,
+        ViolationRule::DeprecatedUsage,
+        ViolationRule::TrivialWrapper,
+        ViolationRule::UnwrapOveruse,
+        ViolationRule::ForceUnwrap,
+        ViolationRule::MissingLocalModule,
+        ViolationRule::RedundantErrorLogging,
+        ViolationRule::InconsistentErrorHandling,
+        ViolationRule::AsyncWithoutAwait,
+        ViolationRule::MutableDefault,
+        ViolationRule::TruncatedCode,
+        ViolationRule::GodFile,
+        ViolationRule::GodFunction,
+        ViolationRule::GodClass,
+        ViolationRule::FillerPhrase,
+        ViolationRule::WeaselWord,
+        ViolationRule::LowDensity,
+        ViolationRule::ProseRepetitiveOpener,
+        ViolationRule::ProseMiddleSag,
+        ViolationRule::ProseWeakTransition,
+    ];
+
+    /// Parse an exact rule name as returned by [`ViolationRule::as_str`].
+    ///
+    /// Use [`str::parse`] to also accept SARIF names, kebab-case, and the
+    /// contract section names in [`RULE_ALIASES`].
     pub fn parse(s: &str) -> Option<Self> {
         match s {
             "forbidden_pattern" => Some(ViolationRule::ForbiddenPattern),
@@ -131,6 +391,46 @@ impl ViolationRule {
             "hallucinated_dependency" => Some(ViolationRule::HallucinatedDependency),
             "hollow_todo" => Some(ViolationRule::HollowTodo),
             "stub_function" => Some(ViolationRule::StubFunction),
+            "insufficient_implementation" => Some(ViolationRule::InsufficientImplementation),
+            "repeated_literal" => Some(ViolationRule::RepeatedLiteral),
+            "floating_promise" => Some(ViolationRule::FloatingPromise),
+            "forbidden_file" => Some(ViolationRule::ForbiddenFile),
+            "case_collision" => Some(ViolationRule::CaseCollision),
+            "bad_header" => Some(ViolationRule::BadHeader),
+            "doc_drift" => Some(ViolationRule::DocDrift),
+            "too_many_params" => Some(ViolationRule::TooManyParams),
+            "empty_handler" => Some(ViolationRule::EmptyHandler),
+            "analysis_timeout" => Some(ViolationRule::AnalysisTimeout),
+            "analysis_error" => Some(ViolationRule::AnalysisError),
+            "unimplemented_interface" => Some(ViolationRule::UnimplementedInterface),
+            "premature_abstraction" => Some(ViolationRule::PrematureAbstraction),
+            "weak_crypto" => Some(ViolationRule::WeakCrypto),
+            "comment_ratio" => Some(ViolationRule::CommentRatio),
+            "doc_coverage" => Some(ViolationRule::DocCoverage),
+            "builtin_shadowing" => Some(ViolationRule::BuiltinShadowing),
+            "hollow_container" => Some(ViolationRule::HollowContainer),
+            "hollow_ci_config" => Some(ViolationRule::HollowCiConfig),
+            "hollow_schema" => Some(ViolationRule::HollowSchema),
+            "environment_mismatch" => Some(ViolationRule::EnvironmentMismatch),
+            "noop_statement" => Some(ViolationRule::NoopStatement),
+            "unhandled_enum_cases" => Some(ViolationRule::UnhandledEnumCases),
+            "hollow_config" => Some(ViolationRule::HollowConfig),
+            "expired_waiver" => Some(ViolationRule::ExpiredWaiver),
+            "unused_suppression" => Some(ViolationRule::UnusedSuppression),
+            "unused_context" => Some(ViolationRule::UnusedContext),
+            "unassigned_named_return" => Some(ViolationRule::UnassignedNamedReturn),
+            "placeholder_constants" => Some(ViolationRule::PlaceholderConstants),
+            "missing_definition" => Some(ViolationRule::MissingDefinition),
+            "deprecated_usage" => Some(ViolationRule::DeprecatedUsage),
+            "trivial_wrapper" => Some(ViolationRule::TrivialWrapper),
+            "unwrap_overuse" => Some(ViolationRule::UnwrapOveruse),
+            "force_unwrap" => Some(ViolationRule::ForceUnwrap),
+            "missing_local_module" => Some(ViolationRule::MissingLocalModule),
+            "redundant_error_logging" => Some(ViolationRule::RedundantErrorLogging),
+            "inconsistent_error_handling" => Some(ViolationRule::InconsistentErrorHandling),
+            "async_without_await" => Some(ViolationRule::AsyncWithoutAwait),
+            "mutable_default" => Some(ViolationRule::MutableDefault),
+            "truncated_code" => Some(ViolationRule::TruncatedCode),
             "god_file" => Some(ViolationRule::GodFile),
             "god_function" => Some(ViolationRule::GodFunction),
             "god_class" => Some(ViolationRule::GodClass),
@@ -148,7 +448,7 @@ impl ViolationRule {
     /// Critical: Missing implementations, hallucinated dependencies
     /// Error: Low complexity (stub implementations), stub functions
     /// Warning: Forbidden patterns (TODOs), god objects, mock data, hollow TODOs
-    /// Info: Weak prose issues
+    /// Info: Weak prose issues, documentation drift
     pub fn default_severity(&self) -> Severity {
         match self {
             // Critical - absolute blockers
@@ -158,16 +458,58 @@ impl ViolationRule {
 
             // Error - serious issues that should block CI
             ViolationRule::LowComplexity => Severity::Error,
+            ViolationRule::ForbiddenFile => Severity::Error,
+            ViolationRule::CaseCollision => Severity::Error,
+            ViolationRule::BadHeader => Severity::Error,
             ViolationRule::StubFunction => Severity::Error,
+            ViolationRule::InsufficientImplementation => Severity::Error,
+            ViolationRule::HollowContainer => Severity::Error,
+            ViolationRule::HollowCiConfig => Severity::Error,
+            ViolationRule::HollowSchema => Severity::Error,
+            ViolationRule::EnvironmentMismatch => Severity::Error,
+            ViolationRule::HollowConfig => Severity::Error,
+            ViolationRule::MissingLocalModule => Severity::Error,
+            ViolationRule::TruncatedCode => Severity::Error,
+            ViolationRule::RedundantErrorLogging => Severity::Info,
 
             // Warning - code smells that don't affect scoring
             ViolationRule::ForbiddenPattern => Severity::Warning,
             ViolationRule::GodFile => Severity::Warning,
             ViolationRule::GodFunction => Severity::Warning,
             ViolationRule::GodClass => Severity::Warning,
+            ViolationRule::TooManyParams => Severity::Warning,
+            ViolationRule::EmptyHandler => Severity::Warning,
+            ViolationRule::AnalysisTimeout => Severity::Warning,
+            ViolationRule::AnalysisError => Severity::Warning,
+            ViolationRule::UnimplementedInterface => Severity::Warning,
+            ViolationRule::BuiltinShadowing => Severity::Warning,
             ViolationRule::MockData => Severity::Warning,
             ViolationRule::MissingTest => Severity::Warning,
             ViolationRule::HollowTodo => Severity::Warning,
+            ViolationRule::RepeatedLiteral => Severity::Warning,
+            ViolationRule::FloatingPromise => Severity::Warning,
+            ViolationRule::UnusedContext => Severity::Warning,
+            ViolationRule::UnassignedNamedReturn => Severity::Warning,
+            ViolationRule::MissingDefinition => Severity::Warning,
+            ViolationRule::UnwrapOveruse => Severity::Warning,
+            ViolationRule::ForceUnwrap => Severity::Warning,
+            ViolationRule::MutableDefault => Severity::Warning,
+            ViolationRule::WeakCrypto => Severity::Warning,
+
+            // Info - reviewer aids that never gate
+            ViolationRule::DocDrift => Severity::Info,
+            ViolationRule::NoopStatement => Severity::Info,
+            ViolationRule::UnhandledEnumCases => Severity::Info,
+            ViolationRule::ExpiredWaiver => Severity::Info,
+            ViolationRule::UnusedSuppression => Severity::Info,
+            ViolationRule::DeprecatedUsage => Severity::Info,
+            ViolationRule::TrivialWrapper => Severity::Info,
+            ViolationRule::AsyncWithoutAwait => Severity::Info,
+            ViolationRule::InconsistentErrorHandling => Severity::Info,
+            ViolationRule::PrematureAbstraction => Severity::Info,
+            ViolationRule::CommentRatio => Severity::Info,
+            ViolationRule::DocCoverage => Severity::Info,
+            ViolationRule::PlaceholderConstants => Severity::Info,
 
             // Prose rules - mostly warnings/info
             ViolationRule::FillerPhrase => Severity::Warning,
@@ -178,6 +520,144 @@ impl ViolationRule {
             ViolationRule::ProseWeakTransition => Severity::Info,
         }
     }
+
+    /// Returns the sub-score this rule counts toward unless the contract
+    /// moves it.
+    /// Structural: the code isn't written (stubs, missing pieces, shape smells)
+    /// Content: the code is written but fake (placeholders, TODOs, configs, prose)
+    pub fn score_category(&self) -> ScoreCategory {
+        match self {
+            // Structural - missing or skeletal implementation
+            ViolationRule::MissingFile => ScoreCategory::Structural,
+            ViolationRule::MissingSymbol => ScoreCategory::Structural,
+            ViolationRule::HallucinatedDependency => ScoreCategory::Structural,
+            ViolationRule::LowComplexity => ScoreCategory::Structural,
+            ViolationRule::StubFunction => ScoreCategory::Structural,
+            ViolationRule::InsufficientImplementation => ScoreCategory::Structural,
+            ViolationRule::MissingLocalModule => ScoreCategory::Structural,
+            ViolationRule::RedundantErrorLogging => ScoreCategory::Structural,
+            ViolationRule::InconsistentErrorHandling => ScoreCategory::Structural,
+            ViolationRule::AsyncWithoutAwait => ScoreCategory::Structural,
+            ViolationRule::MutableDefault => ScoreCategory::Structural,
+            ViolationRule::TruncatedCode => ScoreCategory::Structural,
+            ViolationRule::MissingTest => ScoreCategory::Structural,
+            ViolationRule::EmptyHandler => ScoreCategory::Structural,
+            ViolationRule::UnimplementedInterface => ScoreCategory::Structural,
+            ViolationRule::MissingDefinition => ScoreCategory::Structural,
+            ViolationRule::DocDrift => ScoreCategory::Structural,
+            ViolationRule::FloatingPromise => ScoreCategory::Structural,
+            ViolationRule::UnusedContext => ScoreCategory::Structural,
+            ViolationRule::UnassignedNamedReturn => ScoreCategory::Structural,
+            ViolationRule::UnwrapOveruse => ScoreCategory::Structural,
+            ViolationRule::ForceUnwrap => ScoreCategory::Structural,
+            ViolationRule::BuiltinShadowing => ScoreCategory::Structural,
+            ViolationRule::WeakCrypto => ScoreCategory::Structural,
+            ViolationRule::DeprecatedUsage => ScoreCategory::Structural,
+            ViolationRule::GodFile => ScoreCategory::Structural,
+            ViolationRule::GodFunction => ScoreCategory::Structural,
+            ViolationRule::GodClass => ScoreCategory::Structural,
+            ViolationRule::TooManyParams => ScoreCategory::Structural,
+            ViolationRule::TrivialWrapper => ScoreCategory::Structural,
+            ViolationRule::PrematureAbstraction => ScoreCategory::Structural,
+            ViolationRule::NoopStatement => ScoreCategory::Structural,
+            ViolationRule::UnhandledEnumCases => ScoreCategory::Structural,
+            ViolationRule::AnalysisTimeout => ScoreCategory::Structural,
+            ViolationRule::AnalysisError => ScoreCategory::Structural,
+
+            // Content - placeholder values, markers, and configuration
+            ViolationRule::ForbiddenPattern => ScoreCategory::Content,
+            ViolationRule::MockData => ScoreCategory::Content,
+            ViolationRule::HollowTodo => ScoreCategory::Content,
+            ViolationRule::RepeatedLiteral => ScoreCategory::Content,
+            ViolationRule::ForbiddenFile => ScoreCategory::Content,
+            ViolationRule::CaseCollision => ScoreCategory::Structural,
+            ViolationRule::BadHeader => ScoreCategory::Content,
+            ViolationRule::CommentRatio => ScoreCategory::Content,
+            ViolationRule::DocCoverage => ScoreCategory::Content,
+            ViolationRule::PlaceholderConstants => ScoreCategory::Content,
+            ViolationRule::HollowContainer => ScoreCategory::Content,
+            ViolationRule::HollowCiConfig => ScoreCategory::Content,
+            ViolationRule::HollowSchema => ScoreCategory::Content,
+            ViolationRule::EnvironmentMismatch => ScoreCategory::Content,
+            ViolationRule::HollowConfig => ScoreCategory::Content,
+            ViolationRule::ExpiredWaiver => ScoreCategory::Content,
+            ViolationRule::UnusedSuppression => ScoreCategory::Content,
+
+            // Prose rules
+            ViolationRule::FillerPhrase => ScoreCategory::Content,
+            ViolationRule::WeaselWord => ScoreCategory::Content,
+            ViolationRule::LowDensity => ScoreCategory::Content,
+            ViolationRule::ProseRepetitiveOpener => ScoreCategory::Content,
+            ViolationRule::ProseMiddleSag => ScoreCategory::Content,
+            ViolationRule::ProseWeakTransition => ScoreCategory::Content,
+        }
+    }
+}
+
+/// Contract section names accepted in place of the rule they configure.
+pub const RULE_ALIASES: &[(&str, ViolationRule)] = &[
+    ("forbidden_patterns", ViolationRule::ForbiddenPattern),
+    ("mock_signatures", ViolationRule::MockData),
+    ("required_files", ViolationRule::MissingFile),
+    ("required_symbols", ViolationRule::MissingSymbol),
+    ("complexity", ViolationRule::LowComplexity),
+    ("required_tests", ViolationRule::MissingTest),
+    ("dependency_verification", ViolationRule::HallucinatedDependency),
+    ("hollow_todos", ViolationRule::HollowTodo),
+    ("implementation_coverage", ViolationRule::InsufficientImplementation),
+    ("repeated_literals", ViolationRule::RepeatedLiteral),
+    ("floating_promises", ViolationRule::FloatingPromise),
+    ("forbidden_files", ViolationRule::ForbiddenFile),
+    ("detect_header", ViolationRule::BadHeader),
+    ("long_parameter_lists", ViolationRule::TooManyParams),
+    ("empty_handlers", ViolationRule::EmptyHandler),
+    ("analysis_budget", ViolationRule::AnalysisTimeout),
+    ("unimplemented_interfaces", ViolationRule::UnimplementedInterface),
+    ("premature_abstraction", ViolationRule::PrematureAbstraction),
+    ("containers", ViolationRule::HollowContainer),
+    ("ci_configs", ViolationRule::HollowCiConfig),
+    ("schemas", ViolationRule::HollowSchema),
+    ("environment", ViolationRule::EnvironmentMismatch),
+    ("noop_statements", ViolationRule::NoopStatement),
+    ("missing_definitions", ViolationRule::MissingDefinition),
+    ("config_files", ViolationRule::HollowConfig),
+    ("trivial_wrappers", ViolationRule::TrivialWrapper),
+    ("force_unwraps", ViolationRule::ForceUnwrap),
+    ("mutable_defaults", ViolationRule::MutableDefault),
+    ("missing_local_modules", ViolationRule::MissingLocalModule),
+    ("unassigned_named_returns", ViolationRule::UnassignedNamedReturn),
+];
+
+impl std::str::FromStr for ViolationRule {
+    type Err = String;
+
+    /// Parse a rule name leniently: `god_file`, `god-file`, `GodFile` (the
+    /// SARIF rule name), and aliases from [`RULE_ALIASES`] are all accepted.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let mut name = String::with_capacity(trimmed.len() + 4);
+        for (i, c) in trimmed.chars().enumerate() {
+            match c {
+                '-' | ' ' => name.push('_'),
+                c if c.is_ascii_uppercase() => {
+                    if i > 0 && !name.ends_with('_') {
+                        name.push('_');
+                    }
+                    name.push(c.to_ascii_lowercase());
+                }
+                c => name.push(c),
+            }
+        }
+
+        ViolationRule::parse(&name)
+            .or_else(|| {
+                RULE_ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == name)
+                    .map(|(_, rule)| *rule)
+            })
+            .ok_or_else(|| format!("unknown rule: {}", s))
+    }
 }
 
 impl std::fmt::Display for ViolationRule {
@@ -186,39 +666,225 @@ impl std::fmt::Display for ViolationRule {
     }
 }
 
+/// Label shown in place of a file for project-level violations.
+pub const PROJECT_LOCATION: &str = "(project)";
+
 /// A single detected issue.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Violation {
     pub rule: ViolationRule,
     pub message: String,
-    pub file: String,
+    /// File the violation was found in, or `None` for project-level findings
+    /// (a required test or symbol that exists nowhere, a budget exceeded).
+    pub file: Option<String>,
+    /// Line number; 0 when the violation is not tied to a line.
     pub line: usize,
     pub severity: Severity,
 }
 
 impl Violation {
+    /// Create a project-level violation with the rule's default severity.
+    ///
+    /// Chain [`Violation::at`] to place it in a file and
+    /// [`Violation::with_severity`] to override the severity.
+    pub fn new(rule: ViolationRule, message: impl Into<String>) -> Self {
+        Self {
+            rule,
+            message: message.into(),
+            file: None,
+            line: 0,
+            severity: rule.default_severity(),
+        }
+    }
+
+    /// Place the violation at a line of a file.
+    pub fn at(mut self, file: impl Into<String>, line: usize) -> Self {
+        self.file = Some(file.into());
+        self.line = line;
+        self
+    }
+
+    /// Override the severity.
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
     /// Create a unique key for this violation (for deduplication/comparison).
     /// Includes rule, file, line, and message to ensure exact duplicates are caught.
     pub fn key(&self) -> String {
-        format!("{}|{}|{}|{}", self.rule, self.file, self.line, self.message)
+        format!("{}|{}|{}|{}", self.rule, self.location(), self.line, self.message)
+    }
+
+    /// Check if this violation is about the project as a whole rather than a file.
+    pub fn is_project_level(&self) -> bool {
+        self.file.is_none()
+    }
+
+    /// The file path, or [`PROJECT_LOCATION`] for project-level violations.
+    pub fn location(&self) -> &str {
+        self.file.as_deref().unwrap_or(PROJECT_LOCATION)
+    }
+
+    /// The language the violation's file was analyzed as, when a language
+    /// override routed it to an analyzer its extension would not pick.
+    pub fn language_override(&self) -> Option<&'static str> {
+        self.file
+            .as_deref()
+            .and_then(|f| crate::analysis::language_override(std::path::Path::new(f)))
     }
 }
 
 /// Results of running detection.
+///
+/// Results can be built by hand and passed to scoring and the report
+/// writers like the runner's own:
+///
+/// ```
+/// use hollowcheck::detect::{
+///     DetectionResult, Severity, SuppressedViolation, Suppression, SuppressionType, Violation,
+/// };
+/// use hollowcheck::report::{render_json, write_json, JsonOptions};
+/// use hollowcheck::score::calculate_with_threshold;
+///
+/// let mut result = DetectionResult::new();
+/// result.scanned = 2;
+/// result.add_violation(
+///     Violation::new("stub-function".parse().unwrap(), "function 'charge' has an empty body")
+///         .at("src/payments.go", 12),
+/// );
+/// result.add_violation(
+///     Violation::new("god_file".parse().unwrap(), "file has 1400 lines")
+///         .at("src/payments.go", 1)
+///         .with_severity(Severity::Info),
+/// );
+/// result.add_suppressed(SuppressedViolation::new(
+///     Violation::new("mock_data".parse().unwrap(), "example.com").at("src/fixtures.go", 3),
+///     Suppression::new("mock_data", "src/fixtures.go", 3, SuppressionType::Line)
+///         .with_reason("test fixture"),
+/// ));
+///
+/// let score = calculate_with_threshold(&result, 25, &Default::default());
+/// write_json("src", "hollowcheck.yaml", &result, &score, JsonOptions::default()).unwrap();
+///
+/// let json = render_json("src", "hollowcheck.yaml", &result, &score, JsonOptions::default()).unwrap();
+/// let report: serde_json::Value = serde_json::from_str(&json).unwrap();
+/// assert_eq!(report["violations"][0]["rule"], "stub_function");
+/// assert_eq!(report["violations"][1]["severity"], "info");
+/// assert_eq!(report["suppressed_count"], 1);
+/// ```
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct DetectionResult {
     pub violations: Vec<Violation>,
     /// Violations that were suppressed by inline comments
     #[serde(default)]
     pub suppressed: Vec<super::SuppressedViolation>,
+    /// Violations accepted by a contract waiver; not in `violations` and not scored
+    #[serde(default)]
+    pub waived: Vec<super::WaivedViolation>,
+    /// Violations whose severity was lowered by inline downgrade directives;
+    /// they also appear in `violations` with the lowered severity
+    #[serde(default)]
+    pub downgraded: Vec<super::DowngradedViolation>,
     /// Violations not present in baseline (baseline mode only)
     #[serde(default)]
     pub new_violations: Vec<Violation>,
     /// Number of files scanned
     pub scanned: usize,
+    /// Number of files judged and rejected by forbidden_file checks.
+    /// Unlike excluded paths, these were inspected and count as violations.
+    #[serde(default)]
+    pub rejected: usize,
+    /// Number of files skipped because their language is excluded by the language filter
+    #[serde(default)]
+    pub language_excluded: usize,
+    /// Number of files whose analysis panicked; each also has an `analysis_error` violation
+    #[serde(default)]
+    pub errored: usize,
+    /// Number of files with a generated-code marker that were skipped or
+    /// checked by fewer rules, per the contract's `generated_files`
+    #[serde(default)]
+    pub generated: usize,
+    /// Number of generated files edited by hand since, checked outside
+    /// their generated blocks
+    #[serde(default)]
+    pub generated_modified: usize,
+    /// Violations left out of `violations` by the report limits, per rule.
+    /// They are still scored; see [`DetectionResult::truncate`].
+    #[serde(default)]
+    pub truncated: BTreeMap<String, usize>,
+    /// New violations left out of `new_violations` by the report limits, per rule
+    #[serde(default)]
+    pub new_truncated: BTreeMap<String, usize>,
+    /// Rules the run checked, whether or not they found anything. Filled in by the runner.
+    #[serde(default)]
+    pub checked_rules: Vec<ViolationRule>,
     /// Git ref used for baseline (if baseline mode)
     #[serde(default)]
     pub baseline_ref: Option<String>,
+    /// Directory the scan was scoped to
+    #[serde(default)]
+    pub scan_root: Option<String>,
+    /// Enclosing workspace root, when the scan root is inside one
+    #[serde(default)]
+    pub workspace_root: Option<String>,
+    /// Stable fingerprints of violations, suppressed ones included, keyed by
+    /// `Violation::key()`. Filled in by the runner.
+    #[serde(default)]
+    pub fingerprints: BTreeMap<String, String>,
+    /// Markers behind `hollow_todo` and marker `forbidden_pattern` violations,
+    /// keyed by `Violation::key()`
+    #[serde(default)]
+    pub markers: BTreeMap<String, crate::analysis::Marker>,
+    /// Heuristic effort estimates of hollow-function violations, keyed by
+    /// `Violation::key()`. Filled in by the runner when the contract asks.
+    #[serde(default)]
+    pub efforts: BTreeMap<String, super::Effort>,
+    /// Language, runtime, and framework versions declared by the project
+    #[serde(default)]
+    pub environment: Vec<super::EnvironmentEntry>,
+    /// Problems with the contract found while running it, such as patterns
+    /// disabled after exceeding their matching budget
+    #[serde(default)]
+    pub contract_warnings: Vec<String>,
+    /// Figures measured during the run, such as documentation coverage
+    #[serde(default)]
+    pub stats: ScanStats,
+    /// Pipeline traces of hallucinated dependency violations (explain mode only)
+    #[serde(skip)]
+    pub dependency_traces: Vec<super::DependencyTrace>,
+}
+
+/// Figures measured during a run that teams can trend without gating on them.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScanStats {
+    /// Documented share of exported declarations, when `doc_coverage` ran
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub doc_coverage: Option<super::DocCoverage>,
+    /// Heuristic effort left in hollow functions, when the contract asks for an estimate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<super::EffortEstimate>,
+}
+
+impl ScanStats {
+    /// Whether nothing was measured.
+    pub fn is_empty(&self) -> bool {
+        self.doc_coverage.is_none() && self.effort.is_none()
+    }
+
+    /// Add another run's figures to these.
+    pub fn merge(&mut self, other: ScanStats) {
+        match (&mut self.doc_coverage, other.doc_coverage) {
+            (Some(coverage), Some(other)) => coverage.merge(other),
+            (None, other) => self.doc_coverage = other,
+            (Some(_), None) => {}
+        }
+        match (&mut self.effort, other.effort) {
+            (Some(effort), Some(other)) => effort.merge(other),
+            (None, other) => self.effort = other,
+            (Some(_), None) => {}
+        }
+    }
 }
 
 impl DetectionResult {
@@ -227,10 +893,55 @@ impl DetectionResult {
     }
 
     /// Merge another result into this one.
+    ///
+    /// Violations, suppressed, waived, downgraded, and new violations from `other`
+    /// are appended after this result's, each list keeping its order, and
+    /// its fingerprints, markers, effort estimates, checked rules, environment entries, and contract warnings are added. File
+    /// counts and stats are added. Baseline ref and scan roots are taken from `other`
+    /// only where this result has none.
     pub fn merge(&mut self, other: DetectionResult) {
         self.violations.extend(other.violations);
         self.suppressed.extend(other.suppressed);
+        self.waived.extend(other.waived);
+        self.downgraded.extend(other.downgraded);
+        self.new_violations.extend(other.new_violations);
         self.scanned += other.scanned;
+        self.rejected += other.rejected;
+        self.language_excluded += other.language_excluded;
+        self.errored += other.errored;
+        self.generated += other.generated;
+        self.generated_modified += other.generated_modified;
+        for (rule, omitted) in other.truncated {
+            *self.truncated.entry(rule).or_default() += omitted;
+        }
+        for (rule, omitted) in other.new_truncated {
+            *self.new_truncated.entry(rule).or_default() += omitted;
+        }
+        for rule in other.checked_rules {
+            if !self.checked_rules.contains(&rule) {
+                self.checked_rules.push(rule);
+            }
+        }
+        self.baseline_ref = self.baseline_ref.take().or(other.baseline_ref);
+        self.scan_root = self.scan_root.take().or(other.scan_root);
+        self.workspace_root = self.workspace_root.take().or(other.workspace_root);
+        self.fingerprints.extend(other.fingerprints);
+        self.markers.extend(other.markers);
+        self.efforts.extend(other.efforts);
+        self.environment.extend(other.environment);
+        self.contract_warnings.extend(other.contract_warnings);
+        self.stats.merge(other.stats);
+        self.dependency_traces.extend(other.dependency_traces);
+    }
+
+    /// Stable fingerprint of a violation: the runner's, or for violations it
+    /// did not fingerprint one without the enclosing declaration and content
+    /// window.
+    pub fn fingerprint(&self, violation: &Violation) -> String {
+        self.fingerprints
+            .get(&violation.key())
+            .cloned()
+            .unwrap_or_else(|| super::fallback_fingerprint(violation))
     }
 
     /// Add a violation to the result.
@@ -238,6 +949,24 @@ impl DetectionResult {
         self.violations.push(violation);
     }
 
+    /// Add a violation found on a marker, keeping the marker for the report.
+    pub fn add_marker_violation(&mut self, violation: Violation, marker: crate::analysis::Marker) {
+        self.markers.insert(violation.key(), marker);
+        self.violations.push(violation);
+    }
+
+    /// Add a violation that was suppressed.
+    pub fn add_suppressed(&mut self, suppressed: super::SuppressedViolation) {
+        self.suppressed.push(suppressed);
+    }
+
+    /// Mark the result as compared against a baseline, with the violations
+    /// the baseline does not contain.
+    pub fn set_baseline(&mut self, baseline_ref: impl Into<String>, new_violations: Vec<Violation>) {
+        self.baseline_ref = Some(baseline_ref.into());
+        self.new_violations = new_violations;
+    }
+
     /// Deduplicate violations by removing exact duplicates (same file, line, rule, message).
     /// This prevents the same violation from being reported multiple times.
     pub fn deduplicate(&mut self) {
@@ -254,6 +983,22 @@ impl DetectionResult {
         });
     }
 
+    /// Bound the number of violations kept, per rule and in total.
+    ///
+    /// The violations kept are the most severe ones, then the earliest by
+    /// file and line, so the same findings always survive whatever order the
+    /// detectors produced them in. Kept violations keep their order; the rest
+    /// are only counted in `truncated`. New violations in baseline mode are
+    /// bounded the same way and counted in `new_truncated`. Score the result
+    /// before truncating.
+    pub fn truncate(&mut self, per_rule: Option<usize>, total: Option<usize>) {
+        if per_rule.is_none() && total.is_none() {
+            return;
+        }
+        bound_violations(&mut self.violations, per_rule, total, &mut self.truncated);
+        bound_violations(&mut self.new_violations, per_rule, total, &mut self.new_truncated);
+    }
+
     /// Number of suppressed violations.
     pub fn suppressed_count(&self) -> usize {
         self.suppressed.len()
@@ -292,6 +1037,40 @@ impl DetectionResult {
     }
 }
 
+/// Keep at most `per_rule` violations of each rule and `total` overall,
+/// ranked by severity and then position, counting the rest in `omitted`.
+fn bound_violations(
+    violations: &mut Vec<Violation>,
+    per_rule: Option<usize>,
+    total: Option<usize>,
+    omitted: &mut BTreeMap<String, usize>,
+) {
+    let mut ranked: Vec<usize> = (0..violations.len()).collect();
+    ranked.sort_by(|&a, &b| {
+        let (a, b) = (&violations[a], &violations[b]);
+        (a.severity as u8, &a.file, a.line, a.rule.as_str(), &a.message)
+            .cmp(&(b.severity as u8, &b.file, b.line, b.rule.as_str(), &b.message))
+    });
+
+    let mut keep = vec![false; violations.len()];
+    let mut per_rule_kept: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut kept = 0;
+    for index in ranked {
+        let rule = violations[index].rule.as_str();
+        let rule_kept = per_rule_kept.entry(rule).or_default();
+        if per_rule.is_some_and(|max| *rule_kept >= max) || total.is_some_and(|max| kept >= max) {
+            *omitted.entry(rule.to_string()).or_default() += 1;
+            continue;
+        }
+        *rule_kept += 1;
+        kept += 1;
+        keep[index] = true;
+    }
+
+    let mut keep = keep.into_iter();
+    violations.retain(|_| keep.next().unwrap_or(false));
+}
+
 /// Check if two violations match (ignoring line numbers).
 /// Line numbers are ignored because code changes can shift them.
 #[allow(dead_code)]
@@ -307,7 +1086,7 @@ mod tests {
         Violation {
             rule,
             message: message.to_string(),
-            file: file.to_string(),
+            file: Some(file.to_string()),
             line,
             severity: rule.default_severity(),
         }
@@ -349,6 +1128,61 @@ mod tests {
         assert_eq!(result.violations.len(), 2); // Both should remain (different messages)
     }
 
+    #[test]
+    fn test_truncate_keeps_most_severe_and_earliest() {
+        let mut violations = [
+            make_violation(ViolationRule::MockData, "src/b.rs", 3, "mock"),
+            make_violation(ViolationRule::MockData, "src/a.rs", 9, "mock"),
+            make_violation(ViolationRule::GodFile, "src/a.rs", 1, "too long"),
+            make_violation(ViolationRule::MockData, "src/a.rs", 2, "mock"),
+            make_violation(ViolationRule::ForbiddenPattern, "src/c.rs", 5, "forbidden"),
+        ];
+        violations[4].severity = Severity::Error;
+
+        let kept = |order: &[usize]| {
+            let mut result = DetectionResult::new();
+            for &i in order {
+                result.add_violation(violations[i].clone());
+            }
+            result.truncate(Some(2), Some(3));
+            let mut keys: Vec<_> = result.violations.iter().map(Violation::key).collect();
+            keys.sort();
+            (keys, result.truncated)
+        };
+
+        let (keys, truncated) = kept(&[0, 1, 2, 3, 4]);
+        assert_eq!(
+            keys,
+            vec![
+                "forbidden_pattern|src/c.rs|5|forbidden",
+                "god_file|src/a.rs|1|too long",
+                "mock_data|src/a.rs|2|mock",
+            ]
+        );
+        assert_eq!(truncated, BTreeMap::from([("mock_data".to_string(), 2)]));
+        assert_eq!(kept(&[4, 3, 2, 1, 0]), (keys, truncated));
+    }
+
+    #[test]
+    fn test_truncate_bounds_new_violations() {
+        let mut result = DetectionResult::new();
+        let new: Vec<_> = (1..=4)
+            .map(|line| make_violation(ViolationRule::MockData, "src/a.rs", line, "mock"))
+            .collect();
+        for v in &new {
+            result.add_violation(v.clone());
+        }
+        result.add_violation(make_violation(ViolationRule::GodFile, "src/b.rs", 1, "too long"));
+        result.set_baseline("HEAD~1", new);
+
+        result.truncate(Some(2), None);
+
+        let lines: Vec<_> = result.new_violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, vec![1, 2]);
+        assert_eq!(result.new_truncated, BTreeMap::from([("mock_data".to_string(), 2)]));
+        assert_eq!(result.truncated, BTreeMap::from([("mock_data".to_string(), 2)]));
+    }
+
     #[test]
     fn test_deduplicate_keeps_violations_on_different_lines() {
         let mut result = DetectionResult::new();
@@ -379,6 +1213,63 @@ mod tests {
         assert_eq!(result.violations.len(), 2); // Both should remain (different files)
     }
 
+    #[test]
+    fn test_rule_names_round_trip() {
+        for rule in ViolationRule::ALL {
+            assert_eq!(ViolationRule::parse(rule.as_str()), Some(*rule));
+            assert_eq!(rule.as_str().parse::<ViolationRule>(), Ok(*rule));
+        }
+    }
+
+    #[test]
+    fn test_rule_from_str_aliases() {
+        assert_eq!("god-file".parse(), Ok(ViolationRule::GodFile));
+        assert_eq!("GodFile".parse(), Ok(ViolationRule::GodFile));
+        assert_eq!(" HallucinatedDependency ".parse(), Ok(ViolationRule::HallucinatedDependency));
+        assert_eq!("hollow_todos".parse(), Ok(ViolationRule::HollowTodo));
+        assert_eq!("long-parameter-lists".parse(), Ok(ViolationRule::TooManyParams));
+        assert_eq!(
+            "no_such_rule".parse::<ViolationRule>(),
+            Err("unknown rule: no_such_rule".to_string())
+        );
+    }
+
+    #[test]
+    fn test_violation_builder() {
+        let v = Violation::new(ViolationRule::MissingTest, "test 'TestCharge' not found");
+        assert!(v.is_project_level());
+        assert_eq!(v.line, 0);
+        assert_eq!(v.severity, Severity::Warning);
+
+        let v = v.at("payments_test.go", 4).with_severity(Severity::Error);
+        assert_eq!(v.location(), "payments_test.go");
+        assert_eq!(v.line, 4);
+        assert_eq!(v.severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_merge_preserves_order() {
+        let mut a = DetectionResult::new();
+        a.scanned = 1;
+        a.scan_root = Some("a".to_string());
+        a.add_violation(make_violation(ViolationRule::GodFile, "a.rs", 1, "first"));
+        a.add_violation(make_violation(ViolationRule::GodFile, "a.rs", 2, "second"));
+
+        let mut b = DetectionResult::new();
+        b.scanned = 2;
+        b.scan_root = Some("b".to_string());
+        b.add_violation(make_violation(ViolationRule::MockData, "b.rs", 1, "third"));
+        b.set_baseline("main", vec![make_violation(ViolationRule::MockData, "b.rs", 1, "third")]);
+
+        a.merge(b);
+        let messages: Vec<_> = a.violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(messages, vec!["first", "second", "third"]);
+        assert_eq!(a.scanned, 3);
+        assert_eq!(a.scan_root.as_deref(), Some("a"));
+        assert_eq!(a.baseline_ref.as_deref(), Some("main"));
+        assert_eq!(a.new_violation_count(), 1);
+    }
+
     #[test]
     fn test_forbidden_pattern_severity_is_warning() {
         assert_eq!(ViolationRule::ForbiddenPattern.default_severity(), Severity::Warning);
@@ -0,0 +1,193 @@
+//! Detection of Rust functions that lean on `unwrap`, `expect`, and `panic!`.
+//!
+//! Generated Rust skips error handling by unwrapping every `Result` on the
+//! way. Panicking call sites per function come from `FileFacts::unwrap_usage`;
+//! a function is flagged when it has more than `max_per_function` of them, or
+//! any at all in a file matching `production_paths`. Test code (`#[test]`
+//! functions, `#[cfg(test)]` modules, and the files the `FactsBundle`
+//! classifies as tests), files under `benches/` and `examples/`, and `main`
+//! are exempt.
+
+use std::path::{Component, Path};
+
+use crate::analysis::UnwrapUsage;
+use crate::contract::UnwrapOveruseConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Directories of a Rust package that hold benchmark and example code.
+const NON_PRODUCTION_DIRS: &[&str] = &["benches", "examples"];
+
+/// Whether a file, relative to the project root, is benchmark or example code.
+fn is_example_path(relative: &Path) -> bool {
+    relative.components().any(|c| {
+        matches!(c, Component::Normal(dir) if dir.to_str().is_some_and(|d| NON_PRODUCTION_DIRS.contains(&d)))
+    })
+}
+
+/// Describe the call sites of a function: "2 unwrap, 1 panic!".
+fn breakdown(usage: &UnwrapUsage) -> String {
+    [(usage.unwraps, "unwrap"), (usage.expects, "expect"), (usage.panics, "panic!")]
+        .iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, name)| format!("{} {}", count, name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Flag Rust functions with too many `unwrap`/`expect`/`panic!` sites.
+pub fn detect_unwrap_overuse<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &UnwrapOveruseConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let production = build_globs("unwrap_overuse production_paths", &config.production_paths)?;
+    let max = config.max_per_function();
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        if file_facts.language != "rust" {
+            continue;
+        }
+        let relative = path.strip_prefix(facts.base_dir()).unwrap_or(path);
+        if facts.is_test_file(path) || is_example_path(relative) {
+            continue;
+        }
+        result.scanned += 1;
+        let in_production = production.is_match(relative);
+
+        for usage in &file_facts.unwrap_usage {
+            if usage.in_test || usage.function == "main" {
+                continue;
+            }
+            let total = usage.total();
+            let message = if total > max {
+                format!(
+                    "{} can panic at {} sites ({}), more than the limit of {}",
+                    usage.function,
+                    total,
+                    breakdown(usage),
+                    max
+                )
+            } else if in_production {
+                format!(
+                    "{} can panic at {} site{} ({}) in a production path",
+                    usage.function,
+                    total,
+                    if total == 1 { "" } else { "s" },
+                    breakdown(usage)
+                )
+            } else {
+                continue;
+            };
+            result.add_violation(
+                Violation::new(ViolationRule::UnwrapOveruse, message)
+                    .at(path.to_string_lossy(), usage.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &UnwrapOveruseConfig) -> Vec<(String, usize, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::UnwrapOveruse]);
+        detect_unwrap_overuse(&facts, &paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                let file = v.file.as_deref().unwrap();
+                let relative = Path::new(file).strip_prefix(temp.path()).unwrap().display().to_string();
+                (relative, v.line, v.message)
+            })
+            .collect()
+    }
+
+    const SERVER: &str = r#"fn main() {
+    let config = load_config().unwrap();
+    let listener = bind(&config).unwrap();
+    serve(listener).unwrap();
+    shutdown().unwrap();
+}
+
+fn load_config() -> Result<Config, Error> {
+    let text = std::fs::read_to_string("server.toml").unwrap();
+    let config: Config = toml::from_str(&text).unwrap();
+    let port = config.port.expect("port is set");
+    if port == 0 {
+        panic!("port must not be zero");
+    }
+    Ok(config)
+}
+
+fn addr(config: &Config) -> String {
+    config.host.clone().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn loads() {
+        let a = load_config().unwrap();
+        let b = load_config().unwrap();
+        let c = load_config().unwrap();
+        let d = load_config().unwrap();
+    }
+}
+"#;
+
+    #[test]
+    fn test_functions_over_the_limit() {
+        let found = detect(&[("src/server.rs", SERVER)], &UnwrapOveruseConfig::default());
+        assert_eq!(
+            found,
+            vec![(
+                "src/server.rs".to_string(),
+                8,
+                "load_config can panic at 4 sites (2 unwrap, 1 expect, 1 panic!), more than the limit of 3"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_any_site_in_production_paths() {
+        let config = UnwrapOveruseConfig {
+            production_paths: vec!["src/**".to_string()],
+            ..Default::default()
+        };
+        let found = detect(&[("src/server.rs", SERVER), ("tests/server.rs", SERVER)], &config);
+        let lines: Vec<_> = found.iter().map(|(file, line, _)| (file.as_str(), *line)).collect();
+        assert_eq!(lines, vec![("src/server.rs", 8), ("src/server.rs", 18)]);
+        assert_eq!(found[1].2, "addr can panic at 1 site (1 unwrap) in a production path");
+    }
+}
@@ -0,0 +1,431 @@
+//! Safeguards for regexes supplied by the contract.
+//!
+//! Forbidden patterns and mock signatures are user regexes run over inputs
+//! of any size. The regex crate never backtracks, but a pattern with a large
+//! program (nested or huge bounded repeats, Unicode classes) can still fall
+//! back to its slowest engine and crawl through a multi-megabyte minified
+//! file. Three layers keep one such pattern from holding up a run:
+//!
+//! - Patterns are compiled with a size limit (`PATTERN_SIZE_LIMIT`); larger
+//!   programs are rejected when the contract is validated.
+//! - `suspicious_pattern` names nested quantifiers and huge bounded repeats,
+//!   which contract validation reports as warnings.
+//! - Matching is charged to a per-file, per-pattern time budget. Long lines
+//!   are searched in bounded windows that overlap, so a match spanning a
+//!   window boundary is still found, and the deadline is checked between
+//!   lines and windows. A pattern that runs out of budget is disabled for
+//!   the rest of the run through the shared `PatternGuard`, which reports it
+//!   once as a contract warning.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use regex::{Regex, RegexBuilder};
+
+/// Compiled program size above which a contract regex is rejected (5 MiB).
+pub const PATTERN_SIZE_LIMIT: usize = 5 << 20;
+
+/// Bounded repeat count above which a pattern is reported as suspicious.
+const MAX_BOUNDED_REPEAT: u32 = 1000;
+
+/// Bytes of a long line searched at a time.
+const WINDOW: usize = 16 << 10;
+
+/// Bytes by which consecutive windows overlap; matches up to this long are
+/// found even when they straddle a window boundary.
+const WINDOW_OVERLAP: usize = 2 << 10;
+
+/// Compile a contract regex under the size limit.
+pub fn compile_user_pattern(pattern: &str) -> Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern).size_limit(PATTERN_SIZE_LIMIT).build()
+}
+
+/// Why a pattern is likely to match slowly on large inputs, if it is.
+///
+/// Flags a quantified group that itself contains an unbounded quantifier
+/// (`(a+)+b`, `(\w*\s?)*`) and bounded repeats over 1000 (`x{5000}`).
+pub fn suspicious_pattern(pattern: &str) -> Option<String> {
+    // Per open group: whether it contains an unbounded quantifier
+    let mut groups: Vec<bool> = vec![false];
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '(' => {
+                groups.push(false);
+                i += 1;
+                // Group flags and names: `(?:`, `(?i)`, `(?P<name>`
+                if chars.get(i) == Some(&'?') {
+                    i += 1;
+                }
+            }
+            ')' => {
+                let inner = groups.pop().unwrap_or(false);
+                if groups.is_empty() {
+                    groups.push(false);
+                }
+                i += 1;
+                let (quantifier, next) = read_quantifier(&chars, i);
+                if let Some(quantifier) = quantifier {
+                    if let Some(reason) = repeat_reason(quantifier) {
+                        return Some(reason);
+                    }
+                    if inner && quantifier.unbounded_or_repeated() {
+                        return Some("nests a quantifier inside a repeated group".to_string());
+                    }
+                }
+                let top = groups.last_mut().expect("outer frame");
+                *top |= inner || quantifier.is_some_and(|q| q.unbounded());
+                i = next;
+            }
+            atom => {
+                // An escape, a character class, or a single character
+                i = match atom {
+                    '\\' => i + 2,
+                    '[' => skip_class(&chars, i),
+                    _ => i + 1,
+                };
+                let (quantifier, next) = read_quantifier(&chars, i);
+                if let Some(quantifier) = quantifier {
+                    if let Some(reason) = repeat_reason(quantifier) {
+                        return Some(reason);
+                    }
+                    let top = groups.last_mut().expect("outer frame");
+                    *top |= quantifier.unbounded();
+                }
+                i = next;
+            }
+        }
+    }
+    None
+}
+
+#[derive(Clone, Copy)]
+enum Quantifier {
+    /// `*`, `+`, or `{n,}`
+    Unbounded,
+    /// `?`
+    Optional,
+    /// `{n}` or `{n,m}`, with the largest count
+    Bounded(u32),
+}
+
+impl Quantifier {
+    fn unbounded(self) -> bool {
+        matches!(self, Quantifier::Unbounded)
+    }
+
+    fn unbounded_or_repeated(self) -> bool {
+        match self {
+            Quantifier::Unbounded => true,
+            Quantifier::Optional => false,
+            Quantifier::Bounded(max) => max > 1,
+        }
+    }
+}
+
+fn repeat_reason(quantifier: Quantifier) -> Option<String> {
+    match quantifier {
+        Quantifier::Bounded(max) if max > MAX_BOUNDED_REPEAT => {
+            Some(format!("repeats up to {} times", max))
+        }
+        _ => None,
+    }
+}
+
+/// Read the quantifier starting at `i`, with its lazy `?` suffix, if any.
+fn read_quantifier(chars: &[char], i: usize) -> (Option<Quantifier>, usize) {
+    let (quantifier, mut next) = match chars.get(i) {
+        Some('*') | Some('+') => (Quantifier::Unbounded, i + 1),
+        Some('?') => (Quantifier::Optional, i + 1),
+        Some('{') => {
+            let Some(close) = chars[i..].iter().position(|&c| c == '}') else {
+                return (None, i);
+            };
+            let body: String = chars[i + 1..i + close].iter().collect();
+            let mut bounds = body.splitn(2, ',');
+            let min = bounds.next().and_then(|n| n.trim().parse::<u32>().ok());
+            let quantifier = match (min, bounds.next().map(str::trim)) {
+                (Some(min), None) => Quantifier::Bounded(min),
+                (Some(_), Some("")) => Quantifier::Unbounded,
+                (Some(min), Some(max)) => match max.parse::<u32>() {
+                    Ok(max) => Quantifier::Bounded(min.max(max)),
+                    Err(_) => return (None, i),
+                },
+                // Not a repeat (`{` as a literal)
+                (None, _) => return (None, i),
+            };
+            (quantifier, i + close + 1)
+        }
+        _ => return (None, i),
+    };
+    if chars.get(next) == Some(&'?') {
+        next += 1;
+    }
+    (Some(quantifier), next)
+}
+
+/// Index just past the character class opening at `start`.
+fn skip_class(chars: &[char], start: usize) -> usize {
+    let mut i = start + 1;
+    if chars.get(i) == Some(&'^') {
+        i += 1;
+    }
+    // A `]` first in the class is a literal
+    if chars.get(i) == Some(&']') {
+        i += 1;
+    }
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '[' => i = skip_class(chars, i),
+            ']' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    i
+}
+
+/// Patterns disabled during a run after exceeding their matching budget.
+///
+/// Shared by every file scan of a run, so a pattern that blew its budget on
+/// one file is skipped for all the rest.
+#[derive(Debug, Default)]
+pub struct PatternGuard {
+    budget: Option<Duration>,
+    /// `(section, pattern)` to the file where the budget ran out
+    disabled: Mutex<BTreeMap<(String, String), String>>,
+}
+
+impl PatternGuard {
+    /// A guard charging each pattern at most `budget` per file; `None` never disables.
+    pub fn new(budget: Option<Duration>) -> Self {
+        Self {
+            budget,
+            disabled: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Whether the pattern of a contract section has been disabled.
+    pub fn is_disabled(&self, section: &str, pattern: &str) -> bool {
+        let disabled = self.disabled.lock().unwrap_or_else(|e| e.into_inner());
+        disabled.contains_key(&(section.to_string(), pattern.to_string()))
+    }
+
+    fn disable(&self, section: &str, pattern: &str, file: &str) {
+        let mut disabled = self.disabled.lock().unwrap_or_else(|e| e.into_inner());
+        disabled
+            .entry((section.to_string(), pattern.to_string()))
+            .or_insert_with(|| file.to_string());
+    }
+
+    /// Start charging one pattern's matching on one file.
+    pub fn meter<'a>(&'a self, section: &'a str, regex: &'a Regex, file: &'a str) -> PatternMeter<'a> {
+        PatternMeter {
+            guard: self,
+            section,
+            regex,
+            file,
+            spent: Duration::ZERO,
+            exhausted: self.is_disabled(section, regex.as_str()),
+        }
+    }
+
+    /// One warning per disabled pattern, naming it and the file where it ran out.
+    pub fn warnings(&self) -> Vec<String> {
+        let disabled = self.disabled.lock().unwrap_or_else(|e| e.into_inner());
+        let budget = self.budget.unwrap_or_default();
+        disabled
+            .iter()
+            .map(|((section, pattern), file)| {
+                format!(
+                    "{} pattern {:?} took over {} ms on {} and was disabled for the rest of the run",
+                    section,
+                    pattern,
+                    budget.as_millis(),
+                    file
+                )
+            })
+            .collect()
+    }
+}
+
+/// Time spent by one pattern on one file, against the guard's budget.
+pub struct PatternMeter<'a> {
+    guard: &'a PatternGuard,
+    section: &'a str,
+    regex: &'a Regex,
+    file: &'a str,
+    spent: Duration,
+    exhausted: bool,
+}
+
+impl PatternMeter<'_> {
+    /// Whether the pattern is out of budget, on this file or an earlier one.
+    pub fn exhausted(&self) -> bool {
+        self.exhausted
+    }
+
+    /// Byte ranges of the matches in one line, or `None` once the pattern
+    /// is out of budget.
+    ///
+    /// Lines longer than a window are searched one window at a time, each
+    /// extended by an overlap; a window reports the matches starting in it.
+    /// `$` and `\b` can match where a window's text is cut off mid-line, so a
+    /// match ending at the cut is searched for again on the whole line.
+    pub fn find(&mut self, line: &str) -> Option<Vec<(usize, usize)>> {
+        if self.exhausted {
+            return None;
+        }
+        let mut found = Vec::new();
+        let mut window_start = 0;
+        let mut pos = 0;
+
+        loop {
+            let window_end = char_boundary(line, window_start + WINDOW);
+            let haystack = &line[..char_boundary(line, window_end + WINDOW_OVERLAP)];
+            let started = Instant::now();
+            while pos < window_end || (pos == window_end && window_end == line.len()) {
+                let Some(mut m) = self.regex.find_at(haystack, pos) else {
+                    break;
+                };
+                if m.end() == haystack.len() && haystack.len() < line.len() {
+                    match self.regex.find_at(line, pos) {
+                        Some(full) => m = full,
+                        None => {
+                            pos = line.len();
+                            break;
+                        }
+                    }
+                }
+                if m.start() >= window_end && window_end < line.len() {
+                    break;
+                }
+                found.push((m.start(), m.end()));
+                pos = if m.end() > m.start() {
+                    m.end()
+                } else {
+                    char_boundary(line, m.end() + 1)
+                };
+                if m.end() == line.len() {
+                    break;
+                }
+            }
+            self.spent += started.elapsed();
+            if self.guard.budget.is_some_and(|b| self.spent > b) {
+                self.exhausted = true;
+                self.guard.disable(self.section, self.regex.as_str(), self.file);
+                return None;
+            }
+            if window_end >= line.len() {
+                return Some(found);
+            }
+            window_start = window_end;
+            pos = pos.max(window_start);
+        }
+    }
+
+    /// Whether the line has a match, or `None` once the pattern is out of budget.
+    pub fn is_match(&mut self, line: &str) -> Option<bool> {
+        if line.len() <= WINDOW {
+            if self.exhausted {
+                return None;
+            }
+            let started = Instant::now();
+            let matched = self.regex.is_match(line);
+            self.spent += started.elapsed();
+            if self.guard.budget.is_some_and(|b| self.spent > b) {
+                self.exhausted = true;
+                self.guard.disable(self.section, self.regex.as_str(), self.file);
+                return None;
+            }
+            return Some(matched);
+        }
+        self.find(line).map(|found| !found.is_empty())
+    }
+}
+
+/// The largest char boundary of `text` at or below `index`, capped at its length.
+fn char_boundary(text: &str, index: usize) -> usize {
+    let mut index = index.min(text.len());
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suspicious_patterns() {
+        assert!(suspicious_pattern(r"(a+)+b").unwrap().contains("nests a quantifier"));
+        assert!(suspicious_pattern(r"(?:\w*\s?)*$").unwrap().contains("nests a quantifier"));
+        assert!(suspicious_pattern(r"((ab)+c){2,}").unwrap().contains("nests a quantifier"));
+        assert!(suspicious_pattern(r"x{5000}").unwrap().contains("up to 5000 times"));
+        assert!(suspicious_pattern(r"(ab){10,2000}").unwrap().contains("up to 2000 times"));
+
+        for pattern in [r"TODO|FIXME", r"(foo|bar)+", r"(a+)?b", r"[(+)]+", r"\(a+\)+", r"(?i)lorem\s+ipsum", r"x{3,}", r"{literal}"] {
+            assert_eq!(suspicious_pattern(pattern), None, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_size_limit() {
+        assert!(compile_user_pattern(r"TODO\(\w+\)").is_ok());
+        assert!(compile_user_pattern(r"\w{1000}").is_err());
+    }
+
+    #[test]
+    fn test_windows_find_matches_across_boundaries() {
+        let regex = Regex::new(r"needle-\d+").unwrap();
+        let guard = PatternGuard::new(None);
+        // One match in the first window, one straddling its end, and one past
+        // a second boundary that falls inside a multibyte character
+        let mut line = format!("needle-1{}", "a".repeat(WINDOW - 12));
+        line.push_str("needle-22");
+        line.push('b');
+        line.push_str(&"é".repeat(WINDOW / 2));
+        line.push_str("needle-333");
+
+        let found = guard.meter("forbidden_patterns", &regex, "min.js").find(&line).unwrap();
+        let texts: Vec<&str> = found.iter().map(|&(s, e)| &line[s..e]).collect();
+        assert_eq!(texts, vec!["needle-1", "needle-22", "needle-333"]);
+        assert!(found[1].0 < WINDOW && found[1].1 > WINDOW);
+        assert_eq!(found, regex.find_iter(&line).map(|m| (m.start(), m.end())).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_windows_do_not_anchor_at_the_cut() {
+        let guard = PatternGuard::new(None);
+        // A run of word characters reaching past the first window's overlap
+        let line = format!("{} end", "x".repeat(WINDOW + WINDOW_OVERLAP + 10));
+
+        for pattern in [r"x+$", r"x+\b", r"\w+\z"] {
+            let regex = Regex::new(pattern).unwrap();
+            let found = guard.meter("forbidden_patterns", &regex, "min.js").find(&line).unwrap();
+            let expected: Vec<_> = regex.find_iter(&line).map(|m| (m.start(), m.end())).collect();
+            assert_eq!(found, expected, "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn test_budget_disables_pattern() {
+        let regex = Regex::new(r"a").unwrap();
+        let guard = PatternGuard::new(Some(Duration::ZERO));
+
+        let mut meter = guard.meter("mock_signatures", &regex, "big.txt");
+        assert_eq!(meter.is_match("banana"), None);
+        assert!(meter.exhausted());
+        assert!(guard.is_disabled("mock_signatures", "a"));
+        // Later files skip the pattern without matching
+        assert_eq!(guard.meter("mock_signatures", &regex, "other.txt").find("a"), None);
+        assert_eq!(
+            guard.warnings(),
+            vec!["mock_signatures pattern \"a\" took over 0 ms on big.txt and was disabled for the rest of the run"]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+//! Skipping of vendored dependency and build directories.
+//!
+//! A stray `node_modules/` or `.venv/` under the scan root holds more code
+//! than the project itself, none of it the project's. File discovery already
+//! avoids these directories, but the runner also accepts file lists built
+//! elsewhere, so it drops them again before any rule runs.
+
+use std::path::{Component, Path, PathBuf};
+
+use crate::contract::DefaultExcludesConfig;
+
+/// Check whether a file sits below one of `dirs`, relative to `base_dir`.
+///
+/// Only directories below the base are considered, so scanning a package
+/// inside `node_modules` on purpose still works.
+pub fn is_vendored(base_dir: &Path, path: &Path, dirs: &[&str]) -> bool {
+    let relative = path.strip_prefix(base_dir).unwrap_or(path);
+    let Some(parent) = relative.parent() else {
+        return false;
+    };
+    parent.components().any(|c| match c {
+        Component::Normal(name) => name.to_str().is_some_and(|n| dirs.contains(&n)),
+        _ => false,
+    })
+}
+
+/// Split off files in vendored directories; returns the kept files and the
+/// number dropped.
+pub fn drop_vendored_files(
+    base_dir: &Path,
+    files: Vec<PathBuf>,
+    config: &DefaultExcludesConfig,
+) -> (Vec<PathBuf>, usize) {
+    if !config.is_enabled() {
+        return (files, 0);
+    }
+
+    let dirs = config.dirs();
+    let total = files.len();
+    let kept: Vec<PathBuf> = files
+        .into_iter()
+        .filter(|f| !is_vendored(base_dir, f, &dirs))
+        .collect();
+    let dropped = total - kept.len();
+    (kept, dropped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drop_vendored_files() {
+        let base = Path::new("/repo");
+        let files: Vec<PathBuf> = [
+            "/repo/src/main.go",
+            "/repo/vendor/github.com/pkg/errors/errors.go",
+            "/repo/web/node_modules/react/index.js",
+            "/repo/api/.venv/lib/site.py",
+            "/repo/src/vendor.go",
+        ]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+
+        let (kept, dropped) = drop_vendored_files(base, files.clone(), &DefaultExcludesConfig::default());
+        assert_eq!(kept, vec![files[0].clone(), files[4].clone()]);
+        assert_eq!(dropped, 3);
+
+        // Only the selected ecosystems, minus kept directories
+        let config = DefaultExcludesConfig {
+            ecosystems: Some(vec!["go".to_string(), "javascript".to_string()]),
+            keep: vec!["vendor".to_string()],
+            ..DefaultExcludesConfig::default()
+        };
+        let (kept, dropped) = drop_vendored_files(base, files.clone(), &config);
+        assert_eq!(dropped, 1);
+        assert!(kept.contains(&files[1]));
+        assert!(kept.contains(&files[3]));
+
+        // A scan rooted inside a dependency directory is not emptied
+        let base = Path::new("/repo/web/node_modules/react");
+        assert!(!is_vendored(base, &files[2], &config.dirs()));
+    }
+}
@@ -0,0 +1,275 @@
+//! Contract waivers: hollow code accepted on purpose, until a date.
+//!
+//! A waiver in the contract names a rule, a path glob or a fingerprint, an
+//! owner, a justification, and the last day it applies:
+//!
+//! ```yaml
+//! waivers:
+//!   - rule: stub_function
+//!     path: "src/export/**"
+//!     owner: "@data-team"
+//!     justification: Phase 2 implements the export path
+//!     expires: 2026-12-31
+//! ```
+//!
+//! Until then, matching violations move to the result's `waived` list and
+//! count toward neither the score nor pass/fail. Afterwards they are reported
+//! again, and the waiver itself is reported as `expired_waiver` so that the
+//! debt is renewed or paid rather than forgotten.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::contract::Waiver;
+
+use super::suppress::days_since_epoch;
+use super::{Violation, ViolationRule};
+
+/// Days before expiry at which a waiver counts as expiring, by default.
+pub const DEFAULT_EXPIRY_WINDOW: i64 = 14;
+
+/// A violation accepted by a contract waiver.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaivedViolation {
+    pub violation: Violation,
+    pub waiver: Waiver,
+}
+
+/// Where a waiver stands on a given day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WaiverStatus {
+    /// Applies, with time to spare
+    Active,
+    /// Applies, but expires within the warning window
+    Expiring,
+    /// Past its expiry date; no longer applies
+    Expired,
+}
+
+impl WaiverStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WaiverStatus::Active => "active",
+            WaiverStatus::Expiring => "expiring",
+            WaiverStatus::Expired => "expired",
+        }
+    }
+}
+
+/// Days from `today` to the waiver's last day: 0 on that day, negative once
+/// it has expired. `None` when either date is not `YYYY-MM-DD`.
+pub fn waiver_days_left(waiver: &Waiver, today: &str) -> Option<i64> {
+    Some(days_since_epoch(&waiver.expires)? - days_since_epoch(today)?)
+}
+
+/// Status of a waiver on `today`; `Expiring` when `within` days or fewer
+/// remain. A waiver with an unreadable date counts as expired.
+pub fn waiver_status(waiver: &Waiver, today: &str, within: i64) -> WaiverStatus {
+    match waiver_days_left(waiver, today) {
+        Some(days) if days > within => WaiverStatus::Active,
+        Some(days) if days >= 0 => WaiverStatus::Expiring,
+        _ => WaiverStatus::Expired,
+    }
+}
+
+/// A waiver with its status on a given day, for listings.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaiverEntry {
+    #[serde(flatten)]
+    pub waiver: Waiver,
+    pub status: WaiverStatus,
+    /// Days left until expiry; negative once expired
+    pub days_left: Option<i64>,
+}
+
+/// The status of every waiver on `today`, soonest expiry first.
+pub fn waiver_entries(waivers: &[Waiver], today: &str, within: i64) -> Vec<WaiverEntry> {
+    let mut entries: Vec<WaiverEntry> = waivers
+        .iter()
+        .map(|w| WaiverEntry {
+            waiver: w.clone(),
+            status: waiver_status(w, today, within),
+            days_left: waiver_days_left(w, today),
+        })
+        .collect();
+    entries.sort_by_key(|e| e.days_left.unwrap_or(i64::MIN));
+    entries
+}
+
+/// What a waiver applies to, for messages.
+pub fn waiver_target(waiver: &Waiver) -> String {
+    match (&waiver.path, &waiver.fingerprint) {
+        (Some(path), _) => path.clone(),
+        (None, Some(fingerprint)) => format!("fingerprint {}", fingerprint),
+        (None, None) => "(nothing)".to_string(),
+    }
+}
+
+/// A waiver ready to match violations.
+struct LiveWaiver<'a> {
+    waiver: &'a Waiver,
+    rule: ViolationRule,
+    glob: Option<globset::GlobMatcher>,
+}
+
+impl LiveWaiver<'_> {
+    fn matches(&self, violation: &Violation, base_dir: &Path, fingerprint: &str) -> bool {
+        if violation.rule != self.rule {
+            return false;
+        }
+        if let Some(expected) = &self.waiver.fingerprint {
+            return expected == fingerprint;
+        }
+        let (Some(glob), Some(file)) = (&self.glob, violation.file.as_deref()) else {
+            return false;
+        };
+        let path = Path::new(file);
+        let relative = path.strip_prefix(base_dir).unwrap_or(path);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        glob.is_match(relative.trim_start_matches("./"))
+    }
+}
+
+/// Separate the violations accepted by unexpired waivers, and report every
+/// expired waiver.
+///
+/// Returns the remaining violations, followed by one `expired_waiver` notice
+/// per expired waiver, and the waived ones. File paths are matched relative
+/// to `base_dir`; `fingerprint` gives each violation's fingerprint.
+pub fn apply_waivers(
+    violations: Vec<Violation>,
+    waivers: &[Waiver],
+    base_dir: &Path,
+    today: &str,
+    fingerprint: impl Fn(&Violation) -> String,
+) -> anyhow::Result<(Vec<Violation>, Vec<WaivedViolation>)> {
+    let mut live = Vec::new();
+    let mut notices = Vec::new();
+    for waiver in waivers {
+        if waiver_status(waiver, today, 0) == WaiverStatus::Expired {
+            notices.push(Violation::new(
+                ViolationRule::ExpiredWaiver,
+                format!(
+                    "waiver for {} on {} expired {} (owner {}): {}",
+                    waiver.rule,
+                    waiver_target(waiver),
+                    waiver.expires,
+                    waiver.owner,
+                    waiver.justification
+                ),
+            ));
+            continue;
+        }
+        let rule = waiver
+            .rule
+            .parse()
+            .map_err(|e| anyhow::anyhow!("invalid waiver rule: {}", e))?;
+        let glob = match &waiver.path {
+            Some(path) => Some(
+                globset::Glob::new(path)
+                    .map_err(|e| anyhow::anyhow!("invalid waiver path {:?}: {}", path, e))?
+                    .compile_matcher(),
+            ),
+            None => None,
+        };
+        live.push(LiveWaiver { waiver, rule, glob });
+    }
+
+    let mut active = Vec::new();
+    let mut waived = Vec::new();
+    for violation in violations {
+        let fp = if live.is_empty() { String::new() } else { fingerprint(&violation) };
+        match live.iter().find(|w| w.matches(&violation, base_dir, &fp)) {
+            Some(w) => waived.push(WaivedViolation {
+                violation,
+                waiver: w.waiver.clone(),
+            }),
+            None => active.push(violation),
+        }
+    }
+    active.extend(notices);
+    Ok((active, waived))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn waiver(rule: &str, path: Option<&str>, fingerprint: Option<&str>, expires: &str) -> Waiver {
+        Waiver {
+            rule: rule.to_string(),
+            path: path.map(String::from),
+            fingerprint: fingerprint.map(String::from),
+            owner: "@data-team".to_string(),
+            justification: "phase 2 implements the export path".to_string(),
+            expires: expires.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_waiver_entries_sorted_by_expiry() {
+        let waivers = vec![
+            waiver("stub_function", Some("a/**"), None, "2027-03-01"),
+            waiver("mock_data", Some("b/**"), None, "2026-10-01"),
+            waiver("hollow_todo", Some("c/**"), None, "2026-10-20"),
+        ];
+        let entries = waiver_entries(&waivers, "2026-10-17", DEFAULT_EXPIRY_WINDOW);
+        let summary: Vec<_> = entries
+            .iter()
+            .map(|e| (e.waiver.rule.as_str(), e.status, e.days_left))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("mock_data", WaiverStatus::Expired, Some(-16)),
+                ("hollow_todo", WaiverStatus::Expiring, Some(3)),
+                ("stub_function", WaiverStatus::Active, Some(135)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_waiver_status() {
+        let w = waiver("stub_function", Some("src/**"), None, "2026-10-31");
+        assert_eq!(waiver_days_left(&w, "2026-10-17"), Some(14));
+        assert_eq!(waiver_status(&w, "2026-10-17", 7), WaiverStatus::Active);
+        assert_eq!(waiver_status(&w, "2026-10-17", 14), WaiverStatus::Expiring);
+        assert_eq!(waiver_status(&w, "2026-10-31", 0), WaiverStatus::Expiring);
+        assert_eq!(waiver_status(&w, "2026-11-01", 0), WaiverStatus::Expired);
+    }
+
+    #[test]
+    fn test_apply_waivers() {
+        let base = Path::new("/project");
+        let violations = vec![
+            Violation::new(ViolationRule::StubFunction, "function Export is a stub").at("/project/src/export/csv.go", 4),
+            Violation::new(ViolationRule::StubFunction, "function Run is a stub").at("src/main.go", 9),
+            Violation::new(ViolationRule::MockData, "mock data found").at("src/export/csv.go", 12),
+            Violation::new(ViolationRule::MissingFile, "required file missing: LICENSE"),
+        ];
+        let waivers = vec![
+            waiver("stub_function", Some("src/export/**"), None, "2026-12-31"),
+            waiver("missing_file", None, Some("fp-license"), "2026-10-17"),
+            waiver("mock_data", Some("src/**"), None, "2026-10-16"),
+        ];
+        let fingerprint = |v: &Violation| match v.rule {
+            ViolationRule::MissingFile => "fp-license".to_string(),
+            _ => "other".to_string(),
+        };
+
+        let (active, waived) = apply_waivers(violations, &waivers, base, "2026-10-17", fingerprint).unwrap();
+        let waived: Vec<_> = waived.iter().map(|w| w.violation.message.as_str()).collect();
+        assert_eq!(waived, vec!["function Export is a stub", "required file missing: LICENSE"]);
+
+        // The expired mock_data waiver no longer applies and is reported itself
+        let rules: Vec<_> = active.iter().map(|v| v.rule).collect();
+        assert_eq!(
+            rules,
+            vec![ViolationRule::StubFunction, ViolationRule::MockData, ViolationRule::ExpiredWaiver]
+        );
+        assert!(active[2].is_project_level());
+        assert!(active[2].message.contains("expired 2026-10-16 (owner @data-team)"));
+    }
+}
@@ -0,0 +1,304 @@
+//! Detection of callables that only forward their parameters to another function.
+//!
+//! Generated code likes layers: `getUserById(id)` calling
+//! `userService.getUserById(id)` calling `repo.getUserById(id)`, each adding a
+//! name and nothing else. Forwarding bodies come from
+//! `FileFacts::forwarding_calls`; the callee must be a function or method
+//! declared in the project (from the symbol index), so thin wrappers over
+//! libraries are left alone.
+//!
+//! Forwarding is sometimes the point, so methods of types that implement an
+//! interface or trait, property accessors, and files matching
+//! `api_facade_paths` are exempt. Calls match by name, which makes this a
+//! low-confidence reviewer aid: one wrapper is noise, but many wrappers of the
+//! same callee are reported together as one finding.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use crate::analysis::{DeclarationKind, ForwardingCall};
+use crate::contract::TrivialWrappersConfig;
+
+use super::globs::build_globs;
+use super::{DetectionResult, FactsBundle, Violation, ViolationRule};
+
+/// Interfaces and traits of the project, and the supertypes of each type.
+#[derive(Default)]
+struct TypeGraph {
+    members: HashMap<String, Vec<String>>,
+    supertypes: HashMap<String, Vec<String>>,
+}
+
+impl TypeGraph {
+    /// Whether a method may exist to satisfy an interface its type implements.
+    ///
+    /// A supertype declared outside the scan (`Comparable`, `fmt::Display`)
+    /// has unknown members, so it exempts every method of the type.
+    fn satisfies_interface(&self, receiver: &str, method: &str) -> bool {
+        self.supertypes.get(receiver).is_some_and(|interfaces| {
+            interfaces.iter().any(|interface| match self.members.get(interface) {
+                Some(members) => members.iter().any(|m| m == method),
+                None => true,
+            })
+        })
+    }
+}
+
+/// Flag callables that only forward their parameters to a project function.
+///
+/// More than `consolidate_after` wrappers of one callee are reported as a
+/// single violation at the first of them.
+pub fn detect_trivial_wrappers<P: AsRef<Path>>(
+    facts: &FactsBundle,
+    files: &[P],
+    config: &TrivialWrappersConfig,
+) -> anyhow::Result<DetectionResult> {
+    let mut result = DetectionResult::new();
+    let facades = build_globs("trivial_wrappers api_facade_paths", &config.api_facade_paths)?;
+
+    // Sort files for deterministic processing
+    let mut sorted_files: Vec<_> = files.iter().map(|f| f.as_ref()).collect();
+    sorted_files.sort();
+
+    let mut types = TypeGraph::default();
+    for path in &sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        for decl in &file_facts.declarations {
+            if matches!(decl.kind, DeclarationKind::Interface | DeclarationKind::Trait) {
+                types.members.entry(decl.name.clone()).or_default().extend(decl.members.iter().cloned());
+            } else if matches!(decl.kind, DeclarationKind::Struct | DeclarationKind::Type) {
+                // Classes extended in the project declare no member list
+                types.members.entry(decl.name.clone()).or_default();
+            }
+        }
+        for implementation in &file_facts.implementations {
+            types
+                .supertypes
+                .entry(implementation.type_name.clone())
+                .or_default()
+                .push(implementation.interface.clone());
+        }
+    }
+
+    let mut by_callee: BTreeMap<&str, Vec<(&Path, &ForwardingCall)>> = BTreeMap::new();
+    for path in sorted_files {
+        let Some(Ok(file_facts)) = facts.file(path) else {
+            continue;
+        };
+        result.scanned += 1;
+
+        let relative = path.strip_prefix(facts.base_dir()).unwrap_or(path);
+        if facades.is_match(relative) {
+            continue;
+        }
+
+        for wrapper in &file_facts.forwarding_calls {
+            if wrapper.is_accessor || wrapper.in_trait_impl {
+                continue;
+            }
+            if let Some(receiver) = &wrapper.receiver {
+                if types.satisfies_interface(receiver, &wrapper.name) {
+                    continue;
+                }
+            }
+            // The callee must be project code, and not the wrapper calling itself
+            let is_local = facts.symbols_named(&wrapper.callee).iter().any(|symbol| {
+                matches!(symbol.kind, DeclarationKind::Function | DeclarationKind::Method)
+                    && !(symbol.file == path && symbol.line == wrapper.span.start_line)
+            });
+            if is_local {
+                by_callee.entry(&wrapper.callee).or_default().push((path, wrapper));
+            }
+        }
+    }
+
+    let display = |path: &Path| path.strip_prefix(facts.base_dir()).unwrap_or(path).display().to_string();
+    let qualified = |wrapper: &ForwardingCall| match &wrapper.receiver {
+        Some(receiver) => format!("{}.{}", receiver, wrapper.name),
+        None => wrapper.name.clone(),
+    };
+    for (callee, wrappers) in by_callee {
+        if wrappers.len() > config.consolidate_after() {
+            let (first_path, first) = wrappers[0];
+            let locations: Vec<_> = wrappers
+                .iter()
+                .map(|(path, w)| format!("{} at {}:{}", qualified(w), display(path), w.span.start_line))
+                .collect();
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::TrivialWrapper,
+                    format!(
+                        "{} functions only forward their parameters to {}: {}",
+                        wrappers.len(),
+                        callee,
+                        locations.join(", ")
+                    ),
+                )
+                .at(first_path.to_string_lossy(), first.span.start_line),
+            );
+            continue;
+        }
+
+        for (path, wrapper) in wrappers {
+            result.add_violation(
+                Violation::new(
+                    ViolationRule::TrivialWrapper,
+                    format!("{} only forwards its parameters to {}", qualified(wrapper), callee),
+                )
+                .at(path.to_string_lossy(), wrapper.span.start_line),
+            );
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::AnalysisContext;
+    use tempfile::TempDir;
+
+    fn detect(files: &[(&str, &str)], config: &TrivialWrappersConfig) -> Vec<(String, usize, String)> {
+        crate::analysis::register_analyzers();
+
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<_> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+
+        let ctx = AnalysisContext::new(temp.path());
+        let facts = FactsBundle::for_rules(&ctx, &paths, &[ViolationRule::TrivialWrapper]);
+        detect_trivial_wrappers(&facts, &paths, config)
+            .unwrap()
+            .violations
+            .into_iter()
+            .map(|v| {
+                let file = Path::new(v.file.as_deref().unwrap()).file_name().unwrap().to_string_lossy().to_string();
+                (file, v.line, v.message)
+            })
+            .collect()
+    }
+
+    const SERVICE: &str = r#"export class UserService {
+  async getUserById(id) {
+    const row = await db.query("select * from users where id = ?", [id]);
+    return row ? toUser(row) : null;
+  }
+}
+"#;
+
+    const CONTROLLER: &str = r#"export async function getUserById(id) {
+  return await userService.getUserById(id);
+}
+
+export function formatDate(date) {
+  return date.toISOString();
+}
+
+export function parseJson(text) {
+  return JSON.parse(text);
+}
+
+export function findUser(id) {
+  return userService.getUserById(id, { cache: true });
+}
+"#;
+
+    #[test]
+    fn test_wrapper_of_project_function() {
+        let found = detect(
+            &[("service.js", SERVICE), ("controller.js", CONTROLLER)],
+            &TrivialWrappersConfig::default(),
+        );
+        assert_eq!(
+            found,
+            vec![(
+                "controller.js".to_string(),
+                1,
+                "getUserById only forwards its parameters to getUserById".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_api_facade_paths_are_exempt() {
+        let config = TrivialWrappersConfig {
+            api_facade_paths: vec!["api/**".to_string()],
+            ..Default::default()
+        };
+        let found = detect(&[("service.js", SERVICE), ("api/controller.js", CONTROLLER)], &config);
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_interface_methods_and_accessors_are_exempt() {
+        let source = r#"interface Store {
+  get(key: string): string;
+}
+
+class CachedStore implements Store {
+  get(key: string): string {
+    return this.inner.get(key);
+  }
+  put(key: string, value: string): void {
+    this.inner.put(key, value);
+  }
+  get size(): number {
+    return this.inner.size();
+  }
+}
+
+class MemoryStore implements Store {
+  get(key: string): string {
+    return this.data[key];
+  }
+  put(key: string, value: string): void {
+    this.data[key] = value;
+  }
+  size(): number {
+    return 0;
+  }
+}
+"#;
+        let found = detect(&[("store.ts", source)], &TrivialWrappersConfig::default());
+        assert_eq!(
+            found,
+            vec![(
+                "store.ts".to_string(),
+                9,
+                "CachedStore.put only forwards its parameters to put".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_many_wrappers_of_one_callee_are_consolidated() {
+        let library = "def load_config(path):\n    with open(path) as f:\n        return parse(f.read())\n";
+        let wrapper = |name: &str| format!("def {}(path):\n    return config.load_config(path)\n", name);
+        let (a, b, c) = (wrapper("read_settings"), wrapper("get_config"), wrapper("load"));
+        let config = TrivialWrappersConfig {
+            consolidate_after: Some(2),
+            ..Default::default()
+        };
+        let found = detect(
+            &[("config.py", library), ("a.py", &a), ("b.py", &b), ("c.py", &c)],
+            &config,
+        );
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].0.as_str(), found[0].1), ("a.py", 1));
+        assert_eq!(
+            found[0].2,
+            "3 functions only forward their parameters to load_config: read_settings at a.py:1, \
+             get_config at b.py:1, load at c.py:1"
+        );
+    }
+}
@@ -0,0 +1,575 @@
+//! Comparison of two JSON reports.
+//!
+//! Violations are matched by their canonical fingerprint (see
+//! [`crate::detect::fingerprint_violations`]), which leaves out the line so
+//! that a violation shifted by an edit above it is reported as moved rather
+//! than as removed and added again. Reports written without fingerprints are
+//! matched on rule, message, and the file relative to the scan root.
+//! Violations with the same fingerprint are paired in line order; a line
+//! present in both reports is paired first.
+//!
+//! A baseline for `lint --baseline` can also be fetched over HTTPS from an
+//! artifact store. Each URL is fetched once per run. Baselines are matched
+//! on paths relative to the scan root, so one made in a CI runner's
+//! workspace applies to a checkout anywhere else.
+
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as FmtWrite;
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+use crate::detect::{fallback_fingerprint_parts, DetectionResult, Violation};
+use crate::policy;
+use crate::report::{JsonReport, JsonViolation};
+use crate::score::SubScore;
+
+/// A violation present in both reports at different lines.
+#[derive(Serialize, Deserialize)]
+pub struct MovedViolation {
+    pub fingerprint: String,
+    pub rule: String,
+    pub file: Option<String>,
+    pub message: String,
+    pub from_line: usize,
+    pub to_line: usize,
+}
+
+/// Differences between a base and a head report.
+#[derive(Serialize, Deserialize)]
+pub struct ReportDiff {
+    pub base_score: i32,
+    pub head_score: i32,
+    /// Head score minus base score; positive means the head is hollower.
+    pub score_delta: i32,
+    /// Structural and content sub-scores, when both reports carry them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_scores: Option<SubScoresDiff>,
+    /// Violations only in the head report, each with its fingerprint set
+    pub added: Vec<JsonViolation>,
+    /// Violations only in the base report, each with its fingerprint set
+    pub removed: Vec<JsonViolation>,
+    pub moved: Vec<MovedViolation>,
+}
+
+/// A sub-score in the base and head reports.
+#[derive(Serialize, Deserialize)]
+pub struct SubScoreChange {
+    pub base: i32,
+    pub head: i32,
+    pub delta: i32,
+}
+
+impl SubScoreChange {
+    fn new(base: &SubScore, head: &SubScore) -> Self {
+        Self {
+            base: base.score,
+            head: head.score,
+            delta: head.score - base.score,
+        }
+    }
+}
+
+/// Structural and content sub-scores in the base and head reports.
+#[derive(Serialize, Deserialize)]
+pub struct SubScoresDiff {
+    pub structural: SubScoreChange,
+    pub content: SubScoreChange,
+}
+
+impl ReportDiff {
+    /// Whether the head report has violations the base does not.
+    pub fn has_added(&self) -> bool {
+        !self.added.is_empty()
+    }
+}
+
+/// Fingerprint of a report violation: the canonical one the report carries
+/// or, when `canonical` is false, the fallback fingerprint of its rule,
+/// message, and file relative to `root`.
+fn report_fingerprint(v: &JsonViolation, root: &Path, canonical: bool) -> String {
+    match &v.fingerprint {
+        Some(fingerprint) if canonical => fingerprint.clone(),
+        _ => fallback_fingerprint_parts(&v.rule, v.file.as_deref(), &v.message, root),
+    }
+}
+
+/// Load a JSON report written by `hollowcheck lint --format json`.
+pub fn load_report(path: &Path) -> anyhow::Result<JsonReport> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("cannot read report {:?}: {}", path, e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("invalid report {:?}: {}", path, e))
+}
+
+/// Largest baseline report accepted from a server.
+const MAX_BASELINE_BYTES: usize = 64 * 1024 * 1024;
+
+/// Baselines fetched in this run, by URL.
+static FETCHED_BASELINES: Lazy<Mutex<HashMap<String, String>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Load a baseline report from a file path or an `http(s)://` URL.
+///
+/// URLs are fetched with the client remote contracts use, so plain HTTP is
+/// only allowed to the local machine.
+pub fn load_baseline(location: &str) -> anyhow::Result<JsonReport> {
+    if location.starts_with("s3://") {
+        anyhow::bail!(
+            "cannot read baseline {}: s3:// URLs are not supported, use an https URL to the object (such as a presigned URL)",
+            location
+        );
+    }
+    if !policy::is_remote(location) {
+        return load_report(Path::new(location));
+    }
+    let content = fetch_baseline(location)?;
+    serde_json::from_str(&content).map_err(|e| anyhow::anyhow!("invalid report {}: {}", location, e))
+}
+
+/// Fetch a baseline report, once per run for each URL.
+fn fetch_baseline(url: &str) -> anyhow::Result<String> {
+    if let Some(content) = FETCHED_BASELINES.lock().unwrap().get(url) {
+        return Ok(content.clone());
+    }
+    let timeout = Duration::from_millis(policy::DEFAULT_FETCH_TIMEOUT_MS);
+    let body = policy::download(url, timeout, MAX_BASELINE_BYTES, "baselines")
+        .map_err(|e| anyhow::anyhow!("cannot fetch baseline {}: {}", url, e))?;
+    let content = String::from_utf8(body).map_err(|_| anyhow::anyhow!("baseline {} is not valid UTF-8", url))?;
+    FETCHED_BASELINES.lock().unwrap().insert(url.to_string(), content.clone());
+    Ok(content)
+}
+
+/// Violations a baseline report does not have, matched by fingerprint so
+/// that violations shifted to other lines stay grandfathered. Each baseline
+/// violation grandfathers one current violation with its fingerprint.
+///
+/// Fingerprints hold paths relative to the scan root, so a baseline made
+/// from another checkout path (a CI runner's workspace) still matches. A
+/// baseline written without fingerprints (`--go-compat`, or before
+/// violations carried one) is matched on rule, message, and the file
+/// relative to each side's scan root.
+pub fn new_violations(baseline: &JsonReport, result: &DetectionResult) -> Vec<Violation> {
+    let canonical = baseline.violations.iter().all(|v| v.fingerprint.is_some());
+    let baseline_root = report_root(baseline);
+    let current_root = Path::new(result.scan_root.as_deref().unwrap_or(""));
+
+    let mut known: BTreeMap<String, usize> = BTreeMap::new();
+    for v in &baseline.violations {
+        *known.entry(report_fingerprint(v, baseline_root, canonical)).or_default() += 1;
+    }
+
+    result
+        .violations
+        .iter()
+        .filter(|v| {
+            let key = if canonical {
+                result.fingerprint(v)
+            } else {
+                fallback_fingerprint_parts(v.rule.as_str(), v.file.as_deref(), &v.message, current_root)
+            };
+            match known.get_mut(&key) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// Directory a report's violation paths are relative to.
+fn report_root(report: &JsonReport) -> &Path {
+    Path::new(report.scan_root.as_deref().unwrap_or(&report.path))
+}
+
+/// Compare two reports.
+///
+/// Canonical fingerprints are used when both reports carry them on every
+/// violation, and fallback fingerprints otherwise, so the two sides are
+/// always hashed the same way.
+pub fn diff_reports(base: JsonReport, head: JsonReport) -> ReportDiff {
+    let canonical = base.violations.iter().chain(&head.violations).all(|v| v.fingerprint.is_some());
+    let mut groups: BTreeMap<String, (Vec<JsonViolation>, Vec<JsonViolation>)> = BTreeMap::new();
+    let base_root = report_root(&base).to_path_buf();
+    for v in base.violations {
+        groups.entry(report_fingerprint(&v, &base_root, canonical)).or_default().0.push(v);
+    }
+    let head_root = report_root(&head).to_path_buf();
+    for v in head.violations {
+        groups.entry(report_fingerprint(&v, &head_root, canonical)).or_default().1.push(v);
+    }
+
+    let mut diff = ReportDiff {
+        base_score: base.score,
+        head_score: head.score,
+        score_delta: head.score - base.score,
+        sub_scores: base.sub_scores.zip(head.sub_scores).map(|(base, head)| SubScoresDiff {
+            structural: SubScoreChange::new(&base.structural, &head.structural),
+            content: SubScoreChange::new(&base.content, &head.content),
+        }),
+        added: Vec::new(),
+        removed: Vec::new(),
+        moved: Vec::new(),
+    };
+
+    for (fingerprint, (mut before, mut after)) in groups {
+        // Unchanged lines first, then pair what is left in line order
+        before.retain(|b| match after.iter().position(|a| a.line == b.line) {
+            Some(pos) => {
+                after.remove(pos);
+                false
+            }
+            None => true,
+        });
+        before.sort_by_key(|v| v.line);
+        after.sort_by_key(|v| v.line);
+
+        let mut before = before.into_iter();
+        let mut after = after.into_iter();
+        loop {
+            match (before.next(), after.next()) {
+                (Some(b), Some(a)) => diff.moved.push(MovedViolation {
+                    fingerprint: fingerprint.clone(),
+                    rule: a.rule,
+                    file: a.file,
+                    message: a.message,
+                    from_line: b.line,
+                    to_line: a.line,
+                }),
+                (Some(mut b), None) => {
+                    b.fingerprint = Some(fingerprint.clone());
+                    diff.removed.push(b);
+                }
+                (None, Some(mut a)) => {
+                    a.fingerprint = Some(fingerprint.clone());
+                    diff.added.push(a);
+                }
+                (None, None) => break,
+            }
+        }
+    }
+
+    let location = |v: &JsonViolation| (v.file.clone(), v.line);
+    diff.added.sort_by_key(location);
+    diff.removed.sort_by_key(location);
+    diff.moved.sort_by(|a, b| (&a.file, a.to_line).cmp(&(&b.file, b.to_line)));
+    diff
+}
+
+/// Render a diff as JSON.
+pub fn render_diff_json(diff: &ReportDiff) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(diff)?)
+}
+
+/// Render a diff for humans; colors follow the `colored` crate's settings.
+pub fn render_diff_pretty(diff: &ReportDiff) -> String {
+    let mut buf = String::new();
+
+    writeln!(buf).unwrap();
+    let delta = |delta: i32| match delta {
+        d if d > 0 => format!("+{}", d).red().to_string(),
+        d if d < 0 => d.to_string().green().to_string(),
+        _ => "±0".dimmed().to_string(),
+    };
+    writeln!(
+        buf,
+        "  Hollowness: {} → {} ({})",
+        diff.base_score, diff.head_score, delta(diff.score_delta)
+    )
+    .unwrap();
+    if let Some(ref sub_scores) = diff.sub_scores {
+        let change = |c: &SubScoreChange| format!("{} → {} ({})", c.base, c.head, delta(c.delta));
+        writeln!(
+            buf,
+            "  Structural: {}, Content: {}",
+            change(&sub_scores.structural),
+            change(&sub_scores.content)
+        )
+        .unwrap();
+    }
+    writeln!(
+        buf,
+        "  {} added, {} removed, {} moved",
+        diff.added.len(),
+        diff.removed.len(),
+        diff.moved.len()
+    )
+    .unwrap();
+
+    let location = |file: &Option<String>, line: usize| match (file.as_deref(), line) {
+        (None, _) | (Some(""), _) => "(project)".to_string(),
+        (Some(file), 0) => file.to_string(),
+        (Some(file), line) => format!("{}:{}", file, line),
+    };
+
+    for (title, entries, marker) in [
+        ("Added", &diff.added, "+".red()),
+        ("Removed", &diff.removed, "-".green()),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        writeln!(buf).unwrap();
+        writeln!(buf, "  {} ({}):", title.bold(), entries.len()).unwrap();
+        for v in entries {
+            writeln!(
+                buf,
+                "    {} {:<18}{}",
+                marker,
+                v.rule.dimmed(),
+                location(&v.file, v.line).blue()
+            )
+            .unwrap();
+            writeln!(buf, "        {}", v.message).unwrap();
+        }
+    }
+
+    if !diff.moved.is_empty() {
+        writeln!(buf).unwrap();
+        writeln!(buf, "  {} ({}):", "Moved".bold(), diff.moved.len()).unwrap();
+        for m in &diff.moved {
+            writeln!(
+                buf,
+                "    ~ {:<18}{} {}",
+                m.rule.dimmed(),
+                location(&m.file, m.from_line).blue(),
+                format!("→ line {}", m.to_line).dimmed()
+            )
+            .unwrap();
+        }
+    }
+    writeln!(buf).unwrap();
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule: &str, file: &str, line: usize, message: &str) -> JsonViolation {
+        JsonViolation {
+            rule: rule.to_string(),
+            severity: "error".to_string(),
+            file: Some(file.to_string()),
+            line,
+            message: message.to_string(),
+            explanation: None,
+            original_severity: None,
+            downgrade_reason: None,
+            fingerprint: None,
+            language_override: None,
+            marker: None,
+            effort: None,
+        }
+    }
+
+    fn report(score: i32, violations: Vec<JsonViolation>) -> JsonReport {
+        JsonReport {
+            version: "0.1.0".to_string(),
+            path: ".".to_string(),
+            scan_root: None,
+            workspace_root: None,
+            contract: String::new(),
+            score,
+            grade: "A".to_string(),
+            threshold: 25,
+            passed: true,
+            files_scanned: 1,
+            files_rejected: 0,
+            files_excluded_by_language: 0,
+            files_errored: 0,
+            files_generated: 0,
+            files_generated_modified: 0,
+            violations,
+            truncated: Default::default(),
+            new_violations: vec![],
+            new_truncated: Default::default(),
+            baseline_ref: None,
+            suppressed: vec![],
+            suppressed_count: 0,
+            waived: vec![],
+            waived_count: 0,
+            breakdown: vec![],
+            debt: None,
+            sub_scores: None,
+            environment: vec![],
+            contract_warnings: vec![],
+            stats: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_fallback_fingerprint_ignores_line_whitespace_and_root() {
+        let fingerprint = |v: &JsonViolation, root: &str| report_fingerprint(v, Path::new(root), false);
+        let a = violation("mock_data", "/ci/work/a.go", 3, "mock data found:  example.com");
+        let b = violation("mock_data", "/home/dev/app/a.go", 40, "mock data found: example.com");
+        assert_eq!(fingerprint(&a, "/ci/work"), fingerprint(&b, "/home/dev/app"));
+        assert_eq!(fingerprint(&a, "/ci/work").len(), 16);
+
+        let other_file = violation("mock_data", "/ci/work/b.go", 3, "mock data found: example.com");
+        assert_ne!(fingerprint(&a, "/ci/work"), fingerprint(&other_file, "/ci/work"));
+
+        // A fingerprint the report carries is used as is
+        let mut canonical = a;
+        canonical.fingerprint = Some("565fb4b86bc21b63".to_string());
+        assert_eq!(report_fingerprint(&canonical, Path::new("/ci/work"), true), "565fb4b86bc21b63");
+    }
+
+    #[test]
+    fn test_diff_added_removed_moved() {
+        let base = report(
+            20,
+            vec![
+                violation("forbidden_pattern", "main.go", 10, "forbidden pattern found: TODO"),
+                violation("forbidden_pattern", "main.go", 30, "forbidden pattern found: TODO"),
+                violation("mock_data", "main.go", 5, "mock data found: example.com"),
+            ],
+        );
+        let head = report(
+            28,
+            vec![
+                violation("forbidden_pattern", "main.go", 12, "forbidden pattern found: TODO"),
+                violation("forbidden_pattern", "main.go", 30, "forbidden pattern found: TODO"),
+                violation("stub_function", "util.go", 4, "function Run is a stub"),
+            ],
+        );
+
+        let diff = diff_reports(base, head);
+        assert_eq!(diff.score_delta, 8);
+        assert!(diff.has_added());
+
+        let added: Vec<_> = diff.added.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(added, vec!["stub_function"]);
+        let removed: Vec<_> = diff.removed.iter().map(|v| v.rule.as_str()).collect();
+        assert_eq!(removed, vec!["mock_data"]);
+
+        // The unchanged line 30 pairs with itself, leaving 10 → 12
+        assert_eq!(diff.moved.len(), 1);
+        assert_eq!((diff.moved[0].from_line, diff.moved[0].to_line), (10, 12));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render_diff_json(&diff).unwrap()).unwrap();
+        assert_eq!(json["score_delta"], 8);
+        assert_eq!(json["added"][0]["file"], "util.go");
+        assert_eq!(json["added"][0]["fingerprint"], diff.added[0].fingerprint.as_deref().unwrap());
+        // Reports without sub-scores compare on the total alone
+        assert!(json.get("sub_scores").is_none());
+    }
+
+    #[test]
+    fn test_diff_matches_canonical_fingerprints() {
+        let with_fingerprint = |root: &str, line: usize, fingerprint: &str| {
+            let mut v = violation("forbidden_pattern", &format!("{}/main.go", root), line, "forbidden pattern found: TODO");
+            v.fingerprint = Some(fingerprint.to_string());
+            v
+        };
+        let mut base = report(10, vec![with_fingerprint("/ci/work", 10, "aaaa"), with_fingerprint("/ci/work", 20, "bbbb")]);
+        base.scan_root = Some("/ci/work".to_string());
+        let mut head = report(10, vec![with_fingerprint("/home/dev/app", 14, "aaaa"), with_fingerprint("/home/dev/app", 20, "cccc")]);
+        head.scan_root = Some("/home/dev/app".to_string());
+
+        let diff = diff_reports(base, head);
+        assert_eq!(diff.moved.len(), 1);
+        assert_eq!((diff.moved[0].fingerprint.as_str(), diff.moved[0].to_line), ("aaaa", 14));
+        assert_eq!(diff.removed[0].fingerprint.as_deref(), Some("bbbb"));
+        assert_eq!(diff.added[0].fingerprint.as_deref(), Some("cccc"));
+    }
+
+    #[test]
+    fn test_diff_sub_scores() {
+        use crate::score::SubScores;
+
+        let with_sub_scores = |score: i32, structural: i32, content: i32| {
+            let mut report = report(score, vec![]);
+            let mut sub_scores = SubScores::default();
+            sub_scores.structural.score = structural;
+            sub_scores.content.score = content;
+            report.sub_scores = Some(sub_scores);
+            report
+        };
+
+        let diff = diff_reports(with_sub_scores(20, 15, 5), with_sub_scores(30, 15, 15));
+        let sub_scores = diff.sub_scores.as_ref().unwrap();
+        assert_eq!((sub_scores.structural.delta, sub_scores.content.delta), (0, 10));
+        assert!(render_diff_pretty(&diff).contains("Content: 5 → 15 (+10)"));
+
+        let diff = diff_reports(report(20, vec![]), with_sub_scores(30, 15, 15));
+        assert!(diff.sub_scores.is_none());
+    }
+
+    #[test]
+    fn test_new_violations_against_baseline() {
+        use crate::detect::ViolationRule;
+
+        let baseline = report(
+            10,
+            vec![
+                violation("forbidden_pattern", "main.go", 10, "forbidden pattern found: TODO"),
+                violation("mock_data", "main.go", 5, "mock data found: example.com"),
+            ],
+        );
+        let mut current = DetectionResult::new();
+        // Moved, still grandfathered
+        current.add_violation(
+            Violation::new(ViolationRule::ForbiddenPattern, "forbidden pattern found: TODO").at("main.go", 14),
+        );
+        // A second copy of a baselined violation is new
+        current.add_violation(
+            Violation::new(ViolationRule::ForbiddenPattern, "forbidden pattern found: TODO").at("main.go", 20),
+        );
+        current.add_violation(Violation::new(ViolationRule::StubFunction, "function Run is a stub").at("util.go", 4));
+
+        let new = new_violations(&baseline, &current);
+        let lines: Vec<_> = new.iter().map(|v| (v.location(), v.line)).collect();
+        assert_eq!(lines, vec![("main.go", 20), ("util.go", 4)]);
+    }
+
+    #[test]
+    fn test_load_baseline_from_url() {
+        use std::io::{BufRead, BufReader, Write};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let baseline = report(10, vec![violation("mock_data", "main.go", 5, "mock data found: example.com")]);
+        let body = serde_json::to_string(&baseline).unwrap();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/baselines/main.json", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                    line.clear();
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", body.len(), body);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        // Fetched once per run
+        for _ in 0..2 {
+            let loaded = load_baseline(&url).unwrap();
+            assert_eq!(loaded.violations.len(), 1);
+            assert_eq!(loaded.violations[0].message, "mock data found: example.com");
+        }
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        let error = |location: &str| load_baseline(location).err().expect("should fail").to_string();
+        let err = error("http://artifacts.example/main.json");
+        assert!(err.contains("baselines must be fetched over https"), "{}", err);
+        let err = error("s3://artifacts/main.json");
+        assert!(err.contains("s3:// URLs are not supported"), "{}", err);
+        let err = error("missing-baseline.json");
+        assert!(err.starts_with("cannot read report"), "{}", err);
+    }
+}
@@ -0,0 +1,436 @@
+//! File discovery: the files a scan analyzes, and how each is routed.
+//!
+//! `hollowcheck lint` walks the scan root, skips hidden, test, example, and
+//! benchmark directories and the contract's vendored dependency and build
+//! directories, applies the contract's `excluded_paths`, language filter,
+//! and `language_overrides`, and keeps files an analyzer or the container or
+//! schema checks handle. [`FileDiscovery`] is that walk, so tools built on
+//! hollowcheck (SBOM generators, ownership mappers, metrics collectors) see
+//! exactly the file set a scan would.
+//!
+//! The [`Runner`](crate::detect::Runner) also accepts file lists built
+//! elsewhere; it routes them with [`FileDiscovery::classify`], which applies
+//! the checks that hold for any list (vendored directories, the language
+//! filter) without the walk's filters.
+//!
+//! Symbolic links are followed. Generated files are recognized later, from
+//! the generator stamp the analyzers read, so discovery does not classify
+//! them.
+//!
+//! ```no_run
+//! use hollowcheck::discovery::{FileClass, FileDiscovery};
+//! use hollowcheck::Contract;
+//!
+//! hollowcheck::init();
+//! let contract = Contract::default_contract();
+//! let files = FileDiscovery::new("/path/to/repo", &contract)
+//!     .exclude_patterns(&["**/migrations/**".to_string()])
+//!     .discover()?;
+//! for file in files.iter().filter(|f| f.class == FileClass::Source) {
+//!     println!("{} {} {} bytes", file.path.display(), file.language.unwrap_or("-"), file.size);
+//! }
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+use crate::analysis::{analysis_extension, get_analyzer, is_compose_file, is_dockerfile, is_schema_file};
+use crate::contract::Contract;
+use crate::detect::{apply_language_overrides, is_test_file_name, is_vendored, resolve_language_override};
+
+/// Extensions of files the walk keeps; other files need a language override.
+const SUPPORTED_EXTENSIONS: &[&str] = &[
+    "go", "rs", "py", "js", "ts", "jsx", "tsx", "java", "kt", "c", "cpp", "h", "hpp",
+];
+
+/// Default directory patterns to exclude from scanning.
+///
+/// Dependency and build directories are configured separately, per
+/// ecosystem, by the contract's `default_excludes`.
+const DEFAULT_EXCLUDED_DIRS: &[&str] = &[
+    // Test directories
+    "test",
+    "tests",
+    "__tests__",
+    "__test__",
+    "testdata",
+    "test_data",
+    "test-data",
+    // Example directories
+    "example",
+    "examples",
+    // Benchmark directories
+    "bench",
+    "benches",
+    "benchmark",
+    "benchmarks",
+    // Documentation
+    "doc",
+    "docs",
+    "documentation",
+];
+
+/// How a discovered file is handled by a scan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileClass {
+    /// Source code, checked by every rule.
+    Source,
+    /// A Dockerfile or compose file, checked only by the container checks.
+    Container,
+    /// A Protobuf or GraphQL schema, checked only by the schema checks.
+    Schema,
+    /// Below a vendored dependency or build directory; not analyzed.
+    Vendored,
+    /// In a language the contract's language filter excludes; not analyzed.
+    LanguageExcluded,
+}
+
+impl FileClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileClass::Source => "source",
+            FileClass::Container => "container",
+            FileClass::Schema => "schema",
+            FileClass::Vendored => "vendored",
+            FileClass::LanguageExcluded => "language_excluded",
+        }
+    }
+
+    /// Whether a scan analyzes files of this class.
+    pub fn is_analyzed(&self) -> bool {
+        matches!(self, FileClass::Source | FileClass::Container | FileClass::Schema)
+    }
+}
+
+/// A file found by [`FileDiscovery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiscoveredFile {
+    /// Path as walked, below the discovery root.
+    pub path: PathBuf,
+    /// Id of the analyzer that handles the file (`go`, `rust`), after
+    /// language overrides; `None` when no analyzer does.
+    pub language: Option<&'static str>,
+    /// How a scan handles the file.
+    pub class: FileClass,
+    /// Size in bytes, following symbolic links; 0 when unreadable.
+    pub size: u64,
+}
+
+/// Finds and classifies the files a scan analyzes.
+///
+/// Configured from the contract (`excluded_paths`, `default_excludes`,
+/// `languages`, `language_overrides`, `include_test_files`) and by the
+/// builder methods, which mirror `lint`'s `--no-default-excludes`,
+/// `--exclude`, and `--include` flags.
+pub struct FileDiscovery<'a> {
+    root: PathBuf,
+    contract: &'a Contract,
+    default_excludes: bool,
+    exclude_patterns: Vec<String>,
+    include_patterns: Vec<String>,
+}
+
+impl<'a> FileDiscovery<'a> {
+    /// Create a discovery of the files below `root`, or of `root` itself
+    /// when it is a file.
+    pub fn new<P: AsRef<Path>>(root: P, contract: &'a Contract) -> Self {
+        Self {
+            root: root.as_ref().to_path_buf(),
+            contract,
+            default_excludes: true,
+            exclude_patterns: Vec::new(),
+            include_patterns: Vec::new(),
+        }
+    }
+
+    /// Set whether the contract's vendored dependency and build directories
+    /// are skipped (default: true).
+    pub fn default_excludes(mut self, enabled: bool) -> Self {
+        self.default_excludes = enabled;
+        self
+    }
+
+    /// Skip files matching these globs, in addition to `excluded_paths`.
+    pub fn exclude_patterns(mut self, patterns: &[String]) -> Self {
+        self.exclude_patterns = patterns.to_vec();
+        self
+    }
+
+    /// Keep files matching these globs even if they are test files or
+    /// excluded by a pattern. The language filter still applies.
+    pub fn include_patterns(mut self, patterns: &[String]) -> Self {
+        self.include_patterns = patterns.to_vec();
+        self
+    }
+
+    /// Vendored directory names skipped, empty when default excludes are off.
+    fn vendored_dirs(&self) -> Vec<&'static str> {
+        let config = self.contract.default_excludes.clone().unwrap_or_default();
+        if self.default_excludes && config.is_enabled() {
+            config.dirs()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Walk the root and classify the files a scan would consider.
+    ///
+    /// Files in excluded languages are returned as
+    /// [`FileClass::LanguageExcluded`] so callers can count them; skipped
+    /// directories are not descended into. A root that is a file is
+    /// classified on its own, without the walk's filters. Language
+    /// overrides found are recorded for later analyzer lookups.
+    pub fn discover(&self) -> anyhow::Result<Vec<DiscoveredFile>> {
+        if !self.root.is_dir() {
+            return Ok(self.classify(std::slice::from_ref(&self.root)));
+        }
+
+        let contract = self.contract;
+        let include_test_files = contract.should_include_test_files();
+        let vendored_dirs = self.vendored_dirs();
+        let matchers = |patterns: &[String]| -> Vec<_> {
+            patterns
+                .iter()
+                .filter_map(|p| globset::Glob::new(p).ok().map(|g| g.compile_matcher()))
+                .collect()
+        };
+        let extra_matchers = matchers(&self.exclude_patterns);
+        let include_matchers = matchers(&self.include_patterns);
+
+        let mut files = Vec::new();
+        for entry in WalkDir::new(&self.root)
+            .follow_links(true)
+            .into_iter()
+            .filter_entry(|e| {
+                let name = e.file_name().to_string_lossy();
+                // Skip hidden directories
+                if e.file_type().is_dir() && name.starts_with('.') {
+                    return false;
+                }
+                // Skip default excluded directories
+                if e.file_type().is_dir() && DEFAULT_EXCLUDED_DIRS.contains(&name.as_ref()) {
+                    return false;
+                }
+                // Skip dependency and build directories below the root
+                if e.depth() > 0 && e.file_type().is_dir() && vendored_dirs.contains(&name.as_ref()) {
+                    return false;
+                }
+                true
+            })
+        {
+            let entry = entry?;
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let path = entry.path();
+            let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+            // A language override routes files with any extension to an analyzer
+            let overridden = match resolve_language_override(&self.root, path, contract) {
+                Some(language) => {
+                    crate::analysis::set_language_override(path, language);
+                    true
+                }
+                None => false,
+            };
+
+            if !(overridden
+                || SUPPORTED_EXTENSIONS.contains(&ext)
+                || is_dockerfile(path)
+                || is_compose_file(path)
+                || is_schema_file(path))
+            {
+                continue;
+            }
+
+            // Language filter applies even to force-included files
+            if !contract.is_path_language_excluded(path) {
+                let path_str = path.to_string_lossy();
+                let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+
+                // Check if file matches include patterns (always include these)
+                let force_include = include_matchers.iter().any(|m| m.is_match(&*path_str));
+
+                if !force_include {
+                    // Skip test files unless explicitly included
+                    if !include_test_files && is_test_file_name(filename) {
+                        continue;
+                    }
+
+                    // Skip files matching excluded_paths patterns from contract
+                    if contract.is_path_excluded(path) {
+                        continue;
+                    }
+
+                    // Skip files matching extra exclude patterns
+                    if extra_matchers.iter().any(|m| m.is_match(&*path_str)) {
+                        continue;
+                    }
+                }
+            }
+
+            files.push(self.entry(path.to_path_buf(), &vendored_dirs));
+        }
+
+        Ok(files)
+    }
+
+    /// Classify a file list built elsewhere, in order.
+    ///
+    /// Only the checks that hold for any list apply: vendored directories
+    /// below the root and the language filter. Test files, `excluded_paths`,
+    /// and the exclude and include patterns are left to whoever built the
+    /// list. Language overrides are recorded as in [`FileDiscovery::discover`].
+    pub fn classify(&self, files: &[PathBuf]) -> Vec<DiscoveredFile> {
+        apply_language_overrides(&self.root, files, self.contract);
+        let vendored_dirs = self.vendored_dirs();
+        files
+            .iter()
+            .map(|file| self.entry(file.clone(), &vendored_dirs))
+            .collect()
+    }
+
+    fn entry(&self, path: PathBuf, vendored_dirs: &[&str]) -> DiscoveredFile {
+        let class = if is_vendored(&self.root, &path, vendored_dirs) {
+            FileClass::Vendored
+        } else if self.contract.is_path_language_excluded(&path) {
+            FileClass::LanguageExcluded
+        } else if is_dockerfile(&path) || is_compose_file(&path) {
+            FileClass::Container
+        } else if is_schema_file(&path) {
+            FileClass::Schema
+        } else {
+            FileClass::Source
+        };
+        let language = get_analyzer(analysis_extension(&path)).map(|a| a.language_id());
+        let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        DiscoveredFile { path, language, class, size }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::Contract;
+    use crate::detect::Runner;
+    use std::sync::{Arc, Mutex};
+
+    fn write(root: &Path, files: &[(&str, &str)]) {
+        for (path, content) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+    }
+
+    fn classes(root: &Path, files: &[DiscoveredFile]) -> Vec<(String, &'static str)> {
+        let mut classes: Vec<_> = files
+            .iter()
+            .map(|f| (f.path.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/"), f.class.as_str()))
+            .collect();
+        classes.sort();
+        classes
+    }
+
+    #[test]
+    fn test_discover_classifies_and_filters() {
+        crate::init();
+        let temp = tempfile::Builder::new().prefix("discovery").tempdir().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            &[
+                ("main.go", "package main\n\nfunc main() {}\n"),
+                ("main_test.go", "package main\n"),
+                ("web/app.ts", "export const a = 1;\n"),
+                ("api/service.proto", "syntax = \"proto3\";\n"),
+                ("Dockerfile", "FROM alpine:3.19\n"),
+                ("README.md", "# readme\n"),
+                ("node_modules/left-pad/index.js", "module.exports = 1;\n"),
+                ("tests/helper.go", "package tests\n"),
+                (".git/hooks/pre-commit.py", "print()\n"),
+                ("gen/models.go", "package gen\n"),
+            ],
+        );
+        let contract: Contract = serde_yaml::from_str("excluded_paths: [\"**/gen/**\"]\nlanguages:\n  include: [go]\n").unwrap();
+
+        let files = FileDiscovery::new(root, &contract).discover().unwrap();
+        assert_eq!(
+            classes(root, &files),
+            vec![
+                ("Dockerfile".to_string(), "container"),
+                ("api/service.proto".to_string(), "schema"),
+                ("main.go".to_string(), "source"),
+                ("web/app.ts".to_string(), "language_excluded"),
+            ]
+        );
+        let main = files.iter().find(|f| f.path.ends_with("main.go")).unwrap();
+        assert_eq!(main.language, Some("go"));
+        assert_eq!(main.size, 29);
+
+        // Programmatic options match the CLI flags
+        let files = FileDiscovery::new(root, &contract)
+            .default_excludes(false)
+            .include_patterns(&["**/main_test.go".to_string()])
+            .exclude_patterns(&["**/Dockerfile".to_string()])
+            .discover()
+            .unwrap();
+        let found = classes(root, &files);
+        assert!(found.contains(&("main_test.go".to_string(), "source")), "{:?}", found);
+        assert!(found.contains(&("node_modules/left-pad/index.js".to_string(), "language_excluded")), "{:?}", found);
+        assert!(!found.iter().any(|(path, _)| path == "Dockerfile"), "{:?}", found);
+    }
+
+    #[test]
+    fn test_runner_analyzes_the_discovered_set() {
+        crate::init();
+        let temp = tempfile::Builder::new().prefix("discovery").tempdir().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            &[
+                ("cmd/server/main.go", "package main\n\nfunc main() { serve() }\n"),
+                ("internal/store/store.go", "package store\n\nfunc Get(k string) string { return k }\n"),
+                ("scripts/build.py", "def build():\n    return 1\n"),
+                ("web/src/app.ts", "export function app(): number { return 1; }\n"),
+                ("web/node_modules/react/index.js", "module.exports = 1;\n"),
+                ("vendor/github.com/pkg/errors/errors.go", "package errors\n"),
+                ("Dockerfile", "FROM alpine:3.19\n"),
+                ("schema/api.graphql", "type Query { a: Int }\n"),
+                ("internal/store/store_test.go", "package store\n"),
+            ],
+        );
+        let contract: Contract = serde_yaml::from_str("languages:\n  include: [go, python, typescript]\nhollow_todos:\n  enabled: false\n").unwrap();
+
+        for default_excludes in [true, false] {
+            let discovered = FileDiscovery::new(root, &contract)
+                .default_excludes(default_excludes)
+                .discover()
+                .unwrap();
+            let files: Vec<PathBuf> = discovered.iter().map(|f| f.path.clone()).collect();
+
+            // The runner routes the same list to the per-file rules
+            let analyzed = Arc::new(Mutex::new(Vec::new()));
+            let sink = analyzed.clone();
+            Runner::new(root)
+                .skip_registry_check(true)
+                .default_excludes(default_excludes)
+                .with_file_callback(move |file, _, _, _| sink.lock().unwrap().push(file.to_path_buf()))
+                .run(&files, &contract)
+                .unwrap();
+            let mut analyzed = analyzed.lock().unwrap().clone();
+            analyzed.sort();
+
+            let mut expected: Vec<PathBuf> = discovered
+                .iter()
+                .filter(|f| f.class == FileClass::Source)
+                .map(|f| f.path.clone())
+                .collect();
+            expected.sort();
+            assert!(!expected.is_empty());
+            assert_eq!(analyzed, expected, "default_excludes: {}", default_excludes);
+        }
+    }
+}
@@ -0,0 +1,496 @@
+//! Self-check of the build and environment for `hollowcheck doctor`.
+//!
+//! When a scan behaves oddly the first question is whether this build can
+//! parse the user's code at all. The doctor runs a small fixture through
+//! every analyzer, each written in recent syntax for its language (Go
+//! generics, Python 3.12 type parameters, TypeScript decorators, Java
+//! records and sealed interfaces, ...), and checks that the expected
+//! declarations come out without parse errors. A grammar that loads but is
+//! too old for the syntax, or that extracts nothing, fails here rather than
+//! silently producing an empty scan.
+//!
+//! It also reports what the rest of a scan depends on: the runtimes queried
+//! for stdlib module lists, whether the disk caches are writable, and,
+//! unless offline, whether each enabled package registry answers.
+
+use std::fmt::Write as FmtWrite;
+use std::path::Path;
+use std::time::Instant;
+
+use colored::*;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::get_analyzer;
+use crate::contract::DependencyVerificationConfig;
+use crate::detect::{runtime_version, stdlib_cache_dir, StdlibLanguage};
+use crate::registry::{RegistryClient, RegistryType};
+
+/// A fixture run through one analyzer.
+struct GrammarFixture {
+    language: &'static str,
+    extension: &'static str,
+    /// Grammar crate, as named in Cargo.toml
+    grammar: &'static str,
+    source: &'static str,
+    /// Qualified names of declarations the analyzer must extract
+    expected: &'static [&'static str],
+}
+
+const FIXTURES: &[GrammarFixture] = &[
+    GrammarFixture {
+        language: "c",
+        extension: "c",
+        grammar: "tree-sitter-c",
+        source: "#include <stddef.h>\n\n_Static_assert(sizeof(int) >= 4, \"int too small\");\n\n#define abs_of(x) _Generic((x), int: abs, double: fabs)(x)\n\nstatic inline size_t clamp(size_t v, size_t hi) {\n    return v > hi ? hi : v;\n}\n",
+        expected: &["clamp"],
+    },
+    GrammarFixture {
+        language: "cpp",
+        extension: "cpp",
+        grammar: "tree-sitter-cpp",
+        source: "#include <concepts>\n\ntemplate <typename T>\nconcept Addable = requires(T a, T b) { a + b; };\n\ntemplate <Addable T>\nauto sum(T a, T b) -> T {\n    return a + b;\n}\n\nclass Widget {\npublic:\n    [[nodiscard]] int size() const { return 0; }\n};\n",
+        expected: &["sum", "Widget"],
+    },
+    GrammarFixture {
+        language: "go",
+        extension: "go",
+        grammar: "tree-sitter-go",
+        source: "package demo\n\ntype Stack[T any] struct {\n\titems []T\n}\n\nfunc Map[T, U any](xs []T, f func(T) U) []U {\n\tout := make([]U, 0, len(xs))\n\tfor _, x := range xs {\n\t\tout = append(out, f(x))\n\t}\n\treturn out\n}\n",
+        expected: &["Stack", "Map"],
+    },
+    GrammarFixture {
+        language: "java",
+        extension: "java",
+        grammar: "tree-sitter-java",
+        source: "package demo;\n\npublic sealed interface Shape permits Circle {}\n\npublic record Circle(double radius) implements Shape {}\n\nclass Areas {\n    static double area(Shape s) {\n        return switch (s) {\n            case Circle c -> Math.PI * c.radius() * c.radius();\n        };\n    }\n}\n",
+        expected: &["Shape", "Areas", "area"],
+    },
+    GrammarFixture {
+        language: "javascript",
+        extension: "js",
+        grammar: "tree-sitter-javascript",
+        source: "export class Counter {\n  #count = 0;\n  static #instances = 0;\n  increment() {\n    this.#count ??= 0;\n    return this.#count++;\n  }\n}\n\nexport async function load(url) {\n  const res = await fetch(url);\n  return res?.body ?? null;\n}\n",
+        expected: &["Counter", "increment", "load"],
+    },
+    GrammarFixture {
+        language: "python",
+        extension: "py",
+        grammar: "tree-sitter-python",
+        source: "type Pair[T] = tuple[T, T]\n\nclass Box[T]:\n    def get(self) -> T:\n        return self.value\n\ndef first[T](items: list[T]) -> T:\n    match items:\n        case [head, *_]:\n            return head\n    raise IndexError\n",
+        expected: &["Box", "first"],
+    },
+    GrammarFixture {
+        language: "rust",
+        extension: "rs",
+        grammar: "tree-sitter-rust",
+        source: "trait Store {\n    async fn get(&self, key: &str) -> Option<String>;\n}\n\nstruct Memory;\n\nimpl Store for Memory {\n    async fn get(&self, key: &str) -> Option<String> {\n        let Some(v) = key.strip_prefix('k') else { return None };\n        Some(v.to_string())\n    }\n}\n",
+        expected: &["Store", "Memory.get"],
+    },
+    GrammarFixture {
+        language: "scala",
+        extension: "scala",
+        grammar: "tree-sitter-scala",
+        source: "enum Color:\n  case Red, Green\n\ntrait Greeter:\n  def greet(name: String): String\n\nobject Main:\n  def run(c: Color): String =\n    c match\n      case Color.Red => \"red\"\n      case Color.Green => \"green\"\n",
+        expected: &["Greeter", "Main", "run"],
+    },
+    GrammarFixture {
+        language: "swift",
+        extension: "swift",
+        grammar: "tree-sitter-swift",
+        source: "actor Counter {\n    var value = 0\n    func increment() async -> Int {\n        value += 1\n        return value\n    }\n}\n\nfunc load() async throws -> Data {\n    let (data, _) = try await URLSession.shared.data(from: url)\n    return data\n}\n",
+        expected: &["Counter", "load"],
+    },
+    GrammarFixture {
+        language: "typescript",
+        extension: "ts",
+        grammar: "tree-sitter-typescript",
+        source: "function sealed(target: unknown) {}\n\n@sealed\nexport class Service {\n  accessor count = 0;\n  @log\n  run(): void {}\n}\n\nconst config = { port: 8080 } satisfies Record<string, number>;\n",
+        expected: &["Service", "run"],
+    },
+];
+
+/// Registries probed, in report order.
+const REGISTRIES: [RegistryType; 4] = [
+    RegistryType::PyPI,
+    RegistryType::Npm,
+    RegistryType::Crates,
+    RegistryType::Go,
+];
+
+/// Result of one analyzer's self-test.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AnalyzerCheck {
+    pub language: String,
+    pub grammar: String,
+    /// Version requirement of the grammar crate in this build's Cargo.toml
+    pub grammar_version: Option<String>,
+    /// Tree-sitter ABI version the grammar was generated with
+    pub abi: Option<usize>,
+    /// Expected declarations the analyzer did not extract
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub missing: Vec<String>,
+    /// Whether the fixture parsed with ERROR or MISSING nodes
+    pub parse_errors: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub passed: bool,
+}
+
+/// A runtime queried for stdlib module lists.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RuntimeCheck {
+    pub runtime: String,
+    /// Version output, or None when the runtime was not found
+    pub version: Option<String>,
+}
+
+/// Whether a disk cache directory is writable.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CacheCheck {
+    pub cache: String,
+    pub path: Option<String>,
+    pub writable: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of probing a package registry.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RegistryProbe {
+    pub registry: String,
+    pub url: String,
+    pub enabled: bool,
+    /// HTTP status of the HEAD request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elapsed_ms: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Everything `hollowcheck doctor` checked.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DoctorReport {
+    pub version: String,
+    pub features: Vec<String>,
+    pub analyzers: Vec<AnalyzerCheck>,
+    pub runtimes: Vec<RuntimeCheck>,
+    pub caches: Vec<CacheCheck>,
+    /// Empty when run offline
+    pub registries: Vec<RegistryProbe>,
+    pub offline: bool,
+}
+
+impl DoctorReport {
+    /// Whether every analyzer passed its self-test. Missing runtimes,
+    /// caches, and registries are reported but do not fail the check.
+    pub fn passed(&self) -> bool {
+        self.analyzers.iter().all(|a| a.passed)
+    }
+}
+
+/// Cargo features enabled in this build.
+pub fn enabled_features() -> Vec<String> {
+    let mut features = Vec::new();
+    if cfg!(feature = "tree-sitter") {
+        features.push("tree-sitter".to_string());
+    }
+    features
+}
+
+/// Version requirement of a dependency in this build's Cargo.toml.
+fn dependency_version(manifest: &str, name: &str) -> Option<String> {
+    let prefix = format!("{} = ", name);
+    let line = manifest.lines().find(|l| l.starts_with(&prefix))?;
+    let value = &line[prefix.len()..];
+    let start = value.find('"')? + 1;
+    let end = start + value[start..].find('"')?;
+    Some(value[start..end].to_string())
+}
+
+/// Run every analyzer's fixture and check the declarations it extracts.
+pub fn check_analyzers() -> Vec<AnalyzerCheck> {
+    const MANIFEST: &str = include_str!("../Cargo.toml");
+
+    FIXTURES
+        .iter()
+        .map(|fixture| {
+            let mut check = AnalyzerCheck {
+                language: fixture.language.to_string(),
+                grammar: fixture.grammar.to_string(),
+                grammar_version: dependency_version(MANIFEST, fixture.grammar),
+                abi: None,
+                missing: Vec::new(),
+                parse_errors: false,
+                error: None,
+                passed: false,
+            };
+            let Some(analyzer) = get_analyzer(fixture.extension) else {
+                check.error = Some("no analyzer registered".to_string());
+                return check;
+            };
+            let path = format!("doctor.{}", fixture.extension);
+            let facts = analyzer
+                .parse(Path::new(&path), fixture.source.as_bytes())
+                .and_then(|parsed| {
+                    check.abi = Some(parsed.tree.language().version());
+                    check.parse_errors = parsed.tree.root_node().has_error();
+                    analyzer.extract_facts(&parsed)
+                });
+            match facts {
+                Ok(facts) => {
+                    let names: Vec<String> = facts.declarations.iter().map(|d| d.qualified_name()).collect();
+                    check.missing = fixture
+                        .expected
+                        .iter()
+                        .filter(|name| !names.iter().any(|n| n == *name))
+                        .map(|name| name.to_string())
+                        .collect();
+                }
+                Err(e) => check.error = Some(e.to_string()),
+            }
+            check.passed = check.error.is_none() && !check.parse_errors && check.missing.is_empty();
+            check
+        })
+        .collect()
+}
+
+/// Find the runtimes queried for stdlib module lists.
+pub fn check_runtimes() -> Vec<RuntimeCheck> {
+    [
+        ("python", StdlibLanguage::Python),
+        ("node", StdlibLanguage::JavaScript),
+        ("go", StdlibLanguage::Go),
+    ]
+    .into_iter()
+    .map(|(runtime, lang)| RuntimeCheck {
+        runtime: runtime.to_string(),
+        version: runtime_version(lang),
+    })
+    .collect()
+}
+
+/// Check that a cache directory can be created and written to.
+fn check_cache(cache: &str, dir: Option<&Path>) -> CacheCheck {
+    let mut check = CacheCheck {
+        cache: cache.to_string(),
+        path: dir.map(|d| d.to_string_lossy().to_string()),
+        writable: false,
+        error: None,
+    };
+    let Some(dir) = dir else {
+        check.error = Some("no cache directory for this user".to_string());
+        return check;
+    };
+    let probe = dir.join(format!(".doctor-{}", std::process::id()));
+    let written = std::fs::create_dir_all(dir)
+        .and_then(|_| std::fs::write(&probe, b"ok"))
+        .and_then(|_| std::fs::remove_file(&probe));
+    match written {
+        Ok(()) => check.writable = true,
+        Err(e) => check.error = Some(e.to_string()),
+    }
+    check
+}
+
+/// Check the stdlib and registry caches.
+pub fn check_caches(client: &RegistryClient) -> Vec<CacheCheck> {
+    vec![
+        check_cache("stdlib", Some(&stdlib_cache_dir())),
+        check_cache("registry", client.cache_dir()),
+    ]
+}
+
+/// Probe each registry's endpoint with a HEAD request; disabled registries
+/// are listed without a request.
+pub fn probe_registries(client: &RegistryClient) -> anyhow::Result<Vec<RegistryProbe>> {
+    let runtime = tokio::runtime::Runtime::new()?;
+    Ok(runtime.block_on(async {
+        let probes = REGISTRIES.iter().map(|registry| async move {
+            let mut probe = RegistryProbe {
+                registry: registry.as_str().to_string(),
+                url: registry.endpoint().to_string(),
+                enabled: client.is_enabled(*registry),
+                status: None,
+                elapsed_ms: None,
+                error: None,
+            };
+            if probe.enabled {
+                let start = Instant::now();
+                match client.probe(*registry).await {
+                    Ok(status) => probe.status = Some(status),
+                    Err(e) => probe.error = Some(e.to_string()),
+                }
+                probe.elapsed_ms = Some(start.elapsed().as_millis() as u64);
+            }
+            probe
+        });
+        futures::future::join_all(probes).await
+    }))
+}
+
+/// Run every check. Registries are only probed when not `offline`.
+pub fn run_doctor(config: DependencyVerificationConfig, offline: bool) -> anyhow::Result<DoctorReport> {
+    crate::init();
+    let client = RegistryClient::new(config);
+    Ok(DoctorReport {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        features: enabled_features(),
+        analyzers: check_analyzers(),
+        runtimes: check_runtimes(),
+        caches: check_caches(&client),
+        registries: if offline { Vec::new() } else { probe_registries(&client)? },
+        offline,
+    })
+}
+
+/// Render a doctor report as JSON.
+pub fn render_doctor_json(report: &DoctorReport) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(report)?)
+}
+
+/// Render a doctor report as tables; colors follow the `colored` crate's settings.
+pub fn render_doctor_pretty(report: &DoctorReport) -> String {
+    let mut buf = String::new();
+    let mark = |ok: bool| if ok { "✓".green() } else { "✗".red() };
+
+    writeln!(buf).unwrap();
+    write!(buf, "  {}", "hollowcheck".cyan().bold()).unwrap();
+    writeln!(buf, " v{}", report.version).unwrap();
+    let features = if report.features.is_empty() {
+        "(none)".to_string()
+    } else {
+        report.features.join(", ")
+    };
+    writeln!(buf, "  {}{}", "Features: ".dimmed(), features).unwrap();
+
+    writeln!(buf).unwrap();
+    writeln!(buf, "  {}", "Analyzers:".bold()).unwrap();
+    for a in &report.analyzers {
+        let grammar = format!("{} {}", a.grammar, a.grammar_version.as_deref().unwrap_or("?"));
+        let abi = a.abi.map_or("-".to_string(), |abi| format!("ABI {}", abi));
+        let problem = if let Some(e) = &a.error {
+            e.clone()
+        } else if a.parse_errors {
+            "fixture has parse errors".to_string()
+        } else if !a.missing.is_empty() {
+            format!("missing {}", a.missing.join(", "))
+        } else {
+            String::new()
+        };
+        writeln!(
+            buf,
+            "    {} {:<12}{:<30}{:<8}{}",
+            mark(a.passed),
+            a.language,
+            grammar,
+            abi,
+            problem.red()
+        )
+        .unwrap();
+    }
+
+    writeln!(buf).unwrap();
+    writeln!(buf, "  {}", "Runtimes (stdlib queries):".bold()).unwrap();
+    for r in &report.runtimes {
+        let version = r.version.as_deref().map_or("not found, using built-in lists".dimmed(), |v| v.normal());
+        writeln!(buf, "    {} {:<12}{}", mark(r.version.is_some()), r.runtime, version).unwrap();
+    }
+
+    writeln!(buf).unwrap();
+    writeln!(buf, "  {}", "Caches:".bold()).unwrap();
+    for c in &report.caches {
+        let detail = match &c.error {
+            Some(e) => format!("{} ({})", c.path.as_deref().unwrap_or("-"), e).red(),
+            None => c.path.as_deref().unwrap_or("-").normal(),
+        };
+        writeln!(buf, "    {} {:<12}{}", mark(c.writable), c.cache, detail).unwrap();
+    }
+
+    writeln!(buf).unwrap();
+    writeln!(buf, "  {}", "Registries:".bold()).unwrap();
+    if report.offline {
+        writeln!(buf, "    {}", "(offline, not probed)".dimmed()).unwrap();
+    }
+    for r in &report.registries {
+        let (ok, detail) = match (r.enabled, r.status, &r.error) {
+            (false, _, _) => (true, "disabled".dimmed()),
+            (_, Some(status), _) => (
+                true,
+                format!("HTTP {} ({} ms)", status, r.elapsed_ms.unwrap_or(0)).normal(),
+            ),
+            (_, _, error) => (false, error.as_deref().unwrap_or("no response").red()),
+        };
+        writeln!(buf, "    {} {:<12}{:<40}{}", mark(ok), r.registry, r.url, detail).unwrap();
+    }
+
+    writeln!(buf).unwrap();
+    if report.passed() {
+        writeln!(buf, "  {}", "All analyzers passed".green()).unwrap();
+    } else {
+        let failed = report.analyzers.iter().filter(|a| !a.passed).count();
+        writeln!(buf, "  {}", format!("{} analyzer(s) failed", failed).red()).unwrap();
+    }
+    writeln!(buf).unwrap();
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_analyzer_passes_its_fixture() {
+        crate::init();
+        let checks = check_analyzers();
+        assert_eq!(checks.len(), FIXTURES.len());
+        for check in &checks {
+            assert!(check.passed, "{:?}", check);
+            assert!(check.grammar_version.is_some(), "{:?}", check);
+            assert!(check.abi.is_some_and(|abi| abi >= 13), "{:?}", check);
+        }
+    }
+
+    #[test]
+    fn test_dependency_version() {
+        let manifest = "[dependencies]\ntree-sitter = { version = \"0.24\", optional = true }\ntree-sitter-go = { version = \"0.23\" }\nregex = \"1.10\"\n";
+        assert_eq!(dependency_version(manifest, "tree-sitter-go").as_deref(), Some("0.23"));
+        assert_eq!(dependency_version(manifest, "regex").as_deref(), Some("1.10"));
+        assert_eq!(dependency_version(manifest, "tree-sitter-c"), None);
+    }
+
+    #[test]
+    fn test_pretty_report_flags_failed_analyzer() {
+        colored::control::set_override(false);
+        let report = DoctorReport {
+            version: "0.1.0".to_string(),
+            features: enabled_features(),
+            analyzers: vec![AnalyzerCheck {
+                language: "go".to_string(),
+                grammar: "tree-sitter-go".to_string(),
+                grammar_version: Some("0.23".to_string()),
+                abi: Some(14),
+                missing: vec!["Stack".to_string()],
+                parse_errors: true,
+                error: None,
+                passed: false,
+            }],
+            runtimes: vec![RuntimeCheck {
+                runtime: "node".to_string(),
+                version: None,
+            }],
+            caches: Vec::new(),
+            registries: Vec::new(),
+            offline: true,
+        };
+        assert!(!report.passed());
+        let output = render_doctor_pretty(&report);
+        assert!(output.contains("✗ go          tree-sitter-go 0.23"), "{}", output);
+        assert!(output.contains("fixture has parse errors"));
+        assert!(output.contains("not found, using built-in lists"));
+        assert!(output.contains("(offline, not probed)"));
+        assert!(output.contains("1 analyzer(s) failed"));
+    }
+}
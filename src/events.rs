@@ -0,0 +1,275 @@
+//! Machine-readable progress events for tools that wrap the CLI.
+//!
+//! With `lint --events jsonl`, each stage of a run is written as one JSON
+//! object per line to stderr, or to the descriptor given by `--events-fd`,
+//! while stdout keeps carrying only the report. When the events go to
+//! stderr, progress output is left out and warnings are sent as events,
+//! so every line of stderr is an event. Events are sent over a
+//! channel to a single writer thread, so lines never interleave even while
+//! files are analyzed in parallel, and `seq` numbers them in the order they
+//! are written.
+//!
+//! The line format is versioned by `schema_version`; fields may be added
+//! within a version, but never removed or changed in meaning.
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::sync::mpsc::{self, Sender};
+use std::thread::JoinHandle;
+
+use serde::Serialize;
+
+/// Version of the event line format.
+pub const EVENTS_SCHEMA_VERSION: u32 = 1;
+
+/// A stage of a lint run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    /// Walking the tree for the files to scan.
+    Discovery,
+    /// Running the rules, registry lookups included.
+    Analysis,
+    /// Baseline comparison, scoring, and report limits.
+    Scoring,
+}
+
+/// One event of a lint run.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// The contract is resolved and the scan is about to start.
+    RunStarted {
+        path: String,
+        contract: String,
+        profile: Option<String>,
+        mode: String,
+        skip_registry_check: bool,
+        only_rules: Vec<String>,
+        skip_rules: Vec<String>,
+        languages: Vec<String>,
+        changed_since: Option<String>,
+        baseline: Option<String>,
+    },
+    PhaseStarted {
+        phase: Phase,
+    },
+    /// `count` is the number of files found for discovery and analyzed for
+    /// analysis, and the number of violations reported for scoring.
+    PhaseCompleted {
+        phase: Phase,
+        count: usize,
+        duration_ms: u64,
+    },
+    /// A file went through the per-file text rules (forbidden patterns, mock
+    /// data, TODOs, size limits); `text_rule_violations` counts their
+    /// findings in it. Findings of the other rules, which need every file,
+    /// are counted in `run_completed` only.
+    FileAnalyzed {
+        file: String,
+        text_rule_violations: usize,
+        analyzed: usize,
+        total: usize,
+    },
+    /// A batch of registry lookups for the dependency check finished.
+    RegistryBatchCompleted {
+        checked: usize,
+        total: usize,
+    },
+    /// A warning about the contract or the run, as printed after `Warning:`.
+    Warning {
+        message: String,
+    },
+    /// The run finished and the report is about to be written.
+    RunCompleted {
+        score: i32,
+        grade: String,
+        threshold: i32,
+        passed: bool,
+        files_scanned: usize,
+        violations: usize,
+        by_severity: BTreeMap<&'static str, usize>,
+        duration_ms: u64,
+    },
+}
+
+/// An event as written: the event with its schema version and sequence number.
+#[derive(Serialize)]
+struct Line<'a> {
+    schema_version: u32,
+    seq: u64,
+    #[serde(flatten)]
+    event: &'a Event,
+}
+
+/// Sends events to the writer; a no-op when events are off. Cheap to clone
+/// into callbacks that run on other threads.
+#[derive(Clone, Default)]
+pub struct EventSink(Option<Sender<Event>>);
+
+impl EventSink {
+    /// Queue an event for writing.
+    pub fn emit(&self, event: Event) {
+        if let Some(tx) = &self.0 {
+            // The writer only stops when its output is gone; nobody is listening then
+            let _ = tx.send(event);
+        }
+    }
+
+    /// Whether events are written anywhere.
+    pub fn is_enabled(&self) -> bool {
+        self.0.is_some()
+    }
+}
+
+/// The writer thread behind an [`EventSink`].
+///
+/// Dropping it waits until every queued event is written, so the events of
+/// a run are complete before the report goes to stdout. Sinks cloned from
+/// it must be dropped first.
+pub struct EventStream {
+    sink: EventSink,
+    writer: Option<JoinHandle<()>>,
+}
+
+impl EventStream {
+    /// A stream that writes nothing.
+    pub fn disabled() -> Self {
+        Self {
+            sink: EventSink::default(),
+            writer: None,
+        }
+    }
+
+    /// Write events as JSON lines to `out`.
+    pub fn to_writer(mut out: impl Write + Send + 'static) -> Self {
+        let (tx, rx) = mpsc::channel::<Event>();
+        let writer = std::thread::spawn(move || {
+            for (seq, event) in (1..).zip(rx) {
+                let line = Line {
+                    schema_version: EVENTS_SCHEMA_VERSION,
+                    seq,
+                    event: &event,
+                };
+                let mut line = serde_json::to_string(&line).expect("events serialize");
+                line.push('\n');
+                // One write per line, so other stderr output cannot split it
+                if out.write_all(line.as_bytes()).and_then(|_| out.flush()).is_err() {
+                    return;
+                }
+            }
+        });
+        Self {
+            sink: EventSink(Some(tx)),
+            writer: Some(writer),
+        }
+    }
+
+    /// Open the stream for `--events FORMAT`, on stderr or on descriptor `fd`.
+    pub fn open(format: &str, fd: Option<i32>) -> anyhow::Result<Self> {
+        if format != "jsonl" {
+            anyhow::bail!("invalid event format {:?}, must be 'jsonl'", format);
+        }
+        match fd {
+            Some(fd) => {
+                let out = std::fs::OpenOptions::new()
+                    .write(true)
+                    .open(format!("/dev/fd/{}", fd))
+                    .map_err(|e| anyhow::anyhow!("cannot write events to descriptor {}: {}", fd, e))?;
+                Ok(Self::to_writer(out))
+            }
+            None => Ok(Self::to_writer(std::io::stderr())),
+        }
+    }
+
+    /// A sink for this stream.
+    pub fn sink(&self) -> EventSink {
+        self.sink.clone()
+    }
+}
+
+impl Drop for EventStream {
+    fn drop(&mut self) {
+        self.sink.0.take();
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{Arc, Mutex};
+
+    /// A writer shared with the test, since the stream owns its output.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_events_written_one_per_line() {
+        let out = Shared::default();
+        let stream = EventStream::to_writer(out.clone());
+        let sink = stream.sink();
+        std::thread::scope(|scope| {
+            for worker in 0..4 {
+                let sink = sink.clone();
+                scope.spawn(move || {
+                    for i in 0..50 {
+                        sink.emit(Event::FileAnalyzed {
+                            file: format!("src/{}_{}.go", worker, i),
+                            text_rule_violations: i % 3,
+                            analyzed: i + 1,
+                            total: 50,
+                        });
+                    }
+                });
+            }
+        });
+        drop(sink);
+        drop(stream);
+
+        let text = String::from_utf8(out.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<serde_json::Value> = text.lines().map(|l| serde_json::from_str(l).unwrap()).collect();
+        assert_eq!(lines.len(), 200);
+        for (i, line) in lines.iter().enumerate() {
+            assert_eq!(line["schema_version"], EVENTS_SCHEMA_VERSION);
+            assert_eq!(line["seq"], i as u64 + 1);
+            assert_eq!(line["event"], "file_analyzed");
+        }
+    }
+
+    #[test]
+    fn test_event_format_and_disabled_sink() {
+        assert!(EventStream::open("json", None).is_err());
+
+        let stream = EventStream::disabled();
+        assert!(!stream.sink().is_enabled());
+        stream.sink().emit(Event::PhaseStarted { phase: Phase::Discovery });
+
+        let line = Line {
+            schema_version: 1,
+            seq: 3,
+            event: &Event::PhaseCompleted {
+                phase: Phase::Scoring,
+                count: 2,
+                duration_ms: 5,
+            },
+        };
+        assert_eq!(
+            serde_json::to_string(&line).unwrap(),
+            r#"{"schema_version":1,"seq":3,"event":"phase_completed","phase":"scoring","count":2,"duration_ms":5}"#
+        );
+    }
+}
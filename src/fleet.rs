@@ -0,0 +1,573 @@
+//! Scanning many repositories in one run for `hollowcheck fleet`.
+//!
+//! A manifest lists already-cloned checkouts, each with an optional contract
+//! and free-form labels (`team`, `tier`). Repositories are scanned on a
+//! bounded worker pool; each scan runs behind its own `catch_unwind`, so an
+//! error or a panic in one repository is recorded against it and the rest of
+//! the batch still completes. The outcomes are folded into one fleet report:
+//! a per-repository table sorted worst-first, rule totals across the fleet,
+//! and totals per label value.
+
+use colored::*;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::Write as FmtWrite;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use crate::detect::{panic_message, DetectionResult};
+use crate::score::HollownessScore;
+
+/// Repositories to scan, as read from a fleet manifest.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FleetManifest {
+    /// Repositories scanned at once (default: the number of CPUs)
+    #[serde(default)]
+    pub jobs: Option<usize>,
+    /// The repositories, in manifest order
+    pub repos: Vec<FleetRepo>,
+}
+
+/// One repository in a fleet manifest.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FleetRepo {
+    /// Checkout to scan; relative paths are resolved against the manifest's directory
+    pub path: PathBuf,
+    /// Name in the report and of the per-repository report file (default: the directory name)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Contract to use instead of the one discovered in the checkout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contract: Option<PathBuf>,
+    /// Labels to aggregate by, such as `team` or `tier`
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+impl FleetRepo {
+    /// Name of the repository: the manifest's `name`, or the checkout's directory name.
+    pub fn name(&self) -> String {
+        self.name.clone().unwrap_or_else(|| {
+            self.path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| self.path.to_string_lossy().to_string())
+        })
+    }
+}
+
+/// File name of a repository's full report: its name with characters other
+/// than letters, digits, `.`, `_`, and `-` replaced, plus `.json`.
+pub fn report_file_name(name: &str) -> String {
+    let stem: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') { c } else { '-' })
+        .collect();
+    format!("{}.json", stem.trim_start_matches('.'))
+}
+
+/// Read a fleet manifest.
+///
+/// Relative repository and contract paths are resolved against the
+/// manifest's directory. Repository names must map to distinct report files.
+pub fn load_manifest(path: &Path) -> anyhow::Result<FleetManifest> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("cannot read manifest {:?}: {}", path, e))?;
+    let mut manifest: FleetManifest = serde_yaml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("invalid manifest {:?}: {}", path, e))?;
+
+    if manifest.repos.is_empty() {
+        anyhow::bail!("manifest {:?} lists no repos", path);
+    }
+    if manifest.jobs == Some(0) {
+        anyhow::bail!("manifest {:?}: jobs must be at least 1", path);
+    }
+
+    let base = path.parent().unwrap_or(Path::new("."));
+    let mut files = BTreeMap::new();
+    for repo in &mut manifest.repos {
+        if repo.path.is_relative() {
+            repo.path = base.join(&repo.path);
+        }
+        if let Some(contract) = repo.contract.as_mut().filter(|c| c.is_relative()) {
+            *contract = base.join(&*contract);
+        }
+        let name = repo.name();
+        if let Some(other) = files.insert(report_file_name(&name), name.clone()) {
+            anyhow::bail!(
+                "manifest {:?}: repos {:?} and {:?} would share a report file; give one a distinct name",
+                path,
+                other,
+                name
+            );
+        }
+    }
+
+    Ok(manifest)
+}
+
+/// What a successful scan of one repository contributes to the fleet report.
+#[derive(Debug, Clone)]
+pub struct RepoScan {
+    /// The repository's score, against its own contract's threshold
+    pub score: HollownessScore,
+    /// Violations per rule, including ones left out of a bounded report
+    pub rules: BTreeMap<String, usize>,
+    /// Where the repository's full report was written, if it was
+    pub report: Option<PathBuf>,
+}
+
+impl RepoScan {
+    /// Summarize a scored detection result.
+    pub fn new(result: &DetectionResult, score: HollownessScore) -> Self {
+        let mut rules: BTreeMap<String, usize> = result.truncated.clone();
+        for v in &result.violations {
+            *rules.entry(v.rule.as_str().to_string()).or_default() += 1;
+        }
+        Self {
+            score,
+            rules,
+            report: None,
+        }
+    }
+}
+
+/// The outcome of scanning one repository.
+#[derive(Debug)]
+pub struct RepoOutcome {
+    pub repo: FleetRepo,
+    /// The scan, or why it could not be completed
+    pub result: Result<RepoScan, String>,
+    pub elapsed_ms: u64,
+}
+
+/// Scan every repository with at most `jobs` at once.
+///
+/// Errors and panics from `scan` are recorded against their repository.
+/// Outcomes are returned in manifest order.
+pub fn scan_fleet<F>(repos: &[FleetRepo], jobs: usize, scan: F) -> anyhow::Result<Vec<RepoOutcome>>
+where
+    F: Fn(&FleetRepo) -> anyhow::Result<RepoScan> + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(jobs.max(1)).build()?;
+    Ok(pool.install(|| {
+        repos
+            .par_iter()
+            .map(|repo| {
+                let start = Instant::now();
+                let result = match panic::catch_unwind(AssertUnwindSafe(|| scan(repo))) {
+                    Ok(Ok(scan)) => Ok(scan),
+                    Ok(Err(e)) => Err(e.to_string()),
+                    Err(payload) => Err(format!("scan crashed ({})", panic_message(payload.as_ref()))),
+                };
+                RepoOutcome {
+                    repo: repo.clone(),
+                    result,
+                    elapsed_ms: start.elapsed().as_millis() as u64,
+                }
+            })
+            .collect()
+    }))
+}
+
+/// How a repository fared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RepoStatus {
+    /// Could not be scanned
+    Error,
+    /// Scored over its threshold
+    Failed,
+    /// Scored within its threshold
+    Passed,
+}
+
+impl RepoStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            RepoStatus::Error => "error",
+            RepoStatus::Failed => "failed",
+            RepoStatus::Passed => "passed",
+        }
+    }
+}
+
+/// One row of the fleet table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepoSummary {
+    pub name: String,
+    pub path: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+    pub status: RepoStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grade: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<i32>,
+    pub violations: usize,
+    /// Violations per rule
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub rules: BTreeMap<String, usize>,
+    /// Why the repository could not be scanned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Path of the repository's full JSON report
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub report: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+/// A rule's violations across the fleet.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleTotal {
+    pub violations: usize,
+    /// Repositories with at least one violation of the rule
+    pub repos: usize,
+}
+
+/// Totals for the repositories sharing a label value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LabelSummary {
+    pub repos: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: usize,
+    /// Mean score of the repositories that were scanned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mean_score: Option<f64>,
+    pub violations: usize,
+}
+
+/// The consolidated report of a fleet run.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FleetReport {
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub errors: usize,
+    /// Repositories, worst first: unscanned, then by score, highest first
+    pub repos: Vec<RepoSummary>,
+    /// Violations per rule across the fleet
+    pub rules: BTreeMap<String, RuleTotal>,
+    /// Totals per label, then per label value
+    pub labels: BTreeMap<String, BTreeMap<String, LabelSummary>>,
+}
+
+impl FleetReport {
+    /// Whether every repository was scanned and passed.
+    pub fn passed(&self) -> bool {
+        self.passed == self.total
+    }
+}
+
+/// Fold scan outcomes into a fleet report.
+pub fn aggregate(outcomes: Vec<RepoOutcome>) -> FleetReport {
+    let mut repos: Vec<RepoSummary> = outcomes
+        .into_iter()
+        .map(|outcome| {
+            let name = outcome.repo.name();
+            let path = outcome.repo.path.to_string_lossy().to_string();
+            match outcome.result {
+                Ok(scan) => RepoSummary {
+                    name,
+                    path,
+                    labels: outcome.repo.labels,
+                    status: if scan.score.passed { RepoStatus::Passed } else { RepoStatus::Failed },
+                    score: Some(scan.score.score),
+                    grade: Some(scan.score.grade),
+                    threshold: Some(scan.score.threshold),
+                    violations: scan.rules.values().sum(),
+                    rules: scan.rules,
+                    error: None,
+                    report: scan.report.map(|p| p.to_string_lossy().to_string()),
+                    elapsed_ms: outcome.elapsed_ms,
+                },
+                Err(error) => RepoSummary {
+                    name,
+                    path,
+                    labels: outcome.repo.labels,
+                    status: RepoStatus::Error,
+                    score: None,
+                    grade: None,
+                    threshold: None,
+                    violations: 0,
+                    rules: BTreeMap::new(),
+                    error: Some(error),
+                    report: None,
+                    elapsed_ms: outcome.elapsed_ms,
+                },
+            }
+        })
+        .collect();
+    repos.sort_by(|a, b| {
+        (a.status, std::cmp::Reverse(a.score), &a.name).cmp(&(b.status, std::cmp::Reverse(b.score), &b.name))
+    });
+
+    let mut rules: BTreeMap<String, RuleTotal> = BTreeMap::new();
+    for repo in &repos {
+        for (rule, count) in &repo.rules {
+            let total = rules.entry(rule.clone()).or_default();
+            total.violations += count;
+            total.repos += 1;
+        }
+    }
+
+    let mut labels: BTreeMap<String, BTreeMap<String, LabelSummary>> = BTreeMap::new();
+    let mut score_sums: BTreeMap<(&str, &str), (i64, usize)> = BTreeMap::new();
+    for repo in &repos {
+        for (key, value) in &repo.labels {
+            let summary = labels.entry(key.clone()).or_default().entry(value.clone()).or_default();
+            summary.repos += 1;
+            summary.violations += repo.violations;
+            match repo.status {
+                RepoStatus::Passed => summary.passed += 1,
+                RepoStatus::Failed => summary.failed += 1,
+                RepoStatus::Error => summary.errors += 1,
+            }
+            if let Some(score) = repo.score {
+                let sum = score_sums.entry((key, value)).or_default();
+                sum.0 += score as i64;
+                sum.1 += 1;
+            }
+        }
+    }
+    for ((key, value), (sum, count)) in score_sums {
+        labels.get_mut(key).unwrap().get_mut(value).unwrap().mean_score = Some(sum as f64 / count as f64);
+    }
+
+    let count = |status: RepoStatus| repos.iter().filter(|r| r.status == status).count();
+    FleetReport {
+        total: repos.len(),
+        passed: count(RepoStatus::Passed),
+        failed: count(RepoStatus::Failed),
+        errors: count(RepoStatus::Error),
+        repos,
+        rules,
+        labels,
+    }
+}
+
+/// Render a fleet report as JSON.
+pub fn render_fleet_json(report: &FleetReport) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(report)?)
+}
+
+/// Render a fleet report as tables; colors follow the `colored` crate's settings.
+pub fn render_fleet_pretty(report: &FleetReport) -> String {
+    let mut buf = String::new();
+
+    writeln!(buf).unwrap();
+    writeln!(
+        buf,
+        "  {} {} repositories, {} passed, {} failed, {} could not be scanned",
+        "Fleet:".bold(),
+        report.total,
+        report.passed,
+        report.failed,
+        report.errors
+    )
+    .unwrap();
+
+    let width = report.repos.iter().map(|r| r.name.chars().count()).max().unwrap_or(0).max(10) + 2;
+    writeln!(buf).unwrap();
+    writeln!(
+        buf,
+        "    {}",
+        format!(
+            "  {:<width$}{:>5}  {:<6}{:<8}{:>10}  Labels",
+            "Repository",
+            "Score",
+            "Grade",
+            "Status",
+            "Violations",
+            width = width
+        )
+        .dimmed()
+    )
+    .unwrap();
+    for repo in &report.repos {
+        let (mark, status) = match repo.status {
+            RepoStatus::Passed => ("✓".green(), repo.status.as_str().green()),
+            RepoStatus::Failed => ("✗".red(), repo.status.as_str().red()),
+            RepoStatus::Error => ("!".yellow(), repo.status.as_str().yellow()),
+        };
+        let score = repo.score.map_or("-".to_string(), |s| s.to_string());
+        let violations = match repo.status {
+            RepoStatus::Error => "-".to_string(),
+            _ => repo.violations.to_string(),
+        };
+        let labels: Vec<String> = repo.labels.iter().map(|(k, v)| format!("{}={}", k, v)).collect();
+        writeln!(
+            buf,
+            "    {} {:<width$}{:>5}  {:<6}{:<8}{:>10}  {}",
+            mark,
+            repo.name,
+            score,
+            repo.grade.as_deref().unwrap_or("-"),
+            status,
+            violations,
+            labels.join(" ").dimmed(),
+            width = width
+        )
+        .unwrap();
+        if let Some(ref error) = repo.error {
+            writeln!(buf, "      {}", error.dimmed()).unwrap();
+        }
+    }
+
+    if !report.rules.is_empty() {
+        let mut rules: Vec<(&String, &RuleTotal)> = report.rules.iter().collect();
+        rules.sort_by(|a, b| b.1.violations.cmp(&a.1.violations).then(a.0.cmp(b.0)));
+        writeln!(buf).unwrap();
+        writeln!(buf, "  {}", "Rules across the fleet:".bold()).unwrap();
+        for (rule, total) in rules {
+            writeln!(
+                buf,
+                "    {:<28}{:>7}  {}",
+                rule,
+                total.violations,
+                format!("in {} repo{}", total.repos, if total.repos == 1 { "" } else { "s" }).dimmed()
+            )
+            .unwrap();
+        }
+    }
+
+    for (key, values) in &report.labels {
+        writeln!(buf).unwrap();
+        writeln!(buf, "  {}", format!("By {}:", key).bold()).unwrap();
+        for (value, s) in values {
+            let mean = s.mean_score.map_or("-".to_string(), |m| format!("{:.1}", m));
+            writeln!(
+                buf,
+                "    {:<20}{} repo{}, {} passed, {} failed, {} not scanned, mean score {}, {} violation{}",
+                value,
+                s.repos,
+                if s.repos == 1 { "" } else { "s" },
+                s.passed,
+                s.failed,
+                s.errors,
+                mean,
+                s.violations,
+                if s.violations == 1 { "" } else { "s" }
+            )
+            .unwrap();
+        }
+    }
+    writeln!(buf).unwrap();
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::score;
+    use tempfile::TempDir;
+
+    fn repo(name: &str, labels: &[(&str, &str)]) -> FleetRepo {
+        FleetRepo {
+            path: PathBuf::from("/checkouts").join(name),
+            name: None,
+            contract: None,
+            labels: labels.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+        }
+    }
+
+    fn scored(points: i32, threshold: i32, rules: &[(&str, usize)]) -> RepoScan {
+        let mut result = DetectionResult::new();
+        result.truncated = rules.iter().map(|(r, n)| (r.to_string(), *n)).collect();
+        let mut score = score::calculate_with_threshold(&result, threshold, &Default::default());
+        score.score = points;
+        score.grade = score::calculate_grade(points);
+        score.passed = points <= threshold;
+        RepoScan::new(&result, score)
+    }
+
+    #[test]
+    fn test_load_manifest_resolves_paths() {
+        let temp = TempDir::new().unwrap();
+        let manifest = temp.path().join("repos.yaml");
+        std::fs::write(
+            &manifest,
+            "jobs: 2\nrepos:\n  - path: checkouts/api\n    contract: contracts/strict.yaml\n    labels: {team: core, tier: \"1\"}\n  - path: /srv/web\n    name: web frontend\n",
+        )
+        .unwrap();
+
+        let loaded = load_manifest(&manifest).unwrap();
+        assert_eq!(loaded.jobs, Some(2));
+        assert_eq!(loaded.repos[0].path, temp.path().join("checkouts/api"));
+        assert_eq!(loaded.repos[0].contract, Some(temp.path().join("contracts/strict.yaml")));
+        assert_eq!(loaded.repos[0].name(), "api");
+        assert_eq!(loaded.repos[1].path, PathBuf::from("/srv/web"));
+        assert_eq!(report_file_name(&loaded.repos[1].name()), "web-frontend.json");
+
+        std::fs::write(&manifest, "repos:\n  - path: a/api\n  - path: b/api\n").unwrap();
+        let err = load_manifest(&manifest).unwrap_err().to_string();
+        assert!(err.contains("would share a report file"), "{}", err);
+    }
+
+    #[test]
+    fn test_failures_are_isolated_per_repo() {
+        let repos = vec![repo("api", &[]), repo("broken", &[]), repo("crashes", &[])];
+        let outcomes = scan_fleet(&repos, 2, |repo| match repo.name().as_str() {
+            "broken" => anyhow::bail!("cannot access path"),
+            "crashes" => panic!("grammar exploded"),
+            _ => Ok(scored(5, 25, &[])),
+        })
+        .unwrap();
+
+        let results: Vec<_> = outcomes.iter().map(|o| (o.repo.name(), o.result.as_ref().err().cloned())).collect();
+        assert_eq!(
+            results,
+            vec![
+                ("api".to_string(), None),
+                ("broken".to_string(), Some("cannot access path".to_string())),
+                ("crashes".to_string(), Some("scan crashed (grammar exploded)".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_sorts_worst_first_and_totals() {
+        let outcome = |repo: FleetRepo, result: Result<RepoScan, String>| RepoOutcome {
+            repo,
+            result,
+            elapsed_ms: 0,
+        };
+        let report = aggregate(vec![
+            outcome(repo("web", &[("team", "frontend")]), Ok(scored(4, 25, &[("stub_function", 2)]))),
+            outcome(
+                repo("billing", &[("team", "payments")]),
+                Ok(scored(60, 25, &[("stub_function", 10), ("mock_data", 3)])),
+            ),
+            outcome(repo("ledger", &[("team", "payments")]), Err("no files to scan".to_string())),
+            outcome(repo("checkout", &[("team", "payments")]), Ok(scored(20, 25, &[]))),
+        ]);
+
+        let rows: Vec<_> = report.repos.iter().map(|r| (r.name.as_str(), r.status, r.score)).collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("ledger", RepoStatus::Error, None),
+                ("billing", RepoStatus::Failed, Some(60)),
+                ("checkout", RepoStatus::Passed, Some(20)),
+                ("web", RepoStatus::Passed, Some(4)),
+            ]
+        );
+        assert_eq!((report.total, report.passed, report.failed, report.errors), (4, 2, 1, 1));
+        assert!(!report.passed());
+        assert_eq!(report.rules["stub_function"], RuleTotal { violations: 12, repos: 2 });
+        assert_eq!(report.rules["mock_data"], RuleTotal { violations: 3, repos: 1 });
+
+        let payments = &report.labels["team"]["payments"];
+        assert_eq!((payments.repos, payments.passed, payments.failed, payments.errors), (3, 1, 1, 1));
+        assert_eq!(payments.mean_score, Some(40.0));
+        assert_eq!(payments.violations, 13);
+    }
+}
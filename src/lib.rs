@@ -10,11 +10,28 @@
 //! The codebase uses tree-sitter for AST-based analysis:
 //!
 //! - `analysis`: Core AST analysis module with language analyzers
+//! - `cargo`: Argument handling and package lookup for `cargo hollowcheck`
 //! - `parser`: Legacy tree-sitter parsers (being migrated to `analysis`)
 //! - `detect`: Detection rules that consume AST-derived facts
 //! - `contract`: YAML contract schema definitions
 //! - `report`: Output formatting (text, JSON)
+//! - `diff`: Comparison of two JSON reports
+//! - `discovery`: The files a scan analyzes, for tools that need the same set
+//! - `ci`: CI platform detection and output plans for `hollowcheck ci`
+//! - `doctor`: Build and environment self-check for `hollowcheck doctor`
+//! - `events`: Machine-readable progress events for `lint --events`
+//! - `fleet`: Scans of many repositories and the consolidated report for `hollowcheck fleet`
+//! - `migrate`: Conversion of Go-version contracts and reports
+//! - `patch`: Diff preview and atomic application of edits to user files
+//! - `prefetch`: Registry and stdlib cache warming for `hollowcheck prefetch`
+//! - `policy`: Remote contracts fetched over HTTPS and pinned by sha256
 //! - `score`: Hollowness score calculation
+//! - `serve`: The latest report over HTTP for `lint --watch --serve`
+//! - `spec`: Task specification checks for `hollowcheck verify-spec`
+//! - `theme`: Color themes and accessibility symbols for pretty output
+//! - `user_config`: The user-level config file (`~/.config/hollowcheck/config.toml`)
+//! - `watch`: Change detection for `lint --watch`
+//! - `workspace`: Workspace root discovery for runs inside a sub-project
 //!
 //! # Adding a New Language
 //!
@@ -22,20 +39,39 @@
 //! trait and register in `languages/mod.rs`.
 
 pub mod analysis;
+mod cache_file;
+pub mod cargo;
+pub mod ci;
 pub mod cli;
 pub mod contract;
 pub mod detect;
+pub mod diff;
+pub mod discovery;
+pub mod doctor;
+pub mod events;
+pub mod fleet;
+pub mod migrate;
 pub mod parser;
+pub mod patch;
+pub mod policy;
+pub mod prefetch;
 pub mod registry;
 pub mod report;
 pub mod score;
+pub mod serve;
+pub mod spec;
+pub mod theme;
+pub mod user_config;
+pub mod watch;
+pub mod workspace;
 
 pub use analysis::{
-    register_analyzers, AnalysisContext, Declaration, DeclarationKind, FileFacts,
-    GoAnalyzer, LanguageAnalyzer, RustAnalyzer, StubDetector, StubFinding,
+    register_analyzers, AnalysisContext, Complexity, Declaration, DeclarationKind, FileFacts,
+    GoAnalyzer, LanguageAnalyzer, RustAnalyzer, StubAnalysis, StubDetector, StubFinding,
 };
 pub use contract::Contract;
 pub use detect::{DetectionResult, Runner, Violation};
+pub use discovery::{DiscoveredFile, FileClass, FileDiscovery};
 pub use parser::{for_extension, init as init_parsers, Parser, Symbol};
 pub use registry::{RegistryClient, RegistryType};
 pub use score::HollownessScore;
@@ -1,27 +1,7 @@
 //! Hollowcheck CLI entry point.
 
-use clap::Parser;
-use hollowcheck::cli::{self, Cli, Commands, EXIT_ERROR};
+use hollowcheck::cli;
 
 fn main() {
-    let cli = Cli::parse();
-
-    let exit_code = match cli.command {
-        Commands::Lint(args) => match cli::run_lint(&args) {
-            Ok(code) => code,
-            Err(e) => {
-                eprintln!("Error: {}", e);
-                EXIT_ERROR
-            }
-        },
-        Commands::Init(args) => match cli::run_init(&args) {
-            Ok(code) => code,
-            Err(e) => {
-                eprintln!("Error: {}", e);
-                EXIT_ERROR
-            }
-        },
-    };
-
-    std::process::exit(exit_code);
+    std::process::exit(cli::run(cli::parse_args()));
 }
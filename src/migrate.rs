@@ -0,0 +1,522 @@
+//! Migration of contracts and reports from the Go version of hollowcheck.
+//!
+//! The Go version's contract schema has drifted from this one: rules were
+//! toggled and tuned in a `rules:` section, a few top-level fields had other
+//! names, and some list entries spelled their keys differently. Such a
+//! contract either fails to load or, worse, loads with its settings silently
+//! ignored. [`migrate_contract`] maps every Go-version field onto the current
+//! schema and says what it did with each; fields with no equivalent are
+//! dropped with a warning rather than guessed at.
+//!
+//! Go-version JSON reports are close to [`JsonReport`] but predate some of
+//! its required fields. [`migrate_report`] upgrades them so archived history
+//! can be compared with current reports by `hollowcheck diff`.
+
+use std::collections::BTreeMap;
+
+use serde_yaml::{Mapping, Value};
+
+use crate::contract::{self, Contract};
+use crate::detect::ViolationRule;
+use crate::report::JsonReport;
+use crate::score::{self, DEFAULT_THRESHOLD};
+
+/// Top-level fields only the Go-version contract schema has.
+const GO_CONTRACT_FIELDS: &[&str] = &[
+    "rules",
+    "ignore_paths",
+    "include_tests",
+    "max_score",
+    "output",
+    "fail_on_warnings",
+];
+
+/// Top-level fields renamed since the Go version: (Go name, current name).
+const RENAMED_FIELDS: Renames = &[
+    ("include_tests", "include_test_files"),
+    ("max_score", "threshold"),
+];
+
+/// Key renames: (Go name, current name).
+type Renames = &'static [(&'static str, &'static str)];
+
+/// List entry keys renamed since the Go version, per list.
+const RENAMED_ENTRY_KEYS: &[(&str, Renames)] = &[
+    ("required_symbols", &[("type", "kind")]),
+    ("forbidden_patterns", &[("regex", "pattern"), ("reason", "description")]),
+    ("complexity", &[("function", "symbol"), ("min", "min_complexity")]),
+];
+
+/// Go-version rules that map onto a contract section: (Go rule, section,
+/// option renames). Options not listed keep their name.
+const RULE_SECTIONS: &[(&str, &str, Renames)] = &[
+    ("hollow_todo", "hollow_todos", &[]),
+    ("floating_promise", "floating_promises", &[]),
+    ("god_object", "god_objects", &[]),
+    ("too_many_params", "long_parameter_lists", &[("max", "max_params")]),
+    ("repeated_literal", "repeated_literals", &[]),
+    ("empty_handler", "empty_handlers", &[]),
+];
+
+/// Go-version rules that cannot be switched off in this version.
+const ALWAYS_ON_RULES: &[&str] = &[
+    "stub_function",
+    "low_complexity",
+    "missing_file",
+    "missing_symbol",
+    "missing_test",
+    "forbidden_pattern",
+    "hallucinated_dependency",
+];
+
+/// A contract converted to the current schema.
+#[derive(Debug)]
+pub struct ContractMigration {
+    /// The converted contract, with comments marking each mapped field.
+    pub yaml: String,
+    /// Settings that were dropped or changed meaning.
+    pub warnings: Vec<String>,
+}
+
+/// A report upgraded to the current JSON report shape.
+pub struct ReportMigration {
+    /// The upgraded report.
+    pub report: JsonReport,
+    /// What was changed, one line per kind of change.
+    pub changes: Vec<String>,
+}
+
+/// The Go-version fields a contract document uses, in document order.
+///
+/// Empty for contracts in the current schema.
+pub fn go_contract_fields(document: &Value) -> Vec<String> {
+    let Some(mapping) = document.as_mapping() else {
+        return Vec::new();
+    };
+    mapping
+        .iter()
+        .filter_map(|(key, value)| {
+            let key = key.as_str()?;
+            let is_go = GO_CONTRACT_FIELDS.contains(&key)
+                // The Go version listed mock signatures as plain strings
+                || (key == "mock_signatures" && value.is_sequence());
+            is_go.then(|| key.to_string())
+        })
+        .collect()
+}
+
+/// Decisions made while converting one contract.
+#[derive(Default)]
+struct Notes {
+    /// Comments to write above each top-level field of the output.
+    by_field: BTreeMap<String, Vec<String>>,
+    warnings: Vec<String>,
+}
+
+impl Notes {
+    fn note(&mut self, field: &str, note: String) {
+        self.by_field.entry(field.to_string()).or_default().push(note);
+    }
+}
+
+/// Rename keys of each mapping in a list; returns how many entries changed.
+fn rename_entry_keys(list: &mut Value, renames: &[(&str, &str)]) -> usize {
+    let Some(entries) = list.as_sequence_mut() else {
+        return 0;
+    };
+    let mut changed = 0;
+    for entry in entries.iter_mut().filter_map(Value::as_mapping_mut) {
+        let mut renamed = false;
+        for (old, new) in renames {
+            if let Some(value) = entry.remove(*old) {
+                entry.insert(Value::from(*new), value);
+                renamed = true;
+            }
+        }
+        changed += usize::from(renamed);
+    }
+    changed
+}
+
+/// Merge `settings` into the mapping at `section` of the output.
+fn merge_section(output: &mut Mapping, section: &str, settings: Mapping) {
+    let entry = output
+        .entry(Value::from(section))
+        .or_insert_with(|| Value::Mapping(Mapping::new()));
+    if let Some(existing) = entry.as_mapping_mut() {
+        existing.extend(settings);
+    }
+}
+
+/// Convert Go-version `ignore_paths` prefixes to `excluded_paths` globs.
+fn convert_ignore_paths(value: Value, output: &mut Mapping, notes: &mut Notes) {
+    let mut globs = Vec::new();
+    for path in value.as_sequence().into_iter().flatten() {
+        let Some(path) = path.as_str() else {
+            notes.warnings.push(format!("ignore_paths: {:?} is not a string; dropped", path));
+            continue;
+        };
+        if path.contains(['*', '?', '[']) {
+            globs.push(Value::from(path));
+            continue;
+        }
+        let glob = format!("{}/**", path.trim_end_matches('/'));
+        notes.warnings.push(format!(
+            "ignore_paths: {:?} was a path prefix; excluded_paths are globs, so it became {:?}",
+            path, glob
+        ));
+        globs.push(Value::from(glob));
+    }
+
+    let existing = output
+        .entry(Value::from("excluded_paths"))
+        .or_insert_with(|| Value::Sequence(Vec::new()));
+    if let Some(list) = existing.as_sequence_mut() {
+        list.extend(globs);
+    }
+    notes.note("excluded_paths", "from ignore_paths; path prefixes became globs".to_string());
+}
+
+/// Map the Go-version `rules:` section onto contract sections.
+fn convert_rules(rules: Value, output: &mut Mapping, notes: &mut Notes) {
+    let Value::Mapping(rules) = rules else {
+        notes.warnings.push("rules: not a mapping; dropped".to_string());
+        return;
+    };
+
+    for (name, settings) in rules {
+        let name = name.as_str().unwrap_or_default().replace('-', "_");
+        // `stub_function: false` is shorthand for `enabled: false`
+        let mut settings = match settings {
+            Value::Mapping(m) => m,
+            Value::Bool(enabled) => Mapping::from_iter([(Value::from("enabled"), Value::Bool(enabled))]),
+            _ => Mapping::new(),
+        };
+        let disabled = settings.get("enabled").and_then(Value::as_bool) == Some(false);
+
+        if let Some((_, section, renames)) = RULE_SECTIONS.iter().find(|(rule, _, _)| *rule == name) {
+            for (old, new) in *renames {
+                if let Some(value) = settings.remove(*old) {
+                    settings.insert(Value::from(*new), value);
+                }
+            }
+            merge_section(output, section, settings);
+            notes.note(section, format!("from rules.{}", name));
+        } else if name == "mock_data" {
+            if disabled {
+                notes.warnings.push(
+                    "rules.mock_data.enabled: mock data detection has no switch; suppress mock_data violations with waivers instead"
+                        .to_string(),
+                );
+            }
+            settings.remove("enabled");
+            if !settings.is_empty() {
+                merge_section(output, "mock_signatures", settings);
+                notes.note("mock_signatures", "options from rules.mock_data".to_string());
+            }
+        } else if ALWAYS_ON_RULES.contains(&name.as_str()) {
+            if disabled {
+                notes.warnings.push(format!(
+                    "rules.{}.enabled: {} cannot be disabled; suppress its violations with waivers instead",
+                    name, name
+                ));
+            }
+            settings.remove("enabled");
+            for option in settings.keys().filter_map(Value::as_str) {
+                notes.warnings.push(format!("rules.{}.{}: no longer exists; dropped", name, option));
+            }
+        } else {
+            notes.warnings.push(format!("rules.{}: no rule of that name in this version; dropped", name));
+        }
+    }
+}
+
+/// Render the converted document with a header and a comment above each mapped field.
+fn render(output: Mapping, origin: &str, notes: &Notes) -> anyhow::Result<String> {
+    let mut yaml = format!(
+        "# Migrated from the Go-version contract {} by `hollowcheck migrate contract`.\n",
+        origin
+    );
+    if !notes.warnings.is_empty() {
+        yaml.push_str("# Review before use:\n");
+        for warning in &notes.warnings {
+            yaml.push_str(&format!("#   - {}\n", warning));
+        }
+    }
+    yaml.push('\n');
+
+    for (key, value) in output {
+        if let Some(field_notes) = key.as_str().and_then(|k| notes.by_field.get(k)) {
+            for note in field_notes {
+                yaml.push_str(&format!("# {}\n", note));
+            }
+        }
+        yaml.push_str(&serde_yaml::to_string(&Mapping::from_iter([(key, value)]))?);
+    }
+    Ok(yaml)
+}
+
+/// Convert a Go-version contract to the current schema.
+///
+/// `origin` names the source in the header comment. The result is checked to
+/// load and validate as a current contract.
+pub fn migrate_contract(source: &str, origin: &str) -> anyhow::Result<ContractMigration> {
+    let document: Value = serde_yaml::from_str(source)?;
+    let Value::Mapping(document) = document else {
+        anyhow::bail!("a contract must be a YAML mapping");
+    };
+
+    let mut notes = Notes::default();
+    let mut output = Mapping::new();
+    let mut rules = None;
+
+    for (key, mut value) in document {
+        let Some(name) = key.as_str() else {
+            output.insert(key, value);
+            continue;
+        };
+        if let Some((_, new)) = RENAMED_FIELDS.iter().find(|(old, _)| *old == name) {
+            notes.note(new, format!("renamed from {}", name));
+            output.insert(Value::from(*new), value);
+            continue;
+        }
+        match name {
+            "rules" => rules = Some(value),
+            "ignore_paths" => convert_ignore_paths(value, &mut output, &mut notes),
+            "output" => notes.warnings.push(
+                "output: report settings are command-line flags now (--format, --output); dropped".to_string(),
+            ),
+            "fail_on_warnings" => notes.warnings.push(
+                "fail_on_warnings: warnings never count toward the score in this version; gate with threshold instead; dropped"
+                    .to_string(),
+            ),
+            "mock_signatures" if value.is_sequence() => {
+                let patterns: Vec<Value> = value
+                    .as_sequence()
+                    .into_iter()
+                    .flatten()
+                    .map(|p| match p {
+                        Value::String(s) => Value::Mapping(Mapping::from_iter([(Value::from("pattern"), Value::from(s.as_str()))])),
+                        other => other.clone(),
+                    })
+                    .collect();
+                merge_section(
+                    &mut output,
+                    "mock_signatures",
+                    Mapping::from_iter([(Value::from("patterns"), Value::Sequence(patterns))]),
+                );
+                notes.note("mock_signatures", "patterns from the mock_signatures list".to_string());
+            }
+            _ => {
+                if let Some((_, renames)) = RENAMED_ENTRY_KEYS.iter().find(|(list, _)| *list == name) {
+                    let changed = rename_entry_keys(&mut value, renames);
+                    if changed > 0 {
+                        let renamed: Vec<_> = renames.iter().map(|(o, n)| format!("{} -> {}", o, n)).collect();
+                        notes.note(name, format!("entry keys renamed: {}", renamed.join(", ")));
+                    }
+                }
+                output.insert(key, value);
+            }
+        }
+    }
+    if let Some(rules) = rules {
+        convert_rules(rules, &mut output, &mut notes);
+    }
+
+    let contract: Contract = serde_yaml::from_value(Value::Mapping(output.clone()))
+        .map_err(|e| anyhow::anyhow!("converted contract does not load: {}", e))?;
+    contract::validate(&contract).map_err(|e| anyhow::anyhow!("converted contract is invalid: {}", e))?;
+
+    Ok(ContractMigration {
+        yaml: render(output, origin, &notes)?,
+        warnings: notes.warnings,
+    })
+}
+
+/// Normalize a Go-version violation in place; returns whether its file was emptied.
+fn normalize_violation(violation: &mut serde_json::Value, renamed_rules: &mut usize) -> bool {
+    let Some(object) = violation.as_object_mut() else {
+        return false;
+    };
+    if let Some(rule) = object.get("rule").and_then(|r| r.as_str()) {
+        if let Ok(parsed) = rule.parse::<ViolationRule>() {
+            if parsed.as_str() != rule {
+                *renamed_rules += 1;
+                object.insert("rule".to_string(), parsed.as_str().into());
+            }
+        }
+    }
+    if let Some(severity) = object.get("severity").and_then(|s| s.as_str()) {
+        let lower = severity.to_ascii_lowercase();
+        object.insert("severity".to_string(), lower.into());
+    }
+    let emptied = object.get("file").and_then(|f| f.as_str()) == Some("");
+    if emptied {
+        object.insert("file".to_string(), serde_json::Value::Null);
+    }
+    emptied
+}
+
+/// Upgrade a Go-version JSON report to the current [`JsonReport`] shape.
+///
+/// Project-level violations get a `null` file instead of `""`, rule names and
+/// severities are normalized, and fields the Go version did not write
+/// (`grade`, `passed`, `breakdown`, `suppressed_count`) are derived from the
+/// rest of the report. Reports already in the current shape are unchanged.
+pub fn migrate_report(source: &str) -> anyhow::Result<ReportMigration> {
+    let mut value: serde_json::Value = serde_json::from_str(source)?;
+    let Some(report) = value.as_object_mut() else {
+        anyhow::bail!("a report must be a JSON object");
+    };
+    let mut changes = Vec::new();
+
+    let (mut emptied, mut renamed_rules) = (0, 0);
+    for list in ["violations", "new_violations"] {
+        for violation in report.get_mut(list).and_then(|l| l.as_array_mut()).into_iter().flatten() {
+            emptied += usize::from(normalize_violation(violation, &mut renamed_rules));
+        }
+    }
+    for list in ["suppressed", "waived"] {
+        for entry in report.get_mut(list).and_then(|l| l.as_array_mut()).into_iter().flatten() {
+            if let Some(violation) = entry.get_mut("violation") {
+                emptied += usize::from(normalize_violation(violation, &mut renamed_rules));
+            }
+        }
+    }
+    if emptied > 0 {
+        changes.push(format!("{} project-level violation file(s): \"\" became null", emptied));
+    }
+    if renamed_rules > 0 {
+        changes.push(format!("{} rule name(s) normalized", renamed_rules));
+    }
+
+    let score = report.get("score").and_then(|s| s.as_i64()).unwrap_or(0) as i32;
+    if !report.contains_key("threshold") {
+        report.insert("threshold".to_string(), DEFAULT_THRESHOLD.into());
+        changes.push(format!("threshold: missing, set to the default {}", DEFAULT_THRESHOLD));
+    }
+    let threshold = report.get("threshold").and_then(|t| t.as_i64()).unwrap_or(DEFAULT_THRESHOLD as i64) as i32;
+    if !report.contains_key("grade") {
+        report.insert("grade".to_string(), score::calculate_grade(score).into());
+        changes.push("grade: derived from the score".to_string());
+    }
+    if !report.contains_key("passed") {
+        report.insert("passed".to_string(), (score <= threshold).into());
+        changes.push("passed: derived from the score and threshold".to_string());
+    }
+    if !report.contains_key("suppressed_count") {
+        let count = report.get("suppressed").and_then(|s| s.as_array()).map_or(0, Vec::len);
+        report.insert("suppressed_count".to_string(), count.into());
+        changes.push("suppressed_count: counted from suppressed".to_string());
+    }
+    if !report.contains_key("breakdown") {
+        let mut breakdown: BTreeMap<String, (i32, i32)> = BTreeMap::new();
+        for violation in report.get("violations").and_then(|v| v.as_array()).into_iter().flatten() {
+            let rule = violation.get("rule").and_then(|r| r.as_str()).unwrap_or_default();
+            let critical = violation.get("severity").and_then(|s| s.as_str()) == Some("critical");
+            let entry = breakdown.entry(rule.to_string()).or_default();
+            let points = score::get_points_for_rule(rule);
+            entry.0 += if critical { points.max(score::points::CRITICAL_MIN) } else { points };
+            entry.1 += 1;
+        }
+        let entries: Vec<_> = breakdown
+            .into_iter()
+            .map(|(rule, (points, violations))| {
+                serde_json::json!({"rule": rule, "points": points, "violations": violations})
+            })
+            .collect();
+        report.insert("breakdown".to_string(), entries.into());
+        changes.push("breakdown: derived from violations".to_string());
+    }
+
+    let report: JsonReport = serde_json::from_value(value)
+        .map_err(|e| anyhow::anyhow!("cannot upgrade report: {}", e))?;
+    Ok(ReportMigration { report, changes })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> String {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("testdata/migrate").join(name);
+        std::fs::read_to_string(path).unwrap()
+    }
+
+    #[test]
+    fn test_go_contract_fields() {
+        let go: Value = serde_yaml::from_str(&fixture("go_contract.yaml")).unwrap();
+        assert_eq!(
+            go_contract_fields(&go),
+            vec!["include_tests", "max_score", "ignore_paths", "mock_signatures", "output", "fail_on_warnings", "rules"]
+        );
+        let current: Value = serde_yaml::from_str("version: \"1.0\"\nname: x\nthreshold: 20\n").unwrap();
+        assert!(go_contract_fields(&current).is_empty());
+    }
+
+    #[test]
+    fn test_migrate_contract_round_trip() {
+        let migration = migrate_contract(&fixture("go_contract.yaml"), "go_contract.yaml").unwrap();
+        assert!(migration.yaml.contains("# renamed from max_score\nthreshold: 20\n"), "{}", migration.yaml);
+        assert!(migration.yaml.contains("# from rules.too_many_params\n"), "{}", migration.yaml);
+
+        let document: Value = serde_yaml::from_str(&migration.yaml).unwrap();
+        assert!(go_contract_fields(&document).is_empty());
+        let contract: Contract = serde_yaml::from_value(document).unwrap();
+        assert_eq!(contract.threshold, Some(20));
+        assert_eq!(contract.include_test_files, Some(false));
+        assert_eq!(contract.excluded_paths, vec!["vendor/**", "**/generated/**"]);
+        assert_eq!(contract.required_symbols.len(), 2);
+        assert_eq!(contract.forbidden_patterns[0].pattern, "TODO\\(ai\\)");
+        assert_eq!(contract.forbidden_patterns[0].description.as_deref(), Some("Unfinished generated code"));
+        assert_eq!(contract.complexity[0].symbol, "ProcessPayment");
+        assert_eq!(contract.complexity[0].min_complexity, 4);
+        let mocks = contract.mock_signatures.as_ref().unwrap();
+        assert_eq!(mocks.patterns.len(), 2);
+        assert_eq!(mocks.skip_test_files, Some(true));
+        let god = contract.god_objects.as_ref().unwrap();
+        assert_eq!((god.max_file_lines, god.max_function_lines), (Some(800), Some(80)));
+        assert_eq!(contract.long_parameter_lists.as_ref().unwrap().max_params, Some(6));
+        assert!(contract.hollow_todos.as_ref().unwrap().enabled);
+
+        let warnings = migration.warnings.join("\n");
+        for expected in [
+            "\"vendor/\" was a path prefix",
+            "output: report settings are command-line flags now",
+            "fail_on_warnings:",
+            "rules.stub_function.enabled: stub_function cannot be disabled",
+            "rules.magic_numbers: no rule of that name",
+        ] {
+            assert!(warnings.contains(expected), "missing {:?} in:\n{}", expected, warnings);
+        }
+
+        // A migrated contract migrates to itself, with nothing left to warn about
+        let again = migrate_contract(&migration.yaml, "migrated.yaml").unwrap();
+        assert!(again.warnings.is_empty(), "{:?}", again.warnings);
+        let twice: Contract = serde_yaml::from_str(&again.yaml).unwrap();
+        assert_eq!(twice.excluded_paths, contract.excluded_paths);
+    }
+
+    #[test]
+    fn test_migrate_report_round_trip() {
+        let migration = migrate_report(&fixture("go_report.json")).unwrap();
+        let report = &migration.report;
+        assert_eq!(report.violations[1].rule, "missing_file");
+        assert_eq!(report.violations[1].severity, "critical");
+        assert_eq!(report.violations[1].file, None);
+        assert_eq!((report.grade.as_str(), report.passed), ("C", false));
+        assert_eq!(report.suppressed_count, 1);
+        let breakdown: Vec<_> = report.breakdown.iter().map(|b| (b.rule.as_str(), b.points, b.violations)).collect();
+        assert_eq!(
+            breakdown,
+            vec![("hollow_todo", 5, 1), ("missing_file", 20, 1), ("stub_function", 10, 1)]
+        );
+        assert!(migration.changes.iter().any(|c| c.contains("1 project-level violation")));
+
+        // The upgraded report loads as a current report and upgrades to itself
+        let json = serde_json::to_string_pretty(report).unwrap();
+        let again = migrate_report(&json).unwrap();
+        assert!(again.changes.is_empty(), "{:?}", again.changes);
+        assert_eq!(serde_json::to_string_pretty(&again.report).unwrap(), json);
+    }
+}
@@ -0,0 +1,221 @@
+//! Previewing and applying edits to the user's files.
+//!
+//! Every mode that writes to files the user owns (`lint
+//! --fix-suppressions`) computes its edits in memory as a [`PatchSet`]
+//! first. The set renders as a unified diff for review, or for `git apply`
+//! with `--diff-only`, and is applied one file at a time through a
+//! temporary file and a rename, so an interrupted run leaves each file
+//! either untouched or fully edited.
+//!
+//! Producers keep the file's own conventions: the edited text uses the
+//! original line endings and final-newline state, and applying keeps the
+//! original permissions.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use colored::*;
+use similar::TextDiff;
+
+/// The new contents of one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileEdit {
+    pub path: PathBuf,
+    /// Contents the edit was computed from
+    pub original: String,
+    pub modified: String,
+}
+
+/// Edits to several files, previewed and applied together.
+#[derive(Debug, Clone, Default)]
+pub struct PatchSet {
+    edits: Vec<FileEdit>,
+}
+
+/// The line ending a text uses: `\r\n` if any line ends with it.
+pub fn line_ending(text: &str) -> &'static str {
+    if text.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    }
+}
+
+/// Replace `path` with `contents` through a temporary file in the same
+/// directory, keeping the file's permissions.
+pub fn write_atomic(path: &Path, contents: &str) -> anyhow::Result<()> {
+    let permissions = fs::metadata(path)
+        .map_err(|e| anyhow::anyhow!("cannot read {}: {}", path.display(), e))?
+        .permissions();
+    let name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    let temp = path.with_file_name(format!(".{}.hollowcheck-tmp", name));
+
+    let write = || -> std::io::Result<()> {
+        let mut file = fs::OpenOptions::new().write(true).create_new(true).open(&temp)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        fs::set_permissions(&temp, permissions)?;
+        fs::rename(&temp, path)
+    };
+    write().map_err(|e| {
+        let _ = fs::remove_file(&temp);
+        anyhow::anyhow!("cannot write {}: {}", path.display(), e)
+    })
+}
+
+impl PatchSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an edit; an edit that changes nothing is dropped.
+    pub fn push(&mut self, path: impl Into<PathBuf>, original: String, modified: String) {
+        if original != modified {
+            self.edits.push(FileEdit {
+                path: path.into(),
+                original,
+                modified,
+            });
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    pub fn edits(&self) -> &[FileEdit] {
+        &self.edits
+    }
+
+    /// Render the edits as a unified diff with paths relative to `base`,
+    /// in the `a/` and `b/` form `git apply` reads. Line endings are kept,
+    /// and a missing final newline is marked as git marks it.
+    pub fn render(&self, base: &Path, color: bool) -> String {
+        let mut out = String::new();
+        for edit in &self.edits {
+            let rel = edit.path.strip_prefix(base).unwrap_or(&edit.path);
+            let rel = rel.to_string_lossy().replace('\\', "/");
+            let diff = TextDiff::from_lines(&edit.original, &edit.modified);
+            let unified = diff
+                .unified_diff()
+                .context_radius(3)
+                .header(&format!("a/{}", rel), &format!("b/{}", rel))
+                .to_string();
+
+            for line in unified.split_inclusive('\n') {
+                let text = line.trim_end_matches(['\r', '\n']);
+                let ending = &line[text.len()..];
+                if !color {
+                    out.push_str(line);
+                    continue;
+                }
+                let styled = if text.starts_with("---") || text.starts_with("+++") {
+                    text.bold()
+                } else if text.starts_with("@@") {
+                    text.cyan()
+                } else if text.starts_with('+') {
+                    text.green()
+                } else if text.starts_with('-') {
+                    text.red()
+                } else {
+                    text.normal()
+                };
+                let _ = write!(out, "{}{}", styled, ending);
+            }
+        }
+        out
+    }
+
+    /// Files in the set with unstaged modifications in their git repository.
+    pub fn dirty_files(&self) -> anyhow::Result<Vec<&Path>> {
+        let mut dirty = Vec::new();
+        let mut checked: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+        for edit in &self.edits {
+            let dir = edit.path.parent().unwrap_or(Path::new(".")).to_path_buf();
+            if !checked.contains_key(&dir) {
+                checked.insert(dir.clone(), crate::ci::unstaged_files(&dir)?);
+            }
+            let path = edit.path.canonicalize().unwrap_or_else(|_| edit.path.clone());
+            if checked[&dir].contains(&path) {
+                dirty.push(edit.path.as_path());
+            }
+        }
+        Ok(dirty)
+    }
+
+    /// Write every edit. A file that changed since its edit was computed
+    /// is left alone and reported.
+    pub fn apply(&self) -> anyhow::Result<()> {
+        for edit in &self.edits {
+            let current = fs::read_to_string(&edit.path)
+                .map_err(|e| anyhow::anyhow!("cannot read {}: {}", edit.path.display(), e))?;
+            if current != edit.original {
+                anyhow::bail!(
+                    "{} changed since the patch was computed; run again",
+                    edit.path.display()
+                );
+            }
+            write_atomic(&edit.path, &edit.modified)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_render_keeps_line_endings_and_final_newline() {
+        let base = Path::new("/repo");
+        let mut patch = PatchSet::new();
+        patch.push(
+            "/repo/src/a.go",
+            "package a\r\nfunc A() {}\r\n".to_string(),
+            "package a\r\n// note\r\nfunc A() {}\r\n".to_string(),
+        );
+        patch.push("/repo/b.py", "x = 1".to_string(), "# note\nx = 1".to_string());
+        patch.push("/repo/same.py", "x\n".to_string(), "x\n".to_string());
+        assert_eq!(patch.edits().len(), 2);
+
+        let diff = patch.render(base, false);
+        assert_eq!(
+            diff,
+            "--- a/src/a.go\n+++ b/src/a.go\n@@ -1,2 +1,3 @@\n package a\r\n+// note\r\n func A() {}\r\n\
+             --- a/b.py\n+++ b/b.py\n@@ -1 +1,2 @@\n+# note\n x = 1\n\\ No newline at end of file\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_is_atomic_and_checks_original() {
+        let temp = TempDir::new().unwrap();
+        let file = temp.path().join("a.sh");
+        fs::write(&file, "echo a\n").unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&file, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+
+        let mut patch = PatchSet::new();
+        patch.push(&file, "echo a\n".to_string(), "# note\necho a\n".to_string());
+        assert!(patch.dirty_files().unwrap().is_empty());
+        patch.apply().unwrap();
+        assert_eq!(fs::read_to_string(&file).unwrap(), "# note\necho a\n");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&file).unwrap().permissions().mode() & 0o777, 0o755);
+        }
+        let leftovers: Vec<_> = fs::read_dir(temp.path()).unwrap().collect();
+        assert_eq!(leftovers.len(), 1);
+
+        // The file no longer matches the original the edit was computed from
+        let err = patch.apply().unwrap_err().to_string();
+        assert!(err.contains("changed since the patch was computed"), "{}", err);
+    }
+}
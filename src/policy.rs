@@ -0,0 +1,256 @@
+//! Remote contracts: organization policy bundles fetched over HTTPS.
+//!
+//! A platform team publishes one contract and every repository builds on it
+//! with `extends: {url: ..., sha256: ...}`, or runs it directly with
+//! `--contract <url> --contract-sha256 <hash>`. The pin is mandatory: a
+//! compromised or misconfigured policy server cannot change the gates a
+//! repository runs under without the repository changing its pin.
+//!
+//! Fetched contracts are cached in the user cache directory under their hash.
+//! Since the content is pinned, a cached copy never goes stale, and once a
+//! contract has been fetched, runs work offline.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+
+use crate::cache_file;
+
+/// Fetch timeout when the contract does not set one.
+pub const DEFAULT_FETCH_TIMEOUT_MS: u64 = 10_000;
+
+/// Redirects followed before a fetch fails.
+const MAX_REDIRECTS: usize = 5;
+
+/// Largest contract accepted from a server.
+const MAX_CONTRACT_BYTES: usize = 1024 * 1024;
+
+/// A remote contract, pinned by the SHA-256 of its content.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct RemoteContract {
+    /// HTTPS URL of the contract.
+    pub url: String,
+    /// Hex SHA-256 of the contract as served.
+    pub sha256: String,
+    /// Fetch timeout in milliseconds (default: 10000)
+    #[serde(default)]
+    pub timeout_ms: Option<u64>,
+}
+
+impl RemoteContract {
+    /// Returns the fetch timeout (defaults to 10 seconds).
+    pub fn timeout(&self) -> Duration {
+        Duration::from_millis(self.timeout_ms.unwrap_or(DEFAULT_FETCH_TIMEOUT_MS))
+    }
+
+    /// The pin, lowercased, once it is checked to be a SHA-256 hex digest.
+    fn pin(&self) -> anyhow::Result<String> {
+        let pin = self.sha256.trim().to_ascii_lowercase();
+        if pin.len() != 64 || !pin.bytes().all(|b| b.is_ascii_hexdigit()) {
+            anyhow::bail!(
+                "contract {} has an invalid sha256 pin {:?}: expected 64 hex digits",
+                self.url,
+                self.sha256
+            );
+        }
+        Ok(pin)
+    }
+}
+
+/// Whether a contract location is a URL rather than a file path.
+pub fn is_remote(location: &str) -> bool {
+    location.starts_with("https://") || location.starts_with("http://")
+}
+
+/// Whether documents may be fetched from `url`: HTTPS, or plain HTTP to
+/// the local machine.
+fn is_allowed_url(url: &reqwest::Url) -> bool {
+    match url.scheme() {
+        "https" => true,
+        "http" => matches!(url.host_str(), Some("localhost" | "127.0.0.1" | "[::1]")),
+        _ => false,
+    }
+}
+
+/// Hex SHA-256 of `data`.
+pub fn sha256_hex(data: &[u8]) -> String {
+    ring::digest::digest(&ring::digest::SHA256, data)
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Directory fetched contracts are cached in, if one could be determined.
+pub fn cache_dir() -> Option<PathBuf> {
+    ProjectDirs::from("", "", "hollowcheck").map(|dirs| dirs.cache_dir().join("contracts"))
+}
+
+/// Fetch a remote contract, from the cache when it holds the pinned content.
+pub fn fetch_contract(remote: &RemoteContract) -> anyhow::Result<String> {
+    fetch_contract_cached(remote, cache_dir().as_deref())
+}
+
+/// Fetch a remote contract, caching it in `cache_dir`.
+///
+/// Non-2xx responses, more than five redirects, redirects off HTTPS, and
+/// content that does not match the pin are errors.
+pub fn fetch_contract_cached(remote: &RemoteContract, cache_dir: Option<&Path>) -> anyhow::Result<String> {
+    let pin = remote.pin()?;
+    let cache_path = cache_dir.map(|dir| dir.join(format!("{}.yaml", pin)));
+
+    if let Some(content) = cache_path.as_deref().and_then(cache_file::read_verified) {
+        if sha256_hex(content.as_bytes()) == pin {
+            return Ok(content);
+        }
+    }
+
+    let body = download(&remote.url, remote.timeout(), MAX_CONTRACT_BYTES, "contracts").map_err(|e| {
+        anyhow::anyhow!("cannot fetch contract {}: {} (no cached copy for its sha256 pin)", remote.url, e)
+    })?;
+    let actual = sha256_hex(&body);
+    if actual != pin {
+        anyhow::bail!(
+            "contract {} does not match its pin: sha256 is {}, expected {}",
+            remote.url,
+            actual,
+            pin
+        );
+    }
+    let content = String::from_utf8(body)
+        .map_err(|_| anyhow::anyhow!("contract {} is not valid UTF-8", remote.url))?;
+
+    // A failed cache write only costs a fetch next time
+    if let Some(path) = cache_path {
+        let _ = cache_file::write_atomic(&path, &content);
+    }
+    Ok(content)
+}
+
+/// Fetch `url` over HTTPS, or plain HTTP to the local machine.
+///
+/// `what` names the fetched documents in errors ("contracts"). Non-2xx
+/// responses, more than five redirects, redirects off HTTPS, and bodies
+/// larger than `max_bytes` are errors.
+pub(crate) fn download(url: &str, timeout: Duration, max_bytes: usize, what: &str) -> anyhow::Result<Vec<u8>> {
+    let url = reqwest::Url::parse(url).map_err(|e| anyhow::anyhow!("invalid URL: {}", e))?;
+    if !is_allowed_url(&url) {
+        anyhow::bail!("{} must be fetched over https", what);
+    }
+
+    let redirects = reqwest::redirect::Policy::custom(|attempt| {
+        if attempt.previous().len() > MAX_REDIRECTS {
+            attempt.error(format!("more than {} redirects", MAX_REDIRECTS))
+        } else if !is_allowed_url(attempt.url()) {
+            attempt.error("redirected off https")
+        } else {
+            attempt.follow()
+        }
+    });
+    let client = reqwest::Client::builder()
+        .redirect(redirects)
+        .timeout(timeout)
+        .user_agent(concat!("hollowcheck/", env!("CARGO_PKG_VERSION")))
+        .build()?;
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(async {
+        let response = client.get(url).send().await?;
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("HTTP {}", status);
+        }
+        let body = response.bytes().await?;
+        if body.len() > max_bytes {
+            anyhow::bail!("response is larger than {} bytes", max_bytes);
+        }
+        Ok(body.to_vec())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    const POLICY: &str = "version: \"1.0\"\nname: base-v3\nthreshold: 20\n";
+
+    /// Serve `body` with `status` on a local port, counting requests.
+    fn serve(status: &'static str, body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hollowcheck/base-v3.yaml", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                    line.clear();
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+        (url, requests)
+    }
+
+    fn remote(url: &str, sha256: &str) -> RemoteContract {
+        RemoteContract {
+            url: url.to_string(),
+            sha256: sha256.to_string(),
+            timeout_ms: Some(5_000),
+        }
+    }
+
+    #[test]
+    fn test_fetch_then_cache_hit() {
+        let (url, requests) = serve("200 OK", POLICY);
+        let cache = TempDir::new().unwrap();
+        let pinned = remote(&url, &sha256_hex(POLICY.as_bytes()).to_uppercase());
+
+        assert_eq!(fetch_contract_cached(&pinned, Some(cache.path())).unwrap(), POLICY);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Served from the cache, even with the server gone
+        let offline = remote("https://policies.invalid/base-v3.yaml", &pinned.sha256);
+        assert_eq!(fetch_contract_cached(&offline, Some(cache.path())).unwrap(), POLICY);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_hash_mismatch_is_rejected() {
+        let (url, _) = serve("200 OK", POLICY);
+        let cache = TempDir::new().unwrap();
+        let pin = sha256_hex(b"threshold: 100\n");
+
+        let err = fetch_contract_cached(&remote(&url, &pin), Some(cache.path())).unwrap_err();
+        assert!(err.to_string().contains("does not match its pin"), "{}", err);
+        assert_eq!(std::fs::read_dir(cache.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_fetch_errors() {
+        let (url, _) = serve("404 Not Found", "missing");
+        let pin = sha256_hex(POLICY.as_bytes());
+        let err = fetch_contract_cached(&remote(&url, &pin), None).unwrap_err();
+        assert!(err.to_string().contains("HTTP 404 Not Found"), "{}", err);
+
+        let err = fetch_contract_cached(&remote("http://policies.example/base.yaml", &pin), None).unwrap_err();
+        assert!(err.to_string().contains("must be fetched over https"), "{}", err);
+
+        let err = fetch_contract_cached(&remote("https://policies.example/base.yaml", "abc"), None).unwrap_err();
+        assert!(err.to_string().contains("invalid sha256 pin"), "{}", err);
+    }
+}
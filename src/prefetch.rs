@@ -0,0 +1,293 @@
+//! Cache warming for `hollowcheck prefetch`.
+//!
+//! Every shard of a CI matrix that lints the same tree looks up the same
+//! packages and queries the same runtimes. `prefetch` does that work once,
+//! before the shards start: it extracts the tree's imports, looks up every
+//! package the dependency check would look up, and loads the stdlib module
+//! list of each language it found. Results land in the registry and stdlib
+//! disk caches; no detection runs. Lint runs that share the cache directory
+//! then answer from cache until `cache_ttl_hours` passes.
+//!
+//! The package set is the dependency check's: imports covered by the
+//! manifest or the allowlist, Go imports (checked against go.mod only), and
+//! packages of disabled registries are not looked up. A lookup that fails
+//! is reported but not cached, so the lint run asks again.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as FmtWrite;
+use std::path::{Path, PathBuf};
+
+use colored::*;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::analysis_extension;
+use crate::contract::DependencyVerificationConfig;
+use crate::detect::{extract_imports, stdlib_cache_dir, warm_stdlib, DependencyValidator, ManifestType, StdlibLanguage};
+use crate::registry::{PackageStatus, RegistryType};
+
+/// Concurrent registry lookups, as in the dependency check.
+const CONCURRENT_LOOKUPS: usize = 50;
+
+/// A stdlib module list loaded into the cache.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct StdlibWarm {
+    pub language: String,
+    pub modules: usize,
+    /// `cached` when the disk cache was already fresh, otherwise the runtime
+    /// version queried, or `embedded` when the runtime is not installed
+    pub source: String,
+}
+
+/// Lookups made against one registry.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct RegistryWarm {
+    pub registry: String,
+    /// Unique packages the dependency check would look up
+    pub packages: usize,
+    /// Packages whose status was already cached
+    pub cached: usize,
+    /// Packages fetched and found
+    pub exists: usize,
+    /// Packages fetched and not found
+    pub not_found: usize,
+    /// Lookups that failed (timeout, rate limit, network), left uncached
+    pub failed: usize,
+}
+
+/// Everything `hollowcheck prefetch` warmed.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PrefetchReport {
+    pub files: usize,
+    pub stdlib: Vec<StdlibWarm>,
+    pub stdlib_cache: String,
+    pub registries: Vec<RegistryWarm>,
+    pub registry_cache: Option<String>,
+    /// Why registry lookups were skipped, if they were
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+}
+
+impl PrefetchReport {
+    /// Whether every lookup the dependency check needs is now cached.
+    pub fn complete(&self) -> bool {
+        self.skipped.is_none() && self.registries.iter().all(|r| r.failed == 0)
+    }
+}
+
+fn stdlib_language(registry: RegistryType) -> StdlibLanguage {
+    match registry {
+        RegistryType::PyPI => StdlibLanguage::Python,
+        RegistryType::Npm => StdlibLanguage::JavaScript,
+        RegistryType::Crates => StdlibLanguage::Rust,
+        RegistryType::Go => StdlibLanguage::Go,
+    }
+}
+
+fn language_name(registry: RegistryType) -> &'static str {
+    match registry {
+        RegistryType::PyPI => "python",
+        RegistryType::Npm => "javascript",
+        RegistryType::Crates => "rust",
+        RegistryType::Go => "go",
+    }
+}
+
+/// Warm the stdlib and registry caches for the imports of `files`.
+///
+/// Stdlib lists are loaded first, so import extraction filters stdlib
+/// modules against the lists the lint run will use.
+pub fn run_prefetch(
+    base_dir: &Path,
+    files: &[PathBuf],
+    config: &DependencyVerificationConfig,
+) -> anyhow::Result<PrefetchReport> {
+    crate::init();
+
+    let languages: BTreeSet<RegistryType> = files
+        .iter()
+        .filter_map(|f| RegistryType::from_extension(analysis_extension(f)))
+        .collect();
+    let stdlib = languages
+        .iter()
+        .map(|&registry| {
+            let (modules, source) = warm_stdlib(stdlib_language(registry));
+            StdlibWarm {
+                language: language_name(registry).to_string(),
+                modules,
+                source,
+            }
+        })
+        .collect();
+
+    let mut report = PrefetchReport {
+        files: files.len(),
+        stdlib,
+        stdlib_cache: stdlib_cache_dir().display().to_string(),
+        registries: Vec::new(),
+        registry_cache: None,
+        skipped: None,
+    };
+    if !config.is_enabled() {
+        report.skipped = Some("dependency_verification is not enabled in the contract".to_string());
+        return Ok(report);
+    }
+
+    let validator = DependencyValidator::new(ManifestType::Auto, base_dir, config)?;
+    let client = validator.registry_client();
+    report.registry_cache = client.cache_dir().map(|d| d.display().to_string());
+
+    // The packages the dependency check would send to a registry
+    let mut packages: BTreeSet<(RegistryType, String)> = BTreeSet::new();
+    for file in files {
+        let Ok(imports) = extract_imports(file) else {
+            continue;
+        };
+        for import in imports {
+            if import.registry == RegistryType::Go
+                || !client.is_enabled(import.registry)
+                || client.is_allowlisted(&import.name)
+                || validator.is_valid_import(&import.name, file)
+            {
+                continue;
+            }
+            let package = validator.package_for(&import.name, import.registry).unwrap_or(&import.name);
+            if !client.is_allowlisted(package) {
+                packages.insert((import.registry, package.to_string()));
+            }
+        }
+    }
+
+    let max_packages = config.max_packages();
+    if packages.len() > max_packages {
+        report.skipped = Some(format!(
+            "{} unique packages need a lookup, more than dependency_verification.max_packages ({}); the lint run skips them too",
+            packages.len(),
+            max_packages
+        ));
+        return Ok(report);
+    }
+
+    let runtime = tokio::runtime::Runtime::new()?;
+    let results: Vec<(RegistryType, bool, Option<PackageStatus>)> = runtime.block_on(async {
+        use futures::stream::{self, StreamExt};
+
+        stream::iter(packages)
+            .map(|(registry, package)| async move {
+                let cached = client.cached_status(registry, &package).is_some();
+                (registry, cached, client.check_package(registry, &package).await.ok())
+            })
+            .buffer_unordered(CONCURRENT_LOOKUPS)
+            .collect()
+            .await
+    });
+
+    let mut registries: BTreeMap<&str, RegistryWarm> = BTreeMap::new();
+    for (registry, cached, status) in results {
+        let warm = registries.entry(registry.as_str()).or_insert_with(|| RegistryWarm {
+            registry: registry.as_str().to_string(),
+            ..Default::default()
+        });
+        warm.packages += 1;
+        match status {
+            _ if cached => warm.cached += 1,
+            Some(PackageStatus::Exists) => warm.exists += 1,
+            Some(PackageStatus::NotFound) => warm.not_found += 1,
+            Some(PackageStatus::Unknown(_)) | None => warm.failed += 1,
+        }
+    }
+    report.registries = registries.into_values().collect();
+
+    Ok(report)
+}
+
+/// Render a prefetch report as JSON.
+pub fn render_prefetch_json(report: &PrefetchReport) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(report)?)
+}
+
+/// Render a prefetch report as tables; colors follow the `colored` crate's settings.
+pub fn render_prefetch_pretty(report: &PrefetchReport) -> String {
+    let mut buf = String::new();
+    let mark = |ok: bool| if ok { "✓".green() } else { "✗".red() };
+
+    writeln!(buf).unwrap();
+    writeln!(buf, "  {} {} files", "Prefetched imports of".bold(), report.files).unwrap();
+
+    writeln!(buf).unwrap();
+    writeln!(buf, "  {} {}", "Stdlib:".bold(), report.stdlib_cache.dimmed()).unwrap();
+    if report.stdlib.is_empty() {
+        writeln!(buf, "    {}", "(no files with a stdlib list)".dimmed()).unwrap();
+    }
+    for s in &report.stdlib {
+        writeln!(buf, "    {} {:<12}{:>5} modules  {}", mark(true), s.language, s.modules, s.source.dimmed()).unwrap();
+    }
+
+    writeln!(buf).unwrap();
+    let cache = report.registry_cache.as_deref().unwrap_or("no disk cache");
+    writeln!(buf, "  {} {}", "Registries:".bold(), cache.dimmed()).unwrap();
+    if let Some(reason) = &report.skipped {
+        writeln!(buf, "    {}", format!("skipped: {}", reason).yellow()).unwrap();
+    } else if report.registries.is_empty() {
+        writeln!(buf, "    {}", "(no packages to look up)".dimmed()).unwrap();
+    }
+    for r in &report.registries {
+        let mut detail = format!(
+            "{} packages: {} cached, {} found, {} not found",
+            r.packages, r.cached, r.exists, r.not_found
+        );
+        if r.failed > 0 {
+            write!(detail, ", {} failed", r.failed).unwrap();
+        }
+        writeln!(buf, "    {} {:<12}{}", mark(r.failed == 0), r.registry, detail).unwrap();
+    }
+    writeln!(buf).unwrap();
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contract::Contract;
+    use tempfile::TempDir;
+
+    fn default_config() -> DependencyVerificationConfig {
+        Contract::default_contract().dependency_verification.unwrap()
+    }
+
+    #[test]
+    fn test_prefetch_skips_covered_imports() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("go.mod"), "module example.com/app\n\ngo 1.22\n").unwrap();
+        let main_go = temp.path().join("main.go");
+        std::fs::write(&main_go, "package main\n\nimport (\n\t\"fmt\"\n\t\"github.com/acme/missing\"\n)\n").unwrap();
+        let app_py = temp.path().join("app.py");
+        std::fs::write(&app_py, "import os\nimport requests\n").unwrap();
+
+        let mut config = default_config();
+        config.allowlist.push("requests".to_string());
+        let report = run_prefetch(temp.path(), &[main_go, app_py], &config).unwrap();
+
+        let languages: Vec<_> = report.stdlib.iter().map(|s| s.language.as_str()).collect();
+        assert_eq!(languages, vec!["python", "go"]);
+        assert!(report.stdlib.iter().all(|s| s.modules > 0));
+        // Go imports are checked against go.mod and `requests` is allowlisted
+        assert!(report.registries.is_empty());
+        assert!(report.complete());
+    }
+
+    #[test]
+    fn test_prefetch_respects_max_packages() {
+        let temp = TempDir::new().unwrap();
+        let app_py = temp.path().join("app.py");
+        std::fs::write(&app_py, "import left_pad_clone\nimport another_missing_pkg\n").unwrap();
+
+        let mut config = default_config();
+        config.max_packages = Some(1);
+        let report = run_prefetch(temp.path(), &[app_py], &config).unwrap();
+
+        assert!(report.registries.is_empty());
+        assert!(report.skipped.as_deref().unwrap().starts_with("2 unique packages"));
+        assert!(!report.complete());
+    }
+}
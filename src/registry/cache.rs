@@ -5,6 +5,7 @@
 //! repeated network calls. Cache is stored in ~/.cache/hollowcheck/registry/
 
 use super::{PackageStatus, RegistryType};
+use crate::cache_file;
 use directories::ProjectDirs;
 use std::collections::HashMap;
 use std::fs;
@@ -33,7 +34,11 @@ impl RegistryCache {
     pub fn new(ttl_hours: u32) -> Self {
         let cache_dir =
             ProjectDirs::from("", "", "hollowcheck").map(|dirs| dirs.cache_dir().join("registry"));
+        Self::with_cache_dir(cache_dir, ttl_hours)
+    }
 
+    /// Create a registry cache backed by a specific directory.
+    pub(super) fn with_cache_dir(cache_dir: Option<PathBuf>, ttl_hours: u32) -> Self {
         // Try to create cache directory
         if let Some(ref dir) = cache_dir {
             let _ = fs::create_dir_all(dir);
@@ -46,6 +51,11 @@ impl RegistryCache {
         }
     }
 
+    /// Directory cache files are written to, if one could be determined.
+    pub fn cache_dir(&self) -> Option<&std::path::Path> {
+        self.cache_dir.as_deref()
+    }
+
     /// Generate a cache key for a registry/package pair.
     fn cache_key(registry: RegistryType, package: &str) -> String {
         format!("{}:{}", registry.as_str(), package)
@@ -99,17 +109,23 @@ impl RegistryCache {
     }
 
     /// Read from file cache.
+    ///
+    /// Files that fail verification (truncated, or written by an older
+    /// version without a footer) are treated as a miss.
     fn read_file_cache(&self, key: &str) -> Option<CacheEntry> {
         let path = self.cache_file_path(key)?;
-        let content = fs::read_to_string(path).ok()?;
+        let content = cache_file::read_verified(&path)?;
         parse_cache_entry(&content)
     }
 
     /// Write to file cache.
+    ///
+    /// The write is atomic, so concurrent hollowcheck processes sharing the
+    /// cache directory never observe a partial entry.
     fn write_file_cache(&self, key: &str, entry: &CacheEntry) {
         if let Some(path) = self.cache_file_path(key) {
             let content = format_cache_entry(entry);
-            let _ = fs::write(path, content);
+            let _ = cache_file::write_atomic(&path, &content);
         }
     }
 
@@ -217,4 +233,40 @@ mod tests {
         let result = cache.get(RegistryType::PyPI, "flask");
         assert_eq!(result, None);
     }
+
+    #[test]
+    fn test_file_cache_concurrent_set_get() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = Some(temp.path().to_path_buf());
+
+        std::thread::scope(|scope| {
+            for i in 0..8 {
+                let dir = dir.clone();
+                scope.spawn(move || {
+                    // Separate instances so every lookup goes to disk
+                    let cache = RegistryCache::with_cache_dir(dir, 24);
+                    for _ in 0..50 {
+                        let status = if i % 2 == 0 {
+                            PackageStatus::Exists
+                        } else {
+                            PackageStatus::Unknown(format!("timeout from worker {}", i))
+                        };
+                        cache.set(RegistryType::Npm, "left-pad", status);
+
+                        let reader = RegistryCache::with_cache_dir(
+                            Some(cache.cache_dir.clone().unwrap()),
+                            24,
+                        );
+                        match reader.get(RegistryType::Npm, "left-pad") {
+                            Some(PackageStatus::Exists) => {}
+                            Some(PackageStatus::Unknown(msg)) => {
+                                assert!(msg.starts_with("timeout from worker "), "{}", msg)
+                            }
+                            other => panic!("corrupted cache read: {:?}", other),
+                        }
+                    }
+                });
+            }
+        });
+    }
 }
@@ -2,34 +2,28 @@
 //!
 //! Checks package existence via: GET https://crates.io/api/v1/crates/{crate}
 
-use super::{PackageStatus, RegistryError};
+use super::{response_status, PackageStatus, RegistryError};
 use reqwest::Client;
 use std::time::Duration;
 
 /// Check if a crate exists on crates.io.
 pub async fn check(
     client: &Client,
+    base: &str,
     crate_name: &str,
     timeout: Duration,
 ) -> Result<PackageStatus, RegistryError> {
     // Normalize crate name (crates.io allows both - and _, treats them the same)
     let normalized = normalize_crate_name(crate_name);
-    let url = format!("https://crates.io/api/v1/crates/{}", normalized);
+    let url = format!("{}{}", base, normalized);
 
     let response = client
         .get(&url)
         .timeout(timeout)
         .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                RegistryError::Timeout
-            } else {
-                RegistryError::Network(e)
-            }
-        })?;
+        .await?;
 
-    match response.status().as_u16() {
+    match response_status(response).await {
         200 => Ok(PackageStatus::Exists),
         404 => Ok(PackageStatus::NotFound),
         429 => Err(RegistryError::RateLimited),
@@ -3,35 +3,29 @@
 //! Checks package existence via: GET https://proxy.golang.org/{module}/@v/list
 //! Returns 200 with version list if module exists, 404 if not.
 
-use super::{PackageStatus, RegistryError};
+use super::{response_status, PackageStatus, RegistryError};
 use reqwest::Client;
 use std::time::Duration;
 
 /// Check if a Go module exists.
 pub async fn check(
     client: &Client,
+    base: &str,
     module: &str,
     timeout: Duration,
 ) -> Result<PackageStatus, RegistryError> {
     // Go modules use case-sensitive paths but proxy requires lowercase encoding
     // for uppercase letters (e.g., GitHub -> !github)
     let encoded = encode_module_path(module);
-    let url = format!("https://proxy.golang.org/{}/@v/list", encoded);
+    let url = format!("{}{}/@v/list", base, encoded);
 
     let response = client
         .get(&url)
         .timeout(timeout)
         .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                RegistryError::Timeout
-            } else {
-                RegistryError::Network(e)
-            }
-        })?;
+        .await?;
 
-    match response.status().as_u16() {
+    match response_status(response).await {
         200 => Ok(PackageStatus::Exists),
         404 | 410 => Ok(PackageStatus::NotFound), // 410 Gone for retracted modules
         429 => Err(RegistryError::RateLimited),
@@ -5,6 +5,19 @@
 //! - npm (Node Package Manager)
 //! - crates.io (Rust crates)
 //! - Go proxy (Go modules)
+//!
+//! All lookups share one HTTP client, so connections to a registry are pooled
+//! and kept alive across packages; small responses are read to the end (see
+//! [`response_status`]) so their connections can be reused. Each registry
+//! host also gets a fixed number of request slots: however many packages are
+//! checked concurrently, at most [`MAX_CONNECTIONS_PER_HOST`] requests are in
+//! flight to one host, which keeps large dependency sets from exhausting
+//! ephemeral ports or file descriptors.
+//!
+//! A [`RegistryClient`] is cheap to clone, and clones share the connection
+//! pool, request slots, cache, and in-flight lookups, so an embedder can run
+//! many analyses against one client. Concurrent lookups of the same package
+//! share one request; see [`RegistryClient::check_package`].
 
 mod cache;
 mod crates;
@@ -15,14 +28,57 @@ mod pypi;
 pub use cache::RegistryCache;
 
 use crate::contract::{DependencyVerificationConfig, RegistryConfig};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::Duration;
 use thiserror::Error;
+use tokio::sync::Semaphore;
+
+/// Most requests in flight to one registry host; also the idle pool size per host.
+pub const MAX_CONNECTIONS_PER_HOST: usize = 16;
+
+/// How long an idle pooled connection is kept for reuse.
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// TCP keep-alive interval for pooled connections.
+const TCP_KEEPALIVE: Duration = Duration::from_secs(60);
+
+/// Largest response body read just to return its connection to the pool.
+const MAX_DRAINED_BODY: u64 = 64 * 1024;
+
+/// Read a response's status, then consume its body so the connection goes
+/// back to the pool.
+///
+/// A response dropped with its body unread closes the connection, so every
+/// lookup would open a new one. Bodies are read up to [`MAX_DRAINED_BODY`],
+/// whether or not their length is known; a larger body is not worth
+/// downloading, so reading stops there and its connection closes.
+pub(crate) async fn response_status(mut response: reqwest::Response) -> u16 {
+    let status = response.status().as_u16();
+    if response.content_length().is_some_and(|len| len > MAX_DRAINED_BODY) {
+        return status;
+    }
+    let mut drained = 0;
+    while let Ok(Some(chunk)) = response.chunk().await {
+        drained += chunk.len() as u64;
+        if drained > MAX_DRAINED_BODY {
+            break;
+        }
+    }
+    status
+}
 
 /// Errors that can occur during registry checks.
-#[derive(Error, Debug)]
+///
+/// Errors are cloneable so one failed lookup can be handed to every caller
+/// waiting on it.
+#[derive(Error, Debug, Clone)]
 pub enum RegistryError {
     #[error("network error: {0}")]
-    Network(#[from] reqwest::Error),
+    Network(Arc<reqwest::Error>),
     #[error("request timed out")]
     Timeout,
     #[error("rate limited by registry")]
@@ -31,6 +87,16 @@ pub enum RegistryError {
     Unavailable(String),
 }
 
+impl From<reqwest::Error> for RegistryError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            RegistryError::Timeout
+        } else {
+            RegistryError::Network(Arc::new(e))
+        }
+    }
+}
+
 /// Result of checking if a package exists.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum PackageStatus {
@@ -43,7 +109,7 @@ pub enum PackageStatus {
 }
 
 /// The type of registry to check.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum RegistryType {
     PyPI,
     Npm,
@@ -52,6 +118,14 @@ pub enum RegistryType {
 }
 
 impl RegistryType {
+    /// Every registry type.
+    pub const ALL: [RegistryType; 4] = [
+        RegistryType::PyPI,
+        RegistryType::Npm,
+        RegistryType::Crates,
+        RegistryType::Go,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             RegistryType::PyPI => "pypi",
@@ -71,6 +145,26 @@ impl RegistryType {
         }
     }
 
+    /// Base URL package lookups are made under.
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            RegistryType::PyPI => "https://pypi.org/pypi/",
+            RegistryType::Npm => "https://registry.npmjs.org/",
+            RegistryType::Crates => "https://crates.io/api/v1/crates/",
+            RegistryType::Go => "https://proxy.golang.org/",
+        }
+    }
+
+    /// URL probed by `hollowcheck doctor` to check the registry is reachable.
+    pub fn endpoint(&self) -> &'static str {
+        match self {
+            RegistryType::PyPI => "https://pypi.org/simple/",
+            RegistryType::Npm => "https://registry.npmjs.org/",
+            RegistryType::Crates => "https://index.crates.io/config.json",
+            RegistryType::Go => "https://proxy.golang.org/",
+        }
+    }
+
     /// Determine registry type from file extension.
     pub fn from_extension(ext: &str) -> Option<Self> {
         match ext {
@@ -89,126 +183,250 @@ impl std::fmt::Display for RegistryType {
     }
 }
 
+/// A registry lookup that every caller asking for the same package awaits.
+type InFlight = Shared<BoxFuture<'static, Result<PackageStatus, RegistryError>>>;
+
 /// Registry client that can check multiple registries.
+///
+/// Cloning is cheap: clones share one connection pool, cache, and set of
+/// in-flight lookups.
+#[derive(Clone)]
 pub struct RegistryClient {
+    inner: Arc<ClientInner>,
+}
+
+/// State shared by every clone of a [`RegistryClient`].
+struct ClientInner {
     http: reqwest::Client,
+    /// Request slots per registry host, indexed by `RegistryType`
+    host_permits: [Semaphore; 4],
+    /// Lookup base URLs, indexed by `RegistryType`
+    base_urls: [String; 4],
     cache: RegistryCache,
     config: DependencyVerificationConfig,
+    /// Lookups with a request under way, by registry and package
+    in_flight: Mutex<HashMap<(RegistryType, String), InFlight>>,
     /// Track cache statistics
-    cache_hits: std::sync::atomic::AtomicUsize,
-    cache_misses: std::sync::atomic::AtomicUsize,
+    cache_hits: AtomicUsize,
+    cache_misses: AtomicUsize,
 }
 
 impl RegistryClient {
     /// Create a new registry client with the given configuration.
     pub fn new(config: DependencyVerificationConfig) -> Self {
+        let cache = RegistryCache::new(config.cache_ttl_hours);
+        Self::from_parts(config, cache, RegistryType::ALL.map(|r| r.base_url().to_string()))
+    }
+
+    /// Create a client with its own cache and lookup base URLs.
+    fn from_parts(config: DependencyVerificationConfig, cache: RegistryCache, base_urls: [String; 4]) -> Self {
         let http = reqwest::Client::builder()
             .user_agent("hollowcheck/0.1.0")
+            .pool_max_idle_per_host(MAX_CONNECTIONS_PER_HOST)
+            .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+            .tcp_keepalive(TCP_KEEPALIVE)
             .build()
             .expect("failed to create HTTP client");
 
-        let cache = RegistryCache::new(config.cache_ttl_hours);
-
         Self {
-            http,
-            cache,
-            config,
-            cache_hits: std::sync::atomic::AtomicUsize::new(0),
-            cache_misses: std::sync::atomic::AtomicUsize::new(0),
+            inner: Arc::new(ClientInner {
+                http,
+                host_permits: RegistryType::ALL.map(|_| Semaphore::new(MAX_CONNECTIONS_PER_HOST)),
+                base_urls,
+                cache,
+                config,
+                in_flight: Mutex::new(HashMap::new()),
+                cache_hits: AtomicUsize::new(0),
+                cache_misses: AtomicUsize::new(0),
+            }),
         }
     }
 
+    /// A client that sends every lookup to `url` and caches in memory only.
+    #[cfg(test)]
+    pub(crate) fn with_base_url(url: &str) -> Self {
+        let config = DependencyVerificationConfig {
+            cache_ttl_hours: 24,
+            ..Default::default()
+        };
+        let cache = RegistryCache::with_cache_dir(None, config.cache_ttl_hours);
+        Self::from_parts(config, cache, RegistryType::ALL.map(|_| url.to_string()))
+    }
+
     /// Get cache statistics (hits, misses).
+    ///
+    /// A lookup that joins another caller's request counts as a hit; each
+    /// request sent counts as one miss.
     pub fn cache_stats(&self) -> (usize, usize) {
         (
-            self.cache_hits.load(std::sync::atomic::Ordering::Relaxed),
-            self.cache_misses.load(std::sync::atomic::Ordering::Relaxed),
+            self.inner.cache_hits.load(Ordering::Relaxed),
+            self.inner.cache_misses.load(Ordering::Relaxed),
         )
     }
 
     /// Check if a package exists in the specified registry.
+    ///
+    /// Concurrent calls for the same package share one request: the first
+    /// caller starts it, later callers await the same lookup, and its result
+    /// is cached once and handed to all of them. Only definite answers are
+    /// cached; an error or an unknown status leaves the next lookup to ask
+    /// the registry again.
     pub async fn check_package(
         &self,
         registry: RegistryType,
         package: &str,
     ) -> Result<PackageStatus, RegistryError> {
         // Check if this registry is enabled
-        let reg_config = self.get_registry_config(registry);
+        let reg_config = self.inner.get_registry_config(registry);
         if !reg_config.enabled {
             return Ok(PackageStatus::Unknown("registry disabled".to_string()));
         }
 
         // Check cache first
-        if let Some(cached) = self.cache.get(registry, package) {
-            self.cache_hits
-                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        if let Some(cached) = self.inner.cache.get(registry, package) {
+            self.inner.cache_hits.fetch_add(1, Ordering::Relaxed);
             return Ok(cached);
         }
 
-        self.cache_misses
-            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        self.join_lookup(registry, package).await
+    }
 
-        // Make the request
-        let timeout = Duration::from_millis(reg_config.timeout_ms);
-        let status = match registry {
-            RegistryType::PyPI => pypi::check(&self.http, package, timeout).await,
-            RegistryType::Npm => npm::check(&self.http, package, timeout).await,
-            RegistryType::Crates => crates::check(&self.http, package, timeout).await,
-            RegistryType::Go => go::check(&self.http, package, timeout).await,
-        };
+    /// Await the in-flight lookup of a package, starting one if there is none.
+    fn join_lookup(&self, registry: RegistryType, package: &str) -> InFlight {
+        let key = (registry, package.to_string());
+        let mut in_flight = self.inner.in_flight.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(lookup) = in_flight.get(&key) {
+            self.inner.cache_hits.fetch_add(1, Ordering::Relaxed);
+            return lookup.clone();
+        }
 
-        // Cache the result (both positive and negative)
-        match &status {
-            Ok(PackageStatus::Exists) | Ok(PackageStatus::NotFound) => {
-                self.cache
-                    .set(registry, package, status.as_ref().unwrap().clone());
-            }
-            _ => {}
+        // A lookup that finished since the cache was checked has cached its
+        // result before leaving the in-flight set
+        if let Some(cached) = self.inner.cache.get(registry, package) {
+            self.inner.cache_hits.fetch_add(1, Ordering::Relaxed);
+            return futures::future::ready(Ok(cached)).boxed().shared();
         }
 
-        status
+        self.inner.cache_misses.fetch_add(1, Ordering::Relaxed);
+        let inner = Arc::clone(&self.inner);
+        let lookup = async move {
+            let status = inner.fetch(registry, &key.1).await;
+            inner.in_flight.lock().unwrap_or_else(PoisonError::into_inner).remove(&key);
+            status
+        }
+        .boxed()
+        .shared();
+        in_flight.insert((registry, package.to_string()), lookup.clone());
+        lookup
     }
 
-    /// Get the configuration for a specific registry.
-    fn get_registry_config(&self, registry: RegistryType) -> &RegistryConfig {
-        match registry {
-            RegistryType::PyPI => &self.config.registries.pypi,
-            RegistryType::Npm => &self.config.registries.npm,
-            RegistryType::Crates => &self.config.registries.crates,
-            RegistryType::Go => &self.config.registries.go,
-        }
+    /// Send a HEAD request to a registry's endpoint, within its timeout.
+    ///
+    /// Returns the HTTP status; any response, even an error status, means
+    /// the registry is reachable.
+    pub async fn probe(&self, registry: RegistryType) -> Result<u16, RegistryError> {
+        let timeout = Duration::from_millis(self.inner.get_registry_config(registry).timeout_ms);
+        let request = self.inner.http.head(registry.endpoint()).timeout(timeout).send();
+        let response = self.inner.with_host_permit(registry, request).await??;
+        Ok(response.status().as_u16())
+    }
+
+    /// Whether lookups in a registry are enabled.
+    pub fn is_enabled(&self, registry: RegistryType) -> bool {
+        self.inner.get_registry_config(registry).enabled
+    }
+
+    /// Directory registry results are cached in, if one could be determined.
+    pub fn cache_dir(&self) -> Option<&std::path::Path> {
+        self.inner.cache.cache_dir()
     }
 
     /// Check if a package is in the allowlist.
     pub fn is_allowlisted(&self, package: &str) -> bool {
-        use globset::{Glob, GlobSetBuilder};
+        self.allowlist_match(package).is_some()
+    }
 
-        if self.config.allowlist.is_empty() {
-            return false;
-        }
+    /// The first allowlist pattern matching a package, if any.
+    pub fn allowlist_match(&self, package: &str) -> Option<&str> {
+        use globset::Glob;
 
-        // Build a glob set from the allowlist patterns
-        let mut builder = GlobSetBuilder::new();
-        for pattern in &self.config.allowlist {
-            if let Ok(glob) = Glob::new(pattern) {
-                builder.add(glob);
-            } else if pattern == package {
+        self.inner
+            .config
+            .allowlist
+            .iter()
+            .find(|pattern| match Glob::new(pattern) {
+                Ok(glob) => glob.compile_matcher().is_match(package),
                 // Exact match fallback for invalid glob patterns
-                return true;
-            }
-        }
+                Err(_) => *pattern == package,
+            })
+            .map(String::as_str)
+    }
 
-        if let Ok(glob_set) = builder.build() {
-            glob_set.is_match(package)
-        } else {
-            // Fallback to exact matching
-            self.config.allowlist.iter().any(|p| p == package)
-        }
+    /// Number of allowlist patterns, including auto-detected local packages.
+    pub fn allowlist_len(&self) -> usize {
+        self.inner.config.allowlist.len()
+    }
+
+    /// The cached status of a package, without counting a cache hit or querying.
+    pub fn cached_status(&self, registry: RegistryType, package: &str) -> Option<PackageStatus> {
+        self.inner.cache.get(registry, package)
     }
 
     /// Whether to fail on timeout errors.
     pub fn fail_on_timeout(&self) -> bool {
-        self.config.fail_on_timeout
+        self.inner.config.fail_on_timeout
+    }
+}
+
+impl ClientInner {
+    /// Ask a registry about a package, caching a definite answer.
+    async fn fetch(&self, registry: RegistryType, package: &str) -> Result<PackageStatus, RegistryError> {
+        let timeout = Duration::from_millis(self.get_registry_config(registry).timeout_ms);
+        let base = &self.base_urls[registry as usize];
+        let status = self
+            .with_host_permit(registry, async {
+                match registry {
+                    RegistryType::PyPI => pypi::check(&self.http, base, package, timeout).await,
+                    RegistryType::Npm => npm::check(&self.http, base, package, timeout).await,
+                    RegistryType::Crates => crates::check(&self.http, base, package, timeout).await,
+                    RegistryType::Go => go::check(&self.http, base, package, timeout).await,
+                }
+            })
+            .await?;
+
+        // Cache the result (both positive and negative)
+        if let Ok(found @ (PackageStatus::Exists | PackageStatus::NotFound)) = &status {
+            self.cache.set(registry, package, found.clone());
+        }
+
+        status
+    }
+
+    /// Run a request to a registry host once one of its request slots is free.
+    ///
+    /// Holding a slot for the whole request keeps the number of connections
+    /// open to the host at or below [`MAX_CONNECTIONS_PER_HOST`]; the shared
+    /// client then reuses them from its pool.
+    async fn with_host_permit<T>(
+        &self,
+        registry: RegistryType,
+        request: impl Future<Output = T>,
+    ) -> Result<T, RegistryError> {
+        let _permit = self.host_permits[registry as usize]
+            .acquire()
+            .await
+            .map_err(|_| RegistryError::Unavailable(format!("{} client closed", registry)))?;
+        Ok(request.await)
+    }
+
+    /// Get the configuration for a specific registry.
+    fn get_registry_config(&self, registry: RegistryType) -> &RegistryConfig {
+        match registry {
+            RegistryType::PyPI => &self.config.registries.pypi,
+            RegistryType::Npm => &self.config.registries.npm,
+            RegistryType::Crates => &self.config.registries.crates,
+            RegistryType::Go => &self.config.registries.go,
+        }
     }
 }
 
@@ -255,5 +473,159 @@ mod tests {
         assert!(client.is_allowlisted("company-core"));
         assert!(client.is_allowlisted("@myorg/auth"));
         assert!(!client.is_allowlisted("other-pkg"));
+        assert_eq!(client.allowlist_match("@myorg/auth"), Some("@myorg/*"));
+    }
+
+    #[test]
+    fn test_response_status_stops_draining_at_cap() {
+        use std::io::{BufRead, BufReader, Write};
+
+        // A chunked body that never ends
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut stream = stream;
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                line.clear();
+            }
+            let head = "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n";
+            let chunk = format!("400\r\n{}\r\n", "x".repeat(1024));
+            if stream.write_all(head.as_bytes()).is_err() {
+                return;
+            }
+            while stream.write_all(chunk.as_bytes()).is_ok() {}
+        });
+
+        let client = reqwest::Client::new();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let status = runtime.block_on(async {
+            let response = client.get(&url).send().await.unwrap();
+            tokio::time::timeout(Duration::from_secs(10), response_status(response)).await
+        });
+        assert_eq!(status, Ok(200));
+    }
+
+    /// Answer each request on a local port with the next status line of
+    /// `statuses` (the last one repeats) after `delay`, counting requests.
+    fn serve_counting_requests(
+        statuses: &'static [&'static str],
+        delay: Duration,
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::io::{BufRead, BufReader, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let counter = Arc::clone(&counter);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut stream = stream;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        match reader.read_line(&mut line) {
+                            Ok(0) | Err(_) => return,
+                            Ok(_) if line == "\r\n" => {
+                                let n = counter.fetch_add(1, Ordering::SeqCst);
+                                let status = statuses[n.min(statuses.len() - 1)];
+                                std::thread::sleep(delay);
+                                let response = format!("HTTP/1.1 {}\r\nContent-Length: 0\r\n\r\n", status);
+                                if stream.write_all(response.as_bytes()).is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(_) => {}
+                        }
+                    }
+                });
+            }
+        });
+        (url, requests)
+    }
+
+    #[test]
+    fn test_concurrent_lookups_share_one_request() {
+        let (url, requests) = serve_counting_requests(&["200 OK"], Duration::from_millis(200));
+        let client = RegistryClient::with_base_url(&url);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let statuses: Vec<_> = runtime.block_on(async {
+            let tasks: Vec<_> = (0..40)
+                .map(|_| {
+                    let client = client.clone();
+                    tokio::spawn(async move { client.check_package(RegistryType::PyPI, "requests").await })
+                })
+                .collect();
+            futures::future::join_all(tasks).await
+        });
+
+        assert!(statuses.into_iter().all(|s| s.unwrap().unwrap() == PackageStatus::Exists));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        assert_eq!(client.cache_stats(), (39, 1));
+        assert_eq!(client.cached_status(RegistryType::PyPI, "requests"), Some(PackageStatus::Exists));
+        assert!(client.inner.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_failed_lookup_is_not_cached() {
+        let (url, requests) = serve_counting_requests(&["429 Too Many Requests", "200 OK"], Duration::ZERO);
+        let client = RegistryClient::with_base_url(&url);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let check = || runtime.block_on(client.check_package(RegistryType::Npm, "left-pad"));
+
+        assert!(matches!(check(), Err(RegistryError::RateLimited)));
+        assert_eq!(client.cached_status(RegistryType::Npm, "left-pad"), None);
+
+        // The next lookup asks again, and its answer is cached
+        assert_eq!(check().unwrap(), PackageStatus::Exists);
+        assert_eq!(check().unwrap(), PackageStatus::Exists);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+}
+
+/// Local HTTP servers for tests of registry lookups.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// Serve `response` to every request over keep-alive HTTP/1.1 on a
+    /// local port, counting accepted connections.
+    pub(crate) fn serve_counting_connections(response: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&accepted);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut stream = stream;
+                    let mut line = String::new();
+                    loop {
+                        // Read one request head, then answer it on the same connection
+                        line.clear();
+                        match reader.read_line(&mut line) {
+                            Ok(0) | Err(_) => return,
+                            Ok(_) if line == "\r\n" => {
+                                if stream.write_all(response.as_bytes()).is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(_) => {}
+                        }
+                    }
+                });
+            }
+        });
+        (url, accepted)
     }
 }
@@ -3,34 +3,34 @@
 //! Checks package existence via: GET https://registry.npmjs.org/{package}
 //! Handles scoped packages like @org/package
 
-use super::{PackageStatus, RegistryError};
+use super::{response_status, PackageStatus, RegistryError};
 use reqwest::Client;
 use std::time::Duration;
 
+/// Media type of npm's abbreviated package metadata, with a full-document fallback.
+const ABBREVIATED_METADATA: &str = "application/vnd.npm.install-v1+json; q=1.0, application/json; q=0.8";
+
 /// Check if a package exists on npm.
 pub async fn check(
     client: &Client,
+    base: &str,
     package: &str,
     timeout: Duration,
 ) -> Result<PackageStatus, RegistryError> {
     // URL encode the package name (important for scoped packages like @types/node)
     let encoded = encode_package_name(package);
-    let url = format!("https://registry.npmjs.org/{}", encoded);
+    let url = format!("{}{}", base, encoded);
 
+    // Ask for the abbreviated metadata document npm clients install from;
+    // the full document can run to megabytes
     let response = client
         .get(&url)
+        .header(reqwest::header::ACCEPT, ABBREVIATED_METADATA)
         .timeout(timeout)
         .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                RegistryError::Timeout
-            } else {
-                RegistryError::Network(e)
-            }
-        })?;
+        .await?;
 
-    match response.status().as_u16() {
+    match response_status(response).await {
         200 => Ok(PackageStatus::Exists),
         404 => Ok(PackageStatus::NotFound),
         429 => Err(RegistryError::RateLimited),
@@ -3,7 +3,7 @@
 //! Uses a smart variant-based approach to handle Python's import-name vs package-name mismatch.
 //! Instead of maintaining an unmaintainable alias list, we try multiple common naming patterns.
 
-use super::{PackageStatus, RegistryError};
+use super::{response_status, PackageStatus, RegistryError};
 use reqwest::Client;
 use std::time::Duration;
 
@@ -24,6 +24,7 @@ use std::time::Duration;
 /// while avoiding false positives from naming mismatches.
 pub async fn check(
     client: &Client,
+    base: &str,
     package: &str,
     timeout: Duration,
 ) -> Result<PackageStatus, RegistryError> {
@@ -32,7 +33,7 @@ pub async fn check(
 
     // Try each variant - return Exists on first match
     for variant in &variants {
-        match check_single(client, variant, timeout).await {
+        match check_single(client, base, variant, timeout).await {
             Ok(PackageStatus::Exists) => return Ok(PackageStatus::Exists),
             Ok(PackageStatus::NotFound) => continue,
             // On rate limit or network error, don't fail - benefit of the doubt
@@ -112,26 +113,20 @@ fn generate_name_variants(normalized: &str) -> Vec<String> {
 /// Check a single package name on PyPI using HEAD request for speed.
 async fn check_single(
     client: &Client,
+    base: &str,
     package_name: &str,
     timeout: Duration,
 ) -> Result<PackageStatus, RegistryError> {
-    let url = format!("https://pypi.org/pypi/{}/json", package_name);
+    let url = format!("{}{}/json", base, package_name);
 
     // Use HEAD request - faster than GET since we only need status
     let response = client
         .head(&url)
         .timeout(timeout)
         .send()
-        .await
-        .map_err(|e| {
-            if e.is_timeout() {
-                RegistryError::Timeout
-            } else {
-                RegistryError::Network(e)
-            }
-        })?;
+        .await?;
 
-    match response.status().as_u16() {
+    match response_status(response).await {
         200 => Ok(PackageStatus::Exists),
         404 => Ok(PackageStatus::NotFound),
         429 => Err(RegistryError::RateLimited),
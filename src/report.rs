@@ -5,14 +5,20 @@
 //! - Pretty: colored terminal output for human readability
 //! - JSON: structured output for programmatic consumption (matches Go version)
 //! - SARIF: Static Analysis Results Interchange Format for IDE/CI integration
+//! - Cargo: rustc-style diagnostics and `--message-format json` lines for `cargo hollowcheck`
 
 use colored::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::path::Path;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
 
-use crate::detect::{DetectionResult, Severity, SuppressedViolation, Violation};
-use crate::score::HollownessScore;
+use crate::cargo::Package;
+use crate::contract::Waiver;
+use crate::detect::{
+    DetectionResult, Effort, EffortBucket, EnvironmentEntry, ScanStats, Severity, SuppressedViolation, Violation, ViolationRule, WaivedViolation,
+    WaiverEntry, WaiverStatus,
+};
+use crate::score::{DebtScore, HollownessScore, SubScore, SubScores};
 
 // =============================================================================
 // JSON Format (matches Go version exactly)
@@ -23,31 +29,99 @@ use crate::score::HollownessScore;
 pub struct JsonReport {
     pub version: String,
     pub path: String,
+    /// Absolute directory the scan was scoped to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scan_root: Option<String>,
+    /// Workspace root used for manifests and contract discovery
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workspace_root: Option<String>,
     pub contract: String,
     pub score: i32,
     pub grade: String,
     pub threshold: i32,
     pub passed: bool,
     pub files_scanned: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub files_rejected: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub files_excluded_by_language: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub files_errored: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub files_generated: usize,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub files_generated_modified: usize,
     pub violations: Vec<JsonViolation>,
+    /// Violations left out of `violations` by the report limits, per rule
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub truncated: BTreeMap<String, usize>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub new_violations: Vec<JsonViolation>,
+    /// New violations left out of `new_violations` by the report limits, per rule
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub new_truncated: BTreeMap<String, usize>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub baseline_ref: Option<String>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub suppressed: Vec<JsonSuppressedViolation>,
     pub suppressed_count: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub waived: Vec<JsonWaivedViolation>,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub waived_count: usize,
     pub breakdown: Vec<BreakdownEntry>,
+    /// Violations kept out of the score, by mechanism and rule; left out of the Go-compatible schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub debt: Option<DebtScore>,
+    /// The score split into structural and content hollowness; left out of the Go-compatible schema
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_scores: Option<SubScores>,
+    /// Language, runtime, and framework versions the project declares; left out of the Go-compatible schema
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub environment: Vec<EnvironmentEntry>,
+    /// Problems with the contract found during the run; left out of the Go-compatible schema
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contract_warnings: Vec<String>,
+    /// Figures measured during the run, such as documentation coverage; left out of the Go-compatible schema
+    #[serde(default, skip_serializing_if = "ScanStats::is_empty")]
+    pub stats: ScanStats,
 }
 
 /// JSON violation structure matching Go's JSONViolation.
+///
+/// `file` is `null` for project-level violations unless the Go-compatible
+/// schema is requested, in which case it is an empty string. `explanation`
+/// holds the dependency trace of a `hallucinated_dependency` violation when
+/// the run used `--explain-dependencies`. `fingerprint` is the stable
+/// violation fingerprint; the Go-compatible schema leaves it out.
+/// `language_override` names the language a file was routed to by a
+/// language override, whose parse is less reliable than a native file's.
+/// `marker` holds the marker behind a `hollow_todo` or `forbidden_pattern`
+/// violation: its kind, text, and ticket. `effort` holds the heuristic
+/// estimate of a hollow function's remaining work, when the contract asks.
 #[derive(Serialize, Deserialize)]
 pub struct JsonViolation {
     pub rule: String,
     pub severity: String,
-    pub file: String,
+    pub file: Option<String>,
     pub line: usize,
     pub message: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<serde_json::Value>,
+    /// Severity before an inline downgrade directive lowered it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original_severity: Option<String>,
+    /// Reason given on the downgrade directive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub downgrade_reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fingerprint: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub language_override: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marker: Option<crate::analysis::Marker>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort: Option<Effort>,
 }
 
 /// Breakdown entry for score details.
@@ -77,26 +151,72 @@ pub struct JsonSuppression {
     pub suppression_type: String,
 }
 
+/// Violation accepted by a contract waiver, with the waiver that accepted it.
+#[derive(Serialize, Deserialize)]
+pub struct JsonWaivedViolation {
+    pub violation: JsonViolation,
+    pub waiver: Waiver,
+}
+
+/// Options controlling JSON output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonOptions {
+    /// Write the report on a single line, for consumers reading a pipe.
+    pub compact: bool,
+    /// Keep the Go version's schema, where every violation has a string `file`.
+    pub go_compat: bool,
+}
+
 /// Write results in JSON format (matches Go version exactly).
 pub fn write_json(
     path: &str,
     contract_path: &str,
     result: &DetectionResult,
     score: &HollownessScore,
+    options: JsonOptions,
 ) -> anyhow::Result<()> {
-    let violations: Vec<JsonViolation> = result.violations.iter().map(violation_to_json).collect();
+    println!("{}", render_json(path, contract_path, result, score, options)?);
+    Ok(())
+}
 
-    let new_violations: Vec<JsonViolation> = result
-        .new_violations
-        .iter()
-        .map(violation_to_json)
-        .collect();
+/// Render results as a JSON report string.
+pub fn render_json(
+    path: &str,
+    contract_path: &str,
+    result: &DetectionResult,
+    score: &HollownessScore,
+    options: JsonOptions,
+) -> anyhow::Result<String> {
+    let to_json = |v: &Violation| {
+        let mut json = violation_to_json(v, options.go_compat);
+        if !options.go_compat {
+            json.fingerprint = Some(result.fingerprint(v));
+            json.marker = result.markers.get(&v.key()).cloned();
+            json.effort = result.efforts.get(&v.key()).cloned();
+        }
+        if v.rule == ViolationRule::HallucinatedDependency {
+            json.explanation = result
+                .dependency_traces
+                .iter()
+                .find(|t| t.matches(v.file.as_deref(), v.line, &v.message))
+                .and_then(|t| serde_json::to_value(t).ok());
+        }
+        if let Some(d) = result.downgraded.iter().find(|d| d.violation.key() == v.key()) {
+            json.original_severity = Some(d.original_severity.to_string());
+            let reason = &d.downgrade.suppression.reason;
+            json.downgrade_reason = (!reason.is_empty()).then(|| reason.clone());
+        }
+        json
+    };
+    let violations: Vec<JsonViolation> = result.violations.iter().map(to_json).collect();
+
+    let new_violations: Vec<JsonViolation> = result.new_violations.iter().map(to_json).collect();
 
     let suppressed: Vec<JsonSuppressedViolation> = result
         .suppressed
         .iter()
         .map(|sv| JsonSuppressedViolation {
-            violation: violation_to_json(&sv.violation),
+            violation: to_json(&sv.violation),
             suppression: JsonSuppression {
                 rule: sv.suppression.rule.clone(),
                 reason: sv.suppression.reason.clone(),
@@ -107,6 +227,15 @@ pub fn write_json(
         })
         .collect();
 
+    let waived: Vec<JsonWaivedViolation> = result
+        .waived
+        .iter()
+        .map(|wv| JsonWaivedViolation {
+            violation: to_json(&wv.violation),
+            waiver: wv.waiver.clone(),
+        })
+        .collect();
+
     // Build breakdown with violation counts
     let breakdown: Vec<BreakdownEntry> = score
         .breakdown
@@ -121,32 +250,66 @@ pub fn write_json(
     let report = JsonReport {
         version: env!("CARGO_PKG_VERSION").to_string(),
         path: path.to_string(),
+        scan_root: result.scan_root.clone(),
+        workspace_root: result.workspace_root.clone(),
         contract: contract_path.to_string(),
         score: score.score,
         grade: score.grade.clone(),
         threshold: score.threshold,
         passed: score.passed,
         files_scanned: result.scanned,
+        files_rejected: result.rejected,
+        files_excluded_by_language: result.language_excluded,
+        files_errored: result.errored,
+        files_generated: result.generated,
+        files_generated_modified: result.generated_modified,
         violations,
+        truncated: result.truncated.clone(),
         new_violations,
+        new_truncated: result.new_truncated.clone(),
         baseline_ref: result.baseline_ref.clone(),
         suppressed,
         suppressed_count: result.suppressed.len(),
+        waived,
+        waived_count: result.waived.len(),
         breakdown,
+        debt: (!options.go_compat).then(|| score.debt.clone()),
+        sub_scores: (!options.go_compat).then_some(score.sub_scores),
+        environment: if options.go_compat { Vec::new() } else { result.environment.clone() },
+        contract_warnings: if options.go_compat { Vec::new() } else { result.contract_warnings.clone() },
+        stats: if options.go_compat { ScanStats::default() } else { result.stats.clone() },
     };
 
-    let json = serde_json::to_string_pretty(&report)?;
-    println!("{}", json);
-    Ok(())
+    let json = if options.compact {
+        serde_json::to_string(&report)?
+    } else {
+        serde_json::to_string_pretty(&report)?
+    };
+    Ok(json)
 }
 
-fn violation_to_json(v: &Violation) -> JsonViolation {
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+fn violation_to_json(v: &Violation, go_compat: bool) -> JsonViolation {
+    let file = match &v.file {
+        None if go_compat => Some(String::new()),
+        file => file.clone(),
+    };
     JsonViolation {
         rule: v.rule.as_str().to_string(),
         severity: v.severity.to_string(),
-        file: v.file.clone(),
+        file,
         line: v.line,
         message: v.message.clone(),
+        explanation: None,
+        original_severity: None,
+        downgrade_reason: None,
+        fingerprint: None,
+        language_override: v.language_override().filter(|_| !go_compat).map(str::to_string),
+        marker: None,
+        effort: None,
     }
 }
 
@@ -211,8 +374,13 @@ struct SarifResult {
     #[serde(rename = "ruleId")]
     rule_id: String,
     level: String,
+    /// Priority from 0.0 to 100.0; separates Critical from Error within the "error" level
+    rank: f64,
     message: SarifMessage,
     locations: Vec<SarifLocation>,
+    /// Stable fingerprint, under `hollowcheck/v<version>`
+    #[serde(rename = "partialFingerprints")]
+    partial_fingerprints: std::collections::BTreeMap<String, String>,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -220,10 +388,20 @@ struct SarifMessage {
     text: String,
 }
 
+/// A result location: a file region, or for project-level violations a
+/// logical location naming the project.
 #[derive(Serialize, Deserialize)]
 struct SarifLocation {
-    #[serde(rename = "physicalLocation")]
-    physical_location: SarifPhysicalLocation,
+    #[serde(rename = "physicalLocation", skip_serializing_if = "Option::is_none")]
+    physical_location: Option<SarifPhysicalLocation>,
+    #[serde(rename = "logicalLocations", skip_serializing_if = "Option::is_none")]
+    logical_locations: Option<Vec<SarifLogicalLocation>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SarifLogicalLocation {
+    name: String,
+    kind: String,
 }
 
 #[derive(Serialize, Deserialize)]
@@ -290,6 +468,286 @@ fn get_rule_info(rule_id: &str) -> RuleInfo {
             help_uri: "#complexity",
             default_level: "error",
         },
+        "insufficient_implementation" => RuleInfo {
+            name: "InsufficientImplementation",
+            short_description: "Detects modules where too few functions are implemented",
+            full_description: "Computes the ratio of non-stub functions and methods to all callables for each configured glob, and flags globs that fall below the contract's minimum ratio.",
+            help_uri: "#implementation-coverage",
+            default_level: "error",
+        },
+        "repeated_literal" => RuleInfo {
+            name: "RepeatedLiteral",
+            short_description: "Detects string literals duplicated across the project",
+            full_description: "Identifies string literals such as regions, API paths, and queue names that are repeated at many call sites instead of being defined once as a named constant.",
+            help_uri: "#repeated-literals",
+            default_level: "warning",
+        },
+        "floating_promise" => RuleInfo {
+            name: "FloatingPromise",
+            short_description: "Detects JS/TS promises that are never awaited or returned",
+            full_description: "Identifies calls to async functions, or functions whose name suggests they return a promise, that are used as bare statements without await, return, void, or a .then/.catch chain.",
+            help_uri: "#floating-promises",
+            default_level: "warning",
+        },
+        "forbidden_file" => RuleInfo {
+            name: "ForbiddenFile",
+            short_description: "Detects junk files and oversized files in the deliverable",
+            full_description: "Flags files matching contract forbidden_files globs (patch leftovers, logs, archives, compiled artifacts), .env files, and files exceeding the max_file_size limits. Only paths and metadata are inspected.",
+            help_uri: "#forbidden-files",
+            default_level: "error",
+        },
+        "case_collision" => RuleInfo {
+            name: "CaseCollision",
+            short_description: "Detects paths that differ only by case",
+            full_description: "Flags files and directories in the same directory whose names differ only by case, such as README.md and Readme.md. A checkout on a case-insensitive filesystem (macOS, Windows) keeps only one of them.",
+            help_uri: "#case-collisions",
+            default_level: "error",
+        },
+        "bad_header" => RuleInfo {
+            name: "BadHeader",
+            short_description: "Detects missing, mismatched, or placeholder license headers",
+            full_description: "Checks that each file's first comment block matches the header configured in the contract, and flags copyright notices left with template placeholders such as <YEAR> or <COMPANY>.",
+            help_uri: "#header-detection",
+            default_level: "error",
+        },
+        "doc_drift" => RuleInfo {
+            name: "DocDrift",
+            short_description: "Detects documentation that references API the code never defines",
+            full_description: "Extracts function calls, CamelCase type names, and configured CLI subcommands from code blocks and inline code in the contract's documentation files, and reports references that match no declaration in the project.",
+            help_uri: "#doc-drift",
+            default_level: "note",
+        },
+        "too_many_params" => RuleInfo {
+            name: "TooManyParams",
+            short_description: "Detects functions with suspiciously long parameter lists",
+            full_description: "Counts the parameters in each function signature, excluding receivers such as self, and flags functions above the contract's max_params. Constructors and builders matching the allowlist are exempt.",
+            help_uri: "#long-parameter-lists",
+            default_level: "warning",
+        },
+        "empty_handler" => RuleInfo {
+            name: "EmptyHandler",
+            short_description: "Detects HTTP handlers that respond 200 with an empty body",
+            full_description: "Matches per-framework patterns for success responses with no content, such as net/http WriteHeader(200) without a Write, Express res.sendStatus(200) or res.json({}), and Flask return \"\", 200, inside a handler function. Frameworks are extensible in the contract.",
+            help_uri: "#empty-handlers",
+            default_level: "warning",
+        },
+        "analysis_timeout" => RuleInfo {
+            name: "AnalysisTimeout",
+            short_description: "Reports large files that could not be analyzed within the time budget",
+            full_description: "Files above the contract's large_file_lines threshold are parsed under a wall-clock budget before any rule runs. A file that exceeds it is skipped by every rule and reported, so an oversized or adversarial file neither stalls the run nor passes silently.",
+            help_uri: "#analysis-budget",
+            default_level: "warning",
+        },
+        "analysis_error" => RuleInfo {
+            name: "AnalysisError",
+            short_description: "Reports files whose analysis crashed",
+            full_description: "Every file with an analyzer is parsed once before any rule runs, with a crash in the parser or fact extraction contained to that file. A file that crashes is skipped by every rule and reported, so one pathological input neither aborts the run nor passes silently.",
+            help_uri: "#analysis-errors",
+            default_level: "warning",
+        },
+        "unimplemented_interface" => RuleInfo {
+            name: "UnimplementedInterface",
+            short_description: "Detects interfaces and traits that no type in the project implements",
+            full_description: "Looks for an implementing type for every interface, trait, and abstract base class: an implements/extends clause or Rust impl block, or for Go a type whose methods cover the interface's. Declared abstractions with nothing behind them pass required_symbols but deliver no behavior.",
+            help_uri: "#unimplemented-interfaces",
+            default_level: "warning",
+        },
+        "premature_abstraction" => RuleInfo {
+            name: "PrematureAbstraction",
+            short_description: "Detects abstractions with a single concrete type behind them",
+            full_description: "Flags interfaces and traits with exactly one implementing type, New*/Create* factories that return such an interface, and generic functions whose type parameter is used in a single position. Each layer is indirection without a second implementation to justify it; findings are reported once per abstraction.",
+            help_uri: "#premature-abstraction",
+            default_level: "note",
+        },
+        "weak_crypto" => RuleInfo {
+            name: "WeakCrypto",
+            short_description: "Detects weak hashes and ciphers, ECB mode, and hardcoded IVs and salts",
+            full_description: "Matches per-language calls that select MD5 or SHA-1 digests, DES, 3DES, RC4, or Blowfish ciphers, or ECB mode, and IVs and salts passed as literals or zero-filled buffers. Covers Python hashlib, pycryptodome, and cryptography, Go crypto packages, Java JCA, and Node crypto; a pattern applies only in files that import its module.",
+            help_uri: "#weak-crypto",
+            default_level: "warning",
+        },
+        "comment_ratio" => RuleInfo {
+            name: "CommentRatio",
+            short_description: "Detects source files with no comments across complex functions, or mostly comments",
+            full_description: "Counts comment and code lines per file from the parse tree, with docstrings as comments and the license header left out. Flags files whose complex functions carry no comments, and files with far more comment lines than code, which is often padding that restates the code. Both bounds are configurable.",
+            help_uri: "#comment-ratio",
+            default_level: "note",
+        },
+        "doc_coverage" => RuleInfo {
+            name: "DocCoverage",
+            short_description: "Detects exported declarations without doc comments",
+            full_description: "Checks Go, Rust, Python, Java, JavaScript, and TypeScript declarations that are part of the public API for a doc comment right above them or a Python docstring. Undocumented ones are reported per file up to a cap, and the project-wide coverage is an error when it falls below the contract's min_doc_coverage. Constants and test files are left out by default.",
+            help_uri: "#doc-coverage",
+            default_level: "note",
+        },
+        "builtin_shadowing" => RuleInfo {
+            name: "BuiltinShadowing",
+            short_description: "Detects Python names that shadow builtins",
+            full_description: "Flags Python functions, classes, and module-level variables named after a builtin such as list, dict, id, type, or input. Methods are not checked. The builtin list and an allowlist are configurable in the contract.",
+            help_uri: "#builtin-shadowing",
+            default_level: "warning",
+        },
+        "hollow_container" => RuleInfo {
+            name: "HollowContainer",
+            short_description: "Detects Dockerfiles and compose services that run nothing real",
+            full_description: "Flags CMD, ENTRYPOINT, and HEALTHCHECK commands that only echo, sleep, or exit 0, EXPOSE on an image with nothing to run, unpinned base images, COPY of the whole context without a .dockerignore, and compose services that are empty or use placeholder images. ENV, ARG, and compose environment values are checked against the mock signatures.",
+            help_uri: "#container-checks",
+            default_level: "error",
+        },
+        "hollow_ci_config" => RuleInfo {
+            name: "HollowCiConfig",
+            short_description: "Detects CI pipelines with placeholder images or TODO steps",
+            full_description: "Reads GitHub Actions workflows and .gitlab-ci.yml line by line and flags placeholder job, service, and step images and values, images without a tag or tagged latest, and TODO markers, including steps that only echo one.",
+            help_uri: "#ci-pipeline-checks",
+            default_level: "error",
+        },
+        "hollow_schema" => RuleInfo {
+            name: "HollowSchema",
+            short_description: "Detects empty and placeholder Protobuf and GraphQL definitions",
+            full_description: "Reads .proto and .graphql files and flags messages, enums, services, types, and inputs that define no fields, placeholder fields such as _empty: Boolean, fields left commented out, and TODO markers in comments. Empty definitions whose names match the contract's allow_empty patterns are not reported.",
+            help_uri: "#schema-checks",
+            default_level: "error",
+        },
+        "environment_mismatch" => RuleInfo {
+            name: "EnvironmentMismatch",
+            short_description: "Detects language, runtime, and framework versions that differ from the contract",
+            full_description: "Reads the versions the delivery targets from the go.mod go and toolchain lines, pyproject requires-python, package.json engines, Cargo.toml rust-version, and the declared dependency constraints, and flags those whose lowest admitted version is outside the contract's environment section, or that are not declared at all.",
+            help_uri: "#environment",
+            default_level: "error",
+        },
+        "hollow_config" => RuleInfo {
+            name: "HollowConfig",
+            short_description: "Detects configuration files with placeholder or missing settings",
+            full_description: "Checks YAML, JSON, and TOML files matching the contract's config_files globs: required keys must be present and non-empty, numeric keys must be within their ranges, and values must not be placeholders such as TODO, empty credentials, or port 0. Values matching a mock signature are reported as mock_data.",
+            help_uri: "#config-files",
+            default_level: "error",
+        },
+        "expired_waiver" => RuleInfo {
+            name: "ExpiredWaiver",
+            short_description: "Reports contract waivers past their expiry date",
+            full_description: "A contract waiver accepts violations of one rule in matching files, or one violation by fingerprint, until its expires date. After that date the violations are reported again and the waiver itself is reported, with its owner and justification, so it is renewed or removed.",
+            help_uri: "#waivers",
+            default_level: "note",
+        },
+        "unused_suppression" => RuleInfo {
+            name: "UnusedSuppression",
+            short_description: "Reports inline suppressions that suppress nothing",
+            full_description: "An inline hollowcheck:ignore directive whose rule found nothing on the lines it covers is stale: the code was fixed or moved, and the directive would silently hide the next finding there. A line suppression in a file whose ignore-file directive already covers the same rule is redundant. Only suppressions of rules that ran are checked.",
+            help_uri: "#unused-suppressions",
+            default_level: "note",
+        },
+        "unused_context" => RuleInfo {
+            name: "UnusedContext",
+            short_description: "Detects Go context.Context parameters that are never used",
+            full_description: "A Go function that accepts a context.Context but never passes it on or checks ctx.Done() or ctx.Err() ignores cancellation and deadlines, which usually means the implementation is incomplete. Name the parameter _ when an interface requires it and it is intentionally unused.",
+            help_uri: "#unused-context",
+            default_level: "warning",
+        },
+        "unassigned_named_return" => RuleInfo {
+            name: "UnassignedNamedReturn",
+            short_description: "Detects Go named results returned without ever being assigned",
+            full_description: "A Go function with named results whose bare return hands back a result that no statement assigns before it always returns that result's zero value, typically a nil error on every path. Writes inside a deferred closure, and passing &result to a call, count as assignments.",
+            help_uri: "#unassigned-named-return",
+            default_level: "warning",
+        },
+        "placeholder_constants" => RuleInfo {
+            name: "PlaceholderConstants",
+            short_description: "Detects constant groups whose values were never decided",
+            full_description: "A Go const block, enum, or run of Rust constants whose members all have the same literal value (A = 0; B = 0; C = 0), or sequential or placeholder values next to a TODO marker, models a domain that was never filled in. Go iota blocks and enums with implicit values are not flagged.",
+            help_uri: "#placeholder-constants",
+            default_level: "note",
+        },
+        "missing_definition" => RuleInfo {
+            name: "MissingDefinition",
+            short_description: "Detects C/C++ header functions that are never defined",
+            full_description: "A function declared in a C or C++ header, with no definition of the same name and parameter count in any scanned translation unit. A complete, documented header in front of a source file that implements a few of its functions is a common shape of hollow C code. extern declarations of configured external libraries and ignored names are exempt.",
+            help_uri: "#missing-definitions",
+            default_level: "warning",
+        },
+        "deprecated_usage" => RuleInfo {
+            name: "DeprecatedUsage",
+            short_description: "Reports deprecated callables that are still widely called",
+            full_description: "A function or method marked deprecated (Rust #[deprecated], Java @Deprecated, a Python @deprecated decorator or .. deprecated:: docstring, a JSDoc @deprecated tag) that is still called from at least min_call_sites places in the scanned tree. Calls are matched by name. The report tracks how much of a migration remains.",
+            help_uri: "#deprecated-usage",
+            default_level: "note",
+        },
+        "unwrap_overuse" => RuleInfo {
+            name: "UnwrapOveruse",
+            short_description: "Detects Rust functions that unwrap instead of handling errors",
+            full_description: "A Rust function with more than max_per_function .unwrap(), .expect(...), and panic! sites, or any of them in a file matching production_paths. Unwrapping every Result is how generated code skips error handling. Test functions, #[cfg(test)] modules, files under tests/, benches/, and examples/, and main are exempt.",
+            help_uri: "#unwrap-overuse",
+            default_level: "warning",
+        },
+        "force_unwrap" => RuleInfo {
+            name: "ForceUnwrap",
+            short_description: "Detects Swift functions that force-unwrap instead of handling nil or errors",
+            full_description: "A Swift function or initializer with more than max_per_function postfix ! force-unwraps and try! expressions, each of which crashes the app when the value is nil or the call throws. Generated Swift reaches for them to get past the type checker. Closures count towards their enclosing function. Functions marked @Test, methods of XCTestCase subclasses, and files matching test_paths are exempt.",
+            help_uri: "#force-unwraps",
+            default_level: "warning",
+        },
+        "missing_local_module" => RuleInfo {
+            name: "MissingLocalModule",
+            short_description: "Detects imports of project modules that do not exist",
+            full_description: "A relative or project-local import that resolves to no file in the project: a Python relative import or an absolute import of a package in the project, a JavaScript or TypeScript ./ or ../ specifier or one mapped by tsconfig paths or baseUrl, or a Go import under the go.mod module path. The message lists the paths that were tried. Python imports guarded by try/except ImportError are reported as notes.",
+            help_uri: "#missing-local-modules",
+            default_level: "error",
+        },
+        "trivial_wrapper" => RuleInfo {
+            name: "TrivialWrapper",
+            short_description: "Reports functions that only forward their parameters to another project function",
+            full_description: "A function or method whose body is a single call, after return, await, or ?, passing its own parameters through unchanged and in order to another function declared in the project. Such a layer adds a name and nothing else. Methods of types that implement an interface or trait, property accessors, and files matching api_facade_paths are exempt. Matching is by name, so confidence is low; many wrappers of one callee are reported together.",
+            help_uri: "#trivial-wrappers",
+            default_level: "note",
+        },
+        "noop_statement" => RuleInfo {
+            name: "NoopStatement",
+            short_description: "Detects statements that have no effect",
+            full_description: "Flags padding statements that make a body look substantive: Go `_ = x` on a plain value, Python literals used as statements and `pass` next to other statements, JavaScript/TypeScript empty statements, and a bare return ending a function with no results.",
+            help_uri: "#noop-statements",
+            default_level: "note",
+        },
+        "redundant_error_logging" => RuleInfo {
+            name: "RedundantErrorLogging",
+            short_description: "Detects error handlers that log an error and rethrow it unchanged",
+            full_description: "Flags a catch or except clause, or a Go `if err != nil` block, that calls a logger and then passes the same error on unchanged: Python `raise` or `raise e`, Java and JavaScript/TypeScript `throw e`, Go `return ..., err`. When every layer does this, one failure is logged once per layer. Rethrows that add context, such as `raise ... from e`, a new exception wrapping the error, or `fmt.Errorf` with %w, are not flagged.",
+            help_uri: "#redundant-error-logging",
+            default_level: "note",
+        },
+        "inconsistent_error_handling" => RuleInfo {
+            name: "InconsistentErrorHandling",
+            short_description: "Detects files that mix error-returning and panicking functions",
+            full_description: "Flags functions whose way of reporting failure is the minority in their file: in Rust, Go, Swift, and TypeScript, a function that panics or throws (`panic!`, `panic`, `log.Fatal`, `fatalError`, `throw`) where most of the file returns `Result`, an `error`, or is declared `throws`, or the reverse. A file stitched together from separate generations, or half migrated from one strategy to the other, fails in ways its callers cannot handle uniformly. Entry points, Go `Must*` helpers, and stubs are not counted, and files with as many functions of each strategy are not flagged.",
+            help_uri: "#inconsistent-error-handling",
+            default_level: "note",
+        },
+        "async_without_await" => RuleInfo {
+            name: "AsyncWithoutAwait",
+            short_description: "Detects async functions whose body never awaits anything",
+            full_description: "Flags Python, JavaScript, TypeScript, and Rust functions declared async whose body has no await expression, async for/with, or for await loop outside nested functions: synchronous code with an async signature, often because the I/O it stands for was never written. Bodies already reported as stubs, abstract and overload stubs, interface and trait members and their implementations, Rust bodies returning an async block, and names matching the passthrough list are not flagged.",
+            help_uri: "#async-without-await",
+            default_level: "note",
+        },
+        "mutable_default" => RuleInfo {
+            name: "MutableDefault",
+            short_description: "Detects Python parameters with mutable default values",
+            full_description: "Flags a Python parameter whose default is a list, dict, or set literal or comprehension, or a call to a mutable constructor such as list(), dict(), set(), or defaultdict(). The default is evaluated once, when the function is defined, so every call that mutates it changes the default seen by the next call. Default to None and build the value in the body instead.",
+            help_uri: "#mutable-defaults",
+            default_level: "warning",
+        },
+        "truncated_code" => RuleInfo {
+            name: "TruncatedCode",
+            short_description: "Detects functions cut off at the end of the file",
+            full_description: "Flags a file that ends inside a function: the body is never closed, or its last statement stops halfway, so the parse damage reaches the end of the file. Generated code that ran out of tokens looks like this, unlike an ordinary syntax error elsewhere in the file. Regenerate the file rather than patching it.",
+            help_uri: "#truncated-code",
+            default_level: "error",
+        },
+        "unhandled_enum_cases" => RuleInfo {
+            name: "UnhandledEnumCases",
+            short_description: "Detects switches that leave most enum variants to a trivial default",
+            full_description: "Flags a switch or match over an enum declared in the project (Go iota constants, TypeScript enums and string literal unions, Rust enums) that handles fewer than the configured fraction of the variants and sends the rest to an empty default such as `default: return nil` or `_ => {}`.",
+            help_uri: "#unhandled-enum-cases",
+            default_level: "note",
+        },
         "missing_test" => RuleInfo {
             name: "MissingTest",
             short_description: "Detects missing required test functions",
@@ -369,6 +827,17 @@ fn map_severity_to_level(severity: &Severity) -> &'static str {
     }
 }
 
+/// SARIF rank for a severity. Critical and Error share the "error" level,
+/// so the rank is what lets consumers sort blockers first.
+fn map_severity_to_rank(severity: &Severity) -> f64 {
+    match severity {
+        Severity::Critical => 95.0,
+        Severity::Error => 75.0,
+        Severity::Warning => 40.0,
+        Severity::Info => 10.0,
+    }
+}
+
 fn make_relative_path(file_path: &str, base_path: &Path) -> String {
     if base_path.to_string_lossy().is_empty() {
         return file_path.to_string();
@@ -389,8 +858,49 @@ fn make_relative_path(file_path: &str, base_path: &Path) -> String {
         .unwrap_or_else(|_| file_path.to_string())
 }
 
+/// Name of the scanned project, for SARIF logical locations.
+fn project_name(base_path: &Path, result: &DetectionResult) -> String {
+    let root = result
+        .scan_root
+        .as_deref()
+        .map(Path::new)
+        .unwrap_or(base_path);
+    root.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_else(|| root.to_string_lossy().to_string())
+}
+
+fn sarif_location(v: &Violation, base_path: &Path, project: &str) -> SarifLocation {
+    match &v.file {
+        Some(file) => SarifLocation {
+            physical_location: Some(SarifPhysicalLocation {
+                artifact_location: SarifArtifact {
+                    uri: make_relative_path(file, base_path),
+                },
+                region: SarifRegion {
+                    start_line: if v.line > 0 { v.line } else { 1 },
+                },
+            }),
+            logical_locations: None,
+        },
+        None => SarifLocation {
+            physical_location: None,
+            logical_locations: Some(vec![SarifLogicalLocation {
+                name: project.to_string(),
+                kind: "module".to_string(),
+            }]),
+        },
+    }
+}
+
 /// Write results in SARIF format.
 pub fn write_sarif(base_path: &Path, result: &DetectionResult) -> anyhow::Result<()> {
+    println!("{}", render_sarif(base_path, result)?);
+    Ok(())
+}
+
+/// Render results as a SARIF log string.
+pub fn render_sarif(base_path: &Path, result: &DetectionResult) -> anyhow::Result<String> {
     // Collect unique rules from violations
     let rule_set: HashSet<String> = result
         .violations
@@ -425,25 +935,23 @@ pub fn write_sarif(base_path: &Path, result: &DetectionResult) -> anyhow::Result
         .collect();
 
     // Build results list
+    let project = project_name(base_path, result);
     let results: Vec<SarifResult> = result
         .violations
         .iter()
         .map(|v| SarifResult {
             rule_id: v.rule.as_str().to_string(),
             level: map_severity_to_level(&v.severity).to_string(),
+            rank: map_severity_to_rank(&v.severity),
             message: SarifMessage {
                 text: v.message.clone(),
             },
-            locations: vec![SarifLocation {
-                physical_location: SarifPhysicalLocation {
-                    artifact_location: SarifArtifact {
-                        uri: make_relative_path(&v.file, base_path),
-                    },
-                    region: SarifRegion {
-                        start_line: if v.line > 0 { v.line } else { 1 },
-                    },
-                },
-            }],
+            locations: vec![sarif_location(v, base_path, &project)],
+            partial_fingerprints: [(
+                format!("hollowcheck/v{}", crate::detect::FINGERPRINT_VERSION),
+                result.fingerprint(v),
+            )]
+            .into(),
         })
         .collect();
 
@@ -463,9 +971,509 @@ pub fn write_sarif(base_path: &Path, result: &DetectionResult) -> anyhow::Result
         }],
     };
 
-    let json = serde_json::to_string_pretty(&report)?;
-    println!("{}", json);
-    Ok(())
+    Ok(serde_json::to_string_pretty(&report)?)
+}
+
+// =============================================================================
+// GitHub Actions Format (workflow command annotations)
+// =============================================================================
+
+/// Escape a workflow command message (`%`, CR, LF).
+fn escape_workflow_data(s: &str) -> String {
+    s.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value (also `:` and `,`).
+fn escape_workflow_property(s: &str) -> String {
+    escape_workflow_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Render results as GitHub Actions workflow commands, one annotation per
+/// violation. Project-level violations are annotations without a file.
+pub fn render_github_annotations(base_path: &Path, result: &DetectionResult) -> String {
+    let mut buf = String::new();
+    for v in &result.violations {
+        let level = match v.severity {
+            Severity::Critical | Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "notice",
+        };
+        let mut properties = Vec::new();
+        if let Some(file) = &v.file {
+            properties.push(format!(
+                "file={}",
+                escape_workflow_property(&make_relative_path(file, base_path))
+            ));
+            if v.line > 0 {
+                properties.push(format!("line={}", v.line));
+            }
+        }
+        properties.push(format!("title={}", escape_workflow_property(v.rule.as_str())));
+        buf.push_str(&format!(
+            "::{} {}::{}\n",
+            level,
+            properties.join(","),
+            escape_workflow_data(&v.message)
+        ));
+    }
+    buf
+}
+
+/// Render one compiler-style `path:line:col: severity: [rule] message` line
+/// per violation, for editor quickfix lists (Vim's `errorformat`, Emacs
+/// `compile`).
+///
+/// Paths are relative to `base_path` when below it. Violations carry no
+/// column, so it is always 1, as is the line of violations not tied to one.
+/// Project-level violations point at `.`. Critical becomes `error` and info
+/// stays `info`, so the severity's first letter is Vim's `%t` type.
+pub fn render_editor(base_path: &Path, result: &DetectionResult) -> String {
+    let mut buf = String::new();
+    for v in &result.violations {
+        let path = v.file.as_deref().map_or_else(|| ".".to_string(), |f| make_relative_path(f, base_path));
+        let level = match v.severity {
+            Severity::Critical | Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        };
+        let message = v.message.split_whitespace().collect::<Vec<_>>().join(" ");
+        buf.push_str(&format!(
+            "{}:{}:1: {}: [{}] {}\n",
+            path,
+            v.line.max(1),
+            level,
+            v.rule.as_str(),
+            message
+        ));
+    }
+    buf
+}
+
+/// Most violations listed in a step summary; the rest are counted.
+const STEP_SUMMARY_MAX_ROWS: usize = 50;
+
+/// Render a Markdown job summary for `$GITHUB_STEP_SUMMARY`.
+pub fn render_step_summary(base_path: &Path, result: &DetectionResult, score: &HollownessScore) -> String {
+    let mut buf = String::new();
+    let status = if score.passed { "✅ Passed" } else { "❌ Failed" };
+    buf.push_str("## hollowcheck\n\n");
+    buf.push_str(&format!(
+        "**{}**: hollowness {}% (grade {}, threshold {})\n\n",
+        status, score.score, score.grade, score.threshold
+    ));
+
+    if result.violations.is_empty() {
+        buf.push_str("No violations.\n");
+        return buf;
+    }
+
+    buf.push_str("| Severity | Rule | Location | Message |\n");
+    buf.push_str("|----------|------|----------|---------|\n");
+    for v in result.violations.iter().take(STEP_SUMMARY_MAX_ROWS) {
+        let location = match &v.file {
+            Some(file) if v.line > 0 => format!("`{}:{}`", make_relative_path(file, base_path), v.line),
+            Some(file) => format!("`{}`", make_relative_path(file, base_path)),
+            None => "(project)".to_string(),
+        };
+        buf.push_str(&format!(
+            "| {} | `{}` | {} | {} |\n",
+            v.severity,
+            v.rule.as_str(),
+            location,
+            v.message.replace('|', "\\|").replace('\n', " ")
+        ));
+    }
+    if result.violations.len() > STEP_SUMMARY_MAX_ROWS {
+        buf.push_str(&format!(
+            "\n…and {} more violations.\n",
+            result.violations.len() - STEP_SUMMARY_MAX_ROWS
+        ));
+    }
+    buf
+}
+
+// =============================================================================
+// Severity Summary
+// =============================================================================
+
+/// Render a one-line status for shell prompts and status bars, such as
+/// `hollowcheck: 3E 5W 1I (D, 42%)`.
+///
+/// Counts are listed from critical to info and severities without
+/// violations are left out; a run with no violations reads `clean`.
+pub fn render_severity_summary(result: &DetectionResult, score: &HollownessScore) -> String {
+    let counts: Vec<String> = [
+        (Severity::Critical, 'C'),
+        (Severity::Error, 'E'),
+        (Severity::Warning, 'W'),
+        (Severity::Info, 'I'),
+    ]
+    .into_iter()
+    .filter_map(|(severity, letter)| {
+        let count = result.violations.iter().filter(|v| v.severity == severity).count();
+        (count > 0).then(|| format!("{}{}", count, letter))
+    })
+    .collect();
+
+    let counts = if counts.is_empty() {
+        "clean".to_string()
+    } else {
+        counts.join(" ")
+    };
+    format!("hollowcheck: {} ({}, {}%)", counts, score.grade, score.score)
+}
+
+/// Render violation counts per rule, one `rule_id: count` line each in rule
+/// order, followed by `total: N`.
+///
+/// Suppressed and waived violations are not counted, so the numbers match
+/// what a report would show.
+pub fn render_rule_counts(result: &DetectionResult) -> String {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for v in &result.violations {
+        *counts.entry(v.rule.as_str()).or_default() += 1;
+    }
+
+    let mut buf = String::new();
+    for (rule, count) in &counts {
+        writeln!(buf, "{}: {}", rule, count).unwrap();
+    }
+    write!(buf, "total: {}", result.violations.len()).unwrap();
+    buf
+}
+
+// =============================================================================
+// Waiver Listing
+// =============================================================================
+
+/// Render contract waivers and their status as JSON.
+pub fn render_waivers_json(entries: &[WaiverEntry]) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(entries)?)
+}
+
+/// Render contract waivers and their status for humans, soonest expiry first.
+pub fn render_waivers_pretty(entries: &[WaiverEntry], today: &str) -> String {
+    let mut buf = String::new();
+    writeln!(buf).unwrap();
+    if entries.is_empty() {
+        writeln!(buf, "  {}", "No waivers in the contract".dimmed()).unwrap();
+        writeln!(buf).unwrap();
+        return buf;
+    }
+
+    let count = |status: WaiverStatus| entries.iter().filter(|e| e.status == status).count();
+    writeln!(
+        buf,
+        "  Waivers: {} active, {} expiring, {} expired  {}",
+        count(WaiverStatus::Active),
+        count(WaiverStatus::Expiring).to_string().yellow(),
+        count(WaiverStatus::Expired).to_string().red(),
+        format!("(as of {})", today).dimmed()
+    )
+    .unwrap();
+    writeln!(buf).unwrap();
+
+    for e in entries {
+        let w = &e.waiver;
+        let status = match e.status {
+            WaiverStatus::Active => format!("{:<9}", e.status.as_str()).green(),
+            WaiverStatus::Expiring => format!("{:<9}", e.status.as_str()).yellow(),
+            WaiverStatus::Expired => format!("{:<9}", e.status.as_str()).red(),
+        };
+        let when = match e.days_left {
+            Some(days) if days < 0 => format!("expired {} ({} days ago)", w.expires, -days),
+            Some(0) => format!("expires {} (today)", w.expires),
+            Some(days) => format!("expires {} (in {} days)", w.expires, days),
+            None => format!("expires {:?}", w.expires),
+        };
+        writeln!(
+            buf,
+            "    {} {:<18}{}",
+            status,
+            w.rule.dimmed(),
+            crate::detect::waiver_target(w).blue()
+        )
+        .unwrap();
+        writeln!(buf, "              {}, owner {}", when, w.owner).unwrap();
+        writeln!(buf, "              {}", w.justification.dimmed()).unwrap();
+    }
+    writeln!(buf).unwrap();
+    buf
+}
+
+// =============================================================================
+// GitLab Code Quality Format
+// =============================================================================
+
+/// One entry of a GitLab code quality report.
+#[derive(Serialize, Deserialize)]
+pub struct GitlabIssue {
+    pub description: String,
+    pub check_name: String,
+    pub fingerprint: String,
+    /// One of `info`, `minor`, `major`, `critical`, `blocker`.
+    pub severity: String,
+    pub location: GitlabLocation,
+}
+
+/// Location of a GitLab code quality issue.
+#[derive(Serialize, Deserialize)]
+pub struct GitlabLocation {
+    pub path: String,
+    pub lines: GitlabLines,
+}
+
+/// Line range of a GitLab code quality issue.
+#[derive(Serialize, Deserialize)]
+pub struct GitlabLines {
+    pub begin: usize,
+}
+
+fn map_severity_to_gitlab(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Info => "info",
+    }
+}
+
+/// Render results as a GitLab code quality report.
+///
+/// Fingerprints are the canonical violation fingerprints, which number
+/// repeats of one violation in a file, so GitLab does not merge them.
+/// Project-level violations are reported against the project root (`.`).
+pub fn render_gitlab(base_path: &Path, result: &DetectionResult) -> anyhow::Result<String> {
+    let issues: Vec<GitlabIssue> = result
+        .violations
+        .iter()
+        .map(|v| {
+            let path = v.file.as_deref().map(|f| make_relative_path(f, base_path));
+            GitlabIssue {
+                description: v.message.clone(),
+                check_name: v.rule.as_str().to_string(),
+                fingerprint: result.fingerprint(v),
+                severity: map_severity_to_gitlab(&v.severity).to_string(),
+                location: GitlabLocation {
+                    path: path.unwrap_or_else(|| ".".to_string()),
+                    lines: GitlabLines { begin: v.line.max(1) },
+                },
+            }
+        })
+        .collect();
+
+    Ok(serde_json::to_string_pretty(&issues)?)
+}
+
+// =============================================================================
+// Cargo Format (`cargo hollowcheck`)
+// =============================================================================
+
+/// The source line a violation points at, for code frames and spans.
+struct SourceLine {
+    text: String,
+    /// Byte offset in the file of the line's first non-blank character
+    byte_start: usize,
+    /// 1-based columns of the line's first and one past its last non-blank character
+    column_start: usize,
+    column_end: usize,
+}
+
+impl SourceLine {
+    fn read(path: &Path, line: usize) -> Option<Self> {
+        if line == 0 {
+            return None;
+        }
+        let content = std::fs::read_to_string(path).ok()?;
+        let mut offset = 0;
+        for raw in content.split_inclusive('\n').take(line - 1) {
+            offset += raw.len();
+        }
+        let text = content[offset..].lines().next()?.trim_end().to_string();
+        let indent = text.len() - text.trim_start().len();
+        Some(Self {
+            byte_start: offset + indent,
+            column_start: text[..indent].chars().count() + 1,
+            column_end: text.chars().count() + 1,
+            text,
+        })
+    }
+
+    fn byte_end(&self) -> usize {
+        self.byte_start + self.text.trim_start().len()
+    }
+}
+
+/// Absolute path of a violation's file.
+fn violation_path(file: &str, base_path: &Path) -> PathBuf {
+    let path = Path::new(file);
+    if path.is_absolute() || base_path.is_file() {
+        path.to_path_buf()
+    } else {
+        base_path.join(path)
+    }
+}
+
+/// Render one violation the way rustc renders a diagnostic.
+fn render_cargo_diagnostic(v: &Violation, base_path: &Path, source: Option<&SourceLine>, color: bool) -> String {
+    let level = map_severity_to_level(&v.severity);
+    let paint = |text: &str, c: Option<Color>| match c {
+        _ if !color => text.to_string(),
+        Some(c) => text.color(c).bold().to_string(),
+        None => text.bold().to_string(),
+    };
+    let level_color = Some(match v.severity {
+        Severity::Critical | Severity::Error => Color::Red,
+        Severity::Warning => Color::Yellow,
+        Severity::Info => Color::Green,
+    });
+    let blue = Some(Color::Blue);
+
+    let mut buf = String::new();
+    let _ = writeln!(buf, "{}{}", paint(level, level_color), paint(&format!(": {}", v.rule.as_str()), None));
+    let Some(file) = &v.file else {
+        let _ = writeln!(buf, "  {} {}: {}", paint("=", blue), paint("note", None), v.message);
+        return buf;
+    };
+
+    let gutter = " ".repeat(v.line.max(1).to_string().len());
+    let column = source.map_or(1, |s| s.column_start);
+    let location = if v.line > 0 {
+        format!("{}:{}:{}", make_relative_path(file, base_path), v.line, column)
+    } else {
+        make_relative_path(file, base_path)
+    };
+    let _ = writeln!(buf, "{}{} {}", gutter, paint("-->", blue), location);
+    let bar = paint("|", blue);
+    if let Some(source) = source {
+        let width = source.column_end.saturating_sub(source.column_start).max(1);
+        let _ = writeln!(buf, "{} {}", gutter, bar);
+        let _ = writeln!(buf, "{} {} {}", paint(&v.line.to_string(), blue), bar, source.text);
+        let _ = writeln!(
+            buf,
+            "{} {} {}{}",
+            gutter,
+            bar,
+            " ".repeat(source.column_start - 1),
+            paint(&"^".repeat(width), level_color)
+        );
+    }
+    let _ = writeln!(buf, "{} {}", gutter, bar);
+    let _ = writeln!(buf, "{} {} {}: {}", gutter, paint("=", blue), paint("note", None), v.message);
+    buf
+}
+
+/// Render violations as rustc-style diagnostics with code frames, for stderr.
+pub fn render_cargo_diagnostics(base_path: &Path, result: &DetectionResult, score: &HollownessScore, color: bool) -> String {
+    let mut buf = String::new();
+    for v in &result.violations {
+        let source = v
+            .file
+            .as_deref()
+            .and_then(|file| SourceLine::read(&violation_path(file, base_path), v.line));
+        buf.push_str(&render_cargo_diagnostic(v, base_path, source.as_ref(), color));
+        buf.push('\n');
+    }
+
+    let count = result.violations.len();
+    let noun = if count == 1 { "violation" } else { "violations" };
+    if !score.passed {
+        let _ = writeln!(
+            buf,
+            "{}: hollowcheck found {} {}: hollowness {}% exceeds threshold {}",
+            if color { "error".red().bold().to_string() } else { "error".to_string() },
+            count,
+            noun,
+            score.score,
+            score.threshold
+        );
+    } else if count > 0 {
+        let _ = writeln!(
+            buf,
+            "{}: hollowcheck found {} {}: hollowness {}% within threshold {}",
+            if color { "warning".yellow().bold().to_string() } else { "warning".to_string() },
+            count,
+            noun,
+            score.score,
+            score.threshold
+        );
+    }
+    buf
+}
+
+/// Render violations as Cargo `--message-format json` lines.
+///
+/// Each violation is a `compiler-message` attributed to `package`, with the
+/// rustc diagnostic shape IDEs already parse; a final `build-finished` line
+/// carries whether the quality gate passed.
+pub fn render_cargo_messages(
+    base_path: &Path,
+    package: &Package,
+    result: &DetectionResult,
+    score: &HollownessScore,
+) -> anyhow::Result<String> {
+    let target = serde_json::json!({
+        "kind": ["lib"],
+        "crate_types": ["lib"],
+        "name": package.name,
+        "src_path": package.dir().join("src").join("lib.rs"),
+        "edition": "2021",
+        "doc": false,
+        "doctest": false,
+        "test": false,
+    });
+
+    let mut lines = Vec::new();
+    for v in &result.violations {
+        let path = v.file.as_deref().map(|file| violation_path(file, base_path));
+        let source = path.as_deref().and_then(|p| SourceLine::read(p, v.line));
+        let spans = match &path {
+            Some(path) => {
+                let line = v.line.max(1);
+                let (byte_start, byte_end, column_start, column_end, text) = match &source {
+                    Some(s) => (s.byte_start, s.byte_end(), s.column_start, s.column_end, s.text.clone()),
+                    None => (0, 0, 1, 1, String::new()),
+                };
+                vec![serde_json::json!({
+                    "file_name": path,
+                    "byte_start": byte_start,
+                    "byte_end": byte_end,
+                    "line_start": line,
+                    "line_end": line,
+                    "column_start": column_start,
+                    "column_end": column_end,
+                    "is_primary": true,
+                    "text": [{"text": text, "highlight_start": column_start, "highlight_end": column_end}],
+                    "label": null,
+                    "suggested_replacement": null,
+                    "suggestion_applicability": null,
+                    "expansion": null,
+                })]
+            }
+            None => Vec::new(),
+        };
+        let message = serde_json::json!({
+            "reason": "compiler-message",
+            "package_id": package.id,
+            "manifest_path": package.manifest_path,
+            "target": target,
+            "message": {
+                "$message_type": "diagnostic",
+                "message": v.message,
+                "code": {"code": v.rule.as_str(), "explanation": null},
+                "level": map_severity_to_level(&v.severity),
+                "spans": spans,
+                "children": [],
+                "rendered": render_cargo_diagnostic(v, base_path, source.as_ref(), false),
+            },
+        });
+        lines.push(serde_json::to_string(&message)?);
+    }
+    lines.push(serde_json::to_string(&serde_json::json!({
+        "reason": "build-finished",
+        "success": score.passed,
+    }))?);
+    Ok(lines.join("\n"))
 }
 
 // =============================================================================
@@ -475,6 +1483,24 @@ pub fn write_sarif(base_path: &Path, result: &DetectionResult) -> anyhow::Result
 use std::fmt::Write as FmtWrite;
 use std::io::Write;
 
+use crate::theme::{score_band, Palette, Style, Theme};
+
+/// Options controlling pretty output.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PrettyOptions {
+    /// List suppressed violations instead of only counting them.
+    pub show_suppressed: bool,
+    /// Print each violation's stable fingerprint under its message.
+    pub show_fingerprints: bool,
+    /// In baseline mode, list only new violations and count the rest.
+    pub only_new: bool,
+    /// Colors and severity symbols.
+    pub theme: Theme,
+    /// Force colors on or off; `None` follows the `colored` crate's
+    /// settings (terminal detection, `NO_COLOR`).
+    pub color: Option<bool>,
+}
+
 /// Write results in pretty (human-readable) format.
 ///
 /// Uses buffered output for better performance - all formatting is done
@@ -484,141 +1510,349 @@ pub fn write_pretty(
     contract_path: &str,
     result: &DetectionResult,
     score: &HollownessScore,
-    show_suppressed: bool,
+    options: PrettyOptions,
 ) {
+    let buf = render_pretty(path, contract_path, result, score, options);
+
+    // Write all output at once
+    let stdout = std::io::stdout();
+    let mut handle = stdout.lock();
+    let _ = handle.write_all(buf.as_bytes());
+}
+
+/// Render results in pretty format in the options' theme.
+pub fn render_pretty(
+    path: &str,
+    contract_path: &str,
+    result: &DetectionResult,
+    score: &HollownessScore,
+    options: PrettyOptions,
+) -> String {
+    let color = options.color.unwrap_or_else(|| colored::control::SHOULD_COLORIZE.should_colorize());
+    let p = Palette::new(options.theme, color);
     let mut buf = String::with_capacity(4096);
 
     // Header
     writeln!(buf).unwrap();
-    write!(buf, "  {}", "hollowcheck".cyan().bold()).unwrap();
+    write!(buf, "  {}", p.paint(Style::Banner, "hollowcheck")).unwrap();
     writeln!(buf, " v{}", env!("CARGO_PKG_VERSION")).unwrap();
     writeln!(buf).unwrap();
 
     // Scan info
-    write!(buf, "  {}", "Scanning: ".dimmed()).unwrap();
+    write!(buf, "  {}", p.paint(Style::Muted, "Scanning: ")).unwrap();
     writeln!(buf, "{}", path).unwrap();
-    write!(buf, "  {}", "Contract: ".dimmed()).unwrap();
+    write!(buf, "  {}", p.paint(Style::Muted, "Contract: ")).unwrap();
     writeln!(buf, "{}", contract_path).unwrap();
 
+    // Files judged by forbidden_file checks (not the same as excluded)
+    if result.rejected > 0 {
+        write!(buf, "  {}", p.paint(Style::Muted, "Rejected: ")).unwrap();
+        writeln!(buf, "{} forbidden file(s)", result.rejected).unwrap();
+    }
+    if result.language_excluded > 0 {
+        write!(buf, "  {}", p.paint(Style::Muted, "Excluded: ")).unwrap();
+        writeln!(buf, "{} file(s) by language filter", result.language_excluded).unwrap();
+    }
+    if result.errored > 0 {
+        write!(buf, "  {}", p.paint(Style::Muted, "Errored:  ")).unwrap();
+        writeln!(buf, "{} file(s) could not be analyzed", result.errored).unwrap();
+    }
+    if result.generated > 0 {
+        write!(buf, "  {}", p.paint(Style::Muted, "Generated: ")).unwrap();
+        writeln!(buf, "{} file(s) skipped or checked by fewer rules", result.generated).unwrap();
+    }
+    if result.generated_modified > 0 {
+        write!(buf, "  {}", p.paint(Style::Muted, "Edited:   ")).unwrap();
+        writeln!(
+            buf,
+            "{} generated file(s) edited by hand, checked outside generated blocks",
+            result.generated_modified
+        )
+        .unwrap();
+    }
+    if let Some(coverage) = result.stats.doc_coverage.as_ref().filter(|c| c.total > 0) {
+        write!(buf, "  {}", p.paint(Style::Muted, "Docs:     ")).unwrap();
+        writeln!(
+            buf,
+            "{} of {} exported declarations documented ({:.1}%)",
+            coverage.documented,
+            coverage.total,
+            coverage.coverage * 100.0
+        )
+        .unwrap();
+    }
+    if let Some(effort) = result.stats.effort.as_ref().filter(|e| e.total() > 0) {
+        let buckets: Vec<String> = EffortBucket::ALL
+            .iter()
+            .rev()
+            .filter(|b| effort.count(**b) > 0)
+            .map(|b| format!("{} {}", effort.count(*b), b))
+            .collect();
+        write!(buf, "  {}", p.paint(Style::Muted, "Effort:   ")).unwrap();
+        writeln!(buf, "estimated remaining work: {} (heuristic)", buckets.join(", ")).unwrap();
+    }
+
     // Show baseline ref if in baseline mode
     if let Some(ref baseline) = result.baseline_ref {
-        write!(buf, "  {}", "Baseline: ".dimmed()).unwrap();
+        write!(buf, "  {}", p.paint(Style::Muted, "Baseline: ")).unwrap();
         writeln!(buf, "{}", baseline).unwrap();
     }
     writeln!(buf).unwrap();
 
     // Result summary
-    write_result_summary_buf(&mut buf, score, result.suppressed.len());
+    write_result_summary_buf(&mut buf, &p, score, result.suppressed.len(), result.waived.len());
     writeln!(buf).unwrap();
 
-    // Violations
-    if !result.violations.is_empty() {
-        write_violations_buf(&mut buf, &result.violations);
+    // Violations, with project-level findings in their own section
+    let only_new = options.only_new && result.is_baseline_mode();
+    let (title, listed, truncated) = if only_new {
+        ("New violations", &result.new_violations, &result.new_truncated)
+    } else {
+        ("Violations", &result.violations, &result.truncated)
+    };
+    let (project, in_files): (Vec<&Violation>, Vec<&Violation>) =
+        listed.iter().partition(|v| v.is_project_level());
+    let fingerprint = |v: &Violation| options.show_fingerprints.then(|| result.fingerprint(v));
+    if !in_files.is_empty() {
+        write_violations_buf(&mut buf, &p, title, &in_files, fingerprint);
+        writeln!(buf).unwrap();
+    }
+    if !project.is_empty() {
+        write_project_violations_buf(&mut buf, &p, &project, fingerprint);
+        writeln!(buf).unwrap();
+    }
+
+    // The baselined violations collapse to a count
+    if only_new {
+        let total = result.violations.len() + result.truncated.values().sum::<usize>();
+        let new = result.new_violations.len() + result.new_truncated.values().sum::<usize>();
+        let baselined = total.saturating_sub(new);
+        if baselined > 0 {
+            let line = format!(
+                "{} pre-existing violation{} in the baseline not shown",
+                baselined,
+                if baselined == 1 { "" } else { "s" }
+            );
+            writeln!(buf, "  {}", p.paint(Style::Muted, &line)).unwrap();
+            writeln!(buf).unwrap();
+        }
+    }
+
+    // Rules whose violations were cut short by the report limits
+    if !truncated.is_empty() {
+        write_truncated_buf(&mut buf, &p, listed, truncated);
         writeln!(buf).unwrap();
     }
 
     // Suppressed violations
     if !result.suppressed.is_empty() {
-        write_suppressed_summary_buf(&mut buf, &result.suppressed, show_suppressed);
+        write_suppressed_summary_buf(&mut buf, &p, &result.suppressed, options.show_suppressed);
+        writeln!(buf).unwrap();
+    }
+
+    // Waived violations, one line per waiver
+    if !result.waived.is_empty() {
+        write_waived_summary_buf(&mut buf, &p, &result.waived);
         writeln!(buf).unwrap();
     }
 
     // Breakdown
     if !score.breakdown.is_empty() {
-        write_breakdown_buf(&mut buf, score);
+        write_breakdown_buf(&mut buf, &p, score);
+        writeln!(buf).unwrap();
+    }
+
+    // Known violations kept out of the score
+    if score.debt.violations() > 0 || score.debt.max_debt.is_some() {
+        write_debt_buf(&mut buf, &p, &score.debt);
         writeln!(buf).unwrap();
     }
 
     // Final status line
-    write_final_status_buf(&mut buf, score);
+    write_final_status_buf(&mut buf, &p, score);
     writeln!(buf).unwrap();
 
-    // Write all output at once
-    let stdout = std::io::stdout();
-    let mut handle = stdout.lock();
-    let _ = handle.write_all(buf.as_bytes());
+    buf
 }
 
-fn write_result_summary_buf(buf: &mut String, score: &HollownessScore, suppressed_count: usize) {
+fn write_result_summary_buf(
+    buf: &mut String,
+    p: &Palette,
+    score: &HollownessScore,
+    suppressed_count: usize,
+    waived_count: usize,
+) {
     if score.passed {
-        write!(buf, "  {}", "✓ PASS".green()).unwrap();
+        write!(buf, "  {}", p.paint(Style::Good, "✓ PASS")).unwrap();
     } else {
-        write!(buf, "  {}", "✗ FAIL".red()).unwrap();
+        write!(buf, "  {}", p.paint(Style::Bad, "✗ FAIL")).unwrap();
     }
 
     write!(buf, "  Hollowness: ").unwrap();
-    write_colored_score_buf(buf, score.score);
-    write!(buf, "%  Grade: ").unwrap();
-    write_colored_grade_buf(buf, &score.grade);
+    write!(buf, "{}", p.paint(p.score_style(score.score), &score.score.to_string())).unwrap();
+    write!(buf, "% ({})  Grade: ", score_band(score.score)).unwrap();
+    write!(buf, "{}", p.paint(p.score_style(score.score), &score.grade)).unwrap();
+    write_sub_scores_buf(buf, p, &score.sub_scores);
 
     if suppressed_count > 0 {
         write!(
             buf,
             "  {}",
-            format!("({} suppressed)", suppressed_count).dimmed()
+            p.paint(Style::Muted, &format!("({} suppressed)", suppressed_count))
         ).unwrap();
     }
+    if waived_count > 0 {
+        write!(buf, "  {}", p.paint(Style::Muted, &format!("({} waived)", waived_count))).unwrap();
+    }
 
     writeln!(buf).unwrap();
 }
 
-fn write_colored_score_buf(buf: &mut String, s: i32) {
-    match s {
-        s if s <= 10 => write!(buf, "{}", s.to_string().green().bold()).unwrap(),
-        s if s <= 25 => write!(buf, "{}", s.to_string().green()).unwrap(),
-        s if s <= 50 => write!(buf, "{}", s.to_string().yellow()).unwrap(),
-        s if s <= 75 => write!(buf, "{}", s.to_string().yellow().bold()).unwrap(),
-        _ => write!(buf, "{}", s.to_string().red()).unwrap(),
-    }
-}
-
-fn write_colored_grade_buf(buf: &mut String, grade: &str) {
-    match grade {
-        "A" => write!(buf, "{}", grade.green().bold()).unwrap(),
-        "B" => write!(buf, "{}", grade.green()).unwrap(),
-        "C" => write!(buf, "{}", grade.yellow()).unwrap(),
-        "D" => write!(buf, "{}", grade.yellow().bold()).unwrap(),
-        _ => write!(buf, "{}", grade.red()).unwrap(),
-    }
+/// `Structural: 31, Content: 12`; a sub-score over its threshold says so
+/// and is painted as failing.
+fn write_sub_scores_buf(buf: &mut String, p: &Palette, sub_scores: &SubScores) {
+    let sub_score = |s: &SubScore| {
+        let text = match (s.threshold, s.passed) {
+            (Some(max), true) => format!("{} (max {})", s.score, max),
+            (Some(max), false) => format!("{} (max {}, exceeded)", s.score, max),
+            (None, _) => s.score.to_string(),
+        };
+        if s.passed {
+            text
+        } else {
+            p.paint(Style::Bad, &text)
+        }
+    };
+    write!(
+        buf,
+        "  {} {}, {} {}",
+        p.paint(Style::Muted, "Structural:"),
+        sub_score(&sub_scores.structural),
+        p.paint(Style::Muted, "Content:"),
+        sub_score(&sub_scores.content)
+    )
+    .unwrap();
 }
 
-fn write_violations_buf(buf: &mut String, violations: &[Violation]) {
-    writeln!(buf, "  {} ({}):", "Violations".bold(), violations.len()).unwrap();
+fn write_violations_buf(
+    buf: &mut String,
+    p: &Palette,
+    title: &str,
+    violations: &[&Violation],
+    fingerprint: impl Fn(&Violation) -> Option<String>,
+) {
+    writeln!(buf, "  {} ({}):", p.paint(Style::Heading, title), violations.len()).unwrap();
     writeln!(buf).unwrap();
 
     for v in violations {
-        write_severity_tag_buf(buf, &v.severity);
+        write_severity_tag_buf(buf, p, &v.severity);
         write!(buf, "   ").unwrap();
-        write!(buf, "{:<18}", v.rule.as_str().dimmed()).unwrap();
-        write!(buf, "{}", v.file.blue()).unwrap();
+        write!(buf, "{}", p.paint(Style::Muted, &format!("{:<18}", v.rule.as_str()))).unwrap();
+        write!(buf, "{}", p.paint(Style::Location, v.location())).unwrap();
         if v.line > 0 {
-            write!(buf, "{}", format!(":{}", v.line).dimmed()).unwrap();
+            write!(buf, "{}", p.paint(Style::Muted, &format!(":{}", v.line))).unwrap();
+        }
+        if let Some(language) = v.language_override() {
+            write!(buf, " {}", p.paint(Style::Muted, &format!("(analyzed as {})", language))).unwrap();
         }
         writeln!(buf).unwrap();
 
         // Message on next line, indented
         writeln!(buf, "            {}", v.message).unwrap();
+        if let Some(fp) = fingerprint(v) {
+            writeln!(buf, "            {}", p.paint(Style::Muted, &format!("fingerprint: {}", fp))).unwrap();
+        }
         writeln!(buf).unwrap();
     }
 }
 
-fn write_severity_tag_buf(buf: &mut String, severity: &Severity) {
-    match severity {
-        Severity::Critical => write!(buf, "    {} ", "CRIT ".red().bold()).unwrap(),
-        Severity::Error => write!(buf, "    {} ", "ERROR".red()).unwrap(),
-        Severity::Warning => write!(buf, "    {} ", "WARN ".yellow()).unwrap(),
-        Severity::Info => write!(buf, "    {} ", "INFO ".blue()).unwrap(),
+/// Violations about the project as a whole; there is no file to group by,
+/// so the message goes on the same line as the rule.
+fn write_project_violations_buf(
+    buf: &mut String,
+    p: &Palette,
+    violations: &[&Violation],
+    fingerprint: impl Fn(&Violation) -> Option<String>,
+) {
+    writeln!(buf, "  {} ({}):", p.paint(Style::Heading, "Project"), violations.len()).unwrap();
+    writeln!(buf).unwrap();
+
+    for v in violations {
+        write_severity_tag_buf(buf, p, &v.severity);
+        write!(buf, "   ").unwrap();
+        write!(buf, "{}", p.paint(Style::Muted, &format!("{:<18}", v.rule.as_str()))).unwrap();
+        write!(buf, "{}", v.message).unwrap();
+        if let Some(fp) = fingerprint(v) {
+            write!(buf, "  {}", p.paint(Style::Muted, &format!("[{}]", fp))).unwrap();
+        }
+        writeln!(buf).unwrap();
     }
 }
 
-fn write_breakdown_buf(buf: &mut String, score: &HollownessScore) {
-    writeln!(buf, "  {}", "Breakdown:".bold()).unwrap();
+/// Format a count with thousands separators: 48112 -> "48,112".
+fn group_digits(n: usize) -> String {
+    let digits = n.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+/// One line per truncated rule: "mock_data: showing 200 of 48,112".
+fn write_truncated_buf(
+    buf: &mut String,
+    p: &Palette,
+    listed: &[Violation],
+    truncated: &BTreeMap<String, usize>,
+) {
+    writeln!(buf, "  {}:", p.paint(Style::Muted, "Truncated")).unwrap();
+    for (rule, omitted) in truncated {
+        let shown = listed.iter().filter(|v| v.rule.as_str() == rule).count();
+        writeln!(
+            buf,
+            "    {}: showing {} of {}",
+            rule,
+            group_digits(shown),
+            group_digits(shown + omitted)
+        )
+        .unwrap();
+    }
+}
+
+/// `ERROR`, or `✖ ERROR` in themes with severity symbols.
+fn write_severity_tag_buf(buf: &mut String, p: &Palette, severity: &Severity) {
+    let tag = match severity {
+        Severity::Critical => "CRIT ",
+        Severity::Error => "ERROR",
+        Severity::Warning => "WARN ",
+        Severity::Info => "INFO ",
+    };
+    let tag = match p.severity_symbol(severity) {
+        Some(symbol) => format!("{} {}", symbol, tag),
+        None => tag.to_string(),
+    };
+    write!(buf, "    {} ", p.paint(p.severity_style(severity), &tag)).unwrap();
+}
+
+fn write_breakdown_buf(buf: &mut String, p: &Palette, score: &HollownessScore) {
+    writeln!(buf, "  {}", p.paint(Style::Heading, "Breakdown:")).unwrap();
 
-    // Sort rules by points descending
+    // Sort rules by points descending, clean rules last
     let mut rules: Vec<(&String, &i32)> = score.breakdown.iter().collect();
-    rules.sort_by(|a, b| b.1.cmp(a.1));
+    rules.sort_by(|a, b| {
+        (score.is_clean(a.0), b.1, a.0).cmp(&(score.is_clean(b.0), a.1, b.0))
+    });
 
     for (rule, points) in rules {
+        if score.is_clean(rule) {
+            writeln!(buf, "{}", p.paint(Style::Muted, &format!("    {:<20} {:>3} pts (0 violations, checked)", rule, points)))
+                .unwrap();
+            continue;
+        }
         let count = score.violation_count(rule);
         let plural = if count != 1 { "s" } else { "" };
         writeln!(
@@ -629,25 +1863,86 @@ fn write_breakdown_buf(buf: &mut String, score: &HollownessScore) {
     }
 }
 
-fn write_final_status_buf(buf: &mut String, score: &HollownessScore) {
-    write!(buf, "  {}", format!("Threshold: {}", score.threshold).dimmed()).unwrap();
+fn write_final_status_buf(buf: &mut String, p: &Palette, score: &HollownessScore) {
+    write!(buf, "  {}", p.paint(Style::Muted, &format!("Threshold: {}", score.threshold))).unwrap();
     write!(buf, "  Score: ").unwrap();
-    write_colored_score_buf(buf, score.score);
-    write!(buf, "  ").unwrap();
+    write!(buf, "{}", p.paint(p.score_style(score.score), &score.score.to_string())).unwrap();
+    write!(buf, " ({})  ", score_band(score.score)).unwrap();
 
     if score.passed {
-        write!(buf, "{}", "PASSED".green()).unwrap();
+        write!(buf, "{}", p.paint(Style::Good, "PASSED")).unwrap();
+    } else {
+        write!(buf, "{}", p.paint(Style::Bad, "FAILED")).unwrap();
+    }
+    writeln!(buf).unwrap();
+}
+
+/// One line: debt points, then violations per mechanism and the limit.
+fn write_debt_buf(buf: &mut String, p: &Palette, debt: &DebtScore) {
+    let mechanisms: Vec<String> = [
+        ("inline_suppression", "suppressed"),
+        ("suppression_file", "in suppression file"),
+        ("downgrade", "downgraded"),
+        ("waiver", "waived"),
+        ("baseline", "baselined"),
+    ]
+    .iter()
+    .filter_map(|(key, label)| debt.by_mechanism.get(*key).map(|e| format!("{} {}", e.violations, label)))
+    .collect();
+
+    write!(buf, "  {} ", p.paint(Style::Muted, "Debt:")).unwrap();
+    let points = format!("{} points", debt.score);
+    if debt.passed {
+        write!(buf, "{}", points).unwrap();
     } else {
-        write!(buf, "{}", "FAILED".red()).unwrap();
+        write!(buf, "{}", p.paint(Style::Bad, &points)).unwrap();
+    }
+    if !mechanisms.is_empty() {
+        write!(buf, " {}", p.paint(Style::Muted, &format!("({})", mechanisms.join(", ")))).unwrap();
+    }
+    match (debt.max_debt, debt.passed) {
+        (Some(max), true) => write!(buf, "  {}", p.paint(Style::Muted, &format!("max {}", max))).unwrap(),
+        (Some(max), false) => write!(buf, "  {}", p.paint(Style::Bad, &format!("max {}, exceeded", max))).unwrap(),
+        (None, _) => {}
     }
     writeln!(buf).unwrap();
 }
 
-fn write_suppressed_summary_buf(buf: &mut String, suppressed: &[SuppressedViolation], show_details: bool) {
-    writeln!(buf, "  {} ({}):", "Suppressed".dimmed(), suppressed.len()).unwrap();
+fn write_waived_summary_buf(buf: &mut String, p: &Palette, waived: &[WaivedViolation]) {
+    writeln!(buf, "  {} ({}):", p.paint(Style::Muted, "Waived"), waived.len()).unwrap();
+    writeln!(buf).unwrap();
+
+    // Group by waiver, in contract order of first use
+    let mut groups: Vec<(&Waiver, usize)> = Vec::new();
+    for wv in waived {
+        let w = &wv.waiver;
+        match groups.iter_mut().find(|(g, _)| {
+            g.rule == w.rule && g.path == w.path && g.fingerprint == w.fingerprint && g.expires == w.expires
+        }) {
+            Some((_, count)) => *count += 1,
+            None => groups.push((w, 1)),
+        }
+    }
+
+    for (w, count) in groups {
+        let plural = if count != 1 { "s" } else { "" };
+        write!(buf, "    {}", p.paint(Style::Muted, &format!("{:<18}", w.rule))).unwrap();
+        write!(buf, "{}", p.paint(Style::Location, &crate::detect::waiver_target(w))).unwrap();
+        writeln!(
+            buf,
+            "  {}",
+            p.paint(Style::Muted, &format!("{} violation{}, {} until {}", count, plural, w.owner, w.expires))
+        )
+        .unwrap();
+        writeln!(buf, "            {}", p.paint(Style::Muted, &w.justification)).unwrap();
+    }
+}
+
+fn write_suppressed_summary_buf(buf: &mut String, p: &Palette, suppressed: &[SuppressedViolation], show_details: bool) {
+    writeln!(buf, "  {} ({}):", p.paint(Style::Muted, "Suppressed"), suppressed.len()).unwrap();
 
     if !show_details {
-        writeln!(buf, "    {}", "(use --show-suppressed to see details)".dimmed()).unwrap();
+        writeln!(buf, "    {}", p.paint(Style::Muted, "(use --show-suppressed to see details)")).unwrap();
         return;
     }
 
@@ -656,17 +1951,20 @@ fn write_suppressed_summary_buf(buf: &mut String, suppressed: &[SuppressedViolat
         let v = &sv.violation;
         let s = &sv.suppression;
 
-        write!(buf, "    {:<18}", v.rule.as_str().dimmed()).unwrap();
-        write!(buf, "{}", v.file.blue()).unwrap();
+        write!(buf, "    {}", p.paint(Style::Muted, &format!("{:<18}", v.rule.as_str()))).unwrap();
+        write!(buf, "{}", p.paint(Style::Location, v.location())).unwrap();
         if matches!(s.suppression_type, crate::detect::SuppressionType::File) {
-            write!(buf, "{}", ":* (file)".dimmed()).unwrap();
+            write!(buf, "{}", p.paint(Style::Muted, ":* (file)")).unwrap();
         } else if v.line > 0 {
-            write!(buf, "{}", format!(":{}", v.line).dimmed()).unwrap();
+            write!(buf, "{}", p.paint(Style::Muted, &format!(":{}", v.line))).unwrap();
+        }
+        if matches!(s.suppression_type, crate::detect::SuppressionType::Fingerprint) {
+            write!(buf, " {}", p.paint(Style::Muted, "(suppression file)")).unwrap();
         }
         writeln!(buf).unwrap();
 
         if !s.reason.is_empty() {
-            writeln!(buf, "            {}", format!("reason: {:?}", s.reason).dimmed()).unwrap();
+            writeln!(buf, "            {}", p.paint(Style::Muted, &format!("reason: {:?}", s.reason))).unwrap();
         }
     }
 }
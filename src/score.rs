@@ -1,12 +1,16 @@
 //! Scoring and grading system for hollowcheck.
 //!
 //! Calculates a hollowness score (0-100) based on violation counts and weights.
+//! The weights are the [`points`] constants unless the contract overrides
+//! them per rule (`points: {mock_data: 5}`), see [`PointWeights`].
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use crate::contract::Contract;
-use crate::detect::{DetectionResult, ViolationRule};
+use crate::contract::{Contract, SubScoresConfig};
+use crate::detect::{
+    DetectionResult, ScoreCategory, Severity, SuppressionType, Violation, ViolationRule,
+};
 
 /// Point weights for each violation type.
 pub mod points {
@@ -16,12 +20,52 @@ pub mod points {
     pub const FORBIDDEN_PATTERN: i32 = 10; // error
     pub const LOW_COMPLEXITY: i32 = 10; // error
     pub const STUB_FUNCTION: i32 = 10; // error - AST-detected hollow function
+    pub const INSUFFICIENT_IMPLEMENTATION: i32 = 10; // error - module mostly stubs
+    pub const FORBIDDEN_FILE: i32 = 10; // error - junk artifact in the deliverable
+    pub const CASE_COLLISION: i32 = 5; // error - checkout loses a file on macOS and Windows
+    pub const BAD_HEADER: i32 = 5; // error - wrong or placeholder license header
     pub const GOD_FILE: i32 = 8; // warning - architectural smell
     pub const GOD_FUNCTION: i32 = 8; // warning - architectural smell
     pub const GOD_CLASS: i32 = 8; // warning - architectural smell
+    pub const TOO_MANY_PARAMS: i32 = 3; // warning - under-abstracted signature
     pub const MISSING_TEST: i32 = 5; // warning
     pub const MOCK_DATA: i32 = 3; // warning
     pub const HOLLOW_TODO: i32 = 5; // warning - context-less TODO
+    pub const REPEATED_LITERAL: i32 = 3; // warning - magic string drift risk
+    pub const FLOATING_PROMISE: i32 = 5; // warning - unobserved async result
+    pub const EMPTY_HANDLER: i32 = 5; // warning - endpoint that answers but does nothing
+    pub const ANALYSIS_TIMEOUT: i32 = 3; // warning - file skipped, contents unchecked
+    pub const ANALYSIS_ERROR: i32 = 3; // warning - file skipped, contents unchecked
+    pub const UNIMPLEMENTED_INTERFACE: i32 = 5; // warning - abstraction with nothing behind it
+    pub const BUILTIN_SHADOWING: i32 = 3; // warning - later builtin calls hit the local name
+    pub const UNUSED_CONTEXT: i32 = 3; // warning - cancellation and deadlines silently ignored
+    pub const UNASSIGNED_NAMED_RETURN: i32 = 3; // warning - every path returns the zero value
+    pub const MISSING_DEFINITION: i32 = 5; // warning - documented API with nothing behind it
+    pub const UNWRAP_OVERUSE: i32 = 3; // warning - error handling skipped
+    pub const FORCE_UNWRAP: i32 = 3; // warning - crashes instead of handling nil or errors
+    pub const MUTABLE_DEFAULT: i32 = 3; // warning - state shared across calls
+    pub const WEAK_CRYPTO: i32 = 5; // warning - broken primitive or fixed IV/salt
+    pub const HOLLOW_CONTAINER: i32 = 8; // error - image that builds or runs nothing real
+    pub const HOLLOW_CI_CONFIG: i32 = 5; // error - pipeline that was never filled in
+    pub const HOLLOW_SCHEMA: i32 = 8; // error - API surface that describes nothing
+    pub const ENVIRONMENT_MISMATCH: i32 = 5; // error - built for a different runtime than specified
+    pub const HOLLOW_CONFIG: i32 = 8; // error - settings that were never filled in
+    pub const MISSING_LOCAL_MODULE: i32 = 10; // error - import that fails at load time
+    pub const TRUNCATED_CODE: i32 = 10; // error - function that stops mid-body
+    pub const NOOP_STATEMENT: i32 = 1; // info - padding that inflates statement counts
+    pub const REDUNDANT_ERROR_LOGGING: i32 = 1; // info - duplicate log lines, no lost behavior
+    pub const UNHANDLED_ENUM_CASES: i32 = 2; // info - variants dropped into a do-nothing default
+    pub const DOC_DRIFT: i32 = 1; // info - documented API that was never built
+    pub const EXPIRED_WAIVER: i32 = 1; // info - accepted debt past its date, violations are back
+    pub const UNUSED_SUPPRESSION: i32 = 1; // info - directive hygiene, nothing hidden
+    pub const DEPRECATED_USAGE: i32 = 1; // info - migration still outstanding
+    pub const TRIVIAL_WRAPPER: i32 = 1; // info - a layer that only renames, low confidence
+    pub const ASYNC_WITHOUT_AWAIT: i32 = 1; // info - async signature over synchronous code
+    pub const INCONSISTENT_ERROR_HANDLING: i32 = 1; // info - mixed error strategies, each works on its own
+    pub const PREMATURE_ABSTRACTION: i32 = 1; // info - indirection with one type behind it
+    pub const COMMENT_RATIO: i32 = 1; // info - comment density, a style signal
+    pub const PLACEHOLDER_CONSTANTS: i32 = 1; // info - constant values nobody decided on yet
+    pub const DOC_COVERAGE: i32 = 5; // info per declaration; error when coverage is below the gate
 
     // Prose-specific point weights
     pub const FILLER_PHRASE: i32 = 2; // warning
@@ -31,6 +75,10 @@ pub mod points {
     pub const MIDDLE_SAG: i32 = 8; // error
     pub const WEAK_TRANSITION: i32 = 2; // info
     pub const PROSE_DEFAULT: i32 = 2; // default for prose issues
+
+    /// Minimum points for any Critical violation, so a rule escalated to
+    /// Critical always outweighs an Error (10 points max).
+    pub const CRITICAL_MIN: i32 = 15;
 }
 
 /// Default threshold when the contract doesn't specify one.
@@ -53,10 +101,236 @@ pub struct HollownessScore {
     pub grade: String,
     /// Points by violation category
     pub breakdown: HashMap<String, i32>,
+    /// Number of violations by category
+    #[serde(default)]
+    pub counts: HashMap<String, i32>,
     /// Whether the check passed (score <= threshold)
     pub passed: bool,
     /// The threshold used
     pub threshold: i32,
+    /// Known violations kept out of the score by suppressions, downgrades,
+    /// waivers, and the baseline
+    #[serde(default)]
+    pub debt: DebtScore,
+    /// The score split into structural and content hollowness
+    #[serde(default)]
+    pub sub_scores: SubScores,
+    /// The contract's per-rule point overrides the score was calculated with
+    #[serde(default, skip_serializing_if = "PointWeights::is_default")]
+    pub points: PointWeights,
+}
+
+/// Points per violation of each rule: the built-in [`points`] weights, with
+/// the contract's overrides.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PointWeights(BTreeMap<String, i32>);
+
+impl PointWeights {
+    /// The contract's `points` overrides, keyed by canonical rule name.
+    pub fn from_contract(contract: &Contract) -> Self {
+        Self(
+            contract
+                .points
+                .iter()
+                .filter_map(|(name, points)| {
+                    let rule = name.parse::<ViolationRule>().ok()?;
+                    Some((rule.as_str().to_string(), *points))
+                })
+                .collect(),
+        )
+    }
+
+    /// Whether no rule's weight is overridden.
+    pub fn is_default(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Points per violation of a rule, by name.
+    pub fn rule(&self, rule: &str) -> i32 {
+        self.0.get(rule).copied().unwrap_or_else(|| get_points_for_rule(rule))
+    }
+
+    /// Points for a single violation, weighing Critical above Error.
+    fn violation(&self, v: &Violation) -> i32 {
+        let points = self.rule(v.rule.as_str());
+        match v.severity {
+            Severity::Critical => points.max(points::CRITICAL_MIN),
+            Severity::Error | Severity::Warning | Severity::Info => points,
+        }
+    }
+
+    /// Points a violation adds to the score: nothing below Error.
+    fn scoring(&self, v: &Violation) -> i32 {
+        if v.severity.counts_toward_score() {
+            self.violation(v)
+        } else {
+            0
+        }
+    }
+}
+
+/// One category's share of the score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubScore {
+    /// Points capped at 100, like the total score
+    pub score: i32,
+    /// Points before capping
+    pub points: i32,
+    /// The contract's threshold for this sub-score, if it sets one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threshold: Option<i32>,
+    /// Whether the sub-score is within its threshold
+    #[serde(default = "default_passed")]
+    pub passed: bool,
+}
+
+impl Default for SubScore {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            points: 0,
+            threshold: None,
+            passed: true,
+        }
+    }
+}
+
+impl SubScore {
+    fn new(points: i32, threshold: Option<i32>) -> Self {
+        let score = points.min(100);
+        Self {
+            score,
+            points,
+            threshold,
+            passed: threshold.is_none_or(|max| score <= max),
+        }
+    }
+}
+
+/// Structural hollowness (the code isn't written) and content hollowness
+/// (the code is written but fake).
+///
+/// Each violation's score points go to the category of its rule, so the two
+/// point totals add up to the total score's points, and each sub-score is
+/// capped at 100 the same way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubScores {
+    pub structural: SubScore,
+    pub content: SubScore,
+}
+
+impl SubScores {
+    /// Split the score points of `violations` by category, with the
+    /// contract's overrides and thresholds.
+    fn tally(violations: &[Violation], config: Option<&SubScoresConfig>, weights: &PointWeights) -> Self {
+        let (mut structural, mut content) = (0, 0);
+        for v in violations {
+            let category = match config {
+                Some(config) => config.category(v.rule),
+                None => v.rule.score_category(),
+            };
+            match category {
+                ScoreCategory::Structural => structural += weights.scoring(v),
+                ScoreCategory::Content => content += weights.scoring(v),
+            }
+        }
+        Self {
+            structural: SubScore::new(structural, config.and_then(|c| c.structural_threshold)),
+            content: SubScore::new(content, config.and_then(|c| c.content_threshold)),
+        }
+    }
+
+    /// Whether both sub-scores are within their thresholds.
+    pub fn passed(&self) -> bool {
+        self.structural.passed && self.content.passed
+    }
+}
+
+/// Violations and score points carried by one debt mechanism or rule.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DebtEntry {
+    /// Violations kept out of the score, or scored lower
+    pub violations: i32,
+    /// Score points they would add back
+    pub points: i32,
+}
+
+impl DebtEntry {
+    fn add(&mut self, points: i32) {
+        self.violations += 1;
+        self.points += points;
+    }
+}
+
+/// Known-hollow code the live score does not see.
+///
+/// Each suppressed, waived, or baseline-grandfathered violation carries the
+/// points it would add to the score at its original severity; a downgraded
+/// violation carries the points the downgrade took off. Points use the same
+/// rule weights as the live score, so Warning and Info violations are
+/// counted but add nothing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtScore {
+    /// Debt points, capped at 100
+    pub score: i32,
+    /// Debt points before capping
+    pub points: i32,
+    /// Totals by mechanism: `inline_suppression`, `suppression_file`,
+    /// `downgrade`, `waiver`, and `baseline`
+    #[serde(default)]
+    pub by_mechanism: BTreeMap<String, DebtEntry>,
+    /// Totals by rule
+    #[serde(default)]
+    pub by_rule: BTreeMap<String, DebtEntry>,
+    /// Per-rule totals within each mechanism
+    #[serde(default)]
+    pub by_mechanism_rule: BTreeMap<String, BTreeMap<String, DebtEntry>>,
+    /// The `max_debt` limit, if the contract sets one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_debt: Option<i32>,
+    /// Whether the debt score is within `max_debt`
+    #[serde(default = "default_passed")]
+    pub passed: bool,
+}
+
+fn default_passed() -> bool {
+    true
+}
+
+impl Default for DebtScore {
+    fn default() -> Self {
+        Self {
+            score: 0,
+            points: 0,
+            by_mechanism: BTreeMap::new(),
+            by_rule: BTreeMap::new(),
+            by_mechanism_rule: BTreeMap::new(),
+            max_debt: None,
+            passed: true,
+        }
+    }
+}
+
+impl DebtScore {
+    /// Number of violations carried as debt.
+    pub fn violations(&self) -> i32 {
+        self.by_mechanism.values().map(|e| e.violations).sum()
+    }
+
+    fn add(&mut self, mechanism: &str, v: &Violation, points: i32) {
+        let rule = v.rule.as_str();
+        self.by_mechanism.entry(mechanism.to_string()).or_default().add(points);
+        self.by_rule.entry(rule.to_string()).or_default().add(points);
+        self.by_mechanism_rule
+            .entry(mechanism.to_string())
+            .or_default()
+            .entry(rule.to_string())
+            .or_default()
+            .add(points);
+        self.points += points;
+        self.score = self.points.min(100);
+    }
 }
 
 impl HollownessScore {
@@ -67,17 +341,56 @@ impl HollownessScore {
 
     /// Get the number of violations for a given rule.
     pub fn violation_count(&self, rule: &str) -> i32 {
+        if let Some(count) = self.counts.get(rule) {
+            return *count;
+        }
+        // Scores deserialized from older reports only carry points
         let points = self.breakdown.get(rule).copied().unwrap_or(0);
-        let per_violation = get_points_for_rule(rule);
+        let per_violation = self.points.rule(rule);
         if per_violation == 0 {
             return 0;
         }
         points / per_violation
     }
+
+    /// Add each checked rule that found nothing to the breakdown, with 0
+    /// points and 0 violations, so a clean rule is told apart from one that
+    /// did not run.
+    pub fn include_clean_rules(&mut self, checked: &[ViolationRule]) {
+        for rule in checked {
+            let name = rule.as_str();
+            if !self.breakdown.contains_key(name) {
+                self.breakdown.insert(name.to_string(), 0);
+                self.counts.insert(name.to_string(), 0);
+            }
+        }
+    }
+
+    /// Whether a breakdown entry is a checked rule that found nothing.
+    pub fn is_clean(&self, rule: &str) -> bool {
+        self.counts.get(rule) == Some(&0)
+    }
+
+    /// Fail the run when the debt score exceeds `max_debt`, whatever the live score.
+    pub fn with_max_debt(mut self, max_debt: Option<i32>) -> Self {
+        self.debt.max_debt = max_debt;
+        self.debt.passed = max_debt.is_none_or(|max| self.debt.score <= max);
+        self.passed &= self.debt.passed;
+        self
+    }
+
+    /// Split the score of `violations` into sub-scores with the contract's
+    /// category overrides, and fail the run when a sub-score exceeds its
+    /// threshold, whatever the total.
+    pub fn with_sub_scores(mut self, violations: &[Violation], config: Option<&SubScoresConfig>) -> Self {
+        self.sub_scores = SubScores::tally(violations, config, &self.points);
+        self.passed &= self.sub_scores.passed();
+        self
+    }
 }
 
 /// Get the point weight for a violation rule.
-fn get_points_for_rule(rule: &str) -> i32 {
+pub(crate) fn get_points_for_rule(rule: &str) -> i32 {
     match rule {
         "missing_file" => points::MISSING_FILE,
         "missing_symbol" => points::MISSING_SYMBOL,
@@ -85,12 +398,52 @@ fn get_points_for_rule(rule: &str) -> i32 {
         "forbidden_pattern" => points::FORBIDDEN_PATTERN,
         "low_complexity" => points::LOW_COMPLEXITY,
         "stub_function" => points::STUB_FUNCTION,
+        "insufficient_implementation" => points::INSUFFICIENT_IMPLEMENTATION,
         "god_file" => points::GOD_FILE,
         "god_function" => points::GOD_FUNCTION,
         "god_class" => points::GOD_CLASS,
+        "too_many_params" => points::TOO_MANY_PARAMS,
         "missing_test" => points::MISSING_TEST,
         "mock_data" => points::MOCK_DATA,
         "hollow_todo" => points::HOLLOW_TODO,
+        "repeated_literal" => points::REPEATED_LITERAL,
+        "floating_promise" => points::FLOATING_PROMISE,
+        "forbidden_file" => points::FORBIDDEN_FILE,
+        "case_collision" => points::CASE_COLLISION,
+        "bad_header" => points::BAD_HEADER,
+        "doc_drift" => points::DOC_DRIFT,
+        "empty_handler" => points::EMPTY_HANDLER,
+        "analysis_timeout" => points::ANALYSIS_TIMEOUT,
+        "analysis_error" => points::ANALYSIS_ERROR,
+        "unimplemented_interface" => points::UNIMPLEMENTED_INTERFACE,
+        "builtin_shadowing" => points::BUILTIN_SHADOWING,
+        "hollow_container" => points::HOLLOW_CONTAINER,
+        "hollow_ci_config" => points::HOLLOW_CI_CONFIG,
+        "hollow_schema" => points::HOLLOW_SCHEMA,
+        "environment_mismatch" => points::ENVIRONMENT_MISMATCH,
+        "hollow_config" => points::HOLLOW_CONFIG,
+        "expired_waiver" => points::EXPIRED_WAIVER,
+        "unused_suppression" => points::UNUSED_SUPPRESSION,
+        "unused_context" => points::UNUSED_CONTEXT,
+        "unassigned_named_return" => points::UNASSIGNED_NAMED_RETURN,
+        "missing_definition" => points::MISSING_DEFINITION,
+        "deprecated_usage" => points::DEPRECATED_USAGE,
+        "trivial_wrapper" => points::TRIVIAL_WRAPPER,
+        "async_without_await" => points::ASYNC_WITHOUT_AWAIT,
+        "mutable_default" => points::MUTABLE_DEFAULT,
+        "truncated_code" => points::TRUNCATED_CODE,
+        "premature_abstraction" => points::PREMATURE_ABSTRACTION,
+        "comment_ratio" => points::COMMENT_RATIO,
+        "doc_coverage" => points::DOC_COVERAGE,
+        "placeholder_constants" => points::PLACEHOLDER_CONSTANTS,
+        "unwrap_overuse" => points::UNWRAP_OVERUSE,
+        "force_unwrap" => points::FORCE_UNWRAP,
+        "weak_crypto" => points::WEAK_CRYPTO,
+        "missing_local_module" => points::MISSING_LOCAL_MODULE,
+        "noop_statement" => points::NOOP_STATEMENT,
+        "redundant_error_logging" => points::REDUNDANT_ERROR_LOGGING,
+        "inconsistent_error_handling" => points::INCONSISTENT_ERROR_HANDLING,
+        "unhandled_enum_cases" => points::UNHANDLED_ENUM_CASES,
         // Prose rules
         "filler_phrase" => points::FILLER_PHRASE,
         "weasel_word" => points::WEASEL_WORD,
@@ -109,13 +462,70 @@ fn get_points_for_rule(rule: &str) -> i32 {
     }
 }
 
-/// Get the point weight for a ViolationRule enum.
-fn get_points(rule: ViolationRule) -> i32 {
-    get_points_for_rule(rule.as_str())
+/// Tally the violations suppressed, waived, and downgraded in a result, and
+/// `baselined` ones, at their original severities.
+fn tally_debt(result: &DetectionResult, baselined: &[&Violation], weights: &PointWeights) -> DebtScore {
+    let mut debt = DebtScore::default();
+
+    for s in &result.suppressed {
+        let mechanism = match s.suppression.suppression_type {
+            SuppressionType::Fingerprint => "suppression_file",
+            SuppressionType::Line | SuppressionType::NextLine | SuppressionType::File => {
+                "inline_suppression"
+            }
+        };
+        debt.add(mechanism, &s.violation, weights.scoring(&s.violation));
+    }
+    for d in &result.downgraded {
+        let original = Violation {
+            severity: d.original_severity,
+            ..d.violation.clone()
+        };
+        let points = weights.scoring(&original) - weights.scoring(&d.violation);
+        debt.add("downgrade", &d.violation, points);
+    }
+    for w in &result.waived {
+        debt.add("waiver", &w.violation, weights.scoring(&w.violation));
+    }
+    for v in baselined {
+        debt.add("baseline", v, weights.scoring(v));
+    }
+
+    debt
+}
+
+/// Points and counts by rule, plus the total of score-counting points.
+struct Tally {
+    breakdown: HashMap<String, i32>,
+    counts: HashMap<String, i32>,
+    scoring_points: i32,
+}
+
+/// Tally violations by rule. Only Critical/Error add to the scoring total.
+fn tally(violations: &[Violation], weights: &PointWeights) -> Tally {
+    let mut tally = Tally {
+        breakdown: HashMap::new(),
+        counts: HashMap::new(),
+        scoring_points: 0,
+    };
+
+    for v in violations {
+        let points = weights.violation(v);
+        let rule = v.rule.as_str().to_string();
+        *tally.breakdown.entry(rule.clone()).or_insert(0) += points;
+        *tally.counts.entry(rule).or_insert(0) += 1;
+
+        // Only add to scoring total if this severity counts toward score
+        if v.severity.counts_toward_score() {
+            tally.scoring_points += points;
+        }
+    }
+
+    tally
 }
 
 /// Determine the letter grade from a score.
-fn calculate_grade(score: i32) -> String {
+pub(crate) fn calculate_grade(score: i32) -> String {
     match score {
         s if s <= grades::A_MAX => "A".to_string(),
         s if s <= grades::B_MAX => "B".to_string(),
@@ -129,52 +539,45 @@ fn calculate_grade(score: i32) -> String {
 /// Only Critical and Error severity violations count toward the score.
 /// Warning and Info violations are tracked in breakdown but don't affect pass/fail.
 pub fn calculate(result: &DetectionResult, contract: &Contract) -> HollownessScore {
-    let mut breakdown: HashMap<String, i32> = HashMap::new();
-    let mut scoring_points = 0;
+    let points = PointWeights::from_contract(contract);
 
     // Count violations by rule and calculate points
     // Only Critical/Error count toward the score
-    for v in &result.violations {
-        let points = get_points(v.rule);
-        *breakdown.entry(v.rule.as_str().to_string()).or_insert(0) += points;
-
-        // Only add to scoring total if this severity counts toward score
-        if v.severity.counts_toward_score() {
-            scoring_points += points;
-        }
-    }
+    let Tally {
+        breakdown,
+        counts,
+        scoring_points,
+    } = tally(&result.violations, &points);
 
     // Cap at 100
     let score = scoring_points.min(100);
 
-    // Determine threshold (could be extended to read from contract)
-    let threshold = DEFAULT_THRESHOLD;
-    let _ = contract; // Silence unused warning for now
+    let threshold = contract.threshold.unwrap_or(DEFAULT_THRESHOLD);
 
     HollownessScore {
         score,
         grade: calculate_grade(score),
         breakdown,
+        counts,
         passed: score <= threshold,
         threshold,
+        debt: tally_debt(result, &[], &points),
+        sub_scores: SubScores::default(),
+        points,
     }
+    .with_max_debt(contract.max_debt)
+    .with_sub_scores(&result.violations, contract.sub_scores.as_ref())
 }
 
-/// Calculate the hollowness score with a custom threshold.
+/// Calculate the hollowness score with a custom threshold and point weights
+/// (`PointWeights::default()` for the built-in ones).
 /// Only Critical and Error severity violations count toward the score.
-pub fn calculate_with_threshold(result: &DetectionResult, threshold: i32) -> HollownessScore {
-    let mut breakdown: HashMap<String, i32> = HashMap::new();
-    let mut scoring_points = 0;
-
-    for v in &result.violations {
-        let points = get_points(v.rule);
-        *breakdown.entry(v.rule.as_str().to_string()).or_insert(0) += points;
-
-        // Only add to scoring total if this severity counts toward score
-        if v.severity.counts_toward_score() {
-            scoring_points += points;
-        }
-    }
+pub fn calculate_with_threshold(result: &DetectionResult, threshold: i32, points: &PointWeights) -> HollownessScore {
+    let Tally {
+        breakdown,
+        counts,
+        scoring_points,
+    } = tally(&result.violations, points);
 
     let score = scoring_points.min(100);
 
@@ -182,53 +585,61 @@ pub fn calculate_with_threshold(result: &DetectionResult, threshold: i32) -> Hol
         score,
         grade: calculate_grade(score),
         breakdown,
+        counts,
         passed: score <= threshold,
         threshold,
+        debt: tally_debt(result, &[], points),
+        sub_scores: SubScores::tally(&result.violations, None, points),
+        points: points.clone(),
     }
 }
 
 /// Calculate a score based only on new violations (baseline mode).
+/// Violations the baseline already had are carried as debt.
 /// The threshold defaults to 0 if not specified (any new violation fails).
 /// Only Critical and Error severity violations count toward the score.
-pub fn calculate_for_new_violations(result: &DetectionResult, threshold: i32) -> HollownessScore {
-    let mut breakdown: HashMap<String, i32> = HashMap::new();
-    let mut scoring_points = 0;
-
+pub fn calculate_for_new_violations(result: &DetectionResult, threshold: i32, points: &PointWeights) -> HollownessScore {
     // Only count new violations
-    for v in &result.new_violations {
-        let points = get_points(v.rule);
-        *breakdown.entry(v.rule.as_str().to_string()).or_insert(0) += points;
-
-        // Only add to scoring total if this severity counts toward score
-        if v.severity.counts_toward_score() {
-            scoring_points += points;
-        }
-    }
+    let Tally {
+        breakdown,
+        counts,
+        scoring_points,
+    } = tally(&result.new_violations, points);
 
     let score = scoring_points.min(100);
 
     // For baseline mode, default threshold is 0 (any new violation fails)
     let threshold = if threshold < 0 { 0 } else { threshold };
 
+    let new_keys: HashSet<String> = result.new_violations.iter().map(Violation::key).collect();
+    let baselined: Vec<&Violation> = result
+        .violations
+        .iter()
+        .filter(|v| !new_keys.contains(&v.key()))
+        .collect();
+
     HollownessScore {
         score,
         grade: calculate_grade(score),
         breakdown,
+        counts,
         passed: score <= threshold,
         threshold,
+        debt: tally_debt(result, &baselined, points),
+        sub_scores: SubScores::tally(&result.new_violations, None, points),
+        points: points.clone(),
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::detect::Violation;
 
     fn make_violation(rule: ViolationRule) -> Violation {
         Violation {
             rule,
             message: "test".to_string(),
-            file: "test.go".to_string(),
+            file: Some("test.go".to_string()),
             line: 1,
             severity: rule.default_severity(),
         }
@@ -284,6 +695,24 @@ mod tests {
         assert_eq!(score.total_points(), 400); // Breakdown still has actual total
     }
 
+    #[test]
+    fn test_critical_weighs_above_error() {
+        let mut result = DetectionResult::new();
+        let mut escalated = make_violation(ViolationRule::ForbiddenPattern); // 10 pts by rule
+        escalated.severity = Severity::Critical;
+        result.add_violation(escalated.clone());
+        result.add_violation(escalated);
+        result.add_violation(make_violation(ViolationRule::LowComplexity)); // 10 pts (Error)
+
+        let score = calculate(&result, &Contract::default());
+
+        assert_eq!(score.breakdown.get("forbidden_pattern"), Some(&(2 * points::CRITICAL_MIN)));
+        assert_eq!(score.score, 2 * points::CRITICAL_MIN + 10);
+        // Counts are tracked directly, not derived from points
+        assert_eq!(score.violation_count("forbidden_pattern"), 2);
+        assert_eq!(score.violation_count("low_complexity"), 1);
+    }
+
     #[test]
     fn test_grade_thresholds() {
         assert_eq!(calculate_grade(0), "A");
@@ -298,15 +727,28 @@ mod tests {
         assert_eq!(calculate_grade(100), "F");
     }
 
+    #[test]
+    fn test_calculate_uses_contract_threshold() {
+        let mut result = DetectionResult::new();
+        result.add_violation(make_violation(ViolationRule::MissingFile));
+
+        let mut contract = Contract::default();
+        assert!(calculate(&result, &contract).passed);
+        contract.threshold = Some(5);
+        let score = calculate(&result, &contract);
+        assert_eq!(score.threshold, 5);
+        assert!(!score.passed);
+    }
+
     #[test]
     fn test_calculate_with_custom_threshold() {
         let mut result = DetectionResult::new();
         result.add_violation(make_violation(ViolationRule::LowComplexity)); // 10 points (Error - counts)
 
-        let score = calculate_with_threshold(&result, 5);
+        let score = calculate_with_threshold(&result, 5, &PointWeights::default());
         assert!(!score.passed); // 10 > 5
 
-        let score = calculate_with_threshold(&result, 15);
+        let score = calculate_with_threshold(&result, 15, &PointWeights::default());
         assert!(score.passed); // 10 <= 15
     }
 
@@ -324,11 +766,187 @@ mod tests {
             .new_violations
             .push(make_violation(ViolationRule::MockData)); // 3 pts (Warning - doesn't count)
 
-        let score = calculate_for_new_violations(&result, 0);
+        let score = calculate_for_new_violations(&result, 0, &PointWeights::default());
         assert_eq!(score.score, 10); // Only LowComplexity (Error) counts
         assert!(!score.passed); // 10 > 0
 
-        let score = calculate_for_new_violations(&result, 15);
+        let score = calculate_for_new_violations(&result, 15, &PointWeights::default());
         assert!(score.passed); // 10 <= 15
     }
+
+    #[test]
+    fn test_include_clean_rules() {
+        let mut result = DetectionResult::new();
+        result.add_violation(make_violation(ViolationRule::MockData));
+        let mut score = calculate(&result, &Contract::default());
+
+        score.include_clean_rules(&[ViolationRule::MockData, ViolationRule::HollowTodo]);
+        assert_eq!(score.breakdown.get("mock_data"), Some(&points::MOCK_DATA));
+        assert_eq!(score.breakdown.get("hollow_todo"), Some(&0));
+        assert_eq!(score.violation_count("hollow_todo"), 0);
+        assert!(score.is_clean("hollow_todo"));
+        assert!(!score.is_clean("mock_data"));
+        assert!(!score.is_clean("god_file"));
+        assert_eq!(score.total_points(), points::MOCK_DATA);
+    }
+
+    #[test]
+    fn test_debt_by_mechanism_and_rule() {
+        use crate::contract::Waiver;
+        use crate::detect::{Downgrade, DowngradedViolation, SuppressedViolation, Suppression, WaivedViolation};
+
+        let suppressed = |rule, suppression_type| {
+            SuppressedViolation::new(make_violation(rule), Suppression::new(rule.as_str(), "test.go", 1, suppression_type))
+        };
+        let mut result = DetectionResult::new();
+        result.add_violation(make_violation(ViolationRule::MockData));
+        result.suppressed = vec![
+            suppressed(ViolationRule::StubFunction, SuppressionType::Line), // 10 pts
+            suppressed(ViolationRule::MockData, SuppressionType::File),     // Warning, 0 pts
+            suppressed(ViolationRule::StubFunction, SuppressionType::Fingerprint), // 10 pts
+        ];
+        result.waived = vec![WaivedViolation {
+            violation: make_violation(ViolationRule::MissingFile), // 20 pts
+            waiver: Waiver::default(),
+        }];
+        let mut lowered = make_violation(ViolationRule::LowComplexity);
+        lowered.severity = Severity::Warning;
+        result.downgraded = vec![DowngradedViolation {
+            violation: lowered,
+            original_severity: Severity::Error, // 10 pts no longer scored
+            downgrade: Downgrade {
+                suppression: Suppression::new("low_complexity", "test.go", 1, SuppressionType::File),
+                severity: Severity::Warning,
+            },
+        }];
+
+        let score = calculate(&result, &Contract::default());
+        let debt = &score.debt;
+        assert_eq!(score.score, 0);
+        assert_eq!((debt.score, debt.points, debt.violations()), (50, 50, 5));
+        let entry = |violations, points| DebtEntry { violations, points };
+        assert_eq!(debt.by_mechanism["inline_suppression"], entry(2, 10));
+        assert_eq!(debt.by_mechanism["suppression_file"], entry(1, 10));
+        assert_eq!(debt.by_mechanism["waiver"], entry(1, 20));
+        assert_eq!(debt.by_mechanism["downgrade"], entry(1, 10));
+        assert_eq!(debt.by_rule["stub_function"], entry(2, 20));
+        assert_eq!(debt.by_rule["mock_data"], entry(1, 0));
+        assert_eq!(debt.by_mechanism_rule["inline_suppression"]["stub_function"], entry(1, 10));
+        assert!(score.passed && debt.passed);
+
+        // max_debt fails the run on its own
+        let contract = Contract {
+            max_debt: Some(40),
+            ..Contract::default()
+        };
+        let score = calculate(&result, &contract);
+        assert!(!score.debt.passed);
+        assert!(!score.passed);
+        assert!(!calculate_with_threshold(&result, 100, &PointWeights::default()).with_max_debt(Some(40)).passed);
+    }
+
+    #[test]
+    fn test_baseline_violations_are_debt() {
+        let mut result = DetectionResult::new();
+        let old = make_violation(ViolationRule::StubFunction);
+        let mut new = make_violation(ViolationRule::StubFunction);
+        new.line = 9;
+        result.add_violation(old);
+        result.add_violation(new.clone());
+        result.set_baseline("main", vec![new]);
+
+        let score = calculate_for_new_violations(&result, 0, &PointWeights::default());
+        assert_eq!(score.score, 10);
+        assert_eq!(score.debt.by_mechanism["baseline"], DebtEntry { violations: 1, points: 10 });
+        // The full score does not hide old violations
+        assert!(calculate(&result, &Contract::default()).debt.by_mechanism.is_empty());
+    }
+
+    #[test]
+    fn test_sub_scores_reconcile_with_total() {
+        let mut result = DetectionResult::new();
+        result.add_violation(make_violation(ViolationRule::StubFunction)); // 10 pts structural
+        result.add_violation(make_violation(ViolationRule::MissingSymbol)); // 15 pts structural
+        result.add_violation(make_violation(ViolationRule::HollowConfig)); // 8 pts content
+        result.add_violation(make_violation(ViolationRule::BadHeader)); // 5 pts content
+        result.add_violation(make_violation(ViolationRule::MockData)); // Warning - doesn't count
+
+        let score = calculate(&result, &Contract::default());
+        let SubScores { structural, content } = score.sub_scores;
+        assert_eq!((structural.score, content.score), (25, 13));
+        assert_eq!(structural.points + content.points, score.score);
+        assert!(structural.passed && content.passed && structural.threshold.is_none());
+
+        // Each sub-score is capped like the total, so only the points add up past 100
+        for _ in 0..5 {
+            result.add_violation(make_violation(ViolationRule::MissingFile)); // 20 pts structural
+        }
+        let score = calculate_with_threshold(&result, 100, &PointWeights::default());
+        let SubScores { structural, content } = score.sub_scores;
+        assert_eq!((structural.score, structural.points, content.score), (100, 125, 13));
+        assert_eq!(score.score, (structural.points + content.points).min(100));
+    }
+
+    #[test]
+    fn test_sub_score_thresholds_and_overrides() {
+        use crate::contract::SubScoresConfig;
+
+        let mut result = DetectionResult::new();
+        result.add_violation(make_violation(ViolationRule::StubFunction)); // 10 pts structural
+        result.add_violation(make_violation(ViolationRule::HollowConfig)); // 8 pts content
+
+        // Lenient on content, blocking on structural hollowness
+        let mut config = SubScoresConfig {
+            structural_threshold: Some(5),
+            content_threshold: Some(60),
+            ..SubScoresConfig::default()
+        };
+        let contract = Contract {
+            sub_scores: Some(config.clone()),
+            ..Contract::default()
+        };
+        let score = calculate(&result, &contract);
+        assert_eq!(score.score, 18);
+        assert!(!score.sub_scores.structural.passed && score.sub_scores.content.passed);
+        assert!(!score.passed); // 18 <= 25, but structural 10 > 5
+
+        // Moving a rule moves its points
+        config.categories.insert("stub_function".to_string(), ScoreCategory::Content);
+        let score = calculate_with_threshold(&result, 25, &PointWeights::default())
+            .with_sub_scores(&result.violations, Some(&config));
+        assert_eq!((score.sub_scores.structural.score, score.sub_scores.content.score), (0, 18));
+        assert!(score.passed);
+    }
+
+    #[test]
+    fn test_contract_point_values() {
+        let mut result = DetectionResult::new();
+        result.add_violation(make_violation(ViolationRule::LowComplexity));
+        result.add_violation(make_violation(ViolationRule::LowComplexity));
+        result.add_violation(make_violation(ViolationRule::MockData));
+        result.add_violation(make_violation(ViolationRule::StubFunction).with_severity(Severity::Critical));
+        result.waived = vec![crate::detect::WaivedViolation {
+            violation: make_violation(ViolationRule::StubFunction),
+            waiver: crate::contract::Waiver::default(),
+        }];
+
+        let contract: Contract =
+            serde_yaml::from_str("points:\n  low-complexity: 4\n  mock_data: 5\n  stub_function: 2\n").unwrap();
+        let score = calculate(&result, &contract);
+
+        assert_eq!(score.breakdown.get("low_complexity"), Some(&8));
+        assert_eq!(score.breakdown.get("mock_data"), Some(&5));
+        // Critical violations keep their minimum
+        assert_eq!(score.breakdown.get("stub_function"), Some(&points::CRITICAL_MIN));
+        assert_eq!(score.score, 8 + points::CRITICAL_MIN);
+        assert_eq!(score.sub_scores.structural.points, score.score);
+        assert_eq!(score.debt.points, 2);
+        assert_eq!(score.violation_count("low_complexity"), 2);
+
+        // Reports record the overrides, keyed by canonical rule name
+        let json = serde_json::to_value(&score).unwrap();
+        assert_eq!(json["points"]["low_complexity"], 4);
+        let default = calculate(&result, &Contract::default());
+        assert!(serde_json::to_value(&default).unwrap().get("points").is_none());
+    }
 }
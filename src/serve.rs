@@ -0,0 +1,207 @@
+//! The latest report over HTTP, for `lint --watch --serve`.
+//!
+//! `GET /report` returns the JSON report of the most recent scan, and
+//! `GET /events` is a server-sent event stream that pushes each new report
+//! as a `report` event, starting with the current one. An editor or browser
+//! tab can display the result live as the tree changes.
+//!
+//! The server needs the `serve` feature; without it [`serve`] fails, so the
+//! default build carries no HTTP server.
+
+use std::net::SocketAddr;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+
+/// The latest report and the event streams waiting for the next one.
+#[derive(Clone, Default)]
+pub struct ReportHub {
+    latest: Arc<Mutex<Option<String>>>,
+    subscribers: Arc<Mutex<Vec<Sender<String>>>>,
+}
+
+impl ReportHub {
+    /// Record a new JSON report and push it to every open event stream.
+    pub fn publish(&self, report: String) {
+        // Under the subscriber lock, so a new stream sees either this report
+        // as the latest or receives it as an event, never neither
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|subscriber| subscriber.send(report.clone()).is_ok());
+        *self.latest.lock().unwrap() = Some(report);
+    }
+
+    /// The most recent JSON report, if a scan has finished.
+    pub fn latest(&self) -> Option<String> {
+        self.latest.lock().unwrap().clone()
+    }
+
+    /// Receive every report published from now on, after the current one.
+    pub fn subscribe(&self) -> Receiver<String> {
+        let (tx, rx) = mpsc::channel();
+        // Hold the subscriber list, which publish also holds while it updates the latest report
+        let mut subscribers = self.subscribers.lock().unwrap();
+        if let Some(report) = self.latest() {
+            let _ = tx.send(report);
+        }
+        subscribers.push(tx);
+        rx
+    }
+}
+
+/// A report as a server-sent event.
+#[cfg(any(feature = "serve", test))]
+fn sse_event(report: &str) -> String {
+    let data: String = report.lines().map(|line| format!("data: {}\n", line)).collect();
+    format!("event: report\n{}\n", data)
+}
+
+/// Serve the hub's reports on `addr` from a background thread, returning
+/// the address bound (useful with port 0).
+#[cfg(feature = "serve")]
+pub fn serve(addr: &str, hub: ReportHub) -> anyhow::Result<SocketAddr> {
+    let server =
+        tiny_http::Server::http(addr).map_err(|e| anyhow::anyhow!("cannot serve reports on {}: {}", addr, e))?;
+    let bound = server
+        .server_addr()
+        .to_ip()
+        .ok_or_else(|| anyhow::anyhow!("cannot serve reports on {}: not an IP address", addr))?;
+    std::thread::spawn(move || {
+        for request in server.incoming_requests() {
+            handle(request, &hub);
+        }
+    });
+    Ok(bound)
+}
+
+/// Serve the hub's reports; needs the `serve` feature.
+#[cfg(not(feature = "serve"))]
+pub fn serve(addr: &str, _hub: ReportHub) -> anyhow::Result<SocketAddr> {
+    anyhow::bail!(
+        "cannot serve reports on {}: hollowcheck was built without the `serve` feature",
+        addr
+    )
+}
+
+#[cfg(feature = "serve")]
+fn handle(request: tiny_http::Request, hub: &ReportHub) {
+    use tiny_http::{Header, Method, Response};
+
+    let header = |name: &str, value: &str| Header::from_bytes(name, value).unwrap();
+    let path = request.url().split('?').next().unwrap_or("");
+    let response = match (request.method(), path) {
+        (Method::Get, "/report") => match hub.latest() {
+            Some(report) => Response::from_string(report).with_header(header("Content-Type", "application/json")),
+            None => Response::from_string("no scan has finished yet\n").with_status_code(503),
+        },
+        (Method::Get, "/events") => {
+            let events = hub.subscribe();
+            // Each event is written and flushed as it comes, so the stream
+            // is written by hand rather than as a buffered response body
+            std::thread::spawn(move || {
+                use std::io::Write;
+                let mut writer = request.into_writer();
+                let head = "HTTP/1.1 200 OK\r\nContent-Type: text/event-stream\r\nCache-Control: no-cache\r\n\r\n";
+                if writer.write_all(head.as_bytes()).and_then(|_| writer.flush()).is_err() {
+                    return;
+                }
+                for report in events {
+                    let sent = writer.write_all(sse_event(&report).as_bytes()).and_then(|_| writer.flush());
+                    if sent.is_err() {
+                        return;
+                    }
+                }
+            });
+            return;
+        }
+        (Method::Get, _) => Response::from_string("not found\n").with_status_code(404),
+        _ => Response::from_string("method not allowed\n").with_status_code(405),
+    };
+    let _ = request.respond(response);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hub_publish_and_subscribe() {
+        let hub = ReportHub::default();
+        assert_eq!(hub.latest(), None);
+
+        let early = hub.subscribe();
+        hub.publish("{\"score\":10}".to_string());
+        let late = hub.subscribe();
+        hub.publish("{\"score\":0}".to_string());
+
+        assert_eq!(hub.latest().as_deref(), Some("{\"score\":0}"));
+        assert_eq!(early.try_iter().collect::<Vec<_>>(), vec!["{\"score\":10}", "{\"score\":0}"]);
+        assert_eq!(late.try_iter().collect::<Vec<_>>(), vec!["{\"score\":10}", "{\"score\":0}"]);
+
+        // Closed streams are dropped on the next publish
+        drop(early);
+        hub.publish("{}".to_string());
+        assert_eq!(hub.subscribers.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_subscribe_while_publishing_sees_last_report() {
+        let hub = ReportHub::default();
+        let streams = std::thread::scope(|scope| {
+            let publisher = hub.clone();
+            scope.spawn(move || {
+                for i in 0..200 {
+                    publisher.publish(i.to_string());
+                }
+            });
+            (0..200).map(|_| hub.subscribe()).collect::<Vec<_>>()
+        });
+        for stream in streams {
+            assert_eq!(stream.try_iter().last().as_deref(), Some("199"));
+        }
+    }
+
+    #[test]
+    fn test_sse_event() {
+        assert_eq!(sse_event("{\"a\":1}"), "event: report\ndata: {\"a\":1}\n\n");
+        assert_eq!(sse_event("{\n  \"a\": 1\n}"), "event: report\ndata: {\ndata:   \"a\": 1\ndata: }\n\n");
+    }
+
+    #[cfg(feature = "serve")]
+    #[test]
+    fn test_serve_report_and_events() {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpStream;
+
+        let get = |addr: SocketAddr, path: &str| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path).unwrap();
+            stream
+        };
+
+        let hub = ReportHub::default();
+        let addr = serve("127.0.0.1:0", hub.clone()).unwrap();
+
+        let mut response = String::new();
+        get(addr, "/report").read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 503"), "{}", response);
+
+        hub.publish("{\"score\":3}".to_string());
+        let mut response = String::new();
+        get(addr, "/report").read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200"), "{}", response);
+        assert!(response.ends_with("{\"score\":3}"), "{}", response);
+
+        let mut events = BufReader::new(get(addr, "/events"));
+        let mut lines = Vec::new();
+        let mut line = String::new();
+        while lines.len() < 2 || !lines.contains(&"data: {\"score\":7}".to_string()) {
+            line.clear();
+            assert!(events.read_line(&mut line).unwrap() > 0, "{:?}", lines);
+            lines.push(line.trim_end().to_string());
+            if lines.contains(&"data: {\"score\":3}".to_string()) && lines.last().unwrap().is_empty() {
+                hub.publish("{\"score\":7}".to_string());
+            }
+        }
+        assert!(lines.contains(&"Content-Type: text/event-stream".to_string()));
+        assert!(lines.contains(&"event: report".to_string()));
+    }
+}
@@ -0,0 +1,1311 @@
+//! Task specification checks for `hollowcheck verify-spec`.
+//!
+//! An agent pipeline that plans its work as a structured task spec can have
+//! the delivery checked against it instead of by a human. The spec is a
+//! versioned JSON document listing the expected artifacts: files, symbols,
+//! CLI subcommands, and API routes. Each entry resolves to `satisfied`,
+//! `missing`, or `hollow`: present, but its implementing function is
+//! classified as a stub the way the `stub_function` rule classifies it.
+//!
+//! Files and symbols are looked up in the scan's declarations. Subcommands
+//! and routes have no declaration of their own, so they are found where they
+//! are registered, with per-framework heuristics: argument-parser
+//! declarations (clap, argparse, click and Typer, cobra) and route
+//! registrations (net/http, Gin/Echo/Chi/Fiber, Express, NestJS, Flask,
+//! FastAPI, Django, Spring, axum, actix and Rocket). A spec can add its own
+//! regexes for frameworks the heuristics miss.
+
+use std::collections::HashSet;
+use std::fmt::Write as FmtWrite;
+use std::path::{Path, PathBuf};
+
+use colored::*;
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::{AnalysisContext, Declaration, DeclarationKind};
+use crate::contract::SymbolKind;
+use crate::detect::{
+    case_mismatch_message, declaration_kind_to_symbol_kind, detect_stub_functions, lookup_exact_case, CaseLookup,
+    FactKind, FactsBundle, StubDetectionConfig, ViolationRule,
+};
+
+/// Spec format version this build reads.
+pub const SPEC_VERSION: u64 = 1;
+
+/// Completion required when neither the spec nor the command line sets one.
+pub const DEFAULT_MIN_COMPLETION: f64 = 100.0;
+
+/// Lines below a decorator or annotation searched for the function it applies to.
+const DECORATOR_REACH: usize = 8;
+
+/// The `Run` or `RunE` field of a cobra command.
+static COBRA_RUN: Lazy<Regex> = Lazy::new(|| Regex::new(r"\bRunE?:\s*([A-Za-z_][\w.]*)").unwrap());
+
+/// A task specification: the artifacts a delivery must contain.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TaskSpec {
+    /// Format version; must be `SPEC_VERSION`
+    pub version: u64,
+    /// Task name shown in reports
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Completion percentage the delivery must reach (default: 100)
+    #[serde(default)]
+    pub min_completion: Option<f64>,
+    /// Route registration patterns tried after the built-in ones
+    #[serde(default)]
+    pub route_patterns: Vec<SpecPattern>,
+    /// Subcommand declaration patterns tried after the built-in ones
+    #[serde(default)]
+    pub command_patterns: Vec<SpecPattern>,
+    /// Expected artifacts, reported in this order
+    pub expected: Vec<ExpectedArtifact>,
+}
+
+/// A spec-supplied regex for a framework the heuristics do not cover.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SpecPattern {
+    /// Framework name shown for matches
+    pub name: String,
+    /// File extensions the pattern applies to, without the dot (default: all)
+    #[serde(default)]
+    pub extensions: Vec<String>,
+    /// Regex with named groups: `path`, and optionally `method` and `handler`,
+    /// for routes; `name`, and optionally `handler`, for commands
+    pub pattern: String,
+    /// Whether the handler is the function declared just below the match,
+    /// as for decorators and annotations
+    #[serde(default)]
+    pub decorated: bool,
+}
+
+/// One expected artifact.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case", deny_unknown_fields)]
+pub enum ExpectedArtifact {
+    /// A file at an exact relative path
+    File { path: String },
+    /// A declaration, optionally of one kind, in one file, or inside one type
+    Symbol {
+        name: String,
+        #[serde(default)]
+        kind: Option<SymbolKind>,
+        #[serde(default)]
+        file: Option<String>,
+        #[serde(default)]
+        container: Option<String>,
+    },
+    /// A CLI subcommand; `handler` names the function that implements it
+    Command {
+        name: String,
+        #[serde(default)]
+        handler: Option<String>,
+    },
+    /// An API route, for one HTTP method or any; `handler` names the function that serves it
+    Route {
+        path: String,
+        #[serde(default)]
+        method: Option<String>,
+        #[serde(default)]
+        handler: Option<String>,
+    },
+}
+
+impl ExpectedArtifact {
+    /// Entry type as written in the spec.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExpectedArtifact::File { .. } => "file",
+            ExpectedArtifact::Symbol { .. } => "symbol",
+            ExpectedArtifact::Command { .. } => "command",
+            ExpectedArtifact::Route { .. } => "route",
+        }
+    }
+
+    /// Short description of the entry for reports (`GET /users/{id}`).
+    pub fn label(&self) -> String {
+        match self {
+            ExpectedArtifact::File { path } => path.clone(),
+            ExpectedArtifact::Symbol { name, kind, container, .. } => {
+                let name = match container {
+                    Some(container) => format!("{}.{}", container, name),
+                    None => name.clone(),
+                };
+                match kind {
+                    Some(kind) => format!("{} {}", kind, name),
+                    None => name,
+                }
+            }
+            ExpectedArtifact::Command { name, .. } => name.clone(),
+            ExpectedArtifact::Route { path, method, .. } => match method {
+                Some(method) => format!("{} {}", method.to_uppercase(), path),
+                None => path.clone(),
+            },
+        }
+    }
+}
+
+impl TaskSpec {
+    /// Read and validate a spec file.
+    pub fn parse_file(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("cannot read spec {:?}: {}", path, e))?;
+        Self::parse(&content).map_err(|e| anyhow::anyhow!("invalid spec {:?}: {}", path, e))
+    }
+
+    /// Parse and validate a spec. The version is checked first, so a spec
+    /// written for another format version fails on that rather than on its fields.
+    pub fn parse(content: &str) -> anyhow::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(content)?;
+        match value.get("version").and_then(serde_json::Value::as_u64) {
+            Some(SPEC_VERSION) => {}
+            Some(version) => {
+                anyhow::bail!("unsupported spec version {}, this build reads version {}", version, SPEC_VERSION)
+            }
+            None => anyhow::bail!("missing spec version, expected \"version\": {}", SPEC_VERSION),
+        }
+        let spec: TaskSpec = serde_json::from_value(value)?;
+
+        if let Some(min) = spec.min_completion {
+            if !(0.0..=100.0).contains(&min) {
+                anyhow::bail!("min_completion {} must be between 0 and 100", min);
+            }
+        }
+        compile_patterns(&spec.route_patterns, "route", "path")?;
+        compile_patterns(&spec.command_patterns, "command", "name")?;
+        Ok(spec)
+    }
+}
+
+/// How an entry resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntryStatus {
+    Satisfied,
+    Missing,
+    Hollow,
+}
+
+impl EntryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EntryStatus::Satisfied => "satisfied",
+            EntryStatus::Missing => "missing",
+            EntryStatus::Hollow => "hollow",
+        }
+    }
+}
+
+/// The resolution of one expected artifact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryResult {
+    /// Entry type: file, symbol, command, or route
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub expected: String,
+    pub status: EntryStatus,
+    /// Where the artifact was found (`src/users.go:12`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// Why the entry is missing or hollow, or which framework registered it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Everything `hollowcheck verify-spec` resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpecReport {
+    pub spec_version: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub entries: Vec<EntryResult>,
+    pub satisfied: usize,
+    pub hollow: usize,
+    pub missing: usize,
+    /// Percentage of entries satisfied; hollow entries do not count
+    pub completion: f64,
+    pub min_completion: f64,
+}
+
+impl SpecReport {
+    /// Whether the delivery reached the minimum completion.
+    pub fn passed(&self) -> bool {
+        self.completion >= self.min_completion
+    }
+}
+
+/// Where the function behind a registration is found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HandlerAt {
+    /// The pattern's `handler` group names it
+    Captured,
+    /// It is the function declared just below (decorators, annotations)
+    Below,
+    /// It is the `Run` or `RunE` field of the struct literal (cobra)
+    RunField,
+}
+
+/// A built-in registration heuristic.
+struct Heuristic {
+    framework: &'static str,
+    extensions: &'static [&'static str],
+    pattern: &'static str,
+    handler: HandlerAt,
+}
+
+/// Route registrations of common web frameworks.
+const ROUTE_HEURISTICS: &[Heuristic] = &[
+    Heuristic {
+        framework: "net/http",
+        extensions: &["go"],
+        pattern: r#"\b(?:HandleFunc|Handle)\(\s*"(?:(?P<method>[A-Z]+)\s+)?(?P<path>/[^"]*)"\s*,\s*(?P<handler>[A-Za-z_][\w.]*)"#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "gin/echo/chi/fiber",
+        extensions: &["go"],
+        pattern: r#"\.(?P<method>GET|POST|PUT|PATCH|DELETE|Get|Post|Put|Patch|Delete)\(\s*"(?P<path>/[^"]*)"\s*,\s*(?:[A-Za-z_][\w.]*\s*,\s*)*(?P<handler>[A-Za-z_][\w.]*)\s*\)"#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "express",
+        extensions: &["js", "jsx", "ts", "tsx"],
+        pattern: r#"\.(?P<method>get|post|put|patch|delete|all)\(\s*['"`](?P<path>/[^'"`]*)['"`]\s*(?:,\s*(?:[A-Za-z_$][\w.$]*\s*,\s*)*(?P<handler>[A-Za-z_$][\w.$]*)\s*\))?"#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "nestjs",
+        extensions: &["ts"],
+        pattern: r#"@(?P<method>Get|Post|Put|Patch|Delete|All)\(\s*['"`](?P<path>[^'"`]*)['"`]\s*\)"#,
+        handler: HandlerAt::Below,
+    },
+    Heuristic {
+        framework: "flask",
+        extensions: &["py"],
+        pattern: r#"@[\w.]+\.route\(\s*['"](?P<path>[^'"]+)['"](?:[^)\n]*methods\s*=\s*[\[(]\s*['"](?P<method>\w+)['"])?"#,
+        handler: HandlerAt::Below,
+    },
+    Heuristic {
+        framework: "fastapi/flask",
+        extensions: &["py"],
+        pattern: r#"@[\w.]+\.(?P<method>get|post|put|patch|delete)\(\s*['"](?P<path>[^'"]+)['"]"#,
+        handler: HandlerAt::Below,
+    },
+    Heuristic {
+        framework: "django",
+        extensions: &["py"],
+        pattern: r#"(?:^|[\s(\[,])(?:re_)?path\(\s*r?['"](?P<path>[^'"]*)['"]\s*,\s*(?P<handler>[A-Za-z_][\w.]*)"#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "spring",
+        extensions: &["java", "kt"],
+        pattern: r#"@(?P<method>Get|Post|Put|Patch|Delete|Request)Mapping\(\s*(?:(?:value|path)\s*=\s*)?\{?\s*"(?P<path>[^"]*)""#,
+        handler: HandlerAt::Below,
+    },
+    Heuristic {
+        framework: "axum",
+        extensions: &["rs"],
+        pattern: r#"\.route\(\s*"(?P<path>/[^"]*)"\s*,\s*(?P<method>get|post|put|patch|delete)\(\s*(?P<handler>[A-Za-z_][\w:]*)\s*\)"#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "actix/rocket",
+        extensions: &["rs"],
+        pattern: r#"#\[(?P<method>get|post|put|patch|delete)\(\s*"(?P<path>/[^"]*)""#,
+        handler: HandlerAt::Below,
+    },
+];
+
+/// Subcommand declarations of common argument parsers. Derived clap
+/// subcommands are read from the declarations instead (see `clap_variants`).
+const COMMAND_HEURISTICS: &[Heuristic] = &[
+    Heuristic {
+        framework: "clap",
+        extensions: &["rs"],
+        pattern: r#"(?:Command::new|SubCommand::with_name)\(\s*"(?P<name>[\w-]+)""#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "clap",
+        extensions: &["rs"],
+        pattern: r#"#\[(?:command|clap|structopt)\([^\]]*?\b(?:name|alias|visible_alias)\s*=\s*"(?P<name>[\w-]+)""#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "argparse",
+        extensions: &["py"],
+        pattern: r#"\.add_parser\(\s*['"](?P<name>[\w-]+)['"]"#,
+        handler: HandlerAt::Captured,
+    },
+    Heuristic {
+        framework: "click/typer",
+        extensions: &["py"],
+        pattern: r#"@[\w.]+\.command\(\s*(?:(?:name\s*=\s*)?['"](?P<name>[\w-]+)['"])?"#,
+        handler: HandlerAt::Below,
+    },
+    Heuristic {
+        framework: "cobra",
+        extensions: &["go"],
+        pattern: r#"\bUse:\s*"(?P<name>[\w-]+)"#,
+        handler: HandlerAt::RunField,
+    },
+];
+
+/// A registration pattern with its regex compiled.
+struct CompiledPattern {
+    framework: String,
+    extensions: Vec<String>,
+    regex: Regex,
+    handler: HandlerAt,
+}
+
+fn compile_heuristics(heuristics: &[Heuristic]) -> Vec<CompiledPattern> {
+    heuristics
+        .iter()
+        .map(|h| CompiledPattern {
+            framework: h.framework.to_string(),
+            extensions: h.extensions.iter().map(|e| e.to_string()).collect(),
+            regex: Regex::new(h.pattern).expect("built-in pattern compiles"),
+            handler: h.handler,
+        })
+        .collect()
+}
+
+/// Compile spec patterns, requiring the group that names what they register.
+fn compile_patterns(patterns: &[SpecPattern], kind: &str, group: &str) -> anyhow::Result<Vec<CompiledPattern>> {
+    patterns
+        .iter()
+        .map(|p| {
+            let regex = Regex::new(&p.pattern)
+                .map_err(|e| anyhow::anyhow!("invalid {} pattern {:?}: {}", kind, p.name, e))?;
+            // A decorated command pattern may name the command after its function
+            let optional = p.decorated && group == "name";
+            if !optional && !regex.capture_names().flatten().any(|n| n == group) {
+                anyhow::bail!("{} pattern {:?} has no named group \"{}\"", kind, p.name, group);
+            }
+            Ok(CompiledPattern {
+                framework: p.name.clone(),
+                extensions: p.extensions.clone(),
+                regex,
+                handler: if p.decorated { HandlerAt::Below } else { HandlerAt::Captured },
+            })
+        })
+        .collect()
+}
+
+/// A place where a subcommand or route is registered.
+#[derive(Debug, Clone)]
+struct Site {
+    /// Normalized subcommand name or route path
+    key: String,
+    /// Upper-cased HTTP method; `None` for any
+    method: Option<String>,
+    file: PathBuf,
+    line: usize,
+    framework: String,
+    /// The function that implements it, if the registration names one
+    handler: Option<(PathBuf, Declaration)>,
+}
+
+/// Declarations and stub classification of the scanned files.
+struct Project {
+    base_dir: PathBuf,
+    /// Each file with facts, in path order, with its declarations
+    files: Vec<(PathBuf, Vec<Declaration>)>,
+    /// Start lines of functions classified as stubs, by file
+    stubs: HashSet<(PathBuf, usize)>,
+}
+
+impl Project {
+    fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.base_dir).unwrap_or(path).to_string_lossy().to_string()
+    }
+
+    fn location(&self, path: &Path, line: usize) -> String {
+        format!("{}:{}", self.relative(path), line)
+    }
+
+    fn declarations(&self, path: &Path) -> Option<&[Declaration]> {
+        self.files.iter().find(|(p, _)| p == path).map(|(_, decls)| decls.as_slice())
+    }
+
+    /// Whether `decl` was classified as a stub. Decorated Python functions
+    /// are declared twice, with and without their decorators, and the stub
+    /// is reported on the line of the inner declaration.
+    fn is_stub(&self, path: &Path, decl: &Declaration) -> bool {
+        let stub_at = |d: &Declaration| self.stubs.contains(&(path.to_path_buf(), d.span.start_line));
+        decl.kind.is_callable()
+            && (stub_at(decl)
+                || self.declarations(path).unwrap_or_default().iter().any(|d| {
+                    d.kind.is_callable()
+                        && d.name == decl.name
+                        && decl.span.start_byte <= d.span.start_byte
+                        && d.span.end_byte <= decl.span.end_byte
+                        && stub_at(d)
+                }))
+    }
+
+    /// A callable named like `handler` (its last `.` or `::` segment),
+    /// preferring one declared in `near`.
+    fn callable_named(&self, handler: &str, near: &Path) -> Option<(PathBuf, Declaration)> {
+        let handler = handler.strip_suffix(".as_view").unwrap_or(handler);
+        let name = handler.rsplit(['.', ':']).next().unwrap_or(handler);
+        let mut found = self.files.iter().flat_map(|(path, decls)| {
+            decls
+                .iter()
+                .filter(|d| d.kind.is_callable() && d.name == name)
+                .map(move |d| (path, d))
+        });
+        let first = found.clone().next();
+        found
+            .find(|(path, _)| path.as_path() == near)
+            .or(first)
+            .map(|(path, decl)| (path.clone(), decl.clone()))
+    }
+
+    /// The first callable declared at or below `line`, within `DECORATOR_REACH` lines.
+    fn callable_below(&self, path: &Path, line: usize) -> Option<(PathBuf, Declaration)> {
+        self.declarations(path)?
+            .iter()
+            .filter(|d| d.kind.is_callable() && d.span.start_line >= line && d.span.start_line <= line + DECORATOR_REACH)
+            .min_by_key(|d| (d.span.start_line, d.span.end_byte - d.span.start_byte))
+            .map(|d| (path.to_path_buf(), d.clone()))
+    }
+}
+
+/// Lowercase a subcommand name and drop `-` and `_`, as documentation drift does.
+fn normalize_command(name: &str) -> String {
+    name.chars()
+        .filter(|c| *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Normalize a route path: one leading `/`, no trailing `/`, and every
+/// parameter (`{id}`, `:id`, `<int:id>`, `*rest`) written as `{}`.
+fn normalize_route(path: &str) -> String {
+    let path = path.trim().trim_start_matches('^').trim_end_matches('$');
+    let segments: Vec<&str> = path
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let param = s.starts_with(':')
+                || s.starts_with('*')
+                || (s.starts_with('{') && s.ends_with('}'))
+                || (s.starts_with('<') && s.ends_with('>'));
+            if param {
+                "{}"
+            } else {
+                s
+            }
+        })
+        .collect();
+    format!("/{}", segments.join("/"))
+}
+
+/// HTTP method of a registration; methods that mean "any" become `None`.
+fn normalize_method(method: &str) -> Option<String> {
+    let method = method.to_uppercase();
+    (!matches!(method.as_str(), "ALL" | "ANY" | "REQUEST" | "")).then_some(method)
+}
+
+/// Line (1-indexed) of a byte offset.
+fn line_at(source: &str, offset: usize) -> usize {
+    source[..offset].matches('\n').count() + 1
+}
+
+/// The `Run` or `RunE` function of the struct literal a match sits in.
+fn run_field(source: &str, offset: usize) -> Option<String> {
+    let mut depth = 0i32;
+    let mut end = source.len();
+    for (idx, c) in source[offset..].char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => {
+                end = offset + idx;
+                break;
+            }
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    let caps = COBRA_RUN.captures(&source[offset..end])?;
+    (&caps[1] != "func").then(|| caps[1].to_string())
+}
+
+/// Find registrations in the scanned files. `key` reads what a match
+/// registers; matches it returns `None` for are skipped.
+fn find_sites(
+    project: &Project,
+    patterns: &[CompiledPattern],
+    key: impl Fn(&Captures, Option<&Declaration>) -> Option<String>,
+) -> Vec<Site> {
+    let mut sites = Vec::new();
+    for (path, _) in &project.files {
+        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+        let applicable: Vec<&CompiledPattern> = patterns
+            .iter()
+            .filter(|p| p.extensions.is_empty() || p.extensions.iter().any(|e| e == ext))
+            .collect();
+        if applicable.is_empty() {
+            continue;
+        }
+        let Ok(source) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        for pattern in applicable {
+            for caps in pattern.regex.captures_iter(&source) {
+                let whole = caps.get(0).expect("match has group 0");
+                let line = line_at(&source, whole.start());
+                let handler = match pattern.handler {
+                    HandlerAt::Captured => caps.name("handler").and_then(|h| project.callable_named(h.as_str(), path)),
+                    HandlerAt::Below => project.callable_below(path, line),
+                    HandlerAt::RunField => {
+                        run_field(&source, whole.end()).and_then(|h| project.callable_named(&h, path))
+                    }
+                };
+                let Some(key) = key(&caps, handler.as_ref().map(|(_, d)| d)) else {
+                    continue;
+                };
+                sites.push(Site {
+                    key,
+                    method: caps.name("method").and_then(|m| normalize_method(m.as_str())),
+                    file: path.clone(),
+                    line,
+                    framework: pattern.framework.clone(),
+                    handler,
+                });
+            }
+        }
+    }
+    sites
+}
+
+/// Subcommands of enums deriving clap's `Subcommand`, named as clap names
+/// them by default.
+fn clap_variants(project: &Project) -> Vec<Site> {
+    let mut sites = Vec::new();
+    for (path, decls) in &project.files {
+        if path.extension().is_none_or(|e| e != "rs") {
+            continue;
+        }
+        let Ok(source) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        for decl in decls.iter().filter(|d| d.kind == DeclarationKind::Enum) {
+            // Attributes sit on the lines above the enum
+            let attributes: Vec<&str> = source[..decl.span.start_byte]
+                .lines()
+                .rev()
+                .skip_while(|l| l.trim().is_empty())
+                .take_while(|l| {
+                    let l = l.trim();
+                    l.starts_with("#[") || l.starts_with("///") || l.starts_with("//")
+                })
+                .collect();
+            if !attributes.iter().any(|a| a.contains("derive(") && a.contains("Subcommand")) {
+                continue;
+            }
+            for variant in &decl.members {
+                sites.push(Site {
+                    key: normalize_command(variant),
+                    method: None,
+                    file: path.clone(),
+                    line: decl.span.start_line,
+                    framework: "clap".to_string(),
+                    handler: None,
+                });
+            }
+        }
+    }
+    sites
+}
+
+/// Resolve registrations of an entry: satisfied if any has a handler that is
+/// not a stub or names none, hollow if every handler is a stub.
+fn resolve_sites(project: &Project, sites: &[&Site], handler: Option<&str>, what: &str) -> (EntryStatus, Option<String>, Option<String>) {
+    let Some(first) = sites.first() else {
+        return (EntryStatus::Missing, None, Some(format!("no {} found", what)));
+    };
+    let handler_of = |site: &Site| match handler {
+        Some(name) => project.callable_named(name, &site.file),
+        None => site.handler.clone(),
+    };
+
+    for site in sites {
+        let stub = handler_of(site).filter(|(path, decl)| project.is_stub(path, decl));
+        if stub.is_none() {
+            return (
+                EntryStatus::Satisfied,
+                Some(project.location(&site.file, site.line)),
+                Some(format!("registered with {}", site.framework)),
+            );
+        }
+    }
+    let (path, decl) = handler_of(first).expect("every site has a stub handler");
+    (
+        EntryStatus::Hollow,
+        Some(project.location(&first.file, first.line)),
+        Some(format!("handler {} is a stub at {}", decl.name, project.location(&path, decl.span.start_line))),
+    )
+}
+
+/// Whether `decl` belongs to the type named `container`: as a method's
+/// receiver, inside its declaration, or among its declared members.
+fn in_container(decls: &[Declaration], decl: &Declaration, container: &str) -> bool {
+    if decl.receiver.as_deref().map(|r| r.trim_start_matches('*')) == Some(container) {
+        return true;
+    }
+    decls.iter().any(|c| {
+        c.name == container
+            && !c.kind.is_callable()
+            && ((c.span.start_byte <= decl.span.start_byte
+                && decl.span.end_byte <= c.span.end_byte
+                && c.span != decl.span)
+                || c.members.contains(&decl.name))
+    })
+}
+
+fn resolve_file(project: &Project, path: &str) -> (EntryStatus, Option<String>, Option<String>) {
+    let full = project.base_dir.join(path);
+    match lookup_exact_case(&project.base_dir, path) {
+        CaseLookup::Missing => (EntryStatus::Missing, None, Some("file does not exist".to_string())),
+        CaseLookup::CaseMismatch(found) => (EntryStatus::Missing, Some(found.clone()), Some(case_mismatch_message(&found, path))),
+        CaseLookup::Exact if full.is_dir() => {
+            (EntryStatus::Missing, None, Some("path is a directory, not a file".to_string()))
+        }
+        CaseLookup::Exact => {
+            let empty = std::fs::read_to_string(&full).is_ok_and(|c| c.trim().is_empty());
+            let callables: Vec<&Declaration> = project
+                .declarations(&full)
+                .unwrap_or_default()
+                .iter()
+                .filter(|d| d.kind.is_callable())
+                .collect();
+            if empty {
+                (EntryStatus::Hollow, Some(path.to_string()), Some("file is empty".to_string()))
+            } else if !callables.is_empty() && callables.iter().all(|d| project.is_stub(&full, d)) {
+                (EntryStatus::Hollow, Some(path.to_string()), Some("every function in the file is a stub".to_string()))
+            } else {
+                (EntryStatus::Satisfied, Some(path.to_string()), None)
+            }
+        }
+    }
+}
+
+fn resolve_symbol(
+    project: &Project,
+    name: &str,
+    kind: Option<SymbolKind>,
+    file: Option<&str>,
+    container: Option<&str>,
+) -> (EntryStatus, Option<String>, Option<String>) {
+    let mut candidates = Vec::new();
+    for (path, decls) in &project.files {
+        if file.is_some_and(|f| project.relative(path) != f) {
+            continue;
+        }
+        for decl in decls {
+            if decl.name == name
+                && kind.is_none_or(|k| declaration_kind_to_symbol_kind(decl.kind) == k)
+                && container.is_none_or(|c| in_container(decls, decl, c))
+            {
+                candidates.push((path, decl));
+            }
+        }
+    }
+
+    if let Some((path, decl)) = candidates.iter().find(|(path, decl)| !project.is_stub(path, decl)) {
+        return (EntryStatus::Satisfied, Some(project.location(path, decl.span.start_line)), None);
+    }
+    if let Some((path, decl)) = candidates.first() {
+        let location = project.location(path, decl.span.start_line);
+        return (EntryStatus::Hollow, Some(location.clone()), Some(format!("stub at {}", location)));
+    }
+    let detail = match file.map(|f| lookup_exact_case(&project.base_dir, f)) {
+        Some(CaseLookup::CaseMismatch(found)) => case_mismatch_message(&found, file.unwrap_or_default()),
+        Some(CaseLookup::Missing) => "file does not exist".to_string(),
+        _ => "no matching declaration".to_string(),
+    };
+    (EntryStatus::Missing, None, Some(detail))
+}
+
+/// Resolve every entry of a spec against the scanned files.
+///
+/// `files` are the files `lint` would scan under `base_dir`; stubs are
+/// classified with `stub_config`.
+pub fn verify_spec(
+    base_dir: &Path,
+    files: &[PathBuf],
+    spec: &TaskSpec,
+    stub_config: &StubDetectionConfig,
+    min_completion: Option<f64>,
+) -> anyhow::Result<SpecReport> {
+    let mut route_patterns = compile_heuristics(ROUTE_HEURISTICS);
+    route_patterns.extend(compile_patterns(&spec.route_patterns, "route", "path")?);
+    let mut command_patterns = compile_heuristics(COMMAND_HEURISTICS);
+    command_patterns.extend(compile_patterns(&spec.command_patterns, "command", "name")?);
+
+    let analysis_ctx = AnalysisContext::new(base_dir);
+    let facts = FactsBundle::build(&analysis_ctx, files, &[FactKind::Declarations]);
+    let mut sorted_files: Vec<&PathBuf> = files.iter().collect();
+    sorted_files.sort();
+    let project_files = sorted_files
+        .into_iter()
+        .filter_map(|path| match facts.file(path) {
+            Some(Ok(file_facts)) => Some((path.clone(), file_facts.declarations.clone())),
+            _ => None,
+        })
+        .collect();
+    let stubs = detect_stub_functions(files, Some(stub_config))?
+        .violations
+        .into_iter()
+        .filter(|v| v.rule == ViolationRule::StubFunction)
+        .filter_map(|v| Some((PathBuf::from(v.file?), v.line)))
+        .collect();
+    let project = Project {
+        base_dir: base_dir.to_path_buf(),
+        files: project_files,
+        stubs,
+    };
+
+    let needs = |kind: &str| spec.expected.iter().any(|e| e.kind() == kind);
+    let routes = if needs("route") {
+        find_sites(&project, &route_patterns, |caps, _| caps.name("path").map(|p| normalize_route(p.as_str())))
+    } else {
+        Vec::new()
+    };
+    let commands = if needs("command") {
+        let mut sites = find_sites(&project, &command_patterns, |caps, handler| {
+            let name = caps.name("name").map(|n| n.as_str().to_string());
+            name.or_else(|| handler.map(|d| d.name.clone())).map(|n| normalize_command(&n))
+        });
+        sites.extend(clap_variants(&project));
+        sites
+    } else {
+        Vec::new()
+    };
+
+    let mut entries = Vec::new();
+    for expected in &spec.expected {
+        let (status, location, detail) = match expected {
+            ExpectedArtifact::File { path } => resolve_file(&project, path),
+            ExpectedArtifact::Symbol { name, kind, file, container } => {
+                resolve_symbol(&project, name, *kind, file.as_deref(), container.as_deref())
+            }
+            ExpectedArtifact::Command { name, handler } => {
+                let key = normalize_command(name);
+                let sites: Vec<&Site> = commands.iter().filter(|s| s.key == key).collect();
+                resolve_sites(&project, &sites, handler.as_deref(), "subcommand declaration")
+            }
+            ExpectedArtifact::Route { path, method, handler } => {
+                let key = normalize_route(path);
+                let method = method.as_deref().and_then(normalize_method);
+                let sites: Vec<&Site> = routes
+                    .iter()
+                    .filter(|s| s.key == key && (method.is_none() || s.method.is_none() || s.method == method))
+                    .collect();
+                resolve_sites(&project, &sites, handler.as_deref(), "route registration")
+            }
+        };
+        entries.push(EntryResult {
+            kind: expected.kind().to_string(),
+            expected: expected.label(),
+            status,
+            location,
+            detail,
+        });
+    }
+
+    let count = |status| entries.iter().filter(|e| e.status == status).count();
+    let (satisfied, hollow, missing) = (
+        count(EntryStatus::Satisfied),
+        count(EntryStatus::Hollow),
+        count(EntryStatus::Missing),
+    );
+    let completion = if entries.is_empty() {
+        100.0
+    } else {
+        satisfied as f64 * 100.0 / entries.len() as f64
+    };
+    Ok(SpecReport {
+        spec_version: spec.version,
+        name: spec.name.clone(),
+        entries,
+        satisfied,
+        hollow,
+        missing,
+        completion,
+        min_completion: min_completion.or(spec.min_completion).unwrap_or(DEFAULT_MIN_COMPLETION),
+    })
+}
+
+/// Render a spec report as JSON.
+pub fn render_spec_json(report: &SpecReport) -> anyhow::Result<String> {
+    Ok(serde_json::to_string_pretty(report)?)
+}
+
+/// Render a spec report as a table; colors follow the `colored` crate's settings.
+pub fn render_spec_pretty(report: &SpecReport) -> String {
+    let mut buf = String::new();
+    let width = report.entries.iter().map(|e| e.expected.chars().count()).max().unwrap_or(0);
+
+    writeln!(buf).unwrap();
+    let name = report.name.as_deref().unwrap_or("task spec");
+    writeln!(buf, "  {} {}", "Spec:".bold(), format!("{} (version {})", name, report.spec_version).dimmed()).unwrap();
+    writeln!(buf).unwrap();
+    if report.entries.is_empty() {
+        writeln!(buf, "    {}", "(no expected artifacts)".dimmed()).unwrap();
+    }
+    for entry in &report.entries {
+        let mark = match entry.status {
+            EntryStatus::Satisfied => "✓".green(),
+            EntryStatus::Hollow => "~".yellow(),
+            EntryStatus::Missing => "✗".red(),
+        };
+        let note = match (entry.status, &entry.location, &entry.detail) {
+            (EntryStatus::Satisfied, Some(location), _) => location.clone(),
+            (_, _, Some(detail)) => detail.clone(),
+            _ => String::new(),
+        };
+        writeln!(
+            buf,
+            "    {} {:<8}{:<width$}  {}",
+            mark,
+            entry.kind,
+            entry.expected,
+            note.dimmed(),
+            width = width
+        )
+        .unwrap();
+    }
+
+    writeln!(buf).unwrap();
+    let summary = format!(
+        "{:.1}% ({} satisfied, {} hollow, {} missing of {}); minimum {:.1}%",
+        report.completion,
+        report.satisfied,
+        report.hollow,
+        report.missing,
+        report.entries.len(),
+        report.min_completion
+    );
+    let summary = if report.passed() { summary.green() } else { summary.red() };
+    writeln!(buf, "  {} {}", "Completion:".bold(), summary).unwrap();
+    writeln!(buf).unwrap();
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Write fixture files and verify a spec against them.
+    fn verify(files: &[(&str, &str)], spec: &str) -> SpecReport {
+        crate::analysis::register_analyzers();
+        let temp = TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = files
+            .iter()
+            .map(|(name, content)| {
+                let path = temp.path().join(name);
+                std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+                std::fs::write(&path, content).unwrap();
+                path
+            })
+            .collect();
+        let spec = TaskSpec::parse(spec).unwrap();
+        verify_spec(temp.path(), &paths, &spec, &StubDetectionConfig::default_enabled(), None).unwrap()
+    }
+
+    fn statuses(report: &SpecReport) -> Vec<(&str, &str)> {
+        report.entries.iter().map(|e| (e.expected.as_str(), e.status.as_str())).collect()
+    }
+
+    #[test]
+    fn test_parse_spec() {
+        let err = TaskSpec::parse(r#"{"version": 2, "expected": [{"type": "unknown"}]}"#).unwrap_err();
+        assert_eq!(err.to_string(), "unsupported spec version 2, this build reads version 1");
+        let err = TaskSpec::parse(r#"{"expected": []}"#).unwrap_err();
+        assert!(err.to_string().starts_with("missing spec version"));
+        assert!(TaskSpec::parse(r#"{"version": 1, "expected": [{"type": "file", "path": "a", "extra": 1}]}"#).is_err());
+        assert!(TaskSpec::parse(r#"{"version": 1, "min_completion": 120, "expected": []}"#).is_err());
+        let err = TaskSpec::parse(
+            r#"{"version": 1, "route_patterns": [{"name": "custom", "pattern": "route\\((.*)\\)"}], "expected": []}"#,
+        )
+        .unwrap_err();
+        assert_eq!(err.to_string(), "route pattern \"custom\" has no named group \"path\"");
+
+        let spec = TaskSpec::parse(
+            r#"{"version": 1, "name": "users", "expected": [
+                {"type": "symbol", "name": "Create", "kind": "method", "container": "UserService"},
+                {"type": "route", "path": "/users/{id}", "method": "get"}
+            ]}"#,
+        )
+        .unwrap();
+        let labels: Vec<_> = spec.expected.iter().map(|e| (e.kind(), e.label())).collect();
+        assert_eq!(
+            labels,
+            vec![("symbol", "method UserService.Create".to_string()), ("route", "GET /users/{id}".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_normalize_route() {
+        assert_eq!(normalize_route("/users/{id}/"), "/users/{}");
+        assert_eq!(normalize_route("users/<int:id>"), "/users/{}");
+        assert_eq!(normalize_route("^users/:id$"), "/users/{}");
+        assert_eq!(normalize_route("/"), "/");
+        assert_eq!(normalize_method("all"), None);
+        assert_eq!(normalize_method("Get"), Some("GET".to_string()));
+    }
+
+    #[test]
+    fn test_files_and_symbols() {
+        let service = r#"package users
+
+type UserService struct{}
+
+func (s *UserService) Create(name string) error {
+	if name == "" {
+		return errors.New("name required")
+	}
+	return s.store.Insert(name)
+}
+
+func (s *UserService) Delete(id string) error {
+	panic("not implemented")
+}
+"#;
+        let report = verify(
+            &[("users/service.go", service), ("README.md", "# users\n"), ("NOTES.md", "  \n")],
+            r#"{"version": 1, "expected": [
+                {"type": "file", "path": "README.md"},
+                {"type": "file", "path": "readme.md"},
+                {"type": "file", "path": "NOTES.md"},
+                {"type": "file", "path": "users/service.go"},
+                {"type": "symbol", "name": "Create", "kind": "method", "container": "UserService"},
+                {"type": "symbol", "name": "Delete", "file": "users/service.go"},
+                {"type": "symbol", "name": "Create", "container": "OrderService"},
+                {"type": "symbol", "name": "UserService", "kind": "type", "file": "Users/service.go"}
+            ]}"#,
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("README.md", "satisfied"),
+                ("readme.md", "missing"),
+                ("NOTES.md", "hollow"),
+                ("users/service.go", "satisfied"),
+                ("method UserService.Create", "satisfied"),
+                ("Delete", "hollow"),
+                ("OrderService.Create", "missing"),
+                ("type UserService", "missing"),
+            ]
+        );
+        assert_eq!(
+            report.entries[1].detail.as_deref(),
+            Some("found 'README.md' but contract requires 'readme.md' — case mismatch")
+        );
+        assert_eq!(report.entries[4].location.as_deref(), Some("users/service.go:5"));
+        assert_eq!(report.entries[5].detail.as_deref(), Some("stub at users/service.go:12"));
+        assert_eq!(
+            report.entries[7].detail.as_deref(),
+            Some("found 'users/service.go' but contract requires 'Users/service.go' — case mismatch")
+        );
+        assert_eq!((report.satisfied, report.hollow, report.missing), (3, 2, 3));
+        assert!((report.completion - 37.5).abs() < 1e-9);
+        assert!(!report.passed());
+    }
+
+    #[test]
+    fn test_go_routes_and_cobra_commands() {
+        let server = r#"package main
+
+func main() {
+	http.HandleFunc("GET /health", health)
+	r := gin.Default()
+	r.GET("/users/:id", auth, getUser)
+	r.POST("/users", createUser)
+}
+
+func health(w http.ResponseWriter, r *http.Request) {
+	w.WriteHeader(http.StatusOK)
+	fmt.Fprintln(w, "ok")
+}
+
+func getUser(c *gin.Context) {
+	c.JSON(200, store.Find(c.Param("id")))
+}
+
+func createUser(c *gin.Context) {
+}
+"#;
+        let cmd = r#"package cmd
+
+var serveCmd = &cobra.Command{
+	Use:   "serve",
+	Short: "Start the server",
+	RunE:  runServe,
+}
+
+var migrateCmd = &cobra.Command{
+	Use: "migrate",
+	Run: func(cmd *cobra.Command, args []string) {},
+}
+
+func runServe(cmd *cobra.Command, args []string) error {
+	panic("TODO")
+}
+"#;
+        let report = verify(
+            &[("main.go", server), ("cmd/root.go", cmd)],
+            r#"{"version": 1, "expected": [
+                {"type": "route", "path": "/health", "method": "GET"},
+                {"type": "route", "path": "/users/{id}", "method": "GET"},
+                {"type": "route", "path": "/users", "method": "POST"},
+                {"type": "route", "path": "/users", "method": "DELETE"},
+                {"type": "command", "name": "serve"},
+                {"type": "command", "name": "migrate"},
+                {"type": "command", "name": "version"}
+            ]}"#,
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("GET /health", "satisfied"),
+                ("GET /users/{id}", "satisfied"),
+                ("POST /users", "hollow"),
+                ("DELETE /users", "missing"),
+                ("serve", "hollow"),
+                ("migrate", "satisfied"),
+                ("version", "missing"),
+            ]
+        );
+        assert_eq!(report.entries[0].location.as_deref(), Some("main.go:4"));
+        assert_eq!(report.entries[0].detail.as_deref(), Some("registered with net/http"));
+        assert_eq!(report.entries[2].detail.as_deref(), Some("handler createUser is a stub at main.go:19"));
+        assert_eq!(report.entries[4].detail.as_deref(), Some("handler runServe is a stub at cmd/root.go:14"));
+    }
+
+    #[test]
+    fn test_python_routes_and_commands() {
+        let app = r#"from flask import Flask
+
+app = Flask(__name__)
+
+
+@app.route("/orders/<int:order_id>", methods=["GET"])
+def get_order(order_id):
+    return jsonify(db.get(order_id))
+
+
+@app.post("/orders")
+def create_order():
+    raise Exception("not implemented")
+"#;
+        let urls = r#"from django.urls import path
+from . import views
+
+urlpatterns = [
+    path("reports/", views.report_list),
+]
+"#;
+        let views = r#"def report_list(request):
+    reports = Report.objects.all()
+    return render(request, "reports.html", {"reports": reports})
+"#;
+        let cli = r#"import argparse
+import click
+
+parser = argparse.ArgumentParser()
+sub = parser.add_subparsers()
+sub.add_parser("import-data")
+
+
+@click.command()
+def export_data():
+    raise Exception("not implemented")
+"#;
+        let report = verify(
+            &[("app.py", app), ("reports/urls.py", urls), ("reports/views.py", views), ("cli.py", cli)],
+            r#"{"version": 1, "expected": [
+                {"type": "route", "path": "/orders/{id}", "method": "GET"},
+                {"type": "route", "path": "/orders", "method": "POST"},
+                {"type": "route", "path": "/reports"},
+                {"type": "command", "name": "import_data"},
+                {"type": "command", "name": "export-data"}
+            ]}"#,
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("GET /orders/{id}", "satisfied"),
+                ("POST /orders", "hollow"),
+                ("/reports", "satisfied"),
+                ("import_data", "satisfied"),
+                ("export-data", "hollow"),
+            ]
+        );
+        assert_eq!(report.entries[0].detail.as_deref(), Some("registered with flask"));
+        assert_eq!(report.entries[2].detail.as_deref(), Some("registered with django"));
+    }
+
+    #[test]
+    fn test_js_and_java_routes() {
+        let server = r#"const express = require('express');
+const app = express();
+
+app.get('/items/:id', (req, res) => {
+  res.json(items.find(req.params.id));
+});
+app.post('/items', createItem);
+
+function createItem(req, res) {
+  throw new Error('not implemented');
+}
+"#;
+        let controller = r#"package com.example;
+
+@RestController
+public class AccountController {
+    @GetMapping("/accounts/{id}")
+    public Account get(@PathVariable String id) {
+        return repository.findById(id).orElseThrow();
+    }
+
+    @PostMapping(value = "/accounts")
+    public Account create(@RequestBody Account account) {
+        throw new UnsupportedOperationException("TODO");
+    }
+}
+"#;
+        let report = verify(
+            &[("server.js", server), ("src/AccountController.java", controller)],
+            r#"{"version": 1, "expected": [
+                {"type": "route", "path": "/items/{id}", "method": "GET"},
+                {"type": "route", "path": "/items", "method": "POST"},
+                {"type": "route", "path": "/accounts/{id}", "method": "GET"},
+                {"type": "route", "path": "/accounts", "method": "POST"}
+            ]}"#,
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("GET /items/{id}", "satisfied"),
+                ("POST /items", "hollow"),
+                ("GET /accounts/{id}", "satisfied"),
+                ("POST /accounts", "hollow"),
+            ]
+        );
+        assert_eq!(report.entries[0].detail.as_deref(), Some("registered with express"));
+        assert_eq!(report.entries[2].detail.as_deref(), Some("registered with spring"));
+    }
+
+    #[test]
+    fn test_rust_routes_and_clap_commands() {
+        let server = r#"pub fn app() -> Router {
+    Router::new()
+        .route("/tasks", get(list_tasks))
+        .route("/tasks/:id", delete(remove_task))
+}
+
+async fn list_tasks() -> Json<Vec<Task>> {
+    Json(store().all().await)
+}
+
+async fn remove_task(Path(id): Path<u64>) -> StatusCode {
+    todo!()
+}
+
+#[get("/status")]
+async fn status() -> impl Responder {
+    HttpResponse::Ok().body(format!("up {}", uptime()))
+}
+"#;
+        let cli = r#"#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Check the tree
+    Lint(LintArgs),
+    #[command(name = "why")]
+    WhyImport(WhyArgs),
+}
+
+fn build() -> Command {
+    Command::new("app").subcommand(Command::new("prefetch"))
+}
+"#;
+        let report = verify(
+            &[("src/server.rs", server), ("src/cli.rs", cli)],
+            r#"{"version": 1, "expected": [
+                {"type": "route", "path": "/tasks", "method": "GET"},
+                {"type": "route", "path": "/tasks/{id}", "method": "DELETE"},
+                {"type": "route", "path": "/status"},
+                {"type": "command", "name": "lint"},
+                {"type": "command", "name": "why-import"},
+                {"type": "command", "name": "why"},
+                {"type": "command", "name": "prefetch"},
+                {"type": "command", "name": "fleet"}
+            ]}"#,
+        );
+        assert_eq!(
+            statuses(&report),
+            vec![
+                ("GET /tasks", "satisfied"),
+                ("DELETE /tasks/{id}", "hollow"),
+                ("/status", "satisfied"),
+                ("lint", "satisfied"),
+                ("why-import", "satisfied"),
+                ("why", "satisfied"),
+                ("prefetch", "satisfied"),
+                ("fleet", "missing"),
+            ]
+        );
+        assert_eq!(report.entries[2].detail.as_deref(), Some("registered with actix/rocket"));
+    }
+
+    #[test]
+    fn test_spec_patterns_and_handlers() {
+        let routes = r#"package main
+
+func register(s *Server) {
+	s.Mount("/billing", billing)
+}
+
+func billing(w http.ResponseWriter, r *http.Request) {}
+
+func realBilling(w http.ResponseWriter, r *http.Request) {
+	json.NewEncoder(w).Encode(invoices.All())
+}
+"#;
+        let report = verify(
+            &[("routes.go", routes)],
+            r#"{"version": 1, "min_completion": 50, "route_patterns": [
+                {"name": "mount", "extensions": ["go"], "pattern": "\\.Mount\\(\"(?P<path>[^\"]+)\",\\s*(?P<handler>\\w+)"}
+            ], "expected": [
+                {"type": "route", "path": "/billing"},
+                {"type": "route", "path": "/billing", "handler": "realBilling"}
+            ]}"#,
+        );
+        assert_eq!(statuses(&report), vec![("/billing", "hollow"), ("/billing", "satisfied")]);
+        assert_eq!(report.entries[1].detail.as_deref(), Some("registered with mount"));
+        assert_eq!(report.min_completion, 50.0);
+        assert!(report.passed());
+
+        let pretty = render_spec_pretty(&report);
+        assert!(pretty.contains("handler billing is a stub at routes.go:7"));
+        assert!(pretty.contains("50.0% (1 satisfied, 1 hollow, 0 missing of 2); minimum 50.0%"));
+    }
+}
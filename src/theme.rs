@@ -0,0 +1,241 @@
+//! Presentation themes for the pretty reporter.
+//!
+//! `default` keeps the red/yellow/green palette. `colorblind` swaps it for
+//! blue and orange (the Okabe-Ito colors) and marks each severity with its
+//! own symbol, so color is never the only channel. `mono` writes no escape
+//! codes at all but keeps the symbols, for output piped into other tools.
+//!
+//! Whatever the theme, the score band, grade, and pass/fail status are also
+//! written as text; colors only repeat what the words already say.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::detect::Severity;
+use crate::score::grades;
+
+/// Environment variable selecting a theme when `--theme` is not given.
+pub const THEME_ENV: &str = "HOLLOWCHECK_THEME";
+
+/// A color theme for pretty output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// Red, yellow, and green.
+    #[default]
+    Default,
+    /// Blue and orange, with a symbol per severity.
+    Colorblind,
+    /// No colors; symbols per severity.
+    Mono,
+}
+
+impl Theme {
+    /// All themes, in the order the help text lists them.
+    pub const ALL: [Theme; 3] = [Theme::Default, Theme::Colorblind, Theme::Mono];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Theme::Default => "default",
+            Theme::Colorblind => "colorblind",
+            Theme::Mono => "mono",
+        }
+    }
+
+    /// Pick the theme: the `--theme` flag, then `HOLLOWCHECK_THEME`, then
+    /// the user config's `theme`. With none of those, `NO_COLOR` selects
+    /// `mono`. An invalid value is an error naming where it came from.
+    pub fn resolve(
+        flag: Option<&str>,
+        env: Option<&str>,
+        user_config: Option<&str>,
+        no_color: bool,
+    ) -> anyhow::Result<Theme> {
+        let sources = [
+            (flag, "--theme"),
+            (env.filter(|v| !v.is_empty()), THEME_ENV),
+            (user_config, "theme in the user config"),
+        ];
+        for (value, source) in sources {
+            if let Some(value) = value {
+                return value
+                    .parse()
+                    .map_err(|e| anyhow::anyhow!("invalid {}: {}", source, e));
+            }
+        }
+        Ok(if no_color { Theme::Mono } else { Theme::Default })
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Theme::ALL
+            .into_iter()
+            .find(|t| t.as_str() == s.trim().to_lowercase())
+            .ok_or_else(|| format!("unknown theme {:?} (expected default, colorblind, or mono)", s))
+    }
+}
+
+/// What a piece of pretty output means; the palette picks its look.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Style {
+    /// The `hollowcheck` banner.
+    Banner,
+    /// Section titles.
+    Heading,
+    /// Labels and secondary details.
+    Muted,
+    /// File paths and waiver targets.
+    Location,
+    /// A clean score or a passing check.
+    Good,
+    /// The best score band.
+    GoodStrong,
+    /// A middling score band.
+    Caution,
+    /// The worst middling score band.
+    CautionStrong,
+    /// A failing check or the worst score band.
+    Bad,
+    /// Critical findings.
+    BadStrong,
+    /// Informational findings.
+    Note,
+}
+
+/// A theme plus whether to write escape codes at all.
+///
+/// Escape codes are written directly rather than through the terminal
+/// color crate, so the same palette renders the same bytes in tests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    pub theme: Theme,
+    color: bool,
+}
+
+impl Palette {
+    /// A palette for `theme`; `mono` never colors, whatever `color` says.
+    pub fn new(theme: Theme, color: bool) -> Self {
+        Palette {
+            theme,
+            color: color && theme != Theme::Mono,
+        }
+    }
+
+    /// Wrap `text` in the escape codes for `style`, if coloring.
+    pub fn paint(&self, style: Style, text: &str) -> String {
+        if !self.color || text.is_empty() {
+            return text.to_string();
+        }
+        format!("\x1b[{}m{}\x1b[0m", self.codes(style), text)
+    }
+
+    /// SGR codes for a style in this theme.
+    fn codes(&self, style: Style) -> &'static str {
+        match (self.theme, style) {
+            (_, Style::Heading) => "1",
+            (_, Style::Muted) => "2",
+            (Theme::Colorblind, Style::Banner) => "1;38;2;86;180;233",
+            (Theme::Colorblind, Style::Location | Style::Note) => "38;2;86;180;233",
+            (Theme::Colorblind, Style::Good) => "38;2;0;114;178",
+            (Theme::Colorblind, Style::GoodStrong) => "1;38;2;0;114;178",
+            (Theme::Colorblind, Style::Caution) => "38;2;230;159;0",
+            (Theme::Colorblind, Style::CautionStrong) => "1;38;2;230;159;0",
+            (Theme::Colorblind, Style::Bad) => "38;2;213;94;0",
+            (Theme::Colorblind, Style::BadStrong) => "1;38;2;213;94;0",
+            (_, Style::Banner) => "1;36",
+            (_, Style::Location | Style::Note) => "34",
+            (_, Style::Good) => "32",
+            (_, Style::GoodStrong) => "1;32",
+            (_, Style::Caution) => "33",
+            (_, Style::CautionStrong) => "1;33",
+            (_, Style::Bad) => "31",
+            (_, Style::BadStrong) => "1;31",
+        }
+    }
+
+    /// Symbol written before a severity tag, if the theme uses them.
+    pub fn severity_symbol(&self, severity: &Severity) -> Option<&'static str> {
+        if self.theme == Theme::Default {
+            return None;
+        }
+        Some(match severity {
+            Severity::Critical | Severity::Error => "✖",
+            Severity::Warning => "▲",
+            Severity::Info => "ℹ",
+        })
+    }
+
+    /// Style for a severity tag.
+    pub fn severity_style(&self, severity: &Severity) -> Style {
+        match severity {
+            Severity::Critical => Style::BadStrong,
+            Severity::Error => Style::Bad,
+            Severity::Warning => Style::Caution,
+            Severity::Info => Style::Note,
+        }
+    }
+
+    /// Style for a score or grade, by band.
+    pub fn score_style(&self, score: i32) -> Style {
+        match score {
+            s if s <= grades::A_MAX => Style::GoodStrong,
+            s if s <= grades::B_MAX => Style::Good,
+            s if s <= grades::C_MAX => Style::Caution,
+            s if s <= grades::D_MAX => Style::CautionStrong,
+            _ => Style::Bad,
+        }
+    }
+}
+
+/// Name of the band a score falls in, matching the grade boundaries.
+pub fn score_band(score: i32) -> &'static str {
+    match score {
+        s if s <= grades::A_MAX => "minimal",
+        s if s <= grades::B_MAX => "low",
+        s if s <= grades::C_MAX => "moderate",
+        s if s <= grades::D_MAX => "high",
+        _ => "severe",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_precedence() {
+        let resolve = |flag, env, config, no_color| Theme::resolve(flag, env, config, no_color).unwrap();
+        assert_eq!(resolve(None, None, None, false), Theme::Default);
+        assert_eq!(resolve(None, None, None, true), Theme::Mono);
+        assert_eq!(resolve(None, None, Some("colorblind"), true), Theme::Colorblind);
+        assert_eq!(resolve(None, Some("mono"), Some("colorblind"), false), Theme::Mono);
+        assert_eq!(resolve(None, Some(""), Some("colorblind"), false), Theme::Colorblind);
+        assert_eq!(resolve(Some("Default"), Some("mono"), None, true), Theme::Default);
+
+        let err = Theme::resolve(None, Some("neon"), None, false).unwrap_err();
+        assert!(err.to_string().contains("invalid HOLLOWCHECK_THEME"), "{}", err);
+    }
+
+    #[test]
+    fn test_palette_codes() {
+        let default = Palette::new(Theme::Default, true);
+        assert_eq!(default.paint(Style::Bad, "FAIL"), "\x1b[31mFAIL\x1b[0m");
+        assert_eq!(default.severity_symbol(&Severity::Error), None);
+
+        let colorblind = Palette::new(Theme::Colorblind, true);
+        assert_eq!(colorblind.paint(Style::Good, "PASS"), "\x1b[38;2;0;114;178mPASS\x1b[0m");
+        assert_eq!(colorblind.severity_symbol(&Severity::Warning), Some("▲"));
+
+        // Mono and disabled colors write no escape codes
+        assert_eq!(Palette::new(Theme::Mono, true).paint(Style::Bad, "FAIL"), "FAIL");
+        assert_eq!(Palette::new(Theme::Colorblind, false).paint(Style::Bad, "FAIL"), "FAIL");
+    }
+}
@@ -0,0 +1,89 @@
+//! The user-level config file, `~/.config/hollowcheck/config.toml`.
+//!
+//! It holds personal presentation preferences, never project policy: the
+//! contract decides what fails a scan, this file only decides how the
+//! result looks on one person's terminal. `$XDG_CONFIG_HOME` replaces
+//! `~/.config` when set. A missing file is the same as an empty one.
+//!
+//! ```toml
+//! theme = "colorblind"
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+use crate::analysis::{parse_config, ConfigFormat, ConfigValue};
+
+/// Settings read from the user config file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UserConfig {
+    /// Theme for pretty output, as written; checked when it is resolved.
+    pub theme: Option<String>,
+}
+
+impl UserConfig {
+    /// Location of the user config file, if a home directory is known.
+    pub fn path() -> Option<PathBuf> {
+        let base = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(base.join("hollowcheck").join("config.toml"))
+    }
+
+    /// Load the user config file; empty when there is none.
+    pub fn load() -> Result<Self> {
+        match Self::path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Load a config file; empty when the file does not exist.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        let source = match std::fs::read_to_string(path) {
+            Ok(source) => source,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        };
+        Self::parse(&source).with_context(|| format!("in {}", path.display()))
+    }
+
+    /// Parse config TOML. Unknown keys are ignored so older versions can
+    /// read a file written for newer ones.
+    pub fn parse(source: &str) -> Result<Self> {
+        let mut config = Self::default();
+        for entry in parse_config(ConfigFormat::Toml, source)? {
+            if entry.path == "theme" {
+                match entry.value {
+                    ConfigValue::String(theme) => config.theme = Some(theme),
+                    other => anyhow::bail!("line {}: theme must be a string, got {:?}", entry.line, other),
+                }
+            }
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_user_config() {
+        let config = UserConfig::parse("# personal\ntheme = \"colorblind\"\nfuture_key = 3\n").unwrap();
+        assert_eq!(config.theme.as_deref(), Some("colorblind"));
+        assert_eq!(UserConfig::parse("").unwrap(), UserConfig::default());
+
+        let err = UserConfig::parse("theme = 1\n").unwrap_err();
+        assert!(err.to_string().contains("theme must be a string"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_missing_file_is_empty() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = UserConfig::load_from(&temp.path().join("config.toml")).unwrap();
+        assert_eq!(config, UserConfig::default());
+    }
+}
@@ -0,0 +1,92 @@
+//! Change detection for `lint --watch`.
+//!
+//! The scanned tree is polled: a snapshot records the size and modification
+//! time of every file, and a scan re-runs when a later snapshot differs.
+//! Polling needs no platform file-notification API and is cheap at the
+//! interval used, since only metadata is read. Hidden directories (`.git`)
+//! and dependency and build output directories are not watched.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use walkdir::WalkDir;
+
+/// How often the tree is checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Directories whose changes never trigger a scan.
+const IGNORED_DIRS: &[&str] = &["node_modules", "target", "vendor", "__pycache__", "dist", "build"];
+
+/// Size and modification time of every watched file under a root.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Snapshot(BTreeMap<PathBuf, (u64, Option<SystemTime>)>);
+
+impl Snapshot {
+    /// Record the files under `root`, or `root` itself when it is a file.
+    pub fn take(root: &Path) -> Self {
+        let watched = |entry: &walkdir::DirEntry| {
+            entry.depth() == 0
+                || !entry.file_type().is_dir()
+                || !entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.starts_with('.') || IGNORED_DIRS.contains(&name))
+        };
+        let files = WalkDir::new(root)
+            .into_iter()
+            .filter_entry(watched)
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .filter_map(|entry| {
+                let metadata = entry.metadata().ok()?;
+                Some((entry.into_path(), (metadata.len(), metadata.modified().ok())))
+            })
+            .collect();
+        Self(files)
+    }
+}
+
+/// Call `on_change` once, then again after each change under `root`.
+///
+/// Runs until `on_change` fails. Changes made while it runs are picked up
+/// by the next poll.
+pub fn watch(root: &Path, mut on_change: impl FnMut() -> anyhow::Result<()>) -> anyhow::Result<()> {
+    let mut snapshot = Snapshot::take(root);
+    on_change()?;
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current = Snapshot::take(root);
+        if current != snapshot {
+            snapshot = current;
+            on_change()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_snapshot_changes() {
+        let temp = TempDir::new().unwrap();
+        std::fs::write(temp.path().join("main.go"), "package main\n").unwrap();
+        std::fs::create_dir_all(temp.path().join(".git")).unwrap();
+        std::fs::create_dir_all(temp.path().join("node_modules/left-pad")).unwrap();
+        let before = Snapshot::take(temp.path());
+        assert_eq!(before.0.len(), 1);
+
+        // Ignored directories do not count
+        std::fs::write(temp.path().join(".git/index"), "x").unwrap();
+        std::fs::write(temp.path().join("node_modules/left-pad/index.js"), "x").unwrap();
+        assert_eq!(Snapshot::take(temp.path()), before);
+
+        std::fs::write(temp.path().join("main.go"), "package main\n\nfunc main() {}\n").unwrap();
+        assert_ne!(Snapshot::take(temp.path()), before);
+
+        // A single file is watched on its own
+        assert_eq!(Snapshot::take(&temp.path().join("main.go")).0.len(), 1);
+    }
+}
@@ -0,0 +1,353 @@
+//! Workspace root discovery for runs started inside a sub-project.
+//!
+//! Running `hollowcheck lint .` from `crates/worker/` of a Cargo workspace, or
+//! from `packages/web/` of a pnpm monorepo, scans only that directory, but
+//! sibling packages, manifests, and the contract usually live at the
+//! workspace root. This module walks up from the scan root to find it.
+//!
+//! Markers, checked in each directory from the scan root upwards:
+//! - `Cargo.toml` with a `[workspace]` table
+//! - `pnpm-workspace.yaml`
+//! - `go.work`
+//! - `package.json` with a `workspaces` field (npm/yarn)
+//!
+//! The walk stops at the first directory containing `.git`; if no marker was
+//! found by then, that repository root is used as a last resort.
+
+use std::path::{Path, PathBuf};
+
+use globset::{Glob, GlobSetBuilder};
+use walkdir::WalkDir;
+
+/// Directories never searched for workspace members.
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target", "vendor", "dist", "build"];
+
+/// Maximum depth below the workspace root at which members are looked for.
+const MAX_MEMBER_DEPTH: usize = 5;
+
+/// The marker a workspace root was recognized by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WorkspaceKind {
+    /// `Cargo.toml` with a `[workspace]` table.
+    Cargo,
+    /// `pnpm-workspace.yaml`.
+    Pnpm,
+    /// `package.json` with a `workspaces` field.
+    Npm,
+    /// `go.work`.
+    GoWork,
+    /// Repository root (`.git`), used when no workspace marker exists.
+    Git,
+}
+
+impl WorkspaceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkspaceKind::Cargo => "cargo",
+            WorkspaceKind::Pnpm => "pnpm",
+            WorkspaceKind::Npm => "npm",
+            WorkspaceKind::GoWork => "go-work",
+            WorkspaceKind::Git => "git",
+        }
+    }
+}
+
+/// A workspace enclosing the scanned directory.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    /// Directory containing the workspace marker.
+    pub root: PathBuf,
+    /// Which marker identified the workspace.
+    pub kind: WorkspaceKind,
+}
+
+/// Returns true if `dir/Cargo.toml` declares a `[workspace]` table.
+fn is_cargo_workspace(dir: &Path) -> bool {
+    std::fs::read_to_string(dir.join("Cargo.toml"))
+        .map(|content| content.lines().any(|l| l.trim() == "[workspace]"))
+        .unwrap_or(false)
+}
+
+/// Returns the `workspaces` patterns of `dir/package.json`, if it has any.
+fn npm_workspace_patterns(dir: &Path) -> Option<Vec<String>> {
+    let content = std::fs::read_to_string(dir.join("package.json")).ok()?;
+    let json: serde_json::Value = serde_json::from_str(&content).ok()?;
+    let workspaces = json.get("workspaces")?;
+    // Either `["packages/*"]` or yarn's `{ "packages": ["packages/*"] }`
+    let list = workspaces.as_array().or_else(|| workspaces.get("packages")?.as_array())?;
+    Some(
+        list.iter()
+            .filter_map(|v| v.as_str().map(String::from))
+            .collect(),
+    )
+}
+
+/// Identify a workspace marker in a single directory.
+fn marker_in(dir: &Path) -> Option<WorkspaceKind> {
+    if is_cargo_workspace(dir) {
+        Some(WorkspaceKind::Cargo)
+    } else if dir.join("pnpm-workspace.yaml").is_file() {
+        Some(WorkspaceKind::Pnpm)
+    } else if dir.join("go.work").is_file() {
+        Some(WorkspaceKind::GoWork)
+    } else if npm_workspace_patterns(dir).is_some() {
+        Some(WorkspaceKind::Npm)
+    } else {
+        None
+    }
+}
+
+/// Find the workspace enclosing `start` (a directory or a file in it).
+///
+/// Returns `None` when no marker and no `.git` directory exists between
+/// `start` and the filesystem root.
+pub fn find_workspace(start: &Path) -> Option<Workspace> {
+    let start = if start.is_file() { start.parent()? } else { start };
+
+    for dir in start.ancestors() {
+        if let Some(kind) = marker_in(dir) {
+            return Some(Workspace {
+                root: dir.to_path_buf(),
+                kind,
+            });
+        }
+        // `.git` is a file in worktrees and submodules
+        if dir.join(".git").exists() {
+            return Some(Workspace {
+                root: dir.to_path_buf(),
+                kind: WorkspaceKind::Git,
+            });
+        }
+    }
+    None
+}
+
+/// Extract the `members` patterns of a Cargo `[workspace]` table.
+fn cargo_member_patterns(content: &str) -> Vec<String> {
+    let mut in_workspace = false;
+    let mut in_members = false;
+    let mut patterns = Vec::new();
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') && !in_members {
+            in_workspace = trimmed == "[workspace]";
+            continue;
+        }
+        if !in_workspace {
+            continue;
+        }
+
+        let rest = if in_members {
+            trimmed
+        } else if let Some(value) = trimmed
+            .strip_prefix("members")
+            .and_then(|r| r.trim_start().strip_prefix('='))
+        {
+            in_members = true;
+            value.trim_start().trim_start_matches('[')
+        } else {
+            continue;
+        };
+
+        let (items, closed) = match rest.find(']') {
+            Some(end) => (&rest[..end], true),
+            None => (rest, false),
+        };
+        patterns.extend(
+            items
+                .split(',')
+                .map(|s| s.trim().trim_matches('"').trim_matches('\''))
+                .filter(|s| !s.is_empty() && !s.starts_with('#'))
+                .map(String::from),
+        );
+        if closed {
+            in_members = false;
+        }
+    }
+
+    patterns
+}
+
+/// Extract the `use` directories of a `go.work` file.
+fn go_work_patterns(content: &str) -> Vec<String> {
+    let mut patterns = Vec::new();
+    let mut in_block = false;
+
+    for line in content.lines() {
+        let line = line.split("//").next().unwrap_or("").trim();
+        if in_block {
+            if line == ")" {
+                in_block = false;
+            } else if !line.is_empty() {
+                patterns.push(line.to_string());
+            }
+        } else if let Some(rest) = line.strip_prefix("use") {
+            let rest = rest.trim();
+            if rest == "(" {
+                in_block = true;
+            } else if !rest.is_empty() {
+                patterns.push(rest.to_string());
+            }
+        }
+    }
+
+    patterns
+}
+
+impl Workspace {
+    /// Member package patterns declared by the workspace marker.
+    ///
+    /// Patterns are relative to the root; a leading `!` excludes matches.
+    fn member_patterns(&self) -> Vec<String> {
+        let read = |name: &str| std::fs::read_to_string(self.root.join(name)).unwrap_or_default();
+
+        match self.kind {
+            WorkspaceKind::Cargo => cargo_member_patterns(&read("Cargo.toml")),
+            WorkspaceKind::Pnpm => {
+                #[derive(serde::Deserialize)]
+                struct PnpmWorkspace {
+                    #[serde(default)]
+                    packages: Vec<String>,
+                }
+                serde_yaml::from_str::<PnpmWorkspace>(&read("pnpm-workspace.yaml"))
+                    .map(|w| w.packages)
+                    .unwrap_or_default()
+            }
+            WorkspaceKind::Npm => npm_workspace_patterns(&self.root).unwrap_or_default(),
+            WorkspaceKind::GoWork => go_work_patterns(&read("go.work")),
+            WorkspaceKind::Git => Vec::new(),
+        }
+    }
+
+    /// Directories of the workspace's member packages, sorted.
+    ///
+    /// Glob patterns (`crates/*`, `packages/**`) are expanded against the
+    /// directories under the root; the root itself is never a member.
+    pub fn member_dirs(&self) -> Vec<PathBuf> {
+        let mut include = GlobSetBuilder::new();
+        let mut exclude = GlobSetBuilder::new();
+        let mut has_include = false;
+
+        for pattern in self.member_patterns() {
+            let (negated, pattern) = match pattern.strip_prefix('!') {
+                Some(p) => (true, p),
+                None => (false, pattern.as_str()),
+            };
+            let pattern = pattern.trim_start_matches("./").trim_end_matches('/');
+            let Ok(glob) = Glob::new(pattern) else {
+                continue;
+            };
+            if negated {
+                exclude.add(glob);
+            } else {
+                include.add(glob);
+                has_include = true;
+            }
+        }
+        if !has_include {
+            return Vec::new();
+        }
+        let (Ok(include), Ok(exclude)) = (include.build(), exclude.build()) else {
+            return Vec::new();
+        };
+
+        let walker = WalkDir::new(&self.root)
+            .min_depth(1)
+            .max_depth(MAX_MEMBER_DEPTH)
+            .into_iter()
+            .filter_entry(|e| {
+                let name = e.file_name().to_string_lossy();
+                e.file_type().is_dir()
+                    && !name.starts_with('.')
+                    && !SKIPPED_DIRS.contains(&name.as_ref())
+            });
+
+        let mut members: Vec<PathBuf> = walker
+            .filter_map(|e| e.ok())
+            .filter(|e| {
+                let rel = e.path().strip_prefix(&self.root).unwrap_or(e.path());
+                let rel = rel.to_string_lossy().replace('\\', "/");
+                include.is_match(&rel) && !exclude.is_match(&rel)
+            })
+            .map(|e| e.into_path())
+            .collect();
+        members.sort();
+        members
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(root: &Path, rel: &str, content: &str) {
+        let path = root.join(rel);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_cargo_workspace_from_leaf_crate() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            "Cargo.toml",
+            "[workspace]\nresolver = \"2\"\nmembers = [\n    \"crates/*\", # all crates\n]\n\n[workspace.dependencies]\nserde = \"1\"\n",
+        );
+        write(root, "crates/worker/Cargo.toml", "[package]\nname = \"worker\"\n");
+        write(root, "crates/shared-models/Cargo.toml", "[package]\nname = \"shared-models\"\n");
+
+        let workspace = find_workspace(&root.join("crates/worker")).unwrap();
+        assert_eq!(workspace.root, root);
+        assert_eq!(workspace.kind, WorkspaceKind::Cargo);
+        assert_eq!(
+            workspace.member_dirs(),
+            vec![root.join("crates/shared-models"), root.join("crates/worker")]
+        );
+    }
+
+    #[test]
+    fn test_pnpm_workspace_from_leaf_package() {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        write(
+            root,
+            "pnpm-workspace.yaml",
+            "packages:\n  - 'packages/*'\n  - '!packages/legacy'\n",
+        );
+        write(root, "package.json", "{\"name\": \"monorepo\", \"private\": true}");
+        write(root, "packages/web/package.json", "{\"name\": \"@acme/web\"}");
+        write(root, "packages/ui/package.json", "{\"name\": \"@acme/ui\"}");
+        write(root, "packages/legacy/package.json", "{\"name\": \"@acme/legacy\"}");
+        // A leaf package.json is not a workspace marker
+        let workspace = find_workspace(&root.join("packages/web/package.json")).unwrap();
+
+        assert_eq!(workspace.root, root);
+        assert_eq!(workspace.kind, WorkspaceKind::Pnpm);
+        assert_eq!(
+            workspace.member_dirs(),
+            vec![root.join("packages/ui"), root.join("packages/web")]
+        );
+    }
+
+    #[test]
+    fn test_go_work_and_git_fallback() {
+        assert_eq!(
+            go_work_patterns("go 1.22\n\nuse (\n\t./api\n\t./shared // models\n)\nuse ./tools\n"),
+            vec!["./api", "./shared", "./tools"]
+        );
+
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join(".git")).unwrap();
+        write(root, "svc/go.mod", "module example.com/svc\n");
+
+        let workspace = find_workspace(&root.join("svc")).unwrap();
+        assert_eq!(workspace.root, root);
+        assert_eq!(workspace.kind, WorkspaceKind::Git);
+        assert!(workspace.member_dirs().is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+pub struct Client {
+    base: String,
+}
+
+impl Client {
+    pub async fn endpoint(&self, path: &str) -> String {
+        format!("{}/{}", self.base, path)
+    }
+
+    pub async fn fetch(&self, path: &str) -> String {
+        let url = self.endpoint(path).await;
+        reqwest::get(url).await.unwrap().text().await.unwrap()
+    }
+}
@@ -0,0 +1,13 @@
+"""Order lookups for the storefront API."""
+
+CACHE = []
+
+
+async def fetch_orders(customer_id):
+    orders = [o for o in CACHE if o.customer == customer_id]
+    return sorted(orders, key=lambda o: o.created_at)
+
+
+async def refresh_orders(client):
+    rows = await client.get("/orders")
+    CACHE[:] = rows
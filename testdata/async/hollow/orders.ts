@@ -0,0 +1,14 @@
+import { Order } from "./types";
+
+const ORDERS: Order[] = [];
+
+export async function fetchOrders(customerId: string): Promise<Order[]> {
+  const orders = ORDERS.filter((o) => o.customer === customerId);
+  return orders;
+}
+
+export async function syncOrders(source: AsyncIterable<Order>): Promise<void> {
+  for await (const order of source) {
+    ORDERS.push(order);
+  }
+}
@@ -0,0 +1,10 @@
+async function getVersion() {
+  return "1.0.0";
+}
+
+async function loadConfig(path) {
+  const text = await readFile(path, "utf8");
+  return JSON.parse(text);
+}
+
+module.exports = { getVersion, loadConfig };
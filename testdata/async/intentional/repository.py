@@ -0,0 +1,25 @@
+"""Storage backends; every backend is used through the async Repository API."""
+
+from abc import ABC, abstractmethod
+
+
+class Repository(ABC):
+    @abstractmethod
+    async def save(self, order):
+        ...
+
+
+class MemoryRepository(Repository):
+    """Async for interface compatibility: callers await every Repository."""
+
+    def __init__(self):
+        self.orders = []
+
+    async def save(self, order):
+        self.orders.append(order)
+
+    async def __aenter__(self):
+        return self
+
+    async def __aexit__(self, *exc):
+        self.orders.clear()
@@ -0,0 +1,14 @@
+import { Order } from "./types";
+
+export interface Repository {
+  save(order: Order): Promise<void>;
+}
+
+// Async for interface compatibility: callers await every Repository.
+export class MemoryRepository implements Repository {
+  private orders: Order[] = [];
+
+  async save(order: Order): Promise<void> {
+    this.orders.push(order);
+  }
+}
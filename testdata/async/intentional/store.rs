@@ -0,0 +1,23 @@
+use std::future::Future;
+
+pub trait Store {
+    async fn get(&self, key: &str) -> Option<String>;
+}
+
+pub struct MemoryStore {
+    map: std::collections::HashMap<String, String>,
+}
+
+// Async for interface compatibility: the trait fixes the signature.
+impl Store for MemoryStore {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.map.get(key).cloned()
+    }
+}
+
+impl MemoryStore {
+    pub async fn deferred_len(&self) -> impl Future<Output = usize> {
+        let len = self.map.len();
+        async move { len }
+    }
+}
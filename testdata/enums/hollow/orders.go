@@ -0,0 +1,24 @@
+package orders
+
+// Status is the lifecycle state of an order.
+type Status int
+
+const (
+	Pending Status = iota
+	Paid
+	Shipped
+	Delivered
+	Cancelled
+)
+
+// Notify sends the customer an email for the order's new state.
+func Notify(o *Order) error {
+	switch o.Status {
+	case Pending:
+		return sendEmail(o.Customer, "We received your order")
+	case Paid:
+		return sendEmail(o.Customer, "Payment confirmed")
+	default:
+		return nil
+	}
+}
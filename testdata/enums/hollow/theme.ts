@@ -0,0 +1,16 @@
+export enum Theme {
+  Light,
+  Dark,
+  HighContrast,
+  System,
+}
+
+export function background(theme: Theme): string {
+  switch (theme) {
+    case Theme.Light:
+      return "#ffffff";
+    default:
+      break;
+  }
+  return "";
+}
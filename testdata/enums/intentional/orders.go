@@ -0,0 +1,25 @@
+package orders
+
+// Status is the lifecycle state of an order.
+type Status int
+
+const (
+	Pending Status = iota
+	Paid
+	Shipped
+	Delivered
+	Cancelled
+)
+
+// Refund returns the payment for orders that have not shipped yet.
+func Refund(o *Order) error {
+	switch o.Status {
+	case Pending:
+		o.Status = Cancelled
+		return nil
+	case Paid:
+		return payments.Refund(o.PaymentID, o.Total)
+	default:
+		return fmt.Errorf("order %s is %v and can no longer be refunded", o.ID, o.Status)
+	}
+}
@@ -0,0 +1,17 @@
+export enum Theme {
+  Light,
+  Dark,
+  HighContrast,
+  System,
+}
+
+export function background(theme: Theme): string {
+  switch (theme) {
+    case Theme.Dark:
+      return "#000000";
+    default:
+      // Every other theme derives its background from the user's palette
+      const palette = loadPalette(theme);
+      return palette.background ?? "#ffffff";
+  }
+}
@@ -5,7 +5,7 @@
 
 use std::path::PathBuf;
 
-use hollowcheck::contract::Contract;
+use hollowcheck::contract::{Contract, UnhandledEnumCasesConfig};
 use hollowcheck::detect::{Runner, ViolationRule};
 use hollowcheck::parser;
 use hollowcheck::score;
@@ -391,3 +391,223 @@ fn test_expected_score_range() {
         hollowness.score, hollowness.threshold
     );
 }
+
+/// Run detection with the default contract over every file in a container fixture directory.
+fn run_container_fixture(name: &str) -> hollowcheck::detect::DetectionResult {
+    setup();
+
+    let dir = testdata_path().join("containers").join(name);
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .expect("should read fixture dir")
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .collect();
+    files.sort();
+
+    Runner::new(&dir)
+        .skip_registry_check(true)
+        .run(&files, &Contract::default_contract())
+        .expect("detection should succeed")
+}
+
+#[test]
+fn test_detection_finds_hollow_containers() {
+    let result = run_container_fixture("hollow");
+
+    let found: Vec<_> = result
+        .violations
+        .iter()
+        .map(|v| {
+            let file = PathBuf::from(v.file.as_deref().unwrap_or(""));
+            let name = file.file_name().unwrap().to_string_lossy().to_string();
+            (name, v.line, v.rule)
+        })
+        .collect();
+
+    for expected in [
+        ("Dockerfile", 1, ViolationRule::HollowContainer),  // node:latest
+        ("Dockerfile", 4, ViolationRule::HollowContainer),  // COPY . . without .dockerignore
+        ("Dockerfile", 7, ViolationRule::MockData),         // API_KEY=CHANGEME
+        ("Dockerfile", 11, ViolationRule::HollowContainer), // HEALTHCHECK exit 0
+        ("Dockerfile", 12, ViolationRule::HollowContainer), // CMD echo
+        ("docker-compose.yml", 3, ViolationRule::HollowContainer), // placeholder image
+        ("docker-compose.yml", 8, ViolationRule::MockData),        // JWT_SECRET=CHANGEME
+        ("docker-compose.yml", 9, ViolationRule::HollowContainer), // empty service
+        ("docker-compose.yml", 12, ViolationRule::HollowContainer), // healthcheck true
+    ] {
+        let expected = (expected.0.to_string(), expected.1, expected.2);
+        assert!(found.contains(&expected), "missing {:?} in {:?}", expected, found);
+    }
+    assert_eq!(found.len(), 9, "{:?}", found);
+}
+
+#[test]
+fn test_minimal_containers_are_clean() {
+    let result = run_container_fixture("minimal");
+
+    assert!(result.violations.is_empty(), "{:?}", result.violations);
+}
+
+/// Run detection with `unhandled_enum_cases` enabled against a fixture directory.
+fn run_enum_fixture(name: &str) -> Vec<(String, usize)> {
+    setup();
+
+    let dir = testdata_path().join("enums").join(name);
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .expect("should read fixture dir")
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .collect();
+    files.sort();
+
+    let mut contract = Contract::default_contract();
+    contract.unhandled_enum_cases = Some(UnhandledEnumCasesConfig {
+        enabled: true,
+        ..Default::default()
+    });
+    let result = Runner::new(&dir)
+        .skip_registry_check(true)
+        .run(&files, &contract)
+        .expect("detection should succeed");
+
+    result
+        .violations
+        .iter()
+        .filter(|v| v.rule == ViolationRule::UnhandledEnumCases)
+        .map(|v| {
+            let file = PathBuf::from(v.file.as_deref().unwrap_or(""));
+            (file.file_name().unwrap().to_string_lossy().to_string(), v.line)
+        })
+        .collect()
+}
+
+#[test]
+fn test_detection_finds_unhandled_enum_cases() {
+    let found = run_enum_fixture("hollow");
+
+    assert_eq!(
+        found,
+        vec![("orders.go".to_string(), 16), ("theme.ts".to_string(), 9)]
+    );
+}
+
+#[test]
+fn test_intentional_enum_defaults_are_clean() {
+    let found = run_enum_fixture("intentional");
+
+    assert!(found.is_empty(), "{:?}", found);
+}
+
+/// Run default detection against an async fixture directory.
+fn run_async_fixture(name: &str) -> Vec<(String, usize)> {
+    setup();
+
+    let dir = testdata_path().join("async").join(name);
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .expect("should read fixture dir")
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .collect();
+    files.sort();
+
+    let result = Runner::new(&dir)
+        .skip_registry_check(true)
+        .run(&files, &Contract::default_contract())
+        .expect("detection should succeed");
+
+    result
+        .violations
+        .iter()
+        .filter(|v| v.rule == ViolationRule::AsyncWithoutAwait)
+        .map(|v| {
+            let file = PathBuf::from(v.file.as_deref().unwrap_or(""));
+            (file.file_name().unwrap().to_string_lossy().to_string(), v.line)
+        })
+        .collect()
+}
+
+#[test]
+fn test_detection_finds_async_without_await() {
+    let found = run_async_fixture("hollow");
+
+    assert_eq!(
+        found,
+        vec![
+            ("client.rs".to_string(), 6),
+            ("orders.py".to_string(), 6),
+            ("orders.ts".to_string(), 5),
+            ("version.js".to_string(), 1),
+        ]
+    );
+}
+
+#[test]
+fn test_intentional_async_functions_are_clean() {
+    let found = run_async_fixture("intentional");
+
+    assert!(found.is_empty(), "{:?}", found);
+}
+
+/// Run detection with `config_files` expectations against a fixture directory.
+fn run_config_fixture(name: &str) -> Vec<(String, usize, ViolationRule)> {
+    setup();
+
+    let dir = testdata_path().join("config").join(name);
+    let mut contract: Contract = serde_yaml::from_str(
+        r#"
+config_files:
+  - pattern: "**/config.yaml"
+    required_keys: [database.url, database.pool]
+  - pattern: "*.json"
+    ranges:
+      - key: Smtp.Port
+        min: 1
+        max: 65535
+  - pattern: "*.toml"
+    required_keys: [database.url, features]
+"#,
+    )
+    .expect("contract should parse");
+    contract.mock_signatures = Contract::default_contract().mock_signatures;
+
+    // Configuration files are found by walking the project, not passed in
+    let result = Runner::new(&dir)
+        .skip_registry_check(true)
+        .run(&[], &contract)
+        .expect("detection should succeed");
+
+    result
+        .violations
+        .iter()
+        .map(|v| (v.file.clone().unwrap_or_default(), v.line, v.rule))
+        .collect()
+}
+
+#[test]
+fn test_detection_finds_hollow_config() {
+    let found = run_config_fixture("hollow");
+
+    let expected: Vec<(String, usize, ViolationRule)> = [
+        ("appsettings.json", 6, ViolationRule::HollowConfig), // "<smtp-host>"
+        ("appsettings.json", 7, ViolationRule::HollowConfig), // port 70000
+        ("appsettings.json", 8, ViolationRule::MockData),     // dummy_user@
+        ("appsettings.json", 10, ViolationRule::HollowConfig), // empty ApiKey
+        ("config.yaml", 4, ViolationRule::HollowConfig),      // port 0
+        ("config.yaml", 6, ViolationRule::HollowConfig),      // url: TODO
+        ("config.yaml", 7, ViolationRule::HollowConfig),      // required pool: {}
+        ("config.yaml", 9, ViolationRule::HollowConfig),      // empty jwt_secret
+        ("settings.toml", 6, ViolationRule::HollowConfig),    // CHANGEME
+        ("settings.toml", 8, ViolationRule::HollowConfig),    // required [features] is empty
+    ]
+    .into_iter()
+    .map(|(file, line, rule)| (file.to_string(), line, rule))
+    .collect();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn test_complete_config_is_clean() {
+    let found = run_config_fixture("complete");
+
+    assert!(found.is_empty(), "{:?}", found);
+}
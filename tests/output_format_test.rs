@@ -6,9 +6,11 @@
 use std::path::PathBuf;
 
 use hollowcheck::contract::Contract;
-use hollowcheck::detect::Runner;
+use hollowcheck::detect::{
+    detect_hallucinated_dependencies, DetectionResult, Runner, Severity, Violation, ViolationRule,
+};
 use hollowcheck::parser;
-use hollowcheck::report::{BreakdownEntry, JsonReport, JsonViolation};
+use hollowcheck::report::{self, BreakdownEntry, JsonOptions, JsonReport, JsonViolation};
 use hollowcheck::score;
 
 fn testdata_path() -> PathBuf {
@@ -50,6 +52,13 @@ fn run_and_get_json() -> JsonReport {
             file: v.file.clone(),
             line: v.line,
             message: v.message.clone(),
+            explanation: None,
+            original_severity: None,
+            downgrade_reason: None,
+            fingerprint: Some(result.fingerprint(v)),
+            language_override: None,
+            marker: None,
+            effort: None,
         })
         .collect();
 
@@ -66,18 +75,34 @@ fn run_and_get_json() -> JsonReport {
     JsonReport {
         version: env!("CARGO_PKG_VERSION").to_string(),
         path: testdata.to_string_lossy().to_string(),
+        scan_root: result.scan_root.clone(),
+        workspace_root: result.workspace_root.clone(),
         contract: contract_path.to_string_lossy().to_string(),
         score: hollowness.score,
         grade: hollowness.grade.clone(),
         threshold: hollowness.threshold,
         passed: hollowness.passed,
         files_scanned: result.scanned,
+        files_rejected: result.rejected,
+        files_excluded_by_language: result.language_excluded,
+        files_errored: result.errored,
+        files_generated: result.generated,
+        files_generated_modified: result.generated_modified,
         violations,
+        truncated: result.truncated.clone(),
         new_violations: vec![],
+        new_truncated: Default::default(),
         baseline_ref: None,
         suppressed: vec![],
         suppressed_count: 0,
+        waived: vec![],
+        waived_count: 0,
         breakdown,
+        debt: Some(hollowness.debt.clone()),
+        sub_scores: Some(hollowness.sub_scores),
+        environment: result.environment.clone(),
+        contract_warnings: result.contract_warnings.clone(),
+        stats: result.stats.clone(),
     }
 }
 
@@ -106,7 +131,10 @@ fn test_json_violations_format() {
     for v in &report.violations {
         assert!(!v.rule.is_empty(), "rule should not be empty");
         assert!(!v.severity.is_empty(), "severity should not be empty");
-        assert!(!v.file.is_empty(), "file should not be empty");
+        assert!(
+            v.file.as_ref().is_none_or(|f| !f.is_empty()),
+            "file should be null (project-level) or non-empty"
+        );
         assert!(!v.message.is_empty(), "message should not be empty");
 
         // Severity should be one of: error, warning, info
@@ -320,3 +348,1999 @@ fn test_expected_violations() {
         complexity_count
     );
 }
+
+/// With the default `--format auto`, piped output is compact JSON.
+#[test]
+fn test_auto_format_emits_compact_json_when_piped() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .arg("lint")
+        .arg(&project)
+        .arg("--contract")
+        .arg(testdata_path().join("test-contract.yaml"))
+        .arg("--skip-registry-check")
+        .output()
+        .expect("should run hollowcheck");
+
+    let stdout = String::from_utf8(output.stdout).expect("stdout should be UTF-8");
+    assert_eq!(stdout.trim_end().lines().count(), 1, "expected compact JSON: {}", stdout);
+    assert!(!stdout.contains('\u{1b}'), "piped output must not contain ANSI escapes");
+
+    let report: JsonReport = serde_json::from_str(&stdout).expect("stdout should be JSON");
+    assert!(report.files_scanned > 0);
+    assert!(!report.violations.is_empty());
+}
+
+/// A result with one file-level and one project-level violation.
+fn mixed_result() -> DetectionResult {
+    let mut result = DetectionResult::new();
+    result.add_violation(Violation {
+        rule: ViolationRule::ForbiddenPattern,
+        message: "forbidden pattern found: TODO".to_string(),
+        file: Some("src/main.go".to_string()),
+        line: 12,
+        severity: Severity::Error,
+    });
+    result.add_violation(Violation {
+        rule: ViolationRule::MissingTest,
+        message: "required test \"TestCheckout\" not found".to_string(),
+        file: None,
+        line: 0,
+        severity: Severity::Warning,
+    });
+    result
+}
+
+#[test]
+fn test_project_level_violation_json() {
+    let result = mixed_result();
+    let hollowness = score::calculate(&result, &Contract::default_contract());
+
+    let json = report::render_json(".", "", &result, &hollowness, JsonOptions::default()).unwrap();
+    let report: JsonReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(report.violations[0].file.as_deref(), Some("src/main.go"));
+    assert_eq!(report.violations[1].file, None);
+    assert!(json.contains("\"file\": null"));
+
+    // The Go schema has no nullable file
+    let options = JsonOptions {
+        go_compat: true,
+        ..JsonOptions::default()
+    };
+    let json = report::render_json(".", "", &result, &hollowness, options).unwrap();
+    let report: JsonReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(report.violations[1].file.as_deref(), Some(""));
+}
+
+#[test]
+fn test_project_level_violation_sarif() {
+    let result = mixed_result();
+    let sarif = report::render_sarif(std::path::Path::new("/work/shop"), &result).unwrap();
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let results = &sarif["runs"][0]["results"];
+
+    let file_location = &results[0]["locations"][0];
+    assert_eq!(
+        file_location["physicalLocation"]["artifactLocation"]["uri"],
+        "src/main.go"
+    );
+    assert!(file_location.get("logicalLocations").is_none());
+
+    let project_location = &results[1]["locations"][0];
+    assert!(project_location.get("physicalLocation").is_none());
+    assert_eq!(project_location["logicalLocations"][0]["name"], "shop");
+    assert_eq!(project_location["logicalLocations"][0]["kind"], "module");
+}
+
+#[test]
+fn test_project_level_violation_pretty() {
+    colored::control::set_override(false);
+    let result = mixed_result();
+    let hollowness = score::calculate(&result, &Contract::default_contract());
+
+    let output = report::render_pretty(".", "", &result, &hollowness, report::PrettyOptions::default());
+    assert!(output.contains("Violations (1):"));
+    assert!(output.contains("src/main.go:12"));
+    assert!(output.contains("Project (1):"));
+    assert!(output.contains("missing_test      required test \"TestCheckout\" not found"));
+    assert!(!output.contains("(project)"));
+}
+
+#[test]
+fn test_debt_in_json_and_pretty() {
+    colored::control::set_override(false);
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\n// hollowcheck:ignore-next-line stub_function - rewrite planned\n\
+         func Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let contract = temp.path().join("contract.yaml");
+    std::fs::write(&contract, "version: \"1.0\"\nname: \"debt\"\nmax_debt: 5\n").unwrap();
+
+    let lint = |format: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--skip-registry-check")
+            .args(["--format", format])
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    // The live score is clean, but the suppressed stub is over max_debt
+    let output = lint("json");
+    assert_eq!(output.status.code(), Some(1));
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report.score, 0);
+    assert!(!report.passed);
+    let debt = report.debt.expect("debt section");
+    assert_eq!((debt.score, debt.max_debt, debt.passed), (10, Some(5), false));
+    assert_eq!(debt.by_mechanism["inline_suppression"].violations, 1);
+    assert_eq!(debt.by_rule["stub_function"].points, 10);
+
+    let stdout = String::from_utf8(lint("pretty").stdout).unwrap();
+    assert!(stdout.contains("Debt: 10 points (1 suppressed)  max 5, exceeded"), "{}", stdout);
+}
+
+#[test]
+fn test_sub_scores_in_json_and_pretty() {
+    colored::control::set_override(false);
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let contract = temp.path().join("contract.yaml");
+    std::fs::write(
+        &contract,
+        "version: \"1.0\"\nname: \"sub-scores\"\nsub_scores:\n  structural_threshold: 5\n",
+    )
+    .unwrap();
+
+    let lint = |format: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--skip-registry-check")
+            .args(["--format", format])
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    // The total is under the default threshold, but the stub is structural
+    let output = lint("json");
+    assert_eq!(output.status.code(), Some(1));
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(report.score <= report.threshold && !report.passed);
+    let sub_scores = report.sub_scores.expect("sub_scores section");
+    assert_eq!(sub_scores.structural.points + sub_scores.content.points, report.score);
+    assert_eq!((sub_scores.structural.threshold, sub_scores.structural.passed), (Some(5), false));
+    assert!(sub_scores.content.passed);
+
+    let stdout = String::from_utf8(lint("pretty").stdout).unwrap();
+    let expected = format!(
+        "Structural: {} (max 5, exceeded), Content: {}",
+        sub_scores.structural.score, sub_scores.content.score
+    );
+    assert!(stdout.contains(&expected), "{}", stdout);
+}
+
+#[test]
+fn test_doc_coverage_in_json_and_pretty() {
+    colored::control::set_override(false);
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("store.go"),
+        "package store\n\n// Store holds items.\ntype Store struct{ n int }\n\n\
+         func (s *Store) Len() int {\n\treturn s.n\n}\n",
+    )
+    .unwrap();
+    let contract = temp.path().join("contract.yaml");
+    std::fs::write(
+        &contract,
+        "version: \"1.0\"\nname: \"docs\"\ndoc_coverage:\n  min_doc_coverage: 0.75\n",
+    )
+    .unwrap();
+
+    let lint = |format: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--skip-registry-check")
+            .args(["--format", format])
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let report: JsonReport = serde_json::from_slice(&lint("json").stdout).unwrap();
+    let coverage = report.stats.doc_coverage.expect("doc_coverage stats");
+    assert_eq!((coverage.documented, coverage.total, coverage.coverage), (1, 2, 0.5));
+    let rules: Vec<_> = report
+        .violations
+        .iter()
+        .filter(|v| v.rule == "doc_coverage")
+        .map(|v| (v.severity.as_str(), v.file.is_some()))
+        .collect();
+    assert_eq!(rules, vec![("info", true), ("error", false)]);
+
+    let stdout = String::from_utf8(lint("pretty").stdout).unwrap();
+    assert!(stdout.contains("1 of 2 exported declarations documented (50.0%)"), "{}", stdout);
+}
+
+#[test]
+fn test_effort_estimate_in_json_and_pretty() {
+    colored::control::set_override(false);
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("store.rs"),
+        "/// Read the file at path and check every section against the schema.\n\
+         pub fn load_config(path: &str, strict: bool) -> Result<Config, Error> {\n    todo!()\n}\n\n\
+         fn drain_queue() {}\n",
+    )
+    .unwrap();
+    std::fs::write(project.join("main.rs"), "fn main() {\n    store::load_config(\"app.yaml\", true).ok();\n}\n").unwrap();
+    let contract = temp.path().join("contract.yaml");
+    std::fs::write(&contract, "version: \"1.0\"\nname: \"effort\"\nestimation: {}\n").unwrap();
+
+    let lint = |format: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--skip-registry-check")
+            .args(["--format", format])
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let report: JsonReport = serde_json::from_slice(&lint("json").stdout).unwrap();
+    let efforts: Vec<_> = report
+        .violations
+        .iter()
+        .filter(|v| v.rule == "stub_function")
+        .map(|v| {
+            let effort = v.effort.as_ref().expect("effort estimate");
+            (v.line, effort.bucket.as_str(), effort.points)
+        })
+        .collect();
+    assert_eq!(efforts, vec![(2, "medium", 10), (6, "trivial", 0)]);
+    let estimate = report.stats.effort.expect("effort stats");
+    assert!(estimate.heuristic);
+    assert_eq!((estimate.medium, estimate.trivial, estimate.weighted_total), (1, 1, 6));
+
+    let stdout = String::from_utf8(lint("pretty").stdout).unwrap();
+    assert!(stdout.contains("estimated remaining work: 1 medium, 1 trivial (heuristic)"), "{}", stdout);
+}
+
+#[test]
+fn test_fingerprints_in_json_sarif_and_pretty() {
+    colored::control::set_override(false);
+    let report = run_and_get_json();
+    let fingerprints: Vec<&str> = report
+        .violations
+        .iter()
+        .map(|v| v.fingerprint.as_deref().expect("every violation has a fingerprint"))
+        .collect();
+    assert!(fingerprints.iter().all(|fp| fp.len() == 16));
+    let unique: std::collections::HashSet<_> = fingerprints.iter().collect();
+    assert_eq!(unique.len(), fingerprints.len());
+
+    let result = mixed_result();
+    let sarif = report::render_sarif(std::path::Path::new("/work/shop"), &result).unwrap();
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let fp = result.fingerprint(&result.violations[0]);
+    assert_eq!(
+        sarif["runs"][0]["results"][0]["partialFingerprints"]["hollowcheck/v1"],
+        fp.as_str()
+    );
+
+    let hollowness = score::calculate(&result, &Contract::default_contract());
+    let options = report::PrettyOptions {
+        show_fingerprints: true,
+        ..Default::default()
+    };
+    let output = report::render_pretty(".", "", &result, &hollowness, options);
+    assert!(output.contains(&format!("fingerprint: {}", fp)));
+    let plain = report::render_pretty(".", "", &result, &hollowness, report::PrettyOptions::default());
+    assert!(!plain.contains(&fp));
+
+    // Not part of the Go schema
+    let options = JsonOptions {
+        go_compat: true,
+        ..JsonOptions::default()
+    };
+    let json = report::render_json(".", "", &result, &hollowness, options).unwrap();
+    assert!(!json.contains("\"fingerprint\""));
+}
+
+#[test]
+fn test_explain_dependencies_attaches_trace_to_json() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("go.mod"),
+        "module example.com/shop\n\nrequire github.com/pkg/errors v0.9.1\n",
+    )
+    .unwrap();
+    let files = vec![project.join("main.go")];
+    std::fs::write(
+        &files[0],
+        "package main\n\nimport (\n\t\"github.com/pkg/errors\"\n\t\"github.com/acme/imaginary\"\n)\n",
+    )
+    .unwrap();
+
+    let contract = Contract::default_contract();
+    let config = contract.dependency_verification.as_ref();
+    let result = detect_hallucinated_dependencies(&project, &files, config, true, None).unwrap();
+    let hollowness = score::calculate(&result, &contract);
+
+    let json = report::render_json(".", "", &result, &hollowness, JsonOptions::default()).unwrap();
+    let report: JsonReport = serde_json::from_str(&json).unwrap();
+    assert_eq!(report.violations.len(), 1);
+
+    let trace = report.violations[0].explanation.as_ref().unwrap();
+    assert_eq!(trace["import"], "github.com/acme/imaginary");
+    assert_eq!(trace["line"], 5);
+    assert_eq!(trace["manifest"]["provider"], "go");
+    assert_eq!(trace["verdict"], "not-in-go-mod");
+    let attempts = trace["manifest"]["explanation"]["attempts"].as_array().unwrap();
+    assert!(attempts.iter().all(|a| a["rule"].is_null()));
+
+    // Without explain mode the field is omitted
+    let result = detect_hallucinated_dependencies(&project, &files, config, false, None).unwrap();
+    let json = report::render_json(".", "", &result, &hollowness, JsonOptions::default()).unwrap();
+    assert!(!json.contains("\"explanation\""));
+}
+
+#[test]
+fn test_diff_command_reports_added_violations() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let write_report = |name: &str, result: &DetectionResult| {
+        let hollowness = score::calculate(result, &Contract::default_contract());
+        let json = report::render_json(".", "", result, &hollowness, JsonOptions::default()).unwrap();
+        let path = temp.path().join(name);
+        std::fs::write(&path, json).unwrap();
+        path
+    };
+    let base = write_report("base.json", &DetectionResult::new());
+    let head = write_report("head.json", &mixed_result());
+
+    let run = |base: &PathBuf, head: &PathBuf| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("diff")
+            .arg("--base")
+            .arg(base)
+            .arg("--head")
+            .arg(head)
+            .arg("--format")
+            .arg("json")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = run(&base, &head);
+    assert_eq!(output.status.code(), Some(1));
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(diff["added"].as_array().unwrap().len(), 2);
+    assert!(diff["removed"].as_array().unwrap().is_empty());
+    assert!(diff["score_delta"].as_i64().unwrap() > 0);
+
+    // Each entry has exactly one fingerprint, the one the head report carries;
+    // a parsed value would hide a repeated key, so count them in the text
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(text.matches("\"fingerprint\":").count(), 2, "{}", text);
+    let head_report: JsonReport = serde_json::from_str(&std::fs::read_to_string(&head).unwrap()).unwrap();
+    let mut added: Vec<_> = diff["added"].as_array().unwrap().iter().map(|v| v["fingerprint"].as_str()).collect();
+    let mut expected: Vec<_> = head_report.violations.iter().map(|v| v.fingerprint.as_deref()).collect();
+    added.sort();
+    expected.sort();
+    assert_eq!(added, expected);
+
+    // The reverse direction only removes violations
+    let output = run(&head, &base);
+    assert_eq!(output.status.code(), Some(0));
+    let diff: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(diff["removed"].as_array().unwrap().len(), 2);
+}
+
+#[test]
+fn test_github_annotations_and_gitlab_report() {
+    let result = mixed_result();
+
+    let annotations = report::render_github_annotations(std::path::Path::new("."), &result);
+    let lines: Vec<_> = annotations.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "::error file=src/main.go,line=12,title=forbidden_pattern::forbidden pattern found: TODO",
+            "::warning title=missing_test::required test \"TestCheckout\" not found",
+        ]
+    );
+
+    let gitlab = report::render_gitlab(std::path::Path::new("."), &result).unwrap();
+    let issues: Vec<report::GitlabIssue> = serde_json::from_str(&gitlab).unwrap();
+    assert_eq!(issues[0].check_name, "forbidden_pattern");
+    assert_eq!(issues[0].severity, "major");
+    assert_eq!(issues[0].location.path, "src/main.go");
+    assert_eq!(issues[0].location.lines.begin, 12);
+    assert_eq!(issues[1].severity, "minor");
+    assert_eq!(issues[1].location.path, ".");
+    assert_ne!(issues[0].fingerprint, issues[1].fingerprint);
+    assert_eq!(issues[0].fingerprint, result.fingerprint(&result.violations[0]));
+}
+
+#[test]
+fn test_editor_format() {
+    let mut result = mixed_result();
+    result.add_violation(Violation {
+        rule: ViolationRule::StubFunction,
+        message: "stub function \"Run\":\nonly contains panic".to_string(),
+        file: Some("/work/app/cmd/run.go".to_string()),
+        line: 0,
+        severity: Severity::Critical,
+    });
+
+    let output = report::render_editor(std::path::Path::new("/work/app"), &result);
+    let lines: Vec<_> = output.lines().collect();
+    assert_eq!(
+        lines,
+        vec![
+            "src/main.go:12:1: error: [forbidden_pattern] forbidden pattern found: TODO",
+            ".:1:1: warning: [missing_test] required test \"TestCheckout\" not found",
+            "cmd/run.go:1:1: error: [stub_function] stub function \"Run\": only contains panic",
+        ]
+    );
+}
+
+/// `--profile` and `HOLLOWCHECK_PROFILE` merge a profile over the contract.
+#[test]
+fn test_contract_profile_selection() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.join("hollowcheck.yaml"),
+        "threshold: 100\nprofiles:\n  ci:\n    threshold: 0\n",
+    )
+    .unwrap();
+
+    let run = |profile_arg: Option<&str>, profile_env: Option<&str>| {
+        let mut cmd = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"));
+        cmd.arg("lint")
+            .arg(&project)
+            .args(["--format", "json", "--skip-registry-check"])
+            .current_dir(&project)
+            .env_remove("HOLLOWCHECK_PROFILE");
+        if let Some(profile) = profile_arg {
+            cmd.args(["--profile", profile]);
+        }
+        if let Some(profile) = profile_env {
+            cmd.env("HOLLOWCHECK_PROFILE", profile);
+        }
+        cmd.output().expect("should run hollowcheck")
+    };
+
+    let threshold = |output: &std::process::Output| {
+        let report: JsonReport = serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+        report.threshold
+    };
+
+    let output = run(None, None);
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(threshold(&output), 100);
+
+    let output = run(Some("ci"), None);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(threshold(&output), 0);
+
+    let output = run(None, Some("ci"));
+    assert_eq!(threshold(&output), 0);
+
+    let output = run(Some("release"), Some("ci"));
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown profile \"release\""));
+}
+
+/// Waived violations are listed with their waiver and do not fail the run,
+/// unless `--strict-waivers` finds one inside its window.
+#[test]
+fn test_contract_waivers() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(project.join("export")).unwrap();
+    std::fs::write(
+        project.join("export/csv.go"),
+        "package export\n\nfunc Export() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.join("hollowcheck.yaml"),
+        "threshold: 0\nwaivers:\n  - rule: stub_function\n    path: \"export/**\"\n    owner: \"@data-team\"\n    justification: phase 2 implements the export path\n    expires: 2999-12-31\n",
+    )
+    .unwrap();
+
+    let run = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .args(args)
+            .current_dir(&project)
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = run(&["lint", ".", "--format", "json", "--skip-registry-check"]);
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(report.violations.is_empty());
+    assert_eq!(report.waived_count, 1);
+    assert_eq!(report.waived[0].violation.rule, "stub_function");
+    assert_eq!(report.waived[0].waiver.owner, "@data-team");
+    assert_eq!(report.waived[0].waiver.expires, "2999-12-31");
+
+    let output = run(&["lint", ".", "--format", "json", "--skip-registry-check", "--strict-waivers", "400000"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("expires 2999-12-31"));
+
+    let output = run(&["waivers", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(0));
+    let entries: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(entries[0]["status"], "active");
+    assert_eq!(entries[0]["path"], "export/**");
+}
+
+/// `--show-clean-rules` lists checked rules that found nothing with 0 points.
+#[test]
+fn test_show_clean_rules() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("csv.go"),
+        "package export\n\nfunc Export() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    std::fs::write(project.join("hollowcheck.yaml"), "threshold: 100\n").unwrap();
+
+    let breakdown = |extra: &[&str]| -> Vec<BreakdownEntry> {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .args(["lint", ".", "--format", "json", "--skip-registry-check"])
+            .args(extra)
+            .current_dir(&project)
+            .output()
+            .expect("should run hollowcheck");
+        let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+        report.breakdown
+    };
+
+    let entry = |entries: &[BreakdownEntry], rule: &str| {
+        entries
+            .iter()
+            .find(|e| e.rule == rule)
+            .map(|e| (e.points, e.violations))
+    };
+
+    let default = breakdown(&[]);
+    assert!(entry(&default, "stub_function").is_some());
+    assert_eq!(entry(&default, "hollow_todo"), None);
+
+    let clean = breakdown(&["--show-clean-rules"]);
+    assert_eq!(entry(&clean, "stub_function"), entry(&default, "stub_function"));
+    assert_eq!(entry(&clean, "hollow_todo"), Some((0, 0)));
+    // Rules the contract does not enable stay out
+    assert_eq!(entry(&clean, "repeated_literal"), None);
+}
+
+#[test]
+fn test_doctor_json_offline() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .args(["doctor", "--offline", "--format", "json"])
+        .output()
+        .expect("should run hollowcheck");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stdout));
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["offline"], true);
+    assert!(report["registries"].as_array().unwrap().is_empty());
+    let analyzers = report["analyzers"].as_array().unwrap();
+    assert!(analyzers.iter().any(|a| a["language"] == "go" && a["grammar"] == "tree-sitter-go"));
+    assert!(analyzers.iter().all(|a| a["passed"] == true));
+    assert_eq!(report["runtimes"].as_array().unwrap().len(), 3);
+}
+
+#[test]
+fn test_severity_summary_line() {
+    let mut result = mixed_result();
+    result.add_violation(
+        Violation::new(ViolationRule::MockData, "mock data found: example.com")
+            .at("src/main.go", 20)
+            .with_severity(Severity::Warning),
+    );
+    let hollowness = score::calculate(&result, &Contract::default_contract());
+    assert_eq!(
+        report::render_severity_summary(&result, &hollowness),
+        format!("hollowcheck: 1E 2W ({}, {}%)", hollowness.grade, hollowness.score)
+    );
+
+    let clean = DetectionResult::new();
+    let hollowness = score::calculate(&clean, &Contract::default_contract());
+    assert_eq!(report::render_severity_summary(&clean, &hollowness), "hollowcheck: clean (A, 0%)");
+}
+
+#[test]
+fn test_severity_summary_flag() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let contract = temp.path().join("contract.yaml");
+    std::fs::write(&contract, "version: \"1.0\"\nname: \"summary\"\n").unwrap();
+
+    let lint = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--skip-registry-check")
+            .arg("--severity-summary")
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = lint(&["--quiet"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(stdout.lines().count(), 1, "{}", stdout);
+    assert!(stdout.starts_with("hollowcheck: "), "{}", stdout);
+    assert!(stdout.contains("E "), "{}", stdout);
+
+    // Quiet prints nothing once the tree is clean
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(\"ok\")\n}\n",
+    )
+    .unwrap();
+    let output = lint(&["--quiet"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty(), "{}", String::from_utf8_lossy(&output.stdout));
+}
+
+#[test]
+fn test_count_only_flag() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n\n\
+         func Render() {\n\tpanic(\"not implemented\")\n}\n\n\
+         // hollowcheck:ignore-next-line stub_function - scheduled for removal\n\
+         func Legacy() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let contract = temp.path().join("contract.yaml");
+    std::fs::write(&contract, "version: \"1.0\"\nname: \"counts\"\n").unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .arg("lint")
+        .arg(&project)
+        .arg("--contract")
+        .arg(&contract)
+        .arg("--skip-registry-check")
+        .arg("--count-only")
+        .output()
+        .expect("should run hollowcheck");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+
+    // The suppressed stub is not counted
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(lines.contains(&"stub_function: 2"), "{}", stdout);
+    let total: usize = lines
+        .iter()
+        .filter(|l| !l.starts_with("total: "))
+        .map(|l| l.rsplit_once(": ").unwrap().1.parse::<usize>().unwrap())
+        .sum();
+    assert_eq!(lines.last(), Some(&format!("total: {}", total).as_str()), "{}", stdout);
+}
+
+#[test]
+fn test_cargo_subcommand_scans_workspace_root() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let root = temp.path().join("project");
+    for (path, content) in [
+        ("Cargo.toml", "[workspace]\nmembers = [\"crates/*\"]\n"),
+        ("crates/app/Cargo.toml", "[package]\nname = \"app\"\n"),
+        ("crates/app/src/lib.rs", "pub fn run() -> i32 {\n    todo!()\n}\n"),
+        ("crates/worker/Cargo.toml", "[package]\nname = \"worker\"\n"),
+        ("crates/worker/src/lib.rs", "pub fn process(x: i32) -> i32 {\n    unimplemented!()\n}\n"),
+    ] {
+        std::fs::create_dir_all(root.join(path).parent().unwrap()).unwrap();
+        std::fs::write(root.join(path), content).unwrap();
+    }
+
+    // Cargo runs `cargo hollowcheck` with the subcommand name as the first argument
+    let run = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("hollowcheck")
+            .args(args)
+            .arg("--skip-registry-check")
+            .current_dir(root.join("crates/app"))
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = run(&["--message-format", "json"]);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let messages: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("each line should be JSON"))
+        .collect();
+    let (finished, diagnostics) = messages.split_last().unwrap();
+    assert_eq!(finished["reason"], "build-finished", "{}", stdout);
+    assert_eq!(finished["success"], true);
+
+    // Run from a member, the scan still covers the whole workspace
+    let mut files: Vec<&str> = diagnostics
+        .iter()
+        .map(|m| {
+            assert_eq!(m["reason"], "compiler-message");
+            assert_eq!(m["message"]["code"]["code"], "stub_function");
+            let span = &m["message"]["spans"][0];
+            assert_eq!(span["line_start"], 1);
+            assert_eq!(span["column_start"], 1);
+            span["file_name"].as_str().unwrap()
+        })
+        .collect();
+    files.sort();
+    assert_eq!(files.len(), 2, "{}", stdout);
+    assert!(files[0].ends_with("crates/app/src/lib.rs"), "{:?}", files);
+    assert!(files[1].ends_with("crates/worker/src/lib.rs"), "{:?}", files);
+
+    let output = run(&["--message-format", "human"]);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("error: stub_function\n --> crates/app/src/lib.rs:1:1\n"), "{}", stderr);
+    assert!(stderr.contains("1 | pub fn run() -> i32 {\n"), "{}", stderr);
+    assert!(output.stdout.is_empty());
+
+    // Without the `cargo` feature there is no `cargo metadata` to resolve packages with
+    if !cfg!(feature = "cargo") {
+        let output = run(&["--package", "worker"]);
+        assert_eq!(output.status.code(), Some(2));
+        assert!(String::from_utf8_lossy(&output.stderr).contains("`cargo` feature"));
+    }
+}
+
+#[test]
+fn test_ci_command_writes_platform_outputs() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let artifact = temp.path().join("sarif.json");
+    let summary = temp.path().join("summary.md");
+
+    let ci = || {
+        let mut command = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"));
+        command
+            .arg("ci")
+            .arg(&project)
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .env_remove("GITLAB_CI")
+            .env_remove("JENKINS_URL")
+            .env("GITHUB_ACTIONS", "true")
+            .env("GITHUB_BASE_REF", "")
+            .env("GITHUB_STEP_SUMMARY", &summary);
+        command
+    };
+
+    let output = ci().arg("--dry-run").output().expect("should run hollowcheck");
+    let plan = String::from_utf8(output.stdout).unwrap();
+    assert!(plan.contains("platform:      github"), "{}", plan);
+    assert!(plan.contains("hollowcheck.sarif (sarif)"), "{}", plan);
+    assert!(plan.contains("changed since: full scan"), "{}", plan);
+
+    let output = ci()
+        .arg("--output")
+        .arg(&artifact)
+        .output()
+        .expect("should run hollowcheck");
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.lines().any(|l| l.starts_with("::error file=main.go,line=3")), "{}", stdout);
+
+    let sarif: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&artifact).unwrap()).unwrap();
+    assert_eq!(sarif["version"], "2.1.0");
+    let summary = std::fs::read_to_string(&summary).unwrap();
+    assert!(summary.starts_with("## hollowcheck"), "{}", summary);
+    assert!(summary.contains("`main.go:3`"), "{}", summary);
+}
+
+/// Linting with a Go-version contract points at `migrate contract`, whose
+/// output lints cleanly.
+#[test]
+fn test_go_contract_is_detected_and_migrated() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(project.join("main.go"), "package main\n\nfunc main() {}\n").unwrap();
+    let go_contract = testdata_path().join("migrate/go_contract.yaml");
+    let migrated = temp.path().join("hollowcheck.yaml");
+
+    let lint = |contract: &std::path::Path| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(contract)
+            .arg("--skip-registry-check")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = lint(&go_contract);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("looks like a contract for the Go version"), "{}", stderr);
+    assert!(stderr.contains("hollowcheck migrate contract"), "{}", stderr);
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .args(["migrate", "contract"])
+        .arg(&go_contract)
+        .arg("--output")
+        .arg(&migrated)
+        .output()
+        .expect("should run hollowcheck");
+    assert!(output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("Warning: rules.magic_numbers"), "{}", stderr);
+
+    let output = lint(&migrated);
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(!stderr.contains("Go version"), "{}", stderr);
+    let report: JsonReport = serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+    assert_eq!(report.threshold, 20);
+}
+
+#[test]
+fn test_violation_limits_bound_report_but_not_score() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    for name in ["a", "b", "c"] {
+        std::fs::write(
+            project.join(format!("{}.go", name)),
+            "package main\n\nfunc Load() {\n\tpanic(\"not implemented\")\n}\n\n\
+             func Save() {\n\tpanic(\"not implemented\")\n}\n",
+        )
+        .unwrap();
+    }
+
+    let lint = |extra: &[&str]| {
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .arg("--format")
+            .arg("json")
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck");
+        serde_json::from_slice::<JsonReport>(&output.stdout).expect("stdout should be JSON")
+    };
+
+    let full = lint(&[]);
+    let bounded = lint(&["--max-violations-per-rule", "1"]);
+    assert!(full.truncated.is_empty());
+    assert_eq!(bounded.score, full.score);
+    assert_eq!(bounded.passed, full.passed);
+    assert!(!bounded.truncated.is_empty());
+
+    // Every rule keeps one violation, and the rest are counted
+    for (rule, omitted) in &bounded.truncated {
+        let total = full.violations.iter().filter(|v| &v.rule == rule).count();
+        let shown: Vec<_> = bounded.violations.iter().filter(|v| &v.rule == rule).collect();
+        assert_eq!(shown.len() + omitted, total, "{}", rule);
+        assert_eq!(shown.len(), 1, "{}", rule);
+    }
+    assert_eq!(
+        bounded.violations.len() + bounded.truncated.values().sum::<usize>(),
+        full.violations.len()
+    );
+
+    // The same violations survive every run
+    let again = lint(&["--max-violations-per-rule", "1"]);
+    let keys = |report: &JsonReport| {
+        report
+            .violations
+            .iter()
+            .map(|v| (v.rule.clone(), v.file.clone(), v.line))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(keys(&again), keys(&bounded));
+}
+
+/// `--fix-suppressions` previews its edits, refuses files with unstaged
+/// changes, and leaves a tree that lints with the violations suppressed.
+#[test]
+fn test_fix_suppressions_previews_and_refuses_dirty_files() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    let source = "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n";
+    std::fs::write(project.join("main.go"), source).unwrap();
+
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(&project)
+            .env("GIT_AUTHOR_NAME", "test")
+            .env("GIT_AUTHOR_EMAIL", "test@example.com")
+            .env("GIT_COMMITTER_NAME", "test")
+            .env("GIT_COMMITTER_EMAIL", "test@example.com")
+            .output()
+            .expect("should run git")
+            .status;
+        assert!(status.success(), "git {:?}", args);
+    };
+    git(&["init", "-q"]);
+    git(&["add", "-A"]);
+    git(&["commit", "-q", "-m", "init"]);
+
+    let lint = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .args(extra)
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = lint(&["--fix-suppressions", "--diff-only"]);
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(diff.starts_with("--- a/main.go\n+++ b/main.go\n"), "{}", diff);
+    assert!(diff.contains("\n+// hollowcheck:ignore-next-line stub_function - "), "{}", diff);
+    assert_eq!(std::fs::read_to_string(project.join("main.go")).unwrap(), source);
+
+    // Unstaged changes are never overwritten without --force
+    let edited = format!("{}// local edit\n", source);
+    std::fs::write(project.join("main.go"), &edited).unwrap();
+    let output = lint(&["--fix-suppressions", "--yes"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unstaged changes"));
+    assert_eq!(std::fs::read_to_string(project.join("main.go")).unwrap(), edited);
+
+    git(&["commit", "-q", "-a", "-m", "edit"]);
+    let output = lint(&["--fix-suppressions", "--yes"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Suppressed "));
+
+    let output = lint(&["--format", "json"]);
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(report.violations.iter().all(|v| v.file.as_deref() != Some("main.go")));
+}
+
+/// `--only-new` lists and gates on the violations a baseline report does
+/// not have, and collapses the rest to a count.
+#[test]
+fn test_only_new_against_baseline() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let baseline = temp.path().join("baseline.json");
+
+    let lint = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .args(extra)
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = lint(&["--format", "json"]);
+    assert_eq!(output.status.code(), Some(1));
+    std::fs::write(&baseline, &output.stdout).unwrap();
+    let baselined = serde_json::from_slice::<JsonReport>(&output.stdout).unwrap().violations.len();
+    let only_new = ["--format", "pretty", "--baseline", baseline.to_str().unwrap(), "--only-new"];
+
+    // Nothing new: the baselined stub no longer fails the run
+    let output = lint(&only_new);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.code(), Some(0), "{}", stdout);
+    assert!(!stdout.contains("Violations ("), "{}", stdout);
+    let summary = format!("{} pre-existing violations in the baseline not shown", baselined);
+    assert!(stdout.contains(&summary), "{}", stdout);
+
+    // A second stub, moving the first one down, is the only new violation
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nfunc Validate() {\n\tpanic(\"not implemented\")\n}\n\n\
+         func Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+    let output = lint(&only_new);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(stdout.contains("New violations (1):"), "{}", stdout);
+    assert!(stdout.contains("main.go:3"), "{}", stdout);
+    assert!(!stdout.contains("main.go:7"), "{}", stdout);
+
+    // Without --only-new the whole report is shown and gated, with new ones recorded
+    let output = lint(&["--format", "json", "--baseline", baseline.to_str().unwrap()]);
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report.new_violations.len(), 1);
+    assert_eq!(report.violations.len(), baselined + 1);
+    assert_eq!(report.baseline_ref.as_deref(), baseline.to_str());
+
+    // An unreachable remote baseline warns and treats every violation as new
+    let unreachable = "http://127.0.0.1:1/baselines/main.json";
+    let output = lint(&["--format", "json", "--baseline", unreachable]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("comparing against an empty baseline"), "{}", stderr);
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report.new_violations.len(), report.violations.len());
+    assert_eq!(report.baseline_ref.as_deref(), Some(unreachable));
+
+    // The report limits bound the new violations too, counting what they leave out
+    let output = lint(&["--format", "json", "--baseline", unreachable, "--max-violations-total", "1"]);
+    let bounded: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(bounded.new_violations.len(), 1);
+    assert_eq!(
+        bounded.new_violations.len() + bounded.new_truncated.values().sum::<usize>(),
+        report.new_violations.len()
+    );
+}
+
+/// Serve `body` over HTTP to every connection, for remote baselines.
+fn serve_baseline(body: Vec<u8>) -> String {
+    use std::io::{BufRead, BufReader, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/baselines/main.json", listener.local_addr().unwrap());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).is_ok_and(|n| n > 0) && line != "\r\n" {
+                line.clear();
+            }
+            let head = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", body.len());
+            let _ = stream.write_all(head.as_bytes());
+            let _ = stream.write_all(&body);
+        }
+    });
+    url
+}
+
+/// A baseline made from one checkout matches an identical tree at another
+/// path, with or without fingerprints in the baseline, and when fetched from
+/// an artifact store.
+#[test]
+fn test_baseline_from_another_checkout_path() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let source = "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n";
+    let lint = |project: &std::path::Path, extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(project)
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .args(extra)
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let mut projects = Vec::new();
+    for checkout in ["runner", "local"] {
+        let project = temp.path().join(checkout).join("project");
+        std::fs::create_dir_all(&project).unwrap();
+        std::fs::write(project.join("main.go"), source).unwrap();
+        projects.push(project);
+    }
+
+    for (name, extra) in [("baseline.json", &[][..]), ("go-compat.json", &["--go-compat"][..])] {
+        let baseline = temp.path().join(name);
+        let output = lint(&projects[0], &[&["--format", "json"], extra].concat());
+        let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+        assert!(!report.violations.is_empty());
+        std::fs::write(&baseline, &output.stdout).unwrap();
+
+        let url = serve_baseline(output.stdout);
+        for location in [baseline.to_str().unwrap(), &url] {
+            let output = lint(&projects[1], &["--format", "json", "--baseline", location]);
+            let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+            assert_eq!(report.baseline_ref.as_deref(), Some(location));
+            assert!(
+                report.new_violations.is_empty(),
+                "{} from {}: {:?}",
+                name,
+                location,
+                report.new_violations.iter().map(|v| &v.message).collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_environment_block_and_mismatch() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(project.join("go.mod"), "module example.com/app\n\ngo 1.19\n").unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\nimport \"fmt\"\n\nfunc main() {\n\tfmt.Println(\"ok\")\n}\n",
+    )
+    .unwrap();
+
+    let lint = |contract: &str| {
+        let contract_path = temp.path().join("contract.yaml");
+        std::fs::write(&contract_path, contract).unwrap();
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract_path)
+            .arg("--skip-registry-check")
+            .args(["--format", "json"])
+            .output()
+            .expect("should run hollowcheck");
+        serde_json::from_slice::<JsonReport>(&output.stdout).unwrap()
+    };
+
+    // Without an environment section the versions are only reported
+    let report = lint("version: \"1\"\nname: test\n");
+    assert_eq!(report.environment.len(), 1);
+    assert_eq!(report.environment[0].name, "go");
+    assert_eq!(report.environment[0].declared, "1.19");
+    assert!(!report.violations.iter().any(|v| v.rule == "environment_mismatch"));
+
+    let report = lint("version: \"1\"\nname: test\nenvironment:\n  go: \"1.22\"\n");
+    let mismatches: Vec<_> = report
+        .violations
+        .iter()
+        .filter(|v| v.rule == "environment_mismatch")
+        .map(|v| (v.file.as_deref(), v.line, v.message.as_str()))
+        .collect();
+    assert_eq!(
+        mismatches,
+        vec![(
+            Some("go.mod"),
+            3,
+            "go version mismatch: go.mod declares 1.19 (go directive), contract expects 1.22"
+        )]
+    );
+    assert_eq!(report.environment[0].expected.as_deref(), Some("1.22"));
+}
+
+#[test]
+fn test_only_and_skip_rules() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("main.go"),
+        "package main\n\n// TODO: implement\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n",
+    )
+    .unwrap();
+
+    let lint = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .args(["--format", "json"])
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck")
+    };
+    let rules = |output: &std::process::Output| -> std::collections::BTreeSet<String> {
+        let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+        report.violations.into_iter().map(|v| v.rule).collect()
+    };
+
+    let all = rules(&lint(&[]));
+    assert!(all.contains("stub_function") && all.len() > 1, "{:?}", all);
+
+    let only = rules(&lint(&["--only", "stub_function", "--only", "mock-data"]));
+    let expected: std::collections::BTreeSet<String> = all
+        .iter()
+        .filter(|r| *r == "stub_function" || *r == "mock_data")
+        .cloned()
+        .collect();
+    assert_eq!(only, expected);
+
+    let skipped = rules(&lint(&["--skip", "stub_function"]));
+    assert!(!skipped.contains("stub_function"));
+    assert_eq!(skipped.len(), all.len() - 1);
+
+    let output = lint(&["--only", "no_such_rule"]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unknown rule: no_such_rule"));
+}
+
+/// An unknown `--language` is rejected as a bad flag value, not as a
+/// problem with the contract.
+#[test]
+fn test_unknown_language_flag() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let lint = |language: &str| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(temp.path())
+            .arg("--contract")
+            .arg(testdata_path().join("test-contract.yaml"))
+            .arg("--skip-registry-check")
+            .args(["--language", language])
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = lint("go,cobol");
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("--language") && stderr.contains("unknown language \"cobol\""), "{}", stderr);
+    assert!(!stderr.contains("invalid contract"), "{}", stderr);
+
+    assert_ne!(lint("go,python").status.code(), Some(2));
+}
+
+#[test]
+fn test_config_dump_prints_effective_contract() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let contract = temp.path().join("hollowcheck.yaml");
+    std::fs::write(
+        &contract,
+        "version: \"1.0\"\nname: layered\nthreshold: 50\nprofiles:\n  ci:\n    threshold: 10\n    god_objects:\n      enabled: true\n",
+    )
+    .unwrap();
+
+    let dump = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(temp.path())
+            .arg("--contract")
+            .arg(&contract)
+            .args(["--profile", "ci", "--strict", "--config-dump"])
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = dump(&[]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("#   threshold: profile \"ci\""), "{}", stdout);
+    assert!(stdout.contains("#   god_objects.max_file_lines: --strict"), "{}", stdout);
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&stdout).unwrap();
+    assert_eq!(yaml["threshold"], serde_yaml::Value::from(10));
+    assert!(!stdout.contains("Hollowness"), "dump should not scan");
+
+    let output = dump(&["--format", "json", "--threshold", "5"]);
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["contract"]["threshold"], 5);
+    assert_eq!(json["contract"]["name"], "layered");
+    assert_eq!(json["sources"]["threshold"], "--threshold");
+    assert_eq!(json["sources"]["god_objects.enabled"], "profile \"ci\"");
+}
+
+#[test]
+fn test_language_overrides_route_unusual_files() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(project.join("tools")).unwrap();
+    let padded = "def process(items):\n    count = len(items)\n    0\n    return count\n";
+    std::fs::write(project.join("tools/rules.star"), padded).unwrap();
+    std::fs::write(project.join("deploy"), format!("#!/bin/sh\n# hollowcheck:language python\n{}", padded)).unwrap();
+    std::fs::write(project.join("notes.star"), padded).unwrap();
+    let contract = project.join("hollowcheck.yaml");
+    std::fs::write(
+        &contract,
+        "version: \"1.0\"\nname: overrides\nlanguage_overrides:\n  \"tools/*.star\": python\n",
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .arg("lint")
+        .arg(&project)
+        .arg("--contract")
+        .arg(&contract)
+        .args(["--skip-registry-check", "--format", "json", "--only", "noop_statement"])
+        .output()
+        .expect("should run hollowcheck");
+    let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+    let mut noops: Vec<(String, Option<String>)> = report
+        .violations
+        .into_iter()
+        .map(|v| {
+            let file = v.file.unwrap_or_default();
+            let name = file.rsplit('/').next().unwrap_or_default().to_string();
+            (name, v.language_override)
+        })
+        .collect();
+    noops.sort();
+    assert_eq!(
+        noops,
+        vec![
+            ("deploy".to_string(), Some("python".to_string())),
+            ("rules.star".to_string(), Some("python".to_string())),
+        ]
+    );
+}
+
+#[test]
+fn test_marker_fields_in_json_report() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("billing.py"),
+        "def charge(order):\n    # TODO(PAY-118): implement this\n    # Fixme: handle\n    return order.total\n",
+    )
+    .unwrap();
+    std::fs::write(project.join("refund.go"), "package billing\n\nfunc Refund() {\n\t// PENDING\n}\n").unwrap();
+    let contract = project.join("hollowcheck.yaml");
+    std::fs::write(
+        &contract,
+        "version: \"1.0\"\nname: markers\nmarkers:\n  words: [PENDING]\n  ticket_pattern: 'PAY-\\d+'\n",
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .arg("lint")
+        .arg(&project)
+        .arg("--contract")
+        .arg(&contract)
+        .args(["--skip-registry-check", "--format", "json", "--only", "hollow_todo", "--only", "stub_function"])
+        .output()
+        .expect("should run hollowcheck");
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let violations = report["violations"].as_array().unwrap();
+    let mut found: Vec<_> = violations
+        .iter()
+        .map(|v| {
+            let file = v["file"].as_str().unwrap().rsplit('/').next().unwrap();
+            (file, v["rule"].as_str().unwrap(), v["line"].as_u64().unwrap(), v["severity"].as_str().unwrap())
+        })
+        .collect();
+    found.sort();
+    // The added PENDING word counts for stub detection as well as hollow TODOs
+    assert_eq!(
+        found,
+        vec![
+            ("billing.py", "hollow_todo", 2, "info"),
+            ("billing.py", "hollow_todo", 3, "warning"),
+            ("refund.go", "hollow_todo", 4, "warning"),
+            ("refund.go", "stub_function", 3, "warning"),
+        ]
+    );
+
+    let mut hollow: Vec<_> = violations.iter().filter(|v| v["rule"] == "hollow_todo").collect();
+    hollow.sort_by_key(|v| (v["file"].as_str().unwrap().to_string(), v["line"].as_u64()));
+    assert_eq!(hollow[0]["marker"], serde_json::json!({"kind": "TODO", "text": "implement this", "ticket": "PAY-118"}));
+    assert_eq!(hollow[1]["marker"], serde_json::json!({"kind": "FIXME", "text": "handle"}));
+    assert_eq!(hollow[2]["marker"]["kind"], "PENDING");
+}
+
+#[test]
+fn test_fleet_report_isolates_failures() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let fleet = temp.path().join("fleet");
+    std::fs::create_dir_all(fleet.join("api")).unwrap();
+    std::fs::create_dir_all(fleet.join("web")).unwrap();
+    std::fs::write(fleet.join("api/app.py"), "def handler(req):\n    # TODO: implement\n    return None\n").unwrap();
+    std::fs::write(fleet.join("web/index.js"), "export function add(a, b) {\n  return a + b;\n}\n").unwrap();
+    std::fs::write(
+        fleet.join("strict.yaml"),
+        "version: \"1.0\"\nthreshold: 0\nrequired_files:\n  - path: README.md\n    required: true\n",
+    )
+    .unwrap();
+    std::fs::write(
+        fleet.join("repos.yaml"),
+        "repos:\n  - path: api\n    contract: strict.yaml\n    labels: {team: payments}\n  - path: web\n    labels: {team: frontend}\n  - path: missing\n    name: legacy\n    labels: {team: payments}\n",
+    )
+    .unwrap();
+    // A report left by an earlier run of a repository that can no longer be scanned
+    let reports = fleet.join("reports");
+    std::fs::create_dir_all(&reports).unwrap();
+    std::fs::write(reports.join("legacy.json"), "{}").unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .arg("fleet")
+        .arg("--manifest")
+        .arg(fleet.join("repos.yaml"))
+        .arg("--reports-dir")
+        .arg(&reports)
+        .args(["--format", "json", "--jobs", "2", "--skip-registry-check"])
+        .output()
+        .expect("should run hollowcheck");
+
+    assert_eq!(output.status.code(), Some(1));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!((json["total"].as_u64(), json["passed"].as_u64()), (Some(3), Some(1)));
+    assert_eq!((json["failed"].as_u64(), json["errors"].as_u64()), (Some(1), Some(1)));
+
+    let rows: Vec<(&str, &str)> = json["repos"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|r| (r["name"].as_str().unwrap(), r["status"].as_str().unwrap()))
+        .collect();
+    assert_eq!(rows, vec![("legacy", "error"), ("api", "failed"), ("web", "passed")]);
+    assert!(json["repos"][0]["error"].as_str().unwrap().contains("cannot access path"));
+    assert_eq!(json["rules"]["missing_file"]["repos"], 1);
+    assert_eq!(json["labels"]["team"]["payments"]["repos"], 2);
+    assert_eq!(json["labels"]["team"]["payments"]["errors"], 1);
+
+    // Full reports under stable names; none for the repository that failed
+    let api: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(reports.join("api.json")).unwrap()).unwrap();
+    assert_eq!(api["threshold"], 0);
+    assert!(reports.join("web.json").exists());
+    assert!(!reports.join("legacy.json").exists());
+}
+
+#[test]
+fn test_prefetch_warms_caches_without_linting() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(project.join("vendor/lib")).unwrap();
+    std::fs::write(project.join("app.py"), "import os\nimport requests\n\ndef handler():\n    # TODO: implement\n    pass\n").unwrap();
+    std::fs::write(project.join("vendor/lib/left.py"), "import left_pad\n").unwrap();
+    std::fs::write(
+        project.join("hollowcheck.yaml"),
+        "version: \"1.0\"\nexcluded_paths:\n  - \"**/vendor/**\"\ndependency_verification:\n  allowlist: [requests]\n",
+    )
+    .unwrap();
+
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+        .arg("prefetch")
+        .arg(&project)
+        .arg("--contract")
+        .arg(project.join("hollowcheck.yaml"))
+        .args(["--format", "json"])
+        .output()
+        .expect("should run hollowcheck");
+
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["files"], 1);
+    assert_eq!(json["stdlib"][0]["language"], "python");
+    assert!(json["stdlib"][0]["modules"].as_u64().unwrap() > 0);
+    // `requests` is allowlisted and the excluded vendor file is never read
+    assert_eq!(json["registries"].as_array().unwrap().len(), 0);
+    assert!(json.get("skipped").is_none());
+    // No detection ran
+    assert!(json.get("violations").is_none());
+}
+
+#[test]
+fn test_stub_exemptions_remove_builtin_by_id() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(
+        project.join("payments.rs"),
+        "pub struct Payment;\n\npub fn on_payment_received(payment: Payment) {}\n\npub fn visit_payment(payment: &Payment) {}\n",
+    )
+    .unwrap();
+
+    let stubs = |contract_body: &str| -> Vec<String> {
+        let contract = temp.path().join("contract.yaml");
+        std::fs::write(&contract, format!("version: \"1.0\"\nname: \"stubs\"\n{}", contract_body)).unwrap();
+        let output = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .arg("--skip-registry-check")
+            .args(["--format", "json"])
+            .output()
+            .expect("should run hollowcheck");
+        let report: JsonReport = serde_json::from_slice(&output.stdout).unwrap();
+        report
+            .violations
+            .iter()
+            .filter(|v| v.rule == "stub_function")
+            .map(|v| v.message.clone())
+            .collect()
+    };
+
+    // The built-in rust-on-hooks rule exempts the empty handler
+    let flagged = stubs("");
+    assert_eq!(flagged.len(), 1, "{:?}", flagged);
+    assert!(flagged[0].contains("visit_payment"));
+
+    let flagged = stubs(
+        "stub_exemptions:\n  remove: [rust-on-hooks]\n  rules:\n    - id: visitor-noops\n      \
+         name_pattern: \"^visit_\"\n      languages: [rust]\n      kinds: [empty]\n",
+    );
+    assert_eq!(flagged.len(), 1, "{:?}", flagged);
+    assert!(flagged[0].contains("on_payment_received"));
+}
+
+#[test]
+fn test_verify_spec_reports_completion_and_exit_code() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    std::fs::write(
+        project.join("server.go"),
+        "package main\n\nfunc main() {\n\thttp.HandleFunc(\"/health\", health)\n\thttp.HandleFunc(\"/users\", users)\n}\n\nfunc health(w http.ResponseWriter, r *http.Request) {\n\tfmt.Fprintln(w, \"ok\")\n}\n\nfunc users(w http.ResponseWriter, r *http.Request) {\n}\n",
+    )
+    .unwrap();
+    let spec = temp.path().join("spec.json");
+    std::fs::write(
+        &spec,
+        r#"{"version": 1, "name": "health", "expected": [
+            {"type": "file", "path": "server.go"},
+            {"type": "route", "path": "/health"},
+            {"type": "route", "path": "/users"},
+            {"type": "symbol", "name": "Shutdown", "kind": "function"}
+        ]}"#,
+    )
+    .unwrap();
+
+    let verify = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("verify-spec")
+            .arg(&spec)
+            .arg(&project)
+            .args(["--format", "json"])
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = verify(&[]);
+    assert_eq!(output.status.code(), Some(1), "{}", String::from_utf8_lossy(&output.stderr));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["spec_version"], 1);
+    assert_eq!(json["name"], "health");
+    assert_eq!(json["completion"], 50.0);
+    assert_eq!(json["min_completion"], 100.0);
+    let statuses: Vec<_> = json["entries"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|e| (e["type"].as_str().unwrap(), e["status"].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![("file", "satisfied"), ("route", "satisfied"), ("route", "hollow"), ("symbol", "missing")]
+    );
+    assert_eq!(json["entries"][1]["location"], "server.go:4");
+
+    let output = verify(&["--min-completion", "50"]);
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+
+    std::fs::write(&spec, r#"{"version": 2, "expected": []}"#).unwrap();
+    let output = verify(&[]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("unsupported spec version 2"));
+}
+
+#[test]
+fn test_events_stream_follows_schema_and_order() {
+    // TempDir names start with '.', which the scanner treats as hidden
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    for i in 0..12 {
+        std::fs::write(
+            project.join(format!("handler_{}.go", i)),
+            format!("package handlers
+
+// TODO: implement
+func Handle{}() error {{
+	return nil
+}}
+", i),
+        )
+        .unwrap();
+    }
+    let contract = temp.path().join("hollowcheck.yaml");
+    // A slow pattern, so the run has a contract warning to report
+    std::fs::write(
+        &contract,
+        "version: \"1\"\nforbidden_patterns:\n  - pattern: \"(a+)+$\"\n",
+    )
+    .unwrap();
+
+    let run = |format: &str, extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .arg("--contract")
+            .arg(&contract)
+            .args(["--format", "json", "--skip-registry-check", "--events", format])
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    // Fields each event must carry, besides schema_version, seq, and event
+    let schema: &[(&str, &[&str])] = &[
+        ("run_started", &["path", "contract", "profile", "mode", "skip_registry_check", "only_rules", "skip_rules", "languages", "changed_since", "baseline"]),
+        ("phase_started", &["phase"]),
+        ("phase_completed", &["phase", "count", "duration_ms"]),
+        ("file_analyzed", &["file", "text_rule_violations", "analyzed", "total"]),
+        ("registry_batch_completed", &["checked", "total"]),
+        ("warning", &["message"]),
+        ("run_completed", &["score", "grade", "threshold", "passed", "files_scanned", "violations", "by_severity", "duration_ms"]),
+    ];
+
+    for extra in [&[][..], &["--events-fd", "2"][..]] {
+        let output = run("jsonl", extra);
+        assert_ne!(output.status.code(), Some(2), "{}", String::from_utf8_lossy(&output.stderr));
+
+        // Stdout is still exactly the report
+        let report: JsonReport = serde_json::from_slice(&output.stdout).expect("stdout should be the report");
+
+        // Stderr holds the events and nothing else
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let events: Vec<serde_json::Value> = stderr
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap_or_else(|e| panic!("{:?} is not an event: {}", l, e)))
+            .collect();
+        assert!(!events.is_empty());
+
+        let mut open_phase: Option<String> = None;
+        let mut phases = Vec::new();
+        let mut analyzed = 0;
+        let mut analysis_count = None;
+        let mut warnings = Vec::new();
+        for (i, event) in events.iter().enumerate() {
+            assert_eq!(event["schema_version"], 1);
+            assert_eq!(event["seq"], i as u64 + 1);
+            let name = event["event"].as_str().unwrap();
+            let (_, fields) = schema.iter().find(|(n, _)| *n == name).unwrap_or_else(|| panic!("unknown event {}", name));
+            for field in *fields {
+                assert!(event.get(*field).is_some(), "{} lacks {}", name, field);
+            }
+
+            match name {
+                "run_started" => assert_eq!(i, 0),
+                "run_completed" => assert_eq!(i, events.len() - 1),
+                "phase_started" => {
+                    assert!(open_phase.is_none(), "phases overlap at {}", event);
+                    open_phase = event["phase"].as_str().map(String::from);
+                }
+                "phase_completed" => {
+                    let phase = open_phase.take().expect("phase completed before it started");
+                    assert_eq!(event["phase"], phase.as_str());
+                    if phase == "analysis" {
+                        assert_eq!(event["count"], 12);
+                        analysis_count = Some(event["count"].clone());
+                    }
+                    phases.push(phase);
+                }
+                "file_analyzed" | "registry_batch_completed" => {
+                    assert_eq!(open_phase.as_deref(), Some("analysis"), "{} outside analysis", name);
+                    if name == "file_analyzed" {
+                        analyzed += 1;
+                        assert!(!event["file"].as_str().unwrap().starts_with('/'));
+                    }
+                }
+                "warning" => warnings.push(event["message"].as_str().unwrap().to_string()),
+                _ => unreachable!(),
+            }
+        }
+        assert!(warnings.iter().any(|w| w.contains("may match slowly")), "{:?}", warnings);
+        assert_eq!(phases, vec!["discovery", "analysis", "scoring"]);
+        assert_eq!(analyzed, 12);
+
+        let completed = events.last().unwrap();
+        assert_eq!(completed["score"], report.score);
+        assert_eq!(completed["passed"], report.passed);
+        assert_eq!(completed["violations"], report.violations.len());
+        assert_eq!(Some(&completed["files_scanned"]), analysis_count.as_ref());
+    }
+
+    let output = run("xml", &[]);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid event format"));
+}
+
+/// Pretty output of a failing scan, byte for byte, in each theme.
+#[test]
+fn test_pretty_theme_snapshots() {
+    use hollowcheck::theme::Theme;
+
+    let result = mixed_result();
+    let mut contract = Contract::default_contract();
+    contract.threshold = Some(5);
+    contract.sub_scores = Some(hollowcheck::contract::SubScoresConfig {
+        content_threshold: Some(5),
+        ..Default::default()
+    });
+    let hollowness = score::calculate(&result, &contract);
+    let render = |theme| {
+        let options = report::PrettyOptions {
+            theme,
+            color: Some(true),
+            ..Default::default()
+        };
+        report::render_pretty(".", "contract.yaml", &result, &hollowness, options)
+            .replace(env!("CARGO_PKG_VERSION"), "VERSION")
+    };
+
+    let expected = [
+        "",
+        "  \x1b[1;36mhollowcheck\x1b[0m vVERSION",
+        "",
+        "  \x1b[2mScanning: \x1b[0m.",
+        "  \x1b[2mContract: \x1b[0mcontract.yaml",
+        "",
+        "  \x1b[31m✗ FAIL\x1b[0m  Hollowness: \x1b[1;32m10\x1b[0m% (minimal)  Grade: \x1b[1;32mA\x1b[0m  \x1b[2mStructural:\x1b[0m 0, \x1b[2mContent:\x1b[0m \x1b[31m10 (max 5, exceeded)\x1b[0m",
+        "",
+        "  \x1b[1mViolations\x1b[0m (1):",
+        "",
+        "    \x1b[31mERROR\x1b[0m    \x1b[2mforbidden_pattern \x1b[0m\x1b[34msrc/main.go\x1b[0m\x1b[2m:12\x1b[0m",
+        "            forbidden pattern found: TODO",
+        "",
+        "",
+        "  \x1b[1mProject\x1b[0m (1):",
+        "",
+        "    \x1b[33mWARN \x1b[0m    \x1b[2mmissing_test      \x1b[0mrequired test \"TestCheckout\" not found",
+        "",
+        "  \x1b[1mBreakdown:\x1b[0m",
+        "    forbidden_pattern     10 pts (1 violation)",
+        "    missing_test           5 pts (1 violation)",
+        "",
+        "  \x1b[2mThreshold: 5\x1b[0m  Score: \x1b[1;32m10\x1b[0m (minimal)  \x1b[31mFAILED\x1b[0m",
+        "",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(render(Theme::Default), expected);
+
+    let expected = [
+        "",
+        "  \x1b[1;38;2;86;180;233mhollowcheck\x1b[0m vVERSION",
+        "",
+        "  \x1b[2mScanning: \x1b[0m.",
+        "  \x1b[2mContract: \x1b[0mcontract.yaml",
+        "",
+        "  \x1b[38;2;213;94;0m✗ FAIL\x1b[0m  Hollowness: \x1b[1;38;2;0;114;178m10\x1b[0m% (minimal)  Grade: \x1b[1;38;2;0;114;178mA\x1b[0m  \x1b[2mStructural:\x1b[0m 0, \x1b[2mContent:\x1b[0m \x1b[38;2;213;94;0m10 (max 5, exceeded)\x1b[0m",
+        "",
+        "  \x1b[1mViolations\x1b[0m (1):",
+        "",
+        "    \x1b[38;2;213;94;0m✖ ERROR\x1b[0m    \x1b[2mforbidden_pattern \x1b[0m\x1b[38;2;86;180;233msrc/main.go\x1b[0m\x1b[2m:12\x1b[0m",
+        "            forbidden pattern found: TODO",
+        "",
+        "",
+        "  \x1b[1mProject\x1b[0m (1):",
+        "",
+        "    \x1b[38;2;230;159;0m▲ WARN \x1b[0m    \x1b[2mmissing_test      \x1b[0mrequired test \"TestCheckout\" not found",
+        "",
+        "  \x1b[1mBreakdown:\x1b[0m",
+        "    forbidden_pattern     10 pts (1 violation)",
+        "    missing_test           5 pts (1 violation)",
+        "",
+        "  \x1b[2mThreshold: 5\x1b[0m  Score: \x1b[1;38;2;0;114;178m10\x1b[0m (minimal)  \x1b[38;2;213;94;0mFAILED\x1b[0m",
+        "",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(render(Theme::Colorblind), expected);
+
+    let expected = [
+        "",
+        "  hollowcheck vVERSION",
+        "",
+        "  Scanning: .",
+        "  Contract: contract.yaml",
+        "",
+        "  ✗ FAIL  Hollowness: 10% (minimal)  Grade: A  Structural: 0, Content: 10 (max 5, exceeded)",
+        "",
+        "  Violations (1):",
+        "",
+        "    ✖ ERROR    forbidden_pattern src/main.go:12",
+        "            forbidden pattern found: TODO",
+        "",
+        "",
+        "  Project (1):",
+        "",
+        "    ▲ WARN     missing_test      required test \"TestCheckout\" not found",
+        "",
+        "  Breakdown:",
+        "    forbidden_pattern     10 pts (1 violation)",
+        "    missing_test           5 pts (1 violation)",
+        "",
+        "  Threshold: 5  Score: 10 (minimal)  FAILED",
+        "",
+        "",
+    ]
+    .join("\n");
+    assert_eq!(render(Theme::Mono), expected);
+
+    // Mono never writes escape codes, even when colors are forced on
+    assert!(!render(Theme::Mono).contains('\x1b'));
+}
+
+/// `--theme` beats `HOLLOWCHECK_THEME`, which beats the user config.
+#[test]
+fn test_theme_from_flag_env_and_user_config() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    std::fs::create_dir(&project).unwrap();
+    std::fs::write(project.join("main.go"), "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n").unwrap();
+    let config_home = temp.path().join("config");
+    std::fs::create_dir_all(config_home.join("hollowcheck")).unwrap();
+    std::fs::write(config_home.join("hollowcheck/config.toml"), "theme = \"colorblind\"\n").unwrap();
+
+    let lint = |args: &[&str], env_theme: Option<&str>| {
+        let mut command = std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"));
+        command
+            .arg("lint")
+            .arg(&project)
+            .arg("--skip-registry-check")
+            .args(["--format", "pretty"])
+            .args(args)
+            .env("XDG_CONFIG_HOME", &config_home)
+            .env_remove("NO_COLOR")
+            .env_remove("HOLLOWCHECK_THEME");
+        if let Some(theme) = env_theme {
+            command.env("HOLLOWCHECK_THEME", theme);
+        }
+        command.output().expect("should run hollowcheck")
+    };
+
+    // Not a terminal, so no colors; the symbols still show the theme
+    let stdout = String::from_utf8(lint(&[], None).stdout).unwrap();
+    assert!(stdout.contains("✖ ERROR") && !stdout.contains('\x1b'), "{}", stdout);
+    let stdout = String::from_utf8(lint(&[], Some("default")).stdout).unwrap();
+    assert!(stdout.contains("    ERROR") && !stdout.contains('✖'), "{}", stdout);
+    let stdout = String::from_utf8(lint(&["--theme", "mono"], Some("default")).stdout).unwrap();
+    assert!(stdout.contains("✖ ERROR"), "{}", stdout);
+
+    let output = lint(&["--theme", "neon"], None);
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid --theme"));
+
+    // A malformed user config only warns, and the default theme is used
+    for config in ["theme = [\"colorblind\"]\n", "theme = \"neon\"\n"] {
+        std::fs::write(config_home.join("hollowcheck/config.toml"), config).unwrap();
+        let output = lint(&[], None);
+        assert_eq!(output.status.code(), Some(1));
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("Warning: ignoring"), "{}", stderr);
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(stdout.contains("    ERROR") && !stdout.contains('✖'), "{}", stdout);
+    }
+}
+
+/// `--list-files` prints the scan scope with each file's analyzer and exits
+/// before any rule runs, without writing a report.
+#[test]
+fn test_list_files_prints_scope_without_scanning() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    for dir in ["src", "legacy", "node_modules/left-pad"] {
+        std::fs::create_dir_all(project.join(dir)).unwrap();
+    }
+    std::fs::write(project.join("main.go"), "package main\n\nfunc Process() {\n\tpanic(\"not implemented\")\n}\n").unwrap();
+    std::fs::write(project.join("src/app.py"), "def handle():\n    pass\n").unwrap();
+    std::fs::write(project.join("Dockerfile"), "FROM ubuntu\n").unwrap();
+    std::fs::write(project.join("legacy/old.go"), "package legacy\n").unwrap();
+    std::fs::write(project.join("node_modules/left-pad/index.js"), "module.exports = 1;\n").unwrap();
+
+    let lint = |extra: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_hollowcheck"))
+            .arg("lint")
+            .arg(&project)
+            .args(["--format", "json", "--skip-registry-check", "--list-files", "--exclude", "**/legacy/**"])
+            .args(extra)
+            .output()
+            .expect("should run hollowcheck")
+    };
+
+    let output = lint(&[]);
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let listed: Vec<Vec<&str>> = stdout
+        .lines()
+        .map(|line| line.split("  ").map(str::trim).filter(|s| !s.is_empty()).collect())
+        .collect();
+    assert_eq!(
+        listed,
+        vec![
+            vec!["Dockerfile", "skipped: no analyzer"],
+            vec!["main.go", "go"],
+            vec!["src/app.py", "python"],
+        ],
+        "{}",
+        stdout
+    );
+    // No report, though main.go holds a stub
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_err(), "{}", stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("3 files would be scanned"), "{}", stderr);
+
+    // The event stream ends after discovery: no rule ran
+    let output = lint(&["--events", "jsonl"]);
+    assert_eq!(output.status.code(), Some(0));
+    let events: Vec<serde_json::Value> = String::from_utf8(output.stderr)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap_or_else(|e| panic!("{}: {:?}", e, line)))
+        .collect();
+    let kinds: Vec<_> = events
+        .iter()
+        .map(|e| format!("{} {}", e["event"].as_str().unwrap(), e["phase"].as_str().unwrap_or("")))
+        .collect();
+    assert_eq!(kinds, vec!["run_started ", "phase_started discovery", "phase_completed discovery"]);
+    assert_eq!(events[2]["count"], 3);
+}